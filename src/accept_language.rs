@@ -1,15 +1,19 @@
-use crate::*;
+#[cfg(feature = "rocket")]
+use crate::rocket_impl::PrivConfig;
+use crate::{locale::Locale, negotiation::Candidate, source::LangSource, *};
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
+#[cfg(feature = "rocket")]
 use rocket::Request;
+#[cfg(feature = "rocket")]
+use std::convert::Infallible;
 
 static PATTERN: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?:^|,| )(\w{1,3})(?:-\w{1,3})? ?(?:;q=([\d\.]+))?").unwrap());
+    Lazy::new(|| Regex::new(r"(?:^|,| )(\w{1,3})((?:-\w+)*) ?(?:;q=([\d\.]+))?").unwrap());
 
-fn accept_language<'a>(req: &'a Request<'_>) -> &'a str {
-    req.headers()
-        .get("Accept-Language")
-        .next()
+fn accept_language(source: &(impl LangSource + ?Sized)) -> &str {
+    source
+        .accept_language()
         .unwrap_or("en")
 }
 
@@ -32,67 +36,179 @@ fn quality_from_capture(capt: &Captures) -> f32 {
         .unwrap_or(1.0)
 }
 
-fn from_regex_capture(cap: Captures) -> Option<(LangCode, f32)> {
-    let lang = lang_from_capture(&cap)?;
-    let q = quality_from_capture(&cap);
-    Some((lang, q))
+/// Returns the tag of a capture. Subtags after the script and region, such as
+/// variants and extensions, are ignored.
+fn locale_from_capture(capt: &Captures) -> Option<Locale> {
+    let lang = lang_from_capture(capt)?;
+    let subtags: Vec<&str> = capt[2]
+        .split('-')
+        .skip(1)
+        .take(2)
+        .collect();
+    let tag = (0..=subtags.len())
+        .rev()
+        .find_map(|len| {
+            [&capt[1]]
+                .into_iter()
+                .chain(subtags[..len].iter().copied())
+                .collect::<Vec<_>>()
+                .join("-")
+                .parse()
+                .ok()
+        });
+    Some(tag.unwrap_or(Locale::new(lang)))
 }
 
-pub(crate) fn languages(text: &'_ str) -> impl Iterator<Item = (LangCode, f32)> + '_ {
+/// Parses the tags of an accept language header, with their quality.
+fn tags(text: &'_ str) -> impl Iterator<Item = (Locale, f32)> + '_ {
     PATTERN
         .captures_iter(text)
-        .flat_map(from_regex_capture)
+        .flat_map(|cap| Some((locale_from_capture(&cap)?, quality_from_capture(&cap))))
 }
 
-struct Decider<'a> {
-    lang: Option<LangCode>,
-    q: Option<f32>,
-    config: &'a Config,
-}
+/// The languages accepted by the client, sorted by quality from highest to lowest.
+/// Languages with the same quality keep the order of the `Accept-Language` header.
+/// Only the languages supported by the attached `Config` are kept, so the first
+/// one can be used for the content, and the next ones as alternatives:
+#[cfg_attr(feature = "rocket", doc = "```rust")]
+#[cfg_attr(not(feature = "rocket"), doc = "```ignore")]
+/// # use rocket::get;
+/// # use rocket_lang::*;
+/// #[get("/video")]
+/// fn video(accepted: AcceptedLanguages) -> String {
+///     let audio = accepted.get(0).unwrap_or(En);
+///     let subtitles = accepted.get(1).unwrap_or(audio);
+///     format!("audio: {audio}, subtitles: {subtitles}")
+/// }
+/// ```
+/// If no `Config` is attached, every language in the header is kept.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AcceptedLanguages(Vec<(LangCode, f32)>);
 
-impl<'a> Decider<'a> {
-    fn new(config: &'a Config) -> Self {
-        Self {
-            lang: None,
-            q: None,
-            config,
+impl AcceptedLanguages {
+    /// Parses an `Accept-Language` header value, keeping only the languages with
+    /// a non-zero quality in the configuration. Each language is kept once,
+    /// with the highest quality it was requested with.
+    pub fn new(header: &str, config: Option<&Config>) -> Self {
+        let mut accepted: Vec<(LangCode, f32)> = vec![];
+        let supported = tags(header)
+            .filter(|(tag, q)| {
+                *q > 0.0 && config.is_none_or(|config| config.tag_quality(tag) > 0.0)
+            })
+            .map(|(tag, q)| (tag.lang(), q));
+        for (lang, q) in supported {
+            match accepted
+                .iter_mut()
+                .find(|(accepted, _)| *accepted == lang)
+            {
+                Some(entry) => entry.1 = entry.1.max(q),
+                None => accepted.push((lang, q)),
+            }
         }
+        // the sort is stable, so ties keep the header order.
+        accepted.sort_by(|(_, q1), (_, q2)| q2.total_cmp(q1));
+        Self(accepted)
     }
-    fn is_none(&self) -> bool {
-        self.lang.is_none()
+
+    /// Returns the accepted languages of a request.
+    #[cfg(feature = "rocket")]
+    pub fn for_request(req: &Request) -> Self {
+        let config = req
+            .rocket()
+            .state::<PrivConfig>()
+            .map(|config| config.0.for_request(req));
+        let header = req
+            .headers()
+            .get_one("Accept-Language")
+            .unwrap_or("");
+        Self::new(header, config)
     }
-    fn compare(&mut self, lang2: LangCode, qclient2: f32) {
-        let lang1 = self.lang.unwrap();
-        let qclient1 = self.q.unwrap();
-        let qserver1 = self.config[lang1];
-        let qserver2 = self.config[lang2];
-        if (qserver1 - qserver2) / qserver1 < (qclient2 - qclient1) / qclient2 {
-            self.lang = Some(lang2);
-            self.q = Some(qclient2)
-        }
+
+    /// Returns the language at the given rank, starting from 0.
+    pub fn get(&self, index: usize) -> Option<LangCode> {
+        self.0
+            .get(index)
+            .map(|&(lang, _)| lang)
     }
-    fn add_preference(&mut self, lang: LangCode, q: f32) {
-        if self.config[lang] == 0.0 || self.config[lang].is_nan() {
-            return;
-        }
-        if self.is_none() {
-            self.lang = Some(lang);
-            self.q = Some(q);
-        } else {
-            self.compare(lang, q);
-        }
+
+    /// Returns the quality the client requested a language with,
+    /// or `None` if it was not accepted.
+    pub fn quality(&self, lang: LangCode) -> Option<f32> {
+        self.iter()
+            .find(|&(accepted, _)| accepted == lang)
+            .map(|(_, q)| q)
     }
-    fn result(&self) -> Result<LangCode, Error> {
-        self.lang
-            .ok_or(Error::NotAcceptable)
+
+    /// Returns an iterator over the languages and their qualities, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (LangCode, f32)> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns an iterator over the languages, in order.
+    pub fn languages(&self) -> impl Iterator<Item = LangCode> + '_ {
+        self.iter()
+            .map(|(lang, _)| lang)
+    }
+
+    /// Returns the amount of accepted languages.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if none of the languages are accepted.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AcceptedLanguages {
+    type Error = Infallible;
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(AcceptedLanguages::for_request(request))
     }
 }
 
-pub(crate) fn with_config(req: &Request, config: &Config) -> Result<LangCode, Error> {
-    let header = accept_language(req);
-    let mut decider = Decider::new(config);
-    for (lang, q) in languages(header) {
-        decider.add_preference(lang, q);
+/// Returns the tag the client requested a language with, such as `pt-BR` for `pt`.
+/// Among the tags of the language, the one with the highest quality in the configuration
+/// wins, and then the one with the highest quality in the header, or the first one.
+/// It is the language alone if the client didn't request a supported tag for it.
+pub(crate) fn locale(
+    source: &(impl LangSource + ?Sized),
+    config: Option<&Config>,
+    lang: LangCode,
+) -> Locale {
+    let mut best: Option<(Locale, f32, f32)> = None;
+    for (tag, client) in tags(accept_language(source)) {
+        let server = config.map_or(1.0, |config| config.tag_quality(&tag));
+        if tag.lang() != lang || client <= 0.0 || server <= 0.0 {
+            continue;
+        }
+        if best
+            .as_ref()
+            .is_none_or(|&(_, s, c)| (server, client) > (s, c))
+        {
+            best = Some((tag, server, client));
+        }
     }
-    decider.result()
+    best.map_or_else(|| Locale::new(lang), |(tag, _, _)| tag)
+}
+
+pub(crate) fn with_config(
+    source: &(impl LangSource + ?Sized),
+    config: &Config,
+) -> Result<LangCode, Error> {
+    let candidates: Vec<_> = tags(accept_language(source))
+        .map(|(tag, client)| Candidate {
+            lang: tag.lang(),
+            client,
+            server: config.tag_quality(&tag),
+        })
+        .filter(|candidate| candidate.server > 0.0)
+        .collect();
+    config
+        .strategy
+        .negotiate(&candidates)
+        .ok_or(Error::NotAcceptable)
 }
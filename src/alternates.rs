@@ -0,0 +1,248 @@
+//! Alternate links for every language variant of the current page.
+//!
+//! Search engines use `hreflang` alternates to find the translations of a page.
+//! They are computed from the url position set with `Config::url`,
+//! and the languages with a non-zero quality. If a wildcard is set,
+//! it is used for the `x-default` alternate. Search engines require absolute urls,
+//! so the base url of the site must be set with `Config::base_url`.
+//!
+//! The links can be emitted as `Link` headers by attaching the `AlternateLinks` fairing,
+//! or rendered in a template with the `Alternates` request guard:
+#![cfg_attr(feature = "rocket", doc = "```rust")]
+#![cfg_attr(not(feature = "rocket"), doc = "```ignore")]
+//! # use rocket::get;
+//! # use rocket_lang::alternates::Alternates;
+//! #[get("/<_>/home")]
+//! fn home(alternates: Alternates) -> String {
+//!     // <link rel="alternate" hreflang="en" href="https://example.com/en/home">
+//!     // <link rel="alternate" hreflang="es" href="https://example.com/es/home">
+//!     alternates.html()
+//! }
+//! ```
+#[cfg(feature = "rocket")]
+use crate::rocket_impl::PrivConfig;
+use crate::*;
+#[cfg(feature = "rocket")]
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::Header,
+    serde::Serialize,
+    Orbit, Request, Response, Rocket,
+};
+
+/// A single alternate version of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rocket", derive(Serialize), serde(crate = "rocket::serde"))]
+pub struct Alternate {
+    /// The language code, or `x-default`.
+    pub hreflang: String,
+    /// The absolute url of the alternate page.
+    pub href: String,
+}
+
+/// The alternate versions of the requested page.
+/// It serializes as a list of `{ hreflang, href }` objects,
+/// so it can be passed to a template context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rocket",
+    derive(Serialize),
+    serde(crate = "rocket::serde", transparent)
+)]
+pub struct Alternates(Vec<Alternate>);
+
+impl Alternates {
+    /// Computes the alternates for the request path with the given configuration.
+    /// It is empty if the base url or the url position isn't set, or if the segment
+    /// at that position is not a language code.
+    /// ```rust
+    /// # use rocket_lang::{*, alternates::Alternates};
+    /// let config = Config::new()
+    ///     .base_url("https://example.com")
+    ///     .url(0)
+    ///     .supported(&[En]);
+    /// let alternates = Alternates::new(&config, "/es/home");
+    /// assert_eq!(alternates.iter().next().unwrap().href, "https://example.com/en/home");
+    /// ```
+    pub fn new(config: &Config, path: &str) -> Self {
+        Self::with_base(config, config.base_url.as_deref(), path)
+    }
+
+    fn with_base(config: &Config, base_url: Option<&str>, path: &str) -> Self {
+        let (Some(base_url), Some(pos)) = (base_url, config.url) else {
+            return Self::default();
+        };
+        let href = |lang| Some(format!("{base_url}{}", url::replace(path, pos, lang)?));
+        let mut alternates: Vec<_> = config
+            .supported_languages()
+            .into_iter()
+            .filter_map(|lang| {
+                Some(Alternate {
+                    hreflang: lang.to_string(),
+                    href: href(lang)?,
+                })
+            })
+            .collect();
+        if alternates.is_empty() {
+            return Self::default();
+        }
+        if let Some(href) = config.wildcard.and_then(href) {
+            alternates.push(Alternate {
+                hreflang: "x-default".into(),
+                href,
+            });
+        }
+        Self(alternates)
+    }
+
+    /// Computes the alternates for a request. The base url of its scope
+    /// is used, or the one of the attached configuration.
+    #[cfg(feature = "rocket")]
+    pub fn for_request(req: &Request) -> Self {
+        match req
+            .rocket()
+            .state::<PrivConfig>()
+        {
+            Some(PrivConfig(config)) => {
+                let path = req.uri().path().as_str();
+                let scoped = config.for_request(req);
+                let base_url = scoped
+                    .base_url
+                    .as_deref()
+                    .or(config.base_url.as_deref());
+                Self::with_base(scoped, base_url, path)
+            }
+            None => Self::default(),
+        }
+    }
+
+    /// Returns an iterator over the alternates.
+    pub fn iter(&self) -> impl Iterator<Item = &Alternate> {
+        self.0.iter()
+    }
+
+    /// Returns true if there are no alternates.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a `Link` header with every alternate, or `None` if there are none.
+    #[cfg(feature = "rocket")]
+    pub fn link_header(&self) -> Option<Header<'static>> {
+        self.link_value()
+            .map(|value| Header::new("Link", value))
+    }
+
+    /// Returns the value of a `Link` header with every alternate,
+    /// or `None` if there are none.
+    pub fn link_value(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let value: Vec<_> = self
+            .iter()
+            .map(|alt| {
+                format!(
+                    "<{}>; rel=\"alternate\"; hreflang=\"{}\"",
+                    link_target(&alt.href),
+                    quoted(&alt.hreflang)
+                )
+            })
+            .collect();
+        Some(value.join(", "))
+    }
+
+    /// Renders the alternates as html `<link>` tags, one per line.
+    pub fn html(&self) -> String {
+        self.iter()
+            .map(|alt| {
+                format!(
+                    "<link rel=\"alternate\" hreflang=\"{}\" href=\"{}\">\n",
+                    sitemap::escape(&alt.hreflang),
+                    sitemap::escape(&alt.href)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Percent-encodes the characters that would end the target of a `Link` header,
+/// or split it into several links.
+fn link_target(href: &str) -> String {
+    let mut target = String::with_capacity(href.len());
+    for c in href.chars() {
+        if matches!(c, '<' | '>' | '"' | ',' | '\\') || c.is_whitespace() || c.is_control() {
+            let mut buf = [0; 4];
+            for byte in c
+                .encode_utf8(&mut buf)
+                .bytes()
+            {
+                target.push_str(&format!("%{byte:02X}"));
+            }
+        } else {
+            target.push(c);
+        }
+    }
+    target
+}
+
+/// Escapes a `Link` header parameter value for use in a quoted string.
+fn quoted(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control())
+        .flat_map(|c| match c {
+            '"' | '\\' => vec!['\\', c],
+            c => vec![c],
+        })
+        .collect()
+}
+
+impl<'a> IntoIterator for &'a Alternates {
+    type Item = &'a Alternate;
+    type IntoIter = std::slice::Iter<'a, Alternate>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Alternates {
+    type Error = std::convert::Infallible;
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(Alternates::for_request(request))
+    }
+}
+
+/// A response fairing that adds a `Link` header
+/// with the alternates of the requested page.
+/// A warning is logged on liftoff if the attached `Config` has no base url.
+#[cfg(feature = "rocket")]
+pub struct AlternateLinks;
+
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl Fairing for AlternateLinks {
+    fn info(&self) -> Info {
+        Info {
+            name: "Alternate language links",
+            kind: Kind::Liftoff | Kind::Response,
+        }
+    }
+    async fn on_liftoff(&self, rocket: &Rocket<Orbit>) {
+        let base_url = rocket
+            .state::<PrivConfig>()
+            .and_then(|config| config.0.base_url.as_deref());
+        if base_url.is_none() {
+            rocket::warn!(
+                "No base url is set with `Config::base_url`, so no alternate links are emitted."
+            );
+        }
+    }
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Some(header) = Alternates::for_request(req).link_header() {
+            res.adjoin_header(header);
+        }
+    }
+}
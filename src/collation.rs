@@ -0,0 +1,122 @@
+//! Locale-aware string comparison, following the Unicode Collation Algorithm.
+//!
+//! Strings are compared first by their base letters, then by their accents, and then by their case,
+//! with the root collation order and the tailorings of a language:
+//! ```rust
+//! # use rocket_lang::{*, collation::sort};
+//! let mut words = ["zebra", "Ähre", "Affe", "Öl", "Ofen"];
+//! sort(De, &mut words);
+//! assert_eq!(words, ["Affe", "Ähre", "Ofen", "Öl", "zebra"]);
+//!
+//! // in Swedish, `å`, `ä` and `ö` are letters of their own, after `z`.
+//! sort(Sv, &mut words);
+//! assert_eq!(words, ["Affe", "Ofen", "zebra", "Ähre", "Öl"]);
+//! ```
+//! The collation data is the CLDR data compiled into `icu_collator`, with the tailorings
+//! of every language that has one. Other languages use the root collation order.
+use crate::*;
+use icu_collator::{options::CollatorOptions, CollatorBorrowed};
+use icu_locale_core::LanguageIdentifier;
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Compares strings in the order of a language.
+/// ```rust
+/// # use rocket_lang::{*, collation::Collator};
+/// use std::cmp::Ordering;
+///
+/// let spanish = Collator::new(Es);
+/// assert_eq!(spanish.compare("nube", "ñandú"), Ordering::Less);
+/// assert_eq!(spanish.compare("ñandú", "oso"), Ordering::Less);
+/// assert_eq!(spanish.compare("role", "rôle"), Ordering::Less);
+/// assert_eq!(spanish.compare("rôle", "Role"), Ordering::Greater);
+/// ```
+#[derive(Debug)]
+pub struct Collator {
+    lang: LangCode,
+    collator: CollatorBorrowed<'static>,
+}
+
+impl Collator {
+    /// Returns the collator of a language.
+    pub fn new(lang: LangCode) -> Self {
+        // Tagalog is collated as Filipino, as in the CLDR.
+        let code = match lang {
+            Tl => "fil",
+            lang => lang.as_str(),
+        };
+        let id = LanguageIdentifier::try_from_str(code).unwrap_or(LanguageIdentifier::UNKNOWN);
+        let collator = icu_collator::Collator::try_new((&id).into(), CollatorOptions::default())
+            .expect("the compiled data has a collation for every locale");
+        Collator { lang, collator }
+    }
+
+    /// Compares two strings.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.collator.compare(a, b)
+    }
+
+    /// Returns a key that orders strings as `compare` does,
+    /// for sorting with `sort_by_cached_key`.
+    pub fn sort_key(&self, text: &str) -> Vec<u8> {
+        let mut key = vec![];
+        let Ok(()) = self
+            .collator
+            .write_sort_key_to(text, &mut key);
+        key
+    }
+}
+
+impl Clone for Collator {
+    fn clone(&self) -> Self {
+        Collator::new(self.lang)
+    }
+}
+
+/// Collators are equal if they are for the same language.
+impl PartialEq for Collator {
+    fn eq(&self, other: &Self) -> bool {
+        self.lang == other.lang
+    }
+}
+
+impl Eq for Collator {}
+
+impl Hash for Collator {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lang.hash(state);
+    }
+}
+
+/// Compares two strings in the order of a language.
+pub fn compare(lang: LangCode, a: &str, b: &str) -> Ordering {
+    Collator::new(lang).compare(a, b)
+}
+
+/// Sorts strings in the order of a language.
+pub fn sort<T: AsRef<str>>(lang: LangCode, items: &mut [T]) {
+    let collator = Collator::new(lang);
+    items.sort_by_cached_key(|item| collator.sort_key(item.as_ref()));
+}
+
+impl Config {
+    /// Returns the supported languages, sorted by their name in a language,
+    /// for a language picker.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// let mut config = Config::new();
+    /// config[En] = 1.0;
+    /// config[De] = 1.0;
+    /// config[Es] = 1.0;
+    /// assert_eq!(config.languages_by_name(Es), [De, Es, En]);
+    /// assert_eq!(config.languages_by_name(En), [En, De, Es]);
+    /// ```
+    pub fn languages_by_name(&self, lang: LangCode) -> Vec<LangCode> {
+        let collator = Collator::new(lang);
+        let mut languages = self.supported_languages();
+        languages.sort_by_cached_key(|code| collator.sort_key(code.display_name_in(lang)));
+        languages
+    }
+}
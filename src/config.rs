@@ -1,48 +1,71 @@
-use crate::*;
-use rocket::{
-    async_trait,
-    fairing::{Fairing, Info, Kind},
-    Build, Data, Request, Rocket, State,
+#[cfg(feature = "rocket")]
+use crate::rocket_impl::{AsyncFn, Fun};
+use crate::source::LangSource;
+use crate::{
+    locale::Locale,
+    negotiation::{Legacy, NegotiationStrategy},
+    *,
 };
 use std::{
     collections::HashMap,
     ops::{Index, IndexMut},
     sync::Arc,
 };
-use std::{future::Future, pin::Pin};
 
-// type aliases for reduced verbosity
-type Fun = fn(&Request) -> Result<LangCode, Error>;
-type AsyncFn = Arc<
-    dyn Fn(
-            &Request,
-        )
-            -> Pin<Box<dyn Future<Output = Result<LangCode, Error>> + Send + Sync + 'static>>
-        + Send
-        + Sync
-        + 'static,
->;
+/// A custom resolver that works with any `LangSource`.
+pub(crate) type Resolver = fn(&dyn LangSource) -> Result<LangCode, Error>;
+
+/// The amount of language codes. Qualities are indexed by the `LangCode` discriminant.
+const LANGUAGES: usize = LangCode::ALL_CODES.len();
 
 /// This struct allows for customization of `LangCode`'s
 /// behavior.
 /// The precedence for every configuration is:
 ///   1. custom closure
-///   2. from url
-///   3. accept language header
-///   4. wildcard
+///   2. custom resolver
+///   3. from url
+///   4. preference cookie
+///   5. accept language header
+///   6. wildcard
 ///
 /// If none of these are able to produce an Ok value, an error is returned.
 /// Note that returning errors is discouraged, as it may lead to a poor user experience.
 ///
+/// The language is resolved the first time a request asks for it, either with the `LangCode`
+/// guard or `LangCode::try_from`, so requests that never use it don't pay for the negotiation.
+/// `try_from` can't wait for an async custom resolver, so if it is the first to ask, and an async
+/// resolver doesn't complete right away, it fails with `Error::Pending` and nothing is cached.
+/// Use `Config::eager` to resolve the language of every request before routing instead.
 ///
 /// ## Custom closure
 /// This configuration has the biggest precedence.
 /// There is full access to the incoming request.
-/// ```
+#[cfg_attr(feature = "rocket", doc = "```rust")]
+#[cfg_attr(not(feature = "rocket"), doc = "```ignore")]
+/// # use rocket_lang::*;
+/// # use rocket::Request;
+/// # fn lang_from_request(_: &Request) -> LangCode { En }
 /// let config = Config::new().custom(|request: &Request| {
 ///     Ok(lang_from_request(request))
 /// });
 /// ```
+/// ## Custom resolver
+/// The custom closures need a `rocket::Request`. A resolver only sees the parts of the request
+/// in `LangSource`, so it is also used by `Config::negotiate`, and the `tower` and `actix`
+/// middlewares. If it fails, the url, cookie, header and wildcard are tried.
+/// ```rust
+/// # use rocket_lang::{*, source::{LangSource, Parts}};
+/// let config = Config::new()
+///     .wildcard(En)
+///     .resolver(|source: &dyn LangSource| match source.cookie("country") {
+///         Some("mx" | "ar") => Ok(Es),
+///         Some("br") => Ok(Pt),
+///         _ => Err(Error::NotAcceptable),
+///     });
+/// let parts = Parts::new("/").cookies("country=mx");
+/// assert_eq!(config.negotiate(&parts).unwrap(), Es);
+/// assert_eq!(config.negotiate(&Parts::new("/")).unwrap(), En);
+/// ```
 /// ## Url
 /// The url method can be used to specify which segment
 /// should be interpreted as a language code. Negative indexes
@@ -50,31 +73,53 @@ type AsyncFn = Arc<
 /// Thus -1 corresponds to the last segment, -2 the second to last, and so on.
 ///
 /// ### examples
-/// ```rust
+#[cfg_attr(feature = "rocket", doc = "```rust")]
+#[cfg_attr(not(feature = "rocket"), doc = "```ignore")]
+/// # use rocket_lang::*;
+/// # use rocket::get;
 /// // we use -1 to specify that the last segment is our language code
 /// let config = Config::new().url(-1);
 /// // we have to specify which language we want on the handler
-/// #[get("/index/en")
+/// #[get("/index/en")]
 /// fn english_language() {
 ///    /* ... */
 /// }
 /// // we can handle all languages at once with a wildcard segment.
 /// // if we visit with an invalid language code ("/index/not-a-lang-code/"), an error is returned.
-/// #[get("/index/<_>", rank = 2)
+/// #[get("/index/<_>", rank = 2)]
 /// fn any_language() {
 ///    /* ... */
 /// }
 /// ```
 ///
+/// ## Cookie
+/// The language can be read from a cookie holding a language code. Missing cookies,
+/// invalid codes and languages with a quality of 0.0 are ignored, so the next resolution
+/// method is tried. If no qualities are set, any language is accepted.
+/// The cookie can be written with the routes in the [`switcher`](crate::switcher) module.
+/// ```rust
+/// # use rocket_lang::*;
+/// let config = Config::new().cookie("lang");
+/// ```
+///
 /// ## Accept Language
 /// The accept language header qualities can be set by indexing into the config struct.
 /// By default, all values are set to 0.0. These values should correspond to a
 /// number between 0.0 and 1.0 specifying the quality of that language support of your site.
 /// ```rust
-/// let config = Config::new();
+/// # use rocket_lang::*;
+/// let mut config = Config::new();
 /// config[En] = 0.3;
 /// config[Ar] = 1.0;
 /// ```
+/// Regions can be given their own quality with a tag. Regions without a quality
+/// inherit the quality of their language.
+/// ```rust
+/// # use rocket_lang::*;
+/// let mut config = Config::new();
+/// config["pt-BR"] = 1.0;
+/// config["pt-PT"] = 0.4;
+/// ```
 ///
 /// ## Wildcard
 /// The wildcard will be used to create a value if none of the previous attempts succeeded.
@@ -82,15 +127,48 @@ type AsyncFn = Arc<
 /// By default the wildcard is set to `None`.
 ///
 /// ```rust
+/// # use rocket_lang::*;
 /// let config = Config::new().url(1).wildcard(Es);
 /// ```
+///
+/// ## Scopes
+/// Different parts of an application may need different policies. A scoped configuration
+/// is used instead of the global one for requests whose path starts with its base path.
+/// When several scopes match, the one with the longest base path is used.
+/// Note that url positions are still relative to the full request path.
+///
+/// A configuration can also be scoped to a route by its name, which is the name of the
+/// handler function. Route scopes take precedence over path scopes, but they are only
+/// known once the request is routed, so they are ignored by `Config::eager`.
+/// ```rust
+/// # use rocket_lang::*;
+/// let mut api = Config::new().wildcard(En);
+/// api[En] = 1.0;
+/// api[Es] = 0.8;
+///
+/// let config = Config::new()
+///     .url(0)
+///     .scope("/api", api)
+///     .scope("/admin", Config::new().wildcard(En))
+///     .route_scope("health", Config::new().wildcard(En));
+/// ```
 
 #[derive(Clone)]
 pub struct Config {
     pub wildcard: Option<LangCode>,
-    pub(crate) accept_language: HashMap<LangCode, f32>,
+    pub(crate) accept_language: [f32; LANGUAGES],
+    pub(crate) tags: HashMap<Locale, f32>,
     pub(crate) url: Option<i32>,
+    pub(crate) cookie: Option<String>,
+    pub(crate) base_url: Option<String>,
+    #[cfg(feature = "rocket")]
     pub(crate) custom: Option<Result<Fun, AsyncFn>>,
+    pub(crate) resolver: Option<Resolver>,
+    pub(crate) scopes: Vec<(String, Config)>,
+    pub(crate) route_scopes: Vec<(String, Config)>,
+    #[cfg(feature = "rocket")]
+    pub(crate) eager: bool,
+    pub(crate) strategy: Arc<dyn NegotiationStrategy>,
 }
 
 impl Config {
@@ -99,6 +177,12 @@ impl Config {
         self.wildcard = Some(lang);
         self
     }
+    /// Used to specify a custom language resolution method that works with every
+    /// integration. It is tried after the `custom` closures, if they are set.
+    pub fn resolver(mut self, f: Resolver) -> Self {
+        self.resolver = Some(f);
+        self
+    }
     /// Takes the Config structure by value and returns
     /// a new one with the url configuration set.
     /// The position parameter determines which path segment
@@ -109,38 +193,246 @@ impl Config {
         self.url = Some(position);
         self
     }
+    /// Takes the Config structure by value and returns
+    /// a new one that reads the language from the cookie with the given name.
+    pub fn cookie(mut self, name: impl Into<String>) -> Self {
+        self.cookie = Some(name.into());
+        self
+    }
+    /// Takes the Config structure by value and returns a new one with the
+    /// scheme and host of the site, such as `https://example.com`.
+    /// It is used to build the absolute urls of the [`alternates`](crate::alternates).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        self.base_url = Some(
+            base_url
+                .trim_end_matches('/')
+                .to_string(),
+        );
+        self
+    }
+    /// Takes the Config structure by value and returns a new one
+    /// with the qualities of the given languages set.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// let config = Config::new().languages([(En, 1.0), (Es, 0.8)]);
+    /// assert_eq!(config[Es], 0.8);
+    /// ```
+    pub fn languages(mut self, qualities: impl IntoIterator<Item = (LangCode, f32)>) -> Self {
+        for (lang, quality) in qualities {
+            self[lang] = quality;
+        }
+        self
+    }
+    /// Takes the Config structure by value and returns a new one
+    /// where the given languages have a quality of 1.0.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// let config = Config::new().supported(&[En, Es, Fr]);
+    /// assert_eq!(config[Fr], 1.0);
+    /// ```
+    pub fn supported(self, languages: &[LangCode]) -> Self {
+        self.languages(
+            languages
+                .iter()
+                .map(|&lang| (lang, 1.0)),
+        )
+    }
+    /// Takes the Config structure by value and returns a new one where requests under
+    /// the `base` path are resolved with the scoped configuration instead.
+    /// Scopes of the scoped configuration are ignored.
+    pub fn scope(mut self, base: impl Into<String>, config: Config) -> Self {
+        let base: String = base.into();
+        let base = base.trim_end_matches('/');
+        self.scopes
+            .push((base.to_string(), config));
+        self
+    }
+
+    /// Takes the Config structure by value and returns a new one where requests
+    /// routed to the route with the given name are resolved with the scoped configuration.
+    /// Scopes of the scoped configuration are ignored.
+    pub fn route_scope(mut self, name: impl Into<String>, config: Config) -> Self {
+        self.route_scopes
+            .push((name.into(), config));
+        self
+    }
+
+    /// Returns the configuration that applies to a request path,
+    /// which is either the scope with the longest matching base path, or `self`.
+    pub fn for_path(&self, path: &str) -> &Config {
+        self.scopes
+            .iter()
+            .filter(|(base, _)| {
+                path.strip_prefix(base.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|(base, _)| base.len())
+            .map_or(self, |(_, config)| config)
+    }
+
+    /// Returns the languages with a non-zero quality,
+    /// either for the language itself or for one of its tags.
+    pub fn supported_languages(&self) -> Vec<LangCode> {
+        LangCode::ALL_CODES
+            .iter()
+            .copied()
+            .filter(|&lang| {
+                self[lang] > 0.0
+                    || self
+                        .tags
+                        .iter()
+                        .any(|(tag, &q)| tag.lang() == lang && q > 0.0)
+            })
+            .collect()
+    }
+
+    /// Checks the configuration and its scopes for invalid values,
+    /// and returns every problem found. It also runs when the config is attached,
+    /// where launch is aborted if it fails.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// let mut config = Config::new();
+    /// config[Es] = 7.0;
+    /// assert!(config.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let problems = self.problems(None);
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// `segments` is the amount of segments of the longest route, if it is bounded.
+    pub(crate) fn problems(&self, segments: Option<usize>) -> Vec<ConfigError> {
+        let qualities = LangCode::ALL_CODES
+            .iter()
+            .map(|&lang| (lang.to_string(), self[lang]))
+            .chain(
+                self.tags
+                    .iter()
+                    .map(|(tag, &q)| (tag.to_string(), q)),
+            );
+        let mut problems: Vec<_> = qualities
+            .filter(|(_, q)| !(0.0..=1.0).contains(q))
+            .map(|(tag, quality)| ConfigError::InvalidQuality { tag, quality })
+            .collect();
+        problems.sort_by_key(ToString::to_string);
+
+        if let (Some(position), Some(segments)) = (self.url, segments) {
+            let in_range = match usize::try_from(position) {
+                Ok(position) => position < segments,
+                Err(_) => position.unsigned_abs() as usize <= segments,
+            };
+            if !in_range {
+                problems.push(ConfigError::UrlPosition { position, segments });
+            }
+        }
+        for (base, config) in self
+            .scopes
+            .iter()
+            .chain(&self.route_scopes)
+        {
+            problems.extend(
+                config
+                    .problems(segments)
+                    .into_iter()
+                    .map(|problem| ConfigError::Scope(base.clone(), Box::new(problem))),
+            );
+        }
+        problems
+    }
+
+    /// Returns the quality of a language tag requested by a client.
+    /// A tag without its own quality takes the quality of its maximized tag,
+    /// then of its likely region, then of its likely script, and then of its language,
+    /// so `zh-TW` inherits the quality of `zh-Hant`.
+    /// A tag without a script or region takes the best quality among the language and its tags.
+    pub(crate) fn tag_quality(&self, tag: &Locale) -> f32 {
+        let lang = tag.lang();
+        if tag.is_lang() {
+            return self
+                .tags
+                .iter()
+                .filter(|(tag, _)| tag.lang() == lang)
+                .map(|(_, &q)| q)
+                .fold(self[lang], f32::max);
+        }
+        std::iter::once(tag.clone())
+            .chain(tag.fallbacks())
+            .find_map(|tag| self.tags.get(&tag).copied())
+            .unwrap_or(self[lang])
+    }
 
     /// Constructs a new configuration object.
     pub fn new() -> Self {
         Self::default()
     }
-    /// Used to specify a custom language resolution method.
-    pub fn custom(self, f: Fun) -> Self {
-        Self {
-            custom: Some(Ok(f)),
-            ..self
-        }
+    /// Takes the Config structure by value and returns a new one that chooses
+    /// from the accept language header with the given strategy.
+    /// By default the [`Legacy`] strategy is used.
+    pub fn strategy(mut self, strategy: impl NegotiationStrategy + 'static) -> Self {
+        self.strategy = Arc::new(strategy);
+        self
     }
-    /// Used to specify a custom language resolution method with async block.
-    pub fn custom_async<F>(self, f: fn(&Request) -> F) -> Self
-    where
-        F: Future<Output = Result<LangCode, Error>> + Send + Sync + 'static,
-    {
-        Self {
-            custom: Some(Err(Arc::new(move |req| Box::pin(f(req))))),
-            ..self
-        }
+    /// Resolves the language of a request from its parts. The scope matching its
+    /// path is used. The custom resolver set with `Config::resolver`
+    /// is tried first, while the `custom` closures are ignored, as they need a `rocket::Request`.
+    /// ```rust
+    /// # use rocket_lang::{*, source::Parts};
+    /// let config = Config::new().url(0).supported(&[En, Es]);
+    /// let parts = Parts::new("/es/home").accept_language("en");
+    /// assert_eq!(config.negotiate(&parts).unwrap(), Es);
+    /// ```
+    pub fn negotiate(&self, source: &(impl LangSource + ?Sized)) -> Result<LangCode, Error> {
+        self.for_path(source.path())
+            .choose_after(source, Err(None))
+    }
+
+    /// Resolves the language of a request like `negotiate`, and returns it with the tag
+    /// the client requested it with, so regional variants can be told apart.
+    /// Among the tags of the language in the header, the one with the highest quality
+    /// in the configuration wins, then the one the client prefers.
+    /// ```rust
+    /// # use rocket_lang::{*, source::Parts};
+    /// let mut config = Config::new();
+    /// config["pt-BR"] = 1.0;
+    /// config["pt-PT"] = 0.5;
+    /// let parts = Parts::new("/").accept_language("pt-PT, pt-BR;q=0.9");
+    /// assert_eq!(config.negotiate_locale(&parts).unwrap().to_string(), "pt-BR");
+    /// ```
+    pub fn negotiate_locale(&self, source: &(impl LangSource + ?Sized)) -> Result<Locale, Error> {
+        let lang = self.negotiate(source)?;
+        Ok(crate::accept_language::locale(
+            source,
+            Some(self.for_path(source.path())),
+            lang,
+        ))
     }
 
-    pub(crate) async fn choose(&self, req: &Request<'_>) -> Result<LangCode, Error> {
-        self.with_custom(req)
-            .await
+    /// Tries the resolver, url, cookie, header and wildcard, if the custom closure failed.
+    pub(crate) fn choose_after(
+        &self,
+        source: &(impl LangSource + ?Sized),
+        custom: Result<LangCode, Option<Error>>,
+    ) -> Result<LangCode, Error> {
+        custom
+            .or_else(|e1| {
+                self.with_resolver(source)
+                    .map_err(|e2| e1.or(e2))
+            })
             .or_else(|e1| {
-                self.with_url(req)
+                self.with_url(source)
                     .map_err(|e2| e1.or(e2))
             })
             .or_else(|e1| {
-                self.with_lang_header(req)
+                self.with_cookie(source)
+                    .ok_or(e1)
+            })
+            .or_else(|e1| {
+                self.with_lang_header(source)
                     .map_err(|e2| e1.or(Some(e2)))
             })
             .or_else(|err| {
@@ -152,71 +444,60 @@ impl Config {
             .map_err(Option::unwrap)
     }
 
-    async fn with_custom(&self, req: &Request<'_>) -> Result<LangCode, Option<Error>> {
-        match self.custom.as_ref() {
-            Some(Ok(custom)) => {
-                return custom(req).map_err(Some);
-            }
-            Some(Err(custom)) => {
-                return custom(req)
-                    .await
-                    .map_err(Some);
-            }
+    fn with_resolver(
+        &self,
+        source: &(impl LangSource + ?Sized),
+    ) -> Result<LangCode, Option<Error>> {
+        match self.resolver {
+            Some(resolver) => resolver(&source).map_err(Some),
             None => Err(None),
         }
     }
 
-    fn with_url(&self, req: &Request) -> Result<LangCode, Option<Error>> {
+    fn with_url(&self, source: &(impl LangSource + ?Sized)) -> Result<LangCode, Option<Error>> {
         if let Some(pos) = self.url {
-            return crate::url::get(req, pos).map_err(Some);
+            return crate::url::get(source.path(), pos).map_err(Some);
         }
         Err(None)
     }
 
-    fn with_lang_header(&self, req: &Request) -> Result<LangCode, Error> {
-        crate::accept_language::with_config(req, self)
+    fn with_cookie(&self, source: &(impl LangSource + ?Sized)) -> Option<LangCode> {
+        source
+            .cookie(self.cookie.as_deref()?)?
+            .parse()
+            .ok()
+            .filter(|&lang| self.allows(lang))
+    }
+
+    /// Returns true if a language chosen by the client can be used, which is
+    /// when it has a non-zero quality, or when no qualities are set at all.
+    pub(crate) fn allows(&self, lang: LangCode) -> bool {
+        let supported = self.supported_languages();
+        supported.is_empty() || supported.contains(&lang)
     }
-}
 
-pub(crate) struct PrivConfig(Config);
+    fn with_lang_header(&self, source: &(impl LangSource + ?Sized)) -> Result<LangCode, Error> {
+        crate::accept_language::with_config(source, self)
+    }
+}
 
 impl Default for Config {
     fn default() -> Self {
-        let mut config = Config {
+        Config {
             wildcard: None,
             url: None,
-            accept_language: HashMap::with_capacity(LangCode::ALL_CODES.len()),
+            cookie: None,
+            base_url: None,
+            accept_language: [0.0; LANGUAGES],
+            tags: HashMap::new(),
+            #[cfg(feature = "rocket")]
             custom: None,
-        };
-        for lang in LangCode::ALL_CODES {
-            config
-                .accept_language
-                .insert(*lang, 0.0);
-        }
-        config
-    }
-}
-
-#[async_trait]
-impl Fairing for Config {
-    fn info(&self) -> Info {
-        Info {
-            name: "Language configuration",
-            kind: Kind::Ignite | Kind::Request,
-        }
-    }
-    async fn on_ignite(&self, rocket: Rocket<Build>) -> rocket::fairing::Result {
-        Ok(rocket.manage(PrivConfig(self.clone())))
-    }
-    // this will get executed before routing the request
-    // so retrieving the `langCode` guard
-    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
-        if let Outcome::Success(config) = req
-            .guard::<&State<PrivConfig>>()
-            .await
-        {
-            let result = config.0.choose(req).await;
-            req.local_cache(|| result);
+            resolver: None,
+            scopes: vec![],
+            route_scopes: vec![],
+            #[cfg(feature = "rocket")]
+            eager: false,
+            strategy: Arc::new(Legacy),
         }
     }
 }
@@ -224,14 +505,52 @@ impl Fairing for Config {
 impl Index<LangCode> for Config {
     type Output = f32;
     fn index(&self, index: LangCode) -> &Self::Output {
-        &self.accept_language[&index]
+        &self.accept_language[index as usize]
     }
 }
 
 impl IndexMut<LangCode> for Config {
     fn index_mut(&mut self, index: LangCode) -> &mut Self::Output {
-        self.accept_language
-            .get_mut(&index)
-            .unwrap()
+        &mut self.accept_language[index as usize]
+    }
+}
+
+/// Qualities can also be set for a script or region of a language, with tags such as
+/// `zh-Hant` or `pt-BR`. Tags without a quality of their own inherit the quality of their language.
+/// ```rust
+/// # use rocket_lang::*;
+/// let mut config = Config::new();
+/// config[Pt] = 0.4;
+/// config["pt-BR"] = 1.0;
+/// assert_eq!(config["pt-PT"], 0.4);
+/// ```
+/// # Panics
+/// Panics if the tag is not a valid language code, optionally followed by a script and a region.
+impl Index<&str> for Config {
+    type Output = f32;
+    fn index(&self, tag: &str) -> &Self::Output {
+        match tag.parse::<Locale>() {
+            Ok(tag) if tag.is_lang() => &self[tag.lang()],
+            Ok(tag) => self
+                .tags
+                .get(&tag)
+                .unwrap_or(&self[tag.lang()]),
+            Err(_) => panic!("invalid language tag: {tag:?}"),
+        }
+    }
+}
+
+impl IndexMut<&str> for Config {
+    fn index_mut(&mut self, tag: &str) -> &mut Self::Output {
+        match tag.parse::<Locale>() {
+            Ok(tag) if tag.is_lang() => &mut self[tag.lang()],
+            Ok(tag) => {
+                let inherited = self[tag.lang()];
+                self.tags
+                    .entry(tag)
+                    .or_insert(inherited)
+            }
+            Err(_) => panic!("invalid language tag: {tag:?}"),
+        }
     }
 }
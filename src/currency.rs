@@ -0,0 +1,215 @@
+//! Locale-aware currency formatting, with data from the Unicode CLDR.
+//!
+//! Amounts are formatted with the currency pattern of a language, the symbol of an
+//! ISO 4217 currency code, and the number of fraction digits of the currency:
+//! ```rust
+//! # use rocket_lang::{*, currency::format_currency};
+//! assert_eq!(format_currency(En, 1234.56, "EUR"), "€1,234.56");
+//! assert_eq!(format_currency(De, 1234.56, "EUR"), "1.234,56\u{A0}€");
+//! assert_eq!(format_currency(Ja, 1234.56, "JPY"), "￥1,235");
+//! assert_eq!(format_currency(En, 1234.5, "CHF"), "CHF\u{A0}1,234.50");
+//! ```
+//! A `CurrencyFormat` can be adjusted for a region, for narrow symbols or ISO codes,
+//! and for the accounting format, where negative amounts are written in parentheses:
+//! ```rust
+//! # use rocket_lang::{*, currency::{CurrencyDisplay, CurrencyFormat}};
+//! let mexico = CurrencyFormat::with_region(Es, "MX", "MXN");
+//! assert_eq!(mexico.format(1234.5), "$1,234.50");
+//!
+//! let narrow = CurrencyFormat::new(Fr, "CAD").display(CurrencyDisplay::Narrow);
+//! assert_eq!(narrow.format(5.0), "5,00\u{A0}$");
+//!
+//! let accounting = CurrencyFormat::new(En, "USD").accounting();
+//! assert_eq!(accounting.format(-5.0), "($5.00)");
+//! ```
+//! The data is generated from the Unicode CLDR by `tools/cldr`. Languages without
+//! currency data of their own use the patterns and symbols of the root locale, and
+//! currencies without symbol are written with their ISO 4217 code:
+//! ```rust
+//! # use rocket_lang::{*, currency::format_currency};
+//! assert_eq!(format_currency(Aa, 5.0, "EUR"), "€\u{A0}5.00");
+//! assert_eq!(format_currency(En, 5.0, "XYZ"), "XYZ\u{A0}5.00");
+//! ```
+use crate::{
+    numbers::{NumberFormat, Options},
+    *,
+};
+
+#[rustfmt::skip]
+mod data;
+
+/// The currency patterns of a locale. The `alpha` ones are used when a symbol
+/// that starts or ends with a letter is written next to the number.
+#[derive(Debug, PartialEq)]
+struct Patterns {
+    standard: &'static str,
+    standard_alpha: &'static str,
+    accounting: &'static str,
+    accounting_alpha: &'static str,
+}
+
+/// How the currency is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CurrencyDisplay {
+    /// The symbol used in the language, such as `US$` or `$`.
+    #[default]
+    Symbol,
+    /// The shortest symbol, such as `$`, even if it is ambiguous.
+    Narrow,
+    /// The ISO 4217 code, such as `USD`.
+    Code,
+}
+
+/// The currency format of a language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyFormat {
+    lang: LangCode,
+    region: Option<String>,
+    code: String,
+    number: NumberFormat,
+    patterns: &'static Patterns,
+    accounting: bool,
+    display: CurrencyDisplay,
+}
+
+impl CurrencyFormat {
+    /// Returns the format of a currency in a language.
+    pub fn new(lang: LangCode, code: &str) -> Self {
+        Self {
+            lang,
+            region: None,
+            code: code.to_uppercase(),
+            number: NumberFormat::new(lang),
+            patterns: data::LANGUAGES[lang as usize],
+            accounting: false,
+            display: CurrencyDisplay::Symbol,
+        }
+    }
+
+    /// Returns the format of a currency in a language and region, such as `MX`.
+    /// The currency of the region is written with its local symbol.
+    pub fn with_region(lang: LangCode, region: &str, code: &str) -> Self {
+        let region = region.to_uppercase();
+        let patterns = data::REGIONS
+            .iter()
+            .find(|(l, r, _)| *l == lang && *r == region)
+            .map_or(data::LANGUAGES[lang as usize], |&(_, _, patterns)| patterns);
+        Self {
+            number: NumberFormat::with_region(lang, &region),
+            region: Some(region),
+            patterns,
+            ..Self::new(lang, code)
+        }
+    }
+
+    /// Sets how the currency is written.
+    pub fn display(mut self, display: CurrencyDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Uses the accounting format, where negative amounts are usually written in parentheses.
+    pub fn accounting(mut self) -> Self {
+        self.accounting = true;
+        self
+    }
+
+    /// Returns the symbol the currency is written with.
+    pub fn symbol(&self) -> &str {
+        let symbols = self.symbols();
+        match self.display {
+            CurrencyDisplay::Symbol => symbols.map_or(&self.code, |(symbol, _)| symbol),
+            CurrencyDisplay::Narrow => symbols.map_or(&self.code, |(_, narrow)| narrow),
+            CurrencyDisplay::Code => &self.code,
+        }
+    }
+
+    /// Returns the symbol and narrow symbol of the currency in the region,
+    /// the language, or the root locale.
+    fn symbols(&self) -> Option<(&'static str, &'static str)> {
+        let code = self.code.as_str();
+        let region = self.region.as_deref();
+        let regional = data::REGION_SYMBOLS
+            .iter()
+            .find(|(l, r, c, ..)| *l == self.lang && Some(*r) == region && *c == code)
+            .map(|&(.., symbol, narrow)| (symbol, narrow));
+        let local = || {
+            data::LANGUAGE_SYMBOLS
+                .binary_search_by(|(l, c, ..)| (*l as usize, *c).cmp(&(self.lang as usize, code)))
+                .ok()
+                .map(|i| (data::LANGUAGE_SYMBOLS[i].2, data::LANGUAGE_SYMBOLS[i].3))
+        };
+        let root = || {
+            data::SYMBOLS
+                .binary_search_by(|(c, ..)| c.cmp(&code))
+                .ok()
+                .map(|i| (data::SYMBOLS[i].1, data::SYMBOLS[i].2))
+        };
+        regional
+            .or_else(local)
+            .or_else(root)
+    }
+
+    /// Formats an amount, rounded to the fraction digits of the currency.
+    pub fn format(&self, value: f64) -> String {
+        let symbol = self.symbol();
+        let patterns = self.patterns;
+        let (pattern, alpha) = match self.accounting {
+            true => (patterns.accounting, patterns.accounting_alpha),
+            false => (patterns.standard, patterns.standard_alpha),
+        };
+        let pattern = match alpha_next_to_number(pattern, symbol) {
+            true => alpha,
+            false => pattern,
+        };
+        let options = Options {
+            fraction_digits: Some(fraction_digits(&self.code)),
+            currency: symbol,
+        };
+        self.number
+            .apply(pattern, value, &options)
+    }
+}
+
+/// Returns true if the symbol is written next to the number in the positive
+/// pattern, and starts or ends with a letter on that side.
+fn alpha_next_to_number(pattern: &str, symbol: &str) -> bool {
+    let positive = pattern
+        .split(';')
+        .next()
+        .unwrap_or(pattern);
+    if positive.contains("¤#") || positive.contains("¤0") {
+        symbol
+            .chars()
+            .last()
+            .is_some_and(char::is_alphabetic)
+    } else if positive.contains("0¤") {
+        symbol
+            .chars()
+            .next()
+            .is_some_and(char::is_alphabetic)
+    } else {
+        false
+    }
+}
+
+/// Formats an amount of a currency for a language.
+pub fn format_currency(lang: LangCode, value: f64, code: &str) -> String {
+    CurrencyFormat::new(lang, code).format(value)
+}
+
+/// Returns the number of fraction digits an amount of the currency is written with.
+/// ```rust
+/// # use rocket_lang::currency::fraction_digits;
+/// assert_eq!(fraction_digits("EUR"), 2);
+/// assert_eq!(fraction_digits("JPY"), 0);
+/// assert_eq!(fraction_digits("KWD"), 3);
+/// ```
+pub fn fraction_digits(code: &str) -> usize {
+    let code = code.to_uppercase();
+    data::FRACTION_DIGITS
+        .binary_search_by(|(c, _)| c.cmp(&code.as_str()))
+        .map_or(data::DEFAULT_FRACTION_DIGITS, |i| {
+            data::FRACTION_DIGITS[i].1
+        })
+}
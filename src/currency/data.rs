@@ -0,0 +1,1699 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::Patterns;
+use crate::LangCode::{self, *};
+
+static P0: Patterns = Patterns { standard: "¤\u{A0}#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤\u{A0}#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00" };
+static P1: Patterns = Patterns { standard: "¤#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤#,##0.00;(¤#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P2: Patterns = Patterns { standard: "#,##0.00\u{A0}¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "#,##0.00\u{A0}¤;(#,##0.00\u{A0}¤)", accounting_alpha: "#,##0.00\u{A0}¤;(#,##0.00\u{A0}¤)" };
+static P3: Patterns = Patterns { standard: "\u{200F}#,##0.00\u{A0}¤", standard_alpha: "\u{200F}#,##0.00\u{A0}¤", accounting: "\u{200F}#,##0.00\u{A0}¤", accounting_alpha: "\u{200F}#,##0.00\u{A0}¤" };
+static P4: Patterns = Patterns { standard: "\u{200F}#,##0.00\u{A0}¤;\u{200F}-#,##0.00\u{A0}¤", standard_alpha: "\u{200F}#,##0.00\u{A0}¤;\u{200F}-#,##0.00\u{A0}¤", accounting: "\u{61C}#,##0.00¤;(\u{61C}#,##0.00¤)", accounting_alpha: "\u{61C}#,##0.00\u{A0}¤;(\u{61C}#,##0.00\u{A0}¤)" };
+static P5: Patterns = Patterns { standard: "#,##0.00\u{A0}¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "#,##0.00\u{A0}¤", accounting_alpha: "#,##0.00\u{A0}¤" };
+static P6: Patterns = Patterns { standard: "¤\u{A0}#,##,##0.00", standard_alpha: "¤\u{A0}#,##,##0.00", accounting: "¤#,##,##0.00;(¤#,##,##0.00)", accounting_alpha: "¤\u{A0}#,##,##0.00;(¤\u{A0}#,##,##0.00)" };
+static P7: Patterns = Patterns { standard: "¤#,##,##0.00", standard_alpha: "¤\u{A0}#,##,##0.00", accounting: "¤#,##,##0.00;(¤#,##,##0.00)", accounting_alpha: "¤\u{A0}#,##,##0.00;(¤\u{A0}#,##,##0.00)" };
+static P8: Patterns = Patterns { standard: "#,##,##0.00¤", standard_alpha: "#,##,##0.00\u{A0}¤", accounting: "#,##,##0.00¤;(#,##,##0.00¤)", accounting_alpha: "#,##,##0.00\u{A0}¤;(#,##,##0.00\u{A0}¤)" };
+static P9: Patterns = Patterns { standard: "#,##0.00\u{A0}¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "¤\u{A0}#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00" };
+static P10: Patterns = Patterns { standard: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00", standard_alpha: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00", accounting: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P11: Patterns = Patterns { standard: "¤\u{A0}#,##0.00;¤-#,##0.00", standard_alpha: "¤\u{A0}#,##0.00;¤-#,##0.00", accounting: "¤\u{A0}#,##0.00;¤-#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00;¤-#,##0.00" };
+static P12: Patterns = Patterns { standard: "¤#,##0.00", standard_alpha: "¤#,##0.00", accounting: "¤#,##0.00;(¤#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P13: Patterns = Patterns { standard: "#,##0.00\u{202F}¤", standard_alpha: "#,##0.00\u{202F}¤", accounting: "#,##0.00\u{202F}¤;(#,##0.00\u{202F}¤)", accounting_alpha: "#,##0.00\u{202F}¤;(#,##0.00\u{202F}¤)" };
+static P14: Patterns = Patterns { standard: "¤#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤#,##0.00;(¤#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00" };
+static P15: Patterns = Patterns { standard: "\u{200F}#,##0.00\u{A0}\u{200F}¤;\u{200F}-#,##0.00\u{A0}\u{200F}¤", standard_alpha: "\u{200F}#,##0.00\u{A0}\u{200F}¤;\u{200F}-#,##0.00\u{A0}\u{200F}¤", accounting: "\u{200F}#,##0.00\u{A0}\u{200F}¤;\u{200F}-#,##0.00\u{A0}\u{200F}¤", accounting_alpha: "\u{200F}#,##0.00\u{A0}\u{200F}¤;\u{200F}-#,##0.00\u{A0}\u{200F}¤" };
+static P16: Patterns = Patterns { standard: "¤#,##,##0.00", standard_alpha: "¤\u{A0}#,##,##0.00", accounting: "¤#,##,##0.00", accounting_alpha: "¤\u{A0}#,##,##0.00" };
+static P17: Patterns = Patterns { standard: "¤\u{A0}#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P18: Patterns = Patterns { standard: "¤#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00" };
+static P19: Patterns = Patterns { standard: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00", standard_alpha: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00", accounting: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00;¤\u{A0}-#,##0.00" };
+static P20: Patterns = Patterns { standard: "#,##0.00¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "#,##0.00¤;(#,##0.00¤)", accounting_alpha: "#,##0.00\u{A0}¤;(#,##0.00\u{A0}¤)" };
+static P21: Patterns = Patterns { standard: "¤#,##0.00;¤-#,##0.00", standard_alpha: "¤\u{A0}#,##0.00;¤-#,##0.00", accounting: "¤#,##0.00;¤-#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00;¤-#,##0.00" };
+static P22: Patterns = Patterns { standard: "¤\u{A0}#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤#,##0.00;(¤#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P23: Patterns = Patterns { standard: "¤#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤#,##0.00", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P24: Patterns = Patterns { standard: "¤\u{A0}#,##,##0.00", standard_alpha: "¤\u{A0}#,##,##0.00", accounting: "¤\u{A0}#,##,##0.00", accounting_alpha: "¤\u{A0}#,##,##0.00" };
+static P25: Patterns = Patterns { standard: "#,##0.00\u{A0}¤;-#,##0.00\u{A0}¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P26: Patterns = Patterns { standard: "#,##0.00¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "#,##0.00¤", accounting_alpha: "#,##0.00\u{A0}¤" };
+static P27: Patterns = Patterns { standard: "#,##0.00¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "#,##0.00¤", accounting_alpha: "#,##0.00\u{A0}¤;(#,##0.00\u{A0}¤)" };
+static P28: Patterns = Patterns { standard: "¤#,##,##0.00", standard_alpha: "¤\u{A0}#,##,##0.00", accounting: "¤\u{A0}#,##,##0.00", accounting_alpha: "¤\u{A0}#,##,##0.00" };
+static P29: Patterns = Patterns { standard: "¤\u{A0}#,##0.00", standard_alpha: "¤\u{A0}#,##0.00", accounting: "¤\u{A0}#,##0.00;\u{200E}(¤\u{A0}#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;\u{200E}(¤\u{A0}#,##0.00)" };
+static P30: Patterns = Patterns { standard: "\u{200E}¤#,##0.00", standard_alpha: "\u{200E}¤\u{A0}#,##0.00", accounting: "\u{200E}¤\u{A0}#,##0.00;\u{200E}(¤\u{A0}#,##0.00)", accounting_alpha: "\u{200E}¤\u{A0}#,##0.00;\u{200E}(¤\u{A0}#,##0.00)" };
+static P31: Patterns = Patterns { standard: "#,##0.00\u{A0}¤", standard_alpha: "#,##0.00\u{A0}¤", accounting: "¤#,##0.00;(¤#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+static P32: Patterns = Patterns { standard: "¤\u{A0}#,##0.00;¤\u{A0}#,##0.00-", standard_alpha: "¤\u{A0}#,##0.00;¤\u{A0}#,##0.00-", accounting: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)", accounting_alpha: "¤\u{A0}#,##0.00;(¤\u{A0}#,##0.00)" };
+
+/// The patterns of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [&Patterns; 184] = [
+    /* aa */ &P0,
+    /* ab */ &P0,
+    /* af */ &P1,
+    /* ak */ &P1,
+    /* sq */ &P2,
+    /* am */ &P1,
+    /* ar */ &P4,
+    /* an */ &P0,
+    /* hy */ &P5,
+    /* as */ &P6,
+    /* av */ &P0,
+    /* ae */ &P0,
+    /* ay */ &P0,
+    /* az */ &P5,
+    /* bm */ &P0,
+    /* ba */ &P5,
+    /* eu */ &P2,
+    /* be */ &P5,
+    /* bn */ &P8,
+    /* bh */ &P0,
+    /* bi */ &P0,
+    /* bs */ &P5,
+    /* br */ &P2,
+    /* bg */ &P2,
+    /* my */ &P9,
+    /* ca */ &P2,
+    /* ch */ &P0,
+    /* ce */ &P0,
+    /* ny */ &P0,
+    /* zh */ &P1,
+    /* cv */ &P2,
+    /* kw */ &P0,
+    /* co */ &P0,
+    /* cr */ &P0,
+    /* hr */ &P5,
+    /* cs */ &P5,
+    /* da */ &P5,
+    /* dv */ &P0,
+    /* nl */ &P10,
+    /* dz */ &P0,
+    /* en */ &P1,
+    /* eo */ &P13,
+    /* et */ &P2,
+    /* ee */ &P14,
+    /* fo */ &P2,
+    /* fj */ &P0,
+    /* fi */ &P5,
+    /* fr */ &P2,
+    /* ff */ &P0,
+    /* gl */ &P2,
+    /* ka */ &P5,
+    /* de */ &P5,
+    /* el */ &P5,
+    /* gn */ &P0,
+    /* gu */ &P7,
+    /* ht */ &P2,
+    /* ha */ &P0,
+    /* he */ &P15,
+    /* hz */ &P0,
+    /* hi */ &P16,
+    /* ho */ &P0,
+    /* hu */ &P5,
+    /* ia */ &P17,
+    /* id */ &P18,
+    /* ie */ &P19,
+    /* ga */ &P1,
+    /* ig */ &P1,
+    /* ik */ &P0,
+    /* io */ &P0,
+    /* is */ &P5,
+    /* it */ &P5,
+    /* iu */ &P0,
+    /* ja */ &P1,
+    /* jv */ &P0,
+    /* kl */ &P0,
+    /* kn */ &P1,
+    /* kr */ &P0,
+    /* ks */ &P18,
+    /* kk */ &P5,
+    /* km */ &P20,
+    /* ki */ &P0,
+    /* rw */ &P0,
+    /* ky */ &P5,
+    /* kv */ &P0,
+    /* kg */ &P0,
+    /* ko */ &P1,
+    /* ku */ &P2,
+    /* kj */ &P0,
+    /* la */ &P0,
+    /* lb */ &P5,
+    /* lg */ &P0,
+    /* li */ &P0,
+    /* ln */ &P0,
+    /* lo */ &P21,
+    /* lt */ &P5,
+    /* lu */ &P0,
+    /* lv */ &P5,
+    /* gv */ &P0,
+    /* mk */ &P5,
+    /* mg */ &P0,
+    /* ms */ &P1,
+    /* ml */ &P7,
+    /* mt */ &P18,
+    /* mi */ &P0,
+    /* mr */ &P7,
+    /* mh */ &P0,
+    /* mn */ &P0,
+    /* na */ &P0,
+    /* nv */ &P0,
+    /* nd */ &P0,
+    /* ne */ &P24,
+    /* ng */ &P0,
+    /* nb */ &P25,
+    /* nn */ &P25,
+    /* no */ &P25,
+    /* ii */ &P0,
+    /* nr */ &P0,
+    /* oc */ &P27,
+    /* oj */ &P0,
+    /* cu */ &P0,
+    /* om */ &P18,
+    /* or */ &P7,
+    /* os */ &P0,
+    /* pa */ &P28,
+    /* pi */ &P0,
+    /* fa */ &P30,
+    /* pl */ &P2,
+    /* ps */ &P22,
+    /* pt */ &P0,
+    /* qu */ &P0,
+    /* rm */ &P1,
+    /* rn */ &P0,
+    /* ro */ &P2,
+    /* ru */ &P5,
+    /* sa */ &P28,
+    /* sc */ &P2,
+    /* sd */ &P5,
+    /* se */ &P0,
+    /* sm */ &P0,
+    /* sg */ &P0,
+    /* sr */ &P2,
+    /* gd */ &P1,
+    /* sn */ &P0,
+    /* si */ &P1,
+    /* sk */ &P2,
+    /* sl */ &P2,
+    /* so */ &P1,
+    /* st */ &P18,
+    /* es */ &P5,
+    /* su */ &P18,
+    /* sw */ &P0,
+    /* ss */ &P0,
+    /* sv */ &P5,
+    /* ta */ &P7,
+    /* te */ &P7,
+    /* tg */ &P5,
+    /* th */ &P1,
+    /* ti */ &P18,
+    /* bo */ &P0,
+    /* tk */ &P5,
+    /* tl */ &P1,
+    /* tn */ &P18,
+    /* to */ &P0,
+    /* tr */ &P1,
+    /* ts */ &P0,
+    /* tt */ &P5,
+    /* tw */ &P0,
+    /* ty */ &P0,
+    /* ug */ &P14,
+    /* uk */ &P5,
+    /* ur */ &P1,
+    /* uz */ &P31,
+    /* ve */ &P0,
+    /* vi */ &P5,
+    /* vo */ &P0,
+    /* wa */ &P0,
+    /* cy */ &P1,
+    /* wo */ &P0,
+    /* fy */ &P32,
+    /* xh */ &P18,
+    /* yi */ &P0,
+    /* yo */ &P1,
+    /* za */ &P0,
+    /* zu */ &P1,
+];
+
+/// The patterns of the regions that differ from the patterns of their language.
+pub(super) static REGIONS: &[(LangCode, &str, &Patterns)] = &[
+    (Ar, "BH", &P3),
+    (Ar, "DJ", &P3),
+    (Ar, "EG", &P3),
+    (Ar, "ER", &P3),
+    (Ar, "IL", &P3),
+    (Ar, "IQ", &P3),
+    (Ar, "JO", &P3),
+    (Ar, "KM", &P3),
+    (Ar, "KW", &P3),
+    (Ar, "LB", &P3),
+    (Ar, "MR", &P3),
+    (Ar, "OM", &P3),
+    (Ar, "PS", &P3),
+    (Ar, "QA", &P3),
+    (Ar, "SA", &P3),
+    (Ar, "SD", &P3),
+    (Ar, "SO", &P3),
+    (Ar, "SS", &P3),
+    (Ar, "SY", &P3),
+    (Ar, "TD", &P3),
+    (Ar, "YE", &P3),
+    (Az, "IQ", &P0),
+    (Az, "IR", &P0),
+    (Az, "RU", &P0),
+    (Bn, "IN", &P7),
+    (En, "150", &P5),
+    (En, "AT", &P0),
+    (En, "BE", &P5),
+    (En, "CH", &P11),
+    (En, "CZ", &P5),
+    (En, "DE", &P5),
+    (En, "DK", &P5),
+    (En, "EE", &P5),
+    (En, "ES", &P5),
+    (En, "FI", &P5),
+    (En, "FR", &P5),
+    (En, "GE", &P5),
+    (En, "HU", &P5),
+    (En, "IN", &P7),
+    (En, "IT", &P5),
+    (En, "LT", &P5),
+    (En, "LV", &P5),
+    (En, "MV", &P0),
+    (En, "NL", &P10),
+    (En, "NO", &P5),
+    (En, "PL", &P2),
+    (En, "PT", &P2),
+    (En, "RO", &P2),
+    (En, "SE", &P5),
+    (En, "SI", &P2),
+    (En, "SK", &P2),
+    (En, "UA", &P5),
+    (En, "ZA", &P12),
+    (De, "AT", &P0),
+    (De, "CH", &P11),
+    (De, "LI", &P0),
+    (It, "CH", &P11),
+    (Kk, "AF", &P0),
+    (Kk, "CN", &P0),
+    (Kk, "IR", &P0),
+    (Kk, "MN", &P0),
+    (Ky, "CN", &P0),
+    (Ky, "TR", &P0),
+    (Ku, "AM", &P0),
+    (Ku, "AZ", &P0),
+    (Ku, "GE", &P0),
+    (Ku, "IQ", &P0),
+    (Ku, "IR", &P0),
+    (Ku, "LB", &P0),
+    (Ku, "TM", &P0),
+    (Ms, "BN", &P22),
+    (Ms, "CC", &P0),
+    (Ms, "ID", &P23),
+    (Oc, "ES", &P26),
+    (Pa, "PK", &P0),
+    (Fa, "AF", &P29),
+    (Pt, "AO", &P2),
+    (Pt, "CH", &P2),
+    (Pt, "CV", &P2),
+    (Pt, "FR", &P2),
+    (Pt, "GQ", &P2),
+    (Pt, "GW", &P2),
+    (Pt, "LU", &P2),
+    (Pt, "MO", &P2),
+    (Pt, "MZ", &P2),
+    (Pt, "PT", &P2),
+    (Pt, "ST", &P2),
+    (Pt, "TL", &P2),
+    (Sd, "IN", &P0),
+    (Es, "419", &P18),
+    (Es, "AR", &P17),
+    (Es, "BO", &P18),
+    (Es, "BR", &P18),
+    (Es, "CL", &P21),
+    (Es, "CO", &P0),
+    (Es, "CR", &P18),
+    (Es, "CU", &P18),
+    (Es, "DO", &P1),
+    (Es, "EC", &P21),
+    (Es, "GQ", &P18),
+    (Es, "GT", &P18),
+    (Es, "HN", &P18),
+    (Es, "JP", &P18),
+    (Es, "MX", &P18),
+    (Es, "NI", &P18),
+    (Es, "PA", &P18),
+    (Es, "PE", &P0),
+    (Es, "PR", &P18),
+    (Es, "PY", &P19),
+    (Es, "SV", &P18),
+    (Es, "US", &P18),
+    (Es, "UY", &P17),
+    (Es, "VE", &P21),
+    (Ta, "MY", &P22),
+    (Tg, "PK", &P0),
+    (Ug, "KZ", &P0),
+    (Ug, "MN", &P0),
+    (Uz, "AF", &P0),
+    (Uz, "CN", &P5),
+];
+
+/// The symbol and narrow symbol of the currencies, sorted by code.
+pub(super) static SYMBOLS: &[(&str, &str, &str)] = &[
+    ("AFN", "AFN", "؋"),
+    ("AMD", "AMD", "֏"),
+    ("AOA", "AOA", "Kz"),
+    ("ARS", "ARS", "$"),
+    ("AUD", "A$", "$"),
+    ("AZN", "AZN", "₼"),
+    ("BAM", "BAM", "KM"),
+    ("BBD", "BBD", "$"),
+    ("BDT", "BDT", "৳"),
+    ("BMD", "BMD", "$"),
+    ("BND", "BND", "$"),
+    ("BOB", "BOB", "Bs"),
+    ("BRL", "R$", "R$"),
+    ("BSD", "BSD", "$"),
+    ("BWP", "BWP", "P"),
+    ("BZD", "BZD", "$"),
+    ("CAD", "CA$", "$"),
+    ("CLP", "CLP", "$"),
+    ("CNY", "CN¥", "¥"),
+    ("COP", "COP", "$"),
+    ("CRC", "CRC", "₡"),
+    ("CUC", "CUC", "$"),
+    ("CUP", "CUP", "$"),
+    ("CZK", "CZK", "Kč"),
+    ("DKK", "DKK", "kr"),
+    ("DOP", "DOP", "$"),
+    ("EGP", "EGP", "E£"),
+    ("ESP", "ESP", "₧"),
+    ("EUR", "€", "€"),
+    ("FJD", "FJD", "$"),
+    ("FKP", "FKP", "£"),
+    ("GBP", "£", "£"),
+    ("GEL", "GEL", "₾"),
+    ("GHS", "GHS", "GH₵"),
+    ("GIP", "GIP", "£"),
+    ("GNF", "GNF", "FG"),
+    ("GTQ", "GTQ", "Q"),
+    ("GYD", "GYD", "$"),
+    ("HKD", "HK$", "$"),
+    ("HNL", "HNL", "L"),
+    ("HRK", "HRK", "kn"),
+    ("HUF", "HUF", "Ft"),
+    ("IDR", "IDR", "Rp"),
+    ("ILS", "₪", "₪"),
+    ("INR", "₹", "₹"),
+    ("ISK", "ISK", "kr"),
+    ("JMD", "JMD", "$"),
+    ("JPY", "JP¥", "¥"),
+    ("KGS", "KGS", "⃀"),
+    ("KHR", "KHR", "៛"),
+    ("KMF", "KMF", "CF"),
+    ("KPW", "KPW", "₩"),
+    ("KRW", "₩", "₩"),
+    ("KYD", "KYD", "$"),
+    ("KZT", "KZT", "₸"),
+    ("LAK", "LAK", "₭"),
+    ("LBP", "LBP", "L£"),
+    ("LKR", "LKR", "Rs"),
+    ("LRD", "LRD", "$"),
+    ("LTL", "LTL", "Lt"),
+    ("LVL", "LVL", "Ls"),
+    ("MGA", "MGA", "Ar"),
+    ("MMK", "MMK", "K"),
+    ("MNT", "MNT", "₮"),
+    ("MUR", "MUR", "Rs"),
+    ("MXN", "MX$", "$"),
+    ("MYR", "MYR", "RM"),
+    ("NAD", "NAD", "$"),
+    ("NGN", "NGN", "₦"),
+    ("NIO", "NIO", "C$"),
+    ("NOK", "NOK", "kr"),
+    ("NPR", "NPR", "Rs"),
+    ("NZD", "NZ$", "$"),
+    ("PHP", "₱", "₱"),
+    ("PKR", "PKR", "Rs"),
+    ("PLN", "PLN", "zł"),
+    ("PYG", "PYG", "₲"),
+    ("RON", "RON", "lei"),
+    ("RUB", "RUB", "₽"),
+    ("RWF", "RWF", "RF"),
+    ("SBD", "SBD", "$"),
+    ("SEK", "SEK", "kr"),
+    ("SGD", "SGD", "$"),
+    ("SHP", "SHP", "£"),
+    ("SRD", "SRD", "$"),
+    ("SSP", "SSP", "£"),
+    ("STN", "STN", "Db"),
+    ("SYP", "SYP", "£"),
+    ("THB", "THB", "฿"),
+    ("TOP", "TOP", "T$"),
+    ("TRY", "TRY", "₺"),
+    ("TTD", "TTD", "$"),
+    ("TWD", "NT$", "$"),
+    ("UAH", "UAH", "₴"),
+    ("USD", "US$", "$"),
+    ("UYU", "UYU", "$"),
+    ("VEF", "VEF", "Bs"),
+    ("VND", "₫", "₫"),
+    ("XAF", "FCFA", "FCFA"),
+    ("XCD", "EC$", "$"),
+    ("XCG", "Cg.", "Cg."),
+    ("XOF", "F\u{202F}CFA", "F\u{202F}CFA"),
+    ("XPF", "CFPF", "CFPF"),
+    ("XXX", "¤", "¤"),
+    ("ZAR", "ZAR", "R"),
+    ("ZMW", "ZMW", "ZK"),
+];
+
+/// The symbols of the languages that differ from the root ones, sorted by
+/// language and code.
+pub(super) static LANGUAGE_SYMBOLS: &[(LangCode, &str, &str, &str)] = &[
+    (Af, "BYN", "BYN", "р."),
+    (Af, "RON", "RON", "leu"),
+    (Af, "THB", "฿", "฿"),
+    (Af, "TWD", "NT$", "NT$"),
+    (Af, "ZAR", "R", "R"),
+    (Ak, "GHS", "GH₵", "GH₵"),
+    (Ak, "IQD", "Irak dinaa", "Irak dinaa"),
+    (Ak, "XOF", "AAS", "AAS"),
+    (Sq, "ALL", "Lekë", "Lekë"),
+    (Sq, "HKD", "HK$", "HKS"),
+    (Sq, "THB", "฿", "฿"),
+    (Sq, "XCG", "ANG", "ANG"),
+    (Am, "AUD", "AU$", "$"),
+    (Am, "BYN", "BYN", "р."),
+    (Am, "ETB", "ብር", "ብር"),
+    (Am, "THB", "฿", "฿"),
+    (Am, "TWD", "NT$", "NT$"),
+    (Ar, "AED", "د.إ.\u{200F}", "د.إ.\u{200F}"),
+    (Ar, "ARS", "ARS", "AR$"),
+    (Ar, "AUD", "AU$", "AU$"),
+    (Ar, "BBD", "BBD", "BB$"),
+    (Ar, "BHD", "د.ب.\u{200F}", "د.ب.\u{200F}"),
+    (Ar, "BMD", "BMD", "BM$"),
+    (Ar, "BND", "BND", "BN$"),
+    (Ar, "BSD", "BSD", "BS$"),
+    (Ar, "BYN", "BYN", "р."),
+    (Ar, "BZD", "BZD", "BZ$"),
+    (Ar, "CAD", "CA$", "CA$"),
+    (Ar, "CLP", "CLP", "CL$"),
+    (Ar, "CNY", "CN¥", "CN¥"),
+    (Ar, "COP", "COP", "CO$"),
+    (Ar, "CUP", "CUP", "CU$"),
+    (Ar, "DOP", "DOP", "DO$"),
+    (Ar, "DZD", "د.ج.\u{200F}", "د.ج.\u{200F}"),
+    (Ar, "EGP", "ج.م.\u{200F}", "E£"),
+    (Ar, "FJD", "FJD", "FJ$"),
+    (Ar, "GBP", "UK£", "UK£"),
+    (Ar, "GYD", "GYD", "GY$"),
+    (Ar, "HKD", "HK$", "HK$"),
+    (Ar, "IQD", "د.ع.\u{200F}", "د.ع.\u{200F}"),
+    (Ar, "IRR", "ر.إ.", "ر.إ."),
+    (Ar, "JMD", "JMD", "JM$"),
+    (Ar, "JOD", "د.أ.\u{200F}", "د.أ.\u{200F}"),
+    (Ar, "JPY", "JP¥", "JP¥"),
+    (Ar, "KWD", "د.ك.\u{200F}", "د.ك.\u{200F}"),
+    (Ar, "KYD", "KYD", "KY$"),
+    (Ar, "LBP", "ل.ل.\u{200F}", "L£"),
+    (Ar, "LRD", "LRD", "$LR"),
+    (Ar, "LYD", "د.ل.\u{200F}", "د.ل.\u{200F}"),
+    (Ar, "MAD", "د.م.\u{200F}", "د.م.\u{200F}"),
+    (Ar, "MRU", "أ.م.", "أ.م."),
+    (Ar, "MXN", "MX$", "MX$"),
+    (Ar, "NZD", "NZ$", "NZ$"),
+    (Ar, "OMR", "ر.ع.\u{200F}", "ر.ع.\u{200F}"),
+    (Ar, "QAR", "ر.ق.\u{200F}", "ر.ق.\u{200F}"),
+    (Ar, "SAR", "ر.س.\u{200F}", "ر.س.\u{200F}"),
+    (Ar, "SBD", "SBD", "SB$"),
+    (Ar, "SDD", "د.س.\u{200F}", "د.س.\u{200F}"),
+    (Ar, "SDG", "ج.س.", "ج.س."),
+    (Ar, "SRD", "SRD", "SR$"),
+    (Ar, "SYP", "ل.س.\u{200F}", "£"),
+    (Ar, "THB", "฿", "฿"),
+    (Ar, "TND", "د.ت.\u{200F}", "د.ت.\u{200F}"),
+    (Ar, "TTD", "TTD", "TT$"),
+    (Ar, "TWD", "NT$", "NT$"),
+    (Ar, "USD", "US$", "US$"),
+    (Ar, "UYU", "UYU", "UY$"),
+    (Ar, "YER", "ر.ي.\u{200F}", "ر.ي.\u{200F}"),
+    (Hy, "AMD", "֏", "֏"),
+    (Hy, "BYN", "BYN", "р."),
+    (Hy, "THB", "฿", "฿"),
+    (Hy, "TWD", "NT$", "NT$"),
+    (Hy, "USD", "$", "$"),
+    (As, "BYN", "BYN", "р."),
+    (Az, "AZN", "₼", "₼"),
+    (Az, "BYN", "BYN", "р."),
+    (Az, "RON", "RON", "ley"),
+    (Az, "SYP", "SYP", "S£"),
+    (Az, "THB", "฿", "฿"),
+    (Az, "TWD", "NT$", "NT$"),
+    (Ba, "RUR", "р.", "р."),
+    (Ba, "USD", "$", "$"),
+    (Ba, "XOF", "F CFA", "F CFA"),
+    (Eu, "BYN", "BYN", "р."),
+    (Eu, "ESP", "₧", "₧"),
+    (Eu, "THB", "฿", "฿"),
+    (Eu, "TWD", "NT$", "NT$"),
+    (Be, "AUD", "A$", "A$"),
+    (Be, "BBD", "BBD", "Bds$"),
+    (Be, "BMD", "BMD", "BD$"),
+    (Be, "BSD", "BSD", "B$"),
+    (Be, "BYN", "Br", "Br"),
+    (Be, "BZD", "BZD", "BZ$"),
+    (Be, "CAD", "CA$", "CA$"),
+    (Be, "CUC", "CUC", "CUC$"),
+    (Be, "CUP", "CUP", "$MN"),
+    (Be, "DOP", "DOP", "RD$"),
+    (Be, "FJD", "FJD", "FJ$"),
+    (Be, "FKP", "FKP", "FK£"),
+    (Be, "GYD", "GYD", "G$"),
+    (Be, "ISK", "ISK", "Íkr"),
+    (Be, "JMD", "JMD", "J$"),
+    (Be, "JPY", "¥", "¥"),
+    (Be, "KYD", "KYD", "CI$"),
+    (Be, "LRD", "LRD", "L$"),
+    (Be, "MXN", "MX$", "MX$"),
+    (Be, "NAD", "NAD", "N$"),
+    (Be, "NZD", "NZ$", "NZ$"),
+    (Be, "RUB", "₽", "руб."),
+    (Be, "SBD", "SBD", "SI$"),
+    (Be, "SGD", "SGD", "S$"),
+    (Be, "TTD", "TTD", "TT$"),
+    (Be, "USD", "$", "$"),
+    (Be, "UYU", "UYU", "$U"),
+    (Be, "XCD", "EC$", "EC$"),
+    (Bn, "BDT", "৳", "৳"),
+    (Bn, "BYN", "BYN", "р."),
+    (Bn, "THB", "฿", "฿"),
+    (Bn, "TWD", "NT$", "NT$"),
+    (Bs, "BAM", "KM", "KM"),
+    (Bs, "BYN", "BYN", "р."),
+    (Bs, "HRK", "kn", "kn"),
+    (Bs, "JPY", "¥", "¥"),
+    (Bs, "RSD", "din.", "din."),
+    (Bs, "THB", "฿", "฿"),
+    (Bs, "TWD", "NT$", "NT$"),
+    (Br, "AUD", "$A", "$"),
+    (Br, "BYN", "BYN", "р."),
+    (Br, "CAD", "$CA", "$"),
+    (Br, "EGP", "EGP", "£ E"),
+    (Br, "GBP", "£ RU", "£"),
+    (Br, "HKD", "$ HK", "$"),
+    (Br, "LBP", "LBP", "£L"),
+    (Br, "NZD", "$ ZN", "$"),
+    (Br, "RUR", "RUR", "р."),
+    (Br, "TOP", "TOP", "$ T"),
+    (Br, "USD", "$ SU", "$"),
+    (Bg, "AFN", "AFN", "Af"),
+    (Bg, "BGN", "лв.", "лв."),
+    (Bg, "USD", "щ.д.", "$"),
+    (My, "ANG", "NAf", "NAf"),
+    (My, "AWG", "Afl", "Afl"),
+    (My, "BBD", "BBD", "Bds$"),
+    (My, "BSD", "BSD", "B$"),
+    (My, "BYN", "BYN", "р."),
+    (My, "HTG", "G", "G"),
+    (My, "MMK", "K", "K"),
+    (My, "PAB", "B/.", "B/."),
+    (My, "THB", "฿", "฿"),
+    (My, "TTD", "TT$", "$"),
+    (Ca, "AUD", "AU$", "$"),
+    (Ca, "BYN", "BYN", "р."),
+    (Ca, "ESP", "₧", "₧"),
+    (Ca, "JPY", "¥", "¥"),
+    (Ca, "THB", "฿", "฿"),
+    (Ca, "VEF", "VEF", "Bs F"),
+    (Zh, "AUD", "AU$", "$"),
+    (Zh, "BYN", "BYN", "р."),
+    (Zh, "CNY", "¥", "¥"),
+    (Zh, "ILR", "ILS", "ILS"),
+    (Zh, "RUR", "RUR", "р."),
+    (Zh, "TWD", "NT$", "NT$"),
+    (Cv, "RUB", "₽", "₽"),
+    (Cv, "USD", "$", "$"),
+    (Hr, "BYN", "BYN", "р."),
+    (Hr, "HRK", "kn", "kn"),
+    (Hr, "RUR", "RUR", "р."),
+    (Hr, "TWD", "NT$", "NT$"),
+    (Cs, "AUD", "AU$", "$"),
+    (Cs, "BYN", "BYN", "р."),
+    (Cs, "CSK", "Kčs", "Kčs"),
+    (Cs, "CZK", "Kč", "Kč"),
+    (Cs, "RON", "RON", "L"),
+    (Cs, "RUR", "RUR", "р."),
+    (Cs, "TWD", "NT$", "NT$"),
+    (Cs, "XEU", "ECU", "ECU"),
+    (Da, "AUD", "AU$", "$"),
+    (Da, "BYN", "BYN", "Br."),
+    (Da, "DKK", "kr.", "kr."),
+    (Da, "ISK", "ISK", "kr."),
+    (Da, "NOK", "NOK", "kr."),
+    (Da, "RON", "RON", "L"),
+    (Da, "SEK", "SEK", "kr."),
+    (Da, "THB", "฿", "฿"),
+    (Da, "TWD", "NT$", "NT$"),
+    (Nl, "AUD", "AU$", "$"),
+    (Nl, "BYN", "BYN", "р."),
+    (Nl, "CAD", "C$", "$"),
+    (Nl, "FJD", "FJ$", "$"),
+    (Nl, "RUR", "RUR", "р."),
+    (Nl, "SBD", "SI$", "$"),
+    (Nl, "THB", "฿", "฿"),
+    (Nl, "TWD", "NT$", "NT$"),
+    (Nl, "XCG", "Cg.", "Cg"),
+    (Nl, "ZWG", "ZiG", "ZiG"),
+    (En, "JPY", "¥", "¥"),
+    (En, "USD", "$", "$"),
+    (Eo, "GHS", "GHS", "₵"),
+    (Eo, "LKR", "LKR", "₨"),
+    (Eo, "MUR", "MUR", "₨"),
+    (Eo, "NPR", "NPR", "₨"),
+    (Eo, "PKR", "PKR", "₨"),
+    (Et, "AUD", "AU$", "$"),
+    (Et, "EEK", "kr", "kr"),
+    (Et, "JPY", "¥", "¥"),
+    (Et, "THB", "฿", "฿"),
+    (Et, "TWD", "NT$", "NT$"),
+    (Et, "USD", "$", "$"),
+    (Ee, "AUD", "AU$", "$"),
+    (Ee, "BYN", "BYN", "р."),
+    (Ee, "GHS", "GH₵", "GH₵"),
+    (Ee, "THB", "฿", "฿"),
+    (Fo, "BYN", "BYN", "р."),
+    (Fo, "DKK", "kr", "kr"),
+    (Fi, "FIM", "mk", "mk"),
+    (Fi, "JPY", "¥", "¥"),
+    (Fi, "STN", "STN", "STD"),
+    (Fi, "USD", "$", "$"),
+    (Fr, "ARS", "$AR", "$"),
+    (Fr, "AUD", "$AU", "$"),
+    (Fr, "BEF", "FB", "FB"),
+    (Fr, "BMD", "$BM", "$"),
+    (Fr, "BND", "$BN", "$"),
+    (Fr, "BYN", "BYN", "р."),
+    (Fr, "BZD", "$BZ", "$"),
+    (Fr, "CAD", "$CA", "$"),
+    (Fr, "CLP", "$CL", "$"),
+    (Fr, "COP", "$CO", "$"),
+    (Fr, "CYP", "£CY", "£CY"),
+    (Fr, "EGP", "EGP", "£E"),
+    (Fr, "FJD", "$FJ", "$"),
+    (Fr, "FKP", "£FK", "£"),
+    (Fr, "FRF", "F", "F"),
+    (Fr, "GBP", "£GB", "£"),
+    (Fr, "GIP", "£GI", "£"),
+    (Fr, "IEP", "£IE", "£IE"),
+    (Fr, "ILP", "£IL", "£IL"),
+    (Fr, "ITL", "₤IT", "₤IT"),
+    (Fr, "KMF", "KMF", "FC"),
+    (Fr, "LBP", "£LB", "£L"),
+    (Fr, "MTP", "£MT", "£MT"),
+    (Fr, "MXN", "$MX", "$"),
+    (Fr, "NAD", "$NA", "$"),
+    (Fr, "NIO", "NIO", "$C"),
+    (Fr, "NZD", "$NZ", "$"),
+    (Fr, "RHD", "$RH", "$RH"),
+    (Fr, "RON", "RON", "L"),
+    (Fr, "RWF", "RWF", "FR"),
+    (Fr, "SBD", "$SB", "$"),
+    (Fr, "SGD", "$SG", "$"),
+    (Fr, "SRD", "$SR", "$"),
+    (Fr, "TOP", "TOP", "$T"),
+    (Fr, "TTD", "$TT", "$"),
+    (Fr, "TWD", "NT$", "NT$"),
+    (Fr, "USD", "$US", "$"),
+    (Fr, "UYU", "$UY", "$"),
+    (Fr, "WST", "$WS", "$WS"),
+    (Fr, "XPF", "FCFP", "FCFP"),
+    (Fr, "ZMW", "ZMW", "Kw"),
+    (Gl, "BYN", "BYN", "Br"),
+    (Gl, "ESP", "₧", "₧"),
+    (Gl, "KMF", "KMF", "FC"),
+    (Gl, "MXN", "$MX", "$"),
+    (Gl, "RUB", "RUB", "руб"),
+    (Gl, "THB", "฿", "฿"),
+    (Gl, "TWD", "NT$", "NT$"),
+    (Gl, "USD", "$", "$"),
+    (Gl, "XCG", "ƒ", "ƒ"),
+    (Ka, "BYN", "BYN", "р."),
+    (Ka, "GEL", "₾", "₾"),
+    (Ka, "TWD", "NT$", "NT$"),
+    (De, "ATS", "öS", "öS"),
+    (De, "AUD", "AU$", "$"),
+    (De, "BGM", "BGK", "BGK"),
+    (De, "BGO", "BGJ", "BGJ"),
+    (De, "BYN", "BYN", "р."),
+    (De, "CUC", "CUC", "Cub$"),
+    (De, "DEM", "DM", "DM"),
+    (De, "FKP", "FKP", "Fl£"),
+    (De, "GHS", "GHS", "₵"),
+    (De, "GNF", "GNF", "F.G."),
+    (De, "JPY", "¥", "¥"),
+    (De, "KMF", "KMF", "FC"),
+    (De, "RON", "RON", "L"),
+    (De, "RUR", "RUR", "р."),
+    (De, "RWF", "RWF", "F.Rw"),
+    (De, "THB", "฿", "฿"),
+    (De, "TWD", "NT$", "NT$"),
+    (De, "USD", "$", "$"),
+    (De, "ZMW", "ZMW", "K"),
+    (El, "BYN", "BYN", "р."),
+    (El, "GRD", "Δρχ", "Δρχ"),
+    (El, "THB", "฿", "฿"),
+    (El, "USD", "$", "$"),
+    (Gu, "BYN", "BYN", "р."),
+    (Gu, "MUR", "MUR", "રૂ."),
+    (Gu, "THB", "฿", "฿"),
+    (Gu, "TWD", "NT$", "NT$"),
+    (Ht, "ARS", "$AR", "$"),
+    (Ht, "AUD", "$AU", "$"),
+    (Ht, "BEF", "FB", "FB"),
+    (Ht, "BMD", "$BM", "$"),
+    (Ht, "BND", "$BN", "$"),
+    (Ht, "BYN", "BYN", "р."),
+    (Ht, "BZD", "$BZ", "$"),
+    (Ht, "CAD", "$CA", "$"),
+    (Ht, "CLP", "$CL", "$"),
+    (Ht, "COP", "$CO", "$"),
+    (Ht, "CYP", "£CY", "£CY"),
+    (Ht, "EGP", "EGP", "£E"),
+    (Ht, "FJD", "$FJ", "$"),
+    (Ht, "FKP", "£FK", "£"),
+    (Ht, "FRF", "F", "F"),
+    (Ht, "GBP", "£GB", "£"),
+    (Ht, "GIP", "£GI", "£"),
+    (Ht, "HTG", "G", "G"),
+    (Ht, "IEP", "£IE", "£IE"),
+    (Ht, "ILP", "£IL", "£IL"),
+    (Ht, "ITL", "₤IT", "₤IT"),
+    (Ht, "KMF", "KMF", "FC"),
+    (Ht, "LBP", "£LB", "£L"),
+    (Ht, "MTP", "£MT", "£MT"),
+    (Ht, "MXN", "$MX", "$"),
+    (Ht, "NAD", "$NA", "$"),
+    (Ht, "NIO", "NIO", "$C"),
+    (Ht, "NZD", "$NZ", "$"),
+    (Ht, "RHD", "$RH", "$RH"),
+    (Ht, "RON", "RON", "L"),
+    (Ht, "RWF", "RWF", "FR"),
+    (Ht, "SBD", "$SB", "$"),
+    (Ht, "SGD", "$SG", "$"),
+    (Ht, "SRD", "$SR", "$"),
+    (Ht, "TOP", "TOP", "$T"),
+    (Ht, "TTD", "$TT", "$"),
+    (Ht, "TWD", "NT$", "NT$"),
+    (Ht, "USD", "$US", "$"),
+    (Ht, "UYU", "$UY", "$"),
+    (Ht, "WST", "$WS", "$WS"),
+    (Ht, "XPF", "FCFP", "FCFP"),
+    (Ht, "ZMW", "ZMW", "Kw"),
+    (Ha, "JPY", "¥", "¥"),
+    (Ha, "NGN", "₦", "₦"),
+    (Ha, "USD", "$", "$"),
+    (He, "BYN", "BYN", "р"),
+    (He, "CNY", "\u{200E}CN¥\u{200E}", "¥"),
+    (He, "ILP", "ל״י", "ל״י"),
+    (He, "JPY", "¥", "¥"),
+    (He, "THB", "฿", "฿"),
+    (He, "TWD", "NT$", "NT$"),
+    (He, "USD", "$", "$"),
+    (Hi, "BYN", "BYN", "р."),
+    (Hi, "RON", "RON", "लेई"),
+    (Hi, "THB", "฿", "฿"),
+    (Hi, "TWD", "NT$", "NT$"),
+    (Hi, "USD", "$", "$"),
+    (Hu, "BYN", "BYN", "р."),
+    (Hu, "HUF", "Ft", "Ft"),
+    (Hu, "JPY", "¥", "¥"),
+    (Hu, "TWD", "NT$", "NT$"),
+    (Ia, "NLG", "ƒ", "ƒ"),
+    (Ia, "RUB", "₽", "₽"),
+    (Id, "AUD", "AU$", "$"),
+    (Id, "BYN", "BYN", "р."),
+    (Id, "IDR", "Rp", "Rp"),
+    (Id, "INR", "Rs", "₹"),
+    (Id, "THB", "฿", "฿"),
+    (Id, "TWD", "NT$", "NT$"),
+    (Ga, "JPY", "¥", "¥"),
+    (Ga, "RUR", "RUR", "р."),
+    (Ga, "THB", "฿", "฿"),
+    (Ga, "TWD", "NT$", "NT$"),
+    (Ga, "USD", "$", "$"),
+    (Ig, "JPY", "¥", "¥"),
+    (Ig, "NGN", "₦", "₦"),
+    (Ig, "USD", "$", "$"),
+    (Is, "DKK", "DKK", "kr."),
+    (Is, "ISK", "kr.", "kr."),
+    (Is, "NOK", "NOK", "kr."),
+    (Is, "SEK", "SEK", "kr."),
+    (Is, "TWD", "NT$", "NT$"),
+    (It, "BYN", "BYN", "Br"),
+    (It, "EGP", "EGP", "£E"),
+    (It, "NOK", "NOK", "NKr"),
+    (It, "THB", "฿", "฿"),
+    (It, "TWD", "NT$", "NT$"),
+    (It, "XCG", "Cf", "Cf"),
+    (Ja, "BYN", "BYN", "р."),
+    (Ja, "CNY", "元", "￥"),
+    (Ja, "JPY", "￥", "￥"),
+    (Ja, "RON", "RON", "レイ"),
+    (Ja, "USD", "$", "$"),
+    (Ja, "XCG", "Cg", "Cg"),
+    (Jv, "IDR", "Rp", "Rp"),
+    (Kn, "BYN", "BYN", "р."),
+    (Kn, "RON", "RON", "ಲೀ"),
+    (Kn, "THB", "฿", "฿"),
+    (Kn, "TWD", "NT$", "NT$"),
+    (Kn, "USD", "$", "$"),
+    (Ks, "JPY", "¥", "¥"),
+    (Ks, "USD", "$", "$"),
+    (Kk, "BYN", "BYN", "р."),
+    (Kk, "KZT", "₸", "₸"),
+    (Kk, "LSL", "ЛСЛ", "ЛСЛ"),
+    (Kk, "RUB", "₽", "₽"),
+    (Kk, "THB", "฿", "฿"),
+    (Kk, "TWD", "NT$", "NT$"),
+    (Kk, "USD", "$", "$"),
+    (Km, "BYN", "BYN", "р."),
+    (Km, "KHR", "៛", "៛"),
+    (Km, "LSL", "ឡូទី", "ឡូទី"),
+    (Km, "THB", "฿", "฿"),
+    (Km, "TWD", "NT$", "NT$"),
+    (Km, "USD", "$", "$"),
+    (Rw, "RWF", "RF", "RF"),
+    (Ky, "BMD", "BMD", "BD$"),
+    (Ky, "BSD", "BSD", "B$"),
+    (Ky, "BYN", "BYN", "р."),
+    (Ky, "BZD", "BZD", "BZ$"),
+    (Ky, "CAD", "CA$", "C$"),
+    (Ky, "DOP", "DOP", "RD$"),
+    (Ky, "EGP", "EGP", "LE"),
+    (Ky, "HKD", "HK$", "HK$"),
+    (Ky, "HRK", "HRK", "Kn"),
+    (Ky, "JMD", "JMD", "J$"),
+    (Ky, "KGS", "сом", "⃀"),
+    (Ky, "THB", "฿", "฿"),
+    (Ky, "TTD", "TTD", "TT$"),
+    (Ky, "TWD", "NT$", "NT$"),
+    (Ko, "AUD", "AU$", "$"),
+    (Ko, "BYN", "BYN", "р."),
+    (Ko, "RON", "RON", "L"),
+    (Ko, "TWD", "NT$", "NT$"),
+    (Ku, "TRY", "₺", "₺"),
+    (Ku, "USD", "$", "$"),
+    (Lb, "ATS", "öS", "öS"),
+    (Lb, "AUD", "AU$", "$"),
+    (Lb, "JPY", "¥", "¥"),
+    (Lb, "THB", "฿", "฿"),
+    (Lb, "TWD", "NT$", "NT$"),
+    (Lb, "USD", "$", "$"),
+    (Lo, "BYN", "BYN", "р."),
+    (Lo, "LAK", "₭", "₭"),
+    (Lo, "THB", "฿", "฿"),
+    (Lo, "TWD", "NT$", "NT$"),
+    (Lt, "BYN", "BYN", "Br"),
+    (Lt, "PLN", "PLN", "zl"),
+    (Lt, "PYG", "PYG", "Gs"),
+    (Lt, "RUB", "RUB", "rb"),
+    (Lv, "AUD", "AU$", "$"),
+    (Lv, "BYN", "BYN", "р."),
+    (Lv, "JPY", "¥", "¥"),
+    (Lv, "LVL", "Ls", "Ls"),
+    (Lv, "THB", "฿", "฿"),
+    (Lv, "TWD", "NT$", "NT$"),
+    (Lv, "USD", "$", "$"),
+    (Mk, "BYN", "BYN", "р."),
+    (Mk, "MKD", "ден.", "ден."),
+    (Mk, "TWD", "NT$", "NT$"),
+    (Ms, "BYN", "BYN", "р."),
+    (Ms, "MYR", "RM", "RM"),
+    (Ms, "TWD", "NT$", "NT$"),
+    (Ml, "BYN", "BYN", "р."),
+    (Ml, "JPY", "¥", "¥"),
+    (Ml, "THB", "฿", "฿"),
+    (Ml, "TWD", "NT$", "NT$"),
+    (Ml, "USD", "$", "$"),
+    (Mt, "BYN", "BYN", "р."),
+    (Mi, "JPY", "¥", "¥"),
+    (Mi, "NZD", "$", "$"),
+    (Mr, "THB", "฿", "฿"),
+    (Mr, "TWD", "NT$", "NT$"),
+    (Mr, "USD", "$", "$"),
+    (Mn, "BYN", "BYN", "р."),
+    (Mn, "MNT", "₮", "₮"),
+    (Mn, "SEK", "SEK", "кр"),
+    (Mn, "THB", "฿", "฿"),
+    (Mn, "TWD", "NT$", "NT$"),
+    (Mn, "USD", "$", "$"),
+    (Ne, "BYN", "BYN", "р."),
+    (Ne, "NPR", "नेरू", "रू"),
+    (Ne, "THB", "฿", "฿"),
+    (Nb, "BYN", "BYN", "р."),
+    (Nb, "NOK", "kr", "kr"),
+    (Nb, "RON", "RON", "L"),
+    (Nb, "TWD", "NT$", "NT$"),
+    (Nn, "BYN", "BYN", "р."),
+    (Nn, "NOK", "kr", "kr"),
+    (No, "BYN", "BYN", "р."),
+    (No, "NOK", "kr", "kr"),
+    (No, "RON", "RON", "L"),
+    (No, "TWD", "NT$", "NT$"),
+    (Ii, "CNY", "¥", "¥"),
+    (Om, "ETB", "Br", "Br"),
+    (Or, "BYN", "BYN", "р."),
+    (Or, "JPY", "¥", "¥"),
+    (Or, "USD", "$", "$"),
+    (Pa, "BYN", "BYN", "р."),
+    (Pa, "THB", "฿", "฿"),
+    (Pa, "TWD", "NT$", "NT$"),
+    (Fa, "AFN", "؋", "؋"),
+    (Fa, "BYN", "BYN", "Br"),
+    (Fa, "CAD", "$CA", "$"),
+    (Fa, "CNY", "¥CN", "¥"),
+    (Fa, "HKD", "$HK", "$"),
+    (Fa, "IRR", "ریال", "ریال"),
+    (Fa, "JPY", "¥", "¥"),
+    (Fa, "MXN", "$MX", "$"),
+    (Fa, "NZD", "$NZ", "$"),
+    (Fa, "THB", "฿", "฿"),
+    (Fa, "USD", "$", "$"),
+    (Fa, "XCD", "$EC", "$"),
+    (Fa, "XOF", "فرانک\u{202F}CFA", "فرانک\u{202F}CFA"),
+    (Pl, "PLN", "zł", "zł"),
+    (Pl, "RON", "RON", "lej"),
+    (Pl, "TWD", "NT$", "NT$"),
+    (Ps, "AFN", "؋", "؋"),
+    (Ps, "BYN", "BYN", "р."),
+    (Ps, "USD", "$", "$"),
+    (Pt, "AUD", "AU$", "$"),
+    (Pt, "BYN", "BYN", "р."),
+    (Pt, "PTE", "Esc.", "Esc."),
+    (Pt, "RON", "RON", "L"),
+    (Pt, "SYP", "SYP", "S£"),
+    (Pt, "THB", "฿", "฿"),
+    (Pt, "TWD", "NT$", "NT$"),
+    (Qu, "BBD", "BBG", "$"),
+    (Qu, "BMD", "DBM", "$"),
+    (Qu, "BZD", "DBZ", "$"),
+    (Qu, "CAD", "$CA", "$"),
+    (Qu, "GHS", "GHS", "GHC"),
+    (Qu, "PEN", "S/", "S/"),
+    (Qu, "USD", "$US", "$"),
+    (Rm, "USD", "$", "$"),
+    (Ro, "BYN", "BYN", "р."),
+    (Ro, "TWD", "NT$", "NT$"),
+    (Ru, "BYN", "BYN", "р."),
+    (Ru, "GEL", "GEL", "ლ"),
+    (Ru, "JPY", "¥", "¥"),
+    (Ru, "RON", "RON", "L"),
+    (Ru, "RUB", "₽", "₽"),
+    (Ru, "RUR", "р.", "р."),
+    (Ru, "THB", "฿", "฿"),
+    (Ru, "TMT", "ТМТ", "ТМТ"),
+    (Ru, "TWD", "NT$", "NT$"),
+    (Ru, "UAH", "₴", "₴"),
+    (Ru, "USD", "$", "$"),
+    (Ru, "XCG", "Cg", "Cg"),
+    (Ru, "XXX", "XXXX", "XXXX"),
+    (Sc, "XDR", "DIP", "DIP"),
+    (Sd, "BYN", "BYN", "р."),
+    (Sd, "PKR", "Rs", "Rs"),
+    (Sr, "BAM", "КМ", "KM"),
+    (Sr, "BYN", "BYN", "р."),
+    (Sr, "GEL", "GEL", "ლ"),
+    (Sr, "JPY", "¥", "¥"),
+    (Sr, "TWD", "NT$", "NT$"),
+    (Gd, "BYN", "BYN", "р."),
+    (Gd, "RON", "RON", "leu"),
+    (Gd, "RUR", "RUR", "р."),
+    (Gd, "THB", "฿", "฿"),
+    (Gd, "TWD", "NT$", "NT$"),
+    (Gd, "USD", "$", "$"),
+    (Si, "BYN", "BYN", "р."),
+    (Si, "LKR", "රු.", "රු."),
+    (Si, "THB", "฿", "฿"),
+    (Si, "TWD", "NT$", "NT$"),
+    (Si, "XOF", "සිෆ්එ", "සිෆ්එ"),
+    (Sk, "BYN", "BYN", "р."),
+    (Sk, "ILS", "NIS", "₪"),
+    (Sk, "RUR", "RUR", "р."),
+    (Sk, "TWD", "NT$", "NT$"),
+    (Sk, "XCG", "Cg", "Cg"),
+    (Sl, "BYN", "BYN", "р."),
+    (Sl, "JPY", "¥", "¥"),
+    (Sl, "TWD", "NT$", "NT$"),
+    (Sl, "USD", "$", "$"),
+    (So, "BBD", "DBB", "$"),
+    (So, "SOS", "S", "S"),
+    (St, "ZAR", "R", "R"),
+    (Es, "BYN", "BYN", "р."),
+    (Es, "ESP", "₧", "₧"),
+    (Es, "RON", "RON", "L"),
+    (Es, "THB", "฿", "฿"),
+    (Es, "TWD", "NT$", "NT$"),
+    (Su, "IDR", "Rp", "Rp"),
+    (Su, "JPY", "¥", "¥"),
+    (Su, "USD", "$", "$"),
+    (Sw, "BYN", "BYN", "р."),
+    (Sw, "KES", "Ksh", "Ksh"),
+    (Sw, "THB", "฿", "฿"),
+    (Sw, "TWD", "NT$", "NT$"),
+    (Sw, "TZS", "TSh", "TSh"),
+    (Sv, "BBD", "Bds$", "$"),
+    (Sv, "BMD", "BM$", "$"),
+    (Sv, "BRL", "BR$", "R$"),
+    (Sv, "BSD", "BS$", "$"),
+    (Sv, "BYN", "BYN", "р."),
+    (Sv, "BZD", "BZ$", "$"),
+    (Sv, "DKK", "Dkr", "kr"),
+    (Sv, "DOP", "RD$", "$"),
+    (Sv, "EEK", "Ekr", "Ekr"),
+    (Sv, "EGP", "EG£", "E£"),
+    (Sv, "IEP", "IE£", "IE£"),
+    (Sv, "ISK", "Ikr", "kr"),
+    (Sv, "JMD", "JM$", "$"),
+    (Sv, "NOK", "Nkr", "kr"),
+    (Sv, "RON", "RON", "L"),
+    (Sv, "SEK", "kr", "kr"),
+    (Sv, "TWD", "NT$", "NT$"),
+    (Ta, "BYN", "BYN", "р."),
+    (Ta, "JPY", "¥", "¥"),
+    (Ta, "THB", "฿", "฿"),
+    (Ta, "TWD", "NT$", "NT$"),
+    (Ta, "USD", "$", "$"),
+    (Te, "BYN", "BYN", "р."),
+    (Te, "THB", "฿", "฿"),
+    (Te, "TWD", "NT$", "NT$"),
+    (Te, "USD", "$", "$"),
+    (Tg, "TJS", "сом.", "сом."),
+    (Tg, "USD", "$", "$"),
+    (Th, "AUD", "AU$", "$"),
+    (Th, "BYN", "BYN", "р."),
+    (Th, "JPY", "¥", "¥"),
+    (Th, "THB", "฿", "฿"),
+    (Th, "TWD", "NT$", "NT$"),
+    (Ti, "ETB", "Br", "Br"),
+    (Tk, "BYN", "BYN", "р."),
+    (Tk, "XCG", "Kg.", "Kg."),
+    (Tl, "BYN", "BYN", "р."),
+    (Tl, "JPY", "¥", "¥"),
+    (Tl, "THB", "฿", "฿"),
+    (Tl, "TWD", "NT$", "NT$"),
+    (Tl, "USD", "$", "$"),
+    (Tn, "ZAR", "R", "R"),
+    (To, "AUD", "AUD$", "AU$"),
+    (To, "FJD", "FJD", "F$"),
+    (To, "NZD", "NZD$", "NZ$"),
+    (To, "SBD", "SBD", "S$"),
+    (To, "TOP", "T$", "T$"),
+    (Tr, "AUD", "AU$", "$"),
+    (Tr, "BYN", "BYN", "р."),
+    (Tr, "JPY", "¥", "¥"),
+    (Tr, "RON", "RON", "L"),
+    (Tr, "RUR", "RUR", "р."),
+    (Tr, "THB", "฿", "฿"),
+    (Tr, "TRY", "₺", "₺"),
+    (Tr, "TWD", "NT$", "NT$"),
+    (Tr, "USD", "$", "$"),
+    (Tt, "RUB", "₽", "₽"),
+    (Tt, "USD", "$", "$"),
+    (Ug, "CNY", "￥", "¥"),
+    (Ug, "USD", "$", "$"),
+    (Uk, "BYN", "BYN", "р."),
+    (Uk, "JPY", "¥", "¥"),
+    (Uk, "RUR", "RUR", "р."),
+    (Uk, "UAH", "₴", "₴"),
+    (Uk, "UAK", "крб.", "крб."),
+    (Ur, "BYN", "BYN", "р."),
+    (Ur, "PKR", "Rs", "Rs"),
+    (Ur, "THB", "฿", "฿"),
+    (Ur, "TWD", "NT$", "NT$"),
+    (Ur, "USD", "$", "$"),
+    (Uz, "BYN", "BYN", "р."),
+    (Uz, "UZS", "soʻm", "soʻm"),
+    (Vi, "AUD", "AU$", "$"),
+    (Vi, "BYN", "BYN", "р."),
+    (Vi, "JPY", "¥", "¥"),
+    (Vi, "THB", "฿", "฿"),
+    (Vi, "TWD", "NT$", "NT$"),
+    (Cy, "BDT", "BDT", "TK"),
+    (Cy, "BYN", "BYN", "р."),
+    (Cy, "HKD", "HK$", "HK$"),
+    (Cy, "THB", "฿", "฿"),
+    (Cy, "TWD", "NT$", "NT$"),
+    (Wo, "CLP", "CLP", "Vote $"),
+    (Wo, "EGP", "EGPP", "E£"),
+    (Wo, "GHS", "GHS.", "GH₵"),
+    (Wo, "GIP", "GIIP", "£"),
+    (Wo, "GTQ", "GT Q", "Q"),
+    (Wo, "HRK", "HRKS", "kn"),
+    (Wo, "HUF", "HUF", "Vote Ft"),
+    (Wo, "MDL", "Vote MDL", "Vote MDL"),
+    (Wo, "NGN", "NGN.", "₦"),
+    (Wo, "RON", "RON", "Vote lei"),
+    (Wo, "USD", "$", "$"),
+    (Fy, "AUD", "AU$", "$"),
+    (Fy, "CAD", "C$", "$"),
+    (Fy, "FJD", "FJ$", "$"),
+    (Fy, "SBD", "SI$", "$"),
+    (Fy, "THB", "฿", "฿"),
+    (Xh, "CNH", "I-CNH", "I-CNH"),
+    (Xh, "JPY", "¥", "¥"),
+    (Xh, "USD", "$", "$"),
+    (Xh, "XCG", "ICg.", "ICg."),
+    (Xh, "ZAR", "R", "R"),
+    (Xh, "ZWG", "IZWG", "IZWG"),
+    (Yo, "NGN", "₦", "₦"),
+    (Yo, "RUB", "₽", "₽"),
+    (Yo, "USD", "$", "$"),
+    (Zu, "BYN", "BYN", "P."),
+    (Zu, "DKK", "DKK", "Kr"),
+    (Zu, "HRK", "HRK", "Kn"),
+    (Zu, "ISK", "ISK", "Kr"),
+    (Zu, "NOK", "NOK", "Kr"),
+    (Zu, "PLN", "PLN", "Zł"),
+    (Zu, "SEK", "SEK", "Kr"),
+    (Zu, "THB", "฿", "฿"),
+    (Zu, "TWD", "NT$", "NT$"),
+    (Zu, "USD", "$", "$"),
+    (Zu, "ZAR", "R", "R"),
+];
+
+/// The symbols of the regions that differ from the ones of their language.
+pub(super) static REGION_SYMBOLS: &[(LangCode, &str, &str, &str, &str)] = &[
+    (Af, "NA", "NAD", "$", "$"),
+    (Sq, "MK", "MKD", "den", "den"),
+    (Ar, "AE", "BND", "BND", "$"),
+    (Ar, "DJ", "DJF", "Fdj", "Fdj"),
+    (Ar, "ER", "ERN", "Nfk", "Nfk"),
+    (Ar, "KM", "KMF", "CF", "CF"),
+    (Ar, "SO", "SOS", "S", "S"),
+    (Ar, "SS", "GBP", "GB£", "UK£"),
+    (Ar, "SS", "SSP", "£", "£"),
+    (Bn, "IN", "USD", "$", "$"),
+    (Ca, "FR", "FRF", "F", "F"),
+    (Zh, "BN", "CNY", "CN¥", "¥"),
+    (Zh, "BN", "JPY", "¥", "¥"),
+    (Zh, "BN", "KRW", "￦", "₩"),
+    (Zh, "BN", "RON", "RON", "L"),
+    (Zh, "BN", "TWD", "$", "$"),
+    (Zh, "GB", "CNY", "CN¥", "¥"),
+    (Zh, "GB", "JPY", "¥", "¥"),
+    (Zh, "GB", "KRW", "￦", "₩"),
+    (Zh, "GB", "RON", "RON", "L"),
+    (Zh, "GB", "TWD", "$", "$"),
+    (Zh, "GF", "CNY", "CN¥", "¥"),
+    (Zh, "GF", "JPY", "¥", "¥"),
+    (Zh, "GF", "KRW", "￦", "₩"),
+    (Zh, "GF", "RON", "RON", "L"),
+    (Zh, "GF", "TWD", "$", "$"),
+    (Zh, "HK", "CNY", "CN¥", "¥"),
+    (Zh, "HK", "JPY", "¥", "¥"),
+    (Zh, "HK", "RON", "RON", "L"),
+    (Zh, "HK", "TWD", "NT$", "$"),
+    (Zh, "ID", "CNY", "CN¥", "¥"),
+    (Zh, "ID", "JPY", "¥", "¥"),
+    (Zh, "ID", "KRW", "￦", "₩"),
+    (Zh, "ID", "RON", "RON", "L"),
+    (Zh, "ID", "TWD", "$", "$"),
+    (Zh, "MO", "CNY", "CN¥", "¥"),
+    (Zh, "MO", "JPY", "¥", "¥"),
+    (Zh, "MO", "MOP", "MOP$", "MOP$"),
+    (Zh, "MO", "RON", "RON", "L"),
+    (Zh, "MO", "TWD", "NT$", "$"),
+    (Zh, "PA", "CNY", "CN¥", "¥"),
+    (Zh, "PA", "JPY", "¥", "¥"),
+    (Zh, "PA", "KRW", "￦", "₩"),
+    (Zh, "PA", "RON", "RON", "L"),
+    (Zh, "PA", "TWD", "$", "$"),
+    (Zh, "PF", "CNY", "CN¥", "¥"),
+    (Zh, "PF", "JPY", "¥", "¥"),
+    (Zh, "PF", "KRW", "￦", "₩"),
+    (Zh, "PF", "RON", "RON", "L"),
+    (Zh, "PF", "TWD", "$", "$"),
+    (Zh, "PH", "CNY", "CN¥", "¥"),
+    (Zh, "PH", "JPY", "¥", "¥"),
+    (Zh, "PH", "KRW", "￦", "₩"),
+    (Zh, "PH", "RON", "RON", "L"),
+    (Zh, "PH", "TWD", "$", "$"),
+    (Zh, "SR", "CNY", "CN¥", "¥"),
+    (Zh, "SR", "JPY", "¥", "¥"),
+    (Zh, "SR", "KRW", "￦", "₩"),
+    (Zh, "SR", "RON", "RON", "L"),
+    (Zh, "SR", "TWD", "$", "$"),
+    (Zh, "TH", "CNY", "CN¥", "¥"),
+    (Zh, "TH", "JPY", "¥", "¥"),
+    (Zh, "TH", "KRW", "￦", "₩"),
+    (Zh, "TH", "RON", "RON", "L"),
+    (Zh, "TH", "TWD", "$", "$"),
+    (Zh, "TW", "CNY", "CN¥", "¥"),
+    (Zh, "TW", "JPY", "¥", "¥"),
+    (Zh, "TW", "KRW", "￦", "₩"),
+    (Zh, "TW", "RON", "RON", "L"),
+    (Zh, "TW", "TWD", "$", "$"),
+    (Zh, "US", "CNY", "CN¥", "¥"),
+    (Zh, "US", "JPY", "¥", "¥"),
+    (Zh, "US", "KRW", "￦", "₩"),
+    (Zh, "US", "RON", "RON", "L"),
+    (Zh, "US", "TWD", "$", "$"),
+    (Zh, "VN", "CNY", "CN¥", "¥"),
+    (Zh, "VN", "JPY", "¥", "¥"),
+    (Zh, "VN", "KRW", "￦", "₩"),
+    (Zh, "VN", "RON", "RON", "L"),
+    (Zh, "VN", "TWD", "$", "$"),
+    (Hr, "BA", "BAM", "KM", "KM"),
+    (Nl, "AW", "AWG", "Afl.", "Afl."),
+    (Nl, "BQ", "USD", "$", "$"),
+    (Nl, "CW", "ANG", "NAf.", "NAf."),
+    (Nl, "SR", "SRD", "$", "$"),
+    (En, "001", "JPY", "JP¥", "¥"),
+    (En, "001", "USD", "US$", "$"),
+    (En, "150", "JPY", "JP¥", "¥"),
+    (En, "150", "USD", "US$", "$"),
+    (En, "AT", "JPY", "JP¥", "¥"),
+    (En, "AT", "USD", "US$", "$"),
+    (En, "BE", "JPY", "JP¥", "¥"),
+    (En, "BE", "USD", "US$", "$"),
+    (En, "BI", "BIF", "FBu", "FBu"),
+    (En, "CA", "CAD", "$", "$"),
+    (En, "CA", "JPY", "JP¥", "¥"),
+    (En, "CA", "USD", "US$", "$"),
+    (En, "CA", "XCG", "Cg", "Cg"),
+    (En, "CC", "AUD", "$", "$"),
+    (En, "CC", "JPY", "JP¥", "¥"),
+    (En, "CC", "USD", "US$", "$"),
+    (En, "CH", "JPY", "JP¥", "¥"),
+    (En, "CH", "USD", "US$", "$"),
+    (En, "CM", "JPY", "JP¥", "¥"),
+    (En, "CM", "USD", "US$", "$"),
+    (En, "CY", "JPY", "JP¥", "¥"),
+    (En, "CY", "USD", "US$", "$"),
+    (En, "CZ", "JPY", "JP¥", "¥"),
+    (En, "CZ", "USD", "US$", "$"),
+    (En, "DE", "JPY", "JP¥", "¥"),
+    (En, "DE", "USD", "US$", "$"),
+    (En, "DK", "DKK", "kr.", "kr"),
+    (En, "DK", "JPY", "JP¥", "¥"),
+    (En, "DK", "USD", "US$", "$"),
+    (En, "EE", "JPY", "JP¥", "¥"),
+    (En, "EE", "USD", "US$", "$"),
+    (En, "ER", "ERN", "Nfk", "Nfk"),
+    (En, "ER", "JPY", "JP¥", "¥"),
+    (En, "ER", "USD", "US$", "$"),
+    (En, "ES", "JPY", "JP¥", "¥"),
+    (En, "ES", "USD", "US$", "$"),
+    (En, "FI", "JPY", "JP¥", "¥"),
+    (En, "FI", "USD", "US$", "$"),
+    (En, "FR", "JPY", "JP¥", "¥"),
+    (En, "FR", "USD", "US$", "$"),
+    (En, "GB", "JPY", "JP¥", "¥"),
+    (En, "GB", "USD", "US$", "$"),
+    (En, "GE", "GEL", "₾", "₾"),
+    (En, "GE", "JPY", "JP¥", "¥"),
+    (En, "GE", "USD", "US$", "$"),
+    (En, "GH", "GHS", "GH₵", "GH₵"),
+    (En, "GH", "JPY", "JP¥", "¥"),
+    (En, "GH", "USD", "US$", "$"),
+    (En, "HK", "JPY", "JP¥", "¥"),
+    (En, "HK", "USD", "US$", "$"),
+    (En, "HU", "JPY", "JP¥", "¥"),
+    (En, "HU", "USD", "US$", "$"),
+    (En, "ID", "IDR", "Rp", "Rp"),
+    (En, "ID", "JPY", "JP¥", "¥"),
+    (En, "ID", "USD", "US$", "$"),
+    (En, "IE", "JPY", "JP¥", "¥"),
+    (En, "IE", "USD", "US$", "$"),
+    (En, "IL", "JPY", "JP¥", "¥"),
+    (En, "IL", "USD", "US$", "$"),
+    (En, "IN", "JPY", "JP¥", "¥"),
+    (En, "IT", "JPY", "JP¥", "¥"),
+    (En, "IT", "USD", "US$", "$"),
+    (En, "KE", "JPY", "JP¥", "¥"),
+    (En, "KE", "KES", "Ksh", "Ksh"),
+    (En, "KE", "USD", "US$", "$"),
+    (En, "LR", "JPY", "JP¥", "¥"),
+    (En, "LR", "LRD", "$", "$"),
+    (En, "LR", "USD", "US$", "$"),
+    (En, "LS", "JPY", "JP¥", "¥"),
+    (En, "LS", "USD", "US$", "$"),
+    (En, "LS", "ZAR", "R", "R"),
+    (En, "LT", "JPY", "JP¥", "¥"),
+    (En, "LT", "USD", "US$", "$"),
+    (En, "LV", "JPY", "JP¥", "¥"),
+    (En, "LV", "USD", "US$", "$"),
+    (En, "MG", "JPY", "JP¥", "¥"),
+    (En, "MG", "MGA", "Ar", "Ar"),
+    (En, "MG", "USD", "US$", "$"),
+    (En, "MO", "JPY", "JP¥", "¥"),
+    (En, "MO", "MOP", "MOP$", "MOP$"),
+    (En, "MO", "USD", "US$", "$"),
+    (En, "MT", "GBP", "GB£", "£"),
+    (En, "MT", "JPY", "JP¥", "¥"),
+    (En, "MT", "USD", "US$", "$"),
+    (En, "MU", "JPY", "JP¥", "¥"),
+    (En, "MU", "MUR", "Rs", "Rs"),
+    (En, "MU", "USD", "US$", "$"),
+    (En, "MV", "JPY", "JP¥", "¥"),
+    (En, "MV", "MVR", "Rf", "Rf"),
+    (En, "MV", "USD", "US$", "$"),
+    (En, "MY", "JPY", "JP¥", "¥"),
+    (En, "MY", "MYR", "RM", "RM"),
+    (En, "MY", "USD", "US$", "$"),
+    (En, "NA", "JPY", "JP¥", "¥"),
+    (En, "NA", "NAD", "$", "$"),
+    (En, "NA", "USD", "US$", "$"),
+    (En, "NG", "JPY", "JP¥", "¥"),
+    (En, "NG", "NGN", "₦", "₦"),
+    (En, "NG", "USD", "US$", "$"),
+    (En, "NL", "JPY", "JP¥", "¥"),
+    (En, "NL", "USD", "US$", "$"),
+    (En, "NO", "JPY", "JP¥", "¥"),
+    (En, "NO", "NOK", "kr", "kr"),
+    (En, "NO", "USD", "US$", "$"),
+    (En, "NZ", "JPY", "JP¥", "¥"),
+    (En, "NZ", "NZD", "$", "$"),
+    (En, "NZ", "USD", "US$", "$"),
+    (En, "PG", "JPY", "JP¥", "¥"),
+    (En, "PG", "PGK", "K", "K"),
+    (En, "PG", "USD", "US$", "$"),
+    (En, "PK", "JPY", "JP¥", "¥"),
+    (En, "PK", "PKR", "Rs", "Rs"),
+    (En, "PK", "USD", "US$", "$"),
+    (En, "PL", "JPY", "JP¥", "¥"),
+    (En, "PL", "USD", "US$", "$"),
+    (En, "PT", "JPY", "JP¥", "¥"),
+    (En, "PT", "USD", "US$", "$"),
+    (En, "PW", "JPY", "JP¥", "¥"),
+    (En, "PW", "USD", "US$", "$"),
+    (En, "RO", "JPY", "JP¥", "¥"),
+    (En, "RO", "USD", "US$", "$"),
+    (En, "RW", "JPY", "JP¥", "¥"),
+    (En, "RW", "RWF", "RF", "RF"),
+    (En, "RW", "USD", "US$", "$"),
+    (En, "SC", "JPY", "JP¥", "¥"),
+    (En, "SC", "SCR", "SR", "SR"),
+    (En, "SC", "USD", "US$", "$"),
+    (En, "SD", "JPY", "JP¥", "¥"),
+    (En, "SD", "USD", "US$", "$"),
+    (En, "SE", "JPY", "JP¥", "¥"),
+    (En, "SE", "SEK", "kr", "kr"),
+    (En, "SE", "USD", "US$", "$"),
+    (En, "SI", "JPY", "JP¥", "¥"),
+    (En, "SI", "USD", "US$", "$"),
+    (En, "SK", "JPY", "JP¥", "¥"),
+    (En, "SK", "USD", "US$", "$"),
+    (En, "SL", "JPY", "JP¥", "¥"),
+    (En, "SL", "SLE", "Le", "Le"),
+    (En, "SL", "USD", "US$", "$"),
+    (En, "SS", "GBP", "GB£", "£"),
+    (En, "SS", "JPY", "JP¥", "¥"),
+    (En, "SS", "SSP", "£", "£"),
+    (En, "SS", "USD", "US$", "$"),
+    (En, "TK", "JPY", "JP¥", "¥"),
+    (En, "TK", "NZD", "$", "$"),
+    (En, "TK", "USD", "US$", "$"),
+    (En, "TO", "JPY", "JP¥", "¥"),
+    (En, "TO", "TOP", "T$", "T$"),
+    (En, "TO", "USD", "US$", "$"),
+    (En, "TV", "AUD", "$", "$"),
+    (En, "TV", "JPY", "JP¥", "¥"),
+    (En, "TV", "USD", "US$", "$"),
+    (En, "TZ", "JPY", "JP¥", "¥"),
+    (En, "TZ", "TZS", "TSh", "TSh"),
+    (En, "TZ", "USD", "US$", "$"),
+    (En, "UA", "JPY", "JP¥", "¥"),
+    (En, "UA", "USD", "US$", "$"),
+    (En, "UG", "JPY", "JP¥", "¥"),
+    (En, "UG", "UGX", "USh", "USh"),
+    (En, "UG", "USD", "US$", "$"),
+    (En, "VU", "JPY", "JP¥", "¥"),
+    (En, "VU", "USD", "US$", "$"),
+    (En, "VU", "VUV", "VT", "VT"),
+    (En, "WS", "JPY", "JP¥", "¥"),
+    (En, "WS", "USD", "US$", "$"),
+    (En, "WS", "WST", "WS$", "WS$"),
+    (En, "ZA", "JPY", "JP¥", "¥"),
+    (En, "ZA", "USD", "US$", "$"),
+    (En, "ZA", "ZAR", "R", "R"),
+    (En, "ZW", "JPY", "JP¥", "¥"),
+    (En, "ZW", "USD", "US$", "$"),
+    (Fo, "DK", "DKK", "kr.", "kr"),
+    (Fr, "BI", "BIF", "FBu", "FBu"),
+    (Fr, "CA", "AUD", "$\u{A0}AU", "$"),
+    (Fr, "CA", "BYN", "BYN", "Br"),
+    (Fr, "CA", "CAD", "$", "$"),
+    (Fr, "CA", "GBP", "£", "£"),
+    (Fr, "CA", "HKD", "$\u{A0}HK", "$"),
+    (Fr, "CA", "JPY", "¥", "¥"),
+    (Fr, "CA", "KMF", "KMF", "CF"),
+    (Fr, "CA", "NIO", "NIO", "C$"),
+    (Fr, "CA", "NZD", "$\u{A0}NZ", "$"),
+    (Fr, "CA", "SGD", "$\u{A0}SG", "$"),
+    (Fr, "CA", "USD", "$\u{A0}US", "$"),
+    (Fr, "CA", "ZMW", "ZMW", "ZK"),
+    (Fr, "CD", "CDF", "FC", "FC"),
+    (Fr, "DJ", "DJF", "Fdj", "Fdj"),
+    (Fr, "DZ", "DZD", "DA", "DA"),
+    (Fr, "GN", "GNF", "FG", "FG"),
+    (Fr, "HT", "HTG", "G", "G"),
+    (Fr, "KM", "KMF", "CF", "FC"),
+    (Fr, "LU", "LUF", "F", "F"),
+    (Fr, "MG", "MGA", "Ar", "Ar"),
+    (Fr, "MR", "MRU", "UM", "UM"),
+    (Fr, "MU", "MUR", "Rs", "Rs"),
+    (Fr, "RW", "RWF", "RF", "FR"),
+    (Fr, "SC", "SCR", "SR", "SR"),
+    (Fr, "SY", "SYP", "LS", "£"),
+    (Fr, "TN", "TND", "DT", "DT"),
+    (Fr, "VU", "VUV", "VT", "VT"),
+    (De, "LU", "LUF", "F", "F"),
+    (Ha, "GH", "GHS", "GH₵", "GH₵"),
+    (Ms, "BN", "BND", "$", "$"),
+    (Ms, "ID", "IDR", "Rp", "Rp"),
+    (Om, "KE", "KES", "Ksh", "Ksh"),
+    (Ps, "PK", "PKR", "Rs", "Rs"),
+    (Pt, "AO", "AOA", "Kz", "Kz"),
+    (Pt, "AO", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "AO", "SYP", "SYP", "£"),
+    (Pt, "CH", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "CH", "SYP", "SYP", "£"),
+    (Pt, "CV", "CVE", "\u{200B}", "\u{200B}"),
+    (Pt, "CV", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "CV", "SYP", "SYP", "£"),
+    (Pt, "FR", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "FR", "SYP", "SYP", "£"),
+    (Pt, "GQ", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "GQ", "SYP", "SYP", "£"),
+    (Pt, "GW", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "GW", "SYP", "SYP", "£"),
+    (Pt, "LU", "LUF", "F", "F"),
+    (Pt, "LU", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "LU", "SYP", "SYP", "£"),
+    (Pt, "MO", "MOP", "MOP$", "MOP$"),
+    (Pt, "MO", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "MO", "SYP", "SYP", "£"),
+    (Pt, "MZ", "MZN", "MTn", "MTn"),
+    (Pt, "MZ", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "MZ", "SYP", "SYP", "£"),
+    (Pt, "PT", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "PT", "SYP", "SYP", "£"),
+    (Pt, "ST", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "ST", "STN", "Db", "Db"),
+    (Pt, "ST", "SYP", "SYP", "£"),
+    (Pt, "TL", "PTE", "\u{200B}", "\u{200B}"),
+    (Pt, "TL", "SYP", "SYP", "£"),
+    (Qu, "BO", "BOB", "Bs", "Bs"),
+    (Qu, "EC", "USD", "$", "$"),
+    (Ro, "MD", "MDL", "L", "L"),
+    (Ru, "BY", "BYN", "Br", "Br"),
+    (Ru, "KG", "KGS", "сом", "⃀"),
+    (Ru, "KZ", "KZT", "₸", "₸"),
+    (Ru, "MD", "MDL", "L", "L"),
+    (Sd, "IN", "JPY", "¥", "¥"),
+    (Sd, "IN", "USD", "$", "$"),
+    (Sr, "ME", "BAM", "KM", "KM"),
+    (Sr, "ME", "BYN", "BYN", "r."),
+    (Sr, "RO", "BAM", "KM", "KM"),
+    (Sr, "RO", "BYN", "BYN", "r."),
+    (Sr, "TR", "BAM", "KM", "KM"),
+    (Sr, "TR", "BYN", "BYN", "r."),
+    (So, "DJ", "DJF", "Fdj", "Fdj"),
+    (So, "ET", "ETB", "Br", "Br"),
+    (So, "KE", "KES", "Ksh", "Ksh"),
+    (St, "LS", "LSL", "M", "M"),
+    (Es, "419", "FKP", "FKP", "FK£"),
+    (Es, "419", "SSP", "SSP", "SD£"),
+    (Es, "419", "SYP", "SYP", "S£"),
+    (Es, "419", "VEF", "VEF", "BsF"),
+    (Es, "AR", "ARS", "$", "$"),
+    (Es, "AR", "FKP", "FKP", "FK£"),
+    (Es, "AR", "SSP", "SSP", "SD£"),
+    (Es, "AR", "SYP", "SYP", "S£"),
+    (Es, "AR", "VEF", "VEF", "BsF"),
+    (Es, "BO", "BOB", "Bs", "Bs"),
+    (Es, "BO", "FKP", "FKP", "FK£"),
+    (Es, "BO", "SSP", "SSP", "SD£"),
+    (Es, "BO", "SYP", "SYP", "S£"),
+    (Es, "BO", "VEF", "VEF", "BsF"),
+    (Es, "BR", "FKP", "FKP", "FK£"),
+    (Es, "BR", "SSP", "SSP", "SD£"),
+    (Es, "BR", "SYP", "SYP", "S£"),
+    (Es, "BR", "VEF", "VEF", "BsF"),
+    (Es, "CL", "CLP", "$", "$"),
+    (Es, "CL", "FKP", "FKP", "FK£"),
+    (Es, "CL", "SSP", "SSP", "SD£"),
+    (Es, "CL", "SYP", "SYP", "S£"),
+    (Es, "CL", "VEF", "VEF", "BsF"),
+    (Es, "CO", "COP", "$", "$"),
+    (Es, "CO", "FKP", "FKP", "FK£"),
+    (Es, "CO", "SSP", "SSP", "SD£"),
+    (Es, "CO", "SYP", "SYP", "S£"),
+    (Es, "CO", "VEF", "VEF", "BsF"),
+    (Es, "CR", "CRC", "₡", "₡"),
+    (Es, "CR", "FKP", "FKP", "FK£"),
+    (Es, "CR", "SSP", "SSP", "SD£"),
+    (Es, "CR", "SYP", "SYP", "S£"),
+    (Es, "CR", "VEF", "VEF", "BsF"),
+    (Es, "CU", "CUP", "$", "$"),
+    (Es, "CU", "FKP", "FKP", "FK£"),
+    (Es, "CU", "SSP", "SSP", "SD£"),
+    (Es, "CU", "SYP", "SYP", "S£"),
+    (Es, "CU", "VEF", "VEF", "BsF"),
+    (Es, "DO", "DOP", "RD$", "$"),
+    (Es, "DO", "FKP", "FKP", "FK£"),
+    (Es, "DO", "SSP", "SSP", "SD£"),
+    (Es, "DO", "SYP", "SYP", "S£"),
+    (Es, "DO", "VEF", "VEF", "BsF"),
+    (Es, "EC", "FKP", "FKP", "FK£"),
+    (Es, "EC", "SSP", "SSP", "SD£"),
+    (Es, "EC", "SYP", "SYP", "S£"),
+    (Es, "EC", "USD", "$", "$"),
+    (Es, "EC", "VEF", "VEF", "BsF"),
+    (Es, "GT", "FKP", "FKP", "FK£"),
+    (Es, "GT", "GTQ", "Q", "Q"),
+    (Es, "GT", "SSP", "SSP", "SD£"),
+    (Es, "GT", "SYP", "SYP", "S£"),
+    (Es, "GT", "VEF", "VEF", "BsF"),
+    (Es, "HN", "FKP", "FKP", "FK£"),
+    (Es, "HN", "HNL", "L", "L"),
+    (Es, "HN", "SSP", "SSP", "SD£"),
+    (Es, "HN", "SYP", "SYP", "S£"),
+    (Es, "HN", "VEF", "VEF", "BsF"),
+    (Es, "JP", "FKP", "FKP", "FK£"),
+    (Es, "JP", "SSP", "SSP", "SD£"),
+    (Es, "JP", "SYP", "SYP", "S£"),
+    (Es, "JP", "VEF", "VEF", "BsF"),
+    (Es, "MX", "BYN", "BYN", "p."),
+    (Es, "MX", "MRO", "MRU", "MRU"),
+    (Es, "MX", "MRU", "UM", "UM"),
+    (Es, "MX", "MXN", "$", "$"),
+    (Es, "MX", "RON", "RON", "lei"),
+    (Es, "NI", "FKP", "FKP", "FK£"),
+    (Es, "NI", "NIO", "C$", "C$"),
+    (Es, "NI", "SSP", "SSP", "SD£"),
+    (Es, "NI", "SYP", "SYP", "S£"),
+    (Es, "NI", "VEF", "VEF", "BsF"),
+    (Es, "PA", "FKP", "FKP", "FK£"),
+    (Es, "PA", "PAB", "B/.", "B/."),
+    (Es, "PA", "SSP", "SSP", "SD£"),
+    (Es, "PA", "SYP", "SYP", "S£"),
+    (Es, "PA", "VEF", "VEF", "BsF"),
+    (Es, "PE", "FKP", "FKP", "FK£"),
+    (Es, "PE", "PEN", "S/", "S/"),
+    (Es, "PE", "SSP", "SSP", "SD£"),
+    (Es, "PE", "SYP", "SYP", "S£"),
+    (Es, "PE", "VEF", "VEF", "BsF"),
+    (Es, "PR", "FKP", "FKP", "FK£"),
+    (Es, "PR", "SSP", "SSP", "SD£"),
+    (Es, "PR", "SYP", "SYP", "S£"),
+    (Es, "PR", "USD", "$", "$"),
+    (Es, "PR", "VEF", "VEF", "BsF"),
+    (Es, "PY", "FKP", "FKP", "FK£"),
+    (Es, "PY", "PYG", "Gs.", "₲"),
+    (Es, "PY", "SSP", "SSP", "SD£"),
+    (Es, "PY", "SYP", "SYP", "S£"),
+    (Es, "PY", "VEF", "VEF", "BsF"),
+    (Es, "SV", "FKP", "FKP", "FK£"),
+    (Es, "SV", "SSP", "SSP", "SD£"),
+    (Es, "SV", "SYP", "SYP", "S£"),
+    (Es, "SV", "USD", "$", "$"),
+    (Es, "SV", "VEF", "VEF", "BsF"),
+    (Es, "US", "JPY", "¥", "¥"),
+    (Es, "US", "RON", "RON", "lei"),
+    (Es, "US", "USD", "$", "$"),
+    (Es, "UY", "FKP", "FKP", "FK£"),
+    (Es, "UY", "SSP", "SSP", "SD£"),
+    (Es, "UY", "SYP", "SYP", "S£"),
+    (Es, "UY", "UYU", "$", "$"),
+    (Es, "UY", "UYW", "UP", "UP"),
+    (Es, "UY", "VEF", "VEF", "BsF"),
+    (Es, "VE", "FKP", "FKP", "FK£"),
+    (Es, "VE", "SSP", "SSP", "SD£"),
+    (Es, "VE", "SYP", "SYP", "S£"),
+    (Es, "VE", "VEF", "Bs.", "Bs."),
+    (Es, "VE", "VES", "Bs.S", "Bs.S"),
+    (Sw, "CD", "CDF", "FC", "FC"),
+    (Sw, "KE", "USD", "$", "$"),
+    (Sw, "UG", "UGX", "USh", "USh"),
+    (Ta, "LK", "LKR", "Rs.", "Rs"),
+    (Ta, "MY", "MYR", "RM", "RM"),
+    (Ta, "MY", "SGD", "S$", "$"),
+    (Ti, "ER", "ERN", "Nfk", "Nfk"),
+    (Uz, "AF", "UZS", "UZS", "UZS"),
+    (Uz, "CN", "THB", "฿", "฿"),
+    (Uz, "CN", "UZS", "сўм", "сўм"),
+];
+
+/// The fraction digits of the currencies without fraction digits of their own.
+pub(super) const DEFAULT_FRACTION_DIGITS: usize = 2;
+
+/// The fraction digits of the other currencies, sorted by code.
+pub(super) static FRACTION_DIGITS: &[(&str, usize)] = &[
+    ("ADP", 0),
+    ("AFN", 0),
+    ("ALL", 0),
+    ("BHD", 3),
+    ("BIF", 0),
+    ("BYR", 0),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("COP", 0),
+    ("DJF", 0),
+    ("ESP", 0),
+    ("GNF", 0),
+    ("HUF", 0),
+    ("IDR", 0),
+    ("IQD", 0),
+    ("IRR", 0),
+    ("ISK", 0),
+    ("ITL", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KPW", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LAK", 0),
+    ("LBP", 0),
+    ("LUF", 0),
+    ("LYD", 3),
+    ("MGA", 0),
+    ("MGF", 0),
+    ("MMK", 0),
+    ("MRO", 0),
+    ("OMR", 3),
+    ("PKR", 0),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("SLL", 0),
+    ("SOS", 0),
+    ("STD", 0),
+    ("SYP", 0),
+    ("TMM", 0),
+    ("TND", 3),
+    ("TRL", 0),
+    ("UGX", 0),
+    ("UYI", 0),
+    ("UYW", 4),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+    ("YER", 0),
+    ("ZMK", 0),
+    ("ZWD", 0),
+];
@@ -0,0 +1,501 @@
+//! Locale-aware date and time formatting, with data from the Unicode CLDR.
+//!
+//! Dates and times are formatted with the patterns of a language, in one of four lengths,
+//! and so can some of the fields of a date, such as its month and day, with `format_fields`:
+//! ```rust
+//! # use rocket_lang::{*, dates::{format_date, format_time, Length}};
+//! let date = (2024, 3, 9);
+//! assert_eq!(format_date(En, &date, Length::Full), "Saturday, March 9, 2024");
+//! assert_eq!(format_date(Es, &date, Length::Long), "9 de marzo de 2024");
+//! assert_eq!(format_date(De, &date, Length::Medium), "09.03.2024");
+//! assert_eq!(format_date(Ja, &date, Length::Full), "2024年3月9日土曜日");
+//! assert_eq!(format_time(Fr, &(16, 5, 0), Length::Short), "16:05");
+//! ```
+//! Besides `(year, month, day)` and `(hour, minute, second)` tuples, values from `chrono`
+//! and `time` can be formatted with the features of the same name. Zoned values are
+//! formatted in their own offset, and time zone names are not written.
+//!
+//! The month and weekday names are available on their own, as is the first day of the week:
+//! ```rust
+//! # use rocket_lang::{*, dates::{first_day_of_week, month_name, Weekday, Width}};
+//! assert_eq!(month_name(Fr, 2, Width::Abbreviated), Some("févr."));
+//! assert_eq!(first_day_of_week(En, None), Weekday::Sunday);
+//! assert_eq!(first_day_of_week(En, Some("GB")), Weekday::Monday);
+//! ```
+//! Names are in the format context, as used inside dates. Only the Gregorian calendar is
+//! supported. The data of every language is generated from CLDR by `tools/cldr`.
+//! Languages without CLDR data of their own have no names, and are formatted with the
+//! patterns and placeholder names of the root locale, as in `2024 M03 9`.
+use crate::*;
+
+/// The length of a date or time pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Length {
+    /// `Saturday, March 9, 2024`
+    Full,
+    /// `March 9, 2024`
+    Long,
+    /// `Mar 9, 2024`
+    Medium,
+    /// `3/9/24`
+    Short,
+}
+
+/// The width of a month or weekday name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// `Saturday`
+    Wide,
+    /// `Sat`
+    Abbreviated,
+    /// `S`
+    Narrow,
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the weekday of a date in the proleptic Gregorian calendar.
+    /// ```rust
+    /// # use rocket_lang::dates::Weekday;
+    /// assert_eq!(Weekday::of(&(2000, 1, 1)), Weekday::Saturday);
+    /// ```
+    pub fn of(date: &impl CalendarDate) -> Weekday {
+        let (year, month, day) = date.ymd();
+        // Sakamoto's method, with 0 for Sunday.
+        const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = i64::from(year) - i64::from(month < 3);
+        let month = usize::from(month.clamp(1, 12) - 1);
+        let sunday_based = (year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[month]
+            + i64::from(day))
+        .rem_euclid(7);
+        Weekday::ALL[(sunday_based as usize + 6) % 7]
+    }
+}
+
+/// A value with a date in the Gregorian calendar.
+pub trait CalendarDate {
+    /// Returns the year, the month from 1 to 12, and the day from 1 to 31.
+    fn ymd(&self) -> (i32, u8, u8);
+}
+
+/// A value with a time of the day.
+pub trait ClockTime {
+    /// Returns the hour from 0 to 23, the minute, and the second.
+    fn hms(&self) -> (u8, u8, u8);
+}
+
+impl CalendarDate for (i32, u8, u8) {
+    fn ymd(&self) -> (i32, u8, u8) {
+        *self
+    }
+}
+
+impl ClockTime for (u8, u8, u8) {
+    fn hms(&self) -> (u8, u8, u8) {
+        *self
+    }
+}
+
+impl<T: CalendarDate + ?Sized> CalendarDate for &T {
+    fn ymd(&self) -> (i32, u8, u8) {
+        (**self).ymd()
+    }
+}
+
+impl<T: ClockTime + ?Sized> ClockTime for &T {
+    fn hms(&self) -> (u8, u8, u8) {
+        (**self).hms()
+    }
+}
+
+/// The fields of a date, which are formatted with the pattern the language has for them.
+/// ```rust
+/// # use rocket_lang::{*, dates::{format_fields, Fields, Length}};
+/// let date = (2024, 3, 9);
+/// assert_eq!(format_fields(En, &date, Fields::MonthDay, Length::Long), "March 9");
+/// assert_eq!(format_fields(De, &date, Fields::YearMonth, Length::Long), "März 2024");
+/// assert_eq!(format_fields(Ja, &date, Fields::DayWeekday, Length::Medium), "9日(土)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fields {
+    /// `2024`
+    Year,
+    /// `March 2024`
+    YearMonth,
+    /// `March 9, 2024`
+    YearMonthDay,
+    /// `Saturday, March 9, 2024`
+    YearMonthDayWeekday,
+    /// `March`
+    Month,
+    /// `March 9`
+    MonthDay,
+    /// `Saturday, March 9`
+    MonthDayWeekday,
+    /// `9`
+    Day,
+    /// `9 Saturday`
+    DayWeekday,
+    /// `Saturday`
+    Weekday,
+}
+
+/// The CLDR data of a language.
+struct Symbols {
+    /// The names in the format context, as in `9 марта`.
+    months: [[&'static str; 12]; 3],
+    /// The names in the standalone context, as in `март`.
+    standalone_months: [[&'static str; 12]; 3],
+    /// Starting on Monday.
+    weekdays: [[&'static str; 7]; 3],
+    standalone_weekdays: [[&'static str; 7]; 3],
+    am_pm: [&'static str; 2],
+    /// Before and after the common era.
+    eras: [&'static str; 2],
+    /// The date patterns, from full to short.
+    dates: [&'static str; 4],
+    /// The time patterns, from full to short.
+    times: [&'static str; 4],
+    /// The date and time patterns, from full to short.
+    date_times: [&'static str; 4],
+    /// The patterns of every `Fields`, from long to short.
+    fields: [[&'static str; 3]; 10],
+    first_day: Weekday,
+}
+
+#[rustfmt::skip]
+mod data;
+
+/// Returns the data of a language, or `None` if CLDR has none for it.
+fn data(lang: LangCode) -> Option<&'static Symbols> {
+    data::LANGUAGES[lang as usize]
+}
+
+/// Returns the data of a language, or the data of the root locale.
+fn symbols(lang: LangCode) -> &'static Symbols {
+    data(lang).unwrap_or(&data::ROOT)
+}
+
+fn width_index(width: Width) -> usize {
+    match width {
+        Width::Wide => 0,
+        Width::Abbreviated => 1,
+        Width::Narrow => 2,
+    }
+}
+
+fn length_index(length: Length) -> usize {
+    match length {
+        Length::Full => 0,
+        Length::Long => 1,
+        Length::Medium => 2,
+        Length::Short => 3,
+    }
+}
+
+/// Returns the name of a month, from 1 to 12. Returns `None` if the month is out of range,
+/// or if CLDR has no names for the language.
+/// ```rust
+/// # use rocket_lang::{*, dates::{month_name, Width}};
+/// assert_eq!(month_name(Ru, 3, Width::Wide), Some("марта"));
+/// assert_eq!(month_name(En, 13, Width::Wide), None);
+/// assert_eq!(month_name(Ay, 3, Width::Wide), None);
+/// ```
+pub fn month_name(lang: LangCode, month: u8, width: Width) -> Option<&'static str> {
+    let index = usize::from(month).checked_sub(1)?;
+    data(lang)?.months[width_index(width)]
+        .get(index)
+        .copied()
+}
+
+/// Returns the name of a weekday, or `None` if CLDR has no names for the language.
+/// ```rust
+/// # use rocket_lang::{*, dates::{weekday_name, Weekday, Width}};
+/// assert_eq!(weekday_name(Es, Weekday::Wednesday, Width::Narrow), Some("X"));
+/// ```
+pub fn weekday_name(lang: LangCode, weekday: Weekday, width: Width) -> Option<&'static str> {
+    Some(data(lang)?.weekdays[width_index(width)][weekday as usize])
+}
+
+/// Returns the CLDR date pattern of a language, such as `d MMM y`.
+pub fn date_pattern(lang: LangCode, length: Length) -> &'static str {
+    symbols(lang).dates[length_index(length)]
+}
+
+/// Returns the CLDR time pattern of a language, such as `HH:mm`.
+pub fn time_pattern(lang: LangCode, length: Length) -> &'static str {
+    symbols(lang).times[length_index(length)]
+}
+
+/// Returns the CLDR pattern of some fields of a date, such as `MMM d`.
+/// `Length::Full` is the same as `Length::Long`.
+pub fn fields_pattern(lang: LangCode, fields: Fields, length: Length) -> &'static str {
+    let index = length_index(length).max(1) - 1;
+    symbols(lang).fields[fields as usize][index]
+}
+
+/// Returns the first day of the week in a region. Without a region,
+/// the region where the language is most spoken is used.
+pub fn first_day_of_week(lang: LangCode, region: Option<&str>) -> Weekday {
+    let Some(region) = region else {
+        return symbols(lang).first_day;
+    };
+    let region = region.to_uppercase();
+    data::FIRST_DAYS
+        .iter()
+        .find(|(r, _)| *r == region)
+        .map_or(Weekday::Monday, |&(_, day)| day)
+}
+
+/// Formats a date.
+pub fn format_date(lang: LangCode, date: &impl CalendarDate, length: Length) -> String {
+    Formatter::new(lang, Some(date.ymd()), None).format(date_pattern(lang, length))
+}
+
+/// Formats some fields of a date.
+pub fn format_fields(
+    lang: LangCode,
+    date: &impl CalendarDate,
+    fields: Fields,
+    length: Length,
+) -> String {
+    Formatter::new(lang, Some(date.ymd()), None).format(fields_pattern(lang, fields, length))
+}
+
+/// Formats a time of the day.
+pub fn format_time(lang: LangCode, time: &impl ClockTime, length: Length) -> String {
+    Formatter::new(lang, None, Some(time.hms())).format(time_pattern(lang, length))
+}
+
+/// Formats a date and time, with the date pattern of the same length.
+/// ```rust
+/// # use rocket_lang::{*, dates::{format_date_time, Length}};
+/// # #[cfg(feature = "chrono")] {
+/// let value = chrono::NaiveDate::from_ymd_opt(2024, 3, 9)
+///     .unwrap()
+///     .and_hms_opt(16, 5, 0)
+///     .unwrap();
+/// assert_eq!(format_date_time(De, &value, Length::Long), "9. März 2024 um 16:05:00");
+/// # }
+/// ```
+pub fn format_date_time(
+    lang: LangCode,
+    value: &(impl CalendarDate + ClockTime),
+    length: Length,
+) -> String {
+    let pattern = symbols(lang).date_times[length_index(length)];
+    Formatter::new(lang, Some(value.ymd()), Some(value.hms())).format(pattern)
+}
+
+/// Writes the fields of a CLDR pattern.
+struct Formatter {
+    lang: LangCode,
+    date: Option<(i32, u8, u8)>,
+    time: Option<(u8, u8, u8)>,
+}
+
+impl Formatter {
+    fn new(lang: LangCode, date: Option<(i32, u8, u8)>, time: Option<(u8, u8, u8)>) -> Self {
+        Self { lang, date, time }
+    }
+
+    fn format(&self, pattern: &str) -> String {
+        let mut out = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    out.push('\'');
+                    continue;
+                }
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    out.push(c);
+                }
+            } else if c.is_ascii_alphabetic() {
+                let mut count = 1;
+                while chars.next_if_eq(&c).is_some() {
+                    count += 1;
+                }
+                match self.field(c, count) {
+                    Some(field) => out.push_str(&field),
+                    None => out.extend(std::iter::repeat_n(c, count)),
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        self.lang
+            .number_format()
+            .digits
+            .convert(&out)
+    }
+
+    /// Returns the text of a field, or `None` if it is not supported.
+    fn field(&self, letter: char, count: usize) -> Option<String> {
+        let symbols = symbols(self.lang);
+        let width = match count {
+            4 => width_index(Width::Wide),
+            5 => width_index(Width::Narrow),
+            _ => width_index(Width::Abbreviated),
+        };
+        let number = |value: u32, count: usize| format!("{value:0count$}");
+        Some(match letter {
+            'G' => {
+                let (year, _, _) = self.date?;
+                symbols.eras[usize::from(year > 0)].to_string()
+            }
+            'y' => {
+                let (year, _, _) = self.date?;
+                match count {
+                    2 => number(year.rem_euclid(100) as u32, 2),
+                    _ => format!("{year:0count$}"),
+                }
+            }
+            'M' | 'L' => {
+                let (_, month, _) = self.date?;
+                let names = match letter {
+                    'M' => &symbols.months,
+                    _ => &symbols.standalone_months,
+                };
+                match count {
+                    1 | 2 => number(month.into(), count),
+                    _ => names[width]
+                        .get(usize::from(month).checked_sub(1)?)?
+                        .to_string(),
+                }
+            }
+            'd' => number(self.date?.2.into(), count),
+            'E' | 'c' => {
+                let weekday = Weekday::of(&self.date?);
+                let names = match letter {
+                    'E' => &symbols.weekdays,
+                    _ => &symbols.standalone_weekdays,
+                };
+                names[width][weekday as usize].to_string()
+            }
+            'H' => number(self.time?.0.into(), count),
+            'h' => {
+                let hour = match self.time?.0 % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                number(hour.into(), count)
+            }
+            'm' => number(self.time?.1.into(), count),
+            's' => number(self.time?.2.into(), count),
+            'a' => symbols.am_pm[usize::from(self.time?.0 >= 12)].to_string(),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use super::*;
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+    impl CalendarDate for NaiveDate {
+        fn ymd(&self) -> (i32, u8, u8) {
+            (self.year(), self.month() as u8, self.day() as u8)
+        }
+    }
+
+    impl ClockTime for NaiveTime {
+        fn hms(&self) -> (u8, u8, u8) {
+            (self.hour() as u8, self.minute() as u8, self.second() as u8)
+        }
+    }
+
+    impl CalendarDate for NaiveDateTime {
+        fn ymd(&self) -> (i32, u8, u8) {
+            self.date().ymd()
+        }
+    }
+
+    impl ClockTime for NaiveDateTime {
+        fn hms(&self) -> (u8, u8, u8) {
+            self.time().hms()
+        }
+    }
+
+    impl<Tz: TimeZone> CalendarDate for chrono::DateTime<Tz> {
+        fn ymd(&self) -> (i32, u8, u8) {
+            self.naive_local().ymd()
+        }
+    }
+
+    impl<Tz: TimeZone> ClockTime for chrono::DateTime<Tz> {
+        fn hms(&self) -> (u8, u8, u8) {
+            self.naive_local().hms()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use super::*;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    impl CalendarDate for Date {
+        fn ymd(&self) -> (i32, u8, u8) {
+            (self.year(), self.month() as u8, self.day())
+        }
+    }
+
+    impl ClockTime for Time {
+        fn hms(&self) -> (u8, u8, u8) {
+            self.as_hms()
+        }
+    }
+
+    impl CalendarDate for PrimitiveDateTime {
+        fn ymd(&self) -> (i32, u8, u8) {
+            self.date().ymd()
+        }
+    }
+
+    impl ClockTime for PrimitiveDateTime {
+        fn hms(&self) -> (u8, u8, u8) {
+            self.time().hms()
+        }
+    }
+
+    impl CalendarDate for OffsetDateTime {
+        fn ymd(&self) -> (i32, u8, u8) {
+            self.date().ymd()
+        }
+    }
+
+    impl ClockTime for OffsetDateTime {
+        fn hms(&self) -> (u8, u8, u8) {
+            self.time().hms()
+        }
+    }
+}
@@ -0,0 +1,2068 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::{Symbols, Weekday};
+
+/// The data of the root locale, used by the languages without data of their own.
+pub(super) static ROOT: Symbols = Symbols {
+    months: [["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], ["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], ["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE HH:mm:ss", "y MMMM d HH:mm:ss", "y MMM d HH:mm:ss", "y-MM-dd HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// af
+static S0: Symbols = Symbols {
+    months: [["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], ["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], ["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Maandag", "Dinsdag", "Woensdag", "Donderdag", "Vrydag", "Saterdag", "Sondag"], ["Ma.", "Di.", "Wo.", "Do.", "Vr.", "Sa.", "So."], ["M", "D", "W", "D", "V", "S", "S"]],
+    standalone_weekdays: [["Maandag", "Dinsdag", "Woensdag", "Donderdag", "Vrydag", "Saterdag", "Sondag"], ["Ma.", "Di.", "Wo.", "Do.", "Vr.", "Sa.", "So."], ["M", "D", "W", "D", "V", "S", "S"]],
+    am_pm: ["vm.", "nm."],
+    eras: ["v.C.", "n.C."],
+    dates: ["EEEE dd MMMM y", "dd MMMM y", "dd MMM y", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE dd MMMM y 'om' HH:mm:ss", "dd MMMM y 'om' HH:mm:ss", "dd MMM y HH:mm:ss", "y-MM-dd HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["dd MMMM y", "dd MMM y", "y-MM-dd"], ["EEEE dd MMMM y", "E dd MMM y", "E y-MM-dd"], ["LLLL", "LLL", "L"], ["dd MMMM", "dd MMM", "dd-MM"], ["EEEE dd MMMM", "E dd MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ak
+static S1: Symbols = Symbols {
+    months: [["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], ["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], ["Ɔp", "Ɔg", "Ɔb", "O", "K", "A", "Ku", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], ["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], ["Ɔ", "Ɔ", "Ɔ", "O", "K", "A", "K", "Ɔ", "Ɛ", "A", "O", "Ɔ"]],
+    weekdays: [["Dwoada", "Benada", "Wukuada", "Yawoada", "Fiada", "Memeneda", "Sun"], ["Dwo", "Ben", "Wuk", "Yaw", "Fia", "Mem", "Kwa"], ["D", "B", "W", "Y", "F", "M", "K"]],
+    standalone_weekdays: [["Dwoada", "Benada", "Wukuada", "Yawoada", "Fiada", "Memeneda", "Sun"], ["Dwo", "Ben", "Wuk", "Yaw", "Fia", "Mem", "Kwa"], ["D", "B", "W", "Y", "F", "M", "K"]],
+    am_pm: ["AN", "ANW"],
+    eras: ["AK", "KE"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "yy/MM/dd"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, MMMM d, y 'wɔ' h:mm:ss a", "MMMM d, y 'wɔ' h:mm:ss a", "MMM d, y, h:mm:ss a", "yy/MM/dd, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["MMMM d, y", "MMM d, y", "yy/MM/dd"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, MM/dd/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM/dd"], ["EEEE, MMMM d", "E, MMM d", "E, MM/dd"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sq
+static S2: Symbols = Symbols {
+    months: [["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], ["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], ["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"]],
+    standalone_months: [["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], ["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], ["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"]],
+    weekdays: [["e hënë", "e martë", "e mërkurë", "e enjte", "e premte", "e shtunë", "e diel"], ["hën", "mar", "mër", "enj", "pre", "sht", "die"], ["h", "m", "m", "e", "p", "sh", "d"]],
+    standalone_weekdays: [["e hënë", "e martë", "e mërkurë", "e enjte", "e premte", "e shtunë", "e diel"], ["hën", "mar", "mër", "enj", "pre", "sht", "die"], ["h", "m", "m", "e", "p", "sh", "d"]],
+    am_pm: ["p.d.", "m.d."],
+    eras: ["p.K.", "mb.K."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d.M.yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, d MMMM y 'në' h:mm:ss\u{202F}a", "d MMMM y 'në' h:mm:ss\u{202F}a", "d MMM y, h:mm:ss\u{202F}a", "d.M.yy, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M.yy"], ["d MMMM y", "d MMM y", "d.M.yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d.M.yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d.M"], ["EEEE, d MMMM", "E, d MMM", "E, d.M"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// am
+static S3: Symbols = Symbols {
+    months: [["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], ["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], ["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"]],
+    standalone_months: [["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], ["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], ["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"]],
+    weekdays: [["ሰኞ", "ማክሰኞ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ", "እሑድ"], ["ሰኞ", "ማክሰ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ", "እሑድ"], ["ሰ", "ማ", "ረ", "ሐ", "ዓ", "ቅ", "እ"]],
+    standalone_weekdays: [["ሰኞ", "ማክሰኞ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ", "እሑድ"], ["ሰኞ", "ማክሰ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ", "እሑድ"], ["ሰ", "ማ", "ረ", "ሐ", "ዓ", "ቅ", "እ"]],
+    am_pm: ["ጥዋት", "ከሰዓት"],
+    eras: ["ዓ/ዓ", "ዓ/ም"],
+    dates: ["EEEE፣ MMMM d y", "MMMM d y", "MMM d y", "dd/MM/y"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE፣ MMMM d y h:mm:ss a", "MMMM d y h:mm:ss a", "MMM d y h:mm:ss a", "dd/MM/y h:mm a"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["MMMM d y", "MMM d y", "dd/MM/y"], ["EEEE፣ MMMM d y", "E፣ MMM d y", "E፣ dd/MM/y"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "dd/MM"], ["EEEE፣ MMMM d", "E፣ MMM d", "E፣ dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ar
+static S4: Symbols = Symbols {
+    months: [["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], ["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"]],
+    standalone_months: [["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], ["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"]],
+    weekdays: [["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"], ["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"], ["ن", "ث", "ر", "خ", "ج", "س", "ح"]],
+    standalone_weekdays: [["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"], ["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"], ["ن", "ث", "ر", "خ", "ج", "س", "ح"]],
+    am_pm: ["ص", "م"],
+    eras: ["ق.م", "م"],
+    dates: ["EEEE، d MMMM y", "d MMMM y", "dd\u{200F}/MM\u{200F}/y", "d\u{200F}/M\u{200F}/y"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE، d MMMM y في h:mm:ss a", "d MMMM y في h:mm:ss a", "dd\u{200F}/MM\u{200F}/y، h:mm:ss a", "d\u{200F}/M\u{200F}/y، h:mm a"],
+    fields: [["y", "y", "y"], ["MMMM y", "MM\u{200F}/y", "M\u{200F}/y"], ["d MMMM y", "dd\u{200F}/MM\u{200F}/y", "d\u{200F}/M\u{200F}/y"], ["EEEE، d MMMM y", "E، dd\u{200F}/MM\u{200F}/y", "E، d\u{200F}/M\u{200F}/y"], ["LLLL", "LLL", "L"], ["d MMMM", "dd\u{200F}/MM", "d\u{200F}/M"], ["EEEE، d MMMM", "E، dd\u{200F}/MM", "E، d\u{200F}/M"], ["d", "d", "d"], ["EEEE، d", "E، d", "E، d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Saturday,
+};
+
+// hy
+static S5: Symbols = Symbols {
+    months: [["հունվարի", "փետրվարի", "մարտի", "ապրիլի", "մայիսի", "հունիսի", "հուլիսի", "օգոստոսի", "սեպտեմբերի", "հոկտեմբերի", "նոյեմբերի", "դեկտեմբերի"], ["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], ["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"]],
+    standalone_months: [["հունվար", "փետրվար", "մարտ", "ապրիլ", "մայիս", "հունիս", "հուլիս", "օգոստոս", "սեպտեմբեր", "հոկտեմբեր", "նոյեմբեր", "դեկտեմբեր"], ["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], ["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"]],
+    weekdays: [["երկուշաբթի", "երեքշաբթի", "չորեքշաբթի", "հինգշաբթի", "ուրբաթ", "շաբաթ", "կիրակի"], ["երկ", "երք", "չրք", "հնգ", "ուր", "շբթ", "կիր"], ["Ե", "Ե", "Չ", "Հ", "Ո", "Շ", "Կ"]],
+    standalone_weekdays: [["երկուշաբթի", "երեքշաբթի", "չորեքշաբթի", "հինգշաբթի", "ուրբաթ", "շաբաթ", "կիրակի"], ["երկ", "երք", "չրք", "հնգ", "ուր", "շբթ", "կիր"], ["Ե", "Ե", "Չ", "Հ", "Ո", "Շ", "Կ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["մ.թ.ա.", "մ.թ."],
+    dates: ["y թ. MMMM dd, EEEE", "dd MMMM, y թ.", "dd MMM, y թ.", "dd.MM.yy"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["y թ. MMMM dd, EEEE, H:mm:ss", "dd MMMM, y թ., H:mm:ss", "dd MMM, y թ., H:mm:ss", "dd.MM.yy, H:mm"],
+    fields: [["y", "y", "yy"], ["y թ․ LLLL", "y թ. LLL", "MM.yy"], ["dd MMMM, y թ.", "dd MMM, y թ.", "dd.MM.yy"], ["y թ. MMMM dd, EEEE", "y թ. MMM dd, E", "dd.MM.yy, E"], ["LLLL", "LLL", "L"], ["MMMM dd", "dd MMM", "dd.MM"], ["MMMM dd, EEEE", "MMM dd, E", "dd.MM, E"], ["d", "d", "d"], ["d, cccc", "d, ccc", "d, ccc"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// as
+static S6: Symbols = Symbols {
+    months: [["জানুৱাৰী", "ফেব্ৰুৱাৰী", "মাৰ্চ", "এপ্ৰিল", "মে’", "জুন", "জুলাই", "আগষ্ট", "ছেপ্তেম্বৰ", "অক্টোবৰ", "নৱেম্বৰ", "ডিচেম্বৰ"], ["জানু", "ফেব্ৰু", "মাৰ্চ", "এপ্ৰিল", "মে’", "জুন", "জুলাই", "আগ", "ছেপ্তে", "অক্টো", "নৱে", "ডিচে"], ["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"]],
+    standalone_months: [["জানুৱাৰী", "ফেব্ৰুৱাৰী", "মাৰ্চ", "এপ্ৰিল", "মে’", "জুন", "জুলাই", "আগষ্ট", "ছেপ্তেম্বৰ", "অক্টোবৰ", "নৱেম্বৰ", "ডিচেম্বৰ"], ["জানু", "ফেব্ৰু", "মাৰ্চ", "এপ্ৰিল", "মে’", "জুন", "জুলাই", "আগ", "ছেপ্তে", "অক্টো", "নৱে", "ডিচে"], ["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"]],
+    weekdays: [["সোমবাৰ", "মঙ্গলবাৰ", "বুধবাৰ", "বৃহস্পতিবাৰ", "শুক্ৰবাৰ", "শনিবাৰ", "দেওবাৰ"], ["সোম", "মঙ্গল", "বুধ", "বৃহ", "শুক্ৰ", "শনি", "দেও"], ["স", "ম", "ব", "ব", "শ", "শ", "দ"]],
+    standalone_weekdays: [["সোমবাৰ", "মঙ্গলবাৰ", "বুধবাৰ", "বৃহস্পতিবাৰ", "শুক্ৰবাৰ", "শনিবাৰ", "দেওবাৰ"], ["সোম", "মঙ্গল", "বুধ", "বৃহ", "শুক্ৰ", "শনি", "দেও"], ["স", "ম", "ব", "ব", "শ", "শ", "দ"]],
+    am_pm: ["পূৰ্বাহ্ন", "অপৰাহ্ন"],
+    eras: ["খ্ৰীঃ পূঃ", "খ্ৰীঃ"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "dd-MM-y", "dd-MM-y"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM y 'at' h:mm:ss a", "d MMMM y 'at' h:mm:ss a", "dd-MM-y, h:mm:ss a", "dd-MM-y, h:mm a"],
+    fields: [["y", "y", "y"], ["MMMM y", "MM-y", "MM-y"], ["d MMMM y", "dd-MM-y", "dd-MM-y"], ["EEEE, d MMMM y", "E, dd-MM-y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "dd-MM", "dd-MM"], ["EEEE, d MMMM", "E, dd-MM", "E, dd-MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// az
+static S7: Symbols = Symbols {
+    months: [["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], ["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], ["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["bazar ertəsi", "çərşənbə axşamı", "çərşənbə", "cümə axşamı", "cümə", "şənbə", "bazar"], ["B.e.", "Ç.a.", "Ç.", "C.a.", "C.", "Ş.", "B."], ["1", "2", "3", "4", "5", "6", "7"]],
+    standalone_weekdays: [["bazar ertəsi", "çərşənbə axşamı", "çərşənbə", "cümə axşamı", "cümə", "şənbə", "bazar"], ["B.E.", "Ç.A.", "Ç.", "C.A.", "C.", "Ş.", "B."], ["1", "2", "3", "4", "5", "6", "7"]],
+    am_pm: ["AM", "PM"],
+    eras: ["e.ə.", "y.e."],
+    dates: ["d MMMM y, EEEE", "d MMMM y", "d MMM y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["d MMMM y, EEEE/HH:mm:ss", "d MMMM y/HH:mm:ss", "d MMM y, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM.yy"], ["d MMMM y", "d MMM y", "dd.MM.yy"], ["d MMMM y, EEEE", "d MMM y, E", "dd.MM.yy, E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["d MMMM, EEEE", "d MMM, E", "dd.MM, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ba
+static S8: Symbols = Symbols {
+    months: [["ғинуар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["ғин.", "фев.", "мар.", "апр.", "май", "июн.", "июл.", "авг.", "сент.", "окт.", "нояб.", "дек."], ["Ғ", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    standalone_months: [["ғинуар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["ғин.", "фев.", "мар.", "апр.", "май", "июн.", "июл.", "авг.", "сент.", "окт.", "нояб.", "дек."], ["Ғ", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    weekdays: [["дүшәмбе", "шишәмбе", "шаршамбы", "кесаҙна", "йома", "шәмбе", "йәкшәмбе"], ["дүш.", "шиш.", "шар.", "кес.", "йом.", "шәм.", "йәк."], ["Д", "Ш", "Ш", "К", "Й", "Ш", "Й"]],
+    standalone_weekdays: [["дүшәмбе", "шишәмбе", "шаршамбы", "кесаҙна", "йома", "шәмбе", "йәкшәмбе"], ["дүш.", "шиш.", "шар.", "кес.", "йом.", "шәм.", "йәк."], ["Д", "Ш", "Ш", "К", "Й", "Ш", "Й"]],
+    am_pm: ["AM", "PM"],
+    eras: ["б.э.т.", "б.э."],
+    dates: ["d MMMM, EEEE, y й.", "d MMMM, y й.", "d MMM, y й.", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["d MMMM, EEEE, y й., HH:mm:ss", "d MMMM, y й., HH:mm:ss", "d MMM, y й., HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM, y й.", "MMM, y й.", "y-MM"], ["d MMMM, y й.", "d MMM, y й.", "dd.MM.y"], ["d MMMM, EEEE, y й.", "d MMM, E, y й.", "dd.MM.y, E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["d MMMM, EEEE", "d MMM, E", "dd.MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// eu
+static S9: Symbols = Symbols {
+    months: [["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], ["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], ["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"]],
+    standalone_months: [["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], ["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], ["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"]],
+    weekdays: [["astelehena", "asteartea", "asteazkena", "osteguna", "ostirala", "larunbata", "igandea"], ["al.", "ar.", "az.", "og.", "or.", "lr.", "ig."], ["A", "A", "A", "O", "O", "L", "I"]],
+    standalone_weekdays: [["astelehena", "asteartea", "asteazkena", "osteguna", "ostirala", "larunbata", "igandea"], ["al.", "ar.", "az.", "og.", "or.", "lr.", "ig."], ["A", "A", "A", "O", "O", "L", "I"]],
+    am_pm: ["AM", "PM"],
+    eras: ["K.a.", "K.o."],
+    dates: ["y'(e)ko' MMMM'ren' d'(a),' EEEE", "y'(e)ko' MMMM'ren' d'(a)'", "y MMM d'(a)'", "yy/M/d"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y'(e)ko' MMMM'ren' d'(a),' EEEE (HH:mm:ss)", "y'(e)ko' MMMM'ren' d'(a) ('HH:mm:ss)", "y MMM d'(a) ('HH:mm:ss)", "yy/M/d (HH:mm)"],
+    fields: [["y", "y", "yy"], ["y'(e)ko' MMMM", "y MMM", "yy/M"], ["y'(e)ko' MMMM'ren' d'(a)'", "y MMM d'(a)'", "yy/M/d"], ["y'(e)ko' MMMM'ren' d'(a),' EEEE", "y MMM d'(a),' E", "yy/M/d, E"], ["LLLL", "LLL", "L"], ["MMMM'ren' d'(a)'", "MMM d'(a)'", "M/d"], ["MMMM d'(a),' EEEE", "MMM d'(a),' E", "M/d, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// be
+static S10: Symbols = Symbols {
+    months: [["студзеня", "лютага", "сакавіка", "красавіка", "мая", "чэрвеня", "ліпеня", "жніўня", "верасня", "кастрычніка", "лістапада", "снежня"], ["сту", "лют", "сак", "кра", "мая", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], ["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"]],
+    standalone_months: [["студзень", "люты", "сакавік", "красавік", "май", "чэрвень", "ліпень", "жнівень", "верасень", "кастрычнік", "лістапад", "снежань"], ["сту", "лют", "сак", "кра", "май", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], ["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"]],
+    weekdays: [["панядзелак", "аўторак", "серада", "чацвер", "пятніца", "субота", "нядзеля"], ["пн", "аў", "ср", "чц", "пт", "сб", "нд"], ["п", "а", "с", "ч", "п", "с", "н"]],
+    standalone_weekdays: [["панядзелак", "аўторак", "серада", "чацвер", "пятніца", "субота", "нядзеля"], ["пн", "аў", "ср", "чц", "пт", "сб", "нд"], ["п", "а", "с", "ч", "п", "с", "н"]],
+    am_pm: ["AM", "PM"],
+    eras: ["да н.э.", "н.э."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d.MM.y", "d.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y у HH:mm:ss", "d MMMM y у HH:mm:ss", "d.MM.y, HH:mm:ss", "d.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["LLLL y", "MM.y", "MM.yy"], ["d MMMM y", "d.MM.y", "d.MM.yy"], ["EEEE, d MMMM y", "E, d.MM.y", "E, d.MM.yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d.MM", "d.MM"], ["EEEE, d MMMM", "E, d.MM", "E, d.MM"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// bn
+static S11: Symbols = Symbols {
+    months: [["জানুয়ারি", "ফেব্রুয়ারি", "মার্চ", "এপ্রিল", "মে", "জুন", "জুলাই", "আগস্ট", "সেপ্টেম্বর", "অক্টোবর", "নভেম্বর", "ডিসেম্বর"], ["জানু", "ফেব", "মার্চ", "এপ্রি", "মে", "জুন", "জুল", "আগ", "সেপ", "অক্টো", "নভে", "ডিসে"], ["জা", "ফে", "মা", "এ", "মে", "জুন", "জু", "আ", "সে", "অ", "ন", "ডি"]],
+    standalone_months: [["জানুয়ারি", "ফেব্রুয়ারি", "মার্চ", "এপ্রিল", "মে", "জুন", "জুলাই", "আগস্ট", "সেপ্টেম্বর", "অক্টোবর", "নভেম্বর", "ডিসেম্বর"], ["জানু", "ফেব", "মার্চ", "এপ্রিল", "মে", "জুন", "জুলাই", "আগস্ট", "সেপ্ট", "অক্টো", "নভেম্বর", "ডিসে"], ["জা", "ফে", "মা", "এ", "মে", "জুন", "জু", "আ", "সে", "অ", "ন", "ডি"]],
+    weekdays: [["সোমবার", "মঙ্গলবার", "বুধবার", "বৃহস্পতিবার", "শুক্রবার", "শনিবার", "রবিবার"], ["সোম", "মঙ্গল", "বুধ", "বৃহস্পতি", "শুক্র", "শনি", "রবি"], ["সো", "ম", "বু", "বৃ", "শু", "শ", "র"]],
+    standalone_weekdays: [["সোমবার", "মঙ্গলবার", "বুধবার", "বৃহস্পতিবার", "শুক্রবার", "শনিবার", "রবিবার"], ["সোম", "মঙ্গল", "বুধ", "বৃহস্পতি", "শুক্র", "শনি", "রবি"], ["সো", "ম", "বু", "বৃ", "শু", "শ", "র"]],
+    am_pm: ["AM", "PM"],
+    eras: ["খ্রিস্টপূর্ব", "খৃষ্টাব্দ"],
+    dates: ["EEEE, d MMMM, y", "d MMMM, y", "d MMM, y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM, y এ h:mm:ss a", "d MMMM, y এ h:mm:ss a", "d MMM, y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "d/M/yy"], ["EEEE, d MMMM, y", "E, d MMM, y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE d MMMM", "E d MMM", "E, d-M"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// bs
+static S12: Symbols = Symbols {
+    months: [["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], ["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]],
+    standalone_months: [["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], ["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    weekdays: [["ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota", "nedjelja"], ["pon", "uto", "sri", "čet", "pet", "sub", "ned"], ["P", "U", "S", "Č", "P", "S", "N"]],
+    standalone_weekdays: [["ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota", "nedjelja"], ["pon", "uto", "sri", "čet", "pet", "sub", "ned"], ["p", "u", "s", "č", "p", "s", "n"]],
+    am_pm: ["a.\u{202F}m.", "p.\u{202F}m."],
+    eras: ["p. n. e.", "n. e."],
+    dates: ["EEEE, d. MMMM y.", "d. MMMM y.", "d. MMM y.", "d. M. y."],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y'. u' HH:mm:ss", "d. MMMM y'. u' HH:mm:ss", "d. MMM y'. u' HH:mm:ss", "d. M. y'. u' HH:mm"],
+    fields: [["y.", "y.", "y."], ["LLLL y.", "MMM y.", "MM/y"], ["d. MMMM y.", "d. MMM y.", "d. M. y."], ["EEEE, d. MMMM y.", "E, d. MMM y.", "E, d. M. y."], ["LLLL", "LLL", "L"], ["d. MMMM", "d. MMM", "d. M."], ["EEEE, d. MMMM", "E, d. MMM", "E, d. M."], ["d.", "d.", "d."], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// br
+static S13: Symbols = Symbols {
+    months: [["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], ["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], ["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"]],
+    standalone_months: [["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], ["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], ["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"]],
+    weekdays: [["Lun", "Meurzh", "Mercʼher", "Yaou", "Gwener", "Sadorn", "Sul"], ["Lun", "Meu.", "Mer.", "Yaou", "Gwe.", "Sad.", "Sul"], ["L", "Mz", "Mc", "Y", "G", "Sa", "Su"]],
+    standalone_weekdays: [["Lun", "Meurzh", "Mercʼher", "Yaou", "Gwener", "Sadorn", "Sul"], ["Lun", "Meu.", "Mer.", "Yaou", "Gwe.", "Sad.", "Sul"], ["L", "Mz", "Mc", "Y", "G", "Sa", "Su"]],
+    am_pm: ["A.M.", "G.M."],
+    eras: ["a-raok J.K.", "goude J.K."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'da' HH:mm:ss", "d MMMM y 'da' HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["EEEE d MMMM y", "E d MMM y", "E dd/MM/y"], ["LLLL", "LLL", "MM"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE d MMMM", "E d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// bg
+static S14: Symbols = Symbols {
+    months: [["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], ["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], ["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"]],
+    standalone_months: [["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], ["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], ["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"]],
+    weekdays: [["понеделник", "вторник", "сряда", "четвъртък", "петък", "събота", "неделя"], ["пн", "вт", "ср", "чт", "пт", "сб", "нд"], ["п", "в", "с", "ч", "п", "с", "н"]],
+    standalone_weekdays: [["понеделник", "вторник", "сряда", "четвъртък", "петък", "събота", "неделя"], ["пн", "вт", "ср", "чт", "пт", "сб", "нд"], ["п", "в", "с", "ч", "п", "с", "н"]],
+    am_pm: ["am", "pm"],
+    eras: ["пр.Хр.", "сл.Хр."],
+    dates: ["EEEE, d MMMM y\u{202F}г.", "d MMMM y\u{202F}г.", "d.MM.y\u{202F}г.", "d.MM.yy\u{202F}г."],
+    times: ["HH:mm:ss ч.", "HH:mm:ss ч.", "HH:mm:ss ч.", "HH:mm ч."],
+    date_times: ["EEEE, d MMMM y\u{202F}г. в HH:mm:ss ч.", "d MMMM y\u{202F}г. в HH:mm:ss ч.", "d.MM.y\u{202F}г., HH:mm:ss ч.", "d.MM.yy\u{202F}г., HH:mm ч."],
+    fields: [["y\u{202F}г.", "y\u{202F}г.", "yy\u{202F}г."], ["MMMM y\u{202F}г.", "MM.y\u{202F}г.", "MM.yy\u{202F}г."], ["d MMMM y\u{202F}г.", "d.MM.y\u{202F}г.", "d.MM.yy\u{202F}г."], ["EEEE, d MMMM y\u{202F}г.", "E, d.MM.y\u{202F}г.", "E, d.MM.yy\u{202F}г."], ["LLLL", "MM", "L"], ["d MMMM", "d.MM", "d.MM"], ["EEEE, d MMMM", "E, d.MM", "E, d.MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// my
+static S15: Symbols = Symbols {
+    months: [["ဇန်နဝါရီ", "ဖေဖော်ဝါရီ", "မတ်", "ဧပြီ", "မေ", "ဇွန်", "ဇူလိုင်", "ဩဂုတ်", "စက်တင်ဘာ", "အောက်တိုဘာ", "နိုဝင်ဘာ", "ဒီဇင်ဘာ"], ["ဇန်", "ဖေ", "မတ်", "ဧ", "မေ", "ဇွန်", "ဇူ", "ဩ", "စက်", "အောက်", "နို", "ဒီ"], ["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"]],
+    standalone_months: [["ဇန်နဝါရီ", "ဖေဖော်ဝါရီ", "မတ်", "ဧပြီ", "မေ", "ဇွန်", "ဇူလိုင်", "ဩဂုတ်", "စက်တင်ဘာ", "အောက်တိုဘာ", "နိုဝင်ဘာ", "ဒီဇင်ဘာ"], ["ဇန်", "ဖေ", "မတ်", "ဧ", "မေ", "ဇွန်", "ဇူ", "ဩ", "စက်", "အောက်", "နို", "ဒီ"], ["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"]],
+    weekdays: [["တနင်္လာ", "အင်္ဂါ", "ဗုဒ္ဓဟူး", "ကြာသပတေး", "သောကြာ", "စနေ", "တနင်္ဂနွေ"], ["တနင်္လာ", "အင်္ဂါ", "ဗုဒ္ဓဟူး", "ကြာသပတေး", "သောကြာ", "စနေ", "တနင်္ဂနွေ"], ["တ", "အ", "ဗ", "က", "သ", "စ", "တ"]],
+    standalone_weekdays: [["တနင်္လာ", "အင်္ဂါ", "ဗုဒ္ဓဟူး", "ကြာသပတေး", "သောကြာ", "စနေ", "တနင်္ဂနွေ"], ["တနင်္လာ", "အင်္ဂါ", "ဗုဒ္ဓဟူး", "ကြာသပတေး", "သောကြာ", "စနေ", "တနင်္ဂနွေ"], ["တ", "အ", "ဗ", "က", "သ", "စ", "တ"]],
+    am_pm: ["နံနက်", "ညနေ"],
+    eras: ["ဘီစီ", "အဒေီ"],
+    dates: ["y MMMM d EEEE", "y MMMM d", "y MMM d", "dd/MM/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d EEEE HH:mm:ss", "y MMMM d HH:mm:ss", "y MMM d HH:mm:ss", "dd/MM/yy HH:mm"],
+    fields: [["y", "y", "yy"], ["y MMMM", "y MMM", "yy-MM"], ["y MMMM d", "y MMM d", "dd/MM/yy"], ["y MMMM d EEEE", "y MMM d E", "dd/MM/yy E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "dd/MM"], ["MMMM d EEEE", "MMM d E", "dd/MM E"], ["d", "d", "d"], ["d ရက် EEEE", "d ရက် E", "d ရက် E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ca
+static S16: Symbols = Symbols {
+    months: [["de gener", "de febrer", "de març", "d’abril", "de maig", "de juny", "de juliol", "d’agost", "de setembre", "d’octubre", "de novembre", "de desembre"], ["de gen.", "de febr.", "de març", "d’abr.", "de maig", "de juny", "de jul.", "d’ag.", "de set.", "d’oct.", "de nov.", "de des."], ["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"]],
+    standalone_months: [["gener", "febrer", "març", "abril", "maig", "juny", "juliol", "agost", "setembre", "octubre", "novembre", "desembre"], ["gen.", "febr.", "març", "abr.", "maig", "juny", "jul.", "ag.", "set.", "oct.", "nov.", "des."], ["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"]],
+    weekdays: [["dilluns", "dimarts", "dimecres", "dijous", "divendres", "dissabte", "diumenge"], ["dl.", "dt.", "dc.", "dj.", "dv.", "ds.", "dg."], ["dl.", "dt.", "dc.", "dj.", "dv.", "ds.", "dg."]],
+    standalone_weekdays: [["dilluns", "dimarts", "dimecres", "dijous", "divendres", "dissabte", "diumenge"], ["dl.", "dt.", "dc.", "dj.", "dv.", "ds.", "dg."], ["dl.", "dt.", "dc.", "dj.", "dv.", "ds.", "dg."]],
+    am_pm: ["a.\u{202F}m.", "p.\u{202F}m."],
+    eras: ["aC", "dC"],
+    dates: ["EEEE, d MMMM 'del' y", "d MMMM 'del' y", "d MMM 'del' y", "d/M/yy"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE, d MMMM 'del' y', a les' H:mm:ss", "d MMMM 'del' y', a les' H:mm:ss", "d MMM 'del' y, H:mm:ss", "d/M/yy H:mm"],
+    fields: [["y", "y", "yy"], ["LLLL 'del' y", "LLL 'del' y", "M/yy"], ["d MMMM 'del' y", "d MMM 'del' y", "d/M/yy"], ["EEEE, d MMMM 'del' y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// zh
+static S17: Symbols = Symbols {
+    months: [["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"], ["周一", "周二", "周三", "周四", "周五", "周六", "周日"], ["一", "二", "三", "四", "五", "六", "日"]],
+    standalone_weekdays: [["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"], ["周一", "周二", "周三", "周四", "周五", "周六", "周日"], ["一", "二", "三", "四", "五", "六", "日"]],
+    am_pm: ["上午", "下午"],
+    eras: ["公元前", "公元"],
+    dates: ["y年M月d日EEEE", "y年M月d日", "y年M月d日", "y/M/d"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y年M月d日EEEE HH:mm:ss", "y年M月d日 HH:mm:ss", "y年M月d日 HH:mm:ss", "y/M/d HH:mm"],
+    fields: [["y年", "y年", "y年"], ["y年M月", "y年M月", "y/M"], ["y年M月d日", "y年M月d日", "y/M/d"], ["y年M月d日EEEE", "y年M月d日E", "y/M/dE"], ["LLLL", "LLL", "M月"], ["M月d日", "M月d日", "M/d"], ["M月d日EEEE", "M月d日E", "M/dE"], ["d日", "d日", "d日"], ["d日EEEE", "d日E", "d日E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// cv
+static S18: Symbols = Symbols {
+    months: [["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], ["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗрт.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], ["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"]],
+    standalone_months: [["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], ["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗрт.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], ["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"]],
+    weekdays: [["тунти кун", "ытлари кун", "юн кун", "кӗҫнерни кун", "эрне кун", "шӑмат кун", "вырсарни кун"], ["тн", "ыт", "юн", "кҫ", "эр", "шм", "вр"], ["Т", "Ы", "Ю", "К", "Э", "Ш", "В"]],
+    standalone_weekdays: [["тунти кун", "ытлари кун", "юн кун", "кӗҫнерни кун", "эрне кун", "шӑмат кун", "вырсарни кун"], ["тн", "ыт", "юн", "кҫ", "эр", "шм", "вр"], ["Т", "Ы", "Ю", "К", "Э", "Ш", "В"]],
+    am_pm: ["к.\u{202F}у.", "к.\u{202F}х."],
+    eras: ["х.\u{202F}с-ч.", "х.\u{202F}с."],
+    dates: ["y, MMMM, d, EEEE", "y, MMMM, d", "y, MMM, d", "y.MM.dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y, MMMM, d, EEEE, HH:mm:ss", "y, MMMM, d, HH:mm:ss", "y, MMM, d, HH:mm:ss", "y.MM.dd, HH:mm"],
+    fields: [["y", "y", "y"], ["y, MMMM", "y, MMM", "y.MM"], ["y, MMMM, d", "y, MMM, d", "y.MM.dd"], ["y, MMMM, d, EEEE", "y, MMM, d, E", "y.MM.dd (ccc)"], ["LLLL", "LLL", "L"], ["MMMM, d", "MMM, d", "MM.dd"], ["MMMM, d, EEEE", "MMM, d, E", "MM.dd (E)"], ["d", "d", "d"], ["d, cccc", "d, ccc", "d, ccc"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// hr
+static S19: Symbols = Symbols {
+    months: [["siječnja", "veljače", "ožujka", "travnja", "svibnja", "lipnja", "srpnja", "kolovoza", "rujna", "listopada", "studenoga", "prosinca"], ["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], ["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]],
+    standalone_months: [["siječanj", "veljača", "ožujak", "travanj", "svibanj", "lipanj", "srpanj", "kolovoz", "rujan", "listopad", "studeni", "prosinac"], ["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], ["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]],
+    weekdays: [["ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota", "nedjelja"], ["pon", "uto", "sri", "čet", "pet", "sub", "ned"], ["P", "U", "S", "Č", "P", "S", "N"]],
+    standalone_weekdays: [["ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota", "nedjelja"], ["pon", "uto", "sri", "čet", "pet", "sub", "ned"], ["p", "u", "s", "č", "p", "s", "n"]],
+    am_pm: ["AM", "PM"],
+    eras: ["pr. Kr.", "po. Kr."],
+    dates: ["EEEE, d. MMMM y.", "d. MMMM y.", "d. MMM y.", "dd. MM. y."],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y'. u' HH:mm:ss", "d. MMMM y'. u' HH:mm:ss", "d. MMM y. HH:mm:ss", "dd. MM. y. HH:mm"],
+    fields: [["y.", "y.", "y."], ["LLLL y.", "LLL y.", "MM. y."], ["d. MMMM y.", "d. MMM y.", "dd. MM. y."], ["EEEE, d. MMMM y.", "E, d. MMM y.", "E, dd. MM. y."], ["LLLL", "LLL", "L."], ["d. MMMM", "d. MMM", "dd. MM."], ["EEEE, d. MMMM", "E, d. MMM", "E, dd. MM."], ["d.", "d.", "d."], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// cs
+static S20: Symbols = Symbols {
+    months: [["ledna", "února", "března", "dubna", "května", "června", "července", "srpna", "září", "října", "listopadu", "prosince"], ["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["leden", "únor", "březen", "duben", "květen", "červen", "červenec", "srpen", "září", "říjen", "listopad", "prosinec"], ["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["pondělí", "úterý", "středa", "čtvrtek", "pátek", "sobota", "neděle"], ["po", "út", "st", "čt", "pá", "so", "ne"], ["P", "Ú", "S", "Č", "P", "S", "N"]],
+    standalone_weekdays: [["pondělí", "úterý", "středa", "čtvrtek", "pátek", "sobota", "neděle"], ["po", "út", "st", "čt", "pá", "so", "ne"], ["P", "Ú", "S", "Č", "P", "S", "N"]],
+    am_pm: ["dop.", "odp."],
+    eras: ["př. n. l.", "n. l."],
+    dates: ["EEEE d. MMMM y", "d. MMMM y", "d. M. y", "dd. MM. yy"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE d. MMMM y 'v' H:mm:ss", "d. MMMM y 'v' H:mm:ss", "d. M. y H:mm:ss", "dd. MM. yy H:mm"],
+    fields: [["y", "y", "yy"], ["LLLL y", "M/y", "MM/yy"], ["d. MMMM y", "d. M. y", "dd. MM. yy"], ["EEEE d. MMMM y", "E d. M. y", "E dd. MM. yy"], ["LLLL", "LLL", "L"], ["d. MMMM", "d. M.", "dd. MM."], ["EEEE d. MMMM", "E d. M.", "E dd. MM."], ["d.", "d.", "d."], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// da
+static S21: Symbols = Symbols {
+    months: [["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], ["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], ["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag"], ["man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."], ["M", "T", "O", "T", "F", "L", "S"]],
+    standalone_weekdays: [["mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag"], ["man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."], ["M", "T", "O", "T", "F", "L", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE d. MMMM y", "d. MMMM y", "d. MMM y", "dd.MM.y"],
+    times: ["HH.mm.ss", "HH.mm.ss", "HH.mm.ss", "HH.mm"],
+    date_times: ["EEEE d. MMMM y 'kl.' HH.mm.ss", "d. MMMM y 'kl.' HH.mm.ss", "d. MMM y, HH.mm.ss", "dd.MM.y, HH.mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d. MMMM y", "d. MMM y", "dd.MM.y"], ["EEEE d. MMMM y", "E d. MMM y", "E dd.MM.y"], ["MMMM", "MMM", "M"], ["d. MMMM", "d. MMM", "dd.MM"], ["EEEE d. MMMM", "E d. MMM", "E dd.MM"], ["d.", "d.", "d."], ["EEEE 'den' d.", "E 'den' d.", "E 'den' d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// nl
+static S22: Symbols = Symbols {
+    months: [["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], ["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], ["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"], ["ma", "di", "wo", "do", "vr", "za", "zo"], ["M", "D", "W", "D", "V", "Z", "Z"]],
+    standalone_weekdays: [["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"], ["ma", "di", "wo", "do", "vr", "za", "zo"], ["M", "D", "W", "D", "V", "Z", "Z"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["v.Chr.", "n.Chr."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'om' HH:mm:ss", "d MMMM y 'om' HH:mm:ss", "d MMM y, HH:mm:ss", "dd-MM-y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE d MMMM y", "E d MMM y", "E dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE d MMMM", "E d MMM", "E dd-MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// en
+static S23: Symbols = Symbols {
+    months: [["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"], ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"], ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, MMMM d, y 'at' h:mm:ss\u{202F}a", "MMMM d, y 'at' h:mm:ss\u{202F}a", "MMM d, y, h:mm:ss\u{202F}a", "M/d/yy, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["MMMM d, y", "MMM d, y", "M/d/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "M/d"], ["EEEE, MMMM d", "E, MMM d", "E, M/d"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// eo
+static S24: Symbols = Symbols {
+    months: [["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], ["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], ["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["lundo", "mardo", "merkredo", "ĵaŭdo", "vendredo", "sabato", "dimanĉo"], ["lu", "ma", "me", "ĵa", "ve", "sa", "di"], ["l", "m", "m", "ĵ", "v", "s", "d"]],
+    standalone_weekdays: [["lundo", "mardo", "merkredo", "ĵaŭdo", "vendredo", "sabato", "dimanĉo"], ["lu", "ma", "me", "ĵa", "ve", "sa", "di"], ["l", "m", "m", "ĵ", "v", "s", "d"]],
+    am_pm: ["atm", "ptm"],
+    eras: ["a.n.e.", "n.e."],
+    dates: ["EEEE, dd MMMM y", "dd MMMM y", "dd MMM y", "yy-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, dd MMMM y 'je' HH:mm:ss", "dd MMMM y 'je' HH:mm:ss", "dd MMM y, HH:mm:ss", "yy-MM-dd HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "yy-MM"], ["dd MMMM y", "dd MMM y", "yy-MM-dd"], ["EEEE, dd MMMM y", "E, dd MMM y", "E, yy-MM-dd"], ["MMMM", "MMM", "L"], ["dd MMMM", "dd MMM", "MM-dd"], ["EEEE, dd MMMM", "E, dd MMM", "E, MM-dd"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// et
+static S25: Symbols = Symbols {
+    months: [["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], ["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], ["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], ["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], ["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["esmaspäev", "teisipäev", "kolmapäev", "neljapäev", "reede", "laupäev", "pühapäev"], ["E", "T", "K", "N", "R", "L", "P"], ["E", "T", "K", "N", "R", "L", "P"]],
+    standalone_weekdays: [["esmaspäev", "teisipäev", "kolmapäev", "neljapäev", "reede", "laupäev", "pühapäev"], ["E", "T", "K", "N", "R", "L", "P"], ["E", "T", "K", "N", "R", "L", "P"]],
+    am_pm: ["AM", "PM"],
+    eras: ["eKr", "pKr"],
+    dates: ["EEEE, d. MMMM y", "d. MMMM y", "d. MMM y", "dd.MM.yy"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y', kell' H:mm:ss", "d. MMMM y', kell' H:mm:ss", "d. MMM y, H:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM.yy"], ["d. MMMM y", "d. MMM y", "dd.MM.yy"], ["EEEE, d. MMMM y", "E, d. MMMM y", "E, dd.MM.yy"], ["MMMM", "MMM", "M"], ["d. MMMM", "d. MMM", "dd.MM"], ["EEEE, d. MMMM", "E, d. MMM", "E, dd.MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ee
+static S26: Symbols = Symbols {
+    months: [["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], ["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], ["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"]],
+    standalone_months: [["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], ["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], ["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"]],
+    weekdays: [["dzoɖa", "blaɖa", "kuɖa", "yawoɖa", "fiɖa", "memleɖa", "kɔsiɖa"], ["dzo", "bla", "kuɖ", "yaw", "fiɖ", "mem", "kɔs"], ["d", "b", "k", "y", "f", "m", "k"]],
+    standalone_weekdays: [["dzoɖa", "blaɖa", "kuɖa", "yawoɖa", "fiɖa", "memleɖa", "kɔsiɖa"], ["dzo", "bla", "kuɖ", "yaw", "fiɖ", "mem", "kɔs"], ["d", "b", "k", "y", "f", "m", "k"]],
+    am_pm: ["ŋdi", "ɣetrɔ"],
+    eras: ["HYV", "Yŋ"],
+    dates: ["EEEE, MMMM d, y", "MMMM d 'lia,' y", "MMM d 'lia,' y", "M/d/yy"],
+    times: ["a 'ga' h:mm:ss", "a 'ga' h:mm:ss", "a 'ga' h:mm:ss", "a 'ga' h:mm"],
+    date_times: ["a 'ga' h:mm:ss EEEE, MMMM d, y", "a 'ga' h:mm:ss MMMM d 'lia,' y", "a 'ga' h:mm:ss MMM d 'lia,' y", "a 'ga' h:mm M/d/yy"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["MMMM d 'lia,' y", "MMM d 'lia,' y", "M/d/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d 'lia'", "MMM d 'lia'", "M/d"], ["EEEE, MMMM d 'lia'", "E, MMM d 'lia'", "E, M/d"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// fo
+static S27: Symbols = Symbols {
+    months: [["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "des."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["mánadagur", "týsdagur", "mikudagur", "hósdagur", "fríggjadagur", "leygardagur", "sunnudagur"], ["mán.", "týs.", "mik.", "hós.", "frí.", "ley.", "sun."], ["M", "T", "M", "H", "F", "L", "S"]],
+    standalone_weekdays: [["mánadagur", "týsdagur", "mikudagur", "hósdagur", "fríggjadagur", "leygardagur", "sunnudagur"], ["mán", "týs", "mik", "hós", "frí", "ley", "sun"], ["M", "T", "M", "H", "F", "L", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d. MMMM y 'kl.' HH:mm:ss", "d. MMMM y 'kl.' HH:mm:ss", "dd.MM.y, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MM.y", "MM.yy"], ["d. MMMM y", "dd.MM.y", "dd.MM.yy"], ["EEEE d. MMMM y", "E dd.MM.y", "E dd.MM.yy"], ["LLLL", "LLL", "LL"], ["d. MMMM", "dd.MM", "dd.MM"], ["EEEE d. MMMM", "E dd.MM", "E dd.MM"], ["d.", "d.", "d."], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// fi
+static S28: Symbols = Symbols {
+    months: [["tammikuuta", "helmikuuta", "maaliskuuta", "huhtikuuta", "toukokuuta", "kesäkuuta", "heinäkuuta", "elokuuta", "syyskuuta", "lokakuuta", "marraskuuta", "joulukuuta"], ["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], ["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"]],
+    standalone_months: [["tammikuu", "helmikuu", "maaliskuu", "huhtikuu", "toukokuu", "kesäkuu", "heinäkuu", "elokuu", "syyskuu", "lokakuu", "marraskuu", "joulukuu"], ["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], ["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"]],
+    weekdays: [["maanantaina", "tiistaina", "keskiviikkona", "torstaina", "perjantaina", "lauantaina", "sunnuntaina"], ["ma", "ti", "ke", "to", "pe", "la", "su"], ["M", "T", "K", "T", "P", "L", "S"]],
+    standalone_weekdays: [["maanantai", "tiistai", "keskiviikko", "torstai", "perjantai", "lauantai", "sunnuntai"], ["ma", "ti", "ke", "to", "pe", "la", "su"], ["M", "T", "K", "T", "P", "L", "S"]],
+    am_pm: ["ap.", "ip."],
+    eras: ["eKr.", "jKr."],
+    dates: ["cccc d. MMMM y", "d. MMMM y", "d.M.y", "d.M.y"],
+    times: ["H.mm.ss", "H.mm.ss", "H.mm.ss", "H.mm"],
+    date_times: ["cccc d. MMMM y 'klo' H.mm.ss", "d. MMMM y 'klo' H.mm.ss", "d.M.y 'klo' H.mm.ss", "d.M.y 'klo' H.mm"],
+    fields: [["y", "y", "y"], ["LLLL y", "L.y", "L.y"], ["d. MMMM y", "d.M.y", "d.M.y"], ["cccc d. MMMM y", "E d.M.y", "E d.M.y"], ["LLLL", "LLL", "L"], ["d. MMMM", "d.M.", "d.M."], ["cccc d. MMMM", "E d.M.", "E d.M."], ["d", "d", "d"], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// fr, ht
+static S29: Symbols = Symbols {
+    months: [["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"], ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."], ["L", "M", "M", "J", "V", "S", "D"]],
+    standalone_weekdays: [["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"], ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."], ["L", "M", "M", "J", "V", "S", "D"]],
+    am_pm: ["AM", "PM"],
+    eras: ["av. J.-C.", "ap. J.-C."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y à HH:mm:ss", "d MMMM y à HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/y HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["EEEE d MMMM y", "E d MMM y", "E dd/MM/y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE d MMMM", "E d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["EEEE", "E", "E"]],
+    first_day: Weekday::Monday,
+};
+
+// gl
+static S30: Symbols = Symbols {
+    months: [["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], ["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], ["x.", "f.", "m.", "a.", "m.", "x.", "x.", "a.", "s.", "o.", "n.", "d."]],
+    standalone_months: [["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], ["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], ["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "N", "D"]],
+    weekdays: [["luns", "martes", "mércores", "xoves", "venres", "sábado", "domingo"], ["luns", "mar.", "mér.", "xov.", "ven.", "sáb.", "dom."], ["l.", "m.", "m.", "x.", "v.", "s.", "d."]],
+    standalone_weekdays: [["luns", "martes", "mércores", "xoves", "venres", "sábado", "domingo"], ["luns", "mar.", "mér.", "xov.", "ven.", "sáb.", "dom."], ["L", "M", "M", "X", "V", "S", "D"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["a.C.", "d.C."],
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "d 'de' MMM 'de' y", "dd/MM/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d 'de' MMMM 'de' y, HH:mm:ss", "d 'de' MMMM 'de' y, HH:mm:ss", "d 'de' MMM 'de' y, HH:mm:ss", "dd/MM/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM 'de' y", "MMM 'de' y", "MM/yy"], ["d 'de' MMMM 'de' y", "d 'de' MMM 'de' y", "dd/MM/yy"], ["EEEE, d 'de' MMMM 'de' y", "E, d 'de' MMM 'de' y", "E, dd/MM/yy"], ["LLLL", "LLL", "L"], ["d 'de' MMMM", "d 'de' MMM", "dd/MM"], ["EEEE, d 'de' MMMM", "E, d 'de' MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ka
+static S31: Symbols = Symbols {
+    months: [["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], ["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], ["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"]],
+    standalone_months: [["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], ["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], ["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"]],
+    weekdays: [["ორშაბათი", "სამშაბათი", "ოთხშაბათი", "ხუთშაბათი", "პარასკევი", "შაბათი", "კვირა"], ["ორშ", "სამ", "ოთხ", "ხუთ", "პარ", "შაბ", "კვი"], ["ო", "ს", "ო", "ხ", "პ", "შ", "კ"]],
+    standalone_weekdays: [["ორშაბათი", "სამშაბათი", "ოთხშაბათი", "ხუთშაბათი", "პარასკევი", "შაბათი", "კვირა"], ["ორშ", "სამ", "ოთხ", "ხუთ", "პარ", "შაბ", "კვი"], ["ო", "ს", "ო", "ხ", "პ", "შ", "კ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ძვ. წ.", "ახ. წ."],
+    dates: ["EEEE, d MMMM. y", "d MMMM. y", "d MMM. y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM. y, HH:mm:ss", "d MMMM. y, HH:mm:ss", "d MMM. y, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM, y", "MMM. y", "MM.yy"], ["d MMMM. y", "d MMM. y", "dd.MM.yy"], ["EEEE, d MMMM. y", "E, d MMM. y", "E, dd.MM.yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd.MM"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// de
+static S32: Symbols = Symbols {
+    months: [["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"], ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."], ["M", "D", "M", "D", "F", "S", "S"]],
+    standalone_weekdays: [["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"], ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"], ["M", "D", "M", "D", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["v. Chr.", "n. Chr."],
+    dates: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y 'um' HH:mm:ss", "d. MMMM y 'um' HH:mm:ss", "dd.MM.y, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MM/y", "MM/yy"], ["d. MMMM y", "dd.MM.y", "dd.MM.yy"], ["EEEE, d. MMMM y", "E, dd.MM.y", "E, dd.MM.yy"], ["LLLL", "LLL", "L"], ["d. MMMM", "dd.MM.", "dd.MM."], ["EEEE, d. MMMM", "E, dd.MM.", "E, dd.MM."], ["d", "d", "d"], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// el
+static S33: Symbols = Symbols {
+    months: [["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"], ["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αυγ", "Σεπ", "Οκτ", "Νοε", "Δεκ"], ["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]],
+    standalone_months: [["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αύγουστος", "Σεπτέμβριος", "Οκτώβριος", "Νοέμβριος", "Δεκέμβριος"], ["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"], ["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]],
+    weekdays: [["Δευτέρα", "Τρίτη", "Τετάρτη", "Πέμπτη", "Παρασκευή", "Σάββατο", "Κυριακή"], ["Δευ", "Τρί", "Τετ", "Πέμ", "Παρ", "Σάβ", "Κυρ"], ["Δ", "Τ", "Τ", "Π", "Π", "Σ", "Κ"]],
+    standalone_weekdays: [["Δευτέρα", "Τρίτη", "Τετάρτη", "Πέμπτη", "Παρασκευή", "Σάββατο", "Κυριακή"], ["Δευ", "Τρί", "Τετ", "Πέμ", "Παρ", "Σάβ", "Κυρ"], ["Δ", "Τ", "Τ", "Π", "Π", "Σ", "Κ"]],
+    am_pm: ["π.μ.", "μ.μ."],
+    eras: ["π.Χ.", "μ.Χ."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE d MMMM y στις h:mm:ss\u{202F}a", "d MMMM y στις h:mm:ss\u{202F}a", "d MMM y, h:mm:ss\u{202F}a", "d/M/yy, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["LLLL y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE d MMMM y", "E d MMM y", "E d/M/yy"], ["MMMM", "MMM", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE d MMMM", "E d MMM", "E d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// gu
+static S34: Symbols = Symbols {
+    months: [["જાન્યુઆરી", "ફેબ્રુઆરી", "માર્ચ", "એપ્રિલ", "મે", "જૂન", "જુલાઈ", "ઑગસ્ટ", "સપ્ટેમ્બર", "ઑક્ટોબર", "નવેમ્બર", "ડિસેમ્બર"], ["જાન્યુ", "ફેબ્રુ", "માર્ચ", "એપ્રિલ", "મે", "જૂન", "જુલાઈ", "ઑગસ્ટ", "સપ્ટે", "ઑક્ટો", "નવે", "ડિસે"], ["જા", "ફે", "મા", "એ", "મે", "જૂ", "જુ", "ઑ", "સ", "ઑ", "ન", "ડિ"]],
+    standalone_months: [["જાન્યુઆરી", "ફેબ્રુઆરી", "માર્ચ", "એપ્રિલ", "મે", "જૂન", "જુલાઈ", "ઑગસ્ટ", "સપ્ટેમ્બર", "ઑક્ટોબર", "નવેમ્બર", "ડિસેમ્બર"], ["જાન્યુ", "ફેબ્રુ", "માર્ચ", "એપ્રિલ", "મે", "જૂન", "જુલાઈ", "ઑગસ્ટ", "સપ્ટે", "ઑક્ટો", "નવે", "ડિસે"], ["જા", "ફે", "મા", "એ", "મે", "જૂ", "જુ", "ઑ", "સ", "ઑ", "ન", "ડિ"]],
+    weekdays: [["સોમવાર", "મંગળવાર", "બુધવાર", "ગુરુવાર", "શુક્રવાર", "શનિવાર", "રવિવાર"], ["સોમ", "મંગળ", "બુધ", "ગુરુ", "શુક્ર", "શનિ", "રવિ"], ["સો", "મં", "બુ", "ગુ", "શુ", "શ", "ર"]],
+    standalone_weekdays: [["સોમવાર", "મંગળવાર", "બુધવાર", "ગુરુવાર", "શુક્રવાર", "શનિવાર", "રવિવાર"], ["સોમ", "મંગળ", "બુધ", "ગુરુ", "શુક્ર", "શનિ", "રવિ"], ["સો", "મં", "બુ", "ગુ", "શુ", "શ", "ર"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ઈ.સ.પૂર્વે", "ઈ.સ."],
+    dates: ["EEEE, d MMMM, y", "d MMMM, y", "d MMM, y", "d/M/yy"],
+    times: ["hh:mm:ss a", "hh:mm:ss a", "hh:mm:ss a", "hh:mm a"],
+    date_times: ["EEEE, d MMMM, y hh:mm:ss a", "d MMMM, y hh:mm:ss a", "d MMM, y hh:mm:ss a", "d/M/yy hh:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "d/M/yy"], ["EEEE, d MMMM, y", "E, d MMM, y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ha
+static S35: Symbols = Symbols {
+    months: [["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], ["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], ["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], ["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], ["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"]],
+    weekdays: [["Litinin", "Talata", "Laraba", "Alhamis", "Jummaʼa", "Asabar", "Lahadi"], ["Lit", "Tal", "Lar", "Alh", "Jum", "Asa", "Lah"], ["L", "T", "L", "A", "J", "A", "L"]],
+    standalone_weekdays: [["Litinin", "Talata", "Laraba", "Alhamis", "Jummaʼa", "Asabar", "Lahadi"], ["Lit", "Tal", "Lar", "Alh", "Jum", "Asa", "Lah"], ["L", "T", "L", "A", "J", "A", "L"]],
+    am_pm: ["SF", "YM"],
+    eras: ["K.H", "BHAI"],
+    dates: ["y MMMM d, EEEE", "d MMMM, y", "d MMM, y", "yy-M-d"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE 'da' HH:mm:ss", "d MMMM, y 'da' HH:mm:ss", "d MMM, y, HH:mm:ss", "yy-M-d, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "yy-M-d"], ["y MMMM d, EEEE", "y MMM d, E", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "M/d"], ["EEEE, MMMM d", "E, MMM d", "E, M/d"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// he
+static S36: Symbols = Symbols {
+    months: [["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], ["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], ["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["יום שני", "יום שלישי", "יום רביעי", "יום חמישי", "יום שישי", "יום שבת", "יום ראשון"], ["יום ב׳", "יום ג׳", "יום ד׳", "יום ה׳", "יום ו׳", "שבת", "יום א׳"], ["ב׳", "ג׳", "ד׳", "ה׳", "ו׳", "ש׳", "א׳"]],
+    standalone_weekdays: [["יום שני", "יום שלישי", "יום רביעי", "יום חמישי", "יום שישי", "יום שבת", "יום ראשון"], ["יום ב׳", "יום ג׳", "יום ד׳", "יום ה׳", "יום ו׳", "שבת", "יום א׳"], ["ב׳", "ג׳", "ד׳", "ה׳", "ו׳", "ש׳", "א׳"]],
+    am_pm: ["AM", "PM"],
+    eras: ["לפנה״ס", "לספירה"],
+    dates: ["EEEE, d בMMMM y", "d בMMMM y", "d בMMM y", "d.M.y"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE, d בMMMM y בשעה H:mm:ss", "d בMMMM y בשעה H:mm:ss", "d בMMM y, H:mm:ss", "d.M.y, H:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "M.y"], ["d בMMMM y", "d בMMM y", "d.M.y"], ["EEEE, d בMMMM y", "E, d בMMM y", "E, d.M.y"], ["LLLL", "LLL", "L"], ["d בMMMM", "d בMMM", "d.M"], ["EEEE, d בMMMM", "E, d בMMM", "E, d.M"], ["d", "d", "d"], ["EEEE ה-d", "E ה-d", "E ה-d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// hi
+static S37: Symbols = Symbols {
+    months: [["जनवरी", "फ़रवरी", "मार्च", "अप्रैल", "मई", "जून", "जुलाई", "अगस्त", "सितंबर", "अक्टूबर", "नवंबर", "दिसंबर"], ["जन॰", "फ़र॰", "मार्च", "अप्रैल", "मई", "जून", "जुल॰", "अग॰", "सित॰", "अक्टू॰", "नव॰", "दिस॰"], ["ज", "फ़", "मा", "अ", "म", "जू", "जु", "अ", "सि", "अ", "न", "दि"]],
+    standalone_months: [["जनवरी", "फ़रवरी", "मार्च", "अप्रैल", "मई", "जून", "जुलाई", "अगस्त", "सितंबर", "अक्टूबर", "नवंबर", "दिसंबर"], ["जन॰", "फ़र॰", "मार्च", "अप्रैल", "मई", "जून", "जुल॰", "अग॰", "सित॰", "अक्टू॰", "नव॰", "दिस॰"], ["ज", "फ़", "मा", "अ", "म", "जू", "जु", "अ", "सि", "अ", "न", "दि"]],
+    weekdays: [["सोमवार", "मंगलवार", "बुधवार", "गुरुवार", "शुक्रवार", "शनिवार", "रविवार"], ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    standalone_weekdays: [["सोमवार", "मंगलवार", "बुधवार", "गुरुवार", "शुक्रवार", "शनिवार", "रविवार"], ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    am_pm: ["am", "pm"],
+    eras: ["ईसा-पूर्व", "ईस्वी"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM y को h:mm:ss a बजे", "d MMMM y को h:mm:ss a बजे", "d MMM y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// hu
+static S38: Symbols = Symbols {
+    months: [["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], ["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], ["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"]],
+    standalone_months: [["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], ["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], ["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"]],
+    weekdays: [["hétfő", "kedd", "szerda", "csütörtök", "péntek", "szombat", "vasárnap"], ["H", "K", "Sze", "Cs", "P", "Szo", "V"], ["H", "K", "Sz", "Cs", "P", "Sz", "V"]],
+    standalone_weekdays: [["hétfő", "kedd", "szerda", "csütörtök", "péntek", "szombat", "vasárnap"], ["H", "K", "Sze", "Cs", "P", "Szo", "V"], ["H", "K", "Sz", "Cs", "P", "Sz", "V"]],
+    am_pm: ["de.", "du."],
+    eras: ["i. e.", "i. sz."],
+    dates: ["y. MMMM d., EEEE", "y. MMMM d.", "y. MMM d.", "y. MM. dd."],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["y. MMMM d., EEEE H:mm:ss", "y. MMMM d. H:mm:ss", "y. MMM d. H:mm:ss", "y. MM. dd. H:mm"],
+    fields: [["y.", "y.", "y."], ["y. MMMM", "y. MMM", "y. MM."], ["y. MMMM d.", "y. MMM d.", "y. MM. dd."], ["y. MMMM d., EEEE", "y. MMM d., E", "y. MM. dd., E"], ["LLLL", "LLL", "L"], ["MMMM d.", "MMM d.", "MM. dd."], ["MMMM d., EEEE", "MMM d., E", "MM. dd., E"], ["d", "d", "d"], ["d., EEEE", "d., E", "d., E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ia
+static S39: Symbols = Symbols {
+    months: [["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], ["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    standalone_months: [["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], ["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["lunedi", "martedi", "mercuridi", "jovedi", "venerdi", "sabbato", "dominica"], ["lun", "mar", "mer", "jov", "ven", "sab", "dom"], ["l", "m", "m", "j", "v", "s", "d"]],
+    standalone_weekdays: [["lunedi", "martedi", "mercuridi", "jovedi", "venerdi", "sabbato", "dominica"], ["lun", "mar", "mer", "jov", "ven", "sab", "dom"], ["l", "m", "m", "j", "v", "s", "d"]],
+    am_pm: ["AM", "PM"],
+    eras: ["a.Chr.", "p.Chr."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'a' HH:mm:ss", "d MMMM y 'a' HH:mm:ss", "d MMM y, HH:mm:ss", "dd-MM-y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE d MMMM y", "E d MMM y", "E dd-MM-y"], ["LLLL", "LLL", "L"], ["d 'de' MMMM", "d MMM", "dd-MM"], ["EEEE d MMMM", "E d MMM", "E dd-MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// id
+static S40: Symbols = Symbols {
+    months: [["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], ["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], ["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Senin", "Selasa", "Rabu", "Kamis", "Jumat", "Sabtu", "Minggu"], ["Sen", "Sel", "Rab", "Kam", "Jum", "Sab", "Min"], ["S", "S", "R", "K", "J", "S", "M"]],
+    standalone_weekdays: [["Senin", "Selasa", "Rabu", "Kamis", "Jumat", "Sabtu", "Minggu"], ["Sen", "Sel", "Rab", "Kam", "Jum", "Sab", "Min"], ["S", "S", "R", "K", "J", "S", "M"]],
+    am_pm: ["AM", "PM"],
+    eras: ["SM", "M"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+    times: ["HH.mm.ss", "HH.mm.ss", "HH.mm.ss", "HH.mm"],
+    date_times: ["EEEE, d MMMM y 'pukul' HH.mm.ss", "d MMMM y 'pukul' HH.mm.ss", "d MMM y, HH.mm.ss", "dd/MM/yy, HH.mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["d MMMM y", "d MMM y", "dd/MM/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd/MM/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ie
+static S41: Symbols = Symbols {
+    months: [["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], ["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], ["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["lunedí", "mardí", "mercurdí", "jovedí", "venerdí", "saturdí", "soledí"], ["lun.", "mar.", "mer.", "jov.", "ven.", "sat.", "sol."], ["L", "M", "M", "J", "V", "S", "S"]],
+    standalone_weekdays: [["lunedí", "mardí", "mercurdí", "jovedí", "venerdí", "saturdí", "soledí"], ["lun.", "mar.", "mer.", "jov.", "ven.", "sat.", "sol."], ["L", "M", "M", "J", "V", "S", "S"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["a.C.", "e.C."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'ye' HH:mm:ss", "d MMMM y 'ye' HH:mm:ss", "d MMM y HH:mm:ss", "dd.MM.y HH:mm"],
+    fields: [["y", "y", "y"], ["LLLL y", "MMM y", "MM.y"], ["d MMMM y", "d MMM y", "dd.MM.y"], ["EEEE d MMMM y", "EEEE d MMM y", "E dd.MM.y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["EEEE d MMMM", "E d MMM", "E dd.MM"], ["d.", "d.", "d."], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ga
+static S42: Symbols = Symbols {
+    months: [["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], ["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], ["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"]],
+    standalone_months: [["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], ["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], ["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"]],
+    weekdays: [["Dé Luain", "Dé Máirt", "Dé Céadaoin", "Déardaoin", "Dé hAoine", "Dé Sathairn", "Dé Domhnaigh"], ["Luan", "Máirt", "Céad", "Déar", "Aoine", "Sath", "Domh"], ["L", "M", "C", "D", "A", "S", "D"]],
+    standalone_weekdays: [["Dé Luain", "Dé Máirt", "Dé Céadaoin", "Déardaoin", "Dé hAoine", "Dé Sathairn", "Dé Domhnaigh"], ["Luan", "Máirt", "Céad", "Déar", "Aoine", "Sath", "Domh"], ["L", "M", "C", "D", "A", "S", "D"]],
+    am_pm: ["r.n.", "i.n."],
+    eras: ["RC", "AD"],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'ag' HH:mm:ss", "d MMMM y 'ag' HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["EEEE d MMMM y", "E d MMM y", "E dd/MM/y"], ["LLLL", "LLL", "LL"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE d MMMM", "E d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ig
+static S43: Symbols = Symbols {
+    months: [["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], ["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], ["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"]],
+    standalone_months: [["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], ["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], ["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"]],
+    weekdays: [["Mọnde", "Tiuzdee", "Wenezdee", "Tọọzdee", "Fraịdee", "Satọdee", "Sọndee"], ["Mọn", "Tiu", "Wen", "Tọọ", "Fraị", "Sat", "Sọn"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Mọnde", "Tiuzdee", "Wenezdee", "Tọọzdee", "Fraịdee", "Satọdee", "Sọndee"], ["Mọn", "Tiu", "Wen", "Tọọ", "Fraị", "Sat", "Sọn"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["N’ụtụtụ", "N’abalị"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'na' HH:mm:ss", "d MMMM y 'na' HH:mm:ss", "d MMM y, HH:mm:ss", "d/M/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// is
+static S44: Symbols = Symbols {
+    months: [["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], ["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], ["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"]],
+    standalone_months: [["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], ["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], ["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"]],
+    weekdays: [["mánudagur", "þriðjudagur", "miðvikudagur", "fimmtudagur", "föstudagur", "laugardagur", "sunnudagur"], ["mán.", "þri.", "mið.", "fim.", "fös.", "lau.", "sun."], ["M", "Þ", "M", "F", "F", "L", "S"]],
+    standalone_weekdays: [["mánudagur", "þriðjudagur", "miðvikudagur", "fimmtudagur", "föstudagur", "laugardagur", "sunnudagur"], ["mán.", "þri.", "mið.", "fim.", "fös.", "lau.", "sun."], ["M", "Þ", "M", "F", "F", "L", "S"]],
+    am_pm: ["f.h.", "e.h."],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE, d. MMMM y", "d. MMMM y", "d. MMM y", "d.M.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y 'kl.' HH:mm:ss", "d. MMMM y 'kl.' HH:mm:ss", "d. MMM y, HH:mm:ss", "d.M.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "M. y"], ["d. MMMM y", "d. MMM y", "d.M.y"], ["EEEE, d. MMMM y", "E, d. MMM y", "E, d.M.y"], ["LLLL", "LLL", "L"], ["d. MMMM", "d. MMM", "d.M."], ["EEEE, d. MMMM", "E, d. MMM", "E, d.M."], ["d", "d", "d"], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// it
+static S45: Symbols = Symbols {
+    months: [["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], ["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]],
+    standalone_months: [["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], ["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]],
+    weekdays: [["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"], ["lun", "mar", "mer", "gio", "ven", "sab", "dom"], ["L", "M", "M", "G", "V", "S", "D"]],
+    standalone_weekdays: [["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"], ["lun", "mar", "mer", "gio", "ven", "sab", "dom"], ["L", "M", "M", "G", "V", "S", "D"]],
+    am_pm: ["AM", "PM"],
+    eras: ["a.C.", "d.C."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'alle ore' HH:mm:ss", "d MMMM y 'alle ore' HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["d MMMM y", "d MMM y", "dd/MM/yy"], ["EEEE d MMMM y", "E d MMM y", "E dd/MM/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE d MMMM", "E d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ja
+static S46: Symbols = Symbols {
+    months: [["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"], ["月", "火", "水", "木", "金", "土", "日"], ["月", "火", "水", "木", "金", "土", "日"]],
+    standalone_weekdays: [["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"], ["月", "火", "水", "木", "金", "土", "日"], ["月", "火", "水", "木", "金", "土", "日"]],
+    am_pm: ["午前", "午後"],
+    eras: ["紀元前", "西暦"],
+    dates: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["y年M月d日EEEE H:mm:ss", "y年M月d日 H:mm:ss", "y/MM/dd H:mm:ss", "y/MM/dd H:mm"],
+    fields: [["y年", "y年", "y年"], ["y年M月", "y/MM", "y/MM"], ["y年M月d日", "y/MM/dd", "y/MM/dd"], ["y年M月d日EEEE", "y/MM/dd(E)", "y/MM/dd(E)"], ["M月", "M月", "M月"], ["M月d日", "MM/dd", "MM/dd"], ["M月d日EEEE", "MM/dd(E)", "MM/dd(E)"], ["d日", "d日", "d日"], ["d日EEEE", "d日(E)", "d日(E)"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// jv
+static S47: Symbols = Symbols {
+    months: [["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], ["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], ["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Senin", "Selasa", "Rabu", "Kamis", "Jumat", "Sabtu", "Ahad"], ["Sen", "Sel", "Rab", "Kam", "Jum", "Sab", "Ahad"], ["S", "S", "R", "K", "J", "S", "A"]],
+    standalone_weekdays: [["Senin", "Selasa", "Rabu", "Kamis", "Jumat", "Sabtu", "Ahad"], ["Sen", "Sel", "Rab", "Kam", "Jum", "Sab", "Ahad"], ["S", "S", "R", "K", "J", "S", "A"]],
+    am_pm: ["Isuk", "Wengi"],
+    eras: ["SM", "M"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'ing' HH:mm:ss", "d MMMM y 'ing' HH:mm:ss", "d MMM y, HH:mm:ss", "dd-MM-y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE, d MMMM", "E, d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// kn
+static S48: Symbols = Symbols {
+    months: [["ಜನವರಿ", "ಫೆಬ್ರವರಿ", "ಮಾರ್ಚ್", "ಏಪ್ರಿಲ್", "ಮೇ", "ಜೂನ್", "ಜುಲೈ", "ಆಗಸ್ಟ್", "ಸೆಪ್ಟೆಂಬರ್", "ಅಕ್ಟೋಬರ್", "ನವೆಂಬರ್", "ಡಿಸೆಂಬರ್"], ["ಜನ", "ಫೆಬ್ರ", "ಮಾರ್ಚ್", "ಏಪ್ರಿ", "ಮೇ", "ಜೂನ್", "ಜುಲೈ", "ಆಗ", "ಸೆಪ್ಟೆಂ", "ಅಕ್ಟೋ", "ನವೆಂ", "ಡಿಸೆಂ"], ["ಜ", "ಫೆ", "ಮಾ", "ಏ", "ಮೇ", "ಜೂ", "ಜು", "ಆ", "ಸೆ", "ಅ", "ನ", "ಡಿ"]],
+    standalone_months: [["ಜನವರಿ", "ಫೆಬ್ರವರಿ", "ಮಾರ್ಚ್", "ಏಪ್ರಿಲ್", "ಮೇ", "ಜೂನ್", "ಜುಲೈ", "ಆಗಸ್ಟ್", "ಸೆಪ್ಟೆಂಬರ್", "ಅಕ್ಟೋಬರ್", "ನವೆಂಬರ್", "ಡಿಸೆಂಬರ್"], ["ಜನ", "ಫೆಬ್ರ", "ಮಾರ್ಚ್", "ಏಪ್ರಿ", "ಮೇ", "ಜೂನ್", "ಜುಲೈ", "ಆಗ", "ಸೆಪ್ಟೆಂ", "ಅಕ್ಟೋ", "ನವೆಂ", "ಡಿಸೆಂ"], ["ಜ", "ಫೆ", "ಮಾ", "ಏ", "ಮೇ", "ಜೂ", "ಜು", "ಆ", "ಸೆ", "ಅ", "ನ", "ಡಿ"]],
+    weekdays: [["ಸೋಮವಾರ", "ಮಂಗಳವಾರ", "ಬುಧವಾರ", "ಗುರುವಾರ", "ಶುಕ್ರವಾರ", "ಶನಿವಾರ", "ಭಾನುವಾರ"], ["ಸೋಮ", "ಮಂಗಳ", "ಬುಧ", "ಗುರು", "ಶುಕ್ರ", "ಶನಿ", "ಭಾನು"], ["ಸೋ", "ಮಂ", "ಬು", "ಗು", "ಶು", "ಶ", "ಭಾ"]],
+    standalone_weekdays: [["ಸೋಮವಾರ", "ಮಂಗಳವಾರ", "ಬುಧವಾರ", "ಗುರುವಾರ", "ಶುಕ್ರವಾರ", "ಶನಿವಾರ", "ಭಾನುವಾರ"], ["ಸೋಮ", "ಮಂಗಳ", "ಬುಧ", "ಗುರು", "ಶುಕ್ರ", "ಶನಿ", "ಭಾನು"], ["ಸೋ", "ಮಂ", "ಬು", "ಗು", "ಶು", "ಶ", "ಭಾ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ಕ್ರಿ.ಪೂ", "ಕ್ರಿ.ಶ"],
+    dates: ["EEEE, MMMM d, y", "MMMM d,y", "MMM d,y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, MMMM d, y ರಂದು h:mm:ss a ಸಮಯಕ್ಕೆ", "MMMM d,y ರಂದು h:mm:ss a ಸಮಯಕ್ಕೆ", "MMM d,y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["MMMM d,y", "MMM d,y", "d/M/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "d/M, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ks
+static S49: Symbols = Symbols {
+    months: [["جنؤری", "فرؤری", "مارٕچ", "اپریل", "مئی", "جوٗن", "جُلَے", "اگست", "ستمبر", "اکتوٗبر", "نومبر", "دَسَمبَر"], ["جنؤری", "فرؤری", "مارٕچ", "اپریل", "مئی", "جوٗن", "جُلَے", "اگست", "ستمبر", "اکتوٗبر", "نومبر", "دسمبر"], ["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"]],
+    standalone_months: [["جنؤری", "فرؤری", "مارٕچ", "اپریل", "مئی", "جوٗن", "جوٗلایی", "اگست", "ستمبر", "اکتوٗبر", "نومبر", "دسمبر"], ["جنؤری", "فرؤری", "مارٕچ", "اپریل", "مئی", "جوٗن", "جوٗلایی", "اگست", "ستمبر", "اکتوٗبر", "نومبر", "دسمبر"], ["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"]],
+    weekdays: [["ژٔندرٕروار", "بۆموار", "بودوار", "برؠسوار", "جُمہ", "بٹوار", "اَتھوار"], ["ژٔندٕروار", "بۆموار", "بودوار", "برؠسوار", "جُمہ", "بٹوار", "آتھوار"], ["ژ", "ب", "ب", "ب", "ج", "ب", "ا"]],
+    standalone_weekdays: [["ژٔندرٕروار", "بۆموار", "بودوار", "برؠسوار", "جُمہ", "بٹوار", "اَتھوار"], ["ژٔندٕروار", "بۆموار", "بودوار", "برؠسوار", "جُمہ", "بٹوار", "آتھوار"], ["ژ", "ب", "ب", "ب", "ج", "ب", "ا"]],
+    am_pm: ["AM", "PM"],
+    eras: ["بی سی", "اے ڈی"],
+    dates: ["EEEE, MMMM d, y", "y MMMM d", "y MMM d", "M/d/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["h:mm:ss a پٮ۪ٹھؠ EEEE, MMMM d, y", "h:mm:ss a پٮ۪ٹھؠ y MMMM d", "y MMM d, h:mm:ss a", "M/d/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["y MMMM d", "y MMM d", "M/d/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "M/d"], ["EEEE, MMMM d", "E, MMM d", "E, M/d"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// kk
+static S50: Symbols = Symbols {
+    months: [["қаңтар", "ақпан", "наурыз", "сәуір", "мамыр", "маусым", "шілде", "тамыз", "қыркүйек", "қазан", "қараша", "желтоқсан"], ["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], ["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"]],
+    standalone_months: [["Қаңтар", "Ақпан", "Наурыз", "Сәуір", "Мамыр", "Маусым", "Шілде", "Тамыз", "Қыркүйек", "Қазан", "Қараша", "Желтоқсан"], ["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], ["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"]],
+    weekdays: [["дүйсенбі", "сейсенбі", "сәрсенбі", "бейсенбі", "жұма", "сенбі", "жексенбі"], ["дс", "сс", "ср", "бс", "жм", "сб", "жс"], ["Д", "С", "С", "Б", "Ж", "С", "Ж"]],
+    standalone_weekdays: [["дүйсенбі", "сейсенбі", "сәрсенбі", "бейсенбі", "жұма", "сенбі", "жексенбі"], ["дс", "сс", "ср", "бс", "жм", "сб", "жс"], ["Д", "С", "С", "Б", "Ж", "С", "Ж"]],
+    am_pm: ["AM", "PM"],
+    eras: ["б.з.д.", "б.з."],
+    dates: ["y\u{202F}ж. d MMMM, EEEE", "y\u{202F}ж. d MMMM", "y\u{202F}ж. dd MMM", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y\u{202F}ж. d MMMM, EEEE, HH:mm:ss", "y\u{202F}ж. d MMMM, HH:mm:ss", "y\u{202F}ж. dd MMM, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["y\u{202F}ж. MMMM", "y\u{202F}ж. MMM", "MM.yy"], ["y\u{202F}ж. d MMMM", "y\u{202F}ж. dd MMM", "dd.MM.yy"], ["y\u{202F}ж. d MMMM, EEEE", "y\u{202F}ж. dd MMM, E", "dd.MM.yy, E"], ["LLLL", "LLL", "L"], ["d MMMM", "dd MMM", "dd.MM"], ["d MMMM, EEEE", "dd MMM, E", "dd.MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// km
+static S51: Symbols = Symbols {
+    months: [["មករា", "កុម្ភៈ", "មីនា", "មេសា", "ឧសភា", "មិថុនា", "កក្កដា", "សីហា", "កញ្ញា", "តុលា", "វិច្ឆិកា", "ធ្នូ"], ["មករា", "កុម្ភៈ", "មីនា", "មេសា", "ឧសភា", "មិថុនា", "កក្កដា", "សីហា", "កញ្ញា", "តុលា", "វិច្ឆិកា", "ធ្នូ"], ["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"]],
+    standalone_months: [["មករា", "កុម្ភៈ", "មីនា", "មេសា", "ឧសភា", "មិថុនា", "កក្កដា", "សីហា", "កញ្ញា", "តុលា", "វិច្ឆិកា", "ធ្នូ"], ["មករា", "កុម្ភៈ", "មីនា", "មេសា", "ឧសភា", "មិថុនា", "កក្កដា", "សីហា", "កញ្ញា", "តុលា", "វិច្ឆិកា", "ធ្នូ"], ["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"]],
+    weekdays: [["ច័ន្ទ", "អង្គារ", "ពុធ", "ព្រហស្បតិ៍", "សុក្រ", "សៅរ៍", "អាទិត្យ"], ["ចន្ទ", "អង្គារ", "ពុធ", "ព្រហ", "សុក្រ", "សៅរ៍", "អាទិត្យ"], ["ច", "អ", "ព", "ព", "ស", "ស", "អ"]],
+    standalone_weekdays: [["ចន្ទ", "អង្គារ", "ពុធ", "ព្រហស្បតិ៍", "សុក្រ", "សៅរ៍", "អាទិត្យ"], ["ចន្ទ", "អង្គារ", "ពុធ", "ព្រហ", "សុក្រ", "សៅរ៍", "អាទិត្យ"], ["ច", "អ", "ព", "ព", "ស", "ស", "អ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["មុន គ.ស.", "គ.ស."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE d MMMM y នៅ\u{200B}ម៉ោង h:mm:ss a", "d MMMM y នៅ\u{200B}ម៉ោង h:mm:ss a", "d MMM y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE d MMMM y", "E d MMM y", "E d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE d MMMM", "E d MMM", "E d/M"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// rw
+static S52: Symbols = Symbols {
+    months: [["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], ["Mut.", "Gas.", "Wer.", "Mat.", "Gic.", "Kam.", "Nya.", "Kan.", "Nze.", "Ukw.", "Ugu.", "Uku."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], ["Mut.", "Gas.", "Wer.", "Mat.", "Gic.", "Kam.", "Nya.", "Kan.", "Nze.", "Ukw.", "Ugu.", "Uku."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Kuwa mbere", "Kuwa kabiri", "Kuwa gatatu", "Kuwa kane", "Kuwa gatanu", "Kuwa gatandatu", "Ku cyumweru"], ["Mbe.", "Kab.", "Gtu.", "Kan.", "Gnu.", "Gnd.", "Cyu."], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Kuwa mbere", "Kuwa kabiri", "Kuwa gatatu", "Kuwa kane", "Kuwa gatanu", "Kuwa gatandatu", "Ku cyumweru"], ["Mbe.", "Kab.", "Gtu.", "Kan.", "Gnu.", "Gnd.", "Cyu."], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMMM y", "dd-MM-y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y HH:mm:ss", "d MMMM y HH:mm:ss", "d MMMM y HH:mm:ss", "dd-MM-y HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMMM y", "dd-MM-y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd-MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ky
+static S53: Symbols = Symbols {
+    months: [["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["янв.", "фев.", "мар.", "апр.", "май", "июн.", "июл.", "авг.", "сен.", "окт.", "ноя.", "дек."], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    standalone_months: [["Январь", "Февраль", "Март", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"], ["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    weekdays: [["дүйшөмбү", "шейшемби", "шаршемби", "бейшемби", "жума", "ишемби", "жекшемби"], ["дүй.", "шейш.", "шарш.", "бейш.", "жума", "ишм.", "жек."], ["Д", "Ш", "Ш", "Б", "Ж", "И", "Ж"]],
+    standalone_weekdays: [["дүйшөмбү", "шейшемби", "шаршемби", "бейшемби", "жума", "ишемби", "жекшемби"], ["дүй.", "шейш.", "шарш.", "бейш.", "жума", "ишм.", "жек."], ["Д", "Ш", "Ш", "Б", "Ж", "И", "Ж"]],
+    am_pm: ["тң", "тк"],
+    eras: ["б.з.ч.", "б.з."],
+    dates: ["y-ж. d-MMMM, EEEE", "y-ж. d-MMMM", "y-ж. d-MMM", "yy-d-M"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y-ж. d-MMMM, EEEE HH:mm:ss", "y-ж. d-MMMM HH:mm:ss", "y-ж. d-MMM HH:mm:ss", "yy-d-M HH:mm"],
+    fields: [["y", "y", "yy"], ["y-ж., MMMM", "y-ж. MMM", "yy-M"], ["y-ж. d-MMMM", "y-ж. d-MMM", "yy-d-M"], ["y-ж. d-MMMM, EEEE", "y-ж. d-MMM, E", "yy-d-M, E"], ["LLLL", "LLL", "L"], ["d-MMMM", "d-MMM", "dd-MM"], ["d-MMMM, EEEE", "d-MMM, E", "dd-MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ko
+static S54: Symbols = Symbols {
+    months: [["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"]],
+    standalone_months: [["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"]],
+    weekdays: [["월요일", "화요일", "수요일", "목요일", "금요일", "토요일", "일요일"], ["월", "화", "수", "목", "금", "토", "일"], ["월", "화", "수", "목", "금", "토", "일"]],
+    standalone_weekdays: [["월요일", "화요일", "수요일", "목요일", "금요일", "토요일", "일요일"], ["월", "화", "수", "목", "금", "토", "일"], ["월", "화", "수", "목", "금", "토", "일"]],
+    am_pm: ["오전", "오후"],
+    eras: ["BC", "AD"],
+    dates: ["y년 MMMM d일 EEEE", "y년 MMMM d일", "y. M. d.", "yy. M. d."],
+    times: ["a h:mm:ss", "a h:mm:ss", "a h:mm:ss", "a h:mm"],
+    date_times: ["y년 MMMM d일 EEEE a h:mm:ss", "y년 MMMM d일 a h:mm:ss", "y. M. d. a h:mm:ss", "yy. M. d. a h:mm"],
+    fields: [["y년", "y년", "yy년"], ["y년 MMMM", "y. M.", "yy. M."], ["y년 MMMM d일", "y. M. d.", "yy. M. d."], ["y년 MMMM d일 EEEE", "y. M. d. (E)", "yy. M. d. (E)"], ["LLLL", "LLL", "M월"], ["MMMM d일", "M. d.", "M. d."], ["MMMM d일 EEEE", "M. d. (E)", "M. d. (E)"], ["d일", "d일", "d일"], ["d일 EEEE", "d일 (E)", "d일 (E)"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ku
+static S55: Symbols = Symbols {
+    months: [["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], ["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], ["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"]],
+    standalone_months: [["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], ["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], ["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"]],
+    weekdays: [["duşem", "sêşem", "çarşem", "pêncşem", "înî", "şemî", "yekşem"], ["dşm", "sşm", "çşm", "pşm", "înî", "şem", "yşm"], ["D", "S", "Ç", "P", "Î", "Ş", "Y"]],
+    standalone_weekdays: [["duşem", "sêşem", "çarşem", "pêncşem", "înî", "şemî", "yekşem"], ["dşm", "sşm", "çşm", "pşm", "înî", "şem", "yşm"], ["D", "S", "Ç", "P", "Î", "Ş", "Y"]],
+    am_pm: ["BN", "PN"],
+    eras: ["BM", "PM"],
+    dates: ["d MMMM y, EEEE", "d MMMM, y", "d MMM, y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["d MMMM y, EEEE HH:mm:ss", "d MMMM, y HH:mm:ss", "d MMM, y HH:mm:ss", "dd.MM.y HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM'a' y'an'", "MMM, y", "MM.y"], ["d MMMM, y", "d MMM, y", "dd.MM.y"], ["d MMMM y, EEEE", "d MMM y, E", "dd.MM.y, E"], ["LLLL", "LLL", "L"], ["dê MMMMê", "d MMM", "dd.MM"], ["d MMMM, EEEE", "d MMM, E", "dd/MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// lb
+static S56: Symbols = Symbols {
+    months: [["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], ["Jan.", "Feb.", "Mäe.", "Abr.", "Mee", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], ["Jan", "Feb", "Mäe", "Abr", "Mee", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Méindeg", "Dënschdeg", "Mëttwoch", "Donneschdeg", "Freideg", "Samschdeg", "Sonndeg"], ["Méi.", "Dën.", "Mët.", "Don.", "Fre.", "Sam.", "Son."], ["M", "D", "M", "D", "F", "S", "S"]],
+    standalone_weekdays: [["Méindeg", "Dënschdeg", "Mëttwoch", "Donneschdeg", "Freideg", "Samschdeg", "Sonndeg"], ["Méi", "Dën", "Mët", "Don", "Fre", "Sam", "Son"], ["M", "D", "M", "D", "F", "S", "S"]],
+    am_pm: ["moies", "nomëttes"],
+    eras: ["v. Chr.", "n. Chr."],
+    dates: ["EEEE, d. MMMM y", "d. MMMM y", "d. MMM y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d. MMMM y HH:mm:ss", "d. MMMM y HH:mm:ss", "d. MMM y HH:mm:ss", "dd.MM.yy HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM.yy"], ["d. MMMM y", "d. MMM y", "dd.MM.yy"], ["EEEE, d. MMMM y", "E, d. MMM y", "E, dd.MM.yy"], ["LLLL", "LLL", "L"], ["d. MMMM", "d. MMM", "dd.MM."], ["EEEE, d. MMMM", "E, d. MMM", "E, dd.MM."], ["d", "d", "d"], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// lo
+static S57: Symbols = Symbols {
+    months: [["ມັງກອນ", "ກຸມພາ", "ມີນາ", "ເມສາ", "ພຶດສະພາ", "ມິຖຸນາ", "ກໍລະກົດ", "ສິງຫາ", "ກັນຍາ", "ຕຸລາ", "ພະຈິກ", "ທັນວາ"], ["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມິ.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["ມັງກອນ", "ກຸມພາ", "ມີນາ", "ເມສາ", "ພຶດສະພາ", "ມິຖຸນາ", "ກໍລະກົດ", "ສິງຫາ", "ກັນຍາ", "ຕຸລາ", "ພະຈິກ", "ທັນວາ"], ["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມິ.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["ວັນຈັນ", "ວັນອັງຄານ", "ວັນພຸດ", "ວັນພະຫັດ", "ວັນສຸກ", "ວັນເສົາ", "ວັນອາທິດ"], ["ຈັນ", "ອັງຄານ", "ພຸດ", "ພະຫັດ", "ສຸກ", "ເສົາ", "ອາທິດ"], ["ຈ", "ອ", "ພ", "ພຫ", "ສຸ", "ສ", "ອາ"]],
+    standalone_weekdays: [["ວັນຈັນ", "ວັນອັງຄານ", "ວັນພຸດ", "ວັນພະຫັດ", "ວັນສຸກ", "ວັນເສົາ", "ວັນອາທິດ"], ["ຈັນ", "ອັງຄານ", "ພຸດ", "ພະຫັດ", "ສຸກ", "ເສົາ", "ອາທິດ"], ["ຈ", "ອ", "ພ", "ພຫ", "ສຸ", "ສ", "ອາ"]],
+    am_pm: ["ກ່ອນທ່ຽງ", "ຫຼັງທ່ຽງ"],
+    eras: ["ກ່ອນ ຄ.ສ.", "ຄ.ສ."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/M/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y, HH:mm:ss", "d MMMM y, HH:mm:ss", "d MMM y, HH:mm:ss", "d/M/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "M/y"], ["d MMMM y", "d MMM y", "d/M/y"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE d MMMM", "E d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// lt
+static S58: Symbols = Symbols {
+    months: [["sausio", "vasario", "kovo", "balandžio", "gegužės", "birželio", "liepos", "rugpjūčio", "rugsėjo", "spalio", "lapkričio", "gruodžio"], ["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], ["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"]],
+    standalone_months: [["sausis", "vasaris", "kovas", "balandis", "gegužė", "birželis", "liepa", "rugpjūtis", "rugsėjis", "spalis", "lapkritis", "gruodis"], ["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], ["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"]],
+    weekdays: [["pirmadienis", "antradienis", "trečiadienis", "ketvirtadienis", "penktadienis", "šeštadienis", "sekmadienis"], ["pr", "an", "tr", "kt", "pn", "št", "sk"], ["P", "A", "T", "K", "P", "Š", "S"]],
+    standalone_weekdays: [["pirmadienis", "antradienis", "trečiadienis", "ketvirtadienis", "penktadienis", "šeštadienis", "sekmadienis"], ["pr", "an", "tr", "kt", "pn", "št", "sk"], ["P", "A", "T", "K", "P", "Š", "S"]],
+    am_pm: ["priešpiet", "popiet"],
+    eras: ["pr. Kr.", "po Kr."],
+    dates: ["y 'm.' MMMM d 'd.,' EEEE", "y 'm.' MMMM d 'd.'", "y-MM-dd", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y 'm.' MMMM d 'd.,' EEEE HH:mm:ss", "y 'm.' MMMM d 'd.' HH:mm:ss", "y-MM-dd HH:mm:ss", "y-MM-dd HH:mm"],
+    fields: [["y", "y", "y"], ["y 'm.' LLLL", "y-MM", "y-MM"], ["y 'm.' MMMM d 'd.'", "y-MM-dd", "y-MM-dd"], ["y 'm.' MMMM d 'd.,' EEEE", "y-MM-dd, E", "y-MM-dd, E"], ["LLLL", "MM", "MM"], ["MMMM d 'd.'", "MM-dd", "MM-dd"], ["MMMM d 'd.,' EEEE", "MM-dd, E", "MM-dd, E"], ["dd", "dd", "dd"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// lv
+static S59: Symbols = Symbols {
+    months: [["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], ["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], ["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["pirmdiena", "otrdiena", "trešdiena", "ceturtdiena", "piektdiena", "sestdiena", "svētdiena"], ["pirmd.", "otrd.", "trešd.", "ceturtd.", "piektd.", "sestd.", "svētd."], ["P", "O", "T", "C", "P", "S", "S"]],
+    standalone_weekdays: [["Pirmdiena", "Otrdiena", "Trešdiena", "Ceturtdiena", "Piektdiena", "Sestdiena", "Svētdiena"], ["Pirmd.", "Otrd.", "Trešd.", "Ceturtd.", "Piektd.", "Sestd.", "Svētd."], ["P", "O", "T", "C", "P", "S", "S"]],
+    am_pm: ["priekšp.", "pēcp."],
+    eras: ["p.m.ē.", "m.ē."],
+    dates: ["EEEE, y'. g.' d. MMMM", "y'. g.' d. MMMM", "y'. g.' d. MMM", "dd.MM.yy."],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, y'. g.' d. MMMM HH:mm:ss", "y'. g.' d. MMMM HH:mm:ss", "y'. g.' d. MMM HH:mm:ss", "dd.MM.yy. HH:mm"],
+    fields: [["y'. g.'", "y'. g.'", "yy'. g.'"], ["y'. g.' MMMM", "y'. g.' MMM", "MM.yy."], ["y'. g.' d. MMMM", "y'. g.' d. MMM", "dd.MM.yy."], ["EEEE, y'. g.' d. MMMM", "E, y'. g.' d. MMM", "E, dd.MM.yy."], ["LLLL", "LLL", "L"], ["d. MMMM", "d. MMM", "dd.MM."], ["EEEE, d. MMMM", "E, d. MMM", "E, dd.MM."], ["d", "d", "d"], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// mk
+static S60: Symbols = Symbols {
+    months: [["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], ["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], ["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]],
+    standalone_months: [["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], ["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], ["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]],
+    weekdays: [["понеделник", "вторник", "среда", "четврток", "петок", "сабота", "недела"], ["пон.", "вто.", "сре.", "чет.", "пет.", "саб.", "нед."], ["п", "в", "с", "ч", "п", "с", "н"]],
+    standalone_weekdays: [["понеделник", "вторник", "среда", "четврток", "петок", "сабота", "недела"], ["пон.", "вто.", "сре.", "чет.", "пет.", "саб.", "нед."], ["п", "в", "с", "ч", "п", "с", "н"]],
+    am_pm: ["претпл.", "попл."],
+    eras: ["пр. н. е.", "н. е."],
+    dates: ["EEEE, d MMMM y\u{202F}г.", "d MMMM y\u{202F}г.", "d.M.y\u{202F}г.", "d.M.yy\u{202F}г."],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE, d MMMM y\u{202F}г., во H:mm:ss", "d MMMM y\u{202F}г., во H:mm:ss", "d.M.y\u{202F}г., во H:mm:ss", "d.M.yy\u{202F}г., H:mm"],
+    fields: [["y\u{202F}г.", "y\u{202F}г.", "yy\u{202F}г."], ["MMMM y\u{202F}г.", "M.y\u{202F}г.", "M.yy\u{202F}г."], ["d MMMM y\u{202F}г.", "d.M.y\u{202F}г.", "d.M.yy\u{202F}г."], ["EEEE, d MMMM y\u{202F}г.", "E, d.M.y\u{202F}г.", "E, d.M.yy\u{202F}г."], ["LLLL", "LLL", "L"], ["d MMMM", "d.M", "d.M"], ["EEEE, d MMMM", "E, d.M", "E, d.M"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ms
+static S61: Symbols = Symbols {
+    months: [["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], ["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], ["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]],
+    standalone_months: [["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], ["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], ["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]],
+    weekdays: [["Isnin", "Selasa", "Rabu", "Khamis", "Jumaat", "Sabtu", "Ahad"], ["Isn", "Sel", "Rab", "Kha", "Jum", "Sab", "Ahd"], ["I", "S", "R", "K", "J", "S", "A"]],
+    standalone_weekdays: [["Isnin", "Selasa", "Rabu", "Khamis", "Jumaat", "Sabtu", "Ahad"], ["Isn", "Sel", "Rab", "Kha", "Jum", "Sab", "Ahd"], ["I", "S", "R", "K", "J", "S", "A"]],
+    am_pm: ["PG", "PTG"],
+    eras: ["S.M.", "TM"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/MM/yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, d MMMM y 'pada' h:mm:ss\u{202F}a", "d MMMM y 'pada' h:mm:ss\u{202F}a", "d MMM y, h:mm:ss\u{202F}a", "d/MM/yy, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM-yy"], ["d MMMM y", "d MMM y", "d/MM/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/MM/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d-MM"], ["EEEE, d MMMM", "E, d MMM", "E, d-MM"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ml
+static S62: Symbols = Symbols {
+    months: [["ജനുവരി", "ഫെബ്രുവരി", "മാർച്ച്", "ഏപ്രിൽ", "മേയ്", "ജൂൺ", "ജൂലൈ", "ഓഗസ്റ്റ്", "സെപ്റ്റംബർ", "ഒക്\u{200C}ടോബർ", "നവംബർ", "ഡിസംബർ"], ["ജനു", "ഫെബ്രു", "മാർ", "ഏപ്രി", "മേയ്", "ജൂൺ", "ജൂലൈ", "ഓഗ", "സെപ്റ്റം", "ഒക്ടോ", "നവം", "ഡിസം"], ["ജ", "ഫെ", "മാ", "ഏ", "മെ", "ജൂൺ", "ജൂ", "ഓ", "സെ", "ഒ", "ന", "ഡി"]],
+    standalone_months: [["ജനുവരി", "ഫെബ്രുവരി", "മാർച്ച്", "ഏപ്രിൽ", "മേയ്", "ജൂൺ", "ജൂലൈ", "ഓഗസ്റ്റ്", "സെപ്റ്റംബർ", "ഒക്\u{200C}ടോബർ", "നവംബർ", "ഡിസംബർ"], ["ജനു", "ഫെബ്രു", "മാർ", "ഏപ്രി", "മേയ്", "ജൂൺ", "ജൂലൈ", "ഓഗ", "സെപ്റ്റം", "ഒക്ടോ", "നവം", "ഡിസം"], ["ജ", "ഫെ", "മാ", "ഏ", "മെ", "ജൂൺ", "ജൂ", "ഓ", "സെ", "ഒ", "ന", "ഡി"]],
+    weekdays: [["തിങ്കളാഴ്\u{200C}ച", "ചൊവ്വാഴ്ച", "ബുധനാഴ്\u{200C}ച", "വ്യാഴാഴ്\u{200C}ച", "വെള്ളിയാഴ്\u{200C}ച", "ശനിയാഴ്\u{200C}ച", "ഞായറാഴ്\u{200C}ച"], ["തിങ്കൾ", "ചൊവ്വ", "ബുധൻ", "വ്യാഴം", "വെള്ളി", "ശനി", "ഞായർ"], ["തി", "ചൊ", "ബു", "വ്യാ", "വെ", "ശ", "ഞ"]],
+    standalone_weekdays: [["തിങ്കളാഴ്\u{200C}ച", "ചൊവ്വാഴ്\u{200C}ച", "ബുധനാഴ്\u{200C}ച", "വ്യാഴാഴ്\u{200C}ച", "വെള്ളിയാഴ്\u{200C}ച", "ശനിയാഴ്\u{200C}ച", "ഞായറാഴ്\u{200C}ച"], ["തിങ്കൾ", "ചൊവ്വ", "ബുധൻ", "വ്യാഴം", "വെള്ളി", "ശനി", "ഞായർ"], ["തി", "ചൊ", "ബു", "വ്യാ", "വെ", "ശ", "ഞാ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ബിസി", "എഡി"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["y MMMM d, EEEE, h:mm:ss a-ന്", "y MMMM d, h:mm:ss a-ന്", "y MMM d, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["y MMMM", "y MMM", "M/yy"], ["y MMMM d", "y MMM d", "d/M/yy"], ["y MMMM d, EEEE", "y MMM d, E", "d/M/yy, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "d/M"], ["MMMM d, EEEE", "MMM d, E", "d/M, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// mt
+static S63: Symbols = Symbols {
+    months: [["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], ["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], ["J", "F", "M", "A", "M", "Ġ", "L", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], ["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], ["Jn", "Fr", "Mz", "Ap", "Mj", "Ġn", "Lj", "Aw", "St", "Ob", "Nv", "Dċ"]],
+    weekdays: [["It-Tnejn", "It-Tlieta", "L-Erbgħa", "Il-Ħamis", "Il-Ġimgħa", "Is-Sibt", "Il-Ħadd"], ["Tne", "Tli", "Erb", "Ħam", "Ġim", "Sib", "Ħad"], ["T", "Tl", "Er", "Ħm", "Ġm", "Sb", "Ħd"]],
+    standalone_weekdays: [["It-Tnejn", "It-Tlieta", "L-Erbgħa", "Il-Ħamis", "Il-Ġimgħa", "Is-Sibt", "Il-Ħadd"], ["Tne", "Tli", "Erb", "Ħam", "Ġim", "Sib", "Ħad"], ["Tn", "Tl", "Er", "Ħm", "Ġm", "Sb", "Ħd"]],
+    am_pm: ["AM", "PM"],
+    eras: ["QK", "WK"],
+    dates: ["EEEE, d 'ta’' MMMM, y", "d 'ta’' MMMM, y", "dd 'ta’' MMM, y", "MM/dd/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d 'ta’' MMMM, y HH:mm:ss", "d 'ta’' MMMM, y HH:mm:ss", "dd 'ta’' MMM, y HH:mm:ss", "MM/dd/y HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "y-MM"], ["d 'ta’' MMMM, y", "dd 'ta’' MMM, y", "MM/dd/y"], ["EEEE, d 'ta’' MMMM, y", "E, dd 'ta’' MMM, y", "E, dd/MM/y"], ["LLLL", "LLL", "L"], ["d 'ta’' MMMM", "MMM dd", "MM-dd"], ["EEEE, d 'ta’' MMMM", "E, dd 'ta’' MMM", "E, MM-dd"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// mi
+static S64: Symbols = Symbols {
+    months: [["Hānuere", "Pēpuere", "Māehe", "Āpereira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], ["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], ["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"]],
+    standalone_months: [["Hānuere", "Pēpuere", "Māehe", "Āperira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], ["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], ["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"]],
+    weekdays: [["Mane", "Tūrei", "Wenerei", "Tāite", "Paraire", "Rāhoroi", "Rātapu"], ["Man", "Tūr", "Wen", "Tāi", "Par", "Rāh", "Rāt"], ["M", "T", "W", "T", "P", "Rh", "Rt"]],
+    standalone_weekdays: [["Mane", "Tūrei", "Wenerei", "Tāite", "Paraire", "Rāhoroi", "Rātapu"], ["Man", "Tūr", "Wen", "Tāi", "Par", "Rāh", "Rāt"], ["M", "T", "W", "T", "P", "Rh", "Rt"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM y 'i' h:mm:ss a", "d MMMM y 'i' h:mm:ss a", "d MMM y h:mm:ss a", "dd-MM-y h:mm a"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd-MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// mr
+static S65: Symbols = Symbols {
+    months: [["जानेवारी", "फेब्रुवारी", "मार्च", "एप्रिल", "मे", "जून", "जुलै", "ऑगस्ट", "सप्टेंबर", "ऑक्टोबर", "नोव्हेंबर", "डिसेंबर"], ["जाने", "फेब्रु", "मार्च", "एप्रि", "मे", "जून", "जुलै", "ऑग", "सप्टें", "ऑक्टो", "नोव्हें", "डिसें"], ["जा", "फे", "मा", "ए", "मे", "जू", "जु", "ऑ", "स", "ऑ", "नो", "डि"]],
+    standalone_months: [["जानेवारी", "फेब्रुवारी", "मार्च", "एप्रिल", "मे", "जून", "जुलै", "ऑगस्ट", "सप्टेंबर", "ऑक्टोबर", "नोव्हेंबर", "डिसेंबर"], ["जाने", "फेब्रु", "मार्च", "एप्रि", "मे", "जून", "जुलै", "ऑग", "सप्टें", "ऑक्टो", "नोव्हें", "डिसें"], ["जा", "फे", "मा", "ए", "मे", "जू", "जु", "ऑ", "स", "ऑ", "नो", "डि"]],
+    weekdays: [["सोमवार", "मंगळवार", "बुधवार", "गुरुवार", "शुक्रवार", "शनिवार", "रविवार"], ["सोम", "मंगळ", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    standalone_weekdays: [["सोमवार", "मंगळवार", "बुधवार", "गुरुवार", "शुक्रवार", "शनिवार", "रविवार"], ["सोम", "मंगळ", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ई. स. पू.", "इ. स."],
+    dates: ["EEEE, d, MMMM y", "d MMMM, y", "d MMM, y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d, MMMM y रोजी h:mm:ss a वाजता", "d MMMM, y रोजी h:mm:ss a वाजता", "d MMM, y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "d/M/yy"], ["EEEE, d, MMMM y", "E, d, MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// mn
+static S66: Symbols = Symbols {
+    months: [["нэгдүгээр сар", "хоёрдугаар сар", "гуравдугаар сар", "дөрөвдүгээр сар", "тавдугаар сар", "зургаадугаар сар", "долоодугаар сар", "наймдугаар сар", "есдүгээр сар", "аравдугаар сар", "арван нэгдүгээр сар", "арван хоёрдугаар сар"], ["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"]],
+    standalone_months: [["Нэгдүгээр сар", "Хоёрдугаар сар", "Гуравдугаар сар", "Дөрөвдүгээр сар", "Тавдугаар сар", "Зургаадугаар сар", "Долоодугаар сар", "Наймдугаар сар", "Есдүгээр сар", "Аравдугаар сар", "Арван нэгдүгээр сар", "Арван хоёрдугаар сар"], ["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"]],
+    weekdays: [["даваа", "мягмар", "лхагва", "пүрэв", "баасан", "бямба", "ням"], ["Да", "Мя", "Лх", "Пү", "Ба", "Бя", "Ня"], ["Да", "Мя", "Лх", "Пү", "Ба", "Бя", "Ня"]],
+    standalone_weekdays: [["Даваа", "Мягмар", "Лхагва", "Пүрэв", "Баасан", "Бямба", "Ням"], ["Да", "Мя", "Лх", "Пү", "Ба", "Бя", "Ня"], ["Да", "Мя", "Лх", "Пү", "Ба", "Бя", "Ня"]],
+    am_pm: ["ү.ө.", "ү.х."],
+    eras: ["МЭӨ", "МЭ"],
+    dates: ["y\u{202F}оны MMMMын d. EEEE", "y\u{202F}оны MMMMын d", "y\u{202F}оны MMMын d", "y.MM.dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y\u{202F}оны MMMMын d. EEEE HH:mm:ss", "y\u{202F}оны MMMMын d HH:mm:ss", "y\u{202F}оны MMMын d HH:mm:ss", "y.MM.dd HH:mm"],
+    fields: [["y", "y", "y"], ["y\u{202F}оны MMMM", "y\u{202F}оны MMM", "y MMMMM"], ["y\u{202F}оны MMMMын d", "y\u{202F}оны MMMын d", "y.MM.dd"], ["y\u{202F}оны MMMMын d. EEEE", "y\u{202F}оны MMMын d. E", "y.MM.dd. E"], ["LLLL", "LLL", "LLLLL"], ["MMMMын d", "MMMын d", "MMMMM/dd"], ["MMMMын d. EEEE", "MMMын d. E", "MMMMM/dd. E"], ["d", "d", "d"], ["d. EEEE", "dd. E", "dd. E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ne
+static S67: Symbols = Symbols {
+    months: [["जनवरी", "फेब्रुअरी", "मार्च", "अप्रिल", "मे", "जुन", "जुलाई", "अगस्ट", "सेप्टेम्बर", "अक्टोबर", "नोभेम्बर", "डिसेम्बर"], ["जनवरी", "फेब्रुअरी", "मार्च", "अप्रिल", "मे", "जुन", "जुलाई", "अगस्ट", "सेप्टेम्बर", "अक्टोबर", "नोभेम्बर", "डिसेम्बर"], ["जन", "फेब", "मार्च", "अप्र", "मे", "जुन", "जुल", "अग", "सेप", "अक्टो", "नोभे", "डिसे"]],
+    standalone_months: [["जनवरी", "फेब्रुअरी", "मार्च", "अप्रिल", "मे", "जुन", "जुलाई", "अगस्ट", "सेप्टेम्बर", "अक्टोबर", "नोभेम्बर", "डिसेम्बर"], ["जनवरी", "फेब्रुअरी", "मार्च", "अप्रिल", "मे", "जुन", "जुलाई", "अगस्ट", "सेप्टेम्बर", "अक्टोबर", "नोभेम्बर", "डिसेम्बर"], ["जन", "फेेब", "मार्च", "अप्र", "मे", "जुन", "जुल", "अग", "सेप", "अक्टो", "नोभे", "डिसे"]],
+    weekdays: [["सोमबार", "मङ्गलबार", "बुधबार", "बिहिबार", "शुक्रबार", "शनिबार", "आइतबार"], ["सोम", "मङ्गल", "बुध", "बिहि", "शुक्र", "शनि", "आइत"], ["सो", "म", "बु", "बि", "शु", "श", "आ"]],
+    standalone_weekdays: [["सोमबार", "मङ्गलबार", "बुधबार", "बिहिबार", "शुक्रबार", "शनिबार", "आइतबार"], ["सोम", "मङ्गल", "बुध", "बिहि", "शुक्र", "शनि", "आइत"], ["सो", "म", "बु", "बि", "शु", "श", "आ"]],
+    am_pm: ["पूर्वाह्न", "अपराह्न"],
+    eras: ["ईसा पूर्व", "सन्"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "yy-M-d"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE: HH:mm:ss", "y MMMM d: HH:mm:ss", "y MMM d, HH:mm:ss", "yy-M-d, HH:mm"],
+    fields: [["y", "y", "yy"], ["y MMMM", "y MMM", "yy-M"], ["y MMMM d", "y MMM d", "yy-M-d"], ["y MMMM d, EEEE", "y MMM d, E", "yy-M-d, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// nb, no
+static S68: Symbols = Symbols {
+    months: [["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan.", "feb.", "mars", "apr.", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag"], ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."], ["M", "T", "O", "T", "F", "L", "S"]],
+    standalone_weekdays: [["mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag"], ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."], ["M", "T", "O", "T", "F", "L", "S"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE d. MMMM y", "d. MMMM y", "d. MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d. MMMM y 'kl.' HH:mm:ss", "d. MMMM y 'kl.' HH:mm:ss", "d. MMM y, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d. MMMM y", "d. MMM y", "dd.MM.y"], ["EEEE d. MMMM y", "E d. MMM y", "E dd.MM.y"], ["LLLL", "LLL", "L."], ["d. MMMM", "d. MMM", "d.M."], ["EEEE d. MMMM", "E d. MMM", "E dd.MM."], ["d.", "d.", "d."], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// nn
+static S69: Symbols = Symbols {
+    months: [["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan.", "feb.", "mars", "apr.", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], ["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["måndag", "tysdag", "onsdag", "torsdag", "fredag", "laurdag", "søndag"], ["må.", "ty.", "on.", "to.", "fr.", "la.", "sø."], ["M", "T", "O", "T", "F", "L", "S"]],
+    standalone_weekdays: [["måndag", "tysdag", "onsdag", "torsdag", "fredag", "laurdag", "søndag"], ["mån", "tys", "ons", "tor", "fre", "lau", "søn"], ["M", "T", "O", "T", "F", "L", "S"]],
+    am_pm: ["f.m.", "e.m."],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE d. MMMM y", "d. MMMM y", "d. MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d. MMMM y 'kl.' HH:mm:ss", "d. MMMM y 'kl.' HH:mm:ss", "d. MMM y, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d. MMMM y", "d. MMM y", "dd.MM.y"], ["EEEE d. MMMM y", "E d. MMM y", "E dd.MM.y"], ["LLLL", "LLL", "L."], ["d. MMMM", "d. MMM", "d.M."], ["EEEE d. MMMM", "E d. MMM", "E dd.MM."], ["d.", "d.", "d."], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ii
+static S70: Symbols = Symbols {
+    months: [["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], ["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], ["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["ꆏꊂꋍ", "ꆏꊂꑍ", "ꆏꊂꌕ", "ꆏꊂꇖ", "ꆏꊂꉬ", "ꆏꊂꃘ", "ꑬꆏꑍ"], ["ꆏꋍ", "ꆏꑍ", "ꆏꌕ", "ꆏꇖ", "ꆏꉬ", "ꆏꃘ", "ꑬꆏ"], ["ꋍ", "ꑍ", "ꌕ", "ꇖ", "ꉬ", "ꃘ", "ꆏ"]],
+    standalone_weekdays: [["ꆏꊂꋍ", "ꆏꊂꑍ", "ꆏꊂꌕ", "ꆏꊂꇖ", "ꆏꊂꉬ", "ꆏꊂꃘ", "ꑬꆏꑍ"], ["ꆏꋍ", "ꆏꑍ", "ꆏꌕ", "ꆏꇖ", "ꆏꉬ", "ꆏꃘ", "ꑬꆏ"], ["ꋍ", "ꑍ", "ꌕ", "ꇖ", "ꉬ", "ꃘ", "ꆏ"]],
+    am_pm: ["ꎸꄑ", "ꁯꋒ"],
+    eras: ["ꃅꋊꂿ", "ꃅꋊꊂ"],
+    dates: ["y MMMM dꑍ,EEEE", "yꈎMꆪdꑍ", "yꈎMꆪdꑍ", "y-MM-dd"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["y MMMM dꑍ,EEEE h:mm:ss a", "yꈎMꆪdꑍ h:mm:ss a", "yꈎMꆪdꑍ h:mm:ss a", "y-MM-dd h:mm a"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["yꈎMꆪdꑍ", "yꈎMꆪdꑍ", "y-MM-dd"], ["y MMMM dꑍ,EEEE", "y MMM dꑍ,E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM dꑍ", "MMM dꑍ", "MMꆪ-ddꑍ"], ["MMMM dꑍ,EEEE", "MMMM dꑍ,E", "MMꆪ-ddꑍ，E"], ["dꑍ", "dꑍ", "dꑍ"], ["dꑍ, EEEE", "dꑍ, E", "dꑍ, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// oc
+static S71: Symbols = Symbols {
+    months: [["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], ["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], ["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["diluns", "dimars", "dimècres", "dijòus", "divendres", "dissabte", "dimenge"], ["diluns", "dimars", "dimècres", "dijòus", "divendres", "dissabte", "dimenge"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["diluns", "dimars", "dimècres", "dijòus", "divendres", "dissabte", "dimenge"], ["diluns", "dimars", "dimècres", "dijòus", "divendres", "dissabte", "dimenge"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["y MMMM d, EEEE", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE HH:mm:ss", "d MMMM y HH:mm:ss", "d MMM y HH:mm:ss", "dd/MM/y HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// om
+static S72: Symbols = Symbols {
+    months: [["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], ["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], ["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"]],
+    standalone_months: [["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], ["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], ["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"]],
+    weekdays: [["Wiixata", "Kibxata", "Roobii", "Kamisa", "Jimaata", "Sanbata", "Dilbata"], ["Wix", "Kib", "Rob", "Kam", "Jim", "San", "Dil"], ["W", "K", "R", "K", "J", "S", "D"]],
+    standalone_weekdays: [["Wiixata", "Kibxata", "Roobii", "Kamisa", "Jimaata", "Sanbata", "Dilbata"], ["Wix", "Kib", "Rob", "Kam", "Jim", "San", "Dil"], ["W", "K", "R", "K", "J", "S", "D"]],
+    am_pm: ["WD", "WB"],
+    eras: ["DhKD", "BA"],
+    dates: ["EEEE, MMMM dd, y", "MMMM dd, y", "MMM dd, y", "MM/dd/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, MMMM dd, y h:mm:ss a 'tti'", "MMMM dd, y h:mm:ss a 'tti'", "MMM dd, y, h:mm:ss a", "MM/dd/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["MMMM dd, y", "MMM dd, y", "MM/dd/yy"], ["EEEE, MMMM dd, y", "E, MMM dd, y", "E, MM/dd/yy"], ["LLLL", "LLL", "L"], ["MMMM dd", "MMM dd", "dd/MM"], ["EEEE, MMMM dd", "E, MMM dd", "E, MM/dd"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// or
+static S73: Symbols = Symbols {
+    months: [["ଜାନୁଆରୀ", "ଫେବୃଆରୀ", "ମାର୍ଚ୍ଚ", "ଅପ୍ରେଲ", "ମଇ", "ଜୁନ", "ଜୁଲାଇ", "ଅଗଷ୍ଟ", "ସେପ୍ଟେମ୍ବର", "ଅକ୍ଟୋବର", "ନଭେମ୍ବର", "ଡିସେମ୍ବର"], ["ଜାନୁଆରୀ", "ଫେବୃଆରୀ", "ମାର୍ଚ୍ଚ", "ଅପ୍ରେଲ", "ମଇ", "ଜୁନ", "ଜୁଲାଇ", "ଅଗଷ୍ଟ", "ସେପ୍ଟେମ୍ବର", "ଅକ୍ଟୋବର", "ନଭେମ୍ବର", "ଡିସେମ୍ବର"], ["ଜା", "ଫେ", "ମା", "ଅ", "ମଇ", "ଜୁ", "ଜୁ", "ଅ", "ସେ", "ଅ", "ନ", "ଡି"]],
+    standalone_months: [["ଜାନୁଆରୀ", "ଫେବୃଆରୀ", "ମାର୍ଚ୍ଚ", "ଅପ୍ରେଲ", "ମଇ", "ଜୁନ", "ଜୁଲାଇ", "ଅଗଷ୍ଟ", "ସେପ୍ଟେମ୍ବର", "ଅକ୍ଟୋବର", "ନଭେମ୍ବର", "ଡିସେମ୍ବର"], ["ଜାନୁଆରୀ", "ଫେବୃଆରୀ", "ମାର୍ଚ୍ଚ", "ଅପ୍ରେଲ", "ମଇ", "ଜୁନ", "ଜୁଲାଇ", "ଅଗଷ୍ଟ", "ସେପ୍ଟେମ୍ବର", "ଅକ୍ଟୋବର", "ନଭେମ୍ବର", "ଡିସେମ୍ବର"], ["ଜା", "ଫେ", "ମା", "ଅ", "ମଇ", "ଜୁ", "ଜୁ", "ଅ", "ସେ", "ଅ", "ନ", "ଡି"]],
+    weekdays: [["ସୋମବାର", "ମଙ୍ଗଳବାର", "ବୁଧବାର", "ଗୁରୁବାର", "ଶୁକ୍ରବାର", "ଶନିବାର", "ରବିବାର"], ["ସୋମ", "ମଙ୍ଗଳ", "ବୁଧ", "ଗୁରୁ", "ଶୁକ୍ର", "ଶନି", "ରବି"], ["ସୋ", "ମ", "ବୁ", "ଗୁ", "ଶୁ", "ଶ", "ର"]],
+    standalone_weekdays: [["ସୋମବାର", "ମଙ୍ଗଳବାର", "ବୁଧବାର", "ଗୁରୁବାର", "ଶୁକ୍ରବାର", "ଶନିବାର", "ରବିବାର"], ["ସୋମ", "ମଙ୍ଗଳ", "ବୁଧ", "ଗୁରୁ", "ଶୁକ୍ର", "ଶନି", "ରବି"], ["ସୋ", "ମ", "ବୁ", "ଗୁ", "ଶୁ", "ଶ", "ର"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["h:mm:ss a ଠାରେ EEEE, MMMM d, y", "h:mm:ss a ଠାରେ MMMM d, y", "MMM d, y, h:mm:ss a", "M/d/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["MMMM d, y", "MMM d, y", "M/d/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "M/d"], ["EEEE, MMMM d", "E, MMM d", "E, M/d"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// pa
+static S74: Symbols = Symbols {
+    months: [["ਜਨਵਰੀ", "ਫ਼ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ੍ਰੈਲ", "ਮਈ", "ਜੂਨ", "ਜੁਲਾਈ", "ਅਗਸਤ", "ਸਤੰਬਰ", "ਅਕਤੂਬਰ", "ਨਵੰਬਰ", "ਦਸੰਬਰ"], ["ਜਨ", "ਫ਼ਰ", "ਮਾਰਚ", "ਅਪ੍ਰੈ", "ਮਈ", "ਜੂਨ", "ਜੁਲਾ", "ਅਗ", "ਸਤੰ", "ਅਕਤੂ", "ਨਵੰ", "ਦਸੰ"], ["ਜ", "ਫ਼", "ਮਾ", "ਅ", "ਮ", "ਜੂ", "ਜੁ", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"]],
+    standalone_months: [["ਜਨਵਰੀ", "ਫ਼ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ੍ਰੈਲ", "ਮਈ", "ਜੂਨ", "ਜੁਲਾਈ", "ਅਗਸਤ", "ਸਤੰਬਰ", "ਅਕਤੂਬਰ", "ਨਵੰਬਰ", "ਦਸੰਬਰ"], ["ਜਨ", "ਫ਼ਰ", "ਮਾਰਚ", "ਅਪ੍ਰੈ", "ਮਈ", "ਜੂਨ", "ਜੁਲਾ", "ਅਗ", "ਸਤੰ", "ਅਕਤੂ", "ਨਵੰ", "ਦਸੰ"], ["ਜ", "ਫ਼", "ਮਾ", "ਅ", "ਮ", "ਜੂ", "ਜੁ", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"]],
+    weekdays: [["ਸੋਮਵਾਰ", "ਮੰਗਲਵਾਰ", "ਬੁੱਧਵਾਰ", "ਵੀਰਵਾਰ", "ਸ਼ੁੱਕਰਵਾਰ", "ਸ਼ਨੀਵਾਰ", "ਐਤਵਾਰ"], ["ਸੋਮ", "ਮੰਗਲ", "ਬੁੱਧ", "ਵੀਰ", "ਸ਼ੁੱਕਰ", "ਸ਼ਨੀ", "ਐਤ"], ["ਸੋ", "ਮੰ", "ਬੁੱ", "ਵੀ", "ਸ਼ੁੱ", "ਸ਼", "ਐ"]],
+    standalone_weekdays: [["ਸੋਮਵਾਰ", "ਮੰਗਲਵਾਰ", "ਬੁੱਧਵਾਰ", "ਵੀਰਵਾਰ", "ਸ਼ੁੱਕਰਵਾਰ", "ਸ਼ਨੀਵਾਰ", "ਐਤਵਾਰ"], ["ਸੋਮ", "ਮੰਗਲ", "ਬੁੱਧ", "ਵੀਰ", "ਸ਼ੁੱਕਰ", "ਸ਼ਨੀ", "ਐਤ"], ["ਸੋ", "ਮੰ", "ਬੁੱ", "ਵੀ", "ਸ਼ੁੱ", "ਸ਼", "ਐ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ਈ. ਪੂ.", "ਸੰਨ"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM y h:mm:ss a", "d MMMM y h:mm:ss a", "d MMM y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, dd-MM."], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// fa
+static S75: Symbols = Symbols {
+    months: [["ژانویهٔ", "فوریهٔ", "مارس", "آوریل", "مهٔ", "ژوئن", "ژوئیهٔ", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], ["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], ["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"]],
+    standalone_months: [["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], ["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], ["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"]],
+    weekdays: [["دوشنبه", "سه\u{200C}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه", "یکشنبه"], ["دوشنبه", "سه\u{200C}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه", "یکشنبه"], ["د", "س", "چ", "پ", "ج", "ش", "ی"]],
+    standalone_weekdays: [["دوشنبه", "سه\u{200C}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه", "یکشنبه"], ["دوشنبه", "سه\u{200C}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه", "یکشنبه"], ["د", "س", "چ", "پ", "ج", "ش", "ی"]],
+    am_pm: ["ق.ظ.", "ب.ظ."],
+    eras: ["ق.م.", "م."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "y/M/d"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE d MMMM y ساعت H:mm:ss", "d MMMM y ساعت H:mm:ss", "d MMM y، H:mm:ss", "y/M/d, H:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "y/M"], ["d MMMM y", "d MMM y", "y/M/d"], ["EEEE d MMMM y", "E d MMM y", "E y/M/d"], ["LLLL", "LLL", "L"], ["d LLLL", "d LLL", "M/d"], ["EEEE d LLLL", "E d LLL", "E M/d"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Saturday,
+};
+
+// pl
+static S76: Symbols = Symbols {
+    months: [["stycznia", "lutego", "marca", "kwietnia", "maja", "czerwca", "lipca", "sierpnia", "września", "października", "listopada", "grudnia"], ["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], ["s", "l", "m", "k", "m", "c", "l", "s", "w", "p", "l", "g"]],
+    standalone_months: [["styczeń", "luty", "marzec", "kwiecień", "maj", "czerwiec", "lipiec", "sierpień", "wrzesień", "październik", "listopad", "grudzień"], ["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], ["S", "L", "M", "K", "M", "C", "L", "S", "W", "P", "L", "G"]],
+    weekdays: [["poniedziałek", "wtorek", "środa", "czwartek", "piątek", "sobota", "niedziela"], ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."], ["p", "w", "ś", "c", "p", "s", "n"]],
+    standalone_weekdays: [["poniedziałek", "wtorek", "środa", "czwartek", "piątek", "sobota", "niedziela"], ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."], ["P", "W", "Ś", "C", "P", "S", "N"]],
+    am_pm: ["AM", "PM"],
+    eras: ["p.n.e.", "n.e."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y HH:mm:ss", "d MMMM y HH:mm:ss", "d MMM y, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["LLLL y", "LLL y", "MM.y"], ["d MMMM y", "d MMM y", "dd.MM.y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd.MM.y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd.MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ps
+static S77: Symbols = Symbols {
+    months: [["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], ["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], ["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"]],
+    standalone_months: [["جنوري", "فېبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], ["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], ["ج", "ف", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["دونۍ", "درېنۍ", "څلرنۍ", "پينځنۍ", "جمعه", "اونۍ", "يونۍ"], ["دونۍ", "درېنۍ", "څلرنۍ", "پينځنۍ", "جمعه", "اونۍ", "يونۍ"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["دونۍ", "درېنۍ", "څلرنۍ", "پينځنۍ", "جمعه", "اونۍ", "يونۍ"], ["دونۍ", "درېنۍ", "څلرنۍ", "پينځنۍ", "جمعه", "اونۍ", "يونۍ"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["غ.م.", "غ.و."],
+    eras: ["له میلاد وړاندې", "م."],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["y MMMM d, EEEE په H:mm:ss", "y MMMM d په H:mm:ss", "y MMM d H:mm:ss", "y-MM-dd H:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["EEEE, MMMM d", "E, MMM d", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Saturday,
+};
+
+// pt
+static S78: Symbols = Symbols {
+    months: [["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], ["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], ["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"], ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."], ["S", "T", "Q", "Q", "S", "S", "D"]],
+    standalone_weekdays: [["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"], ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."], ["S", "T", "Q", "Q", "S", "S", "D"]],
+    am_pm: ["AM", "PM"],
+    eras: ["a.C.", "d.C."],
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "d 'de' MMM 'de' y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d 'de' MMMM 'de' y 'às' HH:mm:ss", "d 'de' MMMM 'de' y 'às' HH:mm:ss", "d 'de' MMM 'de' y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM 'de' y", "MMM 'de' y", "MM/y"], ["d 'de' MMMM 'de' y", "d 'de' MMM 'de' y", "dd/MM/y"], ["EEEE, d 'de' MMMM 'de' y", "E, d 'de' MMM 'de' y", "E, dd/MM/y"], ["LLLL", "LLL", "L"], ["d 'de' MMMM", "d 'de' MMM", "dd/MM"], ["EEEE, d 'de' MMMM", "E, d 'de' MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// qu
+static S79: Symbols = Symbols {
+    months: [["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], ["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], ["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Lunes", "Martes", "Miércoles", "Jueves", "Viernes", "Sábado", "Domingo"], ["Lun", "Mar", "Mié", "Jue", "Vie", "Sab", "Dom"], ["L", "M", "X", "J", "V", "S", "D"]],
+    standalone_weekdays: [["Lunes", "Martes", "Miércoles", "Jueves", "Viernes", "Sábado", "Domingo"], ["Lun", "Mar", "Mié", "Jue", "Vie", "Sab", "Dom"], ["L", "M", "X", "J", "V", "S", "D"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["a.d.", "d.C."],
+    dates: ["EEEE, d MMMM, y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, d MMMM, y h:mm:ss\u{202F}a", "d MMMM y h:mm:ss\u{202F}a", "d MMM y, h:mm:ss\u{202F}a", "dd-MM-y, h:mm\u{202F}a"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "y-MM"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE, d MMMM, y", "E, d MMM, y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "MM-dd"], ["EEEE, d MMMM", "E, d MMM", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// rm
+static S80: Symbols = Symbols {
+    months: [["da schaner", "da favrer", "da mars", "d’avrigl", "da matg", "da zercladur", "da fanadur", "d’avust", "da settember", "d’october", "da november", "da december"], ["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], ["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"]],
+    standalone_months: [["schaner", "favrer", "mars", "avrigl", "matg", "zercladur", "fanadur", "avust", "settember", "october", "november", "december"], ["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], ["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"]],
+    weekdays: [["glindesdi", "mardi", "mesemna", "gievgia", "venderdi", "sonda", "dumengia"], ["gli", "ma", "me", "gie", "ve", "so", "du"], ["G", "M", "M", "G", "V", "S", "D"]],
+    standalone_weekdays: [["glindesdi", "mardi", "mesemna", "gievgia", "venderdi", "sonda", "dumengia"], ["gli", "ma", "me", "gie", "ve", "so", "du"], ["G", "M", "M", "G", "V", "S", "D"]],
+    am_pm: ["AM", "PM"],
+    eras: ["a.Cr.", "s.C."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "dd-MM-y", "dd-MM-yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'a las' HH:mm:ss", "d MMMM y 'a las' HH:mm:ss", "dd-MM-y, HH:mm:ss", "dd-MM-yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["LLLL y", "LL-y", "LL-yy"], ["d MMMM y", "dd-MM-y", "dd-MM-yy"], ["EEEE, d MMMM y", "E, dd-MM-y", "E, dd-MM-yy"], ["LLLL", "LLL", "L"], ["d MMMM", "dd-MM", "dd-MM"], ["EEEE, d MMMM", "E, dd-MM", "E, dd-MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ro
+static S81: Symbols = Symbols {
+    months: [["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], ["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], ["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    standalone_months: [["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], ["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], ["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    weekdays: [["luni", "marți", "miercuri", "joi", "vineri", "sâmbătă", "duminică"], ["lun.", "mar.", "mie.", "joi", "vin.", "sâm.", "dum."], ["L", "M", "M", "J", "V", "S", "D"]],
+    standalone_weekdays: [["luni", "marți", "miercuri", "joi", "vineri", "sâmbătă", "duminică"], ["lun.", "mar.", "mie.", "joi", "vin.", "sâm.", "dum."], ["L", "M", "M", "J", "V", "S", "D"]],
+    am_pm: ["a.m.", "p.m."],
+    eras: ["î.Hr.", "d.Hr."],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'la' HH:mm:ss", "d MMMM y 'la' HH:mm:ss", "d MMM y, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d MMMM y", "d MMM y", "dd.MM.y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd.MM.y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd.MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ru
+static S82: Symbols = Symbols {
+    months: [["января", "февраля", "марта", "апреля", "мая", "июня", "июля", "августа", "сентября", "октября", "ноября", "декабря"], ["янв.", "февр.", "мар.", "апр.", "мая", "июн.", "июл.", "авг.", "сент.", "окт.", "нояб.", "дек."], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    standalone_months: [["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["янв.", "февр.", "март", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    weekdays: [["понедельник", "вторник", "среда", "четверг", "пятница", "суббота", "воскресенье"], ["пн", "вт", "ср", "чт", "пт", "сб", "вс"], ["П", "В", "С", "Ч", "П", "С", "В"]],
+    standalone_weekdays: [["понедельник", "вторник", "среда", "четверг", "пятница", "суббота", "воскресенье"], ["пн", "вт", "ср", "чт", "пт", "сб", "вс"], ["П", "В", "С", "Ч", "П", "С", "В"]],
+    am_pm: ["AM", "PM"],
+    eras: ["до н. э.", "н. э."],
+    dates: ["EEEE, d MMMM y\u{202F}г.", "d MMMM y\u{202F}г.", "d MMM y\u{202F}г.", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y\u{202F}г. в HH:mm:ss", "d MMMM y\u{202F}г. в HH:mm:ss", "d MMM y\u{202F}г., HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["LLLL y\u{202F}г.", "LLL y\u{202F}г.", "MM.y"], ["d MMMM y\u{202F}г.", "d MMM y\u{202F}г.", "dd.MM.y"], ["EEEE, d MMMM y\u{202F}г.", "E, d MMM y\u{202F}г.", "ccc, dd.MM.y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["cccc, d MMMM", "ccc, d MMM", "E, dd.MM"], ["d", "d", "d"], ["cccc, d", "ccc, d", "ccc, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sa
+static S83: Symbols = Symbols {
+    months: [["जनवरीमासः", "फरवरीमासः", "मार्चमासः", "अप्रैलमासः", "मईमासः", "जूनमासः", "जुलाईमासः", "अगस्तमासः", "सितंबरमासः", "अक्तूबरमासः", "नवंबरमासः", "दिसंबरमासः"], ["जनवरी:", "फरवरी:", "मार्च:", "अप्रैल:", "मई", "जून:", "जुलाई:", "अगस्त:", "सितंबर:", "अक्तूबर:", "नवंबर:", "दिसंबर:"], ["ज", "फ", "मा", "अ", "म", "जू", "जु", "अ", "सि", "अ", "न", "दि"]],
+    standalone_months: [["जनवरीमासः", "फरवरीमासः", "मार्चमासः", "अप्रैलमासः", "मईमासः", "जूनमासः", "जुलाईमासः", "अगस्तमासः", "सितंबरमासः", "अक्तूबरमासः", "नवंबरमासः", "दिसंबरमासः"], ["जनवरी:", "फरवरी:", "मार्च:", "अप्रैल:", "मई", "जून:", "जुलाई:", "अगस्त:", "सितंबर:", "अक्तूबर:", "नवंबर:", "दिसंबर:"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["सोमवासरः", "मंगलवासरः", "बुधवासरः", "गुरुवासर:", "शुक्रवासरः", "शनिवासरः", "रविवासरः"], ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    standalone_weekdays: [["सोमवासरः", "मंगलवासरः", "बुधवासरः", "गुरुवासर:", "शुक्रवासरः", "शनिवासरः", "रविवासरः"], ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"], ["सो", "मं", "बु", "गु", "शु", "श", "र"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM y तदा h:mm:ss a", "d MMMM y तदा h:mm:ss a", "d MMM y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// sc
+static S84: Symbols = Symbols {
+    months: [["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], ["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], ["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"]],
+    standalone_months: [["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], ["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], ["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"]],
+    weekdays: [["lunis", "martis", "mèrcuris", "giòbia", "chenàbura", "sàbadu", "domìniga"], ["lun", "mar", "mèr", "giò", "che", "sàb", "dom"], ["L", "M", "M", "G", "C", "S", "D"]],
+    standalone_weekdays: [["lunis", "martis", "mèrcuris", "giòbia", "chenàbura", "sàbadu", "domìniga"], ["lun", "mar", "mèr", "giò", "che", "sàb", "dom"], ["L", "M", "M", "G", "C", "S", "D"]],
+    am_pm: ["AM", "PM"],
+    eras: ["a.C.", "p.C."],
+    dates: ["EEEE d 'de' MMMM y", "d 'de' MMMM y", "d 'de' MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d 'de' MMMM y 'a sas' HH:mm:ss", "d 'de' MMMM y 'a sas' HH:mm:ss", "d 'de' MMM y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM 'de su' y", "MMM y", "MM'/Y'"], ["d 'de' MMMM y", "d 'de' MMM y", "dd/MM/y"], ["EEEE d 'de' MMMM y", "E d 'de' MMM y", "E dd/MM/y"], ["LLLL", "LLL", "L"], ["d 'de' MMMM", "d 'de' MMM", "dd/MM"], ["EEEE d 'de' MMMM", "E d 'de' MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sd
+static S85: Symbols = Symbols {
+    months: [["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاءِ", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], ["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاءِ", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاءِ", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], ["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاءِ", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["سومر", "اڱارو", "اربع", "خميس", "جمعو", "ڇنڇر", "آچر"], ["سومر", "اڱارو", "اربع", "خميس", "جمعو", "ڇنڇر", "آچر"], ["سو", "اڱ", "اربع", "خم", "جم", "ڇن", "آچر"]],
+    standalone_weekdays: [["سومر", "اڱارو", "اربع", "خميس", "جمعو", "ڇنڇر", "آچر"], ["سومر", "اڱارو", "اربع", "خميس", "جمعو", "ڇنڇر", "آچر"], ["سو", "اڱ", "اربع", "خم", "جم", "ڇن", "آچر"]],
+    am_pm: ["صبح", "شام"],
+    eras: ["ق م", "ع"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["y MMMM d, EEEE h:mm:ss a", "y MMMM d h:mm:ss a", "y MMM d h:mm:ss a", "y-MM-dd h:mm a"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// sr
+static S86: Symbols = Symbols {
+    months: [["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], ["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], ["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]],
+    standalone_months: [["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], ["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], ["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]],
+    weekdays: [["понедељак", "уторак", "среда", "четвртак", "петак", "субота", "недеља"], ["пон", "уто", "сре", "чет", "пет", "суб", "нед"], ["п", "у", "с", "ч", "п", "с", "н"]],
+    standalone_weekdays: [["понедељак", "уторак", "среда", "четвртак", "петак", "субота", "недеља"], ["пон", "уто", "сре", "чет", "пет", "суб", "нед"], ["п", "у", "с", "ч", "п", "с", "н"]],
+    am_pm: ["AM", "PM"],
+    eras: ["п. н. е.", "н. е."],
+    dates: ["EEEE, dd. MMMM y.", "dd. MMMM y.", "dd.MM.y.", "d. M. yy."],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, dd. MMMM y. HH:mm:ss", "dd. MMMM y. HH:mm:ss", "dd.MM.y. HH:mm:ss", "d. M. yy. HH:mm"],
+    fields: [["y.", "y.", "yy."], ["MMMM y.", "MM.y.", "M. yy."], ["dd. MMMM y.", "dd.MM.y.", "d. M. yy."], ["EEEE, dd. MMMM y.", "E, dd. MM. y.", "E, d. M. yy."], ["LLLL", "LLL", "L"], ["dd.MMMM", "dd.MM.", "d. M."], ["EEEE, dd. MMMM", "E, dd. MM.", "E, d. M."], ["d", "d", "d"], ["EEEE d.", "E d.", "E d."], ["EEEE", "E", "E"]],
+    first_day: Weekday::Monday,
+};
+
+// gd
+static S87: Symbols = Symbols {
+    months: [["dhen Fhaoilleach", "dhen Ghearran", "dhen Mhàrt", "dhen Ghiblean", "dhen Chèitean", "dhen Ògmhios", "dhen Iuchar", "dhen Lùnastal", "dhen t-Sultain", "dhen Dàmhair", "dhen t-Samhain", "dhen Dùbhlachd"], ["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], ["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"]],
+    standalone_months: [["Am Faoilleach", "An Gearran", "Am Màrt", "An Giblean", "An Cèitean", "An t-Ògmhios", "An t-Iuchar", "An Lùnastal", "An t-Sultain", "An Dàmhair", "An t-Samhain", "An Dùbhlachd"], ["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], ["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"]],
+    weekdays: [["DiLuain", "DiMàirt", "DiCiadain", "DiarDaoin", "DihAoine", "DiSathairne", "DiDòmhnaich"], ["DiL", "DiM", "DiC", "Dia", "Dih", "DiS", "DiD"], ["L", "M", "C", "A", "H", "S", "D"]],
+    standalone_weekdays: [["DiLuain", "DiMàirt", "DiCiadain", "DiarDaoin", "DihAoine", "DiSathairne", "DiDòmhnaich"], ["DiL", "DiM", "DiC", "Dia", "Dih", "DiS", "DiD"], ["L", "M", "C", "A", "H", "S", "D"]],
+    am_pm: ["m", "f"],
+    eras: ["RC", "AD"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'aig' HH:mm:ss", "d MMMM y 'aig' HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["LLLL y", "LLL 'Y'", "LL/y"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd/MM/y"], ["LLLL", "LLL", "L"], ["d'mh' MMMM", "d MMM", "dd/MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// si
+static S88: Symbols = Symbols {
+    months: [["දුරුතු", "නවම්", "මැදින්", "බක්", "වෙසක්", "පොසොන්", "ඇසළ", "නිකිණි", "බිනර", "වප්", "ඉල්", "උඳුවප්"], ["දුරුතු", "නවම්", "මැදින්", "බක්", "වෙසක්", "පොසොන්", "ඇසළ", "නිකිණි", "බිනර", "වප්", "ඉල්", "උඳුවප්"], ["දු", "න", "මැ", "බ", "වෙ", "පො", "ඇ", "නි", "බි", "ව", "ඉ", "උ"]],
+    standalone_months: [["දුරුතු", "නවම්", "මැදින්", "බක්", "වෙසක්", "පොසොන්", "ඇසළ", "නිකිණි", "බිනර", "වප්", "ඉල්", "උඳුවප්"], ["දුරුතු", "නවම්", "මැදින්", "බක්", "වෙසක්", "පොසොන්", "ඇසළ", "නිකිණි", "බිනර", "වප්", "ඉල්", "උඳුවප්"], ["දු", "න", "මැ", "බ", "වෙ", "පො", "ඇ", "නි", "බි", "ව", "ඉ", "උ"]],
+    weekdays: [["සඳුදා", "අඟහරුවාදා", "බදාදා", "බ්\u{200D}රහස්පතින්දා", "සිකුරාදා", "සෙනසුරාදා", "ඉරිදා"], ["සඳුදා", "අඟහ", "බදාදා", "බ්\u{200D}රහස්", "සිකු", "සෙන", "ඉරිදා"], ["ස", "අ", "බ", "බ්\u{200D}ර", "සි", "සෙ", "ඉ"]],
+    standalone_weekdays: [["සඳුදා", "අඟහරුවාදා", "බදාදා", "බ්\u{200D}රහස්පතින්දා", "සිකුරාදා", "සෙනසුරාදා", "ඉරිදා"], ["සඳුදා", "අඟහ", "බදාදා", "බ්\u{200D}රහස්", "සිකු", "සෙන", "ඉරිදා"], ["ස", "අ", "බ", "බ්\u{200D}ර", "සි", "සෙ", "ඉ"]],
+    am_pm: ["පෙ.ව.", "ප.ව."],
+    eras: ["ක්\u{200D}රි.පූ.", "ක්\u{200D}රි.ව."],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE දින HH:mm:ss", "y MMMM d දින HH:mm:ss", "y MMM d, HH:mm:ss", "y-MM-dd, HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d EEEE", "MMM d E", "MM-dd, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sk
+static S89: Symbols = Symbols {
+    months: [["januára", "februára", "marca", "apríla", "mája", "júna", "júla", "augusta", "septembra", "októbra", "novembra", "decembra"], ["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    standalone_months: [["január", "február", "marec", "apríl", "máj", "jún", "júl", "august", "september", "október", "november", "december"], ["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    weekdays: [["pondelok", "utorok", "streda", "štvrtok", "piatok", "sobota", "nedeľa"], ["po", "ut", "st", "št", "pi", "so", "ne"], ["p", "u", "s", "š", "p", "s", "n"]],
+    standalone_weekdays: [["pondelok", "utorok", "streda", "štvrtok", "piatok", "sobota", "nedeľa"], ["po", "ut", "st", "št", "pi", "so", "ne"], ["p", "u", "s", "š", "p", "s", "n"]],
+    am_pm: ["AM", "PM"],
+    eras: ["pred Kr.", "po Kr."],
+    dates: ["EEEE d. M. y", "d. MMMM y", "d. M. y", "d. M. y"],
+    times: ["H:mm:ss", "H:mm:ss", "H:mm:ss", "H:mm"],
+    date_times: ["EEEE d. M. y 'o' H:mm:ss", "d. MMMM y 'o' H:mm:ss", "d. M. y, H:mm:ss", "d. M. y H:mm"],
+    fields: [["y", "y", "y"], ["LLLL y", "M/y", "M/y"], ["d. MMMM y", "d. M. y", "d. M. y"], ["EEEE d. M. y", "E d. M. y", "E d. M. y"], ["LLLL", "LLL", "L."], ["d. MMMM", "d. M.", "d. M."], ["EEEE d. MMMM", "E d. M.", "E d. M."], ["d.", "d.", "d."], ["EEEE d.", "E d.", "E d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sl
+static S90: Symbols = Symbols {
+    months: [["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], ["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    standalone_months: [["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], ["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], ["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]],
+    weekdays: [["ponedeljek", "torek", "sreda", "četrtek", "petek", "sobota", "nedelja"], ["pon.", "tor.", "sre.", "čet.", "pet.", "sob.", "ned."], ["p", "t", "s", "č", "p", "s", "n"]],
+    standalone_weekdays: [["ponedeljek", "torek", "sreda", "četrtek", "petek", "sobota", "nedelja"], ["pon.", "tor.", "sre.", "čet.", "pet.", "sob.", "ned."], ["p", "t", "s", "č", "p", "s", "n"]],
+    am_pm: ["dop.", "pop."],
+    eras: ["pr. Kr.", "po Kr."],
+    dates: ["EEEE, dd. MMMM y", "dd. MMMM y", "d. MMM y", "d. MM. yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, dd. MMMM y 'ob' HH:mm:ss", "dd. MMMM y 'ob' HH:mm:ss", "d. MMM y, HH:mm:ss", "d. MM. yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["dd. MMMM y", "d. MMM y", "d. MM. yy"], ["EEEE, dd. MMMM y", "E, d. MMM y", "E, d. MM. yy"], ["LLLL", "LLL", "L"], ["dd. MMMM", "d. MMM", "d. MM."], ["EEEE, dd. MMMM", "E, d. MMM", "E, d. MM."], ["d.", "d.", "d."], ["EEEE, d.", "E, d.", "E, d."], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// so
+static S91: Symbols = Symbols {
+    months: [["Janaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Agosto", "Sebtembar", "Oktoobar", "Noofeembar", "Diseembar"], ["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], ["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"]],
+    standalone_months: [["Jannaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Ogosto", "Sebteembar", "Oktoobar", "Noofeembar", "Diseembar"], ["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], ["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"]],
+    weekdays: [["Isniin", "Talaado", "Arbaco", "Khamiis", "Jimco", "Sabti", "Axad"], ["Isn", "Tldo", "Arbc", "Khms", "Jmc", "Sbti", "Axd"], ["I", "T", "A", "Kh", "J", "S", "A"]],
+    standalone_weekdays: [["Isniin", "Talaado", "Arbaco", "Khamiis", "Jimco", "Sabti", "Axad"], ["Isn", "Tldo", "Arbc", "Khms", "Jmc", "Sbti", "Axd"], ["I", "T", "A", "Kh", "J", "S", "A"]],
+    am_pm: ["GH", "GD"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM dd, y", "MMMM dd, y", "MMM dd, y", "MM/dd/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, MMMM dd, y 'ee' h:mm:ss a", "MMMM dd, y 'ee' h:mm:ss a", "MMM dd, y 'ee' h:mm:ss a", "MM/dd/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["MMMM dd, y", "MMM dd, y", "MM/dd/yy"], ["EEEE, MMMM dd, y", "E, MMM dd, y", "E, MM/dd/yy"], ["LLLL", "LLL", "L"], ["MMMM dd", "MMM dd", "MM/dd"], ["EEEE, MMMM dd", "E, MMM dd", "E, MM/dd"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// st
+static S92: Symbols = Symbols {
+    months: [["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], ["Phe", "Kol", "Hlb", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], ["Phe", "Kol", "Hlb", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Mantaha", "Labobedi", "Laboraro", "Labone", "Labohlano", "Moqebelo", "Sontaha"], ["Mma", "Bed", "Rar", "Ne", "Hla", "Moq", "Son"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Mantaha", "Labobedi", "Laboraro", "Labone", "Labohlano", "Moqebelo", "Sontaha"], ["Mma", "Bed", "Rar", "Ne", "Hla", "Moq", "Son"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE HH:mm:ss", "y MMMM d HH:mm:ss", "y MMM d HH:mm:ss", "y-MM-dd HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// es
+static S93: Symbols = Symbols {
+    months: [["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"], ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"], ["L", "M", "X", "J", "V", "S", "D"]],
+    standalone_weekdays: [["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"], ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"], ["L", "M", "X", "J", "V", "S", "D"]],
+    am_pm: ["a.\u{202F}m.", "p.\u{202F}m."],
+    eras: ["a. C.", "d. C."],
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "d MMM y", "d/M/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d 'de' MMMM 'de' y 'a las' HH:mm:ss", "d 'de' MMMM 'de' y 'a las' HH:mm:ss", "d MMM y, HH:mm:ss", "d/M/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM 'de' y", "MMM y", "M/yy"], ["d 'de' MMMM 'de' y", "d MMM y", "d/M/yy"], ["EEEE, d 'de' MMMM 'de' y", "EEE, d MMM y", "EEE, d/M/yy"], ["LLLL", "LLL", "L"], ["d 'de' MMMM", "d MMM", "d/M"], ["EEEE, d 'de' MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// su
+static S94: Symbols = Symbols {
+    months: [["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], ["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], ["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], ["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], ["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Senén", "Salasa", "Rebo", "Kemis", "Jumaah", "Saptu", "Minggu"], ["Sen", "Sal", "Reb", "Kem", "Jum", "Sap", "Mng"], ["S", "S", "R", "K", "J", "S", "M"]],
+    standalone_weekdays: [["Senén", "Salasa", "Rebo", "Kemis", "Jumaah", "Saptu", "Minggu"], ["Sen", "Sal", "Reb", "Kem", "Jum", "Sap", "Mng"], ["S", "S", "R", "K", "J", "S", "M"]],
+    am_pm: ["AM", "PM"],
+    eras: ["SM", "M"],
+    dates: ["EEEE, d MMMM y", "d MMMM, y", "d MMM, y", "d/M/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'jam' HH:mm:ss", "d MMMM, y 'jam' HH:mm:ss", "d MMM, y, HH:mm:ss", "d/M/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "d/M/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE, d MMMM", "E, d MMM", "E, d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// sw
+static S95: Symbols = Symbols {
+    months: [["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], ["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], ["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi", "Jumapili"], ["Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi", "Jumapili"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi", "Jumapili"], ["Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi", "Jumapili"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["KK", "BK"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y, HH:mm:ss", "d MMMM y, HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["d MMMM y", "d MMM y", "dd/MM/y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd/MM/y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// sv
+static S96: Symbols = Symbols {
+    months: [["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], ["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], ["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag"], ["mån", "tis", "ons", "tors", "fre", "lör", "sön"], ["M", "T", "O", "T", "F", "L", "S"]],
+    standalone_weekdays: [["måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag"], ["mån", "tis", "ons", "tors", "fre", "lör", "sön"], ["M", "T", "O", "T", "F", "L", "S"]],
+    am_pm: ["fm", "em"],
+    eras: ["f.Kr.", "e.Kr."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'kl.' HH:mm:ss", "d MMMM y 'kl.' HH:mm:ss", "d MMM y HH:mm:ss", "y-MM-dd HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "y-MM"], ["d MMMM y", "d MMM y", "y-MM-dd"], ["EEEE d MMMM y", "E d MMM y", "E, y-MM-dd"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd/MM"], ["EEEE d MMMM", "E d MMM", "E dd/MM"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ta
+static S97: Symbols = Symbols {
+    months: [["ஜனவரி", "பிப்ரவரி", "மார்ச்", "ஏப்ரல்", "மே", "ஜூன்", "ஜூலை", "ஆகஸ்ட்", "செப்டம்பர்", "அக்டோபர்", "நவம்பர்", "டிசம்பர்"], ["ஜன.", "பிப்.", "மார்.", "ஏப்.", "மே", "ஜூன்", "ஜூலை", "ஆக.", "செப்.", "அக்.", "நவ.", "டிச."], ["ஜ", "பி", "மா", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"]],
+    standalone_months: [["ஜனவரி", "பிப்ரவரி", "மார்ச்", "ஏப்ரல்", "மே", "ஜூன்", "ஜூலை", "ஆகஸ்ட்", "செப்டம்பர்", "அக்டோபர்", "நவம்பர்", "டிசம்பர்"], ["ஜன.", "பிப்.", "மார்.", "ஏப்.", "மே", "ஜூன்", "ஜூலை", "ஆக.", "செப்.", "அக்.", "நவ.", "டிச."], ["ஜ", "பி", "மா", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"]],
+    weekdays: [["திங்கள்", "செவ்வாய்", "புதன்", "வியாழன்", "வெள்ளி", "சனி", "ஞாயிறு"], ["திங்.", "செவ்.", "புத.", "வியா.", "வெள்.", "சனி", "ஞாயி."], ["தி", "செ", "பு", "வி", "வெ", "ச", "ஞா"]],
+    standalone_weekdays: [["திங்கள்", "செவ்வாய்", "புதன்", "வியாழன்", "வெள்ளி", "சனி", "ஞாயிறு"], ["திங்.", "செவ்.", "புத.", "வியா.", "வெள்.", "சனி", "ஞாயி."], ["தி", "செ", "பு", "வி", "வெ", "ச", "ஞா"]],
+    am_pm: ["AM", "PM"],
+    eras: ["கி.மு.", "கி.பி."],
+    dates: ["EEEE, d MMMM, y", "d MMMM, y", "d MMM, y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, d MMMM, y அன்று h:mm:ss a", "d MMMM, y அன்று h:mm:ss a", "d MMM, y, h:mm:ss a", "d/M/yy, h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM, y", "d MMM, y", "d/M/yy"], ["EEEE, d MMMM, y", "E, d MMM, y", "E, d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "MMM d", "d/M"], ["MMMM d, EEEE", "MMM d, E", "dd-MM, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// te
+static S98: Symbols = Symbols {
+    months: [["జనవరి", "ఫిబ్రవరి", "మార్చి", "ఏప్రిల్", "మే", "జూన్", "జులై", "ఆగస్టు", "సెప్టెంబర్", "అక్టోబర్", "నవంబర్", "డిసెంబర్"], ["జన", "ఫిబ్ర", "మార్చి", "ఏప్రి", "మే", "జూన్", "జులై", "ఆగ", "సెప్టెం", "అక్టో", "నవం", "డిసెం"], ["జ", "ఫి", "మా", "ఏ", "మే", "జూ", "జు", "ఆ", "సె", "అ", "న", "డి"]],
+    standalone_months: [["జనవరి", "ఫిబ్రవరి", "మార్చి", "ఏప్రిల్", "మే", "జూన్", "జులై", "ఆగస్టు", "సెప్టెంబర్", "అక్టోబర్", "నవంబర్", "డిసెంబర్"], ["జన", "ఫిబ్ర", "మార్చి", "ఏప్రి", "మే", "జూన్", "జులై", "ఆగ", "సెప్టెం", "అక్టో", "నవం", "డిసెం"], ["జ", "ఫి", "మా", "ఏ", "మే", "జూ", "జు", "ఆ", "సె", "అ", "న", "డి"]],
+    weekdays: [["సోమవారం", "మంగళవారం", "బుధవారం", "గురువారం", "శుక్రవారం", "శనివారం", "ఆదివారం"], ["సోమ", "మంగళ", "బుధ", "గురు", "శుక్ర", "శని", "ఆది"], ["సో", "మ", "బు", "గు", "శు", "శ", "ఆ"]],
+    standalone_weekdays: [["సోమవారం", "మంగళవారం", "బుధవారం", "గురువారం", "శుక్రవారం", "శనివారం", "ఆదివారం"], ["సోమ", "మంగళ", "బుధ", "గురు", "శుక్ర", "శని", "ఆది"], ["సో", "మ", "బు", "గు", "శు", "శ", "ఆ"]],
+    am_pm: ["AM", "PM"],
+    eras: ["క్రీపూ", "క్రీశ"],
+    dates: ["d MMMM, y, EEEE", "d, MMMM y", "d, MMM y", "dd/MM/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["d MMMM, y, EEEE h:mm:ss aకి", "d, MMMM y h:mm:ss aకి", "d, MMM y h:mm:ss a", "dd/MM/yy h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM-yy"], ["d, MMMM y", "d, MMM y", "dd/MM/yy"], ["d MMMM, y, EEEE", "d MMM, y, E", "dd/MM/yy, E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["d MMMM, EEEE", "d MMM, E", "dd/MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// tg
+static S99: Symbols = Symbols {
+    months: [["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], ["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    standalone_months: [["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], ["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]],
+    weekdays: [["Душанбе", "Сешанбе", "Чоршанбе", "Панҷшанбе", "Ҷумъа", "Шанбе", "Якшанбе"], ["Дшб", "Сшб", "Чшб", "Пшб", "Ҷмъ", "Шнб", "Яшб"], ["Д", "С", "Ч", "П", "Ҷ", "Ш", "Я"]],
+    standalone_weekdays: [["Душанбе", "Сешанбе", "Чоршанбе", "Панҷшанбе", "Ҷумъа", "Шанбе", "Якшанбе"], ["Дшб", "Сшб", "Чшб", "Пшб", "Ҷмъ", "Шнб", "Яшб"], ["Д", "С", "Ч", "П", "Ҷ", "Ш", "Я"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ПеМ", "ПаМ"],
+    dates: ["EEEE, dd MMMM, y", "dd MMMM y", "dd MMM y", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, dd MMMM, y соати HH:mm:ss", "dd MMMM y соати HH:mm:ss", "dd MMM y, HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM-yy"], ["dd MMMM y", "dd MMM y", "dd.MM.yy"], ["EEEE, dd MMMM, y", "E, dd MMM, y", "E, dd/MM/yy"], ["LLLL", "LLL", "L"], ["dd MMMM", "dd MMM", "dd-MM"], ["EEEE, dd MMMM", "E, dd MMM", "E, dd-MM"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// th
+static S100: Symbols = Symbols {
+    months: [["มกราคม", "กุมภาพันธ์", "มีนาคม", "เมษายน", "พฤษภาคม", "มิถุนายน", "กรกฎาคม", "สิงหาคม", "กันยายน", "ตุลาคม", "พฤศจิกายน", "ธันวาคม"], ["ม.ค.", "ก.พ.", "มี.ค.", "เม.ย.", "พ.ค.", "มิ.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], ["ม.ค.", "ก.พ.", "มี.ค.", "เม.ย.", "พ.ค.", "มิ.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."]],
+    standalone_months: [["มกราคม", "กุมภาพันธ์", "มีนาคม", "เมษายน", "พฤษภาคม", "มิถุนายน", "กรกฎาคม", "สิงหาคม", "กันยายน", "ตุลาคม", "พฤศจิกายน", "ธันวาคม"], ["ม.ค.", "ก.พ.", "มี.ค.", "เม.ย.", "พ.ค.", "มิ.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], ["ม.ค.", "ก.พ.", "มี.ค.", "เม.ย.", "พ.ค.", "มิ.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."]],
+    weekdays: [["วันจันทร์", "วันอังคาร", "วันพุธ", "วันพฤหัสบดี", "วันศุกร์", "วันเสาร์", "วันอาทิตย์"], ["จันทร์", "อังคาร", "พุธ", "พฤหัส", "ศุกร์", "เสาร์", "อาทิตย์"], ["จ", "อ", "พ", "พฤ", "ศ", "ส", "อา"]],
+    standalone_weekdays: [["วันจันทร์", "วันอังคาร", "วันพุธ", "วันพฤหัสบดี", "วันศุกร์", "วันเสาร์", "วันอาทิตย์"], ["จันทร์", "อังคาร", "พุธ", "พฤหัส", "ศุกร์", "เสาร์", "อาทิตย์"], ["จ", "อ", "พ", "พฤ", "ศ", "ส", "อา"]],
+    am_pm: ["AM", "PM"],
+    eras: ["ก่อน ค.ศ.", "ค.ศ."],
+    dates: ["EEEEที่ d MMMM GGG y", "d MMMM GGG y", "d MMM y", "d/M/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEEที่ d MMMM GGG y เวลา HH:mm:ss", "d MMMM GGG y เวลา HH:mm:ss", "d MMM y HH:mm:ss", "d/M/yy HH:mm"],
+    fields: [["GGG y", "y", "yy"], ["MMMM GGG y", "MMM y", "M/yy"], ["d MMMM GGG y", "d MMM y", "d/M/yy"], ["EEEEที่ d MMMM GGG y", "E d MMM y", "E d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEEที่ d MMMM", "E d MMM", "E d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// ti
+static S101: Symbols = Symbols {
+    months: [["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], ["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], ["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]],
+    standalone_months: [["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], ["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], ["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]],
+    weekdays: [["ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም", "ሰንበት"], ["ሰኑ", "ሰሉ", "ረቡ", "ሓሙ", "ዓር", "ቀዳ", "ሰን"], ["ሰ", "ሰ", "ረ", "ሓ", "ዓ", "ቀ", "ሰ"]],
+    standalone_weekdays: [["ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም", "ሰንበት"], ["ሰኑ", "ሰሉ", "ረቡ", "ሓሙ", "ዓር", "ቀዳ", "ሰን"], ["ሰ", "ሰ", "ረ", "ሓ", "ዓ", "ቀ", "ሰ"]],
+    am_pm: ["ቅ.ቀ.", "ድ.ቀ."],
+    eras: ["ዓ/ዓ", "ዓ/ም"],
+    dates: ["EEEE, MMMM dd, y", "dd MMMM y", "dd MMM y", "MM/dd/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE, MMMM dd, y ሰዓት h:mm:ss a", "dd MMMM y ሰዓት h:mm:ss a", "dd MMM y h:mm:ss a", "MM/dd/yy h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["dd MMMM y", "dd MMM y", "MM/dd/yy"], ["EEEE, MMMM dd, y", "E, MMM dd, y", "E፣ dd/MM/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "dd MMM", "d/M"], ["EEEE፣ dd MMMM", "E፣ dd MMM", "E፣ dd/MM"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// tk
+static S102: Symbols = Symbols {
+    months: [["ýanwar", "fewral", "mart", "aprel", "maý", "iýun", "iýul", "awgust", "sentýabr", "oktýabr", "noýabr", "dekabr"], ["ýan", "few", "mart", "apr", "maý", "iýun", "iýul", "awg", "sen", "okt", "noý", "dek"], ["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Ýanwar", "Fewral", "Mart", "Aprel", "Maý", "Iýun", "Iýul", "Awgust", "Sentýabr", "Oktýabr", "Noýabr", "Dekabr"], ["Ýan", "Few", "Mar", "Apr", "Maý", "Iýun", "Iýul", "Awg", "Sen", "Okt", "Noý", "Dek"], ["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    weekdays: [["duşenbe", "sişenbe", "çarşenbe", "penşenbe", "anna", "şenbe", "ýekşenbe"], ["duş", "siş", "çar", "pen", "ann", "şen", "ýek"], ["D", "S", "Ç", "P", "A", "Ş", "Ý"]],
+    standalone_weekdays: [["Duşenbe", "Sişenbe", "Çarşenbe", "Penşenbe", "Anna", "Şenbe", "Ýekşenbe"], ["Duş", "Siş", "Çar", "Pen", "Ann", "Şen", "Ýek"], ["D", "S", "Ç", "P", "A", "Ş", "Ý"]],
+    am_pm: ["go.öň", "go.soň"],
+    eras: ["B.e.öň", "B.e."],
+    dates: ["d MMMM y EEEE", "d MMMM y", "d MMM y", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["d MMMM y EEEE 'sagat' HH:mm:ss", "d MMMM y 'sagat' HH:mm:ss", "d MMM y, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d MMMM y", "d MMM y", "dd.MM.y"], ["d MMMM y EEEE", "d MMM y E", "dd.MM.y E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["d MMMM EEEE", "d MMM E", "dd.MM E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// tl
+static S103: Symbols = Symbols {
+    months: [["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], ["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], ["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"]],
+    standalone_months: [["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], ["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], ["E", "P", "M", "A", "M", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"]],
+    weekdays: [["Lunes", "Martes", "Miyerkules", "Huwebes", "Biyernes", "Sabado", "Linggo"], ["Lun", "Mar", "Miy", "Huw", "Biy", "Sab", "Lin"], ["Lun", "Mar", "Miy", "Huw", "Biy", "Sab", "Lin"]],
+    standalone_weekdays: [["Lunes", "Martes", "Miyerkules", "Huwebes", "Biyernes", "Sabado", "Linggo"], ["Lun", "Mar", "Miy", "Huw", "Biy", "Sab", "Lin"], ["Lun", "Mar", "Miy", "Huw", "Biy", "Sab", "Lin"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE, MMMM d, y 'nang' h:mm:ss\u{202F}a", "MMMM d, y 'nang' h:mm:ss\u{202F}a", "MMM d, y, h:mm:ss\u{202F}a", "M/d/yy, h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["MMMM d, y", "MMM d, y", "M/d/yy"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, M/d/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "M/d"], ["EEEE, MMMM d", "E, MMM d", "E, M/d"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// tn
+static S104: Symbols = Symbols {
+    months: [["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], ["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], ["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Mosupologo", "Labobedi", "Laboraro", "Labone", "Labotlhano", "Matlhatso", "Tshipi"], ["Mos", "Labb", "Labr", "Labn", "Labt", "Mat", "Tsh"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["Mosupologo", "Labobedi", "Laboraro", "Labone", "Labotlhano", "Matlhatso", "Tshipi"], ["Mos", "Labb", "Labr", "Labn", "Labt", "Mat", "Tsh"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE 'ka' HH:mm:ss", "y MMMM d 'ka' HH:mm:ss", "y MMM d, HH:mm:ss", "y-MM-dd, HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["y MMMM d", "y MMM d", "y-MM-dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// to
+static S105: Symbols = Symbols {
+    months: [["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sēpitema", "ʻOkatopa", "Nōvema", "Tīsema"], ["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], ["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"]],
+    standalone_months: [["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sepitema", "ʻOkatopa", "Nōvema", "Tīsema"], ["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], ["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"]],
+    weekdays: [["Mōnite", "Tūsite", "Pulelulu", "Tuʻapulelulu", "Falaite", "Tokonaki", "Sāpate"], ["Mōn", "Tūs", "Pul", "Tuʻa", "Fal", "Tok", "Sāp"], ["M", "T", "P", "T", "F", "T", "S"]],
+    standalone_weekdays: [["Mōnite", "Tūsite", "Pulelulu", "Tuʻapulelulu", "Falaite", "Tokonaki", "Sāpate"], ["Mōn", "Tūs", "Pul", "Tuʻa", "Fal", "Tok", "Sāp"], ["M", "T", "P", "T", "F", "T", "S"]],
+    am_pm: ["HH", "EA"],
+    eras: ["KM", "TS"],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "d/M/yy"],
+    times: ["h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a", "h:mm\u{202F}a"],
+    date_times: ["EEEE d MMMM y, h:mm:ss\u{202F}a", "d MMMM y, h:mm:ss\u{202F}a", "d MMM y, h:mm:ss\u{202F}a", "d/M/yy h:mm\u{202F}a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM y", "d MMM y", "d/M/yy"], ["EEEE d MMMM y", "E d MMM y", "E d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE d MMMM", "E d MMM", "E d/M"], ["d", "d", "d"], ["EEEE d", "E d", "E d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// tr
+static S106: Symbols = Symbols {
+    months: [["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], ["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], ["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"]],
+    standalone_months: [["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], ["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], ["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"]],
+    weekdays: [["Pazartesi", "Salı", "Çarşamba", "Perşembe", "Cuma", "Cumartesi", "Pazar"], ["Pzt", "Sal", "Çar", "Per", "Cum", "Cmt", "Paz"], ["P", "S", "Ç", "P", "C", "C", "P"]],
+    standalone_weekdays: [["Pazartesi", "Salı", "Çarşamba", "Perşembe", "Cuma", "Cumartesi", "Pazar"], ["Pzt", "Sal", "Çar", "Per", "Cum", "Cmt", "Paz"], ["P", "S", "Ç", "P", "C", "C", "P"]],
+    am_pm: ["ÖÖ", "ÖS"],
+    eras: ["MÖ", "MS"],
+    dates: ["d MMMM y EEEE", "d MMMM y", "d MMM y", "d.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["d MMMM y EEEE HH:mm:ss", "d MMMM y HH:mm:ss", "d MMM y HH:mm:ss", "d.MM.y HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM.y"], ["d MMMM y", "d MMM y", "d.MM.y"], ["d MMMM y EEEE", "d MMM y E", "d.MM.y E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/MM"], ["d MMMM EEEE", "d MMM E", "d/MM E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// tt
+static S107: Symbols = Symbols {
+    months: [["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], ["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["дүшәмбе", "сишәмбе", "чәршәмбе", "пәнҗешәмбе", "җомга", "шимбә", "якшәмбе"], ["дүш.", "сиш.", "чәр.", "пәнҗ.", "җом.", "шим.", "якш."], ["Д", "С", "Ч", "П", "Җ", "Ш", "Я"]],
+    standalone_weekdays: [["дүшәмбе", "сишәмбе", "чәршәмбе", "пәнҗешәмбе", "җомга", "шимбә", "якшәмбе"], ["дүш.", "сиш.", "чәр.", "пәнҗ.", "җом.", "шим.", "якш."], ["Д", "С", "Ч", "П", "Җ", "Ш", "Я"]],
+    am_pm: ["AM", "PM"],
+    eras: ["б.э.к.", "милади"],
+    dates: ["y\u{202F}ел, d MMMM, EEEE", "y\u{202F}ел, d MMMM", "y\u{202F}ел, d MMM", "dd.MM.y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y\u{202F}ел, d MMMM, EEEE HH:mm:ss сәгатьтә", "y\u{202F}ел, d MMMM HH:mm:ss сәгатьтә", "y\u{202F}ел, d MMM, HH:mm:ss", "dd.MM.y, HH:mm"],
+    fields: [["y", "y", "y"], ["y\u{202F}ел, MMMM", "y\u{202F}ел, MMM", "MM.y"], ["y\u{202F}ел, d MMMM", "y\u{202F}ел, d MMM", "dd.MM.y"], ["y\u{202F}ел, d MMMM, EEEE", "y\u{202F}ел, d MMM, E", "dd.MM.y, E"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd.MM"], ["d MMMM, EEEE", "d MMM, E", "dd.MM, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ug
+static S108: Symbols = Symbols {
+    months: [["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], ["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], ["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["دۈشەنبە", "سەيشەنبە", "چارشەنبە", "پەيشەنبە", "جۈمە", "شەنبە", "يەكشەنبە"], ["دۈ", "سە", "چا", "پە", "جۈ", "شە", "يە"], ["د", "س", "چ", "پ", "ج", "ش", "ي"]],
+    standalone_weekdays: [["دۈشەنبە", "سەيشەنبە", "چارشەنبە", "پەيشەنبە", "جۈمە", "شەنبە", "يەكشەنبە"], ["دۈ", "سە", "چا", "پە", "جۈ", "شە", "يە"], ["د", "س", "چ", "پ", "ج", "ش", "ي"]],
+    am_pm: ["چ.ب", "چ.ك"],
+    eras: ["BCE", "مىلادىيە"],
+    dates: ["y d-MMMM، EEEE", "y d-MMMM", "y d-MMM", "y-dd-MM"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["y d-MMMM، EEEE h:mm:ss a", "y d-MMMM h:mm:ss a", "y d-MMM، h:mm:ss a", "y-dd-MM، h:mm a"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "MM-y"], ["y d-MMMM", "y d-MMM", "y-dd-MM"], ["y d-MMMM، EEEE", "y d-MMM، E", "y-dd-MM، E"], ["LLLL", "LLL", "L"], ["d-MMMM", "d-MMM", "dd-MM"], ["d-MMMM، EEEE", "d-MMM، E", "dd-MM، E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// uk
+static S109: Symbols = Symbols {
+    months: [["січня", "лютого", "березня", "квітня", "травня", "червня", "липня", "серпня", "вересня", "жовтня", "листопада", "грудня"], ["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], ["с", "л", "б", "к", "т", "ч", "л", "с", "в", "ж", "л", "г"]],
+    standalone_months: [["січень", "лютий", "березень", "квітень", "травень", "червень", "липень", "серпень", "вересень", "жовтень", "листопад", "грудень"], ["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], ["С", "Л", "Б", "К", "Т", "Ч", "Л", "С", "В", "Ж", "Л", "Г"]],
+    weekdays: [["понеділок", "вівторок", "середа", "четвер", "пʼятниця", "субота", "неділя"], ["пн", "вт", "ср", "чт", "пт", "сб", "нд"], ["П", "В", "С", "Ч", "П", "С", "Н"]],
+    standalone_weekdays: [["понеділок", "вівторок", "середа", "четвер", "пʼятниця", "субота", "неділя"], ["пн", "вт", "ср", "чт", "пт", "сб", "нд"], ["П", "В", "С", "Ч", "П", "С", "Н"]],
+    am_pm: ["дп", "пп"],
+    eras: ["до н. е.", "н. е."],
+    dates: ["EEEE, d MMMM y\u{202F}р.", "d MMMM y\u{202F}р.", "d MMM y\u{202F}р.", "dd.MM.yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y\u{202F}р. о HH:mm:ss", "d MMMM y\u{202F}р. о HH:mm:ss", "d MMM y\u{202F}р., HH:mm:ss", "dd.MM.yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["LLLL y\u{202F}р.", "LLL y\u{202F}р.", "MM.yy"], ["d MMMM y\u{202F}р.", "d MMM y\u{202F}р.", "dd.MM.yy"], ["EEEE, d MMMM y\u{202F}р.", "E, d MMM y\u{202F}р.", "E, dd.MM.yy"], ["LLLL", "LLL", "LL"], ["d MMMM", "d MMM", "dd.MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd.MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// ur
+static S110: Symbols = Symbols {
+    months: [["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], ["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], ["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["پیر", "منگل", "بدھ", "جمعرات", "جمعہ", "ہفتہ", "اتوار"], ["پیر", "منگل", "بدھ", "جمعرات", "جمعہ", "ہفتہ", "اتوار"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["پیر", "منگل", "بدھ", "جمعرات", "جمعہ", "ہفتہ", "اتوار"], ["پیر", "منگل", "بدھ", "جمعرات", "جمعہ", "ہفتہ", "اتوار"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["قبل مسیح", "عیسوی"],
+    dates: ["EEEE، d MMMM، y", "d MMMM، y", "d MMM، y", "d/M/yy"],
+    times: ["h:mm:ss a", "h:mm:ss a", "h:mm:ss a", "h:mm a"],
+    date_times: ["EEEE، d MMMM، y کو h:mm:ss a", "d MMMM، y کو h:mm:ss a", "d MMM، y، h:mm:ss a", "d/M/yy، h:mm a"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "M/yy"], ["d MMMM، y", "d MMM، y", "d/M/yy"], ["EEEE، d MMMM، y", "E، d MMM، y", "E، d/M/yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "d/M"], ["EEEE، d MMMM", "E، d MMM", "E، d/M"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// uz
+static S111: Symbols = Symbols {
+    months: [["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avgust", "sentabr", "oktabr", "noyabr", "dekabr"], ["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avg", "sen", "okt", "noy", "dek"], ["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Yanvar", "Fevral", "Mart", "Aprel", "May", "Iyun", "Iyul", "Avgust", "Sentabr", "Oktabr", "Noyabr", "Dekabr"], ["Yan", "Fev", "Mar", "Apr", "May", "Iyn", "Iyl", "Avg", "Sen", "Okt", "Noy", "Dek"], ["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]],
+    weekdays: [["dushanba", "seshanba", "chorshanba", "payshanba", "juma", "shanba", "yakshanba"], ["Dush", "Sesh", "Chor", "Pay", "Jum", "Shan", "Yak"], ["D", "S", "C", "P", "J", "S", "Y"]],
+    standalone_weekdays: [["dushanba", "seshanba", "chorshanba", "payshanba", "juma", "shanba", "yakshanba"], ["Dush", "Sesh", "Chor", "Pay", "Jum", "Shan", "Yak"], ["D", "S", "C", "P", "J", "S", "Y"]],
+    am_pm: ["TO", "TK"],
+    eras: ["m.a.", "milodiy"],
+    dates: ["EEEE, d-MMMM, y", "d-MMMM, y", "d-MMM, y", "dd/MM/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d-MMMM, y, HH:mm:ss", "d-MMMM, y, HH:mm:ss", "d-MMM, y, HH:mm:ss", "dd/MM/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM, y", "MMM, y", "MM.yy"], ["d-MMMM, y", "d-MMM, y", "dd/MM/yy"], ["EEEE, d-MMMM, y", "E, d-MMM, y", "E, dd/MM/yy"], ["LLLL", "LLL", "LL"], ["d-MMMM", "d-MMM", "dd/MM"], ["EEEE, d-MMMM", "E, d-MMM", "E, dd/MM"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// vi
+static S112: Symbols = Symbols {
+    months: [["tháng 1", "tháng 2", "tháng 3", "tháng 4", "tháng 5", "tháng 6", "tháng 7", "tháng 8", "tháng 9", "tháng 10", "tháng 11", "tháng 12"], ["thg 1", "thg 2", "thg 3", "thg 4", "thg 5", "thg 6", "thg 7", "thg 8", "thg 9", "thg 10", "thg 11", "thg 12"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], ["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Thứ Hai", "Thứ Ba", "Thứ Tư", "Thứ Năm", "Thứ Sáu", "Thứ Bảy", "Chủ Nhật"], ["Thứ 2", "Thứ 3", "Thứ 4", "Thứ 5", "Thứ 6", "Thứ 7", "CN"], ["T2", "T3", "T4", "T5", "T6", "T7", "CN"]],
+    standalone_weekdays: [["Thứ Hai", "Thứ Ba", "Thứ Tư", "Thứ Năm", "Thứ Sáu", "Thứ Bảy", "Chủ Nhật"], ["Thứ 2", "Thứ 3", "Thứ 4", "Thứ 5", "Thứ 6", "Thứ 7", "CN"], ["T2", "T3", "T4", "T5", "T6", "T7", "CN"]],
+    am_pm: ["SA", "CH"],
+    eras: ["TCN", "SCN"],
+    dates: ["EEEE, d MMMM, y", "d MMMM, y", "d MMM, y", "dd/MM/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["'lúc' HH:mm:ss EEEE, d MMMM, y", "'lúc' HH:mm:ss d MMMM, y", "HH:mm:ss d MMM, y", "HH:mm dd/MM/y"],
+    fields: [["y", "y", "y"], ["MMMM 'năm' y", "MMM y", "'tháng' MM, y"], ["d MMMM, y", "d MMM, y", "dd/MM/y"], ["EEEE, d MMMM, y", "E, d MMM, y", "E, dd/MM/y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd/MM"], ["d", "d", "d"], ["EEEE', ngày' d", "E', ngày' d", "E', ngày' d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// cy
+static S113: Symbols = Symbols {
+    months: [["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], ["Ion", "Chwef", "Maw", "Ebr", "Mai", "Meh", "Gorff", "Awst", "Medi", "Hyd", "Tach", "Rhag"], ["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"]],
+    standalone_months: [["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], ["Ion", "Chw", "Maw", "Ebr", "Mai", "Meh", "Gor", "Awst", "Medi", "Hyd", "Tach", "Rhag"], ["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"]],
+    weekdays: [["Dydd Llun", "Dydd Mawrth", "Dydd Mercher", "Dydd Iau", "Dydd Gwener", "Dydd Sadwrn", "Dydd Sul"], ["Llun", "Maw", "Mer", "Iau", "Gwen", "Sad", "Sul"], ["Ll", "M", "M", "I", "G", "S", "S"]],
+    standalone_weekdays: [["Dydd Llun", "Dydd Mawrth", "Dydd Mercher", "Dydd Iau", "Dydd Gwener", "Dydd Sadwrn", "Dydd Sul"], ["Llun", "Maw", "Mer", "Iau", "Gwe", "Sad", "Sul"], ["Ll", "M", "M", "I", "G", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["CC", "OC"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'am' HH:mm:ss", "d MMMM y 'am' HH:mm:ss", "d MMM y, HH:mm:ss", "dd/MM/yy, HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM/yy"], ["d MMMM y", "d MMM y", "dd/MM/yy"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd/MM/yy"], ["LLLL", "LLL", "L"], ["MMMM d", "d MMM", "dd/MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd/MM"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// wo
+static S114: Symbols = Symbols {
+    months: [["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], ["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], ["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["Altine", "Talaata", "Àlarba", "Alxamis", "Àjjuma", "Aseer", "Dibéer"], ["Alt", "Tal", "Àla", "Alx", "Àjj", "Ase", "Dib"], ["Alt", "Tal", "Àla", "Alx", "Àjj", "Ase", "Dib"]],
+    standalone_weekdays: [["Altine", "Talaata", "Àlarba", "Alxamis", "Àjjuma", "Aseer", "Dibéer"], ["Alt", "Tal", "Àla", "Alx", "Àjj", "Ase", "Dib"], ["Alt", "Tal", "Àla", "Alx", "Àjj", "Ase", "Dib"]],
+    am_pm: ["Sub", "Ngo"],
+    eras: ["JC", "AD"],
+    dates: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMMM y 'ci' HH:mm:ss", "d MMMM y 'ci' HH:mm:ss", "d MMM y - HH:mm:ss", "dd-MM-y - HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM-y"], ["d MMMM y", "d MMM y", "dd-MM-y"], ["EEEE, d MMMM y", "E, d MMM y", "E, dd-MM-y"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE, d MMMM", "E, d MMM", "E, dd-MM"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// fy
+static S115: Symbols = Symbols {
+    months: [["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], ["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], ["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["moandei", "tiisdei", "woansdei", "tongersdei", "freed", "sneon", "snein"], ["mo", "ti", "wo", "to", "fr", "so", "si"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["moandei", "tiisdei", "woansdei", "tongersdei", "freed", "sneon", "snein"], ["mo", "ti", "wo", "to", "fr", "so", "si"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["f.Kr.", "n.Kr."],
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-yy"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE d MMMM y 'om' HH:mm:ss", "d MMMM y 'om' HH:mm:ss", "d MMM y HH:mm:ss", "dd-MM-yy HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "MM-yy"], ["d MMMM y", "d MMM y", "dd-MM-yy"], ["EEEE d MMMM y", "E d MMM y", "E dd-MM-yy"], ["LLLL", "LLL", "L"], ["d MMMM", "d MMM", "dd-MM"], ["EEEE d MMMM", "E d MMM", "E dd-MM"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// xh
+static S116: Symbols = Symbols {
+    months: [["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthobha", "Novemba", "Disemba"], ["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sept", "Okt", "Nov", "Dis"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthoba", "Novemba", "Disemba"], ["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["Mvulo", "Lwesibini", "Lwesithathu", "Lwesine", "Lwesihlanu", "Mgqibelo", "Cawe"], ["Mvu", "Lwesb", "Tha", "Sin", "Hla", "Mgq", "Caw"], ["Mv", "Sb", "Tht", "Sin", "Hl", "Mg", "C"]],
+    standalone_weekdays: [["Mvulo", "Lwesibini", "Lwesithathu", "Lwesine", "Lwesihlanu", "Mgqibelo", "Cawe"], ["Mvu", "Bin", "Tha", "Sin", "Hla", "Mgq", "Caw"], ["Mv", "Sb", "St", "Sin", "Hl", "Mg", "C"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "MM/dd/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, MMMM d, y 'ngo' HH:mm:ss", "MMMM d, y 'ngo' HH:mm:ss", "MMM d, y, HH:mm:ss", "MM/dd/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMMM y", "MMM y", "MM/y"], ["MMMM d, y", "MMM d, y", "MM/dd/y"], ["EEEE, MMMM d, y", "E, MMM d, y", "E, MM/dd/y"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+// yo
+static S117: Symbols = Symbols {
+    months: [["Oṣù Ṣẹ́rẹ́", "Oṣù Èrèlè", "Oṣù Ẹrẹ̀nà", "Oṣù Ìgbé", "Oṣù Ẹ̀bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ̀wàrà", "Oṣù Bélú", "Oṣù Ọ̀pẹ̀"], ["Oṣù Ṣẹ́rẹ́", "Oṣù Èrèlè", "Oṣù Ẹrẹ̀nà", "Oṣù Ìgbé", "Oṣù Ẹ̀bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ̀wàrà", "Oṣù Bélú", "Oṣù Ọ̀pẹ̀"], ["S", "È", "Ẹ", "Ì", "Ẹ̀", "Ò", "A", "Ò", "O", "Ọ̀", "B", "Ọ̀"]],
+    standalone_months: [["Oṣù Ṣẹ́rẹ́", "Oṣù Èrèlè", "Oṣù Ẹrẹ̀nà", "Oṣù Ìgbé", "Oṣù Ẹ̀bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ̀wàrà", "Oṣù Bélú", "Oṣù Ọ̀pẹ̀"], ["Oṣù Ṣẹ́rẹ́", "Oṣù Èrèlè", "Oṣù Ẹrẹ̀nà", "Oṣù Ìgbé", "Oṣù Ẹ̀bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ̀wàrà", "Oṣù Bélú", "Oṣù Ọ̀pẹ̀"], ["S", "È", "Ẹ", "Ì", "Ẹ̀", "Ò", "A", "Ò", "O", "Ọ̀", "B", "Ọ̀"]],
+    weekdays: [["Ọjọ́ Ajé", "Ọjọ́ Ìsẹ́gun", "Ọjọ́rú", "Ọjọ́bọ", "Ọjọ́ Ẹtì", "Ọjọ́ Àbámẹ́ta", "Ọjọ́ Àìkú"], ["Ajé", "Ìsẹ́gun", "Ọjọ́rú", "Ọjọ́bọ", "Ẹtì", "Àbámẹ́ta", "Àìkú"], ["A", "Ì", "Ọ", "Ọ", "Ẹ", "À", "À"]],
+    standalone_weekdays: [["Ajé", "Ìsẹ́gun", "Ọjọ́rú", "Ọjọ́bọ", "Ẹtì", "Àbámẹ́ta", "Àìkú"], ["Ajé", "Ìsẹ́gun", "Ọjọ́rú", "Ọjọ́bọ", "Ẹtì", "Àbámẹ́ta", "Àìkú"], ["A", "Ì", "Ọ", "Ọ", "Ẹ", "À", "À"]],
+    am_pm: ["Àárọ̀", "Ọ̀sán"],
+    eras: ["BCE", "AD"],
+    dates: ["EEEE, d MMM , y", "d MMM y", "d/MM/y", "d/M/y"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, d MMM , y 'ní' HH:mm:ss", "d MMM y 'ní' HH:mm:ss", "d/MM/y, HH:mm:ss", "d/M/y, HH:mm"],
+    fields: [["y", "y", "y"], ["MMM y", "MM/y", "M/y"], ["d MMM y", "d/MM/y", "d/M/y"], ["EEEE, d MMM , y", "d/MM/y, E", "d/M/y, E"], ["LLLL", "LLL", "L"], ["d MMM", "d/MM", "d/M"], ["d MMM, EEEE", "E, d/MM", "E, d/M"], ["d", "d", "d"], ["EEEE, d", "E, d", "E, d"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// za
+static S118: Symbols = Symbols {
+    months: [["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], ["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    standalone_months: [["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], ["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]],
+    weekdays: [["singhgizit", "singhgizngeih", "singhgizsam", "singhgizseiq", "singhgizhaj", "singhgizroek", "ngoenzsinghgiz"], ["singhgizit", "singhgizngeih", "singhgizsam", "singhgizseiq", "singhgizhaj", "singhgizroek", "ngoenzsinghgiz"], ["M", "T", "W", "T", "F", "S", "S"]],
+    standalone_weekdays: [["singhgizit", "singhgizngeih", "singhgizsam", "singhgizseiq", "singhgizhaj", "singhgizroek", "ngoenzsinghgiz"], ["singhgizit", "singhgizngeih", "singhgizsam", "singhgizseiq", "singhgizhaj", "singhgizroek", "ngoenzsinghgiz"], ["M", "T", "W", "T", "F", "S", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BCE", "CE"],
+    dates: ["y MMMM d, EEEE", "'bi' y 'ndwen' M 'ngoenz' d", "'bi' y 'ndwen' M 'ngoenz' d", "y/MM/dd"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["y MMMM d, EEEE HH:mm:ss", "'bi' y 'ndwen' M 'ngoenz' d HH:mm:ss", "'bi' y 'ndwen' M 'ngoenz' d HH:mm:ss", "y/MM/dd HH:mm"],
+    fields: [["y", "y", "y"], ["y MMMM", "y MMM", "y-MM"], ["'bi' y 'ndwen' M 'ngoenz' d", "'bi' y 'ndwen' M 'ngoenz' d", "y/MM/dd"], ["y MMMM d, EEEE", "y MMM d, E", "y-MM-dd, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["MMMM d, EEEE", "MMM d, E", "MM-dd, E"], ["d", "d", "d"], ["d, EEEE", "d, E", "d, E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Monday,
+};
+
+// zu
+static S119: Symbols = Symbols {
+    months: [["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], ["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], ["J", "F", "M", "E", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    standalone_months: [["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], ["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]],
+    weekdays: [["UMsombuluko", "ULwesibili", "ULwesithathu", "ULwesine", "ULwesihlanu", "UMgqibelo", "ISonto"], ["Mso", "Bil", "Tha", "Sin", "Hla", "Mgq", "Son"], ["M", "B", "T", "S", "H", "M", "S"]],
+    standalone_weekdays: [["UMsombuluko", "ULwesibili", "ULwesithathu", "ULwesine", "ULwesihlanu", "UMgqibelo", "ISonto"], ["Mso", "Bil", "Tha", "Sin", "Hla", "Mgq", "Son"], ["M", "B", "T", "S", "H", "M", "S"]],
+    am_pm: ["AM", "PM"],
+    eras: ["BC", "AD"],
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "yy-M-d"],
+    times: ["HH:mm:ss", "HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["EEEE, MMMM d, y HH:mm:ss", "MMMM d, y HH:mm:ss", "MMM d, y HH:mm:ss", "yy-M-d HH:mm"],
+    fields: [["y", "y", "yy"], ["MMMM y", "MMM y", "yy-M"], ["MMMM d, y", "MMM d, y", "yy-M-d"], ["EEEE, MMMM d, y", "E, MMM d, y", "yy-M-d, E"], ["LLLL", "LLL", "L"], ["MMMM d", "MMM d", "MM-dd"], ["EEEE, MMMM d", "E, MMM d", "MM-dd, E"], ["d", "d", "d"], ["d EEEE", "d E", "d E"], ["cccc", "ccc", "ccc"]],
+    first_day: Weekday::Sunday,
+};
+
+/// The data of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [Option<&Symbols>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&S0),
+    /* ak */ Some(&S1),
+    /* sq */ Some(&S2),
+    /* am */ Some(&S3),
+    /* ar */ Some(&S4),
+    /* an */ None,
+    /* hy */ Some(&S5),
+    /* as */ Some(&S6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&S7),
+    /* bm */ None,
+    /* ba */ Some(&S8),
+    /* eu */ Some(&S9),
+    /* be */ Some(&S10),
+    /* bn */ Some(&S11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&S12),
+    /* br */ Some(&S13),
+    /* bg */ Some(&S14),
+    /* my */ Some(&S15),
+    /* ca */ Some(&S16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&S17),
+    /* cv */ Some(&S18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&S19),
+    /* cs */ Some(&S20),
+    /* da */ Some(&S21),
+    /* dv */ None,
+    /* nl */ Some(&S22),
+    /* dz */ None,
+    /* en */ Some(&S23),
+    /* eo */ Some(&S24),
+    /* et */ Some(&S25),
+    /* ee */ Some(&S26),
+    /* fo */ Some(&S27),
+    /* fj */ None,
+    /* fi */ Some(&S28),
+    /* fr */ Some(&S29),
+    /* ff */ None,
+    /* gl */ Some(&S30),
+    /* ka */ Some(&S31),
+    /* de */ Some(&S32),
+    /* el */ Some(&S33),
+    /* gn */ None,
+    /* gu */ Some(&S34),
+    /* ht */ Some(&S29),
+    /* ha */ Some(&S35),
+    /* he */ Some(&S36),
+    /* hz */ None,
+    /* hi */ Some(&S37),
+    /* ho */ None,
+    /* hu */ Some(&S38),
+    /* ia */ Some(&S39),
+    /* id */ Some(&S40),
+    /* ie */ Some(&S41),
+    /* ga */ Some(&S42),
+    /* ig */ Some(&S43),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&S44),
+    /* it */ Some(&S45),
+    /* iu */ None,
+    /* ja */ Some(&S46),
+    /* jv */ Some(&S47),
+    /* kl */ None,
+    /* kn */ Some(&S48),
+    /* kr */ None,
+    /* ks */ Some(&S49),
+    /* kk */ Some(&S50),
+    /* km */ Some(&S51),
+    /* ki */ None,
+    /* rw */ Some(&S52),
+    /* ky */ Some(&S53),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&S54),
+    /* ku */ Some(&S55),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&S56),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&S57),
+    /* lt */ Some(&S58),
+    /* lu */ None,
+    /* lv */ Some(&S59),
+    /* gv */ None,
+    /* mk */ Some(&S60),
+    /* mg */ None,
+    /* ms */ Some(&S61),
+    /* ml */ Some(&S62),
+    /* mt */ Some(&S63),
+    /* mi */ Some(&S64),
+    /* mr */ Some(&S65),
+    /* mh */ None,
+    /* mn */ Some(&S66),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&S67),
+    /* ng */ None,
+    /* nb */ Some(&S68),
+    /* nn */ Some(&S69),
+    /* no */ Some(&S68),
+    /* ii */ Some(&S70),
+    /* nr */ None,
+    /* oc */ Some(&S71),
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ Some(&S72),
+    /* or */ Some(&S73),
+    /* os */ None,
+    /* pa */ Some(&S74),
+    /* pi */ None,
+    /* fa */ Some(&S75),
+    /* pl */ Some(&S76),
+    /* ps */ Some(&S77),
+    /* pt */ Some(&S78),
+    /* qu */ Some(&S79),
+    /* rm */ Some(&S80),
+    /* rn */ None,
+    /* ro */ Some(&S81),
+    /* ru */ Some(&S82),
+    /* sa */ Some(&S83),
+    /* sc */ Some(&S84),
+    /* sd */ Some(&S85),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&S86),
+    /* gd */ Some(&S87),
+    /* sn */ None,
+    /* si */ Some(&S88),
+    /* sk */ Some(&S89),
+    /* sl */ Some(&S90),
+    /* so */ Some(&S91),
+    /* st */ Some(&S92),
+    /* es */ Some(&S93),
+    /* su */ Some(&S94),
+    /* sw */ Some(&S95),
+    /* ss */ None,
+    /* sv */ Some(&S96),
+    /* ta */ Some(&S97),
+    /* te */ Some(&S98),
+    /* tg */ Some(&S99),
+    /* th */ Some(&S100),
+    /* ti */ Some(&S101),
+    /* bo */ None,
+    /* tk */ Some(&S102),
+    /* tl */ Some(&S103),
+    /* tn */ Some(&S104),
+    /* to */ Some(&S105),
+    /* tr */ Some(&S106),
+    /* ts */ None,
+    /* tt */ Some(&S107),
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&S108),
+    /* uk */ Some(&S109),
+    /* ur */ Some(&S110),
+    /* uz */ Some(&S111),
+    /* ve */ None,
+    /* vi */ Some(&S112),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&S113),
+    /* wo */ Some(&S114),
+    /* fy */ Some(&S115),
+    /* xh */ Some(&S116),
+    /* yi */ None,
+    /* yo */ Some(&S117),
+    /* za */ Some(&S118),
+    /* zu */ Some(&S119),
+];
+
+/// The regions whose weeks don't start on Monday.
+pub(super) static FIRST_DAYS: &[(&str, Weekday)] = &[
+    ("AF", Weekday::Saturday),
+    ("AS", Weekday::Sunday),
+    ("BD", Weekday::Sunday),
+    ("BH", Weekday::Saturday),
+    ("BR", Weekday::Sunday),
+    ("BT", Weekday::Sunday),
+    ("CA", Weekday::Sunday),
+    ("CO", Weekday::Sunday),
+    ("DJ", Weekday::Saturday),
+    ("DO", Weekday::Sunday),
+    ("DZ", Weekday::Saturday),
+    ("EG", Weekday::Saturday),
+    ("ET", Weekday::Sunday),
+    ("GT", Weekday::Sunday),
+    ("HK", Weekday::Sunday),
+    ("HN", Weekday::Sunday),
+    ("ID", Weekday::Sunday),
+    ("IL", Weekday::Sunday),
+    ("IN", Weekday::Sunday),
+    ("IQ", Weekday::Saturday),
+    ("IR", Weekday::Saturday),
+    ("IS", Weekday::Sunday),
+    ("JO", Weekday::Saturday),
+    ("JP", Weekday::Sunday),
+    ("KE", Weekday::Sunday),
+    ("KH", Weekday::Sunday),
+    ("KR", Weekday::Sunday),
+    ("KW", Weekday::Saturday),
+    ("LA", Weekday::Sunday),
+    ("LY", Weekday::Saturday),
+    ("MM", Weekday::Sunday),
+    ("MO", Weekday::Sunday),
+    ("MT", Weekday::Sunday),
+    ("MV", Weekday::Friday),
+    ("MX", Weekday::Sunday),
+    ("MZ", Weekday::Sunday),
+    ("NI", Weekday::Sunday),
+    ("NP", Weekday::Sunday),
+    ("OM", Weekday::Saturday),
+    ("PA", Weekday::Sunday),
+    ("PE", Weekday::Sunday),
+    ("PH", Weekday::Sunday),
+    ("PK", Weekday::Sunday),
+    ("PR", Weekday::Sunday),
+    ("PT", Weekday::Sunday),
+    ("PY", Weekday::Sunday),
+    ("QA", Weekday::Saturday),
+    ("SA", Weekday::Sunday),
+    ("SD", Weekday::Saturday),
+    ("SV", Weekday::Sunday),
+    ("SY", Weekday::Saturday),
+    ("TH", Weekday::Sunday),
+    ("TW", Weekday::Sunday),
+    ("US", Weekday::Sunday),
+    ("VE", Weekday::Sunday),
+    ("WS", Weekday::Sunday),
+    ("YE", Weekday::Sunday),
+    ("ZA", Weekday::Sunday),
+    ("ZW", Weekday::Sunday),
+];
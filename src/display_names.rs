@@ -0,0 +1,73 @@
+//! Names of languages and regions in other languages, from the Unicode CLDR.
+//!
+//! A language picker shown in Spanish can name every language in Spanish:
+//! ```rust
+//! # use rocket_lang::{*, display_names::{region_name, tag_name}};
+//! assert_eq!(De.display_name_in(Es), "alemán");
+//! assert_eq!(De.display_name_in(De), "Deutsch");
+//! assert_eq!(Ja.display_name_in(Fr), "japonais");
+//! assert_eq!(region_name("BR", Fr), Some("Brésil"));
+//! assert_eq!(tag_name("pt-BR", Es).unwrap(), "portugués (Brasil)");
+//! ```
+//! The data is generated from the Unicode CLDR by `tools/cldr`. Languages without
+//! names of their own, and the names missing in a language, use the `english_name`
+//! of the language and the English name of the region:
+//! ```rust
+//! # use rocket_lang::{*, display_names::region_name};
+//! assert_eq!(Sv.display_name_in(Pl), "szwedzki");
+//! assert_eq!(Sv.display_name_in(Aa), "Swedish");
+//! assert_eq!(region_name("SE", Aa), Some("Sweden"));
+//! ```
+use crate::{locale::Locale, *};
+
+#[rustfmt::skip]
+mod data;
+
+impl LangCode {
+    /// Returns the name of the language in another language, such as
+    /// `alemán` for German in Spanish. Falls back to the `english_name`.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert_eq!(Fr.display_name_in(Zh), "法语");
+    /// assert_eq!(Fr.display_name_in(Sv), "franska");
+    /// assert_eq!(Fr.display_name_in(Ab), "French");
+    /// ```
+    pub fn display_name_in(self, lang: LangCode) -> &'static str {
+        let names = data::LANGUAGES[lang as usize];
+        match names.map_or("", |names| names[self as usize]) {
+            "" => self.english_name(),
+            name => name,
+        }
+    }
+}
+
+/// Returns the name of a region, such as `BR` or `419`, in a language.
+pub fn region_name(region: &str, lang: LangCode) -> Option<&'static str> {
+    let region = region.to_uppercase();
+    let index = data::REGION_CODES
+        .binary_search(&region.as_str())
+        .ok()?;
+    let name = |lang: LangCode| data::REGIONS[lang as usize].map_or("", |names| names[index]);
+    Some(match name(lang) {
+        "" => name(En),
+        name => name,
+    })
+}
+
+/// Returns the name of a tag such as `pt-BR` in a language, with the region
+/// in parentheses. Unknown regions are written with their code.
+pub fn tag_name(tag: &str, lang: LangCode) -> Option<String> {
+    let tag: Locale = tag.parse().ok()?;
+    let name = tag
+        .lang()
+        .display_name_in(lang);
+    let Some(region) = tag.region() else {
+        return Some(name.to_string());
+    };
+    let region = region_name(region, lang).unwrap_or(region);
+    Some(match lang {
+        Zh => format!("{name}（{region}）"),
+        Ko => format!("{name}({region})"),
+        _ => format!("{name} ({region})"),
+    })
+}
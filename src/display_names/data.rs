@@ -0,0 +1,1102 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+// af
+static L0: [&str; 184] = ["Afar", "Abkasies", "Afrikaans", "Akan", "Albanees", "Amharies", "Arabies", "Aragonees", "Armeens", "Assamees", "Avaries", "", "Aymara", "Azerbeidjans", "Bambara", "Baskir", "Baskies", "Belarussies", "Bengaals", "", "Bislama", "Bosnies", "Bretons", "Bulgaars", "Birmaans", "Katalaans", "Chamorro", "Tsjetsjeens", "Nyanja", "Chinees", "Chuvash", "Kornies", "Korsikaans", "", "Kroaties", "Tsjeggies", "Deens", "Divehi", "Nederlands", "Dzongkha", "Engels", "Esperanto", "Estnies", "Ewe", "Faroëes", "Fidjiaans", "Fins", "Frans", "Fulah", "Galisies", "Georgies", "Duits", "Grieks", "Guarani", "Goedjarati", "Haïtiaans", "Hausa", "Hebreeus", "Herero", "Hindi", "", "Hongaars", "Interlingua", "Indonesies", "Interlingue", "Iers", "Igbo", "", "Ido", "Yslands", "Italiaans", "Inoektitoet", "Japannees", "Javaans", "Kalaallisut", "Kannada", "Kanuri", "Kasjmiri", "Kazaks", "Khmer", "Kikuyu", "Rwandees", "Kirgisies", "Komi", "Kongolees", "Koreaans", "Koerdies", "Kuanyama", "Latyn", "Luxemburgs", "Ganda", "Limburgs", "Lingaals", "Lao", "Litaus", "Luba-Katanga", "Letties", "Manx", "Masedonies", "Malgassies", "Maleis", "Malabaars", "Maltees", "Maori", "Marathi", "Marshallees", "Mongools", "Nauru", "Navajo", "Noord-Ndebele", "Nepalees", "Ndonga", "Boeknoors", "Nuwe Noors", "Noors", "Sichuan Yi", "Suid-Ndebele", "Oksitaans", "", "Kerkslawies", "Oromo", "Odia", "Osseties", "Pandjabi", "", "Persies", "Pools", "Pasjtoe", "Portugees", "Quechua", "Reto-Romaans", "Rundi", "Roemeens", "Russies", "Sanskrit", "Sardinies", "Sindhi", "Noord-Sami", "Samoaans", "Sango", "Serwies", "Skotse Gallies", "Shona", "Sinhala", "Slowaaks", "Sloweens", "Somalies", "Suid-Sotho", "Spaans", "Sundanees", "Swahili", "Swazi", "Sweeds", "Tamil", "Teloegoe", "Tadjiks", "Thai", "Tigrinja", "Tibettaans", "Turkmeens", "Filippyns", "Tswana", "Tongaans", "Turks", "Tsonga", "Tataars", "Twi", "Tahities", "Uighur", "Oekraïens", "Oerdoe", "Oesbekies", "Venda", "Viëtnamees", "Volapük", "Walloon", "Wallies", "Wolof", "Fries", "Xhosa", "Jiddisj", "Joroeba", "Zhuang", "Zoeloe"];
+
+// ak
+static L1: [&str; 184] = ["", "", "Afrikaans", "Akan", "Aabeniani", "Amariki", "Arabeke", "", "Aameniani", "Asamese", "", "", "", "Asabegyanni", "", "", "Baske", "Belarus kasa", "Bengali kasa", "", "", "Bosniani", "Britenni", "Bɔlgeria kasa", "Bɛɛmis kasa", "Katalan", "", "", "", "Kyaena kasa", "Kyuvahyi", "", "", "", "Kurowehyia kasa", "Kyɛk kasa", "Dane kasa", "", "Dɛɛkye", "", "Borɔfo", "Esperanto", "Estonia kasa", "Ayigbe", "Farosi", "", "Finlande kasa", "Frɛnkye", "Fula kasa", "Galisia kasa", "Gyɔɔgyia kasa", "Gyaaman", "Greek kasa", "", "Gugyarata", "", "Hausa", "Hibri kasa", "", "Hindi", "", "Hangri kasa", "Kasa ntam", "Indonihyia kasa", "Kasa afrafra", "Aerelande kasa", "Igbo kasa", "", "", "Aeslande kasa", "Italy kasa", "", "Gyapan kasa", "Gyabanis kasa", "", "Kanada", "", "Kahyimiɛ", "kasaki kasa", "Kambodia kasa", "", "Rewanda kasa", "Kɛgyese kasa", "", "", "Korea kasa", "Kɛɛde kasa", "", "", "Lɔsimbɔge kasa", "", "", "", "Lawo kasa", "Lituania kasa", "", "Latvia kasa", "", "Mɛsidonia kasa", "", "Malay kasa", "Malayalam kasa", "Malta kasa", "Mawori", "Marati", "", "Mongoliafoɔ kasa", "", "", "", "Nɛpal kasa", "", "", "Nɔwefoɔ Ninɔso", "Nɔwefoɔ kasa", "", "", "Osita kasa", "", "", "", "Odia", "", "Pungyabi kasa", "", "Pɛɛhyia kasa", "Pɔland kasa", "Pahyito", "Pɔɔtugal kasa", "Kwɛkya", "Romanhye kasa", "", "Romenia kasa", "Rahyia kasa", "Sanskrit kasa", "Saadinia kasa", "Sindi", "", "", "", "Sɛbia Kasa", "Skotlandfoɔ Galek Kasa", "", "Sinhala", "Slovak Kasa", "Slovɛniafoɔ Kasa", "Somalia kasa", "", "Spain kasa", "Sunda Kasa", "Swahili", "", "Sweden kasa", "Tamil kasa", "Telugu", "Tɛgyeke kasa", "Taeland kasa", "Tigrinya kasa", "", "Tɛkmɛnistan Kasa", "Filipin kasa", "", "Tonga kasa", "Tɛɛki kasa", "", "Tata kasa", "", "", "Yugaa Kasa", "Ukren kasa", "Urdu kasa", "Usbɛkistan Kasa", "", "Viɛtnam kasa", "", "", "Wɛɛhye Kasa", "Wolɔfo Kasa", "Atɔeɛ Fam Frihyia Kasa", "Hosa Kasa", "", "Yoruba", "Zuang", "Zulu"];
+
+// sq
+static L2: [&str; 184] = ["afarisht", "abkazisht", "afrikanisht", "akanisht", "shqip", "amarisht", "arabisht", "aragonezisht", "armenisht", "asamezisht", "avarikisht", "", "ajmarisht", "azerbajxhanisht", "bambarisht", "bashkirisht", "baskisht", "bjellorusisht", "bengalisht", "", "bislamisht", "boshnjakisht", "bretonisht", "bullgarisht", "birmanisht", "katalonisht", "kamoroisht", "çeçenisht", "nianjisht", "kinezisht", "çuvashisht", "kornisht", "korsikisht", "", "kroatisht", "çekisht", "danisht", "divehisht", "holandisht", "xhongaisht", "anglisht", "esperanto", "estonisht", "eveisht", "faroisht", "fixhianisht", "finlandisht", "frëngjisht", "fulaisht", "galicisht", "gjeorgjisht", "gjermanisht", "greqisht", "guaranisht", "guxharatisht", "kreolishte e Haitit", "hausisht", "hebraisht", "hereroisht", "indisht", "", "hungarisht", "interlingua", "indonezisht", "gjuha oksidentale", "irlandisht", "igboisht", "", "idoisht", "islandisht", "italisht", "inuktitutisht", "japonisht", "javanisht", "kalalisutisht", "kanadisht", "kanurisht", "kashmirisht", "kazakisht", "kmerisht", "kikujuisht", "kiniaruandisht", "kirgizisht", "komisht", "", "koreanisht", "kurdisht", "kuanjamaisht", "latinisht", "luksemburgisht", "gandaisht", "limburgisht", "lingalisht", "laosisht", "lituanisht", "luba-katangaisht", "letonisht", "manksisht", "maqedonisht", "madagaskarisht", "malajisht", "malajalamisht", "maltisht", "maorisht", "maratisht", "marshallisht", "mongolisht", "nauruisht", "navahoisht", "ndebelishte veriore", "nepalisht", "ndongaisht", "norvegjishte letrare", "norvegjishte nynorsk", "norvegjisht", "sishuanisht", "ndebelishte jugore", "oksitanisht", "", "sllavishte kishtare", "oromoisht", "odisht", "osetisht", "punxhabisht", "", "persisht", "polonisht", "pashtoisht", "portugalisht", "keçuaisht", "retoromanisht", "rundisht", "rumanisht", "rusisht", "sanskritisht", "sardenjisht", "sindisht", "samishte veriore", "samoanisht", "sangoisht", "serbisht", "galishte skoceze", "shonisht", "sinhalisht", "sllovakisht", "sllovenisht", "somalisht", "sotoishte jugore", "spanjisht", "sundanisht", "suahilisht", "suatisht", "suedisht", "tamilisht", "teluguisht", "taxhikisht", "tajlandisht", "tigrinjaisht", "tibetisht", "turkmenisht", "filipinisht", "cuanaisht", "tonganisht", "turqisht", "congaisht", "tatarisht", "tuisht", "tahitisht", "ujgurisht", "ukrainisht", "urduisht", "uzbekisht", "vendaisht", "vietnamisht", "volapykisht", "ualunisht", "uellsisht", "uolofisht", "frizianishte perëndimore", "xhosaisht", "jidisht", "jorubaisht", "zhuangisht", "zuluisht"];
+
+// am
+static L3: [&str; 184] = ["አፋርኛ", "አብሐዚኛ", "አፍሪካንኛ", "አካንኛ", "አልባንያንኛ", "አማርኛ", "ዓረብኛ", "አራጎንስ", "አርሜንኛ", "አሳሜዝ", "አቫሪክ", "አቬስታን", "አያማርኛ", "አዘርባጃንኛ", "ባምባርኛ", "ባስኪርኛ", "ባስክኛ", "ቤላራሻኛ", "ቤንጋሊኛ", "", "ቢስላምኛ", "ቦስኒያንኛ", "ብሬቶንኛ", "ቡልጋሪኛ", "ቡርማኛ", "ካታላንኛ", "ቻሞሮ", "ችችን", "ንያንጃ", "ቻይንኛ", "ቹቫሽኛ", "ኮርኒሽ", "ኮርሲካኛ", "ክሪ", "ክሮሽያንኛ", "ቼክኛ", "ዴኒሽ", "ዲቬሂ", "ደች", "ድዞንግኻኛ", "እንግሊዝኛ", "ኤስፐራንቶ", "ኢስቶኒያንኛ", "ኢዊ", "ፋሮኛ", "ፊጂኛ", "ፊንላንድኛ", "ፈረንሳይኛ", "ፉላኒኛ", "ጋሊሺያንኛ", "ጆርጂያንኛ", "ጀርመንኛ", "ግሪክኛ", "ጓራኒኛ", "ጉጃርቲኛ", "ሃይትኛ ክሮሌ", "ሃውሳኛ", "ዕብራይስጥ", "ሄሬሮ", "ሕንድኛ", "", "ሀንጋሪኛ", "ኢንቴርሊንጓ", "ኢንዶኔዥያኛ", "እንተርሊንግወ", "አየርላንድኛ", "ኢግቦኛ", "እኑፒያቅኛ", "ኢዶ", "አይስላንድኛ", "ጣሊያንኛ", "እኑክቲቱትኛ", "ጃፓንኛ", "ጃቫኛ", "ካላሊሱት", "ካናዳ", "ካኑሪ", "ካሽሚርኛ", "ካዛክኛ", "ክመር", "ኪኩዩ", "ኪንያርዋንዳ", "ክይርግይዝ", "ኮሚ", "ኮንጎኛ", "ኮሪያኛ", "ኩርድሽ", "ኩዋንያማ", "ላቲንኛ", "ሉክሰምበርግኛ", "ጋንዳኛ", "ሊምቡርጊሽ", "ሊንጋላ", "ላኦኛ", "ሊቱዌንያኛ", "ሉባ-ካታንጋ", "ላትቪያኛ", "ማንክስ", "ሜቄዶንኛ", "ማላጋስይ", "ማላይ", "ማላያላም", "ማልቲዝኛ", "ማኦሪ", "ማራቲ", "ማርሻሊዝ", "ሞንጎሊያኛ", "ናኡሩ", "ናቫጆ", "ሰሜን ንዴብሌ", "ኔፓሊኛ", "ንዶንጋ", "የኖርዌይ ቦክማል", "የኖርዌይ ናይኖርስክ", "ኖርዌይኛ", "ሲቹዋን ዪ", "ደቡብ ንደቤሌ", "ኦሲታን", "", "ቸርች ስላቪክ", "ኦሮሚኛ", "ኦዲያ", "ኦሴቲክ", "ፑንጃብኛ", "", "ፐርሺያኛ", "ፖሊሽ", "ፓሽቶ", "ፖርቹጋልኛ", "ኩዌቹዋ", "ሮማንሽ", "ሩንዲ", "ሮማኒያኛ", "ራሽያኛ", "ሳንስክሪት", "ሳርዲንያን", "ሲንዲ", "ሰሜናዊ ሳሚ", "ሳሞኣን", "ሳንጎ", "ሰርብያኛ", "የስኮትላንድ ጌይሊክ", "ሾና", "ሲንሃላ", "ስሎቫክኛ", "ስሎቬንኛ", "ሱማልኛ", "ደቡባዊ ሶቶ", "ስፓኒሽ", "ሱዳንኛ", "ስዋሂሊኛ", "ስዋቲ", "ስዊድንኛ", "ታሚል", "ተሉጉ", "ታጂክ", "ታይ", "ትግርኛ", "ቲቤታንኛ", "ቱርክሜን", "ታጋሎገኛ", "ጽዋና", "ቶንጋን", "ቱርክኛ", "ጾንጋ", "ታታር", "ትዊኛ", "ታሂታንኛ", "ኡይግሁር", "ዩክሬንኛ", "ኡርዱኛ", "ኡዝቤክኛ", "ቬንዳ", "ቪየትናምኛ", "ቮላፑክኛ", "ዋሎን", "ዌልሽ", "ዎሎፍኛ", "ምዕራባዊ ፍሪሲኛ", "ዞሳኛ", "ይዲሽኛ", "ዮሩባዊኛ", "ዡዋንግኛ", "ዙሉኛ"];
+
+// ar
+static L4: [&str; 184] = ["الأفارية", "الأبخازية", "الأفريقانية", "الأكانية", "الألبانية", "الأمهرية", "العربية", "الأراغونية", "الأرمنية", "الأسامية", "الأوارية", "الأفستية", "الأيمارا", "الأذربيجانية", "البامبارا", "الباشكيرية", "الباسكية", "البيلاروسية", "البنغالية", "", "البيسلامية", "البوسنية", "البريتونية", "البلغارية", "البورمية", "الكتالانية", "التشامورو", "الشيشانية", "النيانجا", "الصينية", "التشوفاشي", "الكورنية", "الكورسيكية", "الكرى", "الكرواتية", "التشيكية", "الدانمركية", "المالديفية", "الهولندية", "دزونكا", "الإنجليزية", "الإسبرانتو", "الإستونية", "الإيوي", "الفاروية", "الفيجية", "الفنلندية", "الفرنسية", "الفولانية", "الجاليكية", "الجورجية", "الألمانية", "اليونانية", "الغوارانية", "الغوجاراتية", "الكريولية الهايتية", "الهوسا", "العبرية", "الهيريرو", "الهندية", "الهيري موتو", "الهنغارية", "اللّغة الوسيطة", "الإندونيسية", "الإنترلينج", "الأيرلندية", "الإيجبو", "الإينبياك", "الإيدو", "الأيسلندية", "الإيطالية", "الإينكتيتت", "اليابانية", "الجاوية", "الكالاليست", "الكانادا", "الكانوري", "الكشميرية", "الكازاخستانية", "الخميرية", "الكيكيو", "الكينيارواندا", "القيرغيزية", "الكومي", "الكونغو", "الكورية", "الكردية", "كوانياما", "اللاتينية", "اللكسمبورغية", "الغاندا", "الليمبورغية", "اللينجالا", "اللاوية", "الليتوانية", "اللوبا كاتانغا", "اللاتفية", "المنكية", "المقدونية", "الملغاشي", "الماليزية", "المالايالامية", "المالطية", "الماورية", "الماراثية", "المارشالية", "المنغولية", "النورو", "النافاجو", "النديبيل الشمالية", "النيبالية", "الندونجا", "النرويجية بوكمال", "النرويجية نينورسك", "النرويجية", "السيتشيون يي", "النديبيل الجنوبي", "الأوكسيتانية", "الأوجيبوا", "سلافية كنسية", "الأورومية", "الأورية", "الأوسيتيك", "البنجابية", "البالية", "الفارسية", "البولندية", "البشتو", "البرتغالية", "كيشوا", "الرومانشية", "الرندي", "الرومانية", "الروسية", "السنسكريتية", "السردينية", "السندية", "سامي الشمالية", "الساموائية", "السانجو", "الصربية", "الغيلية الأسكتلندية", "الشونا", "السنهالية", "السلوفاكية", "السلوفانية", "الصومالية", "السوتو الجنوبية", "الإسبانية", "السوندانية", "السواحلية", "السواتي", "السويدية", "التاميلية", "التيلوغوية", "الطاجيكية", "التايلاندية", "التغرينية", "التبتية", "التركمانية", "التاغالوغية", "التسوانية", "التونغية", "التركية", "السونجا", "التترية", "التوي", "التاهيتية", "الأويغورية", "الأوكرانية", "الأوردية", "الأوزبكية", "الفيندا", "الفيتنامية", "لغة الفولابوك", "الولونية", "الويلزية", "الولوفية", "الفريزيان", "الخوسا", "اليديشية", "اليوروبا", "الزهيونج", "الزولو"];
+
+// hy
+static L5: [&str; 184] = ["աֆարերեն", "աբխազերեն", "աֆրիկաանս", "աքան", "ալբաներեն", "ամհարերեն", "արաբերեն", "արագոներեն", "հայերեն", "ասամերեն", "ավարերեն", "", "այմարա", "ադրբեջաներեն", "բամբարա", "բաշկիրերեն", "բասկերեն", "բելառուսերեն", "բենգալերեն", "", "բիսլամա", "բոսնիերեն", "բրետոներեն", "բուլղարերեն", "բիրմայերեն", "կատալաներեն", "չամոռո", "չեչեներեն", "նյանջա", "չինարեն", "չուվաշերեն", "կոռներեն", "կորսիկերեն", "", "խորվաթերեն", "չեխերեն", "դանիերեն", "դիվեհի", "հոլանդերեն", "ջոնգքհա", "անգլերեն", "էսպերանտո", "էստոներեն", "էվե", "ֆարյորերեն", "ֆիջիերեն", "ֆիններեն", "ֆրանսերեն", "ֆուլահ", "գալիսերեն", "վրացերեն", "գերմաներեն", "հունարեն", "գուարանի", "գուջարաթի", "խառնակերտ հայիթերեն", "հաուսա", "եբրայերեն", "հերերո", "հինդի", "", "հունգարերեն", "ինտերլինգուա", "ինդոնեզերեն", "ինտերլինգուե", "իռլանդերեն", "իգբո", "", "իդո", "իսլանդերեն", "իտալերեն", "ինուկտիտուտ", "ճապոներեն", "ճավայերեն", "կալաալիսուտ", "կաննադա", "կանուրի", "քաշմիրերեն", "ղազախերեն", "քմերերեն", "կիկույու", "կինյառուանդա", "ղրղզերեն", "կոմիերեն", "", "կորեերեն", "քրդերեն", "կուանյամա", "լատիներեն", "լյուքսեմբուրգերեն", "գանդա", "լիմբուրգերեն", "լինգալա", "լաոսերեն", "լիտվերեն", "լուբա-կատանգա", "լատվիերեն", "մեներեն", "մակեդոներեն", "մալգաշերեն", "մալայերեն", "մալայալամ", "մալթայերեն", "մաորի", "մարաթի", "մարշալերեն", "մոնղոլերեն", "նաուրու", "նավախո", "հյուսիսային նդեբելե", "նեպալերեն", "նդոնգա", "գրքային նորվեգերեն", "նոր նորվեգերեն", "նորվեգերեն", "սիչուան", "հարավային նդեբելե", "օքսիտաներեն", "օջիբվա", "սլավոներեն, եկեղեցական", "օրոմո", "օրիյա", "օսերեն", "փենջաբերեն", "պալի", "պարսկերեն", "լեհերեն", "փուշթու", "պորտուգալերեն", "կեչուա", "ռոմանշերեն", "ռունդի", "ռումիներեն", "ռուսերեն", "սանսկրիտ", "սարդիներեն", "սինդհի", "հյուսիսային սաամի", "սամոաերեն", "սանգո", "սերբերեն", "շոտլանդական գաելերեն", "շոնա", "սինհալերեն", "սլովակերեն", "սլովեներեն", "սոմալիերեն", "հարավային սոթո", "իսպաներեն", "սունդաներեն", "սուահիլի", "սվազերեն", "շվեդերեն", "թամիլերեն", "թելուգու", "տաջիկերեն", "թայերեն", "տիգրինյա", "տիբեթերեն", "թուրքմեներեն", "տագալերեն", "ցվանա", "տոնգերեն", "թուրքերեն", "ցոնգա", "թաթարերեն", "տուի", "թաիտերեն", "ույղուրերեն", "ուկրաիներեն", "ուրդու", "ուզբեկերեն", "վենդա", "վիետնամերեն", "վոլապյուկ", "վալոներեն", "ուելսերեն", "վոլոֆ", "արևմտաֆրիզերեն", "քոսա", "իդիշ", "յորուբա", "ժուանգ", "զուլուերեն"];
+
+// as
+static L6: [&str; 184] = ["আফাৰ", "আবখাজিয়ান", "আফ্ৰিকানছ্", "আকান", "আলবেনীয়", "আমহাৰিক", "আৰবী", "আৰ্গোনিজ", "আৰ্মেনীয়", "অসমীয়া", "আভেৰিক", "", "আয়মাৰা", "আজেৰবাইজানী", "বামবাৰা", "বাছখিৰ", "বাস্ক", "বেলাৰুছীয়", "বাংলা", "", "বিছলামা", "বছনীয়", "ব্ৰেটন", "বুলগেৰীয়", "বাৰ্মীজ", "কাতালান", "চামোৰো", "চেচেন", "ন্যাঞ্জা", "চীনা", "চুভাচ", "কোৰ্নিচ", "কোৰ্ছিকান", "", "ক্ৰোৱেচিয়ান", "চেক", "ডেনিচ", "দিবেহি", "ডাচ", "জোংখা", "ইংৰাজী", "এস্পেৰান্তো", "এষ্টোনিয়", "ইৱে", "ফাৰোইজ", "ফিজিয়ান", "ফিনিচ", "ফ্ৰেন্স", "ফুলাহ", "গেলিচিয়ান", "জৰ্জিয়ান", "জাৰ্মান", "গ্ৰীক", "গুৱাৰাণী", "গুজৰাটী", "হেইটিয়ান ক্ৰিয়ল", "হাউছা", "হিব্ৰু", "হেৰেৰো", "হিন্দী", "", "হাঙ্গেৰিয়ান", "ইণ্টাৰলিংগুৱা", "ইণ্ডোনেচিয়", "ইণ্টাৰলিংগুৱে", "আইৰিচ", "ইগ্বো", "", "ইডো", "আইচলেণ্ডিক", "ইটালিয়ান", "ইনুক্টিটুট", "জাপানী", "জাভানী", "কালালিছুট", "কানাড়া", "কানুৰি", "কাশ্মিৰী", "কাজাখ", "খমেৰ", "কিকুয়ু", "কিনয়াৰোৱাণ্ডা", "কিৰ্গিজ", "কোমি", "", "কোৰিয়ান", "কুৰ্ডিচ", "কুয়ানিয়ামা", "লেটিন", "লাক্সেমবাৰ্গিচ", "গান্দা", "লিম্বুৰ্গিচ", "লিংগালা", "লাও", "লিথুৱানিয়ান", "লুবা-কাটাংগা", "লাটভিয়ান", "মেংক্স", "মেচিডোনীয়", "মালাগাছী", "মালয়", "মালায়ালম", "মাল্টিজ", "মাওৰি", "মাৰাঠী", "মাৰ্চলিজ", "মংগোলীয়", "নাউৰু", "নাভাজো", "উত্তৰ নিবেবেলে", "নেপালী", "এন্দোঙ্গা", "নৰৱেজিয়ান বোকমাল", "নৰৱেজিয়ান নায়নোৰ্স্ক", "নৰৱেজিয়ান", "ছিচুৱান ই", "দক্ষিণ দেবেল", "অ’চিটান", "", "চাৰ্চ শ্লেভিক", "ওৰোমো", "ওড়িয়া", "ওছেটিক", "পাঞ্জাবী", "", "ফাৰ্ছী", "প’লিচ", "পুস্ত", "পৰ্তুগীজ", "কুৱেচুৱা", "ৰোমানচ", "ৰুন্দি", "ৰোমানীয়", "ৰাছিয়ান", "সংস্কৃত", "ছাৰ্ডিনিয়ান", "সিন্ধী", "উদীচ্য ছামি", "ছামোন", "ছাঙ্গো", "ছাৰ্বিয়ান", "স্কটিচ গেইলিক", "চোনা", "সিংহলা", "শ্লোভাক", "শ্লোভেনিয়ান", "ছোমালি", "দাক্ষিণাত্য ছোথো", "স্পেনিচ", "ছুণ্ডানীজ", "স্বাহিলি", "স্বাতি", "ছুইডিচ", "তামিল", "তেলুগু", "তাজিক", "থাই", "টিগৰিনিয়া", "তিব্বতী", "তুৰ্কমেন", "ফিলিপিনো", "ছোৱানা", "টোঙ্গান", "তুৰ্কী", "ছোঙ্গা", "তাতাৰ", "", "তাহিতিয়ান", "উইঘুৰ", "ইউক্ৰেইনীয়", "উৰ্দু", "উজবেক", "ভেণ্ডা", "ভিয়েটনামী", "ভোলাপুক", "ৱালুন", "ৱেলচ", "ৱোলাফ", "ৱেষ্টাৰ্ণ ফ্ৰিছিয়ান", "হোছা", "ইদ্দিছ", "ইউৰুবা", "ঝুৱাং", "ঝুলু"];
+
+// az
+static L7: [&str; 184] = ["afar", "abxaz", "afrikaans", "akan", "alban", "amhar", "ərəb", "araqon", "erməni", "assam", "avar", "avestan", "aymara", "azərbaycan", "bambara", "başqırd", "bask", "belarus", "benqal", "", "bislama", "bosniya", "breton", "bolqar", "birman", "katalan", "çamoro", "çeçen", "nyanca", "çin", "çuvaş", "korn", "korsika", "kri", "xorvat", "çex", "danimarka", "maldiv", "holland", "dzonqxa", "ingilis", "esperanto", "eston", "eve", "farer", "fici", "fin", "fransız", "fula", "qalisiya", "gürcü", "alman", "yunan", "quarani", "qucarat", "haiti kreol", "hausa", "ivrit", "herero", "hind", "hiri motu", "macar", "interlinqua", "indoneziya", "interlinqve", "irland", "iqbo", "inupiaq", "ido", "island", "italyan", "inuktitut", "yapon", "yava", "kalaallisut", "kannada", "kanuri", "kəşmir", "qazax", "kxmer", "kikuyu", "kinyarvanda", "qırğız", "komi", "konqo", "koreya", "kürd", "kuanyama", "latın", "lüksemburq", "qanda", "limburq", "linqala", "laos", "litva", "luba-katanqa", "latış", "manks", "makedon", "malaqas", "malay", "malayalam", "malta", "māori", "marathi", "marşal", "monqol", "nauru", "navayo", "şimali ndebele", "nepal", "ndonqa", "bokmal norveç", "nünorsk norveç", "norveç", "siçuan yi", "cənubi ndebele", "oksitan", "ocibva", "slavyan", "oromo", "odiya", "osetin", "pəncab", "pali", "fars", "polyak", "puştu", "portuqal", "keçua", "romanş", "rundi", "rumın", "rus", "sanskrit", "sardin", "sindhi", "şimali sami", "samoa", "sanqo", "serb", "Şotlandiya keltcəsi", "şona", "sinhala", "slovak", "sloven", "somali", "sesoto", "ispan", "sundan", "suahili", "svati", "isveç", "tamil", "teluqu", "tacik", "tay", "tiqrin", "tibet", "türkmən", "taqaloq", "svana", "tonqa", "türk", "sonqa", "tatar", "tvi", "taxiti", "uyğur", "ukrayna", "urdu", "özbək", "venda", "vyetnam", "volapük", "valun", "uels", "volof", "qərbi friz", "xosa", "idiş", "yoruba", "çjuan", "zulu"];
+
+// ba
+static L8: [&str; 184] = ["афар", "абхаз", "африкаанс", "акан", "албан", "амхар", "ғәрәп", "арагон", "әрмән", "ассам", "", "", "", "әзербайжан", "бамбара", "башҡорт", "баск", "белорус", "бенгал", "", "", "босний", "бретон", "болгар", "бирма", "каталан", "", "чечен", "ньянджа", "ҡытай", "сыуаш", "корн", "корсика", "", "хорват", "чех", "дат", "дивехи", "нидерланд", "дзонг-кэ", "инглиз", "эсперанто", "эстон", "эве", "фарер", "", "фин", "француз", "фула", "галисий", "грузин", "немец", "грек", "гуарани", "гуджарати", "гаити креол", "хауса", "иврит", "", "һинди", "", "венгр", "интерлингва", "индонез", "интерлингве", "ирланд", "игбо", "", "идо", "исланд", "итальян", "инуктитут", "япон", "ява", "калааллисут", "каннада", "", "кашмири", "ҡаҙаҡ", "кхмер", "кикуйю", "киньяруанда", "ҡырғыҙ", "", "", "корей", "курд", "", "латин", "люксембург", "ганда", "", "лингала", "лаос", "литва", "луба-катанга", "латыш", "мэн", "македон", "малагаси", "малай", "малаялам", "мальта", "маори", "маратхи", "", "монгол", "", "навахо", "төньяҡ ндебеле", "непал", "", "норвег букмол", "норвег нюнорск", "норвег", "сычуань и", "көньяҡ ндебеле", "окситан", "", "сиркәү славян", "оромо", "одия", "осетин", "панджаби", "пали", "фарсы", "поляк", "пушту", "португал", "кечуа", "романш", "рунди", "румын", "урыҫ", "санскрит", "сардин", "синдхи", "төньяҡ саам", "", "санго", "серб", "шотланд гэл", "шона", "сингал", "словак", "словен", "сомали", "көньяҡ сото", "испан", "сундан", "суахили", "свати", "швед", "тамил", "телугу", "тажик", "тай", "тигринья", "тибет", "төркмән", "филиппин", "тсвана", "тонга", "төрөк", "тсонга", "татар", "", "", "уйғыр", "украин", "урду", "үзбәк", "венда", "вьетнам", "волапюк", "валлон", "валлий", "волоф", "көнбайыш фриз", "коса", "идиш", "йоруба", "чжуан", "зулу"];
+
+// eu
+static L9: [&str; 184] = ["afarera", "abkhaziera", "afrikaansa", "akanera", "albaniera", "amharera", "arabiera", "aragoiera", "armeniera", "assamera", "avarera", "", "aimara", "azerbaijanera", "bambarera", "baxkirera", "euskara", "bielorrusiera", "bengalera", "", "bislama", "bosniera", "bretoiera", "bulgariera", "birmaniera", "katalana", "txamorroera", "txetxenera", "chewera", "txinera", "txuvaxera", "kornubiera", "korsikera", "", "kroaziera", "txekiera", "daniera", "dhivehia", "nederlandera", "dzongkha", "ingelesa", "esperantoa", "estoniera", "eweera", "faroera", "fijiera", "finlandiera", "frantsesa", "fula", "galiziera", "georgiera", "alemana", "greziera", "guaraniera", "gujaratera", "Haitiko kreolera", "hausa", "hebreera", "hereroera", "hindia", "", "hungariera", "interlingua", "indonesiera", "interlinguea", "irlandera", "igboera", "", "idoa", "islandiera", "italiera", "inuitera", "japoniera", "javera", "groenlandiera", "kannada", "kanuriera", "kaxmirera", "kazakhera", "khemerera", "kikuyuera", "kinyaruanda", "kirgizera", "komiera", "kikongoa", "koreera", "kurduera", "kuanyama", "latina", "luxenburgera", "luganda", "limburgera", "lingala", "laosera", "lituaniera", "Katangako lubera", "letoniera", "manxera", "mazedoniera", "malgaxea", "malaysiera", "malabarera", "maltera", "maoriera", "marathera", "marshallera", "mongoliera", "nauruera", "navajoera", "iparraldeko ndebeleera", "nepalera", "ndonga", "bokmål (norvegiera)", "nynorsk (norvegiera)", "norvegiera", "Sichuango yiera", "hegoaldeko ndebeleera", "okzitaniera", "", "elizako eslaviera", "oromoera", "oriya", "osetiera", "punjabera", "", "persiera", "poloniera", "paxtunera", "portugesa", "kitxua", "erretorromaniera", "rundiera", "errumaniera", "errusiera", "sanskritoa", "sardiniera", "sindhia", "iparraldeko samiera", "samoera", "sangoa", "serbiera", "Eskoziako gaelikoa", "shonera", "sinhala", "eslovakiera", "esloveniera", "somaliera", "hegoaldeko sothoera", "gaztelania", "sundanera", "swahilia", "swatiera", "suediera", "tamilera", "telugua", "tajikera", "thailandiera", "tigrinyera", "tibetera", "turkmenera", "tagaloa", "tswanera", "tongera", "turkiera", "tsongera", "tatarera", "twia", "tahitiera", "uigurrera", "ukrainera", "urdua", "uzbekera", "vendera", "vietnamera", "volapük", "valoniera", "galesa", "wolofera", "mendebaldeko frisiera", "xhosera", "yiddisha", "jorubera", "zhuangera", "zuluera"];
+
+// be
+static L10: [&str; 184] = ["афарская", "абхазская", "афрыкаанс", "акан", "албанская", "амхарская", "арабская", "арагонская", "армянская", "асамская", "аварская", "", "аймара", "азербайджанская", "бамбара", "башкірская", "баскская", "беларуская", "бенгальская", "", "біслама", "баснійская", "брэтонская", "балгарская", "бірманская", "каталанская", "чамора", "чачэнская", "ньянджа", "кітайская", "чувашская", "корнская", "карсіканская", "", "харвацкая", "чэшская", "дацкая", "мальдыўская", "нідэрландская", "дзонг-кэ", "англійская", "эсперанта", "эстонская", "эве", "фарэрская", "фіджыйская", "фінская", "французская", "фула", "галісійская", "грузінская", "нямецкая", "грэчаская", "гуарані", "гуджараці", "гаіцянская крэольская", "хауса", "іўрыт", "герэра", "хіндзі", "", "венгерская", "інтэрлінгва", "інданезійская", "інтэрлінгвэ", "ірландская", "ігба", "", "іда", "ісландская", "італьянская", "інуктытут", "японская", "яванская", "грэнландская", "канада", "кануры", "кашмірская", "казахская", "кхмерская", "кікуйю", "руанда", "кіргізская", "комі", "", "карэйская", "курдская", "куаньяма", "лацінская", "люксембургская", "ганда", "лімбургская", "лінгала", "лаоская", "літоўская", "луба-катанга", "латышская", "мэнская", "македонская", "малагасійская", "малайская", "малаялам", "мальтыйская", "маары", "маратхі", "маршальская", "мангольская", "науру", "наваха", "паўночная ндэбеле", "непальская", "ндонга", "нарвежская (букмол)", "нарвежская (нюношк)", "нарвежская", "сычуаньская йі", "паўднёвая ндэбеле", "аксітанская", "аджыбвэ", "царкоўнаславянская", "арома", "орыя", "асецінская", "панджабі", "", "фарсі", "польская", "пушту", "партугальская", "кечуа", "рэтараманская", "рундзі", "румынская", "руская", "санскрыт", "сардзінская", "сіндхі", "паўночнасаамская", "самоа", "санга", "сербская", "шатландская гэльская", "шона", "сінгальская", "славацкая", "славенская", "самалі", "сесута", "іспанская", "сунда", "суахілі", "суаці", "шведская", "тамільская", "тэлугу", "таджыкская", "тайская", "тыгрынья", "тыбецкая", "туркменская", "філіпінская", "тсвана", "танганская", "турэцкая", "тсонга", "татарская", "", "таіці", "уйгурская", "украінская", "урду", "узбекская", "венда", "в’етнамская", "валапюк", "валонская", "валійская", "валоф", "заходняя фрызская", "коса", "ідыш", "ёруба", "чжуанская", "зулу"];
+
+// bn
+static L11: [&str; 184] = ["আফার", "আবখাজিয়ান", "আফ্রিকান", "আকান", "আলবেনীয়", "আমহারিক", "আরবি", "আর্গোনিজ", "আর্মেনিয়", "অসমীয়া", "আভেরিক", "আবেস্তীয়", "আয়মারা", "আজারবাইজানী", "বামবারা", "বাশকির", "বাস্ক", "বেলারুশীয়", "বাংলা", "", "বিসলামা", "বসনীয়", "ব্রেটন", "বুলগেরিয়", "বর্মি", "কাতালান", "চামোরো", "চেচেন", "নায়াঞ্জা", "চীনা", "চুবাস", "কর্ণিশ", "কর্সিকান", "ক্রি", "ক্রোয়েশীয়", "চেক", "ডেনিশ", "দিবেহি", "ওলন্দাজ", "জোংখা", "ইংরেজি", "এস্পেরান্তো", "এস্তোনীয়", "ইউয়ি", "ফেরোইস", "ফিজিয়ান", "ফিনিশ", "ফরাসি", "ফুলা", "গ্যালিশীয়", "জর্জিয়ান", "জার্মান", "গ্রিক", "গুয়ারানি", "গুজরাটি", "হাইতিয়ান ক্রেওল", "হাউসা", "হিব্রু", "হেরেরো", "হিন্দি", "হিরি মোতু", "হাঙ্গেরীয়", "ইন্টারলিঙ্গুয়া", "ইন্দোনেশীয়", "ইন্টারলিঙ্গ", "আইরিশ", "ইগবো", "ইনুপিয়াক", "ইডো", "আইসল্যান্ডীয়", "ইতালীয়", "ইনুক্টিটুট", "জাপানি", "জাভানিজ", "কালাল্লিসুট", "কন্নড়", "কানুরি", "কাশ্মীরি", "কাজাখ", "খমের", "কিকুয়ু", "কিনয়ারোয়ান্ডা", "কিরগিজ", "কোমি", "কঙ্গো", "কোরিয়ান", "কুর্দিশ", "কোয়ানিয়ামা", "লাতিন", "লুক্সেমবার্গীয়", "গান্ডা", "লিম্বুর্গিশ", "লিঙ্গালা", "লাও", "লিথুয়ানীয়", "লুবা-কাটাঙ্গা", "লাটভিয়", "ম্যাঙ্কস", "ম্যাসেডোনিয়া", "মালাগাসি", "মালয়", "মালয়ালম", "মল্টীয়", "মাওরি", "মারাঠি", "মার্শালিজ", "মোঙ্গোলীয়", "নাউরু", "নাভাজো", "উত্তর এন্দেবেলে", "নেপালি", "এন্দোঙ্গা", "নরওয়েজিয়ান বোকমাল", "নরওয়েজিয়ান নিনর্স্ক", "নরওয়েজীয়", "সিচুয়ান য়ি", "দক্ষিণ এনডেবেলে", "অক্সিটান", "ওজিবওয়া", "চার্চ স্লাভিক", "ওরোমো", "ওড়িয়া", "ওসেটিক", "পাঞ্জাবি", "পালি", "ফার্সি", "পোলিশ", "পাশতু", "পর্তুগিজ", "কেচুয়া", "রোমান্স", "রুন্দি", "রোমানীয়", "রুশ", "সংস্কৃত", "সার্ডিনিয়ান", "সিন্ধি", "উত্তরাঞ্চলীয় সামি", "সামোয়ান", "সাঙ্গো", "সার্বীয়", "স্কটিশ-গ্যেলিক", "শোনা", "সিংহলি", "স্লোভাক", "স্লোভেনীয়", "সোমালি", "দক্ষিন সোথো", "স্প্যানিশ", "সুদানী", "সোয়াহিলি", "সোয়াতি", "সুইডিশ", "তামিল", "তেলুগু", "তাজিক", "থাই", "তিগরিনিয়া", "তিব্বতি", "তুর্কমেনী", "তাগালগ", "সোয়ানা", "টোঙ্গান", "তুর্কি", "সঙ্গা", "তাতার", "টোয়াই", "তাহিতিয়ান", "উইঘুর", "ইউক্রেনীয়", "উর্দু", "উজবেক", "ভেন্ডা", "ভিয়েতনামী", "ভোলাপুক", "ওয়ালুন", "ওয়েলশ", "ওলোফ", "পশ্চিম ফ্রিসিয়ান", "খোসা", "ইদ্দিশ", "ইওরুবা", "ঝুয়াং", "জুলু"];
+
+// bs
+static L12: [&str; 184] = ["afarski", "abhaski", "afrikans", "akan", "albanski", "amharski", "arapski", "aragonski", "armenski", "asamski", "avarski", "avestanski", "ajmara", "azerbejdžanski", "bambara", "baškirski", "baskijski", "bjeloruski", "bengalski", "", "bislama", "bosanski", "bretonski", "bugarski", "burmanski", "katalonski", "čamoro", "čečenski", "njanja", "kineski", "čuvaški", "kornski", "korzikanski", "kri", "hrvatski", "češki", "danski", "divehi", "nizozemski", "džonga", "engleski", "esperanto", "estonski", "eve", "farski", "fidžijski", "finski", "francuski", "fulah", "galicijski", "gruzijski", "njemački", "grčki", "gvarani", "gudžarati", "haićanski kreolski", "hausa", "hebrejski", "herero", "hindi", "hiri motu", "mađarski", "interlingva", "indonezijski", "interlingve", "irski", "igbo", "inupiak", "ido", "islandski", "italijanski", "inuktitut", "japanski", "javanski", "kalalisutski", "kanada", "kanuri", "kašmirski", "kazaški", "kmerski", "kikuju", "kinjaruanda", "kirgiški", "komi", "kongo", "korejski", "kurdski", "kuanjama", "latinski", "luksemburški", "ganda", "limburški", "lingala", "laoski", "litvanski", "luba-katanga", "latvijski", "manks", "makedonski", "malgaški", "malajski", "malajalam", "malteški", "maorski", "marati", "maršalski", "mongolski", "nauru", "navaho", "sjeverni ndebele", "nepalski", "ndonga", "norveški (Bokmal)", "norveški (Nynorsk)", "norveški", "sičuan ji", "južni ndebele", "oksitanski", "ojibva", "staroslavenski", "oromo", "odija", "osetski", "pandžapski", "pali", "perzijski", "poljski", "paštu", "portugalski", "kečua", "retoromanski", "rundi", "rumunski", "ruski", "sanskrit", "sardinijski", "sindi", "sjeverni sami", "samoanski", "sango", "srpski", "škotski galski", "šona", "sinhaleški", "slovački", "slovenski", "somalski", "južni soto", "španski", "sundanski", "svahili", "svati", "švedski", "tamilski", "telugu", "tadžički", "tajlandski", "tigrinja", "tibetanski", "turkmenski", "tagalog", "tsvana", "tonganski", "turski", "tsonga", "tatarski", "tvi", "tahićanski", "ujgurski", "ukrajinski", "urdu", "uzbečki", "venda", "vijetnamski", "volapuk", "valun", "velški", "volof", "zapadni frizijski", "hosa", "jidiš", "jorubanski", "zuang", "zulu"];
+
+// br
+static L13: [&str; 184] = ["afar", "abkhazeg", "afrikaaneg", "akaneg", "albaneg", "amhareg", "arabeg", "aragoneg", "armenianeg", "asameg", "avar", "avesteg", "aymara", "azerbaidjaneg", "bambara", "bachkir", "euskareg", "belaruseg", "bengaleg", "", "bislama", "bosneg", "brezhoneg", "bulgareg", "birmaneg", "katalaneg", "chamorru", "tchetcheneg", "nyanja", "sinaeg", "tchouvatcheg", "kerneveureg", "korseg", "kri", "kroateg", "tchekeg", "daneg", "divehi", "nederlandeg", "dzongkha", "saozneg", "esperanteg", "estoneg", "ewe", "faeroeg", "fidjieg", "finneg", "galleg", "fulaeg", "galizeg", "jorjianeg", "alamaneg", "gresianeg", "guarani", "gujarateg", "haitieg", "haousaeg", "hebraeg", "herero", "hindieg", "hiri motu", "hungareg", "interlinguaeg", "indonezeg", "interlingue", "iwerzhoneg", "igboeg", "inupiaq", "ido", "islandeg", "italianeg", "inuktitut", "japaneg", "javaneg", "greunlandeg", "kanareg", "kanouri", "kashmireg", "kazakeg", "khmereg", "kikuyu", "kinyarwanda", "kirgizeg", "komieg", "kongo", "koreaneg", "kurdeg", "kwanyama", "latin", "luksembourgeg", "ganda", "limbourgeg", "lingala", "laoseg", "lituaneg", "luba-katanga", "latvieg", "manaveg", "makedoneg", "malgacheg", "malayseg", "malayalameg", "malteg", "maori", "maratheg", "marshall", "mongoleg", "naurueg", "navacʼho", "ndebele an Norzh", "nepaleg", "ndonga", "norvegeg bokmål", "norvegeg nynorsk", "norvegeg", "yieg Sichuan", "ndebele ar Su", "okitaneg", "ojibweg", "slavoneg iliz", "oromoeg", "oriyaeg", "oseteg", "punjabeg", "pali", "perseg", "poloneg", "pachtoeg", "portugaleg", "kechuaeg", "romañcheg", "rundi", "roumaneg", "rusianeg", "sanskriteg", "sardeg", "sindhi", "sámi an Norzh", "samoan", "sango", "serbeg", "skoseg", "shona", "singhaleg", "slovakeg", "sloveneg", "somalieg", "sothoeg ar Su", "spagnoleg", "sundaneg", "swahilieg", "swati", "svedeg", "tamileg", "telougou", "tadjikeg", "thaieg", "tigrignaeg", "tibetaneg", "turkmeneg", "tagalog", "tswanaeg", "tongaeg", "turkeg", "tsonga", "tatareg", "twi", "tahitianeg", "ouigoureg", "ukraineg", "ourdoueg", "ouzbekeg", "venda", "vietnameg", "volapük", "walloneg", "kembraeg", "wolofeg", "frizeg ar Cʼhornôg", "xhosaeg", "yiddish", "yoroubaeg", "zhuang", "zouloueg"];
+
+// bg
+static L14: [&str; 184] = ["афарски", "абхазки", "африканс", "акан", "албански", "амхарски", "арабски", "арагонски", "арменски", "асамски", "аварски", "авестски", "аймара", "азербайджански", "бамбара", "башкирски", "баски", "беларуски", "бенгалски", "", "бислама", "босненски", "бретонски", "български", "бирмански", "каталонски", "чаморо", "чеченски", "нянджа", "китайски", "чувашки", "корнуолски", "корсикански", "крии", "хърватски", "чешки", "датски", "дивехи", "нидерландски", "дзонгкха", "английски", "есперанто", "естонски", "еве", "фарьорски", "фиджийски", "фински", "френски", "фула", "галисийски", "грузински", "немски", "гръцки", "гуарани", "гуджарати", "хаитянски креолски", "хауса", "иврит", "хереро", "хинди", "хири моту", "унгарски", "интерлингва", "индонезийски", "интерлингве", "ирландски", "игбо", "инупиак", "идо", "исландски", "италиански", "инуктитут", "японски", "явански", "гренландски", "каннада", "канури", "кашмирски", "казахски", "кхмерски", "кикую", "киняруанда", "киргизки", "коми", "конгоански", "корейски", "кюрдски", "кваняма", "латински", "люксембургски", "ганда", "лимбургски", "лингала", "лаоски", "литовски", "луба-катанга", "латвийски", "манкски", "македонски", "малгашки", "малайски", "малаялам", "малтийски", "маорски", "марати", "маршалезе", "монголски", "науру", "навахо", "северен ндебеле", "непалски", "ндонга", "норвежки (букмол)", "норвежки (нюношк)", "норвежки", "съчуански йи", "южен ндебеле", "окситански", "оджибва", "църковнославянски", "оромо", "ория", "осетински", "пенджабски", "пали", "персийски", "полски", "пущу", "португалски", "кечуа", "реторомански", "рунди", "румънски", "руски", "санскрит", "сардински", "синдхи", "северносаамски", "самоански", "санго", "сръбски", "шотландски келтски", "шона", "синхалски", "словашки", "словенски", "сомалийски", "сото", "испански", "сундански", "суахили", "свати", "шведски", "тамилски", "телугу", "таджикски", "тайски", "тигриня", "тибетски", "туркменски", "тагалог", "тсвана", "тонгански", "турски", "цонга", "татарски", "туи", "таитянски", "уйгурски", "украински", "урду", "узбекски", "венда", "виетнамски", "волапюк", "валонски", "уелски", "волоф", "западнофризийски", "кхоса", "идиш", "йоруба", "зуанг", "зулуски"];
+
+// my
+static L15: [&str; 184] = ["အာဖာ", "အဘ်ခါဇီရာ", "အာဖရိကန်", "အာကန်", "အယ်လ်ဘေးနီးယား", "အမ်ဟာရစ်ခ်", "အာရဗီ", "အာရာဂွန်", "အာမေးနီးယား", "အာသံ", "အာဗာရစ်ခ်", "", "အိုင်မာရ", "အဇာဘိုင်ဂျန်", "ဘန်ဘာရာ", "ဘက်ရှ်ကာ", "ဘာစ်ခ်", "ဘီလာရုစ်", "ဘင်္ဂါလီ", "", "ဘစ်စ်လာမာ", "ဘော့စ်နီးယား", "ဘရီတွန်", "ဘူလ်ဂေးရီးယား", "မြန်မာ", "ကတ်တလန်", "ချမိုရို", "ချက်ချန်း", "နရန်ဂျာ", "တရုတ်", "ချူဗက်ရှ်", "ခိုနီရှ်", "ခိုစီကန်", "ခရီး", "ခရိုအေးရှား", "ချက်", "ဒိန်းမတ်", "ဒီဗာဟီ", "ဒတ်ခ်ျ", "ဒဇွန်ကာ", "အင်္ဂလိပ်", "အက်စ်ပရန်တို", "အက်စ်တိုးနီးယား", "အီဝီ", "ဖာရို", "ဖီဂျီ", "ဖင်လန်", "ပြင်သစ်", "ဖူလာ", "ဂါလီစီယာ", "ဂျော်ဂျီယာ", "ဂျာမန်", "ဂရိ", "ဂူအာရာနီ", "ဂူဂျာရသီ", "ဟေတီ", "ဟာဥစာ", "ဟီဘရူး", "ဟီရဲရို", "ဟိန္ဒီ", "", "ဟန်ဂေရီ", "အင်တာလင်ဂွါ", "အင်ဒိုနီးရှား", "အင်တာလင်း", "အိုင်းရစ်ရှ်", "အစ္ဂဘို", "", "အီဒို", "အိုက်စ်လန်", "အီတလီ", "အီနုခ်တီတု", "ဂျပန်", "ဂျာဗား", "ကလာအ်လီဆပ်", "ကန်နာဒါ", "ကနူရီ", "ကက်ရှ်မီးယား", "ကာဇာချ", "ခမာ", "ကီကူယူ", "ကင်ရာဝန်ဒါ", "ကာဂျစ်", "ကိုမီ", "ကွန်ဂို", "ကိုရီးယား", "ကဒ်", "ကွန်းယာမာ", "လက်တင်", "လူဇင်ဘတ်", "ဂန်ဒါ", "လင်ဘာဂစ်ရှ်", "လင်ဂါလာ", "လာအို", "လစ်သူဝေးနီးယား", "လူဘာ-ကတန်ဂါ", "လတ်ဗီးယား", "မန်းဇ်", "မက်ဆီဒိုးနီးယား", "မာလဂက်စီ", "မလေး", "မလေယာလမ်", "မော်လ်တာ", "မာအိုရီ", "မာရသီ", "မာရှယ်လိဇ်", "မွန်ဂိုလီးယား", "နော်ရူး", "နာဗာဟို", "မြောက် အွန်န်ဒီဘီလီ", "နီပေါ", "အွန်ဒွန်ဂါ", "နော်ဝေ ဘွတ်ခ်မော်လ်", "နော်ဝေ နီးနောစ်", "နော်ဝေ", "စီချွမ် ရီ", "တောင် အွန်န်ဘီလီ", "အိုစီတန်", "", "ချပ်ချ် စလာဗစ်", "အိုရိုမို", "အိုရီရာ", "အိုဆဲတစ်ခ်", "ပန်ချာပီ", "ပါဠိ", "ပါရှန်", "ပိုလန်", "ပက်ရှ်တွန်း", "ပေါ်တူဂီ", "ခီချူဝါအိုဝါ", "ရောမ", "ရွန်ဒီ", "ရိုမေနီယား", "ရုရှ", "သင်္သကရိုက်", "ဆာဒီနီးယား", "စင်ဒီ", "မြောက် ဆာမိ", "ဆမိုအာ", "ဆန်ဂို", "ဆားဘီးယား", "စကော့တစ်ရှ် ဂေးလစ်ခ်", "ရှိုနာ", "စင်ဟာလာ", "ဆလိုဗက်", "ဆလိုဗေးနီးယား", "ဆိုမာလီ", "တောင်ပိုင်း ဆိုသို", "စပိန်", "ဆူဒန်", "ဆွာဟီလီ", "ဆွာဇီလန်", "ဆွီဒင်", "တမီးလ်", "တီလီဂူ", "တာဂျစ်", "ထိုင်း", "တီဂ်ရင်ယာ", "တိဘက်", "တာ့ခ်မင်နစ္စတန်", "ဖိလစ်ပိုင်", "တီဆဝါနာ", "တွန်ဂါ", "တူရကီ", "ဆွန်ဂါ", "တာတာ", "", "တဟီတီ", "ဝီဂါ", "ယူကရိန်း", "အူရ်ဒူ", "ဥဇဘတ်", "ဗင်န်ဒါ", "ဗီယက်နမ်", "ဗိုလာပိုက်", "ဝါလူးန်", "ဝေလ", "ဝူလိုဖ်", "အနောက် ဖရီစီရန်", "ဇိုစာ", "ရဟူဒီ", "ယိုရူဘာ", "ဂျွမ်", "ဇူးလူး"];
+
+// ca
+static L16: [&str; 184] = ["àfar", "abkhaz", "afrikaans", "àkan", "albanès", "amhàric", "àrab", "aragonès", "armeni", "assamès", "àvar", "avèstic", "aimara", "azerbaidjanès", "bambara", "baixkir", "basc", "belarús", "bengalí", "", "bislama", "bosnià", "bretó", "búlgar", "birmà", "català", "chamorro", "txetxè", "nyanja", "xinès", "txuvaix", "còrnic", "cors", "cree", "croat", "txec", "danès", "divehi", "neerlandès", "dzongka", "anglès", "esperanto", "estonià", "ewe", "feroès", "fijià", "finès", "francès", "ful", "gallec", "georgià", "alemany", "grec", "guaraní", "gujarati", "crioll d’Haití", "haussa", "hebreu", "herero", "hindi", "hiri motu", "hongarès", "interlingua", "indonesi", "interlingue", "irlandès", "igbo", "inupiak", "ido", "islandès", "italià", "inuktitut", "japonès", "javanès", "groenlandès", "kannada", "kanuri", "caixmiri", "kazakh", "khmer", "kikuiu", "ruandès", "kirguís", "komi", "kongo", "coreà", "kurd", "kuanyama", "llatí", "luxemburguès", "ganda", "limburguès", "lingala", "laosià", "lituà", "luba katanga", "letó", "manx", "macedoni", "malgaix", "malai", "malaiàlam", "maltès", "maori", "marathi", "marshallès", "mongol", "nauruà", "navaho", "ndebele septentrional", "nepalès", "ndonga", "noruec bokmål", "noruec nynorsk", "noruec", "yi sichuan", "ndebele meridional", "occità", "ojibwa", "eslau eclesiàstic", "oromo", "oriya", "osseta", "panjabi", "pali", "persa", "polonès", "paixtu", "portuguès", "quítxua", "retoromànic", "rundi", "romanès", "rus", "sànscrit", "sard", "sindi", "sami septentrional", "samoà", "sango", "serbi", "gaèlic escocès", "shona", "singalès", "eslovac", "eslovè", "somali", "sotho meridional", "espanyol", "sondanès", "suahili", "swazi", "suec", "tàmil", "telugu", "tadjik", "tai", "tigrinya", "tibetà", "turcman", "tagal", "setswana", "tongalès", "turc", "tsonga", "tàtar", "twi", "tahitià", "uigur", "ucraïnès", "urdú", "uzbek", "venda", "vietnamita", "volapük", "való", "gal·lès", "wòlof", "frisó occidental", "xosa", "ídix", "ioruba", "zhuang", "zulu"];
+
+// zh
+static L17: [&str; 184] = ["阿法尔语", "阿布哈西亚语", "南非荷兰语", "阿肯语", "阿尔巴尼亚语", "阿姆哈拉语", "阿拉伯语", "阿拉贡语", "亚美尼亚语", "阿萨姆语", "阿瓦尔语", "阿维斯塔语", "艾马拉语", "阿塞拜疆语", "班巴拉语", "巴什基尔语", "巴斯克语", "白俄罗斯语", "孟加拉语", "", "比斯拉马语", "波斯尼亚语", "布列塔尼语", "保加利亚语", "缅甸语", "加泰罗尼亚语", "查莫罗语", "车臣语", "齐切瓦语", "中文", "楚瓦什语", "康沃尔语", "科西嘉语", "克里语", "克罗地亚语", "捷克语", "丹麦语", "迪维希语", "荷兰语", "宗卡语", "英语", "世界语", "爱沙尼亚语", "埃维语", "法罗语", "斐济语", "芬兰语", "法语", "富拉语", "加利西亚语", "格鲁吉亚语", "德语", "希腊语", "瓜拉尼语", "古吉拉特语", "海地克里奥尔语", "豪萨语", "希伯来语", "赫雷罗语", "印地语", "希里莫图语", "匈牙利语", "国际语", "印度尼西亚语", "国际文字（E）", "爱尔兰语", "伊博语", "伊努皮克语", "伊多语", "冰岛语", "意大利语", "因纽特语", "日语", "爪哇语", "格陵兰语", "卡纳达语", "卡努里语", "克什米尔语", "哈萨克语", "高棉语", "吉库尤语", "卢旺达语", "吉尔吉斯语", "科米语", "刚果语", "韩语", "库尔德语", "宽亚玛语", "拉丁语", "卢森堡语", "卢干达语", "林堡语", "林加拉语", "老挝语", "立陶宛语", "鲁巴加丹加语", "拉脱维亚语", "马恩语", "马其顿语", "马拉加斯语", "马来语", "马拉雅拉姆语", "马耳他语", "毛利语", "马拉地语", "马绍尔语", "蒙古语", "瑙鲁语", "纳瓦霍语", "北恩德贝勒语", "尼泊尔语", "恩东加语", "书面挪威语", "挪威尼诺斯克语", "挪威语", "凉山彝语", "南恩德贝勒语", "奥克语", "奥吉布瓦语", "教会斯拉夫语", "奥罗莫语", "奥里亚语", "奥塞梯语", "旁遮普语", "巴利语", "波斯语", "波兰语", "普什图语", "葡萄牙语", "克丘亚语", "罗曼什语", "隆迪语", "罗马尼亚语", "俄语", "梵语", "萨丁语", "信德语", "北方萨米语", "萨摩亚语", "桑戈语", "塞尔维亚语", "苏格兰盖尔语", "绍纳语", "僧伽罗语", "斯洛伐克语", "斯洛文尼亚语", "索马里语", "南索托语", "西班牙语", "巽他语", "斯瓦希里语", "斯瓦蒂语", "瑞典语", "泰米尔语", "泰卢固语", "塔吉克语", "泰语", "提格利尼亚语", "藏语", "土库曼语", "他加禄语", "茨瓦纳语", "汤加语", "土耳其语", "聪加语", "鞑靼语", "契维语", "塔希提语", "维吾尔语", "乌克兰语", "乌尔都语", "乌兹别克语", "文达语", "越南语", "沃拉普克语", "瓦隆语", "威尔士语", "沃洛夫语", "西弗里西亚语", "科萨语", "意第绪语", "约鲁巴语", "壮语", "祖鲁语"];
+
+// cv
+static L18: [&str; 184] = ["афар чӗлхи", "абхаз чӗлхи", "африкаанс чӗлхи", "акан чӗлхи", "албан чӗлхи", "амхар чӗлхи", "араб чӗлхи", "арагон чӗлхи", "эрмен чӗлхи", "ассам чӗлхи", "", "", "", "азербайджан чӗлхи", "бамбара чӗлхи", "пушкӑрт чӗлхи", "баск чӗлхи", "беларус чӗлхи", "бенгал чӗлхи", "", "", "босни чӗлхи", "бретон чӗлхи", "болгар чӗлхи", "бирман чӗлхи", "каталан чӗлхи", "", "чечен чӗлхи", "ньянджа чӗлхи", "китай чӗлхи", "чӑваш чӗлхи", "корн чӗлхи", "корсикан чӗлхи", "", "хорват чӗлхи", "чех чӗлхи", "датчан чӗлхи", "мальдив чӗлхи", "нидерланд чӗлхи", "дзонг-кэ чӗлхи", "акӑлчан чӗлхи", "эсперанто чӗлхи", "эстон чӗлхи", "эве чӗлхи", "фарер чӗлхи", "", "финн чӗлхи", "француз чӗлхи", "фула чӗлхи", "галиси чӗлхи", "грузин чӗлхи", "нимӗҫ чӗлхи", "грек чӗлхи", "гуарани чӗлхи", "гуджарати чӗлхи", "гаитян чӗлхи", "хауса чӗлхи", "иврит чӗлхи", "", "хинди чӗлхи", "", "венгер чӗлхи", "интерлингва чӗлхи", "индонези чӗлхи", "интерлингве чӗлхи", "ирланд чӗлхи", "игбо чӗлхи", "", "идо чӗлхи", "исланд чӗлхи", "итальян чӗлхи", "инуктитут чӗлхи", "япон чӗлхи", "яван чӗлхи", "гренланд чӗлхи", "каннада чӗлхи", "", "кашмири чӗлхи", "казах чӗлхи", "кхмер чӗлхи", "кикуйю чӗлхи", "киньяруанда чӗлхи", "кӑркӑс чӗлхи", "", "", "корей чӗлхи", "курд чӗлхи", "", "латин чӗлхи", "люксембург чӗлхи", "ганда чӗлхи", "", "лингала чӗлхи", "лаос чӗлхи", "литва чӗлхи", "луба-катанга чӗлхи", "латыш чӗлхи", "мэн чӗлхи", "македон чӗлхи", "малагас чӗлхи", "малай чӗлхи", "малаялам чӗлхи", "мальта чӗлхи", "маори чӗлхи", "маратхи чӗлхи", "", "монгол чӗлхи", "", "навахо чӗлхи", "ҫур ҫӗр ндебеле чӗлхи", "непал чӗлхи", "", "букмол норвег чӗлхи", "нюнорск норвег чӗлхи", "норвег чӗлхи", "носу чӗлхи", "кӑнтӑр ндебеле чӗлхи", "окситан чӗлхи", "", "чиркӳ славян чӗлхи", "оромо чӗлхи", "ори чӗлхи", "осетин чӗлхи", "панджаби чӗлхи", "пали чӗлхи", "перс чӗлхи", "поляк чӗлхи", "пушту чӗлхи", "португал чӗлхи", "кечуа чӗлхи", "романш чӗлхи", "рунди чӗлхи", "румын чӗлхи", "вырӑс чӗлхи", "санскрит чӗлхи", "сардин чӗлхи", "синдхи чӗлхи", "ҫур ҫӗр саам чӗлхи", "", "санго чӗлхи", "серб чӗлхи", "гэл чӗлхи", "шона чӗлхи", "сингал чӗлхи", "словак чӗлхи", "словен чӗлхи", "сомали чӗлхи", "кӑнтӑр сото чӗлхи", "испан чӗлхи", "сундан чӗлхи", "суахили чӗлхи", "свази чӗлхи", "швед чӗлхи", "тамил чӗлхи", "телугу чӗлхи", "таджик чӗлхи", "тай чӗлхи", "тигринья чӗлхи", "тибет чӗлхи", "туркмен чӗлхи", "филиппин чӗлхи", "тсвана чӗлхи", "тонган чӗлхи", "турккӑ чӗлхи", "тсонга чӗлхи", "тутар чӗлхи", "", "", "уйгур чӗлхи", "украин чӗлхи", "урду чӗлхи", "узбек чӗлхи", "венда чӗлхи", "вьетнам чӗлхи", "волапюк чӗлхи", "валлон чӗлхи", "валли чӗлхи", "волоф чӗлхи", "анӑҫ фриз чӗлхи", "коса чӗлхи", "идиш чӗлхи", "йоруба чӗлхи", "чжуань чӗлхи", "зулу чӗлхи"];
+
+// hr
+static L19: [&str; 184] = ["afarski", "abhaski", "afrikaans", "akanski", "albanski", "amharski", "arapski", "aragonski", "armenski", "asamski", "avarski", "avestički", "ajmarski", "azerbajdžanski", "bambara", "baškirski", "baskijski", "bjeloruski", "bangla", "", "bislama", "bosanski", "bretonski", "bugarski", "burmanski", "katalonski", "chamorro", "čečenski", "njandža", "kineski", "čuvaški", "kornski", "korzički", "cree", "hrvatski", "češki", "danski", "divehi", "nizozemski", "dzongkha", "engleski", "esperanto", "estonski", "ewe", "ferojski", "fidžijski", "finski", "francuski", "fula", "galicijski", "gruzijski", "njemački", "grčki", "gvaranski", "gudžaratski", "haićanski kreolski", "hausa", "hebrejski", "herero", "hindski", "hiri motu", "mađarski", "interlingua", "indonezijski", "interligua", "irski", "igbo", "inupiaq", "ido", "islandski", "talijanski", "inuktitut", "japanski", "javanski", "kalaallisut", "karnatački", "kanuri", "kašmirski", "kazaški", "kmerski", "kikuyu", "kinyarwanda", "kirgiski", "komi", "kongo", "korejski", "kurdski", "kuanyama", "latinski", "luksemburški", "ganda", "limburški", "lingala", "laoski", "litavski", "luba-katanga", "latvijski", "manski", "makedonski", "malgaški", "malajski", "malajalamski", "malteški", "maorski", "marathski", "maršalski", "mongolski", "nauru", "navajo", "sjeverni ndebele", "nepalski", "ndonga", "norveški bokmål", "norveški nynorsk", "norveški", "sichuan ji", "južni ndebele", "okcitanski", "ojibwa", "crkvenoslavenski", "oromski", "orijski", "osetski", "pandžapski", "pali", "perzijski", "poljski", "paštunski", "portugalski", "kečuanski", "retoromanski", "rundi", "rumunjski", "ruski", "sanskrtski", "sardski", "sindski", "sjeverni sami", "samoanski", "sango", "srpski", "škotski gaelski", "shona", "sinhaleški", "slovački", "slovenski", "somalski", "sesotski", "španjolski", "sundanski", "svahili", "svati", "švedski", "tamilski", "teluški", "tadžički", "tajlandski", "tigrinja", "tibetski", "turkmenski", "tagalog", "cvana", "tonganski", "turski", "tsonga", "tatarski", "twi", "tahićanski", "ujgurski", "ukrajinski", "urdski", "uzbečki", "venda", "vijetnamski", "volapük", "valonski", "velški", "volof", "zapadnofrizijski", "xhosa", "jidiš", "jorupski", "zhuang", "zulu"];
+
+// cs
+static L20: [&str; 184] = ["afarština", "abcházština", "afrikánština", "akanština", "albánština", "amharština", "arabština", "aragonština", "arménština", "ásámština", "avarština", "avestánština", "ajmarština", "ázerbájdžánština", "bambarština", "baškirština", "baskičtina", "běloruština", "bengálština", "", "bislamština", "bosenština", "bretonština", "bulharština", "barmština", "katalánština", "čamoro", "čečenština", "ňandžština", "čínština", "čuvaština", "kornština", "korsičtina", "kríjština", "chorvatština", "čeština", "dánština", "maledivština", "nizozemština", "dzongkä", "angličtina", "esperanto", "estonština", "eweština", "faerština", "fidžijština", "finština", "francouzština", "fulbština", "galicijština", "gruzínština", "němčina", "řečtina", "guaranština", "gudžarátština", "haitština", "hauština", "hebrejština", "hererština", "hindština", "hiri motu", "maďarština", "interlingua", "indonéština", "interlingue", "irština", "igboština", "inupiakština", "ido", "islandština", "italština", "inuktitutština", "japonština", "javánština", "grónština", "kannadština", "kanuri", "kašmírština", "kazaština", "khmérština", "kikujština", "kiňarwandština", "kyrgyzština", "komijština", "konžština", "korejština", "kurdština", "kuaňamština", "latina", "lucemburština", "gandština", "limburština", "lingalština", "laoština", "litevština", "lubu-katanžština", "lotyština", "manština", "makedonština", "malgaština", "malajština", "malajálamština", "maltština", "maorština", "maráthština", "maršálština", "mongolština", "naurština", "navažština", "ndebele (Zimbabwe)", "nepálština", "ndondština", "norština (bokmål)", "norština (nynorsk)", "norština", "iština (sečuánská)", "ndebele (Jižní Afrika)", "okcitánština", "odžibvejština", "staroslověnština", "oromština", "urijština", "osetština", "paňdžábština", "pálí", "perština", "polština", "paštština", "portugalština", "kečuánština", "rétorománština", "kirundština", "rumunština", "ruština", "sanskrt", "sardština", "sindhština", "sámština (severní)", "samojština", "sangština", "srbština", "skotská gaelština", "šonština", "sinhálština", "slovenština", "slovinština", "somálština", "sotština (jižní)", "španělština", "sundština", "svahilština", "siswatština", "švédština", "tamilština", "telugština", "tádžičtina", "thajština", "tigrinijština", "tibetština", "turkmenština", "tagalog", "setswanština", "tongánština", "turečtina", "tsonga", "tatarština", "twi", "tahitština", "ujgurština", "ukrajinština", "urdština", "uzbečtina", "venda", "vietnamština", "volapük", "valonština", "velština", "wolofština", "fríština (západní)", "xhoština", "jidiš", "jorubština", "čuangština", "zuluština"];
+
+// da
+static L21: [&str; 184] = ["afar", "abkhasisk", "afrikaans", "akan", "albansk", "amharisk", "arabisk", "aragonsk", "armensk", "assamesisk", "avarisk", "avestan", "aymara", "aserbajdsjansk", "bambara", "bashkir", "baskisk", "belarusisk", "bengali", "", "bislama", "bosnisk", "bretonsk", "bulgarsk", "burmesisk", "catalansk", "chamorro", "tjetjensk", "nyanja", "kinesisk", "tjuvasjisk", "cornisk", "korsikansk", "cree", "kroatisk", "tjekkisk", "dansk", "divehi", "nederlandsk", "dzongkha", "engelsk", "esperanto", "estisk", "ewe", "færøsk", "fijiansk", "finsk", "fransk", "fulah", "galicisk", "georgisk", "tysk", "græsk", "guarani", "gujarati", "haitisk", "hausa", "hebraisk", "herero", "hindi", "hirimotu", "ungarsk", "interlingua", "indonesisk", "interlingue", "irsk", "igbo", "inupiaq", "ido", "islandsk", "italiensk", "inuktitut", "japansk", "javanesisk", "grønlandsk", "kannada", "kanuri", "kashmiri", "kasakhisk", "khmer", "kikuyu", "kinyarwanda", "kirgisisk", "komi", "kongo", "koreansk", "kurdisk", "kuanyama", "latin", "luxembourgsk", "ganda", "limburgsk", "lingala", "lao", "litauisk", "luba-Katanga", "lettisk", "manx", "makedonsk", "malagassisk", "malajisk", "malayalam", "maltesisk", "maori", "marathi", "marshallese", "mongolsk", "nauru", "navajo", "nordndebele", "nepalesisk", "ndonga", "bokmål", "nynorsk", "norsk", "sichuan yi", "sydndebele", "occitansk", "ojibwa", "kirkeslavisk", "oromo", "oriya", "ossetisk", "punjabi", "pali", "persisk", "polsk", "pashto", "portugisisk", "quechua", "rætoromansk", "rundi", "rumænsk", "russisk", "sanskrit", "sardinsk", "sindhi", "nordsamisk", "samoansk", "sango", "serbisk", "skotsk gælisk", "shona", "singalesisk", "slovakisk", "slovensk", "somali", "sydsotho", "spansk", "sundanesisk", "swahili", "swati", "svensk", "tamil", "telugu", "tadsjikisk", "thai", "tigrinya", "tibetansk", "turkmensk", "tagalog", "tswana", "tongansk", "tyrkisk", "tsonga", "tatarisk", "twi", "tahitiansk", "uygurisk", "ukrainsk", "urdu", "usbekisk", "venda", "vietnamesisk", "volapyk", "vallonsk", "walisisk", "wolof", "vestfrisisk", "xhosa", "jiddisch", "yoruba", "zhuang", "zulu"];
+
+// nl
+static L22: [&str; 184] = ["Afar", "Abchazisch", "Afrikaans", "Akan", "Albanees", "Amhaars", "Arabisch", "Aragonees", "Armeens", "Assamees", "Avarisch", "Avestisch", "Aymara", "Azerbeidzjaans", "Bambara", "Basjkiers", "Baskisch", "Belarussisch", "Bengaals", "", "Bislama", "Bosnisch", "Bretons", "Bulgaars", "Birmaans", "Catalaans", "Chamorro", "Tsjetsjeens", "Nyanja", "Chinees", "Tsjoevasjisch", "Cornish", "Corsicaans", "Cree", "Kroatisch", "Tsjechisch", "Deens", "Divehi", "Nederlands", "Dzongkha", "Engels", "Esperanto", "Estisch", "Ewe", "Faeröers", "Fijisch", "Fins", "Frans", "Fulah", "Galicisch", "Georgisch", "Duits", "Grieks", "Guaraní", "Gujarati", "Haïtiaans Creools", "Hausa", "Hebreeuws", "Herero", "Hindi", "Hiri Motu", "Hongaars", "Interlingua", "Indonesisch", "Interlingue", "Iers", "Igbo", "Inupiaq", "Ido", "IJslands", "Italiaans", "Inuktitut", "Japans", "Javaans", "Groenlands", "Kannada", "Kanuri", "Kasjmiri", "Kazachs", "Khmer", "Gikuyu", "Kinyarwanda", "Kirgizisch", "Komi", "Kongo", "Koreaans", "Koerdisch", "Kuanyama", "Latijn", "Luxemburgs", "Luganda", "Limburgs", "Lingala", "Laotiaans", "Litouws", "Luba-Katanga", "Lets", "Manx", "Macedonisch", "Malagassisch", "Maleis", "Malayalam", "Maltees", "Maori", "Marathi", "Marshallees", "Mongools", "Nauruaans", "Navajo", "Noord-Ndebele", "Nepalees", "Ndonga", "Noors - Bokmål", "Noors - Nynorsk", "Noors", "Yi", "Zuid-Ndbele", "Occitaans", "Ojibwa", "Kerkslavisch", "Afaan Oromo", "Odia", "Ossetisch", "Punjabi", "Pali", "Perzisch", "Pools", "Pasjtoe", "Portugees", "Quechua", "Reto-Romaans", "Kirundi", "Roemeens", "Russisch", "Sanskriet", "Sardijns", "Sindhi", "Noord-Samisch", "Samoaans", "Sango", "Servisch", "Schots-Gaelisch", "Shona", "Singalees", "Slowaaks", "Sloveens", "Somalisch", "Zuid-Sotho", "Spaans", "Soendanees", "Swahili", "Swazi", "Zweeds", "Tamil", "Telugu", "Tadzjieks", "Thai", "Tigrinya", "Tibetaans", "Turkmeens", "Tagalog", "Tswana", "Tongaans", "Turks", "Tsonga", "Tataars", "Twi", "Tahitiaans", "Oeigoers", "Oekraïens", "Urdu", "Oezbeeks", "Venda", "Vietnamees", "Volapük", "Waals", "Welsh", "Wolof", "Fries", "Xhosa", "Jiddisch", "Yoruba", "Zhuang", "Zoeloe"];
+
+// en
+static L23: [&str; 184] = ["Afar", "Abkhazian", "Afrikaans", "Akan", "Albanian", "Amharic", "Arabic", "Aragonese", "Armenian", "Assamese", "Avaric", "Avestan", "Aymara", "Azerbaijani", "Bambara", "Bashkir", "Basque", "Belarusian", "Bangla", "", "Bislama", "Bosnian", "Breton", "Bulgarian", "Burmese", "Catalan", "Chamorro", "Chechen", "Nyanja", "Chinese", "Chuvash", "Cornish", "Corsican", "Cree", "Croatian", "Czech", "Danish", "Divehi", "Dutch", "Dzongkha", "English", "Esperanto", "Estonian", "Ewe", "Faroese", "Fijian", "Finnish", "French", "Fula", "Galician", "Georgian", "German", "Greek", "Guarani", "Gujarati", "Haitian Creole", "Hausa", "Hebrew", "Herero", "Hindi", "Hiri Motu", "Hungarian", "Interlingua", "Indonesian", "Interlingue", "Irish", "Igbo", "Inupiaq", "Ido", "Icelandic", "Italian", "Inuktitut", "Japanese", "Javanese", "Kalaallisut", "Kannada", "Kanuri", "Kashmiri", "Kazakh", "Khmer", "Kikuyu", "Kinyarwanda", "Kyrgyz", "Komi", "Kongo", "Korean", "Kurdish", "Kuanyama", "Latin", "Luxembourgish", "Ganda", "Limburgish", "Lingala", "Lao", "Lithuanian", "Luba-Katanga", "Latvian", "Manx", "Macedonian", "Malagasy", "Malay", "Malayalam", "Maltese", "Māori", "Marathi", "Marshallese", "Mongolian", "Nauru", "Navajo", "North Ndebele", "Nepali", "Ndonga", "Norwegian Bokmål", "Norwegian Nynorsk", "Norwegian", "Sichuan Yi", "South Ndebele", "Occitan", "Ojibwa", "Church Slavic", "Oromo", "Odia", "Ossetic", "Punjabi", "Pali", "Persian", "Polish", "Pashto", "Portuguese", "Quechua", "Romansh", "Rundi", "Romanian", "Russian", "Sanskrit", "Sardinian", "Sindhi", "Northern Sami", "Samoan", "Sango", "Serbian", "Scottish Gaelic", "Shona", "Sinhala", "Slovak", "Slovenian", "Somali", "Southern Sotho", "Spanish", "Sundanese", "Swahili", "Swati", "Swedish", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinya", "Tibetan", "Turkmen", "Tagalog", "Tswana", "Tongan", "Turkish", "Tsonga", "Tatar", "Twi", "Tahitian", "Uyghur", "Ukrainian", "Urdu", "Uzbek", "Venda", "Vietnamese", "Volapük", "Walloon", "Welsh", "Wolof", "Western Frisian", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// eo
+static L24: [&str; 184] = ["afara", "abĥaza", "afrikansa", "akana", "albana", "amhara", "araba", "aragona", "armena", "asama", "avara", "", "ajmara", "azerbajĝana", "bambara", "baŝkira", "eŭska", "belorusa", "bengala", "", "Bislamo", "bosna", "bretona", "bulgara", "birma", "kataluna", "ĉamora", "ĉeĉena", "njanĝa", "ĉina", "ĉuvaŝa", "kornvala", "korsika", "", "kroata", "ĉeĥa", "dana", "maldiva", "nederlanda", "Dzonko", "angla", "Esperanto", "estona", "evea", "feroa", "fiĝia", "finna", "franca", "fula", "galega", "kartvela", "germana", "greka", "gvarania", "guĝarata", "haitia kreola", "haŭsa", "hebrea", "herera", "hindia", "", "hungara", "Interlingvao", "indonezia", "Interlingveo", "irlanda", "igba", "injupiaka", "Ido", "islanda", "itala", "inuktituta", "japana", "java", "gronlanda", "kanara", "kanura", "kaŝmira", "kazaĥa", "kmera", "kikuja", "ruanda", "kirgiza", "komia", "", "korea", "kurda", "kuanjama", "Latino", "luksemburga", "ganda", "limburga", "lingala", "laŭa", "litova", "katanga-luba", "latva", "manksa", "makedona", "malagasa", "malaja", "malajalama", "malta", "maoria", "marata", "marŝala", "mongola", "naura", "navaha", "nord-matabela", "nepala", "ndonga", "dannorvega", "novnorvega", "norvega", "jia", "sud-matabela", "okcitana", "", "malnovslava", "oroma", "Orijo", "oseta", "panĝaba", "palia", "persa", "pola", "paŝtua", "portugala", "keĉua", "romanĉa", "burunda", "rumana", "rusa", "Sanskrito", "sarda", "sinda", "nord-samea", "samoa", "sangoa", "serba", "skot-gaela", "ŝona", "sinhala", "slovaka", "slovena", "somala", "sota", "hispana", "sunda", "svahila", "svazia", "sveda", "tamila", "telugua", "taĝika", "taja", "tigraja", "tibeta", "turkmena", "tagaloga", "cvana", "tonga", "turka", "conga", "tatara", "", "tahitia", "ujgura", "ukraina", "Urduo", "uzbeka", "vendaa", "vjetnama", "Volapuko", "valona", "kimra", "volofa", "okcident-frisa", "ksosa", "judgermana", "joruba", "ĝuanga", "zulua"];
+
+// et
+static L25: [&str; 184] = ["afari", "abhaasi", "afrikaani", "akani", "albaania", "amhara", "araabia", "aragoni", "armeenia", "assami", "avaari", "avesta", "aimara", "aserbaidžaani", "bambara", "baškiiri", "baski", "valgevene", "bengali", "", "bislama", "bosnia", "bretooni", "bulgaaria", "birma", "katalaani", "tšamorro", "tšetšeeni", "njandža", "hiina", "tšuvaši", "korni", "korsika", "krii", "horvaadi", "tšehhi", "taani", "maldiivi", "hollandi", "dzongkha", "inglise", "esperanto", "eesti", "eve", "fääri", "fidži", "soome", "prantsuse", "fula", "galeegi", "gruusia", "saksa", "kreeka", "guaranii", "gudžarati", "haiti", "hausa", "heebrea", "herero", "hindi", "hirimotu", "ungari", "interlingua", "indoneesia", "interlingue", "iiri", "ibo", "injupiaki", "ido", "islandi", "itaalia", "inuktituti", "jaapani", "jaava", "grööni", "kannada", "kanuri", "kašmiiri", "kasahhi", "khmeeri", "kikuju", "ruanda", "kirgiisi", "komi", "kongo", "korea", "kurdi", "kvanjama", "ladina", "letseburgi", "ganda", "limburgi", "lingala", "lao", "leedu", "Katanga luba", "läti", "mänksi", "makedoonia", "malagassi", "malai", "malajalami", "malta", "maoori", "marathi", "maršalli", "mongoli", "nauru", "navaho", "põhjandebele", "nepali", "ndonga", "norra bokmål", "uusnorra", "norra", "nuosu", "lõunandebele", "oksitaani", "odžibvei", "kirikuslaavi", "oromo", "oria", "osseedi", "pandžabi", "paali", "pärsia", "poola", "puštu", "portugali", "ketšua", "romanši", "rundi", "rumeenia", "vene", "sanskriti", "sardi", "sindhi", "põhjasaami", "samoa", "sango", "serbia", "gaeli", "šona", "singali", "slovaki", "sloveeni", "somaali", "lõunasotho", "hispaania", "sunda", "suahiili", "svaasi", "rootsi", "tamili", "telugu", "tadžiki", "tai", "tigrinja", "tiibeti", "türkmeeni", "tagalogi", "tsvana", "tonga", "türgi", "tsonga", "tatari", "tvii", "tahiti", "uiguuri", "ukraina", "urdu", "usbeki", "venda", "vietnami", "volapüki", "vallooni", "kõmri", "volofi", "läänefriisi", "koosa", "jidiši", "joruba", "tšuangi", "suulu"];
+
+// ee
+static L26: [&str; 184] = ["", "abkhaziagbe", "afrikaangbe", "blugbe", "albaniagbe", "amhariagbe", "Arabiagbe", "", "armeniagbe", "assamegbe", "", "", "aymargbe", "azerbaijangbe", "bambaragbe", "", "basqugbe", "belarusiagbe", "Bengaligbe", "", "", "bosniagbe", "bretongbe", "bulgariagbe", "burmagbe", "katalagbe", "", "", "nyanjagbe", "Chinagbe", "", "", "", "", "kroatiagbe", "tsɛkgbe", "denmarkgbe", "divehgbe", "Hollandgbe", "dzongkhagbe", "iŋlisigbe", "esperantogbe", "estoniagbe", "eʋegbe", "", "fidzigbe", "finlanɖgbe", "Fransegbe", "", "galatagbe", "gɔgiagbe", "Germaniagbe", "grisigbe", "guarangbe", "gujarati", "haitigbe", "hausagbe", "hebrigbe", "", "Hindigbe", "", "hungarigbe", "", "Indonesiagbe", "", "irelanɖgbe", "igbogbe", "", "", "icelanɖgbe", "Italiagbe", "", "Japangbe", "dzavangbe", "", "kannadagbe", "", "kashmirgbe", "kazakhstangbe", "khmergbe", "", "ruwandagbe", "kirghistangbe", "", "", "Koreagbe", "kurdiagbe", "", "latin", "laksembɔggbe", "", "", "lingala", "laogbe", "lithuaniagbe", "", "latviagbe", "", "makedoniagbe", "malagasegbe", "malaygbe", "malayagbe", "maltagbe", "maorgbe", "marathiagbe", "", "mongoliagbe", "", "", "dziehe ndebelegbe", "nepalgbe", "", "nɔweigbe bokmål", "nɔweigbe ninɔsk", "nɔweigbe", "", "", "", "", "", "", "oriyagbe", "ossetiagbe", "pundzabgbe", "", "persiagbe", "Polishgbe", "pashtogbe", "Portuguesegbe", "kwetsuagbe", "romanshgbe", "rundigbe", "romaniagbe", "Russiagbe", "sanskrigbe", "", "sindhgbe", "dziehe samigbe", "samoagbe", "sangogbe", "serbiagbe", "", "shonagbe", "sinhalgbe", "slovakiagbe", "sloveniagbe", "somaliagbe", "anyiehe sothogbe", "Spanishgbe", "", "swahili", "swatgbe", "swedengbe", "tamilgbe", "telegugbe", "tadzikistangbe", "Thailandgbe", "tigrinyagbe", "tibetagbe", "tɛkmengbe", "tagalogbe", "tswanagbe", "tongagbe", "Turkishgbe", "tsongagbe", "", "", "tahitigbe", "uighurgbe", "ukraingbe", "urdugbe", "uzbekistangbe", "vendagbe", "vietnamgbe", "", "", "walesgbe", "wolofgbe", "", "xhosagbe", "", "yorubagbe", "", "zulugbe"];
+
+// fo
+static L27: [&str; 184] = ["afar", "abkhasiskt", "afrikaans", "akan", "albanskt", "amhariskt", "arabiskt", "aragoniskt", "armenskt", "assamesiskt", "avariskt", "", "aymara", "aserbajdsjanskt", "bambara", "bashkir", "baskiskt", "hvitarussiskt", "bangla", "", "bislama", "bosniskt", "bretonskt", "bulgarskt", "burmesiskt", "katalani", "chamorro", "tjetjenskt", "nyanja", "kinesiskt", "chuvash", "corniskt", "korsikanskt", "", "kroatiskt", "kekkiskt", "danskt", "divehi", "hálendskt", "dzongkha", "enskt", "esperanto", "estiskt", "ewe", "føroyskt", "fijimál", "finskt", "franskt", "fulah", "galisiskt", "georgiskt", "týskt", "grikskt", "guarani", "gujarati", "haitiskt creole", "hausa", "hebraiskt", "herero", "hindi", "", "ungarskt", "interlingua", "indonesiskt", "interlingue", "írskt", "igbo", "", "ido", "íslendskt", "italskt", "inuktitut", "japanskt", "javanskt", "kalaallisut", "kannada", "kanuri", "kashmiri", "kazakh", "khmer", "kikuyu", "kinyarwanda", "kyrgyz", "komi", "", "koreanskt", "kurdiskt", "kuanyama", "latín", "luksemborgskt", "ganda", "limburgiskt", "lingala", "laoskt", "litaviskt", "luba-katanga", "lettiskt", "manx", "makedónskt", "malagassiskt", "malaiiskt", "malayalam", "maltiskt", "maori", "marathi", "marshallesiskt", "mongolskt", "nauru", "navajo", "norður ndebele", "nepalskt", "ndonga", "norskt bókmál", "nýnorskt", "norskt", "sichuan yi", "suður ndebele", "occitanskt", "", "kirkju sláviskt", "oromo", "odia", "ossetiskt", "punjabi", "", "persiskt", "pólskt", "pashto", "portugiskiskt", "quechua", "retoromanskt", "rundi", "rumenskt", "russiskt", "sanskrit", "sardiskt", "sindhi", "norður sámiskt", "sámoiskt", "sango", "serbiskt", "skotskt gæliskt", "shona", "singalesiskt", "slovakiskt", "slovenskt", "somaliskt", "sesotho", "spanskt", "sundanesiskt", "swahili", "swatiskt", "svenskt", "tamilskt", "telugu", "tajik", "tailendskt", "tigrinya", "tibetskt", "turkmenskt", "tagalog", "tswana", "tonganskt", "turkiskt", "tsonga", "tatar", "twi", "tahitiskt", "uyghur", "ukrainskt", "urdu", "usbekiskt", "venda", "vjetnamesiskt", "volapykk", "walloon", "walisiskt", "wolof", "vestur frísiskt", "xhosa", "jiddiskt", "yoruba", "zhuang", "sulu"];
+
+// fi
+static L28: [&str; 184] = ["afar", "abhaasi", "afrikaans", "akan", "albania", "amhara", "arabia", "aragonia", "armenia", "assami", "avaari", "avesta", "aimara", "azeri", "bambara", "baškiiri", "baski", "valkovenäjä", "bengali", "", "bislama", "bosnia", "bretoni", "bulgaria", "burma", "katalaani", "tšamorro", "tšetšeeni", "njandža", "kiina", "tšuvassi", "korni", "korsika", "cree", "kroatia", "tšekki", "tanska", "divehi", "hollanti", "dzongkha", "englanti", "esperanto", "viro", "ewe", "fääri", "fidži", "suomi", "ranska", "fulani", "galicia", "georgia", "saksa", "kreikka", "guarani", "gudžarati", "haiti", "hausa", "heprea", "herero", "hindi", "hiri-motu", "unkari", "interlingua", "indonesia", "interlingue", "iiri", "igbo", "inupiaq", "ido", "islanti", "italia", "inuktitut", "japani", "jaava", "kalaallisut", "kannada", "kanuri", "kašmiri", "kazakki", "khmer", "kikuju", "ruanda", "kirgiisi", "komi", "kongo", "korea", "kurdi", "kuanjama", "latina", "luxemburg", "ganda", "limburg", "lingala", "lao", "liettua", "katanganluba", "latvia", "manksi", "makedonia", "malagassi", "malaiji", "malajalam", "malta", "maori", "marathi", "marshall", "mongoli", "nauru", "navajo", "pohjois-ndebele", "nepali", "ndonga", "norjan bokmål", "norjan nynorsk", "norja", "sichuanin-yi", "etelä-ndebele", "oksitaani", "odžibwa", "kirkkoslaavi", "oromo", "orija", "osseetti", "pandžabi", "paali", "persia", "puola", "paštu", "portugali", "ketšua", "retoromaani", "rundi", "romania", "venäjä", "sanskrit", "sardi", "sindhi", "pohjoissaame", "samoa", "sango", "serbia", "gaeli", "šona", "sinhala", "slovakki", "sloveeni", "somali", "eteläsotho", "espanja", "sunda", "swahili", "swazi", "ruotsi", "tamili", "telugu", "tadžikki", "thai", "tigrinja", "tiibet", "turkmeeni", "tagalog", "tswana", "tonga", "turkki", "tsonga", "tataari", "twi", "tahiti", "uiguuri", "ukraina", "urdu", "uzbekki", "venda", "vietnam", "volapük", "valloni", "kymri", "wolof", "länsifriisi", "xhosa", "jiddiš", "joruba", "zhuang", "zulu"];
+
+// fr
+static L29: [&str; 184] = ["afar", "abkhaze", "afrikaans", "akan", "albanais", "amharique", "arabe", "aragonais", "arménien", "assamais", "avar", "avestique", "aymara", "azerbaïdjanais", "bambara", "bachkir", "basque", "biélorusse", "bengali", "", "bichelamar", "bosniaque", "breton", "bulgare", "birman", "catalan", "chamorro", "tchétchène", "chewa", "chinois", "tchouvache", "cornique", "corse", "cree", "croate", "tchèque", "danois", "maldivien", "néerlandais", "dzongkha", "anglais", "espéranto", "estonien", "éwé", "féroïen", "fidjien", "finnois", "français", "peul", "galicien", "géorgien", "allemand", "grec", "guarani", "goudjarati", "créole haïtien", "haoussa", "hébreu", "héréro", "hindi", "hiri motu", "hongrois", "interlingua", "indonésien", "interlingue", "irlandais", "igbo", "inupiaq", "ido", "islandais", "italien", "inuktitut", "japonais", "javanais", "groenlandais", "kannada", "kanouri", "cachemiri", "kazakh", "khmer", "kikuyu", "kinyarwanda", "kirghize", "komi", "kikongo", "coréen", "kurde", "kuanyama", "latin", "luxembourgeois", "ganda", "limbourgeois", "lingala", "lao", "lituanien", "luba-katanga (kiluba)", "letton", "mannois", "macédonien", "malgache", "malais", "malayalam", "maltais", "maori", "marathi", "marshallais", "mongol", "nauruan", "navajo", "ndébélé du Nord", "népalais", "ndonga", "norvégien bokmål", "norvégien nynorsk", "norvégien", "yi du Sichuan", "ndébélé du Sud", "occitan", "ojibwa", "slavon d’église", "oromo", "odia", "ossète", "pendjabi", "pali", "persan", "polonais", "pachto", "portugais", "quechua", "romanche", "roundi", "roumain", "russe", "sanskrit", "sarde", "sindhi", "same du Nord", "samoan", "sango", "serbe", "gaélique écossais", "shona", "cingalais", "slovaque", "slovène", "somali", "sotho du Sud", "espagnol", "soundanais", "swahili", "swati", "suédois", "tamoul", "télougou", "tadjik", "thaï", "tigrigna", "tibétain", "turkmène", "tagalog", "tswana", "tongien", "turc", "tsonga", "tatar", "twi", "tahitien", "ouïghour", "ukrainien", "ourdou", "ouzbek", "venda", "vietnamien", "volapük", "wallon", "gallois", "wolof", "frison occidental", "xhosa", "yiddish", "yoruba", "zhuang", "zoulou"];
+
+// gl
+static L30: [&str; 184] = ["afar", "abkhazo", "afrikaans", "akan", "albanés", "amhárico", "árabe", "aragonés", "armenio", "assamés", "avar", "", "aimará", "acerbaixano", "bambara", "baxkir", "éuscaro", "belaruso", "bengalí", "", "bislama", "bosníaco", "bretón", "búlgaro", "birmano", "catalán", "chamorro", "checheno", "chewa", "chinés", "chuvaxo", "córnico", "corso", "", "croata", "checo", "dinamarqués", "divehi", "neerlandés", "dzongkha", "inglés", "esperanto", "estoniano", "ewe", "feroés", "fixiano", "finés", "francés", "fula", "galego", "xeorxiano", "alemán", "grego", "guaraní", "guxarati", "crioulo haitiano", "hausa", "hebreo", "herero", "hindi", "", "húngaro", "interlingua", "indonesio", "occidental", "irlandés", "igbo", "", "ido", "islandés", "italiano", "inuktitut", "xaponés", "xavanés", "kalaallisut", "kannará", "kanuri", "caxemirés", "kazako", "khmer", "kikuyu", "kiñaruanda", "kirguiz", "komi", "kongo", "coreano", "kurdo", "kuanyama", "latín", "luxemburgués", "ganda", "limburgués", "lingala", "laosiano", "lituano", "luba-katanga", "letón", "manx", "macedonio", "malgaxe", "malaio", "malabar", "maltés", "maorí", "marathi", "marshalés", "mongol", "nauruano", "navajo", "ndebele setentrional", "nepalí", "ndonga", "noruegués bokmål", "noruegués nynorsk", "noruegués", "yi sichuanés", "ndebele meridional", "occitano", "", "eslavo eclesiástico", "oromo", "odiá", "ossetio", "panxabí", "", "persa", "polaco", "paxto", "portugués", "quechua", "romanche", "rundi", "romanés", "ruso", "sánscrito", "sardo", "sindhi", "saami setentrional", "samoano", "sango", "serbio", "gaélico escocés", "shona", "cingalés", "eslovaco", "esloveno", "somalí", "sesotho", "español", "sundanés", "suahili", "suazi", "sueco", "támil", "telugu", "taxico", "tailandés", "tigriña", "tibetano", "turkmeno", "tagalo", "tswana", "tongano", "turco", "tsonga", "tártaro", "twi", "tahitiano", "uigur", "ucraíno", "urdú", "uzbeko", "venda", "vietnamita", "volapuk", "valón", "galés", "wólof", "frisón occidental", "xhosa", "yiddish", "ioruba", "zhuang", "zulú"];
+
+// ka
+static L31: [&str; 184] = ["აფარი", "აფხაზური", "აფრიკაანსი", "აკანი", "ალბანური", "ამჰარული", "არაბული", "არაგონული", "სომხური", "ასამური", "ხუნძური", "ავესტური", "აიმარა", "აზერბაიჯანული", "ბამბარა", "ბაშკირული", "ბასკური", "ბელარუსული", "ბენგალური", "", "ბისლამა", "ბოსნიური", "ბრეტონული", "ბულგარული", "ბირმული", "კატალანური", "ჩამორო", "ჩეჩნური", "ნიანჯა", "ჩინური", "ჩუვაშური", "კორნული", "კორსიკული", "კრი", "ხორვატული", "ჩეხური", "დანიური", "დივეჰი", "ნიდერლანდური", "ძონგკხა", "ინგლისური", "ესპერანტო", "ესტონური", "ევე", "ფარერული", "ფიჯი", "ფინური", "ფრანგული", "ფულა", "გალისიური", "ქართული", "გერმანული", "ბერძნული", "გუარანი", "გუჯარათი", "ჰაიტიური კრეოლი", "ჰაუსა", "ებრაული", "ჰერერო", "ჰინდი", "", "უნგრული", "ინტერლინგუალური", "ინდონეზიური", "ინტერლინგი", "ირლანდიური", "იგბო", "", "იდო", "ისლანდიური", "იტალიური", "ინუკტიტუტი", "იაპონური", "იავური", "დასავლეთ გრენლანდიური", "კანადა", "კანური", "ქაშმირული", "ყაზახური", "ქმერული", "კიკუიუ", "კინიარუანდა", "ყირგიზული", "კომი", "კონგო", "კორეული", "ქურთული", "კუნამა", "ლათინური", "ლუქსემბურგული", "განდა", "ლიმბურგული", "ლინგალა", "ლაოსური", "ლიეტუვური", "ლუბა-კატანგა", "ლატვიური", "მენური", "მაკედონური", "მალაგასიური", "მალაიური", "მალაიალამური", "მალტური", "მაორი", "მარათჰი", "მარშალური", "მონღოლური", "ნაურუ", "ნავახო", "ჩრდილოეთ ნდებელე", "ნეპალური", "ნდონგა", "ნორვეგიული ბუკმოლი", "ნორვეგიული ნიუნორსკი", "ნორვეგიული", "სიჩუანის ი", "სამხრეთ ნდებელური", "ოქსიტანური", "ოჯიბვე", "საეკლესიო სლავური", "ორომო", "ორია", "ოსური", "პენჯაბური", "პალი", "სპარსული", "პოლონური", "პუშტუ", "პორტუგალიური", "კეჩუა", "რეტორომანული", "რუნდი", "რუმინული", "რუსული", "სანსკრიტი", "სარდინიული", "სინდჰური", "ჩრდილოეთ საამური", "სამოა", "სანგო", "სერბული", "შოტლანდიური გელური", "შონა", "სინჰალური", "სლოვაკური", "სლოვენური", "სომალიური", "სამხრეთ სოთოს ენა", "ესპანური", "სუნდური", "სუაჰილი", "სუატი", "შვედური", "ტამილური", "ტელუგუ", "ტაჯიკური", "ტაილანდური", "ტიგრინია", "ტიბეტური", "თურქმენული", "ფილიპინური", "ტსვანა", "ტონგანური", "თურქული", "ტსონგა", "თათრული", "თუი", "ტაიტური", "უიღურული", "უკრაინული", "ურდუ", "უზბეკური", "ვენდა", "ვიეტნამური", "ვოლაპუკი", "ვალონური", "უელსური", "ვოლოფური", "დასავლეთფრიზიული", "ქჰოსა", "იდიში", "იორუბა", "ზჰუანგი", "ზულუ"];
+
+// de
+static L32: [&str; 184] = ["Afar", "Abchasisch", "Afrikaans", "Akan", "Albanisch", "Amharisch", "Arabisch", "Aragonesisch", "Armenisch", "Assamesisch", "Awarisch", "Avestisch", "Aymara", "Aserbaidschanisch", "Bambara", "Baschkirisch", "Baskisch", "Belarussisch", "Bengalisch", "", "Bislama", "Bosnisch", "Bretonisch", "Bulgarisch", "Birmanisch", "Katalanisch", "Chamorro", "Tschetschenisch", "Nyanja", "Chinesisch", "Tschuwaschisch", "Kornisch", "Korsisch", "Cree", "Kroatisch", "Tschechisch", "Dänisch", "Dhivehi", "Niederländisch", "Dzongkha", "Englisch", "Esperanto", "Estnisch", "Ewe", "Färöisch", "Fidschi", "Finnisch", "Französisch", "Ful", "Galicisch", "Georgisch", "Deutsch", "Griechisch", "Guaraní", "Gujarati", "Haiti-Kreolisch", "Haussa", "Hebräisch", "Herero", "Hindi", "Hiri-Motu", "Ungarisch", "Interlingua", "Indonesisch", "Interlingue", "Irisch", "Igbo", "Inupiak", "Ido", "Isländisch", "Italienisch", "Inuktitut", "Japanisch", "Javanisch", "Grönländisch", "Kannada", "Kanuri", "Kaschmiri", "Kasachisch", "Khmer", "Kikuyu", "Kinyarwanda", "Kirgisisch", "Komi", "Kongolesisch", "Koreanisch", "Kurdisch", "Kwanyama", "Latein", "Luxemburgisch", "Ganda", "Limburgisch", "Lingala", "Laotisch", "Litauisch", "Luba-Katanga", "Lettisch", "Manx", "Mazedonisch", "Malagasy", "Malaiisch", "Malayalam", "Maltesisch", "Māori", "Marathi", "Marschallesisch", "Mongolisch", "Nauruisch", "Navajo", "Nord-Ndebele", "Nepalesisch", "Ndonga", "Norwegisch (Bokmål)", "Norwegisch (Nynorsk)", "Norwegisch", "Yi", "Süd-Ndebele", "Okzitanisch", "Ojibwa", "Kirchenslawisch", "Oromo", "Oriya", "Ossetisch", "Punjabi", "Pali", "Persisch", "Polnisch", "Paschtu", "Portugiesisch", "Quechua", "Rätoromanisch", "Rundi", "Rumänisch", "Russisch", "Sanskrit", "Sardisch", "Sindhi", "Nordsamisch", "Samoanisch", "Sango", "Serbisch", "Gälisch (Schottland)", "Shona", "Singhalesisch", "Slowakisch", "Slowenisch", "Somali", "Süd-Sotho", "Spanisch", "Sundanesisch", "Suaheli", "Swazi", "Schwedisch", "Tamil", "Telugu", "Tadschikisch", "Thailändisch", "Tigrinya", "Tibetisch", "Turkmenisch", "Tagalog", "Tswana", "Tongaisch", "Türkisch", "Tsonga", "Tatarisch", "Twi", "Tahitisch", "Uigurisch", "Ukrainisch", "Urdu", "Usbekisch", "Venda", "Vietnamesisch", "Volapük", "Wallonisch", "Walisisch", "Wolof", "Westfriesisch", "Xhosa", "Jiddisch", "Yoruba", "Zhuang", "Zulu"];
+
+// el
+static L33: [&str; 184] = ["Αφάρ", "Αμπχαζικά", "Αφρικάανς", "Ακάν", "Αλβανικά", "Αμχαρικά", "Αραβικά", "Αραγονικά", "Αρμενικά", "Ασαμικά", "Αβαρικά", "Αβεστάν", "Αϊμάρα", "Αζερμπαϊτζανικά", "Μπαμπάρα", "Μπασκίρ", "Βασκικά", "Λευκορωσικά", "Βεγγαλικά", "", "Μπισλάμα", "Βοσνιακά", "Βρετονικά", "Βουλγαρικά", "Βιρμανικά", "Καταλανικά", "Τσαμόρο", "Τσετσενικά", "Νιάντζα", "Κινεζικά", "Τσουβασικά", "Κορνουαλικά", "Κορσικανικά", "Κρι", "Κροατικά", "Τσεχικά", "Δανικά", "Ντιβέχι", "Ολλανδικά", "Ντζόνγκχα", "Αγγλικά", "Εσπεράντο", "Εσθονικά", "Έουε", "Φεροϊκά", "Φίτζι", "Φινλανδικά", "Γαλλικά", "Φουλά", "Γαλικιανά", "Γεωργιανά", "Γερμανικά", "Ελληνικά", "Γκουαρανί", "Γκουτζαρατικά", "Αϊτιανά", "Χάουσα", "Εβραϊκά", "Χερέρο", "Χίντι", "Χίρι Μότου", "Ουγγρικά", "Ιντερλίνγκουα", "Ινδονησιακά", "Ιντερλίνγκουε", "Ιρλανδικά", "Ίγκμπο", "Ινουπιάκ", "Ίντο", "Ισλανδικά", "Ιταλικά", "Ινούκτιτουτ", "Ιαπωνικά", "Ιαβανικά", "Καλαάλισουτ", "Κανάντα", "Κανούρι", "Κασμιρικά", "Καζακικά", "Χμερ", "Κικούγιου", "Κινιαρουάντα", "Κιργιζικά", "Κόμι", "Κονγκό", "Κορεατικά", "Κουρδικά", "Κουανιάμα", "Λατινικά", "Λουξεμβουργιανά", "Γκάντα", "Λιμβουργιανά", "Λινγκάλα", "Λαοτινά", "Λιθουανικά", "Λούμπα-Κατάνγκα", "Λετονικά", "Μανξ", "Σλαβομακεδονικά", "Μαλγασικά", "Μαλαισιανά", "Μαλαγιαλαμικά", "Μαλτεζικά", "Μαορί", "Μαραθικά", "Μαρσαλέζικα", "Μογγολικά", "Ναούρου", "Νάβαχο", "Βόρεια Ντεμπέλε", "Νεπαλικά", "Ντόνγκα", "Νορβηγικά Μποκμάλ", "Νορβηγικά Νινόρσκ", "Νορβηγικά", "Σίτσουαν Γι", "Νότια Ντεμπέλε", "Οξιτανικά", "Οζιβίγουα", "Εκκλησιαστικά Σλαβικά", "Ορόμο", "Όντια", "Οσετικά", "Παντζαπικά", "Πάλι", "Περσικά", "Πολωνικά", "Πάστο", "Πορτογαλικά", "Κέτσουα", "Ρομανικά", "Ρούντι", "Ρουμανικά", "Ρωσικά", "Σανσκριτικά", "Σαρδηνιακά", "Σίντι", "Βόρεια Σάμι", "Σαμοανά", "Σάνγκο", "Σερβικά", "Σκωτικά Κελτικά", "Σόνα", "Σινχαλεζικά", "Σλοβακικά", "Σλοβενικά", "Σομαλικά", "Νότια Σόθο", "Ισπανικά", "Σουνδανικά", "Σουαχίλι", "Σουάτι", "Σουηδικά", "Ταμιλικά", "Τελούγκου", "Τατζικικά", "Ταϊλανδικά", "Τιγκρινικά", "Θιβετιανά", "Τουρκμενικά", "Τάγκαλογκ", "Τσουάνα", "Τονγκανικά", "Τουρκικά", "Τσόνγκα", "Ταταρικά", "Τούι", "Ταϊτιανά", "Ουιγουρικά", "Ουκρανικά", "Ούρντου", "Ουζμπεκικά", "Βέντα", "Βιετναμικά", "Βολαπιούκ", "Βαλλωνικά", "Ουαλικά", "Γουόλοφ", "Δυτικά Φριζικά", "Κόσα", "Γίντις", "Γιορούμπα", "Ζουάνγκ", "Ζουλού"];
+
+// gu
+static L34: [&str; 184] = ["અફાર", "અબખાજિયન", "આફ્રિકન્સ", "અકાન", "અલ્બેનિયન", "એમ્હારિક", "અરબી", "અર્ગોનીઝ", "આર્મેનિયન", "આસામી", "અવેરિક", "અવેસ્તન", "આયમારા", "અઝરબૈજાની", "બામ્બારા", "બશ્કીર", "બાસ્ક", "બેલારુશિયન", "બાંગ્લા", "", "બિસ્લામા", "બોસ્નિયન", "બ્રેટોન", "બલ્ગેરિયન", "બર્મીઝ", "કતલાન", "કેમોરો", "ચેચન", "ન્યાન્જા", "ચાઇનીઝ", "ચૂવાશ", "કોર્નિશ", "કોર્સિકન", "ક્રી", "ક્રોએશિયન", "ચેક", "ડેનિશ", "દિવેહી", "ડચ", "ડ્ઝોંગ્ખા", "અંગ્રેજી", "એસ્પેરાન્ટો", "એસ્ટોનિયન", "ઈવ", "ફોરિસ્ત", "ફીજીયન", "ફિનિશ", "ફ્રેન્ચ", "ફુલા", "ગેલિશિયન", "જ્યોર્જિયન", "જર્મન", "ગ્રીક", "ગુઆરાની", "ગુજરાતી", "હૈતિઅન ક્રેઓલે", "હૌસા", "હીબ્રુ", "હેરેરો", "હિન્દી", "હિરી મોટૂ", "હંગેરિયન", "ઇંટરલિંગુઆ", "ઇન્ડોનેશિયન", "ઇંટરલિંગ", "આઇરિશ", "ઇગ્બો", "ઇનુપિયાક", "ઈડો", "આઇસલેન્ડિક", "ઇટાલિયન", "ઇનુકિટૂટ", "જાપાનીઝ", "જાવાનીસ", "કલાલ્લિસુત", "કન્નડ", "કનુરી", "કાશ્મીરી", "કઝાખ", "ખ્મેર", "કિકુયૂ", "કિન્યારવાન્ડા", "કિર્ગીઝ", "કોમી", "કોંગો", "કોરિયન", "કુર્દિશ", "ક્વાન્યામા", "લેટિન", "લક્ઝેમબર્ગિશ", "ગાંડા", "લિંબૂર્ગિશ", "લિંગાલા", "લાઓ", "લિથુઆનિયન", "લૂબા-કટાંગા", "લાતવિયન", "માંક્સ", "મેસેડોનિયન", "મલાગસી", "મલય", "મલયાલમ", "માલ્ટિઝ", "માઓરી", "મરાઠી", "માર્શલીઝ", "મોંગોલિયન", "નાઉરૂ", "નાવાજો", "ઉત્તર દેબેલ", "નેપાળી", "ડોન્ગા", "નોર્વેજિયન બોકમાલ", "નોર્વેજિયન નાયનૉર્સ્ક", "નૉર્વેજીયન", "સિચુઆન યી", "દક્ષિણ દેબેલ", "ઓક્સિટન", "ઓજિબ્વા", "ચર્ચ સ્લાવિક", "ઓરોમો", "ઉડિયા", "ઓસ્સેટિક", "પંજાબી", "પાલી", "ફારસી", "પોલીશ", "પશ્તો", "પોર્ટુગીઝ", "ક્વેચુઆ", "રોમાન્શ", "રૂન્દી", "રોમાનિયન", "રશિયન", "સંસ્કૃત", "સાર્દિનિયન", "સિંધી", "ઉત્તરી સામી", "સામોન", "સાંગો", "સર્બિયન", "સ્કોટીસ ગેલિક", "શોના", "સિંહાલી", "સ્લોવૅક", "સ્લોવેનિયન", "સોમાલી", "દક્ષિણ સોથો", "સ્પેનિશ", "સંડેનીઝ", "સ્વાહિલી", "સ્વાતી", "સ્વીડિશ", "તમિલ", "તેલુગુ", "તાજીક", "થાઈ", "ટાઇગ્રિનિયા", "તિબેટીયન", "તુર્કમેન", "ટાગાલોગ", "ત્સ્વાના", "ટોંગાન", "ટર્કિશ", "સોંગા", "તતાર", "ટ્વાઇ", "તાહિતિયન", "ઉઇગુર", "યુક્રેનિયન", "ઉર્દૂ", "ઉઝ્બેક", "વેન્દા", "વિયેતનામીસ", "વોલાપુક", "વાલૂન", "વેલ્શ", "વોલોફ", "પશ્ચિમિ ફ્રિશિયન", "ખોસા", "યિદ્દિશ", "યોરૂબા", "ઝુઆગ", "ઝુલુ"];
+
+// ht
+static L35: [&str; 184] = ["afar", "abkhaze", "afrikaans", "akan", "albanais", "amharique", "arabe", "aragonais", "arménien", "assamais", "avar", "avestique", "aymara", "azerbaïdjanais", "bambara", "bachkir", "basque", "biélorusse", "bengali", "", "bichelamar", "bosniaque", "breton", "bulgare", "birman", "catalan", "chamorro", "tchétchène", "chewa", "chinois", "tchouvache", "cornique", "corse", "cree", "croate", "tchèque", "danois", "maldivien", "néerlandais", "dzongkha", "anglais", "espéranto", "estonien", "éwé", "féroïen", "fidjien", "finnois", "français", "peul", "galicien", "géorgien", "allemand", "grec", "guarani", "goudjarati", "Kreyòl Ayisyen", "haoussa", "hébreu", "héréro", "hindi", "hiri motu", "hongrois", "interlingua", "indonésien", "interlingue", "irlandais", "igbo", "inupiaq", "ido", "islandais", "italien", "inuktitut", "japonais", "javanais", "groenlandais", "kannada", "kanouri", "cachemiri", "kazakh", "khmer", "kikuyu", "kinyarwanda", "kirghize", "komi", "kikongo", "coréen", "kurde", "kuanyama", "latin", "luxembourgeois", "ganda", "limbourgeois", "lingala", "lao", "lituanien", "luba-katanga (kiluba)", "letton", "mannois", "macédonien", "malgache", "malais", "malayalam", "maltais", "maori", "marathi", "marshallais", "mongol", "nauruan", "navajo", "ndébélé du Nord", "népalais", "ndonga", "norvégien bokmål", "norvégien nynorsk", "norvégien", "yi du Sichuan", "ndébélé du Sud", "occitan", "ojibwa", "slavon d’église", "oromo", "odia", "ossète", "pendjabi", "pali", "persan", "polonais", "pachto", "portugais", "quechua", "romanche", "roundi", "roumain", "russe", "sanskrit", "sarde", "sindhi", "same du Nord", "samoan", "sango", "serbe", "gaélique écossais", "shona", "cingalais", "slovaque", "slovène", "somali", "sotho du Sud", "espagnol", "soundanais", "swahili", "swati", "suédois", "tamoul", "télougou", "tadjik", "thaï", "tigrigna", "tibétain", "turkmène", "tagalog", "tswana", "tongien", "turc", "tsonga", "tatar", "twi", "tahitien", "ouïghour", "ukrainien", "ourdou", "ouzbek", "venda", "vietnamien", "volapük", "wallon", "gallois", "wolof", "frison occidental", "xhosa", "yiddish", "yoruba", "zhuang", "zoulou"];
+
+// ha
+static L36: [&str; 184] = ["", "Abkhazian", "Afrikaans", "Akan", "Albaniyanci", "Amharik", "Larabci", "Aragonesanci", "Armeniyanci", "Asamisanci", "Avaric", "", "Aymaranci", "Azerbaijanci", "Bambara", "Bashkir", "Basque", "Belarusanci", "Bengali", "", "Bislama", "Bosniyanci", "Buretananci", "Bulgariyanci", "Burmanci", "Kataloniyanci", "Chamorro", "Chechen", "Nyanja", "Harshen Sinanci", "Chuvash", "Cornish", "Corsican", "", "Kuroshiyan", "Cek", "Danish", "Divehi", "Holanci", "Dzongkha", "Turanci", "Esperanto", "Istoniyanci", "Ewe", "Faroese", "Fijiyanci", "Yaren mutanen Finland", "Faransanci", "Fula", "Bagalike", "Jojiyanci", "Jamusanci", "Girkanci", "Guwaraniyanci", "Gujarati", "Haitian Creole", "Hausa", "Ibrananci", "Herero", "Harshen Hindi", "", "Harshen Hungari", "Yare Tsakanin Kasashe", "Harshen Indunusiya", "Intagulanci", "Dan Irish", "Igbo", "", "Ido", "Yaren mutanen Iceland", "Italiyanci", "Inuktitut", "Japananci", "Javananci", "Kalaallisut", "Kannada", "Kanuri", "Kashmiri", "Kazakh", "Harshen Kimar", "Kikuyu", "Kinyarwanda", "Kirgizanci", "Komi", "", "Harshen Koriya", "Kurdanci", "Kuanyama", "Dan Kabilar Latin", "Luxembourgish", "Ganda", "Limburgish", "Lingala", "Lao", "Lituweniyanci", "Luba-Katanga", "Latbiyanci", "Manx", "Dan Masedoniya", "Malagasi", "Harshen Malay", "Malayalamci", "Harshen Maltis", "Maori", "Maratinci", "Marshallese", "Mongoliyanci", "Nauru", "Navajo", "North Ndebele", "Nepali", "Ndonga", "Norwegian Bokmål", "Norwegian Nynorsk", "Harhsen Norway", "Sichuan Yi", "Ndebele na Kudu", "Ositanci", "", "Church Slavic", "Oromo", "Odiya", "Ossetic", "Punjabi", "", "Farisanci", "Harshen Polan", "Pashtanci", "Harshen Potugis", "Quechua", "Romansh", "Rundi", "Romaniyanci", "Rashanci", "Sanskrit", "Sardiniyanci", "Sindiyanci", "Sami ta Arewa", "Samoan", "Sango", "Sabiyan", "Kʼabilan Scots Gaelic", "Shona", "Sinhalanci", "Basulke", "Basulabe", "Somalianci", "Kudancin Sotho", "Sifaniyanci", "Harshen Sundanese", "Harshen Suwahili", "Swati", "Harshen Suwedan", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinyanci", "Tibetan", "Tukmenistanci", "Dan Filifin", "Tswana", "Tonganci", "Harshen Turkiyya", "Tsonga", "Tatar", "Tiwiniyanci", "Tahitiyanci", "Ugiranci", "Harshen Yukuren", "Urdanci", "Uzbek", "Venda", "Harshen Biyetinam", "Volapük", "Walloon", "Welsh", "Wolof", "Frisian ta Yamma", "Xhosa", "Yaren Yiddish", "Yarbanci", "Zhuang", "Harshen Zulu"];
+
+// he
+static L37: [&str; 184] = ["אפארית", "אבחזית", "אפריקאנס", "אקאן", "אלבנית", "אמהרית", "ערבית", "אראגונית", "ארמנית", "אסאמית", "אווארית", "אבסטן", "איימארית", "אזרית", "במבארה", "בשקירית", "בסקית", "בלארוסית", "בנגלית", "", "ביסלמה", "בוסנית", "ברטונית", "בולגרית", "בורמזית", "קטלאנית", "צ׳מורו", "צ׳צ׳נית", "ניאנג׳ה", "סינית", "צ׳ובאש", "קורנית", "קורסיקנית", "קרי", "קרואטית", "צ׳כית", "דנית", "דיבהי", "הולנדית", "דזונקה", "אנגלית", "אספרנטו", "אסטונית", "אווה", "פארואזית", "פיג׳ית", "פינית", "צרפתית", "פולה", "גליציאנית", "גאורגית", "גרמנית", "יוונית", "גוארני", "גוג׳ארטי", "קריאולית (האיטי)", "האוסה", "עברית", "הררו", "הינדי", "הירי מוטו", "הונגרית", "\u{200F}אינטרלינגואה", "אינדונזית", "אינטרלינגה", "אירית", "איגבו", "אינופיאק", "אידו", "איסלנדית", "איטלקית", "אינוקטיטוט", "יפנית", "יאוואית", "גרינלנדית", "קנאדה", "קאנורי", "קשמירית", "קזחית", "חמרית", "קיקויו", "קנירואנדית", "קירגיזית", "קומי", "קונגו", "קוריאנית", "כורדית", "קואניאמה", "לטינית", "לוקסמבורגית", "גאנדה", "לימבורגית", "לינגלה", "לאו", "ליטאית", "לובה-קטנגה", "לטבית", "מאנית", "מקדונית", "מלגשית", "מלאית", "מליאלאם", "מלטית", "מאורית", "מראטהית", "מרשלית", "מונגולית", "נאורית", "נאוואחו", "נדבלה צפונית", "נפאלית", "נדונגה", "נורווגית ספרותית", "נורווגית חדשה", "נורווגית", "סצ׳ואן יי", "נדבלה דרומית", "אוקסיטנית", "אוג׳יבווה", "סלאבית כנסייתית עתיקה", "אורומו", "אורייה", "אוסטית", "פנג׳אבי", "פאלי", "פרסית", "פולנית", "פאשטו", "פורטוגזית", "קצ׳ואה", "רומאנש", "קירונדי", "רומנית", "רוסית", "סנסקריט", "סרדינית", "סינדהית", "סמי צפונית", "סמואית", "סנגו", "סרבית", "גאלית סקוטית", "שונה", "סינהלה", "סלובקית", "סלובנית", "סומלית", "סותו דרומית", "ספרדית", "סונדנזית", "סווהילי", "סאווזי", "שוודית", "טמילית", "טלוגו", "טג׳יקית", "תאית", "תיגרינית", "טיבטית", "טורקמנית", "טאגאלוג", "סוואנה", "טונגאית", "טורקית", "טסונגה", "טטרית", "טווי", "טהיטית", "אויגורית", "אוקראינית", "אורדו", "אוזבקית", "וונדה", "וייטנאמית", "\u{200F}וולאפיק", "ולונית", "וולשית", "וולוף", "פריזית מערבית", "קוסה", "יידיש", "יורובה", "זואנג", "זולו"];
+
+// hi
+static L38: [&str; 184] = ["अफ़ार", "अब्ख़ाज़ियन", "अफ़्रीकी", "अकन", "अल्बानियाई", "अम्हेरी", "अरबी", "अरागोनी", "आर्मेनियाई", "असमिया", "अवेरिक", "अवस्ताई", "आयमारा", "अज़रबैजानी", "बाम्बारा", "बशख़िर", "बास्क", "बेलारूसी", "बंगाली", "", "बिस्लामा", "बोस्नियाई", "ब्रेटन", "बुल्गारियाई", "बर्मीज़", "कातालान", "कमोरो", "चेचन", "न्यानजा", "चीनी", "चूवाश", "कोर्निश", "कोर्सीकन", "क्री", "क्रोएशियाई", "चेक", "डेनिश", "दिवेही", "डच", "ज़ोन्गखा", "अंग्रेज़ी", "एस्पेरेंतो", "एस्टोनियाई", "ईवे", "फ़ैरोइज़", "फिजियन", "फ़िनिश", "फ़्रेंच", "फुलाह", "गैलिशियन", "जॉर्जियाई", "जर्मन", "यूनानी", "गुआरानी", "गुजराती", "हैतियाई", "हौसा", "हिब्रू", "हरैरो", "हिन्दी", "हिरी मोटू", "हंगेरियाई", "इंटरलिंगुआ", "इंडोनेशियाई", "इंटरलिंग", "आयरिश", "ईग्बो", "इनुपियाक्", "इडौ", "आइसलैंडिक", "इतालवी", "इनुक्टिटुट", "जापानी", "जावानीज़", "कलालीसुत", "कन्नड़", "कनुरी", "कश्मीरी", "कज़ाख़", "खमेर", "किकुयू", "किन्यारवांडा", "किर्गीज़", "कोमी", "कोंगो", "कोरियाई", "कुर्दिश", "क्वान्यामा", "लैटिन", "लग्ज़मबर्गी", "गांडा", "लिंबर्गिश", "लिंगाला", "लाओ", "लिथुआनियाई", "ल्यूबा-कटांगा", "लातवियाई", "मैंक्स", "मकदूनियाई", "मालागासी", "मलय", "मलयालम", "माल्टीज़", "माओरी", "मराठी", "मार्शलीज़", "मंगोलियाई", "नाउरू", "नवाहो", "उत्तरी देबेल", "नेपाली", "डोन्गा", "नॉर्वेजियाई बोकमाल", "नॉर्वेजियाई नॉयनॉर्स्क", "नॉर्वेजियाई", "सिचुआन यी", "दक्षिण देबेल", "ओसीटान", "ओजिब्वा", "चर्च साल्विक", "ओरोमो", "ओड़िया", "ओस्सेटिक", "पंजाबी", "पाली", "फ़ारसी", "पोलिश", "पश्तो", "पुर्तगाली", "क्वेचुआ", "रोमान्श", "रुन्दी", "रोमानियाई", "रूसी", "संस्कृत", "सार्दिनियन", "सिंधी", "नॉर्दन सामी", "सामोन", "सांगो", "सर्बियाई", "स्कॉटिश गाएलिक", "शोणा", "सिंहली", "स्लोवाक", "स्लोवेनियाई", "सोमाली", "दक्षिणी सेसेथो", "स्पेनिश", "सुंडानी", "स्वाहिली", "स्वाती", "स्वीडिश", "तमिल", "तेलुगू", "ताजिक", "थाई", "तिग्रीन्या", "तिब्बती", "तुर्कमेन", "टैगलॉग", "सेत्स्वाना", "टोंगन", "तुर्की", "सोंगा", "तातार", "ट्वी", "ताहितियन", "उईग़ूर", "यूक्रेनियाई", "उर्दू", "उज़्बेक", "वेन्दा", "वियतनामी", "वोलापुक", "वाल्लून", "वेल्श", "वोलोफ़", "पश्चिमी फ़्रिसियाई", "ख़ोसा", "यहूदी", "योरूबा", "ज़ुआंग", "ज़ुलू"];
+
+// hu
+static L39: [&str; 184] = ["afar", "abház", "afrikaans", "akan", "albán", "amhara", "arab", "aragonéz", "örmény", "asszámi", "avar", "avesztán", "ajmara", "azerbajdzsáni", "bambara", "baskír", "baszk", "belarusz", "bangla", "", "bislama", "bosnyák", "breton", "bolgár", "burmai", "katalán", "csamoró", "csecsen", "nyandzsa", "kínai", "csuvas", "korni", "korzikai", "krí", "horvát", "cseh", "dán", "divehi", "holland", "dzsonga", "angol", "eszperantó", "észt", "eve", "feröeri", "fidzsi", "finn", "francia", "fulani", "gallego", "grúz", "német", "görög", "guarani", "gudzsaráti", "haiti kreol", "hausza", "héber", "herero", "hindi", "hiri motu", "magyar", "interlingva", "indonéz", "interlingue", "ír", "igbó", "inupiak", "idó", "izlandi", "olasz", "inuktitut", "japán", "jávai", "grönlandi", "kannada", "kanuri", "kasmíri", "kazah", "khmer", "kikuju", "kinyarvanda", "kirgiz", "komi", "kongo", "koreai", "kurd", "kuanyama", "latin", "luxemburgi", "ganda", "limburgi", "lingala", "lao", "litván", "luba-katanga", "lett", "man-szigeti", "macedón", "malgas", "maláj", "malajálam", "máltai", "maori", "maráthi", "marshalli", "mongol", "naurui", "navahó", "északi ndebele", "nepáli", "ndonga", "norvég (bokmål)", "norvég (nynorsk)", "norvég", "szecsuán ji", "déli ndebele", "okszitán", "ojibva", "egyházi szláv", "oromo", "odia", "oszét", "pandzsábi", "pali", "perzsa", "lengyel", "pastu", "portugál", "kecsua", "rétoromán", "kirundi", "román", "orosz", "szanszkrit", "szardíniai", "szindhi", "északi számi", "szamoai", "szangó", "szerb", "skóciai kelta", "sona", "szingaléz", "szlovák", "szlovén", "szomáli", "déli szeszotó", "spanyol", "szundanéz", "szuahéli", "sziszuati", "svéd", "tamil", "telugu", "tadzsik", "thai", "tigrinya", "tibeti", "türkmén", "tagalog", "szecsuáni", "tongai", "török", "conga", "tatár", "twi", "tahiti", "ujgur", "ukrán", "urdu", "üzbég", "venda", "vietnámi", "volapük", "vallon", "walesi", "volof", "nyugati fríz", "xhosza", "jiddis", "joruba", "zsuang", "zulu"];
+
+// ia
+static L40: [&str; 184] = ["afar", "abkhazo", "afrikaans", "akan", "albanese", "amharico", "arabe", "aragonese", "armenio", "assamese", "avaro", "", "aymara", "azerbaidzhano", "bambara", "bashkir", "basco", "bielorusso", "bengalese", "", "bislama", "bosniaco", "breton", "bulgaro", "birmano", "catalano", "chamorro", "checheno", "nyanja", "chinese", "chuvash", "cornico", "corso", "", "croato", "checo", "danese", "divehi", "nederlandese", "dzongkha", "anglese", "esperanto", "estoniano", "ewe", "feroese", "fijiano", "finnese", "francese", "fula", "galleco", "georgiano", "germano", "greco", "guarani", "gujarati", "creolo haitian", "hausa", "hebreo", "herero", "hindi", "", "hungaro", "interlingua", "indonesiano", "interlingue", "irlandese", "igbo", "", "ido", "islandese", "italiano", "inuktitut", "japonese", "javanese", "groenlandese", "kannada", "kanuri", "kashmiri", "kazakh", "khmer", "kikuyu", "kinyarwanda", "kirghizo", "komi", "", "coreano", "kurdo", "kuanyama", "latino", "luxemburgese", "luganda", "limburgese", "lingala", "laotiano", "lithuano", "luba-katanga", "letton", "mannese", "macedone", "malgache", "malay", "malayalam", "maltese", "maori", "marathi", "marshallese", "mongol", "nauru", "navajo", "ndebele del nord", "nepalese", "ndonga", "norvegiano bokmål", "norvegiano nynorsk", "norvegiano", "yi de Sichuan", "ndebele del sud", "occitano", "", "slavo ecclesiastic", "oromo", "oriya", "osseto", "punjabi", "pali", "persa", "polonese", "pashto", "portugese", "quechua", "romanche", "rundi", "romaniano", "russo", "sanscrito", "sardo", "sindhi", "sami del nord", "samoano", "sango", "serbo", "gaelico scotese", "shona", "cingalese", "slovaco", "sloveno", "somali", "sotho del sud", "espaniol", "sundanese", "swahili", "swati", "svedese", "tamil", "telugu", "tajiko", "thai", "tigrinya", "tibetano", "turkmeno", "filipino", "tswana", "tongano", "turco", "tsonga", "tataro", "twi", "tahitiano", "uighur", "ukrainiano", "urdu", "uzbeko", "venda", "vietnamese", "volapük", "wallon", "gallese", "wolof", "frison occidental", "xhosa", "yiddish", "yoruba", "zhuang", "zulu"];
+
+// id
+static L41: [&str; 184] = ["Afar", "Abkhaz", "Afrikaans", "Akan", "Albania", "Amharik", "Arab", "Aragon", "Armenia", "Assam", "Avar", "Avesta", "Aymara", "Azerbaijani", "Bambara", "Bashkir", "Basque", "Belarusia", "Bengali", "", "Bislama", "Bosnia", "Breton", "Bulgaria", "Burma", "Katalan", "Chamorro", "Chechen", "Nyanja", "Tionghoa", "Chuvash", "Kornish", "Korsika", "Kree", "Kroasia", "Ceko", "Dansk", "Divehi", "Belanda", "Dzongkha", "Inggris", "Esperanto", "Estonia", "Ewe", "Faroe", "Fiji", "Suomi", "Prancis", "Fula", "Galisia", "Georgia", "Jerman", "Yunani", "Guarani", "Gujarat", "Kreol Haiti", "Hausa", "Ibrani", "Herero", "Hindi", "Hiri Motu", "Hungaria", "Interlingua", "Indonesia", "Interlingue", "Irlandia", "Igbo", "Inupiak", "Ido", "Islandia", "Italia", "Inuktitut", "Jepang", "Jawa", "Kalaallisut", "Kannada", "Kanuri", "Kashmir", "Kazakh", "Khmer", "Kikuyu", "Kinyarwanda", "Kirgiz", "Komi", "Kongo", "Korea", "Kurdi", "Kuanyama", "Latin", "Luksemburg", "Ganda", "Limburgia", "Lingala", "Lao", "Lituania", "Luba-Katanga", "Latvia", "Manx", "Makedonia", "Malagasi", "Melayu", "Malayalam", "Malta", "Maori", "Marathi", "Marshall", "Mongolia", "Nauru", "Navajo", "Ndebele Utara", "Nepali", "Ndonga", "Bokmål Norwegia", "Nynorsk Norwegia", "Norwegia", "Sichuan Yi", "Ndebele Selatan", "Ositania", "Ojibwa", "Bahasa Gereja Slavonia", "Oromo", "Oriya", "Ossetia", "Punjabi", "Pali", "Persia", "Polski", "Pashto", "Portugis", "Quechua", "Reto-Roman", "Rundi", "Rumania", "Rusia", "Sanskerta", "Sardinia", "Sindhi", "Sami Utara", "Samoa", "Sango", "Serbia", "Gaelik Skotlandia", "Shona", "Sinhala", "Slovak", "Slovenia", "Somalia", "Sotho Selatan", "Spanyol", "Sunda", "Swahili", "Swati", "Swedia", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinya", "Tibet", "Turkmen", "Tagalog", "Tswana", "Tonga", "Turki", "Tsonga", "Tatar", "Twi", "Tahiti", "Uyghur", "Ukraina", "Urdu", "Uzbek", "Venda", "Vietnam", "Volapuk", "Walloon", "Welsh", "Wolof", "Frisia Barat", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// ie
+static L42: [&str; 184] = ["", "", "", "", "", "", "arabic", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "chinesi", "", "", "", "", "", "tchec", "danesi", "", "hollandesi", "", "anglesi", "Esperanto", "estonian", "", "", "", "finn", "francesi", "", "galician", "georgian", "german", "grec", "", "", "", "hausa", "hebreic", "", "hindi", "", "hungarian", "Interlingua", "indonesian", "Interlingue", "irlandesi", "", "", "", "islandesi", "italian", "", "japanesi", "javan", "", "", "", "", "", "", "", "", "", "", "", "korean", "", "", "", "", "", "", "", "", "", "", "lettonian", "", "", "", "", "", "maltesi", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "persian", "polonesi", "", "portugalesi", "", "", "", "", "russ", "", "", "", "", "", "", "", "", "", "", "slovac", "slovenian", "", "", "hispan", "", "swahili", "", "sved", "", "", "", "thai", "", "", "", "filipinesi", "", "", "turc", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// ga
+static L43: [&str; 184] = ["Afáiris", "Abcáisis", "Afracáinis", "Acáinis", "Albáinis", "Amáiris", "Araibis", "Aragóinis", "Airméinis", "Asaimis", "Aváiris", "Aivéistis", "Aidhmiris", "Asarbaiseáinis", "Bambairis", "Baiscíris", "Bascais", "Bealarúisis", "Beangáilis", "", "Bioslaimis", "Boisnis", "Briotáinis", "Bulgáiris", "Burmais", "Catalóinis", "Seamóiris", "Seisnis", "Siséivis", "Sínis", "Suvaisis", "Coirnis", "Corsaicis", "Craís", "Cróitis", "Seicis", "Danmhairgis", "Divéihis", "Ollainnis", "Seoinicis", "Béarla", "Esperanto", "Eastóinis", "Éabhais", "Faróis", "Fidsis", "Fionlainnis", "Fraincis", "Fuláinis", "Gailísis", "Seoirsis", "Gearmáinis", "Gréigis", "Guaráinis", "Gúisearáitis", "Críol Háítí", "Hásais", "Eabhrais", "Heiréiris", "Hiondúis", "Motúis Hírí", "Ungáiris", "Interlingua", "Indinéisis", "Interlingue", "Gaeilge", "Íogbóis", "Iniúipiaicis", "Ídis", "Íoslainnis", "Iodáilis", "Ionúitis", "Seapáinis", "Iáivis", "Kalaallisut", "Cannadais", "Canúiris", "Caismíris", "Casaicis", "Ciméiris", "Ciocúis", "Ciniaruaindis", "Cirgisis", "Coimis", "Congóis", "Cóiréis", "Coirdis", "Cuainiáimis", "Laidin", "Lucsambuirgis", "Lugandais", "Liombuirgis", "Liongáilis", "Laoisis", "Liotuáinis", "Lúba-Cataingis", "Laitvis", "Manainnis", "Macadóinis", "Malagáisis", "Malaeis", "Mailéalaimis", "Máltais", "Maorais", "Maraitis", "Mairsillis", "Mongóilis", "Nárúis", "Navachóis", "N-deibéilis an Tuaiscirt", "Neipeailis", "Ndongais", "Bocmál", "Nua-Ioruais", "Ioruais", "Ís Sichuan", "Ndeibéilis an Deiscirt", "Ocsatáinis", "Óisibis", "Slavais na hEaglaise", "Oraimis", "Oirísis", "Oiséitis", "Puinseáibis", "Páilis", "Peirsis", "Polainnis", "Paistis", "Portaingéilis", "Ceatsuais", "Rómainis", "Rúindis", "Rómáinis", "Rúisis", "Sanscrait", "Sairdínis", "Sindis", "Sáimis an Tuaiscirt", "Samóis", "Sangóis", "Seirbis", "Gaeilge na hAlban", "Seoinis", "Siolóinis", "Slóvaicis", "Slóivéinis", "Somáilis", "Sútúis an Deiscirt", "Spáinnis", "Sundais", "Svahaílis", "Suaisis", "Sualainnis", "Tamailis", "Teileagúis", "Taidsícis", "Téalainnis", "Tigrinis", "Tibéidis", "Tuircméinis", "Tagálaigis", "Suáinis", "Tongais", "Tuircis", "Songais", "Tatairis", "Tíbhis", "Taihítis", "Uigiúiris", "Úcráinis", "Urdúis", "Úisbéiceastáinis", "Veindis", "Vítneaimis", "Volapük", "Vallúnais", "Breatnais", "Volaifis", "Freaslainnis Iartharach", "Cóisis", "Giúdais", "Iarúibis", "Siuáingis", "Súlúis"];
+
+// ig
+static L44: [&str; 184] = ["Afar", "Abkaziani", "Afrikaans", "Akan", "Asụsụ Albania", "Asụsụ Amariikị", "Asụsụ Arabiikị", "Aragonisị", "Asụsụ Armenianị", "Asụsụ Asamisị", "Avarịk", "", "Aymara", "Azerbaijani", "Bambara", "Bashkir", "Basque", "Belarusian", "Bangla", "", "Bislama", "Bosnian", "Breton", "Bulgarian", "Burmese", "Catalan", "Chamorro", "Chechen", "Nyanja", "Chaịniiz", "Chuvash", "Cornish", "Corsican", "", "Croatian", "Czech", "Danish", "Divehi", "Dutch", "Dọzngọka", "Bekee", "Esperanto", "Estonian", "Ewe", "Faroese", "Fijanị", "Finnish", "French", "Fula", "Galician", "Georgian", "German", "Grik", "Guarani", "Gujarati", "Haịtịan ndị Cerọle", "Hausa", "Hebrew", "Herero", "Hindi", "", "Hungarian", "Interlingua", "Indonesian", "Interlingue", "Irish", "Igbo", "", "Ido", "Icelandic", "Italian", "Inuktitut", "Japanese", "Javanese", "Kalaallisut", "Kannada", "Kanuri", "Kashmiri", "Kazakh", "Khmer", "Kikuyu", "Kinyarwanda", "Kyrgyz", "Komi", "", "Korean", "Kurdish", "Kuanyama", "Latin", "Luxembourgish", "Ganda", "Limburgish", "Lingala", "Lao", "Lithuanian", "Luba-Katanga", "Latvian", "Mansị", "Macedonian", "Malagasy", "Malay", "Malayalam", "Asụsụ Malta", "Māori", "Asụsụ Marathi", "Marshallese", "Mọngolịan", "Nauru", "Navajo", "North Ndebele", "Nepali", "Ndonga", "Norwegian Bokmål", "Norwegian Nynorsk", "Norwegian", "Sichuan Yi", "South Ndebele", "Asụsụ Osịtan", "", "Church slavic", "Oromo", "Ọdịa", "Ossetic", "Punjabi", "", "Asụsụ Persia", "Asụsụ Polish", "Pashọ", "Pọrtụgụese", "Asụsụ Quechua", "Asụsụ Romansh", "Rundi", "Asụsụ Romanian", "Asụsụ Russia", "Asụsụ Sanskrit", "Asụsụ Sardini", "Asụsụ Sindhi", "Northern Sami", "Samoan", "Sango", "Asụsụ Serbia", "Asụsụ Scottish Gaelic", "Shona", "Sinhala", "Asụsụ Slovak", "Asụsụ Slovenia", "Somali", "Southern Sotho", "Spanish", "Asụsụ Sundan", "Asụsụ Swahili", "Swati", "Sụwidiishi", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinya", "Tibetan", "Turkmen", "Filipino", "Tswana", "Tongan", "Turkish", "Tsonga", "Asụsụ Tatar", "", "Tahitian", "Uyghur", "Asụsụ Ukrain", "Urdu", "Uzbek", "Venda", "Vietnamese", "Volapük", "Walloon", "Welsh", "Wolof", "Ọdịda anyanwụ Frisian", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// is
+static L45: [&str; 184] = ["afár", "abkasíska", "afríkanska", "akan", "albanska", "amharíska", "arabíska", "aragonska", "armenska", "assamska", "avaríska", "avestíska", "aímara", "aserska", "bambara", "baskír", "baskneska", "hvítrússneska", "bengalska", "", "bíslama", "bosníska", "bretónska", "búlgarska", "búrmneska", "katalónska", "kamorró", "tsjetsjenska", "nýanja", "kínverska", "sjúvas", "kornbreska", "korsíska", "krí", "króatíska", "tékkneska", "danska", "dívehí", "hollenska", "dsongka", "enska", "esperantó", "eistneska", "ewe", "færeyska", "fídjeyska", "finnska", "franska", "fúla", "galisíska", "georgíska", "þýska", "gríska", "gvaraní", "gújaratí", "haítíska", "hása", "hebreska", "hereró", "hindí", "hírímótú", "ungverska", "interlingua", "indónesíska", "interlingve", "írska", "ígbó", "ínúpíak", "ídó", "íslenska", "ítalska", "inúktitút", "japanska", "javanska", "grænlenska", "kannada", "kanúrí", "kasmírska", "kasakska", "kmer", "kíkújú", "kínjarvanda", "kirgiska", "komíska", "kongóska", "kóreska", "kúrdíska", "kúanjama", "latína", "lúxemborgíska", "ganda", "limbúrgíska", "lingala", "laó", "litháíska", "lúbakatanga", "lettneska", "manska", "makedónska", "malagasíska", "malaíska", "malajalam", "maltneska", "maorí", "maratí", "marshallska", "mongólska", "nárúska", "navahó", "norður-ndebele", "nepalska", "ndonga", "norskt bókmál", "nýnorska", "norska", "sísúanjí", "suðurndebele", "oksítaníska", "ojibva", "kirkjuslavneska", "oromo", "óría", "ossetíska", "púnjabí", "palí", "persneska", "pólska", "pastú", "portúgalska", "kvesjúa", "rómanska", "rúndí", "rúmenska", "rússneska", "sanskrít", "sardínska", "sindí", "norðursamíska", "samóska", "sangó", "serbneska", "skosk gelíska", "shona", "singalíska", "slóvakíska", "slóvenska", "sómalska", "suðursótó", "spænska", "súndanska", "svahílí", "svatí", "sænska", "tamílska", "telúgú", "tadsjikska", "taílenska", "tígrinja", "tíbeska", "túrkmenska", "tagalog", "tsúana", "tongverska", "tyrkneska", "tsonga", "tatarska", "tví", "tahítíska", "úígúr", "úkraínska", "úrdú", "úsbekska", "venda", "víetnamska", "volapyk", "vallónska", "velska", "volof", "vesturfrísneska", "sósa", "jiddíska", "jórúba", "súang", "súlú"];
+
+// it
+static L46: [&str; 184] = ["afar", "abcaso", "afrikaans", "akan", "albanese", "amarico", "arabo", "aragonese", "armeno", "assamese", "avaro", "avestan", "aymara", "azerbaigiano", "bambara", "baschiro", "basco", "bielorusso", "bengalese", "", "bislama", "bosniaco", "bretone", "bulgaro", "birmano", "catalano", "chamorro", "ceceno", "nyanja", "cinese", "ciuvascio", "cornico", "corso", "cree", "croato", "ceco", "danese", "divehi", "olandese", "dzongkha", "inglese", "esperanto", "estone", "ewe", "faroese", "figiano", "finlandese", "francese", "fulah", "galiziano", "georgiano", "tedesco", "greco", "guaraní", "gujarati", "creolo haitiano", "hausa", "ebraico", "herero", "hindi", "hiri motu", "ungherese", "interlingua", "indonesiano", "interlingue", "irlandese", "igbo", "inupiak", "ido", "islandese", "italiano", "inuktitut", "giapponese", "giavanese", "groenlandese", "kannada", "kanuri", "kashmiri", "kazako", "khmer", "kikuyu", "kinyarwanda", "kirghiso", "komi", "kongo", "coreano", "curdo", "kuanyama", "latino", "lussemburghese", "ganda", "limburghese", "lingala", "lao", "lituano", "luba-katanga", "lettone", "mannese", "macedone", "malgascio", "malese", "malayalam", "maltese", "maori", "marathi", "marshallese", "mongolo", "nauru", "navajo", "ndebele del nord", "nepalese", "ndonga", "norvegese bokmål", "norvegese nynorsk", "norvegese", "sichuan yi", "ndebele del sud", "occitano", "ojibwa", "slavo ecclesiastico", "oromo", "odia", "ossetico", "punjabi", "pali", "persiano", "polacco", "pashto", "portoghese", "quechua", "romancio", "rundi", "rumeno", "russo", "sanscrito", "sardo", "sindhi", "sami del nord", "samoano", "sango", "serbo", "gaelico scozzese", "shona", "singalese", "slovacco", "sloveno", "somalo", "sotho del sud", "spagnolo", "sundanese", "swahili", "swati", "svedese", "tamil", "telugu", "tagico", "thailandese", "tigrino", "tibetano", "turcomanno", "tagalog", "tswana", "tongano", "turco", "tsonga", "tataro", "ci", "taitiano", "uiguro", "ucraino", "urdu", "uzbeco", "venda", "vietnamita", "volapük", "vallone", "gallese", "wolof", "frisone occidentale", "xhosa", "yiddish", "yoruba", "zhuang", "zulu"];
+
+// ja
+static L47: [&str; 184] = ["アファル語", "アブハズ語", "アフリカーンス語", "アカン語", "アルバニア語", "アムハラ語", "アラビア語", "アラゴン語", "アルメニア語", "アッサム語", "アヴァル語", "アヴェスタ語", "アイマラ語", "アゼルバイジャン語", "バンバラ語", "バシキール語", "バスク語", "ベラルーシ語", "ベンガル語", "", "ビスラマ語", "ボスニア語", "ブルトン語", "ブルガリア語", "ミャンマー語", "カタロニア語", "チャモロ語", "チェチェン語", "ニャンジャ語", "中国語", "チュヴァシ語", "コーンウォール語", "コルシカ語", "クリー語", "クロアチア語", "チェコ語", "デンマーク語", "ディベヒ語", "オランダ語", "ゾンカ語", "英語", "エスペラント語", "エストニア語", "エウェ語", "フェロー語", "フィジー語", "フィンランド語", "フランス語", "フラ語", "ガリシア語", "ジョージア語", "ドイツ語", "ギリシャ語", "グアラニー語", "グジャラート語", "ハイチ・クレオール語", "ハウサ語", "ヘブライ語", "ヘレロ語", "ヒンディー語", "ヒリモツ語", "ハンガリー語", "インターリングア", "インドネシア語", "インターリング", "アイルランド語", "イボ語", "イヌピアック語", "イド語", "アイスランド語", "イタリア語", "イヌクティトット語", "日本語", "ジャワ語", "グリーンランド語", "カンナダ語", "カヌリ語", "カシミール語", "カザフ語", "クメール語", "キクユ語", "キニアルワンダ語", "キルギス語", "コミ語", "コンゴ語", "韓国語", "クルド語", "クワニャマ語", "ラテン語", "ルクセンブルク語", "ガンダ語", "リンブルフ語", "リンガラ語", "ラオ語", "リトアニア語", "ルバ・カタンガ語", "ラトビア語", "マン島語", "マケドニア語", "マダガスカル語", "マレー語", "マラヤーラム語", "マルタ語", "マオリ語", "マラーティー語", "マーシャル語", "モンゴル語", "ナウル語", "ナバホ語", "北ンデベレ語", "ネパール語", "ンドンガ語", "ノルウェー語(ブークモール)", "ノルウェー語(ニーノシュク)", "ノルウェー語", "四川イ語", "南ンデベレ語", "オック語", "オジブウェー語", "教会スラブ語", "オロモ語", "オディア語", "オセット語", "パンジャブ語", "パーリ語", "ペルシア語", "ポーランド語", "パシュトゥー語", "ポルトガル語", "ケチュア語", "ロマンシュ語", "ルンディ語", "ルーマニア語", "ロシア語", "サンスクリット語", "サルデーニャ語", "シンド語", "北サーミ語", "サモア語", "サンゴ語", "セルビア語", "スコットランド・ゲール語", "ショナ語", "シンハラ語", "スロバキア語", "スロベニア語", "ソマリ語", "南部ソト語", "スペイン語", "スンダ語", "スワヒリ語", "スワジ語", "スウェーデン語", "タミル語", "テルグ語", "タジク語", "タイ語", "ティグリニア語", "チベット語", "トルクメン語", "タガログ語", "ツワナ語", "トンガ語", "トルコ語", "ツォンガ語", "タタール語", "トウィ語", "タヒチ語", "ウイグル語", "ウクライナ語", "ウルドゥー語", "ウズベク語", "ベンダ語", "ベトナム語", "ヴォラピュク語", "ワロン語", "ウェールズ語", "ウォロフ語", "西フリジア語", "コサ語", "イディッシュ語", "ヨルバ語", "チワン語", "ズールー語"];
+
+// jv
+static L48: [&str; 184] = ["", "Abkhazian", "Afrikaans", "Akan", "Albania", "Amharik", "Arab", "Aragonese", "Armenia", "Assam", "Avaric", "", "Aymara", "Azerbaijan", "Bambara", "Bashkir", "Basque", "Belarus", "Bengali", "", "Bislama", "Bosnia lan Hercegovina", "Breton", "Bulgaria", "Myanmar", "Katala", "Khamorro", "Chechen", "Nyanja", "Tyonghwa", "Khuvash", "Kernowek", "Korsika", "", "Kroasia", "Ceska", "Dansk", "Divehi", "Walanda", "Dzongkha", "Inggris", "Esperanto", "Estonia", "Ewe", "Faroe", "Fijian", "Suomi", "Prancis", "Fula", "Galisia", "Georgia", "Jérman", "Yunani", "Guarani", "Gujarat", "Kreol Haiti", "Hausa", "Ibrani", "Herero", "India", "", "Hungaria", "Interlingua", "Indonesia", "Interlingue", "Irlandia", "Iqbo", "", "Ido", "Islandia", "Italia", "Inuktitut", "Jepang", "Jawa", "Kalaallisut", "Kannada", "Kanuri", "Kashmiri", "Kazakh", "Khmer", "Kikuyu", "Kinyarwanda", "Kirgis", "Komi", "", "Korea", "Kurdis", "Kuanyama", "Latin", "Luksemburg", "Ganda", "Limburgish", "Lingala", "Laos", "Lithuania", "Luba-Katanga", "Latvia", "Manx", "Makedonia", "Malagasi", "Melayu", "Malayalam", "Malta", "Maori", "Marathi", "Marshallese", "Mongolia", "Nauru", "Navajo", "Ndebele Lor", "Nepal", "Ndonga", "Bokmål Norwegia", "Nynorsk Norwegia", "Norwegia", "Sichuan Yi", "Ndebele Kidul", "Ossitan", "", "Slavia Gerejani", "Oromo", "Odia", "Ossetia", "Punjab", "", "Persia", "Polandia", "Pashto", "Portugis", "Quechua", "Roman", "Rundi", "Rumania", "Rusia", "Sanskerta", "Sardinia", "Sindhi", "Sami Sisih Lor", "Samoa", "Sango", "Serbia", "Gaulia", "Shona", "Sinhala", "Slowakia", "Slovenia", "Somalia", "Sotho Sisih Kidul", "Spanyol", "Sunda", "Swahili", "Swati", "Swedia", "Tamil", "Telugu", "Tajik", "Thailand", "Tigrinya", "Tibet", "Turkmen", "Tagalog", "Tswana", "Tonga", "Turki", "Tsonga", "Tatar", "", "Tahiti", "Uighur", "Ukraina", "Urdu", "Uzbek", "Venda", "Vietnam", "Volapuk", "Walloon", "Welsh", "Wolof", "Frisia Sisih Kulon", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// kn
+static L49: [&str; 184] = ["ಅಫಾರ್", "ಅಬ್ಖಾಜಿಯನ್", "ಆಫ್ರಿಕಾನ್ಸ್", "ಅಕಾನ್", "ಅಲ್ಬೇನಿಯನ್", "ಅಂಹರಿಕ್", "ಅರೇಬಿಕ್", "ಅರಗೊನೀಸ್", "ಅರ್ಮೇನಿಯನ್", "ಅಸ್ಸಾಮೀಸ್", "ಅವರಿಕ್", "ಅವೆಸ್ಟನ್", "ಅಯ್ಮಾರಾ", "ಅಜೆರ್ಬೈಜಾನಿ", "ಬಂಬಾರಾ", "ಬಶ್ಕಿರ್", "ಬಾಸ್ಕ್", "ಬೆಲರೂಸಿಯನ್", "ಬಾಂಗ್ಲಾ", "", "ಬಿಸ್ಲಾಮಾ", "ಬೋಸ್ನಿಯನ್", "ಬ್ರೆಟನ್", "ಬಲ್ಗೇರಿಯನ್", "ಬರ್ಮೀಸ್", "ಕೆಟಲಾನ್", "ಕಮೊರೊ", "ಚಚೆನ್", "ನ್ಯಾಂಜಾ", "ಚೈನೀಸ್", "ಚುವಾಶ್", "ಕಾರ್ನಿಷ್", "ಕೋರ್ಸಿಕನ್", "ಕ್ರೀ", "ಕ್ರೊಯೇಶಿಯನ್", "ಜೆಕ್", "ಡ್ಯಾನಿಶ್", "ದಿವೆಹಿ", "ಡಚ್", "ಜೋಂಗ್\u{200C}ಖಾ", "ಇಂಗ್ಲಿಷ್", "ಎಸ್ಪೆರಾಂಟೊ", "ಎಸ್ಟೊನಿಯನ್", "ಈವ್", "ಫರೋಸಿ", "ಫಿಜಿಯನ್", "ಫಿನ್ನಿಶ್", "ಫ್ರೆಂಚ್", "ಫುಲಾ", "ಗ್ಯಾಲಿಶಿಯನ್", "ಜಾರ್ಜಿಯನ್", "ಜರ್ಮನ್", "ಗ್ರೀಕ್", "ಗೌರಾನಿ", "ಗುಜರಾತಿ", "ಹೈಟಿಯನ್ ಕ್ರಿಯೋಲಿ", "ಹೌಸಾ", "ಹೀಬ್ರೂ", "ಹೆರೆರೊ", "ಹಿಂದಿ", "ಹಿರಿ ಮೊಟು", "ಹಂಗೇರಿಯನ್", "ಇಂಟರ್\u{200C}ಲಿಂಗ್ವಾ", "ಇಂಡೋನೇಶಿಯನ್", "ಇಂಟರ್\u{200C}ಲಿಂಗ್", "ಐರಿಷ್", "ಇಗ್ಬೊ", "ಇನುಪಿಯಾಕ್", "ಇಡೊ", "ಐಸ್\u{200C}ಲ್ಯಾಂಡಿಕ್", "ಇಟಾಲಿಯನ್", "ಇನುಕ್ಟಿಟುಟ್", "ಜಾಪನೀಸ್", "ಜಾವಾನೀಸ್", "ಕಲಾಲ್ಲಿಸುಟ್", "ಕನ್ನಡ", "ಕನುರಿ", "ಕಾಶ್ಮೀರಿ", "ಕಝಕ್", "ಖಮೇರ್", "ಕಿಕುಯು", "ಕಿನ್ಯಾರ್\u{200C}ವಾಂಡಾ", "ಕಿರ್ಗಿಜ್", "ಕೋಮಿ", "ಕಾಂಗೋ", "ಕೊರಿಯನ್", "ಕುರ್ದಿಷ್", "ಕ್ವಾನ್\u{200C}ಯಾಮಾ", "ಲ್ಯಾಟಿನ್", "ಲಕ್ಸಂಬರ್ಗಿಷ್", "ಗಾಂಡಾ", "ಲಿಂಬರ್ಗಿಶ್", "ಲಿಂಗಾಲ", "ಲಾವೋ", "ಲಿಥುವೇನಿಯನ್", "ಲೂಬಾ-ಕಟಾಂಗಾ", "ಲಾಟ್ವಿಯನ್", "ಮ್ಯಾಂಕ್ಸ್", "ಮೆಸಿಡೋನಿಯನ್", "ಮಲಗಾಸಿ", "ಮಲಯ್", "ಮಲಯಾಳಂ", "ಮಾಲ್ಟೀಸ್", "ಮಾವೋರಿ", "ಮರಾಠಿ", "ಮಾರ್ಶಲ್ಲೀಸ್", "ಮಂಗೋಲಿಯನ್", "ನೌರು", "ನವಾಜೊ", "ಉತ್ತರ ದೆಬೆಲೆ", "ನೇಪಾಳಿ", "ಡೋಂಗಾ", "ನಾರ್ವೆಜಿಯನ್ ಬೊಕ್ಮಲ್", "ನಾರ್ವೇಜಿಯನ್ ನೈನಾರ್ಸ್ಕ್", "ನಾರ್ವೇಜಿಯನ್", "ಸಿಚುಅನ್ ಯಿ", "ದಕ್ಷಿಣ ದೆಬೆಲೆ", "ಒಸಿಟನ್", "ಒಜಿಬ್ವಾ", "ಚರ್ಚ್ ಸ್ಲಾವಿಕ್", "ಒರೊಮೊ", "ಒಡಿಯ", "ಒಸ್ಸೆಟಿಕ್", "ಪಂಜಾಬಿ", "ಪಾಲಿ", "ಪರ್ಶಿಯನ್", "ಪೊಲಿಶ್", "ಪಾಷ್ಟೋ", "ಪೋರ್ಚುಗೀಸ್", "ಕ್ವೆಚುವಾ", "ರೊಮಾನ್ಶ್", "ರುಂಡಿ", "ರೊಮೇನಿಯನ್", "ರಷ್ಯನ್", "ಸಂಸ್ಕೃತ", "ಸರ್ಡೀನಿಯನ್", "ಸಿಂಧಿ", "ಉತ್ತರ ಸಾಮಿ", "ಸಮೋವನ್", "ಸಾಂಗೋ", "ಸೆರ್ಬಿಯನ್", "ಸ್ಕಾಟಿಶ್ ಗೆಲಿಕ್", "ಶೋನಾ", "ಸಿಂಹಳ", "ಸ್ಲೋವಾಕ್", "ಸ್ಲೋವೇನಿಯನ್", "ಸೊಮಾಲಿ", "ದಕ್ಷಿಣ ಸೋಥೋ", "ಸ್ಪ್ಯಾನಿಷ್", "ಸುಂಡಾನೀಸ್", "ಸ್ವಹಿಲಿ", "ಸ್ವಾತಿ", "ಸ್ವೀಡಿಷ್", "ತಮಿಳು", "ತೆಲುಗು", "ತಾಜಿಕ್", "ಥಾಯ್", "ಟಿಗ್ರಿನ್ಯಾ", "ಟಿಬೇಟಿಯನ್", "ಟರ್ಕ್\u{200C}ಮೆನ್", "ಟ್ಯಾಗಲೋಗ್", "ಸ್ವಾನಾ", "ಟೋಂಗನ್", "ಟರ್ಕಿಶ್", "ಸೋಂಗಾ", "ಟಾಟರ್", "ಟ್ವಿ", "ಟಹೀಟಿಯನ್", "ಉಯಿಘರ್", "ಉಕ್ರೇನಿಯನ್", "ಉರ್ದು", "ಉಜ್ಬೇಕ್", "ವೆಂಡಾ", "ವಿಯೆಟ್ನಾಮೀಸ್", "ವೋಲಾಪುಕ್", "ವಾಲೂನ್", "ವೆಲ್ಶ್", "ವೋಲೋಫ್", "ಪಶ್ಚಿಮ ಫ್ರಿಸಿಯನ್", "ಕ್ಸೋಸ", "ಯಿಡ್ಡಿಶ್", "ಯೊರುಬಾ", "ಝೂವಾಂಗ್", "ಜುಲು"];
+
+// ks
+static L50: [&str; 184] = ["اَفار", "اَبخازِیان", "اَفریٖکانز", "اَکان", "البانِیَن", "اَمہاری", "عربی", "اَراگونی", "اَرمینیَن", "اسٲمؠ", "اَوارِک", "اَویستَن", "ایمارا", "اَزَربیجانی", "بَمبارا", "بَشکیٖر", "باسک", "بیلَروٗشیَن", "بَنگٲلؠ", "", "بِسلاما", "بوسنِیَن", "بریٹَن", "بینا", "بٔمیٖز", "کَتلان", "کَمورو", "چیچَن", "نِیَنجا", "چیٖنی ﴿ترجمع اِشارٕ: خاص طور، مینڈارن چیٖنی۔﴾", "چُواش", "کورنِش", "کارسِکَن", "کری", "کروشِیَن", "چیک", "ڈینِش", "دِویہی", "ڈَچ", "زونگکھا", "اَنگیٖزؠ", "ایسپَرینٹو", "ایسٹونیَن", "ایٖو", "فَروس", "فِجیَن", "فِنِش", "فرانسیسی", "فُلاہ", "گیلِشِیَن", "جارجِیَن", "جٔرمَن", "یوٗنٲنی", "گُوارَنی", "گُجرٲتی", "ہیتِیاں", "ہاوسا", "عبرٲنؠ", "ہیریٖرو", "ہِندی", "ہِری موتوٗ", "ہَنگیریَن", "اِنٹَرلِنگوا", "اِنڈونیشیا", "اِنٹَر لِننگویے", "اَیرِش", "اِگبو", "اِنُپِیاک", "اِڈو", "آیِسلینڈِک", "اِطالوی", "اِنُکتِتوٗ", "جاپٲنؠ", "جَوَنیٖز", "کَلالِسُت", "کَنَڑ", "کَنوٗری", "کٲشُر", "کازَخ", "خَمیر", "کِکُیوٗ", "کِنیاوِندا", "کِرگِز", "کومی", "کونگو", "کوریَن", "کُردِش", "کُوانیاما", "لاتیٖنی", "لُکھزیمبورگِش", "گاندا", "لِمبٔرگِش", "لِنگالا", "لاو", "لِتھوانِیَن", "لوُبا کَتَنگا", "لَتوِیَن", "مینکس", "میکَڈونیَن", "مَلاگَسی", "مَلَے", "مٔلیالَم", "مَلتیٖس", "ماوری", "مَرٲٹھؠ", "مارشَلیٖز", "مَنگولی", "ناورُ", "نَواجو", "شُمال ڈَبیل", "نیپٲلؠ", "ڈونگا", "ناروییَن بوکمال", "ناروییَن نَے نورسک", "ناروییَن", "سِچوان یٖی", "جنوب ڈیبیل", "اوکسیٖٹَن", "اوجِبوا", "چٔرچ سلاوِک", "اۆرومو", "اۆرِیا", "اۆسیٹِک", "پَنجٲبؠ", "پالی", "فارسی", "پالِش", "پَشتوٗ", "پُرتَگیٖز", "کُویشُوا", "رومانش", "رُندی", "رومٲنی", "روٗسی", "سَنسکرٕت", "سراڈیٖنی", "سِندی", "شُمٲلی سَمی", "سَمواَن", "سَنگو", "سٔربِیَن", "سکوٹِش گیےلِک", "شونا", "سِنہالا", "سلووَک", "سلووینیَن", "سومٲلی", "جنوبی ستھو", "ہسپانوی", "سَنڈَنیٖز", "سواہِلی", "سواتی", "سویٖڈِش", "تَمِل", "تیلگوٗ", "تاجِک", "تھاے", "ٹِگرِنیا", "تِبتی", "تُرکمین", "تَماشیک", "سوانا", "ٹونگا", "تُرکِش", "ژونگا", "تَتار", "توی", "تاہیشِیَن", "", "یوٗکرینیٲیی", "اُردوٗ", "اُزبیک", "ویندا", "وِیَتنَمیٖز", "وولَپُک", "وَلوٗن", "ویلش", "وولوف", "مغربی فرِشیَن", "کھوسا", "یِدِش", "یورُبا", "زُہانگ", "زُلوٗ"];
+
+// kk
+static L51: [&str; 184] = ["афар тілі", "абхаз тілі", "африкаанс тілі", "акан тілі", "албан тілі", "амхар тілі", "араб тілі", "арагон тілі", "армян тілі", "ассам тілі", "авар тілі", "", "аймара тілі", "әзірбайжан тілі", "бамбара тілі", "башқұрт тілі", "баск тілі", "беларусь тілі", "бенгал тілі", "", "бислама тілі", "босния тілі", "бретон тілі", "болгар тілі", "бирма тілі", "каталан тілі", "чаморро тілі", "шешен тілі", "ньянджа тілі", "қытай тілі", "чуваш тілі", "корн тілі", "корсика тілі", "", "хорват тілі", "чех тілі", "дат тілі", "дивехи тілі", "нидерланд тілі", "дзонг-кэ тілі", "ағылшын тілі", "эсперанто тілі", "эстон тілі", "эве тілі", "фарер тілі", "фиджи тілі", "фин тілі", "француз тілі", "фула тілі", "галисия тілі", "грузин тілі", "неміс тілі", "грек тілі", "гуарани тілі", "гуджарати тілі", "гаити тілі", "хауса тілі", "иврит тілі", "гереро тілі", "хинди тілі", "", "венгр тілі", "интерлингва тілі", "индонезия тілі", "интерлингве тілі", "ирланд тілі", "игбо тілі", "", "идо тілі", "исланд тілі", "итальян тілі", "инуктитут тілі", "жапон тілі", "ява тілі", "калаалисут тілі", "каннада тілі", "канури тілі", "кашмир тілі", "қазақ тілі", "кхмер тілі", "кикуйю тілі", "киньяруанда тілі", "қырғыз тілі", "коми тілі", "", "корей тілі", "күрд тілі", "кваньяма тілі", "латын тілі", "люксембург тілі", "ганда тілі", "лимбург тілі", "лингала тілі", "лаос тілі", "литва тілі", "луба-катанга тілі", "латыш тілі", "мэн тілі", "македон тілі", "малагаси тілі", "малай тілі", "малаялам тілі", "мальта тілі", "маори тілі", "маратхи тілі", "маршалл тілі", "моңғол тілі", "науру тілі", "навахо тілі", "солтүстік ндебеле тілі", "непал тілі", "ндонга тілі", "норвегиялық букмол тілі", "норвегиялық нюнорск тілі", "норвег тілі", "сычуан и тілі", "оңтүстік ндебеле тілі", "окситан тілі", "", "шіркеулік славян тілі", "оромо тілі", "ория тілі", "осетин тілі", "пенджаб тілі", "пали тілі", "парсы тілі", "поляк тілі", "пушту тілі", "португал тілі", "кечуа тілі", "романш тілі", "рунди тілі", "румын тілі", "орыс тілі", "санскрит тілі", "сардин тілі", "синдхи тілі", "солтүстік саам тілі", "самоа тілі", "санго тілі", "серб тілі", "шотландиялық гэль тілі", "шона тілі", "сингал тілі", "словак тілі", "словен тілі", "сомали тілі", "оңтүстік сото тілі", "испан тілі", "сундан тілі", "суахили тілі", "свати тілі", "швед тілі", "тамил тілі", "телугу тілі", "тәжік тілі", "тай тілі", "тигринья тілі", "тибет тілі", "түрікмен тілі", "филиппин тілі", "тсвана тілі", "тонган тілі", "түрік тілі", "тсонга тілі", "татар тілі", "тви тілі", "таити тілі", "ұйғыр тілі", "украин тілі", "урду тілі", "өзбек тілі", "венда тілі", "вьетнам тілі", "волапюк тілі", "валлон тілі", "валлий тілі", "волоф тілі", "батыс фриз тілі", "кхоса тілі", "идиш тілі", "йоруба тілі", "чжуан тілі", "зулу тілі"];
+
+// km
+static L52: [&str; 184] = ["អាហ្វារ", "អាប់ខាហ៊្សាន", "អាហ្វ្រិកាន", "អាកាន", "អាល់បានី", "អាំហារិក", "អារ៉ាប់", "អារ៉ាហ្គោន", "អាមេនី", "អាសាមីស", "អាវ៉ារីក", "អាវេស្ថាន", "អីម៉ារ៉ា", "អាស៊ែបៃហ្សង់", "បាម្បារា", "បាស្គៀ", "បាសខ៍", "បេឡារុស", "បង់ក្លា", "", "ប៊ីស្លាម៉ា", "បូស្ន៊ី", "ប្រ៊ីស្តុន", "ប៊ុលហ្ការី", "ភូមា", "កាតាឡាន", "ឈីម៉ូរ៉ូ", "ឈីឆេន", "ណានចា", "ចិន", "ឈូវ៉ាស", "កូនីស", "កូស៊ីខាន", "", "ក្រូអាស៊ី", "ឆែក", "ដាណឺម៉ាក", "ទេវីហ៊ី", "ហូឡង់", "ដុងខា", "អង់គ្លេស", "អេស្ពេរ៉ាន់តូ", "អេស្តូនី", "អ៊ីវ", "ហ្វារូស", "ហ៊្វីជី", "ហ្វាំងឡង់", "បារាំង", "ហ្វ៊ូឡា", "ហ្កាលីស្យាន", "ហ្សក\u{200B}ហ្ស៊ី", "អាល្លឺម៉ង់", "ក្រិក", "ហ្គូរ៉ានី", "ហ្គុយ៉ារ៉ាទី", "ហៃទី", "ហូសា", "ហេប្រឺ", "ហឺរីរ៉ូ", "ហិណ្ឌី", "", "ហុងគ្រី", "អ៊ីនធើលីង", "ឥណ្ឌូណេស៊ី", "អ៊ីនធើលីងវេ", "អៀរឡង់", "អ៊ីកបូ", "", "អ៊ីដូ", "អ៊ីស្លង់", "អ៊ីតាលី", "អ៊ីនុកទីទុត", "ជប៉ុន", "ជ្វា", "កាឡាលលីស៊ុត", "ខាណាដា", "កានូរី", "កាស្មៀរ", "កាហ្សាក់", "ខ្មែរ", "គីគូយូ", "គិនយ៉ាវ៉ាន់ដា", "\u{200B}កៀហ្ស៊ីស", "កូមី", "", "កូរ៉េ", "ឃឺដ", "គូនយ៉ាម៉ា", "ឡាតំាង", "លុចសំបួ", "ហ្កាន់ដា", "លីមប៊ូស", "លីនកាឡា", "ឡាវ", "លីទុយអានី", "លូបាកាតានហ្គា", "ឡាតវី", "មេន", "ម៉ាសេដូនី", "ម៉ាឡាហ្គាស៊ី", "ម៉ាឡេ", "ម៉ាឡាយ៉ាឡាម", "ម៉ាល់តា", "ម៉ោរី", "ម៉ារ៉ាធី", "ម៉ាស់សល", "ម៉ុងហ្គោលី", "ណូរូ", "ណាវ៉ាចូ", "នេបេលេខាងជើង", "នេប៉ាល់", "នុនហ្គា", "ន័រវែស បុកម៉ាល់", "ន័រវែស នីនូស", "ន័រវែស", "ស៊ីឈាន់យី", "នេប៊េលខាងត្បូង", "អូសីតាន់", "", "ឈើជស្លាវិក", "អូរ៉ូម៉ូ", "អូឌៀ", "អូស៊ីទិក", "បឹនជាពិ", "", "ភឺសៀន", "ប៉ូឡូញ", "បាស្តូ", "ព័រទុយហ្គាល់", "ហ្គិកឈួ", "រ៉ូម៉ង់", "រុណ្ឌី", "រូម៉ានី", "រុស្ស៊ី", "សំស្ក្រឹត", "សាឌីនា", "ស៊ីនឌី", "សាមីខាងជើង", "សាម័រ", "សានហ្គោ", "ស៊ែប", "ស្កុតហ្កែលិគ", "សូណា", "ស្រីលង្កា", "ស្លូវ៉ាគី", "ស្លូវ៉ានី", "សូម៉ាលី", "សូថូខាងត្បូង", "អេស្ប៉ាញ", "ស៊ូដង់", "ស្វាហ៊ីលី", "ស្វាទី", "ស៊ុយអែត", "តាមីល", "តេលុគុ", "តាហ្ស៊ីគ", "ថៃ", "ទីហ្គ្រីញ៉ា", "ទីបេ", "តួកម៉េន", "ហ្វ៊ីលីពីន", "ស្វាណា", "តុងហ្គា", "ទួរគី", "សុងហ្គា", "តាតា", "ទ្វី", "តាហ៊ីទី", "អ៊ុយហ្គឺរ", "អ៊ុយក្រែន", "អ៊ូរឌូ", "អ៊ូសបេគ", "វេនដា", "វៀតណាម", "វូឡាពូក", "វ៉ាលូន", "វេល", "វូឡុហ្វ", "ហ្វ្រីស៊ានខាងលិច", "ឃសា", "យ៉ីឌីស", "យរូបា", "ហ្សួង", "ហ្សូលូ"];
+
+// rw
+static L53: [&str; 184] = ["", "", "Ikinyafurikaneri", "", "Icyalubaniya", "Inyamuhariki", "Icyarabu", "", "Ikinyarumeniya", "Icyasamizi", "", "", "", "Inyazeribayijani", "", "", "Ikibasiki", "Ikibelarusiya", "Ikibengali", "", "", "Inyebosiniya", "Inyebiritoni", "Urunyabuligariya", "", "Igikatalani", "", "", "", "", "", "", "", "", "Igikorowasiya", "Igiceke", "Ikidaninwa", "", "Ikinerilande", "", "Icyongereza", "Icyesiperanto", "Icyesitoniya", "", "Inyefaroyizi", "", "Igifinilande", "Igifaransa", "", "Ikigalisiya", "Inyejeworujiya", "Ikidage", "Ikigereki", "Inyaguwarani", "Inyegujarati", "", "", "Igiheburayo", "", "Igihindi", "", "Igihongiriya", "Ururimi Gahuzamiryango", "Ikinyendoziya", "Uruhuzandimi", "Ikirilandi", "", "", "", "Igisilande", "Igitaliyani", "", "Ikiyapani", "Inyejava", "", "Igikanada", "", "", "", "Igikambodiya", "", "Ikinyarwanda", "Inkerigizi", "", "", "Igikoreya", "Inyekuridishi", "", "Ikilatini", "", "", "", "Ilingala", "Ikilawotiyani", "Ikilituwaniya", "", "Ikinyaletoviyani", "", "Ikimasedoniya", "", "Ikimalayi", "Ikimalayalami", "Ikimaliteze", "", "Ikimarati", "", "Ikimongoli", "", "", "", "Ikinepali", "", "", "Inyenoruveji (Nyonorusiki)", "Ikinoruveji", "", "", "Inyogusitani", "", "", "", "Inyoriya", "", "Igipunjabi", "", "Inyeperisi", "Igipolone", "Impashito", "Igiporutugali", "", "", "", "Ikinyarumaniya", "Ikirusiya", "Igisansikiri", "", "Igisindi", "", "", "", "Igiseribe", "Ikigaluwa cy’Igisweduwa", "", "Inyesimpaleze", "Igisilovaki", "Ikinyasiloveniya", "Igisomali", "Inyesesoto", "Icyesipanyolo", "Inyesudani", "Igiswahili", "", "Igisuweduwa", "Igitamili", "Igitelugu", "", "Igitayi", "Inyatigirinya", "", "Inyeturukimeni", "Ikinyafilipine", "", "", "Igiturukiya", "", "", "Inyetuwi", "", "Ikiwiguri", "Ikinyayukereni", "Inyeyurudu", "Inyeyuzubeki", "", "Ikinyaviyetinamu", "", "", "Ikigaluwa", "", "Igifiriziyani", "Inyehawusa", "Inyeyidishi", "", "", "Inyezulu"];
+
+// ky
+static L54: [&str; 184] = ["афарча", "абхазча", "африкаанча", "аканча", "албанча", "амхарча", "арабча", "арагончо", "армянча", "ассамча", "аварикче", "", "аймарача", "азербайжанча", "бамбарача", "башкырча", "баскча", "беларусча", "бангладешче", "", "бисламача", "боснийче", "бретончо", "болгарча", "бурмача", "каталончо", "чаморрочо", "чеченче", "ньянджача", "кытайча", "чувашча", "корнишче", "корсиканча", "", "хорватча", "чехче", "датча", "дивехиче", "голландча", "жонгуча", "англисче", "эсперанто", "эстончо", "эбече", "фарерче", "фижиче", "финче", "французча", "фулача", "галисияча", "грузинче", "немисче", "грекче", "гуараниче", "гужаратча", "гаитиче", "хаусача", "ивритче", "герерочо", "хиндиче", "", "венгерче", "интерлингва", "индонезияча", "интерлинг", "ирландча", "игбочо", "", "идочо", "исландча", "италиянча", "инуктитутча", "жапончо", "жаванизче", "калаалисутча", "каннадача", "кануриче", "кашмирче", "казакча", "кмерче", "кикуйиче", "руандача", "кыргызча", "комиче", "", "корейче", "курдча", "куаньямача", "латынча", "люксембургча", "гандача", "лимбургиче", "лингалача", "лаочо", "литовчо", "луба-катангача", "латышча", "мэнксыча", "македончо", "малагасча", "малайча", "малайаламча", "малтизче", "маориче", "маратиче", "маршаллча", "монголчо", "науруча", "наваджочо", "түндүк ндыбелче", "непалча", "ндонгача", "норвежче (букмал)", "норвежче (нинорск)", "норвежче", "сычуань йиче", "түштүк ндебелече", "окситанча", "", "чиркөө славянча", "оромочо", "орияча", "осетинче", "пунжабиче", "", "фарсча", "полякча", "пуштуча", "португалча", "кечуача", "романшча", "рундиче", "румынча", "орусча", "санскритче", "сардинче", "синдхиче", "түндүк саамиче", "самоанча", "сангочо", "сербче", "шотладиялык гелча", "шонача", "сингалача", "словакча", "словенче", "сомаличе", "сесоточо", "испанча", "сунданча", "суахиличе", "сватиче", "шведче", "тамилче", "телугуча", "тажикче", "тайча", "тигриниача", "тибетче", "түркмөнчө", "филипинче", "тсванача", "тонгача", "түркчө", "тсонгача", "татарча", "тви", "таитиче", "уйгурча", "украинче", "урдуча", "өзбекче", "вендача", "вьетнамча", "волапюкча", "валлончо", "уелшче", "уолофчо", "батыш фризче", "косача", "идишче", "йорубача", "чжуанча", "зулуча"];
+
+// ko
+static L55: [&str; 184] = ["아파르어", "압카즈어", "아프리칸스어", "아칸어", "알바니아어", "암하라어", "아랍어", "아라곤어", "아르메니아어", "아삼어", "아바릭어", "아베스타어", "아이마라어", "아제르바이잔어", "밤바라어", "바슈키르어", "바스크어", "벨라루스어", "벵골어", "", "비슬라마어", "보스니아어", "브르타뉴어", "불가리아어", "버마어", "카탈로니아어", "차모로어", "체첸어", "냔자어", "중국어", "추바시어", "콘월어", "코르시카어", "크리어", "크로아티아어", "체코어", "덴마크어", "디베히어", "네덜란드어", "종카어", "영어", "에스페란토어", "에스토니아어", "에웨어", "페로어", "피지어", "핀란드어", "프랑스어", "풀라어", "갈리시아어", "조지아어", "독일어", "그리스어", "과라니어", "구자라트어", "아이티어", "하우사어", "히브리어", "헤레로어", "힌디어", "히리 모투어", "헝가리어", "인터링구아", "인도네시아어", "인테르링구에", "아일랜드어", "이그보어", "이누피아크어", "이도어", "아이슬란드어", "이탈리아어", "이눅티투트어", "일본어", "자바어", "그린란드어", "칸나다어", "칸누리어", "카슈미르어", "카자흐어", "크메르어", "키쿠유어", "르완다어", "키르기스어", "코미어", "콩고어", "한국어", "쿠르드어", "쿠안야마어", "라틴어", "룩셈부르크어", "간다어", "림버거어", "링갈라어", "라오어", "리투아니아어", "루바-카탄가어", "라트비아어", "맹크스어", "마케도니아어", "말라가시어", "말레이어", "말라얄람어", "몰타어", "마오리어", "마라티어", "마셜어", "몽골어", "나우루어", "나바호어", "북부 은데벨레어", "네팔어", "느동가어", "노르웨이어(보크말)", "노르웨이어(니노르스크)", "노르웨이어", "쓰촨 이어", "남부 은데벨레어", "오크어", "오지브와어", "교회 슬라브어", "오로모어", "오리야어", "오세트어", "펀자브어", "팔리어", "페르시아어", "폴란드어", "파슈토어", "포르투갈어", "케추아어", "로만시어", "룬디어", "루마니아어", "러시아어", "산스크리트어", "사르디니아어", "신디어", "북부 사미어", "사모아어", "산고어", "세르비아어", "스코틀랜드 게일어", "쇼나어", "싱할라어", "슬로바키아어", "슬로베니아어", "소말리아어", "남부 소토어", "스페인어", "순다어", "스와힐리어", "시스와티어", "스웨덴어", "타밀어", "텔루구어", "타지크어", "태국어", "티그리냐어", "티베트어", "투르크멘어", "타갈로그어", "츠와나어", "통가어", "튀르키예어", "총가어", "타타르어", "트위어", "타히티어", "위구르어", "우크라이나어", "우르두어", "우즈베크어", "벤다어", "베트남어", "볼라퓌크어", "왈론어", "웨일스어", "월로프어", "서부 프리지아어", "코사어", "이디시어", "요루바어", "주앙어", "줄루어"];
+
+// ku
+static L56: [&str; 184] = ["afarî", "abxazî", "afrîkansî", "akanî", "arnawidî", "amharî", "erebî", "aragonî", "ermenî", "asamî", "avarî", "", "aymarayî", "azerbaycanî", "bambarayî", "başkîrî", "baskî", "belarûsî", "bengalî", "", "bîslamayî", "bosnî", "bretonî", "bulgarî", "burmayî", "katalanî", "çamoroyî", "çeçenî", "çîçewayî", "çînî", "çuvaşî", "kornî", "korsîkayî", "", "xirwatî", "çekî", "danîmarkî", "divehî", "holendî", "conxayî", "îngilîzî", "esperantoyî", "estonî", "eweyî", "ferî", "fîjî", "fînî", "fransizî", "fulahî", "galîsî", "gurcî", "almanî", "yûnanî", "guwaranî", "gujaratî", "haîtî", "hawsayî", "îbranî", "hereroyî", "hindî", "", "macarî", "înterlîngua", "endonezyayî", "înterlîngue", "îrlendî", "îgboyî", "", "îdoyî", "îzlendî", "îtalî", "înuîtî", "japonî", "javayî", "kalalîsûtî", "kannadayî", "kanurîyî", "keşmîrî", "qazaxî", "khmerî", "kîkûyûyî", "kînyariwandayî", "qirgizî", "komî", "", "koreyî", "kurdî (kurmancî)", "kwanyamayî", "latînî", "luksembûrgî", "lugandayî", "lîmbûrgî", "lingalayî", "lao", "lîtwanî", "luba-katangayî", "latvîyayî", "manksî", "makedonî", "malagasî", "malezî", "malayalamî", "maltayî", "maorî", "maratî", "marşalî", "moxolî", "nawrûyî", "navajoyî", "ndebelîya bakurî", "nepalî", "ndongayî", "norwecî (bokmål)", "norwecî (nynorsk)", "norwecî", "yîyîya siçuwayî", "ndebelîya başûrî", "oksîtanî", "", "slavîya kenîseyî", "oromoyî", "oriyayî", "osetî", "puncabî", "palî", "farisî", "polonî", "peştûyî", "portugalî", "keçwayî", "romancî", "rundî", "romanî", "rûsî", "sanskrîtî", "sardînî", "sindhî", "samîya bakur", "samoayî", "sangoyî", "sirbî", "gaelîka skotî", "şonayî", "kîngalî", "slovakî", "slovenî", "somalî", "sotoyîya başûr", "spanî", "sundanî", "swahîlî", "swazî", "swêdî", "tamîlî", "telûgûyî", "tacikî", "tayî", "tigrînî", "tîbetî", "tirkmenî", "fîlîpînî", "tswanayî", "tongî", "tirkî", "tsongayî", "teterî", "", "tahîtî", "uyxurî", "ukraynî", "urdûyî", "ozbekî", "venda", "vîetnamî", "volapûkî", "walonî", "weylsî", "wolofî", "frîsîya rojavayî", "xosayî", "yidîşî", "yorubayî", "zhuangî", "zuluyî"];
+
+// lb
+static L57: [&str; 184] = ["Afar", "Abchasesch", "Afrikaans", "Akan", "Albanesch", "Amharesch", "Arabesch", "Aragonesesch", "Armenesch", "Assamesesch", "Awaresch", "Avestesch", "Aymara", "Aserbaidschanesch", "Bambara-Sprooch", "Baschkiresch", "Baskesch", "Wäissrussesch", "Bengalesch", "", "Bislama", "Bosnesch", "Bretonesch", "Bulgaresch", "Birmanesch", "Katalanesch", "Chamorro-Sprooch", "Tschetschenesch", "Nyanja-Sprooch", "Chinesesch", "Tschuwaschesch", "Kornesch", "Korsesch", "Cree", "Kroatesch", "Tschechesch", "Dänesch", "Maldivesch", "Hollännesch", "Bhutanesch", "Englesch", "Esperanto", "Estnesch", "Ewe-Sprooch", "Färöesch", "Fidschianesch", "Finnesch", "Franséisch", "Ful", "Galizesch", "Georgesch", "Däitsch", "Griichesch", "Guarani", "Gujarati", "Haitianesch", "Hausa", "Hebräesch", "Herero-Sprooch", "Hindi", "Hiri-Motu", "Ungaresch", "Interlingua", "Indonesesch", "Interlingue", "Iresch", "Igbo-Sprooch", "Inupiak", "Ido-Sprooch", "Islännesch", "Italienesch", "Inukitut", "Japanesch", "Javanesch", "Grönlännesch", "Kannada", "Kanuri-Sprooch", "Kaschmiresch", "Kasachesch", "Kambodschanesch", "Kikuyu-Sprooch", "Ruandesch", "Kirgisesch", "Komi-Sprooch", "Kongolesesch", "Koreanesch", "Kurdesch", "Kwanyama", "Latäin", "Lëtzebuergesch", "Ganda-Sprooch", "Limburgesch", "Lingala", "Laotesch", "Litauesch", "Luba-Katanga", "Lettesch", "Manx", "Mazedonesch", "Malagassi-Sprooch", "Malaiesch", "Malayalam", "Maltesesch", "Maori", "Marathi", "Marschallesesch", "Mongolesch", "Nauruesch", "Navajo", "Nord-Ndebele-Sprooch", "Nepalesesch", "Ndonga", "Norwegesch Bokmål", "Norwegesch Nynorsk", "Norwegesch", "Sichuan Yi", "Süd-Ndebele-Sprooch", "Okzitanesch", "Ojibwa-Sprooch", "Kiercheslawesch", "Oromo", "Orija", "Ossetesch", "Pandschabesch", "Pali", "Persesch", "Polnesch", "Paschtu", "Portugisesch", "Quechua", "Rätoromanesch", "Rundi-Sprooch", "Rumänesch", "Russesch", "Sanskrit", "Sardesch", "Sindhi", "Nordsamesch", "Samoanesch", "Sango", "Serbesch", "Schottescht Gällesch", "Shona", "Singhalesesch", "Slowakesch", "Slowenesch", "Somali", "Süd-Sotho-Sprooch", "Spuenesch", "Sundanesesch", "Suaheli", "Swazi", "Schwedesch", "Tamilesch", "Telugu", "Tadschikesch", "Thailännesch", "Tigrinja", "Tibetesch", "Turkmenesch", "Dagalog", "Tswana-Sprooch", "Tongaesch", "Tierkesch", "Tsonga", "Tataresch", "Twi", "Tahitesch", "Uiguresch", "Ukrainesch", "Urdu", "Usbekesch", "Venda-Sprooch", "Vietnamesesch", "Volapük", "Wallounesch", "Walisesch", "Wolof", "Westfriesesch", "Xhosa", "Jiddesch", "Yoruba", "Zhuang", "Zulu"];
+
+// lo
+static L58: [&str; 184] = ["ອະຟາ", "ແອບຄາຊຽນ", "ອາຟຣິການ", "ອາການ", "ອານບານຽນ", "ອຳຮາຣິກ", "ອາຣັບ", "ອາຣາໂກເນັດ", "ອາເມນຽນ", "ອັສຊາມີສ", "ອາວາຣິກ", "ອາເວັສແຕນ", "ອາຍມາລາ", "ອາເຊີໄບຈານິ", "ບາມບາຣາ", "ບາຣກີ", "ບັສກີ", "ເບລາຣັສຊຽນ", "ເບັງກາລີ", "", "ບິສລະມາ", "ບອສນຽນ", "ເບຣຕັນ", "ບັງກາຣຽນ", "ມຽນມາ", "ຄາຕາລານ", "ຊາມໍໂຣ", "ຊີເຄນ", "ນານຈາ", "ຈີນ", "ຊູວາຊ", "ຄໍນິຊ", "ຄໍຊິກາ", "ຄີ", "ໂຄຣເອທຽນ", "ເຊກ", "ແດນິຊ", "ດີວີຮີ", "ດັຊ", "ດີຊອງຄາ", "ອັງກິດ", "ເອສປາຍ", "ເອສໂຕນຽນ", "ອິວາ", "ຟາໂຣສ", "ຟິຈຽນ", "ຟິນນິຊ", "ຝຣັ່ງ", "ຟູລາ", "ກາລິຊຽນ", "ຈໍຈຽນ", "ເຢຍລະມັນ", "ກຣີກ", "ກົວຣານີ", "ກູຈາຣາຕິ", "ໄຮຕຽນ", "ເຮົາຊາ", "ຮີບຣິວ", "ເຮິຮິໂຣ", "ຮິນດິ", "ຮິຣິໂມຕູ", "ຮັງກາຣຽນ", "ອິນເຕີລິງລົວ", "ອິນໂດເນຊຽນ", "ອິນເຕີລິງກຣີ", "ໄອຣິສ", "ອິກໂບ", "ອິນນູປຽກ", "ອີໂດ", "ໄອສແລນດິກ", "ອິຕາລຽນ", "ອິນນຸກຕິຕັດ", "ຍີ່ປຸ່ນ", "ຈາແວນີສ", "ກຣີນແລນລິດ", "ຄັນນາດາ", "ຄານຸລິ", "ຄາສເມຍຣິ", "ຄາຊັກ", "ຂະເໝນ", "ຄິຄູຢຸ", "ຄິນຢາວານດາ", "ເກຍກີສ", "ໂຄມິ", "ຄອງໂກ", "ເກົາຫລີ", "ເຄີດິສ", "ກວນຍາມາ", "ລາຕິນ", "ລັກເຊມບວກກິຊ", "ແກນດາ", "ລິມເບີກີຊ", "ລິງກາລາ", "ລາວ", "ລິທົວນຽນ", "ລູບາ-ຄາຕັງກາ", "ລັດວຽນ", "ແມງຊ໌", "ແມຊິໂດນຽນ", "ມາລາກາສຊີ", "ມາເລ", "ມາເລອາລຳ", "ມອລທີສ", "ມາວຣິ", "ມາຣາທີ", "ມາຊານເລັດ", "ມອງໂກເລຍ", "ນາຢູລູ", "ນາວາໂຈ", "ເອັນເດເບເລເໜືອ", "ເນປາລີ", "ເອັນດອງກາ", "ນໍເວຈຽນ ບັອກມອລ", "ນໍເວຈຽນ ນີນອກ", "ນໍເວຈຽນ", "ເສສວນ ອີ", "ນີບີລີໃຕ້", "ອັອກຊີຕານ", "ໂອຈິບວາ", "ໂບດສລາວິກ", "ໂອໂຣໂມ", "ໂອເດຍ", "ອອດເຊຕິກ", "ປັນຈາບີ", "ປາລີ", "ເປີຊຽນ", "ໂປລິຊ", "ປາສໂຕ", "ປອກຕຸຍກິສ", "ຄີຊົວ", "ໂຣແມນຊ໌", "ຣຸນດິ", "ໂຣແມນຽນ", "ລັດເຊຍ", "ສັນສະກຣິດ", "ສາດີນຽນ", "ສິນທິ", "ຊາມິເໜືອ", "ຊາມົວ", "ແຊງໂກ", "ເຊີບຽນ", "ສະກັອດເກລິກ", "ໂຊນາ", "ສິນຫາລາ", "ສະໂລແວັກ", "ສະໂລເວນຽນ", "ໂຊມາລີ", "ໂຊໂທໃຕ້", "ສະແປນນິຊ", "ຊຸນແດນນີສ", "ຊວາຮີລິ", "ສະວາຕິ", "ສະວີດິຊ", "ທາມິລ", "ເຕລູກູ", "ທາຈິກ", "ໄທ", "ຕິກຣິນຢາ", "ທິເບທັນ", "ເທີກເມັນ", "ຕາກາລອກ", "ເຕສະວານາ", "ທອງການ", "ເທີຄິຊ", "ເຕຊອງກາ", "ທາທາ", "ທວີ", "ຕາຮີຕຽນ", "ອຸຍເຄີ", "ຢູເຄຣນຽນ", "ອູຣດູ", "ອຸສເບກ", "ເວນດາ", "ຫວຽດນາມ", "ໂວລາພັກ", "ວໍລູມ", "ເວວ", "ວໍລອບ", "ຟຣິຊຽນ ຕາເວັນຕົກ", "ໂຮຊາ", "ຢິວ", "ໂຢຣູບາ", "ຊວາງ", "ຊູລູ"];
+
+// lt
+static L59: [&str; 184] = ["afarų", "abchazų", "afrikanų", "akanų", "albanų", "amharų", "arabų", "aragonesų", "armėnų", "asamų", "avarikų", "avestų", "aimarų", "azerbaidžaniečių", "bambarų", "baškirų", "baskų", "baltarusių", "bengalų", "", "bislama", "bosnių", "bretonų", "bulgarų", "birmiečių", "katalonų", "čamorų", "čečėnų", "nianjų", "kinų", "čiuvašų", "kornų", "korsikiečių", "kry", "kroatų", "čekų", "danų", "divehų", "olandų", "botijų", "anglų", "esperanto", "estų", "evių", "farerų", "fidžių", "suomių", "prancūzų", "fulahų", "galisų", "gruzinų", "vokiečių", "graikų", "gvaranių", "gudžaratų", "Haičio", "hausų", "hebrajų", "hererų", "hindi", "hiri motu", "vengrų", "tarpinė", "indoneziečių", "interkalba", "airių", "igbų", "inupiakų", "ido", "islandų", "italų", "inukitut", "japonų", "javiečių", "kalalisut", "kanadų", "kanurių", "kašmyrų", "kazachų", "khmerų", "kikujų", "kinjaruandų", "kirgizų", "komi", "Kongo", "korėjiečių", "kurdų", "kuaniama", "lotynų", "liuksemburgiečių", "ganda", "limburgiečių", "ngalų", "laosiečių", "lietuvių", "luba katanga", "latvių", "meniečių", "makedonų", "malagasų", "malajiečių", "malajalių", "maltiečių", "maorių", "maratų", "Maršalo Salų", "mongolų", "naurų", "navajų", "šiaurės ndebelų", "nepaliečių", "ndongų", "norvegų bukmolas", "naujoji norvegų", "norvegų", "sičuan ji", "pietų ndebele", "očitarų", "ojibva", "bažnytinė slavų", "oromų", "odijų", "osetinų", "pendžabų", "pali", "persų", "lenkų", "puštūnų", "portugalų", "kečujų", "retoromanų", "rundi", "rumunų", "rusų", "sanskritas", "sardiniečių", "sindų", "šiaurės samių", "Samoa", "sango", "serbų", "škotų (gėlų)", "šonų", "sinhalų", "slovakų", "slovėnų", "somaliečių", "pietų Soto", "ispanų", "sundų", "suahilių", "svatų", "švedų", "tamilų", "telugų", "tadžikų", "tajų", "tigrajų", "tibetiečių", "turkmėnų", "tagalogų", "tsvanų", "tonganų", "turkų", "tsongų", "totorių", "tvi", "taitiečių", "uigūrų", "ukrainiečių", "urdų", "uzbekų", "vendų", "vietnamiečių", "volapiuko", "valonų", "valų", "volofų", "vakarų fryzų", "kosų", "jidiš", "jorubų", "chuang", "zulų"];
+
+// lv
+static L60: [&str; 184] = ["afāru", "abhāzu", "afrikandu", "akanu", "albāņu", "amharu", "arābu", "aragoniešu", "armēņu", "asamiešu", "avāru", "avesta", "aimaru", "azerbaidžāņu", "bambaru", "baškīru", "basku", "baltkrievu", "bengāļu", "", "bišlamā", "bosniešu", "bretoņu", "bulgāru", "birmiešu", "katalāņu", "čamorru", "čečenu", "čičeva", "ķīniešu", "čuvašu", "korniešu", "korsikāņu", "krī", "horvātu", "čehu", "dāņu", "maldīviešu", "holandiešu", "dzongke", "angļu", "esperanto", "igauņu", "evu", "fēru", "fidžiešu", "somu", "franču", "fulu", "galisiešu", "gruzīnu", "vācu", "grieķu", "gvaranu", "gudžaratu", "haitiešu", "hausu", "ivrits", "hereru", "hindi", "hirimotu", "ungāru", "interlingva", "indonēziešu", "interlingve", "īru", "igbo", "inupiaku", "ido", "islandiešu", "itāļu", "inuītu", "japāņu", "javiešu", "grenlandiešu", "kannadu", "kanuru", "kašmiriešu", "kazahu", "khmeru", "kikuju", "kiņaruanda", "kirgīzu", "komiešu", "kongu", "korejiešu", "kurdu", "kvaņamu", "latīņu", "luksemburgiešu", "gandu", "limburgiešu", "lingala", "laosiešu", "lietuviešu", "lubakatanga", "latviešu", "meniešu", "maķedoniešu", "malagasu", "malajiešu", "malajalu", "maltiešu", "maoru", "marathu", "māršaliešu", "mongoļu", "nauruiešu", "navahu", "ziemeļndebelu", "nepāliešu", "ndongu", "norvēģu bukmols", "jaunnorvēģu", "norvēģu", "Sičuaņas ji", "dienvidndebelu", "oksitāņu", "odžibvu", "baznīcslāvu", "oromu", "oriju", "osetīnu", "pandžabu", "pāli", "persiešu", "poļu", "puštu", "portugāļu", "kečvu", "retoromāņu", "rundu", "rumāņu", "krievu", "sanskrits", "sardīniešu", "sindhu", "ziemeļsāmu", "samoāņu", "sango", "serbu", "skotu gēlu", "šonu", "singāļu", "slovāku", "slovēņu", "somāļu", "dienvidsotu", "spāņu", "zundu", "svahili", "svatu", "zviedru", "tamilu", "telugu", "tadžiku", "taju", "tigrinja", "tibetiešu", "turkmēņu", "tagalu", "cvanu", "tongiešu", "turku", "congu", "tatāru", "tvī", "taitiešu", "uiguru", "ukraiņu", "urdu", "uzbeku", "vendu", "vjetnamiešu", "volapiks", "valoņu", "velsiešu", "volofu", "rietumfrīzu", "khosu", "jidišs", "jorubu", "džuanu", "zulu"];
+
+// mk
+static L61: [&str; 184] = ["афарски", "апхаски", "африканс", "акански", "албански", "амхарски", "арапски", "арагонски", "ерменски", "асамски", "аварски", "авестански", "ајмарски", "азербејџански", "бамбара", "башкирски", "баскиски", "белоруски", "бенгалски", "", "бислама", "босански", "бретонски", "бугарски", "бурмански", "каталонски", "чаморски", "чеченски", "њанџа", "кинески", "чувашки", "корнски", "корзикански", "кри", "хрватски", "чешки", "дански", "дивехи", "холандски", "ѕонгка", "англиски", "есперанто", "естонски", "еве", "фарски", "фиџиски", "фински", "француски", "фула", "галисиски", "грузиски", "германски", "грчки", "гварански", "гуџарати", "хаитски", "хауса", "хебрејски", "хереро", "хинди", "хири моту", "унгарски", "интерлингва", "индонезиски", "интерлингве", "ирски", "игбо", "инупијачки", "идо", "исландски", "италијански", "инуктитут", "јапонски", "јавански", "калалисут", "каннада", "канури", "кашмирски", "казашки", "кмерски", "кикују", "руандски", "киргиски", "коми", "конго", "корејски", "курдски", "квањама", "латински", "луксембуршки", "ганда", "лимбуршки", "лингала", "лаошки", "литвански", "луба-катанга", "латвиски", "манкс", "македонски", "малгашки", "малајски", "малајалски", "малтешки", "маорски", "марати", "маршалски", "монголски", "науруански", "навахо", "северен ндебеле", "непалски", "ндонга", "норвешки букмол", "норвешки нинорск", "норвешки", "сичуан ји", "јужен ндебеле", "окситански", "оџибва", "црковнословенски", "оромо", "одија", "осетски", "пенџапски", "пали", "персиски", "полски", "паштунски", "португалски", "кечуански", "реторомански", "рунди", "романски", "руски", "санскрит", "сардински", "синди", "северен сами", "самоански", "санго", "српски", "шкотски гелски", "шона", "синхалски", "словачки", "словенечки", "сомалиски", "сесото", "шпански", "сундски", "свахили", "свати", "шведски", "тамилски", "телугу", "таџикистански", "тајландски", "тигриња", "тибетски", "туркменски", "тагалог", "цвана", "тонгајски", "турски", "цонга", "татарски", "тви", "тахитски", "ујгурски", "украински", "урду", "узбечки", "венда", "виетнамски", "волапик", "валонски", "велшки", "волофски", "западнофризиски", "коса", "јидиш", "јорупски", "џуаншки", "зулу"];
+
+// ms
+static L62: [&str; 184] = ["Afar", "Abkhazia", "Afrikaans", "Akan", "Albania", "Amharic", "Arab", "Aragon", "Armenia", "Assam", "Avaric", "Avestan", "Aymara", "Azerbaijan", "Bambara", "Bashkir", "Basque", "Belarus", "Benggali", "", "Bislama", "Bosnia", "Breton", "Bulgaria", "Burma", "Catalonia", "Chamorro", "Chechen", "Nyanja", "Cina", "Chuvash", "Cornish", "Corsica", "", "Croatia", "Czech", "Denmark", "Divehi", "Belanda", "Dzongkha", "Inggeris", "Esperanto", "Estonia", "Ewe", "Faroe", "Fiji", "Finland", "Perancis", "Fulah", "Galicia", "Georgia", "Jerman", "Greek", "Guarani", "Gujarat", "Kreol Haiti", "Hausa", "Ibrani", "Herero", "Hindi", "", "Hungary", "Interlingua", "Indonesia", "Interlingue", "Ireland", "Igbo", "", "Ido", "Iceland", "Itali", "Inuktitut", "Jepun", "Jawa", "Kalaallisut", "Kannada", "Kanuri", "Kashmir", "Kazakhstan", "Khmer", "Kikuya", "Kinyarwanda", "Kirghiz", "Komi", "Kongo", "Korea", "Kurdish", "Kuanyama", "Latin", "Luxembourg", "Ganda", "Limburgish", "Lingala", "Laos", "Lithuania", "Luba-Katanga", "Latvia", "Manx", "Macedonia", "Malagasy", "Melayu", "Malayalam", "Malta", "Maori", "Marathi", "Marshall", "Mongolia", "Nauru", "Navajo", "Ndebele Utara", "Nepal", "Ndonga", "Bokmal Norway", "Nynorsk Norway", "Norway", "Sichuan Yi", "Ndebele Selatan", "Occitania", "", "Slavik Gereja", "Oromo", "Odia", "Ossete", "Punjabi", "", "Parsi", "Poland", "Pashto", "Portugis", "Quechua", "Romansh", "Rundi", "Romania", "Rusia", "Sanskrit", "Sardinia", "Sindhi", "Sami Utara", "Samoa", "Sango", "Serbia", "Scots Gaelic", "Shona", "Sinhala", "Slovak", "Slovenia", "Somali", "Sotho Selatan", "Sepanyol", "Sunda", "Swahili", "Swati", "Sweden", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinya", "Tibet", "Turkmen", "Filipina", "Tswana", "Tonga", "Turki", "Tsonga", "Tatar", "Twi", "Tahiti", "Uyghur", "Ukraine", "Urdu", "Uzbekistan", "Venda", "Vietnam", "Volapük", "Walloon", "Wales", "Wolof", "Frisian Barat", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// ml
+static L63: [&str; 184] = ["അഫാർ", "അബ്\u{200C}ഖാസിയൻ", "ആഫ്രിക്കാൻസ്", "അകാൻ\u{200C}", "അൽബേനിയൻ", "അംഹാരിക്", "അറബിക്", "അരഗോണീസ്", "അർമേനിയൻ", "ആസ്സാമീസ്", "അവാരിക്", "അവസ്റ്റാൻ", "അയ്മാറ", "അസർബൈജാനി", "ബംബാറ", "ബഷ്ഖിർ", "ബാസ്\u{200C}ക്", "ബെലാറുഷ്യൻ", "ബംഗ്ലാ", "", "ബിസ്\u{200C}ലാമ", "ബോസ്നിയൻ", "ബ്രെട്ടൺ", "ബൾഗേറിയൻ", "ബർമീസ്", "കറ്റാലാൻ", "ചമോറോ", "ചെചൻ", "ന്യൻജ", "ചൈനീസ്", "ചുവാഷ്", "കോർണിഷ്", "കോർസിക്കൻ", "ക്രീ", "ക്രൊയേഷ്യൻ", "ചെക്ക്", "ഡാനിഷ്", "ദിവെഹി", "ഡച്ച്", "ദ്\u{200C}സോങ്ക", "ഇംഗ്ലീഷ്", "എസ്\u{200C}പരാന്റോ", "എസ്റ്റോണിയൻ", "യൂ", "ഫാറോസ്", "ഫിജിയൻ", "ഫിന്നിഷ്", "ഫ്രഞ്ച്", "ഫുല", "ഗലീഷ്യൻ", "ജോർജിയൻ", "ജർമ്മൻ", "ഗ്രീക്ക്", "ഗ്വരനി", "ഗുജറാത്തി", "ഹെയ്\u{200C}തിയൻ ക്രിയോൾ", "ഹൗസ", "ഹീബ്രു", "ഹെരേരൊ", "ഹിന്ദി", "ഹിരി മോതു", "ഹംഗേറിയൻ", "ഇന്റർലിംഗ്വ", "ഇന്തോനേഷ്യൻ", "ഇന്റർലിംഗ്വേ", "ഐറിഷ്", "ഇഗ്ബോ", "ഇനുപിയാക്", "ഇഡോ", "ഐസ്\u{200C}ലാൻഡിക്", "ഇറ്റാലിയൻ", "ഇനുക്റ്റിറ്റുട്ട്", "ജാപ്പനീസ്", "ജാവനീസ്", "കലാല്ലിസുട്ട്", "കന്നഡ", "കനൂറി", "കശ്\u{200C}മീരി", "കസാഖ്", "ഖമെർ", "കികൂയു", "കിന്യാർവാണ്ട", "കിർഗിസ്", "കോമി", "കോംഗോ", "കൊറിയൻ", "കുർദ്ദിഷ്", "ക്വാന്യമ", "ലാറ്റിൻ", "ലക്\u{200C}സംബർഗിഷ്", "ഗാണ്ട", "ലിംബർഗിഷ്", "ലിംഗാല", "ലാവോ", "ലിത്വാനിയൻ", "ലുബ-കറ്റംഗ", "ലാറ്റ്വിയൻ", "മാൻസ്", "മാസിഡോണിയൻ", "മലഗാസി", "മലെയ്", "മലയാളം", "മാൾട്ടീസ്", "മവോറി", "മറാത്തി", "മാർഷല്ലീസ്", "മംഗോളിയൻ", "നൗറു", "നവാജോ", "നോർത്ത് ഡെബിൾ", "നേപ്പാളി", "ഡോങ്ക", "നോർവീജിയൻ ബുക്\u{200C}മൽ", "നോർവീജിയൻ നൈനോർക്\u{200C}സ്", "നോർവീജിയൻ", "ഷുവാൻയി", "ദക്ഷിണ നെഡിബിൾ", "ഓക്\u{200C}സിറ്റൻ", "ഓജിബ്വാ", "ചർച്ച് സ്ലാവിക്", "ഒറോമോ", "ഒഡിയ", "ഒസ്സെറ്റിക്", "പഞ്ചാബി", "പാലി", "പേർഷ്യൻ", "പോളിഷ്", "പഷ്\u{200C}തോ", "പോർച്ചുഗീസ്", "ക്വെച്ചുവ", "റൊമാഞ്ച്", "റുണ്ടി", "റൊമാനിയൻ", "റഷ്യൻ", "സംസ്\u{200C}കൃതം", "സർഡിനിയാൻ", "സിന്ധി", "വടക്കൻ സമി", "സമോവൻ", "സാംഗോ", "സെർബിയൻ", "സ്കോട്ടിഷ് ഗൈലിക്", "ഷോണ", "സിംഹള", "സ്ലോവാക്", "സ്ലോവേനിയൻ", "സോമാലി", "തെക്കൻ സോതോ", "സ്\u{200C}പാനിഷ്", "സുണ്ടാനീസ്", "സ്വാഹിലി", "സ്വാറ്റി", "സ്വീഡിഷ്", "തമിഴ്", "തെലുങ്ക്", "താജിക്", "തായ്", "ടൈഗ്രിന്യ", "ടിബറ്റൻ", "തുർക്\u{200C}മെൻ", "തഗാലോഗ്", "സ്വാന", "ടോംഗൻ", "ടർക്കിഷ്", "സോംഗ", "ടാട്ടർ", "ട്വി", "താഹിതിയൻ", "ഉയ്ഘുർ", "ഉക്രേനിയൻ", "ഉറുദു", "ഉസ്\u{200C}ബെക്ക്", "വെന്ദ", "വിയറ്റ്നാമീസ്", "വോളാപുക്", "വല്ലൂൺ", "വെൽഷ്", "വൊളോഫ്", "പശ്ചിമ ഫ്രിഷിയൻ", "ഖോസ", "യിദ്ദിഷ്", "യൊറൂബാ", "സ്വാംഗ്", "സുലു"];
+
+// mt
+static L64: [&str; 184] = ["Afar", "Abkażjan", "Afrikans", "Akan", "Albaniż", "Amhariku", "Għarbi", "Aragoniż", "Armen", "Assamiż", "Avarik", "Avestan", "Aymara", "Ażerbajġani", "Bambara", "Bashkir", "Bask", "Belarussu", "Bengali", "", "Bislama", "Bożnijaku", "Breton", "Bulgaru", "Burmiż", "Katalan", "Chamorro", "Chechen", "Nyanja", "Ċiniż", "Chuvash", "Korniku", "Korsiku", "Cree", "Kroat", "Ċek", "Daniż", "Divehi", "Olandiż", "Dzongkha", "Ingliż", "Esperanto", "Estonjan", "Ewe", "Faroese", "Fiġjan", "Finlandiż", "Franċiż", "Fulah", "Galiċjan", "Ġorġjan", "Ġermaniż", "Grieg", "Guarani", "Gujarati", "Creole ta’ Haiti", "Hausa", "Ebrajk", "Herero", "Hindi", "Hiri Motu", "Ungeriż", "Interlingua", "Indoneżjan", "Interlingue", "Irlandiż", "Igbo", "Inupjak", "Ido", "Iżlandiż", "Taljan", "Inuktitut", "Ġappuniż", "Ġavaniż", "Kalallisut", "Kannada", "Kanuri", "Kashmiri", "Każak", "Khmer", "Kikuju", "Kinjarwanda", "Kirgiż", "Komi", "Kongo", "Korean", "Kurd", "Kuanyama", "Latin", "Lussemburgiż", "Ganda", "Limburgish", "Lingaljan", "Laosjan", "Litwan", "Luba-Katanga", "Latvjan", "Manx", "Maċedonjan", "Malagasy", "Malay", "Malayalam", "Malti", "Maori", "Marathi", "Marshalljaniż", "Mongoljan", "Naurujan", "Navajo", "Ndebeli tat-Tramuntana", "Nepaliż", "Ndonga", "Bokmal Norveġiż", "Ninorsk Norveġiż", "Norveġiż", "Sichuan Yi", "Ndebele tan-Nofsinhar", "Oċċitan", "Oġibwa", "Slaviku tal-Knisja", "Oromo", "Odia", "Ossettiku", "Punjabi", "Pali", "Persjan", "Pollakk", "Pashto", "Portugiż", "Quechua", "Romanz", "Rundi", "Rumen", "Russu", "Sanskrit", "Sardinjan", "Sindhi", "Sami tat-Tramuntana", "Samoan", "Sango", "Serb", "Galliku Skoċċiż", "Shona", "Sinhala", "Slovakk", "Sloven", "Somali", "Soto tan-Nofsinhar", "Spanjol", "Sundaniż", "Swahili", "Swati", "Żvediż", "Tamil", "Telugu", "Taġik", "Tajlandiż", "Tigrinya", "Tibetjan", "Turkmeni", "Tagalog", "Tswana", "Tongan", "Tork", "Tsonga", "Tatar", "Twi", "Taħitjan", "Uyghur", "Ukren", "Urdu", "Uzbek", "Venda", "Vjetnamiż", "Volapuk", "Walloon", "Welsh", "Wolof", "Frisian tal-Punent", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// mi
+static L65: [&str; 184] = ["", "Apakāhiana", "Awherikāna", "Ākana", "Arapeiniana", "Amahereka", "Ārapi", "Arakonihi", "Āmeniana", "Āhamēhi", "Āwhāriki", "", "Aimāra", "Atepaihānia", "Pāpara", "Pākīra", "Pākihi", "Perarūhiana", "Pākara", "", "Pihirāma", "Pōngiana", "Peretana", "Purukāriana", "Pēmīhi", "Katarana", "Tiamoro", "Tietiene", "Niānia", "Hainamana", "Tiuwhāhi", "Kōnihi", "Kōhikana", "", "Koroātiana", "Tieke", "Teina", "Tīwhehi", "Tati", "Tonoka", "Ingarihi", "Eheperāto", "Etōniana", "Ewe", "Wharoīhi", "Whītīana", "Whinirānia", "Wīwī", "Whūra", "Karīhia", "Hōriana", "Tiamana", "Kariki", "Kuaranī", "Kutarāti", "Kereō Haiti", "Hauha", "Hīperu", "Herero", "Hīni", "", "Hanekari", "Inarīngua", "Initonīhiana", "", "Airihi", "Ikapo", "", "Īto", "Tiorangi", "Itāriana", "Inukitetūta", "Hapanihi", "Hāwhanihi", "Kararīhutu", "Kanara", "Kanuri", "Kahimiri", "Kahāka", "Kimēra", "Kikūiu", "Kiniawāna", "Kiakihi", "Komi", "", "Kōreana", "Kūrihi", "Kuoniāma", "Rātini", "Rakapuō", "Kānata", "Ripūkuihi", "Ringāra", "Rao", "Rituānia", "Rupa Katanga", "Rāwhia", "Manaki", "Makerōnia", "Marakāhi", "Marei", "Mareiārama", "Mārata", "Māori", "Marati", "Mararīhi", "Mongōria", "Nauru", "Nawahō", "Enetepēra ki te Raki", "Nepari", "Natōka", "Pakamō Nōwei", "Nīnōka Nōwei", "Nōwei", "Hīhuana Eī", "Enetepēra ki te Tonga", "Ōkitana", "", "", "Ōromo", "Ōtia", "Ōtītiki", "Punutapi", "", "Pāhiana", "Pōrihi", "Pāhitō", "Pōtukīhi", "Kētua", "Romānihi", "Rūniti", "Romeinia", "Ruhiana", "Hanahiti", "Hārinia", "Hiniti", "Hami ki te Raki", "Hāmoa", "Hāngo", "Hirupia", "Keiriki Kotimana", "Hōna", "Hinihāra", "Horowākia", "Horowinia", "Hamāri", "Hōto ki te Tonga", "Pāniora", "Hunanīhi", "Wāhīri", "Wāti", "Huitene", "Tamira", "Teruku", "Tāhiki", "Tai", "Tekirinia", "Tipete", "Tākamana", "Piripīno", "Hawāna", "Tonga", "Tākei", "Honga", "Tatā", "", "Tahiti", "Wīkura", "Ukareinga", "Ūrutu", "Ūpeke", "Wēnera", "Whitināmu", "", "Warūna", "Werehi", "Warawhe", "Whirīhiana ki te Uru", "Tōha", "Irihi", "Ōrūpa", "", "Tūru"];
+
+// mr
+static L66: [&str; 184] = ["अफार", "अबखेजियन", "अफ्रिकान्स", "अकान", "अल्बानियन", "अम्हारिक", "अरबी", "अर्गोनीज", "अर्मेनियन", "आसामी", "अ\u{200D}ॅव्हेरिक", "अवेस्तन", "ऐमरा", "अझरबैजानी", "बाम्बारा", "बष्किर", "बास्क", "बेलारुशियन", "बंगाली", "", "बिस्लामा", "बोस्नियन", "ब्रेतॉन", "बल्गेरियन", "बर्मी", "कातालान", "कॅमोरो", "चेचेन", "न्यान्जा", "चीनी", "चूवाश", "कोर्निश", "कॉर्सिकन", "क्री", "क्रोएशियन", "झेक", "डॅनिश", "दिवेही", "डच", "झोंगखा", "इंग्रजी", "एस्परान्टो", "इस्टोनियन", "एवे", "फरोइज", "फिजियन", "फिनिश", "फ्रेंच", "फुलाह", "गॅलिशियन", "जॉर्जियन", "जर्मन", "ग्रीक", "गुआरनी", "गुजराती", "हैतीयन क्रेओल", "हौसा", "हिब्रू", "हरेरो", "हिंदी", "हिरी मॉटू", "हंगेरियन", "इंटरलिंग्वा", "इंडोनेशियन", "इंटरलिंग", "आयरिश", "ईग्बो", "इनूपियाक", "इडौ", "आईसलँडिक", "इटालियन", "इनुक्तीटुट", "जपानी", "जावानीज", "कलाल्लिसत", "कन्नड", "कनुरी", "काश्मिरी", "कझाक", "ख्मेर", "किकुयू", "किन्यार्वान्डा", "किरगीझ", "कोमी", "काँगो", "कोरियन", "कुर्दिश", "क्वान्यामा", "लॅटिन", "लक्झेंबर्गिश", "गांडा", "लिंबूर्गिश", "लिंगाला", "लाओ", "लिथुआनियन", "ल्यूबा-कटांगा", "लात्व्हियन", "मांक्स", "मॅसेडोनियन", "मलागसी", "मलय", "मल्याळम", "माल्टिज्", "माओरी", "मराठी", "मार्शलीज", "मंगोलियन", "नउरू", "नावाजो", "उत्तर देबेली", "नेपाळी", "डोंगा", "नॉर्वेजियन बोकमाल", "नॉर्वेजियन न्योर्स्क", "नॉर्वेजियन", "सिचुआन यी", "दक्षिणात्य देबेली", "ऑक्सितान", "ओजिब्वा", "चर्च स्लाव्हिक", "ओरोमो", "उडिया", "ओस्सेटिक", "पंजाबी", "पाली", "फारसी", "पोलिश", "पश्तो", "पोर्तुगीज", "क्वेचुआ", "रोमान्श", "रुन्दी", "रोमानियन", "रशियन", "संस्कृत", "सर्दिनियन", "सिंधी", "उत्तरी सामी", "सामोअन", "सांगो", "सर्बियन", "स्कॉटिश गेलिक", "शोना", "सिंहला", "स्लोव्हाक", "स्लोव्हेनियन", "सोमाली", "दक्षिणी सोथो", "स्पॅनिश", "सुंदानीज", "स्वाहिली", "स्वाती", "स्वीडिश", "तामिळ", "तेलगू", "ताजिक", "थाई", "तिग्रिन्या", "तिबेटी", "तुर्कमेन", "टागालोग", "त्स्वाना", "टोंगन", "तुर्की", "सोंगा", "तातर", "ट्वी", "ताहितीयन", "उइगुर", "युक्रेनियन", "उर्दू", "उझ्बेक", "व्हेंदा", "व्हिएतनामी", "ओलापुक", "वालून", "वेल्श", "वोलोफ", "पश्चिमी फ्रिशियन", "खोसा", "यिद्दिश", "योरुबा", "झुआंग", "झुलू"];
+
+// mn
+static L67: [&str; 184] = ["афар", "абхаз", "африкаанс", "акан", "албани", "амхар", "араб", "арагон", "армен", "ассам", "авар", "", "аймара", "азербайжан", "бамбара", "башкир", "баск", "беларусь", "бенгал", "", "бислам", "босни", "бретон", "болгар", "бирм", "каталан", "чаморро", "чечень", "нянжа", "хятад", "чуваш", "корн", "корсик", "", "хорват", "чех", "дани", "дивехи", "нидерланд", "зонха", "англи", "эсперанто", "эстони", "эвэ", "фарер", "фижи", "фин", "франц", "фула", "галего", "гүрж", "герман", "грек", "гуарани", "гужарати", "Гаитийн креол", "хауса", "еврей", "хереро", "хинди", "", "мажар", "интерлингво", "индонези", "нэгдмэл хэл", "ирланд", "игбо", "", "идо", "исланд", "итали", "инуктитут", "япон", "ява", "калалисут", "каннада", "канури", "кашмир", "казах", "кхмер", "кикуюү", "киньяруанда", "киргиз", "коми", "", "солонгос", "курд", "куаньяма", "латин", "люксембург", "ганда", "лимбург", "лингала", "лаос", "литва", "луба-катанга", "латви", "манкс", "македон", "малагаси", "малай", "малаялам", "малта", "маори", "марати", "маршалл", "монгол", "науру", "навахо", "хойд ндебеле", "балба", "ндонга", "норвегийн букмол", "норвегийн нинорск", "норвег", "сычуань и", "өмнөд ндебеле", "окситан", "", "сүмийн славян", "оромо", "ория", "оссетин", "панжаби", "", "перс", "польш", "пушту", "португал", "кечуа", "романш", "рунди", "румын", "орос", "санскрит", "сардин", "синдхи", "хойд сами", "самоа", "санго", "серб", "шотландын гел", "шона", "синхала", "словак", "словени", "сомали", "сесото", "испани", "сундан", "свахили", "свати", "швед", "тамил", "тэлүгү", "тажик", "тай", "тигринья", "төвд", "туркмен", "филипино", "цвана", "тонга", "турк", "цонга", "татар", "тви", "таити", "уйгур", "украин", "урду", "узбек", "венда", "вьетнам", "волапюк", "уоллун", "уэльс", "волоф", "баруун фриз", "хоса", "иддиш", "ёруба", "чжуанг", "зулу"];
+
+// ne
+static L68: [&str; 184] = ["अफार", "अब्खाजियाली", "अफ्रिकान्स", "आकान", "अल्बानियाली", "अम्हारिक", "अरबी", "अरागोनी", "आर्मेनियाली", "आसामी", "अवारिक", "अवेस्तान", "ऐमारा", "अजरबैजानी", "बाम्बारा", "बास्किर", "बास्क", "बेलारुसी", "बंगाली", "", "बिस्लाम", "बोस्नियाली", "ब्रेटन", "बुल्गेरियाली", "बर्मेली", "क्याटालन", "चामोर्रो", "चेचेन", "न्यान्जा", "चिनियाँ", "चुभास", "कोर्निस", "कोर्सिकन", "क्री", "क्रोयसियाली", "चेक", "डेनिस", "दिबेही", "डच", "जोङ्खा", "अङ्ग्रेजी", "एस्पेरान्तो", "इस्टोनियन", "इवी", "फारोज", "फिजियन", "फिनिस", "फ्रान्सेली", "फुलाह", "गलिसियाली", "जर्जियाली", "जर्मन", "ग्रीक", "गुवारानी", "गुजराती", "हैटियाली क्रियोल", "हाउसा", "हिब्रु", "हेरेरो", "हिन्दी", "हिरी मोटु", "हङ्गेरियाली", "इन्टर्लिङ्गुआ", "इन्डोनेसियाली", "इन्टरलिङ्ग्वे", "आइरिस", "इग्बो", "इनुपिआक्", "इडो", "आइसल्यान्डियाली", "इटालेली", "इनुक्टिटुट", "जापानी", "जाभानी", "कालालिसुट", "कन्नाडा", "कानुरी", "कास्मिरी", "काजाख", "खमेर", "किकुयु", "किन्यारवान्डा", "किर्गिज", "कोमी", "कोङ्गो", "कोरियाली", "कुर्दी", "कुआन्यामा", "ल्याटिन", "लक्जेम्बर्गी", "गान्डा", "लिम्बुर्गी", "लिङ्गाला", "लाओ", "लिथुआनियाली", "लुबा-काताङ्गा", "लात्भियाली", "मान्क्स", "म्यासेडोनियन", "मलागासी", "मलाय", "मलयालम", "माल्टिज", "माओरी", "मराठी", "मार्साली", "मङ्गोलियाली", "नाउरू", "नाभाजो", "उत्तरी न्डेबेले", "नेपाली", "न्दोन्गा", "नर्वेली बोकमाल", "नर्वेली नाइनोर्स्क", "नर्वेली", "सिचुआन यि", "दक्षिण न्देबेले", "अक्सिटन", "ओजिब्वा", "चर्च स्लाभिक", "ओरोमो", "उडिया", "ओस्सेटिक", "पंजाबी", "पाली", "फारसी", "पोलिस", "पास्तो", "पोर्तुगी", "क्वेचुवा", "रोमानिस", "रुन्डी", "रोमानियाली", "रसियाली", "संस्कृत", "सार्डिनियाली", "सिन्धी", "उत्तरी सामी", "सामोआ", "साङ्गो", "सर्बियाली", "स्कटिस गाएलिक", "शोना", "सिन्हाली", "स्लोभाकियाली", "स्लोभेनियाली", "सोमाली", "दक्षिणी सोथो", "स्पेनी", "सुडानी", "स्वाहिली", "स्वाती", "स्विडिस", "तामिल", "तेलुगु", "ताजिक", "थाई", "टिग्रिन्या", "तिब्बती", "टर्कमेन", "फिलिपिनी", "ट्स्वाना", "टोङ्गन", "टर्किश", "ट्सोङ्गा", "तातार", "", "टाहिटियन", "उइघुर", "युक्रेनी", "उर्दु", "उज्बेकी", "भेन्डा", "भियतनामी", "भोलापिक", "वाल्लुन", "वेल्श", "वुलुफ", "पश्चिमी फ्रिसियन", "खोसा", "यिद्दिस", "योरूवा", "झुुआङ्ग", "जुलु"];
+
+// nb, no
+static L69: [&str; 184] = ["afar", "abkhasisk", "afrikaans", "akan", "albansk", "amharisk", "arabisk", "aragonsk", "armensk", "assamesisk", "avarisk", "avestisk", "aymara", "aserbajdsjansk", "bambara", "basjkirsk", "baskisk", "belarusisk", "bengali", "", "bislama", "bosnisk", "bretonsk", "bulgarsk", "burmesisk", "katalansk", "chamorro", "tsjetsjensk", "nyanja", "kinesisk", "tsjuvasjisk", "kornisk", "korsikansk", "cree", "kroatisk", "tsjekkisk", "dansk", "divehi", "nederlandsk", "dzongkha", "engelsk", "esperanto", "estisk", "ewe", "færøysk", "fijiansk", "finsk", "fransk", "fulfulde", "galisisk", "georgisk", "tysk", "gresk", "guarani", "gujarati", "haitisk", "hausa", "hebraisk", "herero", "hindi", "hiri motu", "ungarsk", "interlingua", "indonesisk", "interlingue", "irsk", "ibo", "inupiak", "ido", "islandsk", "italiensk", "inuktitut", "japansk", "javanesisk", "grønlandsk", "kannada", "kanuri", "kasjmiri", "kasakhisk", "khmer", "kikuyu", "kinyarwanda", "kirgisisk", "komi", "kikongo", "koreansk", "kurdisk", "kuanyama", "latin", "luxemburgsk", "ganda", "limburgsk", "lingala", "laotisk", "litauisk", "luba-katanga", "latvisk", "mansk", "makedonsk", "gassisk", "malayisk", "malayalam", "maltesisk", "maori", "marathi", "marshallesisk", "mongolsk", "nauru", "navajo", "nord-ndebele", "nepali", "ndonga", "norsk bokmål", "norsk nynorsk", "norsk", "sichuan-yi", "sør-ndebele", "oksitansk", "ojibwa", "kirkeslavisk", "oromo", "odia", "ossetisk", "panjabi", "pali", "persisk", "polsk", "pashto", "portugisisk", "quechua", "retoromansk", "rundi", "rumensk", "russisk", "sanskrit", "sardisk", "sindhi", "nordsamisk", "samoansk", "sango", "serbisk", "skotsk-gælisk", "shona", "singalesisk", "slovakisk", "slovensk", "somali", "sør-sotho", "spansk", "sundanesisk", "swahili", "swati", "svensk", "tamil", "telugu", "tadsjikisk", "thai", "tigrinja", "tibetansk", "turkmensk", "tagalog", "setswana", "tongansk", "tyrkisk", "tsonga", "tatarisk", "twi", "tahitisk", "uigurisk", "ukrainsk", "urdu", "usbekisk", "venda", "vietnamesisk", "volapyk", "vallonsk", "walisisk", "wolof", "vestfrisisk", "xhosa", "jiddisk", "joruba", "zhuang", "zulu"];
+
+// nn
+static L70: [&str; 184] = ["afar", "abkhasisk", "afrikaans", "akan", "albansk", "amharisk", "arabisk", "aragonsk", "armensk", "assamesisk", "avarisk", "avestisk", "aymara", "aserbajdsjansk", "bambara", "basjkirsk", "baskisk", "belarusisk", "bengali", "", "bislama", "bosnisk", "bretonsk", "bulgarsk", "burmesisk", "katalansk", "chamorro", "tsjetsjensk", "nyanja", "kinesisk", "tsjuvansk", "kornisk", "korsikansk", "cree", "kroatisk", "tsjekkisk", "dansk", "divehi", "nederlandsk", "dzongkha", "engelsk", "esperanto", "estisk", "ewe", "færøysk", "fijiansk", "finsk", "fransk", "fulfulde", "galisisk", "georgisk", "tysk", "gresk", "guarani", "gujarati", "haitisk", "hausa", "hebraisk", "herero", "hindi", "hiri motu", "ungarsk", "interlingua", "indonesisk", "interlingue", "irsk", "ibo", "inupiak", "ido", "islandsk", "italiensk", "inuktitut", "japansk", "javanesisk", "grønlandsk (kalaallisut)", "kannada", "kanuri", "kasjmiri", "kasakhisk", "khmer", "kikuyu", "kinyarwanda", "kirgisisk", "komi", "kikongo", "koreansk", "kurdisk", "kuanyama", "latin", "luxemburgsk", "ganda", "limburgsk", "lingala", "laotisk", "litauisk", "luba-katanga", "latvisk", "manx", "makedonsk", "madagassisk", "malayisk", "malayalam", "maltesisk", "maori", "marathi", "marshallesisk", "mongolsk", "nauru", "navajo", "nord-ndebele", "nepalsk", "ndonga", "norsk bokmål", "norsk nynorsk", "norsk", "sichuan-yi", "sør-ndebele", "oksitansk", "ojibwa", "kyrkjeslavisk", "oromo", "odia", "ossetisk", "panjabi", "pali", "persisk", "polsk", "pashto", "portugisisk", "quechua", "retoromansk", "rundi", "rumensk", "russisk", "sanskrit", "sardinsk", "sindhi", "nordsamisk", "samoansk", "sango", "serbisk", "skotsk-gælisk", "shona", "singalesisk", "slovakisk", "slovensk", "somali", "sørsotho", "spansk", "sundanesisk", "swahili", "swati", "svensk", "tamil", "telugu", "tadsjikisk", "thai", "tigrinja", "tibetansk", "turkmensk", "tagalog", "setswana", "tongansk", "tyrkisk", "tsonga", "tatarisk", "twi", "tahitisk", "uigurisk", "ukrainsk", "urdu", "usbekisk", "venda", "vietnamesisk", "volapyk", "vallonsk", "walisisk", "wolof", "vestfrisisk", "xhosa", "jiddisk", "joruba", "zhuang", "zulu"];
+
+// ii
+static L71: [&str; 184] = ["", "", "", "", "", "", "ꀊꇁꀨꉙ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꍏꇩꉙ", "", "", "", "", "", "", "", "", "ꉿꇂꉙ", "", "ꑱꇩꉙ", "", "", "", "", "", "", "ꃔꇩꉙ", "", "", "", "ꄓꇩꉙ", "", "", "", "", "", "", "", "ꑴꄃꉙ", "", "", "", "", "", "", "", "", "", "", "ꑴꄊꆺꉙ", "", "ꏝꀪꉙ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꆈꌠꉙ", "", "", "", "", "", "", "", "", "", "", "", "", "ꁍꄨꑸꉙ", "", "", "", "ꇆꂷꆀꑸꉙ", "ꊉꇩꉙ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꑭꀠꑸꉙ", "", "ꌖꑟꆺꉙ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// oc
+static L72: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "anglés", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "occitan", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// om
+static L73: [&str; 184] = ["", "", "Afrikoota", "", "Afaan Albaniyaa", "Afaan Amaaraa", "Arabiffaa", "", "Armeeniyaa", "Assamese", "", "", "", "Afaan Azerbaijani", "", "", "Afaan Baskuu", "Afaan Belarusia", "Afaan Baangladeshi", "", "", "Afaan Bosniyaa", "Bireetoon", "Afaan Bulgariya", "Burmeesee", "Afaan Katalaa", "", "", "", "Chinese", "Chuvash", "", "", "", "Afaan Croatian", "Afaan Czech", "Afaan Deenmaark", "", "Afaan Dachii", "", "Afaan Ingilizii", "Afaan Esperantoo", "Afaan Istooniya", "", "Afaan Faroese", "", "Afaan Fiilaandi", "Afaan Faransaayii", "Fula", "Afaan Galishii", "Afaan Georgian", "Afaan Jarmanii", "Afaan Giriiki", "Afaan Guarani", "Afaan Gujarati", "", "Hawusaa", "Afaan Hebrew", "", "Afaan Hindii", "", "Afaan Hangaari", "Interlingua", "Afaan Indoneziya", "", "Afaan Ayirishii", "", "", "", "Ayiislandiffaa", "Afaan Xaaliyaani", "", "Afaan Japanii", "Afaan Java", "", "Afaan Kannada", "", "", "", "", "", "", "", "", "", "Afaan Korea", "", "", "Afaan Laatini", "", "", "", "", "", "Afaan Liituniyaa", "", "Afaan Lativiyaa", "", "Afaan Macedooniyaa", "", "Malaayiffaa", "Malayaalamiffaa", "Afaan Maltesii", "", "Afaan Maratii", "", "", "", "", "", "Afaan Nepalii", "", "", "Afaan Norwegian", "Afaan Norweyii", "", "", "Afaan Occit", "", "", "Oromoo", "", "", "Afaan Punjabii", "", "Afaan Persia", "Afaan Polandii", "", "Afaan Porchugaal", "", "", "", "Afaan Romaniyaa", "Afaan Rushiyaa", "", "", "", "", "", "", "Afaan Serbiya", "Scots Gaelic", "", "Afaan Sinhalese", "Afaan Slovak", "Afaan Islovaniyaa", "", "", "Afaan Ispeen", "Afaan Sudaanii", "Suwahilii", "", "Afaan Suwidiin", "Afaan Tamilii", "Afaan Telugu", "", "Afaan Tayii", "Afaan Tigiree", "", "Lammii Turkii", "Afaan Filippinii", "", "", "Afaan Turkii", "", "", "", "", "", "Afaan Ukreenii", "Afaan Urdu", "Afaan Uzbek", "", "Afaan Veetinam", "", "", "Welishiffaa", "", "Afaan Firisiyaani", "Afaan Xhosa", "", "", "", "Afaan Zuulu"];
+
+// or
+static L74: [&str; 184] = ["ଅଫାର୍", "ଆବ୍ଖାଜିଆନ୍", "ଆଫ୍ରିକୀୟ", "ଅକନ୍", "ଆଲବାନିଆନ୍", "ଆମହାରକି", "ଆରବିକ୍", "ଆର୍ଗୋନୀ", "ଆର୍ମେନିଆନ୍", "ଆସାମୀୟ", "ଆଭାରିକ୍", "ଅବେସ୍ତନ", "ଆୟମାରା", "ଆଜେରବାଇଜାନି", "ବାମ୍ବାରା", "ବାଶକିର୍", "ବାସ୍କ୍ୱି", "ବେଲାରୁଷିଆନ୍", "ବଙ୍ଗଳା", "", "ବିସଲାମା", "ବୋସନିଆନ୍", "ବ୍ରେଟନ୍", "ବୁଲଗେରିଆନ୍", "ବର୍ମୀଜ୍", "କାଟାଲାନ୍", "ଚାମୋରୋ", "ଚେଚନ୍", "ନିୟାଞ୍ଜ", "ଚାଇନିଜ୍\u{200C}", "ଚୁଭାଶ୍", "କୋର୍ନିସ୍", "କୋର୍ସିକାନ୍", "କ୍ରୀ", "କ୍ରୋଏସୀୟ", "ଚେକ୍", "ଡାନିସ୍\u{200C}", "ଡିଭେହୀ", "ଡଚ୍", "ଦଡଜୋଙ୍ଗଖା", "ଇଂରାଜୀ", "ଏସ୍ପାରେଣ୍ଟୋ", "ଏସ୍ତୋନିଆନ୍", "ଇୱେ", "ଫାରୋଇଜ୍\u{200C}", "ଫିଜି", "ଫିନ୍ନିସ୍", "ଫରାସୀ", "ଫୁଲାହ", "ଗାଲିସିଆନ୍\u{200C}", "ଜର୍ଜିଆନ୍\u{200C}", "ଜର୍ମାନ", "ଗ୍ରୀକ୍", "ଗୁଆରାନୀ", "ଗୁଜରାଟୀ", "ହୈତାୟିନ୍", "ହୌସା", "ହିବ୍ରୁ", "ହେରେରୋ", "ହିନ୍ଦୀ", "ହିରି ମୋଟୁ", "ହଙ୍ଗେରୀୟ", "ଇର୍ଣ୍ଟଲିଙ୍ଗୁଆ", "ଇଣ୍ଡୋନେସୀୟ", "ଇର୍ଣ୍ଟରଲିଙ୍ଗୁଇ", "ଆଇରିସ୍\u{200C}", "ଇଗବୋ", "ଇନୁପିୟାକ୍", "ଇଡୋ", "ଆଇସଲାଣ୍ଡିକ୍", "ଇଟାଲୀୟ", "ଇନୁକଟିଟୁତ୍\u{200C}", "ଜାପାନୀ", "ଜାଭାନିଜ୍\u{200C}", "କାଲାଲିସୁଟ୍", "କନ୍ନଡ", "କନୁରୀ", "କାଶ୍ମିରୀ", "କାଜାଖ୍\u{200C}", "ଖାମେର୍", "କୀକୁୟୁ", "କିନ୍ୟାରୱାଣ୍ଡା", "କୀରଗୀଜ୍", "କୋମି", "କଙ୍ଗୋ", "କୋରିଆନ୍", "କୁର୍ଦ୍ଦିଶ୍", "କ୍ୱାନ୍ୟାମ୍", "ଲାଟିନ୍", "ଲକ୍ସେମବର୍ଗିସ୍", "ଗନ୍ଦା", "ଲିମ୍ବୁର୍ଗିସ୍", "ଲିଙ୍ଗାଲା", "ଲାଓ", "ଲିଥୁଆନିଆନ୍", "ଲ୍ୟୁବା-କାଟାଙ୍ଗା", "ଲାଟଭିଆନ୍", "ମାଁକ୍ସ", "ମାସେଡୋନିଆନ୍", "ମାଲାଗାସୀ", "ମାଲୟ", "ମାଲାୟଲମ୍", "ମାଲଟୀଜ୍", "ମାଓରୀ", "ମରାଠୀ", "ମାର୍ଶାଲୀଜ୍", "ମଙ୍ଗୋଲୀୟ", "ନାଉରୁ", "ନାଭାଜୋ", "ଉତ୍ତର ନେଡବେଲେ", "ନେପାଳୀ", "ଡୋଙ୍ଗା", "ନରୱେଜିଆନ୍ ବୋକମଲ୍", "ନରୱେଜିଆନ୍ ନିନର୍ସ୍କ୍\u{200C}", "ନରୱେଜିଆନ୍", "ସିଚୁଆନ୍ ୟୀ", "ଦକ୍ଷିଣ ନେଡବେଲେ", "ଓସିଟାନ୍", "ଓଜିୱା", "ଚର୍ଚ୍ଚ ସ୍ଲାଭିକ୍", "ଓରୋମୋ", "ଓଡ଼ିଆ", "ଓସେଟିକ୍", "ପଞ୍ଜାବୀ", "ପାଲି", "ପର୍ସିଆନ୍", "ପୋଲିଶ୍", "ପାସ୍ତୋ", "ପର୍ତ୍ତୁଗୀଜ୍\u{200C}", "କ୍ୱେଚୁଆ", "ରୋମାନଶ୍\u{200C}", "ରୁଣ୍ଡି", "ରୋମାନିଆନ୍", "ରୁଷିୟ", "ସଂସ୍କୃତ", "ସାର୍ଡିନିଆନ୍\u{200C}", "ସିନ୍ଧୀ", "ଉତ୍ତର ସାମି", "ସାମୋଆନ୍", "ସାଙ୍ଗୋ", "ସର୍ବିୟ", "ସ୍କଟିସ୍ ଗାଏଲିକ୍", "ଶୋନା", "ସିଂହଳ", "ସ୍ଲୋଭାକ୍", "ସ୍ଲୋଭେନିଆନ୍", "ସୋମାଲିଆ", "ସେସୋଥୋ", "ସ୍ପାନିସ୍\u{200C}", "ସୁଦାନୀଜ୍", "ସ୍ୱାହିଲି", "ସ୍ଵାତି", "ସ୍ୱେଡିସ୍", "ତାମିଲ୍", "ତେଲୁଗୁ", "ତାଜିକ୍", "ଥାଇ", "ଟାଇଗ୍ରିନିଆ", "ତିବ୍ବତୀୟ", "ତୁର୍କମେନ୍", "ଟାଗାଲଗ୍", "ସୱାନା", "ଟୋଙ୍ଗା", "ତୁର୍କିସ୍", "ସୋଙ୍ଗା", "ତାତାର୍", "ତ୍ୱି", "ତାହିତିଆନ୍", "ୟୁଘୁର୍", "ୟୁକ୍ରେନିଆନ୍", "ଉର୍ଦ୍ଦୁ", "ଉଜବେକ୍", "ଭେଣ୍ଡା", "ଭିଏତନାମିଜ୍", "ବୋଲାପୁକ", "ୱାଲୁନ୍", "ୱେଲ୍ସ", "ୱୋଲଫ୍", "ପାଶ୍ଚାତ୍ୟ ଫ୍ରିସିଆନ୍", "ଖୋସା", "ୟିଡିସ୍", "ୟୋରୁବା", "ଜୁଆଙ୍ଗ୍\u{200C}", "ଜୁଲୁ"];
+
+// pa
+static L75: [&str; 184] = ["ਅਫ਼ਾਰ", "ਅਬਖਾਜ਼ੀਅਨ", "ਅਫ਼ਰੀਕੀ", "ਅਕਾਨ", "ਅਲਬਾਨੀਆਈ", "ਅਮਹਾਰਿਕ", "ਅਰਬੀ", "ਅਰਾਗੋਨੀ", "ਅਰਮੀਨੀਆਈ", "ਅਸਾਮੀ", "ਅਵਾਰਿਕ", "", "ਅਈਮਾਰਾ", "ਅਜ਼ਰਬਾਈਜਾਨੀ", "ਬੰਬਾਰਾ", "ਬਸ਼ਕੀਰ", "ਬਾਸਕ", "ਬੇਲਾਰੂਸੀ", "ਬੰਗਾਲੀ", "", "ਬਿਸਲਾਮਾ", "ਬੋਸਨੀਆਈ", "ਬਰੇਟਨ", "ਬੁਲਗਾਰੀਆਈ", "ਬਰਮੀ", "ਕੈਟਾਲਾਨ", "ਚਮੋਰੋ", "ਚੇਚਨ", "ਨਯਾਂਜਾ", "ਚੀਨੀ", "ਚੁਵਾਸ਼", "ਕੋਰਨਿਸ਼", "ਕੋਰਸੀਕਨ", "", "ਕ੍ਰੋਏਸ਼ਿਆਈ", "ਚੈੱਕ", "ਡੈਨਿਸ਼", "ਦਿਵੇਹੀ", "ਡੱਚ", "ਜ਼ੋਂਗਖਾ", "ਅੰਗਰੇਜ਼ੀ", "ਇਸਪੇਰਾਂਟੋ", "ਇਸਟੋਨੀਆਈ", "ਈਵਈ", "ਫ਼ੇਰੋਸੇ", "ਫ਼ਿਜ਼ੀ", "ਫਿਨਿਸ਼", "ਫਰਾਂਸੀਸੀ", "ਫੁਲਾਹ", "ਗੈਲਿਸ਼ਿਅਨ", "ਜਾਰਜੀਆਈ", "ਜਰਮਨ", "ਯੂਨਾਨੀ", "ਗੁਆਰਾਨੀ", "ਗੁਜਰਾਤੀ", "ਹੈਤੀਆਈ", "ਹੌਸਾ", "ਹਿਬਰੂ", "ਹਰੇਰੋ", "ਹਿੰਦੀ", "", "ਹੰਗਰੀਆਈ", "ਇੰਟਰਲਿੰਗੁਆ", "ਇੰਡੋਨੇਸ਼ੀਆਈ", "ਇੰਟਰਲਿੰਗੁਈ", "ਆਇਰਸ਼", "ਇਗਬੋ", "", "ਇਡੂ", "ਆਈਸਲੈਂਡਿਕ", "ਇਤਾਲਵੀ", "ਇੰਕਟੀਟੂਤ", "ਜਪਾਨੀ", "ਜਾਵਾਨੀਜ਼", "ਕਲਾਅੱਲੀਸੁਟ", "ਕੰਨੜ", "ਕਨੂਰੀ", "ਕਸ਼ਮੀਰੀ", "ਕਜ਼ਾਖ਼", "ਖਮੇਰ", "ਕਿਕੂਯੂ", "ਕਿਨਿਆਰਵਾਂਡਾ", "ਕਿਰਗੀਜ਼", "ਕੋਮੀ", "", "ਕੋਰੀਆਈ", "ਕੁਰਦਿਸ਼", "ਕੁਆਨਯਾਮਾ", "ਲਾਤੀਨੀ", "ਲਕਜ਼ਮਬਰਗਿਸ਼", "ਗਾਂਡਾ", "ਲਿਮਬੁਰਗੀ", "ਲਿੰਗਾਲਾ", "ਲਾਓ", "ਲਿਥੁਆਨੀਅਨ", "ਲੂਬਾ-ਕਾਟਾਂਗਾ", "ਲਾਤੀਵੀ", "ਮੈਂਕਸ", "ਮੈਕਡੋਨੀਆਈ", "ਮਾਲਾਗੈਸੀ", "ਮਲਯ", "ਮਲਿਆਲਮ", "ਮਾਲਟੀਜ਼", "ਮਾਉਰੀ", "ਮਰਾਠੀ", "ਮਾਰਸ਼ਲੀਜ਼", "ਮੰਗੋਲੀ", "ਨਾਉਰੂ", "ਨਵਾਜੋ", "ਉੱਤਰੀ ਨਡੇਬੇਲੇ", "ਨੇਪਾਲੀ", "ਐਂਡੋਂਗਾ", "ਨਾਰਵੇਜਿਆਈ ਬੋਕਮਲ", "ਨਾਰਵੇਜਿਆਈ ਨਿਓਨੌਰਸਕ", "ਨਾਰਵੇਜਿਆਈ", "ਸਿਚੁਆਨ ਯੀ", "ਸਾਊਥ ਨਡੇਬੇਲੇ", "ਓਕਸੀਟਾਨ", "", "ਚਰਚ ਸਲਾਵੀ", "ਓਰੋਮੋ", "ਉੜੀਆ", "ਓਸੈਟਿਕ", "ਪੰਜਾਬੀ", "ਪਾਲੀ", "ਫ਼ਾਰਸੀ", "ਪੋਲੈਂਡੀ", "ਪਸ਼ਤੋ", "ਪੁਰਤਗਾਲੀ", "ਕਕੇਸ਼ੁਆ", "ਰੋਮਾਂਸ਼", "ਰੁੰਡੀ", "ਰੋਮਾਨੀਆਈ", "ਰੂਸੀ", "ਸੰਸਕ੍ਰਿਤ", "ਸਾਰਡੀਨੀਆਈ", "ਸਿੰਧੀ", "ਉੱਤਰੀ ਸਾਮੀ", "ਸਾਮੋਨ", "ਸਾਂਗੋ", "ਸਰਬੀਆਈ", "ਸਕਾਟਿਸ਼ ਗੇਲਿਕ", "ਸ਼ੋਨਾ", "ਸਿੰਹਾਲਾ", "ਸਲੋਵਾਕ", "ਸਲੋਵੇਨੀਆਈ", "ਸੋਮਾਲੀ", "ਦੱਖਣੀ ਸੋਥੋ", "ਸਪੇਨੀ", "ਸੂੰਡਾਨੀ", "ਸਵਾਹਿਲੀ", "ਸਵਾਤੀ", "ਸਵੀਡਿਸ਼", "ਤਮਿਲ", "ਤੇਲਗੂ", "ਤਾਜਿਕ", "ਥਾਈ", "ਤਿਗ੍ਰੀਨਿਆ", "ਤਿੱਬਤੀ", "ਤੁਰਕਮੇਨ", "ਫਿਲੀਪਿਨੋ", "ਤਸਵਾਨਾ", "ਟੌਂਗਨ", "ਤੁਰਕੀ", "ਸੋਂਗਾ", "ਤਤਾਰ", "ਤ੍ਵਿ", "ਤਾਹੀਟੀ", "ਉਇਗੁਰ", "ਯੂਕਰੇਨੀਆਈ", "ਉਰਦੂ", "ਉਜ਼ਬੇਕ", "ਵੇਂਡਾ", "ਵੀਅਤਨਾਮੀ", "ਵੋਲਾਪੂਕ", "ਵਲੂਨ", "ਵੈਲਸ਼", "ਵੋਲੋਫ", "ਪੱਛਮੀ ਫ੍ਰਿਸੀਅਨ", "ਖੋਸਾ", "ਯਿਦਿਸ਼", "ਯੋਰੂਬਾ", "ਜ਼ੁਆਂਗ", "ਜ਼ੁਲੂ"];
+
+// fa
+static L76: [&str; 184] = ["آفاری", "آبخازی", "آفریکانس", "آکان", "آلبانیایی", "امهری", "عربی", "آراگونی", "ارمنی", "آسامی", "آواری", "اوستایی", "آیمارایی", "ترکی آذربایجانی", "بامبارایی", "باشقیری", "باسکی", "بلاروسی", "بنگالی", "", "بیسلاما", "بوسنیایی", "برتون", "بلغاری", "برمه\u{200C}ای", "کاتالان", "چامورویی", "چچنی", "نیانجایی", "چینی", "چوواشی", "کورنی", "کورسی", "کریایی", "کروات", "چکی", "دانمارکی", "دیوهی", "هلندی", "دزونگخا", "انگلیسی", "اسپرانتو", "استونیایی", "اوه\u{200C}ای", "فارویی", "فیجیایی", "فنلاندی", "فرانسوی", "فولانی", "گالیسیایی", "گرجی", "آلمانی", "یونانی", "گوارانی", "گجراتی", "هائیتیایی", "هوسایی", "عبری", "هریرویی", "هندی", "موتویی هیری", "مجاری", "اینترلینگوا", "اندونزیایی", "اکسیدنتال", "ایرلندی", "ایگبویی", "اینوپیک", "ایدو", "ایسلندی", "ایتالیایی", "اینوکتیتوت", "ژاپنی", "جاوه\u{200C}ای", "گرینلندی", "کانارا", "کانوریایی", "کشمیری", "قزاقی", "خمری", "کیکویویی", "کینیارواندایی", "قرقیزی", "کومیایی", "کنگویی", "کره\u{200C}ای", "کردی", "کوانیاما", "لاتین", "لوگزامبورگی", "گاندایی", "لیمبورگی", "لینگالا", "لائوسی", "لیتوانیایی", "لوبایی‐کاتانگا", "لتونیایی", "مانی", "مقدونی", "مالاگاسی", "مالایی", "مالایالامی", "مالتی", "مائوری", "مراتی", "مارشالی", "مغولی", "نائورویی", "ناواهویی", "انده\u{200C}بله\u{200C}ای شمالی", "نپالی", "اندونگایی", "نروژی بوک\u{200C}مُل", "نروژی نی\u{200C}نُشک", "نروژی", "یی سیچوان", "انده\u{200C}بله\u{200C}ای جنوبی", "اکسیتان", "اوجیبوایی", "اسلاوی کلیسایی", "اورومویی", "اوریه\u{200C}ای", "آسی", "پنجابی", "پالی", "فارسی", "لهستانی", "پشتو", "پرتغالی", "کچوایی", "رومانش", "روندیایی", "رومانیایی", "روسی", "سانسکریت", "ساردینیایی", "سندی", "سامی شمالی", "ساموآیی", "سانگو", "صربی", "گیلی اسکاتلندی", "شونایی", "سینهالی", "اسلواکی", "اسلوونیایی", "سومالیایی", "سوتوی جنوبی", "اسپانیایی", "سوندایی", "سواحیلی", "سوازیایی", "سوئدی", "تامیلی", "تلوگویی", "تاجیکی", "تایلندی", "تیگرینیایی", "تبتی", "ترکمنی", "تاگالوگی", "تسوانایی", "تونگایی", "ترکی استانبولی", "تسونگایی", "تاتاری", "توی\u{200C}یایی", "تاهیتیایی", "اویغوری", "اوکراینی", "اردو", "ازبکی", "وندایی", "ویتنامی", "ولاپوک", "والونی", "ولزی", "ولوفی", "فریسی غربی", "خوسایی", "یدی", "یوروبایی", "ژوانگی", "زولویی"];
+
+// pl
+static L77: [&str; 184] = ["afar", "abchaski", "afrikaans", "akan", "albański", "amharski", "arabski", "aragoński", "ormiański", "asamski", "awarski", "awestyjski", "ajmara", "azerbejdżański", "bambara", "baszkirski", "baskijski", "białoruski", "bengalski", "", "bislama", "bośniacki", "bretoński", "bułgarski", "birmański", "kataloński", "czamorro", "czeczeński", "njandża", "chiński", "czuwaski", "kornijski", "korsykański", "kri", "chorwacki", "czeski", "duński", "malediwski", "niderlandzki", "dzongkha", "angielski", "esperanto", "estoński", "ewe", "farerski", "fidżijski", "fiński", "francuski", "fulani", "galicyjski", "gruziński", "niemiecki", "grecki", "guarani", "gudżarati", "kreolski haitański", "hausa", "hebrajski", "herero", "hindi", "hiri motu", "węgierski", "interlingua", "indonezyjski", "interlingue", "irlandzki", "igbo", "inupiak", "ido", "islandzki", "włoski", "inuktitut", "japoński", "jawajski", "grenlandzki", "kannada", "kanuri", "kaszmirski", "kazachski", "khmerski", "kikuju", "kinya-ruanda", "kirgiski", "komi", "kongo", "koreański", "kurdyjski", "kwanyama", "łaciński", "luksemburski", "ganda", "limburski", "lingala", "laotański", "litewski", "luba-katanga", "łotewski", "manx", "macedoński", "malgaski", "malajski", "malajalam", "maltański", "maoryjski", "marathi", "marszalski", "mongolski", "nauruański", "nawaho", "ndebele północny", "nepalski", "ndonga", "norweski (bokmål)", "norweski (nynorsk)", "norweski", "syczuański", "ndebele południowy", "oksytański", "odżibwa", "cerkiewnosłowiański", "oromo", "orija", "osetyjski", "pendżabski", "palijski", "perski", "polski", "paszto", "portugalski", "keczua", "retoromański", "rundi", "rumuński", "rosyjski", "sanskryt", "sardyński", "sindhi", "północnolapoński", "samoański", "sango", "serbski", "szkocki gaelicki", "shona", "syngaleski", "słowacki", "słoweński", "somalijski", "sotho południowy", "hiszpański", "sundajski", "suahili", "suazi", "szwedzki", "tamilski", "telugu", "tadżycki", "tajski", "tigrinia", "tybetański", "turkmeński", "tagalski", "setswana", "tonga", "turecki", "tsonga", "tatarski", "twi", "tahitański", "ujgurski", "ukraiński", "urdu", "uzbecki", "venda", "wietnamski", "wolapik", "waloński", "walijski", "wolof", "zachodniofryzyjski", "khosa", "jidysz", "joruba", "czuang", "zulu"];
+
+// ps
+static L78: [&str; 184] = ["افري", "ابخازي", "افریکانسي", "اکاني", "الباني", "امهاري", "عربي", "اراگونېسي", "آرمينيايي", "اسامي", "اواري", "", "ایماري", "اذربایجاني", "بمبارا", "باشکير", "باسکي", "بېلاروسي", "بنگالي", "", "بسلاما", "بوسني", "برېتون", "بلغاري", "برمایی", "کټلاني", "چمورو", "چيچني", "نیانجا", "چیني", "چوواشي", "کورنيشي", "کورسيکاني", "", "کروايشيايي", "چېکي", "ډنمارکي", "ديویهی", "هالېنډي", "ژونگکه", "انګليسي", "اسپرانتو", "حبشي", "ايو", "فاروئې", "فجیان", "فینلنډي", "فرانسوي", "فولاح", "ګلېشيايي", "جورجيائي", "الماني", "یوناني", "ګوراني", "ګجراتي", "هيټي کريول", "هوسا", "عبراني", "هیرورو", "هندي", "", "هنګري", "انټرلنګوا", "انډونېزي", "آسا نا جبة", "ائيرلېنډي", "اګبو", "", "اڊو", "ايسلنډي", "ایټالوي", "انوکتیتوت", "جاپاني", "جاوايي", "کالالیست", "کناډا", "کنوری", "کشمیري", "قازق", "خمر", "ککوؤو", "کینیارونډا", "کرغيزي", "کومی", "", "کوریایی", "کردي", "کواناما", "لاتیني", "لوګزامبورګي", "ګانده", "لمبرگیانی", "لنګالا", "لاو", "ليتواني", "لوبا-کټنګا", "لېټواني", "مینکس", "مقدوني", "ملغاسي", "ملایا", "مالايالم", "مالټايي", "ماوري", "مراټهي", "مارشلیز", "منګولیایی", "نایرو", "نواجو", "شمالي نديبل", "نېپالي", "ندونگا", "ناروېئي (بوکمال)", "ناروېئي (نائنورسک)", "ناروېئي", "سیچیان یی", "سويلي نديبيل", "اوکسيټاني", "", "د کليسا سلاوي", "اورومو", "اوڊيا", "اوسيټک", "پنجابي", "", "فارسي", "پولنډي", "پښتو", "پورتګالي", "کېچوا", "رومانیش", "رونډی", "رومانیایی", "روسي", "سنسکریټ", "سارڊيني", "سندهي", "شمالي سامي", "ساموآن", "سانګو", "سربيائي", "سکاټلېنډي ګېلک", "شونا", "سينهالي", "سلوواکي", "سلوواني", "سومالي", "سويلي سوتو", "هسپانوي", "سوډاني", "سواهېلي", "سواتی", "سویډنی", "تامل", "تېليګو", "تاجکي", "تايلېنډي", "تيګريني", "تبتي", "ترکمني", "فلیپیني", "سووانا", "تونګان", "ترکي", "سونګا", "تاتار", "", "تاهیتي", "اويغوري", "اوکرايني", "اردو", "اوزبکي", "ویندا", "وېتنامي", "والاپوک", "والون", "ويلشي", "ولوف", "لوېديځ فريشي", "خوسا", "يديش", "یوروبا", "ژوانګ", "زولو"];
+
+// pt
+static L79: [&str; 184] = ["afar", "abcázio", "africâner", "akan", "albanês", "amárico", "árabe", "aragonês", "armênio", "assamês", "avárico", "avéstico", "aimará", "azerbaijano", "bambara", "bashkir", "basco", "bielorrusso", "bengali", "", "bislamá", "bósnio", "bretão", "búlgaro", "birmanês", "catalão", "chamorro", "checheno", "nianja", "chinês", "tchuvache", "córnico", "corso", "cree", "croata", "tcheco", "dinamarquês", "divehi", "holandês", "dzonga", "inglês", "esperanto", "estoniano", "ewe", "feroês", "fijiano", "finlandês", "francês", "fula", "galego", "georgiano", "alemão", "grego", "guarani", "guzerate", "haitiano", "hauçá", "hebraico", "herero", "hindi", "hiri motu", "húngaro", "interlíngua", "indonésio", "interlingue", "irlandês", "igbo", "inupiaque", "ido", "islandês", "italiano", "inuktitut", "japonês", "javanês", "groenlandês", "canarim", "canúri", "caxemira", "cazaque", "khmer", "quicuio", "quiniaruanda", "quirguiz", "komi", "congolês", "coreano", "curdo", "cuanhama", "latim", "luxemburguês", "luganda", "limburguês", "lingala", "laosiano", "lituano", "luba-catanga", "letão", "manx", "macedônio", "malgaxe", "malaio", "malaiala", "maltês", "maori", "marati", "marshalês", "mongol", "nauruano", "navajo", "ndebele do norte", "nepalês", "dongo", "bokmål norueguês", "nynorsk norueguês", "norueguês", "sichuan yi", "ndebele do sul", "occitânico", "ojibwa", "eslavo eclesiástico", "oromo", "oriá", "osseto", "panjabi", "páli", "persa", "polonês", "pashto", "português", "quíchua", "romanche", "rundi", "romeno", "russo", "sânscrito", "sardo", "sindi", "sami setentrional", "samoano", "sango", "sérvio", "gaélico escocês", "xona", "cingalês", "eslovaco", "esloveno", "somali", "soto do sul", "espanhol", "sundanês", "suaíli", "suázi", "sueco", "tâmil", "télugo", "tadjique", "tailandês", "tigrínia", "tibetano", "turcomeno", "tagalo", "tswana", "tonganês", "turco", "tsonga", "tártaro", "twi", "taitiano", "uigur", "ucraniano", "urdu", "uzbeque", "venda", "vietnamita", "volapuque", "valão", "galês", "uolofe", "frísio ocidental", "xhosa", "iídiche", "iorubá", "zhuang", "zulu"];
+
+// qu
+static L80: [&str; 184] = ["", "Abjasia", "Afrikaans Simi", "Akan Simi", "Albanes Simi", "Amarico Simi", "Arabe Simi", "Aragonesa", "Armenio Simi", "Asames Simi", "Avaric", "", "Aymara Simi", "Azerbaiyano Simi", "Bambara Simi", "Baskir Simi", "Euskera Simi", "Bielorruso Simi", "Bangla Simi", "", "Bislama", "Bosnio Simi", "Breton Simi", "Bulgaro Simi", "Birmano Simi", "Catalan Simi", "Chamorro Simi", "Checheno Simi", "Nyanja Simi", "Chino Simi", "Chuvash Simi", "Córnico Simi", "Corso Simi", "", "Croata Simi", "Checo Simi", "Danes Simi", "Divehi Simi", "Neerlandes Simi", "Butanés Simi", "Ingles Simi", "Esperanto Simi", "Estonio Simi", "Ewé Simi", "Feroes Simi", "Fiyiano Simi", "Fines Simi", "Frances Simi", "Fulah Simi", "Gallego Simi", "Georgiano Simi", "Aleman Simi", "Griego Simi", "Guaraní Simi", "Gujarati Simi", "Haitiano Criollo Simi", "Hausa Simi", "Hebreo Simi", "Herero Simi", "Hindi Simi", "", "Hungaro Simi", "Interlingua Simi", "Indonesio Simi", "Interlingue Simi", "Irlandes Simi", "Igbo Simi", "", "Ido Simi", "Islandes Simi", "Italiano Simi", "Inuktitut Simi", "Japones Simi", "Javanés Simi", "Groenlandes Simi", "Kannada Simi", "Kanuri Simi", "Cachemir Simi", "Kazajo Simi", "Khmer Simi", "Kikuyu Simi", "Kinyarwanda Simi", "Kirghiz Simi", "Komi Simi", "", "Coreano Simi", "Kurdo Simi", "Kuanyama Simi", "Latín Simi", "Luxemburgues Simi", "Luganda Simi", "Limburgues Simi", "Lingala Simi", "Lao Simi", "Lituano Simi", "Luba-Katanga Simi", "Leton Simi", "Manés Simi", "Macedonio Simi", "Malgache Simi", "Malayo Simi", "Malayalam Simi", "Maltes Simi", "Maori Simi", "Marathi Simi", "Marshallese Simi", "Mongol Simi", "Nauru Simi", "Navajo Simi", "Ndebele septentrional Simi", "Nepali Simi", "Ndonga Simi", "Noruego Bokmål Simi", "Noruego Nynorsk Simi", "Noruego Simi", "Yi Simi", "Ndebele del Sur Simi", "Occitano Simi", "", "Eslavo Eclesiástico Simi", "Oromo Simi", "Odia Simi", "Osetio Simi", "Punyabi Simi", "", "Persa Simi", "Polaco Simi", "Pashto Simi", "Portugues Simi", "Runasimi", "Romanche Simi", "Rundi Simi", "Rumano Simi", "Ruso Simi", "Sanscrito Simi", "Sardinian Simi", "Sindhi Simi", "Chincha Sami Simi", "Samoano Simi", "Sango Simi", "Serbio Simi", "Gaelico Escoces Simi", "Shona Simi", "Cingales Simi", "Eslovaco Simi", "Esloveno Simi", "Somali Simi", "Soto Meridional Simi", "Español Simi", "Sundanés Simi", "Suajili Simi", "Swati Simi", "Sueco Simi", "Tamil Simi", "Telugu Simi", "Tayiko Simi", "Tailandes Simi", "Tigriña Simi", "Tibetano Simi", "Turcomano Simi", "Filipino Simi", "Setsuana Simi", "Tongano Simi", "Turco Simi", "Tsonga Simi", "Tartaro Simi", "", "Tahití Simi", "Uigur Simi", "Ucraniano Simi", "Urdu Simi", "Uzbeko Simi", "Venda Simi", "Vietnamita Simi", "Volapük Simi", "Valona Simi", "Gales Simi", "Wolof Simi", "Frison Simi", "Isixhosa Simi", "Yiddish Simi", "Yoruba Simi", "Zhuang", "Isizulu Simi"];
+
+// rm
+static L81: [&str; 184] = ["afar", "abchasian", "afrikaans", "akan", "albanais", "amaric", "arab", "aragonais", "armen", "assami", "avaric", "avestic", "aymara", "lingua aserbaidschana", "bambara", "baschkir", "basc", "lingua bielorussa", "lingua bengala", "", "bislama", "bosniac", "breton", "bulgar", "birman", "catalan", "chamorro", "tschetschen", "nyanja", "chinais", "tschuvasch", "cornic", "cors", "cree", "croat", "tschec", "danais", "maledivic", "neerlandais", "dzongkha", "englais", "esperanto", "eston", "ewe", "feroais", "fidschian", "finlandais", "franzos", "fulah", "galizian", "georgian", "tudestg", "grec", "guarani", "gujarati", "creol haitian", "hausa", "ebraic", "herero", "hindi", "hiri motu", "ungarais", "interlingua", "indonais", "interlingue", "irlandais", "igbo", "inupiak", "ido", "islandais", "talian", "inuktitut", "giapunais", "javanais", "grönlandais", "kannada", "kanuri", "kashmiri", "kasac", "khmer", "kikuyu", "kinyarwanda", "kirghis", "komi", "kongo", "corean", "curd", "kuanyama", "latin", "luxemburgais", "ganda", "limburgais", "lingala", "laot", "lituan", "luba-katanga", "letton", "manx", "macedon", "malagassi", "malaic", "malayalam", "maltais", "māori", "marathi", "marschallais", "mongol", "nauru", "navajo", "ndebele dal nord", "nepalais", "ndonga", "norvegais bokmål", "norvegiais nynorsk", "norvegiais", "sichuan yi", "ndebele dal sid", "occitan", "ojibwa", "slav da baselgia", "oromo", "oriya", "ossetic", "punjabi", "pali", "persian", "polac", "paschto", "portugais", "quechua", "rumantsch", "rundi", "rumen", "russ", "sanscrit", "sard", "sindhi", "sami dal nord", "samoan", "sango", "serb", "gaelic scot", "shona", "singalais", "slovac", "sloven", "somali", "sotho dal sid", "spagnol", "sundanais", "suaheli", "swazi", "svedais", "tamil", "telugu", "tadschic", "tailandais", "tigrinya", "tibetan", "turkmen", "tagalog", "tswana", "tonga", "tirc", "tsonga", "tatar", "twi", "tahitian", "uiguric", "ucranais", "urdu", "usbec", "venda", "vietnamais", "volapuk", "vallon", "valisic", "wolof", "fris occidental", "xhosa", "jiddic", "yoruba", "zhuang", "zulu"];
+
+// ro
+static L82: [&str; 184] = ["afar", "abhază", "afrikaans", "akan", "albaneză", "amharică", "arabă", "aragoneză", "armeană", "asameză", "avară", "avestană", "aymara", "azeră", "bambara", "bașkiră", "bască", "belarusă", "bengaleză", "", "bislama", "bosniacă", "bretonă", "bulgară", "birmană", "catalană", "chamorro", "cecenă", "nyanja", "chineză", "ciuvașă", "cornică", "corsicană", "cree", "croată", "cehă", "daneză", "divehi", "neerlandeză", "dzongkha", "engleză", "esperanto", "estonă", "ewe", "feroeză", "fijiană", "finlandeză", "franceză", "fulah", "galiciană", "georgiană", "germană", "greacă", "guarani", "gujarati", "haitiană", "hausa", "ebraică", "herero", "hindi", "hiri motu", "maghiară", "interlingua", "indoneziană", "interlingue", "irlandeză", "igbo", "inupiak", "ido", "islandeză", "italiană", "inuktitut", "japoneză", "javaneză", "kalaallisut", "kannada", "kanuri", "cașmiră", "kazahă", "khmeră", "kikuyu", "kinyarwanda", "kârgâză", "komi", "congoleză", "coreeană", "kurdă", "kuanyama", "latină", "luxemburgheză", "ganda", "limburgheză", "lingala", "laoțiană", "lituaniană", "luba-katanga", "letonă", "manx", "macedoneană", "malgașă", "malaeză", "malayalam", "malteză", "maori", "marathi", "marshalleză", "mongolă", "nauru", "navajo", "ndebele de nord", "nepaleză", "ndonga", "norvegiană bokmål", "norvegiană nynorsk", "norvegiană", "yi din Sichuan", "ndebele de sud", "occitană", "ojibwa", "slavonă", "oromo", "odia", "osetă", "punjabi", "pali", "persană", "poloneză", "paștună", "portugheză", "quechua", "romanșă", "kirundi", "română", "rusă", "sanscrită", "sardiniană", "sindhi", "sami de nord", "samoană", "sango", "sârbă", "gaelică scoțiană", "shona", "singhaleză", "slovacă", "slovenă", "somaleză", "sesotho", "spaniolă", "sundaneză", "swahili", "swati", "suedeză", "tamilă", "telugu", "tadjică", "thailandeză", "tigrină", "tibetană", "turkmenă", "tagalog", "setswana", "tongană", "turcă", "tsonga", "tătară", "twi", "tahitiană", "uigură", "ucraineană", "urdu", "uzbecă", "venda", "vietnameză", "volapuk", "valonă", "galeză", "wolof", "frizonă occidentală", "xhosa", "idiș", "yoruba", "zhuang", "zulu"];
+
+// ru
+static L83: [&str; 184] = ["афарский", "абхазский", "африкаанс", "акан", "албанский", "амхарский", "арабский", "арагонский", "армянский", "ассамский", "аварский", "авестийский", "аймара", "азербайджанский", "бамбара", "башкирский", "баскский", "белорусский", "бенгальский", "", "бислама", "боснийский", "бретонский", "болгарский", "бирманский", "каталанский", "чаморро", "чеченский", "ньянджа", "китайский", "чувашский", "корнский", "корсиканский", "кри", "хорватский", "чешский", "датский", "мальдивский", "нидерландский", "дзонг-кэ", "английский", "эсперанто", "эстонский", "эве", "фарерский", "фиджи", "финский", "французский", "фулах", "галисийский", "грузинский", "немецкий", "греческий", "гуарани", "гуджарати", "гаитянский", "хауса", "иврит", "гереро", "хинди", "хиримоту", "венгерский", "интерлингва", "индонезийский", "интерлингве", "ирландский", "игбо", "инупиак", "идо", "исландский", "итальянский", "инуктитут", "японский", "яванский", "гренландский", "каннада", "канури", "кашмири", "казахский", "кхмерский", "кикуйю", "киньяруанда", "киргизский", "коми", "конго", "корейский", "курдский", "кунама", "латинский", "люксембургский", "ганда", "лимбургский", "лингала", "лаосский", "литовский", "луба-катанга", "латышский", "мэнский", "македонский", "малагасийский", "малайский", "малаялам", "мальтийский", "маори", "маратхи", "маршалльский", "монгольский", "науру", "навахо", "северный ндебеле", "непальский", "ндонга", "норвежский букмол", "нюнорск", "норвежский", "носу", "южный ндебеле", "окситанский", "оджибва", "церковнославянский", "оромо", "ория", "осетинский", "панджаби", "пали", "персидский", "польский", "пушту", "португальский", "кечуа", "романшский", "рунди", "румынский", "русский", "санскрит", "сардинский", "синдхи", "северносаамский", "самоанский", "санго", "сербский", "гэльский", "шона", "сингальский", "словацкий", "словенский", "сомали", "южный сото", "испанский", "сунданский", "суахили", "свази", "шведский", "тамильский", "телугу", "таджикский", "тайский", "тигринья", "тибетский", "туркменский", "тагалог", "тсвана", "тонганский", "турецкий", "тсонга", "татарский", "тви", "таитянский", "уйгурский", "украинский", "урду", "узбекский", "венда", "вьетнамский", "волапюк", "валлонский", "валлийский", "волоф", "западнофризский", "коса", "идиш", "йоруба", "чжуань", "зулу"];
+
+// sa
+static L84: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "चीनी", "", "", "", "", "", "", "", "", "", "", "आङ्ग्लभाषा", "", "", "", "", "", "", "फ़्रांसदेशीय भाषा:", "", "", "", "जर्मनभाषा:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "इटलीदेशीय भाषा:", "", "सूर्यमूलीय भाषा:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "पुर्तगालदेशीय भाषा:", "", "", "", "", "रष्यदेशीय भाषा:", "संस्कृत भाषा", "", "", "", "", "", "", "", "", "", "", "", "", "", "स्पेनीय भाषा:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// sc
+static L85: [&str; 184] = ["afar", "abcasu", "afrikaans", "akan", "albanesu", "amàricu", "àrabu", "aragonesu", "armenu", "assamesu", "avaru", "", "aimara", "azerbaigianu", "bambara", "baschiru", "bascu", "bielorussu", "bengalesu", "", "bislama", "bosnìacu", "brètone", "bùlgaru", "burmesu", "catalanu", "chamorru", "cecenu", "nyanja", "tzinesu", "ciuvàsciu", "còrnicu", "corsicanu", "", "croatu", "tzecu", "danesu", "malvidianu", "olandesu", "dzongkha", "inglesu", "esperanto", "èstone", "ewe", "faroesu", "fijianu", "finlandesu", "frantzesu", "fulah", "galitzianu", "georgianu", "tedescu", "grecu", "guaranì", "gujarati", "crèolu haitianu", "hausa", "ebràicu", "herero", "hindi", "", "ungheresu", "interlìngua", "indonesianu", "interlìngue", "irlandesu", "igbo", "", "ido", "islandesu", "italianu", "inuktitut", "giaponesu", "giavanesu", "groenlandesu", "kannada", "kanuri", "kashmiri", "kazacu", "khmer", "kikuyu", "kinyarwanda", "chirghisu", "komi", "", "coreanu", "curdu", "kuanyama", "latinu", "lussemburghesu", "ganda", "limburghesu", "lingala", "laotianu", "lituanu", "luba-katanga", "lètone", "mannesu", "matzèdone", "malgàsciu", "malesu", "malayalam", "maltesu", "maori", "marathi", "marshallesu", "mòngolu", "nauru", "navajo", "ndebele de su nord", "nepalesu", "ndonga", "norvegesu bokmål", "norvegesu nynorsk", "norvegesu", "sichuan yi", "ndebele de su sud", "otzitanu", "", "islavu eclesiàsticu", "oromo", "odia", "ossèticu", "punjabi", "pali", "persianu", "polacu", "pashto", "portoghesu", "quechua", "romànciu", "rundi", "rumenu", "russu", "sànscritu", "sardu", "sindhi", "sami setentrionale", "samoanu", "sango", "serbu", "gaèlicu iscotzesu", "shona", "singalesu", "islovacu", "islovenu", "sòmalu", "sotho meridionale", "ispagnolu", "sundanesu", "swahili", "swati", "isvedesu", "tamil", "telugu", "tagicu", "tailandesu", "tigrignu", "tibetanu", "turcmenu", "filipinu", "tswana", "tonganu", "turcu", "tsonga", "tàtaru", "", "taitianu", "uiguru", "ucrainu", "urdu", "uzbecu", "venda", "vietnamita", "volapük", "vallonu", "gallesu", "wolof", "frisone otzidentale", "xhosa", "yiddish", "yoruba", "zhuang", "zulu"];
+
+// sd
+static L86: [&str; 184] = ["افار", "ابقازیان", "آفريڪي", "اڪان", "الباني", "امهاري", "عربي", "ارگني", "ارماني", "آسامي", "اويرس", "", "ایمارا", "آزربائيجاني", "بمبارا", "ڪينيڊا", "باسق", "بيلاروسي", "بنگلا", "", "بسلاما", "بوسنيائي", "بريٽن", "بلغاريائي", "برمي", "ڪيٽالان", "چمورو", "چیچن", "نيانجا", "چيني", "چو واش", "ڪورنش", "ڪارسيڪائي", "", "ڪروشيائي", "چيڪ", "ڊينش", "دويهي", "ڊچ", "زونخا", "انگريزي", "ايسپرانٽو", "ايستونائي", "ايو", "فيروايس", "فجي", "فنش", "فرانسيسي", "فلاهه", "گليشئين", "جارجيائي", "جرمن", "يوناني", "گواراني", "گجراتي", "هيٽي ڪرولي", "هوسا", "عبراني", "هريرو", "هندي", "", "هنگري", "انٽرلنگئا", "انڊونيشي", "انٽرلنگئي", "آئرش", "اگبو", "", "ادو", "آئيس لينڊڪ", "اطالوي", "انو ڪتوت", "جاپاني", "جاونيز", "ڪالا ليسٽ", "ڪناڊا", "ڪنوري", "ڪشميري", "قازق", "خمر", "اڪويو", "ڪنيار وانڊا", "ڪرغيز", "ڪومي", "", "ڪوريائي", "ڪردي", "ڪنياما", "لاطيني", "لگزمبرگ", "گاندا", "لمبرگش", "لنگالا", "لائو", "ليٿونيائي", "لوبا-ڪتانگا", "لاتوين", "مينڪس", "ميسي ڊونيائي", "ملاگاسي", "ملي", "مليالم", "مالٽي", "مائوري", "مراٺي", "مارشليز", "منگولي", "نائو", "نواجو", "اتر دبيلي", "نيپالي", "ڊونگا", "نارويائي بوڪمال", "نارويائي نيوناسڪ", "نارويجيائي", "سچوان يي", "ڏکڻ دبيلي", "آڪسيٽن", "", "چرچ سلاوی", "اورومو", "اوڊيا", "اوسيٽڪ", "پنجابي", "", "فارسي", "پولش", "پشتو", "پورٽگليز", "ڪيچوا", "رومانش", "رونڊي", "روماني", "روسي", "سنسڪرت", "سارڊيني", "سنڌي", "اتر سامي", "سموئا", "سانگو", "سربيائي", "اسڪاٽش گيلڪ", "شونا", "سنهالا", "سلواڪي", "سلوويني", "سومالي", "ڏکڻ سوٿي", "هسپانوي", "سوڊاني", "سواحيلي", "سواتي", "سويڊش", "تامل", "تلگو", "تاجڪ", "ٿائي", "تگرينيائي", "تبيتائي", "ترڪمين", "فلپائني", "تسوانا", "تونگن", "ترڪي", "سونگا", "تاتار", "", "تاهيتي", "يوغور", "يوڪراني", "اردو", "ازبڪ", "وينڊا", "ويتنامي", "والپڪ", "ولون", "ويلش", "وولوف", "مغربي فريشن", "زھوسا", "يدش", "يوروبا", "جوئنگ", "زولو"];
+
+// sr
+static L87: [&str; 184] = ["афарски", "абхаски", "африканс", "акански", "албански", "амхарски", "арапски", "арагонски", "јерменски", "асамски", "аварски", "авестански", "ајмара", "азербејџански", "бамбара", "башкирски", "баскијски", "белоруски", "бенгалски", "", "бислама", "босански", "бретонски", "бугарски", "бурмански", "каталонски", "чаморо", "чеченски", "њанџа", "кинески", "чувашки", "корнволски", "корзикански", "кри", "хрватски", "чешки", "дански", "малдивски", "холандски", "џонга", "енглески", "есперанто", "естонски", "еве", "фарски", "фиџијски", "фински", "француски", "фула", "галицијски", "грузијски", "немачки", "грчки", "гварани", "гуџарати", "хаићански", "хауса", "хебрејски", "хереро", "хинди", "хири моту", "мађарски", "интерлингва", "индонежански", "интерлингве", "ирски", "игбо", "инупик", "идо", "исландски", "италијански", "инуктитутски", "јапански", "јавански", "гренландски", "канада", "канури", "кашмирски", "казашки", "кмерски", "кикују", "кињаруанда", "киргиски", "коми", "конго", "корејски", "курдски", "квањама", "латински", "луксембуршки", "ганда", "лимбуршки", "лингала", "лаоски", "литвански", "луба-катанга", "летонски", "манкс", "македонски", "малгашки", "малајски", "малајалам", "малтешки", "маорски", "марати", "маршалски", "монголски", "науруски", "навахо", "северни ндебеле", "непалски", "ндонга", "норвешки букмол", "норвешки нинорск", "норвешки", "сечуански ји", "јужни ндебеле", "окситански", "оџибве", "црквенословенски", "оромо", "одија", "осетински", "пенџапски", "пали", "персијски", "пољски", "паштунски", "португалски", "кечуа", "романш", "кирунди", "румунски", "руски", "санскрит", "сардински", "синди", "северни сами", "самоански", "санго", "српски", "шкотски гелски", "шона", "синхалешки", "словачки", "словеначки", "сомалски", "сесото", "шпански", "сундски", "свахили", "свази", "шведски", "тамилски", "телугу", "таџички", "тајски", "тигриња", "тибетански", "туркменски", "тагалог", "цвана", "тонгански", "турски", "цонга", "татарски", "тви", "тахићански", "ујгурски", "украјински", "урду", "узбечки", "венда", "вијетнамски", "волапик", "валонски", "велшки", "волоф", "западни фризијски", "коса", "јидиш", "јоруба", "џуаншки", "зулу"];
+
+// gd
+static L88: [&str; 184] = ["Afar", "Abchasais", "Afraganais", "Akan", "Albàinis", "Amtharais", "Arabais", "Aragonais", "Airmeinis", "Asamais", "Avarais", "Avestanais", "Aymara", "Asarbaideànais", "Bambara", "Bashkir", "Basgais", "Bealaruisis", "Bangla", "", "Bislama", "Bosnais", "Breatnais", "Bulgarais", "Burmais", "Catalanais", "Chamorro", "Deideanais", "Nyanja", "Sìnis", "Chuvash", "Còrnais", "Corsais", "Cree", "Cròthaisis", "Seicis", "Danmhairgis", "Divehi", "Duitsis", "Dzongkha", "Beurla", "Esperanto", "Eastoinis", "Ewe", "Fàrothais", "Fìdis", "Fionnlannais", "Fraingis", "Fulah", "Gailìsis", "Cairtbheilis", "Gearmailtis", "Greugais", "Guaraní", "Gujarati", "Crìtheol Haidhti", "Hausa", "Eabhra", "Herero", "Hindis", "Hiri Motu", "Ungairis", "Interlingua", "Innd-Innsis", "Interlingue", "Gaeilge", "Igbo", "Inupiaq", "Ido", "Innis Tìlis", "Eadailtis", "Inuktitut", "Seapanais", "Deàbhanais", "Kalaallisut", "Kannada", "Kanuri", "Caismiris", "Casachais", "Cmèar", "Kikuyu", "Kinyarwanda", "Cìorgasais", "Komi", "Kongo", "Coirèanais", "Cùrdais", "Kuanyama", "Laideann", "Lugsamburgais", "Ganda", "Cànan Limburg", "Lingala", "Làtho", "Liotuainis", "Luba-Katanga", "Laitbheis", "Gaelg", "Masadonais", "Malagasais", "Malaidhis", "Malayalam", "Maltais", "Māori", "Marathi", "Marshallais", "Mongolais", "Nabhru", "Navajo", "Ndebele Thuathach", "Neapàlais", "Ndonga", "Bokmål na Nirribhidh", "Nynorsk na Nirribhidh", "Nirribhis", "Yi Sichuan", "Ndebele Dheasach", "Ogsatanais", "Ojibwa", "Slàbhais na h-Eaglaise", "Oromo", "Odia", "Ossetic", "Panjabi", "Pali", "Peirsis", "Pòlainnis", "Pashto", "Portagailis", "Quechua", "Rumains", "Kirundi", "Romàinis", "Ruisis", "Sanskrit", "Sàrdais", "Sindhi", "Sàmais Thuathach", "Samothais", "Sango", "Sèirbis", "Gàidhlig", "Shona", "Sinhala", "Slòbhacais", "Slòbhainis", "Somàilis", "Sesotho", "Spàinntis", "Cànan Sunda", "Kiswahili", "Swati", "Suainis", "Taimilis", "Telugu", "Taidigis", "Cànan nan Tàidh", "Tigrinya", "Tibeitis", "Turcmanais", "Tagalog", "Tswana", "Tonga", "Turcais", "Tsonga", "Tatarais", "Twi", "Cànan Tahiti", "Ùigiurais", "Ucràinis", "Ùrdu", "Usbagais", "Venda", "Bhiet-Namais", "Volapük", "Walloon", "Cuimris", "Wolof", "Frìoslannais Shiarach", "Xhosa", "Iùdhais", "Yoruba", "Zhuang", "Zulu"];
+
+// si
+static L89: [&str; 184] = ["අෆාර්", "ඇබ්කාසියානු", "අෆ්\u{200D}රිකාන්ස්", "අකාන්", "ඇල්බේනියානු", "ඇම්හාරික්", "අරාබි", "ඇරගොනීස්", "ආර්මේනියානු", "ඇසෑම්", "ඇවරික්", "", "අයිමරා", "අසර්බයිජාන්", "බම්බරා", "බාෂ්කිර්", "බාස්ක්", "බෙලරුසියානු", "බෙංගාලි", "", "බිස්ලමා", "බොස්නියානු", "බ්\u{200D}රේටොන්", "බල්ගේරියානු", "බුරුම", "කැටලන්", "චමොරො", "චෙච්නියානු", "න්යන්ජා", "චීන", "චවේෂ්", "කෝනීසියානු", "කෝසිකානු", "", "කෝඒෂියානු", "චෙක්", "ඩැනිශ්", "ඩිවෙහි", "ලන්දේසි", "ඩිසොන්කා", "ඉංග්\u{200D}රීසි", "එස්පැරන්ටෝ", "එස්තෝනියානු", "ඉව්", "ෆාරෝස්", "ෆීජි", "ෆින්ලන්ත", "ප්\u{200D}රංශ", "ෆුලාහ්", "ගැලීසියානු", "ජෝර්ජියානු", "ජර්මන්", "ග්\u{200D}රීක", "ගුවාරනි", "ගුජරාටි", "හයිටි", "හෝසා", "හීබෲ", "හෙරෙරො", "හින්දි", "", "හන්ගේරියානු", "ඉන්ටලින්ගුආ", "ඉන්දුනීසියානු", "ඉන්ටර්ලින්ග්", "අයර්ලන්ත", "ඉග්බෝ", "", "ඉඩො", "අයිස්ලන්ත", "ඉතාලි", "ඉනුක්ටිටුට්", "ජපන්", "ජාවා", "කලාලිසට්", "කණ්ණඩ", "කනුරි", "කාෂ්මීර්", "කසාඛ්", "කමර්", "කිකුයු", "කින්යර්වන්ඩා", "කිර්ගිස්", "කොමි", "", "කොරියානු", "කුර්දි", "කුයන්යමා", "ලතින්", "ලක්සැම්බර්ග්", "ගන්ඩා", "ලිම්බර්ගිශ්", "ලින්ගලා", "ලාඕ", "ලිතුවේනියානු", "ලුබා-කටන්ගා", "ලැට්වියානු", "මැන්ක්ස්", "මැසිඩෝනියානු", "මලගාසි", "මැලේ", "මලයාලම්", "මොල්ටිස්", "මාවොරි", "මරාති", "මාශලීස්", "මොංගෝලියානු", "නෞරු", "නවාජො", "උතුරු එන්ඩිබෙලෙ", "නේපාල", "න්ඩොන්ගා", "නෝර්වීජියානු බොක්මල්", "නෝර්වීජියානු නයිනෝර්ස්ක්", "නෝර්වීජියානු", "සිචුආන් යී", "සෞත් ඩ්බේල්", "ඔසිටාන්", "", "චර්ච් ස්ලැවික්", "ඔරොමෝ", "ඔඩියා", "ඔසිටෙක්", "පන්ජාබි", "", "පර්සියානු", "පෝලන්ත", "පෂ්ටො", "පෘතුගීසි", "ක්වීචුවා", "රොමෑන්ශ්", "රුන්ඩි", "රොමේනියානු", "රුසියානු", "සංස්කෘත", "සාර්ඩිනිඅන්", "සින්ධි", "උතුරු සාමි", "සෑමොඅන්", "සන්ග්\u{200D}රෝ", "සර්බියානු", "ස්කොට්ටිශ් ගෙලික්", "ශෝනා", "සිංහල", "ස්ලෝවැක්", "ස්ලෝවේනියානු", "සෝමාලි", "සතර්න් සොතො", "ස්පාඤ්ඤ", "සන්ඩනීසියානු", "ස්වාහිලි", "ස්වති", "ස්වීඩන්", "දෙමළ", "තෙළිඟු", "ටජික්", "තායි", "ටිග්\u{200D}රින්යා", "ටිබෙට්", "ටර්ක්මෙන්", "පිලිපීන", "ස්වනා", "ටොංගා", "තුර්කි", "සොන්ග", "ටාටර්", "", "ටහිටියන්", "උයිගර්", "යුක්රේනියානු", "උර්දු", "උස්බෙක්", "වෙන්ඩා", "වියට්නාම්", "වොලපූක්", "වෑලූන්", "වෙල්ෂ්", "වොලොෆ්", "බටහිර ෆ්\u{200D}රිසියානු", "ශෝසා", "යිඩිශ්", "යොරූබා", "ෂුවාං", "සුලු"];
+
+// sk
+static L90: [&str; 184] = ["afarčina", "abcházčina", "afrikánčina", "akančina", "albánčina", "amharčina", "arabčina", "aragónčina", "arménčina", "ásamčina", "avarčina", "avestčina", "aymarčina", "azerbajdžančina", "bambarčina", "baškirčina", "baskičtina", "bieloruština", "bengálčina", "", "bislama", "bosniačtina", "bretónčina", "bulharčina", "barmčina", "katalánčina", "čamorčina", "čečenčina", "ňandža", "čínština", "čuvaština", "kornčina", "korzičtina", "krí", "chorvátčina", "čeština", "dánčina", "maldivčina", "holandčina", "dzongkha", "angličtina", "esperanto", "estónčina", "eweština", "faerčina", "fidžijčina", "fínčina", "francúzština", "fulbčina", "galícijčina", "gruzínčina", "nemčina", "gréčtina", "guaraníjčina", "gudžarátčina", "haitská kreolčina", "hauština", "hebrejčina", "herero", "hindčina", "hiri motu", "maďarčina", "interlingua", "indonézština", "interlingue", "írčina", "igboština", "inupik", "ido", "islandčina", "taliančina", "inuktitut", "japončina", "jávčina", "grónčina", "kannadčina", "kanurijčina", "kašmírčina", "kazaština", "khmérčina", "kikujčina", "rwandčina", "kirgizština", "komijčina", "kongčina", "kórejčina", "kurdčina", "kuaňama", "latinčina", "luxemburčina", "gandčina", "limburčina", "lingalčina", "laoština", "litovčina", "lubčina (katanžská)", "lotyština", "mančina", "macedónčina", "malgaština", "malajčina", "malajálamčina", "maltčina", "maorijčina", "maráthčina", "marshallčina", "mongolčina", "nauruština", "navaho", "ndebelčina (severná)", "nepálčina", "ndonga", "nórčina (bokmal)", "nórčina (nynorsk)", "nórčina", "s’čchuanská iovčina", "ndebelčina (južná)", "okcitánčina", "odžibva", "cirkevná slovančina", "oromčina", "uríjčina", "osetčina", "pandžábčina", "pálí", "perzština", "poľština", "paštčina", "portugalčina", "kečuánčina", "rétorománčina", "rundčina", "rumunčina", "ruština", "sanskrit", "sardínčina", "sindhčina", "saamčina (severná)", "samojčina", "sango", "srbčina", "škótska gaelčina", "šončina", "sinhalčina", "slovenčina", "slovinčina", "somálčina", "sothčina (južná)", "španielčina", "sundčina", "swahilčina", "svazijčina", "švédčina", "tamilčina", "telugčina", "tadžičtina", "thajčina", "tigriňa", "tibetčina", "turkménčina", "tagalčina", "tswančina", "tongčina", "turečtina", "tsongčina", "tatárčina", "twi", "tahitčina", "ujgurčina", "ukrajinčina", "urdčina", "uzbečtina", "vendčina", "vietnamčina", "volapük", "valónčina", "waleština", "wolofčina", "západná frízština", "xhoština", "jidiš", "jorubčina", "čuangčina", "zuluština"];
+
+// sl
+static L91: [&str; 184] = ["afarščina", "abhaščina", "afrikanščina", "akanščina", "albanščina", "amharščina", "arabščina", "aragonščina", "armenščina", "asamščina", "avarščina", "avestijščina", "ajmarščina", "azerbajdžanščina", "bambarščina", "baškirščina", "baskovščina", "beloruščina", "bengalščina", "", "bislamščina", "bosanščina", "bretonščina", "bolgarščina", "burmanščina", "katalonščina", "čamorščina", "čečenščina", "njanščina", "kitajščina", "čuvaščina", "kornijščina", "korziščina", "krijščina", "hrvaščina", "češčina", "danščina", "diveščina", "nizozemščina", "dzonka", "angleščina", "esperanto", "estonščina", "evenščina", "ferščina", "fidžijščina", "finščina", "francoščina", "fulščina", "galicijščina", "gruzijščina", "nemščina", "grščina", "gvaranijščina", "gudžaratščina", "haitijska kreolščina", "havščina", "hebrejščina", "herero", "hindijščina", "hiri motu", "madžarščina", "interlingva", "indonezijščina", "interlingve", "irščina", "igboščina", "inupiaščina", "ido", "islandščina", "italijanščina", "inuktitutščina", "japonščina", "javanščina", "grenlandščina", "kanareščina", "kanurščina", "kašmirščina", "kazaščina", "kmerščina", "kikujščina", "ruandščina", "kirgiščina", "komijščina", "kongovščina", "korejščina", "kurdščina", "kvanjama", "latinščina", "luksemburščina", "ganda", "limburščina", "lingala", "laoščina", "litovščina", "luba-katanga", "latvijščina", "manščina", "makedonščina", "malgaščina", "malajščina", "malajalamščina", "malteščina", "maorščina", "maratščina", "marshallovščina", "mongolščina", "naurujščina", "navajščina", "severna ndebelščina", "nepalščina", "ndonga", "knjižna norveščina", "novonorveščina", "norveščina", "sečuanska jiščina", "južna ndebelščina", "okcitanščina", "anašinabščina", "stara cerkvena slovanščina", "oromo", "odijščina", "osetinščina", "pandžabščina", "palijščina", "perzijščina", "poljščina", "paštunščina", "portugalščina", "kečuanščina", "retoromanščina", "rundščina", "romunščina", "ruščina", "sanskrt", "sardinščina", "sindščina", "severna samijščina", "samoanščina", "sango", "srbščina", "škotska gelščina", "šonščina", "sinhalščina", "slovaščina", "slovenščina", "somalščina", "sesoto", "španščina", "sundanščina", "svahili", "svazijščina", "švedščina", "tamilščina", "telugijščina", "tadžiščina", "tajščina", "tigrajščina", "tibetanščina", "turkmenščina", "tagalogščina", "cvanščina", "tongščina", "turščina", "congščina", "tatarščina", "tvi", "tahitščina", "ujgurščina", "ukrajinščina", "urdujščina", "uzbeščina", "venda", "vietnamščina", "volapik", "valonščina", "valižanščina", "volofščina", "zahodna frizijščina", "koščina", "jidiš", "jorubščina", "džuangščina", "zulujščina"];
+
+// so
+static L92: [&str; 184] = ["", "U dhashay Abkhazia", "Afrikaanka", "Akan", "Albeeniyaan", "Axmaar", "Carabi", "U dhashay Aragon", "Armeeniyaan", "Asaamiis", "U dhashay Avar", "", "U dhashay Aymar", "Asarbayjan", "Bambaara", "Bashkir", "Basquu", "Beleruusiyaan", "Bangladesh", "", "U dhashay Bislam", "Bosniyaan", "Biriton", "Bulgeeriyaan", "Burmese", "Katalaan", "Chamorro", "Jejen", "Inyaanja", "Shinees", "Chuvash", "Kornish", "Korsikan", "", "Koro’eeshiyaan", "Jeeg", "Dhaanish", "Divehi", "Holandays", "D’zongqa", "Ingiriisi", "Isberaanto", "Istooniyaan", "Eewe", "Farowsi", "Fijian", "Finishka", "Faransiis", "Fuulah", "Galiisiyaan", "Joorijiyaan", "Jarmal", "Giriik", "Guarani", "Gujaraati", "Heeytiyaan Karawle", "Hawsa", "Cibraani", "Herero", "Hindi", "", "Hangariyaan", "Interlinguwa", "Indunusiyaan", "Interlingue", "Ayrish", "Igbo", "", "Ido", "Ayslandays", "Talyaani", "Inuktitut", "Jabaaniis", "Jafaaniis", "Kalaallisuut", "Kannadays", "Kanuri", "Kaashmiir", "Kasaaq", "Kamboodhian", "Kikuuyu", "Ruwaandha", "Kirgiis", "Komi", "", "Kuuriyaan", "Kurdishka", "Kuanyama", "Laatiin", "Luksaamboorgish", "Gandha", "Limburgish", "Lingala", "Lao", "Lituwaanays", "Luuba-kataanga", "Laatfiyaan", "Mankis", "Masadooniyaan", "Malagaasi", "Malaay", "Malayalam", "Maltiis", "Maaoori", "Maarati", "Marshallese", "Mangooli", "Nauru", "Navajo", "Indhebeele Waqooyi", "Nebaali", "Ndonga", "Nawrijii Bokmål", "Nawriijiga Nynorsk", "Nawriiji", "Sijuwan Yi", "South Ndebele", "Occitan", "", "Kaniisadda Islaafik", "Oromo", "Oodhiya", "Oseetic", "Bunjaabi", "", "Faarisi", "Boolish", "Bashtuu", "Boortaqiis", "Quechua", "Romaanis", "Rundhi", "Romanka", "Ruush", "Sanskrit", "Sardinian", "Siindhi", "Sami Waqooyi", "Samowan", "Sango", "Seerbiyaan", "Iskot Giilik", "Shoona", "Sinhala", "Isloofaak", "Islofeeniyaan", "Soomaali", "Sesooto", "Isbaanish", "Suudaaniis", "Sawaaxili", "Swati", "Iswiidhish", "Tamiil", "Teluugu", "Taajik", "Taaylandays", "Tigrinya", "Tibeetaan", "Turkumaanish", "Filibiino", "Tswana", "Toongan", "Turkish", "Tsonga", "Taatar", "", "Tahitian", "Uighur", "Yukreeniyaan", "Urduu", "Usbakis", "Venda", "Fiitnaamays", "Folabuuk", "Walloon", "Welsh", "Woolof", "Firiisiyan Galbeed", "Xhosa", "Yadhish", "Yoruuba", "Zhuang", "Zuulu"];
+
+// st
+static L93: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Senyesemane", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Sesotho", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Sewelsh", "", "", "", "", "", "", ""];
+
+// es
+static L94: [&str; 184] = ["afar", "abjasio", "afrikáans", "akan", "albanés", "amárico", "árabe", "aragonés", "armenio", "asamés", "avar", "avéstico", "aimara", "azerbaiyano", "bambara", "baskir", "euskera", "bielorruso", "bengalí", "", "bislama", "bosnio", "bretón", "búlgaro", "birmano", "catalán", "chamorro", "checheno", "nyanja", "chino", "chuvasio", "córnico", "corso", "cree", "croata", "checo", "danés", "divehi", "neerlandés", "dzongkha", "inglés", "esperanto", "estonio", "ewé", "feroés", "fiyiano", "finés", "francés", "fula", "gallego", "georgiano", "alemán", "griego", "guaraní", "guyaratí", "criollo haitiano", "hausa", "hebreo", "herero", "hindi", "hiri motu", "húngaro", "interlingua", "indonesio", "interlingue", "irlandés", "igbo", "inupiaq", "ido", "islandés", "italiano", "inuktitut", "japonés", "javanés", "groenlandés", "canarés", "kanuri", "cachemir", "kazajo", "jemer", "kikuyu", "kinyarwanda", "kirguís", "komi", "kongo", "coreano", "kurdo", "kuanyama", "latín", "luxemburgués", "ganda", "limburgués", "lingala", "lao", "lituano", "luba-katanga", "letón", "manés", "macedonio", "malgache", "malayo", "malayálam", "maltés", "maorí", "maratí", "marshalés", "mongol", "nauruano", "navajo", "ndebele septentrional", "nepalí", "ndonga", "noruego bokmal", "noruego nynorsk", "noruego", "yi de Sichuán", "ndebele meridional", "occitano", "ojibwa", "eslavo eclesiástico", "oromo", "oriya", "osético", "punyabí", "pali", "persa", "polaco", "pastún", "portugués", "quechua", "romanche", "kirundi", "rumano", "ruso", "sánscrito", "sardo", "sindi", "sami septentrional", "samoano", "sango", "serbio", "gaélico escocés", "shona", "cingalés", "eslovaco", "esloveno", "somalí", "sotho meridional", "español", "sundanés", "suajili", "suazi", "sueco", "tamil", "telugu", "tayiko", "tailandés", "tigriña", "tibetano", "turcomano", "tagalo", "setsuana", "tongano", "turco", "tsonga", "tártaro", "twi", "tahitiano", "uigur", "ucraniano", "urdu", "uzbeko", "venda", "vietnamita", "volapük", "valón", "galés", "wólof", "frisón occidental", "xhosa", "yidis", "yoruba", "zhuang", "zulú"];
+
+// su
+static L95: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Tiongkok", "", "", "", "", "", "", "", "", "", "", "Inggris", "", "", "", "", "", "", "Prancis", "", "", "", "Jérman", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Italia", "", "Jepang", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Portugis", "", "", "", "", "Rusia", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Spanyol", "Basa Sunda", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// sw
+static L96: [&str; 184] = ["Kiafar", "Kiabkhazi", "Kiafrikana", "Kiakani", "Kialbania", "Kiamhari", "Kiarabu", "Kiaragoni", "Kiarmenia", "Kiassam", "Kiavari", "", "Kiaymara", "Kiazerbaijani", "Kibambara", "Kibashkiri", "Kibaski", "Kibelarusi", "Kibengali", "", "Kibislama", "Kibosnia", "Kibretoni", "Kibulgaria", "Kiburma", "Kikatalani", "Kichamorro", "Kichechenia", "Kinyanja", "Kichina", "Kichuvash", "Kikorni", "Kikosikani", "", "Kikorasia", "Kicheki", "Kidenmaki", "Kidivehi", "Kiholanzi", "Kizongkha", "Kiingereza", "Kiesperanto", "Kiestonia", "Kiewe", "Kifaroe", "Kifiji", "Kifini", "Kifaransa", "Kifulani", "Kigalisi", "Kijojia", "Kijerumani", "Kigiriki", "Kiguarani", "Kigujarati", "Kihaiti", "Kihausa", "Kiebrania", "Kiherero", "Kihindi", "", "Kihungaria", "Kiintalingua", "Kiindonesia", "lugha ya kisayansi", "Kiayalandi", "Kiigbo", "", "Kiido", "Kiisilandi", "Kiitaliano", "Kiinuktituti", "Kijapani", "Kijava", "Kikalaallisut", "Kikannada", "Kikanuri", "Kikashmiri", "Kikazakh", "Kikambodia", "Kikikuyu", "Kinyarwanda", "Kikyrgyz", "Kikomi", "Kikongo", "Kikorea", "Kikurdi", "Kikwanyama", "Kilatini", "Kilasembagi", "Kiganda", "Limburgish", "Kilingala", "Kilaosi", "Kilithuania", "Kiluba-Katanga", "Kilatvia", "Kimanx", "Kimacedonia", "Kimalagasi", "Kimalei", "Kimalayalamu", "Kimalta", "Kimaori", "Kimarathi", "Kimashale", "Kimongolia", "Kinauru", "Kinavajo", "Kindebele cha Kaskazini", "Kinepali", "Kindonga", "Kinorwe cha Bokmal", "Kinorwe cha Nynorsk", "Kinorwe", "Kiyi cha Sichuan", "Kindebele", "Kiokitani", "", "Kislovakia cha Kanisa", "Kioromo", "Kioriya", "Kiosetia", "Kipunjabi", "", "Kiajemi", "Kipolandi", "Kipashto", "Kireno", "Kikechua", "Kiromanshi", "Kirundi", "Kiromania", "Kirusi", "Kisanskriti", "Kisardinia", "Kisindhi", "Kisami cha Kaskazini", "Kisamoa", "Kisango", "Kiserbia", "Kigaeli cha Uskoti", "Kishona", "Kisinhala", "Kislovakia", "Kislovenia", "Kisomali", "Kisotho", "Kihispania", "Kisunda", "Kiswahili", "Kiswati", "Kiswidi", "Kitamili", "Kitelugu", "Kitajiki", "Kithai", "Kitigrinya", "Kitibeti", "Kiturukimeni", "Kifilipino", "Kitswana", "Kitonga", "Kituruki", "Kitsonga", "Kitatari", "Twi", "Kitahiti", "Kiuyghur", "Kiukraini", "Kiurdu", "Kiuzbeki", "Kivenda", "Kivietinamu", "Kivolapuk", "Kiwaloon", "Kiwelisi", "Kiwolofu", "Kifrisia cha Magharibi", "Kixhosa", "Kiyiddi", "Kiyoruba", "Kizhuang", "Kizulu"];
+
+// sv
+static L97: [&str; 184] = ["afar", "abchaziska", "afrikaans", "akan", "albanska", "amhariska", "arabiska", "aragonesiska", "armeniska", "assamesiska", "avariska", "avestiska", "aymara", "azerbajdzjanska", "bambara", "basjkiriska", "baskiska", "belarusiska", "bengali", "", "bislama", "bosniska", "bretonska", "bulgariska", "burmesiska", "katalanska", "chamorro", "tjetjenska", "nyanja", "kinesiska", "tjuvasjiska", "korniska", "korsikanska", "cree", "kroatiska", "tjeckiska", "danska", "divehi", "nederländska", "dzongkha", "engelska", "esperanto", "estniska", "ewe", "färöiska", "fijianska", "finska", "franska", "fulani", "galiciska", "georgiska", "tyska", "grekiska", "guaraní", "gujarati", "haitiska", "hausa", "hebreiska", "herero", "hindi", "hirimotu", "ungerska", "interlingua", "indonesiska", "interlingue", "iriska", "igbo", "inupiak", "ido", "isländska", "italienska", "inuktitut", "japanska", "javanesiska", "grönländska", "kannada", "kanuri", "kashmiriska", "kazakiska", "kambodjanska", "kikuyu", "kinjarwanda", "kirgiziska", "kome", "kikongo", "koreanska", "kurdiska", "kuanyama", "latin", "luxemburgiska", "luganda", "limburgiska", "lingala", "laotiska", "litauiska", "luba-katanga", "lettiska", "manx", "makedonska", "malagassiska", "malajiska", "malayalam", "maltesiska", "maori", "marathi", "marshalliska", "mongoliska", "nauruanska", "navaho", "nordndebele", "nepalesiska", "ndonga", "norskt bokmål", "nynorska", "norska", "szezuan i", "sydndebele", "occitanska", "odjibwa", "kyrkslaviska", "oromo", "oriya", "ossetiska", "punjabi", "pali", "persiska", "polska", "afghanska", "portugisiska", "quechua", "rätoromanska", "rundi", "rumänska", "ryska", "sanskrit", "sardinska", "sindhi", "nordsamiska", "samoanska", "sango", "serbiska", "skotsk gaeliska", "shona", "singalesiska", "slovakiska", "slovenska", "somaliska", "sydsotho", "spanska", "sundanesiska", "swahili", "swati", "svenska", "tamil", "telugu", "tadzjikiska", "thailändska", "tigrinja", "tibetanska", "turkmeniska", "tagalog", "tswana", "tonganska", "turkiska", "tsonga", "tatariska", "twi", "tahitiska", "uiguriska", "ukrainska", "urdu", "uzbekiska", "venda", "vietnamesiska", "volapük", "vallonska", "walesiska", "wolof", "västfrisiska", "xhosa", "jiddisch", "yoruba", "zhuang", "zulu"];
+
+// ta
+static L98: [&str; 184] = ["அஃபார்", "அப்காஜியான்", "ஆஃப்ரிகான்ஸ்", "அகான்", "அல்பேனியன்", "அம்ஹாரிக்", "அரபிக்", "ஆர்கோனீஸ்", "ஆர்மேனியன்", "அஸ்ஸாமீஸ்", "அவேரிக்", "அவெஸ்தான்", "அய்மரா", "அசர்பைஜானி", "பம்பாரா", "பஷ்கிர்", "பாஸ்க்", "பெலாருஷியன்", "வங்காளம்", "", "பிஸ்லாமா", "போஸ்னியன்", "பிரெட்டன்", "பல்கேரியன்", "பர்மீஸ்", "கேட்டலான்", "சாமோரோ", "செச்சென்", "நயன்ஜா", "சீனம்", "சுவாஷ்", "கார்னிஷ்", "கார்சிகன்", "க்ரீ", "குரோஷியன்", "செக்", "டேனிஷ்", "திவேஹி", "டச்சு", "பூடானி", "ஆங்கிலம்", "எஸ்பரேன்டோ", "எஸ்டோனியன்", "ஈவ்", "ஃபரோயிஸ்", "ஃபிஜியன்", "ஃபின்னிஷ்", "பிரெஞ்சு", "ஃபுலா", "காலிஸியன்", "ஜார்ஜியன்", "ஜெர்மன்", "கிரேக்கம்", "க்வாரனி", "குஜராத்தி", "ஹைத்தியன் க்ரியோலி", "ஹௌஸா", "ஹீப்ரூ", "ஹெரேரோ", "இந்தி", "ஹிரி மோட்டு", "ஹங்கேரியன்", "இன்டர்லிங்வா", "இந்தோனேஷியன்", "இன்டர்லிங்", "ஐரிஷ்", "இக்போ", "இனுபியாக்", "இடோ", "ஐஸ்லேண்டிக்", "இத்தாலியன்", "இனுகிடூட்", "ஜாப்பனீஸ்", "ஜாவனீஸ்", "கலாலிசூட்", "கன்னடம்", "கனுரி", "காஷ்மிரி", "கசாக்", "கெமெர்", "கிகுயூ", "கின்யாருவான்டா", "கிர்கிஸ்", "கொமி", "காங்கோ", "கொரியன்", "குர்திஷ்", "குவான்யாமா", "லத்தின்", "லக்ஸம்போர்கிஷ்", "கான்டா", "லிம்பர்கிஷ்", "லிங்காலா", "லாவோ", "லிதுவேனியன்", "லுபா-கடாங்கா", "லாட்வியன்", "மேங்க்ஸ்", "மாஸிடோனியன்", "மலகாஸி", "மலாய்", "மலையாளம்", "மால்டிஸ்", "மௌரி", "மராத்தி", "மார்ஷெலீஸ்", "மங்கோலியன்", "நவ்ரூ", "நவாஜோ", "வடக்கு தெபெலே", "நேபாளி", "தோங்கா", "நார்வேஜியன் பொக்மால்", "நார்வேஜியன் நியூநார்ஸ்க்", "நார்வேஜியன்", "சிசுவான் ஈ", "தெற்கு தெபெலே", "ஒக்கிடன்", "ஒஜிப்வா", "சர்ச் ஸ்லாவிக்", "ஒரோமோ", "ஒடியா", "ஒசெட்டிக்", "பஞ்சாபி", "பாலி", "பெர்ஷியன்", "போலிஷ்", "பஷ்தோ", "போர்ச்சுகீஸ்", "க்வெச்சுவா", "ரோமான்ஷ்", "ருண்டி", "ரோமேனியன்", "ரஷியன்", "சமஸ்கிருதம்", "சார்தீனியன்", "சிந்தி", "வடக்கு சமி", "சமோவான்", "சாங்கோ", "செர்பியன்", "ஸ்காட்ஸ் கேலிக்", "ஷோனா", "சிங்களம்", "ஸ்லோவாக்", "ஸ்லோவேனியன்", "சோமாலி", "தெற்கு ஸோதோ", "ஸ்பானிஷ்", "சுண்டானீஸ்", "ஸ்வாஹிலி", "ஸ்வாடீ", "ஸ்வீடிஷ்", "தமிழ்", "தெலுங்கு", "தஜிக்", "தாய்", "டிக்ரின்யா", "திபெத்தியன்", "துருக்மென்", "டாகாலோக்", "ஸ்வானா", "டோங்கான்", "துருக்கிஷ்", "ஸோங்கா", "டாடர்", "ட்வி", "தஹிதியன்", "உய்குர்", "உக்ரைனியன்", "உருது", "உஸ்பெக்", "வென்டா", "வியட்நாமீஸ்", "ஒலாபூக்", "ஒவாலூன்", "வேல்ஷ்", "ஓலோஃப்", "மேற்கு ஃப்ரிஷியன்", "ஹோசா", "யெட்டிஷ்", "யோருபா", "ஜுவாங்", "ஜுலு"];
+
+// te
+static L99: [&str; 184] = ["అఫార్", "అబ్ఖాజియన్", "ఆఫ్రికాన్స్", "అకాన్", "అల్బేనియన్", "అమ్హారిక్", "అరబిక్", "అరగోనిస్", "ఆర్మీనియన్", "అస్సామీస్", "అవారిక్", "అవేస్టాన్", "ఐమారా", "అజర్బైజాని", "బంబారా", "బాష్కిర్", "బాస్క్యూ", "బెలారుషియన్", "బంగ్లా", "", "బిస్లామా", "బోస్నియన్", "బ్రెటన్", "బల్గేరియన్", "బర్మీస్", "కాటలాన్", "చమర్రో", "చెచెన్", "న్యాన్జా", "చైనీస్", "చువాష్", "కోర్నిష్", "కోర్సికన్", "క్రి", "క్రొయేషియన్", "చెక్", "డానిష్", "దివేహి", "డచ్", "జోంఖా", "ఇంగ్లీష్", "ఎస్పెరాంటో", "ఎస్టోనియన్", "యూ", "ఫారోస్", "ఫిజియన్", "ఫిన్నిష్", "ఫ్రెంచ్", "ఫూలా", "గాలిషియన్", "జార్జియన్", "జర్మన్", "గ్రీక్", "గ్వారనీ", "గుజరాతీ", "హైటియన్ క్రియోల్", "హౌసా", "హీబ్రూ", "హెరెరో", "హిందీ", "హిరి మోటు", "హంగేరియన్", "ఇంటర్లింగ్వా", "ఇండోనేషియన్", "ఇంటర్లింగ్", "ఐరిష్", "ఇగ్బో", "ఇనుపైయాక్", "ఈడో", "ఐస్లాండిక్", "ఇటాలియన్", "ఇనుక్టిటుట్", "జపనీస్", "జావనీస్", "కలాల్లిసూట్", "కన్నడ", "కానురి", "కాశ్మీరి", "కజఖ్", "ఖ్మేర్", "కికుయు", "కిన్యర్వాండా", "కిర్గిజ్", "కోమి", "కోంగో", "కొరియన్", "కుర్దిష్", "క్వాన్యామ", "లాటిన్", "లక్సెంబర్గిష్", "గాండా", "లిమ్బర్గిష్", "లింగాల", "లావో", "లిథువేనియన్", "లూబ-కటాంగ", "లాట్వియన్", "మాంక్స్", "మాసిడోనియన్", "మలగాసి", "మలయ్", "మలయాళం", "మాల్టీస్", "మావొరీ", "మరాఠీ", "మార్షలీస్", "మంగోలియన్", "నౌరు", "నవాజొ", "ఉత్తర దెబెలె", "నేపాలీ", "డోంగా", "నార్వేజియన్ బొక్మాల్", "నార్వేజియాన్ న్యోర్స్క్", "నార్వేజియన్", "శిషువన్ ఈ", "దక్షిణ దెబెలె", "ఆక్సిటన్", "చేవా", "చర్చ్ స్లావిక్", "ఒరోమో", "ఒడియా", "ఒసేటిక్", "పంజాబీ", "పాలీ", "పర్షియన్", "పోలిష్", "పాష్టో", "పోర్చుగీస్", "కెచువా", "రోమన్ష్", "రుండి", "రొమేనియన్", "రష్యన్", "సంస్కృతం", "సార్డీనియన్", "సింధీ", "ఉత్తర సామి", "సమోవన్", "సాంగో", "సెర్బియన్", "స్కాటిష్ గేలిక్", "షోన", "సింహళం", "స్లోవక్", "స్లోవేనియన్", "సోమాలి", "దక్షిణ సోతో", "స్పానిష్", "సండానీస్", "స్వాహిలి", "స్వాతి", "స్వీడిష్", "తమిళం", "తెలుగు", "తజిక్", "థాయ్", "టిగ్రిన్యా", "టిబెటన్", "తుర్క్\u{200C}మెన్", "టగలాగ్", "స్వానా", "టాంగాన్", "టర్కిష్", "సోంగా", "టాటర్", "ట్వి", "తహితియన్", "ఉయ్\u{200C}ఘర్", "ఉక్రెయినియన్", "ఉర్దూ", "ఉజ్బెక్", "వెండా", "వియత్నామీస్", "వోలాపుక్", "వాలూన్", "వెల్ష్", "ఉలూఫ్", "పశ్చిమ ఫ్రిసియన్", "షోసా", "ఇడ్డిష్", "యోరుబా", "జువాన్", "జూలూ"];
+
+// tg
+static L100: [&str; 184] = ["", "", "африкаанс", "", "албанӣ", "амҳарӣ", "арабӣ", "", "арманӣ", "ассомӣ", "", "", "", "озарбойҷонӣ", "", "бошқирдӣ", "баскӣ", "белорусӣ", "бинғолӣ", "", "", "босниягӣ", "бретонӣ", "булғорӣ", "бирманӣ", "каталонӣ", "", "", "нянҷа", "хитоӣ", "", "", "корсиканӣ", "", "хорватӣ", "чехӣ", "даниягӣ", "дивеҳӣ", "голландӣ", "дзонгха", "англисӣ", "эсперанто", "эстонӣ", "", "фарерӣ", "", "финӣ", "франсузӣ", "фулаҳ", "галисиягӣ", "гурҷӣ", "немисӣ", "юнонӣ", "гуаранӣ", "гуҷаротӣ", "гаитии креолӣ", "ҳауса", "ибронӣ", "ҳереро", "ҳиндӣ", "", "маҷорӣ", "", "индонезӣ", "", "ирландӣ", "игбо", "", "", "исландӣ", "италиявӣ", "инуктитутӣ", "японӣ", "", "", "каннада", "канурӣ", "кашмирӣ", "қазоқӣ", "кхмерӣ", "", "киняруанда", "қирғизӣ", "", "", "кореягӣ", "курдӣ", "", "лотинӣ", "люксембургӣ", "", "", "", "лаосӣ", "литвонӣ", "", "латишӣ", "", "мақдунӣ", "малагасӣ", "малайӣ", "малаяламӣ", "малтӣ", "маорӣ", "маратҳӣ", "", "муғулӣ", "", "", "", "непалӣ", "", "", "", "норвегӣ", "", "", "окситанӣ", "", "", "оромо", "одия", "", "панҷобӣ", "", "форсӣ", "лаҳистонӣ", "пушту", "португалӣ", "кечуа", "ретороманӣ", "", "руминӣ", "русӣ", "санскрит", "", "синдӣ", "самии шимолӣ", "", "", "сербӣ", "шотландии гэлӣ", "", "сингалӣ", "словакӣ", "словенӣ", "сомалӣ", "", "испанӣ", "", "", "", "шведӣ", "тамилӣ", "телугу", "тоҷикӣ", "тайӣ", "тигриня", "тибетӣ", "туркманӣ", "филиппинӣ", "", "тонганӣ", "туркӣ", "", "тоторӣ", "", "", "ӯйғурӣ", "украинӣ", "урду", "ӯзбекӣ", "венда", "ветнамӣ", "", "", "валлӣ", "волоф", "фризии ғарбӣ", "", "идиш", "йоруба", "", ""];
+
+// th
+static L101: [&str; 184] = ["อะฟาร์", "อับฮาเซีย", "แอฟริกานส์", "อาคาน", "แอลเบเนีย", "อัมฮารา", "อาหรับ", "อารากอน", "อาร์เมเนีย", "อัสสัม", "อาวาร์", "อเวสตะ", "ไอย์มารา", "อาเซอร์ไบจาน", "บัมบารา", "บัชคีร์", "บาสก์", "เบลารุส", "บังกลา", "", "บิสลามา", "บอสเนีย", "เบรตัน", "บัลแกเรีย", "พม่า", "คาตาลัน", "ชามอร์โร", "เชเชน", "เนียนจา", "จีน", "ชูวัช", "คอร์นิช", "คอร์ซิกา", "ครี", "โครเอเชีย", "เช็ก", "เดนมาร์ก", "ธิเวหิ", "ดัตช์", "ซองคา", "อังกฤษ", "เอสเปรันโต", "เอสโตเนีย", "เอเว", "แฟโร", "ฟิจิ", "ฟินแลนด์", "ฝรั่งเศส", "ฟูลาห์", "กาลิเซีย", "จอร์เจีย", "เยอรมัน", "กรีก", "กัวรานี", "คุชราต", "เฮติครีโอล", "เฮาซา", "ฮิบรู", "เฮเรโร", "ฮินดี", "ฮีรีโมตู", "ฮังการี", "อินเตอร์ลิงกัว", "อินโดนีเซีย", "อินเตอร์ลิงกิว", "ไอริช", "อิกโบ", "อีนูเปียก", "อีโด", "ไอซ์แลนด์", "อิตาลี", "อินุกติตุต", "ญี่ปุ่น", "ชวา", "กรีนแลนด์", "กันนาดา", "คานูรี", "แคชเมียร์", "คาซัค", "เขมร", "กีกูยู", "รวันดา", "คีร์กีซ", "โกมิ", "คองโก", "เกาหลี", "เคิร์ด", "กวนยามา", "ละติน", "ลักเซมเบิร์ก", "ยูกันดา", "ลิมเบิร์ก", "ลิงกาลา", "ลาว", "ลิทัวเนีย", "ลูบา-กาตองกา", "ลัตเวีย", "มานซ์", "มาซิโดเนีย", "มาลากาซี", "มาเลย์", "มาลายาลัม", "มอลตา", "เมารี", "มราฐี", "มาร์แชลล์", "มองโกเลีย", "นาอูรู", "นาวาโฮ", "เอ็นเดเบเลเหนือ", "เนปาล", "ดองกา", "นอร์เวย์บุคมอล", "นอร์เวย์นีนอสก์", "นอร์เวย์", "เสฉวนยี่", "เอ็นเดเบเลใต้", "อ็อกซิตัน", "โอจิบวา", "เชอร์ชสลาวิก", "โอโรโม", "โอดิยา", "ออสเซเตีย", "ปัญจาบ", "บาลี", "เปอร์เซีย", "โปแลนด์", "พัชโต", "โปรตุเกส", "เคชวา", "โรแมนซ์", "บุรุนดี", "โรมาเนีย", "รัสเซีย", "สันสกฤต", "ซาร์เดญา", "สินธิ", "ซามิเหนือ", "ซามัว", "ซันโก", "เซอร์เบีย", "เกลิกสกอต", "โชนา", "สิงหล", "สโลวัก", "สโลวีเนีย", "โซมาลี", "โซโทใต้", "สเปน", "ซุนดา", "สวาฮีลี", "สวาติ", "สวีเดน", "ทมิฬ", "เตลูกู", "ทาจิก", "ไทย", "ติกริญญา", "ทิเบต", "เติร์กเมน", "ตากาล็อก", "สวานา", "ตองกา", "ตุรกี", "ซองกา", "ตาตาร์", "ทวิ", "ตาฮิตี", "อุยกูร์", "ยูเครน", "อูรดู", "อุซเบก", "เวนดา", "เวียดนาม", "โวลาพึค", "วอลลูน", "เวลส์", "โวลอฟ", "ฟริเซียนตะวันตก", "คะห์โอซา", "ยิดดิช", "โยรูบา", "จ้วง", "ซูลู"];
+
+// ti
+static L102: [&str; 184] = ["ዓፋር", "ኣብካዝኛ", "ኣፍሪካንስ", "ኣካን", "ኣልባንኛ", "ኣምሓርኛ", "ዓረብኛ", "ኣራጎንኛ", "ኣርሜንኛ", "ኣሳሜዝኛ", "ኣቫርኛ", "", "ኣይማራ", "ኣዘርባጃንኛ", "ባምባራ", "ባሽኪር", "ባስክኛ", "ቤላሩስኛ", "በንጋሊ", "", "ቢስላማ", "ቦዝንኛ", "ብረቶንኛ", "ቡልጋርኛ", "በርምኛ", "ካታላን", "ቻሞሮ", "ቸቸንይና", "ንያንጃ", "ቻይንኛ", "ቹቫሽኛ", "ኮርንኛ", "ኮርስኛ", "", "ክሮኤሽያን", "ቸክኛ", "ዳኒሽ", "ዲቨሂ", "ዳች", "ድዞንግካ", "እንግሊዝኛ", "ኤስፐራንቶ", "ኤስቶንኛ", "ኢው", "ፋሮእይና", "ፊጅያንኛ", "ፊንላንድኛ", "ፈረንሳይኛ", "ፉላ", "ጋሊሽያን", "ጆርጅያንኛ", "ጀርመን", "ግሪኽኛ", "ጓራኒ", "ጉጃራቲ", "ክርዮል ሃይትኛ", "ሃውሳ", "እብራይስጢ", "ሄረሮ", "ሂንዲ", "", "ሃንጋርኛ", "ኢንተርሊንጓ", "ኢንዶነዥኛ", "ኢንተርሊንጔ", "ኣየርላንድኛ", "ኢግቦ", "", "ኢዶ", "ኣይስላንድኛ", "ጥልያን", "ኢናክቲቱት", "ጃፓንኛ", "ጃቫንኛ", "ግሪንላንድኛ", "ካንናዳ", "ካኑሪ", "ካሽሚሪ", "ካዛክ", "ክመር", "ኪኩዩ", "ኪንያርዋንዳ", "ኪርጊዝኛ", "ኮሚ", "", "ኮርይኛ", "ኩርዲሽ", "ክዋንያማ", "ላቲን", "ሉክሰምበርግኛ", "ጋንዳ", "ሊምበርግኛ", "ሊንጋላ", "ላኦ", "ሊትዌንኛ", "ሉባ-ካታንጋ", "ላትቭኛ", "ማንክስ", "መቄዶንኛ", "ማላጋሲ", "ማላይኛ", "ማላያላም", "ማልትኛ", "ማኦሪ", "ማራቲ", "ማርሻሊዝኛ", "ሞንጎልኛ", "ናውርዋንኛ", "ናቫሆ", "ሰሜን ኤንደበለ", "ኔፓሊ", "ኤንዶንጋ", "ኖርወያዊ ቦክማል", "ኖርወያዊ ናይኖርስክ", "ኖርወይኛ", "ሲችዋን ዪ", "ደቡብ ኤንደበለ", "ኦክሲታንኛ", "", "ቤተ-ክርስትያን ስላቭኛ", "ኦሮሞ", "ኦድያ", "ኦሰትኛ", "ፑንጃቢ", "ፓሊ", "ፋርስኛ", "ፖሊሽ", "ፓሽቶ", "ፖርቱጊዝኛ", "ቀችዋ", "ሮማንሽ", "ኪሩንዲ", "ሩማንኛ", "ሩስኛ", "ሳንስክሪት", "ሳርዲንኛ", "ሲንድሂ", "ሰሜናዊ ሳሚ", "ሳሞእኛ", "ሳንጎ", "ሰርብኛ", "ስኮትላንዳዊ ጋኤሊክኛ", "ሾና", "ሲንሃላ", "ስሎቫክኛ", "ስሎቬንኛ", "ሶማሊ", "ደቡባዊ ሶቶ", "ስጳንኛ", "ሱዳንኛ", "ስዋሂሊ", "ስዋዚ", "ስዊድንኛ", "ታሚል", "ተሉጉ", "ታጂክኛ", "ታይኛ", "ትግርኛ", "ቲበታንኛ", "ቱርክመንኛ", "ፊሊፒንኛ", "ስዋና", "ቶንጋንኛ", "ቱርክኛ", "ሶንጋ", "ታታር", "ትዊ", "ታሂትኛ", "ኡይጉር", "ዩክረይንኛ", "ኡርዱ", "ኡዝበክኛ", "ቨንዳ", "ቬትናምኛ", "ቮላፑክ", "ዋሎን", "ዌልስኛ", "ዎሎፍ", "ምዕራባዊ ፍሪስኛ", "ኮሳ", "ይሁድኛ", "ዮሩባ", "ዙኣንግ", "ዙሉ"];
+
+// tk
+static L103: [&str; 184] = ["afar dili", "abhaz dili", "afrikaans dili", "akan dili", "alban dili", "amhar dili", "arap dili", "aragon dili", "ermeni dili", "assam dili", "awar dili", "", "aýmara dili", "azerbaýjan dili", "bamana", "başgyrt dili", "bask dili", "belarus dili", "bengal dili", "", "bislama dili", "bosniýa dili", "breton dili", "bolgar dili", "birma dili", "katalan dili", "çamorro", "çeçen dili", "nýanja dili", "hytaý dili", "çuwaş dili", "korn dili", "korsikan dili", "", "horwat dili", "çeh dili", "daniýa dili", "diwehi dili", "niderland dili", "dzong-ke dili", "iňlis dili", "esperanto dili", "eston dili", "ewe dili", "farer dili", "fiji dili", "fin dili", "fransuz dili", "fula dili", "galisiý dili", "gruzin dili", "nemes dili", "grek dili", "guarani dili", "gujarati dili", "gaiti kreol dili", "hausa dili", "ýewreý dili", "gerero dili", "hindi dili", "", "wenger dili", "interlingwa dili", "indonez dili", "interlingwe dili", "irland dili", "igbo dili", "", "ido dili", "island dili", "italýan dili", "inuktitut dili", "ýapon dili", "ýawa dili", "grenland dili", "kannada dili", "kanuri", "kaşmiri dili", "gazak dili", "khmer dili", "kikuýu dili", "kinýaruanda dili", "gyrgyz dili", "komi dili", "", "koreý dili", "kürt dili", "kwanýama dili", "latyn dili", "lýuksemburg dili", "ganda dili", "limburg dili", "lingala dili", "laos dili", "litwa dili", "luba-katanga dili", "latyş dili", "men dili", "makedon dili", "malagasiý dili", "malaý dili", "malaýalam dili", "malta dili", "maori dili", "marathi dili", "marşall dili", "mongol dili", "nauru dili", "nawaho dili", "demirgazyk ndebele dili", "nepal dili", "ndonga dili", "norwegiýa bukmol dili", "norwegiýa nýunorsk dili", "norweg dili", "syçuan-i dili", "günorta ndebele dili", "oksitan dili", "", "buthana slaw dili", "oromo dili", "oriýa dili", "osetin dili", "penjab dili", "", "pars dili", "polýak dili", "puştu dili", "portugal dili", "keçua dili", "retoroman dili", "rundi dili", "rumyn dili", "rus dili", "sanskrit dili", "sardin dili", "sindhi dili", "demirgazyk saam dili", "samoa dili", "sango dili", "serb dili", "şotland kelt dili", "şona dili", "singal dili", "slowak dili", "slowen dili", "somali dili", "günorta soto dili", "ispan dili", "sundan dili", "suahili dili", "swati dili", "şwed dili", "tamil dili", "telugu dili", "täjik dili", "taý dili", "tigrinýa dili", "tibet dili", "türkmen dili", "filippin dili", "tswana dili", "tongan dili", "türk dili", "tsonga dili", "tatar dili", "", "taiti dili", "uýgur dili", "ukrain dili", "urdu", "özbek dili", "wenda dili", "wýetnam dili", "wolapýuk dili", "wallon dili", "walliý dili", "wolof dili", "günbatar friz dili", "kosa dili", "idiş dili", "ýoruba dili", "çžuan dili", "zulu dili"];
+
+// tl
+static L104: [&str; 184] = ["Afar", "Abkhazian", "Afrikaans", "Akan", "Albanian", "Amharic", "Arabic", "Aragonese", "Armenian", "Assamese", "Avaric", "", "Aymara", "Azerbaijani", "Bambara", "Bashkir", "Basque", "Belarusian", "Bangla", "", "Bislama", "Bosnian", "Breton", "Bulgarian", "Burmese", "Catalan", "Chamorro", "Chechen", "Nyanja", "Chinese", "Chuvash", "Cornish", "Corsican", "", "Croatian", "Czech", "Danish", "Divehi", "Dutch", "Dzongkha", "Ingles", "Esperanto", "Estonian", "Ewe", "Faroese", "Fijian", "Finnish", "French", "Fulah", "Galician", "Georgian", "German", "Greek", "Guarani", "Gujarati", "Haitian", "Hausa", "Hebrew", "Herero", "Hindi", "", "Hungarian", "Interlingua", "Indonesian", "Interlingue", "Irish", "Igbo", "", "Ido", "Icelandic", "Italian", "Inuktitut", "Japanese", "Javanese", "Kalaallisut", "Kannada", "Kanuri", "Kashmiri", "Kazakh", "Khmer", "Kikuyu", "Kinyarwanda", "Kyrgyz", "Komi", "Kongo", "Korean", "Kurdish", "Kuanyama", "Latin", "Luxembourgish", "Ganda", "Limburgish", "Lingala", "Lao", "Lithuanian", "Luba-Katanga", "Latvian", "Manx", "Macedonian", "Malagasy", "Malay", "Malayalam", "Maltese", "Māori", "Marathi", "Marshallese", "Mongolian", "Nauru", "Navajo", "Hilagang Ndebele", "Nepali", "Ndonga", "Norwegian Bokmål", "Norwegian Nynorsk", "Norwegian", "Sichuan Yi", "South Ndebele", "Occitan", "", "Church Slavic", "Oromo", "Odia", "Ossetic", "Punjabi", "", "Persian", "Polish", "Pashto", "Portuguese", "Quechua", "Romansh", "Rundi", "Romanian", "Russian", "Sanskrit", "Sardinian", "Sindhi", "Hilagang Sami", "Samoan", "Sango", "Serbian", "Scottish Gaelic", "Shona", "Sinhala", "Slovak", "Slovenian", "Somali", "Katimugang Sotho", "Spanish", "Sundanese", "Swahili", "Swati", "Swedish", "Tamil", "Telugu", "Tajik", "Thai", "Tigrinya", "Tibetan", "Turkmen", "Tagalog", "Tswana", "Tongan", "Turkish", "Tsonga", "Tatar", "Twi", "Tahitian", "Uyghur", "Ukrainian", "Urdu", "Uzbek", "Venda", "Vietnamese", "Volapük", "Walloon", "Welsh", "Wolof", "Western Frisian", "Xhosa", "Yiddish", "Yoruba", "Zhuang", "Zulu"];
+
+// tn
+static L105: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Sekgoa", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Setswana", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// to
+static L106: [&str; 184] = ["lea fakaʻafāla", "lea fakaʻapakasia", "lea fakaʻafilikana", "lea fakaʻakani", "lea fakaʻalapēnia", "lea fakaʻameliki", "lea fakaʻalepea", "lea fakaʻalakoni", "lea fakaʻāmenia", "lea fakaʻasamia", "lea fakaʻavaliki", "lea fakaʻavesitani", "lea fakaʻaimala", "lea fakaʻasepaisani", "lea fakapamipala", "lea fakapasikili", "lea fakapāsiki", "lea fakapelalusi", "lea fakapāngilā", "", "lea fakapisilama", "lea fakaposinia", "lea fakapeletoni", "lea fakapulukalia", "lea fakapema", "lea fakakatalani", "lea fakakamolo", "lea fakasese", "lea fakanianisa", "lea fakasiaina", "lea fakasuvasa", "lea fakakoniuali", "lea fakakōsika", "lea fakakelī", "lea fakakuloisia", "lea fakaseki", "lea fakatenimaʻake", "lea fakativehi", "lea fakahōlani", "lea fakatisōngika", "lea fakapālangi", "lea fakaʻesipulanito", "lea fakaʻesitōnia", "lea fakaʻeue", "lea fakafaloe", "lea fakafisi", "lea fakafinilani", "lea fakafalanisē", "lea fakafulā", "lea fakakalisia", "lea fakaseōsia", "lea fakasiamane", "lea fakakalisi", "lea fakakualani", "lea fakakutalati", "lea fakahaiti", "lea fakahausa", "lea fakahepelū", "lea fakahelelo", "lea fakahinitī", "lea fakahili-motu", "lea fakahungakalia", "lea fakavahaʻalea", "lea fakaʻinitōnesia", "lea fakavahaʻalingikē", "lea fakaʻaelani", "lea fakaʻikipō", "lea fakaʻinupiaki", "lea fakaʻito", "lea fakaʻaisilani", "lea fakaʻītali", "lea fakaʻinuketītuti", "lea fakasiapani", "lea fakasava", "lea fakakalaʻalisuti", "lea fakakanata", "lea fakakanuli", "lea fakakāsimila", "lea fakakasaki", "lea fakakamipōtia", "lea fakakikuiu", "lea fakakiniāuanita", "lea fakakīsisi", "lea fakakomi", "lea fakakongikō", "lea fakakōlea", "lea fakakulitī", "lea fakakuaniama", "lea fakalatina", "lea fakalakisimipeki", "lea fakakanita", "lea fakalimipūliki", "lea lingikala", "lea fakalau", "lea fakalituania", "lea fakalupa-katanga", "lea fakalativia", "lea fakamangikī", "lea fakamasitōnia", "lea fakamalakasi", "lea fakamalei", "lea fakaʻinitia-malāialami", "lea fakamalita", "lea fakamāuli", "lea fakamalati", "lea fakamāsolo", "lea fakamongokōlia", "lea fakanaulu", "lea fakanavaho", "lea fakanetepele-tokelau", "lea fakanepali", "lea fakanetongikā", "lea fakanouaē-pokimali", "lea fakanoauē-ninosiki", "lea fakanouaē", "lea fakasisiuani-ī", "lea fakanetepele-tonga", "lea fakaʻokitane", "lea fakaʻosipiuā", "lea fakasilavia-fakasiasi", "lea fakaʻolomo", "lea fakaʻotia", "lea fakaʻosetiki", "lea fakapūnusapi", "lea fakapāli", "lea fakapēsia", "lea fakapolani", "lea fakapasitō", "lea fakapotukali", "lea fakakuetisa", "lea fakalaito-lomēnia", "lea fakaluaniti", "lea fakalōmenia", "lea fakalūsia", "lea fakasanisukuliti", "lea fakasaletīnia", "lea fakasīniti", "lea fakasami-tokelau", "lea fakahaʻamoa", "lea fakasangikō", "lea fakasēpia", "lea fakakaeliki", "lea fakasiona", "lea fakasingihala", "lea fakasolāvaki", "lea fakasolovenia", "lea fakasomali", "lea fakasoto-tonga", "lea fakasipēnisi", "lea fakasunitā", "lea fakasuahili", "lea fakasuati", "lea fakasuēteni", "lea fakatamili", "lea fakaʻinitia-teluku", "lea fakatāsiki", "lea fakatailani", "lea fakatikilinia", "lea fakatipeti", "lea fakatēkimeni", "lea fakatakāloka", "lea fakatisuana", "lea fakatonga", "lea fakatoake", "lea fakatisonga", "lea fakatatale", "lea fakatusuī", "lea fakatahiti", "lea fakaʻuikūli", "lea fakaʻūkalaʻine", "lea fakaʻūtū", "lea fakaʻusipeki", "lea fakavenitā", "lea fakavietinami", "lea fakavolapiki", "lea fakaʻualonia", "lea fakauēlesi", "lea fakaʻuolofo", "lea fakafilisia-hihifo", "lea fakatōsa", "lea fakaītisi", "lea fakaʻiōlupa", "lea fakasuangi", "lea fakasulu"];
+
+// tr
+static L107: [&str; 184] = ["Afar", "Abhazca", "Afrikaanca", "Akan", "Arnavutça", "Amharca", "Arapça", "Aragonca", "Ermenice", "Assamca", "Avar dili", "Avestçe", "Aymara", "Azerbaycan dili", "Bambara", "Başkırtça", "Baskça", "Belarusça", "Bengalce", "", "Bislama", "Boşnakça", "Bretonca", "Bulgarca", "Birman dili", "Katalanca", "Çamorro dili", "Çeçence", "Nyanja", "Çince", "Çuvaşça", "Kernevekçe", "Korsikaca", "Krice", "Hırvatça", "Çekçe", "Danca", "Divehi dili", "Felemenkçe", "Dzongkha", "İngilizce", "Esperanto", "Estonca", "Ewe", "Faroe dili", "Fiji dili", "Fince", "Fransızca", "Fula dili", "Galiçyaca", "Gürcüce", "Almanca", "Yunanca", "Guarani dili", "Güceratça", "Haiti Kreyolu", "Hausa dili", "İbranice", "Herero dili", "Hintçe", "Hiri Motu", "Macarca", "İnterlingua", "Endonezce", "Interlingue", "İrlandaca", "İbo dili", "İnyupikçe", "Ido", "İzlandaca", "İtalyanca", "İnuktitut dili", "Japonca", "Cava dili", "Grönland dili", "Kannada dili", "Kanuri dili", "Keşmir dili", "Kazakça", "Khmer dili", "Kikuyu", "Kinyarwanda", "Kırgızca", "Komi", "Kongo dili", "Korece", "Kürtçe", "Kuanyama", "Latince", "Lüksemburgca", "Ganda", "Limburgca", "Lingala", "Lao dili", "Litvanca", "Luba-Katanga", "Letonca", "Man dili", "Makedonca", "Malgaşça", "Malayca", "Malayalam dili", "Maltaca", "Maori dili", "Marathi dili", "Marshall Adaları dili", "Moğolca", "Nauru dili", "Navaho dili", "Kuzey Ndebele", "Nepalce", "Ndonga", "Norveççe Bokmål", "Norveççe Nynorsk", "Norveççe", "Sichuan Yi", "Güney Ndebele", "Oksitan dili", "Ojibva dili", "Kilise Slavcası", "Oromo dili", "Oriya dili", "Osetçe", "Pencapça", "Pali", "Farsça", "Lehçe", "Peştuca", "Portekizce", "Keçuva dili", "Romanşça", "Kirundi", "Rumence", "Rusça", "Sanskrit", "Sardunya dili", "Sindhi dili", "Kuzey Laponcası", "Samoa dili", "Sango", "Sırpça", "İskoç Gaelcesi", "Şona dili", "Sinhali dili", "Slovakça", "Slovence", "Somalice", "Güney Sotho dili", "İspanyolca", "Sunda dili", "Svahili dili", "Sisvati", "İsveççe", "Tamilce", "Telugu dili", "Tacikçe", "Tayca", "Tigrinya dili", "Tibetçe", "Türkmence", "Tagalogca", "Setsvana", "Tonga dili", "Türkçe", "Tsonga", "Tatarca", "Tvi", "Tahiti dili", "Uygurca", "Ukraynaca", "Urduca", "Özbekçe", "Venda dili", "Vietnamca", "Volapük", "Valonca", "Galce", "Volofça", "Batı Frizcesi", "Zosa dili", "Yidiş", "Yorubaca", "Zhuangca", "Zuluca"];
+
+// tt
+static L108: [&str; 184] = ["", "", "африкаанс", "", "албан", "амхар", "гарәпчә", "", "әрмән", "ассам", "", "", "", "әзәрбайҗан", "", "башкорт", "баск", "белорус", "бенгалча", "", "", "босния", "бретон", "болгар", "бирма", "каталан", "", "", "ньянҗа", "кытайча", "", "", "корсика", "", "хорват", "чех", "дания", "мальдив", "голланд", "дзонг-кха", "инглиз", "эсперанто", "эстон", "", "фарер", "", "фин", "француз", "фула", "галисия", "грузин", "алман", "грек", "гуарани", "гуҗарати", "гаити креол", "хауса", "яһүд", "гереро", "һинд", "", "венгр", "", "индонезия", "", "ирланд", "игбо", "", "", "исланд", "итальян", "инуктикут", "япон", "", "", "каннада", "канури", "кашмири", "казакъ", "кхмер", "", "руанда", "кыргыз", "", "", "корея", "көрд", "", "латин", "люксембург", "", "", "", "лаос", "литва", "", "латыш", "", "македон", "малагаси", "малай", "малаялам", "мальта", "маори", "маратхи", "", "монгол", "", "", "", "непали", "", "", "", "", "", "", "окситан", "", "", "оромо", "ория", "", "пәнҗаби", "", "фарсы", "поляк", "пушту", "португал", "кечуа", "ретороман", "", "румын", "рус", "санскрит", "", "синдһи", "төньяк саам", "", "", "серб", "шотланд гэль", "", "сингал", "словак", "словен", "сомали", "", "испан", "", "", "", "швед", "тамил", "телугу", "таҗик", "тай", "тигринья", "тибет", "төрекмән", "филиппин", "", "тонга", "төрек", "", "татар", "", "", "уйгыр", "украин", "урду", "үзбәк", "венда", "вьетнам", "", "", "уэльс", "волоф", "", "", "идиш", "йоруба", "", ""];
+
+// ug
+static L109: [&str; 184] = ["ئافارچە", "ئابخازچە", "ئافرىكانچە", "ئاكانچە", "ئالبانچە", "ئامھارچە", "ئەرەبچە", "ئاراگونچە", "ئەرمېنچە", "ئاسامچە", "ئاۋارچە", "ئاۋېستاچە", "ئايماراچە", "ئەزەربەيجانچە", "بامباراچە", "باشقىرتچە", "باسكىچە", "بېلارۇسچە", "بېنگالچە", "", "بىسلاماچە", "بوسىنچە", "بىرېتونچە", "بۇلغارچە", "بىرماچە", "كاتالانچە", "چامورروچە", "چېچىنچە", "نىيانجاچە", "خەنزۇچە", "چۇۋاشچە", "كورنىشچە", "كورسۇچە", "كرىچە", "كىرودىچە", "چېخچە", "دانىشچە", "دىۋېخچە", "گوللاندچە", "زوڭخاچە", "ئىنگلىزچە", "ئېسپرانتوچە", "ئېستونچە", "ئېۋېچە", "فائېروچە", "فىجىچە", "فىنچە", "فىرانسۇزچە", "فۇلاھچە", "گالىچە", "گىرۇزچە", "گېرمانچە", "گىرېكچە", "گۇئارانىچە", "گۇجاراتچە", "ھايتىچە", "خائۇساچە", "ئىبرانىيچە", "خېرېروچە", "ھىندىچە", "ھىرى موتۇچە", "ۋېنگىرچە", "ئارىلىق تىل", "ھىندونېزچە", "ئىنتىرلىڭچە", "ئىرېلاندچە", "ئىگبوچە", "ئىنۇپىكچە", "ئىدوچە", "ئىسلاندچە", "ئىتالىيانچە", "ئىنۇكتىتۇتچە", "ياپونچە", "ياۋاچە", "گىرېنلاندچە", "كانناداچە", "كانۇرچە", "كەشمىرچە", "قازاقچە", "كىمېرچە", "كىكۇيۇچە", "كېنىيەرىۋانداچە", "قىرغىزچە", "كومىچە", "كونگوچە", "كورېيەچە", "كۇردچە", "كىۋانياماچە", "لاتىنچە", "لىيۇكسېمبۇرگچە", "گانداچە", "لىمبۇرگچە", "لىنگالاچە", "لائوسچە", "لىتۋانىچە", "لۇبا-كاتانگاچە", "لاتچە", "مانچە", "ماكېدونچە", "مالاگاسچە", "مالايچە", "مالايالامچە", "مالتاچە", "ماۋرىچە", "ماراتىچە", "مارشالچە", "موڭغۇلچە", "ناۋرۇچە", "ناۋاخوچە", "شىمالى ندەبەلەچە", "نېپالچە", "ندونگاچە", "نورۋىگىيە بوكمالچە", "يېڭى نورۋېگچە", "نورۋېگچە", "يىچە (سىچۈەن)", "جەنۇبى ندەبەلەچە", "ئوكسىتچە", "ئوجىبۋاچە", "قەدىمكى سلاۋيانچە", "ئوروموچە", "ئودىياچە", "ئوسسېتچەچە", "پەنجابچە", "پالىچە", "پارسچە", "پولەكچە", "پۇشتۇچە", "پورتۇگالچە", "كېچىۋاچە", "رومانسچە", "رۇندىچە", "رومىنچە", "رۇسچە", "سانسكرىتچە", "ساردىنىيەچە", "سىندىچە", "شىمالىي سامىچە", "ساموئاچە", "سانگوچە", "سېربچە", "شوتلاندىيە گايلچىسى", "شوناچە", "سىنگالچە", "سىلوۋاكچە", "سىلوۋېنچە", "سومالىچە", "سوتوچە", "ئىسپانچە", "سۇنداچە", "سىۋاھىلچە", "سىۋاتىچە", "شىۋېدچە", "تامىلچە", "تېلۇگۇچە", "تاجىكچە", "تايلاندچە", "تىگرىنياچە", "تىبەتچە", "تۈركمەنچە", "تاگالوگچە", "سىۋاناچە", "تونگانچە", "تۈركچە", "سونگاچە", "تاتارچە", "تىۋىچە", "تاختىچە", "ئۇيغۇرچە", "ئۇكرائىنچە", "ئوردۇچە", "ئۆزبېكچە", "ۋېنداچە", "ۋىيېتنامچە", "ۋولاپۇكچە", "ۋاللۇنچە", "ۋېلشچە", "ۋولوفچە", "غەربىي فىرسچە", "خوساچە", "يىددىشچە", "يورۇباچە", "جۇاڭچە", "زۇلۇچە"];
+
+// uk
+static L110: [&str; 184] = ["афарська", "абхазька", "африкаанс", "акан", "албанська", "амхарська", "арабська", "арагонська", "вірменська", "ассамська", "аварська", "авестійська", "аймара", "азербайджанська", "бамбара", "башкирська", "баскська", "білоруська", "бенгальська", "", "біслама", "боснійська", "бретонська", "болгарська", "бірманська", "каталонська", "чаморро", "чеченська", "ньянджа", "китайська", "чуваська", "корнська", "корсиканська", "крі", "хорватська", "чеська", "данська", "дивехі", "нідерландська", "дзонг-ке", "англійська", "есперанто", "естонська", "еве", "фарерська", "фіджі", "фінська", "французька", "фула", "галісійська", "грузинська", "німецька", "грецька", "гуарані", "гуджараті", "гаїтянська креольська", "хауса", "іврит", "гереро", "гінді", "хірі-моту", "угорська", "інтерлінгва", "індонезійська", "інтерлінгве", "ірландська", "ігбо", "інупіак", "ідо", "ісландська", "італійська", "інуктитут", "японська", "яванська", "калааллісут", "каннада", "канурі", "кашмірська", "казахська", "кхмерська", "кікуйю", "кіньяруанда", "киргизька", "комі", "конґолезька", "корейська", "курдська", "кунама", "латинська", "люксембурзька", "ганда", "лімбургійська", "лінгала", "лаоська", "литовська", "луба-катанга", "латиська", "менкська", "македонська", "малагасійська", "малайська", "малаялам", "мальтійська", "маорі", "маратхі", "маршалльська", "монгольська", "науру", "навахо", "північна ндебеле", "непальська", "ндонга", "норвезька (букмол)", "норвезька (нюношк)", "норвезька", "сичуаньська ї", "ндебелє південна", "окситанська", "оджібва", "церковнословʼянська", "оромо", "одія", "осетинська", "панджабі", "палі", "перська", "польська", "пушту", "португальська", "кечуа", "ретороманська", "рунді", "румунська", "російська", "санскрит", "сардинська", "синдхі", "північносаамська", "самоанська", "санго", "сербська", "шотландська гельська", "шона", "сингальська", "словацька", "словенська", "сомалі", "південна сото", "іспанська", "сунданська", "суахілі", "сисваті", "шведська", "тамільська", "телугу", "таджицька", "тайська", "тигринья", "тибетська", "туркменська", "тагальська", "тсвана", "тонганська", "турецька", "тсонга", "татарська", "тві", "таїтянська", "уйгурська", "українська", "урду", "узбецька", "венда", "вʼєтнамська", "волапюк", "валлонська", "валлійська", "волоф", "західнофризька", "кхоса", "їдиш", "йоруба", "чжуан", "зулуська"];
+
+// ur
+static L111: [&str; 184] = ["افار", "ابقازیان", "افریقی", "اکان", "البانی", "امہاری", "عربی", "اراگونیز", "آرمینیائی", "آسامی", "اواری", "", "ایمارا", "آذربائیجانی", "بمبارا", "باشکیر", "باسکی", "بیلاروسی", "بنگلہ", "", "بسلاما", "بوسنیائی", "بریٹن", "بلغاری", "برمی", "کیٹالان", "چیمارو", "چیچن", "نیانجا", "چینی", "چوواش", "کورنش", "کوراسیکن", "", "کراتی", "چیک", "ڈینش", "ڈیویہی", "ڈچ", "ژونگکھا", "انگریزی", "ایسپرانٹو", "اسٹونین", "ایو", "فیروئیز", "فجی", "فینیش", "فرانسیسی", "فولہ", "گالیشیائی", "جارجیائی", "جرمن", "یونانی", "گُارانی", "گجراتی", "ہیتی", "ہؤسا", "عبرانی", "ہریرو", "ہندی", "", "ہنگیرین", "بین لسانیات", "انڈونیثیائی", "غربی", "آئرش", "اِگبو", "", "ایڈو", "آئس لینڈک", "اطالوی", "اینُکٹیٹٹ", "جاپانی", "جاوی", "کالاليست", "کنّاڈا", "کنوری", "کشمیری", "قزاخ", "خمیر", "کیکویو", "کینیاروانڈا", "کرغیزی", "کومی", "کانگو", "کوریائی", "کردی", "کونیاما", "لاطینی", "لکسمبرگیش", "گینڈا", "لیمبرگش", "لِنگَلا", "لاؤ", "لیتھوینین", "لبا-کاتانجا", "لیٹوین", "مینکس", "مقدونیائی", "ملاگاسی", "مالے", "ملیالم", "مالٹی", "ماؤری", "مراٹھی", "مارشلیز", "منگولین", "ناؤرو", "نواجو", "شمالی دبیل", "نیپالی", "نڈونگا", "نارویجین بوکمل", "نارویجین نینورسک", "نارویجین", "سچوان ای", "جنوبی نڈیبیلی", "آکسیٹان", "", "چرچ سلاوک", "اورومو", "اڑیہ", "اوسیٹک", "پنجابی", "", "فارسی", "پولش", "پشتو", "پُرتگالی", "کویچوآ", "رومانش", "رونڈی", "رومینین", "روسی", "سنسکرت", "سردینین", "سندھی", "شمالی سامی", "ساموآن", "ساںغو", "سربین", "سکاٹش گیلک", "شونا", "سنہالا", "سلوواک", "سلووینیائی", "صومالی", "جنوبی سوتھو", "ہسپانوی", "سنڈانیز", "سواحلی", "سواتی", "سویڈش", "تمل", "تیلگو", "تاجک", "تھائی", "ٹگرینیا", "تبتی", "ترکمان", "ٹیگا لوگ", "سوانا", "ٹونگن", "ترکی", "زونگا", "تاتار", "توی", "تاہیتی", "یوئگہر", "یوکرینیائی", "اردو", "ازبیک", "وینڈا", "ویتنامی", "وولاپوک", "والون", "ویلش", "وولوف", "مغربی فریسیئن", "ژوسا", "یدش", "یوروبا", "ژوانگی", "زولو"];
+
+// uz
+static L112: [&str; 184] = ["afar", "abxaz", "afrikaans", "akan", "alban", "amxar", "arab", "aragon", "arman", "assam", "avar", "", "aymara", "ozarbayjon", "bambara", "boshqird", "bask", "belarus", "bengal", "", "bislama", "bosniy", "breton", "bolgar", "birman", "katalan", "chamorro", "chechen", "cheva", "xitoy", "chuvash", "korn", "korsikan", "", "xorvat", "chex", "dan", "divexi", "niderland", "dzongka", "inglizcha", "esperanto", "estoncha", "eve", "farercha", "fiji", "fincha", "fransuzcha", "fula", "galisiy", "gruzincha", "nemischa", "grek", "guarani", "gujarot", "gaityan", "xausa", "ivrit", "gerero", "hind", "", "venger", "interlingva", "indonez", "interlingve", "irland", "igbo", "", "ido", "island", "italyan", "inuktitut", "yapon", "yavan", "grenland", "kannada", "kanuri", "kashmircha", "qozoqcha", "xmer", "kikuyu", "kinyaruanda", "qirgʻizcha", "komi", "", "koreyscha", "kurdcha", "kvanyama", "lotincha", "lyuksemburgcha", "ganda", "limburg", "lingala", "laos", "litva", "luba-katanga", "latishcha", "men", "makedon", "malagasiy", "malay", "malayalam", "maltiy", "maori", "maratxi", "marshall", "mongol", "nauru", "navaxo", "shimoliy ndebele", "nepal", "ndonga", "norveg-bokmal", "norveg-nyunorsk", "norveg", "sichuan", "janubiy ndebel", "oksitan", "", "slavyan (cherkov)", "oromo", "oriya", "osetin", "panjobcha", "", "fors", "polyakcha", "pushtu", "portugalcha", "kechua", "romansh", "rundi", "rumincha", "ruscha", "sanskrit", "sardin", "sindhi", "shimoliy saam", "samoa", "sango", "serbcha", "shotland-gel", "shona", "singal", "slovakcha", "slovencha", "somalicha", "janubiy soto", "ispancha", "sundan", "suaxili", "svati", "shved", "tamil", "telugu", "tojik", "tay", "tigrinya", "tibet", "turkman", "filipincha", "tsvana", "tongan", "turk", "tsonga", "tatar", "", "taiti", "uyg‘ur", "ukrain", "urdu", "o‘zbek", "venda", "vyetnam", "volapyuk", "vallon", "valliy", "volof", "g‘arbiy friz", "kxosa", "idish", "yoruba", "Chjuan", "zulu"];
+
+// vi
+static L113: [&str; 184] = ["Tiếng Afar", "Tiếng Abkhazia", "Tiếng Hà Lan (Nam Phi)", "Tiếng Akan", "Tiếng Albania", "Tiếng Amharic", "Tiếng Ả Rập", "Tiếng Aragon", "Tiếng Armenia", "Tiếng Assam", "Tiếng Avaric", "Tiếng Avestan", "Tiếng Aymara", "Tiếng Azerbaijan", "Tiếng Bambara", "Tiếng Bashkir", "Tiếng Basque", "Tiếng Belarus", "Tiếng Bangla", "", "Tiếng Bislama", "Tiếng Bosnia", "Tiếng Breton", "Tiếng Bulgaria", "Tiếng Miến Điện", "Tiếng Catalan", "Tiếng Chamorro", "Tiếng Chechen", "Tiếng Nyanja", "Tiếng Trung", "Tiếng Chuvash", "Tiếng Cornwall", "Tiếng Corsica", "Tiếng Cree", "Tiếng Croatia", "Tiếng Séc", "Tiếng Đan Mạch", "Tiếng Divehi", "Tiếng Hà Lan", "Tiếng Dzongkha", "Tiếng Anh", "Tiếng Quốc Tế Ngữ", "Tiếng Estonia", "Tiếng Ewe", "Tiếng Faroe", "Tiếng Fiji", "Tiếng Phần Lan", "Tiếng Pháp", "Tiếng Fulah", "Tiếng Galician", "Tiếng Georgia", "Tiếng Đức", "Tiếng Hy Lạp", "Tiếng Guarani", "Tiếng Gujarati", "Tiếng Haiti", "Tiếng Hausa", "Tiếng Do Thái", "Tiếng Herero", "Tiếng Hindi", "Tiếng Hiri Motu", "Tiếng Hungary", "Tiếng Khoa Học Quốc Tế", "Tiếng Indonesia", "Tiếng Interlingue", "Tiếng Ireland", "Tiếng Igbo", "Tiếng Inupiaq", "Tiếng Ido", "Tiếng Iceland", "Tiếng Italy", "Tiếng Inuktitut", "Tiếng Nhật", "Tiếng Java", "Tiếng Kalaallisut", "Tiếng Kannada", "Tiếng Kanuri", "Tiếng Kashmir", "Tiếng Kazakh", "Tiếng Khmer", "Tiếng Kikuyu", "Tiếng Kinyarwanda", "Tiếng Kyrgyz", "Tiếng Komi", "Tiếng Kongo", "Tiếng Hàn", "Tiếng Kurd", "Tiếng Kuanyama", "Tiếng La-tinh", "Tiếng Luxembourg", "Tiếng Ganda", "Tiếng Limburg", "Tiếng Lingala", "Tiếng Lào", "Tiếng Litva", "Tiếng Luba-Katanga", "Tiếng Latvia", "Tiếng Manx", "Tiếng Macedonia", "Tiếng Malagasy", "Tiếng Mã Lai", "Tiếng Malayalam", "Tiếng Malta", "Tiếng Māori", "Tiếng Marathi", "Tiếng Marshall", "Tiếng Mông Cổ", "Tiếng Nauru", "Tiếng Navajo", "Tiếng Ndebele Miền Bắc", "Tiếng Nepal", "Tiếng Ndonga", "Tiếng Na Uy (Bokmål)", "Tiếng Na Uy (Nynorsk)", "Tiếng Na Uy", "Tiếng Di Tứ Xuyên", "Tiếng Ndebele Miền Nam", "Tiếng Occitan", "Tiếng Ojibwa", "Tiếng Slavơ Nhà thờ", "Tiếng Oromo", "Tiếng Odia", "Tiếng Ossetic", "Tiếng Punjab", "Tiếng Pali", "Tiếng Ba Tư", "Tiếng Ba Lan", "Tiếng Pashto", "Tiếng Bồ Đào Nha", "Tiếng Quechua", "Tiếng Romansh", "Tiếng Rundi", "Tiếng Romania", "Tiếng Nga", "Tiếng Phạn", "Tiếng Sardinia", "Tiếng Sindhi", "Tiếng Sami Miền Bắc", "Tiếng Samoa", "Tiếng Sango", "Tiếng Serbia", "Tiếng Gael Scotland", "Tiếng Shona", "Tiếng Sinhala", "Tiếng Slovak", "Tiếng Slovenia", "Tiếng Somali", "Tiếng Nam Sotho", "Tiếng Tây Ban Nha", "Tiếng Sunda", "Tiếng Swahili", "Tiếng Swati", "Tiếng Thụy Điển", "Tiếng Tamil", "Tiếng Telugu", "Tiếng Tajik", "Tiếng Thái", "Tiếng Tigrinya", "Tiếng Tây Tạng", "Tiếng Turkmen", "Tiếng Tagalog", "Tiếng Tswana", "Tiếng Tonga", "Tiếng Thổ Nhĩ Kỳ", "Tiếng Tsonga", "Tiếng Tatar", "Tiếng Twi", "Tiếng Tahiti", "Tiếng Uyghur", "Tiếng Ukraina", "Tiếng Urdu", "Tiếng Uzbek", "Tiếng Venda", "Tiếng Việt", "Tiếng Volapük", "Tiếng Walloon", "Tiếng Wales", "Tiếng Wolof", "Tiếng Frisia", "Tiếng Xhosa", "Tiếng Yiddish", "Tiếng Yoruba", "Tiếng Choang", "Tiếng Zulu"];
+
+// cy
+static L114: [&str; 184] = ["Affareg", "Abchaseg", "Affricaneg", "Acaneg", "Albaneg", "Amhareg", "Arabeg", "Aragoneg", "Armeneg", "Asameg", "Afareg", "Afestaneg", "Aymareg", "Aserbaijaneg", "Bambareg", "Bashcorteg", "Basgeg", "Belarwseg", "Bengaleg", "", "Bislama", "Bosnieg", "Llydaweg", "Bwlgareg", "Byrmaneg", "Catalaneg", "Tsiamorro", "Tsietsieneg", "Nianja", "Tsieinëeg", "Tshwfasheg", "Cernyweg", "Corseg", "Cri", "Croateg", "Tsieceg", "Daneg", "Difehi", "Iseldireg", "Dzongkha", "Saesneg", "Esperanto", "Estoneg", "Ewe", "Ffaröeg", "Ffijïeg", "Ffinneg", "Ffrangeg", "Ffwla", "Galisieg", "Georgeg", "Almaeneg", "Groeg", "Guaraní", "Gwjarati", "Creol Haiti", "Hawsa", "Hebraeg", "Herero", "Hindi", "", "Hwngareg", "Interlingua", "Indoneseg", "Interlingue", "Gwyddeleg", "Igbo", "Inwpiaceg", "Ido", "Islandeg", "Eidaleg", "Inwctitwt", "Japaneeg", "Jafanaeg", "Kalaallisut", "Kannada", "Canwri", "Cashmireg", "Casacheg", "Chmereg", "Kikuyu", "Ciniarŵandeg", "Cirgiseg", "Comi", "Congo", "Coreeg", "Cwrdeg", "Kuanyama", "Lladin", "Lwcsembwrgeg", "Ganda", "Limbwrgeg", "Lingala", "Laoeg", "Lithwaneg", "Luba-Katanga", "Latfieg", "Manaweg", "Macedoneg", "Malagaseg", "Maleieg", "Malayalam", "Malteg", "Māori", "Marathi", "Marsialeg", "Mongoleg", "Nawrŵeg", "Nafaho", "Ndebele Gogleddol", "Nepaleg", "Ndonga", "Norwyeg Bokmål", "Norwyeg Nynorsk", "Norwyeg", "Nwosw", "Ndebele Deheuol", "Ocsitaneg", "Ojibwa", "Hen Slafoneg", "Oromo", "Odia", "Oseteg", "Pwnjabeg", "Pali", "Perseg", "Pwyleg", "Pashto", "Portiwgaleg", "Quechua", "Románsh", "Rwndi", "Rwmaneg", "Rwseg", "Sansgrit", "Sardeg", "Sindhi", "Sami Gogleddol", "Samöeg", "Sango", "Serbeg", "Gaeleg yr Alban", "Shona", "Sinhaleg", "Slofaceg", "Slofeneg", "Somaleg", "Sesotheg Deheuol", "Sbaeneg", "Swndaneg", "Swahili", "Swati", "Swedeg", "Tamileg", "Telugu", "Tajiceg", "Thai", "Tigrinya", "Tibeteg", "Tyrcmeneg", "Tagalog", "Tswana", "Tongeg", "Tyrceg", "Tsongaeg", "Tatareg", "Twi", "Tahitïeg", "Uighur", "Wcreineg", "Wrdw", "Wsbeceg", "Fendeg", "Fietnameg", "Folapük", "Walwneg", "Cymraeg", "Woloff", "Ffriseg y Gorllewin", "Xhosa", "Iddew-Almaeneg", "Iorwba", "Zhuang", "Swlw"];
+
+// wo
+static L115: [&str; 184] = ["", "", "Afrikaans", "", "Albane", "Amharik", "Arabic", "", "Armaniye", "Asame", "", "", "", "Aserbayjane", "", "Baskir", "Bask", "Belaris", "Baŋla", "", "", "Bosñak", "Breton", "Bilgaar", "Birmes", "Katalan", "", "", "Sewa", "Sinuwaa", "", "", "Kors", "", "Krowat", "Cek", "Danuwa", "Diweyi", "Neyerlànde", "Dsongkaa", "Àngale", "Esperantoo", "Estoñiye", "", "Feroos", "", "Feylànde", "Farañse", "Pël", "Galisiye", "Sorsiye", "Almaa", "Gereg", "Garani", "Gujarati", "Kereyolu Ayti", "Hawsa", "Ebrë", "Herero", "Endo", "", "Ongruwaa", "", "Endonesiye", "", "Irlànde", "Igbo", "", "", "Islànde", "Italiye", "Inuktitit", "Sapone", "", "", "Kannadaa", "Kanuri", "Kashmiri", "Kasax", "Xmer", "", "Kinyarwànda", "Kirgiis", "", "", "Koreye", "Kurdi", "", "Latin", "Liksàmbursuwaa", "", "", "", "Laaw", "Lituyaniye", "", "Letoniye", "", "Maseduwaane", "Malagasi", "Malay", "Malayalam", "Malt", "Mawri", "Marati", "", "Mongoliye", "", "", "", "Nepale", "", "", "", "Nerwesiye", "", "", "Ositan", "", "", "Oromo", "Oja", "", "Punjabi", "", "Pers", "Polone", "Pasto", "Purtugees", "Kesuwa", "Romaas", "", "Rumaniyee", "Rus", "Sanskrit", "", "Sindi", "Penku Sami", "", "", "Serb", "Galuwaa bu Ekos", "", "Sinala", "Eslowaki (Eslowak)", "Esloweniye", "Somali (làkk)", "", "Español", "", "", "", "Suweduwaa", "Tamil", "Telugu", "Tajis", "Tay", "Tigriña", "Tibetan", "Tirkmen", "Filipiye", "", "Tongan", "Tirk", "", "Tatar", "", "", "Uygur", "Ikreniye", "Urdu", "Usbek", "Wenda", "Wiyetnaamiye", "", "", "Wels", "Wolof", "", "", "Yidis", "Yoruba", "", ""];
+
+// fy
+static L116: [&str; 184] = ["Afar", "Abchazysk", "Afrikaansk", "Akan", "Albaneesk", "Amhaarsk", "Arabysk", "Aragoneesk", "Armeensk", "Assameesk", "Avarysk", "Avestysk", "Aymara", "Azerbeidzjaansk", "Bambara", "Basjkiersk", "Baskysk", "Wyt-Russysk", "Bengaalsk", "", "Bislama", "Bosnysk", "Bretonsk", "Bulgaarsk", "Birmees", "Katalaansk", "Chamorro", "Tsjetsjeensk", "Nyanja", "Sineesk", "Tsjoevasjysk", "Cornish", "Korsikaansk", "Cree", "Kroatysk", "Tsjechysk", "Deensk", "Divehi", "Nederlânsk", "Dzongkha", "Ingelsk", "Esperanto", "Estlânsk", "Ewe", "Faeröersk", "Fijysk", "Finsk", "Frânsk", "Fulah", "Galisysk", "Georgysk", "Dútsk", "Gryks", "Guaraní", "Gujarati", "Haïtiaansk", "Hausa", "Hebreeuwsk", "Herero", "Hindi", "Hiri Motu", "Hongaarsk", "Interlingua", "Yndonezysk", "Interlingue", "Iersk", "Igbo", "Inupiaq", "Ido", "Yslâns", "Italiaansk", "Inuktitut", "Japans", "Javaansk", "Grienlâns", "Kannada", "Kanuri", "Kasjmiri", "Kazachs", "Khmer", "Kikuyu", "Kinyarwanda", "Kirgizysk", "Komi", "Kongo", "Koreaansk", "Koerdysk", "Kuanyama", "Latyn", "Luxemburgs", "Ganda", "Limburgs", "Lingala", "Laotiaansk", "Litouws", "Luba-Katanga", "Letlâns", "Manks", "Macedonysk", "Malagasysk", "Maleis", "Malayalam", "Maltees", "Maori", "Marathi", "Marshallees", "Mongools", "Nauruaansk", "Navajo", "Noard-Ndbele", "Nepalees", "Ndonga", "Noors - Bokmål", "Noors - Nynorsk", "Noors", "Sichuan Yi", "Sûd-Ndbele", "Occitaansk", "Ojibwa", "Kerkslavysk", "Oromo", "Odia", "Ossetysk", "Punjabi", "Pali", "Perzysk", "Poalsk", "Pasjtoe", "Portugeesk", "Quechua", "Reto-Romaansk", "Kirundi", "Roemeensk", "Russysk", "Sanskriet", "Sardinysk", "Sindhi", "Noard-Samysk", "Samoaansk", "Sango", "Servysk", "Schotsk Gaelic", "Shona", "Singalees", "Slowaaks", "Sloveensk", "Somalysk", "Sûd-Sotho", "Spaansk", "Soendaneesk", "Swahili", "Swazi", "Zweeds", "Tamil", "Telugu", "Tadzjieks", "Thais", "Tigrinya", "Tibetaansk", "Turkmeens", "Tagalog", "Tswana", "Tongaansk", "Turks", "Tsonga", "Tataars", "Twi", "Tahitysk", "Oeigoers", "Oekraïens", "Urdu", "Oezbeeks", "Venda", "Vietnamees", "Volapük", "Waals", "Welsk", "Wolof", "Frysk", "Xhosa", "Jiddysk", "Yoruba", "Zhuang", "Zulu"];
+
+// xh
+static L117: [&str; 184] = ["", "", "IsiBhulu", "", "IsiAlbania", "IsiAmharic", "Isi-Arabhu", "", "IsiArmenia", "IsiAssamese", "", "", "", "Isi-Azerbaijani", "", "", "Isi-Basque", "Isi-Belarusian", "IsiBangla", "", "", "Isi-Bosnia", "IsiBreton", "Isi-Bulgaria", "", "Isi-Calatan", "", "", "", "IsiMandarin", "", "", "", "", "Isi-Croatia", "Isi-Czech", "Isi-Danish", "", "IsiDatshi", "", "IsiNgesi", "Isi-Esperanto", "Isi-Estonian", "", "Isi-Faroese", "", "Isi-Finnish", "IsiFrentshi", "", "Isi-Galician", "Isi-Georgia", "IsiJamani", "Isi-Greek", "", "Isi-Gujarati", "", "", "Isi-Hebrew", "", "IsiHindi", "", "Isi-Hungarian", "Isi-Interlingua", "Isi-Indonesia", "Isi-Interlingue", "Isi-Irish", "", "", "", "Isi-Icelandic", "IsiTaliyane", "", "IsiJapan", "Isi-Javanese", "", "Isi-Kannada", "", "", "", "IsiCambodia", "", "", "IsiKyrgz", "", "", "Isi-Korean", "IsiKurdish", "", "", "", "", "", "", "IsiLoathian", "Isi-Lithuanian", "", "Isi-Latvian", "", "Isi-Macedonian", "", "Isi-Malay", "Isi-Malayalam", "Isi-Maltese", "", "Isi-Marathi", "", "IsiMongolian", "", "", "", "Isi-Nepali", "", "", "Isi-Norwegia (Nynorsk)", "Isi-Norwegian", "", "", "Iso-Occitan", "", "", "", "Oriya", "", "Isi-Punjabi", "", "Isi-Persia", "Isi-Polish", "IsiPashto", "IsiPhuthukezi", "", "", "", "Isi-Romanian", "Isi-Russian", "IsiSanskrit", "", "IsiSindhi", "", "", "", "Isi-Serbia", "Scots Gaelic", "", "Isi-Sinhalese", "Isi-Slovak", "Isi-Slovenian", "IsiSomaliya", "Sesotho", "Isi-Spanish", "Isi-Sudanese", "Isi-Swahili", "", "Isi-Swedish", "Isi-Tamil", "Isi-Telegu", "", "Isi-Thai", "Isi-Tigrinya", "", "IsiTurkmen", "Isi-Taglog", "", "", "Isi-Turkish", "", "", "", "", "Isi Uighur", "Isi-Ukranian", "IsiUrdu", "Isi-Uzbek", "", "Isi-Vietnamese", "", "", "Isi-Welsh", "", "Isi-Frisian", "IsiXhosa", "", "", "", "IsiZulu"];
+
+// yo
+static L118: [&str; 184] = ["", "Èdè Abasia", "Èdè Afrikani", "Èdè Akani", "Èdè Albania", "Èdè Amariki", "Èdè Lárúbáwá", "Èdè Aragoni", "Èdè Armenia", "Èdè Assam", "Èdè Afariki", "", "Èdè Amara", "Èdè Asabaijani", "Èdè Báḿbàrà", "Èdè Bashiri", "Èdè Baski", "Èdè Belarusi", "Èdè Bengali", "", "Èdè Bisilama", "Èdè Bosnia", "Èdè Bretoni", "Èdè Bugaria", "Èdè Bumiisi", "Èdè Katala", "Èdè S̩amoro", "Èdè Chechen", "Ńyájà", "Edè Ṣáínà", "Èdè Ṣufasi", "Èdè Kọ́nììṣì", "Èdè Corsican", "", "Èdè Kroatia", "Èdè Seeki", "Èdè Denmaki", "Èdè Difehi", "Èdè Dọ́ọ̀ṣì", "Èdè Dzongkha", "Èdè Gẹ̀ẹ́sì", "Èdè Esperanto", "Èdè Estonia", "Èdè Ewè", "Èdè Faroesi", "Èdè Fiji", "Èdè Finisi", "Èdè Faransé", "Èdè Fúlàní", "Èdè Galicia", "Èdè Georgia", "Èdè Jámánì", "Èdè Giriki", "Èdè Guarani", "Èdè Gujarati", "Haitian Creole", "Èdè Hausa", "Èdè Heberu", "Èdè Herero", "Èdè Híńdì", "", "Èdè Hungaria", "Èdè pipo", "Èdè Indonéṣíà", "Èdè àtọwọ́dá", "Èdè Ireland", "Èdè Yíbò", "", "Èdè Ido", "Èdè Icelandic", "Èdè Ítálì", "Èdè Inukitu", "Èdè Jàpáànù", "Èdè Javanasi", "Kalaalísùtì", "Èdè Kannada", "Èdè Kanuri", "Kaṣímirì", "Kaṣakì", "Èdè kameri", "Kíkúyù", "Èdè Ruwanda", "Kírígíìsì", "Èdè Komi", "", "Èdè Kòríà", "Kọdiṣì", "Èdè Kuayama", "Èdè Latini", "Lùṣẹ́mbọ́ọ̀gì", "Ganda", "Èdè Limbogishi", "Lìǹgálà", "Láò", "Èdè Lithuania", "Lúbà-Katanga", "Èdè látífíànì", "Máǹkì", "Èdè Masidonia", "Malagasì", "Èdè Malaya", "Málàyálámù", "Èdè Malta", "Màórì", "Èdè marathi", "Èdè Mashali", "Mòngólíà", "Èdè Nauru", "Èdè Nafajo", "Àríwá Ndebele", "Èdè Nepali", "Èdè Ndonga", "Nọ́ọ́wè Bokímàl", "Nọ́ọ́wè Nínọ̀sìkì", "Èdè Norway", "Ṣíkuán Yì", "Èdè Gusu Ndebele", "Èdè Ọ̀kísítáànì", "", "Èdè Síláfííkì Ilé Ìjọ́sìn", "Òròmọ́", "Èdè Òdíà", "Ọṣẹ́tíìkì", "Èdè Punjabi", "", "Èdè Pasia", "Èdè Póláǹdì", "Páshítò", "Èdè Pọtogí", "Kúẹ́ńjùà", "Rómáǹṣì", "Rúńdì", "Èdè Romania", "Èdè Rọ́ṣíà", "Èdè awon ara Indo", "Èdè Sadini", "Èdè Sindhi", "Apáàríwá Sami", "Sámóánù", "Sango", "Èdè Serbia", "Èdè Gaelik ti Ilu Scotland", "Ṣọnà", "Èdè Sinhalese", "Èdè Slovaki", "Èdè Slovenia", "Èdè ara Somalia", "Èdè Sesoto", "Èdè Sípáníìṣì", "Èdè Sudanísì", "Èdè Swahili", "Èdè Suwati", "Èdè Suwidiisi", "Èdè Tamili", "Èdè Telugu", "Èdè Tàjíìkì", "Èdè Tai", "Èdè Tigrinya", "Tibetán", "Èdè Turkmen", "Èdè Filipino", "Èdè Suwana", "Tóńgàn", "Èdè Tọọkisi", "Èdè Songa", "Tátárì", "", "Èdè Tahiti", "Yúgọ̀", "Èdè Ukania", "Èdè Udu", "Èdè Uzbek", "Èdè Fenda", "Èdè Jetinamu", "Fọ́lápùùkù", "Èdè Waluni", "Èdè Welshi", "Wọ́lọ́ọ̀fù", "Èdè Frisia", "Èdè Xhosa", "Èdè Yiddishi", "Èdè Yorùbá", "Ṣúwáànù", "Èdè Ṣulu"];
+
+// za
+static L119: [&str; 184] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Yinghyij", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Vahcuengh", ""];
+
+// zu
+static L120: [&str; 184] = ["isi-Afar", "isi-Abkhazian", "i-Afrikaans", "isi-Akan", "isi-Albania", "isi-Amharic", "isi-Arabic", "isi-Aragonese", "isi-Armenia", "isi-Assamese", "isi-Avaric", "", "isi-Aymara", "isi-Azerbaijani", "isi-Bambara", "isi-Bashkir", "isi-Basque", "isi-Belarusian", "isi-Bengali", "", "isi-Bislama", "isi-Bosnian", "isi-Breton", "isi-Bulgari", "isi-Burmese", "isi-Catalan", "isi-Chamorro", "isi-Chechen", "isi-Nyanja", "isi-Chinese", "isi-Chuvash", "isi-Cornish", "isi-Corsican", "", "isi-Croatian", "isi-Czech", "isi-Danish", "isi-Divehi", "isi-Dutch", "isi-Dzongkha", "i-English", "isi-Esperanto", "isi-Estonia", "isi-Ewe", "isi-Faroese", "isi-Fijian", "isi-Finnish", "isi-French", "isi-Fulah", "isi-Galicia", "isi-Georgian", "isi-German", "isi-Greek", "isi-Guarani", "isi-Gujarati", "isi-Haitian", "isi-Hausa", "isi-Hebrew", "isi-Herero", "isi-Hindi", "", "isi-Hungarian", "izilimi ezihlangene", "isi-Indonesian", "izimili", "isi-Irish", "isi-Igbo", "", "isi-Ido", "isi-Icelandic", "isi-Italian", "isi-Inuktitut", "isi-Japanese", "isi-Javanese", "isi-Kalaallisut", "isi-Kannada", "isi-Kanuri", "isi-Kashmiri", "isi-Kazakh", "isi-Khmer", "isi-Kikuyu", "isi-Kinyarwanda", "isi-Kyrgyz", "isi-Komi", "isi-Kongo", "isi-Korean", "isi-Kurdish", "isi-Kuanyama", "isi-Latin", "isi-Luxembourgish", "isi-Ganda", "isi-Limburgish", "isi-Lingala", "isi-Lao", "isi-Lithuanian", "isi-Luba-Katanga", "isi-Latvian", "isi-Manx", "isi-Macedonian", "isi-Malagasy", "isi-Malay", "isi-Malayalam", "isi-Maltese", "isi-Maori", "isi-Marathi", "isi-Marshallese", "isi-Mongolian", "isi-Nauru", "isi-Navajo", "isi-North Ndebele", "isi-Nepali", "isi-Ndonga", "isi-Norwegian Bokmål", "isi-Norwegian Nynorsk", "isi-Norwegian", "isi-Sichuan Yi", "isi-South Ndebele", "isi-Occitan", "", "isi-Church Slavic", "isi-Oromo", "isi-Odia", "isi-Ossetic", "isi-Punjabi", "", "isi-Persian", "isi-Polish", "isi-Pashto", "isi-Portuguese", "isi-Quechua", "isi-Romansh", "isi-Rundi", "isi-Romanian", "isi-Russian", "isi-Sanskrit", "isi-Sardinian", "isi-Sindhi", "isi-Northern Sami", "isi-Samoan", "isi-Sango", "isi-Serbian", "isi-Scottish Gaelic", "isiShona", "isi-Sinhala", "isi-Slovak", "isi-Slovenian", "isi-Somali", "isi-Southern Sotho", "isi-Spanish", "isi-Sundanese", "isiSwahili", "isiSwati", "isi-Swedish", "isi-Tamil", "isi-Telugu", "isi-Tajik", "isi-Thai", "isi-Tigrinya", "isi-Tibetan", "isi-Turkmen", "isi-Filipino", "isi-Tswana", "isi-Tongan", "isi-Turkish", "isi-Tsonga", "isi-Tatar", "isi-Twi", "isi-Tahitian", "isi-Uighur", "isi-Ukrainian", "isi-Urdu", "isi-Uzbek", "isi-Venda", "isi-Vietnamese", "isi-Volapük", "isi-Walloon", "isi-Welsh", "isi-Wolof", "isi-Western Frisian", "isiXhosa", "isi-Yiddish", "isi-Yoruba", "IsiZhuang", "isiZulu"];
+
+/// The language names of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [Option<&[&str; 184]>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&L0),
+    /* ak */ Some(&L1),
+    /* sq */ Some(&L2),
+    /* am */ Some(&L3),
+    /* ar */ Some(&L4),
+    /* an */ None,
+    /* hy */ Some(&L5),
+    /* as */ Some(&L6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&L7),
+    /* bm */ None,
+    /* ba */ Some(&L8),
+    /* eu */ Some(&L9),
+    /* be */ Some(&L10),
+    /* bn */ Some(&L11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&L12),
+    /* br */ Some(&L13),
+    /* bg */ Some(&L14),
+    /* my */ Some(&L15),
+    /* ca */ Some(&L16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&L17),
+    /* cv */ Some(&L18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&L19),
+    /* cs */ Some(&L20),
+    /* da */ Some(&L21),
+    /* dv */ None,
+    /* nl */ Some(&L22),
+    /* dz */ None,
+    /* en */ Some(&L23),
+    /* eo */ Some(&L24),
+    /* et */ Some(&L25),
+    /* ee */ Some(&L26),
+    /* fo */ Some(&L27),
+    /* fj */ None,
+    /* fi */ Some(&L28),
+    /* fr */ Some(&L29),
+    /* ff */ None,
+    /* gl */ Some(&L30),
+    /* ka */ Some(&L31),
+    /* de */ Some(&L32),
+    /* el */ Some(&L33),
+    /* gn */ None,
+    /* gu */ Some(&L34),
+    /* ht */ Some(&L35),
+    /* ha */ Some(&L36),
+    /* he */ Some(&L37),
+    /* hz */ None,
+    /* hi */ Some(&L38),
+    /* ho */ None,
+    /* hu */ Some(&L39),
+    /* ia */ Some(&L40),
+    /* id */ Some(&L41),
+    /* ie */ Some(&L42),
+    /* ga */ Some(&L43),
+    /* ig */ Some(&L44),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&L45),
+    /* it */ Some(&L46),
+    /* iu */ None,
+    /* ja */ Some(&L47),
+    /* jv */ Some(&L48),
+    /* kl */ None,
+    /* kn */ Some(&L49),
+    /* kr */ None,
+    /* ks */ Some(&L50),
+    /* kk */ Some(&L51),
+    /* km */ Some(&L52),
+    /* ki */ None,
+    /* rw */ Some(&L53),
+    /* ky */ Some(&L54),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&L55),
+    /* ku */ Some(&L56),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&L57),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&L58),
+    /* lt */ Some(&L59),
+    /* lu */ None,
+    /* lv */ Some(&L60),
+    /* gv */ None,
+    /* mk */ Some(&L61),
+    /* mg */ None,
+    /* ms */ Some(&L62),
+    /* ml */ Some(&L63),
+    /* mt */ Some(&L64),
+    /* mi */ Some(&L65),
+    /* mr */ Some(&L66),
+    /* mh */ None,
+    /* mn */ Some(&L67),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&L68),
+    /* ng */ None,
+    /* nb */ Some(&L69),
+    /* nn */ Some(&L70),
+    /* no */ Some(&L69),
+    /* ii */ Some(&L71),
+    /* nr */ None,
+    /* oc */ Some(&L72),
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ Some(&L73),
+    /* or */ Some(&L74),
+    /* os */ None,
+    /* pa */ Some(&L75),
+    /* pi */ None,
+    /* fa */ Some(&L76),
+    /* pl */ Some(&L77),
+    /* ps */ Some(&L78),
+    /* pt */ Some(&L79),
+    /* qu */ Some(&L80),
+    /* rm */ Some(&L81),
+    /* rn */ None,
+    /* ro */ Some(&L82),
+    /* ru */ Some(&L83),
+    /* sa */ Some(&L84),
+    /* sc */ Some(&L85),
+    /* sd */ Some(&L86),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&L87),
+    /* gd */ Some(&L88),
+    /* sn */ None,
+    /* si */ Some(&L89),
+    /* sk */ Some(&L90),
+    /* sl */ Some(&L91),
+    /* so */ Some(&L92),
+    /* st */ Some(&L93),
+    /* es */ Some(&L94),
+    /* su */ Some(&L95),
+    /* sw */ Some(&L96),
+    /* ss */ None,
+    /* sv */ Some(&L97),
+    /* ta */ Some(&L98),
+    /* te */ Some(&L99),
+    /* tg */ Some(&L100),
+    /* th */ Some(&L101),
+    /* ti */ Some(&L102),
+    /* bo */ None,
+    /* tk */ Some(&L103),
+    /* tl */ Some(&L104),
+    /* tn */ Some(&L105),
+    /* to */ Some(&L106),
+    /* tr */ Some(&L107),
+    /* ts */ None,
+    /* tt */ Some(&L108),
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&L109),
+    /* uk */ Some(&L110),
+    /* ur */ Some(&L111),
+    /* uz */ Some(&L112),
+    /* ve */ None,
+    /* vi */ Some(&L113),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&L114),
+    /* wo */ Some(&L115),
+    /* fy */ Some(&L116),
+    /* xh */ Some(&L117),
+    /* yi */ None,
+    /* yo */ Some(&L118),
+    /* za */ Some(&L119),
+    /* zu */ Some(&L120),
+];
+
+/// The codes of the regions, sorted, in the order of the region names.
+pub(super) static REGION_CODES: [&str; 294] = ["001", "002", "003", "005", "009", "011", "013", "014", "015", "017", "018", "019", "021", "029", "030", "034", "035", "039", "053", "054", "057", "061", "142", "143", "145", "150", "151", "154", "155", "202", "419", "AC", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN", "CO", "CP", "CQ", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DG", "DJ", "DK", "DM", "DO", "DZ", "EA", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "EU", "EZ", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU", "IC", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG", "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "QO", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TA", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "UN", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI", "VN", "VU", "WF", "WS", "XA", "XB", "XK", "YE", "YT", "ZA", "ZM", "ZW"];
+
+// af
+static R0: [&str; 294] = ["wêreld", "Afrika", "Noord-Amerika", "Suid-Amerika", "Oseanië", "Wes-Afrika", "Sentraal-Amerika", "Oos-Afrika", "Noord-Afrika", "Midde-Afrika", "Suider-Afrika", "Amerikas", "Noordelike Amerika", "Karibiese streek", "Oos-Asië", "Suid-Asië", "Suidoos-Asië", "Suid-Europa", "Australasië", "Melanesië", "Mikronesiese streek", "Polinesië", "Asië", "Sentraal-Asië", "Wes-Asië", "Europa", "Oos-Europa", "Noord-Europa", "Wes-Europa", "Afrika suid van die Sahara", "Latyns-Amerika", "Ascensioneiland", "Andorra", "Verenigde Arabiese Emirate", "Afganistan", "Antigua en Barbuda", "Anguilla", "Albanië", "Armenië", "Angola", "Antarktika", "Argentinië", "Amerikaanse Samoa", "Oostenryk", "Australië", "Aruba", "Ålandeilande", "Azerbeidjan", "Bosnië en Herzegowina", "Barbados", "Bangladesj", "België", "Burkina Faso", "Bulgarye", "Bahrein", "Burundi", "Benin", "Sint Barthélemy", "Bermuda", "Broenei", "Bolivië", "Karibiese Nederland", "Brasilië", "Bahamas", "Bhoetan", "Bouvet-eiland", "Botswana", "Belarus", "Belize", "Kanada", "Kokoseilande", "Demokratiese Republiek van die Kongo", "Sentraal-Afrikaanse Republiek", "Kongo-Brazzaville", "Switserland", "Ivoorkus", "Cookeilande", "Chili", "Kameroen", "China", "Colombië", "Clippertoneiland", "Sark", "Costa Rica", "Kuba", "Kaap Verde", "Curaçao", "Kerseiland", "Siprus", "Tsjeggië", "Duitsland", "Diego Garcia", "Djiboeti", "Denemarke", "Dominica", "Dominikaanse Republiek", "Algerië", "Ceuta en Melilla", "Ecuador", "Estland", "Egipte", "Wes-Sahara", "Eritrea", "Spanje", "Ethiopië", "Europese Unie", "Eurosone", "Finland", "Fidji", "Falklandeilande", "Mikronesië", "Faroëreilande", "Frankryk", "Gaboen", "Verenigde Koninkryk", "Grenada", "Georgië", "Frans-Guyana", "Guernsey", "Ghana", "Gibraltar", "Groenland", "Gambië", "Guinee", "Guadeloupe", "Ekwatoriaal-Guinee", "Griekeland", "Suid-Georgië en die Suidelike Sandwicheilande", "Guatemala", "Guam", "Guinee-Bissau", "Guyana", "Hongkong SAS China", "Heardeiland en McDonaldeilande", "Honduras", "Kroasië", "Haïti", "Hongarye", "Kanariese Eilande", "Indonesië", "Ierland", "Israel", "Eiland Man", "Indië", "Brits-Indiese Oseaangebied", "Irak", "Iran", "Ysland", "Italië", "Jersey", "Jamaika", "Jordanië", "Japan", "Kenia", "Kirgistan", "Kambodja", "Kiribati", "Comore", "Sint Kitts en Nevis", "Noord-Korea", "Suid-Korea", "Koeweit", "Kaaimanseilande", "Kazakstan", "Laos", "Libanon", "Sint Lucia", "Liechtenstein", "Sri Lanka", "Liberië", "Lesotho", "Litaue", "Luxemburg", "Letland", "Libië", "Marokko", "Monaco", "Moldowa", "Montenegro", "Sint Martin", "Madagaskar", "Marshalleilande", "Noord-Macedonië", "Mali", "Mianmar (Birma)", "Mongolië", "Macau SAS China", "Noord-Mariane-eilande", "Martinique", "Mauritanië", "Montserrat", "Malta", "Mauritius", "Maledive", "Malawi", "Meksiko", "Maleisië", "Mosambiek", "Namibië", "Nieu-Kaledonië", "Niger", "Norfolkeiland", "Nigerië", "Nicaragua", "Nederland", "Noorweë", "Nepal", "Nauru", "Niue", "Nieu-Seeland", "Oman", "Panama", "Peru", "Frans-Polinesië", "Papoea-Nieu-Guinee", "Filippyne", "Pakistan", "Pole", "Sint Pierre en Miquelon", "Pitcairneilande", "Puerto Rico", "Palestynse Grondgebiede", "Portugal", "Palau", "Paraguay", "Katar", "Afgeleë Oseanië", "Réunion", "Roemenië", "Serwië", "Rusland", "Rwanda", "Saoedi-Arabië", "Salomonseilande", "Seychelle", "Soedan", "Swede", "Singapoer", "Sint Helena", "Slowenië", "Spitsbergen en Jan Mayen", "Slowakye", "Sierra Leone", "San Marino", "Senegal", "Somalië", "Suriname", "Suid-Soedan", "São Tomé en Príncipe", "El Salvador", "Sint Maarten", "Sirië", "Eswatini", "Tristan da Cunha", "Turks- en Caicoseilande", "Tsjad", "Franse Suidelike Gebiede", "Togo", "Thailand", "Tadjikistan", "Tokelau", "Oos-Timor", "Turkmenistan", "Tunisië", "Tonga", "Turkye", "Trinidad en Tobago", "Tuvalu", "Taiwan", "Tanzanië", "Oekraïne", "Uganda", "Klein afgeleë eilande van die VSA", "Verenigde Nasies", "Verenigde State van Amerika", "Uruguay", "Oesbekistan", "Vatikaanstad", "Sint Vincent en die Grenadine", "Venezuela", "Britse Maagde-eilande", "VSA se Maagde-eilande", "Viëtnam", "Vanuatu", "Wallis en Futuna", "Samoa", "Pseudoaksente", "Pseudotweerigting", "Kosovo", "Jemen", "Mayotte", "Suid-Afrika", "Zambië", "Zimbabwe"];
+
+// ak
+static R1: [&str; 294] = ["wiase", "Abibirem", "Amɛrika Atifi", "Amɛrika Anaafoɔ", "Osiana", "Abibirem Atɔeɛ Fam", "Amɛrika Mfimfini", "Abibirem Apueiɛ Fam", "Abibirem Atifi Fam", "Abibirem Mfimfini", "Abibirem Anaafoɔ Fam", "Amɛrikafoɔ", "Amɛrika Atifi Fam", "Karibia", "Asia Apueiɛ", "Asia Anaafoɔ", "Asia Anaafoɔ Apuieɛ", "Yuropu Anaafoɔ", "Ɔstrelia ne Asia", "Melanesia", "Micronesia Mantam", "Pɔlenesia", "Asia", "Asia Mfimfini", "Asia Atɔeɛ", "Yuropu", "Yuropu Apuieɛ", "Yuropu Atifi", "Yuropu Atɔeɛ", "Abibirem Mpaprɛ Anaafoɔ", "Laaten Amɛrika", "Asɛnhyin", "Andora", "United Arab Emirates", "Afganistan", "Antigua ne Baabuda", "Anguila", "Albenia", "Aamenia", "Angola", "Antaatika", "Agyɛntina", "Amɛrika Samoa", "Ɔstria", "Ɔstrelia", "Aruba", "Aland Aeland", "Asabegyan", "Bosnia ne Hɛzegovina", "Baabados", "Bangladɛhye", "Bɛlgyium", "Bɔkina Faso", "Bɔlgeria", "Baren", "Burundi", "Bɛnin", "St. Baatilemi", "Bɛmuda", "Brunae", "Bolivia", "Caribbean Netherlands", "Brazil", "Bahamase", "Butan", "Bouvet Island", "Bɔtswana", "Bɛlarus", "Beliz", "Kanada", "Kokoso Supɔ", "Kongo Kinhyaahya", "Afrika Finimfin Man", "Kongo", "Swetzaland", "Kodivuwa", "Kuk Nsupɔ", "Kyili", "Kamɛrun", "Kyaena", "Kolombia", "Klepatin Aeland", "Sark", "Kɔsta Rika", "Kiuba", "Kepvɛdfo Islands", "Kurakaw", "Buronya Supɔ", "Saeprɔso", "Kyɛk", "Gyaaman", "Diɛgo Gaasia", "Gyibuti", "Dɛnmak", "Dɔmeneka", "Dɔmeneka Man", "Ɔlgyeria", "Ceuta ne Melilla", "Yikuwedɔ", "Ɛstonia", "Misrim", "Sahara Atɔeɛ", "Ɛritrea", "Spain", "Yitiopia", "Yuropu Nkabomkuo", "Yuropu Fam", "Finland", "Figyi", "Fɔkman Aeland", "Maekronehyia", "Faro Aeland", "Franse", "Gabɔn", "UK", "Grenada", "Gyɔgyea", "Frɛnkye Gayana", "Guɛnse", "Gaana", "Gyebralta", "Greenman", "Gambia", "Gini", "Guwadelup", "Gini Ikuweta", "Greekman", "Gyɔɔgyia Anaafoɔ ne Sandwich Aeland Anaafoɔ", "Guwatemala", "Guam", "Gini Bisaw", "Gayana", "Hɔnkɔn Kyaena", "Heard ne McDonald Supɔ", "Hɔnduras", "Krowehyia", "Heiti", "Hangari", "Canary Islands", "Ndoniihyia", "Aereland", "Israe", "Isle of Man", "India", "Britenfo Man Wɔ India Po No Mu", "Irak", "Yiran", "Aesland", "Itali", "Gyɛsi", "Gyameka", "Gyɔdan", "Gyapan", "Kenya", "Kɛɛgestan", "Kambodia", "Kiribati", "Kɔmɔrɔs", "Saint Kitts ne Nevis", "Korea Atifi", "Korea Anaafoɔ", "Kuweti", "Kemanfo Islands", "Kazakstan", "Laos", "Lɛbanɔn", "Saint Lucia", "Lektenstaen", "Sri Lanka", "Laeberia", "Lesoto", "Lituwenia", "Lusimbɛg", "Latvia", "Libya", "Moroko", "Monako", "Mɔldova", "Mɔntenegro", "St. Maatin", "Madagaska", "Mahyaa Aeland", "Mesidonia Atifi", "Mali", "Mayaama (Bɛɛma)", "Mɔngolia", "Makaw Kyaena", "Mariana Atifi Fam Aeland", "Matinik", "Mɔretenia", "Mantserat", "Mɔlta", "Mɔrehyeɔs", "Maldives", "Malawi", "Mɛksiko", "Malehyia", "Mozambik", "Namibia", "Kaledonia Foforo", "Nigyɛɛ", "Norfold Supɔ", "Naegyeria", "Nekaraguwa", "Nɛdɛland", "Nɔɔwe", "Nɛpal", "Naworu", "Niyu", "Ziland Foforo", "Oman", "Panama", "Peru", "Frɛnkye Pɔlenehyia", "Papua Gini Foforɔ", "Filipin", "Pakistan", "Pɔland", "Saint Pierre ne Miquelon", "Pitkaan Nsupɔ", "Puɛto Riko", "Palestaen West Bank ne Gaza", "Pɔtugal", "Palau", "Paraguae", "Kata", "Osiana Ano Ano", "Reyuniɔn", "Romenia", "Sɛbia", "Rɔhyea", "Rewanda", "Saudi Arabia", "Solomɔn Aeland", "Seyhyɛl", "Sudan", "Sweden", "Singapɔ", "Saint Helena", "Slovenia", "Svalbard ne Jan Mayen", "Slovakia", "Sɛra Liɔn", "San Marino", "Senegal", "Somalia", "Suriname", "Sudan Anaafoɔ", "São Tomé ne Príncipe", "Ɛl Salvadɔ", "Sint Maaten", "Siria", "Swaziland", "Tristan da Kuna", "Turks ne Caicos Islands", "Kyad", "Franse Anaafoɔ Nsaase", "Togo", "Taeland", "Tagyikistan", "Tokelau", "Timɔ Boka", "Tɛkmɛnistan", "Tunihyia", "Tonga", "Tɛɛki", "Trinidad ne Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukren", "Yuganda", "U.S. Nkyɛnnkyɛn Supɔ Ahodoɔ", "Amansan Nkabomkuo", "Amɛrika", "Yurugwae", "Usbɛkistan", "Vatican Man", "Saint Vincent ne Grenadines", "Venezuela", "Ngresifoɔ Virgin Island", "Amɛrika Virgin Islands", "Viɛtnam", "Vanuatu", "Wallis ne Futuna", "Samoa", "Anto Kasa", "Anto Bidi", "Kosovo", "Yɛmɛn", "Mayɔte", "Abibirem Anaafoɔ", "Zambia", "Zimbabue"];
+
+// sq
+static R2: [&str; 294] = ["Bota", "Afrikë", "Amerika e Veriut", "Amerika e Jugut", "Oqeani", "Afrika Perëndimore", "Amerika Qendrore", "Afrika Lindore", "Afrika Veriore", "Afrika e Mesme", "Afrika Jugore", "Amerikë", "Amerika Veriore", "Karaibe", "Azia Lindore", "Azia Jugore", "Azia Juglindore", "Evropa Jugore", "Australazia", "Melanezia", "Rajoni Mikronezian", "Polinezia", "Azi", "Azia Qendrore", "Azia Perëndimore", "Evropë", "Evropa Lindore", "Evropa Veriore", "Evropa Perëndimore", "Afrika Subsahariane", "Amerika Latine", "Ishulli Asenshion", "Andorrë", "Emiratet e Bashkuara Arabe", "Afganistan", "Antigua e Barbuda", "Anguilë", "Shqipëri", "Armeni", "Angolë", "Antarktikë", "Argjentinë", "Samoa Amerikane", "Austri", "Australi", "Arubë", "Ishujt Alandë", "Azerbajxhan", "Bosnjë-Hercegovinë", "Barbados", "Bangladesh", "Belgjikë", "Burkina-Faso", "Bullgari", "Bahrejn", "Burundi", "Benin", "Sen-Bartelemi", "Bermude", "Brunei", "Bolivi", "Karaibet holandeze", "Brazil", "Bahama", "Butan", "Ishulli Bove", "Botsvanë", "Bjellorusi", "Belizë", "Kanada", "Ishujt Kokos", "Kongo-Kinshasa", "Republika e Afrikës Qendrore", "Kongo-Brazavilë", "Zvicër", "Côte d’Ivoire", "Ishujt Kuk", "Kili", "Kamerun", "Kinë", "Kolumbi", "Ishulli Klipërton", "Sark", "Kosta-Rikë", "Kubë", "Kepi i Gjelbër", "Kurasao", "Ishulli i Krishtlindjes", "Qipro", "Çeki", "Gjermani", "Diego-Garsia", "Xhibuti", "Danimarkë", "Dominikë", "Republika Dominikane", "Algjeri", "Theuta e Melila", "Ekuador", "Estoni", "Egjipt", "Saharaja Perëndimore", "Eritre", "Spanjë", "Etiopi", "Bashkimi Evropian", "Zona euro", "Finlandë", "Fixhi", "Ishujt Falkland", "Mikronezi", "Ishujt Faroe", "Francë", "Gabon", "Mbretëria e Bashkuar", "Granadë", "Gjeorgji", "Guajana Franceze", "Gernsej", "Ganë", "Gjibraltar", "Grënlandë", "Gambi", "Guine", "Guadelupë", "Guineja Ekuatoriale", "Greqi", "Xhorxha Jugore dhe Ishujt Senduiçë të Jugut", "Guatemalë", "Guam", "Guine-Bisau", "Guajanë", "RPA i Hong-Kongut", "Ishujt Hërd e Mekdonald", "Honduras", "Kroaci", "Haiti", "Hungari", "Ishujt Kanarie", "Indonezi", "Irlandë", "Izrael", "Ishulli i Manit", "Indi", "Territori Britanik i Oqeanit Indian", "Irak", "Iran", "Islandë", "Itali", "Xhersej", "Xhamajkë", "Jordani", "Japoni", "Kenia", "Kirgizi", "Kamboxhia", "Kiribati", "Komore", "Shën-Kits dhe Nevis", "Kore e Veriut", "Kore e Jugut", "Kuvajt", "Ishujt Kajman", "Kazakistan", "Laos", "Liban", "Shën-Luçia", "Lihtenshtajn", "Sri-Lankë", "Liberi", "Lesoto", "Lituani", "Luksemburg", "Letoni", "Libi", "Marok", "Monako", "Moldavi", "Mal i Zi", "Sen-Marten", "Madagaskar", "Ishujt Marshall", "Maqedonia e Veriut", "Mali", "Mianmar (Burmë)", "Mongoli", "RPA i Makaos", "Ishujt e Marianës Veriore", "Martinikë", "Mauritani", "Montserat", "Maltë", "Mauritius", "Maldive", "Malavi", "Meksikë", "Malajzi", "Mozambik", "Namibi", "Kaledoni e Re", "Niger", "Ishulli Norfolk", "Nigeri", "Nikaragua", "Holandë", "Norvegji", "Nepal", "Nauru", "Niue", "Zelandë e Re", "Oman", "Panama", "Peru", "Polinezia Franceze", "Guineja e Re-Papua", "Filipine", "Pakistan", "Poloni", "Shën-Pier dhe Mikelon", "Ishujt Pitkern", "Porto-Riko", "Territoret Palestineze", "Portugali", "Palau", "Paraguai", "Katar", "Oqeania e Largët (Lindja e Largët)", "Reunion", "Rumani", "Serbi", "Rusi", "Ruandë", "Arabi Saudite", "Ishujt Solomon", "Sejshelle", "Sudan", "Suedi", "Singapor", "Shën-Elenë", "Slloveni", "Svalbard e Jan-Majen", "Sllovaki", "Sierra-Leone", "San-Marino", "Senegal", "Somali", "Surinami", "Sudani i Jugut", "Sao-Tome e Principe", "Salvador", "Sint-Marten", "Siri", "Esvatini", "Tristan-da-Kuna", "Ishujt Turks dhe Kaikos", "Çad", "Territoret Jugore Franceze", "Togo", "Tajlandë", "Taxhikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunizi", "Tonga", "Turqi", "Trinidad e Tobago", "Tuvalu", "Tajvan", "Tanzani", "Ukrainë", "Ugandë", "Ishujt Periferikë të SHBA-së", "Organizata e Kombeve të Bashkuara", "SHBA", "Uruguai", "Uzbekistan", "Vatikan", "Shën-Vincent dhe Grenadine", "Venezuelë", "Ishujt e Virgjër Britanikë", "Ishujt e Virgjër të SHBA-së", "Vietnam", "Vanuatu", "Uollis e Futuna", "Samoa", "Pseudo-thekse", "Pseudo-bidi", "Kosovë", "Jemen", "Majotë", "Afrika e Jugut", "Zambi", "Zimbabve"];
+
+// am
+static R3: [&str; 294] = ["ዓለም", "አፍሪካ", "ሰሜን አሜሪካ", "ደቡብ አሜሪካ", "ኦሺያንያ", "ምዕራባዊ አፍሪካ", "መካከለኛው አሜሪካ", "ምስራቅ አፍሪካ", "ሰሜናዊ አፍሪካ", "መካከለኛው አፍሪካ", "ደቡባዊ አፍሪካ", "አሜሪካ", "ሰሜናዊ አሜሪካ", "ካሪቢያን", "ምስራቃዊ እስያ", "ደቡባዊ እስያ", "ደቡብ ምስራቅ እስያ", "ደቡባዊ አውሮፓ", "አውስትራሌዥያ", "ሜላኔዥያ", "የማይክሮኔዥያን ክልል", "ፖሊኔዥያ", "እስያ", "መካከለኛው እስያ", "ምዕራባዊ እስያ", "አውሮፓ", "ምስራቅ አውሮፓ", "ሰሜናዊ አውሮፓ", "ምዕራብ አውሮፓ", "ከሰሃራ በታች አፍሪካ", "ላቲን አሜሪካ", "አሴንሽን ደሴት", "አንዶራ", "የተባበሩት ዓረብ ኤምሬትስ", "አፍጋኒስታን", "አንቲጓ እና ባርቡዳ", "አንጉይላ", "አልባኒያ", "አርሜኒያ", "አንጐላ", "አንታርክቲካ", "አርጀንቲና", "የአሜሪካ ሳሞአ", "ኦስትሪያ", "አውስትራልያ", "አሩባ", "የአላንድ ደሴቶች", "አዘርባጃን", "ቦስኒያ እና ሄርዞጎቪኒያ", "ባርቤዶስ", "ባንግላዲሽ", "ቤልጄም", "ቡርኪና ፋሶ", "ቡልጋሪያ", "ባህሬን", "ብሩንዲ", "ቤኒን", "ሴንት ባርቴሌሚ", "ቤርሙዳ", "ብሩኒ", "ቦሊቪያ", "የካሪቢያን ኔዘርላንድስ", "ብራዚል", "ባሃማስ", "ቡህታን", "ቡቬት ደሴት", "ቦትስዋና", "ቤላሩስ", "በሊዝ", "ካናዳ", "ኮኮስ(ኬሊንግ) ደሴቶች", "ኮንጎ-ኪንሻሳ", "ማዕከላዊ አፍሪካ ሪፑብሊክ", "ኮንጎ ብራዛቪል", "ስዊዘርላንድ", "ኮትዲቯር", "ኩክ ደሴቶች", "ቺሊ", "ካሜሩን", "ቻይና", "ኮሎምቢያ", "ክሊፐርቶን ደሴት", "ሳርክ", "ኮስታሪካ", "ኩባ", "ኬፕቨርዴ", "ኩራሳዎ", "ክሪስማስ ደሴት", "ሳይፕረስ", "ቼቺያ", "ጀርመን", "ዲዬጎ ጋርሲያ", "ጂቡቲ", "ዴንማርክ", "ዶሚኒካ", "ዶመኒካን ሪፑብሊክ", "አልጄሪያ", "ሴኡታና ሜሊላ", "ኢኳዶር", "ኤስቶኒያ", "ግብጽ", "ምዕራባዊ ሳህራ", "ኤርትራ", "ስፔን", "ኢትዮጵያ", "የአውሮፓ ህብረት", "የዩሮ ዞን", "ፊንላንድ", "ፊጂ", "የፎክላንድ ደሴቶች", "ማይክሮኔዢያ", "የፋሮ ደሴቶች", "ፈረንሳይ", "ጋቦን", "ዩናይትድ ኪንግደም", "ግሬናዳ", "ጆርጂያ", "የፈረንሳይ ጉዊአና", "ጉርነሲ", "ጋና", "ጂብራልተር", "ግሪንላንድ", "ጋምቢያ", "ጊኒ", "ጉዋደሉፕ", "ኢኳቶሪያል ጊኒ", "ግሪክ", "ደቡብ ጆርጂያ እና የደቡብ ሳንድዊች ደሴቶች", "ጉዋቲማላ", "ጉዋም", "ጊኒ-ቢሳው", "ጉያና", "ሆንግ ኮንግ ልዩ የአስተዳደር ክልል ቻይና", "ኽርድ ኣና ማክዶናልድ ደሴቶች", "ሆንዱራስ", "ክሮኤሽያ", "ሀይቲ", "ሀንጋሪ", "የካናሪ ደሴቶች", "ኢንዶኔዢያ", "አየርላንድ", "እስራኤል", "አይል ኦፍ ማን", "ህንድ", "የብሪታኒያ ህንድ ውቂያኖስ ግዛት", "ኢራቅ", "ኢራን", "አይስላንድ", "ጣሊያን", "ጀርዚ", "ጃማይካ", "ጆርዳን", "ጃፓን", "ኬንያ", "ኪርጊስታን", "ካምቦዲያ", "ኪሪባቲ", "ኮሞሮስ", "ቅዱስ ኪትስ እና ኔቪስ", "ሰሜን ኮሪያ", "ደቡብ ኮሪያ", "ኩዌት", "ካይማን ደሴቶች", "ካዛኪስታን", "ላኦስ", "ሊባኖስ", "ሴንት ሉቺያ", "ሊችተንስታይን", "ሲሪላንካ", "ላይቤሪያ", "ሌሶቶ", "ሊቱዌኒያ", "ሉክሰምበርግ", "ላትቪያ", "ሊቢያ", "ሞሮኮ", "ሞናኮ", "ሞልዶቫ", "ሞንተኔግሮ", "ሴንት ማርቲን", "ማዳጋስካር", "ማርሻል ደሴቶች", "ሰሜን መቄዶንያ", "ማሊ", "ማይናማር(በርማ)", "ሞንጎሊያ", "ማካኦ ልዩ የአስተዳደር ክልል ቻይና", "የሰሜናዊ ማሪያና ደሴቶች", "ማርቲኒክ", "ሞሪቴኒያ", "ሞንትሴራት", "ማልታ", "ሞሪሸስ", "ማልዲቭስ", "ማላዊ", "ሜክሲኮ", "ማሌዢያ", "ሞዛምቢክ", "ናሚቢያ", "ኒው ካሌዶኒያ", "ኒጀር", "ኖርፎልክ ደሴት", "ናይጄሪያ", "ኒካራጓ", "ኔዘርላንድ", "ኖርዌይ", "ኔፓል", "ናኡሩ", "ኒዌ", "ኒው ዚላንድ", "ኦማን", "ፓናማ", "ፔሩ", "የፈረንሳይ ፖሊኔዢያ", "ፓፑዋ ኒው ጊኒ", "ፊሊፒንስ", "ፓኪስታን", "ፖላንድ", "ሴንት ፒዬር እና ሚኩኤሎን", "ፒትካኢርን ደሴቶች", "ፑዌርቶ ሪኮ", "የፍልስጤም ግዛት", "ፖርቱጋል", "ፓላው", "ፓራጓይ", "ኳታር", "የኦሺያንያ ዳርቻ", "ሪዩኒየን", "ሮሜኒያ", "ሰርብያ", "ሩስያ", "ሩዋንዳ", "ሳውድአረቢያ", "ሰለሞን ደሴቶች", "ሲሼልስ", "ሱዳን", "ስዊድን", "ሲንጋፖር", "ሴንት ሄለና", "ስሎቬኒያ", "ስቫልባርድ እና ጃን ማየን", "ስሎቫኪያ", "ሴራሊዮን", "ሳን ማሪኖ", "ሴኔጋል", "ሶማሊያ", "ሱሪናም", "ደቡብ ሱዳን", "ሳኦ ቶሜ እና ፕሪንሲፔ", "ኤል ሳልቫዶር", "ሲንት ማርተን", "ሶሪያ", "ኤስዋቲኒ", "ትሪስታን ዳ ኩንሃ", "የቱርኮችና የካኢኮስ ደሴቶች", "ቻድ", "የፈረንሳይ ደቡባዊ ግዛቶች", "ቶጐ", "ታይላንድ", "ታጃኪስታን", "ቶክላው", "ቲሞር ሌስቴ", "ቱርክሜኒስታን", "ቱኒዚያ", "ቶንጋ", "ቱርክ", "ትሪናዳድ እና ቶቤጎ", "ቱቫሉ", "ታይዋን", "ታንዛኒያ", "ዩክሬን", "ዩጋንዳ", "የዩ ኤስ ጠረፍ ላይ ያሉ ደሴቶች", "የተባበሩት መንግስታት", "ዩናይትድ ስቴትስ", "ኡራጓይ", "ኡዝቤኪስታን", "ቫቲካን ከተማ", "ሴንት ቪንሴንት እና ግሬናዲንስ", "ቬንዙዌላ", "የእንግሊዝ ቨርጂን ደሴቶች", "የአሜሪካ ቨርጂን ደሴቶች", "ቬትናም", "ቫኑአቱ", "ዋሊስ እና ፉቱና ደሴቶች", "ሳሞአ", "የሀሰት ትእምርት", "የሀሰት ባለሁለት አቅጣጫ", "ኮሶቮ", "የመን", "ሜይኦቴ", "ደቡብ አፍሪካ", "ዛምቢያ", "ዚምቧቤ"];
+
+// ar
+static R4: [&str; 294] = ["العالم", "أفريقيا", "أمريكا الشمالية", "أمريكا الجنوبية", "أوقيانوسيا", "غرب أفريقيا", "أمريكا الوسطى", "شرق أفريقيا", "شمال أفريقيا", "وسط أفريقيا", "أفريقيا الجنوبية", "الأمريكتان", "شمال أمريكا", "الكاريبي", "شرق آسيا", "جنوب آسيا", "جنوب شرق آسيا", "جنوب أوروبا", "أسترالاسيا", "ميلانيزيا", "الجزر الميكرونيزية", "بولينيزيا", "آسيا", "وسط آسيا", "غرب آسيا", "أوروبا", "شرق أوروبا", "شمال أوروبا", "غرب أوروبا", "أفريقيا جنوب الصحراء الكبرى", "أمريكا اللاتينية", "جزيرة أسينشيون", "أندورا", "الإمارات العربية المتحدة", "أفغانستان", "أنتيغوا وبربودا", "أنغويلا", "ألبانيا", "أرمينيا", "أنغولا", "أنتاركتيكا", "الأرجنتين", "ساموا الأمريكية", "النمسا", "أستراليا", "أروبا", "جزر آلاند", "أذربيجان", "البوسنة والهرسك", "بربادوس", "بنغلاديش", "بلجيكا", "بوركينا فاسو", "بلغاريا", "البحرين", "بوروندي", "بنين", "سان بارتليمي", "برمودا", "بروناي", "بوليفيا", "هولندا الكاريبية", "البرازيل", "جزر البهاما", "بوتان", "جزيرة بوفيه", "بوتسوانا", "بيلاروس", "بليز", "كندا", "جزر كوكوس (كيلينغ)", "الكونغو - كينشاسا", "جمهورية أفريقيا الوسطى", "الكونغو - برازافيل", "سويسرا", "ساحل العاج", "جزر كوك", "تشيلي", "الكاميرون", "الصين", "كولومبيا", "جزيرة كليبيرتون", "سارك", "كوستاريكا", "كوبا", "الرأس الأخضر", "كوراساو", "جزيرة كريسماس", "قبرص", "التشيك", "ألمانيا", "دييغو غارسيا", "جيبوتي", "الدانمرك", "دومينيكا", "جمهورية الدومينيكان", "الجزائر", "سيوتا وميليلا", "الإكوادور", "إستونيا", "مصر", "الصحراء الغربية", "إريتريا", "إسبانيا", "إثيوبيا", "الاتحاد الأوروبي", "منطقة اليورو", "فنلندا", "فيجي", "جزر فوكلاند", "ميكرونيزيا", "جزر فارو", "فرنسا", "الغابون", "المملكة المتحدة", "غرينادا", "جورجيا", "غويانا الفرنسية", "غيرنزي", "غانا", "جبل طارق", "غرينلاند", "غامبيا", "غينيا", "غوادلوب", "غينيا الاستوائية", "اليونان", "جورجيا الجنوبية وجزر ساندويتش الجنوبية", "غواتيمالا", "غوام", "غينيا بيساو", "غيانا", "هونغ كونغ الصينية (منطقة إدارية خاصة)", "جزيرة هيرد وجزر ماكدونالد", "هندوراس", "كرواتيا", "هايتي", "هنغاريا", "جزر الكناري", "إندونيسيا", "أيرلندا", "إسرائيل", "جزيرة مان", "الهند", "الإقليم البريطاني في المحيط الهندي", "العراق", "إيران", "آيسلندا", "إيطاليا", "جيرسي", "جامايكا", "الأردن", "اليابان", "كينيا", "قيرغيزستان", "كمبوديا", "كيريباتي", "جزر القمر", "سانت كيتس ونيفيس", "كوريا الشمالية", "كوريا الجنوبية", "الكويت", "جزر كايمان", "كازاخستان", "لاوس", "لبنان", "سانت لوسيا", "ليختنشتاين", "سريلانكا", "ليبيريا", "ليسوتو", "ليتوانيا", "لوكسمبورغ", "لاتفيا", "ليبيا", "المغرب", "موناكو", "مولدوفا", "الجبل الأسود", "سان مارتن", "مدغشقر", "جزر مارشال", "مقدونيا الشمالية", "مالي", "ميانمار (بورما)", "منغوليا", "منطقة ماكاو الإدارية الخاصة", "جزر ماريانا الشمالية", "جزر المارتينيك", "موريتانيا", "مونتسرات", "مالطا", "موريشيوس", "جزر المالديف", "ملاوي", "المكسيك", "ماليزيا", "موزمبيق", "ناميبيا", "كاليدونيا الجديدة", "النيجر", "جزيرة نورفولك", "نيجيريا", "نيكاراغوا", "هولندا", "النرويج", "نيبال", "ناورو", "نيوي", "نيوزيلندا", "عُمان", "بنما", "بيرو", "بولينيزيا الفرنسية", "بابوا غينيا الجديدة", "الفلبين", "باكستان", "بولندا", "سان بيير ومكويلون", "جزر بيتكيرن", "بورتوريكو", "الأراضي الفلسطينية", "البرتغال", "بالاو", "باراغواي", "قطر", "أوقيانوسيا النائية", "روينيون", "رومانيا", "صربيا", "روسيا", "رواندا", "المملكة العربية السعودية", "جزر سليمان", "سيشل", "السودان", "السويد", "سنغافورة", "سانت هيلينا", "سلوفينيا", "سفالبارد وجان ماين", "سلوفاكيا", "سيراليون", "سان مارينو", "السنغال", "الصومال", "سورينام", "جنوب السودان", "ساو تومي وبرينسيبي", "السلفادور", "سانت مارتن", "سوريا", "إسواتيني", "تريستان دا كونا", "جزر توركس وكايكوس", "تشاد", "الأقاليم الجنوبية الفرنسية", "توغو", "تايلاند", "طاجيكستان", "توكيلاو", "تيمور - ليشتي", "تركمانستان", "تونس", "تونغا", "تركيا", "ترينيداد وتوباغو", "توفالو", "تايوان", "تنزانيا", "أوكرانيا", "أوغندا", "جزر الولايات المتحدة النائية", "الأمم المتحدة", "الولايات المتحدة", "أورغواي", "أوزبكستان", "الفاتيكان", "سانت فنسنت وجزر غرينادين", "فنزويلا", "جزر فيرجن البريطانية", "جزر فيرجن الأمريكية", "فيتنام", "فانواتو", "جزر والس وفوتونا", "ساموا", "لكنات تجريبية غير أصلية", "لكنات تجريبية ثنائية الاتجاه", "كوسوفو", "اليمن", "مايوت", "جنوب أفريقيا", "زامبيا", "زيمبابوي"];
+
+// hy
+static R5: [&str; 294] = ["Աշխարհ", "Աֆրիկա", "Հյուսիսային Ամերիկա", "Հարավային Ամերիկա", "Օվկիանիա", "Արևմտյան Աֆրիկա", "Կենտրոնական Ամերիկա", "Արևելյան Աֆրիկա", "Հյուսիսային Աֆրիկա", "Կենտրոնական Աֆրիկա", "Հարավային Աֆրիկա", "Ամերիկա", "Հյուսիսային Ամերիկա - ԱՄՆ և Կանադա", "Կարիբներ", "Արևելյան Ասիա", "Հարավային Ասիա", "Հարավարևելյան Ասիա", "Հարավային Եվրոպա", "Ավստրալասիա", "Մելանեզիա", "Միկրոնեզյան տարածաշրջան", "Պոլինեզիա", "Ասիա", "Կենտրոնական Ասիա", "Արևմտյան Ասիա", "Եվրոպա", "Արևելյան Եվրոպա", "Հյուսիսային Եվրոպա", "Արևմտյան Եվրոպա", "Արևադարձային Աֆրիկա", "Լատինական Ամերիկա", "Համբարձման կղզի", "Անդորրա", "Արաբական Միացյալ Էմիրություններ", "Աֆղանստան", "Անտիգուա և Բարբուդա", "Անգուիլա", "Ալբանիա", "Հայաստան", "Անգոլա", "Անտարկտիդա", "Արգենտինա", "Ամերիկյան Սամոա", "Ավստրիա", "Ավստրալիա", "Արուբա", "Ալանդյան կղզիներ", "Ադրբեջան", "Բոսնիա և Հերցեգովինա", "Բարբադոս", "Բանգլադեշ", "Բելգիա", "Բուրկինա Ֆասո", "Բուլղարիա", "Բահրեյն", "Բուրունդի", "Բենին", "Սուրբ Բարդուղիմեոս", "Բերմուդներ", "Բրունեյ", "Բոլիվիա", "Կարիբյան Նիդեռլանդներ", "Բրազիլիա", "Բահամյան կղզիներ", "Բութան", "Բուվե կղզի", "Բոտսվանա", "Բելառուս", "Բելիզ", "Կանադա", "Կոկոսյան (Քիլինգ) կղզիներ", "Կոնգո - Կինշասա", "Կենտրոնական Աֆրիկյան Հանրապետություն", "Կոնգո - Բրազավիլ", "Շվեյցարիա", "Կոտ դ՚Իվուար", "Կուկի կղզիներ", "Չիլի", "Կամերուն", "Չինաստան", "Կոլումբիա", "Քլիփերթոն կղզի", "Սարկ կղզի", "Կոստա Ռիկա", "Կուբա", "Կաբո Վերդե", "Կյուրասաո", "Սուրբ Ծննդյան կղզի", "Կիպրոս", "Չեխիա", "Գերմանիա", "Դիեգո Գարսիա", "Ջիբութի", "Դանիա", "Դոմինիկա", "Դոմինիկյան Հանրապետություն", "Ալժիր", "Սեուտա և Մելիլյա", "Էկվադոր", "Էստոնիա", "Եգիպտոս", "Արևմտյան Սահարա", "Էրիթրեա", "Իսպանիա", "Եթովպիա", "Եվրոպական Միություն", "Եվրագոտի", "Ֆինլանդիա", "Ֆիջի", "Ֆոլքլենդյան կղզիներ", "Միկրոնեզիա", "Ֆարերյան կղզիներ", "Ֆրանսիա", "Գաբոն", "Միացյալ Թագավորություն", "Գրենադա", "Վրաստան", "Ֆրանսիական Գվիանա", "Գերնսի", "Գանա", "Ջիբրալթար", "Գրենլանդիա", "Գամբիա", "Գվինեա", "Գվադելուպա", "Հասարակածային Գվինեա", "Հունաստան", "Հարավային Ջորջիա և Հարավային Սենդվիչյան կղզիներ", "Գվատեմալա", "Գուամ", "Գվինեա-Բիսաու", "Գայանա", "Հոնկոնգի ՀՎՇ", "Հերդ կղզի և ՄակԴոնալդի կղզիներ", "Հոնդուրաս", "Խորվաթիա", "Հայիթի", "Հունգարիա", "Կանարյան կղզիներ", "Ինդոնեզիա", "Իռլանդիա", "Իսրայել", "Մեն կղզի", "Հնդկաստան", "Բրիտանական տարածք Հնդկական Օվկիանոսում", "Իրաք", "Իրան", "Իսլանդիա", "Իտալիա", "Ջերսի", "Ճամայկա", "Հորդանան", "Ճապոնիա", "Քենիա", "Ղրղզստան", "Կամբոջա", "Կիրիբատի", "Կոմորյան կղզիներ", "Սենթ Քիտս և Նևիս", "Հյուսիսային Կորեա", "Հարավային Կորեա", "Քուվեյթ", "Կայմանյան կղզիներ", "Ղազախստան", "Լաոս", "Լիբանան", "Սենթ Լյուսիա", "Լիխտենշտեյն", "Շրի Լանկա", "Լիբերիա", "Լեսոտո", "Լիտվա", "Լյուքսեմբուրգ", "Լատվիա", "Լիբիա", "Մարոկկո", "Մոնակո", "Մոլդովա", "Չեռնոգորիա", "Սեն Մարտեն", "Մադագասկար", "Մարշալյան կղզիներ", "Հյուսիսային Մակեդոնիա", "Մալի", "Մյանմա (Բիրմա)", "Մոնղոլիա", "Չինաստանի Մակաո ՀՎՇ", "Հյուսիսային Մարիանյան կղզիներ", "Մարտինիկա", "Մավրիտանիա", "Մոնսեռատ", "Մալթա", "Մավրիկիոս", "Մալդիվներ", "Մալավի", "Մեքսիկա", "Մալայզիա", "Մոզամբիկ", "Նամիբիա", "Նոր Կալեդոնիա", "Նիգեր", "Նորֆոլկ կղզի", "Նիգերիա", "Նիկարագուա", "Նիդեռլանդներ", "Նորվեգիա", "Նեպալ", "Նաուրու", "Նիուե", "Նոր Զելանդիա", "Օման", "Պանամա", "Պերու", "Ֆրանսիական Պոլինեզիա", "Պապուա Նոր Գվինեա", "Ֆիլիպիններ", "Պակիստան", "Լեհաստան", "Սեն Պիեռ և Միքելոն", "Պիտկեռն կղզիներ", "Պուերտո Ռիկո", "Պաղեստինյան տարածքներ", "Պորտուգալիա", "Պալաու", "Պարագվայ", "Կատար", "Արտաքին Օվկիանիա", "Ռեյունիոն", "Ռումինիա", "Սերբիա", "Ռուսաստան", "Ռուանդա", "Սաուդյան Արաբիա", "Սողոմոնյան կղզիներ", "Սեյշելներ", "Սուդան", "Շվեդիա", "Սինգապուր", "Սուրբ Հեղինեի կղզի", "Սլովենիա", "Սվալբարդ և Յան Մայեն", "Սլովակիա", "Սիեռա Լեոնե", "Սան Մարինո", "Սենեգալ", "Սոմալի", "Սուրինամ", "Հարավային Սուդան", "Սան Տոմե և Փրինսիպի", "Սալվադոր", "Սինտ Մարտեն", "Սիրիա", "Էսվատինի", "Տրիստան դա Կունյա", "Թըրքս և Կայկոս կղզիներ", "Չադ", "Ֆրանսիական Հարավային Տարածքներ", "Տոգո", "Թաիլանդ", "Տաջիկստան", "Տոկելաու", "Թիմոր Լեշտի", "Թուրքմենստան", "Թունիս", "Տոնգա", "Թուրքիա", "Տրինիդադ և Տոբագո", "Տուվալու", "Թայվան", "Տանզանիա", "Ուկրաինա", "Ուգանդա", "Արտաքին կղզիներ (ԱՄՆ)", "Միավորված ազգերի կազմակերպություն", "Միացյալ Նահանգներ", "Ուրուգվայ", "Ուզբեկստան", "Վատիկան", "Սենթ Վինսենթ և Գրենադիններ", "Վենեսուելա", "Բրիտանական Վիրջինյան կղզիներ", "ԱՄՆ Վիրջինյան կղզիներ", "Վիետնամ", "Վանուատու", "Ուոլիս և Ֆուտունա", "Սամոա", "կեղծ տարբերիչներ", "կեղծ երկուղղված", "Կոսովո", "Եմեն", "Մայոտ", "Հարավաֆրիկյան Հանրապետություն", "Զամբիա", "Զիմբաբվե"];
+
+// as
+static R6: [&str; 294] = ["বিশ্ব", "আফ্ৰিকা", "উত্তৰ আমেৰিকা", "দক্ষিণ আমেৰিকা", "অ’চেনীয়া", "পাশ্চাত্য আফ্ৰিকা", "মধ্য আমেৰিকা", "প্ৰাচ্য আফ্ৰিকা", "উদীচ্য আফ্ৰিকা", "মধ্য আফ্ৰিকা", "দাক্ষিণাত্য আফ্ৰিকা", "আমেৰিকাছ্", "উদীচ্য আমেৰিকা", "কেৰিবিয়ান", "প্ৰাচ্য এছিয়া", "দাক্ষিণাত্য এছিয়া", "দক্ষিণ-পূব এছিয়া", "দাক্ষিণাত্য ইউৰোপ", "অষ্ট্ৰেলেছিয়া", "মেলানেছিয়া", "মাইক্ৰোনেচিয় ক্ষেত্ৰ", "পলিনেচিয়া", "এছিয়া", "মধ্য এছিয়া", "পাশ্চাত্য এছিয়া", "ইউৰোপ", "প্ৰাচ্য ইউৰোপ", "উদীচ্য ইউৰোপ", "পাশ্চাত্য ইউৰোপ", "উপ-ছাহাৰান আফ্ৰিকা", "লেটিন আমেৰিকা", "এচেনচিয়ন দ্বীপ", "আন্দোৰা", "সংযুক্ত আৰব আমিৰাত", "আফগানিস্তান", "এণ্টিগুৱা আৰু বাৰ্বুডা", "এনগুইলা", "আলবেনিয়া", "আৰ্মেনিয়া", "এংগোলা", "এণ্টাৰ্কটিকা", "আৰ্জেণ্টিনা", "আমেৰিকান চামোৱা", "অষ্ট্ৰিয়া", "অষ্ট্ৰেলিয়া", "আৰুবা", "আলণ্ড দ্বীপপুঞ্জ", "আজাৰবেইজান", "ব’ছনিয়া আৰু হাৰ্জেগ’ভিনা", "বাৰ্বাডোচ", "বাংলাদেশ", "বেলজিয়াম", "বুৰকিনা ফাচো", "বুলগেৰিয়া", "বাহৰেইন", "বুৰুণ্ডি", "বেনিন", "ছেইণ্ট বাৰ্থলেমে", "বাৰ্মুডা", "ব্ৰুনেই", "বলিভিয়া", "কেৰিবিয়ান নেদাৰলেণ্ডছ", "ব্ৰাজিল", "বাহামাছ", "ভুটান", "বুভে দ্বীপ", "ব’টচোৱানা", "বেলাৰুছ", "বেলিজ", "কানাডা", "কোকোচ (কীলিং) দ্বীপপুঞ্জ", "কঙ্গো - কিনচাছা", "মধ্য আফ্রিকান প্রজাতন্ত্র", "কঙ্গো - ব্রাজাভিল", "চুইজাৰলেণ্ড", "কোটে ডি আইভৰ", "কুক দ্বীপপুঞ্জ", "চিলি", "কেমেৰুণ", "চীন", "কলম্বিয়া", "ক্লিপাৰটোন দ্বীপ", "চাৰ্ক", "কোষ্টা ৰিকা", "কিউবা", "কেপ ভার্দে", "কুৰাকাও", "খ্ৰীষ্টমাছ দ্বীপ", "চাইপ্ৰাছ", "চিজেচিয়া", "জাৰ্মানী", "ডিয়েগো গাৰ্চিয়া", "জিবুটি", "ডেনমাৰ্ক", "ড’মিনিকা", "ড’মিনিকান ৰিপাব্লিক", "আলজেৰিয়া", "চেউটা আৰু মেলিলা", "ইকুৱেডৰ", "ইষ্টোনিয়া", "ইজিপ্ত", "পশ্চিমীয় ছাহাৰা", "এৰিত্ৰিয়া", "স্পেইন", "ইথিঅ’পিয়া", "ইউৰোপীয় সংঘ", "ইউৰোজ’ন", "ফিনলেণ্ড", "ফিজি", "ফকলেণ্ড দ্বীপপুঞ্জ", "মাইক্ৰোনেচিয়া", "ফাৰো দ্বীপপুঞ্জ", "ফ্ৰান্স", "গেবন", "সংযুক্ত ৰাজ্য", "গ্ৰেনাডা", "জৰ্জিয়া", "ফ্ৰান্স গয়ানা", "গোৰেনচি", "ঘানা", "জিব্ৰাল্টৰ", "গ্ৰীণলেণ্ড", "গাম্বিয়া", "গিনি", "গুৱাডেলুপ", "ইকুৱেটৰিয়েল গিনি", "গ্ৰীচ", "দক্ষিণ জৰ্জিয়া আৰু দক্ষিণ চেণ্ডৱিচ দ্বীপপুঞ্জ", "গুৱাটেমালা", "গুৱাম", "গিনি-বিছাও", "গায়ানা", "হং কং এছ. এ. আৰ. চীন", "হাৰ্ড দ্বীপ আৰু মেকডোনাল্ড দ্বীপপুঞ্জ", "হন্দুৰাছ", "ক্ৰোৱেছিয়া", "হাইটি", "হাংগেৰী", "কেনেৰী দ্বীপপুঞ্জ", "ইণ্ডোনেচিয়া", "আয়াৰলেণ্ড", "ইজৰাইল", "আইল অফ মেন", "ভাৰত", "ব্ৰিটিছ ইণ্ডিয়ান অ’চন টেৰিট’ৰি", "ইৰাক", "ইৰান", "আইচলেণ্ড", "ইটালি", "জাৰ্চি", "জামাইকা", "জৰ্ডান", "জাপান", "কেনিয়া", "কিৰ্গিজস্তান", "কম্বোডিয়া", "কিৰিবাটি", "কোমোৰোজ", "ছেইণ্ট কিটছ আৰু নেভিছ", "উত্তৰ কোৰিয়া", "দক্ষিণ কোৰিয়া", "কুৱেইট", "কেইমেন দ্বীপপুঞ্জ", "কাজাখাস্তান", "লাওচ", "লেবানন", "ছেইণ্ট লুচিয়া", "লিচটেনষ্টেইন", "শ্রীলংকা", "লিবেৰিয়া", "লেছ’থ’", "লিথুৱানিয়া", "লাক্সেমবাৰ্গ", "লাটভিয়া", "লিবিয়া", "মৰক্কো", "মোনাকো", "মোলডোভা", "মণ্টেনেগ্ৰু", "ছেইণ্ট মাৰ্টিন", "মাদাগাস্কাৰ", "মাৰ্শ্বাল দ্বীপপুঞ্জ", "উত্তৰ মেচিডোনীয়া", "মালি", "ম্যানমাৰ (বাৰ্মা)", "মঙ্গোলিয়া", "মাকাও এছ. এ. আৰ. চীন", "উত্তৰ মাৰিয়ানা দ্বীপপুঞ্জ", "মাৰ্টিনিক", "মাউৰিটানিয়া", "ম’ণ্টছেৰাট", "মাল্টা", "মৰিছাছ", "মালদ্বীপ", "মালাৱি", "মেক্সিকো", "মালয়েচিয়া", "ম’জামবিক", "নামিবিয়া", "নিউ কেলিডোনিয়া", "নাইজাৰ", "ন’ৰফ’ক দ্বীপ", "নাইজেৰিয়া", "নিকাৰাগুৱা", "নেডাৰলেণ্ড", "নৰৱে", "নেপাল", "নাউৰু", "নিউ", "নিউজিলেণ্ড", "ওমান", "পানামা", "পেৰু", "ফ্ৰান্স পোলেনচিয়া", "পাপুৱা নিউ গিনি", "ফিলিপাইনছ", "পাকিস্তান", "পোলেণ্ড", "ছেইণ্ট পিয়েৰে আৰু মিকিউৱেলন", "পিটকেইৰ্ণ দ্বীপপুঞ্জ", "পুৱেৰ্টো ৰিকো", "ফিলিস্তিন অঞ্চল", "পৰ্তুগাল", "পালাউ", "পাৰাগুৱে", "কাটাৰ", "আউটলায়িং অ’চিয়ানিয়া", "ৰিইউনিয়ন", "ৰোমানিয়া", "ছাৰ্বিয়া", "ৰাছিয়া", "ৰোৱাণ্ডা", "চৌডি আৰবিয়া", "চোলোমোন দ্বীপপুঞ্জ", "ছিচিলিছ", "চুডান", "চুইডেন", "ছিংগাপুৰ", "ছেইণ্ট হেলেনা", "শ্লোভেনিয়া", "চাভালবাৰ্ড আৰু জন মেয়ন", "শ্লোভাকিয়া", "চিয়েৰা লিঅ’ন", "চান মাৰিনো", "চেনেগাল", "চোমালিয়া", "ছুৰিনাম", "দক্ষিণ চুডান", "চাও টোমে আৰু প্ৰিনচিপে", "এল ছেলভেড’ৰ", "চিণ্ট মাৰ্টেন", "চিৰিয়া", "ইচ্চুটিনি", "ত্ৰিস্তান দ্যা কুনহা", "টাৰ্কছ অৰু কেইক’ছ দ্বীপপুঞ্জ", "চাড", "দক্ষিণ ফ্ৰান্সৰ অঞ্চল", "টোগো", "থাইলেণ্ড", "তাজিকিস্তান", "টোকেলাউ", "টিমোৰ-লেচটে", "তুৰ্কমেনিস্তান", "টুনিচিয়া", "টংগা", "তুৰ্কিয়ে", "ট্ৰিনিডাড আৰু টোবাগো", "টুভালু", "টাইৱান", "তাঞ্জানিয়া", "ইউক্ৰেইন", "উগাণ্ডা", "ইউ. এছ. আউটলায়িং দ্বীপপুঞ্জ", "ৰাষ্ট্ৰসংঘ", "মাৰ্কিন যুক্তৰাষ্ট্ৰ", "উৰুগুৱে", "উজবেকিস্তান", "ভেটিকান চিটি", "ছেইণ্ট ভিনচেণ্ট আৰু গ্ৰীণাডাইনছ", "ভেনিজুৱেলা", "ব্ৰিটিছ ভাৰ্জিন দ্বীপপুঞ্জ", "ইউ. এছ. ভাৰ্জিন দ্বীপপুঞ্জ", "ভিয়েটনাম", "ভানাটু", "ৱালিছ আৰু ফুটুনা", "চামোৱা", "ছুডু-একচেণ্ট", "ছুডু-বিডি", "কচ’ভ’", "য়েমেন", "মায়োট্টে", "দক্ষিণ আফ্রিকা", "জাম্বিয়া", "জিম্বাবৱে"];
+
+// az
+static R7: [&str; 294] = ["Dünya", "Afrika", "Şimali Amerika", "Cənubi Amerika", "Okeaniya", "Qərbi Afrika", "Mərkəzi Amerika", "Şərqi Afrika", "Şimali Afrika", "Mərkəzi Afrika", "Cənubi Afrika", "Amerika", "Şimal Amerikası", "Karib", "Şərqi Asiya", "Cənubi Asiya", "Cənub-Şərqi Asiya", "Cənubi Avropa", "Avstralaziya", "Melaneziya", "Mikroneziya Regionu", "Polineziya", "Asiya", "Mərkəzi Asiya", "Qərbi Asiya", "Avropa", "Şərqi Avropa", "Şimali Avropa", "Qərbi Avropa", "Saharadan cənub", "Latın Amerikası", "Askenson adası", "Andorra", "Birləşmiş Ərəb Əmirlikləri", "Əfqanıstan", "Antiqua və Barbuda", "Angilya", "Albaniya", "Ermənistan", "Anqola", "Antarktika", "Argentina", "Amerika Samoası", "Avstriya", "Avstraliya", "Aruba", "Aland adaları", "Azərbaycan", "Bosniya və Herseqovina", "Barbados", "Banqladeş", "Belçika", "Burkina Faso", "Bolqarıstan", "Bəhreyn", "Burundi", "Benin", "Sent-Bartelemi", "Bermud adaları", "Bruney", "Boliviya", "Karib Niderlandı", "Braziliya", "Baham adaları", "Butan", "Buve adası", "Botsvana", "Belarus", "Beliz", "Kanada", "Kokos (Kilinq) adaları", "Konqo - Kinşasa", "Mərkəzi Afrika Respublikası", "Konqo - Brazzavil", "İsveçrə", "Kotd’ivuar", "Kuk adaları", "Çili", "Kamerun", "Çin", "Kolumbiya", "Klipperton adası", "sark (ingilis dili) dialekti", "Kosta Rika", "Kuba", "Kabo-Verde", "Kurasao", "Milad adası", "Kipr", "Çexiya", "Almaniya", "Dieqo Qarsiya", "Cibuti", "Danimarka", "Dominika", "Dominikan Respublikası", "Əlcəzair", "Seuta və Melilya", "Ekvador", "Estoniya", "Misir", "Qərbi Saxara", "Eritreya", "İspaniya", "Efiopiya", "Avropa Birliyi", "Avrozona", "Finlandiya", "Fici", "Folklend adaları", "Mikroneziya", "Farer adaları", "Fransa", "Qabon", "Birləşmiş Krallıq", "Qrenada", "Gürcüstan", "Fransa Qvianası", "Gernsi", "Qana", "Cəbəllütariq", "Qrenlandiya", "Qambiya", "Qvineya", "Qvadelupa", "Ekvatorial Qvineya", "Yunanıstan", "Cənubi Corciya və Cənubi Sendviç adaları", "Qvatemala", "Quam", "Qvineya-Bisau", "Qayana", "Honq Konq Xüsusi İnzibati Rayonu Çin", "Herd və Makdonald adaları", "Honduras", "Xorvatiya", "Haiti", "Macarıstan", "Kanar adaları", "İndoneziya", "İrlandiya", "İsrail", "Men adası", "Hindistan", "Britaniyanın Hind Okeanı Ərazisi", "İraq", "İran", "İslandiya", "İtaliya", "Cersi", "Yamayka", "İordaniya", "Yaponiya", "Keniya", "Qırğızıstan", "Kamboca", "Kiribati", "Komor adaları", "Sent-Kits və Nevis", "Şimali Koreya", "Cənubi Koreya", "Küveyt", "Kayman adaları", "Qazaxıstan", "Laos", "Livan", "Sent-Lusiya", "Lixtenşteyn", "Şri-Lanka", "Liberiya", "Lesoto", "Litva", "Lüksemburq", "Latviya", "Liviya", "Mərakeş", "Monako", "Moldova", "Monteneqro", "Sent Martin", "Madaqaskar", "Marşal adaları", "Şimali Makedoniya", "Mali", "Myanma", "Monqolustan", "Makao XİR Çin", "Şimali Marian adaları", "Martinik", "Mavritaniya", "Monserat", "Malta", "Mavriki", "Maldiv adaları", "Malavi", "Meksika", "Malayziya", "Mozambik", "Namibiya", "Yeni Kaledoniya", "Niger", "Norfolk adası", "Nigeriya", "Nikaraqua", "Niderland", "Norveç", "Nepal", "Nauru", "Niue", "Yeni Zelandiya", "Oman", "Panama", "Peru", "Fransa Polineziyası", "Papua-Yeni Qvineya", "Filippin", "Pakistan", "Polşa", "Müqəddəs Pyer və Mikelon", "Pitkern adaları", "Puerto Riko", "Fələstin Əraziləri", "Portuqaliya", "Palau", "Paraqvay", "Qətər", "Uzaq Okeaniya", "Reyunyon", "Rumıniya", "Serbiya", "Rusiya", "Ruanda", "Səudiyyə Ərəbistanı", "Solomon adaları", "Seyşel adaları", "Sudan", "İsveç", "Sinqapur", "Müqəddəs Yelena", "Sloveniya", "Svalbard və Yan-Mayen", "Slovakiya", "Syerra-Leone", "San-Marino", "Seneqal", "Somali", "Surinam", "Cənubi Sudan", "San-Tome və Prinsipi", "Salvador", "Sint-Marten", "Suriya", "Esvatini", "Tristan da Kunya", "Törks və Kaykos adaları", "Çad", "Fransanın Cənub Əraziləri", "Toqo", "Tailand", "Tacikistan", "Tokelau", "Şərqi Timor", "Türkmənistan", "Tunis", "Tonqa", "Türkiyə", "Trinidad və Tobaqo", "Tuvalu", "Tayvan", "Tanzaniya", "Ukrayna", "Uqanda", "ABŞ-a bağlı kiçik adacıqlar", "Birləşmiş Millətlər Təşkilatı", "Amerika Birləşmiş Ştatları", "Uruqvay", "Özbəkistan", "Vatikan", "Sent-Vinsent və Qrenadinlər", "Venesuela", "Britaniyanın Virgin adaları", "ABŞ Virgin adaları", "Vyetnam", "Vanuatu", "Uollis və Futuna", "Samoa", "Psevdo-Aksent", "Psevdo-Bidi", "Kosovo", "Yəmən", "Mayot", "Cənub Afrika", "Zambiya", "Zimbabve"];
+
+// ba
+static R8: [&str; 294] = ["донъя", "Африка", "Төньяҡ Америка", "Көньяҡ Америка", "Океания", "Көнбайыш Африка", "Үҙәк Америка", "Көнсығыш Африка", "Төньяҡ Африка", "Үҙәк Африка", "Көньяҡ Африка", "Америка", "Төньяҡ Америка төбәге", "Кариб бассейны", "Көнсығыш Азия", "Көньяҡ Азия", "Көньяҡ-Көнсығыш Азия", "Көньяҡ Европа", "Австралазия", "Меланезия", "Микронезия төбәге", "Полинезия", "Азия", "Үҙәк Азия", "Көнбайыш Азия", "Европа", "Көнсығыш Европа", "Төньяҡ Европа", "Көнбайыш Европа", "Тропик Африка", "Латин Америка", "Асеншен утрауы", "Андорра", "Берләшкән Ғәрәп Әмирлектәре", "Афғанстан", "Антигуа һәм Барбуда", "Ангилья", "Албания", "Әрмәнстан", "Ангола", "Антарктида", "Аргентина", "Америка Самоаһы", "Австрия", "Австралия", "Аруба", "Аланд утрауҙары", "Әзербайжан", "Босния һәм Герцеговина", "Барбадос", "Бангладеш", "Бельгия", "Буркина-Фасо", "Болгария", "Бәхрәйн", "Бурунди", "Бенин", "Сен-Бартелеми", "Бермуд утрауҙары", "Бруней", "Боливия", "Карибтағы Нидерланд", "Бразилия", "Багам утрауҙары", "Бутан", "Буве утрауы", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокос утрауҙары", "Конго - Киншаса", "Үҙәк Африка Республикаһы", "Конго - Браззавиль", "Швейцария", "Кот-д’Ивуар", "Кук утрауҙары", "Чили", "Камерун", "Ҡытай", "Колумбия", "Клиппертон утрауы", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Раштыуа Утрауы", "Кипр", "Чехия", "Германия", "Диего-Гарсия", "Джибути", "Дания", "Доминика", "Доминикан Республикаһы", "Алжир", "Сеута һәм Мелилья", "Эквадор", "Эстония", "Мысыр", "Көнбайыш Сахара", "Эритрея", "Испания", "Эфиопия", "Европа Берлеге", "Еврозона", "Финляндия", "Фиджи", "Фолкленд утрауҙары", "Микронезия", "Фарер утрауҙары", "Франция", "Габон", "Бөйөк Британия", "Гренада", "Грузия", "Француз Гвианаһы", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Экваториаль Гвинея", "Греция", "Көньяҡ Георгия һәм Көньяҡ Сандвич утрауҙары", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Гонконг, Ҡытай МАР-ы", "Херд һәм Макдональд утрауҙары", "Гондурас", "Хорватия", "Гаити", "Венгрия", "Канар утрауҙары", "Индонезия", "Ирландия", "Израиль", "Мэн утрауы", "Һиндостан", "Британияның Һинд океанындағы биләмәһе", "Ираҡ", "Иран", "Исландия", "Италия", "Джерси", "Ямайка", "Иордания", "Япония", "Кения", "Ҡырғыҙстан", "Камбоджа", "Кирибати", "Комор утрауҙары", "Сент-Китс һәм Невис", "Төньяҡ Корея", "Көньяҡ Корея", "Кувейт", "Кайман утрауҙары", "Ҡаҙағстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова", "Черногория", "Сен-Мартен", "Мадагаскар", "Маршалл утрауҙары", "Төньяҡ Македония", "Мали", "Мьянма (Бирма)", "Монголия", "Макао, Ҡытай МАР-ы", "Төньяҡ Мариан утрауҙары", "Мартиника", "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдив утрауҙары", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Яңы Каледония", "Нигер", "Норфолк утрауы", "Нигерия", "Никарагуа", "Нидерланд", "Норвегия", "Непал", "Науру", "Ниуэ", "Яңы Зеландия", "Оман", "Панама", "Перу", "Француз Полинезияһы", "Папуа – Яңы Гвинея", "Филиппин", "Пакистан", "Польша", "Сен-Пьер һәм Микелон", "Питкэрн утрауҙары", "Пуэрто-Рико", "Фәләстин территориялары", "Португалия", "Палау", "Парагвай", "Катар", "Тышҡы Океания", "Реюньон", "Румыния", "Сербия", "Рәсәй", "Руанда", "Сәғүд Ғәрәбстаны", "Соломон утрауҙары", "Сейшель утрауҙары", "Судан", "Швеция", "Сингапур", "Изге Елена утрауы", "Словения", "Шпицберген һәм Ян-Майен", "Словакия", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Көньяҡ Судан", "Сан-Томе һәм Принсипи", "Сальвадор", "Синт-Мартен", "Сүриә", "Эсватини", "Тристан-да-Кунья", "Төркс һәм Кайкос утрауҙары", "Чад", "Францияның Көньяҡ Биләмәләре", "Того", "Тайланд", "Тажикстан", "Токелау", "Тимор-Лесте", "Төркмәнстан", "Тунис", "Тонга", "Төркиә", "Тринидад һәм Тобаго", "Тувалу", "Тайвань", "Танзания", "Украина", "Уганда", "АҠШ-ның ситтәге утрауҙары", "Берләшкән Милләттәр Ойошмаһы", "Америка Ҡушма Штаттары", "Уругвай", "Үзбәкстан", "Ватикан", "Сент-Винсент һәм Гренадиндар", "Венесуэла", "Британия Виргин утрауҙары", "АҠШ Виргин утрауҙары", "Вьетнам", "Вануату", "Уоллис һәм Футуна", "Самоа", "псевдоакценттар", "псевдо ике яҡлы", "Косово", "Йәмән", "Майотта", "Көньяҡ Африка Республикаһы", "Замбия", "Зимбабве"];
+
+// eu
+static R9: [&str; 294] = ["Mundua", "Afrika", "Ipar Amerika", "Hego Amerika", "Ozeania", "Mendebaldeko Afrika", "Erdialdeko Amerika", "Ekialdeko Afrika", "Ipar Afrika", "Erdialdeko Afrika", "Hegoaldeko Afrika", "Amerika", "Amerikako iparraldea", "Karibea", "Ekialdeko Asia", "Hego Asia", "Hego-ekialdeko Asia", "Hego Europa", "Australasia", "Melanesia", "Mikronesia eskualdea", "Polinesia", "Asia", "Erdialdeko Asia", "Mendebaldeko Asia", "Europa", "Ekialdeko Europa", "Ipar Europa", "Mendebaldeko Europa", "Saharaz hegoaldeko Afrika", "Latinoamerika", "Ascension uhartea", "Andorra", "Arabiar Emirerri Batuak", "Afganistan", "Antigua eta Barbuda", "Aingira", "Albania", "Armenia", "Angola", "Antartika", "Argentina", "Samoa Estatubatuarra", "Austria", "Australia", "Aruba", "Åland", "Azerbaijan", "Bosnia-Herzegovina", "Barbados", "Bangladesh", "Belgika", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibeko Herbehereak", "Brasil", "Bahamak", "Bhutan", "Bouvet uhartea", "Botswana", "Bielorrusia", "Belize", "Kanada", "Cocos (Keeling) uharteak", "Kongoko Errepublika Demokratikoa", "Afrika Erdiko Errepublika", "Kongo", "Suitza", "Boli Kosta", "Cook uharteak", "Txile", "Kamerun", "Txina", "Kolonbia", "Clipperton uhartea", "Sark", "Costa Rica", "Kuba", "Cabo Verde", "Curaçao", "Christmas uhartea", "Zipre", "Txekia", "Alemania", "Diego García", "Djibuti", "Danimarka", "Dominika", "Dominikar Errepublika", "Aljeria", "Ceuta eta Melilla", "Ekuador", "Estonia", "Egipto", "Mendebaldeko Sahara", "Eritrea", "Espainia", "Etiopia", "Europar Batasuna", "Eurogunea", "Finlandia", "Fiji", "Falklandak", "Mikronesia", "Faroe uharteak", "Frantzia", "Gabon", "Erresuma Batua", "Grenada", "Georgia", "Guyana Frantsesa", "Guernesey", "Ghana", "Gibraltar", "Groenlandia", "Gambia", "Ginea", "Guadalupe", "Ekuatore Ginea", "Grezia", "Hegoaldeko Georgia eta Hegoaldeko Sandwich uharteak", "Guatemala", "Guam", "Ginea Bissau", "Guyana", "Hong Kong Txinako AEB", "Heard eta McDonald uharteak", "Honduras", "Kroazia", "Haiti", "Hungaria", "Kanariak", "Indonesia", "Irlanda", "Israel", "Man uhartea", "India", "Indiako Ozeanoko lurralde britainiarra", "Irak", "Iran", "Islandia", "Italia", "Jersey", "Jamaika", "Jordania", "Japonia", "Kenya", "Kirgizistan", "Kanbodia", "Kiribati", "Komoreak", "Saint Kitts eta Nevis", "Ipar Korea", "Hego Korea", "Kuwait", "Kaiman uharteak", "Kazakhstan", "Laos", "Libano", "Santa Luzia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxenburgo", "Letonia", "Libia", "Maroko", "Monako", "Moldavia", "Montenegro", "San Martin", "Madagaskar", "Marshall Uharteak", "Ipar Mazedonia", "Mali", "Myanmar (Birmania)", "Mongolia", "Macau Txinako AEB", "Ipar Mariana uharteak", "Martinika", "Mauritania", "Montserrat", "Malta", "Maurizio", "Maldivak", "Malawi", "Mexiko", "Malaysia", "Mozambike", "Namibia", "Kaledonia Berria", "Niger", "Norfolk uhartea", "Nigeria", "Nikaragua", "Herbehereak", "Norvegia", "Nepal", "Nauru", "Niue", "Zeelanda Berria", "Oman", "Panama", "Peru", "Polinesia Frantsesa", "Papua Ginea Berria", "Filipinak", "Pakistan", "Polonia", "Saint-Pierre eta Mikelune", "Pitcairn uharteak", "Puerto Rico", "Palestinar Lurralde Okupatuak", "Portugal", "Palau", "Paraguai", "Qatar", "Mugaz kanpoko Ozeania", "Reunion", "Errumania", "Serbia", "Errusia", "Ruanda", "Saudi Arabia", "Salomon Uharteak", "Seychelleak", "Sudan", "Suedia", "Singapur", "Santa Helena", "Eslovenia", "Svalbard eta Jan Mayen uharteak", "Eslovakia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam", "Hego Sudan", "Sao Tome eta Principe", "El Salvador", "Sint Maarten", "Siria", "Swazilandia", "Tristan da Cunha", "Turk eta Caico uharteak", "Txad", "Hegoaldeko lurralde frantsesak", "Togo", "Thailandia", "Tajikistan", "Tokelau", "Ekialdeko Timor", "Turkmenistan", "Tunisia", "Tonga", "Turkia", "Trinidad eta Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "Ameriketako Estatu Batuetako Kanpoaldeko Uharte Txikiak", "Nazio Batuak", "Ameriketako Estatu Batuak", "Uruguai", "Uzbekistan", "Vatikano Hiria", "Saint Vincent eta Grenadinak", "Venezuela", "Birjina uharte britainiarrak", "Birjina uharte amerikarrak", "Vietnam", "Vanuatu", "Wallis eta Futuna", "Samoa", "Sasiazentuak", "Pseudobidia", "Kosovo", "Yemen", "Mayotte", "Hegoafrika", "Zambia", "Zimbabwe"];
+
+// be
+static R10: [&str; 294] = ["Свет", "Афрыка", "Паўночная Амерыка", "Паўднёвая Амерыка", "Акіянія", "Заходняя Афрыка", "Цэнтральная Амерыка", "Усходняя Афрыка", "Паўночная Афрыка", "Сярэдняя Афрыка", "Паўднёвая Афрыка", "Паўночная і Паўднёвая Амерыкі", "Паўночнаамерыканскі рэгіён", "Карыбскі рэгіён", "Усходняя Азія", "Паўднёвая Азія", "Паўднёва-Усходняя Азія", "Паўднёвая Еўропа", "Аўстралазія", "Меланезія", "Мікранезійскі рэгіён", "Палінезія", "Азія", "Цэнтральная Азія", "Заходняя Азія", "Еўропа", "Усходняя Еўропа", "Паўночная Еўропа", "Заходняя Еўропа", "Трапічная Афрыка", "Лацінская Амерыка", "Востраў Узнясення", "Андора", "Аб’яднаныя Арабскія Эміраты", "Афганістан", "Антыгуа і Барбуда", "Ангілья", "Албанія", "Арменія", "Ангола", "Антарктыка", "Аргенціна", "Амерыканскае Самоа", "Аўстрыя", "Аўстралія", "Аруба", "Аландскія астравы", "Азербайджан", "Боснія і Герцагавіна", "Барбадас", "Бангладэш", "Бельгія", "Буркіна-Фасо", "Балгарыя", "Бахрэйн", "Бурундзі", "Бенін", "Сен-Бартэльмі", "Бермудскія астравы", "Бруней", "Балівія", "Карыбскія Нідэрланды", "Бразілія", "Багамскія астравы", "Бутан", "Востраў Бувэ", "Батсвана", "Беларусь", "Беліз", "Канада", "Какосавыя (Кілінг) астравы", "Конга (Кіншаса)", "Цэнтральна-Афрыканская Рэспубліка", "Конга - Бразавіль", "Швейцарыя", "Кот-д’Івуар", "Астравы Кука", "Чылі", "Камерун", "Кітай", "Калумбія", "Востраў Кліпертан", "Сарк", "Коста-Рыка", "Куба", "Каба-Вердэ", "Кюрасаа", "Востраў Каляд", "Кіпр", "Чэхія", "Германія", "Востраў Дыега-Гарсія", "Джыбуці", "Данія", "Дамініка", "Дамініканская Рэспубліка", "Алжыр", "Сеута і Мелілья", "Эквадор", "Эстонія", "Егіпет", "Заходняя Сахара", "Эрытрэя", "Іспанія", "Эфіопія", "Еўрапейскі саюз", "Еўразона", "Фінляндыя", "Фіджы", "Фалклендскія астравы", "Мікранезія", "Фарэрскія астравы", "Францыя", "Габон", "Вялікабрытанія", "Грэнада", "Грузія", "Французская Гвіяна", "Гернсі", "Гана", "Гібралтар", "Грэнландыя", "Гамбія", "Гвінея", "Гвадэлупа", "Экватарыяльная Гвінея", "Грэцыя", "Паўднёвая Георгія і Паўднёвыя Сандвічавы астравы", "Гватэмала", "Гуам", "Гвінея-Бісау", "Гаяна", "Ганконг, САР (Кітай)", "Астравы Херд і Макдональд", "Гандурас", "Харватыя", "Гаіці", "Венгрыя", "Канарскія астравы", "Інданезія", "Ірландыя", "Ізраіль", "Востраў Мэн", "Індыя", "Брытанская тэрыторыя ў Індыйскім акіяне", "Ірак", "Іран", "Ісландыя", "Італія", "Джэрсі", "Ямайка", "Іарданія", "Японія", "Кенія", "Кыргызстан", "Камбоджа", "Кірыбаці", "Каморскія астравы", "Сент-Кітс і Невіс", "Паўночная Карэя", "Паўднёвая Карэя", "Кувейт", "Кайманавы астравы", "Казахстан", "Лаос", "Ліван", "Сент-Люсія", "Ліхтэнштэйн", "Шры-Ланка", "Ліберыя", "Лесота", "Літва", "Люксембург", "Латвія", "Лівія", "Марока", "Манака", "Малдова", "Чарнагорыя", "Сен-Мартэн", "Мадагаскар", "Маршалавы астравы", "Паўночная Македонія", "Малі", "М’янма (Бірма)", "Манголія", "Макаа, САР (Кітай)", "Паўночныя Марыянскія астравы", "Марцініка", "Маўрытанія", "Мантсерат", "Мальта", "Маўрыкій", "Мальдывы", "Малаві", "Мексіка", "Малайзія", "Мазамбік", "Намібія", "Новая Каледонія", "Нігер", "Востраў Норфалк", "Нігерыя", "Нікарагуа", "Нідэрланды", "Нарвегія", "Непал", "Науру", "Ніуэ", "Новая Зеландыя", "Аман", "Панама", "Перу", "Французская Палінезія", "Папуа-Новая Гвінея", "Філіпіны", "Пакістан", "Польшча", "Сен-П’ер і Мікелон", "Астравы Піткэрн", "Пуэрта-Рыка", "Палесцінскія Тэрыторыі", "Партугалія", "Палау", "Парагвай", "Катар", "Аддаленыя тэрыторыі Акіяніі", "Рэюньён", "Румынія", "Сербія", "Расія", "Руанда", "Саудаўская Аравія", "Саламонавы астравы", "Сейшэльскія астравы", "Судан", "Швецыя", "Сінгапур", "Востраў Святой Алены", "Славенія", "Шпіцберген і Ян-Маен", "Славакія", "Сьера-Леонэ", "Сан-Марына", "Сенегал", "Самалі", "Сурынам", "Паўднёвы Судан", "Сан-Тамэ і Прынсіпі", "Сальвадор", "Сінт-Мартэн", "Сірыя", "Эсватыні", "Трыстан-да-Кунья", "Астравы Цёркс і Кайкас", "Чад", "Французскія паўднёвыя тэрыторыі", "Тога", "Тайланд", "Таджыкістан", "Такелау", "Тымор-Лешці", "Туркменістан", "Туніс", "Тонга", "Турцыя", "Трынідад і Табага", "Тувалу", "Тайвань", "Танзанія", "Украіна", "Уганда", "Малыя Аддаленыя астравы ЗША", "Арганізацыя Аб’яднаных Нацый", "Злучаныя Штаты Амерыкі", "Уругвай", "Узбекістан", "Ватыкан", "Сент-Вінсент і Грэнадзіны", "Венесуэла", "Брытанскія Віргінскія астравы", "Амерыканскія Віргінскія астравы", "В’етнам", "Вануату", "Уоліс і Футуна", "Самоа", "Псеўдаакцэнты", "Псеўдадвухнапрамкавы", "Косава", "Емен", "Маёта", "Паўднёва-Афрыканская Рэспубліка", "Замбія", "Зімбабвэ"];
+
+// bn
+static R11: [&str; 294] = ["পৃথিবী", "আফ্রিকা", "উত্তর আমেরিকা", "দক্ষিণ আমেরিকা", "ওশিয়ানিয়া", "পশ্চিম আফ্রিকা", "মধ্য আমেরিকা", "পূর্ব আফ্রিকা", "উত্তর আফ্রিকা", "মধ্য আফ্রিকা", "দক্ষিন আফ্রিকা", "আমেরিকা", "উত্তরাঞ্চলীয় আমেরিকা", "ক্যারিবিয়ান", "পূর্ব এশিয়া", "দক্ষিণ এশিয়া", "দক্ষিণ পূর্ব এশিয়া", "দক্ষিণ ইউরোপ", "অস্ট্রেলেশিয়া", "মেলানেশিয়া", "মাইক্রোনেশিয়া অঞ্চল", "পলিনেশিয়া", "এশিয়া", "মধ্য এশিয়া", "পশ্চিম এশিয়া", "ইউরোপ", "পূর্ব ইউরোপ", "উত্তর ইউরোপ", "পশ্চিম ইউরোপ", "উপ সাহারান আফ্রিকা", "লাতিন আমেরিকা", "অ্যাসেনশন দ্বীপপুঞ্জ", "আন্ডোরা", "সংযুক্ত আরব আমিরাত", "আফগানিস্তান", "অ্যান্টিগুয়া ও বারবুডা", "অ্যাঙ্গুইলা", "আলবেনিয়া", "আর্মেনিয়া", "অ্যাঙ্গোলা", "অ্যান্টার্কটিকা", "আর্জেন্টিনা", "আমেরিকান সামোয়া", "অস্ট্রিয়া", "অস্ট্রেলিয়া", "আরুবা", "অলান্ড দ্বীপপুঞ্জ", "আজারবাইজান", "বসনিয়া ও হার্জেগোভিনা", "বার্বাডোজ", "বাংলাদেশ", "বেলজিয়াম", "বুরকিনা ফাসো", "বুলগেরিয়া", "বাহারিন", "বুরুন্ডি", "বেনিন", "সেন্ট বার্থেলেমি", "বারমুডা", "ব্রুনেই", "বলিভিয়া", "ক্যারিবিয়ান নেদারল্যান্ডস", "ব্রাজিল", "বাহামা দ্বীপপুঞ্জ", "ভুটান", "বোভেট দ্বীপ", "বতসোয়ানা", "বেলারুশ", "বেলিজ", "কানাডা", "কোকোস (কিলিং) দ্বীপপুঞ্জ", "কঙ্গো-কিনশাসা", "মধ্য আফ্রিকান প্রজাতন্ত্র", "কঙ্গো - ব্রাজাভিল", "সুইজারল্যান্ড", "কোট ডি‘আইভোর", "কুক দ্বীপপুঞ্জ", "চিলি", "ক্যামেরুন", "চীন", "কলম্বিয়া", "ক্লিপারটন দ্বীপপুঞ্জ", "সার্ক দ্বীপ", "কোস্টারিকা", "কিউবা", "কেপ ভার্দে", "কুরাসাও", "ক্রিসমাস দ্বীপ", "সাইপ্রাস", "চেকিয়া", "জার্মানি", "দিয়েগো গার্সিয়া", "জিবুতি", "ডেনমার্ক", "ডোমিনিকা", "ডোমেনিকান প্রজাতন্ত্র", "আলজেরিয়া", "কুউটা এবং মেলিলা", "ইকুয়েডর", "এস্তোনিয়া", "মিশর", "পশ্চিম সাহারা", "ইরিত্রিয়া", "স্পেন", "ইথিওপিয়া", "ইউরোপীয় ইউনিয়ন", "ইউরোজোন", "ফিনল্যান্ড", "ফিজি", "ফকল্যান্ড দ্বীপপুঞ্জ", "মাইক্রোনেশিয়া", "ফ্যারো দ্বীপপুঞ্জ", "ফ্রান্স", "গ্যাবন", "যুক্তরাজ্য", "গ্রেনাডা", "জর্জিয়া", "ফরাসী গায়ানা", "গার্নসি", "ঘানা", "জিব্রাল্টার", "গ্রীনল্যান্ড", "গাম্বিয়া", "গিনি", "গুয়াদেলৌপ", "নিরক্ষীয় গিনি", "গ্রীস", "দক্ষিণ জর্জিয়া ও দক্ষিণ স্যান্ডউইচ দ্বীপপুঞ্জ", "গুয়াতেমালা", "গুয়াম", "গিনি-বিসাউ", "গিয়ানা", "হংকং এসএআর চীনা", "হার্ড এবং ম্যাকডোনাল্ড দ্বীপপুঞ্জ", "হন্ডুরাস", "ক্রোয়েশিয়া", "হাইতি", "হাঙ্গেরি", "ক্যানারি দ্বীপপুঞ্জ", "ইন্দোনেশিয়া", "আয়ারল্যান্ড", "ইজরায়েল", "আইল অফ ম্যান", "ভারত", "ব্রিটিশ ভারত মহাসাগরীয় অঞ্চল", "ইরাক", "ইরান", "আইসল্যান্ড", "ইতালি", "জার্সি", "জামাইকা", "জর্ডন", "জাপান", "কেনিয়া", "কিরগিজিস্তান", "কম্বোডিয়া", "কিরিবাতি", "কমোরোস", "সেন্ট কিটস ও নেভিস", "উত্তর কোরিয়া", "দক্ষিণ কোরিয়া", "কুয়েত", "কেম্যান দ্বীপপুঞ্জ", "কাজাখস্তান", "লাওস", "লেবানন", "সেন্ট লুসিয়া", "লিচেনস্টেইন", "শ্রীলঙ্কা", "লাইবেরিয়া", "লেসোথো", "লিথুয়ানিয়া", "লাক্সেমবার্গ", "লাটভিয়া", "লিবিয়া", "মরক্কো", "মোনাকো", "মলডোভা", "মন্টিনিগ্রো", "সেন্ট মার্টিন", "মাদাগাস্কার", "মার্শাল দ্বীপপুঞ্জ", "উত্তর ম্যাসেডোনিয়া", "মালি", "মায়ানমার (বার্মা)", "মঙ্গোলিয়া", "ম্যাকাও এসএআর চীন", "উত্তরাঞ্চলীয় মারিয়ানা দ্বীপপুঞ্জ", "মার্টিনিক", "মরিতানিয়া", "মন্টসেরাট", "মাল্টা", "মরিশাস", "মালদ্বীপ", "মালাউই", "মেক্সিকো", "মালয়েশিয়া", "মোজাম্বিক", "নামিবিয়া", "নিউ ক্যালেডোনিয়া", "নাইজার", "নরফোক দ্বীপ", "নাইজেরিয়া", "নিকারাগুয়া", "নেদারল্যান্ডস", "নরওয়ে", "নেপাল", "নাউরু", "নিউয়ে", "নিউজিল্যান্ড", "ওমান", "পানামা", "পেরু", "ফরাসী পলিনেশিয়া", "পাপুয়া নিউ গিনি", "ফিলিপাইন", "পাকিস্তান", "পোল্যান্ড", "সেন্ট পিয়ের ও মিকুয়েলন", "পিটকেয়ার্ন দ্বীপপুঞ্জ", "পুয়ের্তো রিকো", "প্যালেস্টাইন ভূখণ্ড", "পর্তুগাল", "পালাউ", "প্যারাগুয়ে", "কাতার", "আউটলাইনিং ওসানিয়া", "রিইউনিয়ন", "রোমানিয়া", "সার্বিয়া", "রাশিয়া", "রুয়ান্ডা", "সৌদি আরব", "সলোমন দ্বীপপুঞ্জ", "সিসিলি", "সুদান", "সুইডেন", "সিঙ্গাপুর", "সেন্ট হেলেনা", "স্লোভানিয়া", "স্বালবার্ড ও জান মেয়েন", "স্লোভাকিয়া", "সিয়েরা লিওন", "সান মারিনো", "সেনেগাল", "সোমালিয়া", "সুরিনাম", "দক্ষিণ সুদান", "সাওটোমা ও প্রিন্সিপি", "এল সালভেদর", "সিন্ট মার্টেন", "সিরিয়া", "ইসওয়াতিনি", "ট্রিস্টান ডা কুনহা", "তুর্কস ও কাইকোস দ্বীপপুঞ্জ", "চাদ", "ফরাসী দক্ষিণাঞ্চল", "টোগো", "থাইল্যান্ড", "তাজিকিস্তান", "টোকেলাউ", "তিমুর-লেস্তে", "তুর্কমেনিস্তান", "তিউনিসিয়া", "টোঙ্গা", "তুরস্ক", "ত্রিনিদাদ ও টোবাগো", "টুভালু", "তাইওয়ান", "তাঞ্জানিয়া", "ইউক্রেন", "উগান্ডা", "যুক্তরাষ্ট্রের পার্শ্ববর্তী দ্বীপপুঞ্জ", "জাতিসংঘ", "মার্কিন যুক্তরাষ্ট্র", "উরুগুয়ে", "উজবেকিস্তান", "ভ্যাটিকান সিটি", "সেন্ট ভিনসেন্ট ও গ্রেনাডিনস", "ভেনেজুয়েলা", "ব্রিটিশ ভার্জিন দ্বীপপুঞ্জ", "মার্কিন যুক্তরাষ্ট্রীয় ভার্জিন দ্বীপপুঞ্জ", "ভিয়েতনাম", "ভানুয়াটু", "ওয়ালিস ও ফুটুনা", "সামোয়া", "সিউডো-অ্যাকসেন্ট", "সিউডো-বিডি", "কসোভো", "ইয়েমেন", "মায়োত্তে", "দক্ষিণ আফ্রিকা", "জাম্বিয়া", "জিম্বাবোয়ে"];
+
+// bs
+static R12: [&str; 294] = ["Svijet", "Afrika", "Sjeverna Amerika", "Južna Amerika", "Okeanija", "Zapadna Afrika", "Srednja Amerika", "Istočna Afrika", "Sjeverna Afrika", "Srednja Afrika", "Južna Afrika", "Amerika", "Sjeverni dio Amerike", "Karibi", "Istočna Azija", "Južna Azija", "Jugoistočna Azija", "Južna Evropa", "Australazija", "Melanezija", "Mikronezijska regija", "Polinezija", "Azija", "Srednja Azija", "Zapadna Azija", "Evropa", "Istočna Evropa", "Sjeverna Evropa", "Zapadna Evropa", "Subsaharska Afrika", "Latinska Amerika", "Ostrvo Ascension", "Andora", "Ujedinjeni Arapski Emirati", "Afganistan", "Antigva i Barbuda", "Angvila", "Albanija", "Armenija", "Angola", "Antarktika", "Argentina", "Američka Samoa", "Austrija", "Australija", "Aruba", "Olandska ostrva", "Azerbejdžan", "Bosna i Hercegovina", "Barbados", "Bangladeš", "Belgija", "Burkina Faso", "Bugarska", "Bahrein", "Burundi", "Benin", "Sveti Bartolomej", "Bermuda", "Brunej", "Bolivija", "Karipska Holandija", "Brazil", "Bahami", "Butan", "Ostrvo Buve", "Bocvana", "Bjelorusija", "Belize", "Kanada", "Kokosova (Keelingova) ostrva", "Demokratska Republika Kongo", "Centralnoafrička Republika", "Kongo", "Švicarska", "Obala Slonovače", "Kukova ostrva", "Čile", "Kamerun", "Kina", "Kolumbija", "Ostrvo Clipperton", "Sark", "Kostarika", "Kuba", "Zelenortska Ostrva", "Kurasao", "Božićno ostrvo", "Kipar", "Češka", "Njemačka", "Dijego Garsija", "Džibuti", "Danska", "Dominika", "Dominikanska Republika", "Alžir", "Seuta i Melilja", "Ekvador", "Estonija", "Egipat", "Zapadna Sahara", "Eritreja", "Španija", "Etiopija", "Evropska unija", "Eurozona", "Finska", "Fidži", "Folklandska ostrva", "Mikronezija", "Farska ostrva", "Francuska", "Gabon", "Ujedinjeno Kraljevstvo", "Grenada", "Gruzija", "Francuska Gvajana", "Guernsey", "Gana", "Gibraltar", "Grenland", "Gambija", "Gvineja", "Gvadalupe", "Ekvatorijalna Gvineja", "Grčka", "Južna Džordžija i Južna Sendvič ostrva", "Gvatemala", "Guam", "Gvineja-Bisao", "Gvajana", "Hong Kong (SAR Kina)", "Ostrvo Heard i arhipelag McDonald", "Honduras", "Hrvatska", "Haiti", "Mađarska", "Kanarska ostrva", "Indonezija", "Irska", "Izrael", "Ostrvo Man", "Indija", "Britanska Teritorija u Indijskom Okeanu", "Irak", "Iran", "Island", "Italija", "Jersey", "Jamajka", "Jordan", "Japan", "Kenija", "Kirgistan", "Kambodža", "Kiribati", "Komori", "Sveti Kits i Nevis", "Sjeverna Koreja", "Južna Koreja", "Kuvajt", "Kajmanska ostrva", "Kazahstan", "Laos", "Liban", "Sveta Lucija", "Lihtenštajn", "Šri Lanka", "Liberija", "Lesoto", "Litvanija", "Luksemburg", "Latvija", "Libija", "Maroko", "Monako", "Moldavija", "Crna Gora", "Sveti Martin", "Madagaskar", "Maršalova ostrva", "Sjeverna Makedonija", "Mali", "Mijanmar", "Mongolija", "Makao (SAR Kina)", "Sjeverna Marijanska ostrva", "Martinik", "Mauritanija", "Monserat", "Malta", "Mauricijus", "Maldivi", "Malavi", "Meksiko", "Malezija", "Mozambik", "Namibija", "Nova Kaledonija", "Niger", "Ostrvo Norfolk", "Nigerija", "Nikaragva", "Nizozemska", "Norveška", "Nepal", "Nauru", "Niue", "Novi Zeland", "Oman", "Panama", "Peru", "Francuska Polinezija", "Papua Nova Gvineja", "Filipini", "Pakistan", "Poljska", "Sveti Petar i Mikelon", "Pitkernska Ostrva", "Porto Riko", "Palestinska Teritorija", "Portugal", "Palau", "Paragvaj", "Katar", "Vanjska Okeanija", "Reunion", "Rumunija", "Srbija", "Rusija", "Ruanda", "Saudijska Arabija", "Solomonska Ostrva", "Sejšeli", "Sudan", "Švedska", "Singapur", "Sveta Helena", "Slovenija", "Svalbard i Jan Mayen", "Slovačka", "Sijera Leone", "San Marino", "Senegal", "Somalija", "Surinam", "Južni Sudan", "Sao Tome i Principe", "Salvador", "Sint Marten", "Sirija", "Esvatini", "Tristan da Cunha", "Ostrva Turks i Kaikos", "Čad", "Francuske Južne Teritorije", "Togo", "Tajland", "Tadžikistan", "Tokelau", "Istočni Timor", "Turkmenistan", "Tunis", "Tonga", "Turska", "Trinidad i Tobago", "Tuvalu", "Tajvan", "Tanzanija", "Ukrajina", "Uganda", "Američka Vanjska Ostrva", "Ujedinjene Nacije", "Sjedinjene Države", "Urugvaj", "Uzbekistan", "Vatikan", "Sveti Vinsent i Grenadin", "Venecuela", "Britanska Djevičanska ostrva", "Američka Djevičanska ostrva", "Vijetnam", "Vanuatu", "Ostrva Valis i Futuna", "Samoa", "Pseudo naglasci", "Pseudo bidi", "Kosovo", "Jemen", "Majote", "Južnoafrička Republika", "Zambija", "Zimbabve"];
+
+// br
+static R13: [&str; 294] = ["bed", "Afrika", "Norzhamerika", "Suamerika", "Oseania", "Afrika ar Cʼhornôg", "Kreizamerika", "Afrika ar Reter", "Afrika an Norzh", "Afrika ar Cʼhreiz", "Afrika ar Su", "Amerikaoù", "Amerika an Norzh", "Karib", "Azia ar Reter", "Azia ar Su", "Azia ar Gevred", "Europa ar Su", "Aostralazia", "Melanezia", "Rannved Mikronezia", "Polinezia", "Azia", "Azia ar Cʼhreiz", "Azia ar Cʼhornôg", "Europa", "Europa ar Reter", "Europa an Norzh", "Europa ar Cʼhornôg", "Afrika issaharat", "Amerika Latin", "Enez Ascension", "Andorra", "Emirelezhioù-Arab-Unanet", "Afghanistan", "Antigua-ha-Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktika", "Arcʼhantina", "Samoa Amerikan", "Aostria", "Aostralia", "Aruba", "Inizi Åland", "Azerbaidjan", "Bosnia-ha-Herzegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrein", "Burundi", "Benin", "Sant-Bertele", "Bermudez", "Brunei", "Bolivia", "Karib Nederlandat", "Brazil", "Bahamas", "Bhoutan", "Enez Bouvet", "Botswana", "Belarus", "Belize", "Kanada", "Inizi Kokoz", "Kongo - Kinshasa", "Republik Kreizafrikan", "Kongo - Brazzaville", "Suis", "Aod-an-Olifant", "Inizi Cook", "Chile", "Kameroun", "Sina", "Kolombia", "Enez Clipperton", "Sark", "Costa Rica", "Kuba", "Kab-Glas", "Curaçao", "Enez Christmas", "Kiprenez", "Tchekia", "Alamagn", "Diego Garcia", "Djibouti", "Danmark", "Dominica", "Republik Dominikan", "Aljeria", "Ceuta ha Melilla", "Ecuador", "Estonia", "Egipt", "Sahara ar Cʼhornôg", "Eritrea", "Spagn", "Etiopia", "Unaniezh Europa", "Takad an euro", "Finland", "Fidji", "Inizi Falkland", "Mikronezia", "Inizi Faero", "Frañs", "Gabon", "Rouantelezh-Unanet", "Grenada", "Jorjia", "Gwiana cʼhall", "Gwernenez", "Ghana", "Jibraltar", "Greunland", "Gambia", "Ginea", "Gwadeloup", "Ginea-ar-Cʼheheder", "Gres", "Inizi Georgia ar Su hag Inizi Sandwich ar Su", "Guatemala", "Guam", "Ginea-Bissau", "Guyana", "Hong Kong RMD Sina", "Inizi Heard-ha-McDonald", "Honduras", "Kroatia", "Haiti", "Hungaria", "Inizi Kanariez", "Indonezia", "Iwerzhon", "Israel", "Enez-Vanav", "India", "Tiriad breizhveurat Meurvor Indez", "Iraq", "Iran", "Island", "Italia", "Jerzenez", "Jamaika", "Jordania", "Japan", "Kenya", "Kyrgyzstan", "Kambodja", "Kiribati", "Komorez", "Saint Kitts-ha-Nevis", "Norzhkorea", "Sukorea", "Koweit", "Inizi Cayman", "Kazakstan", "Laos", "Liban", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luksembourg", "Latvia", "Libia", "Maroko", "Monaco", "Moldova", "Montenegro", "Sant-Martin", "Madagaskar", "Inizi Marshall", "Makedonia an Norzh", "Mali", "Myanmar (Birmania)", "Mongolia", "Makao RMD Sina", "Inizi Mariana an Norzh", "Martinik", "Maouritania", "Montserrat", "Malta", "Moris", "Maldivez", "Malawi", "Mecʼhiko", "Malaysia", "Mozambik", "Namibia", "Kaledonia-Nevez", "Niger", "Enez Norfolk", "Nigeria", "Nicaragua", "Izelvroioù", "Norvegia", "Nepal", "Nauru", "Niue", "Zeland-Nevez", "Oman", "Panamá", "Perou", "Polinezia Cʼhall", "Papoua Ginea-Nevez", "Filipinez", "Pakistan", "Polonia", "Sant-Pêr-ha-Mikelon", "Enez Pitcairn", "Puerto Rico", "Tiriadoù Palestina", "Portugal", "Palau", "Paraguay", "Qatar", "Oseania diabell", "Ar Reünion", "Roumania", "Serbia", "Rusia", "Rwanda", "Arabia Saoudat", "Inizi Salomon", "Sechelez", "Soudan", "Sveden", "Singapoura", "Saint-Helena", "Slovenia", "Svalbard", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Soudan ar Su", "São-Tomé-ha-Príncipe", "Salvador", "Sint Maarten", "Siria", "Eswatini", "Tristan da Cunha", "Inizi Turks-ha-Caicos", "Tchad", "Douaroù aostral Frañs", "Togo", "Thailand", "Tadjikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunizia", "Tonga", "Turkia", "Trinidad-ha-Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Ouganda", "Inizi diabell ar Stadoù-Unanet", "Broadoù unanet", "Stadoù-Unanet", "Uruguay", "Ouzbekistan", "Vatikan", "Sant-Visant hag ar Grenadinez", "Venezuela", "Inizi Gwercʼh Breizh-Veur", "Inizi Gwercʼh ar Stadoù-Unanet", "Viêt Nam", "Vanuatu", "Wallis-ha-Futuna", "Samoa", "Falstiredoù", "Falsdaoudu", "Kosovo", "Yemen", "Mayotte", "Suafrika", "Zambia", "Zimbabwe"];
+
+// bg
+static R14: [&str; 294] = ["свят", "Африка", "Северноамерикански континент", "Южна Америка", "Океания", "Западна Афирка", "Централна Америка", "Източна Африка", "Северна Африка", "Централна Африка", "Южноафрикански регион", "Америка", "Северна Америка", "Карибски регион", "Източна Азия", "Южна Азия", "Югоизточна Азия", "Южна Европа", "Австралазия", "Меланезия", "Микронезийски регион", "Полинезия", "Азия", "Централна Азия", "Западна Азия", "Европа", "Източна Европа", "Северна Европа", "Западна Европа", "Субсахарска Африка", "Латинска Америка", "остров Възнесение", "Андора", "Обединени арабски емирства", "Афганистан", "Антигуа и Барбуда", "Ангуила", "Албания", "Армения", "Ангола", "Антарктика", "Аржентина", "Американска Самоа", "Австрия", "Австралия", "Аруба", "Оландски острови", "Азербайджан", "Босна и Херцеговина", "Барбадос", "Бангладеш", "Белгия", "Буркина Фасо", "България", "Бахрейн", "Бурунди", "Бенин", "Сен Бартелеми", "Бермудски острови", "Бруней Даруссалам", "Боливия", "Карибска Нидерландия", "Бразилия", "Бахамски острови", "Бутан", "остров Буве", "Ботсвана", "Беларус", "Белиз", "Канада", "Кокосови острови (острови Кийлинг)", "Конго (Киншаса)", "Централноафриканска република", "Конго (Бразавил)", "Швейцария", "Кот д’Ивоар", "острови Кук", "Чили", "Камерун", "Китай", "Колумбия", "остров Клипертон", "Сарк", "Коста Рика", "Куба", "Кабо Верде", "Кюрасао", "остров Рождество", "Кипър", "Чехия", "Германия", "Диего Гарсия", "Джибути", "Дания", "Доминика", "Доминиканска република", "Алжир", "Сеута и Мелия", "Еквадор", "Естония", "Египет", "Западна Сахара", "Еритрея", "Испания", "Етиопия", "Европейски съюз", "еврозона", "Финландия", "Фиджи", "Фолкландски острови", "Микронезия", "Фарьорски острови", "Франция", "Габон", "Обединеното кралство", "Гренада", "Грузия", "Френска Гвиана", "Гърнзи", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Екваториална Гвинея", "Гърция", "Южна Джорджия и Южни Сандвичеви острови", "Гватемала", "Гуам", "Гвинея-Бисау", "Гаяна", "Хонконг, САР на Китай", "острови Хърд и Макдоналд", "Хондурас", "Хърватия", "Хаити", "Унгария", "Канарски острови", "Индонезия", "Ирландия", "Израел", "остров Ман", "Индия", "Британска територия в Индийския океан", "Ирак", "Иран", "Исландия", "Италия", "Джърси", "Ямайка", "Йордания", "Япония", "Кения", "Киргизстан", "Камбоджа", "Кирибати", "Коморски острови", "Сейнт Китс и Невис", "Северна Корея", "Южна Корея", "Кувейт", "Кайманови острови", "Казахстан", "Лаос", "Ливан", "Сейнт Лусия", "Лихтенщайн", "Шри Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Либия", "Мароко", "Монако", "Молдова", "Черна гора", "Сен Мартен", "Мадагаскар", "Маршалови острови", "Северна Македония", "Мали", "Мианмар (Бирма)", "Монголия", "Макао, САР на Китай", "Северни Мариански острови", "Мартиника", "Мавритания", "Монтсерат", "Малта", "Мавриций", "Малдиви", "Малави", "Мексико", "Малайзия", "Мозамбик", "Намибия", "Нова Каледония", "Нигер", "остров Норфолк", "Нигерия", "Никарагуа", "Нидерландия", "Норвегия", "Непал", "Науру", "Ниуе", "Нова Зеландия", "Оман", "Панама", "Перу", "Френска Полинезия", "Папуа-Нова Гвинея", "Филипини", "Пакистан", "Полша", "Сен Пиер и Микелон", "Острови Питкерн", "Пуерто Рико", "Палестински територии", "Португалия", "Палау", "Парагвай", "Катар", "Отдалечени острови на Океания", "Реюнион", "Румъния", "Сърбия", "Русия", "Руанда", "Саудитска Арабия", "Соломонови острови", "Сейшели", "Судан", "Швеция", "Сингапур", "Света Елена", "Словения", "Свалбард и Ян Майен", "Словакия", "Сиера Леоне", "Сан Марино", "Сенегал", "Сомалия", "Суринам", "Южен Судан", "Сао Томе и Принсипи", "Салвадор", "Синт Мартен", "Сирия", "Есватини", "Тристан да Куня", "острови Търкс и Кайкос", "Чад", "Френски южни територии", "Того", "Тайланд", "Таджикистан", "Токелау", "Тимор Лесте", "Туркменистан", "Тунис", "Тонга", "Турция", "Тринидад и Тобаго", "Тувалу", "Тайван", "Танзания", "Украйна", "Уганда", "Отдалечени острови на САЩ", "Организация на обединените нации", "Съединени щати", "Уругвай", "Узбекистан", "Ватикан", "Сейнт Винсънт и Гренадини", "Венецуела", "Британски Вирджински острови", "Американски Вирджински острови", "Виетнам", "Вануату", "Уолис и Футуна", "Самоа", "Псевдоакценти", "Псевдодвупосочни", "Косово", "Йемен", "Майот", "Южна Африка", "Замбия", "Зимбабве"];
+
+// my
+static R15: [&str; 294] = ["ကမ္ဘာ", "အာဖရိက", "မြောက် အမေရိကတိုက်", "တောင် အမေရိက", "သမုဒ္ဒရာဒေသ", "အနောက် အာဖရိက", "ဗဟို အမေရိက", "အရှေ့ အာဖရိက", "မြောက် အာဖရိက", "အလယ် အာဖရိက", "အာဖရိက တောင်ပိုင်း", "အမေရိကန်", "မြောက် အမေရိက", "ကာရစ်ဘီယံ", "အရှေ့အာရှ", "တောင်အာရှ", "အရှေ့တောင်အာရှ", "တောင်ဥရောပ", "ဩစတြေးလျနှင့် နယူးဇီလန်", "မီလာနီးရှား", "မိုက်ခရိုနီးရှား ဒေသ", "ပိုလီနီရှား", "အာရှ", "အလယ်အာရှ", "အနောက်အာရှ", "ဥရောပ", "အရှေ့ ဥရောပ", "မြောက် ဥရောပ", "အနောက် ဥရောပ", "ဆာဟာရ-အောက်ပိုင်း အာဖရိက", "လက်တင်အမေရိက", "အဆန်းရှင်းကျွန်း", "အန်ဒိုရာ", "ယူအေအီး", "အာဖဂန်နစ္စတန်", "အန်တီဂွါနှင့် ဘာဘူဒါ", "အန်ဂီလာ", "အယ်လ်ဘေးနီးယား", "အာမေးနီးယား", "အန်ဂိုလာ", "အန်တာတိက", "အာဂျင်တီးနား", "အမေရိကန် ဆမိုးအား", "ဩစတြီးယား", "ဩစတြေးလျ", "အာရူးဗား", "အာလန်ကျွန်း", "အဇာဘိုင်ဂျန်", "ဘော့စနီးယားနှင့် ဟာဇီဂိုဗီနား", "ဘာဘေးဒိုးစ်", "ဘင်္ဂလားဒေ့ရှ်", "ဘယ်လ်ဂျီယမ်", "ဘာကီးနား ဖားဆို", "ဘူလ်ဂေးရီးယား", "ဘာရိန်း", "ဘူရွန်ဒီ", "ဘီနင်", "စိန့်ဘာသယ်လ်မီ", "ဘာမြူဒါ", "ဘရူနိုင်း", "ဘိုလီးဗီးယား", "ကာရစ်ဘီယံ နယ်သာလန်", "ဘရာဇီး", "ဘဟားမား", "ဘူတန်", "ဘူဗက်ကျွန်း", "ဘော့ဆွာနာ", "ဘီလာရုစ်", "ဘလိဇ်", "ကနေဒါ", "ကိုကိုးကျွန်း", "ကွန်ဂို", "ဗဟို အာဖရိက ပြည်ထောင်စု", "ကွန်ဂို-ဘရာဇာဗီးလ်", "ဆွစ်ဇာလန်", "ကို့တ် ဒီဗွာ", "ကွတ် ကျွန်းစု", "ချီလီ", "ကင်မရွန်း", "တရုတ်", "ကိုလံဘီယာ", "ကလစ်ပါတန်ကျွန်း", "ဆာ့က်", "ကို့စ်တာရီကာ", "ကျူးဘား", "ကိတ်ဗာဒီ", "ကျူရေးကိုးစ်", "ခရစ်စမတ် ကျွန်း", "ဆိုက်ပရပ်စ်", "ချက်ကီယား", "ဂျာမနီ", "ဒီအဲဂိုဂါစီရာ", "ဂျီဘူတီ", "ဒိန်းမတ်", "ဒိုမီနီကာ", "ဒိုမီနီကန်", "အယ်လ်ဂျီးရီးယား", "ဆယ်ဥတာနှင့်မယ်လီလ်လာ", "အီကွေဒေါ", "အက်စတိုးနီးယား", "အီဂျစ်", "အနောက် ဆာဟာရ", "အီရီထရီးယား", "စပိန်", "အီသီယိုးပီးယား", "ဥရောပသမဂ္ဂ", "ဥရောပဒေသ", "ဖင်လန်", "ဖီဂျီ", "ဖော့ကလန် ကျွန်းစု", "မိုင်ခရိုနီရှား", "ဖာရိုး ကျွန်းစုများ", "ပြင်သစ်", "ဂါဘွန်", "ယူနိုက်တက်ကင်းဒမ်း", "ဂရီနေဒါ", "ဂျော်ဂျီယာ", "ပြင်သစ် ဂီယာနာ", "ဂွန်းဇီ", "ဂါနာ", "ဂျီဘရော်လ်တာ", "ဂရင်းလန်း", "ဂမ်ဘီရာ", "ဂီနီ", "ဂွါဒီလု", "အီကွေတာ ဂီနီ", "ဂရိ", "တောင် ဂျော်ဂျီယာ နှင့် တောင် ဆင်းဒဝစ်ဂျ် ကျွန်းစုများ", "ဂွါတီမာလာ", "ဂူအမ်", "ဂီနီ-ဘီစော", "ဂိုင်ယာနာ", "ဟောင်ကောင် (တရုတ်ပြည်)", "ဟတ်ကျွန်းနှင့်မက်ဒေါနယ်ကျွန်းစု", "ဟွန်ဒူးရပ်စ်", "ခရိုအေးရှား", "ဟေတီ", "ဟန်ဂေရီ", "ကနေရီ ကျွန်းစု", "အင်ဒိုနီးရှား", "အိုင်ယာလန်", "အစ္စရေး", "မန်ကျွန်း", "အိန္ဒိယ", "ဗြိတိသျှပိုင် အိန္ဒိယသမုဒ္ဒရာကျွန်းများ", "အီရတ်", "အီရန်", "အိုက်စလန်", "အီတလီ", "ဂျာစီ", "ဂျမေကာ", "ဂျော်ဒန်", "ဂျပန်", "ကင်ညာ", "ကာဂျစ္စတန်", "ကမ္ဘောဒီးယား", "ခီရီဘာတီ", "ကိုမိုရိုစ်", "စိန့်ကစ်နှင့်နီဗီစ်", "မြောက်ကိုရီးယား", "တောင်ကိုရီးယား", "ကူဝိတ်", "ကေမန် ကျွန်းစု", "ကာဇက်စတန်", "လာအို", "လက်ဘနွန်", "စိန့်လူစီယာ", "လစ်တန်စတိန်း", "သီရိလင်္ကာ", "လိုက်ဘေးရီးယား", "လီဆိုသို", "လစ်သူဝေးနီးယား", "လူဇင်ဘတ်", "လတ်ဗီးယား", "လစ်ဗျား", "မော်ရိုကို", "မိုနာကို", "မောလ်ဒိုဗာ", "မွန်တီနိဂရိုး", "စိန့်မာတင်", "မဒါဂတ်စကား", "မာရှယ် ကျွန်းစု", "မြောက် မက်ဆီဒိုးနီးယား", "မာလီ", "မြန်မာ", "မွန်ဂိုးလီးယား", "မကာအို (တရုတ်ပြည်)", "မြောက်ပိုင်းမာရီအာနာကျွန်းစု", "မာတီနိခ်", "မော်ရီတေးနီးယား", "မောင့်စဲရက်", "မောလ်တာ", "မောရစ်ရှ", "မော်လ်ဒိုက်", "မာလာဝီ", "မက်ကဆီကို", "မလေးရှား", "မိုဇမ်ဘစ်", "နမီးဘီးယား", "နယူး ကယ်လီဒိုနီးယား", "နိုင်ဂျာ", "နောဖုတ်ကျွန်း", "နိုင်ဂျီးရီးယား", "နီကာရာဂွါ", "နယ်သာလန်", "နော်ဝေ", "နီပေါ", "နော်ရူး", "နီဥူအေ", "နယူးဇီလန်", "အိုမန်", "ပနားမား", "ပီရူး", "ပြင်သစ် ပေါ်လီနီးရှား", "ပါပူအာ နယူးဂီနီ", "ဖိလစ်ပိုင်", "ပါကစ္စတန်", "ပိုလန်", "စိန့်ပီအဲရ်နှင့် မီကွီလွန်", "ပစ်တ်ကိန်းကျွန်းစု", "ပေါ်တိုရီကို", "ပါလက်စတိုင်း ပိုင်နက်", "ပေါ်တူဂီ", "ပလာအို", "ပါရာဂွေး", "ကာတာ", "သမုဒ္ဒရာ အပြင်ဘက်ရှိ ကျွန်းနိုင်ငံများ", "ရီယူနီယန်", "ရိုမေးနီးယား", "ဆားဘီးယား", "ရုရှား", "ရဝန်ဒါ", "ဆော်ဒီအာရေးဘီးယား", "ဆော်လမွန်ကျွန်းစု", "ဆေးရှဲ", "ဆူဒန်", "ဆွီဒင်", "စင်္ကာပူ", "စိန့်ဟယ်လယ်နာ", "ဆလိုဗေးနီးယား", "စဗိုလ်ဘတ်နှင့်ဂျန်မေရန်", "ဆလိုဗက်ကီးယား", "ဆီယာရာ လီယွန်း", "ဆန်မာရီနို", "ဆီနီဂေါ", "ဆိုမာလီယာ", "ဆူရာနမ်", "တောင် ဆူဒန်", "ဆောင်တူမေးနှင့် ပရင်စီပီ", "အယ်လ်ဆာဗေးဒိုး", "စင့်မာတင်", "ဆီးရီးယား", "ဆွာဇီလန်", "ထရစ္စတန် ဒါ ကွန်ဟာ", "တခ်စ်နှင့်ကာအီကိုစ်ကျွန်းစု", "ချဒ်", "ပြင်သစ် တောင်ပိုင်း ပိုင်နက်များ", "တိုဂို", "ထိုင်း", "တာဂျီကစ္စတန်", "တိုကလောင်", "အရှေ့တီမော", "တာ့ခ်မင်နစ္စတန်", "တူနီးရှား", "တွန်ဂါ", "တူရကီ", "ထရီနီဒတ်နှင့် တိုဘက်ဂို", "တူဗားလူ", "ထိုင်ဝမ်", "တန်ဇန်းနီးယား", "ယူကရိန်း", "ယူဂန်းဒါး", "ယူနိုက်တက်စတိတ် ကျွန်းနိုင်ငံများ", "ကုလသမဂ္ဂ", "အမေရိကန် ပြည်ထောင်စု", "ဥရုဂွေး", "ဥဇဘက်ကစ္စတန်", "ဗာတီကန်စီးတီး", "စိန့်ဗင်းဆင့်နှင့် ဂရိနေဒိုင်", "ဗင်နီဇွဲလား", "ဗြိတိသျှ ဗာဂျင်း ကျွန်းစု", "ယူအက်စ် ဗာဂျင်း ကျွန်းစု", "ဗီယက်နမ်", "ဗနွားတူ", "ဝေါလစ်နှင့် ဖူကျူးနား", "ဆမိုးအား", "နိုင်ငံခြားသံ", "စာပြောင်းပြန်", "ကိုဆိုဗို", "ယီမင်", "မေယော့", "တောင်အာဖရိက", "ဇမ်ဘီယာ", "ဇင်ဘာဘွေ"];
+
+// ca
+static R16: [&str; 294] = ["Món", "Àfrica", "Amèrica del Nord", "Amèrica del Sud", "Oceania", "Àfrica occidental", "Amèrica Central", "Àfrica oriental", "Àfrica septentrional", "Àfrica central", "Àfrica meridional", "Amèrica", "Amèrica septentrional", "Carib", "Àsia oriental", "Àsia meridional", "Àsia sud-oriental", "Europa meridional", "Australàsia", "Melanèsia", "Regió de la Micronèsia", "Polinèsia", "Àsia", "Àsia central", "Àsia occidental", "Europa", "Europa oriental", "Europa septentrional", "Europa occidental", "Àfrica subsahariana", "Amèrica Llatina", "Illa de l’Ascensió", "Andorra", "Emirats Àrabs Units", "Afganistan", "Antigua i Barbuda", "Anguilla", "Albània", "Armènia", "Angola", "Antàrtida", "Argentina", "Samoa Americana", "Àustria", "Austràlia", "Aruba", "Illes Åland", "Azerbaidjan", "Bòsnia i Hercegovina", "Barbados", "Bangladesh", "Bèlgica", "Burkina Faso", "Bulgària", "Bahrain", "Burundi", "Benín", "Saint-Barthélemy", "Bermudes", "Brunei", "Bolívia", "Carib Neerlandès", "Brasil", "Bahames", "Bhutan", "Illa Bouvet", "Botswana", "Belarús", "Belize", "Canadà", "Illes Cocos (Keeling)", "Congo - Kinshasa", "República Centreafricana", "Congo - Brazzaville", "Suïssa", "Côte d’Ivoire", "Illes Cook", "Xile", "Camerun", "Xina", "Colòmbia", "Illa Clipperton", "Sark", "Costa Rica", "Cuba", "Cap Verd", "Curaçao", "Illa Christmas", "Xipre", "Txèquia", "Alemanya", "Diego Garcia", "Djibouti", "Dinamarca", "Dominica", "República Dominicana", "Algèria", "Ceuta i Melilla", "Equador", "Estònia", "Egipte", "Sàhara Occidental", "Eritrea", "Espanya", "Etiòpia", "Unió Europea", "zona euro", "Finlàndia", "Fiji", "Illes Falkland", "Micronèsia", "Illes Fèroe", "França", "Gabon", "Regne Unit", "Grenada", "Geòrgia", "Guaiana Francesa", "Guernsey", "Ghana", "Gibraltar", "Groenlàndia", "Gàmbia", "Guinea", "Guadalupe", "Guinea Equatorial", "Grècia", "Illes Geòrgia del Sud i Sandwich del Sud", "Guatemala", "Guam", "Guinea Bissau", "Guyana", "Hong Kong (RAE Xina)", "Illes Heard i McDonald", "Hondures", "Croàcia", "Haití", "Hongria", "Illes Canàries", "Indonèsia", "Irlanda", "Israel", "Illa de Man", "Índia", "Territori Britànic de l’Oceà Índic", "Iraq", "Iran", "Islàndia", "Itàlia", "Jersey", "Jamaica", "Jordània", "Japó", "Kenya", "Kirguizstan", "Cambodja", "Kiribati", "Comores", "Saint Kitts i Nevis", "Corea del Nord", "Corea del Sud", "Kuwait", "Illes Caiman", "Kazakhstan", "Lao", "Líban", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Libèria", "Lesotho", "Lituània", "Luxemburg", "Letònia", "Líbia", "Marroc", "Mònaco", "Moldàvia", "Montenegro", "Saint Martin", "Madagascar", "Illes Marshall", "Macedònia del Nord", "Mali", "Myanmar (Birmània)", "Mongòlia", "Macau (RAE Xina)", "Illes Marianes del Nord", "Martinica", "Mauritània", "Montserrat", "Malta", "Maurici", "Maldives", "Malawi", "Mèxic", "Malàisia", "Moçambic", "Namíbia", "Nova Caledònia", "Níger", "Illa Norfolk", "Nigèria", "Nicaragua", "Països Baixos", "Noruega", "Nepal", "Nauru", "Niue", "Nova Zelanda", "Oman", "Panamà", "Perú", "Polinèsia Francesa", "Papua Nova Guinea", "Filipines", "Pakistan", "Polònia", "Saint-Pierre-et-Miquelon", "Illes Pitcairn", "Puerto Rico", "Territoris palestins", "Portugal", "Palau", "Paraguai", "Qatar", "Territoris allunyats d’Oceania", "Illa de la Reunió", "Romania", "Sèrbia", "Rússia", "Ruanda", "Aràbia Saudí", "Illes Salomó", "Seychelles", "Sudan", "Suècia", "Singapur", "Santa Helena", "Eslovènia", "Svalbard i Jan Mayen", "Eslovàquia", "Sierra Leone", "San Marino", "Senegal", "Somàlia", "Surinam", "Sudan del Sud", "São Tomé i Príncipe", "El Salvador", "Sint Maarten", "Síria", "Eswatini", "Tristan da Cunha", "Illes Turks i Caicos", "Txad", "Terres Australs Antàrtiques Franceses", "Togo", "Tailàndia", "Tadjikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunísia", "Tonga", "Turquia", "Trinidad i Tobago", "Tuvalu", "Taiwan", "Tanzània", "Ucraïna", "Uganda", "Illes Menors Allunyades dels Estats Units", "Nacions Unides", "Estats Units", "Uruguai", "Uzbekistan", "Ciutat del Vaticà", "Saint Vincent i les Grenadines", "Veneçuela", "Illes Verges Britàniques", "Illes Verges dels Estats Units", "Vietnam", "Vanuatu", "Wallis i Futuna", "Samoa", "pseudoaccents", "pseudobidi", "Kosovo", "Iemen", "Mayotte", "Sud-àfrica", "Zàmbia", "Zimbàbue"];
+
+// zh
+static R17: [&str; 294] = ["世界", "非洲", "北美洲", "南美洲", "大洋洲", "西非", "中美洲", "东非", "北非", "中非", "南部非洲", "美洲", "美洲北部", "加勒比地区", "东亚", "南亚", "东南亚", "南欧", "澳大拉西亚", "美拉尼西亚", "密克罗尼西亚地区", "玻利尼西亚", "亚洲", "中亚", "西亚", "欧洲", "东欧", "北欧", "西欧", "撒哈拉以南非洲", "拉丁美洲", "阿森松岛", "安道尔", "阿拉伯联合酋长国", "阿富汗", "安提瓜和巴布达", "安圭拉", "阿尔巴尼亚", "亚美尼亚", "安哥拉", "南极洲", "阿根廷", "美属萨摩亚", "奥地利", "澳大利亚", "阿鲁巴", "奥兰群岛", "阿塞拜疆", "波斯尼亚和黑塞哥维那", "巴巴多斯", "孟加拉国", "比利时", "布基纳法索", "保加利亚", "巴林", "布隆迪", "贝宁", "圣巴泰勒米", "百慕大", "文莱", "玻利维亚", "荷属加勒比区", "巴西", "巴哈马", "不丹", "布韦岛", "博茨瓦纳", "白俄罗斯", "伯利兹", "加拿大", "科科斯（基林）群岛", "刚果（金）", "中非共和国", "刚果（布）", "瑞士", "科特迪瓦", "库克群岛", "智利", "喀麦隆", "中国", "哥伦比亚", "克利珀顿岛", "萨克岛", "哥斯达黎加", "古巴", "佛得角", "库拉索", "圣诞岛", "塞浦路斯", "捷克", "德国", "迪戈加西亚岛", "吉布提", "丹麦", "多米尼克", "多米尼加共和国", "阿尔及利亚", "休达及梅利利亚", "厄瓜多尔", "爱沙尼亚", "埃及", "西撒哈拉", "厄立特里亚", "西班牙", "埃塞俄比亚", "欧盟", "欧元区", "芬兰", "斐济", "福克兰群岛", "密克罗尼西亚", "法罗群岛", "法国", "加蓬", "英国", "格林纳达", "格鲁吉亚", "法属圭亚那", "根西岛", "加纳", "直布罗陀", "格陵兰", "冈比亚", "几内亚", "瓜德罗普", "赤道几内亚", "希腊", "南乔治亚和南桑威奇群岛", "危地马拉", "关岛", "几内亚比绍", "圭亚那", "中国香港特别行政区", "赫德岛和麦克唐纳群岛", "洪都拉斯", "克罗地亚", "海地", "匈牙利", "加纳利群岛", "印度尼西亚", "爱尔兰", "以色列", "马恩岛", "印度", "英属印度洋领地", "伊拉克", "伊朗", "冰岛", "意大利", "泽西岛", "牙买加", "约旦", "日本", "肯尼亚", "吉尔吉斯斯坦", "柬埔寨", "基里巴斯", "科摩罗", "圣基茨和尼维斯", "朝鲜", "韩国", "科威特", "开曼群岛", "哈萨克斯坦", "老挝", "黎巴嫩", "圣卢西亚", "列支敦士登", "斯里兰卡", "利比里亚", "莱索托", "立陶宛", "卢森堡", "拉脱维亚", "利比亚", "摩洛哥", "摩纳哥", "摩尔多瓦", "黑山", "法属圣马丁", "马达加斯加", "马绍尔群岛", "北马其顿", "马里", "缅甸", "蒙古", "中国澳门特别行政区", "北马里亚纳群岛", "马提尼克", "毛里塔尼亚", "蒙特塞拉特", "马耳他", "毛里求斯", "马尔代夫", "马拉维", "墨西哥", "马来西亚", "莫桑比克", "纳米比亚", "新喀里多尼亚", "尼日尔", "诺福克岛", "尼日利亚", "尼加拉瓜", "荷兰", "挪威", "尼泊尔", "瑙鲁", "纽埃", "新西兰", "阿曼", "巴拿马", "秘鲁", "法属波利尼西亚", "巴布亚新几内亚", "菲律宾", "巴基斯坦", "波兰", "圣皮埃尔和密克隆群岛", "皮特凯恩群岛", "波多黎各", "巴勒斯坦领土", "葡萄牙", "帕劳", "巴拉圭", "卡塔尔", "大洋洲边远群岛", "留尼汪", "罗马尼亚", "塞尔维亚", "俄罗斯", "卢旺达", "沙特阿拉伯", "所罗门群岛", "塞舌尔", "苏丹", "瑞典", "新加坡", "圣赫勒拿", "斯洛文尼亚", "斯瓦尔巴和扬马延", "斯洛伐克", "塞拉利昂", "圣马力诺", "塞内加尔", "索马里", "苏里南", "南苏丹", "圣多美和普林西比", "萨尔瓦多", "荷属圣马丁", "叙利亚", "斯威士兰", "特里斯坦-达库尼亚群岛", "特克斯和凯科斯群岛", "乍得", "法属南部领地", "多哥", "泰国", "塔吉克斯坦", "托克劳", "东帝汶", "土库曼斯坦", "突尼斯", "汤加", "土耳其", "特立尼达和多巴哥", "图瓦卢", "台湾", "坦桑尼亚", "乌克兰", "乌干达", "美国本土外小岛屿", "联合国", "美国", "乌拉圭", "乌兹别克斯坦", "梵蒂冈", "圣文森特和格林纳丁斯", "委内瑞拉", "英属维尔京群岛", "美属维尔京群岛", "越南", "瓦努阿图", "瓦利斯和富图纳", "萨摩亚", "伪地区", "伪双向语言地区", "科索沃", "也门", "马约特", "南非", "赞比亚", "津巴布韦"];
+
+// cv
+static R18: [&str; 294] = ["тӗнче", "Африка", "Ҫур ҫӗр Америка", "Кӑнтӑр Америка", "Океани", "Анӑҫ Африка", "Вӑта Америка", "Тухӑҫ Африка", "Ҫур ҫӗр Африка", "Вӑта Африка", "Кӑнтӑр Африка", "Америка", "Ҫур ҫӗр Америка регионӗ", "Кариб", "Тухӑҫ Ази", "Кӑнтӑр Ази", "Кӑнтӑр тухӑҫ Ази", "Кӑнтӑр Европа", "Австралази", "Меланези", "Микронези", "Полинези", "Ази", "Вӑта Ази", "Анӑҫ Ази", "Европа", "Тухӑҫ Европа", "Ҫур ҫӗр Европа", "Анӑҫ Европа", "Сахара ҫум Африка", "Латинла Америка", "Вознесени утравӗ", "Андорра", "Пӗрлешнӗ Араб Эмирачӗсем", "Афганистан", "Антигуапа Барбуда", "Ангилья", "Албани", "Армени", "Ангола", "Антарктида", "Аргентина", "Америка Самойи", "Австри", "Австрали", "Аруба", "Аланд утравӗсем", "Азербайджан", "Боснипе Герцеговина", "Барбадос", "Бангладеш", "Бельги", "Буркина-Фасо", "Болгари", "Бахрейн", "Бурунди", "Бенин", "Сен-Бартелеми", "Бермуд утравӗсем", "Бруней", "Боливи", "Бонэйр, Синт-Эстатиуспа Саба", "Бразили", "Багама утравӗсем", "Бутан", "Буве утравӗ", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокос утравӗсем", "Конго", "Вӑта Африка Республики", "Конго-Браззавиль", "Швейцари", "Кот-д’Ивуар", "Кук утравӗсем", "Чили", "Камерун", "Китай", "Колумби", "Клиппертон утравӗ", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Раштав утравӗ", "Кипр", "Чехи", "Германи", "Диего-Гарси", "Джибути", "Дани", "Доминика", "Доминика Республики", "Алжир", "Сеутӑпа Мелили", "Эквадор", "Эстони", "Египет", "Анӑҫ Сахара", "Эритрей", "Испани", "Эфиопи", "Европа Пӗрлешӗвӗ", "Еврозона", "Финлянди", "Фиджи", "Фолкленд утравӗсем", "Микронези", "Фарер утравӗсем", "Франци", "Габон", "Аслӑ Британи", "Гренада", "Грузи", "Франци Гвиани", "Гернси", "Гана", "Гибралтар", "Гренланди", "Гамби", "Гвиней", "Гваделупа", "Экваторти Гвинея", "Греци", "Кӑнтӑр Георгипе Кӑнтӑр Сандвич утравӗсем", "Гватемала", "Гуам", "Гвиней-Бисау", "Гайана", "Гонконг (ЯАР)", "Хердпа Макдональд утравӗсем", "Гондурас", "Хорвати", "Гаити", "Венгри", "Канар утравӗсем", "Индонези", "Ирланди", "Израиль", "Мэн утравӗ", "Инди", "Инди океанӗнчи Британи территорийӗ", "Ирак", "Иран", "Исланди", "Итали", "Джерси", "Ямайка", "Иордани", "Япони", "Кени", "Кӑркӑсстан", "Камбоджа", "Кирибати", "Комор утравӗсем", "Сент-Китспа Невис", "Ҫур ҫӗр Корея", "Кӑнтӑр Корея", "Кувейт", "Кайман утравӗсем", "Казахстан", "Лаос", "Ливан", "Сент-Люси", "Лихтенштейн", "Шри-Ланка", "Либери", "Лесото", "Литва", "Люксембург", "Латви", "Ливи", "Марокко", "Монако", "Молдова", "Черногори", "Сен-Мартен", "Мадагаскар", "Маршалл утравӗсем", "Ҫур ҫӗр Македони", "Мали", "Мьянма (Бирма)", "Монголи", "Макао (ЯАР)", "Ҫур ҫӗр Мариан утравӗсем", "Мартиника", "Мавритани", "Монтсеррат", "Мальта", "Маврики", "Мальдив", "Малави", "Мексика", "Малайзи", "Мозамбик", "Намиби", "Ҫӗнӗ Каледони", "Нигер", "Норфолк утравӗ", "Нигери", "Никарагуа", "Нидерланд", "Норвеги", "Непал", "Науру", "Ниуэ", "Ҫӗнӗ Зеланди", "Оман", "Панама", "Перу", "Франци Полинезийӗ", "Папуа — Ҫӗнӗ Гвинея", "Филиппин", "Пакистан", "Польша", "Сен-Пьерпа Микелон", "Питкэрн утравӗсем", "Пуэрто-Рико", "Палестина территорийӗсем", "Португали", "Палау", "Парагвай", "Катар", "Тулаш Океани", "Реюньон", "Румыни", "Серби", "Раҫҫей", "Руанда", "Сауд Аравийӗ", "Соломон утравӗсем", "Сейшел утравӗсем", "Судан", "Швеци", "Сингапур", "Сӑваплӑ Елена утравӗ", "Словени", "Шпицбергенпа Ян-Майен", "Словаки", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Кӑнтӑр Судан", "Сан-Томепе Принсипи", "Сальвадор", "Синт-Мартен", "Сири", "Эсватини", "Тристан-да-Куни", "Тёркспа Кайкос утравӗсем", "Чад", "Францин Кӑнтӑр территорийӗсем", "Того", "Таиланд", "Таджикистан", "Токелау", "Тухӑҫ Тимор", "Туркменистан", "Тунис", "Тонга", "Турци", "Тринидадпа Тобаго", "Тувалу", "Тайвань", "Танзани", "Украина", "Уганда", "АПШ-н тулаш пӗчӗк утравӗсем", "Пӗрлешнӗ Нацисен Организацийӗ", "Пӗрлешнӗ Штатсем", "Уругвай", "Узбекистан", "Ватикан", "Сент-Винсентпа Гренада", "Венесуэла", "Виргин утравӗсем (Британи)", "Виргин утравӗсем (АПШ)", "Вьетнам", "Вануату", "Уоллиспа Футуна", "Самоа", "суя акцент", "суя биди", "Косово", "Йемен", "Майотта", "Кӑнтӑр Африка Республики", "Замби", "Зимбабве"];
+
+// hr
+static R19: [&str; 294] = ["Svijet", "Afrika", "Sjevernoamerički kontinent", "Južna Amerika", "Oceanija", "Zapadna Afrika", "Centralna Amerika", "Istočna Afrika", "Sjeverna Afrika", "Središnja Afrika", "Južna Afrika", "Amerike", "Sjeverna Amerika", "Karibi", "Istočna Azija", "Južna Azija", "Jugoistočna Azija", "Južna Europa", "Australazija", "Melanezija", "Mikronezijsko područje", "Polinezija", "Azija", "Srednja Azija", "Zapadna Azija", "Europa", "Istočna Europa", "Sjeverna Europa", "Zapadna Europa", "Subsaharska Afrika", "Latinska Amerika", "Otok Uzašašća", "Andora", "Ujedinjeni Arapski Emirati", "Afganistan", "Antigva i Barbuda", "Angvila", "Albanija", "Armenija", "Angola", "Antarktika", "Argentina", "Američka Samoa", "Austrija", "Australija", "Aruba", "Ålandski otoci", "Azerbajdžan", "Bosna i Hercegovina", "Barbados", "Bangladeš", "Belgija", "Burkina Faso", "Bugarska", "Bahrein", "Burundi", "Benin", "Saint Barthélemy", "Bermudi", "Brunej", "Bolivija", "Karipski otoci Nizozemske", "Brazil", "Bahami", "Butan", "Otok Bouvet", "Bocvana", "Bjelorusija", "Belize", "Kanada", "Kokosovi (Keelingovi) Otoci", "Kongo - Kinshasa", "Srednjoafrička Republika", "Kongo - Brazzaville", "Švicarska", "Obala Bjelokosti", "Cookovi Otoci", "Čile", "Kamerun", "Kina", "Kolumbija", "Otok Clipperton", "Sark", "Kostarika", "Kuba", "Zelenortska Republika", "Curaçao", "Božićni Otok", "Cipar", "Češka", "Njemačka", "Diego Garcia", "Džibuti", "Danska", "Dominika", "Dominikanska Republika", "Alžir", "Ceuta i Melilla", "Ekvador", "Estonija", "Egipat", "Zapadna Sahara", "Eritreja", "Španjolska", "Etiopija", "Europska unija", "eurozona", "Finska", "Fidži", "Falklandski Otoci", "Mikronezija", "Farski Otoci", "Francuska", "Gabon", "Ujedinjeno Kraljevstvo", "Grenada", "Gruzija", "Francuska Gijana", "Guernsey", "Gana", "Gibraltar", "Grenland", "Gambija", "Gvineja", "Guadalupe", "Ekvatorska Gvineja", "Grčka", "Južna Georgia i Otoci Južni Sandwich", "Gvatemala", "Guam", "Gvineja Bisau", "Gvajana", "PUP Hong Kong Kina", "Otoci Heard i McDonald", "Honduras", "Hrvatska", "Haiti", "Mađarska", "Kanari", "Indonezija", "Irska", "Izrael", "Otok Man", "Indija", "Britanski Indijskooceanski Teritorij", "Irak", "Iran", "Island", "Italija", "Jersey", "Jamajka", "Jordan", "Japan", "Kenija", "Kirgistan", "Kambodža", "Kiribati", "Komori", "Sveti Kristofor i Nevis", "Sjeverna Koreja", "Južna Koreja", "Kuvajt", "Kajmanski Otoci", "Kazahstan", "Laos", "Libanon", "Sveta Lucija", "Lihtenštajn", "Šri Lanka", "Liberija", "Lesoto", "Litva", "Luksemburg", "Latvija", "Libija", "Maroko", "Monako", "Moldavija", "Crna Gora", "Saint Martin", "Madagaskar", "Maršalovi Otoci", "Sjeverna Makedonija", "Mali", "Mjanmar (Burma)", "Mongolija", "PUP Makao Kina", "Sjevernomarijanski Otoci", "Martinik", "Mauretanija", "Montserrat", "Malta", "Mauricijus", "Maldivi", "Malavi", "Meksiko", "Malezija", "Mozambik", "Namibija", "Nova Kaledonija", "Niger", "Otok Norfolk", "Nigerija", "Nikaragva", "Nizozemska", "Norveška", "Nepal", "Nauru", "Niue", "Novi Zeland", "Oman", "Panama", "Peru", "Francuska Polinezija", "Papua Nova Gvineja", "Filipini", "Pakistan", "Poljska", "Sveti Petar i Mikelon", "Pitcairnovi Otoci", "Portoriko", "Palestinsko područje", "Portugal", "Palau", "Paragvaj", "Katar", "Vanjska područja Oceanije", "Réunion", "Rumunjska", "Srbija", "Rusija", "Ruanda", "Saudijska Arabija", "Salomonovi Otoci", "Sejšeli", "Sudan", "Švedska", "Singapur", "Sveta Helena", "Slovenija", "Svalbard i Jan Mayen", "Slovačka", "Sijera Leone", "San Marino", "Senegal", "Somalija", "Surinam", "Južni Sudan", "Sveti Toma i Princip", "Salvador", "Sint Maarten", "Sirija", "Esvatini", "Tristan da Cunha", "Otoci Turks i Caicos", "Čad", "Francuski Južni Teritoriji", "Togo", "Tajland", "Tadžikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunis", "Tonga", "Turska", "Trinidad i Tobago", "Tuvalu", "Tajvan", "Tanzanija", "Ukrajina", "Uganda", "Mali udaljeni otoci SAD-a", "Ujedinjeni narodi", "Sjedinjene Američke Države", "Urugvaj", "Uzbekistan", "Vatikan", "Sveti Vincent i Grenadini", "Venezuela", "Britanski Djevičanski Otoci", "Američki Djevičanski Otoci", "Vijetnam", "Vanuatu", "Wallis i Futuna", "Samoa", "pseudo naglasci", "pseudo bidi", "Kosovo", "Jemen", "Mayotte", "Južnoafrička Republika", "Zambija", "Zimbabve"];
+
+// cs
+static R20: [&str; 294] = ["svět", "Afrika", "Severní Amerika", "Jižní Amerika", "Oceánie", "západní Afrika", "Střední Amerika", "východní Afrika", "severní Afrika", "střední Afrika", "jižní Afrika", "Amerika", "Severní Amerika (oblast)", "Karibik", "východní Asie", "jižní Asie", "jihovýchodní Asie", "jižní Evropa", "Australasie", "Melanésie", "Mikronésie (region)", "Polynésie", "Asie", "Střední Asie", "západní Asie", "Evropa", "východní Evropa", "severní Evropa", "západní Evropa", "subsaharská Afrika", "Latinská Amerika", "Ascension", "Andorra", "Spojené arabské emiráty", "Afghánistán", "Antigua a Barbuda", "Anguilla", "Albánie", "Arménie", "Angola", "Antarktida", "Argentina", "Americká Samoa", "Rakousko", "Austrálie", "Aruba", "Ålandy", "Ázerbájdžán", "Bosna a Hercegovina", "Barbados", "Bangladéš", "Belgie", "Burkina Faso", "Bulharsko", "Bahrajn", "Burundi", "Benin", "Svatý Bartoloměj", "Bermudy", "Brunej", "Bolívie", "Karibské Nizozemsko", "Brazílie", "Bahamy", "Bhútán", "Bouvetův ostrov", "Botswana", "Bělorusko", "Belize", "Kanada", "Kokosové ostrovy", "Kongo – Kinshasa", "Středoafrická republika", "Kongo – Brazzaville", "Švýcarsko", "Pobřeží slonoviny", "Cookovy ostrovy", "Chile", "Kamerun", "Čína", "Kolumbie", "Clippertonův ostrov", "Sark", "Kostarika", "Kuba", "Kapverdy", "Curaçao", "Vánoční ostrov", "Kypr", "Česko", "Německo", "Diego García", "Džibutsko", "Dánsko", "Dominika", "Dominikánská republika", "Alžírsko", "Ceuta a Melilla", "Ekvádor", "Estonsko", "Egypt", "Západní Sahara", "Eritrea", "Španělsko", "Etiopie", "Evropská unie", "eurozóna", "Finsko", "Fidži", "Falklandské ostrovy", "Mikronésie", "Faerské ostrovy", "Francie", "Gabon", "Spojené království", "Grenada", "Gruzie", "Francouzská Guyana", "Guernsey", "Ghana", "Gibraltar", "Grónsko", "Gambie", "Guinea", "Guadeloupe", "Rovníková Guinea", "Řecko", "Jižní Georgie a Jižní Sandwichovy ostrovy", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong – ZAO Číny", "Heardův ostrov a McDonaldovy ostrovy", "Honduras", "Chorvatsko", "Haiti", "Maďarsko", "Kanárské ostrovy", "Indonésie", "Irsko", "Izrael", "Ostrov Man", "Indie", "Britské indickooceánské území", "Irák", "Írán", "Island", "Itálie", "Jersey", "Jamajka", "Jordánsko", "Japonsko", "Keňa", "Kyrgyzstán", "Kambodža", "Kiribati", "Komory", "Svatý Kryštof a Nevis", "Severní Korea", "Jižní Korea", "Kuvajt", "Kajmanské ostrovy", "Kazachstán", "Laos", "Libanon", "Svatá Lucie", "Lichtenštejnsko", "Srí Lanka", "Libérie", "Lesotho", "Litva", "Lucembursko", "Lotyšsko", "Libye", "Maroko", "Monako", "Moldavsko", "Černá Hora", "Svatý Martin (Francie)", "Madagaskar", "Marshallovy ostrovy", "Severní Makedonie", "Mali", "Myanmar (Barma)", "Mongolsko", "Macao – ZAO Číny", "Severní Mariany", "Martinik", "Mauritánie", "Montserrat", "Malta", "Mauricius", "Maledivy", "Malawi", "Mexiko", "Malajsie", "Mosambik", "Namibie", "Nová Kaledonie", "Niger", "Norfolk", "Nigérie", "Nikaragua", "Nizozemsko", "Norsko", "Nepál", "Nauru", "Niue", "Nový Zéland", "Omán", "Panama", "Peru", "Francouzská Polynésie", "Papua-Nová Guinea", "Filipíny", "Pákistán", "Polsko", "Saint-Pierre a Miquelon", "Pitcairnovy ostrovy", "Portoriko", "Palestinská území", "Portugalsko", "Palau", "Paraguay", "Katar", "vnější Oceánie", "Réunion", "Rumunsko", "Srbsko", "Rusko", "Rwanda", "Saúdská Arábie", "Šalamounovy ostrovy", "Seychely", "Súdán", "Švédsko", "Singapur", "Svatá Helena", "Slovinsko", "Špicberky a Jan Mayen", "Slovensko", "Sierra Leone", "San Marino", "Senegal", "Somálsko", "Surinam", "Jižní Súdán", "Svatý Tomáš a Princův ostrov", "Salvador", "Svatý Martin (Nizozemsko)", "Sýrie", "Eswatini", "Tristan da Cunha", "Turks a Caicos", "Čad", "Francouzská jižní území", "Togo", "Thajsko", "Tádžikistán", "Tokelau", "Východní Timor", "Turkmenistán", "Tunisko", "Tonga", "Turecko", "Trinidad a Tobago", "Tuvalu", "Tchaj-wan", "Tanzanie", "Ukrajina", "Uganda", "Menší odlehlé ostrovy USA", "Organizace spojených národů", "Spojené státy", "Uruguay", "Uzbekistán", "Vatikán", "Svatý Vincenc a Grenadiny", "Venezuela", "Britské Panenské ostrovy", "Americké Panenské ostrovy", "Vietnam", "Vanuatu", "Wallis a Futuna", "Samoa", "simulovaná diakritika", "simulovaný obousměrný zápis", "Kosovo", "Jemen", "Mayotte", "Jihoafrická republika", "Zambie", "Zimbabwe"];
+
+// da
+static R21: [&str; 294] = ["Verden", "Afrika", "Nordamerika", "Sydamerika", "Oceanien", "Vestafrika", "Mellemamerika", "Østafrika", "Nordafrika", "Centralafrika", "Det sydlige Afrika", "Nord-, Mellem- og Sydamerika", "Det nordlige Amerika", "Caribien", "Østasien", "Sydasien", "Sydøstasien", "Sydeuropa", "Australasien", "Melanesien", "Mikronesiske område", "Polynesien", "Asien", "Centralasien", "Vestasien", "Europa", "Østeuropa", "Nordeuropa", "Vesteuropa", "Subsaharisk Afrika", "Latinamerika", "Ascensionøen", "Andorra", "De Forenede Arabiske Emirater", "Afghanistan", "Antigua og Barbuda", "Anguilla", "Albanien", "Armenien", "Angola", "Antarktis", "Argentina", "Amerikansk Samoa", "Østrig", "Australien", "Aruba", "Åland", "Aserbajdsjan", "Bosnien-Hercegovina", "Barbados", "Bangladesh", "Belgien", "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "Saint Barthélemy", "Bermuda", "Brunei", "Bolivia", "De tidligere Nederlandske Antiller", "Brasilien", "Bahamas", "Bhutan", "Bouvetøen", "Botswana", "Belarus", "Belize", "Canada", "Cocosøerne", "Congo-Kinshasa", "Den Centralafrikanske Republik", "Congo-Brazzaville", "Schweiz", "Elfenbenskysten", "Cookøerne", "Chile", "Cameroun", "Kina", "Colombia", "Clippertonøen", "Sark", "Costa Rica", "Cuba", "Kap Verde", "Curaçao", "Juleøen", "Cypern", "Tjekkiet", "Tyskland", "Diego Garcia", "Djibouti", "Danmark", "Dominica", "Den Dominikanske Republik", "Algeriet", "Ceuta og Melilla", "Ecuador", "Estland", "Egypten", "Vestsahara", "Eritrea", "Spanien", "Etiopien", "Den Europæiske Union", "eurozonen", "Finland", "Fiji", "Falklandsøerne", "Mikronesien", "Færøerne", "Frankrig", "Gabon", "Storbritannien", "Grenada", "Georgien", "Fransk Guyana", "Guernsey", "Ghana", "Gibraltar", "Grønland", "Gambia", "Guinea", "Guadeloupe", "Ækvatorialguinea", "Grækenland", "South Georgia og De Sydlige Sandwichøer", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "SAR Hongkong", "Heard Island og McDonald Islands", "Honduras", "Kroatien", "Haiti", "Ungarn", "Kanariske øer", "Indonesien", "Irland", "Israel", "Isle of Man", "Indien", "Det Britiske Territorium i Det Indiske Ocean", "Irak", "Iran", "Island", "Italien", "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kirgisistan", "Cambodja", "Kiribati", "Comorerne", "Saint Kitts og Nevis", "Nordkorea", "Sydkorea", "Kuwait", "Caymanøerne", "Kasakhstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxembourg", "Letland", "Libyen", "Marokko", "Monaco", "Moldova", "Montenegro", "Saint Martin", "Madagaskar", "Marshalløerne", "Nordmakedonien", "Mali", "Myanmar (Burma)", "Mongoliet", "SAR Macao", "Nordmarianerne", "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius", "Maldiverne", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "Ny Kaledonien", "Niger", "Norfolk Island", "Nigeria", "Nicaragua", "Nederlandene", "Norge", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "Fransk Polynesien", "Papua Ny Guinea", "Filippinerne", "Pakistan", "Polen", "Saint Pierre og Miquelon", "Pitcairn", "Puerto Rico", "De palæstinensiske områder", "Portugal", "Palau", "Paraguay", "Qatar", "Ydre Oceanien", "Réunion", "Rumænien", "Serbien", "Rusland", "Rwanda", "Saudi-Arabien", "Salomonøerne", "Seychellerne", "Sudan", "Sverige", "Singapore", "St. Helena", "Slovenien", "Svalbard og Jan Mayen", "Slovakiet", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sydsudan", "São Tomé og Príncipe", "El Salvador", "Sint Maarten", "Syrien", "Eswatini", "Tristan da Cunha", "Turks- og Caicosøerne", "Tchad", "De Franske Besiddelser i Det Sydlige Indiske Ocean og Antarktis", "Togo", "Thailand", "Tadsjikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunesien", "Tonga", "Tyrkiet", "Trinidad og Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "Amerikanske oversøiske øer", "De Forenede Nationer", "USA", "Uruguay", "Usbekistan", "Vatikanstaten", "Saint Vincent og Grenadinerne", "Venezuela", "De Britiske Jomfruøer", "De Amerikanske Jomfruøer", "Vietnam", "Vanuatu", "Wallis og Futuna", "Samoa", "pseudo-accenter", "pseudo-bidi", "Kosovo", "Yemen", "Mayotte", "Sydafrika", "Zambia", "Zimbabwe"];
+
+// nl
+static R22: [&str; 294] = ["wereld", "Afrika", "Noord-Amerika", "Zuid-Amerika", "Oceanië", "West-Afrika", "Midden-Amerika", "Oost-Afrika", "Noord-Afrika", "Centraal-Afrika", "Zuidelijk Afrika", "Amerika", "Noordelijk Amerika", "Caribisch gebied", "Oost-Azië", "Zuid-Azië", "Zuidoost-Azië", "Zuid-Europa", "Australazië", "Melanesië", "Micronesische regio", "Polynesië", "Azië", "Centraal-Azië", "West-Azië", "Europa", "Oost-Europa", "Noord-Europa", "West-Europa", "Sub-Saharaans Afrika", "Latijns-Amerika", "Ascension", "Andorra", "Verenigde Arabische Emiraten", "Afghanistan", "Antigua en Barbuda", "Anguilla", "Albanië", "Armenië", "Angola", "Antarctica", "Argentinië", "Amerikaans-Samoa", "Oostenrijk", "Australië", "Aruba", "Åland", "Azerbeidzjan", "Bosnië en Herzegovina", "Barbados", "Bangladesh", "België", "Burkina Faso", "Bulgarije", "Bahrein", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Caribisch Nederland", "Brazilië", "Bahama’s", "Bhutan", "Bouveteiland", "Botswana", "Belarus", "Belize", "Canada", "Cocoseilanden", "Congo-Kinshasa", "Centraal-Afrikaanse Republiek", "Congo-Brazzaville", "Zwitserland", "Ivoorkust", "Cookeilanden", "Chili", "Kameroen", "China", "Colombia", "Clipperton", "Sark", "Costa Rica", "Cuba", "Kaapverdië", "Curaçao", "Christmaseiland", "Cyprus", "Tsjechië", "Duitsland", "Diego Garcia", "Djibouti", "Denemarken", "Dominica", "Dominicaanse Republiek", "Algerije", "Ceuta en Melilla", "Ecuador", "Estland", "Egypte", "Westelijke Sahara", "Eritrea", "Spanje", "Ethiopië", "Europese Unie", "eurozone", "Finland", "Fiji", "Falklandeilanden", "Micronesia", "Faeröer", "Frankrijk", "Gabon", "Verenigd Koninkrijk", "Grenada", "Georgië", "Frans-Guyana", "Guernsey", "Ghana", "Gibraltar", "Groenland", "Gambia", "Guinee", "Guadeloupe", "Equatoriaal-Guinea", "Griekenland", "Zuid-Georgia en Zuidelijke Sandwicheilanden", "Guatemala", "Guam", "Guinee-Bissau", "Guyana", "Hongkong SAR van China", "Heard en McDonaldeilanden", "Honduras", "Kroatië", "Haïti", "Hongarije", "Canarische Eilanden", "Indonesië", "Ierland", "Israël", "Isle of Man", "India", "Brits Indische Oceaanterritorium", "Irak", "Iran", "IJsland", "Italië", "Jersey", "Jamaica", "Jordanië", "Japan", "Kenia", "Kirgizië", "Cambodja", "Kiribati", "Comoren", "Saint Kitts en Nevis", "Noord-Korea", "Zuid-Korea", "Koeweit", "Kaaimaneilanden", "Kazachstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litouwen", "Luxemburg", "Letland", "Libië", "Marokko", "Monaco", "Moldavië", "Montenegro", "Saint-Martin", "Madagaskar", "Marshalleilanden", "Noord-Macedonië", "Mali", "Myanmar (Birma)", "Mongolië", "Macau SAR van China", "Noordelijke Marianen", "Martinique", "Mauritanië", "Montserrat", "Malta", "Mauritius", "Maldiven", "Malawi", "Mexico", "Maleisië", "Mozambique", "Namibië", "Nieuw-Caledonië", "Niger", "Norfolk", "Nigeria", "Nicaragua", "Nederland", "Noorwegen", "Nepal", "Nauru", "Niue", "Nieuw-Zeeland", "Oman", "Panama", "Peru", "Frans-Polynesië", "Papoea-Nieuw-Guinea", "Filipijnen", "Pakistan", "Polen", "Saint-Pierre en Miquelon", "Pitcairneilanden", "Puerto Rico", "Palestijnse gebieden", "Portugal", "Palau", "Paraguay", "Qatar", "overig Oceanië", "Réunion", "Roemenië", "Servië", "Rusland", "Rwanda", "Saoedi-Arabië", "Salomonseilanden", "Seychellen", "Soedan", "Zweden", "Singapore", "Sint-Helena", "Slovenië", "Spitsbergen en Jan Mayen", "Slowakije", "Sierra Leone", "San Marino", "Senegal", "Somalië", "Suriname", "Zuid-Soedan", "Sao Tomé en Principe", "El Salvador", "Sint-Maarten", "Syrië", "Eswatini", "Tristan da Cunha", "Turks- en Caicoseilanden", "Tsjaad", "Franse Gebieden in de zuidelijke Indische Oceaan", "Togo", "Thailand", "Tadzjikistan", "Tokelau", "Oost-Timor", "Turkmenistan", "Tunesië", "Tonga", "Turkije", "Trinidad en Tobago", "Tuvalu", "Taiwan", "Tanzania", "Oekraïne", "Oeganda", "Kleine afgelegen eilanden van de Verenigde Staten", "Verenigde Naties", "Verenigde Staten", "Uruguay", "Oezbekistan", "Vaticaanstad", "Saint Vincent en de Grenadines", "Venezuela", "Britse Maagdeneilanden", "Amerikaanse Maagdeneilanden", "Vietnam", "Vanuatu", "Wallis en Futuna", "Samoa", "Pseudo-Accenten", "Pseudo-Bidi", "Kosovo", "Jemen", "Mayotte", "Zuid-Afrika", "Zambia", "Zimbabwe"];
+
+// en
+static R23: [&str; 294] = ["world", "Africa", "North America", "South America", "Oceania", "Western Africa", "Central America", "Eastern Africa", "Northern Africa", "Middle Africa", "Southern Africa", "Americas", "Northern America", "Caribbean", "Eastern Asia", "Southern Asia", "Southeast Asia", "Southern Europe", "Australasia", "Melanesia", "Micronesian Region", "Polynesia", "Asia", "Central Asia", "Western Asia", "Europe", "Eastern Europe", "Northern Europe", "Western Europe", "Sub-Saharan Africa", "Latin America", "Ascension Island", "Andorra", "United Arab Emirates", "Afghanistan", "Antigua & Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "American Samoa", "Austria", "Australia", "Aruba", "Åland Islands", "Azerbaijan", "Bosnia & Herzegovina", "Barbados", "Bangladesh", "Belgium", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "St. Barthélemy", "Bermuda", "Brunei", "Bolivia", "Caribbean Netherlands", "Brazil", "Bahamas", "Bhutan", "Bouvet Island", "Botswana", "Belarus", "Belize", "Canada", "Cocos (Keeling) Islands", "Congo - Kinshasa", "Central African Republic", "Congo - Brazzaville", "Switzerland", "Côte d’Ivoire", "Cook Islands", "Chile", "Cameroon", "China", "Colombia", "Clipperton Island", "Sark", "Costa Rica", "Cuba", "Cape Verde", "Curaçao", "Christmas Island", "Cyprus", "Czechia", "Germany", "Diego Garcia", "Djibouti", "Denmark", "Dominica", "Dominican Republic", "Algeria", "Ceuta & Melilla", "Ecuador", "Estonia", "Egypt", "Western Sahara", "Eritrea", "Spain", "Ethiopia", "European Union", "Eurozone", "Finland", "Fiji", "Falkland Islands", "Micronesia", "Faroe Islands", "France", "Gabon", "United Kingdom", "Grenada", "Georgia", "French Guiana", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Equatorial Guinea", "Greece", "South Georgia & South Sandwich Islands", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hong Kong SAR China", "Heard & McDonald Islands", "Honduras", "Croatia", "Haiti", "Hungary", "Canary Islands", "Indonesia", "Ireland", "Israel", "Isle of Man", "India", "British Indian Ocean Territory", "Iraq", "Iran", "Iceland", "Italy", "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kyrgyzstan", "Cambodia", "Kiribati", "Comoros", "St. Kitts & Nevis", "North Korea", "South Korea", "Kuwait", "Cayman Islands", "Kazakhstan", "Laos", "Lebanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lithuania", "Luxembourg", "Latvia", "Libya", "Morocco", "Monaco", "Moldova", "Montenegro", "St. Martin", "Madagascar", "Marshall Islands", "North Macedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macao SAR China", "Northern Mariana Islands", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldives", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger", "Norfolk Island", "Nigeria", "Nicaragua", "Netherlands", "Norway", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "French Polynesia", "Papua New Guinea", "Philippines", "Pakistan", "Poland", "St. Pierre & Miquelon", "Pitcairn Islands", "Puerto Rico", "Palestinian Territories", "Portugal", "Palau", "Paraguay", "Qatar", "Outlying Oceania", "Réunion", "Romania", "Serbia", "Russia", "Rwanda", "Saudi Arabia", "Solomon Islands", "Seychelles", "Sudan", "Sweden", "Singapore", "St. Helena", "Slovenia", "Svalbard & Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "South Sudan", "São Tomé & Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Turks & Caicos Islands", "Chad", "French Southern Territories", "Togo", "Thailand", "Tajikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Türkiye", "Trinidad & Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "U.S. Outlying Islands", "United Nations", "United States", "Uruguay", "Uzbekistan", "Vatican City", "St. Vincent & Grenadines", "Venezuela", "British Virgin Islands", "U.S. Virgin Islands", "Vietnam", "Vanuatu", "Wallis & Futuna", "Samoa", "Pseudo-Accents", "Pseudo-Bidi", "Kosovo", "Yemen", "Mayotte", "South Africa", "Zambia", "Zimbabwe"];
+
+// eo
+static R24: [&str; 294] = ["mondo", "Afriko", "Nordameriko", "Sudameriko", "Oceanio", "Okcidenta Afriko", "Centra Ameriko", "Orienta Afriko", "Norda Afriko", "Centra Afriko", "Suda Afriko", "Amerikoj", "Norda Ameriko", "Kariba regiono", "Orienta Azio", "Suda Azio", "Sud-orienta Azio", "Suda Eŭropo", "Aŭstralazio", "Melanezio", "Mikronezia regiono", "Polinezio", "Azio", "Centra Azio", "Okcidenta Azio", "Eŭropo", "Orienta Eŭropo", "Norda Eŭropo", "Okcidenta Eŭropo", "Subsahara Afriko", "Latinameriko", "Ascension", "Andoro", "Unuiĝintaj Arabaj Emirlandoj", "Afganujo", "Antigvo kaj Barbudo", "Angvilo", "Albanujo", "Armenujo", "Angolo", "Antarkto", "Argentino", "Usona Samoo", "Aŭstrujo", "Aŭstralio", "Arubo", "Alando", "Azerbajĝano", "Bosnujo kaj Hercegovino", "Barbado", "Bangladeŝo", "Belgujo", "Burkino", "Bulgarujo", "Barejno", "Burundo", "Benino", "Sankta Bartolomeo", "Bermudoj", "Brunejo", "Bolivio", "Kariba Nederlando", "Brazilo", "Bahamoj", "Butano", "Buvetinsulo", "Bocvano", "Belorusujo", "Belizo", "Kanado", "Kokosinsuloj", "Kongo Kinŝasa", "Centr-Afriko", "Kongo Brazavila", "Svisujo", "Ebur-Bordo", "Kukinsuloj", "Ĉilio", "Kameruno", "Ĉinujo", "Kolombio", "Klipertono", "Sark", "Kostariko", "Kubo", "Kaboverdo", "Kuracao", "Kristnaskinsulo", "Kipro", "Ĉeĥujo", "Germanujo", "Diego Garcia", "Ĝibutio", "Danujo", "Dominiko", "Dominika Respubliko", "Alĝerio", "Ceŭto kaj Melilo", "Ekvadoro", "Estonujo", "Egiptujo", "Okcidenta Saharo", "Eritreo", "Hispanujo", "Etiopujo", "Eŭropa Unio", "Eŭrozono", "Finnlando", "Fiĝio", "Falklandoj", "Mikronezio", "Ferooj", "Francujo", "Gabono", "Unuiĝinta Reĝlando", "Grenado", "Kartvelujo", "Franca Gujano", "Gernezejo", "Ganao", "Ĝibraltaro", "Gronlando", "Gambio", "Gvineo", "Gvadelupo", "Ekvatora Gvineo", "Grekujo", "Sud-Georgio kaj Sud-Sandviĉinsuloj", "Gvatemalo", "Gvamo", "Gvineo-Bisaŭo", "Gujano", "Honkongo (SAR de Ĉinujo)", "Herda kaj Makdonaldaj Insuloj", "Honduro", "Kroatujo", "Haitio", "Hungarujo", "Kanarioj", "Indonezio", "Irlando", "Israelo", "Manksinsulo", "Hindujo", "Brita Hindoceana Teritorio", "Irako", "Irano", "Islando", "Italujo", "Ĵerzejo", "Jamajko", "Jordanio", "Japanujo", "Kenjo", "Kirgizujo", "Kamboĝo", "Kiribato", "Komoroj", "Sankta Kristoforo kaj Neviso", "Nord-Koreujo", "Sud-Koreujo", "Kuvajto", "Kajmana Insularo", "Kazaĥujo", "Laoso", "Libano", "Sankta Lucio", "Liĥtenŝtejno", "Srilanko", "Liberio", "Lesoto", "Litovujo", "Luksemburgo", "Latvujo", "Libio", "Maroko", "Monako", "Moldavujo", "Montenegro", "Saint-Martin", "Madagaskaro", "Marŝaloj", "Nord-Makedonujo", "Malio", "Birmo", "Mongolujo", "Makao (SAR de Ĉinujo)", "Nord-Marianoj", "Martiniko", "Maŭritanio", "Moncerato", "Malto", "Maŭricio", "Maldivoj", "Malavio", "Meksiko", "Malajzio", "Mozambiko", "Namibio", "Nov-Kaledonio", "Niĝero", "Norfolkinsulo", "Niĝerio", "Nikaragvo", "Nederlando", "Norvegujo", "Nepalo", "Nauro", "Niuo", "Nov-Zelando", "Omano", "Panamo", "Peruo", "Franca Polinezio", "Papuo-Nov-Gvineo", "Filipinoj", "Pakistano", "Pollando", "Sankta Piero kaj Mikelono", "Pitkarna Insulo", "Puertoriko", "Palestinaj teritorioj", "Portugalujo", "Palaŭo", "Paragvajo", "Kataro", "malproksimaj insuletoj de Oceanio", "Reunio", "Rumanujo", "Serbujo", "Rusujo", "Ruando", "Sauda Arabujo", "Salomonoj", "Sejŝeloj", "Sudano", "Svedujo", "Singapuro", "Sankta Heleno", "Slovenujo", "Svalbardo kaj Janmajeno", "Slovakujo", "Sieraleono", "Sanmarino", "Senegalo", "Somalujo", "Surinamo", "Sud-Sudano", "Santomeo kaj Principeo", "Salvadoro", "Sint-Maarten", "Sirio", "Svazilando", "Tristan da Cunha", "Turkoj kaj Kajkoj", "Ĉado", "Francaj Sudaj Teritorioj", "Togolando", "Tajlando", "Taĝikujo", "Tokelao", "Orienta Timoro", "Turkmenujo", "Tunizio", "Tongo", "Turkujo", "Trinidado kaj Tobago", "Tuvalo", "Tajvano", "Tanzanio", "Ukrainujo", "Ugando", "Usonaj malgrandaj insuloj", "Unuiĝintaj Nacioj", "Usono", "Urugvajo", "Uzbekujo", "Vatikano", "Sankta Vincento kaj Grenadinoj", "Venezuelo", "Britaj Virgulininsuloj", "Usonaj Virgulininsuloj", "Vjetnamujo", "Vanuatuo", "Valiso kaj Futuno", "Samoo", "pseŭdo-supersignoj", "pseŭdo-inversdirekta", "Kosovo", "Jemeno", "Majoto", "Sud-Afriko", "Zambio", "Zimbabvo"];
+
+// et
+static R25: [&str; 294] = ["maailm", "Aafrika", "Põhja-Ameerika", "Lõuna-Ameerika", "Okeaania", "Lääne-Aafrika", "Kesk-Ameerika", "Ida-Aafrika", "Põhja-Aafrika", "Kesk-Aafrika", "Lõuna-Aafrika", "Ameerika", "Ameerika põhjaosa", "Kariibi piirkond", "Ida-Aasia", "Lõuna-Aasia", "Kagu-Aasia", "Lõuna-Euroopa", "Australaasia", "Melaneesia", "Mikroneesia (piirkond)", "Polüneesia", "Aasia", "Kesk-Aasia", "Lääne-Aasia", "Euroopa", "Ida-Euroopa", "Põhja-Euroopa", "Lääne-Euroopa", "Sahara-tagune Aafrika", "Ladina-Ameerika", "Ascensioni saar", "Andorra", "Araabia Ühendemiraadid", "Afganistan", "Antigua ja Barbuda", "Anguilla", "Albaania", "Armeenia", "Angola", "Antarktika", "Argentina", "Ameerika Samoa", "Austria", "Austraalia", "Aruba", "Ahvenamaa", "Aserbaidžaan", "Bosnia ja Hertsegoviina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaaria", "Bahrein", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Boliivia", "Kariibi Madalmaad", "Brasiilia", "Bahama", "Bhutan", "Bouvet’ saar", "Botswana", "Valgevene", "Belize", "Kanada", "Kookossaared", "Kongo DV", "Kesk-Aafrika Vabariik", "Kongo Vabariik", "Šveits", "Elevandiluurannik", "Cooki saared", "Tšiili", "Kamerun", "Hiina", "Colombia", "Clippertoni saar", "Sark", "Costa Rica", "Kuuba", "Roheneemesaared", "Curaçao", "Jõulusaar", "Küpros", "Tšehhi", "Saksamaa", "Diego Garcia", "Djibouti", "Taani", "Dominica", "Dominikaani Vabariik", "Alžeeria", "Ceuta ja Melilla", "Ecuador", "Eesti", "Egiptus", "Lääne-Sahara", "Eritrea", "Hispaania", "Etioopia", "Euroopa Liit", "euroala", "Soome", "Fidži", "Falklandi saared", "Mikroneesia", "Fääri saared", "Prantsusmaa", "Gabon", "Ühendkuningriik", "Grenada", "Gruusia", "Prantsuse Guajaana", "Guernsey", "Ghana", "Gibraltar", "Gröönimaa", "Gambia", "Guinea", "Guadeloupe", "Ekvatoriaal-Guinea", "Kreeka", "Lõuna-Georgia ja Lõuna-Sandwichi saared", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkongi erihalduspiirkond", "Heardi ja McDonaldi saared", "Honduras", "Horvaatia", "Haiti", "Ungari", "Kanaari saared", "Indoneesia", "Iirimaa", "Iisrael", "Mani saar", "India", "Briti India ookeani ala", "Iraak", "Iraan", "Island", "Itaalia", "Jersey", "Jamaica", "Jordaania", "Jaapan", "Keenia", "Kõrgõzstan", "Kambodža", "Kiribati", "Komoorid", "Saint Kitts ja Nevis", "Põhja-Korea", "Lõuna-Korea", "Kuveit", "Kaimanisaared", "Kasahstan", "Laos", "Liibanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Libeeria", "Lesotho", "Leedu", "Luksemburg", "Läti", "Liibüa", "Maroko", "Monaco", "Moldova", "Montenegro", "Saint-Martin", "Madagaskar", "Marshalli Saared", "Põhja-Makedoonia", "Mali", "Myanmar (Birma)", "Mongoolia", "Macau erihalduspiirkond", "Põhja-Mariaanid", "Martinique", "Mauritaania", "Montserrat", "Malta", "Mauritius", "Maldiivid", "Malawi", "Mehhiko", "Malaisia", "Mosambiik", "Namiibia", "Uus-Kaledoonia", "Niger", "Norfolk", "Nigeeria", "Nicaragua", "Holland", "Norra", "Nepal", "Nauru", "Niue", "Uus-Meremaa", "Omaan", "Panama", "Peruu", "Prantsuse Polüneesia", "Paapua Uus-Guinea", "Filipiinid", "Pakistan", "Poola", "Saint-Pierre ja Miquelon", "Pitcairni saared", "Puerto Rico", "Palestiina alad", "Portugal", "Belau", "Paraguay", "Katar", "Okeaania hajasaared", "Réunion", "Rumeenia", "Serbia", "Venemaa", "Rwanda", "Saudi Araabia", "Saalomoni Saared", "Seišellid", "Sudaan", "Rootsi", "Singapur", "Saint Helena", "Sloveenia", "Svalbard ja Jan Mayen", "Slovakkia", "Sierra Leone", "San Marino", "Senegal", "Somaalia", "Suriname", "Lõuna-Sudaan", "São Tomé ja Príncipe", "El Salvador", "Sint Maarten", "Süüria", "Svaasimaa", "Tristan da Cunha", "Turks ja Caicos", "Tšaad", "Prantsuse Lõunaalad", "Togo", "Tai", "Tadžikistan", "Tokelau", "Ida-Timor", "Türkmenistan", "Tuneesia", "Tonga", "Türgi", "Trinidad ja Tobago", "Tuvalu", "Taiwan", "Tansaania", "Ukraina", "Uganda", "Ühendriikide hajasaared", "Ühinenud Rahvaste Organisatsioon", "Ameerika Ühendriigid", "Uruguay", "Usbekistan", "Vatikan", "Saint Vincent ja Grenadiinid", "Venezuela", "Briti Neitsisaared", "USA Neitsisaared", "Vietnam", "Vanuatu", "Wallis ja Futuna", "Samoa", "pseudo-aktsent", "pseudobidi", "Kosovo", "Jeemen", "Mayotte", "Lõuna-Aafrika Vabariik", "Sambia", "Zimbabwe"];
+
+// ee
+static R26: [&str; 294] = ["xexeme", "Afrika nutome", "Dziehe Amerika nutome", "Anyiehe Amerika nutome", "Oceania nutome", "Ɣetoɖoƒelɔƒo Afrika nutome", "Titina Amerika nutome", "Ɣedzeƒe Afrika nutome", "Dziehe Afrika nutome", "Titina Afrika nutome", "Anyiehelɔƒo Afrika nutome", "Amerika nutome", "Dziehelɔƒo Amerika nutome", "Karibbea nutome", "Ɣedzeƒe Asia nutome", "Anyiehelɔƒo Asia nutome", "Anyiehe Ɣedzeƒe Afrika nutome", "Anyiehelɔƒo Europa nutome", "Australia kple New Zealand nutome", "Melanesia nutome", "Mikronesia", "Pɔlinesia nutome", "Asia nutome", "Titina Asia nutome", "Ɣetoɖoƒelɔƒo Asia nutome", "Europa nutome", "Ɣedzeƒe Europa nutome", "Dziehelɔƒo Europa nutome", "Ɣetoɖoƒelɔƒo Europa nutome", "", "Latin Amerika nutome", "Ascension ƒudomekpo nutome", "Andorra nutome", "United Arab Emirates nutome", "Afghanistan nutome", "́Antigua kple Barbuda nutome", "Anguilla nutome", "Albania nutome", "Armenia nutome", "Angola nutome", "Antartica nutome", "Argentina nutome", "Amerika Samoa nutome", "Austria nutome", "Australia nutome", "Aruba nutome", "Åland ƒudomekpo nutome", "Azerbaijan nutome", "Bosnia kple Herzergovina nutome", "Barbados nutome", "Bangladesh nutome", "Belgium nutome", "Burkina Faso nutome", "Bulgaria nutome", "Bahrain nutome", "Burundi nutome", "Benin nutome", "Saint Barthélemy nutome", "Bermuda nutome", "Brunei nutome", "Bolivia nutome", "", "Brazil nutome", "Bahamas nutome", "Bhutan nutome", "Bouvet ƒudomekpo nutome", "Botswana nutome", "Belarus nutome", "Belize nutome", "Canada nutome", "Kokos (Kiling) fudomekpo nutome", "Kongo Kinshasa nutome", "Titina Afrika repɔblik nutome", "Kongo Brazzaville nutome", "Switzerland nutome", "Kote d’Ivoire nutome", "Kook ƒudomekpo nutome", "Tsile nutome", "Kamerun nutome", "Tsaina nutome", "Kolombia nutome", "Klipaton ƒudomekpo nutome", "", "Kosta Rika nutome", "Kuba nutome", "Kape Verde nutome", "", "Kristmas ƒudomekpo nutome", "Saiprus nutome", "Tsɛk repɔblik nutome", "Germania nutome", "Diego Garsia nutome", "Dzibuti nutome", "Denmark nutome", "Dominika nutome", "Dominika repɔblik nutome", "Algeria nutome", "Keuta and Melilla nutome", "Ekuadɔ nutome", "Estonia nutome", "Egypte nutome", "Ɣetoɖoƒe Sahara nutome", "Eritrea nutome", "Spain nutome", "Etiopia nutome", "Europa Wɔɖeka nutome", "", "Finland nutome", "Fidzi nutome", "Falkland ƒudomekpowo nutome", "Mikronesia nutome", "Faroe ƒudomekpowo nutome", "France nutome", "Gabɔn nutome", "United Kingdom nutome", "Grenada nutome", "Georgia nutome", "Frentsi Gayana nutome", "Guernse nutome", "Ghana nutome", "Gibraltar nutome", "Grinland nutome", "Gambia nutome", "Guini nutome", "Guadelupe nutome", "Ekuatorial Guini nutome", "Greece nutome", "Anyiehe Georgia kple Anyiehe Sandwich ƒudomekpowo nutome", "Guatemala nutome", "Guam nutome", "Gini-Bisao nutome", "Guyanadu", "Hɔng Kɔng SAR Tsaina nutome", "Heard kple Mcdonald ƒudomekpowo nutome", "Hondurasdu", "Kroatsia nutome", "Haiti nutome", "Hungari nutome", "Kanari ƒudomekpowo nutome", "Indonesia nutome", "Ireland nutome", "Israel nutome", "Aisle of Man nutome", "India nutome", "", "iraqdukɔ", "Iran nutome", "Aiseland nutome", "Italia nutome", "Dzɛse nutome", "Dzamaika nutome", "Yordan nutome", "Dzapan nutome", "Kenya nutome", "Kirgizstan nutome", "Kambodia nutome", "Kiribati nutome", "Komoros nutome", "Saint Kitis kple Nevis nutome", "Dziehe Korea nutome", "Anyiehe Korea nutome", "Kuwait nutome", "Kayman ƒudomekpowo nutome", "Kazakstan nutome", "Laos nutome", "Lebanɔn nutome", "Saint Lusia nutome", "Litsenstein nutome", "Sri Lanka nutome", "Liberia nutome", "Lɛsoto nutome", "Lituania nutome", "Lazembɔg nutome", "Latvia nutome", "Libya nutome", "Moroko nutome", "Monako nutome", "Moldova nutome", "Montenegro nutome", "Saint Martin nutome", "Madagaska nutome", "Marshal ƒudomekpowo nutome", "", "Mali nutome", "Myanmar (Burma) nutome", "Mongolia nutome", "Macau SAR Tsaina nutome", "Dziehe Marina ƒudomekpowo nutome", "Martiniki nutome", "Mauritania nutome", "Montserrat nutome", "Malta nutome", "mauritiusdukɔ", "maldivesdukɔ", "Malawi nutome", "Mexico nutome", "Malaysia nutome", "Mozambiki nutome", "Namibia nutome", "New Kaledonia nutome", "Niger nutome", "Norfolk ƒudomekpo nutome", "Nigeria nutome", "Nicaraguadukɔ", "Netherlands nutome", "Norway nutome", "Nepal nutome", "Nauru nutome", "Niue nutome", "New Zealand nutome", "Oman nutome", "Panama nutome", "Peru nutome", "Frentsi Pɔlinesia nutome", "Papua New Gini nutome", "Filipini nutome", "Pakistan nutome", "Poland nutome", "Saint Pierre kple Mikelɔn nutome", "Pitkairn ƒudomekpo nutome", "Puerto Riko nutome", "Palestinia nutome", "Portugal nutome", "Palau nutome", "Paragua nutome", "Katar nutome", "Outlaying Oceania nutome", "Réunion nutome", "Romania nutome", "", "Russia nutome", "Rwanda nutome", "Saudi Arabia nutome", "Solomon ƒudomekpowo nutome", "Seshɛls nutome", "Sudan nutome", "Sweden nutome", "Singapɔr nutome", "Saint Helena nutome", "Slovenia nutome", "Svalbard kple Yan Mayen nutome", "Slovakia nutome", "Sierra Leone nutome", "San Marino nutome", "Senegal nutome", "Somalia nutome", "Suriname nutome", "", "São Tomé kple Príncipe nutome", "El Salvadɔ nutome", "", "Siria nutome", "Swaziland nutome", "Tristan da Kunha nutome", "Tɛks kple Kaikos ƒudomekpowo nutome", "Tsad nutome", "Anyiehe Franseme nutome", "Togo nutome", "Thailand nutome", "Tajikistan nutome", "Tokelau nutome", "Timor-Leste nutome", "Tɛkmenistan nutome", "Tunisia nutome", "Tonga nutome", "Tɛki nutome", "Trinidad kple Tobago nutome", "Tuvalu nutome", "Taiwan nutome", "Tanzania nutome", "Ukraine nutome", "Uganda nutome", "U.S. Minor Outlaying ƒudomekpowo nutome", "", "USA nutome", "uruguaydukɔ", "Uzbekistan nutome", "Vatikandu nutome", "Saint Vincent kple Grenadine nutome", "Venezuela nutome", "Britaintɔwo ƒe Virgin ƒudomekpowo nutome", "U.S. Vɛrgin ƒudomekpowo nutome", "Vietnam nutome", "Vanuatu nutome", "Wallis kple Futuna nutome", "Samoa nutome", "", "", "", "Yemen nutome", "Mayotte nutome", "Anyiehe Africa nutome", "Zambia nutome", "Zimbabwe nutome"];
+
+// fo
+static R27: [&str; 294] = ["heimur", "Afrika", "Norðuramerika", "Suðuramerika", "Osiania", "Vesturafrika", "Miðamerika", "Eysturafrika", "Norðurafrika", "Miðafrika", "sunnari partur av Afrika", "Amerika", "Amerika norðanfyri Meksiko", "Karibia", "Eysturasia", "Suðurasia", "Útsynningsasia", "Suðurevropa", "Avstralasia", "Melanesia", "Mikronesi øki", "Polynesia", "Asia", "Miðasia", "Vesturasia", "Evropa", "Eysturevropa", "Norðurevropa", "Vesturevropa", "Afrika sunnanfyri Sahara", "Latínamerika", "Ascension", "Andorra", "Sameindu Emirríkini", "Afganistan", "Antigua & Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktis", "Argentina", "Amerikanska Samoa", "Eysturríki", "Avstralia", "Aruba", "Áland", "Aserbadjan", "Bosnia-Hersegovina", "Barbados", "Bangladesj", "Belgia", "Burkina Faso", "Bulgaria", "Barein", "Burundi", "Benin", "St. Barthélemy", "Bermuda", "Brunei", "Bolivia", "Niðurlonds Karibia", "Brasil", "Bahamaoyggjar", "Butan", "Bouvetoyggj", "Botsvana", "Hvítarussland", "Belis", "Kanada", "Kokosoyggjar", "Kongo, Dem. Lýðveldið", "Miðafrikalýðveldið", "Kongo", "Sveis", "Fílabeinsstrondin", "Cooksoyggjar", "Kili", "Kamerun", "Kina", "Kolombia", "Clipperton", "Sark", "Kosta Rika", "Kuba", "Grønhøvdaoyggjar", "Curaçao", "Jólaoyggjin", "Kýpros", "Kekkia", "Týskland", "Diego Garcia", "Djibuti", "Danmark", "Dominika", "Dominikalýðveldið", "Algeria", "Ceuta & Melilla", "Ekvador", "Estland", "Egyptaland", "Vestursahara", "Eritrea", "Spania", "Etiopia", "Evropasamveldið", "Evrasona", "Finnland", "Fiji", "Falklandsoyggjar", "Mikronesiasamveldið", "Føroyar", "Frakland", "Gabon", "Stórabretland", "Grenada", "Georgia", "Franska Gujana", "Guernsey", "Gana", "Gibraltar", "Grønland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorguinea", "Grikkaland", "Suðurgeorgia og Suðursandwichoyggjar", "Guatemala", "Guam", "Guinea-Bissau", "Gujana", "Hong Kong SAR Kina", "Heard og McDonaldoyggjar", "Honduras", "Kroatia", "Haiti", "Ungarn", "Kanariuoyggjar", "Indonesia", "Írland", "Ísrael", "Isle of Man", "India", "Stóra Bretlands Indiahavoyggjar", "Irak", "Iran", "Ísland", "Italia", "Jersey", "Jamaika", "Jordan", "Japan", "Kenja", "Kirgisia", "Kambodja", "Kiribati", "Komoroyggjar", "St. Kitts & Nevis", "Norðurkorea", "Suðurkorea", "Kuvait", "Caymanoyggjar", "Kasakstan", "Laos", "Libanon", "St. Lusia", "Liktinstein", "Sri Lanka", "Liberia", "Lesoto", "Litava", "Luksemborg", "Lettland", "Libya", "Marokko", "Monako", "Moldova", "Montenegro", "St-Martin", "Madagaskar", "Marshalloyggjar", "Norður Makedónia", "Mali", "Myanmar (Burma)", "Mongolia", "Makao SAR Kina", "Norðaru Mariuoyggjar", "Martinique", "Móritania", "Montserrat", "Malta", "Móritius", "Maldivoyggjar", "Malavi", "Meksiko", "Malaisia", "Mosambik", "Namibia", "Nýkaledónia", "Niger", "Norfolksoyggj", "Nigeria", "Nikaragua", "Niðurlond", "Noreg", "Nepal", "Nauru", "Niue", "Nýsæland", "Oman", "Panama", "Peru", "Franska Polynesia", "Papua Nýguinea", "Filipsoyggjar", "Pakistan", "Pólland", "Saint Pierre & Miquelon", "Pitcairnoyggjar", "Puerto Riko", "Palestinskt landøki", "Portugal", "Palau", "Paraguai", "Katar", "fjarskoti Osiania", "Réunion", "Rumenia", "Serbia", "Russland", "Ruanda", "Saudiarabia", "Salomonoyggjar", "Seyskelloyggjar", "Sudan", "Svøríki", "Singapor", "St. Helena", "Slovenia", "Svalbard & Jan Mayen", "Slovakia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam", "Suðursudan", "Sao Tome & Prinsipi", "El Salvador", "Sint Maarten", "Sýria", "Esvatini", "Tristan da Cunha", "Turks- og Caicosoyggjar", "Kjad", "Fronsku sunnaru landaøki", "Togo", "Tailand", "Tadsjikistan", "Tokelau", "Eysturtimor", "Turkmenistan", "Tunesia", "Tonga", "Turkaland", "Trinidad & Tobago", "Tuvalu", "Taivan", "Tansania", "Ukraina", "Uganda", "Sambandsríki Amerikas fjarskotnu oyggjar", "Sameindu Tjóðir", "Sambandsríki Amerika", "Uruguai", "Usbekistan", "Vatikanbýur", "St. Vinsent & Grenadinoyggjar", "Venesuela", "Stóra Bretlands Jomfrúoyggjar", "Sambandsríki Amerikas Jomfrúoyggjar", "Vjetnam", "Vanuatu", "Wallis- og Futunaoyggjar", "Samoa", "óekta tónalag", "óektaður BIDI tekstur", "Kosovo", "Jemen", "Mayotte", "Suðurafrika", "Sambia", "Simbabvi"];
+
+// fi
+static R28: [&str; 294] = ["maailma", "Afrikka", "Pohjois-Amerikka", "Etelä-Amerikka", "Oseania", "Länsi-Afrikka", "Väli-Amerikka", "Itä-Afrikka", "Pohjois-Afrikka", "Keski-Afrikka", "eteläinen Afrikka", "Amerikka", "pohjoinen Amerikka", "Karibia", "Itä-Aasia", "Etelä-Aasia", "Kaakkois-Aasia", "Etelä-Eurooppa", "Australaasia", "Melanesia", "Mikronesian alue", "Polynesia", "Aasia", "Keski-Aasia", "Länsi-Aasia", "Eurooppa", "Itä-Eurooppa", "Pohjois-Eurooppa", "Länsi-Eurooppa", "Saharan eteläpuolinen Afrikka", "Latinalainen Amerikka", "Ascension-saari", "Andorra", "Arabiemiirikunnat", "Afganistan", "Antigua ja Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktis", "Argentiina", "Amerikan Samoa", "Itävalta", "Australia", "Aruba", "Ahvenanmaa", "Azerbaidžan", "Bosnia ja Hertsegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibian Alankomaat", "Brasilia", "Bahama", "Bhutan", "Bouvet’nsaari", "Botswana", "Valko-Venäjä", "Belize", "Kanada", "Kookossaaret (Keelingsaaret)", "Kongon demokraattinen tasavalta", "Keski-Afrikan tasavalta", "Kongon tasavalta", "Sveitsi", "Norsunluurannikko", "Cookinsaaret", "Chile", "Kamerun", "Kiina", "Kolumbia", "Clippertoninsaari", "Sark", "Costa Rica", "Kuuba", "Kap Verde", "Curaçao", "Joulusaari", "Kypros", "Tšekki", "Saksa", "Diego Garcia", "Djibouti", "Tanska", "Dominica", "Dominikaaninen tasavalta", "Algeria", "Ceuta ja Melilla", "Ecuador", "Viro", "Egypti", "Länsi-Sahara", "Eritrea", "Espanja", "Etiopia", "Euroopan unioni", "euroalue", "Suomi", "Fidži", "Falklandinsaaret", "Mikronesia", "Färsaaret", "Ranska", "Gabon", "Iso-Britannia", "Grenada", "Georgia", "Ranskan Guayana", "Guernsey", "Ghana", "Gibraltar", "Grönlanti", "Gambia", "Guinea", "Guadeloupe", "Päiväntasaajan Guinea", "Kreikka", "Etelä-Georgia ja Eteläiset Sandwichinsaaret", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong – Kiinan erityishallintoalue", "Heard ja McDonaldinsaaret", "Honduras", "Kroatia", "Haiti", "Unkari", "Kanariansaaret", "Indonesia", "Irlanti", "Israel", "Mansaari", "Intia", "Brittiläinen Intian valtameren alue", "Irak", "Iran", "Islanti", "Italia", "Jersey", "Jamaika", "Jordania", "Japani", "Kenia", "Kirgisia", "Kambodža", "Kiribati", "Komorit", "Saint Kitts ja Nevis", "Pohjois-Korea", "Etelä-Korea", "Kuwait", "Caymansaaret", "Kazakstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Liettua", "Luxemburg", "Latvia", "Libya", "Marokko", "Monaco", "Moldova", "Montenegro", "Saint-Martin", "Madagaskar", "Marshallinsaaret", "Pohjois-Makedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macao – Kiinan erityishallintoalue", "Pohjois-Mariaanit", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Malediivit", "Malawi", "Meksiko", "Malesia", "Mosambik", "Namibia", "Uusi-Kaledonia", "Niger", "Norfolkinsaari", "Nigeria", "Nicaragua", "Alankomaat", "Norja", "Nepal", "Nauru", "Niue", "Uusi-Seelanti", "Oman", "Panama", "Peru", "Ranskan Polynesia", "Papua-Uusi-Guinea", "Filippiinit", "Pakistan", "Puola", "Saint-Pierre ja Miquelon", "Pitcairn", "Puerto Rico", "Palestiinalaisalue", "Portugali", "Palau", "Paraguay", "Qatar", "Oseanian erillissaaret", "Réunion", "Romania", "Serbia", "Venäjä", "Ruanda", "Saudi-Arabia", "Salomonsaaret", "Seychellit", "Sudan", "Ruotsi", "Singapore", "Saint Helena", "Slovenia", "Huippuvuoret ja Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Etelä-Sudan", "São Tomé ja Príncipe", "El Salvador", "Sint Maarten", "Syyria", "Eswatini", "Tristan da Cunha", "Turks- ja Caicossaaret", "Tšad", "Ranskan eteläiset ja antarktiset alueet", "Togo", "Thaimaa", "Tadžikistan", "Tokelau", "Itä-Timor", "Turkmenistan", "Tunisia", "Tonga", "Turkki", "Trinidad ja Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukraina", "Uganda", "Yhdysvaltain erillissaaret", "Yhdistyneet kansakunnat", "Yhdysvallat", "Uruguay", "Uzbekistan", "Vatikaani", "Saint Vincent ja Grenadiinit", "Venezuela", "Brittiläiset Neitsytsaaret", "Yhdysvaltain Neitsytsaaret", "Vietnam", "Vanuatu", "Wallis ja Futuna", "Samoa", "pseudoaksentit", "kaksisuuntainen pseudo", "Kosovo", "Jemen", "Mayotte", "Etelä-Afrikka", "Sambia", "Zimbabwe"];
+
+// fr, ht
+static R29: [&str; 294] = ["Monde", "Afrique", "Amérique du Nord", "Amérique du Sud", "Océanie", "Afrique occidentale", "Amérique centrale", "Afrique orientale", "Afrique septentrionale", "Afrique centrale", "Afrique australe", "Amériques", "Amérique septentrionale", "Caraïbes", "Asie de l’Est", "Asie du Sud", "Asie du Sud-Est", "Europe du Sud", "Australasie", "Mélanésie", "région micronésienne", "Polynésie", "Asie", "Asie centrale", "Asie de l’Ouest", "Europe", "Europe de l’Est", "Europe du Nord", "Europe de l’Ouest", "Afrique subsaharienne", "Amérique latine", "Île de l’Ascension", "Andorre", "Émirats arabes unis", "Afghanistan", "Antigua-et-Barbuda", "Anguilla", "Albanie", "Arménie", "Angola", "Antarctique", "Argentine", "Samoa américaines", "Autriche", "Australie", "Aruba", "Îles Åland", "Azerbaïdjan", "Bosnie-Herzégovine", "Barbade", "Bangladesh", "Belgique", "Burkina Faso", "Bulgarie", "Bahreïn", "Burundi", "Bénin", "Saint-Barthélemy", "Bermudes", "Brunei", "Bolivie", "Pays-Bas caribéens", "Brésil", "Bahamas", "Bhoutan", "Île Bouvet", "Botswana", "Biélorussie", "Belize", "Canada", "Îles Cocos", "Congo-Kinshasa", "République centrafricaine", "Congo-Brazzaville", "Suisse", "Côte d’Ivoire", "Îles Cook", "Chili", "Cameroun", "Chine", "Colombie", "Île Clipperton", "Sercq", "Costa Rica", "Cuba", "Cap-Vert", "Curaçao", "Île Christmas", "Chypre", "Tchéquie", "Allemagne", "Diego Garcia", "Djibouti", "Danemark", "Dominique", "République dominicaine", "Algérie", "Ceuta et Melilla", "Équateur", "Estonie", "Égypte", "Sahara occidental", "Érythrée", "Espagne", "Éthiopie", "Union européenne", "zone euro", "Finlande", "Fidji", "Îles Malouines", "Micronésie", "Îles Féroé", "France", "Gabon", "Royaume-Uni", "Grenade", "Géorgie", "Guyane française", "Guernesey", "Ghana", "Gibraltar", "Groenland", "Gambie", "Guinée", "Guadeloupe", "Guinée équatoriale", "Grèce", "Géorgie du Sud-et-les Îles Sandwich du Sud", "Guatemala", "Guam", "Guinée-Bissau", "Guyana", "R.A.S. chinoise de Hong Kong", "Îles Heard-et-MacDonald", "Honduras", "Croatie", "Haïti", "Hongrie", "Îles Canaries", "Indonésie", "Irlande", "Israël", "Île de Man", "Inde", "Territoire britannique de l’océan Indien", "Irak", "Iran", "Islande", "Italie", "Jersey", "Jamaïque", "Jordanie", "Japon", "Kenya", "Kirghizstan", "Cambodge", "Kiribati", "Comores", "Saint-Christophe-et-Niévès", "Corée du Nord", "Corée du Sud", "Koweït", "Îles Caïmans", "Kazakhstan", "Laos", "Liban", "Sainte-Lucie", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituanie", "Luxembourg", "Lettonie", "Libye", "Maroc", "Monaco", "Moldavie", "Monténégro", "Saint-Martin", "Madagascar", "Îles Marshall", "Macédoine du Nord", "Mali", "Myanmar (Birmanie)", "Mongolie", "R.A.S. chinoise de Macao", "Îles Mariannes du Nord", "Martinique", "Mauritanie", "Montserrat", "Malte", "Maurice", "Maldives", "Malawi", "Mexique", "Malaisie", "Mozambique", "Namibie", "Nouvelle-Calédonie", "Niger", "Île Norfolk", "Nigeria", "Nicaragua", "Pays-Bas", "Norvège", "Népal", "Nauru", "Niue", "Nouvelle-Zélande", "Oman", "Panama", "Pérou", "Polynésie française", "Papouasie-Nouvelle-Guinée", "Philippines", "Pakistan", "Pologne", "Saint-Pierre-et-Miquelon", "Îles Pitcairn", "Porto Rico", "Territoires palestiniens", "Portugal", "Palaos", "Paraguay", "Qatar", "régions éloignées de l’Océanie", "La Réunion", "Roumanie", "Serbie", "Russie", "Rwanda", "Arabie saoudite", "Îles Salomon", "Seychelles", "Soudan", "Suède", "Singapour", "Sainte-Hélène", "Slovénie", "Svalbard et Jan Mayen", "Slovaquie", "Sierra Leone", "Saint-Marin", "Sénégal", "Somalie", "Suriname", "Soudan du Sud", "Sao Tomé-et-Principe", "Salvador", "Saint-Martin (partie néerlandaise)", "Syrie", "Eswatini", "Tristan da Cunha", "Îles Turques-et-Caïques", "Tchad", "Terres australes françaises", "Togo", "Thaïlande", "Tadjikistan", "Tokelau", "Timor oriental", "Turkménistan", "Tunisie", "Tonga", "Turquie", "Trinité-et-Tobago", "Tuvalu", "Taïwan", "Tanzanie", "Ukraine", "Ouganda", "Îles mineures éloignées des États-Unis", "Nations Unies", "États-Unis", "Uruguay", "Ouzbékistan", "État de la Cité du Vatican", "Saint-Vincent-et-les Grenadines", "Venezuela", "Îles Vierges britanniques", "Îles Vierges des États-Unis", "Viêt Nam", "Vanuatu", "Wallis-et-Futuna", "Samoa", "pseudo-accents", "pseudo-bidi", "Kosovo", "Yémen", "Mayotte", "Afrique du Sud", "Zambie", "Zimbabwe"];
+
+// gl
+static R30: [&str; 294] = ["Mundo", "África", "América do Norte", "América do Sur", "Oceanía", "África Occidental", "América Central", "África Oriental", "África Setentrional", "África Central", "África Meridional", "América", "América Setentrional", "Caribe", "Asia Oriental", "Asia Meridional", "Sueste Asiático", "Europa Meridional", "Australasia", "Melanesia", "Rexión de Micronesia", "Polinesia", "Asia", "Asia Central", "Asia Occidental", "Europa", "Europa do Leste", "Europa Setentrional", "Europa Occidental", "África subsahariana", "América Latina", "Illa de Ascensión", "Andorra", "Emiratos Árabes Unidos", "Afganistán", "Antigua e Barbuda", "Anguila", "Albania", "Armenia", "Angola", "Antártida", "Arxentina", "Samoa Americana", "Austria", "Australia", "Aruba", "Illas Åland", "Acerbaixán", "Bosnia e Hercegovina", "Barbados", "Bangladesh", "Bélxica", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benín", "Saint Barthélemy", "Illas Bermudas", "Brunei", "Bolivia", "Caribe Neerlandés", "Brasil", "Bahamas", "Bután", "Illa Bouvet", "Botswana", "Belarús", "Belize", "Canadá", "Illas Cocos (Keeling)", "República Democrática do Congo", "República Centroafricana", "República do Congo", "Suíza", "Côte d’Ivoire", "Illas Cook", "Chile", "Camerún", "China", "Colombia", "Illa Clipperton", "Sark", "Costa Rica", "Cuba", "Cabo Verde", "Curaçao", "Illa Christmas", "Chipre", "Chequia", "Alemaña", "Diego García", "Djibuti", "Dinamarca", "Dominica", "República Dominicana", "Alxeria", "Ceuta e Melilla", "Ecuador", "Estonia", "Exipto", "Sáhara Occidental", "Eritrea", "España", "Etiopía", "Unión Europea", "Eurozona", "Finlandia", "Fixi", "Illas Malvinas", "Micronesia", "Illas Feroe", "Francia", "Gabón", "Reino Unido", "Granada", "Xeorxia", "Güiana Francesa", "Guernsey", "Ghana", "Xibraltar", "Groenlandia", "Gambia", "Guinea", "Guadalupe", "Guinea Ecuatorial", "Grecia", "Illas Xeorxia do Sur e Sandwich do Sur", "Guatemala", "Guam", "Guinea Bissau", "Güiana", "Hong Kong RAE da China", "Illa Heard e Illas McDonald", "Honduras", "Croacia", "Haití", "Hungría", "Illas Canarias", "Indonesia", "Irlanda", "Israel", "Illa de Man", "India", "Territorio Británico do Océano Índico", "Iraq", "Irán", "Islandia", "Italia", "Jersey", "Xamaica", "Xordania", "Xapón", "Kenya", "Kirguizistán", "Camboxa", "Kiribati", "Comores", "Saint Kitts e Nevis", "Corea do Norte", "Corea do Sur", "Kuwait", "Illas Caimán", "Kazakistán", "Laos", "Líbano", "Santa Lucía", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxemburgo", "Letonia", "Libia", "Marrocos", "Mónaco", "República Moldova", "Montenegro", "Saint Martin", "Madagascar", "Illas Marshall", "Macedonia do Norte", "Malí", "Myanmar (Birmania)", "Mongolia", "Macau RAE da China", "Illas Marianas do Norte", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauricio", "Maldivas", "Malawi", "México", "Malaisia", "Mozambique", "Namibia", "Nova Caledonia", "Níxer", "Illa Norfolk", "Nixeria", "Nicaragua", "Países Baixos", "Noruega", "Nepal", "Nauru", "Niue", "Nova Zelandia", "Omán", "Panamá", "Perú", "Polinesia Francesa", "Papúa-Nova Guinea", "Filipinas", "Paquistán", "Polonia", "Saint Pierre et Miquelon", "Illas Pitcairn", "Porto Rico", "Territorios Palestinos", "Portugal", "Palau", "Paraguai", "Qatar", "Territorios afastados de Oceanía", "Reunión", "Romanía", "Serbia", "Rusia", "Ruanda", "Arabia Saudita", "Illas Salomón", "Seychelles", "Sudán", "Suecia", "Singapur", "Santa Helena", "Eslovenia", "Svalbard e Jan Mayen", "Eslovaquia", "Serra Leoa", "San Marino", "Senegal", "Somalia", "Suriname", "Sudán do Sur", "San Tomé e Príncipe", "O Salvador", "Sint Maarten", "Siria", "Eswatini", "Tristán da Cunha", "Illas Turks e Caicos", "Chad", "Territorios Austrais Franceses", "Togo", "Tailandia", "Taxiquistán", "Tokelau", "Timor Leste", "Turkmenistán", "Tunisia", "Tonga", "Turquía", "Trinidad e Tobago", "Tuvalu", "Taiwán", "Tanzania", "Ucraína", "Uganda", "Illas Menores Distantes dos Estados Unidos", "Nacións Unidas", "Estados Unidos", "Uruguai", "Uzbekistán", "Cidade do Vaticano", "San Vicente e as Granadinas", "Venezuela", "Illas Virxes Británicas", "Illas Virxes Estadounidenses", "Vietnam", "Vanuatu", "Wallis e Futuna", "Samoa", "Pseudoacentos", "Pseudobidireccional", "Kosovo", "Iemen", "Mayotte", "Suráfrica", "Zambia", "Zimbabwe"];
+
+// ka
+static R31: [&str; 294] = ["მსოფლიო", "აფრიკა", "ჩრდილოეთ ამერიკა", "სამხრეთ ამერიკა", "ოკეანეთი", "დასავლეთ აფრიკა", "ცენტრალური ამერიკა", "აღმოსავლეთ აფრიკა", "ჩრდილოეთ აფრიკა", "შუა აფრიკა", "სამხრეთ აფრიკა", "ამერიკები", "ამერიკის ჩრდილოეთი", "კარიბის ზღვა", "აღმოსავლეთ აზია", "სამხრეთ აზია", "სამხრეთ-აღმოსავლეთ აზია", "სამხრეთ ევროპა", "ავსტრალაზია", "მელანეზია", "მიკრონეზიის რეგიონი", "პოლინეზია", "აზია", "ცენტრალური აზია", "დასავლეთ აზია", "ევროპა", "აღმოსავლეთ ევროპა", "ჩრდილოეთ ევროპა", "დასავლეთ ევროპა", "სუბსაჰარული აფრიკა", "ლათინური ამერიკა", "ამაღლების კუნძული", "ანდორა", "არაბთა გაერთიანებული საამიროები", "ავღანეთი", "ანტიგუა და ბარბუდა", "ანგილია", "ალბანეთი", "სომხეთი", "ანგოლა", "ანტარქტიკა", "არგენტინა", "ამერიკის სამოა", "ავსტრია", "ავსტრალია", "არუბა", "ალანდის კუნძულები", "აზერბაიჯანი", "ბოსნია და ჰერცეგოვინა", "ბარბადოსი", "ბანგლადეში", "ბელგია", "ბურკინა-ფასო", "ბულგარეთი", "ბაჰრეინი", "ბურუნდი", "ბენინი", "სენ-ბართელმი", "ბერმუდა", "ბრუნეი", "ბოლივია", "კარიბის ნიდერლანდები", "ბრაზილია", "ბაჰამის კუნძულები", "ბუტანი", "ბუვე", "ბოტსვანა", "ბელარუსი", "ბელიზი", "კანადა", "ქოქოსის (კილინგის) კუნძულები", "კონგო - კინშასა", "ცენტრალური აფრიკის რესპუბლიკა", "კონგო - ბრაზავილი", "შვეიცარია", "კოტ-დივუარი", "კუკის კუნძულები", "ჩილე", "კამერუნი", "ჩინეთი", "კოლუმბია", "კლიპერტონის კუნძული", "სარკი", "კოსტა-რიკა", "კუბა", "კაბო-ვერდე", "კიურასაო", "შობის კუნძული", "კვიპროსი", "ჩეხეთი", "გერმანია", "დიეგო-გარსია", "ჯიბუტი", "დანია", "დომინიკა", "დომინიკელთა რესპუბლიკა", "ალჟირი", "სეუტა და მელილა", "ეკვადორი", "ესტონეთი", "ეგვიპტე", "დასავლეთ საჰარა", "ერიტრეა", "ესპანეთი", "ეთიოპია", "ევროკავშირი", "ევროზონა", "ფინეთი", "ფიჯი", "ფოლკლენდის კუნძულები", "მიკრონეზია", "ფარერის კუნძულები", "საფრანგეთი", "გაბონი", "გაერთიანებული სამეფო", "გრენადა", "საქართველო", "საფრანგეთის გვიანა", "გერნსი", "განა", "გიბრალტარი", "გრენლანდია", "გამბია", "გვინეა", "გვადელუპა", "ეკვატორული გვინეა", "საბერძნეთი", "სამხრეთ ჯორჯია და სამხრეთ სენდვიჩის კუნძულები", "გვატემალა", "გუამი", "გვინეა-ბისაუ", "გაიანა", "ჰონკონგის სპეციალური ადმინისტრაციული რეგიონი, ჩინეთი", "ჰერდი და მაკდონალდის კუნძულები", "ჰონდურასი", "ხორვატია", "ჰაიტი", "უნგრეთი", "კანარის კუნძულები", "ინდონეზია", "ირლანდია", "ისრაელი", "მენის კუნძული", "ინდოეთი", "ბრიტანეთის ტერიტორია ინდოეთის ოკეანეში", "ერაყი", "ირანი", "ისლანდია", "იტალია", "ჯერსი", "იამაიკა", "იორდანია", "იაპონია", "კენია", "ყირგიზეთი", "კამბოჯა", "კირიბატი", "კომორის კუნძულები", "სენტ-კიტსი და ნევისი", "ჩრდილოეთ კორეა", "სამხრეთ კორეა", "ქუვეითი", "კაიმანის კუნძულები", "ყაზახეთი", "ლაოსი", "ლიბანი", "სენტ-ლუსია", "ლიხტენშტაინი", "შრი-ლანკა", "ლიბერია", "ლესოთო", "ლიეტუვა", "ლუქსემბურგი", "ლატვია", "ლიბია", "მაროკო", "მონაკო", "მოლდოვა", "მონტენეგრო", "სენ-მარტენი", "მადაგასკარი", "მარშალის კუნძულები", "ჩრდილოეთ მაკედონია", "მალი", "მიანმარი (ბირმა)", "მონღოლეთი", "მაკაოს სპეციალური ადმინისტრაციული რეგიონი, ჩინეთი", "ჩრდილოეთ მარიანას კუნძულები", "მარტინიკა", "მავრიტანია", "მონსერატი", "მალტა", "მავრიკი", "მალდივები", "მალავი", "მექსიკა", "მალაიზია", "მოზამბიკი", "ნამიბია", "ახალი კალედონია", "ნიგერი", "ნორფოლკის კუნძული", "ნიგერია", "ნიკარაგუა", "ნიდერლანდები", "ნორვეგია", "ნეპალი", "ნაურუ", "ნიუე", "ახალი ზელანდია", "ომანი", "პანამა", "პერუ", "საფრანგეთის პოლინეზია", "პაპუა-ახალი გვინეა", "ფილიპინები", "პაკისტანი", "პოლონეთი", "სენ-პიერი და მიკელონი", "პიტკერნის კუნძულები", "პუერტო-რიკო", "პალესტინის ტერიტორიები", "პორტუგალია", "პალაუ", "პარაგვაი", "კატარი", "შორეული ოკეანეთი", "რეუნიონი", "რუმინეთი", "სერბეთი", "რუსეთი", "რუანდა", "საუდის არაბეთი", "სოლომონის კუნძულები", "სეიშელის კუნძულები", "სუდანი", "შვედეთი", "სინგაპური", "წმინდა ელენეს კუნძული", "სლოვენია", "შპიცბერგენი და იან-მაიენი", "სლოვაკეთი", "სიერა-ლეონე", "სან-მარინო", "სენეგალი", "სომალი", "სურინამი", "სამხრეთ სუდანი", "სან-ტომე და პრინსიპი", "სალვადორი", "სინტ-მარტენი", "სირია", "სვაზილენდი", "ტრისტან-და-კუნია", "თერქს-ქაიქოსის კუნძულები", "ჩადი", "ფრანგული სამხრეთის ტერიტორიები", "ტოგო", "ტაილანდი", "ტაჯიკეთი", "ტოკელაუ", "ტიმორ-ლესტე", "თურქმენეთი", "ტუნისი", "ტონგა", "თურქეთი", "ტრინიდადი და ტობაგო", "ტუვალუ", "ტაივანი", "ტანზანია", "უკრაინა", "უგანდა", "აშშ-ის შორეული კუნძულები", "გაერო", "ამერიკის შეერთებული შტატები", "ურუგვაი", "უზბეკეთი", "ქალაქი ვატიკანი", "სენტ-ვინსენტი და გრენადინები", "ვენესუელა", "ბრიტანეთის ვირჯინის კუნძულები", "აშშ-ის ვირჯინის კუნძულები", "ვიეტნამი", "ვანუატუ", "უოლისი და ფუტუნა", "სამოა", "ფსევდო-აქცენტები", "ფსევდო-ორმიმართულებიანი", "კოსოვო", "იემენი", "მაიოტა", "სამხრეთ აფრიკის რესპუბლიკა", "ზამბია", "ზიმბაბვე"];
+
+// de
+static R32: [&str; 294] = ["Welt", "Afrika", "Nordamerika", "Südamerika", "Ozeanien", "Westafrika", "Mittelamerika", "Ostafrika", "Nordafrika", "Zentralafrika", "Südliches Afrika", "Amerika", "Nördliches Amerika", "Karibik", "Ostasien", "Südasien", "Südostasien", "Südeuropa", "Australasien", "Melanesien", "Mikronesisches Inselgebiet", "Polynesien", "Asien", "Zentralasien", "Westasien", "Europa", "Osteuropa", "Nordeuropa", "Westeuropa", "Subsahara-Afrika", "Lateinamerika", "Ascension", "Andorra", "Vereinigte Arabische Emirate", "Afghanistan", "Antigua und Barbuda", "Anguilla", "Albanien", "Armenien", "Angola", "Antarktis", "Argentinien", "Amerikanisch-Samoa", "Österreich", "Australien", "Aruba", "Ålandinseln", "Aserbaidschan", "Bosnien und Herzegowina", "Barbados", "Bangladesch", "Belgien", "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "St. Barthélemy", "Bermuda", "Brunei Darussalam", "Bolivien", "Karibische Niederlande", "Brasilien", "Bahamas", "Bhutan", "Bouvetinsel", "Botsuana", "Belarus", "Belize", "Kanada", "Kokosinseln", "Kongo-Kinshasa", "Zentralafrikanische Republik", "Kongo-Brazzaville", "Schweiz", "Côte d’Ivoire", "Cookinseln", "Chile", "Kamerun", "China", "Kolumbien", "Clipperton-Insel", "Sark", "Costa Rica", "Kuba", "Cabo Verde", "Curaçao", "Weihnachtsinsel", "Zypern", "Tschechien", "Deutschland", "Diego Garcia", "Dschibuti", "Dänemark", "Dominica", "Dominikanische Republik", "Algerien", "Ceuta und Melilla", "Ecuador", "Estland", "Ägypten", "Westsahara", "Eritrea", "Spanien", "Äthiopien", "Europäische Union", "Eurozone", "Finnland", "Fidschi", "Falklandinseln", "Mikronesien", "Färöer", "Frankreich", "Gabun", "Vereinigtes Königreich", "Grenada", "Georgien", "Französisch-Guayana", "Guernsey", "Ghana", "Gibraltar", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Äquatorialguinea", "Griechenland", "Südgeorgien und die Südlichen Sandwichinseln", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Sonderverwaltungsregion Hongkong", "Heard und McDonaldinseln", "Honduras", "Kroatien", "Haiti", "Ungarn", "Kanarische Inseln", "Indonesien", "Irland", "Israel", "Isle of Man", "Indien", "Britisches Territorium im Indischen Ozean", "Irak", "Iran", "Island", "Italien", "Jersey", "Jamaika", "Jordanien", "Japan", "Kenia", "Kirgisistan", "Kambodscha", "Kiribati", "Komoren", "St. Kitts und Nevis", "Nordkorea", "Südkorea", "Kuwait", "Kaimaninseln", "Kasachstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxemburg", "Lettland", "Libyen", "Marokko", "Monaco", "Republik Moldau", "Montenegro", "St. Martin", "Madagaskar", "Marshallinseln", "Nordmazedonien", "Mali", "Myanmar", "Mongolei", "Sonderverwaltungsregion Macau", "Nördliche Marianen", "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius", "Malediven", "Malawi", "Mexiko", "Malaysia", "Mosambik", "Namibia", "Neukaledonien", "Niger", "Norfolkinsel", "Nigeria", "Nicaragua", "Niederlande", "Norwegen", "Nepal", "Nauru", "Niue", "Neuseeland", "Oman", "Panama", "Peru", "Französisch-Polynesien", "Papua-Neuguinea", "Philippinen", "Pakistan", "Polen", "St. Pierre und Miquelon", "Pitcairninseln", "Puerto Rico", "Palästinensische Autonomiegebiete", "Portugal", "Palau", "Paraguay", "Katar", "Äußeres Ozeanien", "Réunion", "Rumänien", "Serbien", "Russland", "Ruanda", "Saudi-Arabien", "Salomonen", "Seychellen", "Sudan", "Schweden", "Singapur", "St. Helena", "Slowenien", "Spitzbergen und Jan Mayen", "Slowakei", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Südsudan", "São Tomé und Príncipe", "El Salvador", "Sint Maarten", "Syrien", "Eswatini", "Tristan da Cunha", "Turks- und Caicosinseln", "Tschad", "Französische Süd- und Antarktisgebiete", "Togo", "Thailand", "Tadschikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunesien", "Tonga", "Türkei", "Trinidad und Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukraine", "Uganda", "Amerikanische Überseeinseln", "Vereinte Nationen", "Vereinigte Staaten", "Uruguay", "Usbekistan", "Vatikanstadt", "St. Vincent und die Grenadinen", "Venezuela", "Britische Jungferninseln", "Amerikanische Jungferninseln", "Vietnam", "Vanuatu", "Wallis und Futuna", "Samoa", "Pseudo-Akzente", "Pseudo-Bidi", "Kosovo", "Jemen", "Mayotte", "Südafrika", "Sambia", "Simbabwe"];
+
+// el
+static R33: [&str; 294] = ["Κόσμος", "Αφρική", "Βόρεια Αμερική", "Νότια Αμερική", "Ωκεανία", "Δυτική Αφρική", "Κεντρική Αμερική", "Ανατολική Αφρική", "Βόρεια Αφρική", "Μέση Αφρική", "Νότιος Αφρική", "Αμερική", "Βόρειος Αμερική", "Καραϊβική", "Ανατολική Ασία", "Νότια Ασία", "Νοτιοανατολική Ασία", "Νότια Ευρώπη", "Αυστραλασία", "Μελανησία", "Περιοχή Μικρονησίας", "Πολυνησία", "Ασία", "Κεντρική Ασία", "Δυτική Ασία", "Ευρώπη", "Ανατολική Ευρώπη", "Βόρεια Ευρώπη", "Δυτική Ευρώπη", "Υποσαχάρια Αφρική", "Λατινική Αμερική", "Νήσος Ασενσιόν", "Ανδόρα", "Ηνωμένα Αραβικά Εμιράτα", "Αφγανιστάν", "Αντίγκουα και Μπαρμπούντα", "Ανγκουίλα", "Αλβανία", "Αρμενία", "Αγκόλα", "Ανταρκτική", "Αργεντινή", "Αμερικανική Σαμόα", "Αυστρία", "Αυστραλία", "Αρούμπα", "Νήσοι Όλαντ", "Αζερμπαϊτζάν", "Βοσνία - Ερζεγοβίνη", "Μπαρμπέιντος", "Μπανγκλαντές", "Βέλγιο", "Μπουρκίνα Φάσο", "Βουλγαρία", "Μπαχρέιν", "Μπουρούντι", "Μπενίν", "Άγιος Βαρθολομαίος", "Βερμούδες", "Μπρουνέι", "Βολιβία", "Ολλανδία Καραϊβικής", "Βραζιλία", "Μπαχάμες", "Μπουτάν", "Νήσος Μπουβέ", "Μποτσουάνα", "Λευκορωσία", "Μπελίζ", "Καναδάς", "Νήσοι Κόκος (Κίλινγκ)", "Κονγκό - Κινσάσα", "Κεντροαφρικανική Δημοκρατία", "Κονγκό - Μπραζαβίλ", "Ελβετία", "Ακτή Ελεφαντοστού", "Νήσοι Κουκ", "Χιλή", "Καμερούν", "Κίνα", "Κολομβία", "Νήσος Κλίπερτον", "Σαρκ", "Κόστα Ρίκα", "Κούβα", "Πράσινο Ακρωτήριο", "Κουρασάο", "Νήσος των Χριστουγέννων", "Κύπρος", "Τσεχία", "Γερμανία", "Ντιέγκο Γκαρσία", "Τζιμπουτί", "Δανία", "Ντομίνικα", "Δομινικανή Δημοκρατία", "Αλγερία", "Θέουτα και Μελίγια", "Ισημερινός", "Εσθονία", "Αίγυπτος", "Δυτική Σαχάρα", "Ερυθραία", "Ισπανία", "Αιθιοπία", "Ευρωπαϊκή Ένωση", "Ευρωζώνη", "Φινλανδία", "Φίτζι", "Νήσοι Φόκλαντ", "Μικρονησία", "Νήσοι Φερόες", "Γαλλία", "Γκαμπόν", "Ηνωμένο Βασίλειο", "Γρενάδα", "Γεωργία", "Γαλλική Γουιάνα", "Γκέρνζι", "Γκάνα", "Γιβραλτάρ", "Γροιλανδία", "Γκάμπια", "Γουινέα", "Γουαδελούπη", "Ισημερινή Γουινέα", "Ελλάδα", "Νήσοι Νότια Γεωργία και Νότιες Σάντουιτς", "Γουατεμάλα", "Γκουάμ", "Γουινέα Μπισάου", "Γουιάνα", "Χονγκ Κονγκ ΕΔΠ Κίνας", "Νήσοι Χερντ και Μακντόναλντ", "Ονδούρα", "Κροατία", "Αϊτή", "Ουγγαρία", "Κανάριοι Νήσοι", "Ινδονησία", "Ιρλανδία", "Ισραήλ", "Νήσος του Μαν", "Ινδία", "Βρετανικά Εδάφη Ινδικού Ωκεανού", "Ιράκ", "Ιράν", "Ισλανδία", "Ιταλία", "Τζέρζι", "Τζαμάικα", "Ιορδανία", "Ιαπωνία", "Κένυα", "Κιργιστάν", "Καμπότζη", "Κιριμπάτι", "Κομόρες", "Σεν Κιτς και Νέβις", "Βόρεια Κορέα", "Νότια Κορέα", "Κουβέιτ", "Νήσοι Κέιμαν", "Καζακστάν", "Λάος", "Λίβανος", "Αγία Λουκία", "Λιχτενστάιν", "Σρι Λάνκα", "Λιβερία", "Λεσότο", "Λιθουανία", "Λουξεμβούργο", "Λετονία", "Λιβύη", "Μαρόκο", "Μονακό", "Μολδαβία", "Μαυροβούνιο", "Άγιος Μαρτίνος (Γαλλικό τμήμα)", "Μαδαγασκάρη", "Νήσοι Μάρσαλ", "Βόρεια Μακεδονία", "Μάλι", "Μιανμάρ (Βιρμανία)", "Μογγολία", "Μακάο ΕΔΠ Κίνας", "Νήσοι Βόρειες Μαριάνες", "Μαρτινίκα", "Μαυριτανία", "Μονσεράτ", "Μάλτα", "Μαυρίκιος", "Μαλδίβες", "Μαλάουι", "Μεξικό", "Μαλαισία", "Μοζαμβίκη", "Ναμίμπια", "Νέα Καληδονία", "Νίγηρας", "Νήσος Νόρφολκ", "Νιγηρία", "Νικαράγουα", "Κάτω Χώρες", "Νορβηγία", "Νεπάλ", "Ναουρού", "Νιούε", "Νέα Ζηλανδία", "Ομάν", "Παναμάς", "Περού", "Γαλλική Πολυνησία", "Παπούα Νέα Γουινέα", "Φιλιππίνες", "Πακιστάν", "Πολωνία", "Σεν Πιερ και Μικελόν", "Νήσοι Πίτκερν", "Πουέρτο Ρίκο", "Παλαιστινιακά Εδάφη", "Πορτογαλία", "Παλάου", "Παραγουάη", "Κατάρ", "Περιφερειακή Ωκεανία", "Ρεϊνιόν", "Ρουμανία", "Σερβία", "Ρωσία", "Ρουάντα", "Σαουδική Αραβία", "Νήσοι Σολομώντος", "Σεϋχέλλες", "Σουδάν", "Σουηδία", "Σιγκαπούρη", "Αγία Ελένη", "Σλοβενία", "Σβάλμπαρντ και Γιαν Μαγιέν", "Σλοβακία", "Σιέρα Λεόνε", "Άγιος Μαρίνος", "Σενεγάλη", "Σομαλία", "Σουρινάμ", "Νότιο Σουδάν", "Σάο Τομέ και Πρίνσιπε", "Ελ Σαλβαδόρ", "Άγιος Μαρτίνος (Ολλανδικό τμήμα)", "Συρία", "Εσουατίνι", "Τριστάν ντα Κούνια", "Νήσοι Τερκς και Κάικος", "Τσαντ", "Γαλλικά Νότια Εδάφη", "Τόγκο", "Ταϊλάνδη", "Τατζικιστάν", "Τοκελάου", "Τιμόρ-Λέστε", "Τουρκμενιστάν", "Τυνησία", "Τόνγκα", "Τουρκία", "Τρινιντάντ και Τομπάγκο", "Τουβαλού", "Ταϊβάν", "Τανζανία", "Ουκρανία", "Ουγκάντα", "Απομακρυσμένες Νησίδες ΗΠΑ", "Ηνωμένα Έθνη", "Ηνωμένες Πολιτείες", "Ουρουγουάη", "Ουζμπεκιστάν", "Βατικανό", "Άγιος Βικέντιος και Γρεναδίνες", "Βενεζουέλα", "Βρετανικές Παρθένες Νήσοι", "Αμερικανικές Παρθένες Νήσοι", "Βιετνάμ", "Βανουάτου", "Γουάλις και Φουτούνα", "Σαμόα", "Ψευδο-προφορές", "Ψευδο-αμφικατευθυντικό", "Κοσσυφοπέδιο", "Υεμένη", "Μαγιότ", "Νότια Αφρική", "Ζάμπια", "Ζιμπάμπουε"];
+
+// gu
+static R34: [&str; 294] = ["વિશ્વ", "આફ્રિકા", "ઉત્તર અમેરિકા", "દક્ષિણ અમેરિકા", "ઓશનિયા", "પશ્ચિમી આફ્રિકા", "મધ્ય અમેરિકા", "પૂર્વીય આફ્રિકા", "ઉત્તરી આફ્રિકા", "મધ્ય આફ્રિકા", "સધર્ન આફ્રિકા", "અમેરિકા", "ઉત્તરી અમેરિકા", "કેરિબિયન", "પૂર્વીય એશિયા", "દક્ષિણ એશિયા", "દક્ષિણપૂર્વ એશિયા", "દક્ષિણ યુરોપ", "ઓસ્ટ્રેલેશિયા", "મેલાનેશિયા", "માઈક્રોનેશિયન ક્ષેત્ર", "પોલિનેશિયા", "એશિયા", "મધ્ય એશિયા", "પશ્ચિમી એશિયા", "યુરોપ", "પૂર્વીય યુરોપ", "ઉત્તરીય યુરોપ", "પશ્ચિમી યુરોપ", "સબ-સહારન આફ્રિકા", "લેટિન અમેરિકા", "એસેન્શન આઇલેન્ડ", "એન્ડોરા", "યુનાઇટેડ આરબ અમીરાત", "અફઘાનિસ્તાન", "ઍન્ટિગુઆ અને બર્મુડા", "ઍંગ્વિલા", "અલ્બેનિયા", "આર્મેનિયા", "અંગોલા", "એન્ટાર્કટિકા", "આર્જેન્ટીના", "અમેરિકન સમોઆ", "ઑસ્ટ્રિયા", "ઑસ્ટ્રેલિયા", "અરુબા", "ઑલૅન્ડ આઇલૅન્ડ્સ", "અઝરબૈજાન", "બોસ્નિયા અને હર્ઝેગોવિના", "બારબાડોસ", "બાંગ્લાદેશ", "બેલ્જીયમ", "બુર્કિના ફાસો", "બલ્ગેરિયા", "બેહરીન", "બુરુંડી", "બેનિન", "સેંટ બાર્થેલેમી", "બર્મુડા", "બ્રુનેઇ", "બોલિવિયા", "કેરેબિયન નેધરલેન્ડ્ઝ", "બ્રાઝિલ", "બહામાસ", "ભૂટાન", "બૌવેત આઇલૅન્ડ", "બોત્સ્વાના", "બેલારુસ", "બેલીઝ", "કેનેડા", "કોકોઝ (કીલીંગ) આઇલૅન્ડ્સ", "કોંગો - કિંશાસા", "સેન્ટ્રલ આફ્રિકન રિપબ્લિક", "કોંગો - બ્રાઝાવિલે", "સ્વિટ્ઝર્લૅન્ડ", "કોટ ડીઆઇવરી", "કુક આઇલેન્ડ્સ", "ચિલી", "કૅમરૂન", "ચીન", "કોલંબિયા", "ક્લિપરટન આઇલેન્ડ", "સાર્ક", "કોસ્ટા રિકા", "ક્યુબા", "કૅપ વર્ડે", "ક્યુરાસાઓ", "ક્રિસમસ આઇલૅન્ડ", "સાયપ્રસ", "ચેકીયા", "જર્મની", "ડિએગો ગારસિઆ", "જીબૌટી", "ડેનમાર્ક", "ડોમિનિકા", "ડોમિનિકન રિપબ્લિક", "અલ્જીરિયા", "સ્યુટા અને મેલિલા", "એક્વાડોર", "એસ્ટોનિયા", "ઇજિપ્ત", "પશ્ચિમી સહારા", "એરિટ્રિયા", "સ્પેન", "ઇથિઓપિયા", "યુરોપિયન સંઘ", "યુરોઝોન", "ફિનલેન્ડ", "ફીજી", "ફૉકલૅન્ડ આઇલૅન્ડ્સ", "માઇક્રોનેશિયા", "ફેરો આઇલૅન્ડ્સ", "ફ્રાંસ", "ગેબન", "યુનાઇટેડ કિંગડમ", "ગ્રેનેડા", "જ્યોર્જિયા", "ફ્રેંચ ગયાના", "ગ્વેર્નસે", "ઘાના", "જીબ્રાલ્ટર", "ગ્રીનલેન્ડ", "ગેમ્બિયા", "ગિની", "ગ્વાડેલોપ", "ઇક્વેટોરિયલ ગિની", "ગ્રીસ", "દક્ષિણ જ્યોર્જિયા અને દક્ષિણ સેન્ડવિચ આઇલેન્ડ્સ", "ગ્વાટેમાલા", "ગ્વામ", "ગિની-બિસાઉ", "ગયાના", "હોંગકોંગ SAR ચીન", "હર્ડ અને મેકડોનાલ્ડ આઇલૅન્ડ્સ", "હોન્ડુરસ", "ક્રોએશિયા", "હૈતિ", "હંગેરી", "કૅનેરી આઇલેન્ડ્સ", "ઇન્ડોનેશિયા", "આયરલૅન્ડ", "ઇઝરાઇલ", "આઇલ ઑફ મેન", "ભારત", "બ્રિટિશ ઇન્ડિયન ઓશન ટેરિટરી", "ઇરાક", "ઈરાન", "આઇસલૅન્ડ", "ઇટાલી", "જર્સી", "જમૈકા", "જોર્ડન", "જાપાન", "કેન્યા", "કિર્ગિઝ્સ્તાન", "કંબોડિયા", "કિરિબાટી", "કોમોરસ", "સેંટ કિટ્સ અને નેવિસ", "ઉત્તર કોરિયા", "દક્ષિણ કોરિયા", "કુવૈત", "કેમેન આઇલેન્ડ્સ", "કઝાકિસ્તાન", "લાઓસ", "લેબનોન", "સેંટ લુસિયા", "લૈચટેંસ્ટેઇન", "શ્રીલંકા", "લાઇબેરિયા", "લેસોથો", "લિથુઆનિયા", "લક્ઝમબર્ગ", "લાત્વિયા", "લિબિયા", "મોરોક્કો", "મોનાકો", "મોલડોવા", "મૉન્ટેનેગ્રો", "સેંટ માર્ટિન", "મેડાગાસ્કર", "માર્શલ આઇલેન્ડ્સ", "ઉત્તર મેસેડોનિયા", "માલી", "મ્યાંમાર (બર્મા)", "મંગોલિયા", "મકાઉ SAR ચીન", "ઉત્તરી મારિયાના આઇલેન્ડ્સ", "માર્ટીનીક", "મૌરિટાનિયા", "મોંટસેરાત", "માલ્ટા", "મોરિશિયસ", "માલદિવ્સ", "માલાવી", "મેક્સિકો", "મલેશિયા", "મોઝામ્બિક", "નામિબિયા", "ન્યુ સેલેડોનિયા", "નાઇજર", "નોરફોક આઇલેન્ડ્સ", "નાઇજેરિયા", "નિકારાગુઆ", "નેધરલેન્ડ્સ", "નૉર્વે", "નેપાળ", "નૌરુ", "નીયુ", "ન્યુઝીલૅન્ડ", "ઓમાન", "પનામા", "પેરુ", "ફ્રેંચ પોલિનેશિયા", "પાપુઆ ન્યૂ ગિની", "ફિલિપિન્સ", "પાકિસ્તાન", "પોલેંડ", "સેંટ પીએરી અને મિક્યુલોન", "પીટકૈર્ન આઇલેન્ડ્સ", "પ્યુઅર્ટો રિકો", "પેલેસ્ટિનિયન ટેરિટરી", "પોર્ટુગલ", "પલાઉ", "પેરાગ્વે", "કતાર", "આઉટલાઈન્ગ ઓશનિયા", "રીયુનિયન", "રોમાનિયા", "સર્બિયા", "રશિયા", "રવાંડા", "સાઉદી અરેબિયા", "સોલોમન આઇલેન્ડ્સ", "સેશેલ્સ", "સુદાન", "સ્વીડન", "સિંગાપુર", "સેંટ હેલેના", "સ્લોવેનિયા", "સ્વાલબર્ડ અને જેન મેયન", "સ્લોવેકિયા", "સીએરા લેઓન", "સૅન મેરિનો", "સેનેગલ", "સોમાલિયા", "સુરીનામ", "દક્ષિણ સુદાન", "સાઓ ટૉમ અને પ્રિંસિપે", "એલ સેલ્વાડોર", "સિંટ માર્ટેન", "સીરિયા", "એસ્વાટીની", "ત્રિસ્તાન દા કુન્હા", "તુર્ક્સ અને કેકોઝ આઇલેન્ડ્સ", "ચાડ", "ફ્રેંચ સધર્ન ટેરિટરીઝ", "ટોગો", "થાઇલેંડ", "તાજીકિસ્તાન", "ટોકેલાઉ", "તિમોર-લેસ્તે", "તુર્કમેનિસ્તાન", "ટ્યુનિશિયા", "ટોંગા", "તુર્કિયે", "ટ્રિનીદાદ અને ટોબેગો", "તુવાલુ", "તાઇવાન", "તાંઝાનિયા", "યુક્રેન", "યુગાંડા", "યુ.એસ. આઉટલાઇનિંગ આઇલેન્ડ્સ", "સંયુક્ત રાષ્ટ્ર", "યુનાઇટેડ સ્ટેટ્સ", "ઉરુગ્વે", "ઉઝ્બેકિસ્તાન", "વેટિકન સિટી", "સેંટ વિન્સેંટ અને ગ્રેનેડાઇંસ", "વેનેઝુએલા", "બ્રિટિશ વર્જિન આઇલેન્ડ્સ", "યુએસ વર્જિન આઇલેન્ડ્સ", "વિયેતનામ", "વાનુઆતુ", "વૉલિસ અને ફ્યુચુના", "સમોઆ", "સ્યુડો-ઍક્સન્ટ", "સ્યુડો-દ્વિદિશ", "કોસોવો", "યમન", "મેયોટ", "દક્ષિણ આફ્રિકા", "ઝામ્બિયા", "ઝિમ્બાબ્વે"];
+
+// ha
+static R35: [&str; 294] = ["Duniya", "Afirka", "Amurka ta Arewa", "Amurka ta Kudu", "Osheniya", "Afirka ta Yamma", "Amurka ta Tsakiya", "Afirka ta Gabas", "Arewacin Afirka", "Afirka ta Tsakiya", "Kudancin Afirka", "Nahiyoyin Amurka", "Arewacin Amurka", "Karebiyan", "Gabashin Asiya", "Kudancin Asiya", "Kudu Maso Gabashin Asiya", "Kudancin Turai", "Asturelasiya", "Melanesia", "Yankin Micronesiya", "Kasar Polynesia", "Asiya", "Asiya ta Tsakiya", "Yammacin Asiya", "Turai", "Gabashin Turai", "Arewacin Turai", "Yammacin Turai", "Afirka ta Kudancin Sahara", "Latin Amurka", "Tsibirin Ascension", "Andora", "Haɗaɗɗiyar Daular Larabawa", "Afaganistan", "Antigua da Barbuda", "Angila", "Albaniya", "Armeniya", "Angola", "Antatika", "Ajentina", "Samowa Ta Amurka", "Ostiriya", "Ostareliya", "Aruba", "Tsibirai na Åland", "Azarbaijan", "Bosniya da Harzagobina", "Barbadas", "Bangladesh", "Belgiyom", "Burkina Faso", "Bulgariya", "Baharen", "Burundi", "Binin", "San Barthélemy", "Barmuda", "Burune", "Bolibiya", "Caribbean Netherlands", "Birazil", "Bahamas", "Butan", "Tsibirin Bouvet", "Baswana", "Belarus", "Beliz", "Kanada", "Tsibirai Cocos (Keeling)", "Jamhuriyar Dimokuraɗiyyar Kongo", "Jamhuriyar Afirka Ta Tsakiya", "Kongo", "Suwizalan", "Aibari Kwas", "Tsibiran Cook", "Chile", "Kamaru", "Sin", "Kolambiya", "Tsibirin Clipperton", "Sark", "Kwasta Rika", "Kyuba", "Tsibiran Cape Verde", "Ƙasar Curaçao", "Tsibirin Kirsmati", "Saifurus", "Czechia", "Jamus", "Tsibirn Diego Garcia", "Jibuti", "Danmark", "Dominika", "Jamhuriyar Dominika", "Aljeriya", "Ceuta da Melilla", "Ekwador", "Estoniya", "Misira", "Yammacin Sahara", "Eritireya", "Sipen", "Habasha", "Tarayyar Turai", "Sashin Turai", "Finlan", "Fiji", "Tsibiran Falkilan", "Mikuronesiya", "Tsibirai na Faroe", "Faransa", "Gabon", "Biritaniya", "Girnada", "Jojiya", "Gini Ta Faransa", "Yankin Guernsey", "Gana", "Jibaraltar", "Grinlan", "Gambiya", "Gini", "Gwadaluf", "Ikwatoriyal Gini", "Girka", "Kudancin Geogia da Kudancin Tsibirin Sandiwic", "Gwatamala", "Guam", "Gini Bisau", "Guyana", "Babban Yankin Mulkin Hong Kong na Ƙasar Sin", "Tsibirin Heard da McDonald", "Yankin Honduras", "Kurowaishiya", "Haiti", "Hungari", "Tsibiran Canary", "Indunusiya", "Ayalan", "Israʼila", "Isle of Man", "Indiya", "Yankin Birtaniya Na Tekun Indiya", "Iraƙi", "Iran", "Aisalan", "Italiya", "Kasar Jersey", "Jamaika", "Jordan", "Japan", "Kenya", "Kirgizistan", "Kambodiya", "Kiribati", "Kwamoras", "San Kiti Da Nebis", "Koriya Ta Arewa", "Koriya Ta Kudu", "Kuwet", "Tsibiran Kaiman", "Kazakistan", "Lawos", "Labanan", "San Lusiya", "Licansitan", "Siri Lanka", "Laberiya", "Lesoto", "Lituweniya", "Lukusambur", "Litibiya", "Libiya", "Maroko", "Monako", "Maldoba", "Manteneguro", "San Martin", "Madagaskar", "Tsibiran Marshal", "Macedonia ta Arewa", "Mali", "Burma, Miyamar", "Mangoliya", "Babban Yankin Mulkin Macao na Ƙasar Sin", "Tsibiran Mariyana Na Arewa", "Martinik", "Moritaniya", "Manserati", "Malta", "Moritus", "Maldibi", "Malawi", "Mesiko", "Malesiya", "Mozambik", "Namibiya", "Kaledoniya Sabuwa", "Nijar", "Tsibirin Narfalk", "Nijeriya", "Nikaraguwa", "Holan", "Norwe", "Nefal", "Nauru", "Niue", "Nuzilan", "Oman", "Panama", "Feru", "Folinesiya Ta Faransa", "Papuwa Nugini", "Filipin", "Pakistan", "Polan", "San Piyar da Mikelan", "Tsibiran Pitcairn", "Porto Riko", "Yankunan Palasɗinu", "Portugal", "Palau", "Faragwai", "Katar", "Bakin Teku", "Rawuniyan", "Romaniya", "Sabiya", "Rasha", "Ruwanda", "Saudiyya", "Tsibiran Salaman", "Seychelles", "Sudan", "Suwedan", "Singapur", "San Helena", "Sulobeniya", "Svalbard da Jan Mayen", "Sulobakiya", "Salewo", "San Marino", "Sanigal", "Somaliya", "Suriname", "Sudan ta Kudu", "Sawo Tome Da Paransip", "El Salbador", "San Maarten", "Sham, Siriya", "Eswatini", "Tritan da Kunha", "Turkis Da Tsibiran Kaikwas", "Cadi", "Yankin Faransi ta Kudu", "Togo", "Tailan", "Tajikistan", "Takelau", "Timor-Leste", "Turkumenistan", "Tunisiya", "Tonga", "Turkiyya", "Tirinidad Da Tobago", "Tubalu", "Taiwan", "Tanzaniya", "Yukaran", "Yuganda", "Rukunin Tsibirin U.S", "Majalisar Ɗinkin Duniya", "Amurka", "Yurigwai", "Uzubekistan", "Birnin Batikan", "San Binsan Da Girnadin", "Benezuwela", "Tsibirin Birjin Na Birtaniya", "Tsibiran Birjin Ta Amurka", "Biyetinam", "Banuwatu", "Walis Da Futuna", "Samoa", "Gurɓataccen Karin harshe", "Gurɓataccen Bidi", "Kasar Kosovo", "Yamen", "Mayoti", "Afirka Ta Kudu", "Zambiya", "Zimbabuwe"];
+
+// he
+static R36: [&str; 294] = ["העולם", "אפריקה", "צפון אמריקה", "דרום אמריקה", "אוקיאניה", "מערב אפריקה", "מרכז אמריקה", "מזרח אפריקה", "צפון אפריקה", "מרכז אפריקה", "דרום יבשת אפריקה", "אמריקה", "אמריקה הצפונית", "האיים הקריביים", "מזרח אסיה", "דרום אסיה", "דרום־מזרח אסיה", "דרום אירופה", "אוסטרלאסיה", "מלנזיה", "אזור מיקרונזיה", "פולינזיה", "אסיה", "מרכז אסיה", "מערב אסיה", "אירופה", "מזרח אירופה", "צפון אירופה", "מערב אירופה", "אפריקה שמדרום לסהרה", "אמריקה הלטינית", "האי אסנשן", "אנדורה", "איחוד האמירויות הערביות", "אפגניסטן", "אנטיגואה וברבודה", "אנגווילה", "אלבניה", "ארמניה", "אנגולה", "אנטארקטיקה", "ארגנטינה", "סמואה האמריקנית", "אוסטריה", "אוסטרליה", "ארובה", "איי אולנד", "אזרבייג׳ן", "בוסניה והרצגובינה", "ברבדוס", "בנגלדש", "בלגיה", "בורקינה פאסו", "בולגריה", "בחריין", "בורונדי", "בנין", "סנט ברתולומיאו", "ברמודה", "ברוניי", "בוליביה", "האיים הקריביים ההולנדיים", "ברזיל", "איי בהאמה", "בהוטן", "האי בובה", "בוטסואנה", "בלארוס", "בליז", "קנדה", "איי קוקוס (קילינג)", "קונגו - קינשאסה", "הרפובליקה המרכז-אפריקאית", "קונגו - ברזאויל", "שווייץ", "חוף השנהב", "איי קוק", "צ׳ילה", "קמרון", "סין", "קולומביה", "האי קליפרטון", "סרק", "קוסטה ריקה", "קובה", "כף ורדה", "קוראסאו", "אי חג המולד", "קפריסין", "צ׳כיה", "גרמניה", "דייגו גרסיה", "ג׳יבוטי", "דנמרק", "דומיניקה", "הרפובליקה הדומיניקנית", "אלג׳יריה", "סאוטה ומלייה", "אקוודור", "אסטוניה", "מצרים", "סהרה המערבית", "אריתריאה", "ספרד", "אתיופיה", "האיחוד האירופי", "גוש האירו", "פינלנד", "פיג׳י", "איי פוקלנד", "מיקרונזיה", "איי פארו", "צרפת", "גבון", "בריטניה", "גרנדה", "גאורגיה", "גיאנה הצרפתית", "גרנזי", "גאנה", "גיברלטר", "גרינלנד", "גמביה", "גינאה", "גוואדלופ", "גינאה המשוונית", "יוון", "ג׳ורג׳יה הדרומית ואיי סנדוויץ׳ הדרומיים", "גואטמלה", "גואם", "גינאה-ביסאו", "גיאנה", "הונג קונג (אזור מנהלי מיוחד של סין)", "איי הרד ומקדונלד", "הונדורס", "קרואטיה", "האיטי", "הונגריה", "האיים הקנריים", "אינדונזיה", "אירלנד", "ישראל", "האי מאן", "הודו", "הטריטוריה הבריטית באוקיינוס ההודי", "עיראק", "איראן", "איסלנד", "איטליה", "ג׳רזי", "ג׳מייקה", "ירדן", "יפן", "קניה", "קירגיזסטן", "קמבודיה", "קיריבאטי", "קומורו", "סנט קיטס ונוויס", "קוריאה הצפונית", "קוריאה הדרומית", "כווית", "איי קיימן", "קזחסטן", "לאוס", "לבנון", "סנט לוסיה", "ליכטנשטיין", "סרי לנקה", "ליבריה", "לסוטו", "ליטא", "לוקסמבורג", "לטביה", "לוב", "מרוקו", "מונקו", "מולדובה", "מונטנגרו", "סן מרטן", "מדגסקר", "איי מרשל", "מקדוניה הצפונית", "מאלי", "מיאנמר (בורמה)", "מונגוליה", "מקאו (אזור מנהלי מיוחד של סין)", "איי מריאנה הצפוניים", "מרטיניק", "מאוריטניה", "מונסראט", "מלטה", "מאוריציוס", "האיים המלדיביים", "מלאווי", "מקסיקו", "מלזיה", "מוזמביק", "נמיביה", "קלדוניה החדשה", "ניז׳ר", "האי נורפוק", "ניגריה", "ניקרגואה", "הולנד", "נורווגיה", "נפאל", "נאורו", "ניווה", "ניו זילנד", "עומאן", "פנמה", "פרו", "פולינזיה הצרפתית", "פפואה גינאה החדשה", "הפיליפינים", "פקיסטן", "פולין", "סנט פייר ומיקלון", "איי פיטקרן", "פוארטו ריקו", "השטחים הפלסטיניים", "פורטוגל", "פלאו", "פרגוואי", "קטאר", "טריטוריות באוקיאניה", "ראוניון", "רומניה", "סרביה", "רוסיה", "רואנדה", "ערב הסעודית", "איי שלמה", "איי סיישל", "סודן", "שוודיה", "סינגפור", "סנט הלנה", "סלובניה", "סבאלברד ויאן מאיין", "סלובקיה", "סיירה לאון", "סן מרינו", "סנגל", "סומליה", "סורינאם", "דרום סודן", "סאו טומה ופרינסיפה", "אל סלבדור", "סנט מארטן", "סוריה", "אסוואטיני", "טריסטן דה קונה", "איי טרקס וקייקוס", "צ׳אד", "הטריטוריות הדרומיות של צרפת", "טוגו", "תאילנד", "טג׳יקיסטן", "טוקלאו", "טימור-לסטה", "טורקמניסטן", "תוניסיה", "טונגה", "טורקיה", "טרינידד וטובגו", "טובאלו", "טייוואן", "טנזניה", "אוקראינה", "אוגנדה", "האיים המרוחקים הקטנים של ארה״ב", "האומות המאוחדות", "ארצות הברית", "אורוגוואי", "אוזבקיסטן", "הוותיקן", "סנט וינסנט והגרנדינים", "ונצואלה", "איי הבתולה הבריטיים", "איי הבתולה של ארצות הברית", "וייטנאם", "ונואטו", "איי ווליס ופוטונה", "סמואה", "מבטאים חלקיים", "דו-כיווני חלקי", "קוסובו", "תימן", "מאיוט", "דרום אפריקה", "זמביה", "זימבבואה"];
+
+// hi
+static R37: [&str; 294] = ["विश्व", "अफ़्रीका", "उत्तर अमेरिका", "दक्षिण अमेरिका", "ओशिआनिया", "पश्चिमी अफ़्रीका", "मध्य अमेरिका", "पूर्वी अफ़्रीका", "उत्तरी अफ़्रीका", "मध्य अफ़्रीका", "दक्षिणी अफ़्रीका", "अमेरिकाज़", "उत्तरी अमेरिका", "कैरिबियन", "पूर्वी एशिया", "दक्षिणी एशिया", "दक्षिण-पूर्व एशिया", "दक्षिणी यूरोप", "ऑस्ट्रेलेशिया", "मेलानेशिया", "माइक्रोनेशियाई क्षेत्र", "पोलिनेशिया", "एशिया", "मध्य एशिया", "पश्चिमी एशिया", "यूरोप", "पूर्वी यूरोप", "उत्तरी यूरोप", "पश्चिमी यूरोप", "उप-सहारा अफ़्रीका", "लैटिन अमेरिका", "असेंशन द्वीप", "एंडोरा", "संयुक्त अरब अमीरात", "अफ़गानिस्तान", "एंटिगुआ और बरबुडा", "एंग्विला", "अल्बानिया", "आर्मेनिया", "अंगोला", "अंटार्कटिका", "अर्जेंटीना", "अमेरिकी समोआ", "ऑस्ट्रिया", "ऑस्ट्रेलिया", "अरूबा", "एलैंड द्वीपसमूह", "अज़रबैजान", "बोस्निया और हर्ज़ेगोविना", "बारबाडोस", "बांग्लादेश", "बेल्जियम", "बुर्किना फ़ासो", "बुल्गारिया", "बहरीन", "बुरुंडी", "बेनिन", "सेंट बार्थेलेमी", "बरमूडा", "ब्रूनेई", "बोलीविया", "कैरिबियन नीदरलैंड", "ब्राज़ील", "बहामास", "भूटान", "बोवेत द्वीप", "बोत्स्वाना", "बेलारूस", "बेलीज़", "कनाडा", "कोकोस (कीलिंग) द्वीपसमूह", "कांगो - किंशासा", "मध्य अफ़्रीकी गणराज्य", "कांगो – ब्राज़ाविल", "स्विट्ज़रलैंड", "कोत दिवुआर", "कुक द्वीपसमूह", "चिली", "कैमरून", "चीन", "कोलंबिया", "क्लिपर्टन द्वीप", "सार्क", "कोस्टारिका", "क्यूबा", "केप वर्ड", "कुरासाओ", "क्रिसमस द्वीप", "साइप्रस", "चेकिया", "जर्मनी", "डिएगो गार्सिया", "जिबूती", "डेनमार्क", "डोमिनिका", "डोमिनिकन गणराज्य", "अल्जीरिया", "सेउटा और मेलिला", "इक्वाडोर", "एस्टोनिया", "मिस्र", "पश्चिमी सहारा", "इरिट्रिया", "स्पेन", "इथियोपिया", "यूरोपीय संघ", "यूरोज़ोन", "फ़िनलैंड", "फ़िजी", "फ़ॉकलैंड द्वीपसमूह", "माइक्रोनेशिया", "फ़ेरो द्वीपसमूह", "फ़्रांस", "गैबॉन", "यूनाइटेड किंगडम", "ग्रेनाडा", "जॉर्जिया", "फ़्रेंच गुयाना", "गर्नसी", "घाना", "जिब्राल्टर", "ग्रीनलैंड", "गाम्बिया", "गिनी", "ग्वाडेलूप", "इक्वेटोरियल गिनी", "यूनान", "दक्षिण जॉर्जिया और दक्षिण सैंडविच द्वीपसमूह", "ग्वाटेमाला", "गुआम", "गिनी-बिसाउ", "गुयाना", "हाँग काँग (चीन विशेष प्रशासनिक क्षेत्र)", "हर्ड द्वीप और मैकडोनॉल्ड द्वीपसमूह", "होंडूरास", "क्रोएशिया", "हैती", "हंगरी", "कैनेरी द्वीपसमूह", "इंडोनेशिया", "आयरलैंड", "इज़राइल", "आइल ऑफ़ मैन", "भारत", "ब्रिटिश हिंद महासागरीय क्षेत्र", "इराक", "ईरान", "आइसलैंड", "इटली", "जर्सी", "जमैका", "जॉर्डन", "जापान", "केन्या", "किर्गिज़स्तान", "कंबोडिया", "किरिबाती", "कोमोरोस", "सेंट किट्स और नेविस", "उत्तर कोरिया", "दक्षिण कोरिया", "कुवैत", "कैमेन द्वीपसमूह", "कज़ाखस्तान", "लाओस", "लेबनान", "सेंट लूसिया", "लिचेंस्टीन", "श्रीलंका", "लाइबेरिया", "लेसोथो", "लिथुआनिया", "लग्ज़मबर्ग", "लातविया", "लीबिया", "मोरक्को", "मोनाको", "मॉल्डोवा", "मोंटेनेग्रो", "सेंट मार्टिन", "मेडागास्कर", "मार्शल द्वीपसमूह", "उत्तरी मकदूनिया", "माली", "म्यांमार (बर्मा)", "मंगोलिया", "मकाऊ (विशेष प्रशासनिक क्षेत्र चीन)", "उत्तरी मारियाना द्वीपसमूह", "मार्टीनिक", "मॉरिटानिया", "मोंटसेरात", "माल्टा", "मॉरीशस", "मालदीव", "मलावी", "मैक्सिको", "मलेशिया", "मोज़ांबिक", "नामीबिया", "न्यू कैलेडोनिया", "नाइजर", "नॉरफ़ॉक द्वीप", "नाइजीरिया", "निकारागुआ", "नीदरलैंड", "नॉर्वे", "नेपाल", "नाउरु", "नीयू", "न्यूज़ीलैंड", "ओमान", "पनामा", "पेरू", "फ़्रेंच पोलिनेशिया", "पापुआ न्यू गिनी", "फ़िलिपींस", "पाकिस्तान", "पोलैंड", "सेंट पिएरे और मिक्वेलान", "पिटकैर्न द्वीपसमूह", "पोर्टो रिको", "फ़िलिस्तीनी क्षेत्र", "पुर्तगाल", "पलाऊ", "पराग्वे", "क़तर", "आउटलाइंग ओशिनिया", "रियूनियन", "रोमानिया", "सर्बिया", "रूस", "रवांडा", "सऊदी अरब", "सोलोमन द्वीपसमूह", "सेशेल्स", "सूडान", "स्वीडन", "सिंगापुर", "सेंट हेलेना", "स्लोवेनिया", "स्वालबार्ड और जान मायेन", "स्लोवाकिया", "सिएरा लियोन", "सैन मेरीनो", "सेनेगल", "सोमालिया", "सूरीनाम", "दक्षिण सूडान", "साओ टोम और प्रिंसिपे", "अल सल्वाडोर", "सिंट मार्टिन", "सीरिया", "एस्वाटिनी", "ट्रिस्टन दा कुना", "तुर्क और कैकोज़ द्वीपसमूह", "चाड", "फ़्रांसीसी दक्षिणी क्षेत्र", "टोगो", "थाईलैंड", "ताजिकिस्तान", "तोकेलाउ", "तिमोर-लेस्त", "तुर्कमेनिस्तान", "ट्यूनीशिया", "टोंगा", "तुर्किये", "त्रिनिदाद और टोबैगो", "तुवालू", "ताइवान", "तंज़ानिया", "यूक्रेन", "युगांडा", "यू॰एस॰ आउटलाइंग द्वीपसमूह", "संयुक्त राष्ट्र", "संयुक्त राज्य", "उरूग्वे", "उज़्बेकिस्तान", "वेटिकन सिटी", "सेंट विंसेंट और ग्रेनाडाइंस", "वेनेज़ुएला", "ब्रिटिश वर्जिन द्वीपसमूह", "यू॰एस॰ वर्जिन द्वीपसमूह", "वियतनाम", "वनुआतू", "वालिस और फ़्यूचूना", "समोआ", "सूडो-एक्सेंट", "सूडो-बायडायरेक्शनल", "कोसोवो", "यमन", "मायोते", "दक्षिण अफ़्रीका", "ज़ाम्बिया", "ज़िम्बाब्वे"];
+
+// hu
+static R38: [&str; 294] = ["Világ", "Afrika", "Észak-Amerika", "Dél-Amerika", "Óceánia", "Nyugat-Afrika", "Közép-Amerika", "Kelet-Afrika", "Észak-Afrika", "Közép-Afrika", "Afrika déli része", "Amerika", "Amerika északi része", "Karib-térség", "Kelet-Ázsia", "Dél-Ázsia", "Délkelet-Ázsia", "Dél-Európa", "Ausztrálázsia", "Melanézia", "Mikronéziai régió", "Polinézia", "Ázsia", "Közép-Ázsia", "Nyugat-Ázsia", "Európa", "Kelet-Európa", "Észak-Európa", "Nyugat-Európa", "Szubszaharai Afrika", "Latin-Amerika", "Ascension-sziget", "Andorra", "Egyesült Arab Emírségek", "Afganisztán", "Antigua és Barbuda", "Anguilla", "Albánia", "Örményország", "Angola", "Antarktisz", "Argentína", "Amerikai Szamoa", "Ausztria", "Ausztrália", "Aruba", "Åland-szigetek", "Azerbajdzsán", "Bosznia-Hercegovina", "Barbados", "Banglades", "Belgium", "Burkina Faso", "Bulgária", "Bahrein", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolívia", "Holland Karib-térség", "Brazília", "Bahama-szigetek", "Bhután", "Bouvet-sziget", "Botswana", "Belarusz", "Belize", "Kanada", "Kókusz (Keeling)-szigetek", "Kongó – Kinshasa", "Közép-afrikai Köztársaság", "Kongó – Brazzaville", "Svájc", "Elefántcsontpart", "Cook-szigetek", "Chile", "Kamerun", "Kína", "Kolumbia", "Clipperton-sziget", "Sark", "Costa Rica", "Kuba", "Zöld-foki Köztársaság", "Curaçao", "Karácsony-sziget", "Ciprus", "Csehország", "Németország", "Diego Garcia", "Dzsibuti", "Dánia", "Dominika", "Dominikai Köztársaság", "Algéria", "Ceuta és Melilla", "Ecuador", "Észtország", "Egyiptom", "Nyugat-Szahara", "Eritrea", "Spanyolország", "Etiópia", "Európai Unió", "Eurózóna", "Finnország", "Fidzsi", "Falkland-szigetek", "Mikronézia", "Feröer szigetek", "Franciaország", "Gabon", "Egyesült Királyság", "Grenada", "Grúzia", "Francia Guyana", "Guernsey", "Ghána", "Gibraltár", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Egyenlítői-Guinea", "Görögország", "Déli-Georgia és Déli-Sandwich-szigetek", "Guatemala", "Guam", "Bissau-Guinea", "Guyana", "Hongkong KKT", "Heard-sziget és McDonald-szigetek", "Honduras", "Horvátország", "Haiti", "Magyarország", "Kanári-szigetek", "Indonézia", "Írország", "Izrael", "Man-sziget", "India", "Brit Indiai-óceáni Terület", "Irak", "Irán", "Izland", "Olaszország", "Jersey", "Jamaica", "Jordánia", "Japán", "Kenya", "Kirgizisztán", "Kambodzsa", "Kiribati", "Comore-szigetek", "Saint Kitts és Nevis", "Észak-Korea", "Dél-Korea", "Kuvait", "Kajmán-szigetek", "Kazahsztán", "Laosz", "Libanon", "Saint Lucia", "Liechtenstein", "Srí Lanka", "Libéria", "Lesotho", "Litvánia", "Luxemburg", "Lettország", "Líbia", "Marokkó", "Monaco", "Moldova", "Montenegró", "Saint Martin", "Madagaszkár", "Marshall-szigetek", "Észak-Macedónia", "Mali", "Mianmar", "Mongólia", "Makaó KKT", "Északi Mariana-szigetek", "Martinique", "Mauritánia", "Montserrat", "Málta", "Mauritius", "Maldív-szigetek", "Malawi", "Mexikó", "Malajzia", "Mozambik", "Namíbia", "Új-Kaledónia", "Niger", "Norfolk-sziget", "Nigéria", "Nicaragua", "Hollandia", "Norvégia", "Nepál", "Nauru", "Niue", "Új-Zéland", "Omán", "Panama", "Peru", "Francia Polinézia", "Pápua Új-Guinea", "Fülöp-szigetek", "Pakisztán", "Lengyelország", "Saint-Pierre és Miquelon", "Pitcairn-szigetek", "Puerto Rico", "Palesztin Autonómia", "Portugália", "Palau", "Paraguay", "Katar", "Külső-Óceánia", "Réunion", "Románia", "Szerbia", "Oroszország", "Ruanda", "Szaúd-Arábia", "Salamon-szigetek", "Seychelle-szigetek", "Szudán", "Svédország", "Szingapúr", "Szent Ilona", "Szlovénia", "Svalbard és Jan Mayen", "Szlovákia", "Sierra Leone", "San Marino", "Szenegál", "Szomália", "Suriname", "Dél-Szudán", "São Tomé és Príncipe", "Salvador", "Sint Maarten", "Szíria", "Szváziföld", "Tristan da Cunha", "Turks- és Caicos-szigetek", "Csád", "Francia Déli Területek", "Togo", "Thaiföld", "Tádzsikisztán", "Tokelau", "Kelet-Timor", "Türkmenisztán", "Tunézia", "Tonga", "Törökország", "Trinidad és Tobago", "Tuvalu", "Tajvan", "Tanzánia", "Ukrajna", "Uganda", "Az USA lakatlan külbirtokai", "Egyesült Nemzetek Szervezete", "Egyesült Államok", "Uruguay", "Üzbegisztán", "Vatikán", "Saint Vincent és a Grenadine-szigetek", "Venezuela", "Brit Virgin-szigetek", "Amerikai Virgin-szigetek", "Vietnám", "Vanuatu", "Wallis és Futuna", "Szamoa", "Pszeudo-nyelvjárások", "Pszeudo-kétirányú", "Koszovó", "Jemen", "Mayotte", "Dél-afrikai Köztársaság", "Zambia", "Zimbabwe"];
+
+// ia
+static R39: [&str; 294] = ["mundo", "Africa", "America del Nord", "America del Sud", "Oceania", "Africa occidental", "America central", "Africa oriental", "Africa septentrional", "Africa central", "Africa meridional", "Americas", "America septentrional", "Caribes", "Asia oriental", "Asia meridional", "Asia del sud-est", "Europa meridional", "Australasia", "Melanesia", "Region micronesian", "Polynesia", "Asia", "Asia central", "Asia occidental", "Europa", "Europa oriental", "Europa septentrional", "Europa occidental", "Africa subsaharian", "America latin", "Insula del Ascension", "Andorra", "Emiratos Arabe Unite", "Afghanistan", "Antigua e Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "Samoa american", "Austria", "Australia", "Aruba", "Insulas Åland", "Azerbaidzhan", "Bosnia e Herzegovina", "Barbados", "Bangladesh", "Belgica", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Sancte Bartholomeo", "Bermuda", "Brunei", "Bolivia", "Paises Basse caribe", "Brasil", "Bahamas", "Bhutan", "Insula de Bouvet", "Botswana", "Bielorussia", "Belize", "Canada", "Insulas Cocos (Keeling)", "Congo - Kinshasa", "Republica African Central", "Congo - Brazzaville", "Suissa", "Costa de Ebore", "Insulas Cook", "Chile", "Camerun", "China", "Colombia", "Insula Clipperton", "Sark", "Costa Rica", "Cuba", "Capo Verde", "Curaçao", "Insula de Natal", "Cypro", "Chechia", "Germania", "Diego Garcia", "Djibuti", "Danmark", "Dominica", "Republica Dominican", "Algeria", "Ceuta e Melilla", "Ecuador", "Estonia", "Egypto", "Sahara occidental", "Eritrea", "Espania", "Ethiopia", "Union Europee", "Zona euro", "Finlandia", "Fiji", "Insulas Falkland", "Micronesia", "Insulas Feroe", "Francia", "Gabon", "Regno Unite", "Grenada", "Georgia", "Guyana francese", "Guernsey", "Ghana", "Gibraltar", "Groenlandia", "Gambia", "Guinea", "Guadeloupe", "Guinea equatorial", "Grecia", "Georgia del Sud e Insulas Sandwich Austral", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong, R.A.S. de China", "Insulas Heard e McDonald", "Honduras", "Croatia", "Haiti", "Hungaria", "Insulas Canari", "Indonesia", "Irlanda", "Israel", "Insula de Man", "India", "Territorio oceanic britanno-indian", "Irak", "Iran", "Islanda", "Italia", "Jersey", "Jamaica", "Jordania", "Japon", "Kenya", "Kirghizistan", "Cambodgia", "Kiribati", "Comoros", "Sancte Christophoro e Nevis", "Corea del Nord", "Corea del Sud", "Kuwait", "Insulas de Caiman", "Kazakhstan", "Laos", "Libano", "Sancte Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxemburg", "Lettonia", "Libya", "Marocco", "Monaco", "Moldavia", "Montenegro", "Sancte Martino francese", "Madagascar", "Insulas Marshall", "Macedonia del Nord", "Mali", "Myanmar (Birmania)", "Mongolia", "Macao, R.A.S. de China", "Insulas Marianna del Nord", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauritio", "Maldivas", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "Nove Caledonia", "Niger", "Insula Norfolk", "Nigeria", "Nicaragua", "Nederlandia", "Norvegia", "Nepal", "Nauru", "Niue", "Nove Zelanda", "Oman", "Panama", "Peru", "Polynesia francese", "Papua Nove Guinea", "Philippinas", "Pakistan", "Polonia", "St. Pierre e Miquelon", "Insulas Pitcairn", "Porto Rico", "Territorios palestin", "Portugal", "Palau", "Paraguay", "Qatar", "Oceania remote", "Reunion", "Romania", "Serbia", "Russia", "Ruanda", "Arabia Saudita", "Insulas Solomon", "Seychelles", "Sudan", "Svedia", "Singapur", "Sancte Helena", "Slovenia", "Svalbard e Jan Mayen", "Slovachia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Sudan del Sud", "São Tomé e Príncipe", "El Salvador", "Sancte Martino nederlandese", "Syria", "Eswatini", "Tristan da Cunha", "Insulas Turcos e Caicos", "Tchad", "Territorios meridional francese", "Togo", "Thailandia", "Tadzhikistan", "Tokelau", "Timor del Est", "Turkmenistan", "Tunisia", "Tonga", "Turchia", "Trinidad e Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "Insulas peripheric del SUA", "Nationes Unite", "Statos Unite", "Uruguay", "Uzbekistan", "Citate del Vaticano", "Sancte Vincente e le Grenadinas", "Venezuela", "Insulas Virgine britannic", "Insulas Virgine statounitese", "Vietnam", "Vanuatu", "Wallis e Futuna", "Samoa", "Pseudo-accentos", "Pseudo-bidi", "Kosovo", "Yemen", "Mayotte", "Africa del Sud", "Zambia", "Zimbabwe"];
+
+// id
+static R40: [&str; 294] = ["Dunia", "Afrika", "Amerika Utara", "Amerika Selatan", "Oseania", "Afrika Bagian Barat", "Amerika Tengah", "Afrika Bagian Timur", "Afrika Bagian Utara", "Afrika Bagian Tengah", "Afrika Bagian Selatan", "Amerika", "Amerika Bagian Utara", "Kepulauan Karibia", "Asia Bagian Timur", "Asia Bagian Selatan", "Asia Tenggara", "Eropa Bagian Selatan", "Australasia", "Melanesia", "Wilayah Mikronesia", "Polinesia", "Asia", "Asia Tengah", "Asia Bagian Barat", "Eropa", "Eropa Bagian Timur", "Eropa Bagian Utara", "Eropa Bagian Barat", "Afrika Sub-Sahara", "Amerika Latin", "Pulau Ascension", "Andorra", "Uni Emirat Arab", "Afganistan", "Antigua dan Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktika", "Argentina", "Samoa Amerika", "Austria", "Australia", "Aruba", "Kepulauan Aland", "Azerbaijan", "Bosnia dan Herzegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint Barthélemy", "Bermuda", "Brunei", "Bolivia", "Belanda Karibia", "Brasil", "Bahama", "Bhutan", "Pulau Bouvet", "Botswana", "Belarus", "Belize", "Kanada", "Kepulauan Cocos (Keeling)", "Kongo - Kinshasa", "Republik Afrika Tengah", "Kongo - Brazzaville", "Swiss", "Côte d’Ivoire", "Kepulauan Cook", "Cile", "Kamerun", "Tiongkok", "Kolombia", "Pulau Clipperton", "Sark", "Kosta Rika", "Kuba", "Tanjung Verde", "Curaçao", "Pulau Natal", "Siprus", "Ceko", "Jerman", "Diego Garcia", "Jibuti", "Denmark", "Dominika", "Republik Dominika", "Aljazair", "Ceuta dan Melilla", "Ekuador", "Estonia", "Mesir", "Sahara Barat", "Eritrea", "Spanyol", "Etiopia", "Uni Eropa", "Zona Euro", "Finlandia", "Fiji", "Kepulauan Falkland", "Mikronesia", "Kepulauan Faroe", "Prancis", "Gabon", "Inggris Raya", "Grenada", "Georgia", "Guyana Prancis", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Guinea Ekuatorial", "Yunani", "Georgia Selatan & Kep. Sandwich Selatan", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hong Kong DAK Tiongkok", "Pulau Heard dan Kepulauan McDonald", "Honduras", "Kroasia", "Haiti", "Hungaria", "Kepulauan Canaria", "Indonesia", "Irlandia", "Israel", "Pulau Man", "India", "Wilayah Inggris di Samudra Hindia", "Irak", "Iran", "Islandia", "Italia", "Jersey", "Jamaika", "Yordania", "Jepang", "Kenya", "Kirgizstan", "Kamboja", "Kiribati", "Komoro", "Saint Kitts dan Nevis", "Korea Utara", "Korea Selatan", "Kuwait", "Kepulauan Cayman", "Kazakhstan", "Laos", "Lebanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luksemburg", "Latvia", "Libya", "Maroko", "Monako", "Moldova", "Montenegro", "Saint Martin", "Madagaskar", "Kepulauan Marshall", "Makedonia Utara", "Mali", "Myanmar (Burma)", "Mongolia", "Makau DAK Tiongkok", "Kepulauan Mariana Utara", "Martinik", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maladewa", "Malawi", "Meksiko", "Malaysia", "Mozambik", "Namibia", "Kaledonia Baru", "Niger", "Kepulauan Norfolk", "Nigeria", "Nikaragua", "Belanda", "Norwegia", "Nepal", "Nauru", "Niue", "Selandia Baru", "Oman", "Panama", "Peru", "Polinesia Prancis", "Papua Nugini", "Filipina", "Pakistan", "Polandia", "Saint Pierre dan Miquelon", "Kepulauan Pitcairn", "Puerto Riko", "Wilayah Palestina", "Portugal", "Palau", "Paraguay", "Qatar", "Oseania Luar", "Réunion", "Rumania", "Serbia", "Rusia", "Rwanda", "Arab Saudi", "Kepulauan Solomon", "Seychelles", "Sudan", "Swedia", "Singapura", "Saint Helena", "Slovenia", "Kepulauan Svalbard dan Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Sudan Selatan", "Sao Tome dan Principe", "El Salvador", "Sint Maarten", "Suriah", "eSwatini", "Tristan da Cunha", "Kepulauan Turks dan Caicos", "Chad", "Wilayah Selatan Prancis", "Togo", "Thailand", "Tajikistan", "Tokelau", "Timor Leste", "Turkmenistan", "Tunisia", "Tonga", "Turki", "Trinidad dan Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "Kepulauan Terluar AS", "Perserikatan Bangsa-Bangsa", "Amerika Serikat", "Uruguay", "Uzbekistan", "Vatikan", "Saint Vincent dan Grenadine", "Venezuela", "Kepulauan Virgin Britania Raya", "Kepulauan Virgin Amerika Serikat", "Vietnam", "Vanuatu", "Kepulauan Wallis dan Futuna", "Samoa", "Aksen Asing", "Pseudo-Bidi", "Kosovo", "Yaman", "Mayotte", "Afrika Selatan", "Zambia", "Zimbabwe"];
+
+// ie
+static R41: [&str; 294] = ["munde", "Africa", "septentrional America", "Sud-America", "Oceania", "", "central America", "", "", "", "", "Americas", "Nord-America", "Caribes", "Ost-Asia", "Sud-Asia", "Sudost-Asia", "Sud-Europa", "Australasia", "Melanesia", "region de Micronesia", "Polinesia", "Asia", "Central Asia", "West-Asia", "Europa", "Ost-Europa", "Nord-Europa", "West-Europa", "", "latin America", "", "Andorra", "", "", "", "", "Albania", "", "", "Antarctica", "", "", "Austria", "", "", "Insules Åland", "", "Bosnia e Herzegovina", "", "", "Belgia", "", "Bulgaria", "", "", "", "", "", "", "", "", "", "", "", "", "", "Bielorussia", "", "", "", "", "", "", "Svissia", "", "", "", "", "", "", "", "Sark", "", "", "", "", "", "", "Tchekia", "Germania", "", "", "Dania", "", "", "", "", "", "Estonia", "", "", "Eritrea", "Hispania", "Etiopia", "Union Europan", "Zone de euro", "Finland", "Fidji", "", "", "", "Francia", "", "Unit Reyia", "", "", "", "Guernsey", "", "Gibraltar", "", "", "", "", "", "Grecia", "", "", "", "", "Guyana", "", "", "", "Croatia", "", "Hungaria", "", "Indonesia", "Irland", "", "Insul de Man", "India", "", "", "Iran", "Island", "Italia", "Jersey", "", "", "", "", "", "Cambodja", "", "", "", "", "", "", "", "", "", "", "", "Liechtenstein", "Sri-Lanka", "", "", "Lituania", "Luxemburg", "Lettonia", "", "", "Mónaco", "Moldova", "Montenegro", "", "", "", "Nord-Macedonia", "", "", "", "", "", "Martinica", "", "", "Malta", "Mauricio", "Maldivas", "", "", "", "", "", "", "", "Insul Norfolk", "", "", "Nederland", "Norvegia", "", "Nauru", "", "Nov-Zeland", "", "", "Perú", "", "", "Filipines", "Pakistan", "Polonia", "", "", "Porto-Rico", "", "Portugal", "Palau", "", "", "periferic Oceania", "", "Rumania", "Serbia", "Russia", "", "", "", "", "", "Svedia", "", "", "Slovenia", "Svalbard e Jan Mayen", "Slovakia", "", "San-Marino", "", "", "", "", "", "", "Sint-Maarten", "", "", "", "Turks e Caicos", "Tchad", "", "", "", "", "Tokelau", "Ost-Timor", "", "", "", "", "Trinidad e Tobago", "Tuvalu", "", "", "Ukraina", "", "", "Unit Nationes", "", "", "", "Cité de Vatican", "", "", "", "", "", "Vanuatu", "", "Samoa", "pseudo-diacritica", "pseudo-bidi", "Kosovo", "", "", "", "", ""];
+
+// ga
+static R42: [&str; 294] = ["an Domhan", "an Afraic", "Meiriceá Thuaidh", "Meiriceá Theas", "an Aigéine", "Iarthar na hAfraice", "Meiriceá Láir", "Oirthear na hAfraice", "Tuaisceart na hAfraice", "an Afraic Láir", "Deisceart na hAfraice", "Críocha Mheiriceá", "Tuaisceart Mheiriceá", "an Mhuir Chairib", "Oirthear na hÁise", "Deisceart na hÁise", "an Áise Thoir Theas", "Deisceart na hEorpa", "an Astraláise", "an Mheilinéis", "an Réigiún Micrinéiseach", "an Pholainéis", "an Áise", "an Áise Láir", "Iarthar na hÁise", "an Eoraip", "Oirthear na hEorpa", "Tuaisceart na hEorpa", "Iarthar na hEorpa", "an Afraic fho-Shahárach", "Meiriceá Laidineach", "Oileán na Deascabhála", "Andóra", "Aontas na nÉimíríochtaí Arabacha", "an Afganastáin", "Antigua agus Barbúda", "Angaíle", "an Albáin", "an Airméin", "Angóla", "Antartaice", "an Airgintín", "Samó Mheiriceá", "an Ostair", "an Astráil", "Arúba", "Oileáin Åland", "an Asarbaiseáin", "an Bhoisnia agus an Heirseagaivéin", "Barbadós", "an Bhanglaidéis", "an Bheilg", "Buircíne Fasó", "an Bhulgáir", "Bairéin", "an Bhurúin", "Beinin", "Saint Barthélemy", "Beirmiúda", "Brúiné", "an Bholaiv", "an Ísiltír Chairibeach", "an Bhrasaíl", "na Bahámaí", "an Bhútáin", "Oileán Bouvet", "an Bhotsuáin", "an Bhealarúis", "an Bheilís", "Ceanada", "Oileáin Cocos (Keeling)", "Poblacht Dhaonlathach an Chongó", "Poblacht na hAfraice Láir", "Congó-Brazzaville", "an Eilvéis", "Côte d’Ivoire", "Oileáin Cook", "an tSile", "Camarún", "an tSín", "an Cholóim", "Oileán Clipperton", "an tSairc", "Cósta Ríce", "Cúba", "Poblacht Cabo Verde", "Cúrasó", "Oileán na Nollag", "an Chipir", "an tSeicia", "an Ghearmáin", "Diego Garcia", "Djibouti", "an Danmhairg", "Doiminice", "an Phoblacht Dhoiminiceach", "an Ailgéir", "Ceuta agus Melilla", "Eacuadór", "an Eastóin", "an Éigipt", "an Sahára Thiar", "an Eiritré", "an Spáinn", "an Aetóip", "an tAontas Eorpach", "Limistéar an euro", "an Fhionlainn", "Fidsí", "Oileáin Fháclainne", "an Mhicrinéis", "Oileáin Fharó", "an Fhrainc", "an Ghabúin", "an Ríocht Aontaithe", "Greanáda", "an tSeoirsia", "Guáin na Fraince", "Geansaí", "Gána", "Giobráltar", "an Ghraonlainn", "an Ghaimbia", "an Ghuine", "Guadalúip", "an Ghuine Mheánchiorclach", "an Ghréig", "An tSeoirsia Theas agus Oileáin Sandwich Theas", "Guatamala", "Guam", "Guine Bissau", "An Ghuáin", "Sainréigiún Riaracháin Hong Cong, Daonphoblacht na Síne", "Oileán Heard agus Oileáin McDonald", "Hondúras", "an Chróit", "Háítí", "an Ungáir", "na hOileáin Chanáracha", "an Indinéis", "Éire", "Iosrael", "Oileán Mhanann", "an India", "Críoch Aigéan Indiach na Breataine", "an Iaráic", "an Iaráin", "an Íoslainn", "an Iodáil", "Geirsí", "Iamáice", "an Iordáin", "an tSeapáin", "an Chéinia", "an Chirgeastáin", "an Chambóid", "Ciribeas", "Oileáin Chomóra", "San Críostóir-Nimheas", "an Chóiré Thuaidh", "an Chóiré Theas", "Cuáit", "Oileáin Cayman", "an Chasacstáin", "Laos", "an Liobáin", "Saint Lucia", "Lichtinstéin", "Srí Lanca", "an Libéir", "Leosóta", "an Liotuáin", "Lucsamburg", "an Laitvia", "an Libia", "Maracó", "Monacó", "an Mholdóiv", "Montainéagró", "Saint-Martin", "Madagascar", "Oileáin Marshall", "an Mhacadóin Thuaidh", "Mailí", "Maenmar (Burma)", "an Mhongóil", "Sainréigiún Riaracháin Macao, Daonphoblacht na Síne", "Na hOileáin Mháirianacha Thuaidh", "Martinique", "an Mháratáin", "Montsarat", "Málta", "Oileán Mhuirís", "Oileáin Mhaildíve", "an Mhaláiv", "Meicsiceo", "an Mhalaeisia", "Mósaimbíc", "an Namaib", "an Nua-Chaladóin", "an Nígir", "Oileán Norfolk", "an Nigéir", "Nicearagua", "an Ísiltír", "an Iorua", "Neipeal", "Nárú", "Niue", "an Nua-Shéalainn", "Óman", "Panama", "Peiriú", "Polainéis na Fraince", "Nua-Ghuine Phapua", "Na hOileáin Fhilipíneacha", "an Phacastáin", "an Pholainn", "San Pierre agus Miquelon", "Oileáin Pitcairn", "Pórtó Ríce", "na Críocha Palaistíneacha", "an Phortaingéil", "Oileáin Palau", "Paragua", "Catar", "an Aigéine Imeallach", "La Réunion", "an Rómáin", "an tSeirbia", "an Rúis", "Ruanda", "an Araib Shádach", "Oileáin Sholaimh", "na Séiséil", "an tSúdáin", "an tSualainn", "Singeapór", "San Héilin", "an tSlóivéin", "Svalbard agus Jan Mayen", "an tSlóvaic", "Siarra Leon", "San Mairíne", "an tSeineagáil", "an tSomáil", "Suranam", "an tSúdáin Theas", "São Tomé agus Príncipe", "An tSalvadóir", "Sint Maarten", "an tSiria", "Esuaitíní", "Tristan da Cunha", "Oileáin na dTurcach agus Caicos", "Sead", "Críocha Francacha Dheisceart an Domhain", "Tóga", "an Téalainn", "an Táidsíceastáin", "Tócalá", "Tíomór Thoir", "an Tuircméanastáin", "an Tuinéis", "Tonga", "an Tuirc", "Oileán na Tríonóide agus Tobága", "Túvalú", "an Téaváin", "an Tansáin", "an Úcráin", "Uganda", "Oileáin Imeallacha S.A.M.", "na Náisiúin Aontaithe", "Stáit Aontaithe Mheiriceá", "Uragua", "an Úisbéiceastáin", "Cathair na Vatacáine", "San Uinseann agus na Greanáidíní", "Veiniséala", "Oileáin Bhriotanacha na Maighdean", "Oileáin Mheiriceánacha na Maighdean", "Vítneam", "Vanuatú", "Vailís agus Futúna", "Samó", "Bréagaicinn", "Bréag-Bidi", "an Chosaiv", "Éimin", "Mayotte", "an Afraic Theas", "an tSaimbia", "an tSiombáib"];
+
+// ig
+static R43: [&str; 294] = ["Uwa", "Afrika", "Mpaghara Ugwu Amerịka", "Mpaghara Mgbada Ugwu America", "Oceania", "Mpaghara Ọdịda Anyanwụ Afrịka", "Etiti America", "Mpaghara Ọwụwa Anyanwụ Afrịka", "Mpaghara Ugwu Afrịka", "Etiti Afrịka", "Mpaghara Mgbada Ugwu Afrịka", "Amerịka", "Mpaghara Ugwu America", "Caribbean", "Mpaghara Ọwụwa Anyanwụ Asia", "Mpaghara Mgbada Ugwu Asia", "Mpaghara Mgbada Ugwu Asia dị na Ọwụwa Anyanwụ", "Mpaghara Mgbada Ugwu Europe", "Australasia", "Melanesia", "Mpaghara Micronesian", "Polynesia", "Asia", "Etiti Asia", "Mpaghara Ọdịda Anyanwụ Asia", "Europe", "Mpaghara Ọwụwa Anyanwụ Europe", "Mpaghara Ugwu Europe", "Mpaghara Ọdịda Anyanwụ Europe", "Sub-Saharan Afrịka", "Latin America", "Ascension Island", "Andorra", "United Arab Emirates", "Afghanistan", "Antigua na Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "American Samoa", "Austria", "Australia", "Aruba", "Åland Islands", "Azerbaijan", "Bosnia & Herzegovina", "Barbados", "Bangladesh", "Belgium", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "St. Barthélemy", "Bemuda", "Brunei", "Bolivia", "Caribbean Netherlands", "Brazil", "Bahamas", "Bhutan", "Bouvet Island", "Botswana", "Belarus", "Belize", "Canada", "Agwaetiti Cocos (Keeling)", "Congo - Kinshasa", "Central African Republik", "Congo", "Switzerland", "Côte d’Ivoire", "Agwaetiti Cook", "Chile", "Cameroon", "China", "Colombia", "Agwaetiti Clipperton", "Sark", "Kosta Rika", "Cuba", "Cape Verde", "Kurakao", "Agwaetiti Christmas", "Cyprus", "Czechia", "Germany", "Diego Garcia", "Djibouti", "Denmark", "Dominica", "Dominican Republik", "Algeria", "Ceuta & Melilla", "Ecuador", "Estonia", "Egypt", "Ọdịda Anyanwụ Sahara", "Eritrea", "Spain", "Ethiopia", "Otu nzukọ mba Europe", "Gburugburu Euro", "Finland", "Fiji", "Falkland Islands", "Micronesia", "Faroe Islands", "France", "Gabon", "United Kingdom", "Grenada", "Georgia", "French Guiana", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Equatorial Guinea", "Greece", "South Georgia & South Sandwich Islands", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hong Kong SAR China", "Agwaetiti Heard na Agwaetiti McDonald", "Honduras", "Croatia", "Haiti", "Hungary", "Canary Islands", "Indonesia", "Ireland", "Israel", "Isle of Man", "India", "British Indian Ocean Territory", "Iraq", "Iran", "Iceland", "Italy", "Jersey", "Jamaika", "Jordan", "Japan", "Kenya", "Kyrgyzstan", "Cambodia", "Kiribati", "Comoros", "St. Kitts & Nevis", "North Korea", "South Korea", "Kuwait", "Cayman Islands", "Kazakhstan", "Laos", "Lebanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lithuania", "Luxembourg", "Latvia", "Libia", "Morocco", "Monaco", "Moldova", "Montenegro", "St. Martin", "Madagascar", "Agwaetiti Marshall", "North Macedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macao SAR China", "Agwaetiti Northern Mariana", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldivesa", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger", "Agwaetiti Norfolk", "Naịjịrịa", "Nicaragua", "Netherlands", "Norway", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "French Polynesia", "Papua New Guinea", "Philippines", "Pakistan", "Poland", "St. Pierre & Miquelon", "Agwaetiti Pitcairn", "Puerto Rico", "Mpaghara ndị Palestine", "Portugal", "Palau", "Paraguay", "Qatar", "Outlying Oceania", "Réunion", "Romania", "Serbia", "Russia", "Rwanda", "Saudi Arabia", "Agwaetiti Solomon", "Seychelles", "Sudan", "Sweden", "Singapore", "St. Helena", "Slovenia", "Svalbard & Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "South Sudan", "São Tomé & Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Turks & Caicos Islands", "Chad", "Ụmụ ngalaba Frenchi Southern", "Togo", "Thailand", "Tajikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Türkiye", "Trinidad na Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "Obere Agwaetiti Dị Na Mpụga U.S", "Mba Ụwa Jikọrọ Ọnụ", "United States", "Uruguay", "Uzbekistan", "Vatican City", "St. Vincent & Grenadines", "Venezuela", "British Virgin Islands", "U.S. Virgin Islands", "Vietnam", "Vanuatu", "Wallis & Futuna", "Samoa", "Pseudo-Accents", "Pseudo-Bidi", "Kosovo", "Yemen", "Mayotte", "South Africa", "Zambia", "Zimbabwe"];
+
+// is
+static R44: [&str; 294] = ["Heimurinn", "Afríka", "Norður-Ameríka", "Suður-Ameríka", "Eyjaálfa", "Vestur-Afríka", "Mið-Ameríka", "Austur-Afríka", "Norður-Afríka", "Mið-Afríka", "Suðurhluti Afríku", "Ameríka", "Ameríka norðan Mexíkó", "Karíbahafið", "Austur-Asía", "Suður-Asía", "Suðaustur-Asía", "Suður-Evrópa", "Ástralasía", "Melanesía", "Míkrónesíusvæðið", "Pólýnesía", "Asía", "Mið-Asía", "Vestur-Asía", "Evrópa", "Austur-Evrópa", "Norður-Evrópa", "Vestur-Evrópa", "Afríka sunnan Sahara", "Rómanska Ameríka", "Ascension-eyja", "Andorra", "Sameinuðu arabísku furstadæmin", "Afganistan", "Antígva og Barbúda", "Angvilla", "Albanía", "Armenía", "Angóla", "Suðurskautslandið", "Argentína", "Bandaríska Samóa", "Austurríki", "Ástralía", "Arúba", "Álandseyjar", "Aserbaídsjan", "Bosnía og Hersegóvína", "Barbados", "Bangladess", "Belgía", "Búrkína Fasó", "Búlgaría", "Barein", "Búrúndí", "Benín", "Sankti Bartólómeusareyjar", "Bermúdaeyjar", "Brúnei", "Bólivía", "Karíbahafshluti Hollands", "Brasilía", "Bahamaeyjar", "Bútan", "Bouveteyja", "Botsvana", "Hvíta-Rússland", "Belís", "Kanada", "Kókoseyjar (Keeling)", "Kongó-Kinshasa", "Mið-Afríkulýðveldið", "Kongó-Brazzaville", "Sviss", "Fílabeinsströndin", "Cooks-eyjar", "Síle", "Kamerún", "Kína", "Kólumbía", "Clipperton-eyja", "Sark", "Kostaríka", "Kúba", "Grænhöfðaeyjar", "Curacao", "Jólaey", "Kýpur", "Tékkland", "Þýskaland", "Diego Garcia", "Djíbútí", "Danmörk", "Dóminíka", "Dóminíska lýðveldið", "Alsír", "Ceuta og Melilla", "Ekvador", "Eistland", "Egyptaland", "Vestur-Sahara", "Erítrea", "Spánn", "Eþíópía", "Evrópusambandið", "Evrusvæðið", "Finnland", "Fídjíeyjar", "Falklandseyjar", "Míkrónesía", "Færeyjar", "Frakkland", "Gabon", "Bretland", "Grenada", "Georgía", "Franska Gvæjana", "Guernsey", "Gana", "Gíbraltar", "Grænland", "Gambía", "Gínea", "Gvadelúpeyjar", "Miðbaugs-Gínea", "Grikkland", "Suður-Georgía og Suður-Sandvíkureyjar", "Gvatemala", "Gvam", "Gínea-Bissá", "Gvæjana", "sérstjórnarsvæðið Hong Kong", "Heard og McDonaldseyjar", "Hondúras", "Króatía", "Haítí", "Ungverjaland", "Kanaríeyjar", "Indónesía", "Írland", "Ísrael", "Mön", "Indland", "Bresku Indlandshafseyjar", "Írak", "Íran", "Ísland", "Ítalía", "Jersey", "Jamaíka", "Jórdanía", "Japan", "Kenía", "Kirgistan", "Kambódía", "Kíribatí", "Kómoreyjar", "Sankti Kitts og Nevis", "Norður-Kórea", "Suður-Kórea", "Kúveit", "Caymaneyjar", "Kasakstan", "Laos", "Líbanon", "Sankti Lúsía", "Liechtenstein", "Srí Lanka", "Líbería", "Lesótó", "Litháen", "Lúxemborg", "Lettland", "Líbía", "Marokkó", "Mónakó", "Moldóva", "Svartfjallaland", "Saint-Martin", "Madagaskar", "Marshalleyjar", "Norður-Makedónía", "Malí", "Mjanmar (Búrma)", "Mongólía", "sérstjórnarsvæðið Makaó", "Norður-Maríanaeyjar", "Martiník", "Máritanía", "Montserrat", "Malta", "Máritíus", "Maldíveyjar", "Malaví", "Mexíkó", "Malasía", "Mósambík", "Namibía", "Nýja-Kaledónía", "Níger", "Norfolkeyja", "Nígería", "Níkaragva", "Holland", "Noregur", "Nepal", "Nárú", "Niue", "Nýja-Sjáland", "Óman", "Panama", "Perú", "Franska Pólýnesía", "Papúa Nýja-Gínea", "Filippseyjar", "Pakistan", "Pólland", "Sankti Pierre og Miquelon", "Pitcairn-eyjar", "Púertó Ríkó", "Heimastjórnarsvæði Palestínumanna", "Portúgal", "Palá", "Paragvæ", "Katar", "Ytri Eyjaálfa", "Réunion", "Rúmenía", "Serbía", "Rússland", "Rúanda", "Sádi-Arabía", "Salómonseyjar", "Seychelles-eyjar", "Súdan", "Svíþjóð", "Singapúr", "Sankti Helena", "Slóvenía", "Svalbarði og Jan Mayen", "Slóvakía", "Síerra Leóne", "San Marínó", "Senegal", "Sómalía", "Súrínam", "Suður-Súdan", "Saó Tóme og Prinsípe", "El Salvador", "Sint Maarten", "Sýrland", "Esvatíní", "Tristan da Cunha", "Turks- og Caicoseyjar", "Tsjad", "Frönsku suðlægu landsvæðin", "Tógó", "Taíland", "Tadsíkistan", "Tókelá", "Tímor-Leste", "Túrkmenistan", "Túnis", "Tonga", "Tyrkland", "Trínidad og Tóbagó", "Túvalú", "Taívan", "Tansanía", "Úkraína", "Úganda", "Smáeyjar Bandaríkjanna", "Sameinuðu þjóðirnar", "Bandaríkin", "Úrúgvæ", "Úsbekistan", "Vatíkanið", "Sankti Vinsent og Grenadíneyjar", "Venesúela", "Bresku Jómfrúaeyjar", "Bandarísku Jómfrúaeyjar", "Víetnam", "Vanúatú", "Wallis- og Fútúnaeyjar", "Samóa", "gervihreimur", "gervistaður", "Kósóvó", "Jemen", "Mayotte", "Suður-Afríka", "Sambía", "Simbabve"];
+
+// it
+static R45: [&str; 294] = ["Mondo", "Africa", "Nord America", "America del Sud", "Oceania", "Africa occidentale", "America Centrale", "Africa orientale", "Nordafrica", "Africa centrale", "Africa del Sud", "Americhe", "America del Nord", "Caraibi", "Asia orientale", "Asia del Sud", "Sud-est asiatico", "Europa meridionale", "Australasia", "Melanesia", "Regione micronesiana", "Polinesia", "Asia", "Asia centrale", "Asia occidentale", "Europa", "Europa orientale", "Europa settentrionale", "Europa occidentale", "Africa subsahariana", "America Latina", "Isola Ascensione", "Andorra", "Emirati Arabi Uniti", "Afghanistan", "Antigua e Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antartide", "Argentina", "Samoa Americane", "Austria", "Australia", "Aruba", "Isole Åland", "Azerbaigian", "Bosnia ed Erzegovina", "Barbados", "Bangladesh", "Belgio", "Burkina Faso", "Bulgaria", "Bahrein", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Caraibi Olandesi", "Brasile", "Bahamas", "Bhutan", "Isola Bouvet", "Botswana", "Bielorussia", "Belize", "Canada", "Isole Cocos (Keeling)", "Congo - Kinshasa", "Repubblica Centrafricana", "Congo-Brazzaville", "Svizzera", "Costa d’Avorio", "Isole Cook", "Cile", "Camerun", "Cina", "Colombia", "Isola di Clipperton", "Sark", "Costa Rica", "Cuba", "Capo Verde", "Curaçao", "Isola Christmas", "Cipro", "Cechia", "Germania", "Diego Garcia", "Gibuti", "Danimarca", "Dominica", "Repubblica Dominicana", "Algeria", "Ceuta e Melilla", "Ecuador", "Estonia", "Egitto", "Sahara Occidentale", "Eritrea", "Spagna", "Etiopia", "Unione europea", "Eurozona", "Finlandia", "Figi", "Isole Falkland", "Micronesia", "Isole Fær Øer", "Francia", "Gabon", "Regno Unito", "Grenada", "Georgia", "Guyana Francese", "Guernsey", "Ghana", "Gibilterra", "Groenlandia", "Gambia", "Guinea", "Guadalupa", "Guinea Equatoriale", "Grecia", "Georgia del Sud e Sandwich Australi", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "RAS di Hong Kong", "Isole Heard e McDonald", "Honduras", "Croazia", "Haiti", "Ungheria", "Isole Canarie", "Indonesia", "Irlanda", "Israele", "Isola di Man", "India", "Territorio Britannico dell’Oceano Indiano", "Iraq", "Iran", "Islanda", "Italia", "Jersey", "Giamaica", "Giordania", "Giappone", "Kenya", "Kirghizistan", "Cambogia", "Kiribati", "Comore", "Saint Kitts e Nevis", "Corea del Nord", "Corea del Sud", "Kuwait", "Isole Cayman", "Kazakistan", "Laos", "Libano", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Lussemburgo", "Lettonia", "Libia", "Marocco", "Monaco", "Moldavia", "Montenegro", "Saint Martin", "Madagascar", "Isole Marshall", "Macedonia del Nord", "Mali", "Myanmar (Birmania)", "Mongolia", "RAS di Macao", "Isole Marianne Settentrionali", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldive", "Malawi", "Messico", "Malaysia", "Mozambico", "Namibia", "Nuova Caledonia", "Niger", "Isola Norfolk", "Nigeria", "Nicaragua", "Paesi Bassi", "Norvegia", "Nepal", "Nauru", "Niue", "Nuova Zelanda", "Oman", "Panama", "Perù", "Polinesia Francese", "Papua Nuova Guinea", "Filippine", "Pakistan", "Polonia", "Saint-Pierre e Miquelon", "Isole Pitcairn", "Portorico", "Territori Palestinesi", "Portogallo", "Palau", "Paraguay", "Qatar", "Oceania lontana", "Riunione", "Romania", "Serbia", "Russia", "Ruanda", "Arabia Saudita", "Isole Salomone", "Seychelles", "Sudan", "Svezia", "Singapore", "Sant’Elena", "Slovenia", "Svalbard e Jan Mayen", "Slovacchia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Sud Sudan", "São Tomé e Príncipe", "El Salvador", "Sint Maarten", "Siria", "Eswatini", "Tristan da Cunha", "Isole Turks e Caicos", "Ciad", "Terre Australi Francesi", "Togo", "Thailandia", "Tagikistan", "Tokelau", "Timor Est", "Turkmenistan", "Tunisia", "Tonga", "Turchia", "Trinidad e Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ucraina", "Uganda", "Isole Minori Esterne degli Stati Uniti", "Nazioni Unite", "Stati Uniti", "Uruguay", "Uzbekistan", "Città del Vaticano", "Saint Vincent e Grenadine", "Venezuela", "Isole Vergini Britanniche", "Isole Vergini Americane", "Vietnam", "Vanuatu", "Wallis e Futuna", "Samoa", "Pseudo-accenti", "Pseudo-bidi", "Kosovo", "Yemen", "Mayotte", "Sudafrica", "Zambia", "Zimbabwe"];
+
+// ja
+static R46: [&str; 294] = ["世界", "アフリカ", "北アメリカ大陸", "南アメリカ", "オセアニア", "西アフリカ", "中央アメリカ", "東アフリカ", "北アフリカ", "中部アフリカ", "南部アフリカ", "アメリカ大陸", "北アメリカ", "カリブ", "東アジア", "南アジア", "東南アジア", "南ヨーロッパ", "オーストララシア", "メラネシア", "ミクロネシア", "ポリネシア", "アジア", "中央アジア", "西アジア", "ヨーロッパ", "東ヨーロッパ", "北ヨーロッパ", "西ヨーロッパ", "サブサハラアフリカ", "ラテンアメリカ", "アセンション島", "アンドラ", "アラブ首長国連邦", "アフガニスタン", "アンティグア・バーブーダ", "アンギラ", "アルバニア", "アルメニア", "アンゴラ", "南極", "アルゼンチン", "米領サモア", "オーストリア", "オーストラリア", "アルバ", "オーランド諸島", "アゼルバイジャン", "ボスニア・ヘルツェゴビナ", "バルバドス", "バングラデシュ", "ベルギー", "ブルキナファソ", "ブルガリア", "バーレーン", "ブルンジ", "ベナン", "サン・バルテルミー", "バミューダ", "ブルネイ", "ボリビア", "オランダ領カリブ", "ブラジル", "バハマ", "ブータン", "ブーベ島", "ボツワナ", "ベラルーシ", "ベリーズ", "カナダ", "ココス(キーリング)諸島", "コンゴ民主共和国(キンシャサ)", "中央アフリカ共和国", "コンゴ共和国(ブラザビル)", "スイス", "コートジボワール", "クック諸島", "チリ", "カメルーン", "中国", "コロンビア", "クリッパートン島", "サーク島", "コスタリカ", "キューバ", "カーボベルデ", "キュラソー", "クリスマス島", "キプロス", "チェコ", "ドイツ", "ディエゴガルシア島", "ジブチ", "デンマーク", "ドミニカ国", "ドミニカ共和国", "アルジェリア", "セウタ・メリリャ", "エクアドル", "エストニア", "エジプト", "西サハラ", "エリトリア", "スペイン", "エチオピア", "欧州連合", "ユーロ圏", "フィンランド", "フィジー", "フォークランド諸島", "ミクロネシア連邦", "フェロー諸島", "フランス", "ガボン", "イギリス", "グレナダ", "ジョージア", "仏領ギアナ", "ガーンジー", "ガーナ", "ジブラルタル", "グリーンランド", "ガンビア", "ギニア", "グアドループ", "赤道ギニア", "ギリシャ", "サウスジョージア・サウスサンドウィッチ諸島", "グアテマラ", "グアム", "ギニアビサウ", "ガイアナ", "中華人民共和国香港特別行政区", "ハード島・マクドナルド諸島", "ホンジュラス", "クロアチア", "ハイチ", "ハンガリー", "カナリア諸島", "インドネシア", "アイルランド", "イスラエル", "マン島", "インド", "英領インド洋地域", "イラク", "イラン", "アイスランド", "イタリア", "ジャージー", "ジャマイカ", "ヨルダン", "日本", "ケニア", "キルギス", "カンボジア", "キリバス", "コモロ", "セントクリストファー・ネーヴィス", "北朝鮮", "韓国", "クウェート", "ケイマン諸島", "カザフスタン", "ラオス", "レバノン", "セントルシア", "リヒテンシュタイン", "スリランカ", "リベリア", "レソト", "リトアニア", "ルクセンブルク", "ラトビア", "リビア", "モロッコ", "モナコ", "モルドバ", "モンテネグロ", "サン・マルタン", "マダガスカル", "マーシャル諸島", "北マケドニア", "マリ", "ミャンマー (ビルマ)", "モンゴル", "中華人民共和国マカオ特別行政区", "北マリアナ諸島", "マルティニーク", "モーリタニア", "モントセラト", "マルタ", "モーリシャス", "モルディブ", "マラウイ", "メキシコ", "マレーシア", "モザンビーク", "ナミビア", "ニューカレドニア", "ニジェール", "ノーフォーク島", "ナイジェリア", "ニカラグア", "オランダ", "ノルウェー", "ネパール", "ナウル", "ニウエ", "ニュージーランド", "オマーン", "パナマ", "ペルー", "仏領ポリネシア", "パプアニューギニア", "フィリピン", "パキスタン", "ポーランド", "サンピエール島・ミクロン島", "ピトケアン諸島", "プエルトリコ", "パレスチナ自治区", "ポルトガル", "パラオ", "パラグアイ", "カタール", "オセアニア周辺地域", "レユニオン", "ルーマニア", "セルビア", "ロシア", "ルワンダ", "サウジアラビア", "ソロモン諸島", "セーシェル", "スーダン", "スウェーデン", "シンガポール", "セントヘレナ", "スロベニア", "スバールバル諸島・ヤンマイエン島", "スロバキア", "シエラレオネ", "サンマリノ", "セネガル", "ソマリア", "スリナム", "南スーダン", "サントメ・プリンシペ", "エルサルバドル", "シント・マールテン", "シリア", "エスワティニ", "トリスタン・ダ・クーニャ", "タークス・カイコス諸島", "チャド", "仏領極南諸島", "トーゴ", "タイ", "タジキスタン", "トケラウ", "東ティモール", "トルクメニスタン", "チュニジア", "トンガ", "トルコ", "トリニダード・トバゴ", "ツバル", "台湾", "タンザニア", "ウクライナ", "ウガンダ", "合衆国領有小離島", "国際連合", "アメリカ合衆国", "ウルグアイ", "ウズベキスタン", "バチカン市国", "セントビンセント及びグレナディーン諸島", "ベネズエラ", "英領ヴァージン諸島", "米領ヴァージン諸島", "ベトナム", "バヌアツ", "ウォリス・フツナ", "サモア", "疑似アクセント", "疑似 BIDI", "コソボ", "イエメン", "マヨット", "南アフリカ", "ザンビア", "ジンバブエ"];
+
+// jv
+static R47: [&str; 294] = ["Donya", "Afrika", "Amérika Lor", "Amérika Kidul", "Oséania", "Afrika Kulon", "Amérika Tengah", "Afrika Wétan", "Afrika Lor", "Afrika Sisih Tengah", "Afrika Sisih Kidul", "Amérika", "Amérika Sisih Lor", "Karibia", "Asia Wétan", "Asia Kidul", "Asia Kidul-wétan", "Éropah Kidul", "Australasia", "Melanesia", "Daerah Mikronesia", "Polinesia", "Asia", "Asia Tengah", "Asia Kulon", "Éropah", "Éropah Wétan", "Éropah Lor", "Éropah Kulon", "Afrika Kidule Sahara", "Amérika Latin", "Pulo Ascension", "Andora", "Uni Émirat Arab", "Afganistan", "Antigua lan Barbuda", "Anguilla", "Albani", "Arménia", "Angola", "Antartika", "Argèntina", "Samoa Amerika", "Ostenrik", "Ostrali", "Aruba", "Kapuloan Alan", "Azerbaijan", "Bosnia lan Hèrségovina", "Barbadhos", "Banggaladésa", "Bèlgi", "Burkina Faso", "Bulgari", "Bahrain", "Burundi", "Bénin", "Saint Barthélémi", "Bermuda", "Brunéi", "Bolivia", "Karibia Walanda", "Brasil", "Bahama", "Bhutan", "Pulo Bovèt", "Botswana", "Bélarus", "Bélisé", "Kanada", "Kapuloan Cocos (Keeling)", "Kongo - Kinshasa", "Républik Afrika Tengah", "Kongo - Brassaville", "Switserlan", "Pasisir Gadhing", "Kapuloan Cook", "Cilé", "Kamerun", "Tyongkok", "Kolombia", "Pulo Clipperton", "Sark", "Kosta Rika", "Kuba", "Pongol Verdé", "Kurasao", "Pulo Natal", "Siprus", "Céko", "Jérman", "Diego Garcia", "Jibuti", "Dhènemarken", "Dominika", "Républik Dominika", "Aljasair", "Séuta lan Melila", "Ékuadhor", "Éstonia", "Mesir", "Sahara Kulon", "Éritréa", "Sepanyol", "Étiopia", "Uni Éropah", "Zona Éuro", "Finlan", "Fiji", "Kapuloan Falkland", "Féderasi Mikronésia", "Kapuloan Faro", "Prancis", "Gabon", "Karajan Manunggal", "Grénada", "Géorgia", "Guyana Prancis", "Guernsei", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadélup", "Guinéa Katulistiwa", "Grikenlan", "Georgia Kidul lan Kapuloan Sandwich Kidul", "Guatémala", "Guam", "Guinea-Bissau", "Guyana", "Laladan Administratif Astamiwa Hong Kong", "Kapuloan Heard lan McDonald", "Honduras", "Kroasia", "Haiti", "Honggari", "Kapuloan Kanari", "Indonésia", "Républik Irlan", "Israèl", "Pulo Man", "Indhia", "Wilayah Inggris ing Segara Hindia", "Irak", "Iran", "Èslan", "Itali", "Jersey", "Jamaika", "Yordania", "Jepang", "Kénya", "Kirgistan", "Kamboja", "Kiribati", "Komoro", "Saint Kits lan Nèvis", "Korea Lor", "Koréa Kidul", "Kuwait", "Kapuloan Kéman", "Kasakstan", "Laos", "Libanon", "Santa Lusia", "Liktenstén", "Sri Lanka", "Libèria", "Lésotho", "Litowen", "Luksemburg", "Latvia", "Libya", "Maroko", "Monako", "Moldova", "Montenégro", "Santa Martin", "Madagaskar", "Kapuloan Marshall", "Républik Makédonia Lor", "Mali", "Myanmar (Burma)", "Mongolia", "Laladan Administratif Astamiwa Makau", "Kapuloan Mariana Lor", "Martinik", "Mauritania", "Monsérat", "Malta", "Mauritius", "Maladéwa", "Malawi", "Mèksiko", "Malaysia", "Mosambik", "Namibia", "Kalédonia Anyar", "Nigér", "Pulo Norfolk", "Nigéria", "Nikaragua", "Walanda", "Nurwègen", "Népal", "Nauru", "Niue", "Selandia Anyar", "Oman", "Panama", "Peru", "Polinesia Prancis", "Papua Nugini", "Pilipina", "Pakistan", "Polen", "Saint Pièr lan Mikuélon", "Kapuloan Pitcairn", "Puèrto Riko", "Tlatah Palèstina", "Portugal", "Palau", "Paraguay", "Katar", "Oseania Paling Njaba", "Réunion", "Ruméni", "Sèrbi", "Rusia", "Rwanda", "Arab Saudi", "Kapuloan Suleman", "Sésèl", "Sudan", "Swèdhen", "Singapura", "Saint Héléna", "Slovénia", "Svalbard lan Jan Mayen", "Slowak", "Siéra Léoné", "San Marino", "Sénégal", "Somalia", "Suriname", "Sudan Kidul", "Sao Tomé lan Principé", "Èl Salvador", "Sint Martén", "Suriah", "Swasiland", "Tristan da Cunha", "Turks lan Kapuloan Kaikos", "Chad", "Wilayah Prancis nang Kutub Kidul", "Togo", "Tanah Thai", "Tajikistan", "Tokelau", "Timor Leste", "Turkménistan", "Tunisia", "Tonga", "Turki", "Trinidad lan Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukrania", "Uganda", "Kapuloan AS Paling Njaba", "Pasarékatan Bangsa-Bangsa", "Amérika Sarékat", "Uruguay", "Usbèkistan", "Kutha Vatikan", "Saint Vinsen lan Grénadin", "Vénésuéla", "Kapuloan Virgin Britania", "Kapuloan Virgin Amérika", "Viètnam", "Vanuatu", "Wallis lan Futuna", "Samoa", "Logat Semu", "Rong Arah Semu", "Kosovo", "Yaman", "Mayotte", "Afrika Kidul", "Sambia", "Simbabwe"];
+
+// kn
+static R48: [&str; 294] = ["ಪ್ರಪಂಚ", "ಆಫ್ರಿಕಾ", "ಉತ್ತರ ಅಮೇರಿಕಾ", "ದಕ್ಷಿಣ ಅಮೇರಿಕಾ", "ಓಶಿಯೇನಿಯಾ", "ಪಶ್ಚಿಮ ಆಫ್ರಿಕಾ", "ಮಧ್ಯ ಅಮೇರಿಕಾ", "ಪೂರ್ವ ಆಫ್ರಿಕಾ", "ಉತ್ತರ ಆಫ್ರಿಕಾ", "ಮಧ್ಯ ಆಫ್ರಿಕಾ", "ಆಫ್ರಿಕಾದ ದಕ್ಷಿಣ ಭಾಗ", "ಅಮೆರಿಕಾಸ್", "ಅಮೇರಿಕಾದ ಉತ್ತರ ಭಾಗ", "ಕೆರೀಬಿಯನ್", "ಪೂರ್ವ ಏಷ್ಯಾ", "ದಕ್ಷಿಣ ಏಷ್ಯಾ", "ಆಗ್ನೇಯ ಏಷ್ಯಾ", "ದಕ್ಷಿಣ ಯೂರೋಪ್", "ಆಸ್ಟ್ರೇಲೇಷ್ಯಾ", "ಮೆಲನೇಷಿಯಾ", "ಮೈಕ್ರೋನೇಶಿಯನ್ ಪ್ರದೇಶ", "ಪಾಲಿನೇಷ್ಯಾ", "ಏಷ್ಯಾ", "ಮಧ್ಯ ಏಷ್ಯಾ", "ಪಶ್ಚಿಮ ಏಷ್ಯಾ", "ಯೂರೋಪ್", "ಪೂರ್ವ ಯೂರೋಪ್", "ಉತ್ತರ ಯೂರೋಪ್", "ಪಶ್ಚಿಮ ಯೂರೋಪ್", "ಉಪ-ಸಹಾರನ್ ಆಫ್ರಿಕಾ", "ಲ್ಯಾಟಿನ್ ಅಮೇರಿಕಾ", "ಅಸೆನ್ಶನ್ ದ್ವೀಪ", "ಅಂಡೋರಾ", "ಯುನೈಟೆಡ್ ಅರಬ್ ಎಮಿರೇಟ್ಸ್", "ಅಫಘಾನಿಸ್ಥಾನ", "ಆಂಟಿಗುವಾ ಮತ್ತು ಬರ್ಬುಡಾ", "ಆಂಗ್ವಿಲ್ಲಾ", "ಅಲ್ಬೇನಿಯಾ", "ಆರ್ಮೇನಿಯ", "ಅಂಗೋಲಾ", "ಅಂಟಾರ್ಟಿಕಾ", "ಅರ್ಜೆಂಟಿನಾ", "ಅಮೇರಿಕನ್ ಸಮೋವಾ", "ಆಸ್ಟ್ರಿಯಾ", "ಆಸ್ಟ್ರೇಲಿಯಾ", "ಅರುಬಾ", "ಆಲ್ಯಾಂಡ್ ದ್ವೀಪಗಳು", "ಅಜರ್ಬೈಜಾನ್", "ಬೋಸ್ನಿಯಾ ಮತ್ತು ಹರ್ಜೆಗೋವಿನಾ", "ಬಾರ್ಬಡೋಸ್", "ಬಾಂಗ್ಲಾದೇಶ", "ಬೆಲ್ಜಿಯಮ್", "ಬುರ್ಕಿನಾ ಫಾಸೊ", "ಬಲ್ಗೇರಿಯಾ", "ಬಹ್ರೇನ್", "ಬುರುಂಡಿ", "ಬೆನಿನ್", "ಸೇಂಟ್ ಬಾರ್ಥೆಲೆಮಿ", "ಬರ್ಮುಡಾ", "ಬ್ರೂನಿ", "ಬೊಲಿವಿಯಾ", "ಕೆರೀಬಿಯನ್ ನೆದರ್\u{200C}ಲ್ಯಾಂಡ್ಸ್", "ಬ್ರೆಜಿಲ್", "ಬಹಾಮಾಸ್", "ಭೂತಾನ್", "ಬೋವೆಟ್ ದ್ವೀಪ", "ಬೋಟ್ಸ್\u{200C}ವಾನಾ", "ಬೆಲಾರಸ್", "ಬೆಲಿಜ್", "ಕೆನಡಾ", "ಕೊಕೊಸ್ (ಕೀಲಿಂಗ್) ದ್ವೀಪಗಳು", "ಕಾಂಗೋ - ಕಿನ್ಶಾಸಾ", "ಮಧ್ಯ ಆಫ್ರಿಕಾ ಗಣರಾಜ್ಯ", "ಕಾಂಗೋ - ಬ್ರಾಜಾವಿಲ್ಲೇ", "ಸ್ವಿಟ್ಜರ್ಲ್ಯಾಂಡ್", "ಕೋತ್ ದ್‘ಇವಾರ್", "ಕುಕ್ ದ್ವೀಪಗಳು", "ಚಿಲಿ", "ಕ್ಯಾಮರೂನ್", "ಚೀನಾ", "ಕೊಲಂಬಿಯಾ", "ಕ್ಲಿಪ್ಪರ್\u{200C}ಟಾನ್ ದ್ವೀಪ", "ಸಾರ್ಕ್", "ಕೊಸ್ಟಾ ರಿಕಾ", "ಕ್ಯೂಬಾ", "ಕೇಪ್ ವರ್ಡೆ", "ಕುರಾಕಾವ್", "ಕ್ರಿಸ್ಮಸ್ ದ್ವೀಪ", "ಸೈಪ್ರಸ್", "ಝೆಕಿಯಾ", "ಜರ್ಮನಿ", "ಡೈಗೋ ಗಾರ್ಸಿಯ", "ಜಿಬೂಟಿ", "ಡೆನ್ಮಾರ್ಕ್", "ಡೊಮಿನಿಕಾ", "ಡೊಮೆನಿಕನ್ ರಿಪಬ್ಲಿಕ್", "ಅಲ್ಜೀರಿಯ", "ಸೆಯುಟಾ ಹಾಗೂ ಮೆಲಿಲ್ಲಾ", "ಈಕ್ವೆಡಾರ್", "ಎಸ್ಟೋನಿಯಾ", "ಈಜಿಪ್ಟ್", "ಪಶ್ಚಿಮ ಸಹಾರಾ", "ಎರಿಟ್ರಿಯಾ", "ಸ್ಪೇನ್", "ಇಥಿಯೋಪಿಯಾ", "ಯುರೋಪಿಯನ್ ಒಕ್ಕೂಟ", "ಯೂರೋಝೋನ್\u{200C}", "ಫಿನ್\u{200C}ಲ್ಯಾಂಡ್", "ಫಿಜಿ", "ಫಾಕ್\u{200C}ಲ್ಯಾಂಡ್ ದ್ವೀಪಗಳು", "ಮೈಕ್ರೋನೇಶಿಯಾ", "ಫರೋ ದ್ವೀಪಗಳು", "ಫ್ರಾನ್ಸ್", "ಗೆಬೊನ್", "ಯುನೈಟೆಡ್ ಕಿಂಗ್\u{200C}ಡಮ್", "ಗ್ರೆನೆಡಾ", "ಜಾರ್ಜಿಯಾ", "ಫ್ರೆಂಚ್ ಗಯಾನಾ", "ಗುರ್ನ್\u{200C}ಸೆ", "ಘಾನಾ", "ಗಿಬ್ರಾಲ್ಟರ್", "ಗ್ರೀನ್\u{200C}ಲ್ಯಾಂಡ್", "ಗ್ಯಾಂಬಿಯಾ", "ಗಿನಿ", "ಗುಡೆಲೋಪ್", "ಈಕ್ವೆಟೋರಿಯಲ್ ಗಿನಿ", "ಗ್ರೀಸ್", "ದಕ್ಷಿಣ ಜಾರ್ಜಿಯಾ ಮತ್ತು ದಕ್ಷಿಣ ಸ್ಯಾಂಡ್\u{200D}ವಿಚ್ ದ್ವೀಪಗಳು", "ಗ್ವಾಟೆಮಾಲಾ", "ಗುವಾಮ್", "ಗಿನಿ-ಬಿಸ್ಸಾವ್", "ಗಯಾನಾ", "ಹಾಂಗ್ ಕಾಂಗ್ ಎಸ್ಎಆರ್ ಚೈನಾ", "ಹರ್ಡ್ ಮತ್ತು ಮ್ಯಾಕ್\u{200C}ಡೋನಾಲ್ಡ್ ದ್ವೀಪಗಳು", "ಹೊಂಡುರಾಸ್", "ಕ್ರೊಯೇಷಿಯಾ", "ಹೈಟಿ", "ಹಂಗೇರಿ", "ಕ್ಯಾನರಿ ದ್ವೀಪಗಳು", "ಇಂಡೋನೇಶಿಯಾ", "ಐರ್ಲೆಂಡ್", "ಇಸ್ರೇಲ್", "ಐಲ್ ಆಫ್ ಮ್ಯಾನ್", "ಭಾರತ", "ಬ್ರಿಟೀಷ್ ಹಿಂದೂ ಮಹಾಸಾಗರದ ಪ್ರದೇಶ", "ಇರಾಕ್", "ಇರಾನ್", "ಐಸ್\u{200C}ಲ್ಯಾಂಡ್", "ಇಟಲಿ", "ಜೆರ್ಸಿ", "ಜಮೈಕಾ", "ಜೋರ್ಡಾನ್", "ಜಪಾನ್", "ಕೀನ್ಯಾ", "ಕಿರ್ಗಿಸ್ಥಾನ್", "ಕಾಂಬೋಡಿಯಾ", "ಕಿರಿಬಾಟಿ", "ಕೊಮೊರೊಸ್", "ಸೇಂಟ್ ಕಿಟ್ಸ್ ಮತ್ತು ನೆವಿಸ್", "ಉತ್ತರ ಕೊರಿಯಾ", "ದಕ್ಷಿಣ ಕೊರಿಯಾ", "ಕುವೈತ್", "ಕೇಮನ್ ದ್ವೀಪಗಳು", "ಕಝಾಕಿಸ್ಥಾನ್", "ಲಾವೋಸ್", "ಲೆಬನಾನ್", "ಸೇಂಟ್ ಲೂಸಿಯಾ", "ಲಿಚೆನ್\u{200C}ಸ್ಟೈನ್", "ಶ್ರೀಲಂಕಾ", "ಲಿಬೇರಿಯಾ", "ಲೆಸೊಥೊ", "ಲಿಥುವೇನಿಯಾ", "ಲಕ್ಸೆಂಬರ್ಗ್", "ಲಾಟ್ವಿಯಾ", "ಲಿಬಿಯಾ", "ಮೊರಾಕ್ಕೊ", "ಮೊನಾಕೊ", "ಮೊಲ್ಡೋವಾ", "ಮೊಂಟೆನೆಗ್ರೋ", "ಸೇಂಟ್ ಮಾರ್ಟಿನ್", "ಮಡಗಾಸ್ಕರ್", "ಮಾರ್ಷಲ್ ದ್ವೀಪಗಳು", "ಉತ್ತರ ಮ್ಯಾಸಿಡೋನಿಯಾ", "ಮಾಲಿ", "ಮಯನ್ಮಾರ್ (ಬರ್ಮಾ)", "ಮಂಗೋಲಿಯಾ", "ಮಕಾವು ಎಸ್ಎಆರ್ ಚೈನಾ", "ಉತ್ತರ ಮರಿಯಾನಾ ದ್ವೀಪಗಳು", "ಮಾರ್ಟಿನಿಕ್", "ಮಾರಿಟೇನಿಯಾ", "ಮಾಂಟ್\u{200C}ಸೆರಟ್", "ಮಾಲ್ಟಾ", "ಮಾರಿಷಸ್", "ಮಾಲ್ಡೀವ್ಸ್", "ಮಲಾವಿ", "ಮೆಕ್ಸಿಕೊ", "ಮಲೇಶಿಯಾ", "ಮೊಜಾಂಬಿಕ್", "ನಮೀಬಿಯಾ", "ನ್ಯೂ ಕ್ಯಾಲಿಡೋನಿಯಾ", "ನೈಜರ್", "ನಾರ್ಫೋಕ್ ದ್ವೀಪ", "ನೈಜೀರಿಯಾ", "ನಿಕಾರಾಗುವಾ", "ನೆದರ್\u{200C}ಲ್ಯಾಂಡ್ಸ್", "ನಾರ್ವೆ", "ನೇಪಾಳ", "ನೌರು", "ನಿಯು", "ನ್ಯೂಜಿಲೆಂಡ್", "ಓಮನ್", "ಪನಾಮಾ", "ಪೆರು", "ಫ್ರೆಂಚ್ ಪಾಲಿನೇಷ್ಯಾ", "ಪಪುವಾ ನ್ಯೂಗಿನಿಯಾ", "ಫಿಲಿಫೈನ್ಸ್", "ಪಾಕಿಸ್ತಾನ", "ಪೋಲ್ಯಾಂಡ್", "ಸೇಂಟ್ ಪಿಯರ್ ಮತ್ತು ಮಿಕ್ವೆಲನ್", "ಪಿಟ್\u{200C}ಕೈರ್ನ್ ದ್ವೀಪಗಳು", "ಪ್ಯೂರ್ಟೋ ರಿಕೊ", "ಪ್ಯಾಲೇಸ್ಟೇನಿಯನ್ ಪ್ರದೇಶಗಳು", "ಪೋರ್ಚುಗಲ್", "ಪಲಾವು", "ಪರಾಗ್ವೇ", "ಖತಾರ್", "ಔಟ್ ಲೈಯಿಂಗ್ ಓಷಿಯಾನಿಯಾ", "ರಿಯೂನಿಯನ್", "ರೊಮೇನಿಯಾ", "ಸೆರ್ಬಿಯಾ", "ರಷ್ಯಾ", "ರುವಾಂಡಾ", "ಸೌದಿ ಅರೇಬಿಯಾ", "ಸಾಲೊಮನ್ ದ್ವೀಪಗಳು", "ಸೀಶೆಲ್ಲೆಸ್", "ಸುಡಾನ್", "ಸ್ವೀಡನ್", "ಸಿಂಗಪುರ್", "ಸೇಂಟ್ ಹೆಲೆನಾ", "ಸ್ಲೋವೇನಿಯಾ", "ಸ್ವಾಲ್ಬಾರ್ಡ್ ಮತ್ತು ಜಾನ್ ಮೆಯನ್", "ಸ್ಲೊವಾಕಿಯಾ", "ಸಿಯೆರ್ರಾ ಲಿಯೋನ್", "ಸ್ಯಾನ್ ಮೆರಿನೋ", "ಸೆನೆಗಲ್", "ಸೊಮಾಲಿಯಾ", "ಸುರಿನಾಮ್", "ದಕ್ಷಿಣ ಸುಡಾನ್", "ಸಾವೋ ಟೋಮ್ ಮತ್ತು ಪ್ರಿನ್ಸಿಪಿ", "ಎಲ್ ಸಾಲ್ವೇಡಾರ್", "ಸಿಂಟ್ ಮಾರ್ಟೆನ್", "ಸಿರಿಯಾ", "ಎಸ್ವಾಟಿನಿ", "ಟ್ರಿಸ್ತನ್ ಡಾ ಕುನ್ಹಾ", "ಟರ್ಕ್ಸ್ ಮತ್ತು ಕೈಕೋಸ್ ದ್ವೀಪಗಳು", "ಚಾದ್", "ಫ್ರೆಂಚ್ ದಕ್ಷಿಣ ಪ್ರದೇಶಗಳು", "ಟೋಗೋ", "ಥೈಲ್ಯಾಂಡ್", "ತಜಿಕಿಸ್ತಾನ್", "ಟೊಕೆಲಾವ್", "ಟಿಮೋರ್ ಲೆಸ್ಟೆ", "ತುರ್ಕಮೆನಿಸ್ತಾನ್", "ಟುನೀಶಿಯ", "ಟೊಂಗಾ", "ತುರ್ಕಿಯೆ", "ಟ್ರಿನಿಡಾಡ್ ಮತ್ತು ಟೊಬಾಗೊ", "ಟುವಾಲು", "ತೈವಾನ್", "ತಾಂಜೇನಿಯಾ", "ಉಕ್ರೈನ್", "ಉಗಾಂಡಾ", "ಯುಎಸ್\u{200C} ಔಟ್\u{200C}ಲೇಯಿಂಗ್ ದ್ವೀಪಗಳು", "ಸಂಯುಕ್ತ ಸಂಸ್ಥಾನಗಳು", "ಯುನೈಟೆಡ್ ಸ್ಟೇಟ್ಸ್", "ಉರುಗ್ವೆ", "ಉಜ್ಬೇಕಿಸ್ಥಾನ್", "ವ್ಯಾಟಿಕನ್ ಸಿಟಿ", "ಸೇಂಟ್. ವಿನ್ಸೆಂಟ್ ಮತ್ತು ಗ್ರೆನೆಡೈನ್ಸ್", "ವೆನೆಜುವೆಲಾ", "ಬ್ರಿಟಿಷ್ ವರ್ಜಿನ್ ದ್ವೀಪಗಳು", "ಯು.ಎಸ್. ವರ್ಜಿನ್ ದ್ವೀಪಗಳು", "ವಿಯೆಟ್ನಾಮ್", "ವನೌಟು", "ವಾಲಿಸ್ ಮತ್ತು ಫುಟುನಾ", "ಸಮೋವಾ", "ಸ್ಯುಡೊ ಉಚ್ಛಾರಣೆಗಳು", "ಸ್ಯುಡೊ-ಬಿಡಿ", "ಕೊಸೊವೊ", "ಯೆಮನ್", "ಮಯೊಟ್ಟೆ", "ದಕ್ಷಿಣ ಆಫ್ರಿಕಾ", "ಜಾಂಬಿಯ", "ಜಿಂಬಾಬ್ವೆ"];
+
+// ks
+static R49: [&str; 294] = ["دُنیا", "اَفریٖکا", "شُمٲلی اَمریٖکا", "جَنوٗنی اَمرٖیٖکا", "اوشَنیا", "مَغریٖبی اَفریٖکا", "مرکٔزی اَمریٖکا", "مَشرِقی اَفریٖکا", "شُمٲلی اَفریٖکا", "وسطی اَفریٖکا", "جنوٗبی اَفریٖکا", "اَمریٖکَس", "شُمٲلی اَمریٖکا خٕطہٕ", "کَرِببیٖن", "مَشرِقی ایشیا", "جنوٗبی ایشیا", "جنوٗبہِ مَشرِقی ایشیا", "جنوٗبی یوٗرَپ", "آسٹریلیشیا", "مؠلَنیٖشِیا", "مَیکرونَیشِیَن خٕطہٕ", "پالنیشِیا", "ایشیا", "مرکٔزی ایشیا", "مَغرِبی ایشیا", "یوٗرَپ", "مشرِقی یوٗرَپ", "شُمٲلی یوٗرَپ", "مغرِبی یوٗرَپ", "ذیلی سہارن افریقہ", "لاطیٖنی اَمریٖکا", "ایسنشن جزیرٕ", "اینڈورا", "مُتحدہ عرَب امارات", "اَفغانَستان", "اؠنٹِگُوا تہٕ باربوڑا", "انگوئیلا", "البانیا", "اَرمانِیا", "انگولا", "اینٹارٹِکا", "أرجَنٹینا", "اَمریٖکَن سَموا", "آسٹریا", "آسٹریلِیا", "اَروٗبا", "ایلینڑ جٔزیٖرٕ", "آذربائیجان", "بوسنِیا تہٕ ہَرزِگووِنا", "باربیڈوس", "بَنگلادیش", "بیلجِیَم", "بُرکِنا فیسو", "بَلجیرِیا", "بحریٖن", "بورَنڈِ", "بِنِن", "سینٹ بارتَھیلمی", "برمودا", "برونے", "بولِوِیا", "کیریبین نیدرلینڈس", "برازِل", "بَہامَس", "بوٗٹان", "بووَٹ جٔزیٖرٕ", "بوتَسوانا", "بیلاروٗس", "بیلز", "کینیڈا", "کوکَس (کیٖلِنگ) جٔزیٖرٕ", "کونگو کِنشاسا", "مرکٔزی اَفریٖکی جموٗریَت", "کونگو بٔرزاوِلی", "سُوِزَرلینڑ", "کوٹ ڈلوائر", "کُک جٔزیٖرٕ", "چِلی", "کیمِروٗن", "چیٖن", "کولَمبِیا", "کلیپرٹن جزیرٕ", "", "کوسٹا ریکا", "کیوٗبا", "کیپ ؤرڑی", "کیوراکو", "کرِسمَس جٔزیٖرٕ", "سائپرس", "چیکیا", "جرمٔنی", "ڈیگو گریشیا", "جِبوٗتی", "ڈینمارٕک", "ڈومِنِکا", "ڈومِنِکَن جموٗرِیَت", "اؠلجیرِیا", "سیوٹا تٕہ مٔلیلا", "اِکواڑور", "ایسٹونِیا", "مصر", "مشرِقی سَہارا", "اِرٕٹِیا", "سٕپین", "اِتھوپِیا", "یوٗرپی یونین", "یوٗرو زون", "فِن لینڈ", "فِجی", "فٕلاکلینڑ جٔزیٖرٕ", "مائیکرونیشیا", "فارو جزیرہ", "فرانس", "گیبان", "متحدہ مملِکت", "گرینیڈا", "جارجِیا", "فرانسِسی گِانا", "گورنسے", "گانا", "جِبرالٹَر", "گرین لینڈ", "گَمبِیا", "گِنی", "گواڈلوپ", "اِکوِٹورِیَل گِنی", "گریٖس", "جنوٗبی جارجِیا تہٕ جنوٗبی سینڑوٕچ جٔزیٖرٕ", "گواٹمالا", "گُوام", "گیٖنی بِساو", "گُیانا", "ہانگ کانگ ایس اے آر چیٖن", "ہَرٕڑ تہٕ مؠکڈونالڑٕ جٔزیٖرٕ", "ہونڈورس", "کروشِیا", "ہیتی", "ہَنگری", "کینری جزیرٕ", "انڈونیشیا", "اَیَرلینڑ", "اسرا ییل", "آیِل آف مین", "ہِندوستان", "", "ایٖراق", "ایٖران", "اَیِسلینڑ", "اِٹلی", "جٔرسی", "جَمایکا", "جورڈن", "جاپان", "کِنیا", "کرغزستان", "کَمبوڑِیا", "کِرٕباتی", "کَمورَس", "سینٹ کِٹَس تہٕ نیوِس", "شُمٲلی کورِیا", "جنوٗبی کورِیا", "کُویت", "کیمَن جٔزیٖرٕ", "قازقستان", "لاس", "لؠبنان", "سینٹ لوٗسِیا", "لِکٹیسٹیٖن", "سری لنکا", "لایبیرِیا", "لیسوتھو", "لِتھُوانِیا", "لَکسَمبٔرٕگ", "لیٹوِیا", "لِبیا", "موروکو", "مونیکو", "مولڈووا", "موٹونیگِریو", "سینٹ مارٹِن", "میڈاگاسکار", "مارشَل جٔزیٖرٕ", "شُمالی میسڈونیا", "مالی", "میانمار (برما)", "مَنگولِیا", "مَکاوو ایس اے آر چیٖن", "شُمٲلی مارِیانا جٔزیٖرٕ", "مارٹِنِک", "مارٕٹانِیا", "مانٹسیراٹ", "مالٹا", "مورِشَس", "مالدیٖو", "ملاوی", "مؠکسِکو", "مَلیشِیا", "موزَمبِک", "نامِبِیا", "نِو کیلِڑونِیا", "نایجَر", "نارفاک جٔزیٖرٕ", "نایجیرِیا", "ناکاراگُوا", "نیٖدَرلینڑ", "ناروے", "نیپال", "نارووٗ", "نیوٗ", "نیوزی لینڈ", "اومان", "پَناما", "پیٖروٗ", "فرانسی پولِنیشِیا", "پاپُوا نیوٗ گیٖنی", "فلپائن", "پاکِستان", "پولینڈ", "سینٹ پیٖری تہٕ موکیلِیَن", "پِٹکیرٕنؠ جٔزیٖرٕ", "پٔرٹو رِکو", "فلسطینی علاقٕہ", "پُرتِگال", "پَلاو", "پَراگُے", "قَطِر", "آوُٹلاینِگ اوشینِیا", "رِیوٗنِیَن", "رومانِیا", "سَربِیا", "روٗس", "روٗوانڈا", "سعودی عرب", "سولامان جٔزیٖرٕ", "سیشَلِس", "سوٗڈان", "سویڈن", "سِنگاپوٗر", "سینٹ ہؠلِنا", "سَلووینِیا", "سَوالبریڑ تہٕ جان ماییڑ", "سَلوواکِیا", "سیرا لیون", "سین میرِنو", "سینیگَل", "سومالِیا", "سُرِنام", "جنوبی سوڈان", "ساو توم تہٕ پرنسِپی", "ایل سلویڈر", "سِنٹ مارٹِن", "شام", "ایسواتنی", "ٹریسٹن ڈا کنہا", "تُرکس تٕہ کیکو جزیرٕ", "چاڑ", "فرانسِسی جَنوٗبی عَلاقہٕ", "ٹوگو", "تھائی لینڈ", "تاجکِستان", "ٹوکلو", "تیمور-لیسٹ", "تُرکمنستان", "ٹونیشِیا", "ٹونگا", "تُرکی", "ٹرنِنداد تہٕ ٹوبیگو", "توٗوالوٗ", "تایوان", "تَنجانِیا", "یوٗرِکین", "یوٗگانڑا", "یوٗنایٹِڑ سِٹیٹِس ماینَر آوُٹلییِنگ جٔزیٖرٕ", "متحدہ مُمٲلک", "یوٗنایٹِڑ سِٹیٹِس", "یوٗروگے", "اُزبِکِستان", "ویٹِکَن سِٹی", "سینٹ وینسؠٹ تہٕ گریناڑاینٕز", "وینازوٗلا", "بَرطانوی ؤرجِن جٔزیٖرٕ", "یوٗ ایس ؤرجِن جٔزیٖرٕ", "ویتنام", "وانوٗتوٗ", "والِس تہٕ فیوٗچوٗنا", "سامو", "سوڈو-ایسنٹس", "سوڈو-بیڈی", "کوسوو", "یَمَن", "مَییٹ", "جنوبی افریقہ", "زیمبیا", "زِمبابے"];
+
+// kk
+static R50: [&str; 294] = ["әлем", "Африка", "Солтүстік Америка", "Оңтүстік Америка", "Океания", "Батыс Африка", "Орталық Америка", "Шығыс Африка", "Солтүстік Африка", "Орталық Африка", "Оңтүстік Африка аймағы", "Америка", "Солтүстік Америка (аймақ)", "Кариб", "Шығыс Азия", "Оңтүстік Азия", "Оңтүстік-Шығыс Азия", "Оңтүстік Еуропа", "Аустралазия", "Меланезия", "Микронезия аймағы", "Полинезия", "Азия", "Орталық Азия", "Батыс Азия", "Еуропа", "Шығыс Еуропа", "Солтүстік Еуропа", "Батыс Еуропа", "Субсахаралық Африка", "Латын Америкасы", "Әскенжін аралы", "Андорра", "Біріккен Араб Әмірліктері", "Ауғанстан", "Антигуа және Барбуда", "Ангилья", "Албания", "Армения", "Ангола", "Антарктида", "Аргентина", "Америкалық Самоа", "Аустрия", "Аустралия", "Аруба", "Аланд аралдары", "Әзірбайжан", "Босния және Герцеговина", "Барбадос", "Бангладеш", "Бельгия", "Буркина-Фасо", "Болгария", "Бахрейн", "Бурунди", "Бенин", "Сен-Бартелеми", "Бермуд аралдары", "Бруней", "Боливия", "Бонэйр, Синт-Эстатиус және Саба", "Бразилия", "Багам аралдары", "Бутан", "Буве аралы", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокос (Килинг) аралдары", "Конго", "Орталық Африка Республикасы", "Конго-Браззавиль Республикасы", "Швейцария", "Кот-д’Ивуар", "Кук аралдары", "Чили", "Камерун", "Қытай", "Колумбия", "Клиппертон аралы", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Рождество аралы", "Кипр", "Чехия", "Германия", "Диего-Гарсия", "Джибути", "Дания", "Доминика", "Доминикан Республикасы", "Алжир", "Сеута және Мелилья", "Эквадор", "Эстония", "Мысыр", "Батыс Сахара", "Эритрея", "Испания", "Эфиопия", "Еуропалық Одақ", "Еуроаймақ", "Финляндия", "Фиджи", "Фолкленд аралдары", "Микронезия", "Фарер аралдары", "Франция", "Габон", "Ұлыбритания", "Гренада", "Грузия", "Француз Гвианасы", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Экваторлық Гвинея", "Грекия", "Оңтүстік Георгия және Оңтүстік Сандвич аралдары", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Сянган АӘА", "Херд аралы және Макдональд аралдары", "Гондурас", "Хорватия", "Гаити", "Венгрия", "Канар аралдары", "Индонезия", "Ирландия", "Израиль", "Мэн аралы", "Үндістан", "Үнді мұхитындағы Британ аймағы", "Ирак", "Иран", "Исландия", "Италия", "Джерси", "Ямайка", "Иордания", "Жапония", "Кения", "Қырғызстан", "Камбоджа", "Кирибати", "Комор аралдары", "Сент-Китс және Невис", "Солтүстік Корея", "Оңтүстік Корея", "Кувейт", "Кайман аралдары", "Қазақстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова", "Черногория", "Сен-Мартен", "Мадагаскар", "Маршалл аралдары", "Солтүстік Македония", "Мали", "Мьянма (Бирма)", "Моңғолия", "Макао АӘА", "Солтүстік Мариана аралдары", "Мартиника", "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдив аралдары", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Жаңа Каледония", "Нигер", "Норфолк аралы", "Нигерия", "Никарагуа", "Нидерланд", "Норвегия", "Непал", "Науру", "Ниуэ", "Жаңа Зеландия", "Оман", "Панама", "Перу", "Француз Полинезиясы", "Папуа — Жаңа Гвинея", "Филиппин аралдары", "Пәкістан", "Польша", "Сен-Пьер және Микелон", "Питкэрн аралдары", "Пуэрто-Рико", "Палестина аймақтары", "Португалия", "Палау", "Парагвай", "Катар", "Сыртқы Океания", "Реюньон", "Румыния", "Сербия", "Ресей", "Руанда", "Сауд Арабиясы", "Соломон аралдары", "Сейшель аралдары", "Судан", "Швеция", "Сингапур", "Әулие Елена аралы", "Словения", "Шпицберген және Ян-Майен", "Словакия", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Оңтүстік Судан", "Сан-Томе және Принсипи", "Сальвадор", "Синт-Мартен", "Сирия", "Эсватини", "Тристан-да-Кунья", "Теркс және Кайкос аралдары", "Чад", "Францияның оңтүстік аймақтары", "Того", "Таиланд", "Тәжікстан", "Токелау", "Тимор-Лесте", "Түрікменстан", "Тунис", "Тонга", "Түркия", "Тринидад және Тобаго", "Тувалу", "Тайвань", "Танзания", "Украина", "Уганда", "АҚШ-тың сыртқы кіші аралдары", "Біріккен Ұлттар Ұйымы", "Америка Құрама Штаттары", "Уругвай", "Өзбекстан", "Ватикан", "Сент-Винсент және Гренадин аралдары", "Венесуэла", "Британдық Виргин аралдары", "АҚШ-тың Виргин аралдары", "Вьетнам", "Вануату", "Уоллис және Футуна", "Самоа", "жалған акцент", "жалған Bidi", "Косово", "Йемен", "Майотта", "Оңтүстік Африка", "Замбия", "Зимбабве"];
+
+// km
+static R51: [&str; 294] = ["ពិភពលោក", "អាហ្វ្រិក", "អាមេរិក\u{200B}ខាង\u{200B}ជើង", "អាមេរិក\u{200B}ខាង\u{200B}ត្បូង", "អូសេអានី", "អាហ្វ្រិក\u{200B}ខាង\u{200B}លិច", "អាមេរិក\u{200B}កណ្ដាល", "អាហ្វ្រិកខាងកើត", "អាហ្វ្រិក\u{200B}ខាង\u{200B}ជើង", "អាហ្វ្រិក\u{200B}កណ្តាល", "អាហ្វ្រិកភាគខាងត្បូង", "អាមេរិក", "អាមេរិក\u{200B}ភាគ\u{200B}ខាង\u{200B}ជើង", "ការ៉ាប៊ីន", "អាស៊ី\u{200B}ខាង\u{200B}កើត", "អាស៊ី\u{200B}ខាង\u{200B}ត្បូង", "អាស៊ីអាគ្នេយ៍", "អឺរ៉ុប\u{200B}ខាង\u{200B}ត្បូង", "អូស្ត្រាឡាស៊ី", "មេឡាណេស៊ី", "តំបន់\u{200B}មីក្រូណេស៊ី", "ប៉ូលីណេស៊ី", "អាស៊ី", "អាស៊ី\u{200B}កណ្ដាល", "អាស៊ី\u{200B}ខាង\u{200B}លិច", "អឺរ៉ុប", "អឺរ៉ុប\u{200B}ខាង\u{200B}កើត", "អឺរ៉ុប\u{200B}ខាង\u{200B}ជើង", "អឺរ៉ុប\u{200B}ខាង\u{200B}លិច", "អនុតំបន់សាហារ៉ាអាហ្វ្រិក", "អាមេរិក\u{200B}ឡាទីន", "កោះ\u{200B}អាសេនសិន", "អង់ដូរ៉ា", "អេមីរ៉ាត\u{200B}អារ៉ាប់\u{200B}រួម", "អាហ្វហ្កានីស្ថាន", "អង់ទីហ្គា និង បាប៊ុយដា", "អង់ហ្គីឡា", "អាល់បានី", "អាមេនី", "អង់ហ្គោឡា", "អង់តាក់ទិក", "អាហ្សង់ទីន", "សាម័រ អាមេរិកាំង", "អូទ្រីស", "អូស្ត្រាលី", "អារូបា", "កោះ\u{200B}អាឡង់", "អាស៊ែបៃហ្សង់", "បូស្ន៊ី និងហឺហ្ស៊ីហ្គូវីណា", "បាបាដុស", "បង់ក្លាដែស", "បែលហ្ស៊ិក", "បួគីណាហ្វាសូ", "ប៊ុលហ្ការី", "បារ៉ែន", "ប៊ូរុនឌី", "បេណាំង", "សាំង\u{200B}បាថេឡេមី", "ប៊ឺមុយដា", "ព្រុយណេ", "បូលីវី", "ហូឡង់ ការ៉ាប៊ីន", "ប្រេស៊ីល", "បាហាម៉ា", "ប៊ូតង់", "កោះ\u{200B}ប៊ូវ៉េត", "បុតស្វាណា", "បេឡារុស", "បេលី", "កាណាដា", "កោះ\u{200B}កូកូស (គីលីង)", "កុងហ្គោ- គីនស្ហាសា", "សាធារណរដ្ឋអាហ្វ្រិកកណ្ដាល", "កុងហ្គោ - ប្រាហ្សាវីល", "ស្វ៊ីស", "កូតឌីវ័រ", "កោះ\u{200B}ខូក", "ស៊ីលី", "កាមេរូន", "ចិន", "កូឡុំប៊ី", "កោះ\u{200B}ឃ្លីភឺតុន", "សាខ", "កូស្តារីកា", "គុយបា", "កាប់វែរ", "កូរ៉ាកៅ", "កោះ\u{200B}គ្រីស្មាស", "ស៊ីប", "ឆែក", "អាល្លឺម៉ង់", "ឌៀហ្គោហ្គាស៊ី", "ជីប៊ូទី", "ដាណឺម៉ាក", "ដូមីនីក", "សាធារណរដ្ឋ\u{200B}ដូមីនីក", "អាល់ហ្សេរី", "ជឺតា និង\u{200B}ម៉េលីឡា", "អេក្វាទ័រ", "អេស្តូនី", "អេហ្ស៊ីប", "សាហារ៉ាខាងលិច", "អេរីត្រេ", "អេស្ប៉ាញ", "អេត្យូពី", "សហភាព\u{200B}អឺរ៉ុប", "តំបន់ចាយលុយអឺរ៉ូ", "ហ្វាំងឡង់", "ហ្វីជី", "កោះ\u{200B}ហ្វក់ឡែន", "មីក្រូណេស៊ី", "កោះ\u{200B}ហ្វារ៉ូ", "បារាំង", "ហ្គាបុង", "ចក្រភព\u{200B}អង់គ្លេស", "ហ្គ្រើណាដ", "ហ្សកហ្ស៊ី", "ហ្គីអាណា បារាំង", "ហ្គេនស៊ី", "ហ្គាណា", "ហ្ស៊ីប្រាល់តា", "ហ្គ្រោអង់ឡង់", "ហ្គំប៊ី", "ហ្គីណេ", "ហ្គោដឺឡុប", "ហ្គីណេអេក្វាទ័រ", "ក្រិក", "កោះ\u{200B}ហ្សកហ្ស៊ី\u{200B}ខាងត្បូង និង សង់វិច\u{200B}ខាងត្បូង", "ក្វាតេម៉ាឡា", "ហ្គាំ", "ហ្គីណេប៊ីស្សូ", "ហ្គីយ៉ាន", "ហុងកុង តំបន់រដ្ឋបាលពិសេសចិន", "កោះ\u{200B}ហឺដនិង\u{200B}ម៉ាក់ដូណាល់", "ហុងឌូរ៉ាស", "ក្រូអាស៊ី", "ហៃទី", "ហុងគ្រី", "កោះ\u{200B}កាណារី", "ឥណ្ឌូណេស៊ី", "អៀរឡង់", "អ៊ីស្រាអែល", "អែលអុហ្វមែន", "ឥណ្ឌា", "ដែនដី\u{200B}អង់គ្លេស\u{200B}នៅ\u{200B}មហា\u{200B}សមុទ្រ\u{200B}ឥណ្ឌា", "អ៊ីរ៉ាក់", "អ៊ីរ៉ង់", "អ៊ីស្លង់", "អ៊ីតាលី", "ជើស៊ី", "ហ្សាម៉ាអ៊ីក", "ហ៊្សកដានី", "ជប៉ុន", "កេនយ៉ា", "កៀហ្ស៊ីស៊ីស្ថាន", "កម្ពុជា", "គិរីបាទី", "កូម័រ", "សាំង\u{200B}គីត និង ណេវីស", "កូរ៉េ\u{200B}ខាង\u{200B}ជើង", "កូរ៉េ\u{200B}ខាង\u{200B}ត្បូង", "កូវ៉ែត", "កោះ\u{200B}កៃម៉ង់", "កាហ្សាក់ស្ថាន", "ឡាវ", "លីបង់", "សាំងលូស៊ី", "លិចតិនស្ដាញ", "ស្រីលង្កា", "លីបេរីយ៉ា", "ឡេសូតូ", "លីទុយអានី", "លុចសំបួ", "ឡេតូនី", "លីប៊ី", "ម៉ារ៉ុក", "ម៉ូណាកូ", "ម៉ុលដាវី", "ម៉ុងតេណេហ្គ្រោ", "សាំង\u{200B}ម៉ាទីន", "ម៉ាដាហ្គាស្កា", "កោះ\u{200B}ម៉ាស់សល", "ម៉ាសេដ្វានខាងជើង", "ម៉ាលី", "មីយ៉ាន់ម៉ា (ភូមា)", "ម៉ុងហ្គោលី", "ម៉ាកាវ តំបន់រដ្ឋបាលពិសេសចិន", "កោះ\u{200B}ម៉ារីណា\u{200B}ខាង\u{200B}ជើង", "ម៉ាទីនីក", "ម៉ូរីតានី", "ម៉ុងស៊ែរ៉ា", "ម៉ាល់ត៍", "ម៉ូរីស", "ម៉ាល់ឌីវ", "ម៉ាឡាវី", "ម៉ិកស៊ិក", "ម៉ាឡេស៊ី", "ម៉ូសំប៊ិក", "ណាមីប៊ី", "នូវែល\u{200B}កាឡេដូនី", "នីហ្សេ", "កោះ\u{200B}ណ័រហ្វក់", "នីហ្សេរីយ៉ា", "នីការ៉ាហ្គា", "ហូឡង់", "ន័រវែស", "នេប៉ាល់", "ណូរូ", "ណៀ", "នូវែល\u{200B}សេឡង់", "អូម៉ង់", "ប៉ាណាម៉ា", "ប៉េរូ", "ប៉ូលី\u{200B}ណេស៊ី\u{200B}បារាំង", "ប៉ាពូអាស៊ី\u{200B}នូវែលហ្គីណេ", "ហ្វ៊ីលីពីន", "ប៉ាគីស្ថាន", "ប៉ូឡូញ", "សង់ព្យែរ និង\u{200B}មីគីឡុង", "កោះ\u{200B}ភីតកាន", "ព័រតូរីកូ", "ដែនដីប៉ាឡេស្ទីន", "ព័រទុយហ្កាល់", "ផៅឡូ", "ប៉ារ៉ាហ្គាយ", "កាតា", "តំបន់ជាយអូសេអានី", "រេអុយញ៉ុង", "រូម៉ានី", "សែប៊ី", "រុស្ស៊ី", "រវ៉ាន់ដា", "អារ៉ាប៊ីសាអូឌីត", "កោះ\u{200B}សូឡូម៉ុង", "សីស្ហែល", "ស៊ូដង់", "ស៊ុយអែត", "សិង្ហបុរី", "សង់\u{200B}ហេឡេណា", "ស្លូវេនី", "ស្វាលបាដ និង ហ្សង់ម៉ាយេន", "ស្លូវ៉ាគី", "សៀរ៉ាឡេអូន", "សាន\u{200B}ម៉ារីណូ", "សេណេហ្គាល់", "សូម៉ាលី", "សូរីណាម", "ស៊ូដង់\u{200B}ខាង\u{200B}ត្បូង", "សៅតូម៉េ និង ប្រាំងស៊ីប", "អែលសាល់វ៉ាឌ័រ", "សីង\u{200B}ម៉ាធីន", "ស៊ីរី", "ស្វាស៊ីឡង់", "ទ្រីស្តង់\u{200B}ដា\u{200B}ចូនហា", "កោះ\u{200B}ទួគ និង កៃកូស", "ឆាដ", "ដែនដី\u{200B}បារាំង\u{200B}នៅ\u{200B}ភាគខាងត្បូង", "តូហ្គោ", "ថៃ", "តាហ្ស៊ីគីស្ថាន", "តូខេឡៅ", "ទីម័រលេស្តេ", "តួកម៉េនីស្ថាន", "ទុយនីស៊ី", "តុងហ្គា", "តួកគី", "ទ្រីនីដាត និង\u{200B}តូបាហ្គោ", "ទូវ៉ាលូ", "តៃវ៉ាន់", "តង់សានី", "អ៊ុយក្រែន", "អ៊ូហ្គង់ដា", "កោះ\u{200B}អៅឡាយីង\u{200B}អាមេរិក", "អង្គការសហប្រជាជាតិ", "សហរដ្ឋអាមេរិក", "អ៊ុយរូហ្គាយ", "អ៊ូសបេគីស្ថាន", "បុរី\u{200B}វ៉ាទីកង់", "សាំង\u{200B}វ៉ាំងសង់ និង ហ្គ្រេណាឌីន", "វ៉េណេស៊ុយអេឡា", "កោះ\u{200B}វឺជិន\u{200B}ចក្រភព\u{200B}អង់គ្លេស", "កោះ\u{200B}វឺជីន\u{200B}អាមេរិក", "វៀតណាម", "វ៉ានូទូ", "វ៉ាលីស និង\u{200B}ហ្វូទូណា", "សាម័រ", "Pseudo-Accents", "Pseudo-Bidi", "កូសូវ៉ូ", "យេម៉ែន", "ម៉ាយុត", "អាហ្វ្រិកខាងត្បូង", "សំប៊ី", "ស៊ីមបាវ៉េ"];
+
+// rw
+static R52: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Masedoniya y’Amajyaruguru", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "U Rwanda", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Tonga", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// ky
+static R53: [&str; 294] = ["Дүйнө", "Африка", "Түндүк Америка", "Түштүк Америка", "Океания", "Батыш Африка", "Борбордук Америка", "Чыгыш Африка", "Түндүк Африка", "Борбордук Африка", "Түштүк Африка", "Америка", "Түндүк Америка (чөлкөм)", "Кариб аралдары", "Чыгыш Азия", "Түштүк Азия", "Түштүк-Чыгыш Азия", "Түштүк Европа", "Австралазия", "Меланезия", "Микронезия чөлкөмү", "Полинезия", "Азия", "Борбор Азия", "Батыш Азия", "Европа", "Чыгыш Европа", "Түндүк Европа", "Батыш Европа", "Африка өлкөлөрү", "Латын Америкасы", "Вознесение аралы", "Андорра", "Бириккен Араб Эмираттары", "Афганистан", "Антигуа жана Барбуда", "Ангилья", "Албания", "Армения", "Ангола", "Антарктида", "Аргентина", "Америкалык Самоа", "Австрия", "Австралия", "Аруба", "Аланд аралдары", "Азербайжан", "Босния жана Герцеговина", "Барбадос", "Бангладеш", "Бельгия", "Буркина-Фасо", "Болгария", "Бахрейн", "Бурунди", "Бенин", "Сент Бартелеми", "Бермуд аралдары", "Бруней", "Боливия", "Кариб Нидерланддары", "Бразилия", "Багама аралдары", "Бутан", "Буве аралы", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокос (Килинг) аралдары", "Конго-Киншаса", "Борбордук Африка Республикасы", "Конго-Браззавил", "Швейцария", "Кот-д’Ивуар", "Кук аралдары", "Чили", "Камерун", "Кытай", "Колумбия", "Клиппертон аралы", "саркча (нормандча)", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Рождество аралы", "Кипр", "Чехия", "Германия", "Диего Гарсия", "Джибути", "Дания", "Доминика", "Доминика Республикасы", "Алжир", "Сеута жана Мелилла", "Эквадор", "Эстония", "Египет", "Батыш Сахара", "Эритрея", "Испания", "Эфиопия", "Европа Биримдиги", "Еврозона", "Финляндия", "Фиджи", "Фолкленд аралдары", "Микронезия", "Фарер аралдары", "Франция", "Габон", "Улуу Британия", "Гренада", "Грузия", "Франция Гвианасы", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Экватордук Гвинея", "Греция", "Түштүк Жоржия жана Түштүк Сэндвич аралдары", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Гонконг Кытай ААА", "Херд жана Макдональд аралдары", "Гондурас", "Хорватия", "Гаити", "Венгрия", "Канар аралдары", "Индонезия", "Ирландия", "Израиль", "Мэн аралы", "Индия", "Инди океанындагы Британ территориясы", "Ирак", "Иран", "Исландия", "Италия", "Жерси", "Ямайка", "Иордания", "Жапония", "Кения", "Кыргызстан", "Камбоджа", "Кирибати", "Коморос", "Сент-Китс жана Невис", "Түндүк Корея", "Түштүк Корея", "Кувейт", "Кайман аралдары", "Казакстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова", "Черногория", "Сент-Мартин", "Мадагаскар", "Маршалл аралдары", "Түндүк Македония", "Мали", "Мьянма (Бирма)", "Монголия", "Макао Кытай ААА", "Түндүк Мариана аралдары", "Мартиника", "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдив", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Жаңы Каледония", "Нигер", "Норфолк аралы", "Нигерия", "Никарагуа", "Нидерланд", "Норвегия", "Непал", "Науру", "Ниуэ", "Жаңы Зеландия", "Оман", "Панама", "Перу", "Полинезия (франциялык)", "Папуа-Жаңы Гвинея", "Филиппин", "Пакистан", "Польша", "Сен-Пьер жана Микелон", "Питкэрн аралдары", "Пуэрто-Рико", "Палестина аймактары", "Португалия", "Палау", "Парагвай", "Катар", "Алыскы Океания", "Реюньон", "Румыния", "Сербия", "Россия", "Руанда", "Сауд Арабиясы", "Соломон аралдары", "Сейшел аралдары", "Судан", "Швеция", "Сингапур", "Ыйык Елена", "Словения", "Шпицберген жана Ян-Майен", "Словакия", "Сьерра-Леоне", "Сан Марино", "Сенегал", "Сомали", "Суринам", "Түштүк Судан", "Сан-Томе жана Принсипи", "Эль-Сальвадор", "Синт-Мартен", "Сирия", "Свазиленд", "Тристан-да-Кунья", "Түркс жана Кайкос аралдары", "Чад", "Франциянын Түштүктөгү аймактары", "Того", "Тайланд", "Тажикстан", "Токелау", "Тимор-Лесте", "Түркмөнстан", "Тунис", "Тонга", "Түркия", "Тринидад жана Тобаго", "Тувалу", "Тайвань", "Танзания", "Украина", "Уганда", "АКШнын сырткы аралдары", "БУ", "Кошмо Штаттар", "Уругвай", "Өзбекстан", "Ватикан", "Сент-Винсент жана Гренадиндер", "Венесуэла", "Виргин аралдары (Британия)", "Виргин аралдары (АКШ)", "Вьетнам", "Вануату", "Уоллис жана Футуна", "Самоа", "Жасалма- Акцент", "Жасалма - эки тараптуу", "Косово", "Йемен", "Майотта", "Түштүк-Африка Республикасы", "Замбия", "Зимбабве"];
+
+// ko
+static R54: [&str; 294] = ["세계", "아프리카", "북아메리카", "남아메리카", "오세아니아", "서부 아프리카", "중앙 아메리카", "동부 아프리카", "북부 아프리카", "중부 아프리카", "남부 아프리카", "아메리카 대륙", "북부 아메리카", "카리브 제도", "동아시아", "남아시아", "동남아시아", "남유럽", "오스트랄라시아", "멜라네시아", "미크로네시아 지역", "폴리네시아", "아시아", "중앙 아시아", "서아시아", "유럽", "동유럽", "북유럽", "서유럽", "사하라 사막 이남 아프리카", "라틴 아메리카", "어센션섬", "안도라", "아랍에미리트", "아프가니스탄", "앤티가 바부다", "앵귈라", "알바니아", "아르메니아", "앙골라", "남극 대륙", "아르헨티나", "아메리칸 사모아", "오스트리아", "오스트레일리아", "아루바", "올란드 제도", "아제르바이잔", "보스니아 헤르체고비나", "바베이도스", "방글라데시", "벨기에", "부르키나파소", "불가리아", "바레인", "부룬디", "베냉", "생바르텔레미", "버뮤다", "브루나이", "볼리비아", "네덜란드령 카리브", "브라질", "바하마", "부탄", "부베섬", "보츠와나", "벨라루스", "벨리즈", "캐나다", "코코스 제도", "콩고-킨샤사", "중앙 아프리카 공화국", "콩고-브라자빌", "스위스", "코트디부아르", "쿡 제도", "칠레", "카메룬", "중국", "콜롬비아", "클리퍼턴섬", "사크", "코스타리카", "쿠바", "카보베르데", "퀴라소", "크리스마스섬", "키프로스", "체코", "독일", "디에고 가르시아", "지부티", "덴마크", "도미니카", "도미니카 공화국", "알제리", "세우타 및 멜리야", "에콰도르", "에스토니아", "이집트", "서사하라", "에리트리아", "스페인", "에티오피아", "유럽 연합", "유로존", "핀란드", "피지", "포클랜드 제도", "미크로네시아", "페로 제도", "프랑스", "가봉", "영국", "그레나다", "조지아", "프랑스령 기아나", "건지", "가나", "지브롤터", "그린란드", "감비아", "기니", "과들루프", "적도 기니", "그리스", "사우스조지아 사우스샌드위치 제도", "과테말라", "괌", "기니비사우", "가이아나", "홍콩(중국 특별행정구)", "허드 맥도널드 제도", "온두라스", "크로아티아", "아이티", "헝가리", "카나리아 제도", "인도네시아", "아일랜드", "이스라엘", "맨섬", "인도", "영국령 인도양 지역", "이라크", "이란", "아이슬란드", "이탈리아", "저지", "자메이카", "요르단", "일본", "케냐", "키르기스스탄", "캄보디아", "키리바시", "코모로", "세인트키츠 네비스", "북한", "대한민국", "쿠웨이트", "케이맨 제도", "카자흐스탄", "라오스", "레바논", "세인트루시아", "리히텐슈타인", "스리랑카", "라이베리아", "레소토", "리투아니아", "룩셈부르크", "라트비아", "리비아", "모로코", "모나코", "몰도바", "몬테네그로", "생마르탱", "마다가스카르", "마셜 제도", "북마케도니아", "말리", "미얀마", "몽골", "마카오(중국 특별행정구)", "북마리아나제도", "마르티니크", "모리타니", "몬트세라트", "몰타", "모리셔스", "몰디브", "말라위", "멕시코", "말레이시아", "모잠비크", "나미비아", "뉴칼레도니아", "니제르", "노퍽섬", "나이지리아", "니카라과", "네덜란드", "노르웨이", "네팔", "나우루", "니우에", "뉴질랜드", "오만", "파나마", "페루", "프랑스령 폴리네시아", "파푸아뉴기니", "필리핀", "파키스탄", "폴란드", "생피에르 미클롱", "핏케언 제도", "푸에르토리코", "팔레스타인 지구", "포르투갈", "팔라우", "파라과이", "카타르", "오세아니아 외곽", "레위니옹", "루마니아", "세르비아", "러시아", "르완다", "사우디아라비아", "솔로몬 제도", "세이셸", "수단", "스웨덴", "싱가포르", "세인트헬레나", "슬로베니아", "스발바르제도-얀마웬섬", "슬로바키아", "시에라리온", "산마리노", "세네갈", "소말리아", "수리남", "남수단", "상투메 프린시페", "엘살바도르", "신트마르턴", "시리아", "에스와티니", "트리스탄다쿠나", "터크스 케이커스 제도", "차드", "프랑스령 남방 지역", "토고", "태국", "타지키스탄", "토켈라우", "동티모르", "투르크메니스탄", "튀니지", "통가", "튀르키예", "트리니다드 토바고", "투발루", "대만", "탄자니아", "우크라이나", "우간다", "미국령 해외 제도", "국제연합", "미국", "우루과이", "우즈베키스탄", "바티칸 시국", "세인트빈센트그레나딘", "베네수엘라", "영국령 버진아일랜드", "미국령 버진아일랜드", "베트남", "바누아투", "왈리스-푸투나 제도", "사모아", "유사 억양", "유사 양방향", "코소보", "예멘", "마요트", "남아프리카", "잠비아", "짐바브웨"];
+
+// ku
+static R55: [&str; 294] = ["dinya", "Afrîka", "Amerîkaya Bakur", "Amerîkaya Başûr", "Okyanûsya", "Rojavayê Afrîkayê", "Amerîkaya Navîn", "Rojhilatê Afrîkayê", "Bakurê Afrîkayê", "Afrîkaya Navîn", "Başûrê Afrîkayê", "Amerîka", "Bakurê Amerîkayê", "Karayîb", "Rojhilatê Asyayê", "Başûrê Asyayê", "Başûrrojhilatê Asyayê", "Başûrê Ewropayê", "Awistralasya", "Melanezya", "Herêma Mîkronezyayê", "Polînezya", "Asya", "Asyaya Navîn", "Rojavayê Asyayê", "Ewropa", "Rojhilatê Ewropayê", "Bakurê Ewropayê", "Rojavayê Ewropayê", "Afrîkaya Jêra Sahrayê", "Amerîkaya Latîn", "Girava Ascensionê", "Andorra", "Mîrgehên Erebî yên Yekbûyî", "Efxanistan", "Antîgua û Berbûda", "Anguîla", "Albanya", "Ermenistan", "Angola", "Antarktîka", "Arjantîn", "Samoaya Amerîkanî", "Awistirya", "Awistralya", "Arûba", "Giravên Alandê", "Azerbeycan", "Bosna û Hersek", "Barbados", "Bengladeş", "Belçîka", "Burkîna Faso", "Bulgaristan", "Behreyn", "Bûrûndî", "Bênîn", "Saint Barthelemy", "Bermûda", "Brûney", "Bolîvya", "Holendaya Karayîbê", "Brezîlya", "Bahama", "Bûtan", "Girava Bouvetê", "Botswana", "Belarûs", "Belîze", "Kanada", "Giravên Kokosê (Keeling)", "Kongo - Kînşasa", "Cimhûrîyeta Afrîkaya Navîn", "Kongo - Brazzaville", "Swîsre", "Côte d’Ivoire", "Giravên Cookê", "Şîlî", "Kamerûn", "Çîn", "Kolombîya", "Girava Clippertonê", "Sark", "Kosta Rîka", "Kuba", "Kap Verde", "Curaçao", "Girava Christmasê", "Qibris", "Çekya", "Almanya", "Diego Garcia", "Cîbûtî", "Danîmarka", "Domînîka", "Cimhûrîyeta Domînîkê", "Cezayîr", "Ceuta û Melîla", "Ekwador", "Estonya", "Misir", "Sahraya Rojava", "Erître", "Spanya", "Etîyopya", "Yekîtîya Ewropayê", "Herêma Ewroyê", "Fînlenda", "Fîjî", "Giravên Falklandê", "Mîkronezya", "Giravên Faroeyê", "Fransa", "Gabon", "Qiralîyeta Yekbûyî", "Grenada", "Gurcistan", "Guyanaya Fransî", "Guernsey", "Gana", "Cebelîtariq", "Grînlanda", "Gambîya", "Gîne", "Guadeloupe", "Gîneya Ekwadorê", "Yûnanistan", "Giravên Georgîyaya Başûr û Sandwicha Başûr", "Guatemala", "Guam", "Gîne-Bissau", "Guyana", "Hong Konga HîT ya Çînê", "Giravên Heard û MacDonaldê", "Hondûras", "Xirwatistan", "Haîtî", "Macaristan", "Giravên Kanaryayê", "Endonezya", "Îrlanda", "Îsraîl", "Girava Manê", "Hindistan", "Herêma Okyanûsa Hindî ya Brîtanyayê", "Îraq", "Îran", "Îslanda", "Îtalya", "Jersey", "Jamaîka", "Urdun", "Japonya", "Kenya", "Qirgizistan", "Kamboçya", "Kirîbatî", "Komor", "Saint Kitts û Nevîs", "Koreya Bakur", "Koreya Başûr", "Kuweyt", "Giravên Kaymanê", "Qazaxistan", "Laos", "Libnan", "Saint Lucia", "Liechtenstein", "Srî Lanka", "Lîberya", "Lesoto", "Lîtvanya", "Luksembûrg", "Letonya", "Lîbya", "Fas", "Monako", "Moldova", "Qeredax", "Saint Martin", "Madagaskar", "Giravên Marşalê", "Makedonyaya Bakur", "Malî", "Myanmar (Bûrma)", "Moxolistan", "Makaoya Hît ya Çînê", "Giravên Bakurê Marianan", "Martînîk", "Morîtanya", "Montserat", "Malta", "Mauritius", "Maldîv", "Malawî", "Meksîka", "Malezya", "Mozambîk", "Namîbya", "Kaledonyaya Nû", "Nîjer", "Girava Norfolkê", "Nîjerya", "Nîkaragua", "Holanda", "Norwec", "Nepal", "Naûrû", "Niûe", "Zelandaya Nû", "Uman", "Panama", "Perû", "Polînezyaya Fransizî", "Papua Gîneya Nû", "Fîlîpîn", "Pakistan", "Polonya", "Saint-Pierre û Miquelon", "Giravên Pitcairnê", "Porto Rîko", "Herêmên Filistînî", "Portugal", "Palau", "Paragûay", "Qeter", "Okyanûsyaya Dûr", "Réunion", "Romanya", "Sirbistan", "Rûsya", "Rwanda", "Erebistana Siûdî", "Giravên Solomonê", "Seyşel", "Sûdan", "Swêd", "Sîngapûr", "Saint Helena", "Slovenya", "Svalbard û Jan Mayen", "Slovakya", "Sierra Leone", "San Marîno", "Senegal", "Somalya", "Surînam", "Sûdana Başûr", "Sao Tome û Prînsîpe", "El Salvador", "Sint Marteen", "Sûrîye", "Eswatînî", "Tristan da Cunha", "Giravên Turks û Kaîkosê", "Çad", "Herêmên Başûr ên Fransayê", "Togo", "Tayland", "Tacikistan", "Tokelau", "Tîmor-Leste", "Tirkmenistan", "Tûnis", "Tonga", "Türkiye", "Trînîdad û Tobago", "Tûvalû", "Taywan", "Tanzanya", "Ûkrayna", "Ûganda", "Giravên Biçûk ên Derveyî DAYê", "Miletên Yekbûyî", "Dewletên Amerîkayê yên Yekbûyî", "Ûrûguay", "Ozbekistan", "Vatîkan", "Saint Vincent û Giravên Grenadînê", "Venezuela", "Giravên Vîrjînê yên Brîtanyayê", "Giravên Vîrjînê yên Amerîkayê", "Vîetnam", "Vanûatû", "Wallis û Futuna", "Samoa", "Aksanên Psodoyê", "Psodo Bidî", "Kosova", "Yemen", "Mayotte", "Afrîkaya Başûr", "Zambîya", "Zîmbabwe"];
+
+// lb
+static R56: [&str; 294] = ["Welt", "Afrika", "Nordamerika", "Südamerika", "Ozeanien", "Westafrika", "Mëttelamerika", "Ostafrika", "Nordafrika", "Zentralafrika", "Südlecht Afrika", "Amerika", "Nërdlecht Amerika", "Karibik", "Ostasien", "Südasien", "Südostasien", "Südeuropa", "Australien an Neiséiland", "Melanesien", "Mikronesescht Inselgebitt", "Polynesien", "Asien", "Zentralasien", "Westasien", "Europa", "Osteuropa", "Nordeuropa", "Westeuropa", "", "Latäinamerika", "Ascension", "Andorra", "Vereenegt Arabesch Emirater", "Afghanistan", "Antigua a Barbuda", "Anguilla", "Albanien", "Armenien", "Angola", "Antarktis", "Argentinien", "Amerikanesch-Samoa", "Éisträich", "Australien", "Aruba", "Ålandinselen", "Aserbaidschan", "Bosnien an Herzegowina", "Barbados", "Bangladesch", "Belsch", "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivien", "Karibescht Holland", "Brasilien", "Bahamas", "Bhutan", "Bouvetinsel", "Botsuana", "Wäissrussland", "Belize", "Kanada", "Kokosinselen", "Kongo-Kinshasa", "Zentralafrikanesch Republik", "Kongo-Brazzaville", "Schwäiz", "Côte d’Ivoire", "Cookinselen", "Chile", "Kamerun", "China", "Kolumbien", "Clipperton-Insel", "", "Costa Rica", "Kuba", "Kap Verde", "Curaçao", "Chrëschtdagsinsel", "Zypern", "Tschechien", "Däitschland", "Diego Garcia", "Dschibuti", "Dänemark", "Dominica", "Dominikanesch Republik", "Algerien", "Ceuta a Melilla", "Ecuador", "Estland", "Egypten", "Westsahara", "Eritrea", "Spanien", "Ethiopien", "Europäesch Unioun", "", "Finnland", "Fidschi", "Falklandinselen", "Mikronesien", "Färöer", "Frankräich", "Gabun", "Groussbritannien", "Grenada", "Georgien", "Guayane", "Guernsey", "Ghana", "Gibraltar", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Equatorialguinea", "Griicheland", "Südgeorgien an déi Südlech Sandwichinselen", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Spezialverwaltungszon Hong Kong", "Heard- a McDonald-Inselen", "Honduras", "Kroatien", "Haiti", "Ungarn", "Kanaresch Inselen", "Indonesien", "Irland", "Israel", "Isle of Man", "Indien", "", "Irak", "Iran", "Island", "Italien", "Jersey", "Jamaika", "Jordanien", "Japan", "Kenia", "Kirgisistan", "Kambodscha", "Kiribati", "Komoren", "St. Kitts an Nevis", "Nordkorea", "Südkorea", "Kuwait", "Kaimaninselen", "Kasachstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Lëtzebuerg", "Lettland", "Libyen", "Marokko", "Monaco", "Moldawien", "Montenegro", "St. Martin", "Madagaskar", "Marshallinselen", "Nordmazedonien", "Mali", "Myanmar", "Mongolei", "Spezialverwaltungszon Macau", "Nërdlech Marianen", "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius", "Maldiven", "Malawi", "Mexiko", "Malaysia", "Mosambik", "Namibia", "Neikaledonien", "Niger", "Norfolkinsel", "Nigeria", "Nicaragua", "Holland", "Norwegen", "Nepal", "Nauru", "Niue", "Neiséiland", "Oman", "Panama", "Peru", "Franséisch-Polynesien", "Papua-Neiguinea", "Philippinnen", "Pakistan", "Polen", "St. Pierre a Miquelon", "Pitcairninselen", "Puerto Rico", "Palestinensesch Autonomiegebidder", "Portugal", "Palau", "Paraguay", "Katar", "Baussecht Ozeanien", "Réunion", "Rumänien", "Serbien", "Russland", "Ruanda", "Saudi-Arabien", "Salomonen", "Seychellen", "Sudan", "Schweden", "Singapur", "St. Helena", "Slowenien", "Svalbard a Jan Mayen", "Slowakei", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Südsudan", "São Tomé a Príncipe", "El Salvador", "Sint Maarten", "Syrien", "Swasiland", "Tristan da Cunha", "Turks- a Caicosinselen", "Tschad", "Franséisch Süd- an Antarktisgebidder", "Togo", "Thailand", "Tadschikistan", "Tokelau", "Osttimor", "Turkmenistan", "Tunesien", "Tonga", "Tierkei", "Trinidad an Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukrain", "Uganda", "Amerikanesch-Ozeanien", "", "Vereenegt Staaten", "Uruguay", "Usbekistan", "Vatikanstad", "St. Vincent an d’Grenadinnen", "Venezuela", "Britesch Joffereninselen", "Amerikanesch Joffereninselen", "Vietnam", "Vanuatu", "Wallis a Futuna", "Samoa", "", "", "Kosovo", "Jemen", "Mayotte", "Südafrika", "Sambia", "Simbabwe"];
+
+// lo
+static R57: [&str; 294] = ["ໂລກ", "ອາຟຣິກາ", "ອາເມລິກາເໜືອ", "ອາເມລິກາໃຕ້", "ໂອຊີອານີ", "ອາຟຣິກາຕາເວັນຕົກ", "ອາເມລິກາກາງ", "ອາຟຣິກາຕາເວັນອອກ", "ອາຟຣິກາເໜືອ", "ອາຟຣິກາກາງ", "ອາຟຣິກາຕອນໃຕ້", "ອາເມຣິກາ", "ພາກເໜືອອາເມລີກາ", "ຄາຣິບບຽນ", "ອາຊີຕາເວັນອອກ", "ອາຊີໃຕ້", "ອາຊີຕາເວັນອອກສຽງໃຕ້", "ຢູໂຣບໃຕ້", "ໂອດສະຕາລີ", "ເມລານີເຊຍ", "ເຂດໄມໂຄຣເນຊຽນ", "ໂພລີນີເຊຍ", "ອາຊີ", "ອາຊີກາງ", "ອາຊີຕາເວັນຕົກ", "ຢູໂຣບ", "ຢູໂຣບຕາເວັນອອກ", "ຢູໂຣບເໜືອ", "ຢູໂຣບຕາເວັນຕົກ", "ຊັບ ຊາຮາຣານ ອາຟຣິກາ", "ລາຕິນ ອາເມລິກາ", "ເກາະອາເຊນຊັນ", "ອັນດໍຣາ", "ສະຫະລັດອາຣັບເອມິເຣດ", "ອາຟການິດສະຖານ", "ແອນທິກົວ ແລະ ບາບູດາ", "ແອນກຸຍລາ", "ແອວເບເນຍ", "ອາເມເນຍ", "ແອງໂກລາ", "ແອນຕາດຕິກາ", "ອາເຈນທິນາ", "ອາເມຣິກາ ຊາມົວ", "ອອສເທຣຍ", "ອອສເຕຣເລຍ", "ອາຣູບາ", "ຫມູ່ເກາະໂອລັນ", "ອາເຊີໄບຈານ", "ບອດສະເນຍ ແລະ ແຮສໂກວີນາ", "ບາບາໂດສ", "ບັງກະລາເທດ", "ເບວຢຽມ", "ເບີກິນາ ຟາໂຊ", "ບັງກາເຣຍ", "ບາເຣນ", "ບູຣຸນດິ", "ເບນິນ", "ເຊນ ບາເທເລມີ", "ເບີມິວດາ", "ບຣູໄນ", "ໂບລິເວຍ", "ຄາຣິບບຽນ ເນເທີແລນ", "ບຣາຊິວ", "ບາຮາມາສ", "ພູຖານ", "ເກາະບູເວດ", "ບອດສະວານາ", "ເບລາຣຸສ", "ເບລີຊ", "ແຄນາດາ", "ຫມູ່ເກາະໂກໂກສ", "ຄອງໂກ - ຄິນຊາຊາ", "ສາທາລະນະລັດອາຟຣິກາກາງ", "ຄອງໂກ - ບຣາຊາວິວ", "ສະວິດເຊີແລນ", "ໂຄຕີ ວົວ", "ໝູ່ເກາະຄຸກ", "ຊິລີ", "ຄາເມຣູນ", "ຈີນ", "ໂຄລົມເບຍ", "ເກາະຄລິບເປີຕັນ", "ຊາກຄ໌", "ໂຄສຕາ ຣິກາ", "ຄິວບາ", "ເຄບ ເວີດ", "ຄູຣາຊາວ", "ເກາະຄຣິສມາດ", "ໄຊປຣັສ", "ເຊັກເຊຍ", "ເຢຍລະມັນ", "ດິເອໂກ ກາເຊຍ", "ຈິບູຕິ", "ເດນມາກ", "ໂດມີນິຄາ", "ສາທາລະນະລັດ ໂດມິນິກັນ", "ອັລຈິເຣຍ", "ເຊວຕາ & ເມລິນລາ", "ເອກວາດໍ", "ເອສໂຕເນຍ", "ອີຢິບ", "ຊາຮາຣາຕາເວັນຕົກ", "ເອຣິເທຣຍ", "ສະເປນ", "ອີທິໂອເປຍ", "ສະຫະພາບຢູໂຣບ", "ເຂດຢູໂຣບ", "ຟິນແລນ", "ຟິຈິ", "ຫມູ່ເກາະຟອກແລນ", "ໄມໂຄຣນີເຊຍ", "ຫມູ່ເກາະແຟໂຣ", "ຝຣັ່ງ", "ກາບອນ", "ສະຫະລາດຊະອະນາຈັກ", "ເກຣເນດາ", "ຈໍເຈຍ", "ເຟຣນຊ໌ ກຸຍອານາ", "ເກີນຊີ", "ການາ", "ຈິບບຣອນທາ", "ກຣີນແລນ", "ສາທາລະນະລັດແກມເບຍ", "ກິນີ", "ກົວດາລູບ", "ເອຄົວໂທຣຽວ ກີນີ", "ກຣີຊ", "ໝູ່ເກາະ ຈໍເຈຍຕອນໃຕ້ ແລະ ແຊນວິດຕອນໃຕ້", "ກົວເທມາລາ", "ກວາມ", "ກິນີ-ບິສເຊົາ", "ກາຍຢານາ", "ຮົງກົງ ເຂດປົກຄອງພິເສດ ຈີນ", "ໝູ່ເກາະເຮີດ & ແມັກໂດນອລ", "ຮອນດູຣັສ", "ໂຄຣເອເທຍ", "ໄຮຕິ", "ຮັງກາຣີ", "ໝູ່ເກາະຄານາຣີ", "ອິນໂດເນເຊຍ", "ໄອແລນ", "ອິສຣາເອວ", "ເອວ ອອບ ແມນ", "ອິນເດຍ", "ເຂດແດນອັງກິດໃນມະຫາສະໝຸດອິນເດຍ", "ອີຣັກ", "ອີຣານ", "ໄອສແລນ", "ອິຕາລີ", "ເຈີຊີ", "ຈາໄມຄາ", "ຈໍແດນ", "ຍີ່ປຸ່ນ", "ເຄນຢາ", "ຄຽກກິດສະຖານ", "ກຳປູເຈຍ", "ຄິຣິບາທິ", "ໂຄໂມໂຣສ", "ເຊນ ຄິດ ແລະ ເນວິສ", "ເກົາຫລີເໜືອ", "ເກົາຫລີໃຕ້", "ກູເວດ", "ໝູ່ເກາະ ເຄແມນ", "ຄາຊັກສະຖານ", "ລາວ", "ເລບານອນ", "ເຊນ ລູເຊຍ", "ລິດເທນສະຕາຍ", "ສີລັງກາ", "ລິເບີເຣຍ", "ເລໂຊໂທ", "ລິທົວເນຍ", "ລຸກແຊມເບີກ", "ລັດເວຍ", "ລິເບຍ", "ໂມຣັອກໂຄ", "ໂມນາໂຄ", "ໂມນໂດວາ", "ມອນເຕເນໂກຣ", "ເຊນ ມາທິນ", "ມາດາກາສະກາ", "ຫມູ່ເກາະມາແຊວ", "ແມຊິໂດເນຍເໜືອ", "ມາລີ", "ມຽນມາ (ເບີມາ)", "ມອງໂກເລຍ", "ມາກາວ ເຂດປົກຄອງພິເສດ ຈີນ", "ໝູ່ເກາະມາເຣຍນາຕອນເໜືອ", "ມາຕິນີກ", "ມົວຣິເທເນຍ", "ມອນເຊີຣາດ", "ມອນທາ", "ມົວຣິຊຽສ", "ມັນດິຟ", "ມາລາວີ", "ເມັກຊິໂກ", "ມາເລເຊຍ", "ໂມແຊມບິກ", "ນາມີເບຍ", "ນິວ ຄາເລໂດເນຍ", "ນິເຈີ", "ເກາະນໍໂຟກ", "ໄນຈີເຣຍ", "ນິກຄາຣາກົວ", "ເນເທີແລນ", "ນໍເວ", "ເນປານ", "ນາອູຣູ", "ນີອູເອ", "ນິວຊີແລນ", "ໂອມານ", "ພານາມາ", "ເປຣູ", "ເຟຣນຊ໌ ໂພລິນີເຊຍ", "ປາປົວນິວກີນີ", "ຟິລິບປິນ", "ປາກິດສະຖານ", "ໂປແລນ", "ເຊນ ປີແອ ມິເກວລອນ", "ໝູ່ເກາະພິດແຄນ", "ເພືອໂຕ ຣິໂກ", "ດິນແດນປາເລດສະຕິນ", "ພອລທູໂກ", "ປາລາວ", "ພາຣາກວຍ", "ກາຕາ", "ເຂດຫ່າງໄກໂອຊີເນຍ", "ເຣອູນິຍົງ", "ໂຣແມເນຍ", "ເຊີເບຍ", "ຣັດເຊຍ", "ຣວັນດາ", "ຊາອຸດິ ອາຣາເບຍ", "ຫມູ່ເກາະໂຊໂລມອນ", "ເຊເຊວເລສ", "ຊູດານ", "ສະວີເດັນ", "ສິງກະໂປ", "ເຊນ ເຮເລນາ", "ສະໂລເວເນຍ", "ສະວາບາ ແລະ ແຢນ ມາເຢນ", "ສະໂລວາເກຍ", "ເຊຍຣາ ລີໂອນ", "ແຊນ ມາຣິໂນ", "ເຊນີໂກລ", "ໂຊມາເລຍ", "ຊູຣິນາມ", "ຊູດານໃຕ້", "ເຊົາທູເມ ແລະ ພຣິນຊິບ", "ເອວ ຊໍວາດໍ", "ຊິນ ມາເທັນ", "ຊີເຣຍ", "\u{200B}ເອ\u{200B}ສະ\u{200B}ວາ\u{200B}ຕິ\u{200B}ນີ", "ທຣິສຕັນ ດາ ກັນຮາ", "ໝູ່ເກາະ ເທີກ ແລະ ໄຄໂຄສ", "ຊາດ", "ເຂດແດນທາງໃຕ້ຂອງຝຮັ່ງ", "ໂຕໂກ", "ໄທ", "ທາຈິກິດສະຖານ", "ໂຕເກເລົາ", "ທິມໍ-ເລສເຕ", "ເທີກເມນິສະຖານ", "ຕູນິເຊຍ", "ທອງກາ", "ເທີຄີ", "ທຣິນິແດດ ແລະ ໂທແບໂກ", "ຕູວາລູ", "ໄຕ້ຫວັນ", "ທານຊາເນຍ", "ຢູເຄຣນ", "ອູການດາ", "ໝູ່ເກາະຮອບນອກຂອງສະຫະລັດຯ", "ສະຫະປະຊາຊາດ", "ສະຫະລັດ", "ອູຣຸກວຍ", "ອຸສເບກິສະຖານ", "ນະຄອນ ວາຕິກັນ", "ເຊນ ວິນເຊນ ແລະ ເກຣເນດິນ", "ເວເນຊູເອລາ", "ໝູ່ເກາະ ເວີຈິນຂອງອັງກິດ", "ໝູ່ເກາະ ເວີຈິນ ຂອງສະຫະລັດ", "ຫວຽດນາມ", "ວານົວຕູ", "ວາລລິສ ແລະ ຟູຕູນາ", "ຊາມົວ", "ສຳນຽງຊູໂດ", "ຊູໂດບີດີ", "ໂຄໂຊໂວ", "ເຢເມນ", "ມາຢັອດ", "ອາຟຣິກາໃຕ້", "ແຊມເບຍ", "ຊິມບັບເວ"];
+
+// lt
+static R58: [&str; 294] = ["pasaulis", "Afrika", "Šiaurės Amerika", "Pietų Amerika", "Okeanija", "Vakarų Afrika", "Centrinė Amerika", "Rytų Afrika", "Šiaurės Afrika", "Vidurio Afrika", "Pietinė Afrika", "Amerika", "Šiaurinė Amerika", "Karibai", "Rytų Azija", "Pietų Azija", "Pietryčių Azija", "Pietų Europa", "Australazija", "Melanezija", "Mikronezijos regionas", "Polinezija", "Azija", "Centrinė Azija", "Vakarų Azija", "Europa", "Rytų Europa", "Šiaurės Europa", "Vakarų Europa", "Užsachario Afrika", "Lotynų Amerika", "Dangun Žengimo sala", "Andora", "Jungtiniai Arabų Emyratai", "Afganistanas", "Antigva ir Barbuda", "Angilija", "Albanija", "Armėnija", "Angola", "Antarktida", "Argentina", "Amerikos Samoa", "Austrija", "Australija", "Aruba", "Alandų Salos", "Azerbaidžanas", "Bosnija ir Hercegovina", "Barbadosas", "Bangladešas", "Belgija", "Burkina Fasas", "Bulgarija", "Bahreinas", "Burundis", "Beninas", "Sen Bartelemi", "Bermuda", "Brunėjus", "Bolivija", "Karibų Nyderlandai", "Brazilija", "Bahamos", "Butanas", "Buvė Sala", "Botsvana", "Baltarusija", "Belizas", "Kanada", "Kokosų (Kilingo) Salos", "Kongas-Kinšasa", "Centrinės Afrikos Respublika", "Kongas-Brazavilis", "Šveicarija", "Dramblio Kaulo Krantas", "Kuko Salos", "Čilė", "Kamerūnas", "Kinija", "Kolumbija", "Klipertono sala", "Sarkas", "Kosta Rika", "Kuba", "Žaliasis Kyšulys", "Kiurasao", "Kalėdų Sala", "Kipras", "Čekija", "Vokietija", "Diego Garsija", "Džibutis", "Danija", "Dominika", "Dominikos Respublika", "Alžyras", "Seuta ir Melila", "Ekvadoras", "Estija", "Egiptas", "Vakarų Sachara", "Eritrėja", "Ispanija", "Etiopija", "Europos Sąjunga", "euro zona", "Suomija", "Fidžis", "Folklando salos", "Mikronezija", "Farerų Salos", "Prancūzija", "Gabonas", "Jungtinė Karalystė", "Grenada", "Gruzija", "Prancūzijos Gviana", "Gernsis", "Gana", "Gibraltaras", "Grenlandija", "Gambija", "Gvinėja", "Gvadelupa", "Pusiaujo Gvinėja", "Graikija", "Pietų Džordžija ir Pietų Sandvičo salos", "Gvatemala", "Guamas", "Bisau Gvinėja", "Gajana", "Ypatingasis Administracinis Kinijos Regionas Honkongas", "Herdo ir Makdonaldo Salos", "Hondūras", "Kroatija", "Haitis", "Vengrija", "Kanarų salos", "Indonezija", "Airija", "Izraelis", "Meno Sala", "Indija", "Indijos Vandenyno Britų Sritis", "Irakas", "Iranas", "Islandija", "Italija", "Džersis", "Jamaika", "Jordanija", "Japonija", "Kenija", "Kirgizija", "Kambodža", "Kiribatis", "Komorai", "Sent Kitsas ir Nevis", "Šiaurės Korėja", "Pietų Korėja", "Kuveitas", "Kaimanų Salos", "Kazachstanas", "Laosas", "Libanas", "Sent Lusija", "Lichtenšteinas", "Šri Lanka", "Liberija", "Lesotas", "Lietuva", "Liuksemburgas", "Latvija", "Libija", "Marokas", "Monakas", "Moldova", "Juodkalnija", "Sen Martenas", "Madagaskaras", "Maršalo Salos", "Šiaurės Makedonija", "Malis", "Mianmaras (Birma)", "Mongolija", "Ypatingasis Administracinis Kinijos Regionas Makao", "Marianos Šiaurinės Salos", "Martinika", "Mauritanija", "Montseratas", "Malta", "Mauricijus", "Maldyvai", "Malavis", "Meksika", "Malaizija", "Mozambikas", "Namibija", "Naujoji Kaledonija", "Nigeris", "Norfolko sala", "Nigerija", "Nikaragva", "Nyderlandai", "Norvegija", "Nepalas", "Nauru", "Niujė", "Naujoji Zelandija", "Omanas", "Panama", "Peru", "Prancūzijos Polinezija", "Papua Naujoji Gvinėja", "Filipinai", "Pakistanas", "Lenkija", "Sen Pjeras ir Mikelonas", "Pitkerno salos", "Puerto Rikas", "Palestinos teritorija", "Portugalija", "Palau", "Paragvajus", "Kataras", "Nuošali Okeanija", "Reunjonas", "Rumunija", "Serbija", "Rusija", "Ruanda", "Saudo Arabija", "Saliamono Salos", "Seišeliai", "Sudanas", "Švedija", "Singapūras", "Šv. Elenos Sala", "Slovėnija", "Svalbardas ir Janas Majenas", "Slovakija", "Siera Leonė", "San Marinas", "Senegalas", "Somalis", "Surinamas", "Pietų Sudanas", "San Tomė ir Prinsipė", "Salvadoras", "Sint Martenas", "Sirija", "Svazilandas", "Tristano da Kunjos", "Terkso ir Kaikoso Salos", "Čadas", "Prancūzijos Pietų sritys", "Togas", "Tailandas", "Tadžikija", "Tokelau", "Rytų Timoras", "Turkmėnistanas", "Tunisas", "Tonga", "Turkija", "Trinidadas ir Tobagas", "Tuvalu", "Taivanas", "Tanzanija", "Ukraina", "Uganda", "Jungtinių Valstijų Mažosios Tolimosios Salos", "Jungtinės Tautos", "Jungtinės Valstijos", "Urugvajus", "Uzbekistanas", "Vatikano Miesto Valstybė", "Šventasis Vincentas ir Grenadinai", "Venesuela", "Didžiosios Britanijos Mergelių Salos", "Jungtinių Valstijų Mergelių Salos", "Vietnamas", "Vanuatu", "Volisas ir Futūna", "Samoa", "pseudo A", "pseudo B", "Kosovas", "Jemenas", "Majotas", "Pietų Afrika", "Zambija", "Zimbabvė"];
+
+// lv
+static R59: [&str; 294] = ["pasaule", "Āfrika", "Ziemeļamerika", "Dienvidamerika", "Okeānija", "Rietumāfrika", "Centrālamerika", "Austrumāfrika", "Ziemeļāfrika", "Vidusāfrika", "Dienvidāfrika", "Amerika", "Amerikas ziemeļu daļa", "Karību jūras reģions", "Austrumāzija", "Dienvidāzija", "Centrālaustrumāzija", "Dienvideiropa", "Austrālāzija", "Melanēzija", "Mikronēzijas reģions", "Polinēzija", "Āzija", "Centrālāzija", "Rietumāzija", "Eiropa", "Austrumeiropa", "Ziemeļeiropa", "Rietumeiropa", "Subsahāras Āfrika", "Latīņamerika", "Debesbraukšanas sala", "Andora", "Apvienotie Arābu Emirāti", "Afganistāna", "Antigva un Barbuda", "Angilja", "Albānija", "Armēnija", "Angola", "Antarktika", "Argentīna", "ASV Samoa", "Austrija", "Austrālija", "Aruba", "Olandes salas", "Azerbaidžāna", "Bosnija un Hercegovina", "Barbadosa", "Bangladeša", "Beļģija", "Burkinafaso", "Bulgārija", "Bahreina", "Burundija", "Benina", "Senbartelmī", "Bermudu salas", "Bruneja", "Bolīvija", "Nīderlandes Karību salas", "Brazīlija", "Bahamu salas", "Butāna", "Buvē sala", "Botsvāna", "Baltkrievija", "Beliza", "Kanāda", "Kokosu (Kīlinga) salas", "Kongo (Kinšasa)", "Centrālāfrikas Republika", "Kongo (Brazavila)", "Šveice", "Kotdivuāra", "Kuka salas", "Čīle", "Kamerūna", "Ķīna", "Kolumbija", "Klipertona sala", "Sarka", "Kostarika", "Kuba", "Kaboverde", "Kirasao", "Ziemsvētku sala", "Kipra", "Čehija", "Vācija", "Djego Garsijas atols", "Džibutija", "Dānija", "Dominika", "Dominikāna", "Alžīrija", "Seūta un Melilja", "Ekvadora", "Igaunija", "Ēģipte", "Rietumsahāra", "Eritreja", "Spānija", "Etiopija", "Eiropas Savienība", "Eirozona", "Somija", "Fidži", "Folklenda salas", "Mikronēzija", "Fēru salas", "Francija", "Gabona", "Apvienotā Karaliste", "Grenāda", "Gruzija", "Francijas Gviāna", "Gērnsija", "Gana", "Gibraltārs", "Grenlande", "Gambija", "Gvineja", "Gvadelupa", "Ekvatoriālā Gvineja", "Grieķija", "Dienviddžordžija un Dienvidsendviču salas", "Gvatemala", "Guama", "Gvineja-Bisava", "Gajāna", "Ķīnas īpašās pārvaldes apgabals Honkonga", "Hērda sala un Makdonalda salas", "Hondurasa", "Horvātija", "Haiti", "Ungārija", "Kanāriju salas", "Indonēzija", "Īrija", "Izraēla", "Menas sala", "Indija", "Indijas okeāna Britu teritorija", "Irāka", "Irāna", "Islande", "Itālija", "Džērsija", "Jamaika", "Jordānija", "Japāna", "Kenija", "Kirgizstāna", "Kambodža", "Kiribati", "Komoru salas", "Sentkitsa un Nevisa", "Ziemeļkoreja", "Dienvidkoreja", "Kuveita", "Kaimanu salas", "Kazahstāna", "Laosa", "Libāna", "Sentlūsija", "Lihtenšteina", "Šrilanka", "Libērija", "Lesoto", "Lietuva", "Luksemburga", "Latvija", "Lībija", "Maroka", "Monako", "Moldova", "Melnkalne", "Senmartēna", "Madagaskara", "Māršala salas", "Ziemeļmaķedonija", "Mali", "Mjanma (Birma)", "Mongolija", "ĶTR īpašais administratīvais reģions Makao", "Ziemeļu Marianas salas", "Martinika", "Mauritānija", "Montserrata", "Malta", "Maurīcija", "Maldīvija", "Malāvija", "Meksika", "Malaizija", "Mozambika", "Namībija", "Jaunkaledonija", "Nigēra", "Norfolkas sala", "Nigērija", "Nikaragva", "Nīderlande", "Norvēģija", "Nepāla", "Nauru", "Niue", "Jaunzēlande", "Omāna", "Panama", "Peru", "Francijas Polinēzija", "Papua-Jaungvineja", "Filipīnas", "Pakistāna", "Polija", "Senpjēra un Mikelona", "Pitkērnas salas", "Puertoriko", "Palestīnas teritorijas", "Portugāle", "Palau", "Paragvaja", "Katara", "Okeānijas attālās salas", "Reinjona", "Rumānija", "Serbija", "Krievija", "Ruanda", "Saūda Arābija", "Zālamana salas", "Seišelu salas", "Sudāna", "Zviedrija", "Singapūra", "Sv.Helēnas sala", "Slovēnija", "Svalbāra un Jana Majena sala", "Slovākija", "Sjerraleone", "Sanmarīno", "Senegāla", "Somālija", "Surinama", "Dienvidsudāna", "Santome un Prinsipi", "Salvadora", "Sintmārtena", "Sīrija", "Svatini", "Tristana da Kuņjas salu teritorijas", "Tērksas un Kaikosas salas", "Čada", "Francijas Dienvidjūru teritorija", "Togo", "Taizeme", "Tadžikistāna", "Tokelau", "Austrumtimora", "Turkmenistāna", "Tunisija", "Tonga", "Turcija", "Trinidāda un Tobāgo", "Tuvalu", "Taivāna", "Tanzānija", "Ukraina", "Uganda", "ASV Mazās Aizjūras salas", "Apvienoto Nāciju Organizācija", "Amerikas Savienotās Valstis", "Urugvaja", "Uzbekistāna", "Vatikāns", "Sentvinsenta un Grenadīnas", "Venecuēla", "Britu Virdžīnas", "ASV Virdžīnas", "Vjetnama", "Vanuatu", "Volisa un Futunas salas", "Samoa", "pseidoakcenti", "pseidodivvirzienu", "Kosova", "Jemena", "Majota", "Dienvidāfrikas Republika", "Zambija", "Zimbabve"];
+
+// mk
+static R60: [&str; 294] = ["Свет", "Африка", "Северна Америка", "Јужна Америка", "Океанија", "Западна Африка", "Централна Америка", "Источна Африка", "Северна Африка", "Средна Африка", "Јужна Африка", "Америки", "Северна континентална Америка", "Кариби", "Источна Азија", "Јужна Азија", "Југоисточна Азија", "Јужна Европа", "Австралазија", "Меланезија", "Микронезиски регион", "Полинезија", "Азија", "Централна Азија", "Западна Азија", "Европа", "Источна Европа", "Северна Европа", "Западна Европа", "Супсахарска Африка", "Латинска Америка", "Остров Асенсион", "Андора", "Обединети Арапски Емирати", "Авганистан", "Антига и Барбуда", "Ангвила", "Албанија", "Ерменија", "Ангола", "Антарктик", "Аргентина", "Американска Самоа", "Австрија", "Австралија", "Аруба", "Оландски Острови", "Азербејџан", "Босна и Херцеговина", "Барбадос", "Бангладеш", "Белгија", "Буркина Фасо", "Бугарија", "Бахреин", "Бурунди", "Бенин", "Свети Вартоломеј", "Бермуди", "Брунеј", "Боливија", "Карипска Холандија", "Бразил", "Бахами", "Бутан", "Остров Буве", "Боцвана", "Белорусија", "Белизе", "Канада", "Кокосови (Килиншки) Острови", "Конго - Киншаса", "Централноафриканска Република", "Конго - Бразавил", "Швајцарија", "Брегот на Слоновата Коска", "Кукови Острови", "Чиле", "Камерун", "Кина", "Колумбија", "Остров Клипертон", "Сарк", "Костарика", "Куба", "Кабо Верде", "Курасао", "Божиќен Остров", "Кипар", "Чешка", "Германија", "Диего Гарсија", "Џибути", "Данска", "Доминика", "Доминиканска Република", "Алжир", "Сеута и Мелиља", "Еквадор", "Естонија", "Египет", "Западна Сахара", "Еритреја", "Шпанија", "Етиопија", "Европска унија", "Еврозона", "Финска", "Фиџи", "Фолкландски Острови", "Микронезија", "Фарски Острови", "Франција", "Габон", "Обединето Кралство", "Гренада", "Грузија", "Француска Гвајана", "Гернзи", "Гана", "Гибралтар", "Гренланд", "Гамбија", "Гвинеја", "Гвадалупе", "Екваторска Гвинеја", "Грција", "Јужна Џорџија и Јужни Сендвички Острови", "Гватемала", "Гуам", "Гвинеја Бисао", "Гвајана", "Хонгконг САР Кина", "Остров Херд и Острови Мекдоналд", "Хондурас", "Хрватска", "Хаити", "Унгарија", "Канарски Острови", "Индонезија", "Ирска", "Израел", "Остров Ман", "Индија", "Британска Индоокеанска Територија", "Ирак", "Иран", "Исланд", "Италија", "Џерси", "Јамајка", "Јордан", "Јапонија", "Кенија", "Киргистан", "Камбоџа", "Кирибати", "Коморски Острови", "Свети Китс и Невис", "Северна Кореја", "Јужна Кореја", "Кувајт", "Кајмански Острови", "Казахстан", "Лаос", "Либан", "Сент Лусија", "Лихтенштајн", "Шри Ланка", "Либерија", "Лесото", "Литванија", "Луксембург", "Латвија", "Либија", "Мароко", "Монако", "Молдавија", "Црна Гора", "Сент Мартин", "Мадагаскар", "Маршалски Острови", "Северна Македонија", "Мали", "Мјанмар (Бурма)", "Монголија", "Макао САР", "Северни Маријански Острови", "Мартиник", "Мавританија", "Монсерат", "Малта", "Маврициус", "Малдиви", "Малави", "Мексико", "Малезија", "Мозамбик", "Намибија", "Нова Каледонија", "Нигер", "Норфолшки Остров", "Нигерија", "Никарагва", "Холандија", "Норвешка", "Непал", "Науру", "Ниује", "Нов Зеланд", "Оман", "Панама", "Перу", "Француска Полинезија", "Папуа Нова Гвинеја", "Филипини", "Пакистан", "Полска", "Сент Пјер и Микелан", "Питкернски Острови", "Порторико", "Палестински Територии", "Португалија", "Палау", "Парагвај", "Катар", "Зависни земји во Океанија", "Рејунион", "Романија", "Србија", "Русија", "Руанда", "Саудиска Арабија", "Соломонски Острови", "Сејшели", "Судан", "Шведска", "Сингапур", "Света Елена", "Словенија", "Свалбард и Јан Мајен", "Словачка", "Сиера Леоне", "Сан Марино", "Сенегал", "Сомалија", "Суринам", "Јужен Судан", "Саун Томе и Принсип", "Ел Салвадор", "Свети Мартин", "Сирија", "Есватини", "Тристан да Куња", "Острови Туркс и Каикос", "Чад", "Француски Јужни Територии", "Того", "Тајланд", "Таџикистан", "Токелау", "Тимор Лесте", "Туркменистан", "Тунис", "Тонга", "Турција", "Тринидад и Тобаго", "Тувалу", "Тајван", "Танзанија", "Украина", "Уганда", "Американски територии во Пацификот", "Обединети нации", "Соединети Американски Држави", "Уругвај", "Узбекистан", "Ватикан", "Сент Винсент и Гренадини", "Венецуела", "Британски Девствени Острови", "Американски Девствени Острови", "Виетнам", "Вануату", "Валис и Футуна", "Самоа", "псевдо-акценти", "псевдо-биди", "Косово", "Јемен", "Мајот", "Јужноафриканска Република", "Замбија", "Зимбабве"];
+
+// ms
+static R61: [&str; 294] = ["Dunia", "Afrika", "Amerika Utara", "Amerika Selatan", "Oceania", "Afrika Barat", "Amerika Tengah", "Afrika Timur", "Afrika Utara", "Afrika Tengah", "Selatan Afrika", "Amerika", "Utara Amerika", "Caribbean", "Asia Timur", "Asia Selatan", "Asia Tenggara", "Eropah Selatan", "Australasia", "Melanesia", "Wilayah Mikronesia", "Polinesia", "Asia", "Asia Tengah", "Asia Barat", "Eropah", "Eropah Timur", "Eropah Utara", "Eropah Barat", "Afrika Sub-Sahara", "Amerika Latin", "Pulau Ascension", "Andorra", "Emiriah Arab Bersatu", "Afghanistan", "Antigua dan Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antartika", "Argentina", "Samoa Amerika", "Austria", "Australia", "Aruba", "Kepulauan Aland", "Azerbaijan", "Bosnia dan Herzegovina", "Barbados", "Bangladesh", "Belgium", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "St. Barthelemy", "Bermuda", "Brunei", "Bolivia", "Belanda Caribbean", "Brazil", "Bahamas", "Bhutan", "Pulau Bouvet", "Botswana", "Belarus", "Belize", "Kanada", "Kepulauan Cocos (Keeling)", "Congo - Kinshasa", "Republik Afrika Tengah", "Congo - Brazzaville", "Switzerland", "Cote d’Ivoire", "Kepulauan Cook", "Chile", "Cameroon", "China", "Colombia", "Pulau Clipperton", "Sark", "Costa Rica", "Cuba", "Cape Verde", "Curacao", "Pulau Krismas", "Cyprus", "Czechia", "Jerman", "Diego Garcia", "Djibouti", "Denmark", "Dominica", "Republik Dominica", "Algeria", "Ceuta dan Melilla", "Ecuador", "Estonia", "Mesir", "Sahara Barat", "Eritrea", "Sepanyol", "Ethiopia", "Kesatuan Eropah", "Zon Euro", "Finland", "Fiji", "Kepulauan Falkland", "Micronesia", "Kepulauan Faroe", "Perancis", "Gabon", "United Kingdom", "Grenada", "Georgia", "Guiana Perancis", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Guinea Khatulistiwa", "Greece", "Kepulauan Georgia Selatan & Sandwich Selatan", "Guatemala", "Guam", "Guinea Bissau", "Guyana", "Hong Kong SAR China", "Kepulauan Heard & McDonald", "Honduras", "Croatia", "Haiti", "Hungary", "Kepulauan Canary", "Indonesia", "Ireland", "Israel", "Isle of Man", "India", "Wilayah Lautan Hindi British", "Iraq", "Iran", "Iceland", "Itali", "Jersey", "Jamaica", "Jordan", "Jepun", "Kenya", "Kyrgyzstan", "Kemboja", "Kiribati", "Comoros", "Saint Kitts dan Nevis", "Korea Utara", "Korea Selatan", "Kuwait", "Kepulauan Cayman", "Kazakhstan", "Laos", "Lubnan", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lithuania", "Luxembourg", "Latvia", "Libya", "Maghribi", "Monaco", "Moldova", "Montenegro", "Saint Martin", "Madagaskar", "Kepulauan Marshall", "Macedonia Utara", "Mali", "Myanmar (Burma)", "Mongolia", "Macau SAR China", "Kepulauan Mariana Utara", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldives", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger", "Pulau Norfolk", "Nigeria", "Nicaragua", "Belanda", "Norway", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "Polinesia Perancis", "Papua New Guinea", "Filipina", "Pakistan", "Poland", "Saint Pierre dan Miquelon", "Kepulauan Pitcairn", "Puerto Rico", "Wilayah Palestin", "Portugal", "Palau", "Paraguay", "Qatar", "Oceania Terpencil", "Reunion", "Romania", "Serbia", "Rusia", "Rwanda", "Arab Saudi", "Kepulauan Solomon", "Seychelles", "Sudan", "Sweden", "Singapura", "Saint Helena", "Slovenia", "Svalbard dan Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sudan Selatan", "Sao Tome dan Principe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Kepulauan Turks dan Caicos", "Chad", "Wilayah Selatan Perancis", "Togo", "Thailand", "Tajikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Turkiye", "Trinidad dan Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "Kepulauan Terpencil A.S.", "Bangsa-bangsa Bersatu", "Amerika Syarikat", "Uruguay", "Uzbekistan", "Kota Vatican", "Saint Vincent dan Grenadines", "Venezuela", "Kepulauan Virgin British", "Kepulauan Virgin A.S.", "Vietnam", "Vanuatu", "Wallis dan Futuna", "Samoa", "Aksen Pseudo", "Bidi Pseudo", "Kosovo", "Yaman", "Mayotte", "Afrika Selatan", "Zambia", "Zimbabwe"];
+
+// ml
+static R62: [&str; 294] = ["ലോകം", "ആഫ്രിക്ക", "വടക്കേ അമേരിക്ക", "തെക്കേ അമേരിക്ക", "ഓഷ്യാനിയ", "പശ്ചിമ ആഫ്രിക്ക", "മദ്ധ്യ അമേരിക്ക", "കിഴക്കൻ ആഫ്രിക്ക", "ഉത്തരാഫ്രിക്ക", "മദ്ധ്യ ആഫ്രിക്ക", "തെക്കേ ആഫ്രിക്ക", "അമേരിക്കകൾ", "വടക്കൻ അമേരിക്ക", "കരീബിയൻ", "കിഴക്കൻ ഏഷ്യ", "തെക്കേ ഏഷ്യ", "തെക്ക്-കിഴക്കൻ ഏഷ്യ", "തെക്കേ യൂറോപ്പ്", "ഓസ്\u{200C}ട്രേലിയയും ന്യൂസിലാൻഡും", "മെലനേഷ്യ", "മൈക്രോനേഷ്യൻ പ്രദേശം", "പോളിനേഷ്യ", "ഏഷ്യ", "മദ്ധ്യേഷ്യ", "പശ്ചിമേഷ്യ", "യൂറോപ്പ്", "കിഴക്കൻ യൂറോപ്പ്", "വടക്കേ യൂറോപ്പ്", "പശ്ചിമ യൂറോപ്പ്", "സബ്-സഹാറൻ ആഫ്രിക്ക", "ലാറ്റിനമേരിക്ക", "അസൻഷൻ ദ്വീപ്", "അൻഡോറ", "യുണൈറ്റഡ് അറബ് എമിറൈറ്റ്\u{200C}സ്", "അഫ്\u{200C}ഗാനിസ്ഥാൻ", "ആൻറിഗ്വയും ബർബുഡയും", "ആൻഗ്വില്ല", "അൽബേനിയ", "അർമേനിയ", "അംഗോള", "അന്റാർട്ടിക്ക", "അർജന്റീന", "അമേരിക്കൻ സമോവ", "ഓസ്ട്രിയ", "ഓസ്\u{200C}ട്രേലിയ", "അറൂബ", "അലൻഡ് ദ്വീപുകൾ", "അസർബൈജാൻ", "ബോസ്നിയയും ഹെർസഗോവിനയും", "ബാർബഡോസ്", "ബംഗ്ലാദേശ്", "ബെൽജിയം", "ബർക്കിന ഫാസോ", "ബൾഗേറിയ", "ബഹ്റിൻ", "ബറുണ്ടി", "ബെനിൻ", "സെന്റ് ബാർത്തലമി", "ബർമുഡ", "ബ്രൂണൈ", "ബൊളീവിയ", "കരീബിയൻ നെതർലാൻഡ്സ്", "ബ്രസീൽ", "ബഹാമാസ്", "ഭൂട്ടാൻ", "ബൗവെട്ട് ദ്വീപ്", "ബോട്സ്വാന", "ബെലറൂസ്", "ബെലീസ്", "കാനഡ", "കോക്കസ് (കീലിംഗ്) ദ്വീപുകൾ", "കോംഗോ - കിൻഷാസ", "സെൻട്രൽ ആഫ്രിക്കൻ റിപ്പബ്ലിക്ക്", "കോംഗോ - ബ്രാസവില്ലി", "സ്വിറ്റ്സർലാൻഡ്", "കോട്ട് ഡി വാർ", "കുക്ക് ദ്വീപുകൾ", "ചിലി", "കാമറൂൺ", "ചൈന", "കൊളംബിയ", "ക്ലിപ്പെർട്ടൻ ദ്വീപ്", "സാർക്ക്", "കോസ്റ്ററിക്ക", "ക്യൂബ", "കേപ്പ് വേർഡ്", "കുറാകാവോ", "ക്രിസ്മസ് ദ്വീപ്", "സൈപ്രസ്", "ചെക്കിയ", "ജർമ്മനി", "ഡീഗോ ഗ്രാഷ്യ", "ജിബൂത്തി", "ഡെൻമാർക്ക്", "ഡൊമിനിക്ക", "ഡൊമിനിക്കൻ റിപ്പബ്ലിക്ക്", "അൾജീരിയ", "സെയൂത്ത ആൻഡ് മെലിയ", "ഇക്വഡോർ", "എസ്റ്റോണിയ\u{200D}", "ഈജിപ്ത്", "പശ്ചിമ സഹാറ", "എറിത്രിയ", "സ്\u{200C}പെയിൻ", "എത്യോപ്യ", "യൂറോപ്യൻ യൂണിയൻ", "യൂറോസോൺ", "ഫിൻലാൻഡ്", "ഫിജി", "ഫാക്ക്\u{200C}ലാന്റ് ദ്വീപുകൾ", "മൈക്രോനേഷ്യ", "ഫറോ ദ്വീപുകൾ", "ഫ്രാൻസ്", "ഗാബൺ", "യുണൈറ്റഡ് കിംഗ്ഡം", "ഗ്രനേഡ", "ജോർജ്ജിയ", "ഫ്രഞ്ച് ഗയാന", "ഗേൺസി", "ഘാന", "ജിബ്രാൾട്ടർ", "ഗ്രീൻലൻഡ്", "ഗാംബിയ", "ഗിനിയ", "ഗ്വാഡലൂപ്പ്", "ഇക്വറ്റോറിയൽ ഗിനിയ", "ഗ്രീസ്", "ദക്ഷിണ ജോർജ്ജിയയും ദക്ഷിണ സാൻഡ്\u{200C}വിച്ച് ദ്വീപുകളും", "ഗ്വാട്ടിമാല", "ഗ്വാം", "ഗിനിയ-ബിസൗ", "ഗയാന", "ഹോങ്കോങ് (SAR) ചൈന", "ഹേർഡ്, മക്ഡോണൾഡ് ദ്വീപുകൾ", "ഹോണ്ടുറാസ്", "ക്രൊയേഷ്യ", "ഹെയ്തി", "ഹംഗറി", "കാനറി ദ്വീപുകൾ", "ഇന്തോനേഷ്യ", "അയർലൻഡ്", "ഇസ്രായേൽ", "ഐൽ ഓഫ് മാൻ", "ഇന്ത്യ", "ബ്രിട്ടീഷ് ഇന്ത്യൻ ഓഷ്യൻ ടെറിട്ടറി", "ഇറാഖ്", "ഇറാൻ", "ഐസ്\u{200C}ലാന്റ്", "ഇറ്റലി", "ജേഴ്സി", "ജമൈക്ക", "ജോർദ്ദാൻ", "ജപ്പാൻ", "കെനിയ", "കിർഗിസ്ഥാൻ", "കംബോഡിയ", "കിരിബാട്ടി", "കോമൊറോസ്", "സെന്റ് കിറ്റ്\u{200C}സും നെവിസും", "ഉത്തരകൊറിയ", "ദക്ഷിണകൊറിയ", "കുവൈറ്റ്", "കേയ്മാൻ ദ്വീപുകൾ", "കസാഖിസ്ഥാൻ", "ലാവോസ്", "ലെബനൻ", "സെന്റ് ലൂസിയ", "ലിച്ചൺസ്റ്റൈൻ", "ശ്രീലങ്ക", "ലൈബീരിയ", "ലെസോതോ", "ലിത്വാനിയ", "ലക്സംബർഗ്", "ലാറ്റ്വിയ", "ലിബിയ", "മൊറോക്കൊ", "മൊണാക്കോ", "മൾഡോവ", "മോണ്ടെനെഗ്രോ", "സെന്റ് മാർട്ടിൻ", "മഡഗാസ്കർ", "മാർഷൽ ദ്വീപുകൾ", "നോർത്ത് മാസിഡോണിയ", "മാലി", "മ്യാൻമാർ (ബർമ്മ)", "മംഗോളിയ", "മക്കാവു എസ്.എ.ആർ. ചൈന", "ഉത്തര മറിയാനാ ദ്വീപുകൾ", "മാർട്ടിനിക്ക്", "മൗറിറ്റാനിയ", "മൊണ്ടെസരത്ത്", "മാൾട്ട", "മൗറീഷ്യസ്", "മാലിദ്വീപ്", "മലാവി", "മെക്സിക്കോ", "മലേഷ്യ", "മൊസാംബിക്ക്", "നമീബിയ", "ന്യൂ കാലിഡോണിയ", "നൈജർ", "നോർഫോക് ദ്വീപ്", "നൈജീരിയ", "നിക്കരാഗ്വ", "നെതർലാൻഡ്\u{200C}സ്", "നോർവെ", "നേപ്പാൾ", "നൗറു", "ന്യൂയി", "ന്യൂസിലൻഡ്", "ഒമാൻ", "പനാമ", "പെറു", "ഫ്രഞ്ച് പോളിനേഷ്യ", "പാപ്പുവ ന്യൂ ഗിനിയ", "ഫിലിപ്പീൻസ്", "പാക്കിസ്ഥാൻ", "പോളണ്ട്", "സെന്റ് പിയറി ആൻഡ് മിക്വലൻ", "പിറ്റ്\u{200C}കെയ്\u{200C}ൻ ദ്വീപുകൾ", "പോർട്ടോ റിക്കോ", "പാലസ്\u{200C}തീൻ പ്രദേശങ്ങൾ", "പോർച്ചുഗൽ", "പലാവു", "പരാഗ്വേ", "ഖത്തർ", "ദ്വീപസമൂഹം", "റീയൂണിയൻ", "റൊമാനിയ", "സെർബിയ", "റഷ്യ", "റുവാണ്ട", "സൗദി അറേബ്യ", "സോളമൻ ദ്വീപുകൾ", "സീഷെൽസ്", "സുഡാൻ", "സ്വീഡൻ", "സിംഗപ്പൂർ", "സെന്റ് ഹെലീന", "സ്ലോവേനിയ", "സ്വാൽബാഡും ജാൻ മായേനും", "സ്ലോവാക്യ", "സിയെറ ലിയോൺ", "സാൻ മറിനോ", "സെനഗൽ", "സോമാലിയ", "സുരിനാം", "ദക്ഷിണ സുഡാൻ", "സാവോ ടോമും പ്രിൻസിപെയും", "എൽ സാൽവദോർ", "സിന്റ് മാർട്ടെൻ", "സിറിയ", "സ്വാസിലൻഡ്", "ട്രിസ്റ്റൻ ഡ കൂന", "ടർക്ക്\u{200C}സും കെയ്\u{200C}ക്കോ ദ്വീപുകളും", "ഛാഡ്", "ഫ്രഞ്ച് ദക്ഷിണ ഭൂപ്രദേശം", "ടോഗോ", "തായ്\u{200C}ലാൻഡ്", "താജിക്കിസ്ഥാൻ", "ടോക്കെലൂ", "തിമോർ-ലെസ്റ്റെ", "തുർക്ക്മെനിസ്ഥാൻ", "ടുണീഷ്യ", "ടോംഗ", "തുർക്കിയെ", "ട്രിനിഡാഡും ടുബാഗോയും", "ടുവാലു", "തായ്\u{200C}വാൻ", "ടാൻസാനിയ", "ഉക്രെയ്\u{200C}ൻ", "ഉഗാണ്ട", "യു.എസ്. ദ്വീപസമൂഹങ്ങൾ", "ഐക്യരാഷ്ട്രസഭ", "അമേരിക്കൻ ഐക്യനാടുകൾ", "ഉറുഗ്വേ", "ഉസ്\u{200C}ബെക്കിസ്ഥാൻ", "വത്തിക്കാൻ", "സെന്റ് വിൻസെന്റും ഗ്രനെഡൈൻസും", "വെനിസ്വേല", "ബ്രിട്ടീഷ് വെർജിൻ ദ്വീപുകൾ", "യു.എസ്. വെർജിൻ ദ്വീപുകൾ", "വിയറ്റ്നാം", "വന്വാതു", "വാലിസ് ആന്റ് ഫ്യൂച്യുന", "സമോവ", "കൃത്രിമ ഉച്ചാരണം", "സ്യൂഡോ-ബൈഡി", "കൊസോവൊ", "യെമൻ", "മയോട്ടി", "ദക്ഷിണാഫ്രിക്ക", "സാംബിയ", "സിംബാബ്\u{200C}വേ"];
+
+// mt
+static R63: [&str; 294] = ["Dinja", "Affrika", "", "Amerika t’Isfel", "Oċejanja", "Affrika tal-Punent", "Amerika Ċentrali", "Affrika tal-Lvant", "Affrika ta’ Fuq", "Affrika Nofsani", "Affrika t’Isfel", "Amerika", "", "Karibew", "Asja tal-Lvant", "Asja t’Isfel Ċentrali", "Asja tax-Xlokk", "Ewropa t’Isfel", "Awstralja u New Zealand", "Melanesja", "Reġjun ta’ Mikroneżja", "Polinesja", "Asja", "Asja Ċentrali", "Asja tal-Punent", "Ewropa", "Ewropa tal-Lvant", "Ewropa ta’ Fuq", "Ewropa tal-Punent", "", "Amerika Latina", "Ascension Island", "Andorra", "l-Emirati Għarab Magħquda", "l-Afganistan", "Antigua u Barbuda", "Anguilla", "l-Albanija", "l-Armenja", "l-Angola", "l-Antartika", "l-Arġentina", "is-Samoa Amerikana", "l-Awstrija", "l-Awstralja", "Aruba", "il-Gżejjer Aland", "l-Ażerbajġan", "il-Bożnija-Ħerzegovina", "Barbados", "il-Bangladesh", "il-Belġju", "il-Burkina Faso", "il-Bulgarija", "il-Bahrain", "il-Burundi", "il-Benin", "Saint Barthélemy", "Bermuda", "il-Brunei", "il-Bolivja", "in-Netherlands tal-Karibew", "Il-Brażil", "il-Bahamas", "il-Bhutan", "Gżira Bouvet", "il-Botswana", "il-Belarussja", "il-Belize", "il-Kanada", "Gżejjer Cocos (Keeling)", "ir-Repubblika Demokratika tal-Kongo", "ir-Repubblika Ċentru-Afrikana", "il-Kongo - Brazzaville", "l-Iżvizzera", "il-Kosta tal-Avorju", "Gżejjer Cook", "iċ-Ċili", "il-Kamerun", "iċ-Ċina", "il-Kolombja", "il-Gżira Clipperton", "", "il-Costa Rica", "Kuba", "Cape Verde", "Curaçao", "il-Gżira Christmas", "Ċipru", "ir-Repubblika Ċeka", "il-Ġermanja", "Diego Garcia", "il-Djibouti", "id-Danimarka", "Dominica", "ir-Repubblika Dominicana", "l-Alġerija", "Ceuta u Melilla", "l-Ekwador", "l-Estonja", "l-Eġittu", "is-Saħara tal-Punent", "l-Eritrea", "Spanja", "l-Etjopja", "Unjoni Ewropea", "", "il-Finlandja", "Fiġi", "il-Gżejjer Falkland", "il-Mikroneżja", "il-Gżejjer Faeroe", "Franza", "il-Gabon", "ir-Renju Unit", "Grenada", "il-Georgia", "il-Guyana Franċiża", "Guernsey", "il-Ghana", "Ġibiltà", "Greenland", "il-Gambja", "il-Guinea", "Guadeloupe", "il-Guinea Ekwatorjali", "il-Greċja", "il-Georgia tan-Nofsinhar u l-Gżejjer Sandwich tan-Nofsinhar", "il-Gwatemala", "Guam", "il-Guinea-Bissau", "il-Guyana", "ir-Reġjun Amministrattiv Speċjali ta’ Hong Kong tar-Repubblika tal-Poplu taċ-Ċina", "il-Gżejjer Heard u l-Gżejjer McDonald", "il-Honduras", "il-Kroazja", "il-Haiti", "l-Ungerija", "il-Gżejjer Canary", "l-Indoneżja", "l-Irlanda", "Iżrael", "Isle of Man", "l-Indja", "", "l-Iraq", "l-Iran", "l-Iżlanda", "l-Italja", "Jersey", "il-Ġamajka", "il-Ġordan", "il-Ġappun", "il-Kenja", "il-Kirgiżistan", "il-Kambodja", "Kiribati", "Comoros", "Saint Kitts u Nevis", "il-Korea ta’ Fuq", "il-Korea t’Isfel", "il-Kuwajt", "il-Gżejjer Cayman", "il-Każakistan", "il-Laos", "il-Libanu", "Saint Lucia", "il-Liechtenstein", "is-Sri Lanka", "il-Liberja", "il-Lesoto", "il-Litwanja", "il-Lussemburgu", "il-Latvja", "il-Libja", "il-Marokk", "Monaco", "il-Moldova", "il-Montenegro", "Saint Martin", "Madagascar", "Gżejjer Marshall", "il-Maċedonja ta’ Fuq", "il-Mali", "il-Myanmar/Burma", "il-Mongolja", "ir-Reġjun Amministrattiv Speċjali tal-Macao tar-Repubblika tal-Poplu taċ-Ċina", "Ġżejjer Mariana tat-Tramuntana", "Martinique", "il-Mauritania", "Montserrat", "Malta", "Mauritius", "il-Maldivi", "il-Malawi", "il-Messiku", "il-Malasja", "il-Mozambique", "in-Namibja", "New Caledonia", "in-Niġer", "Gżira Norfolk", "in-Niġerja", "in-Nikaragwa", "in-Netherlands", "in-Norveġja", "in-Nepal", "Nauru", "Niue", "New Zealand", "l-Oman", "il-Panama", "il-Perù", "Polineżja Franċiża", "Papua New Guinea", "il-Filippini", "il-Pakistan", "il-Polonja", "Saint Pierre u Miquelon", "Gżejjer Pitcairn", "Puerto Rico", "it-Territorji Palestinjani", "il-Portugall", "Palau", "il-Paragwaj", "il-Qatar", "", "Réunion", "ir-Rumanija", "is-Serbja", "ir-Russja", "ir-Rwanda", "l-Arabja Sawdija", "il-Gżejjer Solomon", "is-Seychelles", "is-Sudan", "l-Iżvezja", "Singapore", "Saint Helena", "is-Slovenja", "Svalbard u Jan Mayen", "is-Slovakkja", "Sierra Leone", "San Marino", "is-Senegal", "is-Somalja", "is-Suriname", "is-Sudan t’Isfel", "São Tomé u Príncipe", "El Salvador", "Sint Maarten", "is-Sirja", "l-Eswatini", "Tristan da Cunha", "il-Gżejjer Turks u Caicos", "iċ-Chad", "It-Territorji Franċiżi tan-Nofsinhar", "it-Togo", "it-Tajlandja", "it-Taġikistan", "it-Tokelau", "Timor Leste", "it-Turkmenistan", "it-Tuneżija", "Tonga", "it-Turkija", "Trinidad u Tobago", "Tuvalu", "it-Tajwan", "it-Tanzanija", "l-Ukrajna", "l-Uganda", "Il-Gżejjer Minuri Mbiegħda tal-Istati Uniti", "", "l-Istati Uniti", "l-Urugwaj", "l-Użbekistan", "l-Istat tal-Belt tal-Vatikan", "Saint Vincent u l-Grenadini", "il-Venezwela", "il-Gżejjer Verġni Brittaniċi", "il-Gżejjer Verġni tal-Istati Uniti", "il-Vjetnam", "Vanuatu", "Wallis u Futuna", "Samoa", "", "", "il-Kosovo", "il-Jemen", "Mayotte", "l-Afrika t’Isfel", "iż-Żambja", "iż-Żimbabwe"];
+
+// mi
+static R64: [&str; 294] = ["te ao", "Āwherika", "Amerika ki te Raki", "Amerika ki te Tonga", "Ngā Moutere-a-Kiwa", "Āwherika ki te Uru", "Te Puku o Amerika", "Āwherika ki te Rāwhiti", "Āwherika ki te Raki", "Te Pokapū o Āwherika", "Āwherika Whakatetonga", "Ngā Amerika", "Te Raki o Amerika", "Karapīana", "Āhia ki te Rāwhiti", "Āhia ki te Tonga", "Āhia ki te Tonga-mā-rāwhiti", "Ūropi ki te Tonga", "Atareiria", "Meranīhia", "Te Rohe o Mekanēhia", "Te Moana-nui-a-Kiwa", "Āhia", "Te Puku o Āhia", "Āhia ki te Uru", "Ūropi", "Ūropi ki te Rāwhiti", "Ūropi ki te Raki", "Ūropi ki te Uru", "Āwherika ki te Tonga o Te Hahāra", "Amerika Rātini", "Te Moutere Aupikinga", "Anatōra", "Kotahitanga o ngā Whenua o Ārapi", "Awhekenetāna", "Motu Nehe me Pāputa", "Anguira", "Arapeinia", "Āmenia", "Anakora", "Te Kōpakatanga ki te Tonga", "Āketina", "Hāmoa-Amerika", "Ataria", "Ahitereiria", "Arūpa", "Motu Ōrana", "Atepaihānia", "Pōngia & Herekōwini", "Papatohe", "Pākaratēhi", "Peretiama", "Pākina Wharo", "Purukāria", "Pāreina", "Puruniti", "Penīna", "Hato Pāteremi", "Pāmura", "Poronai", "Poriwia", "Karapīana Hōrana", "Parīhi", "Pahama", "Pūtana", "Motu Pūwei", "Poriwana", "Pērara", "Perīhi", "Kānata", "Ngā Moutere Kokoko (Kirini)", "Kōngo - Kinihāha", "Te Whenua Tūhake o Āwherika Waenga", "Kōngo - Pārawhe", "Huiterangi", "Te Tai Rei", "Kuki Airani", "Hiri", "Kamarūna", "Haina", "Koromōpia", "Te Moutere Kiripetone", "Hāka", "Koto Rīka", "Kiupa", "Te Kūrae Matomato", "Kurahao", "Te Moutere Kirihimete", "Haipara", "Tiekia", "Tiamana", "Tieko Kāhia", "Tipūti", "Tenemāka", "Tominika", "Te Whenua Tūhake o Tominika", "Aratiria", "Hūta me Merera", "Ekuatoa", "Etōnia", "Īhipa", "Hahāra ki te Tonga", "Eritēria", "Peina", "Etiopia", "Te Uniana o Ūropi", "Te Rohe o Ūropi", "Whinarana", "Whītī", "Motu Whākarangi", "Mekanēhia", "Motu Wharau", "Wīwī", "Kāpona", "Te Hononga o Piritene", "Kerenāta", "Hōria", "Kiāna Wīwī", "Kōnihi", "Kāna", "Kāmaka", "Whenuakāriki", "Kamopia", "Kini", "Kuatarupa", "Kini Ekuatoria", "Kirihi", "Hōria ki te Tonga me ngā Motu Hanawiti ki te Tonga", "Kuatamāra", "Kuama", "Kini-Pihao", "Kaiana", "Hongipua Haina", "Ngā Moutere Heriti me Makitānara", "Honotura", "Koroātia", "Haiti", "Hanekari", "Motu Kanēre", "Initonīhia", "Airani", "Iharaira", "Te Moutere Mana", "Inia", "Te Rohe o te Moana Īniana Piritihi", "Irāka", "Irāna", "Tiorangi", "Itāria", "Tōrehe", "Hemeika", "Hōrano", "Hapani", "Kenia", "Kikitānga", "Kamapōtia", "Kiripati", "Komoro", "Hato Kiti me Newhi", "Kōrea ki te Raki", "Kōrea ki te Tonga", "Kūweiti", "Ngā Motu Keimana", "Katatānga", "Rāoho", "Repanona", "Hato Ruhia", "Rīkenetaina", "Hiri Rānaka", "Raipiria", "Teroto", "Rituānia", "Rakapuō", "Rāwhia", "Ripia", "Moroko", "Monāko", "Morotawa", "Maungakororiko", "Hato Mātene", "Matakāhika", "Ngā Motu Māhara", "Makerōnia ki te Raki", "Māri", "Pēma", "Mongōria", "Makau Haina", "Ngā Motu Mariana ki te Raki", "Mātiniki", "Mauritānia", "Monoterā", "Mārata", "Marihi", "Māratiri", "Marāwi", "Mēhiko", "Mareia", "Mohapiki", "Namipia", "Whenua Kanaki", "Ngāika", "Te Moutere Nōpoke", "Ngāitiria", "Nikarāhua", "Hōrana", "Nōwei", "Nepōra", "Nauru", "Niue", "Aotearoa", "Ōmana", "Panama", "Peru", "Poronēhia Wīwī", "Papua Nūkini", "Piripīni", "Pakitāne", "Pōrana", "Hato Piere & Mikerona", "Pitikeina", "Peta Riko", "Ngā Rohe o Parihitini", "Potukara", "Pārau", "Parakai", "Katā", "Ngā Moutere-a-Kiwa o Waho atu", "Reūnio", "Romeinia", "Hirupia", "Rūhia", "Rāwana", "Hauri Arāpia", "Ngā Motu Horomona", "Heikere", "Hūtāne", "Huitene", "Hingapoa", "Hato Hērena", "Horowinia", "Heopara me Iana Maiana", "Horowākia", "Te Araone", "Hana Marino", "Henekara", "Hūmārie", "Huriname", "Hūtāne ki te Tonga", "Hato Tomei me Pirinipei", "Whakaora", "Hiti Mātene", "Hiria", "Ehiwatini", "Tiritana da Kunia", "Koru-Kākoa", "Kāta", "Ngā Rohe o Wīwī ki te Tonga", "Toko", "Tairanga", "Takiritānga", "Tokerau", "Tīmoa ki te Rāwhiti", "Tukumanatānga", "Tūnihia", "Tonga", "Tākei", "Tirinaki Tōpako", "Tūwaru", "Taiwana", "Tānahia", "Ukareinga", "Ukānga", "Ngā Moutere Amerika o Waho", "Te Rūnanga Whakakotahi i ngā Iwi o te Ao", "Hononga o Amerika", "Urukoi", "Uhipeketāne", "Te Poho-o-Pita", "Hato Wēneti me Keretīni", "Penehūera", "Ngā Moutere Puhi Piritene", "Ngā Moutere Puhi Amerika", "Whitināmu", "Whenuatū", "Warihi me Whutuna", "Hāmoa", "Mita kikoika", "Piri Kikoika", "Kōhoro", "Īmene", "Māiota", "Āwherika ki te Tonga", "Tāmipia", "Timuwawe"];
+
+// mr
+static R65: [&str; 294] = ["जग", "आफ्रिका", "उत्तर अमेरिका", "दक्षिण अमेरिका", "ओशनिया", "पश्चिम आफ्रिका", "मध्य अमेरिका", "पूर्व आफ्रिका", "उत्तर आफ्रिका", "मध्य आफ्रिका", "दक्षिणी आफ्रिका", "अमेरिका", "उत्तरी अमेरिका", "कॅरीबियन", "पूर्व आशिया", "दक्षिण आशिया", "दक्षिण पूर्व आशिया", "दक्षिण युरोप", "ऑस्\u{200D}ट्रेलेशिया", "मेलानेशिया", "मायक्रोनेशियन प्रदेश", "पॉलिनेशिया", "आशिया", "मध्य आशिया", "पश्चिम आशिया", "युरोप", "पूर्व युरोप", "उत्तर युरोप", "पश्चिम युरोप", "उप-सहारा आफ्रिका", "लॅटिन अमेरिका", "अ\u{200D}ॅसेन्शियन बेट", "अँडोरा", "संयुक्त अरब अमिराती", "अफगाणिस्तान", "अँटिग्वा आणि बर्बुडा", "अँग्विला", "अल्बानिया", "अर्मेनिया", "अंगोला", "अंटार्क्टिका", "अर्जेंटिना", "अमेरिकन सामोआ", "ऑस्ट्रिया", "ऑस्ट्रेलिया", "अरुबा", "अ\u{200D}ॅलँड बेटे", "अझरबैजान", "बोस्निया अणि हर्जेगोविना", "बार्बाडोस", "बांगलादेश", "बेल्जियम", "बुर्किना फासो", "बल्गेरिया", "बहारीन", "बुरुंडी", "बेनिन", "सेंट बार्थेलेमी", "बर्मुडा", "ब्रुनेई", "बोलिव्हिया", "कॅरिबियन नेदरलँड्स", "ब्राझिल", "बहामाज", "भूतान", "बोउवेट बेट", "बोट्सवाना", "बेलारूस", "बेलिझे", "कॅनडा", "कोकोस (कीलिंग) बेटे", "काँगो - किंशासा", "केंद्रीय अफ्रिकी प्रजासत्ताक", "काँगो - ब्राझाविले", "स्वित्झर्लंड", "कोत द’ईवोआर", "कुक बेटे", "चिली", "कॅमेरून", "चीन", "कोलम्बिया", "क्लिपरटोन बेट", "सार्क", "कोस्टा रिका", "क्यूबा", "केप व्हर्डे", "क्युरासाओ", "ख्रिसमस बेट", "सायप्रस", "झेकिया", "जर्मनी", "दिएगो गार्सिया", "जिबौटी", "डेन्मार्क", "डोमिनिका", "डोमिनिकन प्रजासत्ताक", "अल्जीरिया", "स्यूटा आणि मेलिला", "इक्वाडोर", "एस्टोनिया", "इजिप्त", "पश्चिम सहारा", "एरिट्रिया", "स्पेन", "इथिओपिया", "युरोपीय संघ", "युरोझोन", "फिनलंड", "फिजी", "फॉकलंड बेटे", "मायक्रोनेशिया", "फेरो बेटे", "फ्रान्स", "गॅबॉन", "युनायटेड किंगडम", "ग्रेनेडा", "जॉर्जिया", "फ्रेंच गयाना", "ग्वेर्नसे", "घाना", "जिब्राल्टर", "ग्रीनलंड", "गाम्बिया", "गिनी", "ग्वाडेलोउपे", "इक्वेटोरियल गिनी", "ग्रीस", "दक्षिण जॉर्जिया आणि दक्षिण सँडविच बेटे", "ग्वाटेमाला", "गुआम", "गिनी-बिसाउ", "गयाना", "हाँगकाँग एसएआर चीन", "हर्ड आणि मॅक्डोनाल्ड बेटे", "होंडुरास", "क्रोएशिया", "हैती", "हंगेरी", "कॅनरी बेटे", "इंडोनेशिया", "आयर्लंड", "इस्त्राइल", "आयल ऑफ मॅन", "भारत", "ब्रिटिश हिंद महासागर प्रदेश", "इराक", "इराण", "आइसलँड", "इटली", "जर्सी", "जमैका", "जॉर्डन", "जपान", "केनिया", "किरगिझस्तान", "कंबोडिया", "किरीबाटी", "कोमोरोज", "सेंट किट्स आणि नेव्हिस", "उत्तर कोरिया", "दक्षिण कोरिया", "कुवेत", "केमन बेटे", "कझाकस्तान", "लाओस", "लेबनॉन", "सेंट ल्यूसिया", "लिक्टेनस्टाइन", "श्रीलंका", "लायबेरिया", "लेसोथो", "लिथुआनिया", "लक्झेंबर्ग", "लाटव्हिया", "लिबिया", "मोरोक्को", "मोनॅको", "मोल्डोव्हा", "मोंटेनेग्रो", "सेंट मार्टिन", "मादागास्कर", "मार्शल बेटे", "उत्तर मॅसेडोनिया", "माली", "म्यानमार (बर्मा)", "मंगोलिया", "मकाओ एसएआर चीन", "उत्तरी मारियाना बेटे", "मार्टिनिक", "मॉरिटानिया", "मॉन्ट्सेराट", "माल्टा", "मॉरिशस", "मालदीव", "मलावी", "मेक्सिको", "मलेशिया", "मोझाम्बिक", "नामिबिया", "न्यू कॅलेडोनिया", "नाइजर", "नॉरफॉक बेट", "नायजेरिया", "निकाराग्वा", "नेदरलँड", "नॉर्वे", "नेपाळ", "नाउरू", "नीयू", "न्यूझीलंड", "ओमान", "पनामा", "पेरू", "फ्रेंच पॉलिनेशिया", "पापुआ न्यू गिनी", "फिलिपिन्स", "पाकिस्तान", "पोलंड", "सेंट पियरे आणि मिक्वेलोन", "पिटकैर्न बेटे", "प्युएर्तो रिको", "पॅलेस्टिनियन प्रदेश", "पोर्तुगाल", "पलाऊ", "पराग्वे", "कतार", "आउटलाईंग ओशनिया", "रियुनियन", "रोमानिया", "सर्बिया", "रशिया", "रवांडा", "सौदी अरब", "सोलोमन बेटे", "सेशेल्स", "सुदान", "स्वीडन", "सिंगापूर", "सेंट हेलेना", "स्लोव्हेनिया", "स्वालबर्ड आणि जान मायेन", "स्लोव्हाकिया", "सिएरा लिओन", "सॅन मरीनो", "सेनेगल", "सोमालिया", "सुरिनाम", "दक्षिण सुदान", "साओ टोम आणि प्रिंसिपे", "अल साल्वाडोर", "सिंट मार्टेन", "सीरिया", "इस्वातिनी", "ट्रिस्टन दा कुन्हा", "टर्क्स आणि कैकोस बेटे", "चाड", "फ्रेंच दाक्षिणात्य प्रदेश", "टोगो", "थायलंड", "ताजिकिस्तान", "तोकेलाउ", "तिमोर-लेस्ते", "तुर्कमेनिस्तान", "ट्यूनिशिया", "टोंगा", "तुर्किये", "त्रिनिदाद आणि टोबॅगो", "टुवालु", "तैवान", "टांझानिया", "युक्रेन", "युगांडा", "यू.एस. आउटलाइंग बेटे", "संयुक्त राष्ट्र", "युनायटेड स्टेट्स", "उरुग्वे", "उझबेकिस्तान", "व्हॅटिकन सिटी", "सेंट व्हिन्सेंट आणि ग्रेनडाइन्स", "व्हेनेझुएला", "ब्रिटिश व्हर्जिन बेटे", "यू.एस. व्हर्जिन बेटे", "व्हिएतनाम", "वानुआतु", "वालिस आणि फ्यूचूना", "सामोआ", "नकली-उच्चारणे", "नकली-बीडी", "कोसोव्हो", "येमेन", "मायोट्टे", "दक्षिण आफ्रिका", "झाम्बिया", "झिम्बाब्वे"];
+
+// mn
+static R66: [&str; 294] = ["Дэлхий", "Африк", "Хойд Америк", "Өмнөд Америк", "Номхон далайн орнууд", "Баруун Африк", "Төв Америк", "Зүүн Африк", "Хойд Африк", "Төв Африк", "Өмнөд Африк тив", "Америк", "Хойд Америк тив", "Карибын тэнгис", "Зүүн Ази", "Өмнөд Ази", "Зүүн өмнөд Ази", "Өмнөд Европ", "Австрали-Ази", "Меланези", "Микронезийн бүс", "Полинези", "Ази", "Төв Ази", "Баруун Ази", "Европ", "Зүүн Европ", "Хойд Европ", "Баруун Европ", "Сахарын цөлийн урд хэсгийн Африк", "Латин Америк", "Асенсион арал", "Андорра", "Арабын Нэгдсэн Эмират Улс", "Афганистан", "Антигуа ба Барбуда", "Ангилья", "Албани", "Армени", "Ангол", "Антарктид", "Аргентин", "Америкийн Самоа", "Австри", "Австрали", "Аруба", "Аландын арлууд", "Азербайжан", "Босни-Херцеговин", "Барбадос", "Бангладеш", "Бельги", "Буркина Фасо", "Болгар", "Бахрейн", "Бурунди", "Бенин", "Сент-Бартельми", "Бермуда", "Бруней", "Боливи", "Карибын Нидерланд", "Бразил", "Багамын арлууд", "Бутан", "Буве арал", "Ботсван", "Беларусь", "Белизе", "Канад", "Кокос (Кийлинг) арлууд", "Конго-Киншаса", "Төв Африкийн Бүгд Найрамдах Улс", "Конго-Браззавиль", "Швейцар", "Кот-д’Ивуар", "Күүкийн арлууд", "Чили", "Камерун", "Хятад", "Колумби", "Клиппертон арал", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Зул сарын арал", "Кипр", "Чех", "Герман", "Диего Гарсиа", "Джибути", "Дани", "Доминика", "Бүгд Найрамдах Доминикан Улс", "Алжир", "Сеута ба Мелилья", "Эквадор", "Эстони", "Египет", "Баруун Сахар", "Эритрей", "Испани", "Этиоп", "Европын Холбоо", "Евро бүс", "Финланд", "Фижи", "Фолклендийн арлууд", "Микронези", "Фарерын арлууд", "Франц", "Габон", "Их Британи", "Гренада", "Гүрж", "Францын Гвиана", "Гернси", "Гана", "Гибралтар", "Гренланд", "Гамби", "Гвиней", "Гваделуп", "Экваторын Гвиней", "Грек", "Өмнөд Жоржиа ба Өмнөд Сэндвичийн арлууд", "Гватемал", "Гуам", "Гвиней-Бисау", "Гайана", "БНХАУ-ын Тусгай захиргааны бүс Хонг-Конг", "Херд ба Макдональдийн арлууд", "Гондурас", "Хорват", "Гаити", "Унгар", "Канарын арлууд", "Индонез", "Ирланд", "Израил", "Мэн Арал", "Энэтхэг", "Британийн харьяа Энэтхэгийн далай дахь нутаг дэвсгэр", "Ирак", "Иран", "Исланд", "Итали", "Жерси", "Ямайка", "Йордан", "Япон", "Кени", "Киргиз", "Камбож", "Кирибати", "Коморын арлууд", "Сент-Киттс ба Невис", "Хойд Солонгос", "Өмнөд Солонгос", "Кувейт", "Кайманы арлууд", "Казахстан", "Лаос", "Ливан", "Сент Люсиа", "Лихтенштайн", "Шри-Ланка", "Либери", "Лесото", "Литва", "Люксембург", "Латви", "Ливи", "Морокко", "Монако", "Молдова", "Монтенегро", "Сент-Мартин", "Мадагаскар", "Маршаллын арлууд", "Хойд Македон", "Мали", "Мьянмар", "Монгол", "БНХАУ-ын Тусгай захиргааны бүс Макао", "Хойд Марианы арлууд", "Мартиник", "Мавритани", "Монтсеррат", "Мальта", "Маврикий", "Мальдив", "Малави", "Мексик", "Малайз", "Мозамбик", "Намиби", "Шинэ Каледони", "Нигер", "Норфолк арал", "Нигери", "Никарагуа", "Нидерланд", "Норвег", "Балба", "Науру", "Ниуэ", "Шинэ Зеланд", "Оман", "Панам", "Перу", "Францын Полинез", "Папуа Шинэ Гвиней", "Филиппин", "Пакистан", "Польш", "Сент-Пьер ба Микело", "Питкэрн арлууд", "Пуэрто-Рико", "Палестины нутаг дэвсгэр", "Португал", "Палау", "Парагвай", "Катар", "Номхон далайг тойрсон улс орнууд", "Реюнион", "Румын", "Серби", "Орос", "Руанда", "Саудын Араб", "Соломоны арлууд", "Сейшелийн арлууд", "Судан", "Швед", "Сингапур", "Сент Хелена", "Словени", "Свалбард ба Ян Майен", "Словак", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Өмнөд Судан", "Сан-Томе ба Принсипи", "Эль Сальвадор", "Синт Мартен", "Сири", "Эсватини", "Тристан да Кунъя", "Турк ба Кайкосын Арлууд", "Чад", "Францын өмнөд газар нутаг", "Того", "Тайланд", "Тажикистан", "Токелау", "Тимор-Лесте", "Туркменистан", "Тунис", "Тонга", "Турк", "Тринидад ба Тобаго", "Тувалу", "Тайвань", "Танзани", "Украин", "Уганда", "Америкийн Нэгдсэн Улсын бага арлууд", "Нэгдсэн Үндэстний Байгууллага", "Америкийн Нэгдсэн Улс", "Уругвай", "Узбекистан", "Ватикан хот улс", "Сент-Винсент ба Гренадин", "Венесуэл", "Британийн Виржиний арлууд", "АНУ-ын Виржиний арлууд", "Вьетнам", "Вануату", "Уоллис ба Футуна", "Самоа", "хуурмаг аялга", "хуурмаг хоёр чиглэлт", "Косово", "Йемен", "Майотта", "Өмнөд Африк", "Замби", "Зимбабве"];
+
+// ne
+static R67: [&str; 294] = ["विश्व", "अफ्रिका", "उत्तर अमेरिका", "दक्षिण अमेरिका", "ओसनिया", "पश्चिमी अफ्रिका", "केन्द्रीय अमेरिका", "पूर्वी अफ्रिका", "उत्तरी अफ्रिका", "मध्य अफ्रिका", "दक्षिणी अफ्रिका", "अमेरिकास", "उत्तरी अमेरिका", "क्यारिबियन", "पूर्वी एशिया", "दक्षिणी एशिया", "दक्षिण पूर्वी एशिया", "दक्षिणी युरोप", "अष्ट्रालासिया", "मेलानेसिया", "माइक्रोनेसियाली क्षेत्र", "पोलिनेशिया", "एशिया", "केन्द्रीय एशिया", "पश्चिमी एशिया", "युरोप", "पूर्वी युरोप", "उत्तरी युरोप", "पश्चिमी युरोप", "उप-साहारा अफ्रिका", "ल्याटिन अमेरिका", "असेन्सन टापु", "अन्डोर्रा", "संयुक्त अरब इमिराट्स", "अफगानिस्तान", "एन्टिगुआ र बारबुडा", "आङ्गुइला", "अल्बेनिया", "आर्मेनिया", "अङ्गोला", "अन्टारटिका", "अर्जेन्टिना", "अमेरिकी समोआ", "अष्ट्रिया", "अस्ट्रेलिया", "अरुबा", "अलान्ड टापुहरु", "अजरबैजान", "बोस्निया एण्ड हर्जगोभिनिया", "बार्बाडोस", "बङ्गलादेश", "बेल्जियम", "बुर्किना फासो", "बुल्गेरिया", "बहराइन", "बुरूण्डी", "बेनिन", "सेन्ट बार्थेलेमी", "बर्मुडा", "ब्रुनाइ", "बोलिभिया", "क्यारिवियन नेदरल्याण्ड्स", "ब्राजिल", "बहामास", "भुटान", "बुभेट टापु", "बोट्स्वाना", "बेलारूस", "बेलिज", "क्यानाडा", "कोकोस (किलिंग) टापुहरु", "कङ्गो - किन्शासा", "मध्य अफ्रिकी गणतन्त्र", "कङ्गो ब्राजाभिल", "स्विजरल्याण्ड", "कोट दिभोर", "कुक टापुहरु", "चिली", "क्यामरून", "चीन", "कोलोम्बिया", "क्लिप्पेर्टन टापु", "सार्क", "कोष्टारिका", "क्युबा", "केप भर्डे", "कुराकाओ", "क्रिष्टमस टापु", "साइप्रस", "चेकिया", "जर्मनी", "डियगो गार्सिया", "डिजिबुटी", "डेनमार्क", "डोमिनिका", "डोमिनिकन गणतन्त्र", "अल्जेरिया", "सिउटा र मेलिला", "इक्वेडोर", "इस्टोनिया", "इजिप्ट", "पश्चिमी साहारा", "एरिट्रीया", "स्पेन", "इथियोपिया", "युरोपियन युनियन", "युरोजोन", "फिनल्याण्ड", "फिजी", "फकल्याण्ड टापुहरु", "माइक्रोनेसिया", "फारो टापुहरू", "फ्रान्स", "गावोन", "संयुक्त अधिराज्य", "ग्रेनाडा", "जर्जिया", "फ्रान्सेली गायना", "ग्यूर्न्सी", "घाना", "जिब्राल्टार", "ग्रिनल्याण्ड", "गाम्विया", "गिनी", "ग्वाडेलुप", "भू-मध्यीय गिनी", "ग्रीस", "दक्षिण जर्जिया र दक्षिण स्यान्डवीच टापुहरू", "ग्वाटेमाला", "गुवाम", "गिनी-बिसाउ", "गुयाना", "हङकङ चिनियाँ विशेष प्रशासनिक क्षेत्र", "हर्ड टापु र म्याकडोनाल्ड टापुहरु", "हन्डुरास", "क्रोएशिया", "हैटी", "हङ्गेरी", "क्यानारी टापुहरू", "इन्डोनेशिया", "आयरल्याण्ड", "इजरायल", "आइल अफ म्यान", "भारत", "बेलायती हिन्द महासागर क्षेत्र", "इराक", "इरान", "आइस्ल्याण्ड", "इटली", "जर्सी", "जमैका", "जोर्डन", "जापान", "केन्या", "किर्गिस्तान", "कम्बोडिया", "किरिबाटी", "कोमोरोस", "सेन्ट किट्स र नेभिस", "उत्तर कोरिया", "दक्षिण कोरिया", "कुवेत", "केयमान टापु", "काजाकस्तान", "लाओस", "लेबनन", "सेन्ट लुसिया", "लिकटेन्सटाइन", "श्रीलङ्का", "लाइबेरिया", "लेसोथो", "लिथुएनिया", "लक्जेमबर्ग", "लाट्भिया", "लिबिया", "मोरोक्को", "मोनाको", "माल्डोभा", "मोन्टेनिग्रो", "सेन्ट मार्टिन", "माडागास्कर", "मार्शल टापुहरु", "उत्तर म्यासेडोनिया", "माली", "म्यान्मार (बर्मा)", "मङ्गोलिया", "मकाउ चिनियाँ विशेष प्रशासनिक क्षेत्र", "उत्तरी मारिआना टापु", "मार्टिनिक", "माउरिटानिया", "मोन्टसेर्राट", "माल्टा", "मौरिसियस", "माल्दिभ्स", "मालावी", "मेक्सिको", "मलेसिया", "मोजाम्बिक", "नामिबिया", "न्यु क्यालेडोनिया", "नाइजर", "नोरफोल्क टापु", "नाइजेरिया", "निकारागुवा", "नेदरल्याण्ड", "नर्वे", "नेपाल", "नाउरू", "नियुइ", "न्युजिल्याण्ड", "ओमन", "प्यानामा", "पेरू", "फ्रान्सेली पोलिनेसिया", "पपुआ न्यू गाइनिया", "फिलिपिन्स", "पाकिस्तान", "पोल्याण्ड", "सेन्ट पिर्रे र मिक्केलोन", "पिटकाइर्न टापुहरु", "पुएर्टो रिको", "प्यालेस्टिनी भू-भागहरू", "पोर्चुगल", "पलाउ", "प्याराग्वे", "कतार", "बाह्य ओसनिया", "रियुनियन", "रोमेनिया", "सर्बिया", "रूस", "रवाण्डा", "साउदी अरब", "सोलोमन टापुहरू", "सेचेलेस", "सुडान", "स्विडेन", "सिङ्गापुर", "सेन्ट हेलेना", "स्लोभेनिया", "सभाल्बार्ड र जान मायेन", "स्लोभाकिया", "सिएर्रा लिओन", "सान् मारिनो", "सेनेगल", "सोमालिया", "सुरिनेम", "दक्षिण सुडान", "साओ टोमे र प्रिन्सिप", "एल् साल्भाडोर", "सिन्ट मार्टेन", "सिरिया", "स्वाजिल्याण्ड", "ट्रिस्टान डा कुन्हा", "तुर्क र काइकोस टापु", "चाड", "फ्रेन्च दक्षिणी राज्यहरू", "टोगो", "थाइल्याण्ड", "ताजिकिस्तान", "तोकेलाउ", "टिमोर-लेस्टे", "तुर्कमेनिस्तान", "ट्युनिसिया", "टोंगा", "तुर्किये", "त्रिनिडाड एण्ड टोबागो", "तुभालु", "ताइवान", "तान्जानिया", "युक्रेन", "युगाण्डा", "संयुक्त राज्यका बाह्य टापुहरु", "संयुक्त राष्ट्र संघ", "संयुक्त राज्य", "उरूग्वे", "उज्बेकिस्तान", "भेटिकन सिटी", "सेन्ट भिन्सेन्ट र ग्रेनाडिन्स", "भेनेजुएला", "बेलायती भर्जिन टापुहरू", "संयुक्त राज्य भर्जिन टापुहरु", "भिएतनाम", "भानुआतु", "वालिस र फुटुना", "सामोआ", "सिउडो-एक्सेन्ट्स", "सिउडो-बिडी", "कोसोभो", "येमेन", "मायोट्ट", "दक्षिण अफ्रिका", "जाम्बिया", "जिम्बाबवे"];
+
+// nb, no
+static R68: [&str; 294] = ["verden", "Afrika", "Nord-Amerika", "Sør-Amerika", "Oseania", "Vest-Afrika", "Mellom-Amerika", "Øst-Afrika", "Nord-Afrika", "Sentral-Afrika", "Sørlige Afrika", "Amerika", "Nordlige Amerika", "Karibia", "Øst-Asia", "Sør-Asia", "Sørøst-Asia", "Sør-Europa", "Australasia", "Melanesia", "Mikronesia", "Polynesia", "Asia", "Sentral-Asia", "Vest-Asia", "Europa", "Øst-Europa", "Nord-Europa", "Vest-Europa", "Afrika sør for Sahara", "Latin-Amerika", "Ascension", "Andorra", "De forente arabiske emirater", "Afghanistan", "Antigua og Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktis", "Argentina", "Amerikansk Samoa", "Østerrike", "Australia", "Aruba", "Åland", "Aserbajdsjan", "Bosnia-Hercegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibisk Nederland", "Brasil", "Bahamas", "Bhutan", "Bouvetøya", "Botswana", "Belarus", "Belize", "Canada", "Kokosøyene", "Kongo", "Den sentralafrikanske republikk", "Kongo-Brazzaville", "Sveits", "Elfenbenskysten", "Cookøyene", "Chile", "Kamerun", "Kina", "Colombia", "Clippertonøya", "Sark", "Costa Rica", "Cuba", "Kapp Verde", "Curaçao", "Christmasøya", "Kypros", "Tsjekkia", "Tyskland", "Diego Garcia", "Djibouti", "Danmark", "Dominica", "Den dominikanske republikk", "Algerie", "Ceuta og Melilla", "Ecuador", "Estland", "Egypt", "Vest-Sahara", "Eritrea", "Spania", "Etiopia", "Den europeiske union", "eurosonen", "Finland", "Fiji", "Falklandsøyene", "Mikronesiaføderasjonen", "Færøyene", "Frankrike", "Gabon", "Storbritannia", "Grenada", "Georgia", "Fransk Guyana", "Guernsey", "Ghana", "Gibraltar", "Grønland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorial-Guinea", "Hellas", "Sør-Georgia og Sør-Sandwichøyene", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong SAR Kina", "Heard- og McDonaldøyene", "Honduras", "Kroatia", "Haiti", "Ungarn", "Kanariøyene", "Indonesia", "Irland", "Israel", "Man", "India", "Det britiske territoriet i Indiahavet", "Irak", "Iran", "Island", "Italia", "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kirgisistan", "Kambodsja", "Kiribati", "Komorene", "Saint Kitts og Nevis", "Nord-Korea", "Sør-Korea", "Kuwait", "Caymanøyene", "Kasakhstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxemburg", "Latvia", "Libya", "Marokko", "Monaco", "Moldova", "Montenegro", "Saint-Martin", "Madagaskar", "Marshalløyene", "Nord-Makedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macao SAR Kina", "Nord-Marianene", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldivene", "Malawi", "Mexico", "Malaysia", "Mosambik", "Namibia", "Ny-Caledonia", "Niger", "Norfolkøya", "Nigeria", "Nicaragua", "Nederland", "Norge", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "Fransk Polynesia", "Papua Ny-Guinea", "Filippinene", "Pakistan", "Polen", "Saint-Pierre-et-Miquelon", "Pitcairnøyene", "Puerto Rico", "Det palestinske området", "Portugal", "Palau", "Paraguay", "Qatar", "Ytre Oseania", "Réunion", "Romania", "Serbia", "Russland", "Rwanda", "Saudi-Arabia", "Salomonøyene", "Seychellene", "Sudan", "Sverige", "Singapore", "St. Helena", "Slovenia", "Svalbard og Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sør-Sudan", "São Tomé og Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Turks- og Caicosøyene", "Tsjad", "De franske sørterritorier", "Togo", "Thailand", "Tadsjikistan", "Tokelau", "Øst-Timor", "Turkmenistan", "Tunisia", "Tonga", "Tyrkia", "Trinidad og Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "USAs ytre øyer", "FN", "USA", "Uruguay", "Usbekistan", "Vatikanstaten", "St. Vincent og Grenadinene", "Venezuela", "De britiske jomfruøyene", "De amerikanske jomfruøyene", "Vietnam", "Vanuatu", "Wallis og Futuna", "Samoa", "pseudospråk – aksenter", "pseudospråk – tekst begge veier", "Kosovo", "Jemen", "Mayotte", "Sør-Afrika", "Zambia", "Zimbabwe"];
+
+// nn
+static R69: [&str; 294] = ["verda", "Afrika", "Nord-Amerika", "Sør-Amerika", "Oseania", "Vest-Afrika", "Sentral-Amerika", "Aust-Afrika", "Nord-Afrika", "Sentral-Afrika", "Sørlege Afrika", "Amerika", "Nordlege Amerika", "Karibia", "Aust-Asia", "Sør-Asia", "Søraust-Asia", "Sør-Europa", "Australasia", "Melanesia", "Mikronesia", "Polynesia", "Asia", "Sentral-Asia", "Vest-Asia", "Europa", "Aust-Europa", "Nord-Europa", "Vest-Europa", "Afrika sør for Sahara", "Latin-Amerika", "Ascension", "Andorra", "Dei sameinte arabiske emirata", "Afghanistan", "Antigua og Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktis", "Argentina", "Amerikansk Samoa", "Austerrike", "Australia", "Aruba", "Åland", "Aserbajdsjan", "Bosnia-Hercegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibisk Nederland", "Brasil", "Bahamas", "Bhutan", "Bouvetøya", "Botswana", "Belarus", "Belize", "Canada", "Kokosøyane", "Kongo-Kinshasa", "Den sentralafrikanske republikken", "Kongo-Brazzaville", "Sveits", "Elfenbeinskysten", "Cookøyane", "Chile", "Kamerun", "Kina", "Colombia", "Clippertonøya", "Sark", "Costa Rica", "Cuba", "Kapp Verde", "Curaçao", "Christmasøya", "Kypros", "Tsjekkia", "Tyskland", "Diego Garcia", "Djibouti", "Danmark", "Dominica", "Den dominikanske republikken", "Algerie", "Ceuta og Melilla", "Ecuador", "Estland", "Egypt", "Vest-Sahara", "Eritrea", "Spania", "Etiopia", "Den europeiske unionen", "eurosona", "Finland", "Fiji", "Falklandsøyane", "Mikronesiaføderasjonen", "Færøyane", "Frankrike", "Gabon", "Storbritannia", "Grenada", "Georgia", "Fransk Guyana", "Guernsey", "Ghana", "Gibraltar", "Grønland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorial-Guinea", "Hellas", "Sør-Georgia og Sør-Sandwichøyane", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong SAR Kina", "Heardøya og McDonaldøyane", "Honduras", "Kroatia", "Haiti", "Ungarn", "Kanariøyane", "Indonesia", "Irland", "Israel", "Man", "India", "Det britiske territoriet i Indiahavet", "Irak", "Iran", "Island", "Italia", "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kirgisistan", "Kambodsja", "Kiribati", "Komorane", "Saint Kitts og Nevis", "Nord-Korea", "Sør-Korea", "Kuwait", "Caymanøyane", "Kasakhstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxembourg", "Latvia", "Libya", "Marokko", "Monaco", "Moldova", "Montenegro", "Saint-Martin", "Madagaskar", "Marshalløyane", "Nord-Makedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macao SAR Kina", "Nord-Marianane", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldivane", "Malawi", "Mexico", "Malaysia", "Mosambik", "Namibia", "Ny-Caledonia", "Niger", "Norfolkøya", "Nigeria", "Nicaragua", "Nederland", "Noreg", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "Fransk Polynesia", "Papua Ny-Guinea", "Filippinane", "Pakistan", "Polen", "Saint-Pierre-et-Miquelon", "Pitcairn", "Puerto Rico", "Det palestinske området", "Portugal", "Palau", "Paraguay", "Qatar", "Ytre Oseania", "Réunion", "Romania", "Serbia", "Russland", "Rwanda", "Saudi-Arabia", "Salomonøyane", "Seychellane", "Sudan", "Sverige", "Singapore", "Saint Helena", "Slovenia", "Svalbard og Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sør-Sudan", "São Tomé og Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Turks- og Caicosøyane", "Tsjad", "Dei franske sørterritoria", "Togo", "Thailand", "Tadsjikistan", "Tokelau", "Aust-Timor", "Turkmenistan", "Tunisia", "Tonga", "Tyrkia", "Trinidad og Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "USAs ytre småøyar", "Dei sameinte nasjonane", "USA", "Uruguay", "Usbekistan", "Vatikanstaten", "St. Vincent og Grenadinane", "Venezuela", "Dei britiske Jomfruøyane", "Dei amerikanske Jomfruøyane", "Vietnam", "Vanuatu", "Wallis og Futuna", "Samoa", "pseudospråk – aksentar", "pseudospråk – RTL", "Kosovo", "Jemen", "Mayotte", "Sør-Afrika", "Zambia", "Zimbabwe"];
+
+// ii
+static R70: [&str; 294] = ["ꋧꃅ", "ꃏꍏ", "ꀙꂰꍏ", "ꆆꂰꍏ", "ꄊꑸꍏ", "", "", "", "", "", "", "ꂰꍏ", "", "", "", "", "", "", "", "", "", "", "ꑸꍏ", "", "", "ꉩꍏ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꀘꆹꏃ", "", "", "", "", "", "", "", "", "", "", "ꀠꑭ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꍏꇩ", "", "", "", "", "", "", "", "", "", "", "ꄓꇩ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꃔꇩ", "", "ꑱꇩ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꑴꄗ", "", "", "", "", "ꑴꄊꆺ", "", "", "", "ꏝꀪ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꃀꑭꇬ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꊉꇆꌦ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ꂰꇩ", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// oc
+static R71: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Espanha", "", "", "", "", "", "", "", "", "França", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Hong Kong", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// om
+static R72: [&str; 294] = ["addunyaa", "Afrikaa", "Ameerikaa Kaabaa", "Ameerikaa Kibbaa", "Oshiiniyaa", "Afrikaa Dhihaa", "Ameerikaa Gidduugaleessaa", "Afrikaa Bahaa", "Afrikaa Kabaa", "Afrikaa Gidduugaleessaa", "Kibba Afrikaa", "Ameerikaa", "Ameerikaa Kaaba", "Kariibiyaan", "Eeshiyaa Bahaa", "Eeshiyaa Kibbaa", "Kibba baha Eeshiyaa", "Awurooppaa Kibbaa", "Awustiralashiyaa", "Melaaneeshiyaa", "Naannoo Maleeshiyaa", "Poolineeshiyaa", "Eeshiyaa", "Eeshiyaa Gidduugaleessaa", "Eeshiyaa Dhihaa", "Awurooppaa", "Awurooppaa Bahaa", "Awurooppaa Kaabaa", "Awurooppaa Dhihaa", "Afrikaa Sahaaraan Gadii", "Laatin Ameerikaa", "Odola Asenshiin", "Andooraa", "Yuunaatid Arab Emereet", "Afgaanistaan", "Antiiguyaa fi Barbuudaa", "Anguyilaa", "Albaaniyaa", "Armeeniyaa", "Angoolaa", "Antaarkitikaa", "Arjentiinaa", "Saamowa Ameerikaa", "Awustiriyaa", "Awustiraaliyaa", "Arubaa", "Odoloota Alaand", "Azerbaajiyaan", "Bosiiniyaa fi Herzoogovinaa", "Barbaaros", "Banglaadish", "Beeljiyeem", "Burkiinaa Faasoo", "Bulgaariyaa", "Baahireen", "Burundii", "Beenii", "St. Barzeleemii", "Beermudaa", "Biruniyee", "Boliiviyaa", "Neezerlaandota Kariibaan", "Biraazil", "Bahaamas", "Bihuutan", "Odola Bowuvet", "Botosowaanaa", "Beelaarus", "Belize", "Kanaadaa", "Odoloota Kokos (Keeliing)", "Koongoo - Kinshaasaa", "Rippaablika Afrikaa Gidduugaleessaa", "Koongoo - Biraazaavil", "Siwizerlaand", "Koti divoor", "Odoloota Kuuk", "Chiilii", "Kaameruun", "Chaayinaa", "Kolombiyaa", "Odola Kilippertoo", "", "Kostaa Rikaa", "Kuubaa", "Keeppi Vaardee", "Kurakowaa", "Odola Kirismaas", "Qoophiroos", "Cheechiya", "Jarmanii", "Diyeegoo Gaarshiyaa", "Jibuutii", "Deenmaark", "Dominiikaa", "Dominikaa Rippaabilik", "Aljeeriyaa", "Kewuta fi Mililaa", "Ekuwaador", "Istooniyaa", "Missir", "Sahaaraa Dhihaa", "Eertiraa", "Ispeen", "Itoophiyaa", "Gamtaa Awurooppaa", "Zooniiyuuroo", "Fiinlaand", "Fiijii", "Odoloota Faalklaand", "Maayikirooneeshiyaa", "Odoloota Fafo’ee", "Faransaay", "Gaaboon", "United Kingdom", "Girinaada", "Joorjiyaa", "Faransaay Guyiinaa", "Guwernisey", "Gaanaa", "Gibraaltar", "Giriinlaand", "Gaambiyaa", "Giinii", "Gowadelowape", "Ikkuwaatooriyaal Giinii", "Giriik", "Joorjikaa Kibba fi Odoloota Saanduwiich Kibbaa", "Guwaatimaalaa", "Guwama", "Giinii-Bisaawoo", "Guyaanaa", "Hoong Koong SAR Chaayinaa", "Odoloota Herdii fi MaakDoonaald", "Hondurus", "Kirooshiyaa", "Haayitii", "Hangaarii", "Odoloota Kanaarii", "Indooneeshiyaa", "Ayeerlaand", "Israa’eel", "Islee oof Maan", "Hindii", "Daangaa Galaana Hindii Biritish", "Iraaq", "Iraan", "Ayeslaand", "Xaaliyaan", "Jeersii", "Jamaayikaa", "Jirdaan", "Jaappaan", "Keeniyaa", "Kiyirigiyizistan", "Kamboodiyaa", "Kiribaatii", "Komoroos", "St. Kiitis fi Neevis", "Kooriyaa Kaaba", "Kooriyaa Kibbaa", "Kuweet", "Odoloota Saaymaan", "Kazakistaan", "Laa’oos", "Libaanoon", "St. Suusiyaa", "Lichistensteyin", "Siri Laankaa", "Laayibeeriyaa", "Leseettoo", "Lutaaniyaa", "Luksembarg", "Lativiyaa", "Liibiyaa", "Morookoo", "Moonaakoo", "Moldoovaa", "Montenegiroo", "St. Martiin", "Madagaaskaar", "Odoloota Maarshaal", "Maqdooniyaa Kaabaa", "Maalii", "Maayinaamar (Burma)", "Mongoliyaa", "Maka’oo SAR Chaayinaa", "Odola Maariyaanaa Kaabaa", "Martinikuwee", "Mawuritaaniyaa", "Montiseerat", "Maaltaa", "Moorishiyees", "Maaldiivs", "Maalaawwii", "Meeksiikoo", "Maleeshiyaa", "Moozaambik", "Namiibiyaa", "Neewu Kaaleedoniyaa", "Niijer", "Odola Noorfoolk", "Naayijeeriyaa", "Nikaraguwaa", "Neezerlaand", "Noorwey", "Neeppal", "Naawuruu", "Niwu’e", "Neewu Zilaand", "Omaan", "Paanamaa", "Peeruu", "Polineeshiyaa Faransaay", "Papuwa Neawu Giinii", "Filippiins", "Paakistaan", "Poolaand", "Ql. Piyeeree fi Mikuyelon", "Odoloota Pitikaayirin", "Poortaar Riikoo", "Daangaawwan Paalestaayin", "Poorchugaal", "Palaawu", "Paaraguwaay", "Kuwaatar", "Ooshiiniyaa Alaa", "Riyuuniyeen", "Roomaaniyaa", "Serbiyaa", "Raashiyaa", "Ruwwandaa", "Saawud Arabiyaa", "Odoloota Solomoon", "Siisheels", "Sudaan", "Siwiidin", "Singaapoor", "St. Helenaa", "Islooveeniyaa", "Isvaalbaard fi Jan Mayeen", "Isloovaakiyaa", "Seeraaliyoon", "Saan Mariinoo", "Senegaal", "Somaaliyaa", "Suriname", "Sudaan Kibbaa", "Sa’oo Toomee fi Prinsippee", "El Salvaadoor", "Siint Maarteen", "Sooriyaa", "Iswaatinii", "Tiristaan da Kanhaa", "Turkis fi Odoloota Kaayikos", "Chaad", "Daangaawwan Kibbaa Faransaay", "Toogoo", "Taayilaand", "Tajikistaan", "Tokelau", "Tiimoor-Leestee", "Turkimenistaan", "Tuniiziyaa", "Tonga", "Tarkiye", "Tirinidan fi Tobaagoo", "Tuvalu", "Taayiwwan", "Taanzaaniyaa", "Yuukireen", "Ugaandaa", "U.S. Odoloota Alaa", "Mootummoota Gamtooman", "Yiinaayitid Isteet", "Yuraagaay", "Uzbeekistaan", "Vaatikaan Siitii", "St. Vinseet fi Gireenadines", "Veenzuweelaa", "Odoloota Varjiin Biritish", "U.S. Odoloota Varjiin", "Veetinaam", "Vanuwaatu", "Waalis fi Futtuuna", "Saamowa", "Loqoda Sobaa", "Biidii Sobaa", "Kosoovoo", "Yemen", "Maayootee", "Afrikaa Kibbaa", "Zaambiyaa", "Zimbaabuwee"];
+
+// or
+static R73: [&str; 294] = ["ବିଶ୍ୱ", "ଆଫ୍ରିକା", "ଉତ୍ତର ଆମେରିକା", "ଦକ୍ଷିଣ ଆମେରିକା", "ଓସିନିଆ", "ପଶ୍ଚିମ ଆଫ୍ରିକା", "ମଧ୍ୟ ଆମେରିକା", "ପୂର୍ବ ଆଫ୍ରିକା", "ଉତ୍ତର ଆଫ୍ରିକା", "ମଧ୍ୟ ଆଫ୍ରିକା", "ଦକ୍ଷିଣସ୍ଥ ଆଫ୍ରିକା", "ଆମେରିକା", "ଉତ୍ତରସ୍ଥ ଆମେରିକା", "କାରିବିଆନ୍", "ପୂର୍ବ ଏସିଆ", "ଦକ୍ଷିଣ ଏସିଆ", "ଦକ୍ଷିଣପୂର୍ବ ଏସିଆ", "ଦକ୍ଷିଣ ୟୁରୋପ୍", "ଅଷ୍ଟ୍ରେଲେସିଆ", "ମେଲାନେସିଆ", "ମାଇକ୍ରୋନେସିଆନ୍ ଅଞ୍ଚଳ", "ପଲିନେସିଆ", "ଏସିଆ", "ମଧ୍ୟ ଏସିଆ", "ପଶ୍ଚିମ ଏସିଆ", "ୟୁରୋପ୍", "ପୂର୍ବ ୟୁରୋପ୍", "ଉତ୍ତର ୟୁରୋପ୍", "ପଶ୍ଚିମ ୟୁରୋପ୍", "ଉପ-ସାହାରା ଆଫ୍ରିକା", "ଲାଟିନ୍\u{200C} ଆମେରିକା", "ଆସେନସିଅନ୍\u{200C} ଦ୍ୱୀପ", "ଆଣ୍ଡୋରା", "ସଂଯୁକ୍ତ ଆରବ ଏମିରେଟସ୍", "ଆଫଗାନିସ୍ତାନ୍", "ଆଣ୍ଟିଗୁଆ ଏବଂ ବାରବୁଦା", "ଆଙ୍ଗୁଇଲ୍ଲା", "ଆଲବାନିଆ", "ଆର୍ମେନିଆ", "ଆଙ୍ଗୋଲା", "ଆଣ୍ଟାର୍କାଟିକା", "ଆର୍ଜେଣ୍ଟିନା", "ଆମେରିକାନ୍ ସାମୋଆ", "ଅଷ୍ଟ୍ରିଆ", "ଅଷ୍ଟ୍ରେଲିଆ", "ଆରୁବା", "ଅଲାଣ୍ଡ ଦ୍ଵୀପପୁଞ୍ଜ", "ଆଜେରବାଇଜାନ୍", "ବୋସନିଆ ଏବଂ ହର୍ଜଗୋଭିନା", "ବାରବାଡୋସ୍", "ବାଂଲାଦେଶ", "ବେଲଜିୟମ୍", "ବୁର୍କିନା ଫାସୋ", "ବୁଲଗେରିଆ", "ବାହାରିନ୍", "ବୁରୁଣ୍ଡି", "ବେନିନ୍", "ସେଣ୍ଟ ବାର୍ଥେଲେମି", "ବର୍ମୁଡା", "ବ୍ରୁନେଇ", "ବୋଲିଭିଆ", "କାରବିୟନ୍\u{200C} ନେଦରଲ୍ୟାଣ୍ଡ", "ବ୍ରାଜିଲ୍", "ବାହାମାସ୍", "ଭୁଟାନ", "ବୌଭେଟ୍\u{200C} ଦ୍ୱୀପ", "ବୋଟସ୍ୱାନା", "ବେଲାରୁଷ୍", "ବେଲିଜ୍", "କାନାଡା", "କୋକୋସ୍ (କୀଲିଂ) ଦ୍ଵୀପପୁଞ୍ଜ", "କଙ୍ଗୋ (ଡିଆରସି)", "ମଧ୍ୟ ଆଫ୍ରିକୀୟ ସାଧାରଣତନ୍ତ୍ର", "କଙ୍ଗୋ-ବ୍ରାଜିଭିଲ୍ଲେ", "ସ୍ୱିଜରଲ୍ୟାଣ୍ଡ", "କୋତ୍ ଡି ଭ୍ଵାର୍", "କୁକ୍\u{200C} ଦ୍ୱୀପପୁଞ୍ଜ", "ଚିଲି", "କାମେରୁନ୍", "ଚୀନ୍\u{200C}", "କଲମ୍ବିଆ", "କ୍ଲିପରଟନ୍\u{200C} ଦ୍ୱୀପ", "ସର୍କ୍\u{200C}", "କୋଷ୍ଟା ରିକା", "କ\u{200D}୍ୟୁବା", "କେପ୍ ଭର୍ଦେ", "କୁରାକାଓ", "ଖ୍ରୀଷ୍ଟମାସ ଦ୍ୱୀପ", "ସାଇପ୍ରସ୍", "ଚେଚିଆ", "ଜର୍ମାନୀ", "ଡିଏଗୋ ଗାର୍ସିଆ", "ଜିବୋଟି", "ଡେନମାର୍କ", "ଡୋମିନିକା", "ଡୋମିନିକାନ୍\u{200C} ସାଧାରଣତନ୍ତ୍ର", "ଆଲଜେରିଆ", "ସିଉଟା ଏବଂ ମେଲିଲା", "ଇକ୍ୱେଡର୍\u{200C}", "ଏସ୍ତୋନିଆ", "ଇଜିପ୍ଟ", "ପଶ୍ଚିମ ସାହାରା", "ଇରିଟ୍ରିୟା", "ସ୍ପେନ୍", "ଇଥିଓପିଆ", "ୟୁରୋପୀୟ ସଂଘ", "ୟୁରୋକ୍ଷେତ୍ର", "ଫିନଲ୍ୟାଣ୍ଡ", "ଫିଜି", "ଫକ୍\u{200C}ଲ୍ୟାଣ୍ଡ ଦ୍ଵୀପପୁଞ୍ଜ", "ମାଇକ୍ରୋନେସିଆ", "ଫାରୋଇ ଦ୍ୱୀପପୁଞ୍ଜ", "ଫ୍ରାନ୍ସ", "ଗାବୋନ୍", "ଯୁକ୍ତରାଜ୍ୟ", "ଗ୍ରେନାଡା", "ଜର୍ଜିଆ", "ଫ୍ରେଞ୍ଚ ଗୁଇନା", "ଗୁଏରନେସି", "ଘାନା", "ଜିବ୍ରାଲ୍ଟର୍", "ଗ୍ରୀନଲ୍ୟାଣ୍ଡ", "ଗାମ୍ବିଆ", "ଗୁଇନିଆ", "ଗୁଆଡେଲୋପ୍", "ଇକ୍ବାଟୋରିଆଲ୍ ଗୁଇନିଆ", "ଗ୍ରୀସ୍", "ଦକ୍ଷିଣ ଜର୍ଜିଆ ଏବଂ ଦକ୍ଷିଣ ସାଣ୍ଡୱିଚ୍ ଦ୍ୱୀପପୁଞ୍ଜ", "ଗୁଏତମାଲା", "ଗୁଆମ୍", "ଗୁଇନିଆ-ବିସାଉ", "ଗୁଇନା", "ହଂ କଂ ଏସଏଆର୍\u{200C} ଚାଇନା", "ହାର୍ଡ୍\u{200C} ଏବଂ ମ୍ୟାକଡୋନାଲ୍ଡ ଦ୍ୱୀପପୁଞ୍ଜ", "ହୋଣ୍ଡୁରାସ୍\u{200C}", "କ୍ରୋଏସିଆ", "ହାଇତି", "ହଙ୍ଗେରୀ", "କେନେରୀ ଦ୍ୱୀପପୁଞ୍ଜ", "ଇଣ୍ଡୋନେସିଆ", "ଆୟରଲ୍ୟାଣ୍ଡ", "ଇସ୍ରାଏଲ୍", "ଆଇଲ୍\u{200C} ଅଫ୍\u{200C} ମ୍ୟାନ୍\u{200C}", "ଭାରତ", "ବ୍ରିଟିଶ୍\u{200C} ଭାରତୀୟ ମହାସାଗର କ୍ଷେତ୍ର", "ଇରାକ୍", "ଇରାନ", "ଆଇସଲ୍ୟାଣ୍ଡ", "ଇଟାଲୀ", "ଜର୍ସି", "ଜାମାଇକା", "ଜୋର୍ଡାନ୍", "ଜାପାନ", "କେନିୟା", "କିର୍ଗିଜିସ୍ତାନ", "କାମ୍ବୋଡିଆ", "କିରିବାଟି", "କୋମୋରସ୍", "ସେଣ୍ଟ କିଟସ୍\u{200C} ଏବଂ ନେଭିସ୍\u{200C}", "ଉତ୍ତର କୋରିଆ", "ଦକ୍ଷିଣ କୋରିଆ", "କୁଏତ୍", "କେମ୍ୟାନ୍\u{200C} ଦ୍ୱୀପପୁଞ୍ଜ", "କାଜାଖସ୍ତାନ୍\u{200C}", "ଲାଓସ୍", "ଲେବାନନ୍", "ସେଣ୍ଟ ଲୁସିଆ", "ଲିକ୍ଟନ୍\u{200C}ଷ୍ଟାଇନ୍\u{200C}", "ଶ୍ରୀଲଙ୍କା", "ଲାଇବେରିଆ", "ଲେସୋଥୋ", "ଲିଥୁଆନିଆ", "ଲକ୍ସେମବର୍ଗ", "ଲାଟଭିଆ", "ଲିବ୍ୟା", "ମୋରୋକ୍କୋ", "ମୋନାକୋ", "ମୋଲଡୋଭା", "ମଣ୍ଟେନିଗ୍ରୋ", "ସେଣ୍ଟ ମାର୍ଟିନ୍", "ମାଡାଗାସ୍କର୍", "ମାର୍ଶାଲ୍ ଦ୍ୱୀପପୁଞ୍ଜ", "ଉତ୍ତର ମାସେଡୋନିଆ", "ମାଲି", "ମିଆଁମାର", "ମଙ୍ଗୋଲିଆ", "ମାକାଉ ଏସଏଆର୍\u{200C} ଚାଇନା", "ଉତ୍ତର ମାରିଆନା ଦ୍ୱୀପପୁଞ୍ଜ", "ମାର୍ଟିନିକ୍ୟୁ", "ମୌରିଟାନିଆ", "ମଣ୍ଟେସେରାଟ୍", "ମାଲ୍ଟା", "ମରିସସ", "ମାଲଦିଭସ୍\u{200C}", "ମାଲୱି", "ମେକ୍ସିକୋ", "ମାଲେସିଆ", "ମୋଜାମ୍ବିକ୍\u{200C}", "ନାମିବିଆ", "ନୂତନ କାଲେଡୋନିଆ", "ନାଇଜର", "ନର୍ଫକ୍ ଦ୍ଵୀପ", "ନାଇଜେରିଆ", "ନିକାରାଗୁଆ", "ନେଦରଲ୍ୟାଣ୍ଡ", "ନରୱେ", "ନେପାଳ", "ନାଉରୁ", "ନିଉ", "ନ୍ୟୁଜିଲାଣ୍ଡ", "ଓମାନ୍", "ପାନାମା", "ପେରୁ", "ଫ୍ରେଞ୍ଚ ପଲିନେସିଆ", "ପପୁଆ ନ୍ୟୁ ଗିନି", "ଫିଲିପାଇନସ୍", "ପାକିସ୍ତାନ", "ପୋଲାଣ୍ଡ", "ସେଣ୍ଟ ପିଏରେ ଏବଂ ମିକ୍ୱେଲନ୍\u{200C}", "ପିଟକାଇରିନ୍\u{200C} ଦ୍ୱୀପପୁଞ୍ଜ", "ପୁଏର୍ତ୍ତୋ ରିକୋ", "ପାଲେଷ୍ଟେନିୟ ଭୂଭାଗ", "ପର୍ତ୍ତୁଗାଲ୍", "ପାଲାଉ", "ପାରାଗୁଏ", "କତାର୍", "ସୀମାନ୍ତବର୍ତ୍ତୀ ଓସିନିଆ", "ରିୟୁନିଅନ୍", "ରୋମାନିଆ", "ସର୍ବିଆ", "ରୁଷିଆ", "ରାୱାଣ୍ଡା", "ସାଉଦି ଆରବିଆ", "ସୋଲୋମନ୍\u{200C} ଦ୍ୱୀପପୁଞ୍ଜ", "ସେଚେଲସ୍", "ସୁଦାନ", "ସ୍ୱିଡେନ୍\u{200C}", "ସିଙ୍ଗାପୁର୍", "ସେଣ୍ଟ ହେଲେନା", "ସ୍ଲୋଭେନିଆ", "ସାଲବାର୍ଡ ଏବଂ ଜାନ୍\u{200C} ମାୟେନ୍\u{200C}", "ସ୍ଲୋଭାକିଆ", "ସିଏରା ଲିଓନ", "ସାନ୍ ମାରିନୋ", "ସେନେଗାଲ୍", "ସୋମାଲିଆ", "ସୁରିନାମ", "ଦକ୍ଷିଣ ସୁଦାନ", "ସାଓ ଟୋମେ ଏବଂ ପ୍ରିନସିପି", "ଏଲ୍ ସାଲଭାଡୋର୍", "ସିଣ୍ଟ ମାର୍ଟୀନ୍\u{200C}", "ସିରିଆ", "ଇସ୍ୱାତିନୀ", "ଟ୍ରାଇଷ୍ଟନ୍\u{200C} ଦା କୁନ୍\u{200C}ଚା", "ତୁର୍କସ୍\u{200C} ଏବଂ କାଇକୋସ୍\u{200C} ଦ୍ୱୀପପୁଞ୍ଜ", "ଚାଦ୍", "ଫରାସୀ ଦକ୍ଷିଣ କ୍ଷେତ୍ର", "ଟୋଗୋ", "ଥାଇଲ୍ୟାଣ୍ଡ", "ତାଜିକିସ୍ଥାନ୍", "ଟୋକେଲାଉ", "ତିମୋର୍-ଲେଷ୍ଟେ", "ତୁର୍କମେନିସ୍ତାନ", "ଟ୍ୟୁନିସିଆ", "ଟୋଙ୍ଗା", "ତୁର୍କୀ", "ତ୍ରିନିଦାଦ୍ ଏବଂ ଟୋବାଗୋ", "ତୁଭାଲୁ", "ତାଇୱାନ", "ତାଞ୍ଜାନିଆ", "ୟୁକ୍ରେନ୍", "ଉଗାଣ୍ଡା", "ଯୁକ୍ତରାଷ୍ଟ୍ର ଆଉଟ୍\u{200C}ଲାଇଙ୍ଗ ଦ୍ଵୀପପୁଞ୍ଜ", "ଜାତିସଂଘ", "ଯୁକ୍ତ ରାଷ୍ଟ୍ର", "ଉରୁଗୁଏ", "ଉଜବେକିସ୍ତାନ", "ଭାଟିକାନ୍ ସିଟି", "ସେଣ୍ଟ ଭିନସେଣ୍ଟ ଏବଂ ଗ୍ରେନାଡାଇନ୍ସ", "ଭେନେଜୁଏଲା", "ବ୍ରିଟିଶ୍\u{200C} ଭର୍ଜିନ୍ ଦ୍ୱୀପପୁଞ୍ଜ", "ଯୁକ୍ତରାଷ୍ଟ୍ର ଭର୍ଜିନ୍ ଦ୍ଵୀପପୁଞ୍ଜ", "ଭିଏତନାମ୍", "ଭାନୁଆତୁ", "ୱାଲିସ୍ ଏବଂ ଫୁତୁନା", "ସାମୋଆ", "ସିୟୁଡୋ-ଏସେଣ୍ଟ", "ସିୟୁଡୋ-ବିଡି", "କୋସୋଭୋ", "ୟେମେନ୍", "ମାୟୋଟେ", "ଦକ୍ଷିଣ ଆଫ୍ରିକା", "ଜାମ୍ବିଆ", "ଜିମ୍ବାୱେ"];
+
+// pa
+static R74: [&str; 294] = ["ਸੰਸਾਰ", "ਅਫ਼ਰੀਕਾ", "ਉੱਤਰ ਅਮਰੀਕਾ", "ਦੱਖਣ ਅਮਰੀਕਾ", "ਓਸ਼ੇਨੀਆ", "ਪੱਛਮੀ ਅਫ਼ਰੀਕਾ", "ਕੇਂਦਰੀ ਅਮਰੀਕਾ", "ਪੂਰਬੀ ਅਫ਼ਰੀਕਾ", "ਉੱਤਰੀ ਅਫ਼ਰੀਕਾ", "ਮੱਧ ਅਫ਼ਰੀਕਾ", "ਦੱਖਣੀ ਅਫ਼ਰੀਕਾ", "ਅਮਰੀਕਾ", "ਉੱਤਰੀ ਅਮਰੀਕਾ", "ਕੈਰੇਬੀਆਈ", "ਪੂਰਬੀ ਏਸ਼ੀਆ", "ਦੱਖਣੀ ਏਸ਼ੀਆ", "ਦੱਖਣ-ਪੂਰਬੀ ਏਸ਼ੀਆ", "ਦੱਖਣੀ ਯੂਰਪ", "ਆਸਟਰੇਲੇਸ਼ੀਆ", "ਮੇਲਾਨੇਸ਼ੀਆ", "ਮਾਇਕ੍ਰੋਨੇਸ਼ੀਆਈ ਇਲਾਕਾ", "ਪੋਲੀਨੇਸ਼ੀਆ", "ਏਸ਼ੀਆ", "ਕੇਂਦਰੀ ਏਸ਼ੀਆ", "ਪੱਛਮੀ ਏਸ਼ੀਆ", "ਯੂਰਪ", "ਪੂਰਬੀ ਯੂਰਪ", "ਉੱਤਰੀ ਯੂਰਪ", "ਪੱਛਮੀ ਯੂਰਪ", "ਉਪ-ਸਹਾਰਾ ਅਫ਼ਰੀਕਾ", "ਲਾਤੀਨੀ ਅਮਰੀਕਾ", "ਅਸੈਂਸ਼ਨ ਟਾਪੂ", "ਅੰਡੋਰਾ", "ਸੰਯੁਕਤ ਅਰਬ ਅਮੀਰਾਤ", "ਅਫ਼ਗਾਨਿਸਤਾਨ", "ਐਂਟੀਗੁਆ ਅਤੇ ਬਾਰਬੁਡਾ", "ਅੰਗੁਇਲਾ", "ਅਲਬਾਨੀਆ", "ਅਰਮੀਨੀਆ", "ਅੰਗੋਲਾ", "ਅੰਟਾਰਕਟਿਕਾ", "ਅਰਜਨਟੀਨਾ", "ਅਮੈਰੀਕਨ ਸਮੋਆ", "ਆਸਟਰੀਆ", "ਆਸਟ੍ਰੇਲੀਆ", "ਅਰੂਬਾ", "ਅਲੈਂਡ ਟਾਪੂ", "ਅਜ਼ਰਬਾਈਜਾਨ", "ਬੋਸਨੀਆ ਅਤੇ ਹਰਜ਼ੇਗੋਵੀਨਾ", "ਬਾਰਬਾਡੋਸ", "ਬੰਗਲਾਦੇਸ਼", "ਬੈਲਜੀਅਮ", "ਬੁਰਕੀਨਾ ਫ਼ਾਸੋ", "ਬੁਲਗਾਰੀਆ", "ਬਹਿਰੀਨ", "ਬੁਰੁੰਡੀ", "ਬੇਨਿਨ", "ਸੇਂਟ ਬਾਰਥੇਲੇਮੀ", "ਬਰਮੂਡਾ", "ਬਰੂਨੇਈ", "ਬੋਲੀਵੀਆ", "ਕੈਰੇਬੀਆਈ ਨੀਦਰਲੈਂਡ", "ਬ੍ਰਾਜ਼ੀਲ", "ਬਹਾਮਾਸ", "ਭੂਟਾਨ", "ਬੌਵੇਟ ਟਾਪੂ", "ਬੋਤਸਵਾਨਾ", "ਬੇਲਾਰੂਸ", "ਬੇਲੀਜ਼", "ਕੈਨੇਡਾ", "ਕੋਕੋਸ (ਕੀਲਿੰਗ) ਟਾਪੂ", "ਕਾਂਗੋ - ਕਿੰਸ਼ਾਸਾ", "ਕੇਂਦਰੀ ਅਫ਼ਰੀਕੀ ਗਣਰਾਜ", "ਕਾਂਗੋ - ਬ੍ਰਾਜ਼ਾਵਿਲੇ", "ਸਵਿਟਜ਼ਰਲੈਂਡ", "ਕੋਟ ਡੀਵੋਆਰ", "ਕੁੱਕ ਟਾਪੂ", "ਚਿਲੀ", "ਕੈਮਰੂਨ", "ਚੀਨ", "ਕੋਲੰਬੀਆ", "ਕਲਿੱਪਰਟਨ ਟਾਪੂ", "ਸਾਰਕ", "ਕੋਸਟਾ ਰੀਕਾ", "ਕਿਊਬਾ", "ਕੇਪ ਵਰਡੇ", "ਕੁਰਾਕਾਓ", "ਕ੍ਰਿਸਮਿਸ ਟਾਪੂ", "ਸਾਇਪ੍ਰਸ", "ਚੈਕੀਆ", "ਜਰਮਨੀ", "ਡੀਇਗੋ ਗਾਰਸੀਆ", "ਜਿਬੂਤੀ", "ਡੈਨਮਾਰਕ", "ਡੋਮੀਨਿਕਾ", "ਡੋਮੀਨਿਕਾਈ ਗਣਰਾਜ", "ਅਲਜੀਰੀਆ", "ਸਿਓਟਾ ਅਤੇ ਮੇਲਿੱਲਾ", "ਇਕਵੇਡੋਰ", "ਇਸਟੋਨੀਆ", "ਮਿਸਰ", "ਪੱਛਮੀ ਸਹਾਰਾ", "ਇਰੀਟ੍ਰਿਆ", "ਸਪੇਨ", "ਇਥੋਪੀਆ", "ਯੂਰਪੀ ਸੰਘ", "ਯੂਰੋਜ਼ੋਨ", "ਫਿਨਲੈਂਡ", "ਫ਼ਿਜੀ", "ਫ਼ਾਕਲੈਂਡ ਟਾਪੂ", "ਮਾਇਕ੍ਰੋਨੇਸ਼ੀਆ", "ਫੈਰੋ ਟਾਪੂ", "ਫ਼ਰਾਂਸ", "ਗਬੋਨ", "ਯੂਨਾਈਟਡ ਕਿੰਗਡਮ", "ਗ੍ਰੇਨਾਡਾ", "ਜਾਰਜੀਆ", "ਫਰੈਂਚ ਗੁਇਆਨਾ", "ਗਰਨਜੀ", "ਘਾਨਾ", "ਜਿਬਰਾਲਟਰ", "ਗ੍ਰੀਨਲੈਂਡ", "ਗੈਂਬੀਆ", "ਗਿਨੀ", "ਗੁਆਡੇਲੋਪ", "ਭੂ-ਖੰਡੀ ਗਿਨੀ", "ਗ੍ਰੀਸ", "ਦੱਖਣੀ ਜਾਰਜੀਆ ਅਤੇ ਦੱਖਣੀ ਸੈਂਡਵਿਚ ਟਾਪੂ", "ਗੁਆਟੇਮਾਲਾ", "ਗੁਆਮ", "ਗਿਨੀ-ਬਿਸਾਉ", "ਗੁਯਾਨਾ", "ਹਾਂਗ ਕਾਂਗ ਐਸਏਆਰ ਚੀਨ", "ਹਰਡ ਤੇ ਮੈਕਡੋਨਾਲਡ ਟਾਪੂ", "ਹੋਂਡੁਰਸ", "ਕਰੋਏਸ਼ੀਆ", "ਹੈਤੀ", "ਹੰਗਰੀ", "ਕੇਨਾਰੀ ਟਾਪੂ", "ਇੰਡੋਨੇਸ਼ੀਆ", "ਆਇਰਲੈਂਡ", "ਇਜ਼ਰਾਈਲ", "ਆਇਲ ਆਫ ਮੈਨ", "ਭਾਰਤ", "ਬਰਤਾਨਵੀ ਹਿੰਦ ਮਹਾਂਸਾਗਰ ਖਿੱਤਾ", "ਇਰਾਕ", "ਈਰਾਨ", "ਆਈਸਲੈਂਡ", "ਇਟਲੀ", "ਜਰਸੀ", "ਜਮਾਇਕਾ", "ਜੌਰਡਨ", "ਜਪਾਨ", "ਕੀਨੀਆ", "ਕਿਰਗਿਜ਼ਸਤਾਨ", "ਕੰਬੋਡੀਆ", "ਕਿਰਬਾਤੀ", "ਕੋਮੋਰੋਸ", "ਸੇਂਟ ਕਿਟਸ ਐਂਡ ਨੇਵਿਸ", "ਉੱਤਰੀ ਕੋਰੀਆ", "ਦੱਖਣੀ ਕੋਰੀਆ", "ਕੁਵੈਤ", "ਕੇਮੈਨ ਟਾਪੂ", "ਕਜ਼ਾਖਸਤਾਨ", "ਲਾਓਸ", "ਲਿਬਨਾਨ", "ਸੇਂਟ ਲੂਸੀਆ", "ਲਿਚੇਂਸਟਾਇਨ", "ਸ੍ਰੀ ਲੰਕਾ", "ਲਾਈਬੀਰੀਆ", "ਲੇਸੋਥੋ", "ਲਿਥੁਆਨੀਆ", "ਲਕਜ਼ਮਬਰਗ", "ਲਾਤਵੀਆ", "ਲੀਬੀਆ", "ਮੋਰੱਕੋ", "ਮੋਨਾਕੋ", "ਮੋਲਡੋਵਾ", "ਮੋਂਟੇਨੇਗਰੋ", "ਸੇਂਟ ਮਾਰਟਿਨ", "ਮੈਡਾਗਾਸਕਰ", "ਮਾਰਸ਼ਲ ਟਾਪੂ", "ਉੱਤਰੀ ਮੈਕਡੋਨੀਆ", "ਮਾਲੀ", "ਮਿਆਂਮਾਰ (ਬਰਮਾ)", "ਮੰਗੋਲੀਆ", "ਮਕਾਉ ਐਸਏਆਰ ਚੀਨ", "ਉੱਤਰੀ ਮਾਰੀਆਨਾ ਟਾਪੂ", "ਮਾਰਟੀਨਿਕ", "ਮੋਰਿਟਾਨੀਆ", "ਮੋਂਟਸੇਰਾਤ", "ਮਾਲਟਾ", "ਮੌਰੀਸ਼ਸ", "ਮਾਲਦੀਵ", "ਮਲਾਵੀ", "ਮੈਕਸੀਕੋ", "ਮਲੇਸ਼ੀਆ", "ਮੋਜ਼ਾਮਬੀਕ", "ਨਾਮੀਬੀਆ", "ਨਿਊ ਕੈਲੇਡੋਨੀਆ", "ਨਾਈਜਰ", "ਨੋਰਫੌਕ ਟਾਪੂ", "ਨਾਈਜੀਰੀਆ", "ਨਿਕਾਰਾਗੁਆ", "ਨੀਦਰਲੈਂਡ", "ਨਾਰਵੇ", "ਨੇਪਾਲ", "ਨਾਉਰੂ", "ਨਿਯੂ", "ਨਿਊਜ਼ੀਲੈਂਡ", "ਓਮਾਨ", "ਪਨਾਮਾ", "ਪੇਰੂ", "ਫਰੈਂਚ ਪੋਲੀਨੇਸ਼ੀਆ", "ਪਾਪੂਆ ਨਿਊ ਗਿਨੀ", "ਫਿਲੀਪੀਨਜ", "ਪਾਕਿਸਤਾਨ", "ਪੋਲੈਂਡ", "ਸੇਂਟ ਪੀਅਰੇ ਐਂਡ ਮਿਕੇਲਨ", "ਪਿਟਕੇਰਨ ਟਾਪੂ", "ਪਿਊਰਟੋ ਰਿਕੋ", "ਫਿਲਸਤੀਨੀ ਖਿੱਤੇ", "ਪੁਰਤਗਾਲ", "ਪਲਾਉ", "ਪੈਰਾਗਵੇ", "ਕਤਰ", "ਆਊਟਲਾਇੰਗ ਓਸ਼ੀਨੀਆ", "ਰਿਯੂਨੀਅਨ", "ਰੋਮਾਨੀਆ", "ਸਰਬੀਆ", "ਰੂਸ", "ਰਵਾਂਡਾ", "ਸਾਊਦੀ ਅਰਬ", "ਸੋਲੋਮਨ ਟਾਪੂ", "ਸੇਸ਼ਲਸ", "ਸੂਡਾਨ", "ਸਵੀਡਨ", "ਸਿੰਗਾਪੁਰ", "ਸੇਂਟ ਹੇਲੇਨਾ", "ਸਲੋਵੇਨੀਆ", "ਸਵਾਲਬਰਡ ਅਤੇ ਜਾਨ ਮਾਯੇਨ", "ਸਲੋਵਾਕੀਆ", "ਸਿਏਰਾ ਲਿਓਨ", "ਸੈਨ ਮਰੀਨੋ", "ਸੇਨੇਗਲ", "ਸੋਮਾਲੀਆ", "ਸੂਰੀਨਾਮ", "ਦੱਖਣ ਸੁਡਾਨ", "ਸਾਓ ਟੋਮ ਅਤੇ ਪ੍ਰਿੰਸੀਪੇ", "ਅਲ ਸਲਵਾਡੋਰ", "ਸਿੰਟ ਮਾਰਟੀਨ", "ਸੀਰੀਆ", "ਇਸਵਾਤੀਨੀ", "ਟ੍ਰਿਸਟਾਨ ਦਾ ਕੁੰਹਾ", "ਟੁਰਕਸ ਅਤੇ ਕੈਕੋਸ ਟਾਪੂ", "ਚਾਡ", "ਫਰੈਂਚ ਦੱਖਣੀ ਪ੍ਰਦੇਸ਼", "ਟੋਗੋ", "ਥਾਈਲੈਂਡ", "ਤਾਜਿਕਿਸਤਾਨ", "ਟੋਕੇਲਾਉ", "ਤਿਮੋਰ-ਲੇਸਤੇ", "ਤੁਰਕਮੇਨਿਸਤਾਨ", "ਟਿਊਨੀਸ਼ੀਆ", "ਟੌਂਗਾ", "ਤੁਰਕੀ", "ਟ੍ਰਿਨੀਡਾਡ ਅਤੇ ਟੋਬਾਗੋ", "ਟੁਵਾਲੂ", "ਤਾਇਵਾਨ", "ਤਨਜ਼ਾਨੀਆ", "ਯੂਕਰੇਨ", "ਯੂਗਾਂਡਾ", "ਯੂ.ਐੱਸ. ਦੂਰ-ਦੁਰਾਡੇ ਟਾਪੂ", "ਸੰਯੁਕਤ ਰਾਸ਼ਟਰ", "ਸੰਯੁਕਤ ਰਾਜ (ਅਮਰੀਕਾ)", "ਉਰੂਗਵੇ", "ਉਜ਼ਬੇਕਿਸਤਾਨ", "ਵੈਟੀਕਨ ਸਿਟੀ", "ਸੇਂਟ ਵਿਨਸੈਂਟ ਐਂਡ ਗ੍ਰੇਨਾਡੀਨਸ", "ਵੇਨੇਜ਼ੂਏਲਾ", "ਬ੍ਰਿਟਿਸ਼ ਵਰਜਿਨ ਟਾਪੂ", "ਯੂ ਐੱਸ ਵਰਜਿਨ ਟਾਪੂ", "ਵੀਅਤਨਾਮ", "ਵਾਨੂਆਟੂ", "ਵਾਲਿਸ ਅਤੇ ਫੂਟੂਨਾ", "ਸਾਮੋਆ", "ਗਲਤ-ਉਚਾਰਨ", "ਲਿਖਤ ਦੀ ਗਲਤ ਦਿਸ਼ਾ", "ਕੋਸੋਵੋ", "ਯਮਨ", "ਮਾਯੋਟੀ", "ਦੱਖਣੀ ਅਫਰੀਕਾ", "ਜ਼ਾਮਬੀਆ", "ਜ਼ਿੰਬਾਬਵੇ"];
+
+// fa
+static R75: [&str; 294] = ["جهان", "افریقا", "امریکای شمالی", "امریکای جنوبی", "اقیانوسیه", "غرب افریقا", "امریکای مرکزی", "شرق افریقا", "شمال افریقا", "مرکز افریقا", "جنوب افریقا", "امریکا", "شمال امریکا", "کارائیب", "شرق آسیا", "جنوب آسیا", "جنوب شرق آسیا", "جنوب اروپا", "استرالزی", "ملانزی", "ناحیهٔ میکرونزی", "پلی\u{200C}نزی", "آسیا", "آسیای مرکزی", "غرب آسیا", "اروپا", "شرق اروپا", "شمال اروپا", "غرب اروپا", "افریقای جنوب صحرا", "امریکای لاتین", "جزایر آسنسیون", "آندورا", "امارات متحدهٔ عربی", "افغانستان", "آنتیگوا و باربودا", "آنگویلا", "آلبانی", "ارمنستان", "آنگولا", "جنوبگان", "آرژانتین", "ساموآی امریکا", "اتریش", "استرالیا", "آروبا", "جزایر آلاند", "جمهوری آذربایجان", "بوسنی و هرزگوین", "باربادوس", "بنگلادش", "بلژیک", "بورکینافاسو", "بلغارستان", "بحرین", "بوروندی", "بنین", "سن بارتلمی", "برمودا", "برونئی", "بولیوی", "جزایر کارائیب هلند", "برزیل", "باهاما", "بوتان", "جزیرهٔ بووه", "بوتسوانا", "بلاروس", "بلیز", "کانادا", "جزایر کوکوس", "کنگو - کینشاسا", "جمهوری افریقای مرکزی", "کنگو - برازویل", "سوئیس", "ساحل عاج", "جزایر کوک", "شیلی", "کامرون", "چین", "کلمبیا", "جزایر کلیپرتون", "سارک", "کاستاریکا", "کوبا", "کیپ\u{200C}ورد", "کوراسائو", "جزیرهٔ کریسمس", "قبرس", "چک", "آلمان", "دیه\u{200C}گو گارسیا", "جیبوتی", "دانمارک", "دومینیکا", "جمهوری دومینیکن", "الجزایر", "سبته و ملیله", "اکوادور", "استونی", "مصر", "صحرای غربی", "اریتره", "اسپانیا", "اتیوپی", "اتحادیهٔ اروپا", "منطقهٔ یورو", "فنلاند", "فیجی", "جزایر فالکلند", "میکرونزی", "جزایر فارو", "فرانسه", "گابن", "بریتانیا", "گرنادا", "گرجستان", "گویان فرانسه", "گرنزی", "غنا", "جبل\u{200C}الطارق", "گرینلند", "گامبیا", "گینه", "گوادلوپ", "گینهٔ استوایی", "یونان", "جورجیای جنوبی و جزایر ساندویچ جنوبی", "گواتمالا", "گوام", "گینهٔ بیسائو", "گویان", "هنگ\u{200C}کنگ، منطقهٔ ویژهٔ اداری چین", "هرد و جزایر مک\u{200C}دونالد", "هندوراس", "کرواسی", "هائیتی", "مجارستان", "جزایر قناری", "اندونزی", "ایرلند", "اسرائیل", "جزیرهٔ من", "هند", "قلمرو بریتانیا در اقیانوس هند", "عراق", "ایران", "ایسلند", "ایتالیا", "جرزی", "جامائیکا", "اردن", "ژاپن", "کنیا", "قرقیزستان", "کامبوج", "کیریباتی", "کومور", "سنت کیتس و نویس", "کرهٔ شمالی", "کرهٔ جنوبی", "کویت", "جزایر کِیمن", "قزاقستان", "لائوس", "لبنان", "سنت لوسیا", "لیختن\u{200C}اشتاین", "سری\u{200C}لانکا", "لیبریا", "لسوتو", "لیتوانی", "لوکزامبورگ", "لتونی", "لیبی", "مراکش", "موناکو", "مولداوی", "مونته\u{200C}نگرو", "سنت مارتین", "ماداگاسکار", "جزایر مارشال", "مقدونیهٔ شمالی", "مالی", "میانمار (برمه)", "مغولستان", "ماکائو، منطقهٔ ویژهٔ اداری چین", "جزایر ماریانای شمالی", "مارتینیک", "موریتانی", "مونت\u{200C}سرات", "مالت", "موریس", "مالدیو", "مالاوی", "مکزیک", "مالزی", "موزامبیک", "نامیبیا", "کالدونیای جدید", "نیجر", "جزیرهٔ نورفولک", "نیجریه", "نیکاراگوئه", "هلند", "نروژ", "نپال", "نائورو", "نیوئه", "نیوزیلند", "عمان", "پاناما", "پرو", "پلی\u{200C}نزی فرانسه", "پاپوا گینهٔ نو", "فیلیپین", "پاکستان", "لهستان", "سن پیر و میکلن", "جزایر پیت\u{200C}کرن", "پورتوریکو", "سرزمین\u{200C}های فلسطینی", "پرتغال", "پالائو", "پاراگوئه", "قطر", "بخش\u{200C}های دورافتادهٔ اقیانوسیه", "رئونیون", "رومانی", "صربستان", "روسیه", "رواندا", "عربستان سعودی", "جزایر سلیمان", "سیشل", "سودان", "سوئد", "سنگاپور", "سنت هلن", "اسلوونی", "سوالبارد و یان ماین", "اسلواکی", "سیرالئون", "سان\u{200C}مارینو", "سنگال", "سومالی", "سورینام", "سودان جنوبی", "سائوتومه و پرینسیپ", "السالوادور", "سنت مارتن", "سوریه", "اسواتینی", "تریستان دا کونا", "جزایر تورکس و کایکوس", "چاد", "سرزمین\u{200C}های جنوبی فرانسه", "توگو", "تایلند", "تاجیکستان", "توکلائو", "تیمور-لسته", "ترکمنستان", "تونس", "تونگا", "ترکیه", "ترینیداد و توباگو", "تووالو", "تایوان", "تانزانیا", "اوکراین", "اوگاندا", "جزایر دورافتادهٔ ایالات متحده", "سازمان ملل متحد", "ایالات متحده", "اروگوئه", "ازبکستان", "واتیکان", "سنت وینسنت و گرنادین", "ونزوئلا", "جزایر ویرجین بریتانیا", "جزایر ویرجین ایالات متحده", "ویتنام", "وانواتو", "والیس و فوتونا", "ساموآ", "انگلیسی با لهجه خارجی", "مجازی - دوجهته", "کوزوو", "یمن", "مایوت", "افریقای جنوبی", "زامبیا", "زیمبابوه"];
+
+// pl
+static R76: [&str; 294] = ["świat", "Afryka", "Ameryka Północna", "Ameryka Południowa", "Oceania", "Afryka Zachodnia", "Ameryka Środkowa", "Afryka Wschodnia", "Afryka Północna", "Afryka Środkowa", "Afryka Południowa", "Ameryka", "Ameryka Północna (USA, Kanada)", "Karaiby", "Azja Wschodnia", "Azja Południowa", "Azja Południowo-Wschodnia", "Europa Południowa", "Australazja", "Melanezja", "Region Mikronezji", "Polinezja", "Azja", "Azja Środkowa", "Azja Zachodnia", "Europa", "Europa Wschodnia", "Europa Północna", "Europa Zachodnia", "Afryka Subsaharyjska", "Ameryka Łacińska", "Wyspa Wniebowstąpienia", "Andora", "Zjednoczone Emiraty Arabskie", "Afganistan", "Antigua i Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktyda", "Argentyna", "Samoa Amerykańskie", "Austria", "Australia", "Aruba", "Wyspy Alandzkie", "Azerbejdżan", "Bośnia i Hercegowina", "Barbados", "Bangladesz", "Belgia", "Burkina Faso", "Bułgaria", "Bahrajn", "Burundi", "Benin", "Saint-Barthélemy", "Bermudy", "Brunei", "Boliwia", "Niderlandy Karaibskie", "Brazylia", "Bahamy", "Bhutan", "Wyspa Bouveta", "Botswana", "Białoruś", "Belize", "Kanada", "Wyspy Kokosowe", "Demokratyczna Republika Konga", "Republika Środkowoafrykańska", "Kongo", "Szwajcaria", "Côte d’Ivoire", "Wyspy Cooka", "Chile", "Kamerun", "Chiny", "Kolumbia", "Wyspa Clippertona", "Sark", "Kostaryka", "Kuba", "Republika Zielonego Przylądka", "Curaçao", "Wyspa Bożego Narodzenia", "Cypr", "Czechy", "Niemcy", "Diego Garcia", "Dżibuti", "Dania", "Dominika", "Dominikana", "Algieria", "Ceuta i Melilla", "Ekwador", "Estonia", "Egipt", "Sahara Zachodnia", "Erytrea", "Hiszpania", "Etiopia", "Unia Europejska", "strefa euro", "Finlandia", "Fidżi", "Falklandy", "Mikronezja", "Wyspy Owcze", "Francja", "Gabon", "Wielka Brytania", "Grenada", "Gruzja", "Gujana Francuska", "Guernsey", "Ghana", "Gibraltar", "Grenlandia", "Gambia", "Gwinea", "Gwadelupa", "Gwinea Równikowa", "Grecja", "Georgia Południowa i Sandwich Południowy", "Gwatemala", "Guam", "Gwinea Bissau", "Gujana", "SRA Hongkong (Chiny)", "Wyspy Heard i McDonalda", "Honduras", "Chorwacja", "Haiti", "Węgry", "Wyspy Kanaryjskie", "Indonezja", "Irlandia", "Izrael", "Wyspa Man", "Indie", "Brytyjskie Terytorium Oceanu Indyjskiego", "Irak", "Iran", "Islandia", "Włochy", "Jersey", "Jamajka", "Jordania", "Japonia", "Kenia", "Kirgistan", "Kambodża", "Kiribati", "Komory", "Saint Kitts i Nevis", "Korea Północna", "Korea Południowa", "Kuwejt", "Kajmany", "Kazachstan", "Laos", "Liban", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litwa", "Luksemburg", "Łotwa", "Libia", "Maroko", "Monako", "Mołdawia", "Czarnogóra", "Saint-Martin", "Madagaskar", "Wyspy Marshalla", "Macedonia Północna", "Mali", "Mjanma (Birma)", "Mongolia", "SRA Makau (Chiny)", "Mariany Północne", "Martynika", "Mauretania", "Montserrat", "Malta", "Mauritius", "Malediwy", "Malawi", "Meksyk", "Malezja", "Mozambik", "Namibia", "Nowa Kaledonia", "Niger", "Norfolk", "Nigeria", "Nikaragua", "Holandia", "Norwegia", "Nepal", "Nauru", "Niue", "Nowa Zelandia", "Oman", "Panama", "Peru", "Polinezja Francuska", "Papua-Nowa Gwinea", "Filipiny", "Pakistan", "Polska", "Saint-Pierre i Miquelon", "Pitcairn", "Portoryko", "Terytoria Palestyńskie", "Portugalia", "Palau", "Paragwaj", "Katar", "Oceania — wyspy dalekie", "Reunion", "Rumunia", "Serbia", "Rosja", "Rwanda", "Arabia Saudyjska", "Wyspy Salomona", "Seszele", "Sudan", "Szwecja", "Singapur", "Wyspa Świętej Heleny", "Słowenia", "Svalbard i Jan Mayen", "Słowacja", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sudan Południowy", "Wyspy Świętego Tomasza i Książęca", "Salwador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Turks i Caicos", "Czad", "Francuskie Terytoria Południowe i Antarktyczne", "Togo", "Tajlandia", "Tadżykistan", "Tokelau", "Timor Wschodni", "Turkmenistan", "Tunezja", "Tonga", "Turcja", "Trynidad i Tobago", "Tuvalu", "Tajwan", "Tanzania", "Ukraina", "Uganda", "Dalekie Wyspy Mniejsze Stanów Zjednoczonych", "Organizacja Narodów Zjednoczonych", "Stany Zjednoczone", "Urugwaj", "Uzbekistan", "Watykan", "Saint Vincent i Grenadyny", "Wenezuela", "Brytyjskie Wyspy Dziewicze", "Wyspy Dziewicze Stanów Zjednoczonych", "Wietnam", "Vanuatu", "Wallis i Futuna", "Samoa", "Pseudoakcenty", "Pseudodwukierunkowe", "Kosowo", "Jemen", "Majotta", "Republika Południowej Afryki", "Zambia", "Zimbabwe"];
+
+// ps
+static R77: [&str; 294] = ["نړۍ", "افريقا", "شمالی امریکا", "سويلي امريکا", "اوقيانوسيه", "لویدیځ افریقا", "منخنۍ امريکا", "ختیځ افریقا", "شمالي افریقا", "منځنۍ افریقا", "سويلي افريقا", "امريکې", "شمالي امریکا", "کیریبین", "ختیځ آسیا", "سويلي آسيا", "سويلي ختيځ آسيا", "سويلي اروپا", "آسترالیا", "ملانشیا", "د مایکرونیسینین سیمه", "پولنيسيا", "آسيا", "منځنۍ آسيا", "لویدیځ آسیا", "اروپا", "ختيځ اروپا", "شمالي اروپا", "لوېديځ اروپا", "سب سهارن افريقا", "لاتیني امریکا", "اسينشان ټاپو", "اندورا", "متحده عرب امارات", "افغانستان", "انټيګوا او باربودا", "انګیلا", "البانیه", "ارمنستان", "انګولا", "انتارکتیکا", "ارجنټاين", "امریکایی ساماوا", "اتریش", "آسټرالیا", "آروبا", "الاند ټاپوان", "اذربايجان", "بوسنيا او هېرزګوينا", "باربادوس", "بنگله دېش", "بیلجیم", "بورکینا فاسو", "بلغاریه", "بحرين", "بروندي", "بینن", "سينټ بارتيلمي", "برمودا", "برونائي", "بولیویا", "کیریبین هالینډ", "برازیل", "باهماس", "بهوټان", "بوویټ ټاپو", "بوتسوانه", "بیلاروس", "بلیز", "کاناډا", "کوکوز (کيلنګ) ټاپوګان", "کانګو - کینشاسا", "وسطي افريقا جمهور", "کانګو - بروزوییل", "سویس", "د عاج ساحل", "کوک ټاپوګان", "چیلي", "کامرون", "چین", "کولمبیا", "د کلپرټون ټاپو", "سارک", "کوستاریکا", "کیوبا", "کیپ ورد", "کوراکاو", "د کريسمس ټاپو", "قبرس", "چکیا", "المان", "ډایګو ګارسیا", "جبوتي", "ډنمارک", "دومینیکا", "جمهوريه ډومينيکن", "الجزایر", "سيوتا او ماليلا", "اکوادور", "استونیا", "مصر", "لويديځ صحارا", "اریتره", "هسپانیه", "حبشه", "اروپايي اتحاديه", "اروپايي سيمه", "فنلینډ", "فجي", "فاکلينډ ټاپوګان", "میکرونیزیا", "فارو ټاپو", "فرانسه", "ګابن", "برتانیه", "ګرنادا", "گورجستان", "فرانسوي ګانا", "ګرنسي", "ګانا", "جبل الطارق", "ګرینلینډ", "ګامبیا", "ګینه", "ګوادلوپ", "استوایی ګیني", "یونان", "سويلي جارجيا او سويلي سېنډوچ ټاپوګان", "ګواتیمالا", "ګوام", "ګینه بیسو", "ګیانا", "هانګ کانګ SAR چین", "هارډ او ميکډانلډ ټاپوګان", "هانډوراس", "کرواشيا", "هایټي", "مجارستان", "د کناري ټاپوګان", "اندونیزیا", "آيرلېنډ", "اسراييل", "د آئل آف مین", "هند", "د برتانوي هند سمندري سيمه", "عراق", "ايران", "آیسلینډ", "ایټالیه", "جرسی", "جمیکا", "اردن", "جاپان", "کینیا", "قرغزستان", "کمبودیا", "کیري باتي", "کوموروس", "سینټ کټس او نیویس", "شمالی کوریا", "سویلي کوریا", "کويت", "کیمان ټاپوګان", "قزاقستان", "لاوس", "لبنان", "سینټ لوسیا", "لیختن اشتاین", "سريلنکا", "لايبيريا", "لسوتو", "لیتوانیا", "لوګزامبورګ", "ليتهويا", "لیبیا", "مراکش", "موناکو", "مولدوا", "مونټینیګرو", "سینټ مارټن", "مدغاسکر", "مارشل ټاپوګان", "شمالي مقدونيه", "مالي", "ميانمار (برما)", "منګوليا", "مکاو SAR چین", "شمالي ماريانا ټاپوګان", "مارټینیک", "موریتانیا", "مانټیسیرت", "مالټا", "موریشیس", "مالديپ", "مالاوي", "میکسیکو", "مالیزیا", "موزمبيق", "نیمبیا", "نوی کالیډونیا", "نايجير", "نارفولک ټاپوګان", "نایجیریا", "نکاراګوا", "هالېنډ", "ناروۍ", "نیپال", "نایرو", "نیوو", "نیوزیلنډ", "عمان", "پاناما", "پیرو", "فرانسوي پولينيسيا", "پاپوا نيو ګيني", "فلپين", "پاکستان", "پولنډ", "سینټ پییر او میکولون", "پيټکيرن ټاپوګان", "پورتو ریکو", "فلسطیني سيمې", "پورتګال", "پلاؤ", "پاراګوی", "قطر", "بهرنۍ اوسيانه", "ریونین", "رومانیا", "سربيا", "روسیه", "روندا", "سعودي عربستان", "سليمان ټاپوګان", "سیچیلیس", "سوډان", "سویډن", "سينگاپور", "سینټ هیلینا", "سلوانیا", "سوالبارد او جان ميين", "سلواکیا", "سییرا لیون", "سان مارینو", "سينيګال", "سومالیا", "سورینام", "سويلي سوډان", "ساو ټیم او پرنسیپ", "سالوېډور", "سینټ مارټین", "سوریه", "اسواټيني", "تریستان دا کنها", "د ترکیې او کیکاسو ټاپو", "چاډ", "د فرانسې جنوبي سیمې", "ټوګو", "تهايلنډ", "تاجکستان", "توکیلو", "تيمور-ليسټ", "تورکمنستان", "تونس", "تونګا", "ترکي", "ټرينيډاډ او ټوباګو", "توالیو", "تائيوان", "تنزانیا", "اوکراین", "یوګانډا", "د متحده ایالاتو ټاپوګان", "ملگري ملتونه", "متحده آيالات", "یوروګوی", "اوزبکستان", "واتیکان ښار", "سینټ ویسنټینټ او ګرینډینز", "وینزویلا", "بریتانوی ویګور ټاپوګان", "د متحده آيالاتو ورجن ټاپوګان", "وېتنام", "واناتو", "والیس او فوتونا", "ساماوا", "جعلي خج", "سیډو بیډی", "کوسوو", "یمن", "مايوټ", "سویلي افریقا", "زیمبیا", "زیمبابوی"];
+
+// pt
+static R78: [&str; 294] = ["Mundo", "África", "América do Norte", "América do Sul", "Oceania", "África Ocidental", "América Central", "África Oriental", "África Setentrional", "África Central", "África Meridional", "Américas", "América Setentrional", "Caribe", "Ásia Oriental", "Ásia Meridional", "Sudeste Asiático", "Europa Meridional", "Australásia", "Melanésia", "Região da Micronésia", "Polinésia", "Ásia", "Ásia Central", "Ásia Ocidental", "Europa", "Europa Oriental", "Europa Setentrional", "Europa Ocidental", "África Subsaariana", "América Latina", "Ilha de Ascensão", "Andorra", "Emirados Árabes Unidos", "Afeganistão", "Antígua e Barbuda", "Anguila", "Albânia", "Armênia", "Angola", "Antártida", "Argentina", "Samoa Americana", "Áustria", "Austrália", "Aruba", "Ilhas Aland", "Azerbaijão", "Bósnia e Herzegovina", "Barbados", "Bangladesh", "Bélgica", "Burquina Faso", "Bulgária", "Barein", "Burundi", "Benin", "São Bartolomeu", "Bermudas", "Brunei", "Bolívia", "Países Baixos Caribenhos", "Brasil", "Bahamas", "Butão", "Ilha Bouvet", "Botsuana", "Bielorrússia", "Belize", "Canadá", "Ilhas Cocos (Keeling)", "Congo - Kinshasa", "República Centro-Africana", "República do Congo", "Suíça", "Costa do Marfim", "Ilhas Cook", "Chile", "Camarões", "China", "Colômbia", "Ilha de Clipperton", "Sark", "Costa Rica", "Cuba", "Cabo Verde", "Curaçao", "Ilha Christmas", "Chipre", "Tchéquia", "Alemanha", "Diego Garcia", "Djibuti", "Dinamarca", "Dominica", "República Dominicana", "Argélia", "Ceuta e Melilla", "Equador", "Estônia", "Egito", "Saara Ocidental", "Eritreia", "Espanha", "Etiópia", "União Europeia", "zona do euro", "Finlândia", "Fiji", "Ilhas Malvinas", "Micronésia", "Ilhas Faroé", "França", "Gabão", "Reino Unido", "Granada", "Geórgia", "Guiana Francesa", "Guernsey", "Gana", "Gibraltar", "Groenlândia", "Gâmbia", "Guiné", "Guadalupe", "Guiné Equatorial", "Grécia", "Ilhas Geórgia do Sul e Sandwich do Sul", "Guatemala", "Guam", "Guiné-Bissau", "Guiana", "Hong Kong, RAE da China", "Ilhas Heard e McDonald", "Honduras", "Croácia", "Haiti", "Hungria", "Ilhas Canárias", "Indonésia", "Irlanda", "Israel", "Ilha de Man", "Índia", "Território Britânico do Oceano Índico", "Iraque", "Irã", "Islândia", "Itália", "Jersey", "Jamaica", "Jordânia", "Japão", "Quênia", "Quirguistão", "Camboja", "Quiribati", "Comores", "São Cristóvão e Névis", "Coreia do Norte", "Coreia do Sul", "Kuwait", "Ilhas Cayman", "Cazaquistão", "Laos", "Líbano", "Santa Lúcia", "Liechtenstein", "Sri Lanka", "Libéria", "Lesoto", "Lituânia", "Luxemburgo", "Letônia", "Líbia", "Marrocos", "Mônaco", "Moldávia", "Montenegro", "São Martinho", "Madagascar", "Ilhas Marshall", "Macedônia do Norte", "Mali", "Mianmar (Birmânia)", "Mongólia", "Macau, RAE da China", "Ilhas Marianas do Norte", "Martinica", "Mauritânia", "Montserrat", "Malta", "Maurício", "Maldivas", "Malaui", "México", "Malásia", "Moçambique", "Namíbia", "Nova Caledônia", "Níger", "Ilha Norfolk", "Nigéria", "Nicarágua", "Países Baixos", "Noruega", "Nepal", "Nauru", "Niue", "Nova Zelândia", "Omã", "Panamá", "Peru", "Polinésia Francesa", "Papua-Nova Guiné", "Filipinas", "Paquistão", "Polônia", "São Pedro e Miquelão", "Ilhas Pitcairn", "Porto Rico", "Territórios palestinos", "Portugal", "Palau", "Paraguai", "Catar", "Oceania Remota", "Reunião", "Romênia", "Sérvia", "Rússia", "Ruanda", "Arábia Saudita", "Ilhas Salomão", "Seicheles", "Sudão", "Suécia", "Singapura", "Santa Helena", "Eslovênia", "Svalbard e Jan Mayen", "Eslováquia", "Serra Leoa", "San Marino", "Senegal", "Somália", "Suriname", "Sudão do Sul", "São Tomé e Príncipe", "El Salvador", "Sint Maarten", "Síria", "Essuatíni", "Tristão da Cunha", "Ilhas Turcas e Caicos", "Chade", "Territórios Franceses do Sul", "Togo", "Tailândia", "Tadjiquistão", "Tokelau", "Timor-Leste", "Turcomenistão", "Tunísia", "Tonga", "Turquia", "Trinidad e Tobago", "Tuvalu", "Taiwan", "Tanzânia", "Ucrânia", "Uganda", "Ilhas Menores Distantes dos EUA", "Nações Unidas", "Estados Unidos", "Uruguai", "Uzbequistão", "Cidade do Vaticano", "São Vicente e Granadinas", "Venezuela", "Ilhas Virgens Britânicas", "Ilhas Virgens Americanas", "Vietnã", "Vanuatu", "Wallis e Futuna", "Samoa", "Pseudossotaques", "Pseudobidirecional", "Kosovo", "Iêmen", "Mayotte", "África do Sul", "Zâmbia", "Zimbábue"];
+
+// qu
+static R79: [&str; 294] = ["Pacha", "Africa", "Norte America", "Sud America", "Oceania", "Africa Occidental", "America Central", "Africa Oriental", "Africa del Norte", "Africa Media", "Sud Africa", "America", "America del Norte", "Caribe", "Asia Oriental", "Asia del Sur", "Sureste de Asia", "Europa del Sur", "Australasia", "Melanesia", "Región Micronesia", "Polinesia", "Asia", "Asia Central", "Asia Occidental", "Europa", "Europa Oriental", "Europa del Norte", "Europa Occidental", "Africa Sub-Sahariana", "AmericaLatina", "Islas Ascensión", "Andorra", "Emiratos Árabes Unidos", "Afganistán", "Antigua y Barbuda", "Anguila", "Albania", "Armenia", "Angola", "Antártida", "Argentina", "Samoa Americana", "Austria", "Australia", "Aruba", "Islas Åland", "Azerbaiyán", "Bosnia y Herzegovina", "Barbados", "Bangladesh", "Bélgica", "Burkina Faso", "Bulgaria", "Baréin", "Burundi", "Benín", "San Bartolomé", "Bermudas", "Brunéi", "Bolivia", "Bonaire", "Brasil", "Bahamas", "Bután", "Isla Bouvet", "Botsuana", "Belarús", "Belice", "Canadá", "Islas Cocos", "Congo (RDC)", "República Centroafricana", "Congo", "Suiza", "Côte d’Ivoire", "Islas Cook", "Chile", "Camerún", "China", "Colombia", "Isla Clipperton", "Sark", "Costa Rica", "Cuba", "Cabo Verde", "Curazao", "Isla Christmas", "Chipre", "Chequia", "Alemania", "Diego García", "Yibuti", "Dinamarca", "Dominica", "República Dominicana", "Argelia", "Ceuta y Melilla", "Ecuador", "Estonia", "Egipto", "Sahara Occidental", "Eritrea", "España", "Etiopía", "Union Europea", "Eurozona", "Finlandia", "Fiyi", "Islas Malvinas", "Micronesia", "Islas Feroe", "Francia", "Gabón", "Reino Unido", "Granada", "Georgia", "Guayana Francesa", "Guernesey", "Ghana", "Gibraltar", "Groenlandia", "Gambia", "Guinea", "Guadalupe", "Guinea Ecuatorial", "Grecia", "Georgia del Sur e Islas Sandwich del Sur", "Guatemala", "Guam", "Guinea-Bisáu", "Guyana", "Hong Kong RAE China", "Islas Heard y McDonald", "Honduras", "Croacia", "Haití", "Hungría", "Islas Canarias", "Indonesia", "Irlanda", "Israel", "Isla de Man", "India", "Territorio Británico del Océano Índico", "Irak", "Irán", "Islandia", "Italia", "Jersey", "Jamaica", "Jordania", "Japón", "Kenia", "Kirguistán", "Camboya", "Kiribati", "Comoras", "San Cristóbal y Nieves", "Corea del Norte", "Corea del Sur", "Kuwait", "Islas Caimán", "Kazajistán", "Laos", "Líbano", "Santa Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesoto", "Lituania", "Luxemburgo", "Letonia", "Libia", "Marruecos", "Mónaco", "Moldova", "Montenegro", "San Martín", "Madagascar", "Islas Marshall", "Macedonia del Norte", "Malí", "Myanmar", "Mongolia", "Macao RAE China", "Islas Marianas del Norte", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauricio", "Maldivas", "Malawi", "México", "Malasia", "Mozambique", "Namibia", "Nueva Caledonia", "Níger", "Isla Norfolk", "Nigeria", "Nicaragua", "Países Bajos", "Noruega", "Nepal", "Nauru", "Niue", "Nueva Zelanda", "Omán", "Panamá", "Perú", "Polinesia Francesa", "Papúa Nueva Guinea", "Filipinas", "Pakistán", "Polonia", "San Pedro y Miquelón", "Islas Pitcairn", "Puerto Rico", "Palestina Kamachikuq", "Portugal", "Palaos", "Paraguay", "Qatar", "Oceanía Periférica", "Reunión", "Rumania", "Serbia", "Rusia", "Ruanda", "Arabia Saudí", "Islas Salomón", "Seychelles", "Sudán", "Suecia", "Singapur", "Santa Elena", "Eslovenia", "Svalbard y Jan Mayen", "Eslovaquia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam", "Sudán del Sur", "Santo Tomé y Príncipe", "El Salvador", "Sint Maarten", "Siria", "Esuatini", "Tristán de Acuña", "Islas Turcas y Caicos", "Chad", "Territorios Australes Franceses", "Togo", "Tailandia", "Tayikistán", "Tokelau", "Timor-Leste", "Turkmenistán", "Túnez", "Tonga", "Turquía", "Trinidad y Tobago", "Tuvalu", "Taiwán", "Tanzania", "Ucrania", "Uganda", "Islas menores alejadas de los EE.UU.", "Naciones Unidas", "Estados Unidos", "Uruguay", "Uzbekistán", "Santa Sede (Ciudad del Vaticano)", "San Vicente y las Granadinas", "Venezuela", "Islas Vírgenes Británicas", "EE.UU. Islas Vírgenes", "Vietnam", "Vanuatu", "Wallis y Futuna", "Samoa", "Seudo-Acentos", "Seudo-Bidi", "Kosovo", "Yemen", "Mayotte", "Sudáfrica", "Zambia", "Zimbabue"];
+
+// rm
+static R80: [&str; 294] = ["mund", "Africa", "America dal Nord", "America dal Sid", "Oceania", "Africa dal Vest", "America Centrala", "Africa da l’Ost", "Africa dal Nord", "Africa Centrala", "Africa Meridiunala", "America", "America dal nord", "Caribica", "Asia da l’Ost", "Asia dal Sid", "Asia dal Sidost", "Europa dal Sid", "Australasia", "Melanesia", "Regiun Micronesica", "Polinesia", "Asia", "Asia Centrala", "Asia dal Vest", "Europa", "Europa Orientala", "Europa dal Nord", "Europa dal Vest", "Africa Subsaharica", "America latina", "Insla da l’Ascensiun", "Andorra", "Emirats Arabs Unids", "Afganistan", "Antigua e Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "Samoa Americana", "Austria", "Australia", "Aruba", "Inslas Aland", "Aserbaidschan", "Bosnia ed Erzegovina", "Barbados", "Bangladesch", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Son Barthélemy", "Bermudas", "Brunei", "Bolivia", "Pajais Bass caribics", "Brasilia", "Bahamas", "Butan", "Insla da Bouvet", "Botsuana", "Belarus", "Belize", "Canada", "Inslas Cocos", "Congo-Kinshasa", "Republica Centralafricana", "Congo-Brazzaville", "Svizra", "Costa d’Ivur", "Inslas Cook", "Chile", "Camerun", "China", "Columbia", "Insla Clipperton", "Sark", "Costa Rica", "Cuba", "Cap Verd", "Curaçao", "Insla da Nadal", "Cipra", "Tschechia", "Germania", "Diego Garcia", "Dschibuti", "Danemarc", "Dominica", "Republica Dominicana", "Algeria", "Ceuta e Melilla", "Ecuador", "Estonia", "Egipta", "Sahara Occidentala", "Eritrea", "Spagna", "Etiopia", "Uniun europeica", "zona da l’euro", "Finlanda", "Fidschi", "Inslas Falkland", "Micronesia", "Inslas Feroe", "Frantscha", "Gabun", "Reginavel Unì", "Grenada", "Georgia", "Guyana Franzosa", "Guernsey", "Ghana", "Gibraltar", "Grönlanda", "Gambia", "Guinea", "Guadalupa", "Guinea Equatoriala", "Grezia", "Georgia dal Sid e las Inslas Sandwich dal Sid", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Regiun d’administraziun speziala Hongkong, China", "Inslas da Heard e McDonald", "Honduras", "Croazia", "Haiti", "Ungaria", "Inslas Canarias", "Indonesia", "Irlanda", "Israel", "Insla da Man", "India", "Territori Britannic da l’Ocean Indic", "Irac", "Iran", "Islanda", "Italia", "Jersey", "Giamaica", "Jordania", "Giapun", "Kenia", "Kirghistan", "Cambodscha", "Kiribati", "Comoras", "Son Cristof e Nevis", "Corea dal Nord", "Corea dal Sid", "Kuwait", "Inslas Cayman", "Kasachstan", "Laos", "Libanon", "Sontga Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxemburg", "Lettonia", "Libia", "Maroc", "Monaco", "Moldavia", "Montenegro", "Saint Martin", "Madagascar", "Inslas da Marshall", "Macedonia dal Nord", "Mali", "Myanmar (Burma)", "Mongolia", "Regiun d’administraziun speziala Macao, China", "Inslas Mariannas dal Nord", "Martinica", "Mauretania", "Montserrat", "Malta", "Mauritius", "Maledivas", "Malawi", "Mexico", "Malaisia", "Mosambic", "Namibia", "Nova Caledonia", "Niger", "Insla Norfolk", "Nigeria", "Nicaragua", "Pajais Bass", "Norvegia", "Nepal", "Nauru", "Niue", "Nova Zelanda", "Oman", "Panama", "Peru", "Polinesia Franzosa", "Papua Nova Guinea", "Filippinas", "Pakistan", "Pologna", "Saint Pierre e Miquelon", "Pitcairn", "Puerto Rico", "Territori Palestinais", "Portugal", "Palau", "Paraguai", "Qatar", "Oceania Periferica", "Réunion", "Rumenia", "Serbia", "Russia", "Ruanda", "Arabia Saudita", "Inslas da Salomon", "Seychellas", "Sudan", "Svezia", "Singapur", "Sontg’Elena", "Slovenia", "Svalbard e Jan Mayen", "Slovachia", "Sierra Leone", "Son Marin", "Senegal", "Somalia", "Surinam", "Sudan dal Sid", "São Tomé e Príncipe", "El Salvador", "Sint Maarten", "Siria", "Eswatini", "Tristan da Cunha", "Inslas Turks e Caicos", "Tschad", "Territoris Franzos Meridiunals", "Togo", "Tailanda", "Tadschikistan", "Tokelau", "Timor da l’Ost", "Turkmenistan", "Tunesia", "Tonga", "Tirchia", "Trinidad e Tobago", "Tuvalu", "Taiwan", "Tansania", "Ucraina", "Uganda", "Inslas Pitschnas Perifericas dals Stadis Unids da l’America", "Naziuns unidas", "Stadis Unids da l’America", "Uruguai", "Usbekistan", "Citad dal Vatican", "Son Vincenz e las Grenadinas", "Venezuela", "Inslas Virginas Britannicas", "Inslas Virginas Americanas", "Vietnam", "Vanuatu", "Wallis e Futuna", "Samoa", "pseudo-accents", "pseudo-bidi", "Cosovo", "Jemen", "Mayotte", "Africa dal Sid", "Sambia", "Simbabwe"];
+
+// ro
+static R81: [&str; 294] = ["Lume", "Africa", "America de Nord", "America de Sud", "Oceania", "Africa Occidentală", "America Centrală", "Africa Orientală", "Africa Septentrională", "Africa Centrală", "Africa Meridională", "Americi", "America Septentrională", "Caraibe", "Asia Orientală", "Asia Meridională", "Asia de Sud-Est", "Europa Meridională", "Australasia", "Melanezia", "Regiunea Micronezia", "Polinezia", "Asia", "Asia Centrală", "Asia Occidentală", "Europa", "Europa Orientală", "Europa Septentrională", "Europa Occidentală", "Africa Subsahariană", "America Latină", "Insula Ascension", "Andorra", "Emiratele Arabe Unite", "Afganistan", "Antigua și Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "Samoa Americană", "Austria", "Australia", "Aruba", "Insulele Åland", "Azerbaidjan", "Bosnia și Herțegovina", "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Saint-Barthélemy", "Bermuda", "Brunei", "Bolivia", "Insulele Caraibe Olandeze", "Brazilia", "Bahamas", "Bhutan", "Insula Bouvet", "Botswana", "Belarus", "Belize", "Canada", "Insulele Cocos (Keeling)", "Congo - Kinshasa", "Republica Centrafricană", "Congo - Brazzaville", "Elveția", "Côte d’Ivoire", "Insulele Cook", "Chile", "Camerun", "China", "Columbia", "Insula Clipperton", "Sark", "Costa Rica", "Cuba", "Capul Verde", "Curaçao", "Insula Christmas", "Cipru", "Cehia", "Germania", "Diego Garcia", "Djibouti", "Danemarca", "Dominica", "Republica Dominicană", "Algeria", "Ceuta și Melilla", "Ecuador", "Estonia", "Egipt", "Sahara Occidentală", "Eritreea", "Spania", "Etiopia", "Uniunea Europeană", "Zona euro", "Finlanda", "Fiji", "Insulele Falkland", "Micronezia", "Insulele Feroe", "Franța", "Gabon", "Regatul Unit", "Grenada", "Georgia", "Guyana Franceză", "Guernsey", "Ghana", "Gibraltar", "Groenlanda", "Gambia", "Guineea", "Guadelupa", "Guineea Ecuatorială", "Grecia", "Georgia de Sud și Insulele Sandwich de Sud", "Guatemala", "Guam", "Guineea-Bissau", "Guyana", "R.A.S. Hong Kong, China", "Insula Heard și Insulele McDonald", "Honduras", "Croația", "Haiti", "Ungaria", "Insulele Canare", "Indonezia", "Irlanda", "Israel", "Insula Man", "India", "Teritoriul Britanic din Oceanul Indian", "Irak", "Iran", "Islanda", "Italia", "Jersey", "Jamaica", "Iordania", "Japonia", "Kenya", "Kârgâzstan", "Cambodgia", "Kiribati", "Comore", "Saint Kitts și Nevis", "Coreea de Nord", "Coreea de Sud", "Kuweit", "Insulele Cayman", "Kazahstan", "Laos", "Liban", "Sfânta Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxemburg", "Letonia", "Libia", "Maroc", "Monaco", "Republica Moldova", "Muntenegru", "Sfântul Martin", "Madagascar", "Insulele Marshall", "Macedonia de Nord", "Mali", "Myanmar (Birmania)", "Mongolia", "R.A.S. Macao, China", "Insulele Mariane de Nord", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldive", "Malawi", "Mexic", "Malaysia", "Mozambic", "Namibia", "Noua Caledonie", "Niger", "Insula Norfolk", "Nigeria", "Nicaragua", "Țările de Jos", "Norvegia", "Nepal", "Nauru", "Niue", "Noua Zeelandă", "Oman", "Panama", "Peru", "Polinezia Franceză", "Papua-Noua Guinee", "Filipine", "Pakistan", "Polonia", "Saint-Pierre și Miquelon", "Insulele Pitcairn", "Puerto Rico", "Teritoriile Palestiniene", "Portugalia", "Palau", "Paraguay", "Qatar", "Oceania Periferică", "Réunion", "România", "Serbia", "Rusia", "Rwanda", "Arabia Saudită", "Insulele Solomon", "Seychelles", "Sudan", "Suedia", "Singapore", "Sfânta Elena", "Slovenia", "Svalbard și Jan Mayen", "Slovacia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Sudanul de Sud", "São Tomé și Príncipe", "El Salvador", "Sint-Maarten", "Siria", "Eswatini", "Tristan da Cunha", "Insulele Turks și Caicos", "Ciad", "Teritoriile Australe și Antarctice Franceze", "Togo", "Thailanda", "Tadjikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Turcia", "Trinidad și Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ucraina", "Uganda", "Insulele Îndepărtate ale S.U.A.", "Națiunile Unite", "Statele Unite ale Americii", "Uruguay", "Uzbekistan", "Statul Cetății Vaticanului", "Saint Vincent și Grenadinele", "Venezuela", "Insulele Virgine Britanice", "Insulele Virgine Americane", "Vietnam", "Vanuatu", "Wallis și Futuna", "Samoa", "Pseudo-accente", "Pseudo-Bidi", "Kosovo", "Yemen", "Mayotte", "Africa de Sud", "Zambia", "Zimbabwe"];
+
+// ru
+static R82: [&str; 294] = ["весь мир", "Африка", "Северная Америка", "Южная Америка", "Океания", "Западная Африка", "Центральная Америка", "Восточная Африка", "Северная Африка", "Центральная Африка", "Южная Африка", "Америка", "Североамериканский регион", "Карибы", "Восточная Азия", "Южная Азия", "Юго-Восточная Азия", "Южная Европа", "Австралазия", "Меланезия", "Микронезия", "Полинезия", "Азия", "Центральная Азия", "Западная Азия", "Европа", "Восточная Европа", "Северная Европа", "Западная Европа", "Тропическая Африка", "Латинская Америка", "о-в Вознесения", "Андорра", "ОАЭ", "Афганистан", "Антигуа и Барбуда", "Ангилья", "Албания", "Армения", "Ангола", "Антарктида", "Аргентина", "Американское Самоа", "Австрия", "Австралия", "Аруба", "Аландские о-ва", "Азербайджан", "Босния и Герцеговина", "Барбадос", "Бангладеш", "Бельгия", "Буркина-Фасо", "Болгария", "Бахрейн", "Бурунди", "Бенин", "Сен-Бартелеми", "Бермудские о-ва", "Бруней", "Боливия", "Бонэйр, Синт-Эстатиус и Саба", "Бразилия", "Багамы", "Бутан", "о-в Буве", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокосовые о-ва", "Конго - Киншаса", "Центрально-Африканская Республика", "Конго - Браззавиль", "Швейцария", "Кот-д’Ивуар", "о-ва Кука", "Чили", "Камерун", "Китай", "Колумбия", "о-в Клиппертон", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "о-в Рождества", "Кипр", "Чехия", "Германия", "Диего-Гарсия", "Джибути", "Дания", "Доминика", "Доминиканская Республика", "Алжир", "Сеута и Мелилья", "Эквадор", "Эстония", "Египет", "Западная Сахара", "Эритрея", "Испания", "Эфиопия", "Европейский союз", "еврозона", "Финляндия", "Фиджи", "Фолклендские о-ва", "Федеративные Штаты Микронезии", "Фарерские о-ва", "Франция", "Габон", "Великобритания", "Гренада", "Грузия", "Французская Гвиана", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Экваториальная Гвинея", "Греция", "Южная Георгия и Южные Сандвичевы о-ва", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Гонконг (САР)", "о-ва Херд и Макдональд", "Гондурас", "Хорватия", "Гаити", "Венгрия", "Канарские о-ва", "Индонезия", "Ирландия", "Израиль", "о-в Мэн", "Индия", "Британская территория в Индийском океане", "Ирак", "Иран", "Исландия", "Италия", "Джерси", "Ямайка", "Иордания", "Япония", "Кения", "Киргизия", "Камбоджа", "Кирибати", "Коморы", "Сент-Китс и Невис", "КНДР", "Республика Корея", "Кувейт", "о-ва Кайман", "Казахстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова", "Черногория", "Сен-Мартен", "Мадагаскар", "Маршалловы о-ва", "Северная Македония", "Мали", "Мьянма (Бирма)", "Монголия", "Макао (САР)", "Северные Марианские о-ва", "Мартиника", "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдивы", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Новая Каледония", "Нигер", "о-в Норфолк", "Нигерия", "Никарагуа", "Нидерланды", "Норвегия", "Непал", "Науру", "Ниуэ", "Новая Зеландия", "Оман", "Панама", "Перу", "Французская Полинезия", "Папуа — Новая Гвинея", "Филиппины", "Пакистан", "Польша", "Сен-Пьер и Микелон", "о-ва Питкэрн", "Пуэрто-Рико", "Палестинские территории", "Португалия", "Палау", "Парагвай", "Катар", "Внешняя Океания", "Реюньон", "Румыния", "Сербия", "Россия", "Руанда", "Саудовская Аравия", "Соломоновы о-ва", "Сейшельские о-ва", "Судан", "Швеция", "Сингапур", "о-в Св. Елены", "Словения", "Шпицберген и Ян-Майен", "Словакия", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Южный Судан", "Сан-Томе и Принсипи", "Сальвадор", "Синт-Мартен", "Сирия", "Эсватини", "Тристан-да-Кунья", "Тёркс и Кайкос", "Чад", "Французские Южные территории", "Того", "Таиланд", "Таджикистан", "Токелау", "Восточный Тимор", "Туркменистан", "Тунис", "Тонга", "Турция", "Тринидад и Тобаго", "Тувалу", "Тайвань", "Танзания", "Украина", "Уганда", "Внешние малые о-ва (США)", "Организация Объединенных Наций", "Соединенные Штаты", "Уругвай", "Узбекистан", "Ватикан", "Сент-Винсент и Гренадины", "Венесуэла", "Виргинские о-ва (Великобритания)", "Виргинские о-ва (США)", "Вьетнам", "Вануату", "Уоллис и Футуна", "Самоа", "псевдоакценты", "псевдо-Bidi", "Косово", "Йемен", "Майотта", "Южно-Африканская Республика", "Замбия", "Зимбабве"];
+
+// sa
+static R83: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "ब्राजील", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "चीन:", "", "", "", "", "", "", "", "", "", "", "जर्मनीदेश:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "फ़्रांस:", "", "संयुक्त राष्ट्र:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "भारतः", "", "", "", "", "इटली:", "", "", "", "जापन:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "रष्यदेश:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "संयुक्त राज्य:", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// sc
+static R84: [&str; 294] = ["Mundu", "Àfrica", "Amèrica de su Nord", "Amèrica de su Sud", "Otzeània", "Àfrica otzidentale", "Amèrica tzentrale", "Àfrica orientale", "Àfrica setentrionale", "Àfrica tzentrale", "Àfrica meridionale", "Amèricas", "Amèrica setentrionale", "Caràibes", "Àsia orientale", "Àsia meridionale", "Sud-est asiàticu", "Europa meridionale", "Australàsia", "Melanèsia", "Regione micronesiana", "Polinèsia", "Àsia", "Àsia tzentrale", "Àsia otzidentale", "Europa", "Europa orientale", "Europa setentrionale", "Europa otzidentale", "Àfrica sub-sahariana", "Amèrica latina", "Ìsula de s’Ascensione", "Andorra", "Emirados Àrabos Unidos", "Afghànistan", "Antigua e Barbuda", "Anguilla", "Albania", "Armènia", "Angola", "Antàrticu", "Argentina", "Samoa americanas", "Àustria", "Austràlia", "Aruba", "Ìsulas Åland", "Azerbaigiàn", "Bòsnia e Erzegòvina", "Barbados", "Bangladèsh", "Bèlgiu", "Burkina Faso", "Bulgaria", "Bahrein", "Burundi", "Benin", "Santu Bartolomeu", "Bermudas", "Brunei", "Bolìvia", "Caràibes olandesas", "Brasile", "Bahamas", "Bhutàn", "Ìsula Bouvet", "Botswana", "Bielorùssia", "Belize", "Cànada", "Ìsulas Cocos (Keeling)", "Congo - Kinshasa", "Repùblica Tzentrafricana", "Congo - Bratzaville", "Isvìtzera", "Costa de Avòriu", "Ìsulas Cook", "Tzile", "Camerùn", "Tzina", "Colòmbia", "Ìsula de Clipperton", "Sark", "Costa Rica", "Cuba", "Cabu birde", "Curaçao", "Ìsula de sa Natividade", "Tzipru", "Tzèchia", "Germània", "Diego Garcia", "Gibuti", "Danimarca", "Dominica", "Repùblica Dominicana", "Algeria", "Ceuta e Melilla", "Ècuador", "Estònia", "Egitu", "Sahara otzidentale", "Eritrea", "Ispagna", "Etiòpia", "Unione Europea", "Eurozona", "Finlàndia", "Fiji", "Ìsulas Falkland", "Micronèsia", "Ìsulas Føroyar", "Frantza", "Gabòn", "Regnu Unidu", "Grenada", "Geòrgia", "Guiana frantzesa", "Guernsey", "Ghana", "Gibilterra", "Groenlàndia", "Gàmbia", "Guinea", "Guadalupa", "Guinea Ecuadoriale", "Grètzia", "Geòrgia de su Sud e Ìsulas Sandwich Australes", "Guatemala", "Guàm", "Guinea-Bissau", "Guyana", "RAS tzinesa de Hong Kong", "Ìsulas Heard e McDonald", "Honduras", "Croàtzia", "Haiti", "Ungheria", "Ìsulas Canàrias", "Indonèsia", "Irlanda", "Israele", "Ìsula de Man", "Ìndia", "Territòriu Britànnicu de s’Otzèanu Indianu", "Iraq", "Iràn", "Islanda", "Itàlia", "Jersey", "Giamàica", "Giordània", "Giapone", "Kènya", "Kirghìzistan", "Cambòdia", "Kiribati", "Comoras", "Santu Cristolu e Nevis", "Corea de su Nord", "Corea de su Sud", "Kuwait", "Ìsulas Cayman", "Kazàkistan", "Laos", "Lèbanu", "Santa Lughia", "Liechtenstein", "Sri Lanka", "Libèria", "Lesotho", "Lituània", "Lussemburgu", "Letònia", "Lìbia", "Marocu", "Mònacu", "Moldàvia", "Montenegro", "Santu Martine", "Madagascàr", "Ìsulas Marshall", "Matzedònia de su Nord", "Mali", "Myanmàr (Birmània)", "Mongòlia", "RAS tzinesa de Macao", "Ìsulas Mariannas setentrionales", "Martinica", "Mauritània", "Montserrat", "Malta", "Maurìtzius", "Maldivas", "Malawi", "Mèssicu", "Malèsia", "Mozambicu", "Namìbia", "Caledònia Noa", "Niger", "Ìsula Norfolk", "Nigèria", "Nicaràgua", "Paisos Bassos", "Norvègia", "Nèpal", "Nauru", "Niue", "Zelanda Noa", "Omàn", "Pànama", "Perù", "Polinèsia frantzesa", "Pàpua Guinea Noa", "Filipinas", "Pàkistan", "Polònia", "Santu Predu e Miquelon", "Ìsulas Pìtcairn", "Puerto Rico", "Territòrios palestinesos", "Portogallu", "Palau", "Paraguày", "Catàr", "Otzeània perifèrica", "Riunione", "Romania", "Sèrbia", "Rùssia", "Ruanda", "Aràbia Saudita", "Ìsulas Salomone", "Seychelles", "Sudàn", "Isvètzia", "Singapore", "Santa Elene", "Islovènia", "Svalbard e Jan Mayen", "Islovàchia", "Sierra Leone", "Santu Marinu", "Senegal", "Somàlia", "Suriname", "Sudan de su Sud", "São Tomé e Príncipe", "El Salvador", "Sint Maarten", "Sìria", "Eswatini", "Tristan da Cunha", "Ìsulas Turks e Caicos", "Chad", "Terras australes frantzesas", "Togo", "Tailàndia", "Tagìkistan", "Tokelau", "Timor-Est", "Turkmènistan", "Tunisia", "Tonga", "Turchia", "Trinidad e Tobago", "Tuvalu", "Taiwàn", "Tanzània", "Ucraina", "Uganda", "Ìsulas perifèricas de sos Istados Unidos", "Natziones Unidas", "Istados Unidos", "Uruguày", "Uzbèkistan", "Tzitade de su Vaticanu", "Santu Vissente e sas Grenadinas", "Venetzuela", "Ìsulas Vèrgines Britànnicas", "Ìsulas Vèrgines de sos Istados Unidos", "Vietnam", "Vanuatu", "Wallis e Futuna", "Samoa", "psèudo-atzentos", "psèudo-bidi", "Kòssovo", "Yemen", "Mayotte", "Sudàfrica", "Zàmbia", "Zimbabwe"];
+
+// sd
+static R85: [&str; 294] = ["دنيا", "آفريڪا", "اتر آمريڪا", "ڏکڻ آمريڪا", "اوشنيا", "اولهه آفريقا", "وچ آمريڪا", "اوڀر آفريڪا", "اتر آفريڪا", "وچ آفريڪا", "ڏاکڻي آمريڪا", "آمريڪا", "اترين آمريڪا", "ڪيريبين", "اوڀر ايشيا", "ڏکڻ ايشيا", "ڏکڻ اوڀر ايشيا", "ڏکڻ يورپ", "آسٽریلیشیا", "میلانیشیا", "مائڪرونيشائي خطو", "پولینیشیا", "ايشيا", "وچ ايشيا", "اولهه ايشيا", "يورپ", "اوڀر يورپ", "اترين يورپ", "اولهه يورپ", "سب-سهارا آفريڪا", "لاطيني آمريڪا", "طلوع ٻيٽ", "اندورا", "متحده عرب امارات", "افغانستان", "اينٽيگا ۽ باربوڊا", "انگويلا", "البانيا", "آرمينيا", "انگولا", "انٽارڪٽيڪا", "ارجنٽينا", "آمريڪي ساموا", "آسٽريا", "آسٽريليا", "عروبا", "الند ٻيٽ", "آذربائيجان", "بوسنيا ۽ هرزيگووينا", "باربڊوس", "بنگلاديش", "بيلجيم", "برڪينا فاسو", "بلغاريا", "بحرين", "برونڊي", "بينن", "سینٽ برٿلیمی", "برمودا", "برونائي", "بوليويا", "ڪيريبين نيدرلينڊ", "برازيل", "باهاماس", "ڀوٽان", "بووٽ ٻيٽ", "بوٽسوانا", "بيلاروس", "بيليز", "ڪينيڊا", "ڪوڪوس ٻيٽ", "ڪانگو -ڪنشاسا", "وچ آفريقي جمهوريه", "ڪانگو - برازاویل", "سوئزرلينڊ", "ڪوٽي ويرا", "ڪوڪ ٻيٽ", "چلي", "ڪيمرون", "چين", "ڪولمبيا", "ڪلپرٽن ٻيٽ", "سارڪ", "ڪوسٽا ريڪا", "ڪيوبا", "ڪيپ وردي", "ڪيوراسائو", "ڪرسمس ٻيٽ", "سائپرس", "چيڪيا", "جرمني", "ڊئيگو گارسيا", "ڊجبيوتي", "ڊينمارڪ", "ڊومينيڪا", "ڊومينيڪن جمهوريه", "الجيريا", "سیوٽا ۽ میلیلا", "ايڪواڊور", "ايسٽونيا", "مصر", "اولهه صحارا", "ايريٽيريا", "اسپين", "ايٿوپيا", "يورپين يونين", "يورو زون", "فن لينڊ", "فجي", "فاڪ لينڊ ٻيٽ", "مائڪرونيشيا", "فارو ٻيٽ", "فرانس", "گبون", "برطانيہ", "گريناڊا", "جارجيا", "فرانسيسي گيانا", "گورنسي", "گهانا", "جبرالٽر", "گرين لينڊ", "گيمبيا", "گني", "گواڊیلوپ", "ايڪوٽوريل گائينا", "يونان", "ڏکڻ جارجيا ۽ ڏکڻ سينڊوچ ٻيٽ", "گوئٽي مالا", "گوام", "گني بسائو", "گيانا", "هانگ ڪانگ SAR چين", "هرڊ ۽ مڪڊونلڊ ٻيٽ", "هنڊورس", "ڪروئيشيا", "هيٽي", "هنگري", "ڪينري ٻيٽ", "انڊونيشيا", "آئرلينڊ", "اسرائيل", "انسانن جو ٻيٽ", "ڀارت", "برطانوي هندي سمنڊ خطو", "عراق", "ايران", "آئس لينڊ", "اٽلي", "جرسي", "جميڪا", "اردن", "جاپان", "ڪينيا", "ڪرغستان", "ڪمبوڊيا", "ڪرباتي", "ڪوموروس", "سينٽ ڪٽس و نيوس", "اتر ڪوريا", "ڏکڻ ڪوريا", "ڪويت", "ڪي مين ٻيٽ", "قازقستان", "لائوس", "لبنان", "سينٽ لوسيا", "لچي ٽينسٽين", "سري لنڪا", "لائبیریا", "ليسوٿو", "لٿونيا", "لگزمبرگ", "لاتويا", "لبيا", "مراڪش", "موناڪو", "مالدووا", "مونٽي نيگرو", "سينٽ مارٽن", "مدگاسڪر", "مارشل ٻيٽ", "اتر مقدونيا", "مالي", "ميانمار (برما)", "منگوليا", "مڪائو SAR چين", "اتريان ماريانا ٻيٽ", "مارتينڪ", "موريتانيا", "مونٽسراٽ", "مالٽا", "موريشس", "مالديپ", "مالاوي", "ميڪسيڪو", "ملائيشيا", "موزمبیق", "نيميبيا", "نیو ڪالیڊونیا", "نائيجر", "نورفوڪ ٻيٽ", "نائيجيريا", "نڪراگوا", "نيدرلينڊ", "ناروي", "نيپال", "نائورو", "نووي", "نيو زيلينڊ", "عمان", "پناما", "پيرو", "فرانسيسي پولينيشيا", "پاپوا نیو گني", "فلپائن", "پاڪستان", "پولينڊ", "سينٽ پيئر ۽ ميڪيلون", "پٽڪئرن ٻيٽ", "پيوئرٽو ريڪو", "فلسطيني علائقا", "پرتگال", "پلائو", "پيراگوءِ", "قطر", "بيروني سامونڊي", "ري يونين", "رومانيا", "سربيا", "روس", "روانڊا", "سعودي عرب", "سولومون ٻيٽَ", "شي شلز", "سوڊان", "سوئيڊن", "سنگاپور", "سينٽ ھيلينا", "سلوینیا", "سوالبارڊ ۽ يان ماین", "سلوواڪيا", "سيرا ليون", "سان مرینو", "سينيگال", "سوماليا", "سورينام", "ڏکڻ سوڊان", "سائو ٽومي ۽ پرنسپیي", "ال سلواڊور", "سنٽ مارٽن", "شام", "ايسواٽني", "ٽرسٽن دا ڪوها", "ترڪ ۽ ڪيڪوس ٻيٽ", "چاڊ", "فرانسيسي ڏاکڻي علائقا", "ٽوگو", "ٿائيليند", "تاجڪستان", "ٽوڪلائو", "تيمور ليستي", "ترڪمانستان", "تيونيسيا", "ٽونگا", "ترڪييي", "ٽرينيڊاڊ ۽ ٽوباگو", "توالو", "تائیوان", "تنزانيا", "يوڪرين", "يوگنڊا", "آمريڪي خارجي ٻيٽ", "گڏيل قومون", "آمريڪا جون گڏيل رياستون", "يوروگوءِ", "ازبڪستان", "ويٽڪين سٽي", "سینٽ ونسنت ۽ گریناڊینز", "وينزويلا", "برطانوي ورجن ٻيٽ", "آمريڪي ورجن ٻيٽ", "ويتنام", "وينيٽيو", "والس ۽ فتونا", "ساموا", "سوڊو-لهجا", "سوڊو-بي ڊي", "ڪوسووو", "يمن", "مياتي", "ڏکڻ آفريقا", "زيمبيا", "زمبابوي"];
+
+// sr
+static R86: [&str; 294] = ["свет", "Африка", "Северноамерички континент", "Јужна Америка", "Океанија", "Западна Африка", "Централна Америка", "Источна Африка", "Северна Африка", "Централна Африка", "Јужна Африка", "Северна и Јужна Америка", "Северна Америка", "Кариби", "Источна Азија", "Јужна Азија", "Југоисточна Азија", "Јужна Европа", "Аустралија и Нови Зеланд", "Меланезија", "Микронезијски регион", "Полинезија", "Азија", "Централна Азија", "Западна Азија", "Европа", "Источна Европа", "Северна Европа", "Западна Европа", "Подсахарска Африка", "Латинска Америка", "Острво Асенсион", "Андора", "Уједињени Арапски Емирати", "Авганистан", "Антигва и Барбуда", "Ангвила", "Албанија", "Јерменија", "Ангола", "Антарктик", "Аргентина", "Америчка Самоа", "Аустрија", "Аустралија", "Аруба", "Оландска Острва", "Азербејџан", "Босна и Херцеговина", "Барбадос", "Бангладеш", "Белгија", "Буркина Фасо", "Бугарска", "Бахреин", "Бурунди", "Бенин", "Свети Бартоломеј", "Бермуди", "Брунеј", "Боливија", "Карипска Холандија", "Бразил", "Бахами", "Бутан", "Острво Буве", "Боцвана", "Белорусија", "Белизе", "Канада", "Кокосова (Килингова) Острва", "Конго - Киншаса", "Централноафричка Република", "Конго - Бразавил", "Швајцарска", "Обала Слоноваче (Кот д’Ивоар)", "Кукова Острва", "Чиле", "Камерун", "Кина", "Колумбија", "Острво Клипертон", "Сарк", "Костарика", "Куба", "Зеленортска Острва", "Курасао", "Божићно Острво", "Кипар", "Чешка", "Немачка", "Дијего Гарсија", "Џибути", "Данска", "Доминика", "Доминиканска Република", "Алжир", "Сеута и Мелиља", "Еквадор", "Естонија", "Египат", "Западна Сахара", "Еритреја", "Шпанија", "Етиопија", "Европска унија", "Еврозона", "Финска", "Фиџи", "Фокландска Острва", "Микронезија", "Фарска Острва", "Француска", "Габон", "Уједињено Краљевство", "Гренада", "Грузија", "Француска Гвајана", "Гернзи", "Гана", "Гибралтар", "Гренланд", "Гамбија", "Гвинеја", "Гваделуп", "Екваторијална Гвинеја", "Грчка", "Јужна Џорџија и Јужна Сендвичка Острва", "Гватемала", "Гуам", "Гвинеја-Бисао", "Гвајана", "САР Хонгконг (Кина)", "Острво Херд и Мекдоналдова острва", "Хондурас", "Хрватска", "Хаити", "Мађарска", "Канарска Острва", "Индонезија", "Ирска", "Израел", "Острво Ман", "Индија", "Британска територија Индијског океана", "Ирак", "Иран", "Исланд", "Италија", "Џерзи", "Јамајка", "Јордан", "Јапан", "Кенија", "Киргистан", "Камбоџа", "Кирибати", "Коморска Острва", "Сент Китс и Невис", "Северна Кореја", "Јужна Кореја", "Кувајт", "Кајманска Острва", "Казахстан", "Лаос", "Либан", "Света Луција", "Лихтенштајн", "Шри Ланка", "Либерија", "Лесото", "Литванија", "Луксембург", "Летонија", "Либија", "Мароко", "Монако", "Молдавија", "Црна Гора", "Свети Мартин (Француска)", "Мадагаскар", "Маршалска Острва", "Северна Македонија", "Мали", "Мијанмар (Бурма)", "Монголија", "САР Макао (Кина)", "Северна Маријанска Острва", "Мартиник", "Мауританија", "Монсерат", "Малта", "Маурицијус", "Малдиви", "Малави", "Мексико", "Малезија", "Мозамбик", "Намибија", "Нова Каледонија", "Нигер", "Острво Норфок", "Нигерија", "Никарагва", "Холандија", "Норвешка", "Непал", "Науру", "Ниуе", "Нови Зеланд", "Оман", "Панама", "Перу", "Француска Полинезија", "Папуа Нова Гвинеја", "Филипини", "Пакистан", "Пољска", "Сен Пјер и Микелон", "Питкерн", "Порторико", "Палестинске територије", "Португалија", "Палау", "Парагвај", "Катар", "удаљена острва Океаније", "Реинион", "Румунија", "Србија", "Русија", "Руанда", "Саудијска Арабија", "Соломонска Острва", "Сејшели", "Судан", "Шведска", "Сингапур", "Света Јелена", "Словенија", "Свалбард и Јан Мајен", "Словачка", "Сијера Леоне", "Сан Марино", "Сенегал", "Сомалија", "Суринам", "Јужни Судан", "Сао Томе и Принципе", "Салвадор", "Свети Мартин (Холандија)", "Сирија", "Свазиленд", "Тристан да Куња", "Острва Туркс и Каикос", "Чад", "Француске Јужне Територије", "Того", "Тајланд", "Таџикистан", "Токелау", "Тимор-Лесте (Источни Тимор)", "Туркменистан", "Тунис", "Тонга", "Турска", "Тринидад и Тобаго", "Тувалу", "Тајван", "Танзанија", "Украјина", "Уганда", "Удаљена острва САД", "Уједињене нације", "Сједињене Државе", "Уругвај", "Узбекистан", "Ватикан", "Сент Винсент и Гренадини", "Венецуела", "Британска Девичанска Острва", "Америчка Девичанска Острва", "Вијетнам", "Вануату", "Валис и Футуна", "Самоа", "симулиране дијакритике", "Псеудобиди", "Косово", "Јемен", "Мајот", "Јужноафричка Република", "Замбија", "Зимбабве"];
+
+// gd
+static R87: [&str; 294] = ["An Saoghal", "Afraga", "Aimeireaga a Tuath", "Aimeireaga a Deas", "Roinn a’ Chuain Sèimh", "Afraga an Iar", "Meadhan Aimeireaga", "Afraga an Ear", "Afraga a Tuath", "Meadhan Afraga", "Ceann a Deas Afraga", "An Dà Aimeireaga", "Ceann a Tuath Aimeireaga", "Am Muir Caraibeach", "Àisia an Ear", "Àisia a Deas", "Àisia an Ear-dheas", "An Roinn-Eòrpa a Deas", "Astràilia is Sealainn Nuadh", "Na h-Eileanan Dubha", "Roinn nam Meanbh-Eileanan", "Poilinèis", "Àisia", "Meadhan Àisia", "Àisia an Iar", "An Roinn-Eòrpa", "An Roinn-Eòrpa an Ear", "An Roinn-Eòrpa a Tuath", "An Roinn-Eòrpa an Iar", "Afraga Deas air an t-Sathara", "Aimeireaga Laidinneach", "Eilean na Deasgabhalach", "Andorra", "Na h-Iomaratan Arabach Aonaichte", "Afghanastàn", "Aintìoga is Barbuda", "Anguillia", "Albàinia", "Airmeinea", "Angòla", "An Antartaig", "An Argantain", "Samotha na h-Aimeireaga", "An Ostair", "Astràilia", "Arùba", "Na h-Eileanan Åland", "Asarbaideàn", "Bosna is Hearsagobhana", "Barbados", "Bangladais", "A’ Bheilg", "Buirciona Faso", "A’ Bhulgair", "Bachrain", "Burundaidh", "Beinin", "Saint Barthélemy", "Bearmùda", "Brùnaigh", "Boilibhia", "Na Tìrean Ìsle Caraibeach", "Braisil", "Na h-Eileanan Bhathama", "Butàn", "Eilean Bouvet", "Botsuana", "A’ Bhealaruis", "A’ Bheilìs", "Canada", "Na h-Eileanan Chocos (Keeling)", "Congo - Kinshasa", "Poblachd Meadhan Afraga", "A’ Chongo - Brazzaville", "An Eilbheis", "Côte d’Ivoire", "Eileanan Cook", "An t-Sile", "Camarun", "An t-Sìn", "Coloimbia", "Eilean Clipperton", "Sarc", "Costa Rìcea", "Cùba", "An Ceap Uaine", "Curaçao", "Eilean na Nollaig", "Cìopras", "An t-Seic", "A’ Ghearmailt", "Diego Garcia", "Diobùtaidh", "An Danmhairg", "Doiminicea", "A’ Phoblachd Dhoiminiceach", "Aildiria", "Ceuta agus Melilla", "Eacuador", "An Eastoin", "An Èipheit", "Sathara an Iar", "Eartra", "An Spàinnt", "An Itiop", "An t-Aonadh Eòrpach", "Raon an Eòro", "An Fhionnlann", "Fìdi", "Na h-Eileanan Fàclannach", "Na Meanbh-eileanan", "Na h-Eileanan Fàro", "An Fhraing", "Gabon", "An Rìoghachd Aonaichte", "Greanàda", "A’ Chairtbheil", "Guidheàna na Frainge", "Geàrnsaidh", "Gàna", "Diobraltar", "A’ Ghraonlann", "A’ Ghaimbia", "Gini", "Guadalup", "Gini Mheadhan-Chriosach", "A’ Ghreug", "Seòirsea a Deas is na h-Eileanan Sandwich a Deas", "Guatamala", "Guam", "Gini-Bioso", "Guidheàna", "Hong Kong SAR na Sìne", "Eilean Heard is Eileanan MhicDhòmhnaill", "Hondùras", "A’ Chròthais", "Haidhti", "An Ungair", "Na h-Eileanan Canàrach", "Na h-Innd-innse", "Èirinn", "Iosrael", "Eilean Mhanainn", "Na h-Innseachan", "Ranntair Breatannach Cuan nan Innseachan", "Ioràc", "Ioràn", "Innis Tìle", "An Eadailt", "Deàrsaidh", "Diameuga", "Iòrdan", "An t-Seapan", "Ceinia", "Cìorgastan", "Cambuidea", "Ciribeas", "Comoros", "Naomh Crìstean is Nibheis", "Coirèa a Tuath", "Coirèa", "Cuibhèit", "Na h-Eileanan Caimean", "Casachstàn", "Làthos", "Leabanon", "Naomh Lùisea", "Lichtenstein", "Sri Lanca", "Libèir", "Leasoto", "An Liotuain", "Lugsamburg", "An Laitbhe", "Libia", "Moroco", "Monaco", "A’ Mholdobha", "Am Monadh Neagrach", "Naomh Màrtainn", "Madagasgar", "Eileanan Mharshall", "A’ Mhasadon a Tuath", "Màili", "Miànmar", "Dùthaich nam Mongol", "Macàthu SAR na Sìne", "Na h-Eileanan Mairianach a Tuath", "Mairtinic", "Moratàinea", "Montsarat", "Malta", "Na h-Eileanan Mhoiriseas", "Na h-Eileanan Mhaladaibh", "Malabhaidh", "Meagsago", "Malaidhsea", "Mòsaimbic", "An Namaib", "Cailleann Nuadh", "Nìgeir", "Eilean Norfolk", "Nigèiria", "Niocaragua", "Na Tìrean Ìsle", "Nirribhidh", "Neapàl", "Nabhru", "Niue", "Sealainn Nuadh", "Omàn", "Panama", "Pearù", "Poilinèis na Frainge", "Gini Nuadh Phaputhach", "Na h-Eileanan Filipineach", "Pagastàn", "A’ Phòlainn", "Saint Pierre agus Miquelon", "Eileanan Pheit a’ Chàirn", "Porto Rìceo", "Ùghdarras nam Palastaineach", "A’ Phortagail", "Palabh", "Paraguaidh", "Catar", "Roinn Iomallach a’ Chuain Sèimh", "Réunion", "Romàinia", "An t-Sèirb", "An Ruis", "Rubhanda", "Aràibia nan Sabhd", "Eileanan Sholaimh", "Na h-Eileanan Sheiseall", "Sudàn", "An t-Suain", "Singeapòr", "Eilean Naomh Eilidh", "An t-Slòbhain", "Svalbard is Jan Mayen", "An t-Slòbhac", "Siarra Leòmhann", "San Marino", "Seanagal", "Somàilia", "Suranam", "Sudàn a Deas", "São Tomé agus Príncipe", "An Salbhador", "Sint Maarten", "Siridhea", "eSwatini", "Tristan da Cunha", "Na h-Eileanan Turcach is Caiceo", "An t-Seàd", "Ranntairean a Deas na Frainge", "Togo", "Dùthaich nan Tàidh", "Taidigeastàn", "Tokelau", "Timor-Leste", "Turcmanastàn", "Tuinisea", "Tonga", "An Tuirc", "Trianaid agus Tobago", "Tubhalu", "Taidh-Bhàn", "An Tansan", "An Ucràin", "Uganda", "Meanbh-Eileanan Iomallach nan SA", "Na Dùthchannan Aonaichte", "Na Stàitean Aonaichte", "Uruguaidh", "Usbagastàn", "Cathair na Bhatacain", "Naomh Bhionsant agus Eileanan Greanadach", "A’ Bheiniseala", "Eileanan Breatannach na Maighdinn", "Eileanan na Maighdinn aig na SA", "Bhiet-Nam", "Vanuatu", "Uallas agus Futuna", "Samotha", "Sràcan fuadain", "Dà-chomhaireach fuadain", "A’ Chosobho", "An Eaman", "Mayotte", "Afraga a Deas", "Sàimbia", "An t-Sìombab"];
+
+// si
+static R88: [&str; 294] = ["ලෝකය", "අප්\u{200D}රිකාව", "උතුරු ඇමෙරිකාව", "දකුණු ඇමෙරිකාව", "ඕෂනියාව", "බටහිරදිග අප්\u{200D}රිකාව", "මධ්\u{200D}යම ඇමෙරිකාව", "පෙරදිග අප්\u{200D}රිකාව", "උතුරුදිග අප්\u{200D}රිකාව", "මධ්\u{200D}යම අප්\u{200D}රිකාව", "දකුණුදිග අප්\u{200D}රිකාව", "ඇමරිකාව", "උතුරුදිග ඇමෙරිකාව", "කැරීබියන්", "නැගෙනහිර ආසියාව", "දකුණු ආසියාව", "අග්නිදිග ආසියාව", "දකුණුදිග යුරෝපය", "ඕස්ට්\u{200D}රලේෂියාව", "මෙලනීසියාව", "මයික්\u{200D}රෝනීසියානු කළාපය", "පොලිනීසියාව", "ආසියාව", "මධ්\u{200D}යම ආසියාව", "බටහිර ආසියාව", "යුරෝපය", "නැගෙනහිර යුරෝපය", "උතුරු යුරෝපය", "බටහිර යුරෝපය", "උප-සහරානු අප්\u{200D}රිකාව", "ලතින් ඇමෙරිකාව", "ඇසෙන්ෂන් දිවයින", "ඇන්ඩෝරාව", "එක්සත් අරාබි එමිර් රාජ්\u{200D}යය", "ඇෆ්ගනිස්ථානය", "ඇන්ටිගුවා සහ බාබියුඩාව", "ඇන්ගුයිලාව", "ඇල්බේනියාව", "ආර්මේනියාව", "ඇන්ගෝලාව", "ඇන්ටාක්ටිකාව", "ආර්ජෙන්ටිනාව", "ඇමරිකානු සැමෝවා", "ඔස්ට්\u{200D}රියාව", "ඕස්ට්\u{200D}රේලියාව", "අරූබා", "ඕලන්ඩ් දූපත්", "අසර්බයිජානය", "බොස්නියාව සහ හර්සගොවීනාව", "බාබඩෝස්", "බංග්ලාදේශය", "බෙල්ජියම", "බර්කිනා ෆාසෝ", "බල්ගේරියාව", "බහරේන්", "බුරුන්දි", "බෙනින්", "ශාන්ත බර්තලෙමි", "බර්මියුඩා", "බෲනායි", "බොලීවියාව", "කැරිබියානු නෙදර්ලන්තය", "බ්\u{200D}රසීලය", "බහමාස්", "භූතානය", "බුවට් දුපත්", "බොට්ස්වානා", "බෙලරුස්", "බෙලීස්", "කැනඩාව", "කොකෝස් දූපත්", "කොංගෝව (ඩීආර්සී)", "මධ්\u{200D}යම අප්\u{200D}රිකානු ජනරජය", "කොංගො - බ්\u{200D}රසාවිල්", "ස්විස්ටර්ලන්තය", "කෝට් දි අයිවරි", "කුක් දූපත්", "චිලී", "කැමරූන්", "චීනය", "කොළොම්බියාව", "ක්ලීපර්ටන් දූපත", "සාර්ක්", "කොස්ටරිකාව", "කියුබාව", "කේප් වර්ඩ්", "කුරකාවෝ", "ක්\u{200D}රිස්මස් දූපත", "සයිප්\u{200D}රසය", "චෙචියාව", "ජර්මනිය", "දියාගෝ ගාර්සියා", "ජිබුටි", "ඩෙන්මාර්කය", "ඩොමිනිකාව", "ඩොමිනිකා ජනරජය", "ඇල්ජීරියාව", "සෙයුටා සහ මෙලිල්ලා", "ඉක්වදෝරය", "එස්තෝනියාව", "ඊජිප්තුව", "බටහිර සහරාව", "එරිත්\u{200D}රියාව", "ස්පාඤ්ඤය", "ඉතියෝපියාව", "යුරෝපා සංගමය", "යුරෝ කලාපය", "ෆින්ලන්තය", "ෆීජී", "ෆෝක්ලන්ත දූපත්", "මයික්\u{200D}රොනීසියාව", "ෆැරෝ දූපත්", "ප්\u{200D}රංශය", "ගැබොන්", "එක්සත් රාජධානිය", "ග්\u{200D}රැනඩාව", "ජෝර්ජියාව", "ප්\u{200D}රංශ ගයනාව", "ගර්න්සිය", "ඝානාව", "ජිබ්\u{200D}රෝල්ටාව", "ග්\u{200D}රීන්ලන්තය", "ගැම්බියාව", "ගිණියාව", "ග්වෝඩලෝප්", "සමක ගිනියාව", "ග්\u{200D}රීසිය", "දකුණු ජෝර්ජියාව සහ දකුණු සැන්ඩ්විච් දූපත්", "ගෝතමාලාව", "ගුවාම්", "ගිනි බිසව්", "ගයනාව", "හොංකොං විශේෂ පරිපාලන කලාපය චීනය", "හර්ඩ් දූපත සහ මැක්ඩොනල්ඩ් දූපත්", "හොන්ඩුරාස්", "ක්\u{200D}රොඒෂියාව", "හයිටි", "හන්ගේරියාව", "කැනරි සූපත්", "ඉන්දුනීසියාව", "අයර්ලන්තය", "ඊශ්\u{200D}රායලය", "අයිල් ඔෆ් මෑන්", "ඉන්දියාව", "බ්\u{200D}රිතාන්\u{200D}ය ඉන්දීය සාගර බල ප්\u{200D}රදේශය", "ඉරාකය", "ඉරානය", "අයිස්ලන්තය", "ඉතාලිය", "ජර්සි", "ජැමෙයිකාව", "ජෝර්දානය", "ජපානය", "කෙන්යාව", "කිර්ගිස්තානය", "කාම්බෝජය", "කිරිබති", "කොමොරෝස්", "ශාන්ත කිට්ස් සහ නේවිස්", "උතුරු කොරියාව", "දකුණු කොරියාව", "කුවේටය", "කේමන් දූපත්", "කසකස්තානය", "ලාඕසය", "ලෙබනනය", "ශාන්ත ලුසියා", "ලික්ටන්ස්ටයින්", "ශ්\u{200D}රී ලංකාව", "ලයිබීරියාව", "ලෙසතෝ", "ලිතුවේනියාව", "ලක්ශම්බර්ග්", "ලැට්වියාව", "ලිබියාව", "මොරොක්කෝව", "මොනාකෝව", "මොල්ඩෝවාව", "මොන්ටෙනීග්\u{200D}රෝ", "ශාන්ත මාර්ටින්", "මැඩගස්කරය", "මාෂල් දූපත්", "උතුරු මැසිඩෝනියාව", "මාලි", "මියන්මාරය (බුරුමය)", "මොන්ගෝලියාව", "මැකාවු විශේෂ පරිපාලන කලාපය චීනය", "උතුරු මරියානා දූපත්", "මර්ටිනික්", "මොරිටේනියාව", "මොන්සෙරාට්", "මෝල්ටාව", "මුරුසිය", "මාල දිවයින", "මලාවි", "මෙක්සිකෝව", "මැලේසියාව", "මොසැම්බික්", "නැමීබියාව", "නව කැලිඩෝනියාව", "නයිජර්", "නෝෆෝක් දූපත", "නයිජීරියාව", "නිකරගුවාව", "නෙදර්ලන්තය", "නෝර්වේ", "නේපාලය", "නාවුරු", "නියූ", "නවසීලන්තය", "ඕමානය", "පැනමාව", "පේරු", "ප්\u{200D}රංශ පොලිනීසියාව", "පැපුවා නිව් ගිනියාව", "පිලිපීනය", "පාකිස්තානය", "පෝලන්තය", "ශාන්ත පියරේ සහ මැකෝලන්", "පිට්කෙය්න් දූපත්", "පුවර්ටෝ රිකෝ", "පලස්තීන රාජ්\u{200D}යය", "පෘතුගාලය", "පලාවු", "පැරගුවේ", "කටාර්", "ඈත ඕෂනියාව", "රීයුනියන්", "රුමේනියාව", "සර්බියාව", "රුසියාව", "රුවන්ඩාව", "සෞදි අරාබිය", "සොලමන් දූපත්", "සීශෙල්ස්", "සූඩානය", "ස්වීඩනය", "සිංගප්පූරුව", "ශාන්ත හෙලේනා", "ස්ලෝවේනියාව", "ස්වෙල්බර්ඩ් සහ ජේන් මයේන්", "ස්ලෝවැකියාව", "සියරාලියෝන්", "සැන් මැරිනෝ", "සෙනගාලය", "සෝමාලියාව", "සුරිනාමය", "දකුණු සුඩානය", "සාඕ තෝම් සහ ප්\u{200D}රින්සිප්", "එල් සැල්වදෝරය", "ශාන්ත මාර්ටෙන්", "සිරියාව", "එස්වාටිනි", "ට්\u{200D}රිස්ටන් ද කුන්හා", "ටර්ක්ස් සහ කයිකොස් දූපත්", "චැච්", "දකුණු ප්\u{200D}රංශ දූපත් සමූහය", "ටොගෝ", "තායිලන්තය", "ටජිකිස්තානය", "ටොකලාවු", "ටිමෝර් - ලෙස්ත්", "ටර්ක්මෙනිස්ථානය", "ටියුනීසියාව", "ටොංගා", "තුර්කිය", "ට්\u{200D}රිනිඩෑඩ් සහ ටොබැගෝ", "ටුවාලූ", "තායිවානය", "ටැන්සානියාව", "යුක්රේනය", "උගන්ඩාව", "එක්සත් ජනපද ඈත දූපත්", "එක්සත් ජාතීන්", "එක්සත් ජනපදය", "උරුගුවේ", "උස්බෙකිස්ථානය", "වතිකානු නගරය", "ශාන්ත වින්සන්ට් සහ ග්\u{200D}රෙනඩින්ස්", "වෙනිසියුලාව", "බ්\u{200D}රිතාන්\u{200D}ය වර්ජින් දූපත්", "ඇමරිකානු වර්ජින් දූපත්", "වියට්නාමය", "වනුවාටු", "වැලිස් සහ ෆුටුනා", "සැමෝවා", "ව්\u{200D}යාජ-උච්චාරණ", "ව්\u{200D}යාජ-බිඩි", "කොසෝවෝ", "යේමනය", "මයෝට්", "දකුණු අප්\u{200D}රිකාව", "සැම්බියාව", "සිම්බාබ්වේ"];
+
+// sk
+static R89: [&str; 294] = ["svet", "Afrika", "Severná Amerika", "Južná Amerika", "Oceánia", "západná Afrika", "Stredná Amerika", "východná Afrika", "severná Afrika", "stredná Afrika", "južné územia Afriky", "Amerika", "severné územia Ameriky", "Karibik", "východná Ázia", "južná Ázia", "juhovýchodná Ázia", "južná Európa", "Australázia", "Melanézia", "oblasť Mikronézie", "Polynézia", "Ázia", "stredná Ázia", "západná Ázia", "Európa", "východná Európa", "severná Európa", "západná Európa", "subsaharská Afrika", "Latinská Amerika", "Ascension", "Andorra", "Spojené arabské emiráty", "Afganistan", "Antigua a Barbuda", "Anguilla", "Albánsko", "Arménsko", "Angola", "Antarktída", "Argentína", "Americká Samoa", "Rakúsko", "Austrália", "Aruba", "Alandy", "Azerbajdžan", "Bosna a Hercegovina", "Barbados", "Bangladéš", "Belgicko", "Burkina Faso", "Bulharsko", "Bahrajn", "Burundi", "Benin", "Svätý Bartolomej", "Bermudy", "Brunej", "Bolívia", "Karibské Holandsko", "Brazília", "Bahamy", "Bhután", "Bouvetov ostrov", "Botswana", "Bielorusko", "Belize", "Kanada", "Kokosové ostrovy", "Konžská demokratická republika", "Stredoafrická republika", "Konžská republika", "Švajčiarsko", "Pobrežie Slonoviny", "Cookove ostrovy", "Čile", "Kamerun", "Čína", "Kolumbia", "Clipperton", "Sark", "Kostarika", "Kuba", "Kapverdy", "Curaçao", "Vianočný ostrov", "Cyprus", "Česko", "Nemecko", "Diego Garcia", "Džibutsko", "Dánsko", "Dominika", "Dominikánska republika", "Alžírsko", "Ceuta a Melilla", "Ekvádor", "Estónsko", "Egypt", "Západná Sahara", "Eritrea", "Španielsko", "Etiópia", "Európska únia", "eurozóna", "Fínsko", "Fidži", "Falklandy", "Mikronézia", "Faerské ostrovy", "Francúzsko", "Gabon", "Spojené kráľovstvo", "Grenada", "Gruzínsko", "Francúzska Guyana", "Guernsey", "Ghana", "Gibraltár", "Grónsko", "Gambia", "Guinea", "Guadeloupe", "Rovníková Guinea", "Grécko", "Južná Georgia a Južné Sandwichove ostrovy", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong – OAO Číny", "Heardov ostrov a Macdonaldove ostrovy", "Honduras", "Chorvátsko", "Haiti", "Maďarsko", "Kanárske ostrovy", "Indonézia", "Írsko", "Izrael", "Ostrov Man", "India", "Britské indickooceánske územie", "Irak", "Irán", "Island", "Taliansko", "Jersey", "Jamajka", "Jordánsko", "Japonsko", "Keňa", "Kirgizsko", "Kambodža", "Kiribati", "Komory", "Svätý Krištof a Nevis", "Severná Kórea", "Južná Kórea", "Kuvajt", "Kajmanie ostrovy", "Kazachstan", "Laos", "Libanon", "Svätá Lucia", "Lichtenštajnsko", "Srí Lanka", "Libéria", "Lesotho", "Litva", "Luxembursko", "Lotyšsko", "Líbya", "Maroko", "Monako", "Moldavsko", "Čierna Hora", "Svätý Martin (fr.)", "Madagaskar", "Marshallove ostrovy", "Severné Macedónsko", "Mali", "Mjanmarsko", "Mongolsko", "Macao – OAO Číny", "Severné Mariány", "Martinik", "Mauritánia", "Montserrat", "Malta", "Maurícius", "Maldivy", "Malawi", "Mexiko", "Malajzia", "Mozambik", "Namíbia", "Nová Kaledónia", "Niger", "Norfolk", "Nigéria", "Nikaragua", "Holandsko", "Nórsko", "Nepál", "Nauru", "Niue", "Nový Zéland", "Omán", "Panama", "Peru", "Francúzska Polynézia", "Papua-Nová Guinea", "Filipíny", "Pakistan", "Poľsko", "Saint Pierre a Miquelon", "Pitcairnove ostrovy", "Portoriko", "Palestínske územia", "Portugalsko", "Palau", "Paraguaj", "Katar", "ostatné Tichomorie", "Réunion", "Rumunsko", "Srbsko", "Rusko", "Rwanda", "Saudská Arábia", "Šalamúnove ostrovy", "Seychely", "Sudán", "Švédsko", "Singapur", "Svätá Helena", "Slovinsko", "Svalbard a Jan Mayen", "Slovensko", "Sierra Leone", "San Maríno", "Senegal", "Somálsko", "Surinam", "Južný Sudán", "Svätý Tomáš a Princov ostrov", "Salvádor", "Svätý Martin (hol.)", "Sýria", "Eswatini", "Tristan da Cunha", "Turks a Caicos", "Čad", "Francúzske južné a antarktické územia", "Togo", "Thajsko", "Tadžikistan", "Tokelau", "Východný Timor", "Turkménsko", "Tunisko", "Tonga", "Turecko", "Trinidad a Tobago", "Tuvalu", "Taiwan", "Tanzánia", "Ukrajina", "Uganda", "Menšie odľahlé ostrovy USA", "Organizácia Spojených národov", "Spojené štáty", "Uruguaj", "Uzbekistan", "Vatikán", "Svätý Vincent a Grenadíny", "Venezuela", "Britské Panenské ostrovy", "Americké Panenské ostrovy", "Vietnam", "Vanuatu", "Wallis a Futuna", "Samoa", "falošná diakritika", "obrátenie sprava doľava", "Kosovo", "Jemen", "Mayotte", "Južná Afrika", "Zambia", "Zimbabwe"];
+
+// sl
+static R90: [&str; 294] = ["svet", "Afrika", "Severna Amerika", "Južna Amerika", "Oceanija", "Zahodna Afrika", "Srednja Amerika", "Vzhodna Afrika", "Severna Afrika", "Srednja Afrika", "Južna Afrika", "Amerike", "severnoameriška celina", "Karibi", "Vzhodna Azija", "Južna Azija", "Jugovzhodna Azija", "Južna Evropa", "Avstralija in Nova Zelandija", "Melanezija", "mikronezijska regija", "Polinezija", "Azija", "Osrednja Azija", "Zahodna Azija", "Evropa", "Vzhodna Evropa", "Severna Evropa", "Zahodna Evropa", "podsaharska Afrika", "Latinska Amerika", "Otok Ascension", "Andora", "Združeni arabski emirati", "Afganistan", "Antigva in Barbuda", "Angvila", "Albanija", "Armenija", "Angola", "Antarktika", "Argentina", "Ameriška Samoa", "Avstrija", "Avstralija", "Aruba", "Alandski otoki", "Azerbajdžan", "Bosna in Hercegovina", "Barbados", "Bangladeš", "Belgija", "Burkina Faso", "Bolgarija", "Bahrajn", "Burundi", "Benin", "Sveti Bartolomej", "Bermudi", "Brunej", "Bolivija", "Nizozemski Karibi", "Brazilija", "Bahami", "Butan", "Bouvetov otok", "Bocvana", "Belorusija", "Belize", "Kanada", "Kokosovi otoki", "Demokratična republika Kongo", "Srednjeafriška republika", "Kongo - Brazzaville", "Švica", "Slonokoščena obala", "Cookovi otoki", "Čile", "Kamerun", "Kitajska", "Kolumbija", "Otok Clipperton", "Sark", "Kostarika", "Kuba", "Zelenortski otoki", "Curaçao", "Božični otok", "Ciper", "Češka", "Nemčija", "Diego Garcia", "Džibuti", "Danska", "Dominika", "Dominikanska republika", "Alžirija", "Ceuta in Melilla", "Ekvador", "Estonija", "Egipt", "Zahodna Sahara", "Eritreja", "Španija", "Etiopija", "Evropska unija", "evroobmočje", "Finska", "Fidži", "Falklandski otoki", "Mikronezija", "Ferski otoki", "Francija", "Gabon", "Združeno kraljestvo", "Grenada", "Gruzija", "Francoska Gvajana", "Guernsey", "Gana", "Gibraltar", "Grenlandija", "Gambija", "Gvineja", "Gvadelup", "Ekvatorialna Gvineja", "Grčija", "Južna Georgia in Južni Sandwichevi otoki", "Gvatemala", "Gvam", "Gvineja Bissau", "Gvajana", "Posebno upravno območje Ljudske republike Kitajske Hongkong", "Heardov otok in McDonaldovi otoki", "Honduras", "Hrvaška", "Haiti", "Madžarska", "Kanarski otoki", "Indonezija", "Irska", "Izrael", "Otok Man", "Indija", "Britansko ozemlje v Indijskem oceanu", "Irak", "Iran", "Islandija", "Italija", "Jersey", "Jamajka", "Jordanija", "Japonska", "Kenija", "Kirgizistan", "Kambodža", "Kiribati", "Komori", "Sveti Krištof in Nevis", "Severna Koreja", "Južna Koreja", "Kuvajt", "Kajmanji otoki", "Kazahstan", "Laos", "Libanon", "Sveta Lucija", "Lihtenštajn", "Šrilanka", "Liberija", "Lesoto", "Litva", "Luksemburg", "Latvija", "Libija", "Maroko", "Monako", "Moldavija", "Črna gora", "Francoski Sveti Martin", "Madagaskar", "Marshallovi otoki", "Severna Makedonija", "Mali", "Mjanmar (Burma)", "Mongolija", "Posebno upravno območje Kitajske Makav", "Severni Marianski otoki", "Martinik", "Mavretanija", "Montserrat", "Malta", "Mavricij", "Maldivi", "Malavi", "Mehika", "Malezija", "Mozambik", "Namibija", "Nova Kaledonija", "Niger", "Norfolški otok", "Nigerija", "Nikaragva", "Nizozemska", "Norveška", "Nepal", "Nauru", "Niue", "Nova Zelandija", "Oman", "Panama", "Peru", "Francoska Polinezija", "Papua Nova Gvineja", "Filipini", "Pakistan", "Poljska", "Sveta Peter in Mihael", "Pitcairn", "Portoriko", "Palestinsko ozemlje", "Portugalska", "Palav", "Paragvaj", "Katar", "Ostala oceanija", "Reunion", "Romunija", "Srbija", "Rusija", "Ruanda", "Saudova Arabija", "Salomonovi otoki", "Sejšeli", "Sudan", "Švedska", "Singapur", "Sveta Helena", "Slovenija", "Svalbard in Jan Mayen", "Slovaška", "Sierra Leone", "San Marino", "Senegal", "Somalija", "Surinam", "Južni Sudan", "Sveti Tomaž in Princ", "Salvador", "Nizozemski Sveti Martin", "Sirija", "Esvatini", "Tristan da Cunha", "Otoki Turks in Caicos", "Čad", "Francosko južno ozemlje", "Togo", "Tajska", "Tadžikistan", "Tokelav", "Timor-Leste", "Turkmenistan", "Tunizija", "Tonga", "Turčija", "Trinidad in Tobago", "Tuvalu", "Tajvan", "Tanzanija", "Ukrajina", "Uganda", "Stranski zunanji otoki Združenih držav", "Združeni narodi", "Združene države Amerike", "Urugvaj", "Uzbekistan", "Vatikan", "Sveti Vincencij in Grenadine", "Venezuela", "Britanski Deviški otoki", "Ameriški Deviški otoki", "Vietnam", "Vanuatu", "Wallis in Futuna", "Samoa", "psevdonaglasi", "psevdobidi", "Kosovo", "Jemen", "Mayotte", "Južnoafriška republika", "Zambija", "Zimbabve"];
+
+// so
+static R91: [&str; 294] = ["dunida", "Afrika", "Waqooyi Ameerika", "Koonfur Ameerika", "Osheeniya", "Galbeeka Afrika", "Bartamaha Ameerika", "Afrikada Bari", "Waqooyiga Afrika", "Afrikada Dhexe", "Afrikada Koonfureed", "Ameerikaas", "Waqooyiga Ameerika", "Karibiyaan", "Aasiyada Bari", "Aasiyada Koonfureed", "Aasiyada Koonfur-galbeed", "Yurubta Koonfureed", "Austraalaasiya", "Melaneesiya", "Gobolka Aasiyada yar", "Booliyneesiya", "Aasiya", "Bartamaha Aasiya", "Aasiyada Galbeed", "Yurub", "Yurubta Bari", "Yurubta Waqooyi", "Yurubta Galbeed", "Afrikada ka hooseysa Saxaraha", "Laatiin Ameerika", "Jasiiradda Asensiyoon", "Andora", "Midawga Imaaraatka Carabta", "Afgaanistaan", "Antigua & Barbuuda", "Anguula", "Albaaniya", "Armeeniya", "Angoola", "Antaarktika", "Arjentiina", "Samowa Ameerika", "Awsteriya", "Awstaraaliya", "Aruba", "Jasiiradda Aland", "Asarbajan", "Boosniya & Harsegofina", "Baarbadoos", "Bangaladhesh", "Biljam", "Burkiina Faaso", "Bulgaariya", "Baxreyn", "Burundi", "Biniin", "St. Baathelemiy", "Barmuuda", "Buruneey", "Boliifiya", "Karibiyaan Nadarlands", "Baraasiil", "Bahaamas", "Buutan", "Buufet Island", "Botuswaana", "Belarus", "Beliis", "Kanada", "Jasiiradda Kookoos", "Jamhuuriyadda Dimuquraadiga Kongo", "Jamhuuriyadda Afrikada Dhexe", "Kongo", "Swiiserlaand", "Ayfori Koost", "Jasiiradda Kook", "Jili", "Kaameruun", "Shiinaha", "Koloombiya", "Jasiiradda Kilibarton", "Sark", "Costa Rica", "Kuuba", "Jasiiradda Kayb Faarde", "Kurakaaw", "Jasiiradda Kirismas", "Qubrus", "Jekiya", "Jarmal", "Diyeego Karsiya", "Jabuuti", "Denmark", "Dominika", "Jamhuuriyaddda Dominika", "Aljeeriya", "Seyuta & Meliila", "Ikuwadoor", "Estooniya", "Masar", "Saxaraha Galbeed", "Eritreeya", "Isbeyn", "Itoobiya", "Midowga Yurub", "Yurusoon", "Finland", "Fiji", "Jaziiradaha Fooklaan", "Mikroneesiya", "Jasiiradda Faroo", "Faransiis", "Gaaboon", "Boqortooyada Midowday", "Giriinaada", "Joorjiya", "Faransiis Gini", "Guurnsey", "Gaana", "Gibraltar", "Greenland", "Gambiya", "Gini", "Guadeluub", "Ekuwatooriyal Gini", "Giriig", "Jasiiradda Joorjiyada Koonfureed & Sandwij", "Guwaatamaala", "Guaam", "Gini-Bisaaw", "Guyana", "Hong Kong", "Jasiiradda Haad & MakDonald", "Honduras", "Korweeshiya", "Haiti", "Hangari", "Jasiiradda Kanari", "Indoneesiya", "Ayrlaand", "Israaʼiil", "Jasiiradda Isle of Man", "Hindiya", "Dhul xadeedka Badweynta Hindiya ee Ingiriiska", "Ciraaq", "Iiraan", "Ayslaand", "Talyaani", "Jaarsey", "Jamaaika", "Urdun", "Jabaan", "Kenya", "Kirgistaan", "Kamboodiya", "Kiribati", "Komooros", "St. Kitts iyo Nevis", "Kuuriyada Waqooyi", "Kuuriyada Koonfureed", "Kuwayt", "Cayman Islands", "Kasaakhistaan", "Laos", "Lubnaan", "St. Lusia", "Liyjtensteyn", "Sirilaanka", "Laybeeriya", "Losooto", "Lituweeniya", "Luksemboorg", "Latfiya", "Liibya", "Morooko", "Moonako", "Moldofa", "Moontenegro", "St. Maartin", "Madagaskar", "Jasiiradda Maarshal", "Masedooniya Waqooyi", "Maali", "Mayanmar", "Mongooliya", "Makaaw", "Jasiiradda Waqooyiga Mariaana", "Maartinik", "Muritaaniya", "Montserrat", "Maalta", "Mawrishiyaas", "Maaldiifis", "Malaawi", "Meksiko", "Malaysiya", "Musambiik", "Namiibiya", "Jasiiradda Niyuu Kaledooniya", "Nayjer", "Jasiiradda Noorfolk", "Nayjeeriya", "Nikaraaguwa", "Nederlaands", "Noorweey", "Nebaal", "Nauru", "Niue", "Niyuusiilaand", "Cumaan", "Baanama", "Beeru", "Booliyneesiya Faransiiska", "Babwa Niyuu Gini", "Filibiin", "Bakistaan", "Booland", "St. Pierre iyo Miquelon", "Bitkairn", "Bueerto Riiko", "Dhulka Falastiiniyiinta daanta galbeed iyo marinka qasa", "Bortugaal", "Balaaw", "Baraguaay", "Qadar", "Dhulxeebeedka Osheeniya", "Riyuuniyon", "Rumaaniya", "Seerbiya", "Ruush", "Ruwanda", "Sacuudi Carabiya", "Jasiiradda Solomon", "Sishelis", "Suudaan", "Iswidhan", "Singaboor", "Saynt Helena", "Islofeeniya", "Jasiiradda Sfaldbaad & Jaan Mayen", "Islofaakiya", "Siraaliyoon", "San Marino", "Sinigaal", "Soomaaliya", "Surineym", "Koonfur Suudaan", "Sao Tome & Birincibal", "El Salfadoor", "Siint Maarteen", "Suuriya", "Eswaatiini", "Tiristan da Kunha", "Turks & Kaikos Island", "Jaad", "Dhul xadeedka Koonfureed ee Faransiiska", "Toogo", "Taylaand", "Tajikistan", "Tokelaaw", "Timor-Leste", "Turkmenistan", "Tuniisiya", "Tonga", "Turki", "Tirinidaad & Tobago", "Tufaalu", "Taywaan", "Tansaaniya", "Yukrayn", "Ugaanda", "Jasiiradaha ka baxsan Maraykanka", "Qaramada Midoobay", "Maraykanka", "Uruguwaay", "Usbakistan", "Magaalada Faatikaan", "St. Finsent & Girenadiins", "Fenisuweela", "Biritish Farjin Island", "U.S Fargin Island", "Fiyetnaam", "Fanuaatu", "Walis & Futuna", "Samoowa", "Lahjadaha Pseudo", "Pseudo-Bidi", "Koosofo", "Yaman", "Mayotte", "Koonfur Afrika", "Saambiya", "Simbaabwe"];
+
+// st
+static R92: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Lesotho", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Afrika Borwa", "", ""];
+
+// es
+static R93: [&str; 294] = ["Mundo", "África", "América del Norte", "Sudamérica", "Oceanía", "África occidental", "Centroamérica", "África oriental", "África septentrional", "África central", "África meridional", "América", "Norteamérica", "Caribe", "Asia oriental", "Asia meridional", "Sudeste asiático", "Europa meridional", "Australasia", "Melanesia", "Región de Micronesia", "Polinesia", "Asia", "Asia central", "Asia occidental", "Europa", "Europa oriental", "Europa septentrional", "Europa occidental", "África subsahariana", "Latinoamérica", "Isla de la Ascensión", "Andorra", "Emiratos Árabes Unidos", "Afganistán", "Antigua y Barbuda", "Anguila", "Albania", "Armenia", "Angola", "Antártida", "Argentina", "Samoa Americana", "Austria", "Australia", "Aruba", "Islas Aland", "Azerbaiyán", "Bosnia y Herzegovina", "Barbados", "Bangladés", "Bélgica", "Burkina Faso", "Bulgaria", "Baréin", "Burundi", "Benín", "San Bartolomé", "Bermudas", "Brunéi", "Bolivia", "Caribe neerlandés", "Brasil", "Bahamas", "Bután", "Isla Bouvet", "Botsuana", "Bielorrusia", "Belice", "Canadá", "Islas Cocos", "República Democrática del Congo", "República Centroafricana", "Congo", "Suiza", "Côte d’Ivoire", "Islas Cook", "Chile", "Camerún", "China", "Colombia", "Isla Clipperton", "Sark", "Costa Rica", "Cuba", "Cabo Verde", "Curazao", "Isla de Navidad", "Chipre", "Chequia", "Alemania", "Diego García", "Yibuti", "Dinamarca", "Dominica", "República Dominicana", "Argelia", "Ceuta y Melilla", "Ecuador", "Estonia", "Egipto", "Sáhara Occidental", "Eritrea", "España", "Etiopía", "Unión Europea", "zona del euro", "Finlandia", "Fiyi", "Islas Malvinas", "Micronesia", "Islas Feroe", "Francia", "Gabón", "Reino Unido", "Granada", "Georgia", "Guayana Francesa", "Guernesey", "Ghana", "Gibraltar", "Groenlandia", "Gambia", "Guinea", "Guadalupe", "Guinea Ecuatorial", "Grecia", "Islas Georgia del Sur y Sandwich del Sur", "Guatemala", "Guam", "Guinea-Bisáu", "Guyana", "RAE de Hong Kong (China)", "Islas Heard y McDonald", "Honduras", "Croacia", "Haití", "Hungría", "Canarias", "Indonesia", "Irlanda", "Israel", "Isla de Man", "India", "Territorio Británico del Océano Índico", "Irak", "Irán", "Islandia", "Italia", "Jersey", "Jamaica", "Jordania", "Japón", "Kenia", "Kirguistán", "Camboya", "Kiribati", "Comoras", "San Cristóbal y Nieves", "Corea del Norte", "Corea del Sur", "Kuwait", "Islas Caimán", "Kazajistán", "Laos", "Líbano", "Santa Lucía", "Liechtenstein", "Sri Lanka", "Liberia", "Lesoto", "Lituania", "Luxemburgo", "Letonia", "Libia", "Marruecos", "Mónaco", "Moldavia", "Montenegro", "San Martín", "Madagascar", "Islas Marshall", "Macedonia del Norte", "Mali", "Myanmar (Birmania)", "Mongolia", "RAE de Macao (China)", "Islas Marianas del Norte", "Martinica", "Mauritania", "Montserrat", "Malta", "Mauricio", "Maldivas", "Malaui", "México", "Malasia", "Mozambique", "Namibia", "Nueva Caledonia", "Níger", "Isla Norfolk", "Nigeria", "Nicaragua", "Países Bajos", "Noruega", "Nepal", "Nauru", "Niue", "Nueva Zelanda", "Omán", "Panamá", "Perú", "Polinesia Francesa", "Papúa Nueva Guinea", "Filipinas", "Pakistán", "Polonia", "San Pedro y Miquelón", "Islas Pitcairn", "Puerto Rico", "Territorios Palestinos", "Portugal", "Palaos", "Paraguay", "Catar", "Territorios alejados de Oceanía", "Reunión", "Rumanía", "Serbia", "Rusia", "Ruanda", "Arabia Saudí", "Islas Salomón", "Seychelles", "Sudán", "Suecia", "Singapur", "Santa Elena", "Eslovenia", "Svalbard y Jan Mayen", "Eslovaquia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam", "Sudán del Sur", "Santo Tomé y Príncipe", "El Salvador", "Sint Maarten", "Siria", "Esuatini", "Tristán de Acuña", "Islas Turcas y Caicos", "Chad", "Territorios Australes Franceses", "Togo", "Tailandia", "Tayikistán", "Tokelau", "Timor-Leste", "Turkmenistán", "Túnez", "Tonga", "Turquía", "Trinidad y Tobago", "Tuvalu", "Taiwán", "Tanzania", "Ucrania", "Uganda", "Islas menores alejadas de EE. UU.", "Naciones Unidas", "Estados Unidos", "Uruguay", "Uzbekistán", "Ciudad del Vaticano", "San Vicente y las Granadinas", "Venezuela", "Islas Vírgenes Británicas", "Islas Vírgenes de EE. UU.", "Vietnam", "Vanuatu", "Wallis y Futuna", "Samoa", "Pseudoacentos", "Pseudobidi", "Kosovo", "Yemen", "Mayotte", "Sudáfrica", "Zambia", "Zimbabue"];
+
+// su
+static R94: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Brasil", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Tiongkok", "", "", "", "", "", "", "", "", "", "", "Jérman", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Prancis", "", "Britania Raya", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Indonesia", "", "", "", "India", "", "", "", "", "Italia", "", "", "", "Jepang", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Rusia", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Amérika Sarikat", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// sw
+static R95: [&str; 294] = ["Dunia", "Afrika", "Amerika Kaskazini", "Amerika Kusini", "Oceania", "Afrika ya Magharibi", "Amerika ya Kati", "Afrika ya Mashariki", "Afrika ya Kaskazini", "Afrika ya Kati", "Afrika ya Kusini", "Amerika", "Amerika ya Kaskazini", "Karibiani", "Asia ya Mashariki", "Asia ya Kusini", "Asia ya Kusini Mashariki", "Ulaya ya Kusini", "Australasia", "Melanesia", "Eneo la Mikronesia", "Polynesia", "Asia", "Asia ya Kati", "Asia ya Magharibi", "Ulaya", "Ulaya ya Mashariki", "Ulaya ya Kaskazini", "Ulaya ya Magharibi", "Afrika ya Kusini mwa Jangwa la Sahara", "Amerika ya Kilatini", "Kisiwa cha Ascension", "Andorra", "Falme za Kiarabu", "Afghanistan", "Antigua na Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antaktiki", "Ajentina", "Samoa ya Marekani", "Austria", "Australia", "Aruba", "Visiwa vya Aland", "Azerbaijani", "Bosnia na Hezegovina", "Babadosi", "Bangladeshi", "Ubelgiji", "Bukinafaso", "Bulgaria", "Bahareni", "Burundi", "Benin", "St. Barthelemy", "Bermuda", "Brunei", "Bolivia", "Uholanzi ya Karibiani", "Brazil", "Bahama", "Bhutan", "Kisiwa cha Bouvet", "Botswana", "Belarus", "Belize", "Kanada", "Visiwa vya Cocos (Keeling)", "Kongo - Kinshasa", "Jamhuri ya Afrika ya Kati", "Kongo - Brazzaville", "Uswisi", "Cote d’Ivoire", "Visiwa vya Cook", "Chile", "Kameruni", "Uchina", "Kolombia", "Kisiwa cha Clipperton", "Sark", "Kostarika", "Kuba", "Cape Verde", "Curacao", "Kisiwa cha Krismasi", "Saiprasi", "Chechia", "Ujerumani", "Diego Garcia", "Jibuti", "Denmaki", "Dominika", "Jamhuri ya Dominika", "Aljeria", "Ceuta na Melilla", "Ecuador", "Estonia", "Misri", "Sahara Magharibi", "Eritrea", "Uhispania", "Ethiopia", "Umoja wa Ulaya", "Jumuiya ya Ulaya", "Ufini", "Fiji", "Visiwa vya Falkland", "Mikronesia", "Visiwa vya Faroe", "Ufaransa", "Gabon", "Ufalme wa Muungano", "Grenada", "Jojia", "Guiana ya Ufaransa", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Gine", "Guadeloupe", "Guinea ya Ikweta", "Ugiriki", "Visiwa vya Georgia Kusini na Sandwich Kusini", "Guatemala", "Guam", "Ginebisau", "Guyana", "Hong Kong SAR China", "Kisiwa cha Heard na Visiwa vya McDonald", "Honduras", "Croatia", "Haiti", "Hungaria", "Visiwa vya Kanari", "Indonesia", "Ayalandi", "Israeli", "Kisiwa cha Man", "India", "Eneo la Uingereza katika Bahari Hindi", "Iraki", "Iran", "Aisilandi", "Italia", "Jersey", "Jamaika", "Jordan", "Japani", "Kenya", "Kirigizistani", "Kambodia", "Kiribati", "Komoro", "St. Kitts na Nevis", "Korea Kaskazini", "Korea Kusini", "Kuwait", "Visiwa vya Cayman", "Kazakistani", "Laos", "Lebanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesoto", "Lithuania", "Luxembourg", "Latvia", "Libya", "Morocco", "Monaco", "Moldova", "Montenegro", "St. Martin", "Madagaska", "Visiwa vya Marshall", "Masedonia ya Kaskazini", "Mali", "Myanmar (Burma)", "Mongolia", "Makau SAR China", "Visiwa vya Mariana vya Kaskazini", "Martinique", "Moritania", "Montserrat", "Malta", "Morisi", "Maldivi", "Malawi", "Meksiko", "Malesia", "Msumbiji", "Namibia", "New Caledonia", "Niger", "Kisiwa cha Norfolk", "Nigeria", "Nikaragwa", "Uholanzi", "Norway", "Nepal", "Nauru", "Niue", "Nyuzilandi", "Oman", "Panama", "Peru", "Polynesia ya Ufaransa", "Papua New Guinea", "Ufilipino", "Pakistani", "Poland", "Santapierre na Miquelon", "Visiwa vya Pitcairn", "Puerto Rico", "Maeneo ya Palestina", "Ureno", "Palau", "Paraguay", "Qatar", "Oceania ya Nje", "Reunion", "Romania", "Serbia", "Urusi", "Rwanda", "Saudia", "Visiwa vya Solomon", "Ushelisheli", "Sudan", "Uswidi", "Singapore", "St. Helena", "Slovenia", "Svalbard na Jan Mayen", "Slovakia", "Siera Leoni", "San Marino", "Senegali", "Somalia", "Suriname", "Sudan Kusini", "Sao Tome na Principe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Visiwa vya Turks na Caicos", "Chad", "Himaya za Kusini za Kifaranza", "Togo", "Tailandi", "Tajikistani", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Uturuki", "Trinidad na Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "Visiwa Vidogo vya Nje vya Marekani", "Umoja wa Mataifa", "Marekani", "Uruguay", "Uzibekistani", "Mji wa Vatican", "St. Vincent na Grenadines", "Venezuela", "Visiwa vya Virgin, Uingereza", "Visiwa vya Virgin, Marekani", "Vietnamu", "Vanuatu", "Wallis na Futuna", "Samoa", "Lafudhi Bandia", "Lafudhi Bidi", "Kosovo", "Yemeni", "Mayotte", "Afrika Kusini", "Zambia", "Zimbabwe"];
+
+// sv
+static R96: [&str; 294] = ["världen", "Afrika", "Nordamerika", "Sydamerika", "Oceanien", "Västafrika", "Centralamerika", "Östafrika", "Nordafrika", "Centralafrika", "södra Afrika", "Nord- och Sydamerika", "norra Amerika", "Karibien", "Östasien", "Sydasien", "Sydostasien", "Sydeuropa", "Australasien", "Melanesien", "Mikronesiska öarna", "Polynesien", "Asien", "Centralasien", "Västasien", "Europa", "Östeuropa", "Nordeuropa", "Västeuropa", "subsahariska Afrika", "Latinamerika", "Ascension", "Andorra", "Förenade Arabemiraten", "Afghanistan", "Antigua och Barbuda", "Anguilla", "Albanien", "Armenien", "Angola", "Antarktis", "Argentina", "Amerikanska Samoa", "Österrike", "Australien", "Aruba", "Åland", "Azerbajdzjan", "Bosnien och Hercegovina", "Barbados", "Bangladesh", "Belgien", "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "S:t Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibiska Nederländerna", "Brasilien", "Bahamas", "Bhutan", "Bouvetön", "Botswana", "Belarus", "Belize", "Kanada", "Kokosöarna", "Kongo-Kinshasa", "Centralafrikanska republiken", "Kongo-Brazzaville", "Schweiz", "Elfenbenskusten", "Cooköarna", "Chile", "Kamerun", "Kina", "Colombia", "Clippertonön", "Sark", "Costa Rica", "Kuba", "Kap Verde", "Curaçao", "Julön", "Cypern", "Tjeckien", "Tyskland", "Diego Garcia", "Djibouti", "Danmark", "Dominica", "Dominikanska republiken", "Algeriet", "Ceuta och Melilla", "Ecuador", "Estland", "Egypten", "Västsahara", "Eritrea", "Spanien", "Etiopien", "Europeiska unionen", "euroområdet", "Finland", "Fiji", "Falklandsöarna", "Mikronesien", "Färöarna", "Frankrike", "Gabon", "Storbritannien", "Grenada", "Georgien", "Franska Guyana", "Guernsey", "Ghana", "Gibraltar", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorialguinea", "Grekland", "Sydgeorgien och Sydsandwichöarna", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong SAR", "Heardön och McDonaldöarna", "Honduras", "Kroatien", "Haiti", "Ungern", "Kanarieöarna", "Indonesien", "Irland", "Israel", "Isle of Man", "Indien", "Brittiska territoriet i Indiska oceanen", "Irak", "Iran", "Island", "Italien", "Jersey", "Jamaica", "Jordanien", "Japan", "Kenya", "Kirgizistan", "Kambodja", "Kiribati", "Komorerna", "S:t Kitts och Nevis", "Nordkorea", "Sydkorea", "Kuwait", "Caymanöarna", "Kazakstan", "Laos", "Libanon", "S:t Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxemburg", "Lettland", "Libyen", "Marocko", "Monaco", "Moldavien", "Montenegro", "Saint-Martin", "Madagaskar", "Marshallöarna", "Nordmakedonien", "Mali", "Myanmar (Burma)", "Mongoliet", "Macao SAR", "Nordmarianerna", "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius", "Maldiverna", "Malawi", "Mexiko", "Malaysia", "Moçambique", "Namibia", "Nya Kaledonien", "Niger", "Norfolkön", "Nigeria", "Nicaragua", "Nederländerna", "Norge", "Nepal", "Nauru", "Niue", "Nya Zeeland", "Oman", "Panama", "Peru", "Franska Polynesien", "Papua Nya Guinea", "Filippinerna", "Pakistan", "Polen", "S:t Pierre och Miquelon", "Pitcairnöarna", "Puerto Rico", "Palestinska territorierna", "Portugal", "Palau", "Paraguay", "Qatar", "yttre öar i Oceanien", "Réunion", "Rumänien", "Serbien", "Ryssland", "Rwanda", "Saudiarabien", "Salomonöarna", "Seychellerna", "Sudan", "Sverige", "Singapore", "S:t Helena", "Slovenien", "Svalbard och Jan Mayen", "Slovakien", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sydsudan", "São Tomé och Príncipe", "El Salvador", "Sint Maarten", "Syrien", "Eswatini", "Tristan da Cunha", "Turks- och Caicosöarna", "Tchad", "Franska sydterritorierna", "Togo", "Thailand", "Tadzjikistan", "Tokelauöarna", "Östtimor", "Turkmenistan", "Tunisien", "Tonga", "Turkiet", "Trinidad och Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "USA:s yttre öar", "Förenta Nationerna", "USA", "Uruguay", "Uzbekistan", "Vatikanstaten", "S:t Vincent och Grenadinerna", "Venezuela", "Brittiska Jungfruöarna", "Amerikanska Jungfruöarna", "Vietnam", "Vanuatu", "Wallis- och Futunaöarna", "Samoa", "fejkade accenter (för test)", "fejkad bidi (för test)", "Kosovo", "Jemen", "Mayotte", "Sydafrika", "Zambia", "Zimbabwe"];
+
+// ta
+static R97: [&str; 294] = ["உலகம்", "ஆப்பிரிக்கா", "வட அமெரிக்கா", "தென் அமெரிக்கா", "ஓஷியானியா", "மேற்கு ஆப்பிரிக்கா", "மத்திய அமெரிக்கா", "கிழக்கு ஆப்பிரிக்கா", "வடக்கு ஆப்பிரிக்கா", "மத்திய ஆப்பிரிக்கா", "தெற்கு ஆப்பிரிக்கா", "அமெரிக்காஸ்", "வடக்கு அமெரிக்கா", "கரீபியன்", "கிழக்கு ஆசியா", "தெற்கு ஆசியா", "தென்கிழக்கு ஆசியா", "தெற்கு ஐரோப்பா", "ஆஸ்திரலேசியா", "மெலனேஷியா", "மைக்ரோனேஷியன் பிரதேசம்", "பாலினேஷியா", "ஆசியா", "மத்திய ஆசியா", "மேற்கு ஆசியா", "ஐரோப்பா", "கிழக்கு ஐரோப்பா", "வடக்கு ஐரோப்பா", "மேற்கு ஐரோப்பா", "துணை சஹாரா ஆப்பிரிக்கா", "லத்தீன் அமெரிக்கா", "அஷன்ஷியன் தீவு", "அன்டோரா", "ஐக்கிய அரபு அமீரகம்", "ஆஃப்கானிஸ்தான்", "ஆண்டிகுவா மற்றும் பார்புடா", "அங்கியுலா", "அல்பேனியா", "அர்மேனியா", "அங்கோலா", "அண்டார்டிகா", "அர்ஜென்டினா", "அமெரிக்க சமோவா", "ஆஸ்திரியா", "ஆஸ்திரேலியா", "அரூபா", "ஆலந்து தீவுகள்", "அசர்பைஜான்", "போஸ்னியா & ஹெர்ஸகோவினா", "பார்படாஸ்", "பங்களாதேஷ்", "பெல்ஜியம்", "புர்கினா ஃபாஸோ", "பல்கேரியா", "பஹ்ரைன்", "புருண்டி", "பெனின்", "செயின்ட் பார்தேலெமி", "பெர்முடா", "புருனே", "பொலிவியா", "கரீபியன் நெதர்லாந்து", "பிரேசில்", "பஹாமாஸ்", "பூடான்", "பொவேட் தீவு", "போட்ஸ்வானா", "பெலாரஸ்", "பெலிஸ்", "கனடா", "கோகோஸ் (கீலிங்) தீவுகள்", "காங்கோ - கின்ஷாசா", "மத்திய ஆப்ரிக்கக் குடியரசு", "காங்கோ - ப்ராஸாவில்லே", "ஸ்விட்சர்லாந்து", "கோட் தி’வாயர்", "குக் தீவுகள்", "சிலி", "கேமரூன்", "சீனா", "கொலம்பியா", "கிலிப்பர்டன் தீவு", "சார்க்", "கோஸ்டாரிகா", "கியூபா", "கேப் வெர்டே", "குராகவ்", "கிறிஸ்துமஸ் தீவு", "சைப்ரஸ்", "செசியா", "ஜெர்மனி", "டியகோ கார்ஷியா", "ஜிபௌட்டி", "டென்மார்க்", "டொமினிகா", "டொமினிகன் குடியரசு", "அல்ஜீரியா", "சியூடா & மெலில்லா", "ஈக்வடார்", "எஸ்டோனியா", "எகிப்து", "மேற்கு சஹாரா", "எரிட்ரியா", "ஸ்பெயின்", "எத்தியோப்பியா", "ஐரோப்பிய யூனியன்", "யூரோஜோன்", "பின்லாந்து", "ஃபிஜி", "ஃபாக்லாந்து தீவுகள்", "மைக்ரோனேஷியா", "ஃபாரோ தீவுகள்", "பிரான்ஸ்", "கேபான்", "யுனைடெட் கிங்டம்", "கிரனெடா", "ஜார்ஜியா", "பிரெஞ்சு கயானா", "கெர்ன்சி", "கானா", "ஜிப்ரால்டர்", "கிரீன்லாந்து", "காம்பியா", "கினியா", "க்வாதேலோப்", "ஈக்வடோரியல் கினியா", "கிரீஸ்", "தெற்கு ஜார்ஜியா மற்றும் தெற்கு சாண்ட்விச் தீவுகள்", "கவுதமாலா", "குவாம்", "கினியா-பிஸ்ஸாவ்", "கயானா", "ஹாங்காங் எஸ்ஏஆர் சீனா", "ஹேர்ட் மற்றும் மெக்டொனால்டு தீவுகள்", "ஹோண்டூராஸ்", "குரோஷியா", "ஹைட்டி", "ஹங்கேரி", "கேனரி தீவுகள்", "இந்தோனேசியா", "அயர்லாந்து", "இஸ்ரேல்", "ஐல் ஆஃப் மேன்", "இந்தியா", "பிரிட்டிஷ் இந்தியப் பெருங்கடல் பிரதேசம்", "ஈராக்", "ஈரான்", "ஐஸ்லாந்து", "இத்தாலி", "ஜெர்சி", "ஜமைக்கா", "ஜோர்டான்", "ஜப்பான்", "கென்யா", "கிர்கிஸ்தான்", "கம்போடியா", "கிரிபாட்டி", "கோமரோஸ்", "செயின்ட் கிட்ஸ் & நெவிஸ்", "வட கொரியா", "தென் கொரியா", "குவைத்", "கெய்மென் தீவுகள்", "கஸகஸ்தான்", "லாவோஸ்", "லெபனான்", "செயின்ட் லூசியா", "லிச்செண்ஸ்டெய்ன்", "இலங்கை", "லைபீரியா", "லெசோதோ", "லிதுவேனியா", "லக்ஸ்சம்பர்க்", "லாட்வியா", "லிபியா", "மொராக்கோ", "மொனாக்கோ", "மால்டோவா", "மான்டேனெக்ரோ", "செயின்ட் மார்ட்டீன்", "மடகாஸ்கர்", "மார்ஷல் தீவுகள்", "வடக்கு மாசிடோனியா", "மாலி", "மியான்மர் (பர்மா)", "மங்கோலியா", "மகாவ் எஸ்ஏஆர் சீனா", "வடக்கு மரியானா தீவுகள்", "மார்டினிக்", "மௌரிடானியா", "மாண்ட்செராட்", "மால்டா", "மொரிசியஸ்", "மாலத்தீவு", "மலாவி", "மெக்சிகோ", "மலேசியா", "மொசாம்பிக்", "நமீபியா", "நியூ கேலிடோனியா", "நைஜர்", "நார்ஃபோக் தீவு", "நைஜீரியா", "நிகரகுவா", "நெதர்லாந்து", "நார்வே", "நேபாளம்", "நௌரு", "நியுவே", "நியூசிலாந்து", "ஓமன்", "பனாமா", "பெரு", "பிரெஞ்சு பாலினேஷியா", "பப்புவா நியூ கினியா", "பிலிப்பைன்ஸ்", "பாகிஸ்தான்", "போலந்து", "செயின்ட் பியர் & மிக்வேலான்", "பிட்கெய்ர்ன் தீவுகள்", "பியூர்டோ ரிகோ", "பாலஸ்தீனிய பிரதேசங்கள்", "போர்ச்சுக்கல்", "பாலோ", "பராகுவே", "கத்தார்", "வெளிப்புற ஓஷியானியா", "ரீயூனியன்", "ருமேனியா", "செர்பியா", "ரஷ்யா", "ருவாண்டா", "சவுதி அரேபியா", "சாலமன் தீவுகள்", "சீஷெல்ஸ்", "சூடான்", "ஸ்வீடன்", "சிங்கப்பூர்", "செயின்ட் ஹெலெனா", "ஸ்லோவேனியா", "ஸ்வல்பார்டு & ஜான் மேயன்", "ஸ்லோவாகியா", "சியாரா லியோன்", "சான் மரினோ", "செனெகல்", "சோமாலியா", "சுரினாம்", "தெற்கு சூடான்", "சாவ் தோம் & ப்ரின்சிபி", "எல் சால்வடார்", "சின்ட் மார்டென்", "சிரியா", "எஸ்வாட்டீனி", "டிரிஸ்டன் டா குன்ஹா", "டர்க்ஸ் & கைகோஸ் தீவுகள்", "சாட்", "பிரெஞ்சு தெற்கு பிரதேசங்கள்", "டோகோ", "தாய்லாந்து", "தஜிகிஸ்தான்", "டோகேலோ", "திமோர்-லெஸ்தே", "துர்க்மெனிஸ்தான்", "டுனிசியா", "டோங்கா", "துருக்கியே", "டிரினிடாட் & டொபாகோ", "துவாலு", "தைவான்", "தான்சானியா", "உக்ரைன்", "உகாண்டா", "யூ.எஸ். வெளிப்புறத் தீவுகள்", "ஐக்கிய நாடுகள்", "அமெரிக்கா", "உருகுவே", "உஸ்பெகிஸ்தான்", "வாடிகன் நகரம்", "செயின்ட் வின்சென்ட் & கிரெனடைன்ஸ்", "வெனிசுலா", "பிரிட்டீஷ் கன்னித் தீவுகள்", "யூ.எஸ். கன்னித் தீவுகள்", "வியட்நாம்", "வனுவாட்டு", "வாலிஸ் மற்றும் ஃபுடுனா", "சமோவா", "போலி உச்சரிப்புகள்", "போலி பீடி", "கொசோவோ", "ஏமன்", "மயோட்", "தென் ஆப்பிரிக்கா", "ஜாம்பியா", "ஜிம்பாப்வே"];
+
+// te
+static R98: [&str; 294] = ["ప్రపంచం", "ఆఫ్రికా", "ఉత్తర అమెరికా", "దక్షిణ అమెరికా", "ఓషియానియా", "పశ్చిమ ఆఫ్రికా భూభాగం", "మధ్య అమెరికా", "తూర్పు ఆఫ్రికా", "ఉత్తర ఆఫ్రికా", "మధ్య ఆఫ్రికా", "దక్షిణ ఆఫ్రికా భూభాగం", "అమెరికాస్", "ఉత్తర అమెరికా భూభాగం", "కరీబియన్", "తూర్పు ఆసియా", "దక్షిణ ఆసియా", "ఆగ్నేయ ఆసియా", "దక్షిణ యూరోప్", "ఆస్ట్రేలేసియా", "మెలనేశియ", "మైక్రోనేసియన్ ప్రాంతం", "పాలినేషియా", "ఆసియా", "మధ్య ఆసియా", "పశ్చిమ ఆసియా", "యూరప్", "తూర్పు యూరోప్", "ఉత్తర యూరోప్", "పశ్చిమ యూరోప్", "ఉప సెహరన్ ఆఫ్రికా", "లాటిన్ అమెరికా", "అసెన్షన్ దీవి", "ఆండోరా", "యునైటెడ్ అరబ్ ఎమిరేట్స్", "ఆఫ్ఘనిస్తాన్", "ఆంటిగ్వా & బార్బుడా", "ఆంగ్విల్లా", "అల్బేనియా", "ఆర్మేనియా", "అంగోలా", "అంటార్కిటికా", "అర్జెంటీనా", "అమెరికన్ సమోవా", "ఆస్ట్రియా", "ఆస్ట్రేలియా", "అరుబా", "ఆలాండ్ దీవులు", "అజర్బైజాన్", "బోస్నియా & హెర్జిగోవినా", "బార్బడోస్", "బంగ్లాదేశ్", "బెల్జియం", "బుర్కినా ఫాసో", "బల్గేరియా", "బహ్రెయిన్", "బురుండి", "బెనిన్", "సెయింట్ బర్థెలిమి", "బెర్ముడా", "బ్రూనే", "బొలీవియా", "కరీబియన్ నెదర్లాండ్స్", "బ్రెజిల్", "బహామాస్", "భూటాన్", "బువై దీవి", "బోట్స్వానా", "బెలారస్", "బెలిజ్", "కెనడా", "కోకోస్ (కీలింగ్) దీవులు", "కాంగో- కిన్షాసా", "సెంట్రల్ ఆఫ్రికన్ రిపబ్లిక్", "కాంగో- బ్రాజావిల్లి", "స్విట్జర్లాండ్", "కోట్ డి ఐవోర్", "కుక్ దీవులు", "చిలీ", "కామెరూన్", "చైనా", "కొలంబియా", "క్లిప్పర్టన్ దీవి", "సార్క్", "కోస్టా రికా", "క్యూబా", "కేప్ వెర్డె", "క్యూరసో", "క్రిస్మస్ దీవి", "సైప్రస్", "చెకియా", "జర్మనీ", "డియాగో గార్సియా", "జిబౌటి", "డెన్మార్క్", "డొమినికా", "డొమినికన్ రిపబ్లిక్", "అల్జీరియా", "స్యూటా & మెలిల్లా", "ఈక్వడార్", "ఎస్టోనియా", "ఈజిప్ట్", "పడమటి సహారా", "ఎరిట్రియా", "స్పెయిన్", "ఇథియోపియా", "యూరోపియన్ యూనియన్", "యూరోజోన్", "ఫిన్లాండ్", "ఫిజీ", "ఫాక్\u{200C}ల్యాండ్ దీవులు", "మైక్రోనేషియా", "ఫారో దీవులు", "ఫ్రాన్స్\u{200C}", "గేబన్", "యునైటెడ్ కింగ్\u{200C}డమ్", "గ్రెనడా", "జార్జియా", "ఫ్రెంచ్ గియానా", "గర్న్\u{200C}సీ", "ఘనా", "జిబ్రాల్టర్", "గ్రీన్\u{200C}ల్యాండ్", "గాంబియా", "గినియా", "గ్వాడెలోప్", "ఈక్వటోరియల్ గినియా", "గ్రీస్", "దక్షిణ జార్జియా మరియు దక్షిణ శాండ్విచ్ దీవులు", "గ్వాటిమాలా", "గ్వామ్", "గినియా-బిస్సావ్", "గయానా", "హాంకాంగ్ SAR చైనా", "హెర్డ్ దీవి మరియు మెక్\u{200C}డొనాల్డ్ దీవులు", "హోండురాస్", "క్రొయేషియా", "హైతీ", "హంగేరీ", "కేనరీ దీవులు", "ఇండోనేషియా", "ఐర్లాండ్", "ఇజ్రాయెల్", "ఐల్ ఆఫ్ మాన్", "భారతదేశం", "బ్రిటిష్ హిందూ మహాసముద్ర ప్రాంతం", "ఇరాక్", "ఇరాన్", "ఐస్లాండ్", "ఇటలీ", "జెర్సీ", "జమైకా", "జోర్డాన్", "జపాన్", "కెన్యా", "కిర్గిజిస్తాన్", "కంబోడియా", "కిరిబాటి", "కొమొరోస్", "సెయింట్ కిట్స్ & నెవిస్", "ఉత్తర కొరియా", "దక్షిణ కొరియా", "కువైట్", "కేమాన్ దీవులు", "కజకిస్తాన్", "లావోస్", "లెబనాన్", "సెయింట్ లూసియా", "లిక్టెన్\u{200C}స్టెయిన్", "శ్రీలంక", "లైబీరియా", "లెసోతో", "లిథువేనియా", "లక్సెంబర్గ్", "లాత్వియా", "లిబియా", "మొరాకో", "మొనాకో", "మోల్డోవా", "మాంటెనెగ్రో", "సెయింట్ మార్టిన్", "మడగాస్కర్", "మార్షల్ దీవులు", "ఉత్తర మాసిడోనియా", "మాలి", "మయన్మార్", "మంగోలియా", "మకావ్ SAR చైనా", "ఉత్తర మరియానా దీవులు", "మార్టినీక్", "మారిటేనియా", "మాంట్సెరాట్", "మాల్టా", "మారిషస్", "మాల్దీవులు", "మలావీ", "మెక్సికో", "మలేషియా", "మొజాంబిక్", "నమీబియా", "కొత్త కాలెడోనియా", "నైజర్", "నార్ఫోక్ దీవి", "నైజీరియా", "నికరాగువా", "నెదర్లాండ్స్", "నార్వే", "నేపాల్", "నౌరు", "నియూ", "న్యూజిలాండ్", "ఓమన్", "పనామా", "పెరూ", "ఫ్రెంచ్ పోలినీషియా", "పాపువా న్యూ గినియా", "ఫిలిప్పైన్స్", "పాకిస్తాన్", "పోలాండ్", "సెయింట్ పియెర్ మరియు మికెలాన్", "పిట్\u{200C}కెయిర్న్ దీవులు", "ప్యూర్టో రికో", "పాలస్తీనియన్ ప్రాంతాలు", "పోర్చుగల్", "పాలావ్", "పరాగ్వే", "ఖతార్", "ఒషీనియా బయటున్నవి", "రీయూనియన్", "రోమేనియా", "సెర్బియా", "రష్యా", "రువాండా", "సౌదీ అరేబియా", "సోలమన్ దీవులు", "సీషెల్స్", "సూడాన్", "స్వీడన్", "సింగపూర్", "సెయింట్ హెలెనా", "స్లోవేనియా", "స్వాల్\u{200C}బార్డ్ మరియు జాన్ మాయెన్", "స్లొవేకియా", "సియెర్రా లియాన్", "శాన్ మారినో", "సెనెగల్", "సోమాలియా", "సూరినామ్", "దక్షిణ సూడాన్", "సావో టోమ్ మరియు ప్రిన్సిపి", "ఎల్ సాల్వడోర్", "సింట్ మార్టెన్", "సిరియా", "ఈస్వాటిని", "ట్రిస్టన్ డ కన్హా", "టర్క్స్ & కైకోస్ దీవులు", "చాద్", "ఫ్రెంచ్ దక్షిణ ప్రాంతాలు", "టోగో", "థాయిలాండ్", "తజికిస్తాన్", "టోకెలావ్", "టిమోర్-లెస్టె", "టర్క్\u{200C}మెనిస్తాన్", "ట్యునీషియా", "టోంగా", "తుర్కియె", "ట్రినిడాడ్ మరియు టొబాగో", "టువాలు", "తైవాన్", "టాంజానియా", "ఉక్రెయిన్", "ఉగాండా", "U.S. బయట ఉన్న దీవులు", "యునైటెడ్ నేషన్స్", "యునైటెడ్ స్టేట్స్", "ఉరుగ్వే", "ఉజ్బెకిస్తాన్", "వాటికన్ నగరం", "సెయింట్ విన్సెంట్ మరియు గ్రెనడీన్స్", "వెనిజులా", "బ్రిటిష్ వర్జిన్ దీవులు", "యు.ఎస్. వర్జిన్ దీవులు", "వియత్నాం", "వనాటు", "వాల్లిస్ మరియు ఫుటునా", "సమోవా", "సూడో యాక్సెంట్స్", "సూడో-బిడి", "కొసోవో", "యెమెన్", "మాయొట్", "దక్షిణ ఆఫ్రికా", "జాంబియా", "జింబాబ్వే"];
+
+// tg
+static R99: [&str; 294] = ["ҷаҳонӣ", "Африқо", "Америкаи Шимолӣ", "Америкаи Ҷанубӣ", "Уқёнус", "Африқои Ғарбӣ", "Америкаи Марказӣ", "Африқои Шимолӣ", "Африқои Шарқӣ", "Африқои Миёна", "Африқои Ҷанубӣ", "Америка", "Амрикои Шимолӣ", "Кариб", "Осиёи Шарқӣ", "Осиёи Ҷанубӣ", "Осиёи Ҷанубу Шарқӣ", "Аврупои Ҷанубӣ", "Австралияву Осиё", "Меланезия", "Минтақаи Микронезия", "Полинезия", "Осиё", "Осиёи Марказӣ", "Осиёи Ғарбӣ", "Аврупо", "Аврупои Шарқӣ", "Аврупои Шимолӣ", "Аврупои Ғарбӣ", "Африқои Суб-Сахара", "Америкаи Лотинӣ", "Асунсон", "Андорра", "Аморатҳои Муттаҳидаи Араб", "Афғонистон", "Антигуа ва Барбуда", "Ангилия", "Албания", "Арманистон", "Ангола", "Антарктида", "Аргентина", "Самоаи Америка", "Австрия", "Австралия", "Аруба", "Ҷазираҳои Аланд", "Озарбойҷон", "Босния ва Ҳерсеговина", "Барбадос", "Бангладеш", "Белгия", "Буркина-Фасо", "Булғория", "Баҳрайн", "Бурунди", "Бенин", "Сент-Бартелми", "Бермуда", "Бруней", "Боливия", "Кариби Нидерланд", "Бразилия", "Багам", "Бутон", "Ҷазираи Буве", "Ботсвана", "Белорус", "Белиз", "Канада", "Ҷазираҳои Кокос (Килинг)", "Конго - Киншаса", "Ҷумҳурии Африқои Марказӣ", "Конго - Браззавил", "Швейтсария", "Кот-д’Ивуар", "Ҷазираҳои Кук", "Чили", "Камерун", "Хитой", "Колумбия", "Ҷазираи Клиппертон", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Ҷазираи Крисмас", "Кипр", "Ҷумҳурии Чех", "Германия", "Диего-Гарсия", "Ҷибути", "Дания", "Доминика", "Ҷумҳурии Доминикан", "Алҷазоир", "Сеута ва Мелилла", "Эквадор", "Эстония", "Миср", "Сахараи Ғарбӣ", "Эритрея", "Испания", "Эфиопия", "Иттиҳоди Аврупо", "Минтақаи Аврупо", "Финляндия", "Фиҷи", "Ҷазираҳои Фолкленд", "Штатҳои Федеративии Микронезия", "Ҷазираҳои Фарер", "Фаронса", "Габон", "Шоҳигарии Муттаҳида", "Гренада", "Гурҷистон", "Гвианаи Фаронса", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Гвинеяи Экваторӣ", "Юнон", "Ҷорҷияи Ҷанубӣ ва Ҷазираҳои Сандвич", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Ҳонконг (МММ)", "Ҷазираи Ҳерд ва Ҷазираҳои Макдоналд", "Гондурас", "Хорватия", "Гаити", "Маҷористон", "Ҷазираҳои Канария", "Индонезия", "Ирландия", "Исроил", "Ҷазираи Мэн", "Ҳиндустон", "Қаламрави Британия дар уқёнуси Ҳинд", "Ироқ", "Эрон", "Исландия", "Италия", "Ҷерси", "Ямайка", "Урдун", "Япония", "Кения", "Қирғизистон", "Камбоҷа", "Кирибати", "Комор", "Сент-Китс ва Невис", "Кореяи Шимолӣ", "Кореяи Ҷанубӣ", "Қувайт", "Ҷазираҳои Кайман", "Қазоқистон", "Лаос", "Лубнон", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Либия", "Марокаш", "Монако", "Молдова", "Черногория", "Ҷазираи Сент-Мартин", "Мадагаскар", "Ҷазираҳои Маршалл", "Македонияи Шимолӣ", "Мали", "Мянма", "Муғулистон", "Макао (МММ)", "Ҷазираҳои Марианаи Шимолӣ", "Мартиника", "Мавритания", "Монтсеррат", "Малта", "Маврикий", "Малдив", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Каледонияи Нав", "Нигер", "Ҷазираи Норфолк", "Нигерия", "Никарагуа", "Нидерландия", "Норвегия", "Непал", "Науру", "Ниуэ", "Зеландияи Нав", "Умон", "Панама", "Перу", "Полинезияи Фаронса", "Папуа Гвинеяи Нав", "Филиппин", "Покистон", "Лаҳистон", "Сент-Пер ва Микелон", "Ҷазираҳои Питкейрн", "Пуэрто-Рико", "Қаламравҳои Фаластинӣ", "Португалия", "Палау", "Парагвай", "Қатар", "Уқёнуси Дур", "Реюнион", "Руминия", "Сербия", "Русия", "Руанда", "Арабистони Саудӣ", "Ҷазираҳои Соломон", "Сейшел", "Судон", "Шветсия", "Сингапур", "Сент Елена", "Словения", "Шпитсберген ва Ян Майен", "Словакия", "Сиерра-Леоне", "Сан-Марино", "Сенегал", "Сомалӣ", "Суринам", "Судони Ҷанубӣ", "Сан Томе ва Принсипи", "Эл-Салвадор", "Синт-Маартен", "Сурия", "Эсватини", "Тристан-да-Куня", "Ҷазираҳои Теркс ва Кайкос", "Чад", "Минтақаҳои Ҷанубии Фаронса", "Того", "Таиланд", "Тоҷикистон", "Токелау", "Тимор-Лесте", "Туркманистон", "Тунис", "Тонга", "Туркия", "Тринидад ва Тобаго", "Тувалу", "Тайван", "Танзания", "Украина", "Уганда", "Ҷазираҳои Хурди Дурдасти ИМА", "Созмони Милали Муттаҳид", "Иёлоти Муттаҳида", "Уругвай", "Ӯзбекистон", "Шаҳри Вотикон", "Сент-Винсент ва Гренадина", "Венесуэла", "Ҷазираҳои Виргини Британия", "Ҷазираҳои Виргини ИМА", "Ветнам", "Вануату", "Уоллис ва Футуна", "Самоа", "Псевдо-аксентҳо", "Псевдо-бидӣ", "Косово", "Яман", "Майотта", "Африкаи Ҷанубӣ", "Замбия", "Зимбабве"];
+
+// th
+static R100: [&str; 294] = ["โลก", "แอฟริกา", "อเมริกาเหนือ", "อเมริกาใต้", "โอเชียเนีย", "แอฟริกาตะวันตก", "อเมริกากลาง", "แอฟริกาตะวันออก", "แอฟริกาเหนือ", "แอฟริกากลาง", "แอฟริกาตอนใต้", "อเมริกา", "อเมริกาตอนเหนือ", "แคริบเบียน", "เอเชียตะวันออก", "เอเชียใต้", "เอเชียตะวันออกเฉียงใต้", "ยุโรปใต้", "ออสตราเลเซีย", "เมลานีเซีย", "เขตไมโครนีเซีย", "โปลินีเซีย", "เอเชีย", "เอเชียกลาง", "เอเชียตะวันตก", "ยุโรป", "ยุโรปตะวันออก", "ยุโรปเหนือ", "ยุโรปตะวันตก", "แอฟริกาใต้สะฮารา", "ละตินอเมริกา", "เกาะแอสเซนชัน", "อันดอร์รา", "สหรัฐอาหรับเอมิเรตส์", "อัฟกานิสถาน", "แอนติกาและบาร์บูดา", "แองกวิลลา", "แอลเบเนีย", "อาร์เมเนีย", "แองโกลา", "แอนตาร์กติกา", "อาร์เจนตินา", "อเมริกันซามัว", "ออสเตรีย", "ออสเตรเลีย", "อารูบา", "หมู่เกาะโอลันด์", "อาเซอร์ไบจาน", "บอสเนียและเฮอร์เซโกวีนา", "บาร์เบโดส", "บังกลาเทศ", "เบลเยียม", "บูร์กินาฟาโซ", "บัลแกเรีย", "บาห์เรน", "บุรุนดี", "เบนิน", "เซนต์บาร์เธเลมี", "เบอร์มิวดา", "บรูไน", "โบลิเวีย", "เนเธอร์แลนด์แคริบเบียน", "บราซิล", "บาฮามาส", "ภูฏาน", "เกาะบูเว", "บอตสวานา", "เบลารุส", "เบลีซ", "แคนาดา", "หมู่เกาะโคโคส (คีลิง)", "คองโก - กินชาซา", "สาธารณรัฐแอฟริกากลาง", "คองโก - บราซซาวิล", "สวิตเซอร์แลนด์", "โกตดิวัวร์", "หมู่เกาะคุก", "ชิลี", "แคเมอรูน", "จีน", "โคลอมเบีย", "เกาะคลิปเปอร์ตัน", "ซาร์ก", "คอสตาริกา", "คิวบา", "เคปเวิร์ด", "คูราเซา", "เกาะคริสต์มาส", "ไซปรัส", "เช็ก", "เยอรมนี", "ดิเอโกการ์เซีย", "จิบูตี", "เดนมาร์ก", "โดมินิกา", "สาธารณรัฐโดมินิกัน", "แอลจีเรีย", "เซวตาและเมลียา", "เอกวาดอร์", "เอสโตเนีย", "อียิปต์", "ซาฮาราตะวันตก", "เอริเทรีย", "สเปน", "เอธิโอเปีย", "สหภาพยุโรป", "ยูโรโซน", "ฟินแลนด์", "ฟิจิ", "หมู่เกาะฟอล์กแลนด์", "ไมโครนีเซีย", "หมู่เกาะแฟโร", "ฝรั่งเศส", "กาบอง", "สหราชอาณาจักร", "เกรเนดา", "จอร์เจีย", "เฟรนช์เกียนา", "เกิร์นซีย์", "กานา", "ยิบรอลตาร์", "กรีนแลนด์", "แกมเบีย", "กินี", "กวาเดอลูป", "อิเควทอเรียลกินี", "กรีซ", "เกาะเซาท์จอร์เจียและหมู่เกาะเซาท์แซนด์วิช", "กัวเตมาลา", "กวม", "กินี-บิสเซา", "กายอานา", "เขตปกครองพิเศษฮ่องกงแห่งสาธารณรัฐประชาชนจีน", "เกาะเฮิร์ดและหมู่เกาะแมกดอนัลด์", "ฮอนดูรัส", "โครเอเชีย", "เฮติ", "ฮังการี", "หมู่เกาะคานารี", "อินโดนีเซีย", "ไอร์แลนด์", "อิสราเอล", "เกาะแมน", "อินเดีย", "บริติชอินเดียนโอเชียนเทร์ริทอรี", "อิรัก", "อิหร่าน", "ไอซ์แลนด์", "อิตาลี", "เจอร์ซีย์", "จาเมกา", "จอร์แดน", "ญี่ปุ่น", "เคนยา", "คีร์กีซสถาน", "กัมพูชา", "คิริบาส", "คอโมโรส", "เซนต์คิตส์และเนวิส", "เกาหลีเหนือ", "เกาหลีใต้", "คูเวต", "หมู่เกาะเคย์แมน", "คาซัคสถาน", "ลาว", "เลบานอน", "เซนต์ลูเซีย", "ลิกเตนสไตน์", "ศรีลังกา", "ไลบีเรีย", "เลโซโท", "ลิทัวเนีย", "ลักเซมเบิร์ก", "ลัตเวีย", "ลิเบีย", "โมร็อกโก", "โมนาโก", "มอลโดวา", "มอนเตเนโกร", "เซนต์มาร์ติน", "มาดากัสการ์", "หมู่เกาะมาร์แชลล์", "มาซิโดเนียเหนือ", "มาลี", "เมียนมา (พม่า)", "มองโกเลีย", "เขตปกครองพิเศษมาเก๊าแห่งสาธารณรัฐประชาชนจีน", "หมู่เกาะนอร์เทิร์นมาเรียนา", "มาร์ตินีก", "มอริเตเนีย", "มอนต์เซอร์รัต", "มอลตา", "มอริเชียส", "มัลดีฟส์", "มาลาวี", "เม็กซิโก", "มาเลเซีย", "โมซัมบิก", "นามิเบีย", "นิวแคลิโดเนีย", "ไนเจอร์", "เกาะนอร์ฟอล์ก", "ไนจีเรีย", "นิการากัว", "เนเธอร์แลนด์", "นอร์เวย์", "เนปาล", "นาอูรู", "นีอูเอ", "นิวซีแลนด์", "โอมาน", "ปานามา", "เปรู", "เฟรนช์โปลินีเซีย", "ปาปัวนิวกินี", "ฟิลิปปินส์", "ปากีสถาน", "โปแลนด์", "แซงปีแยร์และมีเกอลง", "หมู่เกาะพิตแคร์น", "เปอร์โตริโก", "ดินแดนปาเลสไตน์", "โปรตุเกส", "ปาเลา", "ปารากวัย", "กาตาร์", "เอาต์ไลอิงโอเชียเนีย", "เรอูนียง", "โรมาเนีย", "เซอร์เบีย", "รัสเซีย", "รวันดา", "ซาอุดีอาระเบีย", "หมู่เกาะโซโลมอน", "เซเชลส์", "ซูดาน", "สวีเดน", "สิงคโปร์", "เซนต์เฮเลนา", "สโลวีเนีย", "สฟาลบาร์และยานไมเอน", "สโลวะเกีย", "เซียร์ราลีโอน", "ซานมาริโน", "เซเนกัล", "โซมาเลีย", "ซูรินาเม", "ซูดานใต้", "เซาตูเมและปรินซิปี", "เอลซัลวาดอร์", "ซินต์มาร์เทน", "ซีเรีย", "เอสวาตีนี", "ทริสตันดาคูนา", "หมู่เกาะเติกส์และหมู่เกาะเคคอส", "ชาด", "เฟรนช์เซาเทิร์นเทร์ริทอรีส์", "โตโก", "ไทย", "ทาจิกิสถาน", "โตเกเลา", "ติมอร์-เลสเต", "เติร์กเมนิสถาน", "ตูนิเซีย", "ตองกา", "ตุรกี", "ตรินิแดดและโตเบโก", "ตูวาลู", "ไต้หวัน", "แทนซาเนีย", "ยูเครน", "ยูกันดา", "หมู่เกาะรอบนอกของสหรัฐอเมริกา", "สหประชาชาติ", "สหรัฐอเมริกา", "อุรุกวัย", "อุซเบกิสถาน", "นครวาติกัน", "เซนต์วินเซนต์และเกรนาดีนส์", "เวเนซุเอลา", "หมู่เกาะบริติชเวอร์จิน", "หมู่เกาะเวอร์จินของสหรัฐอเมริกา", "เวียดนาม", "วานูอาตู", "วาลลิสและฟุตูนา", "ซามัว", "สำเนียงไม่จริง", "Bidi ไม่จริง", "โคโซโว", "เยเมน", "มายอต", "แอฟริกาใต้", "แซมเบีย", "ซิมบับเว"];
+
+// ti
+static R101: [&str; 294] = ["ዓለም", "ኣፍሪቃ", "ሰሜን ኣመሪካ", "ደቡብ ኣመሪካ", "ኦሽያንያ", "ምዕራባዊ ኣፍሪቃ", "ማእከላይ ኣመሪካ", "ምብራቓዊ ኣፍሪቃ", "ሰሜናዊ ኣፍሪቃ", "ማእከላይ ኣፍሪቃ", "ደቡባዊ ኣፍሪቃ", "ኣመሪካታት", "ሰሜናዊ ኣመሪካ", "ካሪብያን", "ምብራቓዊ ኤስያ", "ደቡባዊ ኤስያ", "ደቡባዊ ምብራቕ ኤስያ", "ደቡባዊ ኤውሮጳ", "ኣውስትራሌዥያ", "መላነዥያ", "ዞባ ማይክሮነዥያ", "ፖሊነዥያ", "ኤስያ", "ማእከላይ ኤስያ", "ምዕራባዊ ኤስያ", "ኤውሮጳ", "ምብራቓዊ ኤውሮጳ", "ሰሜናዊ ኤውሮጳ", "ምዕራባዊ ኤውሮጳ", "ንኡስ ሰሃራዊ ኣፍሪቃ", "ላቲን ኣመሪካ", "ደሴት ኣሰንስዮን", "ኣንዶራ", "ሕቡራት ኢማራት ዓረብ", "ኣፍጋኒስታን", "ኣንቲጓን ባርቡዳን", "ኣንጒላ", "ኣልባንያ", "ኣርሜንያ", "ኣንጎላ", "ኣንታርክቲካ", "ኣርጀንቲና", "ኣመሪካዊት ሳሞኣ", "ኦስትርያ", "ኣውስትራልያ", "ኣሩባ", "ደሴታት ኣላንድ", "ኣዘርባጃን", "ቦዝንያን ሄርዘጎቪናን", "ባርባዶስ", "ባንግላደሽ", "ቤልጅዩም", "ቡርኪና ፋሶ", "ቡልጋርያ", "ባሕሬን", "ብሩንዲ", "ቤኒን", "ቅዱስ ባርተለሚ", "በርሙዳ", "ብሩነይ", "ቦሊቭያ", "ካሪብያን ኔዘርላንድ", "ብራዚል", "ባሃማስ", "ቡታን", "ደሴት ቡቨት", "ቦትስዋና", "ቤላሩስ", "በሊዝ", "ካናዳ", "ደሴታት ኮኮስ", "ደሞክራስያዊት ሪፓብሊክ ኮንጎ", "ሪፓብሊክ ማእከላይ ኣፍሪቃ", "ኮንጎ", "ስዊዘርላንድ", "ኮት ዲቭዋር", "ደሴታት ኩክ", "ቺሌ", "ካሜሩን", "ቻይና", "ኮሎምብያ", "ደሴት ክሊፐርቶን", "ሳርክ", "ኮስታ ሪካ", "ኩባ", "ኬፕ ቨርደ", "ኩራሳው", "ደሴት ክሪስማስ", "ቆጵሮስ", "ቸክያ", "ጀርመን", "ድየጎ ጋርስያ", "ጅቡቲ", "ደንማርክ", "ዶሚኒካ", "ዶሚኒካዊት ሪፓብሊክ", "ኣልጀርያ", "ሴውታን መሊላን", "ኤኳዶር", "ኤስቶንያ", "ግብጺ", "ምዕራባዊ ሰሃራ", "ኤርትራ", "ስጳኛ", "ኢትዮጵያ", "ኤውሮጳዊ ሕብረት", "ዞባ ዩሮ", "ፊንላንድ", "ፊጂ", "ደሴታት ፎክላንድ", "ማይክሮነዥያ", "ደሴታት ፋሮ", "ፈረንሳ", "ጋቦን", "ብሪጣንያ", "ግረናዳ", "ጆርጅያ", "ፈረንሳዊት ጊያና", "ገርንዚ", "ጋና", "ጂብራልታር", "ግሪንላንድ", "ጋምብያ", "ጊኒ", "ጓደሉፕ", "ኢኳቶርያል ጊኒ", "ግሪኽ", "ደሴታት ደቡብ ጆርጅያን ደቡብ ሳንድዊችን", "ጓቲማላ", "ጓም", "ጊኒ-ቢሳው", "ጉያና", "ፍሉይ ምምሕዳራዊ ዞባ ሆንግ ኮንግ (ቻይና)", "ደሴታት ሄርድን ማክዶናልድን", "ሆንዱራስ", "ክሮኤሽያ", "ሃይቲ", "ሃንጋሪ", "ደሴታት ካናሪ", "ኢንዶነዥያ", "ኣየርላንድ", "እስራኤል", "ኣይል ኦፍ ማን", "ህንዲ", "ብሪጣንያዊ ህንዳዊ ውቅያኖስ ግዝኣት", "ዒራቕ", "ኢራን", "ኣይስላንድ", "ኢጣልያ", "ጀርዚ", "ጃማይካ", "ዮርዳኖስ", "ጃፓን", "ኬንያ", "ኪርጊዝስታን", "ካምቦድያ", "ኪሪባቲ", "ኮሞሮስ", "ቅዱስ ኪትስን ኔቪስን", "ሰሜን ኮርያ", "ደቡብ ኮርያ", "ኩዌት", "ደሴታት ካይማን", "ካዛኪስታን", "ላኦስ", "ሊባኖስ", "ቅድስቲ ሉስያ", "ሊኽተንሽታይን", "ስሪ ላንካ", "ላይበርያ", "ሌሶቶ", "ሊትዌንያ", "ሉክሰምበርግ", "ላትቭያ", "ሊብያ", "ሞሮኮ", "ሞናኮ", "ሞልዶቫ", "ሞንተኔግሮ", "ቅዱስ ማርቲን", "ማዳጋስካር", "ደሴታት ማርሻል", "ሰሜን መቄዶንያ", "ማሊ", "ሚያንማር (በርማ)", "ሞንጎልያ", "ፍሉይ ምምሕዳራዊ ዞባ ማካው (ቻይና)", "ሰሜናዊ ደሴታት ማርያና", "ማርቲኒክ", "ማውሪታንያ", "ሞንትሰራት", "ማልታ", "ማውሪሸስ", "ማልዲቭስ", "ማላዊ", "ሜክሲኮ", "ማለዥያ", "ሞዛምቢክ", "ናሚብያ", "ኒው ካለዶንያ", "ኒጀር", "ደሴት ኖርፎልክ", "ናይጀርያ", "ኒካራጓ", "ኔዘርላንድ", "ኖርወይ", "ኔፓል", "ናውሩ", "ኒዩ", "ኒው ዚላንድ", "ዖማን", "ፓናማ", "ፔሩ", "ፈረንሳዊት ፖሊነዥያ", "ፓፕዋ ኒው ጊኒ", "ፊሊፒንስ", "ፓኪስታን", "ፖላንድ", "ቅዱስ ፕየርን ሚከሎንን", "ደሴታት ፒትካርን", "ፖርቶ ሪኮ", "ግዝኣታት ፍልስጤም", "ፖርቱጋል", "ፓላው", "ፓራጓይ", "ቐጠር", "ካብ ኦሽያንያ ርሒቖም ግዝኣታት", "ርዩንየን", "ሩማንያ", "ሰርብያ", "ሩስያ", "ርዋንዳ", "ስዑዲ ዓረብ", "ደሴታት ሰሎሞን", "ሲሸልስ", "ሱዳን", "ሽወደን", "ሲንጋፖር", "ቅድስቲ ሄለና", "ስሎቬንያ", "ስቫልባርድን ጃን ማየንን", "ስሎቫክያ", "ሴራ ልዮን", "ሳን ማሪኖ", "ሰነጋል", "ሶማልያ", "ሱሪናም", "ደቡብ ሱዳን", "ሳኦ ቶመን ፕሪንሲፐን", "ኤል ሳልቫዶር", "ሲንት ማርተን", "ሶርያ", "ኤስዋቲኒ", "ትሪስታን ዳ ኩንያ", "ደሴታት ቱርካትን ካይኮስን", "ቻድ", "ፈረንሳዊ ደቡባዊ ግዝኣታት", "ቶጎ", "ታይላንድ", "ታጂኪስታን", "ቶከላው", "ቲሞር-ለስተ", "ቱርክመኒስታን", "ቱኒዝያ", "ቶንጋ", "ቱርኪ", "ትሪኒዳድን ቶባጎን", "ቱቫሉ", "ታይዋን", "ታንዛንያ", "ዩክሬን", "ኡጋንዳ", "ካብ ኣመሪካ ርሒቐን ንኣሽቱ ደሴታት", "ሕቡራት ሃገራት", "ኣመሪካ", "ኡራጓይ", "ኡዝበኪስታን", "ከተማ ቫቲካን", "ቅዱስ ቪንሰንትን ግረነዲነዝን", "ቬኔዝዌላ", "ደሴታት ደናግል ብሪጣንያ", "ደሴታት ደናግል ኣመሪካ", "ቬትናም", "ቫንዋቱ", "ዋሊስን ፉቱናን", "ሳሞኣ", "ናይ ሓሶት ላህጃታት", "ናይ ሓሶት ክልተ ኣንፈታዊ", "ኮሶቮ", "የመን", "ማዮት", "ደቡብ ኣፍሪቃ", "ዛምብያ", "ዚምባብዌ"];
+
+// tk
+static R102: [&str; 294] = ["Dünýä", "Afrika", "Demirgazyk Amerika", "Günorta Amerika", "Okeaniýa", "Günbatar Afrika", "Orta Amerika", "Gündogar Afrika", "Demirgazyk Afrika", "Orta Afrika", "Afrikanyň günorta sebitleri", "Amerika", "Amerikanyň demirgazyk ýurtlary", "Karib basseýni", "Gündogar Aziýa", "Günorta Aziýa", "Günorta-gündogar Aziýa", "Günorta Ýewropa", "Awstralaziýa", "Melaneziýa", "Mikroneziýa sebti", "Polineziýa", "Aziýa", "Merkezi Aziýa", "Günbatar Aziýa", "Ýewropa", "Gündogar Ýewropa", "Demirgazyk Ýewropa", "Günbatar Ýewropa", "Saharadan aşakdaky Afrika", "Latyn Amerikasy", "Beýgeliş adasy", "Andorra", "Birleşen Arap Emirlikleri", "Owganystan", "Antigua we Barbuda", "Angilýa", "Albaniýa", "Ermenistan", "Angola", "Antarktika", "Argentina", "Amerikan Samoasy", "Awstriýa", "Awstraliýa", "Aruba", "Aland adalary", "Azerbaýjan", "Bosniýa we Gersegowina", "Barbados", "Bangladeş", "Belgiýa", "Burkina-Faso", "Bolgariýa", "Bahreýn", "Burundi", "Benin", "Sen-Bartelemi", "Bermuda", "Bruneý", "Boliwiýa", "Karib Niderlandlary", "Braziliýa", "Bagama adalary", "Butan", "Buwe adasy", "Botswana", "Belarus", "Beliz", "Kanada", "Kokos (Kiling) adalary", "Kongo - Kinşasa", "Merkezi Afrika Respublikasy", "Kongo - Brazzawil", "Şweýsariýa", "Kot-d’Iwuar", "Kuk adalary", "Çili", "Kamerun", "Hytaý", "Kolumbiýa", "Klipperton adasy", "Sark", "Kosta-Rika", "Kuba", "Kabo-Werde", "Kýurasao", "Roždestwo adasy", "Kipr", "Çehiýa", "Germaniýa", "Diýego-Garsiýa", "Jibuti", "Daniýa", "Dominika", "Dominikan Respublikasy", "Alžir", "Seuta we Melilýa", "Ekwador", "Estoniýa", "Müsür", "Günbatar Sahara", "Eritreýa", "Ispaniýa", "Efiopiýa", "Ýewropa Bileleşigi", "Ýewro sebiti", "Finlýandiýa", "Fiji", "Folklend adalary", "Mikroneziýa", "Farer adalary", "Fransiýa", "Gabon", "Birleşen Patyşalyk", "Grenada", "Gruziýa", "Fransuz Gwianasy", "Gernsi", "Gana", "Gibraltar", "Grenlandiýa", "Gambiýa", "Gwineýa", "Gwadelupa", "Ekwatorial Gwineýa", "Gresiýa", "Günorta Georgiýa we Günorta Sendwiç adasy", "Gwatemala", "Guam", "Gwineýa-Bisau", "Gaýana", "Gonkong AAS Hytaý", "Herd we Makdonald adalary", "Gonduras", "Horwatiýa", "Gaiti", "Wengriýa", "Kanar adalary", "Indoneziýa", "Irlandiýa", "Ysraýyl", "Men adasy", "Hindistan", "Britaniýanyň Hindi okeanyndaky territoriýalary", "Yrak", "Eýran", "Islandiýa", "Italiýa", "Jersi", "Ýamaýka", "Iordaniýa", "Ýaponiýa", "Keniýa", "Gyrgyzystan", "Kamboja", "Kiribati", "Komor adalary", "Sent-Kits we Newis", "Demirgazyk Koreýa", "Günorta Koreýa", "Kuweýt", "Kaýman adalary", "Gazagystan", "Laos", "Liwan", "Sent-Lýusiýa", "Lihtenşteýn", "Şri-Lanka", "Liberiýa", "Lesoto", "Litwa", "Lýuksemburg", "Latwiýa", "Liwiýa", "Marokko", "Monako", "Moldowa", "Çernogoriýa", "Sen-Marten", "Madagaskar", "Marşall adalary", "Demirgazyk Makedoniýa", "Mali", "Mýanma (Birma)", "Mongoliýa", "Makao AAS Hytaý", "Demirgazyk Mariana adalary", "Martinika", "Mawritaniýa", "Monserrat", "Malta", "Mawrikiý", "Maldiwler", "Malawi", "Meksika", "Malaýziýa", "Mozambik", "Namibiýa", "Täze Kaledoniýa", "Niger", "Norfolk adasy", "Nigeriýa", "Nikaragua", "Niderlandlar", "Norwegiýa", "Nepal", "Nauru", "Niue", "Täze Zelandiýa", "Oman", "Panama", "Peru", "Fransuz Polineziýasy", "Papua - Täze Gwineýa", "Filippinler", "Pakistan", "Polşa", "Sen-Pýer we Mikelon", "Pitkern adalary", "Puerto-Riko", "Palestina territoriýasy", "Portugaliýa", "Palau", "Paragwaý", "Katar", "Daşky Okeaniýa", "Reýunýon", "Rumyniýa", "Serbiýa", "Russiýa", "Ruanda", "Saud Arabystany", "Solomon adalary", "Seýşel adalary", "Sudan", "Şwesiýa", "Singapur", "Keramatly Ýelena adasy", "Sloweniýa", "Şpisbergen we Ýan-Maýen", "Slowakiýa", "Sýerra-Leone", "San-Marino", "Senegal", "Somali", "Surinam", "Günorta Sudan", "San-Tome we Prinsipi", "Salwador", "Sint-Marten", "Siriýa", "Eswatini", "Tristan-da-Kunýa", "Terks we Kaýkos adalary", "Çad", "Fransuz günorta territoriýalary", "Togo", "Taýland", "Täjigistan", "Tokelau", "Timor-Leste", "Türkmenistan", "Tunis", "Tonga", "Türkiýe", "Trinidad we Tobago", "Tuwalu", "Taýwan", "Tanzaniýa", "Ukraina", "Uganda", "ABŞ-nyň daşarky adalary", "Birleşen Milletler Guramasy", "Amerikanyň Birleşen Ştatlary", "Urugwaý", "Özbegistan", "Watikan", "Sent-Winsent we Grenadinler", "Wenesuela", "Britan Wirgin adalary", "ABŞ-nyň Wirgin adalary", "Wýetnam", "Wanuatu", "Uollis we Futuna", "Samoa", "psewdo-şiweler", "psewdo-bidi", "Kosowo", "Ýemen", "Maýotta", "Günorta Afrika", "Zambiýa", "Zimbabwe"];
+
+// tl
+static R103: [&str; 294] = ["mundo", "Africa", "Hilagang Amerika", "Timog Amerika", "Oceania", "Kanlurang Africa", "Gitnang Amerika", "Silangang Africa", "Hilagang Africa", "Gitnang Africa", "Katimugang Africa", "Americas", "Northern America", "Carribbean", "Silangang Asya", "Katimugang Asya", "Timog-Silangang Asya", "Katimugang Europe", "Australasia", "Melanesia", "Rehiyon ng Micronesia", "Polynesia", "Asya", "Gitnang Asya", "Kanlurang Asya", "Europe", "Silangang Europe", "Hilagang Europe", "Kanlurang Europe", "Sub-Saharan Africa", "Latin America", "Acsencion island", "Andorra", "United Arab Emirates", "Afghanistan", "Antigua & Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Argentina", "American Samoa", "Austria", "Australia", "Aruba", "Åland Islands", "Azerbaijan", "Bosnia and Herzegovina", "Barbados", "Bangladesh", "Belgium", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "St. Barthélemy", "Bermuda", "Brunei", "Bolivia", "Caribbean Netherlands", "Brazil", "Bahamas", "Bhutan", "Bouvet Island", "Botswana", "Belarus", "Belize", "Canada", "Cocos (Keeling) Islands", "Congo - Kinshasa", "Central African Republic", "Congo - Brazzaville", "Switzerland", "Côte d’Ivoire", "Cook Islands", "Chile", "Cameroon", "China", "Colombia", "Clipperton Island", "Sark", "Costa Rica", "Cuba", "Cape Verde", "Curaçao", "Christmas Island", "Cyprus", "Czechia", "Germany", "Diego Garcia", "Djibouti", "Denmark", "Dominica", "Dominican Republic", "Algeria", "Ceuta & Melilla", "Ecuador", "Estonia", "Egypt", "Kanlurang Sahara", "Eritrea", "Spain", "Ethiopia", "European Union", "Eurozone", "Finland", "Fiji", "Falkland Islands", "Micronesia", "Faroe Islands", "France", "Gabon", "United Kingdom", "Grenada", "Georgia", "French Guiana", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Equatorial Guinea", "Greece", "South Georgia & South Sandwich Islands", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hong Kong SAR China", "Heard & McDonald Islands", "Honduras", "Croatia", "Haiti", "Hungary", "Canary Islands", "Indonesia", "Ireland", "Israel", "Isle of Man", "India", "British Indian Ocean Territory", "Iraq", "Iran", "Iceland", "Italy", "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kyrgyzstan", "Cambodia", "Kiribati", "Comoros", "St. Kitts & Nevis", "Hilagang Korea", "Timog Korea", "Kuwait", "Cayman Islands", "Kazakhstan", "Laos", "Lebanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lithuania", "Luxembourg", "Latvia", "Libya", "Morocco", "Monaco", "Moldova", "Montenegro", "Saint Martin", "Madagascar", "Marshall Islands", "North Macedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macau SAR China", "Northern Mariana Islands", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldives", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger", "Norfolk Island", "Nigeria", "Nicaragua", "Netherlands", "Norway", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "French Polynesia", "Papua New Guinea", "Pilipinas", "Pakistan", "Poland", "St. Pierre & Miquelon", "Pitcairn Islands", "Puerto Rico", "Palestinian Territories", "Portugal", "Palau", "Paraguay", "Qatar", "Outlying Oceania", "Réunion", "Romania", "Serbia", "Russia", "Rwanda", "Saudi Arabia", "Solomon Islands", "Seychelles", "Sudan", "Sweden", "Singapore", "St. Helena", "Slovenia", "Svalbard & Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Timog Sudan", "São Tomé & Príncipe", "El Salvador", "Sint Maarten", "Syria", "Swaziland", "Tristan de Cunha", "Turks & Caicos Islands", "Chad", "French Southern Territories", "Togo", "Thailand", "Tajikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Türkiye", "Trinidad & Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "U.S. Outlying Islands", "United Nations", "Estados Unidos", "Uruguay", "Uzbekistan", "Vatican City", "St. Vincent & Grenadines", "Venezuela", "British Virgin Islands", "U.S. Virgin Islands", "Vietnam", "Vanuatu", "Wallis & Futuna", "Samoa", "Mga Pseudo-Accent", "Pseudo-Bidi", "Kosovo", "Yemen", "Mayotte", "South Africa", "Zambia", "Zimbabwe"];
+
+// tn
+static R104: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Botswana", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Aforika Borwa", "", ""];
+
+// to
+static R105: [&str; 294] = ["Māmani", "ʻAfilika", "ʻAmelika tokelau", "ʻAmelika tonga", "ʻOsēnia", "ʻAfilika fakahihifo", "ʻAmelika lotoloto", "ʻAfilika fakahahake", "ʻAfilika fakatokelau", "ʻAfilika lotoloto", "ʻAfilika fakatonga", "Ongo ʻAmelika", "ʻAmelika fakatokelau", "Kalipiane", "ʻĒsia fakahahake", "ʻĒsia fakatonga", "ʻĒsia fakatongahahake", "ʻEulope fakatonga", "ʻAositelēlēsia", "Melanīsia", "Potu fonua Mikolonīsia", "Polinīsia", "ʻĒsia", "ʻĒsia lotoloto", "ʻĒsia fakahihifo", "ʻEulope", "ʻEulope fakahahake", "ʻEulope fakatokelau", "ʻEulope fakahihifo", "ʻAfilika fakasahala-tonga", "ʻAmelika fakalatina", "Motu ʻAsenisini", "ʻAnitola", "ʻAlepea Fakatahataha", "ʻAfikānisitani", "Anitikua mo Palaputa", "Anikuila", "ʻAlipania", "ʻĀmenia", "ʻAngikola", "ʻAnitātika", "ʻAsenitina", "Haʻamoa ʻAmelika", "ʻAositulia", "ʻAositelēlia", "ʻAlupa", "ʻOtumotu ʻAlani", "ʻAsapaisani", "Posinia mo Hesikōvina", "Pāpeitosi", "Pengilātesi", "Pelesiume", "Pekano Faso", "Pulukalia", "Paleini", "Puluniti", "Penini", "Sā Patēlemi", "Pēmuta", "Pulunei", "Polīvia", "Kalipiane fakahōlani", "Palāsili", "Pahama", "Pūtani", "Motu Puveti", "Potisiuana", "Pelalusi", "Pelise", "Kānata", "ʻOtumotu Koko", "Kongo - Kinisasa", "Lepupelika ʻAfilika Lotoloto", "Kongo - Palasavila", "Suisilani", "Matafonua ʻAivolī", "ʻOtumotu Kuki", "Sili", "Kameluni", "Siaina", "Kolomipia", "Motu Kilipatoni", "", "Kosita Lika", "Kiupa", "Muiʻi Vēte", "Kulasao", "Motu Kilisimasi", "Saipalesi", "Sēkia", "Siamane", "Tieko Kāsia", "Siputi", "Tenimaʻake", "Tominika", "Lepupelika Tominika", "ʻAlisilia", "Siuta mo Melila", "ʻEkuetoa", "ʻEsitōnia", "ʻIsipite", "Sahala fakahihifo", "ʻElitulia", "Sipeini", "ʻĪtiōpia", "ʻEulope fakatahataha", "ʻEulope fekauʻaki-paʻanga", "Finilani", "Fisi", "ʻOtumotu Fokulani", "Mikolonīsia", "ʻOtumotu Faloe", "Falanisē", "Kaponi", "Pilitānia", "Kelenatā", "Seōsia", "Kuiana fakafalanisē", "Kuenisī", "Kana", "Sipalālitā", "Kulinilani", "Kamipia", "Kini", "Kuatalupe", "ʻEkueta Kini", "Kalisi", "ʻOtumotu Seōsia-tonga mo Saniuisi-tonga", "Kuatamala", "Kuamu", "Kini-Pisau", "Kuiana", "Hongi Kongi SAR Siaina", "ʻOtumotu Heati mo Makitonali", "Honitulasi", "Kuloisia", "Haiti", "Hungakalia", "ʻOtumotu Kaneli", "ʻInitonēsia", "ʻAealani", "ʻIsileli", "Motu Mani", "ʻInitia", "Potu fonua moana ʻInitia fakapilitānia", "ʻIlaaki", "ʻIlaani", "ʻAisilani", "ʻĪtali", "Selusī", "Samaika", "Soatane", "Siapani", "Keniā", "Kīkisitani", "Kamipōtia", "Kilipasi", "Komolosi", "Sā Kitisi mo Nevisi", "Kōlea tokelau", "Kōlea tonga", "Kueiti", "ʻOtumotu Keimeni", "Kasakitani", "Lau", "Lepanoni", "Sā Lūsia", "Likitenisiteini", "Sīlangikā", "Laipelia", "Lesoto", "Lituania", "Lakisimipeki", "Lativia", "Līpia", "Moloko", "Monako", "Molotova", "Monitenikalo", "Sā Mātini (fakafalanisē)", "Matakasika", "ʻOtumotu Māsolo", "Masetōnia fakatokelau", "Māli", "Mianimā (Pema)", "Mongokōlia", "Makau SAR Siaina", "ʻOtumotu Maliana tokelau", "Mātiniki", "Maulitenia", "Moʻungaselati", "Malita", "Maulitiusi", "Malativisi", "Malaui", "Mekisikou", "Malēsia", "Mosēmipiki", "Namipia", "Niu Kaletōnia", "Nisia", "Motu Nōfoliki", "Naisilia", "Nikalakua", "Hōlani", "Noauē", "Nepali", "Naulu", "Niuē", "Nuʻusila", "ʻOmani", "Panamā", "Pelū", "Polinisia fakafalanisē", "Papuaniukini", "Filipaini", "Pākisitani", "Polani", "Sā Piea mo Mikeloni", "ʻOtumotu Pitikeni", "Puēto Liko", "Potu Palesitaine", "Potukali", "Palau", "Palakuai", "Katā", "ʻOsēnia mamaʻo", "Lēunioni", "Lomēnia", "Sēpia", "Lūsia", "Luanitā", "Saute ʻAlepea", "ʻOtumotu Solomone", "ʻOtumotu Seiseli", "Sūteni", "Suēteni", "Singapoa", "Sā Helena", "Silōvenia", "Sivolopāti mo Sani Maieni", "Silōvakia", "Siela Leone", "Sā Malino", "Senekalo", "Sōmalia", "Suliname", "Sūtani fakatonga", "Sao Tomē mo Pilinisipe", "ʻEle Salavatoa", "Sā Mātini (fakahōlani)", "Sīlia", "ʻEsuatini", "Tulisitani ta Kunuha", "ʻOtumotu Tuki mo Kaikosi", "Sāti", "Potu fonua tonga fakafalanisē", "Toko", "Tailani", "Tasikitani", "Tokelau", "Timoa fakahahake", "Tūkimenisitani", "Tunīsia", "Tonga", "Toake", "Tilinitati mo Topako", "Tūvalu", "Taiuani", "Tenisānia", "ʻŪkalaʻine", "ʻIukanitā", "ʻOtumotu siʻi ʻo ʻAmelika", "ʻŪ fonua fakatahataha", "Puleʻanga fakatahataha ʻAmelika", "ʻUlukuai", "ʻUsipekitani", "Kolo Vatikani", "Sā Viniseni mo Kulenatini", "Venesuela", "ʻOtumotu Vilikini fakapilitānia", "ʻOtumotu Vilikini fakaʻamelika", "Vietinami", "Vanuatu", "ʻUvea mo Futuna", "Haʻamoa", "fasiʻalea loi", "fua-ua loi", "Kōsovo", "Iemeni", "Maiote", "ʻAfilika tonga", "Semipia", "Simipapuei"];
+
+// tr
+static R106: [&str; 294] = ["Dünya", "Afrika", "Kuzey Amerika", "Güney Amerika", "Okyanusya", "Batı Afrika", "Orta Amerika", "Doğu Afrika", "Kuzey Afrika", "Orta Afrika", "Afrika’nın Güneyi", "Amerika", "Amerika’nın Kuzeyi", "Karayipler", "Doğu Asya", "Güney Asya", "Güneydoğu Asya", "Güney Avrupa", "Avustralasya", "Melanezya", "Mikronezya Bölgesi", "Polinezya", "Asya", "Orta Asya", "Batı Asya", "Avrupa", "Doğu Avrupa", "Kuzey Avrupa", "Batı Avrupa", "Sahra Altı Afrika", "Latin Amerika", "Ascension Adası", "Andorra", "Birleşik Arap Emirlikleri", "Afganistan", "Antigua ve Barbuda", "Anguilla", "Arnavutluk", "Ermenistan", "Angola", "Antarktika", "Arjantin", "Amerikan Samoası", "Avusturya", "Avustralya", "Aruba", "Åland Adaları", "Azerbaycan", "Bosna-Hersek", "Barbados", "Bangladeş", "Belçika", "Burkina Faso", "Bulgaristan", "Bahreyn", "Burundi", "Benin", "Saint Barthelemy", "Bermuda", "Brunei", "Bolivya", "Karayip Hollandası", "Brezilya", "Bahamalar", "Butan", "Bouvet Adası", "Botsvana", "Belarus", "Belize", "Kanada", "Cocos (Keeling) Adaları", "Kongo - Kinşasa", "Orta Afrika Cumhuriyeti", "Kongo - Brazavil", "İsviçre", "Côte d’Ivoire", "Cook Adaları", "Şili", "Kamerun", "Çin", "Kolombiya", "Clipperton Adası", "Sark", "Kosta Rika", "Küba", "Cabo Verde", "Curaçao", "Christmas Adası", "Kıbrıs", "Çekya", "Almanya", "Diego Garcia", "Cibuti", "Danimarka", "Dominika", "Dominik Cumhuriyeti", "Cezayir", "Ceuta ve Melilla", "Ekvador", "Estonya", "Mısır", "Batı Sahra", "Eritre", "İspanya", "Etiyopya", "Avrupa Birliği", "Euro Bölgesi", "Finlandiya", "Fiji", "Falkland Adaları", "Mikronezya", "Faroe Adaları", "Fransa", "Gabon", "Birleşik Krallık", "Grenada", "Gürcistan", "Fransız Guyanası", "Guernsey", "Gana", "Cebelitarık", "Grönland", "Gambiya", "Gine", "Guadeloupe", "Ekvator Ginesi", "Yunanistan", "Güney Georgia ve Güney Sandwich Adaları", "Guatemala", "Guam", "Gine-Bissau", "Guyana", "Çin Hong Kong ÖİB", "Heard Adası ve McDonald Adaları", "Honduras", "Hırvatistan", "Haiti", "Macaristan", "Kanarya Adaları", "Endonezya", "İrlanda", "İsrail", "Man Adası", "Hindistan", "Britanya Hint Okyanusu Toprakları", "Irak", "İran", "İzlanda", "İtalya", "Jersey", "Jamaika", "Ürdün", "Japonya", "Kenya", "Kırgızistan", "Kamboçya", "Kiribati", "Komorlar", "Saint Kitts ve Nevis", "Kuzey Kore", "Güney Kore", "Kuveyt", "Cayman Adaları", "Kazakistan", "Laos", "Lübnan", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberya", "Lesotho", "Litvanya", "Lüksemburg", "Letonya", "Libya", "Fas", "Monako", "Moldova", "Karadağ", "Saint Martin", "Madagaskar", "Marshall Adaları", "Kuzey Makedonya", "Mali", "Myanmar (Burma)", "Moğolistan", "Çin Makao ÖİB", "Kuzey Mariana Adaları", "Martinik", "Moritanya", "Montserrat", "Malta", "Mauritius", "Maldivler", "Malavi", "Meksika", "Malezya", "Mozambik", "Namibya", "Yeni Kaledonya", "Nijer", "Norfolk Adası", "Nijerya", "Nikaragua", "Hollanda", "Norveç", "Nepal", "Nauru", "Niue", "Yeni Zelanda", "Umman", "Panama", "Peru", "Fransız Polinezyası", "Papua Yeni Gine", "Filipinler", "Pakistan", "Polonya", "Saint Pierre ve Miquelon", "Pitcairn Adaları", "Porto Riko", "Filistin Bölgeleri", "Portekiz", "Palau", "Paraguay", "Katar", "Uzak Okyanusya", "Reunion", "Romanya", "Sırbistan", "Rusya", "Ruanda", "Suudi Arabistan", "Solomon Adaları", "Seyşeller", "Sudan", "İsveç", "Singapur", "Saint Helena", "Slovenya", "Svalbard ve Jan Mayen", "Slovakya", "Sierra Leone", "San Marino", "Senegal", "Somali", "Surinam", "Güney Sudan", "Sao Tome ve Principe", "El Salvador", "Sint Maarten", "Suriye", "Esvatini", "Tristan da Cunha", "Turks ve Caicos Adaları", "Çad", "Fransız Güney Toprakları", "Togo", "Tayland", "Tacikistan", "Tokelau", "Timor-Leste", "Türkmenistan", "Tunus", "Tonga", "Türkiye", "Trinidad ve Tobago", "Tuvalu", "Tayvan", "Tanzanya", "Ukrayna", "Uganda", "ABD Küçük Harici Adaları", "Birleşmiş Milletler", "Amerika Birleşik Devletleri", "Uruguay", "Özbekistan", "Vatikan", "Saint Vincent ve Grenadinler", "Venezuela", "Britanya Virjin Adaları", "ABD Virjin Adaları", "Vietnam", "Vanuatu", "Wallis ve Futuna", "Samoa", "Psödo Aksanlar", "Psödo Bidi", "Kosova", "Yemen", "Mayotte", "Güney Afrika", "Zambiya", "Zimbabve"];
+
+// tt
+static R107: [&str; 294] = ["дөнья", "Африка", "Төньяк Америка", "Көньяк Америка", "Океания", "Көнбатыш Африка", "Үзәк Америка", "Көнчыгыш Африка", "Төньяк Африка", "Урта Африка", "Көньяктагы Африка", "Америка", "Төньяктагы Америка", "Кариб бассейны", "Көнчыгыш Азия", "Көньяк Азия", "Көньяк-Көнчыгыш Азия", "Көньяк Европа", "Австралазия", "Меланезия", "Микронезия төбәге", "Полинезия", "Азия", "Үзәк Азия", "Көнбатыш Азия", "Европа", "Көнчыгыш Европа", "Төньяк Европа", "Көнбатыш Европа", "Сахарадан көньякта Африка", "Латин Америка", "Вознесение утравы", "Андорра", "Берләшкән Гарәп Әмирлекләре", "Әфганстан", "Антигуа һәм Барбуда", "Ангилья", "Албания", "Әрмәнстан", "Ангола", "Антарктика", "Аргентина", "Америка Самоасы", "Австрия", "Австралия", "Аруба", "Аланд утраулары", "Әзәрбайҗан", "Босния һәм Герцеговина", "Барбадос", "Бангладеш", "Бельгия", "Буркина-Фасо", "Болгария", "Бәхрәйн", "Бурунди", "Бенин", "Сен-Бартельми", "Бермуд утраулары", "Бруней", "Боливия", "Кариб Нидерландлары", "Бразилия", "Багам утраулары", "Бутан", "Буве утравы", "Ботсвана", "Беларусь", "Белиз", "Канада", "Кокос (Килинг) утраулары", "Конго (КДР)", "Үзәк Африка Республикасы", "Конго - Браззавиль", "Швейцария", "Кот-д’Ивуар", "Кук утраулары", "Чили", "Камерун", "Кытай", "Колумбия", "Клиппертон утравы", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Раштуа утравы", "Кипр", "Чехия Республикасы", "Германия", "Диего Гарсия", "Җибүти", "Дания", "Доминика", "Доминикана Республикасы", "Алжир", "Сеута һәм Мелилья", "Эквадор", "Эстония", "Мисыр", "Көнбатыш Сахара", "Эритрея", "Испания", "Эфиопия", "Европа Берлеге", "Еврозона", "Финляндия", "Фиджи", "Фолкленд утраулары", "Микронезия", "Фарер утраулары", "Франция", "Габон", "Берләшкән Корольлек", "Гренада", "Грузия", "Француз Гвианасы", "Гернси", "Гана", "Гибралтар", "Гренландия", "Гамбия", "Гвинея", "Гваделупа", "Экваториаль Гвинея", "Греция", "Көньяк Георгия һәм Көньяк Сандвич утраулары", "Гватемала", "Гуам", "Гвинея-Бисау", "Гайана", "Гонконг Махсус Идарәле Төбәге", "Херд утравы һәм Макдональд утраулары", "Гондурас", "Хорватия", "Гаити", "Венгрия", "Канар утраулары", "Индонезия", "Ирландия", "Израиль", "Мэн утравы", "Индия", "Британиянең Һинд Океанындагы Территориясе", "Гыйрак", "Иран", "Исландия", "Италия", "Джерси", "Ямайка", "Иордания", "Япония", "Кения", "Кыргызстан", "Камбоджа", "Кирибати", "Комор утраулары", "Сент-Китс һәм Невис", "Төньяк Корея", "Көньяк Корея", "Күвәйт", "Кайман утраулары", "Казахстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия", "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова", "Черногория", "Сент-Мартин", "Мадагаскар", "Маршалл утраулары", "Төньяк Македония", "Мали", "Мьянма (Бирма)", "Монголия", "Макао Махсус Идарәле Төбәге", "Төньяк Мариана утраулары", "Мартиника", "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдив утраулары", "Малави", "Мексика", "Малайзия", "Мозамбик", "Намибия", "Яңа Каледония", "Нигер", "Норфолк утравы", "Нигерия", "Никарагуа", "Нидерланд", "Норвегия", "Непал", "Науру", "Ниуэ", "Яңа Зеландия", "Оман", "Панама", "Перу", "Француз Полинезиясе", "Папуа - Яңа Гвинея", "Филиппин", "Пакистан", "Польша", "Сен-Пьер һәм Микелон", "Питкэрн утраулары", "Пуэрто-Рико", "Фәләстин территорияләре", "Португалия", "Палау", "Парагвай", "Катар", "Ерак Океания", "Реюньон", "Румыния", "Сербия", "Россия", "Руанда", "Согуд Гарәбстаны", "Сөләйман утраулары", "Сейшел утраулары", "Судан", "Швеция", "Сингапур", "Изге Елена утравы", "Словения", "Шпицберген һәм Ян-Майен", "Словакия", "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Көньяк Судан", "Сан-Томе һәм Принсипи", "Сальвадор", "Синт-Мартен", "Сүрия", "Свазиленд", "Тристан-да-Кунья", "Теркс һәм Кайкос утраулары", "Чад", "Франциянең Көньяк Территорияләре", "Того", "Тайланд", "Таҗикстан", "Токелау", "Тимор-Лесте", "Төркмәнстан", "Тунис", "Тонга", "Төркия", "Тринидад һәм Тобаго", "Тувалу", "Тайвань", "Танзания", "Украина", "Уганда", "АКШ Кече Читтәге утраулары", "Берләшкән Милләтләр", "АКШ", "Уругвай", "Үзбәкстан", "Ватикан", "Сент-Винсент һәм Гренадин", "Венесуэла", "Британия Виргин утраулары", "АКШ Виргин утраулары", "Вьетнам", "Вануату", "Уоллис һәм Футуна", "Самоа", "Псевдоакцентлар", "Псевдо-Биди", "Косово", "Йәмән", "Майотта", "Көньяк Африка", "Замбия", "Зимбабве"];
+
+// ug
+static R108: [&str; 294] = ["دۇنيا", "ئافرىقا", "شىمالىي ئامېرىكا", "جەنۇبىي ئامېرىكا", "ئوكيانىيە", "غەربىي ئافرىقا", "ئوتتۇرا ئامېرىكا", "شەرقىي ئافرىقا", "شىمالىي ئافرىقا", "ئوتتۇرا ئافرىقا", "جەنۇبىي ئافرىقا رايونى", "ئامېرىكا", "شىمالىي ئامېرىكا رايونى", "كارىب دېڭىزى", "شەرقىي ئاسىيا", "جەنۇبىي ئاسىيا", "شەرقىي جەنۇبىي ئاسىيا", "جەنۇبىي ياۋروپا", "ئاۋسترالئاسىيا", "مېلانېسىيە", "مىكرونېزىيە رايونى", "پولىنىزىيە", "ئاسىيا", "ئوتتۇرا ئاسىيا", "غەربىي ئاسىيا", "ياۋروپا", "شەرقىي ياۋروپا", "شىمالىي ياۋروپا", "غەربىي ياۋروپا", "", "لاتىن ئامېرىكا", "ئاسسېنسىيون ئارىلى", "ئاندوررا", "ئەرەب بىرلەشمە خەلىپىلىكى", "ئافغانىستان", "ئانتىگۇئا ۋە باربۇدا", "ئانگۋىللا", "ئالبانىيە", "ئەرمېنىيە", "ئانگولا", "ئانتاركتىكا", "ئارگېنتىنا", "ئامېرىكا ساموئا", "ئاۋىستىرىيە", "ئاۋسترالىيە", "ئارۇبا", "ئالاند ئاراللىرى", "ئەزەربەيجان", "بوسىنىيە ۋە گېرتسېگوۋىنا", "باربادوس", "بېنگال", "بېلگىيە", "بۇركىنا فاسو", "بۇلغارىيە", "بەھرەين", "بۇرۇندى", "بېنىن", "ساينت بارتېلېمى", "بېرمۇدا", "بىرۇنېي", "بولىۋىيە", "كارىب دېڭىزى گوللاندىيە", "بىرازىلىيە", "باھاما", "بۇتان", "بوۋېت ئارىلى", "بوتسۋانا", "بېلارۇسىيە", "بېلىز", "كانادا", "كوكوس (كىلىڭ) ئاراللىرى", "كونگو - كىنشاسا", "ئوتتۇرا ئافرىقا جۇمھۇرىيىتى", "كونگو - بىراززاۋىل", "شىۋېتسارىيە", "كوتې دې ئىۋوئىر", "كۇك ئاراللىرى", "چىلى", "كامېرون", "جۇڭگو", "كولومبىيە", "كىلىپپېرتون ئاراللىرى", "", "كوستارىكا", "كۇبا", "يېشىل تۇمشۇق", "كۇراچاۋ", "مىلاد ئارىلى", "سىپرۇس", "چېخ جۇمھۇرىيىتى", "گېرمانىيە", "دېگو-گارشىيا", "جىبۇتى", "دانىيە", "دومىنىكا", "دومىنىكا جۇمھۇرىيىتى", "ئالجىرىيە", "سېيتا ۋە مېلىلا", "ئېكۋاتور", "ئېستونىيە", "مىسىر", "غەربىي ساخارا", "ئېرىترىيە", "ئىسپانىيە", "ئېفىيوپىيە", "ياۋروپا ئىتتىپاقى", "", "فىنلاندىيە", "فىجى", "فالكلاند ئاراللىرى", "مىكرونېزىيە", "فارو ئاراللىرى", "فىرانسىيە", "گابون", "بىرلەشمە پادىشاھلىق", "گىرېنادا", "گىرۇزىيە", "فىرانسىيەگە قاراشلىق گىۋىيانا", "گۇرنسېي", "گانا", "جەبىلتارىق", "گىرېنلاندىيە", "گامبىيە", "گىۋىنىيە", "گىۋادېلۇپ", "ئېكۋاتور گىۋىنىيەسى", "گىرېتسىيە", "جەنۇبىي جورجىيە ۋە جەنۇبىي ساندۋىچ ئاراللىرى", "گىۋاتېمالا", "گۇئام", "گىۋىنىيە بىسسائۇ", "گىۋىيانا", "شياڭگاڭ ئالاھىدە مەمۇرىي رايونى (جۇڭگو)", "ھېرد ئارىلى ۋە ماكدونالد ئاراللىرى", "ھوندۇراس", "كىرودىيە", "ھايتى", "ۋېنگىرىيە", "كانارى ئاراللىرى", "ھىندونېزىيە", "ئىرېلاندىيە", "ئىسرائىلىيە", "مان ئارىلى", "ھىندىستان", "", "ئىراق", "ئىران", "ئىسلاندىيە", "ئىتالىيە", "جېرسېي", "يامايكا", "ئىيوردانىيە", "ياپونىيە", "كېنىيە", "قىرغىزىستان", "كامبودژا", "كىرىباتى", "كومورو", "ساينت كىتىس ۋە نېۋىس", "چاۋشيەن", "كورېيە", "كۇۋەيت", "كايمان ئاراللىرى", "قازاقىستان", "لائوس", "لىۋان", "ساينت لۇسىيە", "لىكتېنستېين", "سىرىلانكا", "لىبېرىيە", "لېسوتو", "لىتۋانىيە", "لىيۇكسېمبۇرگ", "لاتۋىيە", "لىۋىيە", "ماراكەش", "موناكو", "مولدوۋا", "قارا تاغ", "ساينت مارتىن", "ماداغاسقار", "مارشال ئاراللىرى", "شىمالىي ماكېدونىيە", "مالى", "بىرما", "موڭغۇلىيە", "ئاۋمېن ئالاھىدە مەمۇرىي رايونى", "شىمالىي مارىيانا ئاراللىرى", "مارتىنىكا", "ماۋرىتانىيە", "مونتسېررات", "مالتا", "ماۋرىتىيۇس", "مالدىۋې", "مالاۋى", "مېكسىكا", "مالايسىيا", "موزامبىك", "نامىبىيە", "يېڭى كالېدونىيە", "نىگېر", "نورفولك ئارىلى", "نىگېرىيە", "نىكاراگۇئا", "گوللاندىيە", "نورۋېگىيە", "نېپال", "ناۋرۇ", "نيۇئې", "يېڭى زېلاندىيە", "ئومان", "پاناما", "پېرۇ", "فىرانسىيەگە قاراشلىق پولىنېزىيە", "پاپۇئا يېڭى گىۋىنىيەسى", "فىلىپپىن", "پاكىستان", "پولشا", "ساينت پىيېر ۋە مىكېلون ئاراللىرى", "پىتكايرن ئاراللىرى", "پۇئېرتو رىكو", "پەلەستىن زېمىنى", "پورتۇگالىيە", "پالائۇ", "پاراگۋاي", "قاتار", "ئوكيانىيە ئەتراپىدىكى ئاراللار", "رېيۇنىيون", "رومىنىيە", "سېربىيە", "رۇسىيە", "رىۋاندا", "سەئۇدىي ئەرەبىستان", "سولومون ئاراللىرى", "سېيشېل", "سۇدان", "شىۋېتسىيە", "سىنگاپور", "ساينىت ھېلېنا", "سىلوۋېنىيە", "سىۋالبارد ۋە يان مايېن", "سىلوۋاكىيە", "سېررالېئون", "سان مارىنو", "سېنېگال", "سومالى", "سۇرىنام", "جەنۇبىي سۇدان", "سان تومې ۋە پرىنسىپې", "سالۋادور", "سىنت مارتېن", "سۇرىيە", "سىۋېزىلاند", "ترىستان داكۇنھا", "تۇركس ۋە كايكوس ئاراللىرى", "چاد", "فىرانسىيەنىڭ جەنۇبىي زېمىنى", "توگو", "تايلاند", "تاجىكىستان", "توكېلاۋ", "شەرقىي تىمور", "تۈركمەنىستان", "تۇنىس", "تونگا", "تۈركىيە", "تىرىنىداد ۋە توباگو", "تۇۋالۇ", "تەيۋەن", "تانزانىيە", "ئۇكرائىنا", "ئۇگاندا", "ئا ق ش تاشقى ئاراللىرى", "", "ئامېرىكا قوشما ئىشتاتلىرى", "ئۇرۇگۋاي", "ئۆزبېكىستان", "ۋاتىكان", "ساينت ۋىنسېنت ۋە گىرېنادىنېس", "ۋېنېسۇئېلا", "ئەنگلىيە ۋىرگىن ئاراللىرى", "ئا ق ش ۋىرگىن ئاراللىرى", "ۋىيېتنام", "ۋانۇئاتۇ", "ۋاللىس ۋە فۇتۇنا", "ساموئا", "", "", "كوسوۋو", "يەمەن", "مايوتى", "جەنۇبىي ئافرىقا", "زامبىيە", "زىمبابۋې"];
+
+// uk
+static R109: [&str; 294] = ["Світ", "Африка", "Північна Америка", "Південна Америка", "Океанія", "Західна Африка", "Центральна Америка", "Східна Африка", "Північна Африка", "Центральна Африка", "Південноафриканський регіон", "Американський регіон", "Північноамериканський регіон", "Карибський басейн", "Східна Азія", "Південна Азія", "Південно-Східна Азія", "Південна Європа", "Австралазія", "Меланезія", "Мікронезійський регіон", "Полінезія", "Азія", "Центральна Азія", "Західна Азія", "Європа", "Східна Європа", "Північна Європа", "Західна Європа", "Африка на південь від Сахари", "Латинська Америка", "Острів Вознесіння", "Андорра", "Обʼєднані Арабські Емірати", "Афганістан", "Антигуа і Барбуда", "Ангілья", "Албанія", "Вірменія", "Ангола", "Антарктика", "Аргентина", "Американське Самоа", "Австрія", "Австралія", "Аруба", "Аландські Острови", "Азербайджан", "Боснія і Герцеговина", "Барбадос", "Бангладеш", "Бельгія", "Буркіна-Фасо", "Болгарія", "Бахрейн", "Бурунді", "Бенін", "Сен-Бартелемі", "Бермудські Острови", "Бруней", "Болівія", "Карибські Нідерланди", "Бразилія", "Багамські Острови", "Бутан", "Острів Буве", "Ботсвана", "Білорусь", "Беліз", "Канада", "Кокосові (Кілінг) Острови", "Конго – Кіншаса", "Центральноафриканська Республіка", "Конго – Браззавіль", "Швейцарія", "Кот-дʼІвуар", "Острови Кука", "Чилі", "Камерун", "Китай", "Колумбія", "Острів Кліппертон", "Сарк", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Острів Різдва", "Кіпр", "Чехія", "Німеччина", "Дієго-Гарсія", "Джибуті", "Данія", "Домініка", "Домініканська Республіка", "Алжир", "Сеута і Мелілья", "Еквадор", "Естонія", "Єгипет", "Західна Сахара", "Еритрея", "Іспанія", "Ефіопія", "Європейський Союз", "Єврозона", "Фінляндія", "Фіджі", "Фолклендські Острови", "Мікронезія", "Фарерські Острови", "Франція", "Габон", "Велика Британія", "Гренада", "Грузія", "Французька Гвіана", "Гернсі", "Гана", "Гібралтар", "Гренландія", "Гамбія", "Гвінея", "Гваделупа", "Екваторіальна Гвінея", "Греція", "Південна Джорджія та Південні Сандвічеві Острови", "Гватемала", "Гуам", "Гвінея-Бісау", "Гаяна", "Гонконг, ОАР Китаю", "Острови Герд і Макдоналд", "Гондурас", "Хорватія", "Гаїті", "Угорщина", "Канарські Острови", "Індонезія", "Ірландія", "Ізраїль", "Острів Мен", "Індія", "Британська територія в Індійському океані", "Ірак", "Іран", "Ісландія", "Італія", "Джерсі", "Ямайка", "Йорданія", "Японія", "Кенія", "Киргизстан", "Камбоджа", "Кірибаті", "Комори", "Сент-Кітс і Невіс", "Північна Корея", "Південна Корея", "Кувейт", "Кайманові Острови", "Казахстан", "Лаос", "Ліван", "Сент-Люсія", "Ліхтенштейн", "Шрі-Ланка", "Ліберія", "Лесото", "Литва", "Люксембург", "Латвія", "Лівія", "Марокко", "Монако", "Молдова", "Чорногорія", "Сен-Мартен", "Мадагаскар", "Маршаллові Острови", "Північна Македонія", "Малі", "Мʼянма (Бірма)", "Монголія", "Макао, ОАР Китаю", "Північні Маріанські Острови", "Мартиніка", "Мавританія", "Монтсеррат", "Мальта", "Маврикій", "Мальдіви", "Малаві", "Мексика", "Малайзія", "Мозамбік", "Намібія", "Нова Каледонія", "Нігер", "Острів Норфолк", "Нігерія", "Нікарагуа", "Нідерланди", "Норвегія", "Непал", "Науру", "Ніуе", "Нова Зеландія", "Оман", "Панама", "Перу", "Французька Полінезія", "Папуа-Нова Гвінея", "Філіппіни", "Пакистан", "Польща", "Сен-Пʼєр і Мікелон", "Острови Піткерн", "Пуерто-Рико", "Палестинські території", "Португалія", "Палау", "Парагвай", "Катар", "Віддалена Океанія", "Реюньйон", "Румунія", "Сербія", "Росія", "Руанда", "Саудівська Аравія", "Соломонові Острови", "Сейшельські Острови", "Судан", "Швеція", "Сінгапур", "Острів Святої Єлени", "Словенія", "Шпіцберген та Ян-Маєн", "Словаччина", "Сьєрра-Леоне", "Сан-Марино", "Сенегал", "Сомалі", "Суринам", "Південний Судан", "Сан-Томе і Принсіпі", "Сальвадор", "Сінт-Мартен", "Сирія", "Есватіні", "Трістан-да-Кунья", "Острови Теркс і Кайкос", "Чад", "Французькі Південні Території", "Того", "Таїланд", "Таджикистан", "Токелау", "Тимор-Лешті", "Туркменістан", "Туніс", "Тонга", "Туреччина", "Тринідад і Тобаго", "Тувалу", "Тайвань", "Танзанія", "Україна", "Уганда", "Віддалені острови США", "Організація Об’єднаних Націй", "Сполучені Штати", "Уругвай", "Узбекистан", "Ватикан", "Сент-Вінсент і Гренадіни", "Венесуела", "Британські Віргінські острови", "Віргінські Острови (США)", "Вʼєтнам", "Вануату", "Уолліс і Футуна", "Самоа", "Псевдоакценти", "Псевдобіді", "Косово", "Ємен", "Майотта", "Південно-Африканська Республіка", "Замбія", "Зімбабве"];
+
+// ur
+static R110: [&str; 294] = ["دنیا", "افریقہ", "شمالی امریکہ", "جنوبی امریکہ", "اوشیانیا", "مغربی افریقہ", "وسطی امریکہ", "مشرقی افریقہ", "شمالی افریقہ", "وسطی افریقہ", "جنوبی افریقہ کا علاقہ", "امیریکاز", "شمالی امریکہ کا علاقہ", "کریبیائی", "مشرقی ایشیا", "جنوبی ایشیا", "جنوب مشرقی ایشیا", "جنوبی یورپ", "آسٹریلیشیا", "مالینیشیا", "مائکرونیشیائی علاقہ", "پولینیشیا", "ایشیا", "وسطی ایشیا", "مغربی ایشیا", "یورپ", "مشرقی یورپ", "شمالی یورپ", "مغربی یورپ", "ذیلی صحارن افریقہ", "لاطینی امریکہ", "اسینشن آئلینڈ", "انڈورا", "متحدہ عرب امارات", "افغانستان", "انٹیگوا اور باربودا", "انگوئیلا", "البانیہ", "آرمینیا", "انگولا", "انٹارکٹیکا", "ارجنٹینا", "امریکی ساموآ", "آسٹریا", "آسٹریلیا", "اروبا", "آلینڈ آئلینڈز", "آذربائیجان", "بوسنیا اور ہرزیگووینا", "بارباڈوس", "بنگلہ دیش", "بیلجیم", "برکینا فاسو", "بلغاریہ", "بحرین", "برونڈی", "بینن", "سینٹ برتھلیمی", "برمودا", "برونائی", "بولیویا", "کریبیائی نیدرلینڈز", "برازیل", "بہاماس", "بھوٹان", "بؤویٹ آئلینڈ", "بوتسوانا", "بیلاروس", "بیلائز", "کینیڈا", "کوکوس (کیلنگ) جزائر", "کانگو - کنشاسا", "وسط افریقی جمہوریہ", "کانگو - برازاویلے", "سوئٹزر لینڈ", "کوٹ ڈی آئیوری", "کک آئلینڈز", "چلی", "کیمرون", "چین", "کولمبیا", "کلپرٹن آئلینڈ", "سارک", "کوسٹا ریکا", "کیوبا", "کیپ ورڈی", "کیوراکاؤ", "جزیرہ کرسمس", "قبرص", "چیکیا", "جرمنی", "ڈائجو گارسیا", "جبوتی", "ڈنمارک", "ڈومنیکا", "جمہوریہ ڈومينيکن", "الجیریا", "سیئوٹا اور میلیلا", "ایکواڈور", "اسٹونیا", "مصر", "مغربی صحارا", "اریٹیریا", "ہسپانیہ", "ایتھوپیا", "یوروپی یونین", "یوروزون", "فن لینڈ", "فجی", "فاکلینڈ جزائر", "مائکرونیشیا", "جزائر فارو", "فرانس", "گیبون", "سلطنت متحدہ", "گریناڈا", "جارجیا", "فرینچ گیانا", "گوئرنسی", "گھانا", "جبل الطارق", "گرین لینڈ", "گیمبیا", "گنی", "گواڈیلوپ", "استوائی گیانا", "یونان", "جنوبی جارجیا اور جنوبی سینڈوچ جزائر", "گواٹے مالا", "گوام", "گنی بساؤ", "گیانا", "ہانگ کانگ SAR چین", "ہیرڈ جزیرہ و میکڈولینڈ جزائر", "ہونڈاروس", "کروشیا", "ہیٹی", "ہنگری", "کینری آئلینڈز", "انڈونیشیا", "آئرلینڈ", "اسرائیل", "آئل آف مین", "بھارت", "برطانوی بحر ہند کا علاقہ", "عراق", "ایران", "آئس لینڈ", "اٹلی", "جرسی", "جمائیکا", "اردن", "جاپان", "کینیا", "کرغزستان", "کمبوڈیا", "کریباتی", "کوموروس", "سینٹ کٹس اور نیویس", "شمالی کوریا", "جنوبی کوریا", "کویت", "کیمین آئلینڈز", "قزاخستان", "لاؤس", "لبنان", "سینٹ لوسیا", "لیشٹنسٹائن", "سری لنکا", "لائبیریا", "لیسوتھو", "لیتھونیا", "لکسمبرگ", "لٹویا", "لیبیا", "مراکش", "موناکو", "مالدووا", "مونٹے نیگرو", "سینٹ مارٹن", "مڈغاسکر", "مارشل آئلینڈز", "شمالی مقدونیہ", "مالی", "میانمار (برما)", "منگولیا", "مکاؤ SAR چین", "شمالی ماریانا آئلینڈز", "مارٹینک", "موریطانیہ", "مونٹسیراٹ", "مالٹا", "ماریشس", "مالدیپ", "ملاوی", "میکسیکو", "ملائشیا", "موزمبیق", "نامیبیا", "نیو کلیڈونیا", "نائجر", "نارفوک آئلینڈ", "نائجیریا", "نکاراگووا", "نیدر لینڈز", "ناروے", "نیپال", "نؤرو", "نیئو", "نیوزی لینڈ", "عمان", "پانامہ", "پیرو", "فرانسیسی پولینیشیا", "پاپوآ نیو گنی", "فلپائن", "پاکستان", "پولینڈ", "سینٹ پیئر اور میکلیئون", "پٹکائرن جزائر", "پیورٹو ریکو", "فلسطینی خطے", "پرتگال", "پلاؤ", "پیراگوئے", "قطر", "بیرونی اوشیانیا", "ری یونین", "رومانیہ", "سربیا", "روس", "روانڈا", "سعودی عرب", "سولومن آئلینڈز", "سشلیز", "سوڈان", "سویڈن", "سنگاپور", "سینٹ ہیلینا", "سلووینیا", "سوالبرڈ اور جان ماین", "سلوواکیہ", "سیرالیون", "سان مارینو", "سینیگل", "صومالیہ", "سورینام", "جنوبی سوڈان", "ساؤ ٹومے اور پرنسپے", "ال سلواڈور", "سنٹ مارٹن", "شام", "سواتنی", "ٹرسٹن ڈا کیونہا", "ٹرکس اور کیکوس جزائر", "چاڈ", "فرانسیسی جنوبی خطے", "ٹوگو", "تھائی لینڈ", "تاجکستان", "ٹوکیلاؤ", "تیمور لیسٹ", "ترکمانستان", "تونس", "ٹونگا", "ترکیہ", "ترینیداد اور ٹوباگو", "ٹووالو", "تائیوان", "تنزانیہ", "یوکرین", "یوگنڈا", "امریکہ سے باہر کے چھوٹے جزائز", "اقوام متحدہ", "ریاست ہائے متحدہ امریکہ", "یوروگوئے", "ازبکستان", "ویٹیکن سٹی", "سینٹ ونسنٹ اور گرینیڈائنز", "وینزوئیلا", "برٹش ورجن آئلینڈز", "امریکی ورجن آئلینڈز", "ویتنام", "وینوآٹو", "ویلیز اور فیوٹیونا", "ساموآ", "بناوٹی لہجے", "بناوٹی دو سمتی", "کوسووو", "یمن", "مایوٹ", "جنوبی افریقہ", "زامبیا", "زمبابوے"];
+
+// uz
+static R111: [&str; 294] = ["Dunyo", "Afrika", "Shimoliy Amerika", "Janubiy Amerika", "Okeaniya", "G‘arbiy Afrika", "Markaziy Amerika", "Sharqiy Afrika", "Shimoliy Afrika", "Markaziy Afrika", "Janubiy Afrika", "Amerika", "Shimoliy Amerika – AQSH va Kanada", "Karib havzasi", "Sharqiy Osiyo", "Janubiy Osiyo", "Janubi-sharqiy Osiyo", "Janubiy Yevropa", "Avstralaziya", "Melaneziya", "Mikroneziya mintaqasi", "Polineziya", "Osiyo", "Markaziy Osiyo", "G‘arbiy Osiyo", "Yevropa", "Sharqiy Yevropa", "Shimoliy Yevropa", "G‘arbiy Yevropa", "Sahro janubidagi Afrika", "Lotin Amerikasi", "Me’roj oroli", "Andorra", "Birlashgan Arab Amirliklari", "Afgʻoniston", "Antigua va Barbuda", "Angilya", "Albaniya", "Armaniston", "Angola", "Antarktida", "Argentina", "Amerika Samoasi", "Avstriya", "Avstraliya", "Aruba", "Aland orollari", "Ozarbayjon", "Bosniya va Gertsegovina", "Barbados", "Bangladesh", "Belgiya", "Burkina-Faso", "Bolgariya", "Bahrayn", "Burundi", "Benin", "Sen-Bartelemi", "Bermuda orollari", "Bruney", "Boliviya", "Boneyr, Sint-Estatius va Saba", "Braziliya", "Bagama orollari", "Butan", "Buve oroli", "Botsvana", "Belarus", "Beliz", "Kanada", "Kokos (Kiling) orollari", "Kongo – Kinshasa", "Markaziy Afrika Respublikasi", "Kongo – Brazzavil", "Shveytsariya", "Kot-d’Ivuar", "Kuk orollari", "Chili", "Kamerun", "Xitoy", "Kolumbiya", "Klipperton oroli", "Sark", "Kosta-Rika", "Kuba", "Kabo-Verde", "Kyurasao", "Rojdestvo oroli", "Kipr", "Chexiya", "Germaniya", "Diyego-Garsiya", "Jibuti", "Daniya", "Dominika", "Dominikan Respublikasi", "Jazoir", "Seuta va Melilya", "Ekvador", "Estoniya", "Misr", "G‘arbiy Sahroi Kabir", "Eritreya", "Ispaniya", "Efiopiya", "Yevropa Ittifoqi", "Yevrozona", "Finlandiya", "Fiji", "Folklend orollari", "Mikroneziya", "Farer orollari", "Fransiya", "Gabon", "Buyuk Britaniya", "Grenada", "Gruziya", "Fransuz Gvianasi", "Gernsi", "Gana", "Gibraltar", "Grenlandiya", "Gambiya", "Gvineya", "Gvadelupe", "Ekvatorial Gvineya", "Gretsiya", "Janubiy Georgiya va Janubiy Sendvich orollari", "Gvatemala", "Guam", "Gvineya-Bisau", "Gayana", "Gonkong (Xitoy MMH)", "Xerd va Makdonald orollari", "Gonduras", "Xorvatiya", "Gaiti", "Vengriya", "Kanar orollari", "Indoneziya", "Irlandiya", "Isroil", "Men oroli", "Hindiston", "Britaniyaning Hind okeanidagi hududi", "Iroq", "Eron", "Islandiya", "Italiya", "Jersi", "Yamayka", "Iordaniya", "Yaponiya", "Keniya", "Qirgʻiziston", "Kambodja", "Kiribati", "Komor orollari", "Sent-Kits va Nevis", "Shimoliy Koreya", "Janubiy Koreya", "Quvayt", "Kayman orollari", "Qozogʻiston", "Laos", "Livan", "Sent-Lyusiya", "Lixtenshteyn", "Shri-Lanka", "Liberiya", "Lesoto", "Litva", "Lyuksemburg", "Latviya", "Liviya", "Marokash", "Monako", "Moldova", "Chernogoriya", "Sent-Martin", "Madagaskar", "Marshall orollari", "Shimoliy Makedoniya", "Mali", "Myanma (Birma)", "Mongoliya", "Makao (Xitoy MMH)", "Shimoliy Mariana orollari", "Martinika", "Mavritaniya", "Montserrat", "Malta", "Mavrikiy", "Maldiv orollari", "Malavi", "Meksika", "Malayziya", "Mozambik", "Namibiya", "Yangi Kaledoniya", "Niger", "Norfolk oroli", "Nigeriya", "Nikaragua", "Niderlandiya", "Norvegiya", "Nepal", "Nauru", "Niue", "Yangi Zelandiya", "Ummon", "Panama", "Peru", "Fransuz Polineziyasi", "Papua – Yangi Gvineya", "Filippin", "Pokiston", "Polsha", "Sen-Pyer va Mikelon", "Pitkern orollari", "Puerto-Riko", "Falastin hududlari", "Portugaliya", "Palau", "Paragvay", "Qatar", "Tashqi Okeaniya", "Reyunion", "Ruminiya", "Serbiya", "Rossiya", "Ruanda", "Saudiya Arabistoni", "Solomon orollari", "Seyshel orollari", "Sudan", "Shvetsiya", "Singapur", "Muqaddas Yelena oroli", "Sloveniya", "Shpitsbergen va Yan-Mayen", "Slovakiya", "Syerra-Leone", "San-Marino", "Senegal", "Somali", "Surinam", "Janubiy Sudan", "San-Tome va Prinsipi", "Salvador", "Sint-Marten", "Suriya", "Svazilend", "Tristan-da-Kunya", "Turks va Kaykos orollari", "Chad", "Fransuz Janubiy hududlari", "Togo", "Tailand", "Tojikiston", "Tokelau", "Timor-Leste", "Turkmaniston", "Tunis", "Tonga", "Turkiya", "Trinidad va Tobago", "Tuvalu", "Tayvan", "Tanzaniya", "Ukraina", "Uganda", "AQSH yondosh orollari", "Birlashgan Millatlar Tashkiloti", "Amerika Qo‘shma Shtatlari", "Urugvay", "Oʻzbekiston", "Vatikan", "Sent-Vinsent va Grenadin", "Venesuela", "Britaniya Virgin orollari", "AQSH Virgin orollari", "Vyetnam", "Vanuatu", "Uollis va Futuna", "Samoa", "Qalbaki urg‘u", "Qalbaki Bidi", "Kosovo", "Yaman", "Mayotta", "Janubiy Afrika Respublikasi", "Zambiya", "Zimbabve"];
+
+// vi
+static R112: [&str; 294] = ["Thế giới", "Châu Phi", "Bắc Mỹ", "Nam Mỹ", "Châu Đại Dương", "Tây Phi", "Trung Mỹ", "Đông Phi", "Bắc Phi", "Trung Phi", "Miền Nam Châu Phi", "Châu Mỹ", "Miền Bắc Châu Mỹ", "Ca-ri-bê", "Đông Á", "Nam Á", "Đông Nam Á", "Nam Âu", "Australasia", "Melanesia", "Vùng Micronesia", "Polynesia", "Châu Á", "Trung Á", "Tây Á", "Châu Âu", "Đông Âu", "Bắc Âu", "Tây Âu", "Châu Phi hạ Sahara", "Châu Mỹ La-tinh", "Đảo Ascension", "Andorra", "Các Tiểu Vương quốc Ả Rập Thống nhất", "Afghanistan", "Antigua và Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Nam Cực", "Argentina", "Samoa thuộc Mỹ", "Áo", "Úc", "Aruba", "Quần đảo Åland", "Azerbaijan", "Bosnia và Herzegovina", "Barbados", "Bangladesh", "Bỉ", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "St. Barthélemy", "Bermuda", "Brunei", "Bolivia", "Ca-ri-bê Hà Lan", "Brazil", "Bahamas", "Bhutan", "Đảo Bouvet", "Botswana", "Belarus", "Belize", "Canada", "Quần đảo Cocos (Keeling)", "Congo - Kinshasa", "Cộng hòa Trung Phi", "Congo - Brazzaville", "Thụy Sĩ", "Côte d’Ivoire", "Quần đảo Cook", "Chile", "Cameroon", "Trung Quốc", "Colombia", "Đảo Clipperton", "Sark", "Costa Rica", "Cuba", "Cape Verde", "Curaçao", "Đảo Giáng Sinh", "Síp", "Séc", "Đức", "Diego Garcia", "Djibouti", "Đan Mạch", "Dominica", "Cộng hòa Dominica", "Algeria", "Ceuta và Melilla", "Ecuador", "Estonia", "Ai Cập", "Tây Sahara", "Eritrea", "Tây Ban Nha", "Ethiopia", "Liên Minh Châu Âu", "Khu vực đồng Euro", "Phần Lan", "Fiji", "Quần đảo Falkland", "Micronesia", "Quần đảo Faroe", "Pháp", "Gabon", "Vương quốc Anh", "Grenada", "Georgia", "Guiana thuộc Pháp", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea", "Guadeloupe", "Guinea Xích Đạo", "Hy Lạp", "Nam Georgia & Quần đảo Nam Sandwich", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Đặc khu Hành chính Hồng Kông, Trung Quốc", "Quần đảo Heard và McDonald", "Honduras", "Croatia", "Haiti", "Hungary", "Quần đảo Canary", "Indonesia", "Ireland", "Israel", "Đảo Man", "Ấn Độ", "Lãnh thổ Ấn Độ Dương thuộc Anh", "Iraq", "Iran", "Iceland", "Italy", "Jersey", "Jamaica", "Jordan", "Nhật Bản", "Kenya", "Kyrgyzstan", "Campuchia", "Kiribati", "Comoros", "St. Kitts và Nevis", "Triều Tiên", "Hàn Quốc", "Kuwait", "Quần đảo Cayman", "Kazakhstan", "Lào", "Li-băng", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litva", "Luxembourg", "Latvia", "Libya", "Ma-rốc", "Monaco", "Moldova", "Montenegro", "St. Martin", "Madagascar", "Quần đảo Marshall", "Bắc Macedonia", "Mali", "Myanmar (Miến Điện)", "Mông Cổ", "Đặc khu Hành chính Macao, Trung Quốc", "Quần đảo Bắc Mariana", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldives", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger", "Đảo Norfolk", "Nigeria", "Nicaragua", "Hà Lan", "Na Uy", "Nepal", "Nauru", "Niue", "New Zealand", "Oman", "Panama", "Peru", "Polynesia thuộc Pháp", "Papua New Guinea", "Philippines", "Pakistan", "Ba Lan", "Saint Pierre và Miquelon", "Quần đảo Pitcairn", "Puerto Rico", "Lãnh thổ Palestine", "Bồ Đào Nha", "Palau", "Paraguay", "Qatar", "Vùng xa xôi thuộc Châu Đại Dương", "Réunion", "Romania", "Serbia", "Nga", "Rwanda", "Ả Rập Xê-út", "Quần đảo Solomon", "Seychelles", "Sudan", "Thụy Điển", "Singapore", "St. Helena", "Slovenia", "Svalbard và Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Nam Sudan", "São Tomé và Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Quần đảo Turks và Caicos", "Chad", "Lãnh thổ phía Nam Thuộc Pháp", "Togo", "Thái Lan", "Tajikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Thổ Nhĩ Kỳ", "Trinidad và Tobago", "Tuvalu", "Đài Loan", "Tanzania", "Ukraina", "Uganda", "Các tiểu đảo xa của Hoa Kỳ", "Liên hợp quốc", "Hoa Kỳ", "Uruguay", "Uzbekistan", "Thành Vatican", "St. Vincent và Grenadines", "Venezuela", "Quần đảo Virgin thuộc Anh", "Quần đảo Virgin thuộc Hoa Kỳ", "Việt Nam", "Vanuatu", "Wallis và Futuna", "Samoa", "Pseudo-Accents", "Pseudo-Bidi", "Kosovo", "Yemen", "Mayotte", "Nam Phi", "Zambia", "Zimbabwe"];
+
+// cy
+static R113: [&str; 294] = ["Y Byd", "Affrica", "Gogledd America", "De America", "Oceania", "Gorllewin Affrica", "Canolbarth America", "Dwyrain Affrica", "Gogledd Affrica", "Canol Affrica", "Deheudir Affrica", "Yr Amerig", "America i’r Gogledd o Fecsico", "Y Caribî", "Dwyrain Asia", "De Asia", "De-Ddwyrain Asia", "De Ewrop", "Awstralasia", "Melanesia", "Rhanbarth Micronesia", "Polynesia", "Asia", "Canol Asia", "Gorllewin Asia", "Ewrop", "Dwyrain Ewrop", "Gogledd Ewrop", "Gorllewin Ewrop", "Affrica Is-Sahara", "America Ladin", "Ynys Ascension", "Andorra", "Emiradau Arabaidd Unedig", "Afghanistan", "Antigua a Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarctica", "Yr Ariannin", "Samoa America", "Awstria", "Awstralia", "Aruba", "Ynysoedd Åland", "Aserbaijan", "Bosnia a Herzegovina", "Barbados", "Bangladesh", "Gwlad Belg", "Burkina Faso", "Bwlgaria", "Bahrain", "Burundi", "Benin", "Saint Barthélemy", "Bermuda", "Brunei", "Bolifia", "Antilles yr Iseldiroedd", "Brasil", "Y Bahamas", "Bhutan", "Ynys Bouvet", "Botswana", "Belarws", "Belize", "Canada", "Ynysoedd Cocos (Keeling)", "Y Congo - Kinshasa", "Gweriniaeth Canolbarth Affrica", "Y Congo - Brazzaville", "Y Swistir", "Côte d’Ivoire", "Ynysoedd Cook", "Chile", "Camerŵn", "Tsieina", "Colombia", "Ynys Clipperton", "Sarc", "Costa Rica", "Ciwba", "Cabo Verde", "Curaçao", "Ynys y Nadolig", "Cyprus", "Tsiecia", "Yr Almaen", "Diego Garcia", "Djibouti", "Denmarc", "Dominica", "Gweriniaeth Dominica", "Algeria", "Ceuta a Melilla", "Ecuador", "Estonia", "Yr Aifft", "Gorllewin Sahara", "Eritrea", "Sbaen", "Ethiopia", "Yr Undeb Ewropeaidd", "Ardal yr Ewro", "Y Ffindir", "Fiji", "Ynysoedd y Falkland/Malvinas", "Micronesia", "Ynysoedd Ffaro", "Ffrainc", "Gabon", "Y Deyrnas Unedig", "Grenada", "Georgia", "Guyane Ffrengig", "Ynys y Garn", "Ghana", "Gibraltar", "Yr Ynys Las", "Gambia", "Gini", "Guadeloupe", "Gini Gyhydeddol", "Gwlad Groeg", "De Georgia ac Ynysoedd Sandwich y De", "Guatemala", "Guam", "Guiné-Bissau", "Guyana", "Hong Kong SAR Tsieina", "Ynys Heard ac Ynysoedd McDonald", "Honduras", "Croatia", "Haiti", "Hwngari", "Yr Ynysoedd Dedwydd", "Indonesia", "Iwerddon", "Israel", "Ynys Manaw", "India", "Tiriogaeth Brydeinig Cefnfor India", "Irac", "Iran", "Gwlad yr Iâ", "Yr Eidal", "Jersey", "Jamaica", "Gwlad Iorddonen", "Japan", "Kenya", "Kyrgyzstan", "Cambodia", "Kiribati", "Comoros", "Saint Kitts a Nevis", "Gogledd Corea", "De Corea", "Kuwait", "Ynysoedd Cayman", "Kazakhstan", "Laos", "Libanus", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lithwania", "Lwcsembwrg", "Latfia", "Libya", "Moroco", "Monaco", "Moldofa", "Montenegro", "Saint Martin", "Madagascar", "Ynysoedd Marshall", "Gogledd Macedonia", "Mali", "Myanmar (Burma)", "Mongolia", "Macau SAR Tsieina", "Ynysoedd Gogledd Mariana", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Y Maldives", "Malawi", "Mecsico", "Malaysia", "Mozambique", "Namibia", "Caledonia Newydd", "Niger", "Ynys Norfolk", "Nigeria", "Nicaragua", "Yr Iseldiroedd", "Norwy", "Nepal", "Nauru", "Niue", "Seland Newydd", "Oman", "Panama", "Periw", "Polynesia Ffrengig", "Papua Guinea Newydd", "Y Philipinau", "Pacistan", "Gwlad Pwyl", "Saint-Pierre-et-Miquelon", "Ynysoedd Pitcairn", "Puerto Rico", "Tiriogaethau Palesteinaidd", "Portiwgal", "Palau", "Paraguay", "Qatar", "Oceania Bellennig", "Réunion", "Rwmania", "Serbia", "Rwsia", "Rwanda", "Saudi Arabia", "Ynysoedd Solomon", "Seychelles", "Swdan", "Sweden", "Singapore", "Saint Helena", "Slofenia", "Svalbard a Jan Mayen", "Slofacia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "De Swdan", "São Tomé a Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini", "Tristan da Cunha", "Ynysoedd Turks a Caicos", "Tsiad", "Tiroedd Deheuol ac Antarctig Ffrainc", "Togo", "Gwlad Thai", "Tajicistan", "Tokelau", "Timor-Leste", "Tyrcmenistan", "Tiwnisia", "Tonga", "Twrci", "Trinidad a Tobago", "Tuvalu", "Taiwan", "Tanzania", "Wcráin", "Uganda", "Ynysoedd Pellennig UDA", "Y Cenhedloedd Unedig", "Yr Unol Daleithiau", "Uruguay", "Uzbekistan", "Y Fatican", "Saint Vincent a’r Grenadines", "Venezuela", "Ynysoedd Gwyryf Prydain", "Ynysoedd Gwyryf yr Unol Daleithiau", "Fietnam", "Vanuatu", "Wallis a Futuna", "Samoa", "Acenion Ffug", "Bidi Ffug", "Kosovo", "Yemen", "Mayotte", "De Affrica", "Zambia", "Zimbabwe"];
+
+// wo
+static R114: [&str; 294] = ["àddina", "Africa", "North America", "Amerique du Sud", "Oseani", "Sowwu Afrique", "Amerique Centrale", "Penku Afrique", "Afrique du Nord", "Moyen Afrique", "Afrique du Sud", "Amerika", "amerique du nord", "Caraïbe", "Asie penku", "Asie du Sud", "Asie Sud-est", "Sud Europe", "Ostralasi", "Melanesi", "Mikronesi", "Polineesi", "Asia", "Asie centrale", "Asie sowwu jàng", "Europe", "Europe bu penku", "Europe du nord", "Europe sowwu jàng", "Afrique sub-saharienne", "Amerique Latine", "Ile Ascension", "Andoor", "Emira Arab Ini", "Afganistaŋ", "Antiguwa ak Barbuda", "Angiiy", "Albani", "Armeni", "Àngolaa", "Antarktik", "Arsàntin", "Samowa bu Amerig", "Ótiriis", "Ostarali", "Aruba", "Duni Aalànd", "Aserbayjaŋ", "Bosni Ersegowin", "Barbad", "Bengalades", "Belsig", "Burkina Faaso", "Bilgari", "Bahreyin", "Burundi", "Benee", "Saŋ Bartalemi", "Bermid", "Burney", "Boliwi", "Pays-Bas bu Caraïbe", "Beresil", "Bahamas", "Butaŋ", "Dunu Buwet", "Botswana", "Belaris", "Belis", "Kanadaa", "Duni Koko (Kilin)", "Kongo (R K D)", "Repiblik Sàntar Afrik", "Réewum Kongo", "Siwis", "Kodiwaar", "Duni Kuuk", "Sili", "Kamerun", "Siin", "Kolombi", "Ile Clipperton", "Guernsey Pound", "Kosta Rika", "Kuba", "Kabo Werde", "Kursawo", "Dunu Kirismas", "Siipar", "Réewum Cek", "Almaañ", "Garsiya", "Jibuti", "Danmàrk", "Dominik", "Repiblik Dominiken", "Alseri", "Ceuta & Melilla", "Ekwaatër", "Estoni", "Esipt", "Sahara bu sowwu", "Eritere", "Españ", "Ecopi", "EZ", "Eurozone", "Finlànd", "Fijji", "Duni Falkland", "Mikoronesi", "Duni Faro", "Faraans", "Gaboŋ", "Ruwaayom Ini", "Garanad", "Seworsi", "Guyaan Farañse", "Gernase", "Gana", "Sibraltaar", "Girinlànd", "Gàmbi", "Gine", "Guwaadelup", "Gine Ekuwatoriyal", "Gerees", "Seworsi di Sid ak Duni Sàndwiis di Sid", "Guwatemala", "Guwam", "Gine-Bisaawóo", "Giyaan", "Ooŋ Koŋ", "Duni Hërd ak Duni MakDonald", "Onduraas", "Korowasi", "Ayti", "Ongari", "Ile Canary", "Indonesi", "Irlànd", "Israyel", "Dunu Maan", "End", "Terituwaaru Brëtaañ ci Oseyaa Enjeŋ", "Irag", "Iraŋ", "Islànd", "Itali", "Serse", "Samayig", "Sordani", "Sàppoŋ", "Keeña", "Kirgistaŋ", "Kàmboj", "Kiribati", "Komoor", "Saŋ Kits ak Newis", "Kore Noor", "Corée du Sud", "Kowet", "Duni Kaymaŋ", "Kasaxstaŋ", "Lawos", "Libaa", "Saŋ Lusi", "Liktensteyin", "Siri Lànka", "Liberiya", "Lesoto", "Litiyani", "Liksàmbur", "Letoni", "Libi", "Marog", "Monako", "Moldawi", "Montenegoro", "Saŋ Marteŋ", "Madagaskaar", "Duni Marsaal", "Maseduwaan bëj Gànnaar", "Mali", "Miyanmaar", "Mongoli", "Makaawo", "Duni Mariyaan Noor", "Martinik", "Mooritani", "Mooseraa", "Malt", "Moriis", "Maldiiw", "Malawi", "Meksiko", "Malesi", "Mosàmbig", "Namibi", "Nuwel Kaledoni", "Niiseer", "Dunu Norfolk", "Niseriya", "Nikaraguwa", "Peyi Baa", "Norwees", "Nepaal", "Nawru", "Niw", "Nuwel Selànd", "Omaan", "Panama", "Peru", "Polinesi Farañse", "Papuwasi Gine Gu Bees", "Filipin", "Pakistaŋ", "Poloñ", "Saŋ Peer ak Mikeloŋ", "Duni Pitkayirn", "Porto Riko", "réew yu Palestine", "Portigaal", "Palaw", "Paraguwe", "Kataar", "Oceanie", "Reeñoo", "Rumani", "Serbi", "Risi", "Ruwànda", "Arabi Sawudi", "Duni Salmoon", "Seysel", "Sudaŋ", "Suwed", "Singapuur", "Saŋ Eleen", "Esloweni", "Swalbaar ak Jan Mayen", "Eslowaki", "Siyera Lewon", "San Marino", "Senegaal", "Somali", "Sirinam", "Sudaŋ di Sid", "Sawo Tome ak Pirinsipe", "El Salwadoor", "Sin Marten", "Siri", "Suwasilànd", "Tristan da Cunha", "Duni Tirk ak Kaykos", "Càdd", "Teer Ostraal gu Fraas", "Togo", "Taylànd", "Tajikistaŋ", "Tokoloo", "Timor Leste", "Tirkmenistaŋ", "Tinisi", "Tonga", "Tirki", "Tirinite ak Tobago", "Tuwalo", "Taywan", "Taŋsani", "Ikeren", "Ugànda", "Duni Amerig Utar meer", "United Nations", "Etaa Sini", "Uruge", "Usbekistaŋ", "Site bu Watikaa", "Saŋ Weesaa ak Garanadin", "Wenesiyela", "Duni Wirsin yu Brëtaañ", "Duni Wirsin yu Etaa-sini", "Wiyetnam", "Wanuatu", "Walis ak Futuna", "Samowa", "Pseudo-aksan", "Pseudo-bidi", "Kosowo", "Yaman", "Mayot", "Afrik di Sid", "Sàmbi", "Simbabwe"];
+
+// fy
+static R115: [&str; 294] = ["Wrâld", "Afrika", "Noard-Amerika", "Sûd-Amerika", "Oceanië", "West-Afrika", "Midden-Amerika", "East-Afrika", "Noard-Afrika", "Sintraal-Afrika", "Sûdelijk Afrika", "Amerika", "Noardlik Amerika", "Karibysk gebiet", "East-Azië", "Sûd-Azië", "Sûdoost-Azië", "Sûd-Europa", "Australazië", "Melanesië", "Micronesyske regio", "Polynesië", "Azië", "Sintraal-Azië", "West-Azië", "Europa", "East-Europa", "Noard-Europa", "West-Europa", "", "Latynsk-Amearika", "Ascension", "Andorra", "Verenigde Arabyske Emiraten", "Afghanistan", "Antigua en Barbuda", "Anguilla", "Albanië", "Armenië", "Angola", "Antarctica", "Argentinië", "Amerikaansk Samoa", "Eastenryk", "Australië", "Aruba", "Ålân", "Azerbeidzjan", "Bosnië en Herzegovina", "Barbados", "Bangladesh", "België", "Burkina Faso", "Bulgarije", "Bahrein", "Burundi", "Benin", "Saint Barthélemy", "Bermuda", "Brunei", "Bolivia", "Karibysk Nederlân", "Brazilië", "Bahama’s", "Bhutan", "Bouveteilân", "Botswana", "Wit-Ruslân", "Belize", "Canada", "Kokosilanen", "Congo-Kinshasa", "Sintraal-Afrikaanske Republyk", "Congo-Brazzaville", "Switserlân", "Ivoorkust", "Cookeilannen", "Chili", "Kameroen", "Sina", "Kolombia", "Clipperton", "", "Costa Rica", "Kuba", "Kaapverdië", "Curaçao", "Krysteilan", "Syprus", "Tsjechje", "Dútslân", "Diego Garcia", "Djibouti", "Denemarken", "Dominika", "Dominikaanske Republyk", "Algerije", "Ceuta en Melilla", "Ecuador", "Estlân", "Egypte", "Westelijke Sahara", "Eritrea", "Spanje", "Ethiopië", "Europeeske Unie", "", "Finlân", "Fiji", "Falklâneilannen", "Micronesië", "Faeröer", "Frankrijk", "Gabon", "Verenigd Koninkrijk", "Grenada", "Georgië", "Frans-Guyana", "Guernsey", "Ghana", "Gibraltar", "Grienlân", "Gambia", "Guinee", "Guadeloupe", "Equatoriaal-Guinea", "Grikelân", "Sûd-Georgia en Sûdlike Sandwicheilannen", "Guatemala", "Guam", "Guinee-Bissau", "Guyana", "Hongkong SAR van Sina", "Heard- en McDonaldeilannen", "Honduras", "Kroatië", "Haïti", "Hongarije", "Kanaryske Eilânnen", "Yndonesië", "Ierlân", "Israël", "Isle of Man", "India", "", "Irak", "Iran", "Yslân", "Italië", "Jersey", "Jamaica", "Jordanië", "Japan", "Kenia", "Kirgizië", "Cambodja", "Kiribati", "Comoren", "Saint Kitts en Nevis", "Noard-Korea", "Sûd-Korea", "Koeweit", "Caymaneilannen", "Kazachstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litouwen", "Luxemburg", "Letlân", "Libië", "Marokko", "Monaco", "Moldavië", "Montenegro", "Saint-Martin", "Madeiaskar", "Marshalleilannen", "", "Mali", "Myanmar (Birma)", "Mongolië", "Macao SAR van Sina", "Noardlike Marianeneilannen", "Martinique", "Mauritanië", "Montserrat", "Malta", "Mauritius", "Maldiven", "Malawi", "Mexico", "Maleisië", "Mozambique", "Namibië", "Nij-Caledonië", "Niger", "Norfolkeilân", "Nigeria", "Nicaragua", "Nederlân", "Noarwegen", "Nepal", "Nauru", "Niue", "Nij-Seelân", "Oman", "Panama", "Peru", "Frans-Polynesië", "Papoea-Nij-Guinea", "Filipijnen", "Pakistan", "Polen", "Saint-Pierre en Miquelon", "Pitcairneilannen", "Puerto Rico", "Palestynske gebieten", "Portugal", "Palau", "Paraguay", "Qatar", "Oerig Oceanië", "Réunion", "Roemenië", "Servië", "Ruslân", "Rwanda", "Saoedi-Arabië", "Salomonseilannen", "Seychellen", "Soedan", "Zweden", "Singapore", "Sint-Helena", "Slovenië", "Spitsbergen en Jan Mayen", "Slowakije", "Sierra Leone", "San Marino", "Senegal", "Somalië", "Suriname", "Sûd-Soedan", "Sao Tomé en Principe", "El Salvador", "Sint-Maarten", "Syrië", "Swazilân", "Tristan da Cunha", "Turks- en Caicoseilannen", "Tsjaad", "Franse Gebieden in de zuidelijke Indyske Oseaan", "Togo", "Thailân", "Tadzjikistan", "Tokelau", "East-Timor", "Turkmenistan", "Tunesië", "Tonga", "Turkije", "Trinidad en Tobago", "Tuvalu", "Taiwan", "Tanzania", "Oekraïne", "Oeganda", "Lyts ôflizzen eilannen fan de Ferienigde Staten", "", "Ferienigde Staten", "Uruguay", "Oezbekistan", "Vaticaanstêd", "Saint Vincent en de Grenadines", "Venezuela", "Britse Maagdeneilannen", "Amerikaanske Maagdeneilannen", "Vietnam", "Vanuatu", "Wallis en Futuna", "Samoa", "", "", "Kosovo", "Jemen", "Mayotte", "Sûd-Afrika", "Zambia", "Zimbabwe"];
+
+// xh
+static R116: [&str; 294] = ["ihlabathi", "IAfrika", "UMntla Melika", "UMzantsi Melika", "E-Oceania", "INtshona Afrika", "UMbindi Melika", "IMpuma Afrika", "UMntla Afrika", "UMbindi Afrika", "EMazantsi e-Afrika", "EMzantsi Melika", "EMantla Melika", "ECaribbean", "IMpuma Asia", "UMzantsi Asia", "UMzantsi-mpuma Asia", "UMzantsi Yurophu", "I-Australasia", "IMelanasia", "UMmandla weMicronesia", "I-Polynesia", "E-Asia", "UMbindi Asia", "INtshona Asia", "EYurophu", "IMpuma Yurophu", "UMntla Yurophu", "INtshona Yurophu", "UMzantsi weSahara", "ILatin America", "E-Ascension Island", "E-Andorra", "E-United Arab Emirates", "E-Afghanistan", "E-Antigua & Barbuda", "E-Anguilla", "E-Albania", "E-Armenia", "E-Angola", "E-Antarctica", "E-Argentina", "E-American Samoa", "E-Austria", "E-Australia", "E-Aruba", "E-Åland Islands", "E-Azerbaijan", "EBosnia & Herzegovina", "EBarbados", "EBangladesh", "EBelgium", "EBurkina Faso", "EBulgaria", "EBahrain", "EBurundi", "EBenin", "ESt. Barthélemy", "EBermuda", "eBrunei", "EBolivia", "ECaribbean Netherlands", "EBrazil", "EBahamas", "EBhutan", "EBouvet Island", "EBotswana", "EBelarus", "EBelize", "EKhanada", "ECocos (Keeling) Islands", "ECongo -Kinshasa", "ECentral African Republic", "ECongo - Brazzaville", "ESwitzerland", "ECôte d’Ivoire", "ECook Islands", "EChile", "ECameroon", "ETshayina", "EColombia", "EClipperton Island", "ESark", "ECosta Rica", "ECuba", "ECape Verde", "ECuraçao", "EChristmas Island", "ECyprus", "ECzechia", "EJamani", "EDiego Garcia", "EDjibouti", "EDenmark", "EDominica", "EDominican Republic", "E-Algeria", "ECeuta & Melilla", "EEcuador", "E-Estonia", "IYiputa", "EWestern Sahara", "E-Eritrea", "ESpain", "E-Ethiopia", "I-European Union", "I-Eurozone", "EFinland", "EFiji", "EFalkland Islands", "EMicronesia", "EFaroe Islands", "EFrance", "EGabon", "E-United Kingdom", "EGrenada", "EGeorgia", "EFrench Guiana", "EGuernsey", "EGhana", "EGibraltar", "EGreenland", "EGambia", "EGuinea", "EGuadeloupe", "E-Equatorial Guinea", "EGreece", "ESouth Georgia & South Sandwich Islands", "EGuatemala", "EGuam", "EGuinea-Bissau", "EGuyana", "EHong Kong SAR China", "EHeard & McDonald Islands", "EHonduras", "ECroatia", "EHaiti", "EHungary", "ECanary Islands", "E-Indonesia", "E-Ireland", "E-Israel", "E-Isle of Man", "E-Indiya", "EBritish Indian Ocean Territory", "E-Iraq", "E-Iran", "E-Iceland", "E-Italy", "EJersey", "EJamaica", "EJordan", "EJapan", "EKenya", "EKyrgyzstan", "ECambodia", "EKiribati", "EComoros", "ESt. Kitts & Nevis", "EMntla Korea", "EMzantsi Korea", "EKuwait", "ECayman Islands", "EKazakhstan", "ELaos", "ELebanon", "ESt. Lucia", "ELiechtenstein", "ESri Lanka", "ELiberia", "ELesotho", "ELithuania", "ELuxembourg", "ELatvia", "ELibya", "EMorocco", "EMonaco", "EMoldova", "EMontenegro", "ESt. Martin", "EMadagascar", "EMarshall Islands", "EMntla Macedonia", "EMali", "EMyanmar (Burma)", "EMongolia", "EMacao SAR China", "ENorthern Mariana Islands", "EMartinique", "EMauritania", "EMontserrat", "EMalta", "EMauritius", "EMaldives", "EMalawi", "EMexico", "EMalaysia", "EMozambique", "ENamibia", "ENew Caledonia", "ENiger", "ENorfolk Island", "ENigeria", "ENicaragua", "ENetherlands", "ENorway", "ENepal", "ENauru", "ENiue", "ENew Zealand", "E-Oman", "EPanama", "EPeru", "EFrench Polynesia", "EPapua New Guinea", "EPhilippines", "EPakistan", "EPoland", "ESt. Pierre & Miquelon", "EPitcairn Islands", "EPuerto Rico", "IPalestinian Territories", "EPortugal", "EPalau", "EParaguay", "EQatar", "I-Oceania Esemaphandleni", "ERéunion", "ERomania", "ESerbia", "ERashiya", "ERwanda", "ESaudi Arabia", "ESolomon Islands", "ESeychelles", "ESudan", "ESweden", "ESingapore", "ESt. Helena", "ESlovenia", "ESvalbard & Jan Mayen", "ESlovakia", "ESierra Leone", "ESan Marino", "ESenegal", "ESomalia", "ESuriname", "ESouth Sudan", "ESão Tomé & Príncipe", "E-El Salvador", "ESint Maarten", "ESiriya", "ESwatini", "ETristan da Cunha", "ETurks & Caicos Islands", "EChad", "EFrench Southern Territories", "ETogo", "EThailand", "ETajikistan", "ETokelau", "ETimor-Leste", "ETurkmenistan", "ETunisia", "ETonga", "ETurkey", "ETrinidad & Tobago", "ETuvalu", "ETaiwan", "ETanzania", "E-Ukraine", "E-Uganda", "I-U.S. Outlying Islands", "Izizwe Ezimanyeneyo", "EMelika", "E-Uruguay", "E-Uzbekistan", "EVatican City", "ESt. Vincent & Grenadines", "EVenezuela", "EBritish Virgin Islands", "E-U.S. Virgin Islands", "EVietnam", "EVanuatu", "EWallis & Futuna", "ESamoa", "I-Pseudo-Accents", "I-Pseudo-Bidi", "EKosovo", "EYemen", "EMayotte", "EMzantsi Afrika", "EZambia", "EZimbabwe"];
+
+// yo
+static R117: [&str; 294] = ["Agbáyé", "Áfíríkà", "Àríwá Amẹ́ríkà", "Gúúṣù Amẹ́ríkà", "Òsọ́ọ́níà", "Ìwọ̀ oorùn Afíríkà", "Ààrin Gbùgbùn Àmẹ́ríkà", "Ìlà Oorùn Áfíríkà", "Àríwá Afíríkà", "Ààrín gbùngbùn Afíríkà", "Apágúúsù Áfíríkà", "Amẹ́ríkà", "Apáàríwá Amẹ́ríkà", "Káríbíànù", "Ìlà Òòrùn Eṣíà", "Gúúṣù Eṣíà", "Gúúṣù ìlà òòrùn Éṣíà", "Gúúṣù Yúróòpù", "Ọṣirélaṣíà", "Mẹlanéṣíà", "Agbègbè Maikironéṣíà", "Polineṣíà", "Áṣíà", "Ààrin Gbùngbùn Éṣíà", "Ìwọ̀ Òòrùn Eṣíà", "Yúróòpù", "Ìlà Òrùn Yúrópù", "Àríwá Yúróòpù", "Ìwọ̀ Òòrùn Yúrópù", "Apá Sàhárà Áfíríkà", "Látín Amẹ́ríkà", "Erékùsù Ascension", "Ààndórà", "Ẹmirate ti Awọn Arabu", "Àfùgànístánì", "Ààntígúà àti Báríbúdà", "Ààngúlílà", "Àlùbàníánì", "Améníà", "Ààngólà", "Antakítíkà", "Agentínà", "Sámóánì ti Orílẹ́ède Àméríkà", "Asítíríà", "Austrálíà", "Árúbà", "Àwọn Erékùsù ti Aland", "Asẹ́bájánì", "Bọ̀síníà àti Ẹtisẹgófínà", "Bábádósì", "Bángáládésì", "Bégíọ́mù", "Bùùkíná Fasò", "Bùùgáríà", "Báránì", "Bùùrúndì", "Bẹ̀nẹ̀", "Ìlú Bátílẹ́mì", "Bémúdà", "Búrúnẹ́lì", "Bọ̀lífíyà", "Kàríbíánì ti Nẹ́dálándì", "Bàràsílì", "Bàhámásì", "Bútánì", "Erékùsù Bouvet", "Bọ̀tìsúwánà", "Bélárúsì", "Bèlísẹ̀", "Kánádà", "Erékùsù Cocos (Keeling)", "Kóńgò – Kinshasa", "Àrin gùngun Áfíríkà", "Kóńgò – Brazaville", "Súwísìlanìdì", "Kóútè forà", "Etíokun Kùúkù", "Ṣílè", "Kamerúúnì", "Ṣáínà", "Kòlómíbìa", "Erékùsù Clipperston", "Sáàkì", "Kuusita Ríkà", "Kúbà", "Etíokun Kápé féndè", "Curaçao", "Erékùsù Christmas", "Kúrúsì", "Ṣẹ́ẹ́kì", "Jámánì", "Diego Gaṣia", "Díbọ́ótì", "Dẹ́mákì", "Dòmíníkà", "Dòmíníkánì", "Àlùgèríánì", "Seuta àti Melilla", "Ekuádò", "Esitonia", "Égípítì", "Ìwọ̀òòrùn Sàhárà", "Eritira", "Sípéìnì", "Etopia", "Àpapọ̀ Yúróòpù", "Agbègbè Yúrò", "Filandi", "Fíjì", "Etikun Fakalandi", "Makoronesia", "Àwọn Erékùsù ti Faroe", "Faranse", "Gabon", "Gẹ̀ẹ́sì", "Genada", "Gọgia", "Firenṣi Guana", "Guernsey", "Gana", "Gibaratara", "Gerelandi", "Gambia", "Gene", "Gadelope", "Ekutoria Gini", "Gíríìsì", "Gúúsù Georgia àti Gúúsù Àwọn Erékùsù Sandwich", "Guatemálà", "Guamu", "Gene-Busau", "Guyana", "Agbègbè Ìṣàkóso Ìṣúná Hong Kong Tí Ṣánà Ń Darí", "Erékùsù Heard àti Erékùsù McDonald", "Hondurasi", "Kòróátíà", "Haati", "Hungari", "Ẹrékùsù Kánárì", "Indonéṣíà", "Ailandi", "Iserẹli", "Erékùṣù ilẹ̀ Man", "Íńdíà", "Etíkun Índíánì ti Ìlú Bírítísì", "Iraki", "Irani", "Aṣilandi", "Itáli", "Jẹsì", "Jamaika", "Jọdani", "Japani", "Kenya", "Kuriṣisitani", "Kàmùbódíà", "Kiribati", "Kòmòrósì", "Kiiti ati Neefi", "Guusu Kọria", "Ariwa Kọria", "Kuweti", "Etíokun Kámánì", "Kaṣaṣatani", "Laosi", "Lebanoni", "Luṣia", "Lẹṣitẹnisiteni", "Siri Lanka", "Laberia", "Lesoto", "Lituania", "Lusemogi", "Latifia", "Libiya", "Moroko", "Monako", "Modofia", "Montenégrò", "Ìlú Màtìnì", "Madasika", "Etikun Máṣali", "Àríwá Macedonia", "Mali", "Manamari", "Mogolia", "Agbègbè Ìṣàkóso Pàtàkì Macao", "Etikun Guusu Mariana", "Matinikuwi", "Maritania", "Motserati", "Malata", "Maritiusi", "Maladifi", "Malawi", "Mesiko", "Malasia", "Moṣamibiku", "Namibia", "Kaledonia Titun", "Nàìjá", "Erékùsù Nọ́úfókì", "Nàìjíríà", "Nikaragua", "Nedalandi", "Nọọwii", "Nepa", "Nauru", "Niue", "Ṣilandi Titun", "Ọọma", "Paramá", "Pèérù", "Firenṣi Polinesia", "Paapu ti Giini", "Filipini", "Pakisitan", "Polandi", "Pẹẹri ati mikuloni", "Pikarini", "Pọto Riko", "Agbègbè ara Palẹsítínì", "Pọ́túgà", "Paalu", "Paraguye", "Kota", "Agbègbè Òṣọ́ọ́níà", "Riuniyan", "Romaniya", "Sẹ́bíà", "Rọṣia", "Ruwanda", "Saudi Arabia", "Etikun Solomoni", "Ṣeṣẹlẹsi", "Sudani", "Swidini", "Singapo", "Hẹlena", "Silofania", "Sífábáàdì àti Jánì Máyẹ̀nì", "Silofakia", "Siria looni", "Sani Marino", "Sẹnẹga", "Sòmálíà", "Surinami", "Gúúsù Sudan", "Sao tomi ati piriiṣipi", "Ẹẹsáfádò", "Síntì Mátẹ́ẹ̀nì", "Siria", "Saṣiland", "Tristan da Kunha", "Tọọki ati Etikun Kakọsi", "Ṣààdì", "Agbègbè Gúúsù Faranṣé", "Togo", "Tailandi", "Takisitani", "Tokelau", "Tímọ̀ Lẹsiti", "Tọ́kìmẹ́nísítànì", "Tuniṣia", "Tonga", "Tọọki", "Tirinida ati Tobaga", "Tufalu", "Taiwani", "Tàǹsáníà", "Ukarini", "Uganda", "Àwọn Erékùsù Kékèké Agbègbè US", "Ìṣọ̀kan àgbáyé", "Amẹrikà", "Úrúgúwè", "Nṣibẹkisitani", "Ìlú Vatican", "Fisẹnnti ati Genadina", "Fẹnẹṣuẹla", "Etíkun Fágínì ti ìlú Bírítísì", "Etikun Fagini ti Amẹrika", "Fẹtinami", "Faniatu", "Wali ati futuna", "Samọ", "ìsọ̀rọ̀sí irọ́", "Agbègbè irọ́", "Kòsófò", "Yemeni", "Mayote", "Gúúṣù Áfíríkà", "Ṣamibia", "Ṣimibabe"];
+
+// za
+static R118: [&str; 294] = ["", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "Cunghgoz", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", ""];
+
+// zu
+static R119: [&str; 294] = ["umhlaba", "i-Africa", "i-North America", "i-South America", "i-Oceania", "i-Western Africa", "i-Central America", "i-Eastern Africa", "i-Northern Africa", "i-Middle Africa", "i-Southern Africa", "i-Americas", "i-Northern America", "i-Caribbean", "i-Eastern Asia", "i-Southern Asia", "i-South-Eastern Asia", "i-Southern Europe", "i-Australasia", "i-Melanesia", "i-Micronesian Region", "i-Polynesia", "i-Asia", "i-Central Asia", "i-Western Asia", "i-Europe", "i-Eastern Europe", "i-Northern Europe", "i-Western Europe", "i-Sub-Saharan Africa", "i-Latin America", "i-Ascension Island", "i-Andorra", "i-United Arab Emirates", "i-Afghanistan", "i-Antigua ne-Barbuda", "i-Anguilla", "i-Albania", "i-Armenia", "i-Angola", "i-Antarctica", "i-Argentina", "i-American Samoa", "i-Austria", "i-Australia", "i-Aruba", "i-Åland Islands", "i-Azerbaijan", "i-Bosnia ne-Herzegovina", "i-Barbados", "i-Bangladesh", "i-Belgium", "i-Burkina Faso", "i-Bulgaria", "i-Bahrain", "i-Burundi", "i-Benin", "i-Saint Barthélemy", "i-Bermuda", "i-Brunei", "i-Bolivia", "i-Caribbean Netherlands", "i-Brazil", "i-Bahamas", "i-Bhutan", "i-Bouvet Island", "iBotswana", "i-Belarus", "i-Belize", "i-Canada", "i-Cocos (Keeling) Islands", "i-Congo - Kinshasa", "i-Central African Republic", "i-Congo - Brazzaville", "i-Switzerland", "i-Côte d’Ivoire", "i-Cook Islands", "i-Chile", "i-Cameroon", "i-China", "i-Colombia", "i-Clipperton Island", "i-Sark", "i-Costa Rica", "i-Cuba", "i-Cape Verde", "i-Curaçao", "i-Christmas Island", "i-Cyprus", "i-Czechia", "i-Germany", "i-Diego Garcia", "i-Djibouti", "i-Denmark", "i-Dominica", "i-Dominican Republic", "i-Algeria", "i-Cueta ne-Melilla", "i-Ecuador", "i-Estonia", "i-Egypt", "i-Western Sahara", "i-Eritrea", "i-Spain", "i-Ethiopia", "i-European Union", "I-Eurozone", "i-Finland", "i-Fiji", "i-Falkland Islands", "i-Micronesia", "i-Faroe Islands", "i-France", "i-Gabon", "i-United Kingdom", "i-Grenada", "i-Georgia", "i-French Guiana", "i-Guernsey", "i-Ghana", "i-Gibraltar", "i-Greenland", "i-Gambia", "i-Guinea", "i-Guadeloupe", "i-Equatorial Guinea", "i-Greece", "i-South Georgia ne-South Sandwich Islands", "i-Guatemala", "i-Guam", "i-Guinea-Bissau", "i-Guyana", "i-Hong Kong SAR China", "I-Heard & McDonald Island", "i-Honduras", "i-Croatia", "i-Haiti", "i-Hungary", "i-Canary Islands", "i-Indonesia", "i-Ireland", "kwa-Israel", "i-Isle of Man", "i-India", "i-British Indian Ocean Territory", "i-Iraq", "i-Iran", "i-Iceland", "i-Italy", "i-Jersey", "i-Jamaica", "i-Jordan", "i-Japan", "i-Kenya", "i-Kyrgyzstan", "i-Cambodia", "i-Kiribati", "i-Comoros", "i-Saint Kitts ne-Nevis", "i-North Korea", "i-South Korea", "i-Kuwait", "i-Cayman Islands", "i-Kazakhstan", "i-Laos", "i-Lebanon", "i-Saint Lucia", "i-Liechtenstein", "i-Sri Lanka", "i-Liberia", "iLesotho", "i-Lithuania", "i-Luxembourg", "i-Latvia", "i-Libya", "i-Morocco", "i-Monaco", "i-Moldova", "i-Montenegro", "i-Saint Martin", "i-Madagascar", "i-Marshall Islands", "i-North Macedonia", "iMali", "i-Myanmar (Burma)", "i-Mongolia", "i-Macau SAR China", "i-Northern Mariana Islands", "i-Martinique", "i-Mauritania", "i-Montserrat", "i-Malta", "i-Mauritius", "i-Maldives", "iMalawi", "i-Mexico", "i-Malaysia", "i-Mozambique", "i-Namibia", "i-New Caledonia", "i-Niger", "i-Norfolk Island", "i-Nigeria", "i-Nicaragua", "i-Netherlands", "i-Norway", "i-Nepal", "i-Nauru", "i-Niue", "i-New Zealand", "i-Oman", "i-Panama", "i-Peru", "i-French Polynesia", "i-Papua New Guinea", "i-Philippines", "i-Pakistan", "i-Poland", "i-Saint Pierre kanye ne-Miquelon", "i-Pitcairn Islands", "i-Puerto Rico", "i-Palestinian Territories", "i-Portugal", "i-Palau", "i-Paraguay", "i-Qatar", "i-Outlying Oceania", "i-Réunion", "i-Romania", "i-Serbia", "i-Russia", "i-Rwanda", "i-Saudi Arabia", "i-Solomon Islands", "i-Seychelles", "i-Sudan", "i-Sweden", "i-Singapore", "i-St. Helena", "i-Slovenia", "i-Svalbard ne-Jan Mayen", "i-Slovakia", "i-Sierra Leone", "i-San Marino", "i-Senegal", "i-Somalia", "i-Suriname", "i-South Sudan", "i-São Tomé kanye ne-Príncipe", "i-El Salvador", "i-Sint Maarten", "i-Syria", "i-Swaziland", "i-Tristan da Cunha", "i-Turks ne-Caicos Islands", "i-Chad", "i-French Southern Territories", "i-Togo", "i-Thailand", "i-Tajikistan", "i-Tokelau", "i-Timor-Leste", "i-Turkmenistan", "i-Tunisia", "i-Tonga", "i-Turkey", "i-Trinidad ne-Tobago", "i-Tuvalu", "i-Taiwan", "i-Tanzania", "i-Ukraine", "i-Uganda", "I-U.S. Outlying Islands", "I-United Nations", "i-United States", "i-Uruguay", "i-Uzbekistan", "i-Vatican City", "i-Saint Vincent ne-Grenadines", "i-Venezuela", "i-British Virgin Islands", "i-U.S. Virgin Islands", "i-Vietnam", "i-Vanuatu", "i-Wallis ne-Futuna", "i-Samoa", "i-Pseudo-Accents", "i-Pseudo-Bidi", "i-Kosovo", "i-Yemen", "i-Mayotte", "iNingizimu Afrika", "i-Zambia", "iZimbabwe"];
+
+/// The region names of every language, indexed by the `LangCode` discriminant.
+pub(super) static REGIONS: [Option<&[&str; 294]>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&R0),
+    /* ak */ Some(&R1),
+    /* sq */ Some(&R2),
+    /* am */ Some(&R3),
+    /* ar */ Some(&R4),
+    /* an */ None,
+    /* hy */ Some(&R5),
+    /* as */ Some(&R6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&R7),
+    /* bm */ None,
+    /* ba */ Some(&R8),
+    /* eu */ Some(&R9),
+    /* be */ Some(&R10),
+    /* bn */ Some(&R11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&R12),
+    /* br */ Some(&R13),
+    /* bg */ Some(&R14),
+    /* my */ Some(&R15),
+    /* ca */ Some(&R16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&R17),
+    /* cv */ Some(&R18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&R19),
+    /* cs */ Some(&R20),
+    /* da */ Some(&R21),
+    /* dv */ None,
+    /* nl */ Some(&R22),
+    /* dz */ None,
+    /* en */ Some(&R23),
+    /* eo */ Some(&R24),
+    /* et */ Some(&R25),
+    /* ee */ Some(&R26),
+    /* fo */ Some(&R27),
+    /* fj */ None,
+    /* fi */ Some(&R28),
+    /* fr */ Some(&R29),
+    /* ff */ None,
+    /* gl */ Some(&R30),
+    /* ka */ Some(&R31),
+    /* de */ Some(&R32),
+    /* el */ Some(&R33),
+    /* gn */ None,
+    /* gu */ Some(&R34),
+    /* ht */ Some(&R29),
+    /* ha */ Some(&R35),
+    /* he */ Some(&R36),
+    /* hz */ None,
+    /* hi */ Some(&R37),
+    /* ho */ None,
+    /* hu */ Some(&R38),
+    /* ia */ Some(&R39),
+    /* id */ Some(&R40),
+    /* ie */ Some(&R41),
+    /* ga */ Some(&R42),
+    /* ig */ Some(&R43),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&R44),
+    /* it */ Some(&R45),
+    /* iu */ None,
+    /* ja */ Some(&R46),
+    /* jv */ Some(&R47),
+    /* kl */ None,
+    /* kn */ Some(&R48),
+    /* kr */ None,
+    /* ks */ Some(&R49),
+    /* kk */ Some(&R50),
+    /* km */ Some(&R51),
+    /* ki */ None,
+    /* rw */ Some(&R52),
+    /* ky */ Some(&R53),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&R54),
+    /* ku */ Some(&R55),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&R56),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&R57),
+    /* lt */ Some(&R58),
+    /* lu */ None,
+    /* lv */ Some(&R59),
+    /* gv */ None,
+    /* mk */ Some(&R60),
+    /* mg */ None,
+    /* ms */ Some(&R61),
+    /* ml */ Some(&R62),
+    /* mt */ Some(&R63),
+    /* mi */ Some(&R64),
+    /* mr */ Some(&R65),
+    /* mh */ None,
+    /* mn */ Some(&R66),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&R67),
+    /* ng */ None,
+    /* nb */ Some(&R68),
+    /* nn */ Some(&R69),
+    /* no */ Some(&R68),
+    /* ii */ Some(&R70),
+    /* nr */ None,
+    /* oc */ Some(&R71),
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ Some(&R72),
+    /* or */ Some(&R73),
+    /* os */ None,
+    /* pa */ Some(&R74),
+    /* pi */ None,
+    /* fa */ Some(&R75),
+    /* pl */ Some(&R76),
+    /* ps */ Some(&R77),
+    /* pt */ Some(&R78),
+    /* qu */ Some(&R79),
+    /* rm */ Some(&R80),
+    /* rn */ None,
+    /* ro */ Some(&R81),
+    /* ru */ Some(&R82),
+    /* sa */ Some(&R83),
+    /* sc */ Some(&R84),
+    /* sd */ Some(&R85),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&R86),
+    /* gd */ Some(&R87),
+    /* sn */ None,
+    /* si */ Some(&R88),
+    /* sk */ Some(&R89),
+    /* sl */ Some(&R90),
+    /* so */ Some(&R91),
+    /* st */ Some(&R92),
+    /* es */ Some(&R93),
+    /* su */ Some(&R94),
+    /* sw */ Some(&R95),
+    /* ss */ None,
+    /* sv */ Some(&R96),
+    /* ta */ Some(&R97),
+    /* te */ Some(&R98),
+    /* tg */ Some(&R99),
+    /* th */ Some(&R100),
+    /* ti */ Some(&R101),
+    /* bo */ None,
+    /* tk */ Some(&R102),
+    /* tl */ Some(&R103),
+    /* tn */ Some(&R104),
+    /* to */ Some(&R105),
+    /* tr */ Some(&R106),
+    /* ts */ None,
+    /* tt */ Some(&R107),
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&R108),
+    /* uk */ Some(&R109),
+    /* ur */ Some(&R110),
+    /* uz */ Some(&R111),
+    /* ve */ None,
+    /* vi */ Some(&R112),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&R113),
+    /* wo */ Some(&R114),
+    /* fy */ Some(&R115),
+    /* xh */ Some(&R116),
+    /* yi */ None,
+    /* yo */ Some(&R117),
+    /* za */ Some(&R118),
+    /* zu */ Some(&R119),
+];
@@ -1,8 +1,5 @@
-use rocket::{
-    http::Status,
-    request::Request,
-    response::{Responder, Response},
-};
+#[cfg(feature = "rocket")]
+use rocket::http::Status;
 
 /// This error implements `Responder`
 /// so not all errors need to be handled
@@ -20,22 +17,64 @@ pub enum Error {
     /// unsupported language codes in the url.
     #[error("404 not found.")]
     NotFound,
+
+    /// Returns a `500 Internal Server Error` response.
+    /// This error is thrown when no `Config` is attached, and
+    /// the `lang_fallback` configuration is set to `"error"`.
+    #[error("no language configuration attached.")]
+    Unconfigured,
+
+    /// Returns a `500 Internal Server Error` response.
+    /// This error is thrown by `LangCode::try_from` when the language
+    /// depends on an async custom resolver that can't complete right away.
+    /// The `LangCode` guard, or `Config::eager`, can wait for it instead.
+    #[error("the language depends on an async resolver that hasn't completed.")]
+    Pending,
+}
+
+/// A problem with a `Config`, found by `Config::validate`.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// Qualities must be numbers between 0.0 and 1.0.
+    #[error("the quality of `{tag}` is {quality}, but it must be between 0.0 and 1.0.")]
+    InvalidQuality {
+        /// The language tag, such as `es` or `pt-BR`.
+        tag: String,
+        /// The invalid quality.
+        quality: f32,
+    },
+
+    /// The url position refers to a segment that none of the mounted routes have.
+    #[error(
+        "the url position {position} is out of range, as routes have at most {segments} segments."
+    )]
+    UrlPosition {
+        /// The configured position.
+        position: i32,
+        /// The amount of segments of the longest route.
+        segments: usize,
+    },
+
+    /// A problem with a scoped configuration.
+    #[error("in the scope `{0}`: {1}")]
+    Scope(String, Box<ConfigError>),
 }
 
 impl Error {
-    /// returns the http status for the error.
-    pub fn status(&self) -> Status {
+    /// returns the http status code for the error.
+    pub fn status_code(&self) -> u16 {
         match self {
-            Self::NotAcceptable => Status::NotAcceptable,
-            Self::NotFound => Status::NotFound,
+            Self::NotAcceptable => 406,
+            Self::NotFound => 404,
+            Self::Unconfigured => 500,
+            Self::Pending => 500,
         }
     }
-}
 
-impl<'r, 'o: 'r> Responder<'r, 'o> for Error {
-    fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
-        Response::build()
-            .status(self.status())
-            .ok()
+    /// returns the http status for the error.
+    #[cfg(feature = "rocket")]
+    pub fn status(&self) -> Status {
+        Status::new(self.status_code())
     }
 }
@@ -0,0 +1,107 @@
+//! Behavior of `LangCode` when no `Config` fairing is attached.
+//!
+//! It is set with the `lang_fallback` key of the Rocket configuration:
+//! * `"warn"`: a warning is logged once, and `En` is used.
+//! * `"error"`: the guard fails with `Error::Unconfigured`.
+//! * a language code, such as `"es"`: that language is used.
+//!
+//! If the key is missing, the behavior is the same as `"warn"`, and a warning
+//! is also logged on launch when a route uses `LangCode`. An invalid value is
+//! reported once on launch, and behaves as `"warn"`.
+//!
+//! Rocket only allows managing state from a fairing, so without a `Config` the
+//! key is read from the configuration of each request, and the routes already
+//! reported are remembered for the whole process. Rocket doesn't expose which
+//! routes use a guard either, so the launch warning doesn't list them: each
+//! route is reported when it first asks for the language.
+use crate::{rocket_impl::PrivConfig, *};
+use rocket::{figment::Figment, Ignite, Rocket, Sentinel};
+use std::{collections::HashSet, sync::Mutex};
+
+/// The Rocket configuration key holding the fallback behavior.
+const KEY: &str = "lang_fallback";
+
+enum Fallback {
+    Warn,
+    Error,
+    Lang(LangCode),
+}
+
+impl Fallback {
+    /// Returns `None` if the key is not set, and the value if it is invalid.
+    fn from_figment(figment: &Figment) -> Option<Result<Self, String>> {
+        let value = figment
+            .extract_inner::<String>(KEY)
+            .ok()?;
+        Some(match value.as_str() {
+            "warn" => Ok(Fallback::Warn),
+            "error" => Ok(Fallback::Error),
+            lang => lang
+                .parse()
+                .map(Fallback::Lang)
+                .map_err(|_| value),
+        })
+    }
+}
+
+/// Resolves the language of a request when no `Config` is attached.
+/// With `"warn"`, a warning is logged the first time each route asks for the language.
+pub(crate) fn resolve(req: &Request<'_>) -> Result<LangCode, Error> {
+    // keyed by route rather than by path, so that it is bounded by the routes of the app.
+    static WARNED: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+    let fallback = Fallback::from_figment(req.rocket().figment())
+        .and_then(Result::ok)
+        .unwrap_or(Fallback::Warn);
+    match fallback {
+        Fallback::Lang(lang) => Ok(lang),
+        Fallback::Error => Err(Error::Unconfigured),
+        Fallback::Warn => {
+            let route = match req.route() {
+                Some(route) => format!("`{} {}`", route.method, route.uri),
+                None => String::from("a catcher or fairing"),
+            };
+            let mut warned = WARNED
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if warned
+                .get_or_insert_with(HashSet::new)
+                .insert(route.clone())
+            {
+                rocket::warn!(
+                    "`LangCode` was requested by {}, but no `rocket_lang::Config` is attached. \
+                     Falling back to `En`.",
+                    route
+                );
+            }
+            Ok(En)
+        }
+    }
+}
+
+/// Logs a warning on launch when a route uses `LangCode`, but there is neither
+/// a `Config` attached, nor a valid fallback set. Launch is never aborted.
+impl Sentinel for LangCode {
+    fn abort(rocket: &Rocket<Ignite>) -> bool {
+        if rocket
+            .state::<PrivConfig>()
+            .is_some()
+        {
+            return false;
+        }
+        match Fallback::from_figment(rocket.figment()) {
+            None => rocket::warn!(
+                "Routes use `LangCode`, but no `rocket_lang::Config` is attached, \
+                 and `{}` is not set. They fall back to `En`, and each route is \
+                 reported the first time it asks for the language.",
+                KEY
+            ),
+            Some(Err(value)) => rocket::warn!(
+                "invalid `{}` value: {:?}. Routes using `LangCode` fall back to `En`.",
+                KEY,
+                value
+            ),
+            Some(Ok(_)) => {}
+        }
+        false
+    }
+}
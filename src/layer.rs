@@ -0,0 +1,129 @@
+//! `tower` middleware, for frameworks built on `http` types such as `axum`.
+//!
+//! `LangLayer` makes the `Config` available to every request. As with the rocket fairing,
+//! the language is resolved the first time it is requested, and the response gets a
+//! `Content-Language` header if it was. The resolver set with `Config::resolver` is used,
+//! while the `custom` closures are only supported by rocket.
+//! ```rust
+//! # use rocket_lang::{*, layer::LangLayer};
+//! # fn route(router: axum::Router) -> axum::Router {
+//! let config = Config::new().supported(&[En, Es]);
+//! router.layer(LangLayer::new(config))
+//! # }
+//! ```
+//! With the `axum` feature, `LangCode` can be used as an extractor:
+//! ```rust
+//! # use rocket_lang::LangCode;
+//! async fn index(lang: LangCode) -> &'static str {
+//!     lang.as_str()
+//! }
+//! ```
+//! Other services can read the language with `resolve`.
+use crate::{source::Negotiation, *};
+use http::{header::CONTENT_LANGUAGE, request::Parts, HeaderValue};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// A layer that negotiates the language of each request with a `Config`.
+#[derive(Clone)]
+pub struct LangLayer {
+    config: Arc<Config>,
+}
+
+impl LangLayer {
+    /// Constructs a layer with the given configuration.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+}
+
+impl<S> Layer<S> for LangLayer {
+    type Service = LangService<S>;
+    fn layer(&self, inner: S) -> Self::Service {
+        LangService {
+            inner,
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// The service produced by `LangLayer`.
+#[derive(Clone)]
+pub struct LangService<S> {
+    inner: S,
+    config: Arc<Config>,
+}
+
+impl<S, B, R> Service<http::Request<B>> for LangService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<R>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        let negotiation = Negotiation::new(self.config.clone());
+        req.extensions_mut()
+            .insert(negotiation.clone());
+        let response = self.inner.call(req);
+        Box::pin(async move {
+            let mut response = response.await?;
+            if let Some(lang) = negotiation.resolved() {
+                response
+                    .headers_mut()
+                    .entry(CONTENT_LANGUAGE)
+                    .or_insert(HeaderValue::from_static(lang.as_str()));
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Resolves the language of a request that went through `LangLayer`.
+/// It returns `Error::Unconfigured` if the layer is missing.
+pub fn resolve(parts: &Parts) -> Result<LangCode, Error> {
+    parts
+        .extensions
+        .get::<Negotiation>()
+        .ok_or(Error::Unconfigured)?
+        .resolve(parts)
+}
+
+#[cfg(feature = "axum")]
+mod axum {
+    use super::*;
+    use axum_core::{
+        extract::FromRequestParts,
+        response::{IntoResponse, Response},
+    };
+    use http::StatusCode;
+
+    impl<S: Sync> FromRequestParts<S> for LangCode {
+        type Rejection = Error;
+        async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+            resolve(parts)
+        }
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> Response {
+            StatusCode::from_u16(self.status_code())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+                .into_response()
+        }
+    }
+}
@@ -3,19 +3,62 @@
 #[macro_use]
 extern crate thiserror;
 
-
+pub use accept_language::AcceptedLanguages;
 pub use config::Config;
-pub use error::Error;
+pub use error::{ConfigError, Error};
+#[cfg(feature = "rocket")]
 use rocket::{
     request::{FromRequest, Outcome},
     Request,
 };
+#[cfg(feature = "sqlx-postgres")]
+pub use sqlx_impl::LangCodeEnum;
 use std::{fmt::Display, hash::Hash, str::FromStr};
 mod accept_language;
+pub mod alternates;
+#[cfg(feature = "collation")]
+pub mod collation;
 mod config;
+#[cfg(feature = "currency")]
+pub mod currency;
+pub mod dates;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+pub mod display_names;
 mod error;
+#[cfg(feature = "rocket")]
+mod fallback;
+#[cfg(feature = "tower")]
+pub mod layer;
+pub mod lists;
+pub mod locale;
+#[cfg(feature = "actix")]
+pub mod middleware;
+pub mod negotiation;
+pub mod numbers;
+pub mod plurals;
+pub mod relative;
+#[cfg(feature = "rocket")]
+mod rocket_impl;
+pub mod sitemap;
+pub mod source;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+mod supported_languages;
+#[cfg(feature = "rocket")]
+pub mod switcher;
+#[cfg(feature = "templates")]
+pub mod templates;
+#[cfg(feature = "units")]
+pub mod units;
 mod url;
 
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "rocket")]
+    pub use rocket;
+}
+
 macro_rules! language_impls {
     ($($upper:ident | $lower:ident | $english_name:literal | $native_name:literal )*) => {
         ///  code | enum variant | English name | Native name
@@ -23,6 +66,8 @@ macro_rules! language_impls {
         $(#[doc = stringify!($lower | $upper | $english_name | $native_name)])*
         #[non_exhaustive]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+        #[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+        #[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
         pub enum LangCode {$(
             #[doc=stringify!($english_name)]
             $upper
@@ -33,9 +78,10 @@ macro_rules! language_impls {
             /// A collection with all the values to
             /// iterate through them easily.
             pub const ALL_CODES: &'static [Self] = &[$(Self::$upper,)*];
-            
+
             /// transforms the enum value to its lower case string representation.
             /// ```rust
+            /// # use rocket_lang::*;
             /// let spanish = Es.as_str();
             /// assert!(spanish == "es");
             /// ```
@@ -48,6 +94,7 @@ macro_rules! language_impls {
             /// Returns the name of the language in
             /// english
             /// ```rust
+            /// # use rocket_lang::*;
             /// let fr = Fr.english_name();
             /// assert!(fr == "French");
             /// ```
@@ -59,6 +106,7 @@ macro_rules! language_impls {
             /// Returns the name of the language in
             /// its native name.
             /// ```rust
+            /// # use rocket_lang::*;
             /// let german = De.native_name();
             /// assert!(german == "Deutsch");
             ///
@@ -89,35 +137,32 @@ macro_rules! language_impls {
 
     }
 }
-impl TryFrom<&Request<'_>> for LangCode {
-    type Error = Error;
-    fn try_from(req: &Request) -> Result<LangCode, Error> {
-        req.local_cache(|| Ok::<_, Error>(En))
-            .clone()
+impl LangCode {
+    /// Returns true if the language is written from right to left.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert!(Ar.is_rtl());
+    /// assert!(!Es.is_rtl());
+    /// ```
+    pub fn is_rtl(self) -> bool {
+        matches!(self, Ar | Dv | Fa | He | Ps | Sd | Ug | Ur | Yi)
     }
-}
-
-/// The language code value gets cached on construction,
-/// so it is ok to construct it multiple times.
-#[rocket::async_trait]
-impl<'r> FromRequest<'r> for LangCode {
-    type Error = Error;
-    /// if there is a config struct set, then
-    /// the local_cache already has a closure for creating the value,
-    /// so this one will get ignored.
-    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-        match request
-            .local_cache(|| Ok::<_, Error>(En))
-            .clone()
-        {
-            Ok(lang) => Outcome::Success(lang),
-            Err(err) => Outcome::Failure((err.status(), err)),
+    /// Returns the writing direction of the language,
+    /// as used by the html `dir` attribute.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert_eq!(He.dir(), "rtl");
+    /// assert_eq!(En.dir(), "ltr");
+    /// ```
+    pub fn dir(self) -> &'static str {
+        if self.is_rtl() {
+            "rtl"
+        } else {
+            "ltr"
         }
     }
 }
 
-
-
 language_impls! {
 Aa | aa  | "Afar"                | "Afaraf"
 Ab | ab  | "Abkhaz"              | "аҧсуа бызшәа"
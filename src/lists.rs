@@ -0,0 +1,132 @@
+//! Locale-aware list formatting, with data from the Unicode CLDR.
+//!
+//! Items are joined with the separators of a language, for a list of
+//! things that all apply, or of alternatives:
+//! ```rust
+//! # use rocket_lang::{*, lists::{format_list, ListType}};
+//! let items = ["A", "B", "C"];
+//! assert_eq!(format_list(En, items, ListType::Conjunction), "A, B, and C");
+//! assert_eq!(format_list(Es, items, ListType::Conjunction), "A, B y C");
+//! assert_eq!(format_list(De, items, ListType::Disjunction), "A, B oder C");
+//! assert_eq!(format_list(Zh, items, ListType::Conjunction), "A、B和C");
+//! ```
+//! Lists can also be written in the short and narrow widths of CLDR:
+//! ```rust
+//! # use rocket_lang::{*, lists::{format_list_width, ListType, ListWidth}};
+//! let items = ["A", "B", "C"];
+//! assert_eq!(format_list_width(En, items, ListType::Conjunction, ListWidth::Short), "A, B, & C");
+//! assert_eq!(format_list_width(En, items, ListType::Conjunction, ListWidth::Narrow), "A, B, C");
+//! ```
+//! The data is generated from the Unicode CLDR by `tools/cldr`. Languages without
+//! patterns of their own use the patterns of the root locale, as in `A, B, C`.
+use crate::*;
+
+#[rustfmt::skip]
+mod data;
+
+/// The kind of list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListType {
+    /// `A, B, and C`
+    Conjunction,
+    /// `A, B, or C`
+    Disjunction,
+}
+
+/// The width of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListWidth {
+    /// `A, B, and C`
+    Wide,
+    /// `A, B, & C`
+    Short,
+    /// `A, B, C`
+    Narrow,
+}
+
+/// The CLDR patterns of a list, where `{0}` and `{1}` are consecutive items.
+struct Patterns {
+    /// Between the first two items of a longer list.
+    start: &'static str,
+    /// Between the items in the middle.
+    middle: &'static str,
+    /// Between the last two items.
+    end: &'static str,
+    /// Between the items of a list of two.
+    two: &'static str,
+}
+
+/// The patterns of both list types in every width, in the order of the `ListType`
+/// and `ListWidth` variants.
+type Names = [[Patterns; 3]; 2];
+
+/// Formats a list of items for a language, in the wide width.
+pub fn format_list<I>(lang: LangCode, items: I, kind: ListType) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    format_list_width(lang, items, kind, ListWidth::Wide)
+}
+
+/// Formats a list of items for a language, in a width.
+pub fn format_list_width<I>(lang: LangCode, items: I, kind: ListType, width: ListWidth) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    let names = data::LANGUAGES[lang as usize].unwrap_or(&data::ROOT);
+    let patterns = &names[kind as usize][width as usize];
+    let join = |pattern: &str, first: &str, rest: &str| {
+        let pattern = spanish_conjunction(lang, pattern, rest);
+        let pattern = hebrew_conjunction(lang, &pattern, rest);
+        pattern
+            .replace("{0}", first)
+            .replace("{1}", rest)
+    };
+    match &items[..] {
+        [] => String::new(),
+        [item] => item.as_ref().to_string(),
+        [first, second] => join(patterns.two, first.as_ref(), second.as_ref()),
+        [first, middle @ .., before_last, last] => {
+            let mut list = join(patterns.end, before_last.as_ref(), last.as_ref());
+            for item in middle.iter().rev() {
+                list = join(patterns.middle, item.as_ref(), &list);
+            }
+            join(patterns.start, first.as_ref(), &list)
+        }
+    }
+}
+
+/// In Spanish, `y` becomes `e` before an `i` sound, and `o` becomes `u` before an `o` sound.
+fn spanish_conjunction(lang: LangCode, pattern: &str, next: &str) -> String {
+    if lang != Es {
+        return pattern.to_string();
+    }
+    let next = next.to_lowercase();
+    let i_sound = (next.starts_with('i') || next.starts_with("hi"))
+        && !next.starts_with("hia")
+        && !next.starts_with("hie")
+        && !next.starts_with("hio")
+        && !next.starts_with("hiu");
+    let o_sound = next.starts_with('o') || next.starts_with("ho") || next.starts_with('8');
+    match pattern {
+        "{0} y {1}" if i_sound => "{0} e {1}".to_string(),
+        "{0} o {1}" if o_sound => "{0} u {1}".to_string(),
+        _ => pattern.to_string(),
+    }
+}
+
+/// In Hebrew, `ו` is joined with a non-breaking hyphen to a word that doesn't start
+/// with a Hebrew letter.
+fn hebrew_conjunction(lang: LangCode, pattern: &str, next: &str) -> String {
+    let hebrew = next
+        .chars()
+        .next()
+        .is_some_and(|c| ('\u{5D0}'..='\u{5EA}').contains(&c));
+    match pattern {
+        "{0} ו{1}" if lang == He && !hebrew => "{0} ו\u{2011}{1}".to_string(),
+        _ => pattern.to_string(),
+    }
+}
@@ -0,0 +1,5074 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::{Names, Patterns};
+
+/// The patterns of the root locale, used by the languages without patterns of their own.
+pub(super) static ROOT: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// af
+static L0: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} en {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+    ],
+];
+
+// ak
+static L1: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ne {1}",
+            two: "{0} ne {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ne {1}",
+            two: "{0} ne {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ne {1}",
+            two: "{0} ne {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, anaa {1}",
+            two: "{0} anaa {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, anaa {1}",
+            two: "{0} anaa {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, anaa {1}",
+            two: "{0} anaa {1}",
+        },
+    ],
+];
+
+// sq
+static L2: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} dhe {1}",
+            two: "{0} dhe {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} dhe {1}",
+            two: "{0} dhe {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} dhe {1}",
+            two: "{0} dhe {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ose {1}",
+            two: "{0} ose {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ose {1}",
+            two: "{0} ose {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ose {1}",
+            two: "{0} ose {1}",
+        },
+    ],
+];
+
+// am
+static L3: Names = [
+    [
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} እና {1}",
+            two: "{0} እና {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} እና {1}",
+            two: "{0} እና {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} እና {1}",
+            two: "{0} እና {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይም {1}",
+            two: "{0} ወይም {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይም {1}",
+            two: "{0} ወይም {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይም {1}",
+            two: "{0} ወይም {1}",
+        },
+    ],
+];
+
+// ar
+static L4: Names = [
+    [
+        Patterns {
+            start: "{0} و{1}",
+            middle: "{0} و{1}",
+            end: "{0} و{1}",
+            two: "{0} و{1}",
+        },
+        Patterns {
+            start: "{0} و{1}",
+            middle: "{0} و{1}",
+            end: "{0} و{1}",
+            two: "{0} و{1}",
+        },
+        Patterns {
+            start: "{0} و{1}",
+            middle: "{0} و{1}",
+            end: "{0} و{1}",
+            two: "{0} و{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0} أو {1}",
+            middle: "{0} أو {1}",
+            end: "{0} أو {1}",
+            two: "{0} أو {1}",
+        },
+        Patterns {
+            start: "{0} أو {1}",
+            middle: "{0} أو {1}",
+            end: "{0} أو {1}",
+            two: "{0} أو {1}",
+        },
+        Patterns {
+            start: "{0} أو {1}",
+            middle: "{0} أو {1}",
+            end: "{0} أو {1}",
+            two: "{0} أو {1}",
+        },
+    ],
+];
+
+// hy
+static L5: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} և {1}",
+            two: "{0} և {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} և {1}",
+            two: "{0} և {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} կամ {1}",
+            two: "{0} կամ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} կամ {1}",
+            two: "{0} կամ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} կամ {1}",
+            two: "{0} կամ {1}",
+        },
+    ],
+];
+
+// as
+static L6: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} আৰু {1}",
+            two: "{0} আৰু {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} আৰু {1}",
+            two: "{0} আৰু {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} বা {1}",
+            two: "{0} বা {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} বা {1}",
+            two: "{0} বা {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} বা {1}",
+            two: "{0} বা {1}",
+        },
+    ],
+];
+
+// az
+static L7: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} və {1}",
+            two: "{0} və {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} və {1}",
+            two: "{0} və {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, yaxud {1}",
+            two: "{0} yaxud {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, yaxud {1}",
+            two: "{0}, yaxud {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, yaxud {1}",
+            two: "{0}, yaxud {1}",
+        },
+    ],
+];
+
+// ba
+static L8: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} һәм {1}",
+            two: "{0} һәм {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} һәм {1}",
+            two: "{0} һәм {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} һәм {1}",
+            two: "{0} һәм {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} йәки {1}",
+            two: "{0} йәки {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} йәки {1}",
+            two: "{0} йәки {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} йәки {1}",
+            two: "{0} йәки {1}",
+        },
+    ],
+];
+
+// eu
+static L9: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eta {1}",
+            two: "{0} eta {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eta {1}",
+            two: "{0} eta {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} edo {1}",
+            two: "{0} edo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} edo {1}",
+            two: "{0} edo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} edo {1}",
+            two: "{0} edo {1}",
+        },
+    ],
+];
+
+// be
+static L10: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} і {1}",
+            two: "{0} і {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} і {1}",
+            two: "{0} і {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} і {1}",
+            two: "{0} і {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ці {1}",
+            two: "{0} ці {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ці {1}",
+            two: "{0} ці {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ці {1}",
+            two: "{0} ці {1}",
+        },
+    ],
+];
+
+// bn
+static L11: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} এবং {1}",
+            two: "{0} এবং {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} এবং {1}",
+            two: "{0} এবং {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, বা {1}",
+            two: "{0} বা {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, বা {1}",
+            two: "{0} বা {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, বা {1}",
+            two: "{0} বা {1}",
+        },
+    ],
+];
+
+// bs, hr
+static L12: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ili {1}",
+            two: "{0} ili {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ili {1}",
+            two: "{0} ili {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ili {1}",
+            two: "{0} ili {1}",
+        },
+    ],
+];
+
+// br
+static L13: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ha {1}",
+            two: "{0} ha {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} & {1}",
+            two: "{0} & {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} pe {1}",
+            two: "{0} pe {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} pe {1}",
+            two: "{0} pe {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} pe {1}",
+            two: "{0} pe {1}",
+        },
+    ],
+];
+
+// bg
+static L14: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+    ],
+];
+
+// my
+static L15: Names = [
+    [
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0}နှင့် {1}",
+            two: "{0}နှင့် {1}",
+        },
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0}နှင့် {1}",
+            two: "{0}နှင့် {1}",
+        },
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0}နှင့် {1}",
+            two: "{0}နှင့် {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0} သို့မဟုတ် {1}",
+            two: "{0} သို့မဟုတ် {1}",
+        },
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0} သို့မဟုတ် {1}",
+            two: "{0} သို့မဟုတ် {1}",
+        },
+        Patterns {
+            start: "{0} - {1}",
+            middle: "{0} - {1}",
+            end: "{0} သို့မဟုတ် {1}",
+            two: "{0} သို့မဟုတ် {1}",
+        },
+    ],
+];
+
+// ca
+static L16: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+    ],
+];
+
+// zh
+static L17: Names = [
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}和{1}",
+            two: "{0}和{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}和{1}",
+            two: "{0}和{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、{1}",
+            two: "{0}、{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}或{1}",
+            two: "{0}或{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}或{1}",
+            two: "{0}或{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}或{1}",
+            two: "{0}或{1}",
+        },
+    ],
+];
+
+// cv
+static L18: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} тата {1}",
+            two: "{0} тата {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} тата {1}",
+            two: "{0} тата {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} е {1}",
+            two: "{0} е {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} е {1}",
+            two: "{0} е {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} е {1}",
+            two: "{0} е {1}",
+        },
+    ],
+];
+
+// cs
+static L19: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a\u{A0}{1}",
+            two: "{0} a\u{A0}{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a\u{A0}{1}",
+            two: "{0} a\u{A0}{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nebo {1}",
+            two: "{0} nebo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nebo {1}",
+            two: "{0} nebo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nebo {1}",
+            two: "{0} nebo {1}",
+        },
+    ],
+];
+
+// da
+static L20: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} el. {1}",
+            two: "{0} el. {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} el. {1}",
+            two: "{0} el. {1}",
+        },
+    ],
+];
+
+// nl
+static L21: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} of {1}",
+            two: "{0} of {1}",
+        },
+    ],
+];
+
+// en
+static L22: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, and {1}",
+            two: "{0} and {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// eo
+static L23: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} kaj {1}",
+            two: "{0} kaj {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} k {1}",
+            two: "{0} k {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} aŭ {1}",
+            two: "{0} aŭ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} aŭ {1}",
+            two: "{0} aŭ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} aŭ {1}",
+            two: "{0} aŭ {1}",
+        },
+    ],
+];
+
+// et
+static L24: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ja {1}",
+            two: "{0} ja {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ja {1}",
+            two: "{0} ja {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} või {1}",
+            two: "{0} või {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} või {1}",
+            two: "{0} või {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} või {1}",
+            two: "{0} või {1}",
+        },
+    ],
+];
+
+// ee
+static L25: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, kple {1}",
+            two: "{0} kple {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, kple {1}",
+            two: "{0} kple {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, kple {1}",
+            two: "{0} kple {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// fo
+static L26: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ella {1}",
+            two: "{0} ella {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ella {1}",
+            two: "{0} ella {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ella {1}",
+            two: "{0} ella {1}",
+        },
+    ],
+];
+
+// fi
+static L27: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ja {1}",
+            two: "{0} ja {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ja {1}",
+            two: "{0} ja {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ja {1}",
+            two: "{0} ja {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} tai {1}",
+            two: "{0} tai {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} tai {1}",
+            two: "{0} tai {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} tai {1}",
+            two: "{0} tai {1}",
+        },
+    ],
+];
+
+// fr, ht
+static L28: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} et {1}",
+            two: "{0} et {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} et {1}",
+            two: "{0} et {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+    ],
+];
+
+// gl
+static L29: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+    ],
+];
+
+// ka
+static L30: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} და {1}",
+            two: "{0} და {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} და {1}",
+            two: "{0} და {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} და {1}",
+            two: "{0} და {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ან {1}",
+            two: "{0} ან {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ან {1}",
+            two: "{0} ან {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ან {1}",
+            two: "{0} ან {1}",
+        },
+    ],
+];
+
+// de
+static L31: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} und {1}",
+            two: "{0} und {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} und {1}",
+            two: "{0} und {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} und {1}",
+            two: "{0} und {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} oder {1}",
+            two: "{0} oder {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} oder {1}",
+            two: "{0} oder {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} oder {1}",
+            two: "{0} oder {1}",
+        },
+    ],
+];
+
+// el
+static L32: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} και {1}",
+            two: "{0} και {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} και {1}",
+            two: "{0} και {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ή {1}",
+            two: "{0} ή {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ή {1}",
+            two: "{0} ή {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ή {1}",
+            two: "{0} ή {1}",
+        },
+    ],
+];
+
+// gu
+static L33: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} અને {1}",
+            two: "{0} અને {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} અને {1}",
+            two: "{0} અને {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, અથવા {1}",
+            two: "{0} અથવા {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} અથવા {1}",
+            two: "{0} અથવા {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} અથવા {1}",
+            two: "{0} અથવા {1}",
+        },
+    ],
+];
+
+// ha
+static L34: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, da {1}",
+            two: "{0} da {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, da {1}",
+            two: "{0} da {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, da {1}",
+            two: "{0} da {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ko {1}",
+            two: "{0} ko {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ko {1}",
+            two: "{0} ko {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ko {1}",
+            two: "{0} ko {1}",
+        },
+    ],
+];
+
+// he
+static L35: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ו{1}",
+            two: "{0} ו{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ו{1}",
+            two: "{0} ו{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ו{1}",
+            two: "{0} ו{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} או {1}",
+            two: "{0} או {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} או {1}",
+            two: "{0} או {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} או {1}",
+            two: "{0} או {1}",
+        },
+    ],
+];
+
+// hi
+static L36: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, और {1}",
+            two: "{0} और {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} और {1}",
+            two: "{0} और {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} और {1}",
+            two: "{0} और {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} या {1}",
+            two: "{0} या {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} या {1}",
+            two: "{0} या {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} या {1}",
+            two: "{0} या {1}",
+        },
+    ],
+];
+
+// hu
+static L37: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} és {1}",
+            two: "{0} és {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} és {1}",
+            two: "{0} és {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} és {1}",
+            two: "{0} és {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vagy {1}",
+            two: "{0} vagy {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vagy {1}",
+            two: "{0} vagy {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vagy {1}",
+            two: "{0} vagy {1}",
+        },
+    ],
+];
+
+// ia
+static L38: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+    ],
+];
+
+// id
+static L39: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, dan {1}",
+            two: "{0} dan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, dan {1}",
+            two: "{0} dan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+    ],
+];
+
+// ie
+static L40: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} e {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// ga
+static L41: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} agus {1}",
+            two: "{0} agus {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} agus {1}",
+            two: "{0} agus {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nó {1}",
+            two: "{0} nó {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nó {1}",
+            two: "{0} nó {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} nó {1}",
+            two: "{0} nó {1}",
+        },
+    ],
+];
+
+// ig
+static L42: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, na {1}",
+            two: "{0} na {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, na {1}",
+            two: "{0} na {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ma ọ bụ {1}",
+            two: "{0} ma ọ bụ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ma ọ bụ {1}",
+            two: "{0} ma ọ bụ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ma ọ bụ {1}",
+            two: "{0} ma ọ bụ {1}",
+        },
+    ],
+];
+
+// is
+static L43: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eða {1}",
+            two: "{0} eða {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eða {1}",
+            two: "{0} eða {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eða {1}",
+            two: "{0} eða {1}",
+        },
+    ],
+];
+
+// it, sc
+static L44: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+    ],
+];
+
+// ja
+static L45: Names = [
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、{1}",
+            two: "{0}、{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、{1}",
+            two: "{0}、{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、{1}",
+            two: "{0}、{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、または{1}",
+            two: "{0}または{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、または{1}",
+            two: "{0}または{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}、または{1}",
+            two: "{0}または{1}",
+        },
+    ],
+];
+
+// jv
+static L46: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, lan {1}",
+            two: "{0} lan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, lan {1}",
+            two: "{0} lan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utowo {1}",
+            two: "{0} utowo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utowo {1}",
+            two: "{0} utowo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utowo {1}",
+            two: "{0} utowo {1}",
+        },
+    ],
+];
+
+// kn
+static L47: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ಮತ್ತು {1}",
+            two: "{0} ಮತ್ತು {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ಮತ್ತು {1}",
+            two: "{0} ಮತ್ತು {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ಅಥವಾ {1}",
+            two: "{0} ಅಥವಾ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ಅಥವಾ {1}",
+            two: "{0} ಅಥವಾ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ಅಥವಾ {1}",
+            two: "{0} ಅಥವಾ {1}",
+        },
+    ],
+];
+
+// ks
+static L48: Names = [
+    [
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، تٕہ {1}",
+            two: "{0} تٕہ {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، تٕہ {1}",
+            two: "{0} تٕہ {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، تٕہ {1}",
+            two: "{0} تٕہ {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// kk
+static L49: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} және {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} және {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} және {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, не болмаса {1}",
+            two: "{0} не {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, не болмаса {1}",
+            two: "{0} не {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, не болмаса {1}",
+            two: "{0} не {1}",
+        },
+    ],
+];
+
+// km
+static L50: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} និង {1}",
+            two: "{0} និង\u{200B}{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} និង {1}",
+            two: "{0} និង {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ឬ {1}",
+            two: "{0} ឬ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ឬ {1}",
+            two: "{0} ឬ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ឬ {1}",
+            two: "{0} ឬ {1}",
+        },
+    ],
+];
+
+// ky
+static L51: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} жана {1}",
+            two: "{0} жана {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} жана {1}",
+            two: "{0} жана {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} жана {1}",
+            two: "{0} жана {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} же {1}",
+            two: "{0} же {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} же {1}",
+            two: "{0} же {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} же {1}",
+            two: "{0} же {1}",
+        },
+    ],
+];
+
+// ko
+static L52: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 및 {1}",
+            two: "{0} 및 {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 및 {1}",
+            two: "{0} 및 {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 및 {1}",
+            two: "{0} 및 {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 또는 {1}",
+            two: "{0} 또는 {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 또는 {1}",
+            two: "{0} 또는 {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} 또는 {1}",
+            two: "{0} 또는 {1}",
+        },
+    ],
+];
+
+// ku
+static L53: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} û {1}",
+            two: "{0} û {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} û {1}",
+            two: "{0} û {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} û {1}",
+            two: "{0} û {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} an {1}",
+            two: "{0} an {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} an {1}",
+            two: "{0} an {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} an {1}",
+            two: "{0} an {1}",
+        },
+    ],
+];
+
+// lb
+static L54: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a(n) {1}",
+            two: "{0} a(n) {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a(n) {1}",
+            two: "{0} a(n) {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a(n) {1}",
+            two: "{0} a(n) {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// lo
+static L55: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} ແລະ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ແລະ {1}",
+            two: "{0} ແລະ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} ແລະ {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ຫຼື {1}",
+            two: "{0} ຫຼື {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ຫຼື {1}",
+            two: "{0} ຫຼື {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ຫຼື {1}",
+            two: "{0} ຫຼື {1}",
+        },
+    ],
+];
+
+// lt
+static L56: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ir {1}",
+            two: "{0} ir {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ir {1}",
+            two: "{0} ir {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ir {1}",
+            two: "{0} ir {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ar {1}",
+            two: "{0} ar {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ar {1}",
+            two: "{0} ar {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ar {1}",
+            two: "{0} ar {1}",
+        },
+    ],
+];
+
+// lv
+static L57: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} un {1}",
+            two: "{0} un {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} un {1}",
+            two: "{0} un {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} un {1}",
+            two: "{0} un {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vai {1}",
+            two: "{0} vai {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vai {1}",
+            two: "{0} vai {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} vai {1}",
+            two: "{0} vai {1}",
+        },
+    ],
+];
+
+// mk, sr
+static L58: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+    ],
+];
+
+// ms
+static L59: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} dan {1}",
+            two: "{0} dan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} dan {1}",
+            two: "{0} dan {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, atau {1}",
+            two: "{0} atau {1}",
+        },
+    ],
+];
+
+// ml
+static L60: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, അല്ലെങ്കിൽ {1}",
+            two: "{0} അല്ലെങ്കിൽ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, അല്ലെങ്കിൽ {1}",
+            two: "{0} അല്ലെങ്കിൽ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, അല്ലെങ്കിൽ {1}",
+            two: "{0} അല്ലെങ്കിൽ {1}",
+        },
+    ],
+];
+
+// mt
+static L61: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, u {1}",
+            two: "{0} u {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, u {1}",
+            two: "{0} u {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, u {1}",
+            two: "{0} u {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// mi
+static L62: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} rānei",
+            two: "{0}, {1} rānei",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} rānei",
+            two: "{0}, {1} rānei",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} rānei",
+            two: "{0}, {1} rānei",
+        },
+    ],
+];
+
+// mr
+static L63: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} आणि {1}",
+            two: "{0} आणि {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} आणि {1}",
+            two: "{0} आणि {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} आणि {1}",
+            two: "{0} आणि {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, किंवा {1}",
+            two: "{0} किंवा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} किंवा {1}",
+            two: "{0} किंवा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} किंवा {1}",
+            two: "{0} किंवा {1}",
+        },
+    ],
+];
+
+// mn
+static L64: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} зэргийн аль нэг",
+            two: "{0} эсвэл {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} зэргийн аль нэг",
+            two: "{0} эсвэл {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1} зэргийн аль нэг",
+            two: "{0} эсвэл {1}",
+        },
+    ],
+];
+
+// ne
+static L65: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} र {1}",
+            two: "{0} र {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} र {1}",
+            two: "{0} र {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, वा {1}",
+            two: "{0} वा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, वा {1}",
+            two: "{0} वा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, वा {1}",
+            two: "{0} वा {1}",
+        },
+    ],
+];
+
+// nb, nn, no
+static L66: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} og {1}",
+            two: "{0} og {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+    ],
+];
+
+// ii
+static L67: Names = [
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꌋꆀ{1}",
+            two: "{0}ꌋꆀ{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꌋꆀ{1}",
+            two: "{0}ꌋꆀ{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꌋꆀ{1}",
+            two: "{0}ꌋꆀ{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꅀ{1}",
+            two: "{0}ꅀ{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꅀ{1}",
+            two: "{0}ꅀ{1}",
+        },
+        Patterns {
+            start: "{0}、{1}",
+            middle: "{0}、{1}",
+            end: "{0}ꅀ{1}",
+            two: "{0}ꅀ{1}",
+        },
+    ],
+];
+
+// or
+static L68: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ଓ {1}",
+            two: "{0} ଓ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ଓ {1}",
+            two: "{0} ଓ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ଓ {1}",
+            two: "{0} ଓ {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} କିମ୍ବା {1}",
+            two: "{0} କିମ୍ବା {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} କିମ୍ବା {1}",
+            two: "{0} କିମ୍ବା {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} କିମ୍ବା {1}",
+            two: "{0} କିମ୍ବା {1}",
+        },
+    ],
+];
+
+// pa
+static L69: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ਅਤੇ {1}",
+            two: "{0} ਅਤੇ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ਅਤੇ {1}",
+            two: "{0} ਅਤੇ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ਜਾਂ {1}",
+            two: "{0} ਜਾਂ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ਜਾਂ {1}",
+            two: "{0} ਜਾਂ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ਜਾਂ {1}",
+            two: "{0} ਜਾਂ {1}",
+        },
+    ],
+];
+
+// fa
+static L70: Names = [
+    [
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}، و {1}",
+            two: "{0} و {1}",
+        },
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}، و {1}",
+            two: "{0} و {1}",
+        },
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}،\u{200F} {1}",
+            two: "{0}،\u{200F} {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+        Patterns {
+            start: "{0}،\u{200F} {1}",
+            middle: "{0}،\u{200F} {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+    ],
+];
+
+// pl
+static L71: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} i {1}",
+            two: "{0} i {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} lub {1}",
+            two: "{0} lub {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} lub {1}",
+            two: "{0} lub {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} lub {1}",
+            two: "{0} lub {1}",
+        },
+    ],
+];
+
+// ps
+static L72: Names = [
+    [
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، او {1}",
+            two: "{0} او {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، او {1}",
+            two: "{0} او {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، او {1}",
+            two: "{0}، {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, یا {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, یا {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, یا {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// pt
+static L73: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ou {1}",
+            two: "{0} ou {1}",
+        },
+    ],
+];
+
+// qu
+static L74: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utaq {1}",
+            two: "{0} utaq {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utaq {1}",
+            two: "{0} utaq {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, utaq {1}",
+            two: "{0} utaq {1}",
+        },
+    ],
+];
+
+// rm
+static L75: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} e {1}",
+            two: "{0} e {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} u {1}",
+            two: "{0} u {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} u {1}",
+            two: "{0} u {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} u {1}",
+            two: "{0} u {1}",
+        },
+    ],
+];
+
+// ro
+static L76: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} și {1}",
+            two: "{0} și {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} și {1}",
+            two: "{0} și {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} sau {1}",
+            two: "{0} sau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} sau {1}",
+            two: "{0} sau {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} sau {1}",
+            two: "{0} sau {1}",
+        },
+    ],
+];
+
+// ru
+static L77: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} и {1}",
+            two: "{0} и {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} или {1}",
+            two: "{0} или {1}",
+        },
+    ],
+];
+
+// sa
+static L78: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, तथा {1}",
+            two: "{0} तथा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, तथा {1}",
+            two: "{0} तथा {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, तथा {1}",
+            two: "{0} तथा {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// sd
+static L79: Names = [
+    [
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، ۽ {1}",
+            two: "{0} ۽ {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، ۽ {1}",
+            two: "{0} ۽ {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}, {1}",
+            two: "{0}، {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, يا {1}",
+            two: "{0} يا {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, يا {1}",
+            two: "{0} يا {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, يا {1}",
+            two: "{0} يا {1}",
+        },
+    ],
+];
+
+// gd
+static L80: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} agus {1}",
+            two: "{0} agus {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ⁊ {1}",
+            two: "{0} ⁊ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} no {1}",
+            two: "{0} no {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} no {1}",
+            two: "{0} no {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} no {1}",
+            two: "{0} no {1}",
+        },
+    ],
+];
+
+// si
+static L81: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, සහ {1}",
+            two: "{0} සහ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, සහ {1}",
+            two: "{0} සහ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, සහ {1}",
+            two: "{0} සහ {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, හෝ {1}",
+            two: "{0} හෝ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, හෝ {1}",
+            two: "{0} හෝ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, හෝ {1}",
+            two: "{0} හෝ {1}",
+        },
+    ],
+];
+
+// sk
+static L82: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a {1}",
+            two: "{0} a\u{A0}{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a {1}",
+            two: "{0} a\u{A0}{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} a {1}",
+            two: "{0} a\u{A0}{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} alebo {1}",
+            two: "{0} alebo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} alebo {1}",
+            two: "{0} alebo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} alebo {1}",
+            two: "{0} alebo {1}",
+        },
+    ],
+];
+
+// sl
+static L83: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} in {1}",
+            two: "{0} in {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} in {1}",
+            two: "{0} in {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} in {1}",
+            two: "{0} in {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ali {1}",
+            two: "{0} ali {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ali {1}",
+            two: "{0} ali {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ali {1}",
+            two: "{0} ali {1}",
+        },
+    ],
+];
+
+// so
+static L84: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} iyo {1}",
+            two: "{0} iyo {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ama {1}",
+            two: "{0} ama {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ama {1}",
+            two: "{0} ama {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ama {1}",
+            two: "{0} ama {1}",
+        },
+    ],
+];
+
+// es
+static L85: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} y {1}",
+            two: "{0} y {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} y {1}",
+            two: "{0} y {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} y {1}",
+            two: "{0} y {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} o {1}",
+            two: "{0} o {1}",
+        },
+    ],
+];
+
+// su
+static L86: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, sareng {1}",
+            two: "{0} sareng {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, sareng {1}",
+            two: "{0} sareng {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, sareng {1}",
+            two: "{0} sareng {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// sw
+static L87: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} na {1}",
+            two: "{0} na {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} na {1}",
+            two: "{0} na {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} na {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} au {1}",
+            two: "{0} au {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, au {1}",
+            two: "{0} au {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, au {1}",
+            two: "{0} au {1}",
+        },
+    ],
+];
+
+// sv
+static L88: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} och {1}",
+            two: "{0} och {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} och {1}",
+            two: "{0} och {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} eller {1}",
+            two: "{0} eller {1}",
+        },
+    ],
+];
+
+// ta
+static L89: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} மற்றும் {1}",
+            two: "{0} மற்றும் {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} மற்றும் {1}",
+            two: "{0} மற்றும் {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} மற்றும் {1}",
+            two: "{0} மற்றும் {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} அல்லது {1}",
+            two: "{0} அல்லது {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} அல்லது {1}",
+            two: "{0} அல்லது {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} அல்லது {1}",
+            two: "{0} அல்லது {1}",
+        },
+    ],
+];
+
+// te
+static L90: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} మరియు {1}",
+            two: "{0} మరియు {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} మరియు {1}",
+            two: "{0} మరియు {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} లేదా {1}",
+            two: "{0} లేదా {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} లేదా {1}",
+            two: "{0} లేదా {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} లేదా {1}",
+            two: "{0} లేదా {1}",
+        },
+    ],
+];
+
+// tg
+static L91: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} ва {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} ва {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} ва {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ё {1}",
+            two: "{0}, ё {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ё {1}",
+            two: "{0}, ё {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ё {1}",
+            two: "{0}, ё {1}",
+        },
+    ],
+];
+
+// th
+static L92: Names = [
+    [
+        Patterns {
+            start: "{0} {1}",
+            middle: "{0} {1}",
+            end: "{0} และ{1}",
+            two: "{0}และ{1}",
+        },
+        Patterns {
+            start: "{0} {1}",
+            middle: "{0} {1}",
+            end: "{0} และ{1}",
+            two: "{0}และ{1}",
+        },
+        Patterns {
+            start: "{0} {1}",
+            middle: "{0} {1}",
+            end: "{0} และ{1}",
+            two: "{0}และ{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} หรือ {1}",
+            two: "{0} หรือ {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} หรือ {1}",
+            two: "{0}หรือ{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} หรือ {1}",
+            two: "{0}หรือ{1}",
+        },
+    ],
+];
+
+// ti
+static L93: Names = [
+    [
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0}ን {1}ን",
+            two: "{0}ን {1}ን",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0}ን {1}ን",
+            two: "{0}ን {1}ን",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0}ን {1}ን",
+            two: "{0}፣ {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይ {1}",
+            two: "{0} ወይ {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይ {1}",
+            two: "{0} ወይ {1}",
+        },
+        Patterns {
+            start: "{0}፣ {1}",
+            middle: "{0}፣ {1}",
+            end: "{0} ወይ {1}",
+            two: "{0} ወይ {1}",
+        },
+    ],
+];
+
+// tk
+static L94: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} we {1}",
+            two: "{0} we {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} we {1}",
+            two: "{0} we {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ýa-da {1}",
+            two: "{0} ýa-da {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ýa-da {1}",
+            two: "{0} ýa-da {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ýa-da {1}",
+            two: "{0} ýa-da {1}",
+        },
+    ],
+];
+
+// tl
+static L95: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, at {1}",
+            two: "{0} at {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, at {1}",
+            two: "{0} at {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, o {1}",
+            two: "{0} o {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, o {1}",
+            two: "{0} o {1}",
+        },
+    ],
+];
+
+// to
+static L96: Names = [
+    [
+        Patterns {
+            start: "{0} mo {1}",
+            middle: "{0} mo {1}",
+            end: "{0} mo {1}",
+            two: "{0} mo {1}",
+        },
+        Patterns {
+            start: "{0} mo {1}",
+            middle: "{0} mo {1}",
+            end: "{0} mo {1}",
+            two: "{0} mo {1}",
+        },
+        Patterns {
+            start: "{0} mo {1}",
+            middle: "{0} mo {1}",
+            end: "{0} mo {1}",
+            two: "{0} mo {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, pē {1}",
+            two: "{0} pē {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, pē {1}",
+            two: "{0} pē {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, pē {1}",
+            two: "{0} pē {1}",
+        },
+    ],
+];
+
+// tr
+static L97: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ve {1}",
+            two: "{0} ve {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} ve {1}",
+            two: "{0} ve {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} veya {1}",
+            two: "{0} veya {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} veya {1}",
+            two: "{0} veya {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} veya {1}",
+            two: "{0} veya {1}",
+        },
+    ],
+];
+
+// tt
+static L98: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} һәм {1}",
+            two: "{0} һәм {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} һәм {1}",
+            two: "{0} һәм {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, яки {1}",
+            two: "{0} яки {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, яки {1}",
+            two: "{0} яки {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, яки {1}",
+            two: "{0} яки {1}",
+        },
+    ],
+];
+
+// ug
+static L99: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, and {1}",
+            two: "{0} and {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, and {1}",
+            two: "{0} and {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, and {1}",
+            two: "{0} and {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// uk
+static L100: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} і {1}",
+            two: "{0} і {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} і {1}",
+            two: "{0} і {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} або {1}",
+            two: "{0} або {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} або {1}",
+            two: "{0} або {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} або {1}",
+            two: "{0} або {1}",
+        },
+    ],
+];
+
+// ur
+static L101: Names = [
+    [
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، اور {1}",
+            two: "{0} اور {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، اور {1}",
+            two: "{0} اور {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، {1}",
+            two: "{0}، {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+        Patterns {
+            start: "{0}، {1}",
+            middle: "{0}، {1}",
+            end: "{0}، یا {1}",
+            two: "{0} یا {1}",
+        },
+    ],
+];
+
+// uz
+static L102: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} va {1}",
+            two: "{0} va {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} va {1}",
+            two: "{0} va {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} yoki {1}",
+            two: "{0} yoki {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} yoki {1}",
+            two: "{0} yoki {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} yoki {1}",
+            two: "{0} yoki {1}",
+        },
+    ],
+];
+
+// vi
+static L103: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} và {1}",
+            two: "{0} và {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} và {1}",
+            two: "{0} và {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} hoặc {1}",
+            two: "{0} hoặc {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} hoặc {1}",
+            two: "{0} hoặc {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} hoặc {1}",
+            two: "{0} hoặc {1}",
+        },
+    ],
+];
+
+// cy
+static L104: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, a(c) {1}",
+            two: "{0} a(c) {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, a(c) {1}",
+            two: "{0} a(c) {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} neu {1}",
+            two: "{0} neu {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} neu {1}",
+            two: "{0} neu {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} neu {1}",
+            two: "{0} neu {1}",
+        },
+    ],
+];
+
+// wo
+static L105: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, & {1}",
+            two: "{0} & {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, & {1}",
+            two: "{0} & {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// fy
+static L106: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} en {1}",
+            two: "{0} en {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+// xh
+static L107: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, okanye {1}",
+            two: "{0} okanye {1}",
+        },
+    ],
+];
+
+// yo
+static L108: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0} àti{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} àti {1}",
+            two: "{0} àti{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0} àti {1}",
+            two: "{0} àti{1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, tabi {1}",
+            two: "{0} tàbí {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, tabi {1}",
+            two: "{0} tàbí {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, tabi {1}",
+            two: "{0} tàbí {1}",
+        },
+    ],
+];
+
+// zu
+static L109: Names = [
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ne-{1}",
+            two: "{0} ne-{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, ne-{1}",
+            two: "{0} ne-{1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, {1}",
+            two: "{0}, {1}",
+        },
+    ],
+    [
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+        Patterns {
+            start: "{0}, {1}",
+            middle: "{0}, {1}",
+            end: "{0}, or {1}",
+            two: "{0} or {1}",
+        },
+    ],
+];
+
+/// The patterns of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [Option<&Names>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&L0),
+    /* ak */ Some(&L1),
+    /* sq */ Some(&L2),
+    /* am */ Some(&L3),
+    /* ar */ Some(&L4),
+    /* an */ None,
+    /* hy */ Some(&L5),
+    /* as */ Some(&L6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&L7),
+    /* bm */ None,
+    /* ba */ Some(&L8),
+    /* eu */ Some(&L9),
+    /* be */ Some(&L10),
+    /* bn */ Some(&L11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&L12),
+    /* br */ Some(&L13),
+    /* bg */ Some(&L14),
+    /* my */ Some(&L15),
+    /* ca */ Some(&L16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&L17),
+    /* cv */ Some(&L18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&L12),
+    /* cs */ Some(&L19),
+    /* da */ Some(&L20),
+    /* dv */ None,
+    /* nl */ Some(&L21),
+    /* dz */ None,
+    /* en */ Some(&L22),
+    /* eo */ Some(&L23),
+    /* et */ Some(&L24),
+    /* ee */ Some(&L25),
+    /* fo */ Some(&L26),
+    /* fj */ None,
+    /* fi */ Some(&L27),
+    /* fr */ Some(&L28),
+    /* ff */ None,
+    /* gl */ Some(&L29),
+    /* ka */ Some(&L30),
+    /* de */ Some(&L31),
+    /* el */ Some(&L32),
+    /* gn */ None,
+    /* gu */ Some(&L33),
+    /* ht */ Some(&L28),
+    /* ha */ Some(&L34),
+    /* he */ Some(&L35),
+    /* hz */ None,
+    /* hi */ Some(&L36),
+    /* ho */ None,
+    /* hu */ Some(&L37),
+    /* ia */ Some(&L38),
+    /* id */ Some(&L39),
+    /* ie */ Some(&L40),
+    /* ga */ Some(&L41),
+    /* ig */ Some(&L42),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&L43),
+    /* it */ Some(&L44),
+    /* iu */ None,
+    /* ja */ Some(&L45),
+    /* jv */ Some(&L46),
+    /* kl */ None,
+    /* kn */ Some(&L47),
+    /* kr */ None,
+    /* ks */ Some(&L48),
+    /* kk */ Some(&L49),
+    /* km */ Some(&L50),
+    /* ki */ None,
+    /* rw */ None,
+    /* ky */ Some(&L51),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&L52),
+    /* ku */ Some(&L53),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&L54),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&L55),
+    /* lt */ Some(&L56),
+    /* lu */ None,
+    /* lv */ Some(&L57),
+    /* gv */ None,
+    /* mk */ Some(&L58),
+    /* mg */ None,
+    /* ms */ Some(&L59),
+    /* ml */ Some(&L60),
+    /* mt */ Some(&L61),
+    /* mi */ Some(&L62),
+    /* mr */ Some(&L63),
+    /* mh */ None,
+    /* mn */ Some(&L64),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&L65),
+    /* ng */ None,
+    /* nb */ Some(&L66),
+    /* nn */ Some(&L66),
+    /* no */ Some(&L66),
+    /* ii */ Some(&L67),
+    /* nr */ None,
+    /* oc */ None,
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ None,
+    /* or */ Some(&L68),
+    /* os */ None,
+    /* pa */ Some(&L69),
+    /* pi */ None,
+    /* fa */ Some(&L70),
+    /* pl */ Some(&L71),
+    /* ps */ Some(&L72),
+    /* pt */ Some(&L73),
+    /* qu */ Some(&L74),
+    /* rm */ Some(&L75),
+    /* rn */ None,
+    /* ro */ Some(&L76),
+    /* ru */ Some(&L77),
+    /* sa */ Some(&L78),
+    /* sc */ Some(&L44),
+    /* sd */ Some(&L79),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&L58),
+    /* gd */ Some(&L80),
+    /* sn */ None,
+    /* si */ Some(&L81),
+    /* sk */ Some(&L82),
+    /* sl */ Some(&L83),
+    /* so */ Some(&L84),
+    /* st */ None,
+    /* es */ Some(&L85),
+    /* su */ Some(&L86),
+    /* sw */ Some(&L87),
+    /* ss */ None,
+    /* sv */ Some(&L88),
+    /* ta */ Some(&L89),
+    /* te */ Some(&L90),
+    /* tg */ Some(&L91),
+    /* th */ Some(&L92),
+    /* ti */ Some(&L93),
+    /* bo */ None,
+    /* tk */ Some(&L94),
+    /* tl */ Some(&L95),
+    /* tn */ None,
+    /* to */ Some(&L96),
+    /* tr */ Some(&L97),
+    /* ts */ None,
+    /* tt */ Some(&L98),
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&L99),
+    /* uk */ Some(&L100),
+    /* ur */ Some(&L101),
+    /* uz */ Some(&L102),
+    /* ve */ None,
+    /* vi */ Some(&L103),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&L104),
+    /* wo */ Some(&L105),
+    /* fy */ Some(&L106),
+    /* xh */ Some(&L107),
+    /* yi */ None,
+    /* yo */ Some(&L108),
+    /* za */ None,
+    /* zu */ Some(&L109),
+];
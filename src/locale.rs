@@ -0,0 +1,285 @@
+//! Language tags with a script and a region, and their likely subtags from the Unicode CLDR.
+//!
+//! A tag is maximized by adding the script and region it most likely refers to,
+//! and minimized by removing the subtags that can be deduced from the others:
+//! ```rust
+//! # use rocket_lang::{*, locale::Locale};
+//! let taiwan: Locale = "zh-TW".parse().unwrap();
+//! assert_eq!(taiwan.maximize().to_string(), "zh-Hant-TW");
+//!
+//! let serbian: Locale = "sr".parse().unwrap();
+//! assert_eq!(serbian.maximize().to_string(), "sr-Cyrl-RS");
+//!
+//! let english: Locale = "en-Latn-US".parse().unwrap();
+//! assert_eq!(english.minimize().to_string(), "en");
+//! ```
+//! The likely subtags are also used in the negotiation, so a client asking
+//! for `zh-TW` gets the quality set for `zh-Hant` in the `Config`.
+//! The likely subtags are generated from the CLDR by `tools/cldr`.
+//!
+//! A `Locale` is also a request guard, holding the negotiated language with the tag
+//! the client requested it with, so `pt-BR` content can be told from `pt-PT`:
+#![cfg_attr(feature = "rocket", doc = "```rust")]
+#![cfg_attr(not(feature = "rocket"), doc = "```ignore")]
+//! # use rocket::get;
+//! # use rocket_lang::locale::Locale;
+//! #[get("/")]
+//! fn index(locale: Locale) -> String {
+//!     format!("{} in {:?}", locale.lang(), locale.region())
+//! }
+//! ```
+#[cfg(feature = "rocket")]
+use crate::rocket_impl::PrivConfig;
+use crate::*;
+
+#[rustfmt::skip]
+mod data;
+
+/// A language tag, such as `zh-Hant-TW`, with an optional script and region.
+/// Scripts are written in title case, and regions in upper case.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    lang: LangCode,
+    script: Option<String>,
+    region: Option<String>,
+}
+
+impl Locale {
+    /// Returns the tag of a language, without a script or region.
+    pub fn new(lang: LangCode) -> Self {
+        Locale {
+            lang,
+            script: None,
+            region: None,
+        }
+    }
+
+    /// Returns the language of the tag.
+    pub fn lang(&self) -> LangCode {
+        self.lang
+    }
+
+    /// Returns the script of the tag, such as `Hant`.
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    /// Returns the region of the tag, such as `TW`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// Returns true if the tag has neither a script nor a region.
+    pub fn is_lang(&self) -> bool {
+        self.script.is_none() && self.region.is_none()
+    }
+
+    /// Adds the likely script and region of the tag.
+    /// ```rust
+    /// # use rocket_lang::{*, locale::Locale};
+    /// let tag: Locale = "sr-ME".parse().unwrap();
+    /// assert_eq!(tag.maximize().to_string(), "sr-Latn-ME");
+    /// ```
+    pub fn maximize(&self) -> Locale {
+        let lang = self.lang;
+        let script = self
+            .script
+            .clone()
+            .or_else(|| {
+                let region = self.region.as_deref()?;
+                region_script(lang, region).map(str::to_string)
+            })
+            .unwrap_or_else(|| {
+                lang.likely_script()
+                    .to_string()
+            });
+        let region = self
+            .region
+            .clone()
+            .or_else(|| script_region(lang, &script).map(str::to_string))
+            .unwrap_or_else(|| {
+                lang.likely_region()
+                    .to_string()
+            });
+        Locale {
+            lang,
+            script: Some(script),
+            region: Some(region),
+        }
+    }
+
+    /// Removes the script and region of the tag when they are the likely ones.
+    /// The region is kept rather than the script, when one of them is enough.
+    /// ```rust
+    /// # use rocket_lang::{*, locale::Locale};
+    /// let tag: Locale = "zh-Hant-TW".parse().unwrap();
+    /// assert_eq!(tag.minimize().to_string(), "zh-TW");
+    /// ```
+    pub fn minimize(&self) -> Locale {
+        let max = self.maximize();
+        let lang = Locale::new(self.lang);
+        let region = Locale {
+            region: max.region.clone(),
+            ..Locale::new(self.lang)
+        };
+        let script = Locale {
+            script: max.script.clone(),
+            ..Locale::new(self.lang)
+        };
+        [lang, region, script]
+            .into_iter()
+            .find(|tag| tag.maximize() == max)
+            .unwrap_or(max)
+    }
+
+    /// Returns the tags to look up for this one, from the most to the least specific:
+    /// its maximized tag, then its likely region, and then its likely script.
+    pub(crate) fn fallbacks(&self) -> [Locale; 3] {
+        let max = self.maximize();
+        let region = Locale {
+            region: max.region.clone(),
+            ..Locale::new(self.lang)
+        };
+        let script = Locale {
+            script: max.script.clone(),
+            ..Locale::new(self.lang)
+        };
+        [max, region, script]
+    }
+}
+
+impl From<LangCode> for Locale {
+    fn from(lang: LangCode) -> Self {
+        Locale::new(lang)
+    }
+}
+
+/// Parses a tag such as `zh-Hant-TW`, `pt_BR` or `sr-latn`.
+/// The script is made of 4 letters, and the region of 2 letters or 3 digits.
+impl FromStr for Locale {
+    type Err = Error;
+    fn from_str(tag: &str) -> Result<Self, Error> {
+        let mut parts = tag.split(['-', '_']);
+        let lang = parts
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+            .parse()?;
+        let mut locale = Locale::new(lang);
+        let mut next = parts.next();
+        if let Some(script) = next.filter(|part| is_script(part)) {
+            let (first, rest) = script.split_at(1);
+            locale.script = Some(first.to_uppercase() + &rest.to_lowercase());
+            next = parts.next();
+        }
+        if let Some(region) = next.filter(|part| is_region(part)) {
+            locale.region = Some(region.to_uppercase());
+            next = parts.next();
+        }
+        match next {
+            Some(_) => Err(Error::NotAcceptable),
+            None => Ok(locale),
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lang)?;
+        if let Some(script) = &self.script {
+            write!(f, "-{script}")?;
+        }
+        if let Some(region) = &self.region {
+            write!(f, "-{region}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The negotiated language, with the tag the client requested it with.
+/// It fails like the `LangCode` guard.
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Locale {
+    type Error = Error;
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let lang = match LangCode::from_request(request).await {
+            Outcome::Success(lang) => lang,
+            Outcome::Failure(failure) => return Outcome::Failure(failure),
+            Outcome::Forward(forward) => return Outcome::Forward(forward),
+        };
+        let config = request
+            .rocket()
+            .state::<PrivConfig>()
+            .map(|config| config.0.for_request(request));
+        Outcome::Success(accept_language::locale(request, config, lang))
+    }
+}
+
+fn is_script(part: &str) -> bool {
+    part.len() == 4
+        && part
+            .chars()
+            .all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_region(part: &str) -> bool {
+    match part.len() {
+        2 => part
+            .chars()
+            .all(|c| c.is_ascii_alphabetic()),
+        3 => part
+            .chars()
+            .all(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+impl LangCode {
+    /// Returns the script the language is most likely written in.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert_eq!(Zh.likely_script(), "Hans");
+    /// assert_eq!(Sr.likely_script(), "Cyrl");
+    /// ```
+    pub fn likely_script(self) -> &'static str {
+        likely_subtags(self).0
+    }
+
+    /// Returns the region the language is most likely spoken in.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert_eq!(En.likely_region(), "US");
+    /// assert_eq!(Pt.likely_region(), "BR");
+    /// ```
+    pub fn likely_region(self) -> &'static str {
+        likely_subtags(self).1
+    }
+}
+
+/// The likely script of a language in a region, when it is not the script of the language.
+fn region_script(lang: LangCode, region: &str) -> Option<&'static str> {
+    lookup(data::REGION_SCRIPTS, lang, region)
+}
+
+/// The likely region of a language in a script, when it is not the region of the language.
+fn script_region(lang: LangCode, script: &str) -> Option<&'static str> {
+    lookup(data::SCRIPT_REGIONS, lang, script)
+}
+
+/// Finds the value of a language and subtag in a table sorted by language and subtag.
+fn lookup(
+    table: &[(LangCode, &str, &'static str)],
+    lang: LangCode,
+    subtag: &str,
+) -> Option<&'static str> {
+    table
+        .binary_search_by(|(l, s, _)| (*l as usize, *s).cmp(&(lang as usize, subtag)))
+        .ok()
+        .map(|i| table[i].2)
+}
+
+/// The likely script and region of a language.
+fn likely_subtags(lang: LangCode) -> (&'static str, &'static str) {
+    data::LIKELY_SUBTAGS[lang as usize]
+}
@@ -0,0 +1,272 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use crate::LangCode::{self, *};
+
+/// The likely script and region of every language, indexed by the `LangCode` discriminant.
+pub(super) static LIKELY_SUBTAGS: [(&str, &str); 184] = [
+    /* aa */ ("Latn", "ET"),
+    /* ab */ ("Cyrl", "GE"),
+    /* af */ ("Latn", "ZA"),
+    /* ak */ ("Latn", "GH"),
+    /* sq */ ("Latn", "AL"),
+    /* am */ ("Ethi", "ET"),
+    /* ar */ ("Arab", "EG"),
+    /* an */ ("Latn", "ES"),
+    /* hy */ ("Armn", "AM"),
+    /* as */ ("Beng", "IN"),
+    /* av */ ("Cyrl", "RU"),
+    /* ae */ ("Avst", "IR"),
+    /* ay */ ("Latn", "BO"),
+    /* az */ ("Latn", "AZ"),
+    /* bm */ ("Latn", "ML"),
+    /* ba */ ("Cyrl", "RU"),
+    /* eu */ ("Latn", "ES"),
+    /* be */ ("Cyrl", "BY"),
+    /* bn */ ("Beng", "BD"),
+    /* bh */ ("Latn", "US"),
+    /* bi */ ("Latn", "VU"),
+    /* bs */ ("Latn", "BA"),
+    /* br */ ("Latn", "FR"),
+    /* bg */ ("Cyrl", "BG"),
+    /* my */ ("Mymr", "MM"),
+    /* ca */ ("Latn", "ES"),
+    /* ch */ ("Latn", "GU"),
+    /* ce */ ("Cyrl", "RU"),
+    /* ny */ ("Latn", "MW"),
+    /* zh */ ("Hans", "CN"),
+    /* cv */ ("Cyrl", "RU"),
+    /* kw */ ("Latn", "GB"),
+    /* co */ ("Latn", "FR"),
+    /* cr */ ("Cans", "CA"),
+    /* hr */ ("Latn", "HR"),
+    /* cs */ ("Latn", "CZ"),
+    /* da */ ("Latn", "DK"),
+    /* dv */ ("Thaa", "MV"),
+    /* nl */ ("Latn", "NL"),
+    /* dz */ ("Tibt", "BT"),
+    /* en */ ("Latn", "US"),
+    /* eo */ ("Latn", "001"),
+    /* et */ ("Latn", "EE"),
+    /* ee */ ("Latn", "GH"),
+    /* fo */ ("Latn", "FO"),
+    /* fj */ ("Latn", "FJ"),
+    /* fi */ ("Latn", "FI"),
+    /* fr */ ("Latn", "FR"),
+    /* ff */ ("Latn", "SN"),
+    /* gl */ ("Latn", "ES"),
+    /* ka */ ("Geor", "GE"),
+    /* de */ ("Latn", "DE"),
+    /* el */ ("Grek", "GR"),
+    /* gn */ ("Latn", "PY"),
+    /* gu */ ("Gujr", "IN"),
+    /* ht */ ("Latn", "HT"),
+    /* ha */ ("Latn", "NG"),
+    /* he */ ("Hebr", "IL"),
+    /* hz */ ("Latn", "NA"),
+    /* hi */ ("Deva", "IN"),
+    /* ho */ ("Latn", "PG"),
+    /* hu */ ("Latn", "HU"),
+    /* ia */ ("Latn", "001"),
+    /* id */ ("Latn", "ID"),
+    /* ie */ ("Latn", "EE"),
+    /* ga */ ("Latn", "IE"),
+    /* ig */ ("Latn", "NG"),
+    /* ik */ ("Latn", "US"),
+    /* io */ ("Latn", "001"),
+    /* is */ ("Latn", "IS"),
+    /* it */ ("Latn", "IT"),
+    /* iu */ ("Cans", "CA"),
+    /* ja */ ("Jpan", "JP"),
+    /* jv */ ("Latn", "ID"),
+    /* kl */ ("Latn", "GL"),
+    /* kn */ ("Knda", "IN"),
+    /* kr */ ("Latn", "NG"),
+    /* ks */ ("Arab", "IN"),
+    /* kk */ ("Cyrl", "KZ"),
+    /* km */ ("Khmr", "KH"),
+    /* ki */ ("Latn", "KE"),
+    /* rw */ ("Latn", "RW"),
+    /* ky */ ("Cyrl", "KG"),
+    /* kv */ ("Cyrl", "RU"),
+    /* kg */ ("Latn", "CD"),
+    /* ko */ ("Kore", "KR"),
+    /* ku */ ("Latn", "TR"),
+    /* kj */ ("Latn", "NA"),
+    /* la */ ("Latn", "VA"),
+    /* lb */ ("Latn", "LU"),
+    /* lg */ ("Latn", "UG"),
+    /* li */ ("Latn", "NL"),
+    /* ln */ ("Latn", "CD"),
+    /* lo */ ("Laoo", "LA"),
+    /* lt */ ("Latn", "LT"),
+    /* lu */ ("Latn", "CD"),
+    /* lv */ ("Latn", "LV"),
+    /* gv */ ("Latn", "IM"),
+    /* mk */ ("Cyrl", "MK"),
+    /* mg */ ("Latn", "MG"),
+    /* ms */ ("Latn", "MY"),
+    /* ml */ ("Mlym", "IN"),
+    /* mt */ ("Latn", "MT"),
+    /* mi */ ("Latn", "NZ"),
+    /* mr */ ("Deva", "IN"),
+    /* mh */ ("Latn", "MH"),
+    /* mn */ ("Cyrl", "MN"),
+    /* na */ ("Latn", "NR"),
+    /* nv */ ("Latn", "US"),
+    /* nd */ ("Latn", "ZW"),
+    /* ne */ ("Deva", "NP"),
+    /* ng */ ("Latn", "NA"),
+    /* nb */ ("Latn", "NO"),
+    /* nn */ ("Latn", "NO"),
+    /* no */ ("Latn", "NO"),
+    /* ii */ ("Yiii", "CN"),
+    /* nr */ ("Latn", "ZA"),
+    /* oc */ ("Latn", "FR"),
+    /* oj */ ("Cans", "CA"),
+    /* cu */ ("Cyrl", "RU"),
+    /* om */ ("Latn", "ET"),
+    /* or */ ("Orya", "IN"),
+    /* os */ ("Cyrl", "GE"),
+    /* pa */ ("Guru", "IN"),
+    /* pi */ ("Latn", "GB"),
+    /* fa */ ("Arab", "IR"),
+    /* pl */ ("Latn", "PL"),
+    /* ps */ ("Arab", "AF"),
+    /* pt */ ("Latn", "BR"),
+    /* qu */ ("Latn", "PE"),
+    /* rm */ ("Latn", "CH"),
+    /* rn */ ("Latn", "BI"),
+    /* ro */ ("Latn", "RO"),
+    /* ru */ ("Cyrl", "RU"),
+    /* sa */ ("Deva", "IN"),
+    /* sc */ ("Latn", "IT"),
+    /* sd */ ("Arab", "PK"),
+    /* se */ ("Latn", "NO"),
+    /* sm */ ("Latn", "WS"),
+    /* sg */ ("Latn", "CF"),
+    /* sr */ ("Cyrl", "RS"),
+    /* gd */ ("Latn", "GB"),
+    /* sn */ ("Latn", "ZW"),
+    /* si */ ("Sinh", "LK"),
+    /* sk */ ("Latn", "SK"),
+    /* sl */ ("Latn", "SI"),
+    /* so */ ("Latn", "SO"),
+    /* st */ ("Latn", "ZA"),
+    /* es */ ("Latn", "ES"),
+    /* su */ ("Latn", "ID"),
+    /* sw */ ("Latn", "TZ"),
+    /* ss */ ("Latn", "ZA"),
+    /* sv */ ("Latn", "SE"),
+    /* ta */ ("Taml", "IN"),
+    /* te */ ("Telu", "IN"),
+    /* tg */ ("Cyrl", "TJ"),
+    /* th */ ("Thai", "TH"),
+    /* ti */ ("Ethi", "ET"),
+    /* bo */ ("Tibt", "CN"),
+    /* tk */ ("Latn", "TM"),
+    /* tl */ ("Latn", "PH"),
+    /* tn */ ("Latn", "ZA"),
+    /* to */ ("Latn", "TO"),
+    /* tr */ ("Latn", "TR"),
+    /* ts */ ("Latn", "ZA"),
+    /* tt */ ("Cyrl", "RU"),
+    /* tw */ ("Latn", "US"),
+    /* ty */ ("Latn", "PF"),
+    /* ug */ ("Arab", "CN"),
+    /* uk */ ("Cyrl", "UA"),
+    /* ur */ ("Arab", "PK"),
+    /* uz */ ("Latn", "UZ"),
+    /* ve */ ("Latn", "ZA"),
+    /* vi */ ("Latn", "VN"),
+    /* vo */ ("Latn", "001"),
+    /* wa */ ("Latn", "BE"),
+    /* cy */ ("Latn", "GB"),
+    /* wo */ ("Latn", "SN"),
+    /* fy */ ("Latn", "NL"),
+    /* xh */ ("Latn", "ZA"),
+    /* yi */ ("Hebr", "UA"),
+    /* yo */ ("Latn", "NG"),
+    /* za */ ("Latn", "CN"),
+    /* zu */ ("Latn", "ZA"),
+];
+
+/// The likely script of a language in a region, when it is not the script of
+/// the language, sorted by language and region.
+pub(super) static REGION_SCRIPTS: &[(LangCode, &str, &str)] = &[
+    (Az, "IQ", "Arab"),
+    (Az, "IR", "Arab"),
+    (Az, "RU", "Cyrl"),
+    (Zh, "BN", "Hant"),
+    (Zh, "GB", "Hant"),
+    (Zh, "GF", "Hant"),
+    (Zh, "HK", "Hant"),
+    (Zh, "ID", "Hant"),
+    (Zh, "MO", "Hant"),
+    (Zh, "PA", "Hant"),
+    (Zh, "PF", "Hant"),
+    (Zh, "PH", "Hant"),
+    (Zh, "SR", "Hant"),
+    (Zh, "TH", "Hant"),
+    (Zh, "TW", "Hant"),
+    (Zh, "US", "Hant"),
+    (Zh, "VN", "Hant"),
+    (Ha, "CM", "Arab"),
+    (Ha, "SD", "Arab"),
+    (Kk, "AF", "Arab"),
+    (Kk, "CN", "Arab"),
+    (Kk, "IR", "Arab"),
+    (Kk, "MN", "Arab"),
+    (Ky, "CN", "Arab"),
+    (Ky, "TR", "Latn"),
+    (Ku, "AM", "Cyrl"),
+    (Ku, "AZ", "Cyrl"),
+    (Ku, "GE", "Cyrl"),
+    (Ku, "IQ", "Arab"),
+    (Ku, "IR", "Arab"),
+    (Ku, "LB", "Arab"),
+    (Ku, "TM", "Cyrl"),
+    (Ms, "CC", "Arab"),
+    (Mn, "CN", "Mong"),
+    (Pa, "PK", "Arab"),
+    (Pi, "IN", "Deva"),
+    (Pi, "LK", "Sinh"),
+    (Pi, "MM", "Mymr"),
+    (Pi, "TH", "Thai"),
+    (Sd, "IN", "Deva"),
+    (Sr, "ME", "Latn"),
+    (Sr, "RO", "Latn"),
+    (Sr, "TR", "Latn"),
+    (Tg, "PK", "Arab"),
+    (Ug, "KZ", "Cyrl"),
+    (Ug, "MN", "Cyrl"),
+    (Uz, "AF", "Arab"),
+    (Uz, "CN", "Cyrl"),
+];
+
+/// The likely region of a language in a script, when it is not the region of
+/// the language, sorted by language and script.
+pub(super) static SCRIPT_REGIONS: &[(LangCode, &str, &str)] = &[
+    (Az, "Arab", "IR"),
+    (Zh, "Bopo", "TW"),
+    (Zh, "Hanb", "TW"),
+    (Zh, "Hant", "TW"),
+    (En, "Shaw", "GB"),
+    (Ff, "Adlm", "GN"),
+    (Kk, "Arab", "CN"),
+    (Ky, "Arab", "CN"),
+    (Ku, "Arab", "IQ"),
+    (Ku, "Cyrl", "AM"),
+    (Ku, "Yezi", "GE"),
+    (Mn, "Mong", "CN"),
+    (Cu, "Glag", "BG"),
+    (Pa, "Arab", "PK"),
+    (Pi, "Deva", "IN"),
+    (Pi, "Mymr", "MM"),
+    (Pi, "Sinh", "LK"),
+    (Pi, "Thai", "TH"),
+    (Sd, "Deva", "IN"),
+    (Sd, "Khoj", "IN"),
+    (Sd, "Sind", "IN"),
+    (Tg, "Arab", "PK"),
+    (Ug, "Cyrl", "KZ"),
+    (Uz, "Arab", "AF"),
+];
@@ -0,0 +1,152 @@
+//! `actix-web` middleware.
+//!
+//! `LangMiddleware` makes the `Config` available to every request. As with the rocket
+//! fairing, the language is resolved the first time it is requested, and the response gets
+//! a `Content-Language` header if it was. The resolver set with `Config::resolver` is used,
+//! while the `custom` closures are only supported by rocket.
+//! ```rust
+//! # use rocket_lang::{*, middleware::LangMiddleware};
+//! # use actix_web::{web, App, HttpResponse};
+//! async fn index(lang: LangCode) -> &'static str {
+//!     lang.as_str()
+//! }
+//!
+//! let app = App::new()
+//!     .wrap(LangMiddleware::new(Config::new().supported(&[En, Es])))
+//!     .route("/", web::get().to(index));
+//! ```
+//! Errors are responded with their status code, and an empty body.
+use crate::{
+    source::{find_cookie, LangSource, Negotiation},
+    *,
+};
+use actix_web::{
+    body::BoxBody,
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    http::{
+        header::{HeaderValue, ACCEPT_LANGUAGE, CONTENT_LANGUAGE, COOKIE},
+        StatusCode,
+    },
+    FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError,
+};
+use std::{
+    future::{ready, Future, Ready},
+    pin::Pin,
+    sync::Arc,
+};
+
+/// A middleware that negotiates the language of each request with a `Config`.
+#[derive(Clone)]
+pub struct LangMiddleware {
+    config: Arc<Config>,
+}
+
+impl LangMiddleware {
+    /// Constructs a middleware with the given configuration.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for LangMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = LangService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, inner: S) -> Self::Future {
+        ready(Ok(LangService {
+            inner,
+            config: self.config.clone(),
+        }))
+    }
+}
+
+/// The service produced by `LangMiddleware`.
+pub struct LangService<S> {
+    inner: S,
+    config: Arc<Config>,
+}
+
+impl<S, B> Service<ServiceRequest> for LangService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(inner);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let negotiation = Negotiation::new(self.config.clone());
+        req.extensions_mut()
+            .insert(negotiation.clone());
+        let response = self.inner.call(req);
+        Box::pin(async move {
+            let mut response = response.await?;
+            let headers = response.headers_mut();
+            if let Some(lang) = negotiation.resolved() {
+                if !headers.contains_key(CONTENT_LANGUAGE) {
+                    headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static(lang.as_str()));
+                }
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Resolves the language of a request that went through `LangMiddleware`.
+/// It returns `Error::Unconfigured` if the middleware is missing.
+pub fn resolve(req: &HttpRequest) -> Result<LangCode, Error> {
+    let negotiation = req
+        .extensions()
+        .get::<Negotiation>()
+        .cloned()
+        .ok_or(Error::Unconfigured)?;
+    negotiation.resolve(req)
+}
+
+impl LangSource for HttpRequest {
+    fn path(&self) -> &str {
+        HttpRequest::path(self)
+    }
+    fn accept_language(&self) -> Option<&str> {
+        self.headers()
+            .get(ACCEPT_LANGUAGE)?
+            .to_str()
+            .ok()
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        self.headers()
+            .get_all(COOKIE)
+            .filter_map(|header| header.to_str().ok())
+            .find_map(|header| find_cookie(header, name))
+    }
+}
+
+impl FromRequest for LangCode {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(resolve(req))
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(Error::status_code(self)).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        HttpResponse::new(ResponseError::status_code(self))
+    }
+}
@@ -0,0 +1,102 @@
+//! Strategies for choosing a language from the `Accept-Language` header.
+//!
+//! The strategy receives the languages of the header that the server supports, with the
+//! quality the client requested them with and the quality set in the `Config`.
+//! It can be selected with `Config::strategy`:
+//! ```rust
+//! # use rocket_lang::{*, negotiation::ClientFirst};
+//! let mut config = Config::new().strategy(ClientFirst);
+//! config[En] = 1.0;
+//! config[Es] = 0.5;
+//! ```
+//! Except for `Legacy`, the built-in strategies ignore languages the client rejected
+//! with a quality of 0, and don't depend on the order of the header. Complete ties
+//! are broken in favor of the language that comes first in `LangCode::ALL_CODES`.
+use crate::*;
+
+/// A language the client requested, that the server supports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate {
+    /// The language code.
+    pub lang: LangCode,
+    /// The quality of the language in the `Accept-Language` header.
+    pub client: f32,
+    /// The quality of the language in the `Config`.
+    pub server: f32,
+}
+
+/// Chooses the language to respond with.
+pub trait NegotiationStrategy: Send + Sync {
+    /// Returns the chosen language, or `None` if none of the candidates are acceptable.
+    /// The candidates are in the order of the header, and all of them have a
+    /// positive server quality.
+    fn negotiate(&self, candidates: &[Candidate]) -> Option<LangCode>;
+}
+
+/// Chooses the language with the highest product of the client and server qualities,
+/// as Apache's content negotiation does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Product;
+
+/// Chooses the language the client prefers. Ties are broken with the server quality.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClientFirst;
+
+/// Chooses the language the server supports best. Ties are broken with the client quality.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerFirst;
+
+/// The strategy used before strategies were configurable, and still the default.
+/// A candidate replaces the previous choice if the relative increase in client quality
+/// is larger than the relative decrease in server quality, so the result may depend
+/// on the order of the header.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Legacy;
+
+/// Returns the acceptable candidate with the largest key.
+fn best_by(candidates: &[Candidate], key: impl Fn(&Candidate) -> (f32, f32)) -> Option<LangCode> {
+    candidates
+        .iter()
+        .filter(|candidate| candidate.client > 0.0)
+        .max_by(|a, b| {
+            let (a1, a2) = key(a);
+            let (b1, b2) = key(b);
+            a1.total_cmp(&b1)
+                .then(a2.total_cmp(&b2))
+                .then(b.lang.cmp(&a.lang))
+        })
+        .map(|candidate| candidate.lang)
+}
+
+impl NegotiationStrategy for Product {
+    fn negotiate(&self, candidates: &[Candidate]) -> Option<LangCode> {
+        best_by(candidates, |c| (c.client * c.server, 0.0))
+    }
+}
+
+impl NegotiationStrategy for ClientFirst {
+    fn negotiate(&self, candidates: &[Candidate]) -> Option<LangCode> {
+        best_by(candidates, |c| (c.client, c.server))
+    }
+}
+
+impl NegotiationStrategy for ServerFirst {
+    fn negotiate(&self, candidates: &[Candidate]) -> Option<LangCode> {
+        best_by(candidates, |c| (c.server, c.client))
+    }
+}
+
+impl NegotiationStrategy for Legacy {
+    fn negotiate(&self, candidates: &[Candidate]) -> Option<LangCode> {
+        let (first, rest) = candidates.split_first()?;
+        let mut best = first;
+        for candidate in rest {
+            if (best.server - candidate.server) / best.server
+                < (candidate.client - best.client) / candidate.client
+            {
+                best = candidate;
+            }
+        }
+        Some(best.lang)
+    }
+}
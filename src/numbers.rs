@@ -0,0 +1,375 @@
+//! Locale-aware number formatting, with data from the Unicode CLDR.
+//!
+//! `format_number` writes a number the way readers of a language expect it:
+//! ```rust
+//! # use rocket_lang::{*, numbers::format_number};
+//! assert_eq!(format_number(En, 1234567.891), "1,234,567.891");
+//! assert_eq!(format_number(De, 1234567.891), "1.234.567,891");
+//! assert_eq!(format_number(Hi, 1234567.891), "12,34,567.891");
+//! assert_eq!(format_number(Ar, 1234.5), "1,234.5");
+//! ```
+//! A `NumberFormat` exposes the data behind it, and can be adjusted for a region
+//! or switched to the native digits of the language:
+//! ```rust
+//! # use rocket_lang::{*, numbers::NumberFormat};
+//! let swiss = NumberFormat::with_region(De, "CH");
+//! assert_eq!(swiss.format(1234567.5), "1'234'567.5");
+//!
+//! let hindi = NumberFormat::new(Hi).native();
+//! assert_eq!(hindi.format(1234567.0), "१२,३४,५६७");
+//!
+//! let egyptian = NumberFormat::with_region(Ar, "EG");
+//! assert_eq!(egyptian.format(1234.5), "١٬٢٣٤٫٥");
+//! ```
+//! The data of every language and region is generated from CLDR by `tools/cldr`.
+//! Languages without CLDR data of their own use the root locale, as in `1,234.5`.
+//! Numbers are rounded to the fraction digits of the pattern, which is 3 for decimals,
+//! and 0 for percents.
+use crate::*;
+
+/// The digits a number is written with.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Digits {
+    /// `0123456789`
+    Latin,
+    /// `٠١٢٣٤٥٦٧٨٩`
+    ArabicIndic,
+    /// `۰۱۲۳۴۵۶۷۸۹`, used for Persian, Pashto and Urdu.
+    ExtendedArabicIndic,
+    /// `০১২৩৪৫৬৭৮৯`
+    Bengali,
+    /// `०१२३४५६७८९`
+    Devanagari,
+    /// `૦૧૨૩૪૫૬૭૮૯`
+    Gujarati,
+    /// `੦੧੨੩੪੫੬੭੮੯`
+    Gurmukhi,
+    /// `೦೧೨೩೪೫೬೭೮೯`
+    Kannada,
+    /// `០១២៣៤៥៦៧៨៩`
+    Khmer,
+    /// `໐໑໒໓໔໕໖໗໘໙`
+    Lao,
+    /// `൦൧൨൩൪൫൬൭൮൯`
+    Malayalam,
+    /// `၀၁၂၃၄၅၆၇၈၉`
+    Myanmar,
+    /// `୦୧୨୩୪୫୬୭୮୯`
+    Odia,
+    /// `௦௧௨௩௪௫௬௭௮௯`
+    Tamil,
+    /// `౦౧౨౩౪౫౬౭౮౯`
+    Telugu,
+    /// `๐๑๒๓๔๕๖๗๘๙`
+    Thai,
+    /// `༠༡༢༣༤༥༦༧༨༩`
+    Tibetan,
+}
+
+impl Digits {
+    /// Returns the digit for zero. The other digits follow it.
+    /// ```rust
+    /// # use rocket_lang::numbers::Digits;
+    /// assert_eq!(Digits::Devanagari.zero(), '०');
+    /// ```
+    pub fn zero(self) -> char {
+        match self {
+            Digits::Latin => '0',
+            Digits::ArabicIndic => '\u{660}',
+            Digits::ExtendedArabicIndic => '\u{6F0}',
+            Digits::Bengali => '\u{9E6}',
+            Digits::Devanagari => '\u{966}',
+            Digits::Gujarati => '\u{AE6}',
+            Digits::Gurmukhi => '\u{A66}',
+            Digits::Kannada => '\u{CE6}',
+            Digits::Khmer => '\u{17E0}',
+            Digits::Lao => '\u{ED0}',
+            Digits::Malayalam => '\u{D66}',
+            Digits::Myanmar => '\u{1040}',
+            Digits::Odia => '\u{B66}',
+            Digits::Tamil => '\u{BE6}',
+            Digits::Telugu => '\u{C66}',
+            Digits::Thai => '\u{E50}',
+            Digits::Tibetan => '\u{F20}',
+        }
+    }
+
+    /// Replaces the ascii digits of a text.
+    pub(crate) fn convert(self, text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '0'..='9' => {
+                    char::from_u32(self.zero() as u32 + (c as u32 - '0' as u32)).unwrap_or(c)
+                }
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+/// The CLDR number-formatting data of a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// The decimal separator.
+    pub decimal: &'static str,
+    /// The grouping separator.
+    pub group: &'static str,
+    /// The percent sign.
+    pub percent_sign: &'static str,
+    /// The minus sign.
+    pub minus_sign: &'static str,
+    /// The decimal pattern, such as `#,##0.###`.
+    pub pattern: &'static str,
+    /// The percent pattern, such as `#,##0%`.
+    pub percent_pattern: &'static str,
+    /// The minimum number of digits in the highest group for grouping to be used.
+    /// With 2, `1234` is not grouped, but `12,345` is.
+    pub min_grouping: usize,
+    /// The digits numbers are written with.
+    pub digits: Digits,
+    /// The native digits of the language.
+    pub native_digits: Digits,
+}
+
+#[rustfmt::skip]
+mod data;
+
+impl NumberFormat {
+    /// Returns the number format of a language.
+    pub fn new(lang: LangCode) -> Self {
+        data::LANGUAGES[lang as usize]
+    }
+
+    /// Returns the number format of a language in a region, such as `CH` or `IN`.
+    /// Regions without differences of their own use the format of the language.
+    /// ```rust
+    /// # use rocket_lang::{*, numbers::NumberFormat};
+    /// assert_eq!(NumberFormat::with_region(En, "IN").format(1234567.0), "12,34,567");
+    /// assert_eq!(NumberFormat::with_region(Es, "MX").format(1234.5), "1,234.5");
+    /// ```
+    pub fn with_region(lang: LangCode, region: &str) -> Self {
+        let region = region.to_uppercase();
+        data::REGIONS
+            .iter()
+            .find(|(l, r, _)| *l == lang && *r == region)
+            .map_or_else(|| Self::new(lang), |&(_, _, format)| format)
+    }
+
+    /// Returns the same format, written with the native digits of the language.
+    /// When the digits change, so do the separators and minus sign, if CLDR has
+    /// symbols for the native digits.
+    /// ```rust
+    /// # use rocket_lang::{*, numbers::NumberFormat};
+    /// let arabic = NumberFormat::new(Ar).native();
+    /// assert_eq!(arabic.format(-1234.5), "\u{61C}-١٬٢٣٤٫٥");
+    /// ```
+    pub fn native(self) -> Self {
+        if self.digits == self.native_digits {
+            return self;
+        }
+        let format = Self {
+            digits: self.native_digits,
+            ..self
+        };
+        match data::SYMBOLS
+            .iter()
+            .find(|(digits, ..)| *digits == self.native_digits)
+        {
+            Some(&(_, decimal, group, minus_sign)) => Self {
+                decimal,
+                group,
+                minus_sign,
+                ..format
+            },
+            None => format,
+        }
+    }
+
+    /// Returns the sizes of the lowest group of digits, and of the groups above it.
+    /// ```rust
+    /// # use rocket_lang::{*, numbers::NumberFormat};
+    /// assert_eq!(NumberFormat::new(En).grouping(), (3, 3));
+    /// assert_eq!(NumberFormat::new(Hi).grouping(), (3, 2));
+    /// ```
+    pub fn grouping(&self) -> (usize, usize) {
+        Pattern::parse(self.pattern).grouping
+    }
+
+    /// Formats a number with the decimal pattern.
+    pub fn format(&self, value: f64) -> String {
+        self.apply(self.pattern, value, &Options::default())
+    }
+
+    /// Formats a ratio as a percentage, so `0.25` is formatted as `25%`.
+    /// ```rust
+    /// # use rocket_lang::{*, numbers::NumberFormat};
+    /// assert_eq!(NumberFormat::new(Tr).format_percent(0.25), "%25");
+    /// assert_eq!(NumberFormat::new(De).format_percent(0.25), "25\u{A0}%");
+    /// ```
+    pub fn format_percent(&self, value: f64) -> String {
+        self.apply(self.percent_pattern, value * 100.0, &Options::default())
+    }
+
+    /// Formats a number with a CLDR pattern, which may have a negative subpattern
+    /// after a `;`. In the affixes, `%`, `-` and `¤` are replaced by their symbols.
+    pub(crate) fn apply(&self, pattern: &str, value: f64, options: &Options) -> String {
+        let (positive, negative) = match pattern.split_once(';') {
+            Some((positive, negative)) => (positive, Some(negative)),
+            None => (pattern, None),
+        };
+        let mut pattern = Pattern::parse(positive);
+        if let Some(digits) = options.fraction_digits {
+            pattern.min_fraction = digits;
+            pattern.max_fraction = digits;
+        }
+        let number = if value.is_nan() {
+            "NaN".to_string()
+        } else if value.is_infinite() {
+            "∞".to_string()
+        } else {
+            self.digits(&pattern, value.abs())
+        };
+        // values rounded to zero are written without a sign.
+        let negative =
+            (value < 0.0 && number.contains(|c| matches!(c, '1'..='9' | '∞'))).then(|| {
+                match negative {
+                    Some(negative) => {
+                        let negative = Pattern::parse(negative);
+                        (negative.prefix.to_string(), negative.suffix)
+                    }
+                    None => (format!("-{}", pattern.prefix), pattern.suffix),
+                }
+            });
+        let (prefix, suffix) = negative.unwrap_or((pattern.prefix.to_string(), pattern.suffix));
+        let number = format!(
+            "{}{}{}",
+            self.affix(&prefix, options),
+            number,
+            self.affix(suffix, options)
+        );
+        self.digits.convert(&number)
+    }
+
+    /// Replaces the symbols of an affix.
+    fn affix(&self, text: &str, options: &Options) -> String {
+        let mut out = String::new();
+        for c in text.chars() {
+            match c {
+                '%' => out.push_str(self.percent_sign),
+                '-' => out.push_str(self.minus_sign),
+                '¤' => out.push_str(options.currency),
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// Rounds and groups the absolute value, with ascii digits.
+    fn digits(&self, pattern: &Pattern, value: f64) -> String {
+        let text = format!("{value:.*}", pattern.max_fraction);
+        let (integer, fraction) = text
+            .split_once('.')
+            .unwrap_or((&text, ""));
+        let mut fraction = fraction.to_string();
+        while fraction.len() > pattern.min_fraction && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        let (primary, secondary) = pattern.grouping;
+        let mut groups = vec![];
+        let mut rest = integer;
+        if primary > 0 && integer.len() >= primary + self.min_grouping {
+            let (head, tail) = rest.split_at(rest.len() - primary);
+            groups.push(tail);
+            rest = head;
+            while rest.len() > secondary {
+                let (head, tail) = rest.split_at(rest.len() - secondary);
+                groups.push(tail);
+                rest = head;
+            }
+        }
+        groups.push(rest);
+        groups.reverse();
+        let mut number = groups.join(self.group);
+        if !fraction.is_empty() {
+            number.push_str(self.decimal);
+            number.push_str(&fraction);
+        }
+        number
+    }
+}
+
+/// Overrides for the patterns of other formatters.
+#[derive(Default)]
+pub(crate) struct Options<'a> {
+    /// The number of fraction digits, instead of the ones of the pattern.
+    pub(crate) fraction_digits: Option<usize>,
+    /// The symbol that replaces `¤`.
+    pub(crate) currency: &'a str,
+}
+
+/// The parts of a CLDR pattern that are used for formatting.
+struct Pattern<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+    grouping: (usize, usize),
+    min_fraction: usize,
+    max_fraction: usize,
+}
+
+impl<'a> Pattern<'a> {
+    fn parse(pattern: &'a str) -> Self {
+        let is_number = |c: char| matches!(c, '#' | '0' | ',' | '.');
+        let start = pattern
+            .find(is_number)
+            .unwrap_or(pattern.len());
+        let end = pattern
+            .rfind(is_number)
+            .map_or(start, |i| i + 1);
+        let number = &pattern[start..end];
+        let (integer, fraction) = number
+            .split_once('.')
+            .unwrap_or((number, ""));
+        let sizes: Vec<_> = integer
+            .rsplit(',')
+            .map(str::len)
+            .collect();
+        let grouping = match sizes[..] {
+            [_] => (0, 0),
+            [primary, _] => (primary, primary),
+            [primary, secondary, ..] => (primary, secondary),
+            [] => unreachable!(),
+        };
+        Pattern {
+            prefix: &pattern[..start],
+            suffix: &pattern[end..],
+            grouping,
+            min_fraction: fraction
+                .chars()
+                .filter(|&c| c == '0')
+                .count(),
+            max_fraction: fraction.len(),
+        }
+    }
+}
+
+/// Formats a number for a language.
+pub fn format_number(lang: LangCode, value: f64) -> String {
+    NumberFormat::new(lang).format(value)
+}
+
+/// Formats a ratio as a percentage for a language.
+pub fn format_percent(lang: LangCode, value: f64) -> String {
+    NumberFormat::new(lang).format_percent(value)
+}
+
+impl LangCode {
+    /// Returns the number format of the language.
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// assert_eq!(Fr.number_format().decimal, ",");
+    /// ```
+    pub fn number_format(self) -> NumberFormat {
+        NumberFormat::new(self)
+    }
+}
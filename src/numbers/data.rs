@@ -0,0 +1,335 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::{Digits, NumberFormat};
+use crate::LangCode::{self, *};
+
+/// The format of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [NumberFormat; 184] = [
+    /* aa */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ab */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* af */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ak */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sq */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* am */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ar */ NumberFormat { decimal: ".", group: ",", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic },
+    /* an */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* hy */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* as */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Bengali, native_digits: Digits::Bengali },
+    /* av */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ae */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ay */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* az */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bm */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ba */ NumberFormat { decimal: ".", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* eu */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "%\u{A0}#,##0", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* be */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Bengali, native_digits: Digits::Bengali },
+    /* bh */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bi */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bs */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* br */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bg */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* my */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Myanmar, native_digits: Digits::Myanmar },
+    /* ca */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ch */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ce */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ny */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* zh */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* cv */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kw */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* co */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* cr */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* hr */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* cs */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* da */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* dv */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nl */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* dz */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Tibetan },
+    /* en */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* eo */ NumberFormat { decimal: ",", group: "\u{202F}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* et */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ee */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 3, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fo */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fj */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fi */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fr */ NumberFormat { decimal: ",", group: "\u{202F}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ff */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* gl */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ka */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* de */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* el */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* gn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* gu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Gujarati },
+    /* ht */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ha */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* he */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* hz */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* hi */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Devanagari },
+    /* ho */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* hu */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ia */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* id */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ie */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ga */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ig */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ik */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* io */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* is */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* it */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* iu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ja */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* jv */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kl */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Kannada },
+    /* kr */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ks */ NumberFormat { decimal: "٫", group: "٬", percent_sign: "%", minus_sign: "\u{200E}-\u{200E}", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ExtendedArabicIndic, native_digits: Digits::ExtendedArabicIndic },
+    /* kk */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* km */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Khmer },
+    /* ki */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* rw */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ky */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kv */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kg */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ko */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ku */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "%#,##0", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* kj */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* la */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* lb */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* lg */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* li */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ln */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* lo */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Lao },
+    /* lt */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* lu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* lv */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* gv */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* mk */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* mg */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ms */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ml */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Malayalam },
+    /* mt */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* mi */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* mr */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Devanagari, native_digits: Digits::Devanagari },
+    /* mh */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* mn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* na */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nv */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nd */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ne */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Devanagari, native_digits: Digits::Devanagari },
+    /* ng */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nb */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nn */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* no */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ii */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* nr */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* oc */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{202F}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* oj */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* cu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* om */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* or */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Odia },
+    /* os */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* pa */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Gurmukhi },
+    /* pi */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fa */ NumberFormat { decimal: "٫", group: "٬", percent_sign: "%", minus_sign: "\u{200E}−", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ExtendedArabicIndic, native_digits: Digits::ExtendedArabicIndic },
+    /* pl */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ps */ NumberFormat { decimal: "٫", group: "٬", percent_sign: "%", minus_sign: "\u{200E}-\u{200E}", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ExtendedArabicIndic, native_digits: Digits::ExtendedArabicIndic },
+    /* pt */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* qu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* rm */ NumberFormat { decimal: ",", group: "\u{202F}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* rn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ro */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ru */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sa */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Devanagari, native_digits: Digits::Devanagari },
+    /* sc */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sd */ NumberFormat { decimal: ".", group: "٬", percent_sign: "%", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic },
+    /* se */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sm */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sg */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sr */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* gd */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sn */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* si */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sk */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sl */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* so */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* st */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* es */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* su */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sw */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ss */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* sv */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "−", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ta */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Tamil },
+    /* te */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Telugu },
+    /* tg */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* th */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Thai },
+    /* ti */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* bo */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Tibetan },
+    /* tk */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* tl */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* tn */ NumberFormat { decimal: ".", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* to */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* tr */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "%#,##0", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ts */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* tt */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* tw */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ty */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ug */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* uk */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ur */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ExtendedArabicIndic },
+    /* uz */ NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* ve */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* vi */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* vo */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* wa */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* cy */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* wo */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* fy */ NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* xh */ NumberFormat { decimal: ".", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* yi */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* yo */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* za */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+    /* zu */ NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin },
+];
+
+/// The formats of the regions that differ from the format of their language.
+pub(super) static REGIONS: &[(LangCode, &str, NumberFormat)] = &[
+    (Ar, "BH", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "DJ", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "DZ", NumberFormat { decimal: ",", group: ".", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic }),
+    (Ar, "EG", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "ER", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "IL", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "IQ", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "JO", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "KM", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "KW", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "LB", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "LY", NumberFormat { decimal: ",", group: ".", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic }),
+    (Ar, "MA", NumberFormat { decimal: ",", group: ".", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic }),
+    (Ar, "MR", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "OM", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "PS", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "QA", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "SA", NumberFormat { decimal: "٫", group: "٬", percent_sign: "٪", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "SD", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "SO", NumberFormat { decimal: "٫", group: "٬", percent_sign: "٪", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "SS", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "SY", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "TD", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Ar, "TN", NumberFormat { decimal: ",", group: ".", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{200E}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic }),
+    (Ar, "YE", NumberFormat { decimal: "٫", group: "٬", percent_sign: "\u{200E}%\u{200E}", minus_sign: "\u{61C}-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ArabicIndic, native_digits: Digits::ArabicIndic }),
+    (Az, "IQ", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Az, "IR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Az, "RU", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "AT", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "BE", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "CH", NumberFormat { decimal: ".", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "CZ", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "DE", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "DK", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "EE", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "ES", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "FI", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "FR", NumberFormat { decimal: ",", group: "\u{202F}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "GE", NumberFormat { decimal: ",", group: "\u{202F}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "HU", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "ID", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "IN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##,##0.###", percent_pattern: "#,##,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "IT", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "LT", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "LV", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "NL", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "NO", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "PL", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "PT", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "RO", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "SE", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "SI", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "SK", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "UA", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (En, "ZA", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Fr, "CA", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Fr, "CH", NumberFormat { decimal: ",", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Fr, "LU", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Fr, "MA", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (De, "AT", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (De, "CH", NumberFormat { decimal: ".", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (De, "LI", NumberFormat { decimal: ".", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (It, "CH", NumberFormat { decimal: ".", group: "'", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Kk, "AF", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Kk, "CN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Kk, "IR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Kk, "MN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ky, "CN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ky, "TR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "AM", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "AZ", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "GE", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "IQ", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "IR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "LB", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ku, "TM", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ms, "BN", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ms, "ID", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pa, "PK", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Gurmukhi }),
+    (Pt, "AO", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "CH", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "CV", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "FR", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "GQ", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "GW", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "LU", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "MO", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "MZ", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "PT", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "ST", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Pt, "TL", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Qu, "BO", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ru, "UA", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0\u{A0}%", min_grouping: 2, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Sd, "IN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::ArabicIndic }),
+    (Es, "419", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "AR", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "BO", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "BR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "CL", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "CO", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "CR", NumberFormat { decimal: ",", group: "\u{A0}", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "CU", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "DO", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "EC", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "GT", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "HN", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "JP", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "MX", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "NI", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "PA", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "PE", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "PR", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "PY", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "SV", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "US", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "UY", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Es, "VE", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Sw, "CD", NumberFormat { decimal: ",", group: ".", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ta, "MY", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Tamil }),
+    (Tg, "PK", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+    (Ur, "IN", NumberFormat { decimal: "٫", group: "٬", percent_sign: "%", minus_sign: "\u{200E}-\u{200E}", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::ExtendedArabicIndic, native_digits: Digits::ExtendedArabicIndic }),
+    (Uz, "AF", NumberFormat { decimal: ".", group: ",", percent_sign: "%", minus_sign: "-", pattern: "#,##0.###", percent_pattern: "#,##0%", min_grouping: 1, digits: Digits::Latin, native_digits: Digits::Latin }),
+];
+
+/// The decimal separator, group separator and minus sign of the numbering systems,
+/// when every language with CLDR data for them uses the same ones.
+pub(super) static SYMBOLS: &[(Digits, &str, &str, &str)] = &[
+    (Digits::ArabicIndic, "٫", "٬", "\u{61C}-"),
+    (Digits::ExtendedArabicIndic, "٫", "٬", "\u{200E}-\u{200E}"),
+    (Digits::Devanagari, ".", ",", "-"),
+    (Digits::Gujarati, ".", ",", "-"),
+    (Digits::Gurmukhi, ".", ",", "-"),
+    (Digits::Khmer, ",", ".", "-"),
+    (Digits::Kannada, ".", ",", "-"),
+    (Digits::Lao, ",", ".", "-"),
+    (Digits::Malayalam, ".", ",", "-"),
+    (Digits::Odia, ".", ",", "-"),
+    (Digits::Tamil, ".", ",", "-"),
+    (Digits::Telugu, ".", ",", "-"),
+    (Digits::Thai, ".", ",", "-"),
+];
@@ -0,0 +1,151 @@
+//! Cardinal plural rules, from the Unicode CLDR.
+//!
+//! The plural category of a number selects the form of the words next to it:
+//! ```rust
+//! # use rocket_lang::{*, plurals::{plural_category, PluralCategory::*}};
+//! assert_eq!(plural_category(En, 1.0), One);
+//! assert_eq!(plural_category(En, 1.5), Other);
+//! assert_eq!(plural_category(Fr, 1.5), One);
+//! assert_eq!(plural_category(Ru, 22.0), Few);
+//! assert_eq!(plural_category(Ar, 11.0), Many);
+//! ```
+//! Numbers are considered as written by `format_number`, with up to 3 fraction digits,
+//! so `1.0` has the category of `1`. The rules are generated from the Unicode CLDR
+//! by `tools/cldr`. Languages without rules of their own, such as Japanese, have
+//! the single category `Other`.
+use crate::*;
+
+#[rustfmt::skip]
+mod data;
+
+/// A CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// An operand of the plural rules, as named by the CLDR. Trailing zeros are never
+/// visible, so the CLDR operands `w` and `t` are `V` and `F`, and numbers are never
+/// compact, so the exponent `c` or `e` is 0.
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    /// The absolute value.
+    N,
+    /// The integer digits.
+    I,
+    /// The number of visible fraction digits.
+    V,
+    /// The visible fraction digits.
+    F,
+    /// The exponent of a compact number.
+    E,
+}
+
+/// A relation of a plural rule, such as `i % 10 = 2..4`. It holds when the operand,
+/// modulo `modulus` unless it is 0, is within one of the ranges, or outside all of
+/// them if `equal` is false.
+#[derive(Debug)]
+struct Relation {
+    operand: Operand,
+    modulus: u64,
+    equal: bool,
+    ranges: &'static [(u64, u64)],
+}
+
+/// The rules of the `zero`, `one`, `two`, `few` and `many` categories, each a list
+/// of alternatives of relations that must all hold. Numbers matching none of them
+/// are `other`.
+type Rules = [&'static [&'static [Relation]]; 5];
+
+/// The operands of the plural rules: the integer digits `i`, the number of visible
+/// fraction digits `v`, and the visible fraction digits `f`.
+struct Operands {
+    i: u64,
+    v: u64,
+    f: u64,
+}
+
+impl Operands {
+    fn new(value: f64) -> Self {
+        let text = format!("{:.3}", value.abs());
+        let (integer, fraction) = text
+            .split_once('.')
+            .unwrap_or((&text, ""));
+        let fraction = fraction.trim_end_matches('0');
+        Operands {
+            i: integer
+                .parse()
+                .unwrap_or(u64::MAX),
+            v: fraction.len() as u64,
+            f: fraction.parse().unwrap_or(0),
+        }
+    }
+}
+
+impl Relation {
+    fn holds(&self, operands: &Operands) -> bool {
+        let value = match self.operand {
+            // a number with fraction digits is in no range of integers.
+            Operand::N if operands.v != 0 => None,
+            Operand::N | Operand::I => Some(operands.i),
+            Operand::V => Some(operands.v),
+            Operand::F => Some(operands.f),
+            Operand::E => Some(0),
+        };
+        let within = value.is_some_and(|value| {
+            let value = match self.modulus {
+                0 => value,
+                modulus => value % modulus,
+            };
+            self.ranges
+                .iter()
+                .any(|(low, high)| (*low..=*high).contains(&value))
+        });
+        within == self.equal
+    }
+}
+
+/// Returns the cardinal plural category of a number.
+pub fn plural_category(lang: LangCode, value: f64) -> PluralCategory {
+    use PluralCategory::*;
+    let Some(rules) = data::LANGUAGES[lang as usize] else {
+        return Other;
+    };
+    let operands = Operands::new(value);
+    [Zero, One, Two, Few, Many]
+        .into_iter()
+        .zip(rules)
+        .find(|(_, rule)| {
+            rule.iter().any(|relations| {
+                relations
+                    .iter()
+                    .all(|relation| relation.holds(&operands))
+            })
+        })
+        .map_or(Other, |(category, _)| category)
+}
+
+/// The forms of a pattern by plural category, as `[zero, one, two, few, many, other]`.
+/// The categories without a form of their own are empty, and use the `other` one.
+pub(crate) type Forms = [&'static str; 6];
+
+/// Returns the form of a pattern for a number.
+pub(crate) fn select(forms: &Forms, lang: LangCode, value: f64) -> &'static str {
+    let index = match plural_category(lang, value) {
+        PluralCategory::Zero => 0,
+        PluralCategory::One => 1,
+        PluralCategory::Two => 2,
+        PluralCategory::Few => 3,
+        PluralCategory::Many => 4,
+        PluralCategory::Other => 5,
+    };
+    match forms[index] {
+        "" => forms[5],
+        form => form,
+    }
+}
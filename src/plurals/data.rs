@@ -0,0 +1,711 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::{Operand::*, Relation, Rules};
+
+// af, sq, az, eu, bg, eo, ee, fo, ka, el, ha, hu, ks, kk, ky, ku, lb, ml, mr, mn, ne, nb, nn, no, om, or, ps, rm, sd, so, st, ta, te, tk, tn, tr, ug, uz, xh
+// one: n = 1
+static P0: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// ak, pa, ti
+// one: n = 0..1
+static P1: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// am, as, bn, gu, hi, kn, fa, zu
+// one: i = 0 or n = 1
+static P2: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// ar
+// zero: n = 0
+// one: n = 1
+// two: n = 2
+// few: n % 100 = 3..10
+// many: n % 100 = 11..99
+static P3: Rules = [
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(2, 2)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(3, 10)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(11, 99)] }],
+    ],
+];
+
+// hy
+// one: i = 0, 1
+static P4: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0), (1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// be
+// one: n % 10 = 1 and n % 100 != 11
+// few: n % 10 = 2..4 and n % 100 != 12..14
+// many: n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14
+static P5: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(2, 4)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(12, 14)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(5, 9)] }],
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(11, 14)] }],
+    ],
+];
+
+// bs, hr, sr
+// one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11
+// few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14
+static P6: Rules = [
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+        &[Relation { operand: F, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: F, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(2, 4)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(12, 14)] }],
+        &[Relation { operand: F, modulus: 10, equal: true, ranges: &[(2, 4)] }, Relation { operand: F, modulus: 100, equal: false, ranges: &[(12, 14)] }],
+    ],
+    &[],
+];
+
+// br
+// one: n % 10 = 1 and n % 100 != 11, 71, 91
+// two: n % 10 = 2 and n % 100 != 12, 72, 92
+// few: n % 10 = 3..4, 9 and n % 100 != 10..19, 70..79, 90..99
+// many: n != 0 and n % 1000000 = 0
+static P7: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(11, 11), (71, 71), (91, 91)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(2, 2)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(12, 12), (72, 72), (92, 92)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(3, 4), (9, 9)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(10, 19), (70, 79), (90, 99)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: N, modulus: 1000000, equal: true, ranges: &[(0, 0)] }],
+    ],
+];
+
+// ca, it
+// one: i = 1 and v = 0
+// many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+static P8: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[],
+    &[
+        &[Relation { operand: E, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 1000000, equal: true, ranges: &[(0, 0)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: E, modulus: 0, equal: false, ranges: &[(0, 5)] }],
+    ],
+];
+
+// cv
+// zero: n = 0
+// one: n = 1
+static P9: Rules = [
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// cs, sk
+// one: i = 1 and v = 0
+// few: i = 2..4 and v = 0
+// many: v != 0
+static P10: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(2, 4)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: false, ranges: &[(0, 0)] }],
+    ],
+];
+
+// da
+// one: n = 1 or t != 0 and i = 0, 1
+static P11: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+        &[Relation { operand: F, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0), (1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// nl, en, et, fi, gl, de, ia, ie, sc, sw, sv, ur, fy
+// one: i = 1 and v = 0
+static P12: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// fr, ht
+// one: i = 0, 1
+// many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+static P13: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0), (1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[
+        &[Relation { operand: E, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 1000000, equal: true, ranges: &[(0, 0)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: E, modulus: 0, equal: false, ranges: &[(0, 5)] }],
+    ],
+];
+
+// he
+// one: i = 1 and v = 0 or i = 0 and v != 0
+// two: i = 2 and v = 0
+static P14: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: V, modulus: 0, equal: false, ranges: &[(0, 0)] }],
+    ],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(2, 2)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[],
+];
+
+// ga
+// one: n = 1
+// two: n = 2
+// few: n = 3..6
+// many: n = 7..10
+static P15: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(2, 2)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(3, 6)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(7, 10)] }],
+    ],
+];
+
+// is
+// one: t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11
+static P16: Rules = [
+    &[],
+    &[
+        &[Relation { operand: F, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+        &[Relation { operand: F, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: F, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// lt
+// one: n % 10 = 1 and n % 100 != 11..19
+// few: n % 10 = 2..9 and n % 100 != 11..19
+// many: f != 0
+static P17: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(11, 19)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(2, 9)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(11, 19)] }],
+    ],
+    &[
+        &[Relation { operand: F, modulus: 0, equal: false, ranges: &[(0, 0)] }],
+    ],
+];
+
+// lv
+// zero: n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19
+// one: n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1
+static P18: Rules = [
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(11, 19)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(2, 2)] }, Relation { operand: F, modulus: 100, equal: true, ranges: &[(11, 19)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: N, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(2, 2)] }, Relation { operand: F, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: F, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+        &[Relation { operand: V, modulus: 0, equal: false, ranges: &[(2, 2)] }, Relation { operand: F, modulus: 10, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// mk
+// one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11
+static P19: Rules = [
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+        &[Relation { operand: F, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: F, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// mt
+// one: n = 1
+// two: n = 2
+// few: n = 0 or n % 100 = 3..10
+// many: n % 100 = 11..19
+static P20: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(2, 2)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(3, 10)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 100, equal: true, ranges: &[(11, 19)] }],
+    ],
+];
+
+// pl
+// one: i = 1 and v = 0
+// few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14
+// many: v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14
+static P21: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(2, 4)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(12, 14)] }],
+    ],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: false, ranges: &[(1, 1)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(0, 1)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(5, 9)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 100, equal: true, ranges: &[(12, 14)] }],
+    ],
+];
+
+// pt
+// one: i = 0..1
+// many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+static P22: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 1)] }],
+    ],
+    &[],
+    &[],
+    &[
+        &[Relation { operand: E, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 1000000, equal: true, ranges: &[(0, 0)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: E, modulus: 0, equal: false, ranges: &[(0, 5)] }],
+    ],
+];
+
+// ro
+// one: i = 1 and v = 0
+// few: v != 0 or n = 0 or n != 1 and n % 100 = 1..19
+static P23: Rules = [
+    &[],
+    &[
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: false, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: N, modulus: 0, equal: false, ranges: &[(1, 1)] }, Relation { operand: N, modulus: 100, equal: true, ranges: &[(1, 19)] }],
+    ],
+    &[],
+];
+
+// ru, uk
+// one: v = 0 and i % 10 = 1 and i % 100 != 11
+// few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14
+// many: v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14
+static P24: Rules = [
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(1, 1)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(11, 11)] }],
+    ],
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(2, 4)] }, Relation { operand: I, modulus: 100, equal: false, ranges: &[(12, 14)] }],
+    ],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: true, ranges: &[(5, 9)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 100, equal: true, ranges: &[(11, 14)] }],
+    ],
+];
+
+// gd
+// one: n = 1, 11
+// two: n = 2, 12
+// few: n = 3..10, 13..19
+static P25: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1), (11, 11)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(2, 2), (12, 12)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(3, 10), (13, 19)] }],
+    ],
+    &[],
+];
+
+// si
+// one: n = 0, 1 or i = 0 and f = 1
+static P26: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0), (1, 1)] }],
+        &[Relation { operand: I, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: F, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// sl
+// one: v = 0 and i % 100 = 1
+// two: v = 0 and i % 100 = 2
+// few: v = 0 and i % 100 = 3..4 or v != 0
+static P27: Rules = [
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 100, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 100, equal: true, ranges: &[(2, 2)] }],
+    ],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 100, equal: true, ranges: &[(3, 4)] }],
+        &[Relation { operand: V, modulus: 0, equal: false, ranges: &[(0, 0)] }],
+    ],
+    &[],
+];
+
+// es
+// one: n = 1
+// many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+static P28: Rules = [
+    &[],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[],
+    &[],
+    &[
+        &[Relation { operand: E, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 1000000, equal: true, ranges: &[(0, 0)] }, Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+        &[Relation { operand: E, modulus: 0, equal: false, ranges: &[(0, 5)] }],
+    ],
+];
+
+// tl
+// one: v = 0 and i = 1, 2, 3 or v = 0 and i % 10 != 4, 6, 9 or v != 0 and f % 10 != 4, 6, 9
+static P29: Rules = [
+    &[],
+    &[
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 0, equal: true, ranges: &[(1, 1), (2, 2), (3, 3)] }],
+        &[Relation { operand: V, modulus: 0, equal: true, ranges: &[(0, 0)] }, Relation { operand: I, modulus: 10, equal: false, ranges: &[(4, 4), (6, 6), (9, 9)] }],
+        &[Relation { operand: V, modulus: 0, equal: false, ranges: &[(0, 0)] }, Relation { operand: F, modulus: 10, equal: false, ranges: &[(4, 4), (6, 6), (9, 9)] }],
+    ],
+    &[],
+    &[],
+    &[],
+];
+
+// cy
+// zero: n = 0
+// one: n = 1
+// two: n = 2
+// few: n = 3
+// many: n = 6
+static P30: Rules = [
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(0, 0)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(1, 1)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(2, 2)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(3, 3)] }],
+    ],
+    &[
+        &[Relation { operand: N, modulus: 0, equal: true, ranges: &[(6, 6)] }],
+    ],
+];
+
+/// The rules of every language, indexed by the `LangCode` discriminant,
+/// `None` for the languages where every number is `other`.
+pub(super) static LANGUAGES: [Option<&Rules>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&P0),
+    /* ak */ Some(&P1),
+    /* sq */ Some(&P0),
+    /* am */ Some(&P2),
+    /* ar */ Some(&P3),
+    /* an */ None,
+    /* hy */ Some(&P4),
+    /* as */ Some(&P2),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&P0),
+    /* bm */ None,
+    /* ba */ None,
+    /* eu */ Some(&P0),
+    /* be */ Some(&P5),
+    /* bn */ Some(&P2),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&P6),
+    /* br */ Some(&P7),
+    /* bg */ Some(&P0),
+    /* my */ None,
+    /* ca */ Some(&P8),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ None,
+    /* cv */ Some(&P9),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&P6),
+    /* cs */ Some(&P10),
+    /* da */ Some(&P11),
+    /* dv */ None,
+    /* nl */ Some(&P12),
+    /* dz */ None,
+    /* en */ Some(&P12),
+    /* eo */ Some(&P0),
+    /* et */ Some(&P12),
+    /* ee */ Some(&P0),
+    /* fo */ Some(&P0),
+    /* fj */ None,
+    /* fi */ Some(&P12),
+    /* fr */ Some(&P13),
+    /* ff */ None,
+    /* gl */ Some(&P12),
+    /* ka */ Some(&P0),
+    /* de */ Some(&P12),
+    /* el */ Some(&P0),
+    /* gn */ None,
+    /* gu */ Some(&P2),
+    /* ht */ Some(&P13),
+    /* ha */ Some(&P0),
+    /* he */ Some(&P14),
+    /* hz */ None,
+    /* hi */ Some(&P2),
+    /* ho */ None,
+    /* hu */ Some(&P0),
+    /* ia */ Some(&P12),
+    /* id */ None,
+    /* ie */ Some(&P12),
+    /* ga */ Some(&P15),
+    /* ig */ None,
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&P16),
+    /* it */ Some(&P8),
+    /* iu */ None,
+    /* ja */ None,
+    /* jv */ None,
+    /* kl */ None,
+    /* kn */ Some(&P2),
+    /* kr */ None,
+    /* ks */ Some(&P0),
+    /* kk */ Some(&P0),
+    /* km */ None,
+    /* ki */ None,
+    /* rw */ None,
+    /* ky */ Some(&P0),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ None,
+    /* ku */ Some(&P0),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&P0),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ None,
+    /* lt */ Some(&P17),
+    /* lu */ None,
+    /* lv */ Some(&P18),
+    /* gv */ None,
+    /* mk */ Some(&P19),
+    /* mg */ None,
+    /* ms */ None,
+    /* ml */ Some(&P0),
+    /* mt */ Some(&P20),
+    /* mi */ None,
+    /* mr */ Some(&P0),
+    /* mh */ None,
+    /* mn */ Some(&P0),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&P0),
+    /* ng */ None,
+    /* nb */ Some(&P0),
+    /* nn */ Some(&P0),
+    /* no */ Some(&P0),
+    /* ii */ None,
+    /* nr */ None,
+    /* oc */ None,
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ Some(&P0),
+    /* or */ Some(&P0),
+    /* os */ None,
+    /* pa */ Some(&P1),
+    /* pi */ None,
+    /* fa */ Some(&P2),
+    /* pl */ Some(&P21),
+    /* ps */ Some(&P0),
+    /* pt */ Some(&P22),
+    /* qu */ None,
+    /* rm */ Some(&P0),
+    /* rn */ None,
+    /* ro */ Some(&P23),
+    /* ru */ Some(&P24),
+    /* sa */ None,
+    /* sc */ Some(&P12),
+    /* sd */ Some(&P0),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&P6),
+    /* gd */ Some(&P25),
+    /* sn */ None,
+    /* si */ Some(&P26),
+    /* sk */ Some(&P10),
+    /* sl */ Some(&P27),
+    /* so */ Some(&P0),
+    /* st */ Some(&P0),
+    /* es */ Some(&P28),
+    /* su */ None,
+    /* sw */ Some(&P12),
+    /* ss */ None,
+    /* sv */ Some(&P12),
+    /* ta */ Some(&P0),
+    /* te */ Some(&P0),
+    /* tg */ None,
+    /* th */ None,
+    /* ti */ Some(&P1),
+    /* bo */ None,
+    /* tk */ Some(&P0),
+    /* tl */ Some(&P29),
+    /* tn */ Some(&P0),
+    /* to */ None,
+    /* tr */ Some(&P0),
+    /* ts */ None,
+    /* tt */ None,
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&P0),
+    /* uk */ Some(&P24),
+    /* ur */ Some(&P12),
+    /* uz */ Some(&P0),
+    /* ve */ None,
+    /* vi */ None,
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&P30),
+    /* wo */ None,
+    /* fy */ Some(&P12),
+    /* xh */ Some(&P0),
+    /* yi */ None,
+    /* yo */ None,
+    /* za */ None,
+    /* zu */ Some(&P2),
+];
@@ -0,0 +1,144 @@
+//! Locale-aware relative time formatting, with data from the Unicode CLDR.
+//!
+//! A duration is written in the largest unit it reaches, truncating the rest.
+//! Negative durations are in the past, and the unit agrees with the value:
+//! ```rust
+//! # use rocket_lang::{*, relative::format_relative};
+//! const DAY: i64 = 24 * 60 * 60;
+//! assert_eq!(format_relative(En, -3 * DAY), "3 days ago");
+//! assert_eq!(format_relative(Es, -3 * DAY), "hace 3 días");
+//! assert_eq!(format_relative(De, 90 * 60), "in 1 Stunde");
+//! assert_eq!(format_relative(Ru, -5 * 60), "5 минут назад");
+//! ```
+//! A zero duration is written with the name CLDR gives to the present:
+//! ```rust
+//! # use rocket_lang::{*, relative::format_relative};
+//! assert_eq!(format_relative(En, 0), "now");
+//! assert_eq!(format_relative(Fr, 0), "maintenant");
+//! ```
+//! Durations can be given in seconds, or as `std`, `chrono` or `time` durations,
+//! the latter two with the features of the same name. The data is generated from
+//! the Unicode CLDR by `tools/cldr`. Languages without patterns of their own use
+//! the patterns of the root locale, as in `-3 d`.
+use crate::{plurals::Forms, *};
+use std::time::Duration;
+
+#[rustfmt::skip]
+mod data;
+
+/// The patterns of a unit in a locale.
+struct Patterns {
+    /// The name of the current unit, such as `now` or `today`.
+    current: &'static str,
+    past: Forms,
+    future: Forms,
+}
+
+/// The patterns of every unit, in the order of the `RelativeUnit` variants.
+type Names = [Patterns; 7];
+
+/// The unit of a relative time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl RelativeUnit {
+    /// The units, from the largest to the smallest.
+    const DESCENDING: [RelativeUnit; 7] = [
+        RelativeUnit::Year,
+        RelativeUnit::Month,
+        RelativeUnit::Week,
+        RelativeUnit::Day,
+        RelativeUnit::Hour,
+        RelativeUnit::Minute,
+        RelativeUnit::Second,
+    ];
+
+    /// Returns the length of the unit in seconds. Months have 30 days, and years 365.
+    pub fn seconds(self) -> i64 {
+        const DAY: i64 = 24 * 60 * 60;
+        match self {
+            RelativeUnit::Second => 1,
+            RelativeUnit::Minute => 60,
+            RelativeUnit::Hour => 60 * 60,
+            RelativeUnit::Day => DAY,
+            RelativeUnit::Week => 7 * DAY,
+            RelativeUnit::Month => 30 * DAY,
+            RelativeUnit::Year => 365 * DAY,
+        }
+    }
+}
+
+/// A signed duration, positive in the future and negative in the past.
+pub trait RelativeDuration {
+    /// Returns the duration in whole seconds.
+    fn seconds(&self) -> i64;
+}
+
+/// A number of seconds.
+impl RelativeDuration for i64 {
+    fn seconds(&self) -> i64 {
+        *self
+    }
+}
+
+/// A duration into the future.
+impl RelativeDuration for Duration {
+    fn seconds(&self) -> i64 {
+        i64::try_from(self.as_secs()).unwrap_or(i64::MAX)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl RelativeDuration for chrono::TimeDelta {
+    fn seconds(&self) -> i64 {
+        self.num_seconds()
+    }
+}
+
+#[cfg(feature = "time")]
+impl RelativeDuration for time::Duration {
+    fn seconds(&self) -> i64 {
+        self.whole_seconds()
+    }
+}
+
+/// Formats a duration relative to now, in the largest unit it reaches.
+pub fn format_relative(lang: LangCode, duration: impl RelativeDuration) -> String {
+    let seconds = duration.seconds();
+    let unit = RelativeUnit::DESCENDING
+        .into_iter()
+        .find(|unit| seconds.unsigned_abs() >= unit.seconds().unsigned_abs())
+        .unwrap_or(RelativeUnit::Second);
+    format_relative_unit(lang, seconds / unit.seconds(), unit)
+}
+
+/// Formats a number of units relative to now, such as `-2` days. Zero is written
+/// with the name of the current unit.
+/// ```rust
+/// # use rocket_lang::{*, relative::{format_relative_unit, RelativeUnit}};
+/// assert_eq!(format_relative_unit(Fr, 2, RelativeUnit::Week), "dans 2 semaines");
+/// assert_eq!(format_relative_unit(Ja, -1, RelativeUnit::Month), "1 か月前");
+/// assert_eq!(format_relative_unit(En, 0, RelativeUnit::Day), "today");
+/// ```
+pub fn format_relative_unit(lang: LangCode, value: i64, unit: RelativeUnit) -> String {
+    let names = data::LANGUAGES[lang as usize].unwrap_or(&data::ROOT);
+    let patterns = &names[unit as usize];
+    if value == 0 && !patterns.current.is_empty() {
+        return patterns.current.to_string();
+    }
+    let count = value.unsigned_abs() as f64;
+    let forms = if value < 0 {
+        &patterns.past
+    } else {
+        &patterns.future
+    };
+    plurals::select(forms, lang, count).replace("{0}", &numbers::format_number(lang, count))
+}
@@ -0,0 +1,4792 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::{Names, Patterns};
+
+/// The patterns of the root locale, used by the languages without patterns of their own.
+pub(super) static ROOT: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "today",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "this week",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "this month",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "this year",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// af
+static R0: Names = [
+    Patterns {
+        current: "nou",
+        past: ["", "{0} sekonde gelede", "", "", "", "{0} sekondes gelede"],
+        future: ["", "oor {0} sekonde", "", "", "", "oor {0} sekondes"],
+    },
+    Patterns {
+        current: "hierdie minuut",
+        past: ["", "{0} minuut gelede", "", "", "", "{0} minute gelede"],
+        future: ["", "oor {0} minuut", "", "", "", "oor {0} minute"],
+    },
+    Patterns {
+        current: "hierdie uur",
+        past: ["", "", "", "", "", "{0} uur gelede"],
+        future: ["", "", "", "", "", "oor {0} uur"],
+    },
+    Patterns {
+        current: "vandag",
+        past: ["", "{0} dag gelede", "", "", "", "{0} dae gelede"],
+        future: ["", "oor {0} dag", "", "", "", "oor {0} dae"],
+    },
+    Patterns {
+        current: "hierdie week",
+        past: ["", "{0} week gelede", "", "", "", "{0} weke gelede"],
+        future: ["", "oor {0} week", "", "", "", "oor {0} weke"],
+    },
+    Patterns {
+        current: "vandeesmaand",
+        past: ["", "{0} maand gelede", "", "", "", "{0} maande gelede"],
+        future: ["", "oor {0} maand", "", "", "", "oor {0} maande"],
+    },
+    Patterns {
+        current: "hierdie jaar",
+        past: ["", "", "", "", "", "{0} jaar gelede"],
+        future: ["", "", "", "", "", "oor {0} jaar"],
+    },
+];
+
+// ak
+static R1: Names = [
+    Patterns {
+        current: "seesei",
+        past: ["", "", "", "", "", "simasin {0} a atwam"],
+        future: ["", "", "", "", "", "simasin {0} mu"],
+    },
+    Patterns {
+        current: "sima yi",
+        past: ["", "", "", "", "", "sima {0} a atwam"],
+        future: ["", "", "", "", "", "sima {0} mu"],
+    },
+    Patterns {
+        current: "dɔnhwere yi",
+        past: ["", "dɔnhwere {0} a atwam", "", "", "", "nnɔnhwere {0} a atwam"],
+        future: ["", "dɔnhwere {0} mu", "", "", "", "nnɔnhwere {0} mu"],
+    },
+    Patterns {
+        current: "ɛnnɛ",
+        past: ["", "da{0} a atwam", "", "", "", "nna{0} a atwam"],
+        future: ["", "da {0} mu", "", "", "", "nna {0} mu"],
+    },
+    Patterns {
+        current: "nnawɔtwe yi",
+        past: ["", "", "", "", "", "nnawɔtwe{0} a atwam"],
+        future: ["", "", "", "", "", "nnawɔtwe {0} mu"],
+    },
+    Patterns {
+        current: "bosome yi",
+        past: ["", "-{0} bosome a atwam", "", "", "", "abosome{0} a atwam"],
+        future: ["", "bosome{0} mu", "", "", "", "abosome{0} mu"],
+    },
+    Patterns {
+        current: "afe yi",
+        past: ["", "afe {0} a atwam", "", "", "", "mfeɛ {0} a atwam"],
+        future: ["", "afe {0} mu", "", "", "", "mfeɛ {0} mu"],
+    },
+];
+
+// sq
+static R2: Names = [
+    Patterns {
+        current: "tani",
+        past: ["", "{0} sekondë më parë", "", "", "", "{0} sekonda më parë"],
+        future: ["", "pas {0} sekonde", "", "", "", "pas {0} sekondash"],
+    },
+    Patterns {
+        current: "këtë minutë",
+        past: ["", "{0} minutë më parë", "", "", "", "{0} minuta më parë"],
+        future: ["", "pas {0} minute", "", "", "", "pas {0} minutash"],
+    },
+    Patterns {
+        current: "këtë orë",
+        past: ["", "", "", "", "", "{0} orë më parë"],
+        future: ["", "pas {0} ore", "", "", "", "pas {0} orësh"],
+    },
+    Patterns {
+        current: "sot",
+        past: ["", "", "", "", "", "{0} ditë më parë"],
+        future: ["", "pas {0} dite", "", "", "", "pas {0} ditësh"],
+    },
+    Patterns {
+        current: "këtë javë",
+        past: ["", "", "", "", "", "{0} javë më parë"],
+        future: ["", "pas {0} jave", "", "", "", "pas {0} javësh"],
+    },
+    Patterns {
+        current: "këtë muaj",
+        past: ["", "", "", "", "", "{0} muaj më parë"],
+        future: ["", "pas {0} muaji", "", "", "", "pas {0} muajsh"],
+    },
+    Patterns {
+        current: "sivjet",
+        past: ["", "{0} vit më parë", "", "", "", "{0} vjet më parë"],
+        future: ["", "pas {0} viti", "", "", "", "pas {0} vjetësh"],
+    },
+];
+
+// am
+static R3: Names = [
+    Patterns {
+        current: "አሁን",
+        past: ["", "ከ{0} ሰከንድ በፊት", "", "", "", "ከ{0} ሰከንዶች በፊት"],
+        future: ["", "በ{0} ሰከንድ ውስጥ", "", "", "", "በ{0} ሰከንዶች ውስጥ"],
+    },
+    Patterns {
+        current: "ይህ ደቂቃ",
+        past: ["", "ከ{0} ደቂቃ በፊት", "", "", "", "ከ{0} ደቂቃዎች በፊት"],
+        future: ["", "በ{0} ደቂቃ ውስጥ", "", "", "", "በ{0} ደቂቃዎች ውስጥ"],
+    },
+    Patterns {
+        current: "ይህ ሰዓት",
+        past: ["", "ከ{0} ሰዓት በፊት", "", "", "", "ከ{0} ሰዓቶች በፊት"],
+        future: ["", "በ{0} ሰዓት ውስጥ", "", "", "", "በ{0} ሰዓቶች ውስጥ"],
+    },
+    Patterns {
+        current: "ዛሬ",
+        past: ["", "ከ{0} ቀን በፊት", "", "", "", "ከ{0} ቀናት በፊት"],
+        future: ["", "በ{0} ቀን ውስጥ", "", "", "", "በ{0} ቀናት ውስጥ"],
+    },
+    Patterns {
+        current: "በዚህ ሳምንት",
+        past: ["", "ከ{0} ሳምንት በፊት", "", "", "", "ከ{0} ሳምንታት በፊት"],
+        future: ["", "በ{0} ሳምንት ውስጥ", "", "", "", "በ{0} ሳምንታት ውስጥ"],
+    },
+    Patterns {
+        current: "በዚህ ወር",
+        past: ["", "ከ{0} ወር በፊት", "", "", "", "ከ{0} ወራት በፊት"],
+        future: ["", "በ{0} ወር ውስጥ", "", "", "", "በ{0} ወራት ውስጥ"],
+    },
+    Patterns {
+        current: "በዚህ ዓመት",
+        past: ["", "ከ{0} ዓመት በፊት", "", "", "", "ከ{0} ዓመታት በፊት"],
+        future: ["", "", "", "", "", "በ{0} ዓመታት ውስጥ"],
+    },
+];
+
+// ar
+static R4: Names = [
+    Patterns {
+        current: "الآن",
+        past: ["", "قبل ثانية واحدة", "قبل ثانيتين", "قبل {0} ثوانِ", "", "قبل {0} ثانية"],
+        future: ["", "خلال ثانية واحدة", "خلال ثانيتين", "خلال {0} ثوانٍ", "", "خلال {0} ثانية"],
+    },
+    Patterns {
+        current: "هذه الدقيقة",
+        past: ["", "قبل دقيقة واحدة", "قبل دقيقتين", "قبل {0} دقائق", "", "قبل {0} دقيقة"],
+        future: ["", "خلال دقيقة واحدة", "خلال دقيقتين", "خلال {0} دقائق", "", "خلال {0} دقيقة"],
+    },
+    Patterns {
+        current: "الساعة الحالية",
+        past: ["", "قبل ساعة واحدة", "قبل ساعتين", "قبل {0} ساعات", "", "قبل {0} ساعة"],
+        future: ["", "خلال ساعة واحدة", "خلال ساعتين", "خلال {0} ساعات", "", "خلال {0} ساعة"],
+    },
+    Patterns {
+        current: "اليوم",
+        past: ["", "قبل يوم واحد", "قبل يومين", "قبل {0} أيام", "قبل {0} يومًا", "قبل {0} يوم"],
+        future: ["", "خلال يوم واحد", "خلال يومين", "خلال {0} أيام", "خلال {0} يومًا", "خلال {0} يوم"],
+    },
+    Patterns {
+        current: "هذا الأسبوع",
+        past: ["", "قبل أسبوع واحد", "قبل أسبوعين", "قبل {0} أسابيع", "قبل {0} أسبوعًا", "قبل {0} أسبوع"],
+        future: ["", "خلال أسبوع واحد", "خلال أسبوعين", "خلال {0} أسابيع", "خلال {0} أسبوعًا", "خلال {0} أسبوع"],
+    },
+    Patterns {
+        current: "هذا الشهر",
+        past: ["", "قبل شهر واحد", "قبل شهرين", "قبل {0} أشهر", "قبل {0} شهرًا", "قبل {0} شهر"],
+        future: ["", "خلال شهر واحد", "خلال شهرين", "خلال {0} أشهر", "خلال {0} شهرًا", "خلال {0} شهر"],
+    },
+    Patterns {
+        current: "السنة الحالية",
+        past: ["", "قبل سنة واحدة", "قبل سنتين", "قبل {0} سنوات", "", "قبل {0} سنة"],
+        future: ["", "خلال سنة واحدة", "خلال سنتين", "خلال {0} سنوات", "", "خلال {0} سنة"],
+    },
+];
+
+// hy
+static R5: Names = [
+    Patterns {
+        current: "հիմա",
+        past: ["", "", "", "", "", "{0} վայրկյան առաջ"],
+        future: ["", "", "", "", "", "{0} վայրկյանից"],
+    },
+    Patterns {
+        current: "այս րոպեին",
+        past: ["", "", "", "", "", "{0} րոպե առաջ"],
+        future: ["", "", "", "", "", "{0} րոպեից"],
+    },
+    Patterns {
+        current: "այս ժամին",
+        past: ["", "", "", "", "", "{0} ժամ առաջ"],
+        future: ["", "", "", "", "", "{0} ժամից"],
+    },
+    Patterns {
+        current: "այսօր",
+        past: ["", "", "", "", "", "{0} օր առաջ"],
+        future: ["", "", "", "", "", "{0} օրից"],
+    },
+    Patterns {
+        current: "այս շաբաթ",
+        past: ["", "", "", "", "", "{0} շաբաթ առաջ"],
+        future: ["", "", "", "", "", "{0} շաբաթից"],
+    },
+    Patterns {
+        current: "այս ամիս",
+        past: ["", "", "", "", "", "{0} ամիս առաջ"],
+        future: ["", "", "", "", "", "{0} ամսից"],
+    },
+    Patterns {
+        current: "այս տարի",
+        past: ["", "", "", "", "", "{0} տարի առաջ"],
+        future: ["", "", "", "", "", "{0} տարուց"],
+    },
+];
+
+// as
+static R6: Names = [
+    Patterns {
+        current: "এতিয়া",
+        past: ["", "", "", "", "", "{0} ছেকেণ্ড পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} ছেকেণ্ডত"],
+    },
+    Patterns {
+        current: "এইটো মিনিটত",
+        past: ["", "", "", "", "", "{0} মিনিট পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} মিনিটত"],
+    },
+    Patterns {
+        current: "এইটো ঘণ্টাত",
+        past: ["", "", "", "", "", "{0} ঘণ্টা পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} ঘণ্টাত"],
+    },
+    Patterns {
+        current: "আজি",
+        past: ["", "", "", "", "", "{0} দিন পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} দিনত"],
+    },
+    Patterns {
+        current: "এই সপ্তাহ",
+        past: ["", "", "", "", "", "{0} সপ্তাহ পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} সপ্তাহত"],
+    },
+    Patterns {
+        current: "এই মাহ",
+        past: ["", "", "", "", "", "{0} মাহ পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} মাহত"],
+    },
+    Patterns {
+        current: "এই বছৰ",
+        past: ["", "", "", "", "", "{0} বছৰৰ পূৰ্বে"],
+        future: ["", "", "", "", "", "{0} বছৰত"],
+    },
+];
+
+// az
+static R7: Names = [
+    Patterns {
+        current: "indi",
+        past: ["", "", "", "", "", "{0} saniyə öncə"],
+        future: ["", "", "", "", "", "{0} saniyə ərzində"],
+    },
+    Patterns {
+        current: "bu dəqiqə",
+        past: ["", "", "", "", "", "{0} dəqiqə öncə"],
+        future: ["", "", "", "", "", "{0} dəqiqə ərzində"],
+    },
+    Patterns {
+        current: "bu saat",
+        past: ["", "", "", "", "", "{0} saat öncə"],
+        future: ["", "", "", "", "", "{0} saat ərzində"],
+    },
+    Patterns {
+        current: "bu gün",
+        past: ["", "", "", "", "", "{0} gün öncə"],
+        future: ["", "", "", "", "", "{0} gün ərzində"],
+    },
+    Patterns {
+        current: "bu həftə",
+        past: ["", "", "", "", "", "{0} həftə öncə"],
+        future: ["", "", "", "", "", "{0} həftə ərzində"],
+    },
+    Patterns {
+        current: "bu ay",
+        past: ["", "", "", "", "", "{0} ay öncə"],
+        future: ["", "", "", "", "", "{0} ay ərzində"],
+    },
+    Patterns {
+        current: "bu il",
+        past: ["", "", "", "", "", "{0} il öncə"],
+        future: ["", "", "", "", "", "{0} il ərzində"],
+    },
+];
+
+// ba
+static R8: Names = [
+    Patterns {
+        current: "хәҙер",
+        past: ["", "", "", "", "", "{0} секунд элек"],
+        future: ["", "", "", "", "", "{0} секундтан"],
+    },
+    Patterns {
+        current: "был минутта",
+        past: ["", "", "", "", "", "{0} минут элек"],
+        future: ["", "", "", "", "", "{0} минуттан"],
+    },
+    Patterns {
+        current: "был сәғәттә",
+        past: ["", "", "", "", "", "{0} сәғәт элек"],
+        future: ["", "", "", "", "", "{0} сәғәттән"],
+    },
+    Patterns {
+        current: "бөгөн",
+        past: ["", "", "", "", "", "{0} көн элек"],
+        future: ["", "", "", "", "", "{0} көндән"],
+    },
+    Patterns {
+        current: "был аҙнала",
+        past: ["", "", "", "", "", "{0} аҙна элек"],
+        future: ["", "", "", "", "", "{0} аҙнанан"],
+    },
+    Patterns {
+        current: "был айҙа",
+        past: ["", "", "", "", "", "{0} ай элек"],
+        future: ["", "", "", "", "", "{0} айҙан"],
+    },
+    Patterns {
+        current: "быйыл",
+        past: ["", "", "", "", "", "{0} йыл элек"],
+        future: ["", "", "", "", "", "{0} йылдан"],
+    },
+];
+
+// eu
+static R9: Names = [
+    Patterns {
+        current: "orain",
+        past: ["", "", "", "", "", "duela {0} segundo"],
+        future: ["", "", "", "", "", "{0} segundo barru"],
+    },
+    Patterns {
+        current: "minutu honetan",
+        past: ["", "", "", "", "", "duela {0} minutu"],
+        future: ["", "", "", "", "", "{0} minutu barru"],
+    },
+    Patterns {
+        current: "ordu honetan",
+        past: ["", "", "", "", "", "duela {0} ordu"],
+        future: ["", "", "", "", "", "{0} ordu barru"],
+    },
+    Patterns {
+        current: "gaur",
+        past: ["", "", "", "", "", "duela {0} egun"],
+        future: ["", "", "", "", "", "{0} egun barru"],
+    },
+    Patterns {
+        current: "aste honetan",
+        past: ["", "", "", "", "", "duela {0} aste"],
+        future: ["", "", "", "", "", "{0} aste barru"],
+    },
+    Patterns {
+        current: "hilabete honetan",
+        past: ["", "", "", "", "", "duela {0} hilabete"],
+        future: ["", "", "", "", "", "{0} hilabete barru"],
+    },
+    Patterns {
+        current: "aurten",
+        past: ["", "", "", "", "", "duela {0} urte"],
+        future: ["", "", "", "", "", "{0} urte barru"],
+    },
+];
+
+// be
+static R10: Names = [
+    Patterns {
+        current: "цяпер",
+        past: ["", "{0} секунду таму", "", "", "{0} секунд таму", "{0} секунды таму"],
+        future: ["", "праз {0} секунду", "", "", "праз {0} секунд", "праз {0} секунды"],
+    },
+    Patterns {
+        current: "у гэту хвіліну",
+        past: ["", "{0} хвіліну таму", "", "", "{0} хвілін таму", "{0} хвіліны таму"],
+        future: ["", "праз {0} хвіліну", "", "", "праз {0} хвілін", "праз {0} хвіліны"],
+    },
+    Patterns {
+        current: "у гэту гадзіну",
+        past: ["", "{0} гадзіну таму", "", "", "{0} гадзін таму", "{0} гадзіны таму"],
+        future: ["", "праз {0} гадзіну", "", "", "праз {0} гадзін", "праз {0} гадзіны"],
+    },
+    Patterns {
+        current: "сёння",
+        past: ["", "{0} дзень таму", "", "{0} дні таму", "{0} дзён таму", "{0} дня таму"],
+        future: ["", "праз {0} дзень", "", "праз {0} дні", "праз {0} дзён", "праз {0} дня"],
+    },
+    Patterns {
+        current: "на гэтым тыдні",
+        past: ["", "{0} тыдзень таму", "", "{0} тыдні таму", "{0} тыдняў таму", "{0} тыдня таму"],
+        future: ["", "праз {0} тыдзень", "", "праз {0} тыдні", "праз {0} тыдняў", "праз {0} тыдня"],
+    },
+    Patterns {
+        current: "у гэтым месяцы",
+        past: ["", "{0} месяц таму", "", "{0} месяцы таму", "{0} месяцаў таму", "{0} месяца таму"],
+        future: ["", "праз {0} месяц", "", "праз {0} месяцы", "праз {0} месяцаў", "праз {0} месяца"],
+    },
+    Patterns {
+        current: "сёлета",
+        past: ["", "{0} год таму", "", "{0} гады таму", "{0} гадоў таму", "{0} года таму"],
+        future: ["", "праз {0} год", "", "праз {0} гады", "праз {0} гадоў", "праз {0} года"],
+    },
+];
+
+// bn
+static R11: Names = [
+    Patterns {
+        current: "এখন",
+        past: ["", "{0} সেকেন্ড আগে", "", "", "", "{0} সেকেন্ড পূর্বে"],
+        future: ["", "", "", "", "", "{0} সেকেন্ডে"],
+    },
+    Patterns {
+        current: "এই মিনিট",
+        past: ["", "", "", "", "", "{0} মিনিট আগে"],
+        future: ["", "", "", "", "", "{0} মিনিটে"],
+    },
+    Patterns {
+        current: "এই ঘণ্টায়",
+        past: ["", "", "", "", "", "{0} ঘণ্টা আগে"],
+        future: ["", "", "", "", "", "{0} ঘণ্টায়"],
+    },
+    Patterns {
+        current: "আজ",
+        past: ["", "", "", "", "", "{0} দিন আগে"],
+        future: ["", "", "", "", "", "{0} দিনের মধ্যে"],
+    },
+    Patterns {
+        current: "এই সপ্তাহ",
+        past: ["", "", "", "", "", "{0} সপ্তাহ আগে"],
+        future: ["", "", "", "", "", "{0} সপ্তাহে"],
+    },
+    Patterns {
+        current: "এই মাস",
+        past: ["", "", "", "", "", "{0} মাস আগে"],
+        future: ["", "", "", "", "", "{0} মাসে"],
+    },
+    Patterns {
+        current: "এই বছর",
+        past: ["", "", "", "", "", "{0} বছর পূর্বে"],
+        future: ["", "", "", "", "", "{0} বছরে"],
+    },
+];
+
+// bs
+static R12: Names = [
+    Patterns {
+        current: "sada",
+        past: ["", "prije {0} sekundu", "", "prije {0} sekunde", "", "prije {0} sekundi"],
+        future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"],
+    },
+    Patterns {
+        current: "ova minuta",
+        past: ["", "prije {0} minutu", "", "prije {0} minute", "", "prije {0} minuta"],
+        future: ["", "za {0} minutu", "", "za {0} minute", "", "za {0} minuta"],
+    },
+    Patterns {
+        current: "ovaj sat",
+        past: ["", "prije {0} sat", "", "prije {0} sata", "", "prije {0} sati"],
+        future: ["", "za {0} sat", "", "za {0} sata", "", "za {0} sati"],
+    },
+    Patterns {
+        current: "danas",
+        past: ["", "prije {0} dan", "", "", "", "prije {0} dana"],
+        future: ["", "za {0} dan", "", "", "", "za {0} dana"],
+    },
+    Patterns {
+        current: "ove sedmice",
+        past: ["", "prije {0} sedmicu", "", "prije {0} sedmice", "", "prije {0} sedmica"],
+        future: ["", "za {0} sedmicu", "", "za {0} sedmice", "", "za {0} sedmica"],
+    },
+    Patterns {
+        current: "ovaj mjesec",
+        past: ["", "prije {0} mjesec", "", "prije {0} mjeseca", "", "prije {0} mjeseci"],
+        future: ["", "za {0} mjesec", "", "za {0} mjeseca", "", "za {0} mjeseci"],
+    },
+    Patterns {
+        current: "ove godine",
+        past: ["", "prije {0} godinu", "", "prije {0} godine", "", "prije {0} godina"],
+        future: ["", "za {0} godinu", "", "za {0} godine", "", "za {0} godina"],
+    },
+];
+
+// br
+static R13: Names = [
+    Patterns {
+        current: "bremañ",
+        past: ["", "", "", "", "", "{0} eilenn zo"],
+        future: ["", "", "", "", "a-benn {0} a eilennoù", "a-benn {0} eilenn"],
+    },
+    Patterns {
+        current: "ar munut-mañ",
+        past: ["", "", "{0} vunut zo", "", "{0} a vunutoù zo", "{0} munut zo"],
+        future: ["", "", "a-benn {0} vunut", "", "a-benn {0} a vunutoù", "a-benn {0} munut"],
+    },
+    Patterns {
+        current: "dʼan eur-mañ",
+        past: ["", "", "", "", "{0} a eurioù zo", "{0} eur zo"],
+        future: ["", "", "", "", "a-benn {0} a eurioù", "a-benn {0} eur"],
+    },
+    Patterns {
+        current: "hiziv",
+        past: ["", "", "{0} zeiz zo", "", "{0} a zeizioù zo", "{0} deiz zo"],
+        future: ["", "", "a-benn {0} zeiz", "", "a-benn {0} a zeizioù", "a-benn {0} deiz"],
+    },
+    Patterns {
+        current: "ar sizhun-mañ",
+        past: ["", "", "", "", "{0} a sizhunioù zo", "{0} sizhun zo"],
+        future: ["", "", "", "", "a-benn {0} a sizhunioù", "a-benn {0} sizhun"],
+    },
+    Patterns {
+        current: "ar miz-mañ",
+        past: ["", "", "{0} viz zo", "", "{0} a vizioù zo", "{0} miz zo"],
+        future: ["", "", "a-benn {0} viz", "", "a-benn {0} a vizioù", "a-benn {0} miz"],
+    },
+    Patterns {
+        current: "hevlene",
+        past: ["", "{0} bloaz zo", "", "{0} bloaz zo", "{0} a vloazioù zo", "{0} vloaz zo"],
+        future: ["", "a-benn {0} bloaz", "", "a-benn {0} bloaz", "a-benn {0} a vloazioù", "a-benn {0} vloaz"],
+    },
+];
+
+// bg
+static R14: Names = [
+    Patterns {
+        current: "сега",
+        past: ["", "преди {0} секунда", "", "", "", "преди {0} секунди"],
+        future: ["", "след {0} секунда", "", "", "", "след {0} секунди"],
+    },
+    Patterns {
+        current: "в тази минута",
+        past: ["", "преди {0} минута", "", "", "", "преди {0} минути"],
+        future: ["", "след {0} минута", "", "", "", "след {0} минути"],
+    },
+    Patterns {
+        current: "в този час",
+        past: ["", "преди {0} час", "", "", "", "преди {0} часа"],
+        future: ["", "след {0} час", "", "", "", "след {0} часа"],
+    },
+    Patterns {
+        current: "днес",
+        past: ["", "преди {0} ден", "", "", "", "преди {0} дни"],
+        future: ["", "след {0} ден", "", "", "", "след {0} дни"],
+    },
+    Patterns {
+        current: "тази седмица",
+        past: ["", "преди {0} седмица", "", "", "", "преди {0} седмици"],
+        future: ["", "след {0} седмица", "", "", "", "след {0} седмици"],
+    },
+    Patterns {
+        current: "този месец",
+        past: ["", "преди {0} месец", "", "", "", "преди {0} месеца"],
+        future: ["", "след {0} месец", "", "", "", "след {0} месеца"],
+    },
+    Patterns {
+        current: "тази година",
+        past: ["", "преди {0} година", "", "", "", "преди {0} години"],
+        future: ["", "след {0} година", "", "", "", "след {0} години"],
+    },
+];
+
+// my
+static R15: Names = [
+    Patterns {
+        current: "ယခု",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} စက္ကန့်"],
+        future: ["", "", "", "", "", "{0} စက္ကန့်အတွင်း"],
+    },
+    Patterns {
+        current: "ဤမိနစ်",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} မိနစ်"],
+        future: ["", "", "", "", "", "{0} မိနစ်အတွင်း"],
+    },
+    Patterns {
+        current: "ဤအချိန်",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} နာရီ"],
+        future: ["", "", "", "", "", "{0} နာရီအတွင်း"],
+    },
+    Patterns {
+        current: "ယနေ့",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} ရက်"],
+        future: ["", "", "", "", "", "{0} ရက်အတွင်း"],
+    },
+    Patterns {
+        current: "ယခု သီတင်းပတ်",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} ပတ်"],
+        future: ["", "", "", "", "", "{0} ပတ်အတွင်း"],
+    },
+    Patterns {
+        current: "ယခုလ",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} လ"],
+        future: ["", "", "", "", "", "{0} လအတွင်း"],
+    },
+    Patterns {
+        current: "ယခုနှစ်",
+        past: ["", "", "", "", "", "ပြီးခဲ့သည့် {0} နှစ်"],
+        future: ["", "", "", "", "", "{0} နှစ်အတွင်း"],
+    },
+];
+
+// ca
+static R16: Names = [
+    Patterns {
+        current: "ara",
+        past: ["", "fa {0} segon", "", "", "", "fa {0} segons"],
+        future: ["", "d’aquí a {0} segon", "", "", "", "d’aquí a {0} segons"],
+    },
+    Patterns {
+        current: "aquest minut",
+        past: ["", "fa {0} minut", "", "", "", "fa {0} minuts"],
+        future: ["", "d’aquí a {0} minut", "", "", "", "d’aquí a {0} minuts"],
+    },
+    Patterns {
+        current: "aquesta hora",
+        past: ["", "fa {0} hora", "", "", "", "fa {0} hores"],
+        future: ["", "d’aquí a {0} hora", "", "", "", "d’aquí a {0} hores"],
+    },
+    Patterns {
+        current: "avui",
+        past: ["", "fa {0} dia", "", "", "", "fa {0} dies"],
+        future: ["", "d’aquí a {0} dia", "", "", "", "d’aquí a {0} dies"],
+    },
+    Patterns {
+        current: "aquesta setmana",
+        past: ["", "fa {0} setmana", "", "", "", "fa {0} setmanes"],
+        future: ["", "d’aquí a {0} setmana", "", "", "", "d’aquí a {0} setmanes"],
+    },
+    Patterns {
+        current: "aquest mes",
+        past: ["", "fa {0} mes", "", "", "", "fa {0} mesos"],
+        future: ["", "d’aquí a {0} mes", "", "", "", "d’aquí a {0} mesos"],
+    },
+    Patterns {
+        current: "enguany",
+        past: ["", "fa {0} any", "", "", "", "fa {0} anys"],
+        future: ["", "d’aquí a {0} any", "", "", "", "d’aquí a {0} anys"],
+    },
+];
+
+// zh
+static R17: Names = [
+    Patterns {
+        current: "现在",
+        past: ["", "", "", "", "", "{0}秒钟前"],
+        future: ["", "", "", "", "", "{0}秒钟后"],
+    },
+    Patterns {
+        current: "此刻",
+        past: ["", "", "", "", "", "{0}分钟前"],
+        future: ["", "", "", "", "", "{0}分钟后"],
+    },
+    Patterns {
+        current: "这一时间 / 此时",
+        past: ["", "", "", "", "", "{0}小时前"],
+        future: ["", "", "", "", "", "{0}小时后"],
+    },
+    Patterns {
+        current: "今天",
+        past: ["", "", "", "", "", "{0}天前"],
+        future: ["", "", "", "", "", "{0}天后"],
+    },
+    Patterns {
+        current: "本周",
+        past: ["", "", "", "", "", "{0}周前"],
+        future: ["", "", "", "", "", "{0}周后"],
+    },
+    Patterns {
+        current: "本月",
+        past: ["", "", "", "", "", "{0}个月前"],
+        future: ["", "", "", "", "", "{0}个月后"],
+    },
+    Patterns {
+        current: "今年",
+        past: ["", "", "", "", "", "{0}年前"],
+        future: ["", "", "", "", "", "{0}年后"],
+    },
+];
+
+// cv
+static R18: Names = [
+    Patterns {
+        current: "халь",
+        past: ["", "", "", "", "", "{0} ҫеккунт каялла"],
+        future: ["", "", "", "", "", "{0} ҫеккунтран"],
+    },
+    Patterns {
+        current: "ку минутра",
+        past: ["", "", "", "", "", "{0} минут каялла"],
+        future: ["", "", "", "", "", "{0} минутран"],
+    },
+    Patterns {
+        current: "ку сехетре",
+        past: ["", "", "", "", "", "{0} сехет каялла"],
+        future: ["", "", "", "", "", "{0} сехетрен"],
+    },
+    Patterns {
+        current: "паян",
+        past: ["", "", "", "", "", "{0} кун каялла"],
+        future: ["", "", "", "", "", "{0} кунтан"],
+    },
+    Patterns {
+        current: "ку эрнере",
+        past: ["", "", "", "", "", "{0} эрне каялла"],
+        future: ["", "", "", "", "", "{0} эрнерен"],
+    },
+    Patterns {
+        current: "ку уйӑхра",
+        past: ["", "", "", "", "", "{0} уйӑх каялла"],
+        future: ["", "", "", "", "", "{0} уйӑхран"],
+    },
+    Patterns {
+        current: "кӑҫал",
+        past: ["", "", "", "", "", "{0} ҫул каялла"],
+        future: ["", "", "", "", "", "{0} ҫултан"],
+    },
+];
+
+// hr
+static R19: Names = [
+    Patterns {
+        current: "sad",
+        past: ["", "prije {0} sekundu", "", "prije {0} sekunde", "", "prije {0} sekundi"],
+        future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"],
+    },
+    Patterns {
+        current: "ova minuta",
+        past: ["", "prije {0} minutu", "", "prije {0} minute", "", "prije {0} minuta"],
+        future: ["", "za {0} minutu", "", "za {0} minute", "", "za {0} minuta"],
+    },
+    Patterns {
+        current: "ovaj sat",
+        past: ["", "prije {0} sat", "", "prije {0} sata", "", "prije {0} sati"],
+        future: ["", "za {0} sat", "", "za {0} sata", "", "za {0} sati"],
+    },
+    Patterns {
+        current: "danas",
+        past: ["", "prije {0} dan", "", "", "", "prije {0} dana"],
+        future: ["", "za {0} dan", "", "", "", "za {0} dana"],
+    },
+    Patterns {
+        current: "ovaj tjedan",
+        past: ["", "prije {0} tjedan", "", "prije {0} tjedna", "", "prije {0} tjedana"],
+        future: ["", "za {0} tjedan", "", "za {0} tjedna", "", "za {0} tjedana"],
+    },
+    Patterns {
+        current: "ovaj mjesec",
+        past: ["", "prije {0} mjesec", "", "prije {0} mjeseca", "", "prije {0} mjeseci"],
+        future: ["", "za {0} mjesec", "", "za {0} mjeseca", "", "za {0} mjeseci"],
+    },
+    Patterns {
+        current: "ove godine",
+        past: ["", "prije {0} godinu", "", "prije {0} godine", "", "prije {0} godina"],
+        future: ["", "za {0} godinu", "", "za {0} godine", "", "za {0} godina"],
+    },
+];
+
+// cs
+static R20: Names = [
+    Patterns {
+        current: "nyní",
+        past: ["", "před {0} sekundou", "", "", "před {0} sekundy", "před {0} sekundami"],
+        future: ["", "za {0} sekundu", "", "za {0} sekundy", "za {0} sekundy", "za {0} sekund"],
+    },
+    Patterns {
+        current: "tuto minutu",
+        past: ["", "před {0} minutou", "", "", "před {0} minuty", "před {0} minutami"],
+        future: ["", "za {0} minutu", "", "za {0} minuty", "za {0} minuty", "za {0} minut"],
+    },
+    Patterns {
+        current: "tuto hodinu",
+        past: ["", "před {0} hodinou", "", "", "před {0} hodiny", "před {0} hodinami"],
+        future: ["", "za {0} hodinu", "", "za {0} hodiny", "za {0} hodiny", "za {0} hodin"],
+    },
+    Patterns {
+        current: "dnes",
+        past: ["", "před {0} dnem", "", "", "před {0} dne", "před {0} dny"],
+        future: ["", "za {0} den", "", "za {0} dny", "za {0} dne", "za {0} dní"],
+    },
+    Patterns {
+        current: "tento týden",
+        past: ["", "před {0} týdnem", "", "", "před {0} týdne", "před {0} týdny"],
+        future: ["", "za {0} týden", "", "za {0} týdny", "za {0} týdne", "za {0} týdnů"],
+    },
+    Patterns {
+        current: "tento měsíc",
+        past: ["", "před {0} měsícem", "", "", "před {0} měsíce", "před {0} měsíci"],
+        future: ["", "za {0} měsíc", "", "za {0} měsíce", "za {0} měsíce", "za {0} měsíců"],
+    },
+    Patterns {
+        current: "tento rok",
+        past: ["", "před {0} rokem", "", "", "před {0} roku", "před {0} lety"],
+        future: ["", "za {0} rok", "", "za {0} roky", "za {0} roku", "za {0} let"],
+    },
+];
+
+// da
+static R21: Names = [
+    Patterns {
+        current: "nu",
+        past: ["", "for {0} sekund siden", "", "", "", "for {0} sekunder siden"],
+        future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"],
+    },
+    Patterns {
+        current: "dette minut",
+        past: ["", "for {0} minut siden", "", "", "", "for {0} minutter siden"],
+        future: ["", "om {0} minut", "", "", "", "om {0} minutter"],
+    },
+    Patterns {
+        current: "denne time",
+        past: ["", "for {0} time siden", "", "", "", "for {0} timer siden"],
+        future: ["", "om {0} time", "", "", "", "om {0} timer"],
+    },
+    Patterns {
+        current: "i dag",
+        past: ["", "for {0} dag siden", "", "", "", "for {0} dage siden"],
+        future: ["", "om {0} dag", "", "", "", "om {0} dage"],
+    },
+    Patterns {
+        current: "denne uge",
+        past: ["", "for {0} uge siden", "", "", "", "for {0} uger siden"],
+        future: ["", "om {0} uge", "", "", "", "om {0} uger"],
+    },
+    Patterns {
+        current: "denne måned",
+        past: ["", "for {0} måned siden", "", "", "", "for {0} måneder siden"],
+        future: ["", "om {0} måned", "", "", "", "om {0} måneder"],
+    },
+    Patterns {
+        current: "i år",
+        past: ["", "", "", "", "", "for {0} år siden"],
+        future: ["", "", "", "", "", "om {0} år"],
+    },
+];
+
+// nl
+static R22: Names = [
+    Patterns {
+        current: "nu",
+        past: ["", "{0} seconde geleden", "", "", "", "{0} seconden geleden"],
+        future: ["", "over {0} seconde", "", "", "", "over {0} seconden"],
+    },
+    Patterns {
+        current: "binnen een minuut",
+        past: ["", "{0} minuut geleden", "", "", "", "{0} minuten geleden"],
+        future: ["", "over {0} minuut", "", "", "", "over {0} minuten"],
+    },
+    Patterns {
+        current: "binnen een uur",
+        past: ["", "", "", "", "", "{0} uur geleden"],
+        future: ["", "", "", "", "", "over {0} uur"],
+    },
+    Patterns {
+        current: "vandaag",
+        past: ["", "{0} dag geleden", "", "", "", "{0} dagen geleden"],
+        future: ["", "over {0} dag", "", "", "", "over {0} dagen"],
+    },
+    Patterns {
+        current: "deze week",
+        past: ["", "{0} week geleden", "", "", "", "{0} weken geleden"],
+        future: ["", "over {0} week", "", "", "", "over {0} weken"],
+    },
+    Patterns {
+        current: "deze maand",
+        past: ["", "{0} maand geleden", "", "", "", "{0} maanden geleden"],
+        future: ["", "over {0} maand", "", "", "", "over {0} maanden"],
+    },
+    Patterns {
+        current: "dit jaar",
+        past: ["", "", "", "", "", "{0} jaar geleden"],
+        future: ["", "", "", "", "", "over {0} jaar"],
+    },
+];
+
+// en
+static R23: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "{0} second ago", "", "", "", "{0} seconds ago"],
+        future: ["", "in {0} second", "", "", "", "in {0} seconds"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "{0} minute ago", "", "", "", "{0} minutes ago"],
+        future: ["", "in {0} minute", "", "", "", "in {0} minutes"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "{0} hour ago", "", "", "", "{0} hours ago"],
+        future: ["", "in {0} hour", "", "", "", "in {0} hours"],
+    },
+    Patterns {
+        current: "today",
+        past: ["", "{0} day ago", "", "", "", "{0} days ago"],
+        future: ["", "in {0} day", "", "", "", "in {0} days"],
+    },
+    Patterns {
+        current: "this week",
+        past: ["", "{0} week ago", "", "", "", "{0} weeks ago"],
+        future: ["", "in {0} week", "", "", "", "in {0} weeks"],
+    },
+    Patterns {
+        current: "this month",
+        past: ["", "{0} month ago", "", "", "", "{0} months ago"],
+        future: ["", "in {0} month", "", "", "", "in {0} months"],
+    },
+    Patterns {
+        current: "this year",
+        past: ["", "{0} year ago", "", "", "", "{0} years ago"],
+        future: ["", "in {0} year", "", "", "", "in {0} years"],
+    },
+];
+
+// eo
+static R24: Names = [
+    Patterns {
+        current: "nun",
+        past: ["", "antaŭ {0} sekundo", "", "", "", "antaŭ {0} sekundoj"],
+        future: ["", "post {0} sekundo", "", "", "", "post {0} sekundoj"],
+    },
+    Patterns {
+        current: "nuna minuto",
+        past: ["", "antaŭ {0} minuto", "", "", "", "antaŭ {0} minutoj"],
+        future: ["", "post {0} minuto", "", "", "", "post {0} minutoj"],
+    },
+    Patterns {
+        current: "nuna horo",
+        past: ["", "antaŭ {0} horo", "", "", "", "antaŭ {0} horoj"],
+        future: ["", "post {0} horo", "", "", "", "post {0} horoj"],
+    },
+    Patterns {
+        current: "hodiaŭ",
+        past: ["", "antaŭ {0} tago", "", "", "", "antaŭ {0} tagoj"],
+        future: ["", "post {0} tago", "", "", "", "post {0} tagoj"],
+    },
+    Patterns {
+        current: "nuna semajno",
+        past: ["", "antaŭ {0} semajno", "", "", "", "antaŭ {0} semajnoj"],
+        future: ["", "post {0} semajno", "", "", "", "post {0} semajnoj"],
+    },
+    Patterns {
+        current: "nuna monato",
+        past: ["", "antaŭ {0} monato", "", "", "", "antaŭ {0} monatoj"],
+        future: ["", "post {0} monato", "", "", "", "post {0} monatoj"],
+    },
+    Patterns {
+        current: "nuna jaro",
+        past: ["", "antaŭ {0} jaro", "", "", "", "antaŭ {0} jaroj"],
+        future: ["", "post {0} jaro", "", "", "", "post {0} jaroj"],
+    },
+];
+
+// et
+static R25: Names = [
+    Patterns {
+        current: "nüüd",
+        past: ["", "", "", "", "", "{0} sekundi eest"],
+        future: ["", "", "", "", "", "{0} sekundi pärast"],
+    },
+    Patterns {
+        current: "praegusel minutil",
+        past: ["", "", "", "", "", "{0} minuti eest"],
+        future: ["", "", "", "", "", "{0} minuti pärast"],
+    },
+    Patterns {
+        current: "praegusel tunnil",
+        past: ["", "", "", "", "", "{0} tunni eest"],
+        future: ["", "", "", "", "", "{0} tunni pärast"],
+    },
+    Patterns {
+        current: "täna",
+        past: ["", "", "", "", "", "{0} päeva eest"],
+        future: ["", "", "", "", "", "{0} päeva pärast"],
+    },
+    Patterns {
+        current: "käesolev nädal",
+        past: ["", "", "", "", "", "{0} nädala eest"],
+        future: ["", "", "", "", "", "{0} nädala pärast"],
+    },
+    Patterns {
+        current: "käesolev kuu",
+        past: ["", "", "", "", "", "{0} kuu eest"],
+        future: ["", "", "", "", "", "{0} kuu pärast"],
+    },
+    Patterns {
+        current: "käesolev aasta",
+        past: ["", "", "", "", "", "{0} aasta eest"],
+        future: ["", "", "", "", "", "{0} aasta pärast"],
+    },
+];
+
+// ee
+static R26: Names = [
+    Patterns {
+        current: "fifi",
+        past: ["", "sekend {0} si va yi", "", "", "", "sekend {0} si wo va yi"],
+        future: ["", "le sekend {0} me", "", "", "", "le sekend {0} wo me"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "aɖabaƒoƒo {0} si va yi", "", "", "", "aɖabaƒoƒo {0} si wo va yi"],
+        future: ["", "le aɖabaƒoƒo {0} me", "", "", "", "le aɖabaƒoƒo {0} wo me"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "gaƒoƒo {0} si va yi", "", "", "", "gaƒoƒo {0} si wo va yi"],
+        future: ["", "le gaƒoƒo {0} me", "", "", "", "le gaƒoƒo {0} wo me"],
+    },
+    Patterns {
+        current: "egbe",
+        past: ["", "ŋkeke {0} si va yi", "", "", "", "ŋkeke {0} si wo va yi"],
+        future: ["", "le ŋkeke {0} me", "", "", "", "le ŋkeke {0} wo me"],
+    },
+    Patterns {
+        current: "kɔsiɖa sia",
+        past: ["", "kɔsiɖa {0} si va yi", "", "", "", "kɔsiɖa {0} si wo va yi"],
+        future: ["", "le kɔsiɖa {0} me", "", "", "", "le kɔsiɖa {0} wo me"],
+    },
+    Patterns {
+        current: "ɣleti sia",
+        past: ["", "ɣleti {0} si va yi", "", "", "", "ɣleti {0} si wo va yi"],
+        future: ["", "le ɣleti {0} me", "", "", "", "le ɣleti {0} wo me"],
+    },
+    Patterns {
+        current: "ƒe sia",
+        past: ["", "ƒe {0} si va yi", "", "", "", "ƒe {0} si wo va yi"],
+        future: ["", "", "", "", "", "le ƒe {0} me"],
+    },
+];
+
+// fo
+static R27: Names = [
+    Patterns {
+        current: "nú",
+        past: ["", "", "", "", "", "{0} sekund síðan"],
+        future: ["", "", "", "", "", "um {0} sekund"],
+    },
+    Patterns {
+        current: "hendan minuttin",
+        past: ["", "{0} minutt síðan", "", "", "", "{0} minuttir síðan"],
+        future: ["", "um {0} minutt", "", "", "", "um {0} minuttir"],
+    },
+    Patterns {
+        current: "hendan tíman",
+        past: ["", "{0} tími síðan", "", "", "", "{0} tímar síðan"],
+        future: ["", "um {0} tíma", "", "", "", "um {0} tímar"],
+    },
+    Patterns {
+        current: "í dag",
+        past: ["", "{0} dagur síðan", "", "", "", "{0} dagar síðan"],
+        future: ["", "um {0} dag", "", "", "", "um {0} dagar"],
+    },
+    Patterns {
+        current: "hesu viku",
+        past: ["", "{0} vika síðan", "", "", "", "{0} vikur síðan"],
+        future: ["", "um {0} viku", "", "", "", "um {0} vikur"],
+    },
+    Patterns {
+        current: "henda mánaðin",
+        past: ["", "{0} mánað síðan", "", "", "", "{0} mánaðir síðan"],
+        future: ["", "um {0} mánað", "", "", "", "um {0} mánaðir"],
+    },
+    Patterns {
+        current: "í ár",
+        past: ["", "", "", "", "", "{0} ár síðan"],
+        future: ["", "", "", "", "", "um {0} ár"],
+    },
+];
+
+// fi
+static R28: Names = [
+    Patterns {
+        current: "nyt",
+        past: ["", "{0} sekunti sitten", "", "", "", "{0} sekuntia sitten"],
+        future: ["", "", "", "", "", "{0} sekunnin päästä"],
+    },
+    Patterns {
+        current: "tämän minuutin aikana",
+        past: ["", "{0} minuutti sitten", "", "", "", "{0} minuuttia sitten"],
+        future: ["", "", "", "", "", "{0} minuutin päästä"],
+    },
+    Patterns {
+        current: "tämän tunnin aikana",
+        past: ["", "{0} tunti sitten", "", "", "", "{0} tuntia sitten"],
+        future: ["", "", "", "", "", "{0} tunnin päästä"],
+    },
+    Patterns {
+        current: "tänään",
+        past: ["", "{0} päivä sitten", "", "", "", "{0} päivää sitten"],
+        future: ["", "", "", "", "", "{0} päivän päästä"],
+    },
+    Patterns {
+        current: "tällä viikolla",
+        past: ["", "{0} viikko sitten", "", "", "", "{0} viikkoa sitten"],
+        future: ["", "", "", "", "", "{0} viikon päästä"],
+    },
+    Patterns {
+        current: "tässä kuussa",
+        past: ["", "{0} kuukausi sitten", "", "", "", "{0} kuukautta sitten"],
+        future: ["", "", "", "", "", "{0} kuukauden päästä"],
+    },
+    Patterns {
+        current: "tänä vuonna",
+        past: ["", "{0} vuosi sitten", "", "", "", "{0} vuotta sitten"],
+        future: ["", "", "", "", "", "{0} vuoden päästä"],
+    },
+];
+
+// fr, ht
+static R29: Names = [
+    Patterns {
+        current: "maintenant",
+        past: ["", "il y a {0} seconde", "", "", "", "il y a {0} secondes"],
+        future: ["", "dans {0} seconde", "", "", "", "dans {0} secondes"],
+    },
+    Patterns {
+        current: "cette minute-ci",
+        past: ["", "il y a {0} minute", "", "", "", "il y a {0} minutes"],
+        future: ["", "dans {0} minute", "", "", "", "dans {0} minutes"],
+    },
+    Patterns {
+        current: "cette heure-ci",
+        past: ["", "il y a {0} heure", "", "", "", "il y a {0} heures"],
+        future: ["", "dans {0} heure", "", "", "", "dans {0} heures"],
+    },
+    Patterns {
+        current: "aujourd’hui",
+        past: ["", "il y a {0} jour", "", "", "", "il y a {0} jours"],
+        future: ["", "dans {0} jour", "", "", "", "dans {0} jours"],
+    },
+    Patterns {
+        current: "cette semaine",
+        past: ["", "il y a {0} semaine", "", "", "", "il y a {0} semaines"],
+        future: ["", "dans {0} semaine", "", "", "", "dans {0} semaines"],
+    },
+    Patterns {
+        current: "ce mois-ci",
+        past: ["", "", "", "", "", "il y a {0} mois"],
+        future: ["", "", "", "", "", "dans {0} mois"],
+    },
+    Patterns {
+        current: "cette année",
+        past: ["", "il y a {0} an", "", "", "", "il y a {0} ans"],
+        future: ["", "dans {0} an", "", "", "", "dans {0} ans"],
+    },
+];
+
+// gl
+static R30: Names = [
+    Patterns {
+        current: "agora",
+        past: ["", "hai {0} segundo", "", "", "", "hai {0} segundos"],
+        future: ["", "en {0} segundo", "", "", "", "en {0} segundos"],
+    },
+    Patterns {
+        current: "este minuto",
+        past: ["", "hai {0} minuto", "", "", "", "hai {0} minutos"],
+        future: ["", "en {0} minuto", "", "", "", "en {0} minutos"],
+    },
+    Patterns {
+        current: "esta hora",
+        past: ["", "hai {0} hora", "", "", "", "hai {0} horas"],
+        future: ["", "en {0} hora", "", "", "", "en {0} horas"],
+    },
+    Patterns {
+        current: "hoxe",
+        past: ["", "hai {0} día", "", "", "", "hai {0} días"],
+        future: ["", "en {0} día", "", "", "", "en {0} días"],
+    },
+    Patterns {
+        current: "esta semana",
+        past: ["", "hai {0} semana", "", "", "", "hai {0} semanas"],
+        future: ["", "en {0} semana", "", "", "", "en {0} semanas"],
+    },
+    Patterns {
+        current: "este mes",
+        past: ["", "hai {0} mes", "", "", "", "hai {0} meses"],
+        future: ["", "en {0} mes", "", "", "", "en {0} meses"],
+    },
+    Patterns {
+        current: "este ano",
+        past: ["", "hai {0} ano", "", "", "", "hai {0} anos"],
+        future: ["", "en {0} ano", "", "", "", "en {0} anos"],
+    },
+];
+
+// ka
+static R31: Names = [
+    Patterns {
+        current: "ახლა",
+        past: ["", "", "", "", "", "{0} წამის წინ"],
+        future: ["", "", "", "", "", "{0} წამში"],
+    },
+    Patterns {
+        current: "ამ წუთში",
+        past: ["", "", "", "", "", "{0} წუთის წინ"],
+        future: ["", "", "", "", "", "{0} წუთში"],
+    },
+    Patterns {
+        current: "ამ საათში",
+        past: ["", "", "", "", "", "{0} საათის წინ"],
+        future: ["", "", "", "", "", "{0} საათში"],
+    },
+    Patterns {
+        current: "დღეს",
+        past: ["", "", "", "", "", "{0} დღის წინ"],
+        future: ["", "", "", "", "", "{0} დღეში"],
+    },
+    Patterns {
+        current: "ამ კვირაში",
+        past: ["", "", "", "", "", "{0} კვირის წინ"],
+        future: ["", "", "", "", "", "{0} კვირაში"],
+    },
+    Patterns {
+        current: "ამ თვეში",
+        past: ["", "", "", "", "", "{0} თვის წინ"],
+        future: ["", "", "", "", "", "{0} თვეში"],
+    },
+    Patterns {
+        current: "ამ წელს",
+        past: ["", "", "", "", "", "{0} წლის წინ"],
+        future: ["", "", "", "", "", "{0} წელიწადში"],
+    },
+];
+
+// de
+static R32: Names = [
+    Patterns {
+        current: "jetzt",
+        past: ["", "vor {0} Sekunde", "", "", "", "vor {0} Sekunden"],
+        future: ["", "in {0} Sekunde", "", "", "", "in {0} Sekunden"],
+    },
+    Patterns {
+        current: "in dieser Minute",
+        past: ["", "vor {0} Minute", "", "", "", "vor {0} Minuten"],
+        future: ["", "in {0} Minute", "", "", "", "in {0} Minuten"],
+    },
+    Patterns {
+        current: "in dieser Stunde",
+        past: ["", "vor {0} Stunde", "", "", "", "vor {0} Stunden"],
+        future: ["", "in {0} Stunde", "", "", "", "in {0} Stunden"],
+    },
+    Patterns {
+        current: "heute",
+        past: ["", "vor {0} Tag", "", "", "", "vor {0} Tagen"],
+        future: ["", "in {0} Tag", "", "", "", "in {0} Tagen"],
+    },
+    Patterns {
+        current: "diese Woche",
+        past: ["", "vor {0} Woche", "", "", "", "vor {0} Wochen"],
+        future: ["", "in {0} Woche", "", "", "", "in {0} Wochen"],
+    },
+    Patterns {
+        current: "diesen Monat",
+        past: ["", "vor {0} Monat", "", "", "", "vor {0} Monaten"],
+        future: ["", "in {0} Monat", "", "", "", "in {0} Monaten"],
+    },
+    Patterns {
+        current: "dieses Jahr",
+        past: ["", "vor {0} Jahr", "", "", "", "vor {0} Jahren"],
+        future: ["", "in {0} Jahr", "", "", "", "in {0} Jahren"],
+    },
+];
+
+// el
+static R33: Names = [
+    Patterns {
+        current: "τώρα",
+        past: ["", "πριν από {0} δευτερόλεπτο", "", "", "", "πριν από {0} δευτερόλεπτα"],
+        future: ["", "σε {0} δευτερόλεπτο", "", "", "", "σε {0} δευτερόλεπτα"],
+    },
+    Patterns {
+        current: "τρέχον λεπτό",
+        past: ["", "πριν από {0} λεπτό", "", "", "", "πριν από {0} λεπτά"],
+        future: ["", "σε {0} λεπτό", "", "", "", "σε {0} λεπτά"],
+    },
+    Patterns {
+        current: "τρέχουσα ώρα",
+        past: ["", "πριν από {0} ώρα", "", "", "", "πριν από {0} ώρες"],
+        future: ["", "σε {0} ώρα", "", "", "", "σε {0} ώρες"],
+    },
+    Patterns {
+        current: "σήμερα",
+        past: ["", "πριν από {0} ημέρα", "", "", "", "πριν από {0} ημέρες"],
+        future: ["", "σε {0} ημέρα", "", "", "", "σε {0} ημέρες"],
+    },
+    Patterns {
+        current: "τρέχουσα εβδομάδα",
+        past: ["", "πριν από {0} εβδομάδα", "", "", "", "πριν από {0} εβδομάδες"],
+        future: ["", "σε {0} εβδομάδα", "", "", "", "σε {0} εβδομάδες"],
+    },
+    Patterns {
+        current: "τρέχων μήνας",
+        past: ["", "πριν από {0} μήνα", "", "", "", "πριν από {0} μήνες"],
+        future: ["", "σε {0} μήνα", "", "", "", "σε {0} μήνες"],
+    },
+    Patterns {
+        current: "φέτος",
+        past: ["", "πριν από {0} έτος", "", "", "", "πριν από {0} έτη"],
+        future: ["", "σε {0} έτος", "", "", "", "σε {0} έτη"],
+    },
+];
+
+// gu
+static R34: Names = [
+    Patterns {
+        current: "હમણાં",
+        past: ["", "", "", "", "", "{0} સેકંડ પહેલાં"],
+        future: ["", "", "", "", "", "{0} સેકંડમાં"],
+    },
+    Patterns {
+        current: "આ મિનિટ",
+        past: ["", "", "", "", "", "{0} મિનિટ પહેલાં"],
+        future: ["", "", "", "", "", "{0} મિનિટમાં"],
+    },
+    Patterns {
+        current: "આ કલાક",
+        past: ["", "", "", "", "", "{0} કલાક પહેલાં"],
+        future: ["", "", "", "", "", "{0} કલાકમાં"],
+    },
+    Patterns {
+        current: "આજે",
+        past: ["", "", "", "", "", "{0} દિવસ પહેલાં"],
+        future: ["", "", "", "", "", "{0} દિવસમાં"],
+    },
+    Patterns {
+        current: "આ અઠવાડિયે",
+        past: ["", "", "", "", "", "{0} અઠવાડિયા પહેલાં"],
+        future: ["", "", "", "", "", "{0} અઠવાડિયામાં"],
+    },
+    Patterns {
+        current: "આ મહિને",
+        past: ["", "", "", "", "", "{0} મહિના પહેલાં"],
+        future: ["", "", "", "", "", "{0} મહિનામાં"],
+    },
+    Patterns {
+        current: "આ વર્ષે",
+        past: ["", "", "", "", "", "{0} વર્ષ પહેલાં"],
+        future: ["", "", "", "", "", "{0} વર્ષમાં"],
+    },
+];
+
+// ha
+static R35: Names = [
+    Patterns {
+        current: "yanzu",
+        past: ["", "dakika {0} da ta gabata", "", "", "", "dakiku {0} da suka gabata"],
+        future: ["", "cikin dakika {0}", "", "", "", "cikin dakiku {0}"],
+    },
+    Patterns {
+        current: "wannan mintin",
+        past: ["", "minti {0} da ya gabata", "", "", "", "mintuna {0} da suka gabata"],
+        future: ["", "cikin minti {0}", "", "", "", "cikin mintuna {0}"],
+    },
+    Patterns {
+        current: "wannan awa",
+        past: ["", "awa {0} da ta gabata", "", "", "", "awanni {0} da suka gabata"],
+        future: ["", "cikin awa {0}", "", "", "", "cikin awanni {0}"],
+    },
+    Patterns {
+        current: "yau",
+        past: ["", "kwana {0} da ya gabata", "", "", "", "kwanaki {0} da suka gabata"],
+        future: ["", "", "", "", "", "a cikin kwanaki {0}"],
+    },
+    Patterns {
+        current: "wannan satin",
+        past: ["", "mako {0} da ya gabata", "", "", "", "makonni {0} da suka gabata"],
+        future: ["", "a cikin mako {0}", "", "", "", "a cikin makonni {0}"],
+    },
+    Patterns {
+        current: "wannan watan",
+        past: ["", "wata {0} da ya gabata", "", "", "", "watanni {0} da suka gabata"],
+        future: ["", "a cikin watan {0}", "", "", "", "a cikin watanni {0}"],
+    },
+    Patterns {
+        current: "bana",
+        past: ["", "shekara {0} da ta gabata", "", "", "", "shekaru {0} da suka gabata"],
+        future: ["", "a shekarar {0}", "", "", "", "a shekaru {0}"],
+    },
+];
+
+// he
+static R36: Names = [
+    Patterns {
+        current: "עכשיו",
+        past: ["", "לפני שנייה", "לפני שתי שניות", "", "", "לפני {0} שניות"],
+        future: ["", "בעוד שנייה ({0})", "בעוד שתי שניות", "", "", "בעוד {0} שניות"],
+    },
+    Patterns {
+        current: "בדקה זו",
+        past: ["", "לפני דקה ({0})", "", "", "", "לפני {0} דקות"],
+        future: ["", "בעוד דקה ({0})", "", "", "", "בעוד {0} דקות"],
+    },
+    Patterns {
+        current: "בשעה זו",
+        past: ["", "לפני שעה ({0})", "לפני שעתיים ({0})", "", "", "לפני {0} שעות"],
+        future: ["", "בעוד שעה ({0})", "בעוד שעתיים ({0})", "", "", "בעוד {0} שעות"],
+    },
+    Patterns {
+        current: "היום",
+        past: ["", "לפני יום אחד ({0})", "לפני יומיים ({0})", "", "", "לפני {0} ימים"],
+        future: ["", "בעוד יום ({0})", "בעוד יומיים ({0})", "", "", "בעוד {0} ימים"],
+    },
+    Patterns {
+        current: "השבוע",
+        past: ["", "לפני שבוע ({0})", "לפני שבועיים ({0})", "", "", "לפני {0} שבועות"],
+        future: ["", "בעוד שבוע ({0})", "בעוד שבועיים ({0})", "", "", "בעוד {0} שבועות"],
+    },
+    Patterns {
+        current: "החודש",
+        past: ["", "לפני חודש ({0})", "לפני חודשיים ({0})", "", "", "לפני {0} חודשים"],
+        future: ["", "בעוד חודש ({0})", "בעוד חודשיים ({0})", "", "", "בעוד {0} חודשים"],
+    },
+    Patterns {
+        current: "השנה",
+        past: ["", "לפני שנה", "לפני שנתיים", "", "", "לפני {0} שנים"],
+        future: ["", "בעוד שנה", "בעוד שנתיים", "", "", "בעוד {0} שנים"],
+    },
+];
+
+// hi
+static R37: Names = [
+    Patterns {
+        current: "अब",
+        past: ["", "", "", "", "", "{0} सेकंड पहले"],
+        future: ["", "", "", "", "", "{0} सेकंड में"],
+    },
+    Patterns {
+        current: "यह मिनट",
+        past: ["", "", "", "", "", "{0} मिनट पहले"],
+        future: ["", "", "", "", "", "{0} मिनट में"],
+    },
+    Patterns {
+        current: "यह घंटा",
+        past: ["", "", "", "", "", "{0} घंटे पहले"],
+        future: ["", "", "", "", "", "{0} घंटे में"],
+    },
+    Patterns {
+        current: "आज",
+        past: ["", "", "", "", "", "{0} दिन पहले"],
+        future: ["", "", "", "", "", "{0} दिन में"],
+    },
+    Patterns {
+        current: "इस सप्ताह",
+        past: ["", "", "", "", "", "{0} सप्ताह पहले"],
+        future: ["", "", "", "", "", "{0} सप्ताह में"],
+    },
+    Patterns {
+        current: "इस माह",
+        past: ["", "", "", "", "", "{0} माह पहले"],
+        future: ["", "", "", "", "", "{0} माह में"],
+    },
+    Patterns {
+        current: "इस वर्ष",
+        past: ["", "", "", "", "", "{0} वर्ष पहले"],
+        future: ["", "", "", "", "", "{0} वर्ष में"],
+    },
+];
+
+// hu
+static R38: Names = [
+    Patterns {
+        current: "most",
+        past: ["", "", "", "", "", "{0} másodperccel ezelőtt"],
+        future: ["", "", "", "", "", "{0} másodperc múlva"],
+    },
+    Patterns {
+        current: "ebben a percben",
+        past: ["", "", "", "", "", "{0} perccel ezelőtt"],
+        future: ["", "", "", "", "", "{0} perc múlva"],
+    },
+    Patterns {
+        current: "ebben az órában",
+        past: ["", "", "", "", "", "{0} órával ezelőtt"],
+        future: ["", "", "", "", "", "{0} óra múlva"],
+    },
+    Patterns {
+        current: "ma",
+        past: ["", "", "", "", "", "{0} nappal ezelőtt"],
+        future: ["", "", "", "", "", "{0} nap múlva"],
+    },
+    Patterns {
+        current: "ez a hét",
+        past: ["", "", "", "", "", "{0} héttel ezelőtt"],
+        future: ["", "", "", "", "", "{0} hét múlva"],
+    },
+    Patterns {
+        current: "ez a hónap",
+        past: ["", "", "", "", "", "{0} hónappal ezelőtt"],
+        future: ["", "", "", "", "", "{0} hónap múlva"],
+    },
+    Patterns {
+        current: "ez az év",
+        past: ["", "", "", "", "", "{0} évvel ezelőtt"],
+        future: ["", "", "", "", "", "{0} év múlva"],
+    },
+];
+
+// ia
+static R39: Names = [
+    Patterns {
+        current: "ora",
+        past: ["", "{0} secunda retro", "", "", "", "{0} secundas retro"],
+        future: ["", "in {0} secunda", "", "", "", "in {0} secundas"],
+    },
+    Patterns {
+        current: "iste minuta",
+        past: ["", "{0} minuta retro", "", "", "", "{0} minutas retro"],
+        future: ["", "in {0} minuta", "", "", "", "in {0} minutas"],
+    },
+    Patterns {
+        current: "iste hora",
+        past: ["", "{0} hora retro", "", "", "", "{0} horas retro"],
+        future: ["", "in {0} hora", "", "", "", "in {0} horas"],
+    },
+    Patterns {
+        current: "hodie",
+        past: ["", "{0} die retro", "", "", "", "{0} dies retro"],
+        future: ["", "in {0} die", "", "", "", "in {0} dies"],
+    },
+    Patterns {
+        current: "iste septimana",
+        past: ["", "{0} septimana retro", "", "", "", "{0} septimanas retro"],
+        future: ["", "in {0} septimana", "", "", "", "in {0} septimanas"],
+    },
+    Patterns {
+        current: "iste mense",
+        past: ["", "{0} mense retro", "", "", "", "{0} menses retro"],
+        future: ["", "in {0} mense", "", "", "", "in {0} menses"],
+    },
+    Patterns {
+        current: "iste anno",
+        past: ["", "{0} anno retro", "", "", "", "{0} annos retro"],
+        future: ["", "in {0} anno", "", "", "", "in {0} annos"],
+    },
+];
+
+// id
+static R40: Names = [
+    Patterns {
+        current: "sekarang",
+        past: ["", "", "", "", "", "{0} detik yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} detik"],
+    },
+    Patterns {
+        current: "menit ini",
+        past: ["", "", "", "", "", "{0} menit yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} menit"],
+    },
+    Patterns {
+        current: "jam ini",
+        past: ["", "", "", "", "", "{0} jam yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} jam"],
+    },
+    Patterns {
+        current: "hari ini",
+        past: ["", "", "", "", "", "{0} hari yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} hari"],
+    },
+    Patterns {
+        current: "minggu ini",
+        past: ["", "", "", "", "", "{0} minggu yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} minggu"],
+    },
+    Patterns {
+        current: "bulan ini",
+        past: ["", "", "", "", "", "{0} bulan yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} bulan"],
+    },
+    Patterns {
+        current: "tahun ini",
+        past: ["", "", "", "", "", "{0} tahun yang lalu"],
+        future: ["", "", "", "", "", "dalam {0} tahun"],
+    },
+];
+
+// ie
+static R41: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "hodie",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "this week",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "ho-mensu",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "ho-annu",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// ga
+static R42: Names = [
+    Patterns {
+        current: "anois",
+        past: ["", "", "{0} shoicind ó shin", "{0} shoicind ó shin", "", "{0} soicind ó shin"],
+        future: ["", "", "i gceann {0} shoicind", "i gceann {0} shoicind", "", "i gceann {0} soicind"],
+    },
+    Patterns {
+        current: "an nóiméad seo",
+        past: ["", "", "", "", "", "{0} nóiméad ó shin"],
+        future: ["", "", "", "", "", "i gceann {0} nóiméad"],
+    },
+    Patterns {
+        current: "an uair seo",
+        past: ["", "", "", "", "{0} n-uair an chloig ó shin", "{0} uair an chloig ó shin"],
+        future: ["", "", "", "", "i gceann {0} n-uair an chloig", "i gceann {0} uair an chloig"],
+    },
+    Patterns {
+        current: "inniu",
+        past: ["", "", "", "", "", "{0} lá ó shin"],
+        future: ["", "", "", "", "", "i gceann {0} lá"],
+    },
+    Patterns {
+        current: "an tseachtain seo",
+        past: ["", "", "{0} sheachtain ó shin", "{0} seachtaine ó shin", "{0} seachtaine ó shin", "{0} seachtain ó shin"],
+        future: ["", "i gceann {0} seachtaine", "i gceann {0} sheachtain", "i gceann {0} seachtaine", "i gceann {0} seachtaine", "i gceann {0} seachtain"],
+    },
+    Patterns {
+        current: "an mhí seo",
+        past: ["", "{0} mhí ó shin", "{0} mhí ó shin", "{0} mhí ó shin", "", "{0} mí ó shin"],
+        future: ["", "i gceann {0} mhí", "i gceann {0} mhí", "i gceann {0} mhí", "", "i gceann {0} mí"],
+    },
+    Patterns {
+        current: "i mbliana",
+        past: ["", "{0} bhliain ó shin", "{0} bhliain ó shin", "{0} bliana ó shin", "{0} mbliana ó shin", "{0} bliain ó shin"],
+        future: ["", "i gceann {0} bliana", "i gceann {0} bhliain", "i gceann {0} bliana", "i gceann {0} mbliana", "i gceann {0} bliain"],
+    },
+];
+
+// ig
+static R43: Names = [
+    Patterns {
+        current: "ụgbụa",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "nkejị a",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "elekere a",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "Taa",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "Izu a",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "Ọnwa a",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "Afọ a",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// is
+static R44: Names = [
+    Patterns {
+        current: "núna",
+        past: ["", "fyrir {0} sekúndu", "", "", "", "fyrir {0} sekúndum"],
+        future: ["", "eftir {0} sekúndu", "", "", "", "eftir {0} sekúndur"],
+    },
+    Patterns {
+        current: "á þessari mínútu",
+        past: ["", "fyrir {0} mínútu", "", "", "", "fyrir {0} mínútum"],
+        future: ["", "eftir {0} mínútu", "", "", "", "eftir {0} mínútur"],
+    },
+    Patterns {
+        current: "þessa stundina",
+        past: ["", "fyrir {0} klukkustund", "", "", "", "fyrir {0} klukkustundum"],
+        future: ["", "eftir {0} klukkustund", "", "", "", "eftir {0} klukkustundir"],
+    },
+    Patterns {
+        current: "í dag",
+        past: ["", "fyrir {0} degi", "", "", "", "fyrir {0} dögum"],
+        future: ["", "eftir {0} dag", "", "", "", "eftir {0} daga"],
+    },
+    Patterns {
+        current: "í þessari viku",
+        past: ["", "fyrir {0} viku", "", "", "", "fyrir {0} vikum"],
+        future: ["", "eftir {0} viku", "", "", "", "eftir {0} vikur"],
+    },
+    Patterns {
+        current: "í þessum mánuði",
+        past: ["", "fyrir {0} mánuði", "", "", "", "fyrir {0} mánuðum"],
+        future: ["", "eftir {0} mánuð", "", "", "", "eftir {0} mánuði"],
+    },
+    Patterns {
+        current: "á þessu ári",
+        past: ["", "fyrir {0} ári", "", "", "", "fyrir {0} árum"],
+        future: ["", "", "", "", "", "eftir {0} ár"],
+    },
+];
+
+// it
+static R45: Names = [
+    Patterns {
+        current: "ora",
+        past: ["", "{0} secondo fa", "", "", "", "{0} secondi fa"],
+        future: ["", "tra {0} secondo", "", "", "", "tra {0} secondi"],
+    },
+    Patterns {
+        current: "questo minuto",
+        past: ["", "{0} minuto fa", "", "", "", "{0} minuti fa"],
+        future: ["", "tra {0} minuto", "", "", "", "tra {0} minuti"],
+    },
+    Patterns {
+        current: "quest’ora",
+        past: ["", "{0} ora fa", "", "", "", "{0} ore fa"],
+        future: ["", "tra {0} ora", "", "", "", "tra {0} ore"],
+    },
+    Patterns {
+        current: "oggi",
+        past: ["", "{0} giorno fa", "", "", "", "{0} giorni fa"],
+        future: ["", "tra {0} giorno", "", "", "", "tra {0} giorni"],
+    },
+    Patterns {
+        current: "questa settimana",
+        past: ["", "{0} settimana fa", "", "", "", "{0} settimane fa"],
+        future: ["", "tra {0} settimana", "", "", "", "tra {0} settimane"],
+    },
+    Patterns {
+        current: "questo mese",
+        past: ["", "{0} mese fa", "", "", "", "{0} mesi fa"],
+        future: ["", "tra {0} mese", "", "", "", "tra {0} mesi"],
+    },
+    Patterns {
+        current: "quest’anno",
+        past: ["", "{0} anno fa", "", "", "", "{0} anni fa"],
+        future: ["", "tra {0} anno", "", "", "", "tra {0} anni"],
+    },
+];
+
+// ja
+static R46: Names = [
+    Patterns {
+        current: "今",
+        past: ["", "", "", "", "", "{0} 秒前"],
+        future: ["", "", "", "", "", "{0} 秒後"],
+    },
+    Patterns {
+        current: "1 分以内",
+        past: ["", "", "", "", "", "{0} 分前"],
+        future: ["", "", "", "", "", "{0} 分後"],
+    },
+    Patterns {
+        current: "1 時間以内",
+        past: ["", "", "", "", "", "{0} 時間前"],
+        future: ["", "", "", "", "", "{0} 時間後"],
+    },
+    Patterns {
+        current: "今日",
+        past: ["", "", "", "", "", "{0} 日前"],
+        future: ["", "", "", "", "", "{0} 日後"],
+    },
+    Patterns {
+        current: "今週",
+        past: ["", "", "", "", "", "{0} 週間前"],
+        future: ["", "", "", "", "", "{0} 週間後"],
+    },
+    Patterns {
+        current: "今月",
+        past: ["", "", "", "", "", "{0} か月前"],
+        future: ["", "", "", "", "", "{0} か月後"],
+    },
+    Patterns {
+        current: "今年",
+        past: ["", "", "", "", "", "{0} 年前"],
+        future: ["", "", "", "", "", "{0} 年後"],
+    },
+];
+
+// jv
+static R47: Names = [
+    Patterns {
+        current: "saiki",
+        past: ["", "", "", "", "", "{0} detik kepungkur"],
+        future: ["", "", "", "", "", "ing {0} detik"],
+    },
+    Patterns {
+        current: "menit iki",
+        past: ["", "", "", "", "", "{0} menit kepungkur"],
+        future: ["", "", "", "", "", "ing {0} menit"],
+    },
+    Patterns {
+        current: "jam iki",
+        past: ["", "", "", "", "", "{0} jam kepungkur"],
+        future: ["", "", "", "", "", "ing {0} jam"],
+    },
+    Patterns {
+        current: "dino iki",
+        past: ["", "", "", "", "", "{0} dina kepungkur"],
+        future: ["", "", "", "", "", "ing {0} dina"],
+    },
+    Patterns {
+        current: "pekan iki",
+        past: ["", "", "", "", "", "{0} pekan kepungkur"],
+        future: ["", "", "", "", "", "ing {0} pekan"],
+    },
+    Patterns {
+        current: "sasi iki",
+        past: ["", "", "", "", "", "{0} sasi kepungkur"],
+        future: ["", "", "", "", "", "ing {0} sasi"],
+    },
+    Patterns {
+        current: "taun iki",
+        past: ["", "", "", "", "", "{0} taun kepungkur"],
+        future: ["", "", "", "", "", "ing {0} taun"],
+    },
+];
+
+// kn
+static R48: Names = [
+    Patterns {
+        current: "ಈಗ",
+        past: ["", "{0} ಸೆಕೆಂಡ್ ಹಿಂದೆ", "", "", "", "{0} ಸೆಕೆಂಡುಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ಸೆಕೆಂಡ್\u{200C}ನಲ್ಲಿ", "", "", "", "{0} ಸೆಕೆಂಡ್\u{200C}ಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಈ ನಿಮಿಷ",
+        past: ["", "{0} ನಿಮಿಷದ ಹಿಂದೆ", "", "", "", "{0} ನಿಮಿಷಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ನಿಮಿಷದಲ್ಲಿ", "", "", "", "{0} ನಿಮಿಷಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಈ ಗಂಟೆ",
+        past: ["", "{0} ಗಂಟೆ ಹಿಂದೆ", "", "", "", "{0} ಗಂಟೆಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ಗಂಟೆಯಲ್ಲಿ", "", "", "", "{0} ಗಂಟೆಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಇಂದು",
+        past: ["", "{0} ದಿನದ ಹಿಂದೆ", "", "", "", "{0} ದಿನಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ದಿನದಲ್ಲಿ", "", "", "", "{0} ದಿನಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಈ ವಾರ",
+        past: ["", "{0} ವಾರದ ಹಿಂದೆ", "", "", "", "{0} ವಾರಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ವಾರದಲ್ಲಿ", "", "", "", "{0} ವಾರಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಈ ತಿಂಗಳು",
+        past: ["", "{0} ತಿಂಗಳ ಹಿಂದೆ", "", "", "", "{0} ತಿಂಗಳುಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ತಿಂಗಳಲ್ಲಿ", "", "", "", "{0} ತಿಂಗಳುಗಳಲ್ಲಿ"],
+    },
+    Patterns {
+        current: "ಈ ವರ್ಷ",
+        past: ["", "{0} ವರ್ಷದ ಹಿಂದೆ", "", "", "", "{0} ವರ್ಷಗಳ ಹಿಂದೆ"],
+        future: ["", "{0} ವರ್ಷದಲ್ಲಿ", "", "", "", "{0} ವರ್ಷಗಳಲ್ಲಿ"],
+    },
+];
+
+// ks
+static R49: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "اَز",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "یٕہ ہفتہ",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "یٕہ ریتھؠ",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "یٕہ ؤری",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// kk
+static R50: Names = [
+    Patterns {
+        current: "қазір",
+        past: ["", "", "", "", "", "{0} секунд бұрын"],
+        future: ["", "", "", "", "", "{0} секундтан кейін"],
+    },
+    Patterns {
+        current: "осы минут",
+        past: ["", "", "", "", "", "{0} минут бұрын"],
+        future: ["", "", "", "", "", "{0} минуттан кейін"],
+    },
+    Patterns {
+        current: "осы сағат",
+        past: ["", "", "", "", "", "{0} сағат бұрын"],
+        future: ["", "", "", "", "", "{0} сағаттан кейін"],
+    },
+    Patterns {
+        current: "бүгін",
+        past: ["", "", "", "", "", "{0} күн бұрын"],
+        future: ["", "", "", "", "", "{0} күннен кейін"],
+    },
+    Patterns {
+        current: "осы апта",
+        past: ["", "", "", "", "", "{0} апта бұрын"],
+        future: ["", "", "", "", "", "{0} аптадан кейін"],
+    },
+    Patterns {
+        current: "осы ай",
+        past: ["", "", "", "", "", "{0} ай бұрын"],
+        future: ["", "", "", "", "", "{0} айдан кейін"],
+    },
+    Patterns {
+        current: "биылғы жыл",
+        past: ["", "", "", "", "", "{0} жыл бұрын"],
+        future: ["", "", "", "", "", "{0} жылдан кейін"],
+    },
+];
+
+// km
+static R51: Names = [
+    Patterns {
+        current: "ឥឡូវ",
+        past: ["", "", "", "", "", "{0} វិនាទី\u{200B}មុន"],
+        future: ["", "", "", "", "", "{0} វិនាទីទៀត"],
+    },
+    Patterns {
+        current: "នាទីនេះ",
+        past: ["", "", "", "", "", "{0} នាទី\u{200B}មុន"],
+        future: ["", "", "", "", "", "{0} នាទីទៀត"],
+    },
+    Patterns {
+        current: "ម៉ោងនេះ",
+        past: ["", "", "", "", "", "{0} ម៉ោង\u{200B}មុន"],
+        future: ["", "", "", "", "", "ក្នុង\u{200B}រយៈ\u{200B}ពេល {0} ម៉ោង"],
+    },
+    Patterns {
+        current: "ថ្ងៃ\u{200B}នេះ",
+        past: ["", "", "", "", "", "{0} ថ្ងៃ\u{200B}មុន"],
+        future: ["", "", "", "", "", "{0} ថ្ងៃទៀត"],
+    },
+    Patterns {
+        current: "សប្ដាហ៍\u{200B}នេះ",
+        past: ["", "", "", "", "", "{0} សប្ដាហ៍\u{200B}មុន"],
+        future: ["", "", "", "", "", "{0} សប្ដាហ៍ទៀត"],
+    },
+    Patterns {
+        current: "ខែ\u{200B}នេះ",
+        past: ["", "", "", "", "", "{0} ខែមុន"],
+        future: ["", "", "", "", "", "{0} ខែទៀត"],
+    },
+    Patterns {
+        current: "ឆ្នាំ\u{200B}នេះ",
+        past: ["", "", "", "", "", "{0} ឆ្នាំ\u{200B}មុន"],
+        future: ["", "", "", "", "", "{0} ឆ្នាំទៀត"],
+    },
+];
+
+// rw
+static R52: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "uyu munsi",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "iki cyumweru",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "uku kwezi",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "uyu mwaka",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// ky
+static R53: Names = [
+    Patterns {
+        current: "азыр",
+        past: ["", "", "", "", "", "{0} секунд мурун"],
+        future: ["", "", "", "", "", "{0} секунддан кийин"],
+    },
+    Patterns {
+        current: "ушул мүнөттө",
+        past: ["", "", "", "", "", "{0} мүнөт мурун"],
+        future: ["", "", "", "", "", "{0} мүнөттөн кийин"],
+    },
+    Patterns {
+        current: "ушул саатта",
+        past: ["", "", "", "", "", "{0} саат мурун"],
+        future: ["", "", "", "", "", "{0} сааттан кийин"],
+    },
+    Patterns {
+        current: "бүгүн",
+        past: ["", "", "", "", "", "{0} күн мурун"],
+        future: ["", "", "", "", "", "{0} күндөн кийин"],
+    },
+    Patterns {
+        current: "ушул аптада",
+        past: ["", "", "", "", "", "{0} апта мурун"],
+        future: ["", "", "", "", "", "{0} аптадан кийин"],
+    },
+    Patterns {
+        current: "бул айда",
+        past: ["", "", "", "", "", "{0} ай мурун"],
+        future: ["", "", "", "", "", "{0} айдан кийин"],
+    },
+    Patterns {
+        current: "быйыл",
+        past: ["", "", "", "", "", "{0} жыл мурун"],
+        future: ["", "", "", "", "", "{0} жылдан кийин"],
+    },
+];
+
+// ko
+static R54: Names = [
+    Patterns {
+        current: "지금",
+        past: ["", "", "", "", "", "{0}초 전"],
+        future: ["", "", "", "", "", "{0}초 후"],
+    },
+    Patterns {
+        current: "현재 분",
+        past: ["", "", "", "", "", "{0}분 전"],
+        future: ["", "", "", "", "", "{0}분 후"],
+    },
+    Patterns {
+        current: "현재 시간",
+        past: ["", "", "", "", "", "{0}시간 전"],
+        future: ["", "", "", "", "", "{0}시간 후"],
+    },
+    Patterns {
+        current: "오늘",
+        past: ["", "", "", "", "", "{0}일 전"],
+        future: ["", "", "", "", "", "{0}일 후"],
+    },
+    Patterns {
+        current: "이번 주",
+        past: ["", "", "", "", "", "{0}주 전"],
+        future: ["", "", "", "", "", "{0}주 후"],
+    },
+    Patterns {
+        current: "이번 달",
+        past: ["", "", "", "", "", "{0}개월 전"],
+        future: ["", "", "", "", "", "{0}개월 후"],
+    },
+    Patterns {
+        current: "올해",
+        past: ["", "", "", "", "", "{0}년 전"],
+        future: ["", "", "", "", "", "{0}년 후"],
+    },
+];
+
+// ku
+static R55: Names = [
+    Patterns {
+        current: "niha",
+        past: ["", "berî {0} saniyeyê", "", "", "", "berî {0} saniyeyan"],
+        future: ["", "piştî {0} saniyeyê", "", "", "", "piştî {0} saniyeyan"],
+    },
+    Patterns {
+        current: "ev deqîqe",
+        past: ["", "berî {0} deqîqeyê", "", "", "", "berî {0} deqîqeyan"],
+        future: ["", "piştî {0} deqîqeyê", "", "", "", "piştî {0} deqîqeyan"],
+    },
+    Patterns {
+        current: "ev saet",
+        past: ["", "berî {0} saetê", "", "", "", "berî {0} saetan"],
+        future: ["", "piştî {0} saetê", "", "", "", "piştî {0} saetan"],
+    },
+    Patterns {
+        current: "îro",
+        past: ["", "berî {0} rojê", "", "", "", "berî {0} rojan"],
+        future: ["", "piştî {0} rojê", "", "", "", "piştî {0} rojan"],
+    },
+    Patterns {
+        current: "ev hefte",
+        past: ["", "berî {0} hefteyê", "", "", "", "berî {0} hefteyan"],
+        future: ["", "piştî {0} hefteyê", "", "", "", "piştî {0} hefteyan"],
+    },
+    Patterns {
+        current: "ev meh",
+        past: ["", "berî {0} mehê", "", "", "", "berî {0} mehan"],
+        future: ["", "piştî {0} mehê", "", "", "", "piştî {0} mehan"],
+    },
+    Patterns {
+        current: "îsal",
+        past: ["", "berî {0} salê", "", "", "", "berî {0} salan"],
+        future: ["", "di {0} salê de", "", "", "", "di {0} salan de"],
+    },
+];
+
+// lb
+static R56: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "virun {0} Sekonn", "", "", "", "viru(n) {0} Sekonnen"],
+        future: ["", "an {0} Sekonn", "", "", "", "a(n) {0} Sekonnen"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "virun {0} Minutt", "", "", "", "viru(n) {0} Minutten"],
+        future: ["", "an {0} Minutt", "", "", "", "a(n) {0} Minutten"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "virun {0} Stonn", "", "", "", "viru(n) {0} Stonnen"],
+        future: ["", "an {0} Stonn", "", "", "", "a(n) {0} Stonnen"],
+    },
+    Patterns {
+        current: "haut",
+        past: ["", "virun {0} Dag", "", "", "", "viru(n) {0} Deeg"],
+        future: ["", "an {0} Dag", "", "", "", "a(n) {0} Deeg"],
+    },
+    Patterns {
+        current: "dës Woch",
+        past: ["", "virun {0} Woch", "", "", "", "viru(n) {0} Wochen"],
+        future: ["", "an {0} Woch", "", "", "", "a(n) {0} Wochen"],
+    },
+    Patterns {
+        current: "dëse Mount",
+        past: ["", "virun {0} Mount", "", "", "", "viru(n) {0} Méint"],
+        future: ["", "an {0} Mount", "", "", "", "a(n) {0} Méint"],
+    },
+    Patterns {
+        current: "dëst Joer",
+        past: ["", "virun {0} Joer", "", "", "", "viru(n) {0} Joer"],
+        future: ["", "an {0} Joer", "", "", "", "a(n) {0} Joer"],
+    },
+];
+
+// lo
+static R57: Names = [
+    Patterns {
+        current: "ຕອນນີ້",
+        past: ["", "", "", "", "", "{0} ວິນາທີກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ວິນາທີ"],
+    },
+    Patterns {
+        current: "ນາທີນີ້",
+        past: ["", "", "", "", "", "{0} ນາທີກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ນາທີ"],
+    },
+    Patterns {
+        current: "ຊົ່ວໂມງນີ້",
+        past: ["", "", "", "", "", "{0} ຊົ່ວໂມງກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ຊົ່ວໂມງ"],
+    },
+    Patterns {
+        current: "ມື້ນີ້",
+        past: ["", "", "", "", "", "{0} ມື້ກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ມື້"],
+    },
+    Patterns {
+        current: "ອາທິດນີ້",
+        past: ["", "", "", "", "", "{0} ອາທິດກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ອາທິດ"],
+    },
+    Patterns {
+        current: "ເດືອນນີ້",
+        past: ["", "", "", "", "", "{0} ເດືອນກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ເດືອນ"],
+    },
+    Patterns {
+        current: "ປີນີ້",
+        past: ["", "", "", "", "", "{0} ປີກ່ອນ"],
+        future: ["", "", "", "", "", "ໃນອີກ {0} ປີ"],
+    },
+];
+
+// lt
+static R58: Names = [
+    Patterns {
+        current: "dabar",
+        past: ["", "prieš {0} sekundę", "", "prieš {0} sekundes", "prieš {0} sekundės", "prieš {0} sekundžių"],
+        future: ["", "po {0} sekundės", "", "", "po {0} sekundės", "po {0} sekundžių"],
+    },
+    Patterns {
+        current: "šią minutę",
+        past: ["", "prieš {0} minutę", "", "prieš {0} minutes", "prieš {0} minutės", "prieš {0} minučių"],
+        future: ["", "po {0} minutės", "", "", "po {0} minutės", "po {0} minučių"],
+    },
+    Patterns {
+        current: "šią valandą",
+        past: ["", "prieš {0} valandą", "", "prieš {0} valandas", "prieš {0} valandos", "prieš {0} valandų"],
+        future: ["", "po {0} valandos", "", "", "po {0} valandos", "po {0} valandų"],
+    },
+    Patterns {
+        current: "šiandien",
+        past: ["", "prieš {0} dieną", "", "prieš {0} dienas", "prieš {0} dienos", "prieš {0} dienų"],
+        future: ["", "po {0} dienos", "", "", "po {0} dienos", "po {0} dienų"],
+    },
+    Patterns {
+        current: "šią savaitę",
+        past: ["", "prieš {0} savaitę", "", "prieš {0} savaites", "prieš {0} savaitės", "prieš {0} savaičių"],
+        future: ["", "po {0} savaitės", "", "", "po {0} savaitės", "po {0} savaičių"],
+    },
+    Patterns {
+        current: "šį mėnesį",
+        past: ["", "prieš {0} mėnesį", "", "prieš {0} mėnesius", "prieš {0} mėnesio", "prieš {0} mėnesių"],
+        future: ["", "po {0} mėnesio", "", "", "po {0} mėnesio", "po {0} mėnesių"],
+    },
+    Patterns {
+        current: "šiais metais",
+        past: ["", "prieš {0} metus", "", "prieš {0} metus", "", "prieš {0} metų"],
+        future: ["", "", "", "", "", "po {0} metų"],
+    },
+];
+
+// lv
+static R59: Names = [
+    Patterns {
+        current: "tagad",
+        past: ["", "pirms {0} sekundes", "", "", "", "pirms {0} sekundēm"],
+        future: ["", "pēc {0} sekundes", "", "", "", "pēc {0} sekundēm"],
+    },
+    Patterns {
+        current: "šajā minūtē",
+        past: ["", "pirms {0} minūtes", "", "", "", "pirms {0} minūtēm"],
+        future: ["", "pēc {0} minūtes", "", "", "", "pēc {0} minūtēm"],
+    },
+    Patterns {
+        current: "šajā stundā",
+        past: ["", "pirms {0} stundas", "", "", "", "pirms {0} stundām"],
+        future: ["", "pēc {0} stundas", "", "", "", "pēc {0} stundām"],
+    },
+    Patterns {
+        current: "šodien",
+        past: ["", "pirms {0} dienas", "", "", "", "pirms {0} dienām"],
+        future: ["", "pēc {0} dienas", "", "", "", "pēc {0} dienām"],
+    },
+    Patterns {
+        current: "šajā nedēļā",
+        past: ["", "pirms {0} nedēļas", "", "", "", "pirms {0} nedēļām"],
+        future: ["", "pēc {0} nedēļas", "", "", "", "pēc {0} nedēļām"],
+    },
+    Patterns {
+        current: "šajā mēnesī",
+        past: ["", "pirms {0} mēneša", "", "", "", "pirms {0} mēnešiem"],
+        future: ["", "pēc {0} mēneša", "", "", "", "pēc {0} mēnešiem"],
+    },
+    Patterns {
+        current: "šajā gadā",
+        past: ["", "pirms {0} gada", "", "", "", "pirms {0} gadiem"],
+        future: ["", "pēc {0} gada", "", "", "", "pēc {0} gadiem"],
+    },
+];
+
+// mk
+static R60: Names = [
+    Patterns {
+        current: "сега",
+        past: ["", "пред {0} секунда", "", "", "", "пред {0} секунди"],
+        future: ["", "за {0} секунда", "", "", "", "за {0} секунди"],
+    },
+    Patterns {
+        current: "оваа минута",
+        past: ["", "пред {0} минута", "", "", "", "пред {0} минути"],
+        future: ["", "за {0} минута", "", "", "", "за {0} минути"],
+    },
+    Patterns {
+        current: "овој час",
+        past: ["", "пред {0} час", "", "", "", "пред {0} часа"],
+        future: ["", "за {0} час", "", "", "", "за {0} часа"],
+    },
+    Patterns {
+        current: "денес",
+        past: ["", "пред {0} ден", "", "", "", "пред {0} дена"],
+        future: ["", "за {0} ден", "", "", "", "за {0} дена"],
+    },
+    Patterns {
+        current: "оваа седмица",
+        past: ["", "пред {0} седмица", "", "", "", "пред {0} седмици"],
+        future: ["", "за {0} седмица", "", "", "", "за {0} седмици"],
+    },
+    Patterns {
+        current: "овој месец",
+        past: ["", "пред {0} месец", "", "", "", "пред {0} месеци"],
+        future: ["", "за {0} месец", "", "", "", "за {0} месеци"],
+    },
+    Patterns {
+        current: "оваа година",
+        past: ["", "пред {0} година", "", "", "", "пред {0} години"],
+        future: ["", "за {0} година", "", "", "", "за {0} години"],
+    },
+];
+
+// ms
+static R61: Names = [
+    Patterns {
+        current: "sekarang",
+        past: ["", "", "", "", "", "{0} saat lalu"],
+        future: ["", "", "", "", "", "dalam {0} saat"],
+    },
+    Patterns {
+        current: "pada minit ini",
+        past: ["", "", "", "", "", "{0} minit lalu"],
+        future: ["", "", "", "", "", "dalam {0} minit"],
+    },
+    Patterns {
+        current: "jam ini",
+        past: ["", "", "", "", "", "{0} jam lalu"],
+        future: ["", "", "", "", "", "dalam {0} jam"],
+    },
+    Patterns {
+        current: "hari ini",
+        past: ["", "", "", "", "", "{0} hari lalu"],
+        future: ["", "", "", "", "", "dalam {0} hari"],
+    },
+    Patterns {
+        current: "minggu ini",
+        past: ["", "", "", "", "", "{0} minggu lalu"],
+        future: ["", "", "", "", "", "dalam {0} minggu"],
+    },
+    Patterns {
+        current: "bulan ini",
+        past: ["", "", "", "", "", "{0} bulan lalu"],
+        future: ["", "", "", "", "", "dalam {0} bulan"],
+    },
+    Patterns {
+        current: "tahun ini",
+        past: ["", "", "", "", "", "{0} tahun lalu"],
+        future: ["", "", "", "", "", "dalam {0} tahun"],
+    },
+];
+
+// ml
+static R62: Names = [
+    Patterns {
+        current: "ഇപ്പോൾ",
+        past: ["", "", "", "", "", "{0} സെക്കൻഡ് മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} സെക്കൻഡിൽ"],
+    },
+    Patterns {
+        current: "ഈ മിനിറ്റിൽ",
+        past: ["", "", "", "", "", "{0} മിനിറ്റ് മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} മിനിറ്റിൽ"],
+    },
+    Patterns {
+        current: "ഈ മണിക്കൂറിൽ",
+        past: ["", "", "", "", "", "{0} മണിക്കൂർ മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} മണിക്കൂറിൽ"],
+    },
+    Patterns {
+        current: "ഇന്ന്",
+        past: ["", "", "", "", "", "{0} ദിവസം മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} ദിവസത്തിൽ"],
+    },
+    Patterns {
+        current: "ഈ ആഴ്ച",
+        past: ["", "", "", "", "", "{0} ആഴ്ച മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} ആഴ്ചയിൽ"],
+    },
+    Patterns {
+        current: "ഈ മാസം",
+        past: ["", "", "", "", "", "{0} മാസം മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} മാസത്തിൽ"],
+    },
+    Patterns {
+        current: "ഈ വർ\u{200C}ഷം",
+        past: ["", "", "", "", "", "{0} വർഷം മുമ്പ്"],
+        future: ["", "", "", "", "", "{0} വർഷത്തിൽ"],
+    },
+];
+
+// mt
+static R63: Names = [
+    Patterns {
+        current: "issa",
+        past: ["", "sekonda ilu", "{0} sekondi ilu", "{0} sekondi ilu", "{0}-il sekonda ilu", "{0} sekonda ilu"],
+        future: ["", "sa sekonda oħra", "sa {0} sekondi oħra", "sa {0} sekondi oħra", "sa {0}-il sekonda oħra", "sa {0} sekonda oħra"],
+    },
+    Patterns {
+        current: "din il-minuta",
+        past: ["", "minuta ilu", "{0} minuti ilu", "{0} minuti ilu", "{0}-il minuta ilu", "{0} minuta ilu"],
+        future: ["", "sa minuta oħra", "sa {0} minuti oħra", "sa {0} minuti oħra", "sa {0}-il minuta oħra", "sa {0} minuta oħra"],
+    },
+    Patterns {
+        current: "din is-siegħa",
+        past: ["", "siegħa ilu", "sagħtejn ilu", "{0} sigħat ilu", "{0}-il siegħa ilu", "{0} siegħa ilu"],
+        future: ["", "fi żmien siegħa oħra", "fi żmien sagħtejn", "fi żmien {0} sigħat", "fi żmien {0}-il siegħa", "fi żmien {0} siegħa"],
+    },
+    Patterns {
+        current: "illum",
+        past: ["", "ġurnata ilu", "jumejn ilu", "{0} ġranet ilu", "{0}-il ġurnata ilu", "{0} ġurnata ilu"],
+        future: ["", "fi żmien ġurnata", "fi żmien jumejn oħra", "fi żmien {0} ġranet oħra", "fi żmien {0}-il ġurnata oħra", "fi żmien {0} ġurnata oħra"],
+    },
+    Patterns {
+        current: "din il-ġimgħa",
+        past: ["", "ġimgħa ilu", "ġimagħtejn ilu", "{0} ġimgħat ilu", "{0}-il ġimgħa ilu", "{0} ġimgħa ilu"],
+        future: ["", "sa ġimgħa oħra", "sa ġimagħtejn oħra", "sa {0} ġimgħat oħra", "sa {0}-il ġimgħa oħra", "sa {0} ġimgħa oħra"],
+    },
+    Patterns {
+        current: "dan ix-xahar",
+        past: ["", "xahar ilu", "xahrejn ilu", "{0} xhur ilu", "{0}-il xahar ilu", "{0} xahar ilu"],
+        future: ["", "sa xahar ieħor", "fi xahrejn oħra", "fi {0} xhur oħra", "fi {0}-xahar oħra", "fi {0} xahar oħra"],
+    },
+    Patterns {
+        current: "din is-sena",
+        past: ["", "sena ilu", "sentejn ilu", "{0} snin ilu", "{0}-il sena ilu", "{0} sena ilu"],
+        future: ["", "fi żmien sena", "fi żmien sentejn oħra", "fi żmien {0} snin oħra", "fi żmien {0}-il sena oħra", "fi żmien {0} sena oħra"],
+    },
+];
+
+// mi
+static R64: Names = [
+    Patterns {
+        current: "ināianei",
+        past: ["", "", "", "", "", "-{0} hēkona i mua"],
+        future: ["", "", "", "", "", "+{0} hēkona"],
+    },
+    Patterns {
+        current: "i tēnei meneti",
+        past: ["", "", "", "", "", "-{0} meneti i mua"],
+        future: ["", "", "", "", "", "+{0} meneti"],
+    },
+    Patterns {
+        current: "i tēnei haora",
+        past: ["", "", "", "", "", "-{0} haora i mua"],
+        future: ["", "", "", "", "", "+{0} haora"],
+    },
+    Patterns {
+        current: "i tēnei rā",
+        past: ["", "", "", "", "", "-{0} rā i mua"],
+        future: ["", "", "", "", "", "+{0} rā"],
+    },
+    Patterns {
+        current: "i tēnei wiki",
+        past: ["", "", "", "", "", "-{0} wiki i mua"],
+        future: ["", "", "", "", "", "+{0} wiki"],
+    },
+    Patterns {
+        current: "i tēnei marama",
+        past: ["", "", "", "", "", "-{0} marama i mua"],
+        future: ["", "", "", "", "", "+{0} marama"],
+    },
+    Patterns {
+        current: "i tēnei tau",
+        past: ["", "", "", "", "", "i te {0} tau i mua"],
+        future: ["", "", "", "", "", "ā te {0} tau"],
+    },
+];
+
+// mr
+static R65: Names = [
+    Patterns {
+        current: "आत्ता",
+        past: ["", "{0} सेकंदापूर्वी", "", "", "", "{0} सेकंदांपूर्वी"],
+        future: ["", "{0} सेकंदामध्ये", "", "", "", "{0} सेकंदांमध्ये"],
+    },
+    Patterns {
+        current: "या मिनिटात",
+        past: ["", "{0} मिनिटापूर्वी", "", "", "", "{0} मिनिटांपूर्वी"],
+        future: ["", "{0} मिनिटामध्ये", "", "", "", "{0} मिनिटांमध्ये"],
+    },
+    Patterns {
+        current: "तासात",
+        past: ["", "{0} तासापूर्वी", "", "", "", "{0} तासांपूर्वी"],
+        future: ["", "{0} तासामध्ये", "", "", "", "{0} तासांमध्ये"],
+    },
+    Patterns {
+        current: "आज",
+        past: ["", "{0} दिवसापूर्वी", "", "", "", "{0} दिवसांपूर्वी"],
+        future: ["", "येत्या {0} दिवसामध्ये", "", "", "", "येत्या {0} दिवसांमध्ये"],
+    },
+    Patterns {
+        current: "हा आठवडा",
+        past: ["", "{0} आठवड्यापूर्वी", "", "", "", "{0} आठवड्यांपूर्वी"],
+        future: ["", "{0} आठवड्यामध्ये", "", "", "", "{0} आठवड्यांमध्ये"],
+    },
+    Patterns {
+        current: "हा महिना",
+        past: ["", "{0} महिन्यापूर्वी", "", "", "", "{0} महिन्यांपूर्वी"],
+        future: ["", "येत्या {0} महिन्यामध्ये", "", "", "", "येत्या {0} महिन्यांमध्ये"],
+    },
+    Patterns {
+        current: "हे वर्ष",
+        past: ["", "{0} वर्षापूर्वी", "", "", "", "{0} वर्षांपूर्वी"],
+        future: ["", "येत्या {0} वर्षामध्ये", "", "", "", "येत्या {0} वर्षांमध्ये"],
+    },
+];
+
+// mn
+static R66: Names = [
+    Patterns {
+        current: "одоо",
+        past: ["", "", "", "", "", "{0} секундын өмнө"],
+        future: ["", "", "", "", "", "{0} секундын дараа"],
+    },
+    Patterns {
+        current: "энэ минут",
+        past: ["", "", "", "", "", "{0} минутын өмнө"],
+        future: ["", "", "", "", "", "{0} минутын дараа"],
+    },
+    Patterns {
+        current: "энэ цаг",
+        past: ["", "", "", "", "", "{0} цагийн өмнө"],
+        future: ["", "", "", "", "", "{0} цагийн дараа"],
+    },
+    Patterns {
+        current: "өнөөдөр",
+        past: ["", "", "", "", "", "{0} өдрийн өмнө"],
+        future: ["", "", "", "", "", "{0} өдрийн дараа"],
+    },
+    Patterns {
+        current: "энэ долоо хоног",
+        past: ["", "", "", "", "", "{0} долоо хоногийн өмнө"],
+        future: ["", "", "", "", "", "{0} долоо хоногийн дараа"],
+    },
+    Patterns {
+        current: "энэ сар",
+        past: ["", "", "", "", "", "{0} сарын өмнө"],
+        future: ["", "", "", "", "", "{0} сарын дараа"],
+    },
+    Patterns {
+        current: "энэ жил",
+        past: ["", "", "", "", "", "{0} жилийн өмнө"],
+        future: ["", "", "", "", "", "{0} жилийн дараа"],
+    },
+];
+
+// ne
+static R67: Names = [
+    Patterns {
+        current: "अहिले",
+        past: ["", "", "", "", "", "{0} सेकेन्ड पहिले"],
+        future: ["", "", "", "", "", "{0} सेकेन्डमा"],
+    },
+    Patterns {
+        current: "यही मिनेटमा",
+        past: ["", "", "", "", "", "{0} मिनेट पहिले"],
+        future: ["", "", "", "", "", "{0} मिनेटमा"],
+    },
+    Patterns {
+        current: "यस घडीमा",
+        past: ["", "", "", "", "", "{0} घण्टा पहिले"],
+        future: ["", "", "", "", "", "{0} घण्टामा"],
+    },
+    Patterns {
+        current: "आज",
+        past: ["", "", "", "", "", "{0} दिन पहिले"],
+        future: ["", "", "", "", "", "{0} दिनमा"],
+    },
+    Patterns {
+        current: "यो हप्ता",
+        past: ["", "", "", "", "", "{0} हप्ता पहिले"],
+        future: ["", "", "", "", "", "{0} हप्तामा"],
+    },
+    Patterns {
+        current: "यो महिना",
+        past: ["", "", "", "", "", "{0} महिना पहिले"],
+        future: ["", "", "", "", "", "{0} महिनामा"],
+    },
+    Patterns {
+        current: "यो वर्ष",
+        past: ["", "", "", "", "", "{0} वर्ष अघि"],
+        future: ["", "", "", "", "", "{0} वर्षमा"],
+    },
+];
+
+// nb, no
+static R68: Names = [
+    Patterns {
+        current: "nå",
+        past: ["", "for {0} sekund siden", "", "", "", "for {0} sekunder siden"],
+        future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"],
+    },
+    Patterns {
+        current: "dette minuttet",
+        past: ["", "for {0} minutt siden", "", "", "", "for {0} minutter siden"],
+        future: ["", "om {0} minutt", "", "", "", "om {0} minutter"],
+    },
+    Patterns {
+        current: "denne timen",
+        past: ["", "for {0} time siden", "", "", "", "for {0} timer siden"],
+        future: ["", "om {0} time", "", "", "", "om {0} timer"],
+    },
+    Patterns {
+        current: "i dag",
+        past: ["", "", "", "", "", "for {0} døgn siden"],
+        future: ["", "", "", "", "", "om {0} døgn"],
+    },
+    Patterns {
+        current: "denne uken",
+        past: ["", "for {0} uke siden", "", "", "", "for {0} uker siden"],
+        future: ["", "om {0} uke", "", "", "", "om {0} uker"],
+    },
+    Patterns {
+        current: "denne måneden",
+        past: ["", "for {0} måned siden", "", "", "", "for {0} måneder siden"],
+        future: ["", "om {0} måned", "", "", "", "om {0} måneder"],
+    },
+    Patterns {
+        current: "i år",
+        past: ["", "", "", "", "", "for {0} år siden"],
+        future: ["", "", "", "", "", "om {0} år"],
+    },
+];
+
+// nn
+static R69: Names = [
+    Patterns {
+        current: "no",
+        past: ["", "", "", "", "", "for {0} sekund sidan"],
+        future: ["", "om {0} sekund", "", "", "", "om {0}\u{A0}sekund"],
+    },
+    Patterns {
+        current: "dette minuttet",
+        past: ["", "", "", "", "", "for {0} minutt sidan"],
+        future: ["", "", "", "", "", "om {0} minutt"],
+    },
+    Patterns {
+        current: "denne timen",
+        past: ["", "for {0} time sidan", "", "", "", "for {0} timar sidan"],
+        future: ["", "om {0} time", "", "", "", "om {0} timar"],
+    },
+    Patterns {
+        current: "i dag",
+        past: ["", "", "", "", "", "for {0} døgn sidan"],
+        future: ["", "", "", "", "", "om {0} døgn"],
+    },
+    Patterns {
+        current: "denne veka",
+        past: ["", "for {0} veke sidan", "", "", "", "for {0} veker sidan"],
+        future: ["", "om {0} veke", "", "", "", "om {0} veker"],
+    },
+    Patterns {
+        current: "denne månaden",
+        past: ["", "for {0} månad sidan", "", "", "", "for {0} månadar sidan"],
+        future: ["", "om {0} månad", "", "", "", "om {0} månadar"],
+    },
+    Patterns {
+        current: "i år",
+        past: ["", "", "", "", "", "for {0} år sidan"],
+        future: ["", "", "", "", "", "om {0} år"],
+    },
+];
+
+// ii
+static R70: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "ꀃꑍ",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "ꀋꃋꋋꆏꊂ",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "ꀋꃋꋋꁮꆪ",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "ꀋꃋꎼꈎ",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// om
+static R71: Names = [
+    Patterns {
+        current: "amma",
+        past: ["", "sekoondii {0} darbe", "", "", "", "sekoondiiwwan {0} darban"],
+        future: ["", "sekoondii {0} keessatti", "", "", "", "sekoondiiwwan {0} keessatti"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "daqiiqaa {0} darbe", "", "", "", "daqiiqaawwan {0} darban"],
+        future: ["", "daqiiqaa {0} keessatti", "", "", "", "daqiiqaawwan {0} keessatti"],
+    },
+    Patterns {
+        current: "sa’aatii kana",
+        past: ["", "sa’aatii {0} darbe", "", "", "", "sa’aatiiwwan {0} darban"],
+        future: ["", "sa’aatii {0} keessatti", "", "", "", "sa’aatiiwwan {0} keessatti"],
+    },
+    Patterns {
+        current: "har’a",
+        past: ["", "guyyaa {0} darbe", "", "", "", "guyyoota {0} darban"],
+        future: ["", "guyyaa {0} keessatti", "", "", "", "guyyoota {0} keessatti"],
+    },
+    Patterns {
+        current: "torbee kana",
+        past: ["", "torbee {0} darbe", "", "", "", "torbanoota {0} darban"],
+        future: ["", "torbee {0} keessatti", "", "", "", "torbanoota {0} keessatti"],
+    },
+    Patterns {
+        current: "ji’a kana",
+        past: ["", "ji’a {0} darbe", "", "", "", "ji’oota {0} darban"],
+        future: ["", "ji’a {0} keessatti", "", "", "", "ji’oota {0} keessatti"],
+    },
+    Patterns {
+        current: "waggaa kana",
+        past: ["", "waggaa {0} dura", "", "", "", "waggoota {0} dura"],
+        future: ["", "waggaa {0} keessatti", "", "", "", "waggoota {0} keessatti"],
+    },
+];
+
+// or
+static R72: Names = [
+    Patterns {
+        current: "ବର୍ତ୍ତମାନ",
+        past: ["", "", "", "", "", "{0} ସେକେଣ୍ଡ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ସେକେଣ୍ଡରେ"],
+    },
+    Patterns {
+        current: "ଏହି ମିନିଟ୍",
+        past: ["", "", "", "", "", "{0} ମିନିଟ୍ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ମିନିଟ୍\u{200C}\u{200C}ରେ"],
+    },
+    Patterns {
+        current: "ଏହି ଘଣ୍ଟା",
+        past: ["", "", "", "", "", "{0} ଘଣ୍ଟା ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ଘଣ୍ଟାରେ"],
+    },
+    Patterns {
+        current: "ଆଜି",
+        past: ["", "", "", "", "", "{0} ଦିନ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ଦିନରେ"],
+    },
+    Patterns {
+        current: "ଏହି ସପ୍ତାହ",
+        past: ["", "", "", "", "", "{0} ସପ୍ତାହ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ସପ୍ତାହରେ"],
+    },
+    Patterns {
+        current: "ଏହି ମାସ",
+        past: ["", "", "", "", "", "{0} ମାସ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ମାସରେ"],
+    },
+    Patterns {
+        current: "ଏହି ବର୍ଷ",
+        past: ["", "", "", "", "", "{0} ବର୍ଷ ପୂର୍ବେ"],
+        future: ["", "", "", "", "", "{0} ବର୍ଷରେ"],
+    },
+];
+
+// pa
+static R73: Names = [
+    Patterns {
+        current: "ਹੁਣ",
+        past: ["", "", "", "", "", "{0} ਸਕਿੰਟ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਸਕਿੰਟ ਵਿੱਚ", "", "", "", "{0} ਸਕਿੰਟਾਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਇਸ ਮਿੰਟ",
+        past: ["", "", "", "", "", "{0} ਮਿੰਟ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਮਿੰਟ ਵਿੱਚ", "", "", "", "{0} ਮਿੰਟਾਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਇਸ ਘੰਟੇ",
+        past: ["", "{0} ਘੰਟਾ ਪਹਿਲਾਂ", "", "", "", "{0} ਘੰਟੇ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਘੰਟੇ ਵਿੱਚ", "", "", "", "{0} ਘੰਟਿਆਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਅੱਜ",
+        past: ["", "", "", "", "", "{0} ਦਿਨ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਦਿਨ ਵਿੱਚ", "", "", "", "{0} ਦਿਨਾਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਇਹ ਹਫ਼ਤਾ",
+        past: ["", "{0} ਹਫ਼ਤਾ ਪਹਿਲਾਂ", "", "", "", "{0} ਹਫ਼ਤੇ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਹਫ਼ਤੇ ਵਿੱਚ", "", "", "", "{0} ਹਫ਼ਤਿਆਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਇਹ ਮਹੀਨਾ",
+        past: ["", "{0} ਮਹੀਨਾ ਪਹਿਲਾਂ", "", "", "", "{0} ਮਹੀਨੇ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਮਹੀਨੇ ਵਿੱਚ", "", "", "", "{0} ਮਹੀਨਿਆਂ ਵਿੱਚ"],
+    },
+    Patterns {
+        current: "ਇਹ ਸਾਲ",
+        past: ["", "", "", "", "", "{0} ਸਾਲ ਪਹਿਲਾਂ"],
+        future: ["", "{0} ਸਾਲ ਵਿੱਚ", "", "", "", "{0} ਸਾਲਾਂ ਵਿੱਚ"],
+    },
+];
+
+// fa
+static R74: Names = [
+    Patterns {
+        current: "اکنون",
+        past: ["", "", "", "", "", "{0} ثانیه پیش"],
+        future: ["", "", "", "", "", "{0} ثانیه بعد"],
+    },
+    Patterns {
+        current: "همین دقیقه",
+        past: ["", "", "", "", "", "{0} دقیقه پیش"],
+        future: ["", "", "", "", "", "{0} دقیقه بعد"],
+    },
+    Patterns {
+        current: "همین ساعت",
+        past: ["", "", "", "", "", "{0} ساعت پیش"],
+        future: ["", "", "", "", "", "{0} ساعت بعد"],
+    },
+    Patterns {
+        current: "امروز",
+        past: ["", "", "", "", "", "{0} روز پیش"],
+        future: ["", "", "", "", "", "{0} روز دیگر"],
+    },
+    Patterns {
+        current: "این هفته",
+        past: ["", "", "", "", "", "{0} هفته پیش"],
+        future: ["", "", "", "", "", "{0} هفته بعد"],
+    },
+    Patterns {
+        current: "این ماه",
+        past: ["", "", "", "", "", "{0} ماه پیش"],
+        future: ["", "", "", "", "", "{0} ماه بعد"],
+    },
+    Patterns {
+        current: "امسال",
+        past: ["", "", "", "", "", "{0} سال پیش"],
+        future: ["", "", "", "", "", "{0} سال بعد"],
+    },
+];
+
+// pl
+static R75: Names = [
+    Patterns {
+        current: "teraz",
+        past: ["", "{0} sekundę temu", "", "", "{0} sekund temu", "{0} sekundy temu"],
+        future: ["", "za {0} sekundę", "", "", "za {0} sekund", "za {0} sekundy"],
+    },
+    Patterns {
+        current: "ta minuta",
+        past: ["", "{0} minutę temu", "", "", "{0} minut temu", "{0} minuty temu"],
+        future: ["", "za {0} minutę", "", "", "za {0} minut", "za {0} minuty"],
+    },
+    Patterns {
+        current: "ta godzina",
+        past: ["", "{0} godzinę temu", "", "", "{0} godzin temu", "{0} godziny temu"],
+        future: ["", "za {0} godzinę", "", "", "za {0} godzin", "za {0} godziny"],
+    },
+    Patterns {
+        current: "dzisiaj",
+        past: ["", "{0} dzień temu", "", "{0} dni temu", "{0} dni temu", "{0} dnia temu"],
+        future: ["", "za {0} dzień", "", "za {0} dni", "za {0} dni", "za {0} dnia"],
+    },
+    Patterns {
+        current: "w tym tygodniu",
+        past: ["", "{0} tydzień temu", "", "{0} tygodnie temu", "{0} tygodni temu", "{0} tygodnia temu"],
+        future: ["", "za {0} tydzień", "", "za {0} tygodnie", "za {0} tygodni", "za {0} tygodnia"],
+    },
+    Patterns {
+        current: "w tym miesiącu",
+        past: ["", "{0} miesiąc temu", "", "{0} miesiące temu", "{0} miesięcy temu", "{0} miesiąca temu"],
+        future: ["", "za {0} miesiąc", "", "za {0} miesiące", "za {0} miesięcy", "za {0} miesiąca"],
+    },
+    Patterns {
+        current: "w tym roku",
+        past: ["", "{0} rok temu", "", "{0} lata temu", "{0} lat temu", "{0} roku temu"],
+        future: ["", "za {0} rok", "", "za {0} lata", "za {0} lat", "za {0} roku"],
+    },
+];
+
+// ps
+static R76: Names = [
+    Patterns {
+        current: "اوس",
+        past: ["", "{0} ثانيه مخکې", "", "", "", "{0} ثانيې مخکې"],
+        future: ["", "په {0} ثانيه کې", "", "", "", "په {0} ثانيو کې"],
+    },
+    Patterns {
+        current: "دا دقيقه",
+        past: ["", "{0} دقيقه مخکې", "", "", "", "{0} دقيقې مخکې"],
+        future: ["", "په {0} دقيقه کې", "", "", "", "په {0} دقيقو کې"],
+    },
+    Patterns {
+        current: "دا ساعت",
+        past: ["", "{0} ساعت مخکې", "", "", "", "{0} ساعتونه مخکې"],
+        future: ["", "په {0} ساعت کې", "", "", "", "په {0} ساعتو کې"],
+    },
+    Patterns {
+        current: "نن",
+        past: ["", "{0} ورځ مخکې", "", "", "", "{0} ورځې مخکې"],
+        future: ["", "په {0} ورځ کې", "", "", "", "په {0} ورځو کې"],
+    },
+    Patterns {
+        current: "دا اونۍ",
+        past: ["", "", "", "", "", "{0} اونۍ مخکې"],
+        future: ["", "په {0} اونۍ کې", "", "", "", "په {0} اونيو کې"],
+    },
+    Patterns {
+        current: "دا مياشت",
+        past: ["", "{0} مياشت مخکې", "", "", "", "{0} مياشتې مخکې"],
+        future: ["", "په {0} مياشت کې", "", "", "", "په {0} مياشتو کې"],
+    },
+    Patterns {
+        current: "سږکال",
+        past: ["", "{0} کال مخکې", "", "", "", "{0} کاله مخکې"],
+        future: ["", "په {0} کال کې", "", "", "", "په {0} کالونو کې"],
+    },
+];
+
+// pt
+static R77: Names = [
+    Patterns {
+        current: "agora",
+        past: ["", "há {0} segundo", "", "", "", "há {0} segundos"],
+        future: ["", "em {0} segundo", "", "", "", "em {0} segundos"],
+    },
+    Patterns {
+        current: "este minuto",
+        past: ["", "há {0} minuto", "", "", "", "há {0} minutos"],
+        future: ["", "em {0} minuto", "", "", "", "em {0} minutos"],
+    },
+    Patterns {
+        current: "esta hora",
+        past: ["", "há {0} hora", "", "", "", "há {0} horas"],
+        future: ["", "em {0} hora", "", "", "", "em {0} horas"],
+    },
+    Patterns {
+        current: "hoje",
+        past: ["", "há {0} dia", "", "", "", "há {0} dias"],
+        future: ["", "em {0} dia", "", "", "", "em {0} dias"],
+    },
+    Patterns {
+        current: "esta semana",
+        past: ["", "há {0} semana", "", "", "", "há {0} semanas"],
+        future: ["", "em {0} semana", "", "", "", "em {0} semanas"],
+    },
+    Patterns {
+        current: "este mês",
+        past: ["", "há {0} mês", "", "", "", "há {0} meses"],
+        future: ["", "em {0} mês", "", "", "", "em {0} meses"],
+    },
+    Patterns {
+        current: "este ano",
+        past: ["", "há {0} ano", "", "", "", "há {0} anos"],
+        future: ["", "em {0} ano", "", "", "", "em {0} anos"],
+    },
+];
+
+// qu
+static R78: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "{0} qipa segundu"],
+        future: ["", "", "", "", "", "{0} segundupi"],
+    },
+    Patterns {
+        current: "kay minuto",
+        past: ["", "", "", "", "", "{0} qipa minutu"],
+        future: ["", "", "", "", "", "{0} minutupi"],
+    },
+    Patterns {
+        current: "kay hora",
+        past: ["", "", "", "", "", "{0} qipa ura"],
+        future: ["", "", "", "", "", "{0} urapi"],
+    },
+    Patterns {
+        current: "kunan punchaw",
+        past: ["", "", "", "", "", "{0} qipa punchaw"],
+        future: ["", "", "", "", "", "{0} punchawpi"],
+    },
+    Patterns {
+        current: "kunan semana",
+        past: ["", "", "", "", "", "{0} qipa semana"],
+        future: ["", "", "", "", "", "{0} semanapi"],
+    },
+    Patterns {
+        current: "kunan killa",
+        past: ["", "", "", "", "", "{0} qipa killa"],
+        future: ["", "", "", "", "", "{0} killapi"],
+    },
+    Patterns {
+        current: "kunan wata",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// rm
+static R79: Names = [
+    Patterns {
+        current: "ussa",
+        past: ["", "avant {0} secunda", "", "", "", "avant {0} secundas"],
+        future: ["", "en {0} secunda", "", "", "", "en {0} secundas"],
+    },
+    Patterns {
+        current: "questa minuta",
+        past: ["", "avant {0} minuta", "", "", "", "avant {0} minutas"],
+        future: ["", "en {0} minuta", "", "", "", "en {0} minutas"],
+    },
+    Patterns {
+        current: "quest’ura",
+        past: ["", "avant {0} ura", "", "", "", "avant {0} uras"],
+        future: ["", "en {0} ura", "", "", "", "en {0} uras"],
+    },
+    Patterns {
+        current: "oz",
+        past: ["", "avant {0} di", "", "", "", "avant {0} dis"],
+        future: ["", "en {0} di", "", "", "", "en {0} dis"],
+    },
+    Patterns {
+        current: "quest’emna",
+        past: ["", "avant {0} emna", "", "", "", "avant {0} emnas"],
+        future: ["", "en {0} emna", "", "", "", "en {0} emnas"],
+    },
+    Patterns {
+        current: "quest mais",
+        past: ["", "", "", "", "", "avant {0} mais"],
+        future: ["", "", "", "", "", "en {0} mais"],
+    },
+    Patterns {
+        current: "quest onn",
+        past: ["", "avant {0} onn", "", "", "", "avant {0} onns"],
+        future: ["", "en {0} onn", "", "", "", "en {0} onns"],
+    },
+];
+
+// ro
+static R80: Names = [
+    Patterns {
+        current: "acum",
+        past: ["", "acum {0} secundă", "", "acum {0} secunde", "", "acum {0} de secunde"],
+        future: ["", "peste {0} secundă", "", "peste {0} secunde", "", "peste {0} de secunde"],
+    },
+    Patterns {
+        current: "minutul acesta",
+        past: ["", "acum {0} minut", "", "acum {0} minute", "", "acum {0} de minute"],
+        future: ["", "peste {0} minut", "", "peste {0} minute", "", "peste {0} de minute"],
+    },
+    Patterns {
+        current: "ora aceasta",
+        past: ["", "acum {0} oră", "", "acum {0} ore", "", "acum {0} de ore"],
+        future: ["", "peste {0} oră", "", "peste {0} ore", "", "peste {0} de ore"],
+    },
+    Patterns {
+        current: "azi",
+        past: ["", "acum {0} zi", "", "acum {0} zile", "", "acum {0} de zile"],
+        future: ["", "peste {0} zi", "", "peste {0} zile", "", "peste {0} de zile"],
+    },
+    Patterns {
+        current: "săptămâna aceasta",
+        past: ["", "acum {0} săptămână", "", "acum {0} săptămâni", "", "acum {0} de săptămâni"],
+        future: ["", "peste {0} săptămână", "", "peste {0} săptămâni", "", "peste {0} de săptămâni"],
+    },
+    Patterns {
+        current: "luna aceasta",
+        past: ["", "acum {0} lună", "", "acum {0} luni", "", "acum {0} de luni"],
+        future: ["", "peste {0} lună", "", "peste {0} luni", "", "peste {0} de luni"],
+    },
+    Patterns {
+        current: "anul acesta",
+        past: ["", "acum {0} an", "", "acum {0} ani", "", "acum {0} de ani"],
+        future: ["", "peste {0} an", "", "peste {0} ani", "", "peste {0} de ani"],
+    },
+];
+
+// ru
+static R81: Names = [
+    Patterns {
+        current: "сейчас",
+        past: ["", "{0} секунду назад", "", "", "{0} секунд назад", "{0} секунды назад"],
+        future: ["", "через {0} секунду", "", "", "через {0} секунд", "через {0} секунды"],
+    },
+    Patterns {
+        current: "в эту минуту",
+        past: ["", "{0} минуту назад", "", "", "{0} минут назад", "{0} минуты назад"],
+        future: ["", "через {0} минуту", "", "", "через {0} минут", "через {0} минуты"],
+    },
+    Patterns {
+        current: "в этот час",
+        past: ["", "{0} час назад", "", "", "{0} часов назад", "{0} часа назад"],
+        future: ["", "через {0} час", "", "", "через {0} часов", "через {0} часа"],
+    },
+    Patterns {
+        current: "сегодня",
+        past: ["", "{0} день назад", "", "", "{0} дней назад", "{0} дня назад"],
+        future: ["", "через {0} день", "", "", "через {0} дней", "через {0} дня"],
+    },
+    Patterns {
+        current: "на этой неделе",
+        past: ["", "{0} неделю назад", "", "", "{0} недель назад", "{0} недели назад"],
+        future: ["", "через {0} неделю", "", "", "через {0} недель", "через {0} недели"],
+    },
+    Patterns {
+        current: "в этом месяце",
+        past: ["", "{0} месяц назад", "", "", "{0} месяцев назад", "{0} месяца назад"],
+        future: ["", "через {0} месяц", "", "", "через {0} месяцев", "через {0} месяца"],
+    },
+    Patterns {
+        current: "в этом году",
+        past: ["", "{0} год назад", "", "", "{0} лет назад", "{0} года назад"],
+        future: ["", "через {0} год", "", "", "через {0} лет", "через {0} года"],
+    },
+];
+
+// sa
+static R82: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "अद्य",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "this week",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "this month",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "this year",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// sc
+static R83: Names = [
+    Patterns {
+        current: "como",
+        past: ["", "{0} segundu a como", "", "", "", "{0} segundos a como"],
+        future: ["", "intro de {0} segundu", "", "", "", "intro de {0} segundos"],
+    },
+    Patterns {
+        current: "custu minutu",
+        past: ["", "{0} minutu a como", "", "", "", "{0} minutos a como"],
+        future: ["", "intro de {0} minutu", "", "", "", "intro de {0} minutos"],
+    },
+    Patterns {
+        current: "cust’ora",
+        past: ["", "{0} ora a como", "", "", "", "{0} oras a como"],
+        future: ["", "intro de {0} ora", "", "", "", "intro de {0} oras"],
+    },
+    Patterns {
+        current: "oe",
+        past: ["", "{0} die a como", "", "", "", "{0} dies a como"],
+        future: ["", "intro de {0} die", "", "", "", "intro de {0} dies"],
+    },
+    Patterns {
+        current: "custa chida",
+        past: ["", "{0} chida a como", "", "", "", "{0} chidas a como"],
+        future: ["", "intro de {0} chida", "", "", "", "intro de {0} chidas"],
+    },
+    Patterns {
+        current: "custu mese",
+        past: ["", "{0} mese a como", "", "", "", "{0} meses a como"],
+        future: ["", "intro de {0} mese", "", "", "", "intro de {0} meses"],
+    },
+    Patterns {
+        current: "ocannu",
+        past: ["", "{0} annu a como", "", "", "", "{0} annos a como"],
+        future: ["", "intro de {0} annu", "", "", "", "intro de {0} annos"],
+    },
+];
+
+// sd
+static R84: Names = [
+    Patterns {
+        current: "هاڻي",
+        past: ["", "", "", "", "", "{0} سيڪنڊ پهرين"],
+        future: ["", "{0} سيڪنڊ ۾", "", "", "", "{0} سيڪنڊن ۾"],
+    },
+    Patterns {
+        current: "هن منٽ",
+        past: ["", "", "", "", "", "{0} منٽ پهرين"],
+        future: ["", "{0} منٽن ۾", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "هن ڪلڪ",
+        past: ["", "", "", "", "", "{0} ڪلاڪ پهرين"],
+        future: ["", "", "", "", "", "{0} ڪلاڪ ۾"],
+    },
+    Patterns {
+        current: "اڄ",
+        past: ["", "", "", "", "", "{0} ڏينهن پهرين"],
+        future: ["", "", "", "", "", "{0} ڏينهن ۾"],
+    },
+    Patterns {
+        current: "هن هفتي",
+        past: ["", "{0} هفتو پهرين", "", "", "", "{0} هفتا پهرين"],
+        future: ["", "{0} هفتي ۾", "", "", "", "{0} هفتن ۾"],
+    },
+    Patterns {
+        current: "هن مهيني",
+        past: ["", "", "", "", "", "{0} مهينا پهرين"],
+        future: ["", "{0} مهيني ۾", "", "", "", "{0} مهينن ۾"],
+    },
+    Patterns {
+        current: "پويون سال",
+        past: ["", "", "", "", "", "{0} سال پهرين"],
+        future: ["", "{0} سال ۾", "", "", "", "{0} سالن ۾"],
+    },
+];
+
+// sr
+static R85: Names = [
+    Patterns {
+        current: "сада",
+        past: ["", "пре {0} секунде", "", "пре {0} секунде", "", "пре {0} секунди"],
+        future: ["", "за {0} секунду", "", "за {0} секунде", "", "за {0} секунди"],
+    },
+    Patterns {
+        current: "овог минута",
+        past: ["", "", "", "", "", "пре {0} минута"],
+        future: ["", "за {0} минут", "", "", "", "за {0} минута"],
+    },
+    Patterns {
+        current: "овог сата",
+        past: ["", "пре {0} сата", "", "пре {0} сата", "", "пре {0} сати"],
+        future: ["", "за {0} сат", "", "за {0} сата", "", "за {0} сати"],
+    },
+    Patterns {
+        current: "данас",
+        past: ["", "", "", "", "", "пре {0} дана"],
+        future: ["", "за {0} дан", "", "", "", "за {0} дана"],
+    },
+    Patterns {
+        current: "ове недеље",
+        past: ["", "пре {0} недеље", "", "пре {0} недеље", "", "пре {0} недеља"],
+        future: ["", "за {0} недељу", "", "за {0} недеље", "", "за {0} недеља"],
+    },
+    Patterns {
+        current: "овог месеца",
+        past: ["", "пре {0} месеца", "", "пре {0} месеца", "", "пре {0} месеци"],
+        future: ["", "за {0} месец", "", "за {0} месеца", "", "за {0} месеци"],
+    },
+    Patterns {
+        current: "ове године",
+        past: ["", "пре {0} године", "", "пре {0} године", "", "пре {0} година"],
+        future: ["", "за {0} годину", "", "за {0} године", "", "за {0} година"],
+    },
+];
+
+// gd
+static R86: Names = [
+    Patterns {
+        current: "an-dràsta",
+        past: ["", "", "{0} dhiog air ais", "{0} diogan air ais", "", "{0} diog air ais"],
+        future: ["", "", "an ceann {0} dhiog", "an ceann {0} diogan", "", "an ceann {0} diog"],
+    },
+    Patterns {
+        current: "am broinn mionaid",
+        past: ["", "{0} mhionaid air ais", "{0} mhionaid air ais", "{0} mionaidean air ais", "", "{0} mionaid air ais"],
+        future: ["", "an ceann {0} mhionaid", "an ceann {0} mhionaid", "an ceann {0} mionaidean", "", "an ceann {0} mionaid"],
+    },
+    Patterns {
+        current: "am broinn uair a thìde",
+        past: ["", "", "", "{0} uairean a thìde air ais", "", "{0} uair a thìde air ais"],
+        future: ["", "", "", "an ceann {0} uairean a thìde", "", "an ceann {0} uair a thìde"],
+    },
+    Patterns {
+        current: "an-diugh",
+        past: ["", "", "", "{0} làithean air ais", "", "{0} latha air ais"],
+        future: ["", "", "", "an ceann {0} làithean", "", "an ceann {0} latha"],
+    },
+    Patterns {
+        current: "an t-seachdain seo",
+        past: ["", "", "{0} sheachdain air ais", "{0} seachdainean air ais", "", "{0} seachdain air ais"],
+        future: ["", "", "an ceann {0} sheachdain", "an ceann {0} seachdainean", "", "an ceann {0} seachdain"],
+    },
+    Patterns {
+        current: "am mìos seo",
+        past: ["", "{0} mhìos air ais", "{0} mhìos air ais", "{0} mìosan air ais", "", "{0} mìos air ais"],
+        future: ["", "an ceann {0} mhìosa", "an ceann {0} mhìosa", "an ceann {0} mìosan", "", "an ceann {0} mìosa"],
+    },
+    Patterns {
+        current: "am bliadhna",
+        past: ["", "{0} bhliadhna air ais", "{0} bhliadhna air ais", "{0} bhliadhnaichean air ais", "", "{0} bliadhna air ais"],
+        future: ["", "an ceann {0} bhliadhna", "an ceann {0} bhliadhna", "an ceann {0} bliadhnaichean", "", "an ceann {0} bliadhna"],
+    },
+];
+
+// si
+static R87: Names = [
+    Patterns {
+        current: "දැන්",
+        past: ["", "", "", "", "", "තත්පර {0}කට පෙර"],
+        future: ["", "", "", "", "", "තත්පර {0}කින්"],
+    },
+    Patterns {
+        current: "මෙම මිනිත්තුව",
+        past: ["", "", "", "", "", "මිනිත්තු {0}කට පෙර"],
+        future: ["", "", "", "", "", "මිනිත්තු {0}කින්"],
+    },
+    Patterns {
+        current: "මෙම පැය",
+        past: ["", "", "", "", "", "පැය {0}කට පෙර"],
+        future: ["", "", "", "", "", "පැය {0}කින්"],
+    },
+    Patterns {
+        current: "අද",
+        past: ["", "", "", "", "", "දින {0}කට පෙර"],
+        future: ["", "", "", "", "", "දින {0}න්"],
+    },
+    Patterns {
+        current: "මෙම සතිය",
+        past: ["", "", "", "", "", "සති {0}කට පෙර"],
+        future: ["", "", "", "", "", "සති {0}කින්"],
+    },
+    Patterns {
+        current: "මෙම මාසය",
+        past: ["", "", "", "", "", "මාස {0}කට පෙර"],
+        future: ["", "", "", "", "", "මාස {0}කින්"],
+    },
+    Patterns {
+        current: "මෙම වසර",
+        past: ["", "", "", "", "", "වසර {0}කට පෙර"],
+        future: ["", "", "", "", "", "වසර {0}කින්"],
+    },
+];
+
+// sk
+static R88: Names = [
+    Patterns {
+        current: "teraz",
+        past: ["", "pred {0} sekundou", "", "", "pred {0} sekundy", "pred {0} sekundami"],
+        future: ["", "o {0} sekundu", "", "o {0} sekundy", "o {0} sekundy", "o {0} sekúnd"],
+    },
+    Patterns {
+        current: "v tejto minúte",
+        past: ["", "pred {0} minútou", "", "", "pred {0} minúty", "pred {0} minútami"],
+        future: ["", "o {0} minútu", "", "o {0} minúty", "o {0} minúty", "o {0} minút"],
+    },
+    Patterns {
+        current: "v tejto hodine",
+        past: ["", "pred {0} hodinou", "", "", "pred {0} hodinou", "pred {0} hodinami"],
+        future: ["", "o {0} hodinu", "", "o {0} hodiny", "o {0} hodiny", "o {0} hodín"],
+    },
+    Patterns {
+        current: "dnes",
+        past: ["", "pred {0} dňom", "", "", "pred {0} dňa", "pred {0} dňami"],
+        future: ["", "o {0} deň", "", "o {0} dni", "o {0} dňa", "o {0} dní"],
+    },
+    Patterns {
+        current: "tento týždeň",
+        past: ["", "pred {0} týždňom", "", "", "pred {0} týždňa", "pred {0} týždňami"],
+        future: ["", "o {0} týždeň", "", "o {0} týždne", "o {0} týždňa", "o {0} týždňov"],
+    },
+    Patterns {
+        current: "tento mesiac",
+        past: ["", "pred {0} mesiacom", "", "", "pred {0} mesiaca", "pred {0} mesiacmi"],
+        future: ["", "o {0} mesiac", "", "o {0} mesiace", "o {0} mesiaca", "o {0} mesiacov"],
+    },
+    Patterns {
+        current: "tento rok",
+        past: ["", "pred {0} rokom", "", "", "pred {0} roka", "pred {0} rokmi"],
+        future: ["", "o {0} rok", "", "o {0} roky", "o {0} roka", "o {0} rokov"],
+    },
+];
+
+// sl
+static R89: Names = [
+    Patterns {
+        current: "zdaj",
+        past: ["", "pred {0} sekundo", "pred {0} sekundama", "", "", "pred {0} sekundami"],
+        future: ["", "čez {0} sekundo", "čez {0} sekundi", "čez {0} sekunde", "", "čez {0} sekund"],
+    },
+    Patterns {
+        current: "to minuto",
+        past: ["", "pred {0} minuto", "pred {0} minutama", "", "", "pred {0} minutami"],
+        future: ["", "čez {0} minuto", "čez {0} minuti", "čez {0} minute", "", "čez {0} minut"],
+    },
+    Patterns {
+        current: "v tej uri",
+        past: ["", "pred {0} uro", "pred {0} urama", "", "", "pred {0} urami"],
+        future: ["", "čez {0} uro", "čez {0} uri", "čez {0} ure", "", "čez {0} ur"],
+    },
+    Patterns {
+        current: "danes",
+        past: ["", "pred {0} dnevom", "pred {0} dnevoma", "", "", "pred {0} dnevi"],
+        future: ["", "čez {0} dan", "čez {0} dneva", "", "", "čez {0} dni"],
+    },
+    Patterns {
+        current: "ta teden",
+        past: ["", "pred {0} tednom", "pred {0} tednoma", "", "", "pred {0} tedni"],
+        future: ["", "čez {0} teden", "čez {0} tedna", "čez {0} tedne", "", "čez {0} tednov"],
+    },
+    Patterns {
+        current: "ta mesec",
+        past: ["", "pred {0} mesecem", "pred {0} mesecema", "", "", "pred {0} meseci"],
+        future: ["", "čez {0} mesec", "čez {0} meseca", "čez {0} mesece", "", "čez {0} mesecev"],
+    },
+    Patterns {
+        current: "letos",
+        past: ["", "pred {0} letom", "pred {0} letoma", "", "", "pred {0} leti"],
+        future: ["", "čez {0} leto", "čez {0} leti", "čez {0} leta", "", "čez {0} let"],
+    },
+];
+
+// so
+static R90: Names = [
+    Patterns {
+        current: "Imika",
+        past: ["", "", "", "", "", "{0} ilbiriqsi kahor"],
+        future: ["", "", "", "", "", "{0} ilbiriqsi"],
+    },
+    Patterns {
+        current: "Daqiiqadan",
+        past: ["", "{0} daqiiqad kahor", "", "", "", "{0} daqiiqadood kahor"],
+        future: ["", "{0} daqiiqad", "", "", "", "{0} daqiidadood"],
+    },
+    Patterns {
+        current: "Saacadan",
+        past: ["", "{0} saacad kahor", "", "", "", "{0} saacadood kahor"],
+        future: ["", "{0} saacad", "", "", "", "{0} saacadood"],
+    },
+    Patterns {
+        current: "Maanta",
+        past: ["", "{0} maalin kahor", "", "", "", "{0} maalmood kahor"],
+        future: ["", "{0} maalin", "", "", "", "{0} maalmood"],
+    },
+    Patterns {
+        current: "Usbuucan",
+        past: ["", "", "", "", "", "{0} toddobaad kahor"],
+        future: ["", "", "", "", "", "{0} toddobaad"],
+    },
+    Patterns {
+        current: "Bishan",
+        past: ["", "{0} bil kahor", "", "", "", "{0} bilood kahor"],
+        future: ["", "{0} bil", "", "", "", "{0} bilood"],
+    },
+    Patterns {
+        current: "Sannadkan",
+        past: ["", "{0} sannad kahor", "", "", "", "{0} sannadood kahor"],
+        future: ["", "{0} sannad", "", "", "", "{0} sannadood"],
+    },
+];
+
+// es
+static R91: Names = [
+    Patterns {
+        current: "ahora",
+        past: ["", "hace {0} segundo", "", "", "", "hace {0} segundos"],
+        future: ["", "dentro de {0} segundo", "", "", "", "dentro de {0} segundos"],
+    },
+    Patterns {
+        current: "este minuto",
+        past: ["", "hace {0} minuto", "", "", "", "hace {0} minutos"],
+        future: ["", "dentro de {0} minuto", "", "", "", "dentro de {0} minutos"],
+    },
+    Patterns {
+        current: "esta hora",
+        past: ["", "hace {0} hora", "", "", "", "hace {0} horas"],
+        future: ["", "dentro de {0} hora", "", "", "", "dentro de {0} horas"],
+    },
+    Patterns {
+        current: "hoy",
+        past: ["", "hace {0} día", "", "", "", "hace {0} días"],
+        future: ["", "dentro de {0} día", "", "", "", "dentro de {0} días"],
+    },
+    Patterns {
+        current: "esta semana",
+        past: ["", "hace {0} semana", "", "", "", "hace {0} semanas"],
+        future: ["", "dentro de {0} semana", "", "", "", "dentro de {0} semanas"],
+    },
+    Patterns {
+        current: "este mes",
+        past: ["", "hace {0} mes", "", "", "", "hace {0} meses"],
+        future: ["", "dentro de {0} mes", "", "", "", "dentro de {0} meses"],
+    },
+    Patterns {
+        current: "este año",
+        past: ["", "hace {0} año", "", "", "", "hace {0} años"],
+        future: ["", "dentro de {0} año", "", "", "", "dentro de {0} años"],
+    },
+];
+
+// su
+static R92: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "dinten ieu",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "this week",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "this month",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "taun ieu",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// sw
+static R93: Names = [
+    Patterns {
+        current: "sasa hivi",
+        past: ["", "Sekunde {0} iliyopita", "", "", "", "Sekunde {0} zilizopita"],
+        future: ["", "", "", "", "", "baada ya sekunde {0}"],
+    },
+    Patterns {
+        current: "dakika hii",
+        past: ["", "dakika {0} iliyopita", "", "", "", "dakika {0} zilizopita"],
+        future: ["", "", "", "", "", "baada ya dakika {0}"],
+    },
+    Patterns {
+        current: "saa hii",
+        past: ["", "saa {0} iliyopita", "", "", "", "saa {0} zilizopita"],
+        future: ["", "", "", "", "", "baada ya saa {0}"],
+    },
+    Patterns {
+        current: "leo",
+        past: ["", "siku {0} iliyopita", "", "", "", "siku {0} zilizopita"],
+        future: ["", "", "", "", "", "baada ya siku {0}"],
+    },
+    Patterns {
+        current: "wiki hii",
+        past: ["", "wiki {0} iliyopita", "", "", "", "wiki {0} zilizopita"],
+        future: ["", "", "", "", "", "baada ya wiki {0}"],
+    },
+    Patterns {
+        current: "mwezi huu",
+        past: ["", "mwezi {0} uliopita", "", "", "", "miezi {0} iliyopita"],
+        future: ["", "baada ya mwezi {0}", "", "", "", "baada ya miezi {0}"],
+    },
+    Patterns {
+        current: "mwaka huu",
+        past: ["", "mwaka {0} uliopita", "", "", "", "miaka {0} iliyopita"],
+        future: ["", "baada ya mwaka {0}", "", "", "", "baada ya miaka {0}"],
+    },
+];
+
+// sv
+static R94: Names = [
+    Patterns {
+        current: "nu",
+        past: ["", "för {0} sekund sedan", "", "", "", "för {0} sekunder sedan"],
+        future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"],
+    },
+    Patterns {
+        current: "denna minut",
+        past: ["", "för {0} minut sedan", "", "", "", "för {0} minuter sedan"],
+        future: ["", "om {0} minut", "", "", "", "om {0} minuter"],
+    },
+    Patterns {
+        current: "denna timme",
+        past: ["", "för {0} timme sedan", "", "", "", "för {0} timmar sedan"],
+        future: ["", "om {0} timme", "", "", "", "om {0} timmar"],
+    },
+    Patterns {
+        current: "i dag",
+        past: ["", "för {0} dag sedan", "", "", "", "för {0} dagar sedan"],
+        future: ["", "om {0} dag", "", "", "", "om {0} dagar"],
+    },
+    Patterns {
+        current: "denna vecka",
+        past: ["", "för {0} vecka sedan", "", "", "", "för {0} veckor sedan"],
+        future: ["", "om {0} vecka", "", "", "", "om {0} veckor"],
+    },
+    Patterns {
+        current: "den här månaden",
+        past: ["", "för {0} månad sedan", "", "", "", "för {0} månader sedan"],
+        future: ["", "om {0} månad", "", "", "", "om {0} månader"],
+    },
+    Patterns {
+        current: "i år",
+        past: ["", "", "", "", "", "för {0} år sedan"],
+        future: ["", "", "", "", "", "om {0} år"],
+    },
+];
+
+// ta
+static R95: Names = [
+    Patterns {
+        current: "இப்போது",
+        past: ["", "{0} விநாடிக்கு முன்", "", "", "", "{0} விநாடிகளுக்கு முன்"],
+        future: ["", "{0} விநாடியில்", "", "", "", "{0} விநாடிகளில்"],
+    },
+    Patterns {
+        current: "இந்த ஒரு நிமிடத்தில்",
+        past: ["", "{0} நிமிடத்திற்கு முன்", "", "", "", "{0} நிமிடங்களுக்கு முன்"],
+        future: ["", "{0} நிமிடத்தில்", "", "", "", "{0} நிமிடங்களில்"],
+    },
+    Patterns {
+        current: "இந்த ஒரு மணிநேரத்தில்",
+        past: ["", "", "", "", "", "{0} மணிநேரம் முன்"],
+        future: ["", "", "", "", "", "{0} மணிநேரத்தில்"],
+    },
+    Patterns {
+        current: "இன்று",
+        past: ["", "{0} நாளுக்கு முன்", "", "", "", "{0} நாட்களுக்கு முன்"],
+        future: ["", "{0} நாளில்", "", "", "", "{0} நாட்களில்"],
+    },
+    Patterns {
+        current: "இந்த வாரம்",
+        past: ["", "{0} வாரத்திற்கு முன்", "", "", "", "{0} வாரங்களுக்கு முன்"],
+        future: ["", "{0} வாரத்தில்", "", "", "", "{0} வாரங்களில்"],
+    },
+    Patterns {
+        current: "இந்த மாதம்",
+        past: ["", "{0} மாதத்துக்கு முன்", "", "", "", "{0} மாதங்களுக்கு முன்"],
+        future: ["", "{0} மாதத்தில்", "", "", "", "{0} மாதங்களில்"],
+    },
+    Patterns {
+        current: "இந்த ஆண்டு",
+        past: ["", "{0} ஆண்டிற்கு முன்", "", "", "", "{0} ஆண்டுகளுக்கு முன்"],
+        future: ["", "{0} ஆண்டில்", "", "", "", "{0} ஆண்டுகளில்"],
+    },
+];
+
+// te
+static R96: Names = [
+    Patterns {
+        current: "ప్రస్తుతం",
+        past: ["", "{0} సెకను క్రితం", "", "", "", "{0} సెకన్ల క్రితం"],
+        future: ["", "{0} సెకనులో", "", "", "", "{0} సెకన్లలో"],
+    },
+    Patterns {
+        current: "ఈ నిమిషం",
+        past: ["", "{0} నిమిషం క్రితం", "", "", "", "{0} నిమిషాల క్రితం"],
+        future: ["", "{0} నిమిషంలో", "", "", "", "{0} నిమిషాల్లో"],
+    },
+    Patterns {
+        current: "ఈ గంట",
+        past: ["", "{0} గంట క్రితం", "", "", "", "{0} గంటల క్రితం"],
+        future: ["", "{0} గంటలో", "", "", "", "{0} గంటల్లో"],
+    },
+    Patterns {
+        current: "ఈ రోజు",
+        past: ["", "{0} రోజు క్రితం", "", "", "", "{0} రోజుల క్రితం"],
+        future: ["", "{0} రోజులో", "", "", "", "{0} రోజుల్లో"],
+    },
+    Patterns {
+        current: "ఈ వారం",
+        past: ["", "{0} వారం క్రితం", "", "", "", "{0} వారాల క్రితం"],
+        future: ["", "{0} వారంలో", "", "", "", "{0} వారాల్లో"],
+    },
+    Patterns {
+        current: "ఈ నెల",
+        past: ["", "{0} నెల క్రితం", "", "", "", "{0} నెలల క్రితం"],
+        future: ["", "{0} నెలలో", "", "", "", "{0} నెలల్లో"],
+    },
+    Patterns {
+        current: "ఈ సంవత్సరం",
+        past: ["", "{0} సంవత్సరం క్రితం", "", "", "", "{0} సంవత్సరాల క్రితం"],
+        future: ["", "{0} సంవత్సరంలో", "", "", "", "{0} సంవత్సరాల్లో"],
+    },
+];
+
+// tg
+static R97: Names = [
+    Patterns {
+        current: "ҳозир",
+        past: ["", "", "", "", "", "{0} сония пеш"],
+        future: ["", "", "", "", "", "пас аз {0} сония"],
+    },
+    Patterns {
+        current: "дақиқаи ҷорӣ",
+        past: ["", "", "", "", "", "{0} дақиқа пеш"],
+        future: ["", "", "", "", "", "пас аз {0} дақиқа"],
+    },
+    Patterns {
+        current: "соати ҷорӣ",
+        past: ["", "", "", "", "", "{0} соат пеш"],
+        future: ["", "", "", "", "", "пас аз {0} соат"],
+    },
+    Patterns {
+        current: "имрӯз",
+        past: ["", "", "", "", "", "{0} рӯз пеш"],
+        future: ["", "", "", "", "", "пас аз {0} рӯз"],
+    },
+    Patterns {
+        current: "ҳафтаи ҷорӣ",
+        past: ["", "", "", "", "", "{0} ҳафта пеш"],
+        future: ["", "", "", "", "", "пас аз {0} ҳафта"],
+    },
+    Patterns {
+        current: "моҳи ҷорӣ",
+        past: ["", "", "", "", "", "{0} моҳ пеш"],
+        future: ["", "", "", "", "", "пас аз {0} моҳ"],
+    },
+    Patterns {
+        current: "соли ҷорӣ",
+        past: ["", "", "", "", "", "{0} сол пеш"],
+        future: ["", "", "", "", "", "пас аз {0} сол"],
+    },
+];
+
+// th
+static R98: Names = [
+    Patterns {
+        current: "ขณะนี้",
+        past: ["", "", "", "", "", "{0} วินาทีที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} วินาที"],
+    },
+    Patterns {
+        current: "นาทีนี้",
+        past: ["", "", "", "", "", "{0} นาทีที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} นาที"],
+    },
+    Patterns {
+        current: "ชั่วโมงนี้",
+        past: ["", "", "", "", "", "{0} ชั่วโมงที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} ชั่วโมง"],
+    },
+    Patterns {
+        current: "วันนี้",
+        past: ["", "", "", "", "", "{0} วันที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} วัน"],
+    },
+    Patterns {
+        current: "สัปดาห์นี้",
+        past: ["", "", "", "", "", "{0} สัปดาห์ที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} สัปดาห์"],
+    },
+    Patterns {
+        current: "เดือนนี้",
+        past: ["", "", "", "", "", "{0} เดือนที่ผ่านมา"],
+        future: ["", "", "", "", "", "ในอีก {0} เดือน"],
+    },
+    Patterns {
+        current: "ปีนี้",
+        past: ["", "", "", "", "", "{0} ปีที่แล้ว"],
+        future: ["", "", "", "", "", "ในอีก {0} ปี"],
+    },
+];
+
+// ti
+static R99: Names = [
+    Patterns {
+        current: "ሕጂ",
+        past: ["", "", "", "", "", "ቅድሚ {0} ካልኢት"],
+        future: ["", "", "", "", "", "ኣብ {0} ካልኢት"],
+    },
+    Patterns {
+        current: "ኣብዚ ደቒቕ",
+        past: ["", "", "", "", "", "ቅድሚ {0} ደቒቕ"],
+        future: ["", "", "", "", "", "ኣብ {0} ደቒቕ"],
+    },
+    Patterns {
+        current: "ኣብዚ ሰዓት",
+        past: ["", "", "", "", "", "ቅድሚ {0} ሰዓት"],
+        future: ["", "", "", "", "", "ኣብ {0} ሰዓት"],
+    },
+    Patterns {
+        current: "ሎሚ",
+        past: ["", "ቅድሚ {0} መዓልቲ", "", "", "", "ኣብ {0} መዓልቲ"],
+        future: ["", "", "", "", "", "ኣብ {0} መዓልቲ"],
+    },
+    Patterns {
+        current: "ህሉው ሰሙን",
+        past: ["", "", "", "", "", "ቅድሚ {0} ሰሙን"],
+        future: ["", "", "", "", "", "ኣብ {0} ሰሙን"],
+    },
+    Patterns {
+        current: "ህሉው ወርሒ",
+        past: ["", "", "", "", "", "ቅድሚ {0} ወርሒ"],
+        future: ["", "", "", "", "", "ኣብ {0} ወርሒ"],
+    },
+    Patterns {
+        current: "ሎሚ ዓመት",
+        past: ["", "", "", "", "", "ቅድሚ {0} ዓ"],
+        future: ["", "", "", "", "", "ኣብ {0} ዓ"],
+    },
+];
+
+// tk
+static R100: Names = [
+    Patterns {
+        current: "häzir",
+        past: ["", "", "", "", "", "{0} sekunt öň"],
+        future: ["", "", "", "", "", "{0} sekuntdan"],
+    },
+    Patterns {
+        current: "şu minut",
+        past: ["", "", "", "", "", "{0} minut öň"],
+        future: ["", "", "", "", "", "{0} minutdan"],
+    },
+    Patterns {
+        current: "şu sagat",
+        past: ["", "", "", "", "", "{0} sagat öň"],
+        future: ["", "", "", "", "", "{0} sagatdan"],
+    },
+    Patterns {
+        current: "şu gün",
+        past: ["", "", "", "", "", "{0} gün öň"],
+        future: ["", "", "", "", "", "{0} günden"],
+    },
+    Patterns {
+        current: "şu hepde",
+        past: ["", "", "", "", "", "{0} hepde öň"],
+        future: ["", "", "", "", "", "{0} hepdeden"],
+    },
+    Patterns {
+        current: "şu aý",
+        past: ["", "", "", "", "", "{0} aý öň"],
+        future: ["", "", "", "", "", "{0} aýdan"],
+    },
+    Patterns {
+        current: "şu ýyl",
+        past: ["", "", "", "", "", "{0} ýyl öň"],
+        future: ["", "", "", "", "", "{0} ýyldan"],
+    },
+];
+
+// tl
+static R101: Names = [
+    Patterns {
+        current: "ngayon",
+        past: ["", "", "", "", "", "{0} segundo ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} segundo"],
+    },
+    Patterns {
+        current: "sa minutong ito",
+        past: ["", "", "", "", "", "{0} minuto ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} minuto"],
+    },
+    Patterns {
+        current: "ngayong oras",
+        past: ["", "", "", "", "", "{0} oras ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} oras"],
+    },
+    Patterns {
+        current: "ngayong araw",
+        past: ["", "", "", "", "", "{0} araw ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} araw"],
+    },
+    Patterns {
+        current: "sa linggong ito",
+        past: ["", "", "", "", "", "{0} linggo ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} linggo"],
+    },
+    Patterns {
+        current: "ngayong buwan",
+        past: ["", "", "", "", "", "{0} buwan ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} buwan"],
+    },
+    Patterns {
+        current: "ngayong taon",
+        past: ["", "", "", "", "", "{0} taon ang nakalipas"],
+        future: ["", "", "", "", "", "sa {0} taon"],
+    },
+];
+
+// tn
+static R102: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "gompieno",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "beke eno",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "kgwedi eno",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "monongwaga",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// to
+static R103: Names = [
+    Patterns {
+        current: "taimí ni",
+        past: ["", "", "", "", "", "sekoni ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he sekoni ʻe {0}"],
+    },
+    Patterns {
+        current: "ko e miniti ʻeni",
+        past: ["", "", "", "", "", "miniti ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he miniti ʻe {0}"],
+    },
+    Patterns {
+        current: "ko e houa ʻeni",
+        past: ["", "", "", "", "", "houa ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he houa ʻe {0}"],
+    },
+    Patterns {
+        current: "ʻahó ni",
+        past: ["", "", "", "", "", "ʻaho ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he ʻaho ʻe {0}"],
+    },
+    Patterns {
+        current: "uiké ni",
+        past: ["", "", "", "", "", "uike ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he uike ʻe {0}"],
+    },
+    Patterns {
+        current: "māhiná ni",
+        past: ["", "", "", "", "", "māhina ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he māhina ʻe {0}"],
+    },
+    Patterns {
+        current: "taʻú ni",
+        past: ["", "", "", "", "", "taʻu ʻe {0} kuoʻosi"],
+        future: ["", "", "", "", "", "ʻi he taʻu ʻe {0}"],
+    },
+];
+
+// tr
+static R104: Names = [
+    Patterns {
+        current: "şimdi",
+        past: ["", "", "", "", "", "{0} saniye önce"],
+        future: ["", "", "", "", "", "{0} saniye sonra"],
+    },
+    Patterns {
+        current: "bu dakika",
+        past: ["", "", "", "", "", "{0} dakika önce"],
+        future: ["", "", "", "", "", "{0} dakika sonra"],
+    },
+    Patterns {
+        current: "bu saat",
+        past: ["", "", "", "", "", "{0} saat önce"],
+        future: ["", "", "", "", "", "{0} saat sonra"],
+    },
+    Patterns {
+        current: "bugün",
+        past: ["", "", "", "", "", "{0} gün önce"],
+        future: ["", "", "", "", "", "{0} gün sonra"],
+    },
+    Patterns {
+        current: "bu hafta",
+        past: ["", "", "", "", "", "{0} hafta önce"],
+        future: ["", "", "", "", "", "{0} hafta sonra"],
+    },
+    Patterns {
+        current: "bu ay",
+        past: ["", "", "", "", "", "{0} ay önce"],
+        future: ["", "", "", "", "", "{0} ay sonra"],
+    },
+    Patterns {
+        current: "bu yıl",
+        past: ["", "", "", "", "", "{0} yıl önce"],
+        future: ["", "", "", "", "", "{0} yıl sonra"],
+    },
+];
+
+// tt
+static R105: Names = [
+    Patterns {
+        current: "хәзер",
+        past: ["", "", "", "", "", "{0} секунд элек"],
+        future: ["", "", "", "", "", "{0} секундтан"],
+    },
+    Patterns {
+        current: "бу минутта",
+        past: ["", "", "", "", "", "{0} минут элек"],
+        future: ["", "", "", "", "", "{0} минуттан"],
+    },
+    Patterns {
+        current: "бу сәгатьтә",
+        past: ["", "", "", "", "", "{0} сәгать элек"],
+        future: ["", "", "", "", "", "{0} сәгатьтән"],
+    },
+    Patterns {
+        current: "бүген",
+        past: ["", "", "", "", "", "{0} көн элек"],
+        future: ["", "", "", "", "", "{0} көннән"],
+    },
+    Patterns {
+        current: "бу атнада",
+        past: ["", "", "", "", "", "{0} атна элек"],
+        future: ["", "", "", "", "", "{0} атнадан"],
+    },
+    Patterns {
+        current: "бу айда",
+        past: ["", "", "", "", "", "{0} ай элек"],
+        future: ["", "", "", "", "", "{0} айдан"],
+    },
+    Patterns {
+        current: "быел",
+        past: ["", "", "", "", "", "{0} ел элек"],
+        future: ["", "", "", "", "", "{0} елдан"],
+    },
+];
+
+// ug
+static R106: Names = [
+    Patterns {
+        current: "now",
+        past: ["", "", "", "", "", "{0} سېكۇنت ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} سېكۇنتتىن كېيىن"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "", "", "", "", "{0} مىنۇت ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} مىنۇتتىن كېيىن"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "{0} سائەت ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} سائەتتىن كېيىن"],
+    },
+    Patterns {
+        current: "بۈگۈن",
+        past: ["", "", "", "", "", "{0} كۈن ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} كۈندىن كېيىن"],
+    },
+    Patterns {
+        current: "بۇ ھەپتە",
+        past: ["", "", "", "", "", "{0} ھەپتە ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} ھەپتىدىن كېيىن"],
+    },
+    Patterns {
+        current: "بۇ ئاي",
+        past: ["", "", "", "", "", "{0} ئاي ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} ئايدىن كېيىن"],
+    },
+    Patterns {
+        current: "بۇ يىل",
+        past: ["", "", "", "", "", "{0} يىل ئىلگىرى"],
+        future: ["", "", "", "", "", "{0} يىلدىن كېيىن"],
+    },
+];
+
+// uk
+static R107: Names = [
+    Patterns {
+        current: "зараз",
+        past: ["", "{0} секунду тому", "", "", "{0} секунд тому", "{0} секунди тому"],
+        future: ["", "через {0} секунду", "", "", "через {0} секунд", "через {0} секунди"],
+    },
+    Patterns {
+        current: "цієї хвилини",
+        past: ["", "{0} хвилину тому", "", "", "{0} хвилин тому", "{0} хвилини тому"],
+        future: ["", "через {0} хвилину", "", "", "через {0} хвилин", "через {0} хвилини"],
+    },
+    Patterns {
+        current: "цієї години",
+        past: ["", "{0} годину тому", "", "", "{0} годин тому", "{0} години тому"],
+        future: ["", "через {0} годину", "", "", "через {0} годин", "через {0} години"],
+    },
+    Patterns {
+        current: "сьогодні",
+        past: ["", "{0} день тому", "", "{0} дні тому", "{0} днів тому", "{0} дня тому"],
+        future: ["", "через {0} день", "", "через {0} дні", "через {0} днів", "через {0} дня"],
+    },
+    Patterns {
+        current: "цього тижня",
+        past: ["", "{0} тиждень тому", "", "{0} тижні тому", "{0} тижнів тому", "{0} тижня тому"],
+        future: ["", "через {0} тиждень", "", "через {0} тижні", "через {0} тижнів", "через {0} тижня"],
+    },
+    Patterns {
+        current: "цього місяця",
+        past: ["", "{0} місяць тому", "", "{0} місяці тому", "{0} місяців тому", "{0} місяця тому"],
+        future: ["", "через {0} місяць", "", "через {0} місяці", "через {0} місяців", "через {0} місяця"],
+    },
+    Patterns {
+        current: "цього року",
+        past: ["", "{0} рік тому", "", "{0} роки тому", "{0} років тому", "{0} року тому"],
+        future: ["", "через {0} рік", "", "через {0} роки", "через {0} років", "через {0} року"],
+    },
+];
+
+// ur
+static R108: Names = [
+    Patterns {
+        current: "اب",
+        past: ["", "", "", "", "", "{0} سیکنڈ پہلے"],
+        future: ["", "", "", "", "", "{0} سیکنڈ میں"],
+    },
+    Patterns {
+        current: "اس منٹ",
+        past: ["", "", "", "", "", "{0} منٹ پہلے"],
+        future: ["", "", "", "", "", "{0} منٹ میں"],
+    },
+    Patterns {
+        current: "اس گھنٹے",
+        past: ["", "{0} گھنٹہ پہلے", "", "", "", "{0} گھنٹے پہلے"],
+        future: ["", "", "", "", "", "{0} گھنٹے میں"],
+    },
+    Patterns {
+        current: "آج",
+        past: ["", "{0} دن پہلے", "", "", "", "{0} دنوں پہلے"],
+        future: ["", "{0} دن میں", "", "", "", "{0} دنوں میں"],
+    },
+    Patterns {
+        current: "اس ہفتہ",
+        past: ["", "{0} ہفتہ پہلے", "", "", "", "{0} ہفتے پہلے"],
+        future: ["", "{0} ہفتہ میں", "", "", "", "{0} ہفتے میں"],
+    },
+    Patterns {
+        current: "اس ماہ",
+        past: ["", "{0} مہینہ پہلے", "", "", "", "{0} مہینے پہلے"],
+        future: ["", "{0} مہینہ میں", "", "", "", "{0} مہینے میں"],
+    },
+    Patterns {
+        current: "اس سال",
+        past: ["", "", "", "", "", "{0} سال پہلے"],
+        future: ["", "", "", "", "", "{0} سال میں"],
+    },
+];
+
+// uz
+static R109: Names = [
+    Patterns {
+        current: "hozir",
+        past: ["", "", "", "", "", "{0} soniya oldin"],
+        future: ["", "", "", "", "", "{0} soniyadan keyin"],
+    },
+    Patterns {
+        current: "shu daqiqada",
+        past: ["", "", "", "", "", "{0} daqiqa oldin"],
+        future: ["", "", "", "", "", "{0} daqiqadan keyin"],
+    },
+    Patterns {
+        current: "shu soatda",
+        past: ["", "", "", "", "", "{0} soat oldin"],
+        future: ["", "", "", "", "", "{0} soatdan keyin"],
+    },
+    Patterns {
+        current: "bugun",
+        past: ["", "", "", "", "", "{0} kun oldin"],
+        future: ["", "", "", "", "", "{0} kundan keyin"],
+    },
+    Patterns {
+        current: "shu hafta",
+        past: ["", "", "", "", "", "{0} hafta oldin"],
+        future: ["", "", "", "", "", "{0} haftadan keyin"],
+    },
+    Patterns {
+        current: "shu oy",
+        past: ["", "", "", "", "", "{0} oy oldin"],
+        future: ["", "", "", "", "", "{0} oydan keyin"],
+    },
+    Patterns {
+        current: "shu yil",
+        past: ["", "", "", "", "", "{0} yil oldin"],
+        future: ["", "", "", "", "", "{0} yildan keyin"],
+    },
+];
+
+// vi
+static R110: Names = [
+    Patterns {
+        current: "bây giờ",
+        past: ["", "", "", "", "", "{0} giây trước"],
+        future: ["", "", "", "", "", "sau {0} giây nữa"],
+    },
+    Patterns {
+        current: "phút này",
+        past: ["", "", "", "", "", "{0} phút trước"],
+        future: ["", "", "", "", "", "sau {0} phút nữa"],
+    },
+    Patterns {
+        current: "giờ này",
+        past: ["", "", "", "", "", "{0} giờ trước"],
+        future: ["", "", "", "", "", "sau {0} giờ nữa"],
+    },
+    Patterns {
+        current: "Hôm nay",
+        past: ["", "", "", "", "", "{0} ngày trước"],
+        future: ["", "", "", "", "", "sau {0} ngày nữa"],
+    },
+    Patterns {
+        current: "tuần này",
+        past: ["", "", "", "", "", "{0} tuần trước"],
+        future: ["", "", "", "", "", "sau {0} tuần nữa"],
+    },
+    Patterns {
+        current: "tháng này",
+        past: ["", "", "", "", "", "{0} tháng trước"],
+        future: ["", "", "", "", "", "sau {0} tháng nữa"],
+    },
+    Patterns {
+        current: "năm nay",
+        past: ["", "", "", "", "", "{0} năm trước"],
+        future: ["", "", "", "", "", "sau {0} năm nữa"],
+    },
+];
+
+// cy
+static R111: Names = [
+    Patterns {
+        current: "nawr",
+        past: ["", "", "", "", "", "{0} eiliad yn ôl"],
+        future: ["", "", "", "", "", "ymhen {0} eiliad"],
+    },
+    Patterns {
+        current: "y funud hon",
+        past: ["", "", "", "", "", "{0} munud yn ôl"],
+        future: ["", "", "", "", "", "ymhen {0} munud"],
+    },
+    Patterns {
+        current: "yr awr hon",
+        past: ["", "", "", "", "", "{0} awr yn ôl"],
+        future: ["", "ymhen awr", "", "", "", "ymhen {0} awr"],
+    },
+    Patterns {
+        current: "heddiw",
+        past: ["", "", "{0} ddiwrnod yn ôl", "", "", "{0} diwrnod yn ôl"],
+        future: ["", "ymhen diwrnod", "ymhen deuddydd", "", "", "ymhen {0} diwrnod"],
+    },
+    Patterns {
+        current: "yr wythnos hon",
+        past: ["", "", "", "", "", "{0} wythnos yn ôl"],
+        future: ["", "ymhen wythnos", "ymhen pythefnos", "", "", "ymhen {0} wythnos"],
+    },
+    Patterns {
+        current: "y mis hwn",
+        past: ["", "", "{0} fis yn ôl", "", "", "{0} mis yn ôl"],
+        future: ["", "ymhen mis", "ymhen deufis", "", "", "ymhen {0} mis"],
+    },
+    Patterns {
+        current: "eleni",
+        past: ["", "blwyddyn yn ôl", "{0} flynedd yn ôl", "", "", "{0} o flynyddoedd yn ôl"],
+        future: ["ymhen {0} o flynyddoedd", "ymhen blwyddyn", "ymhen {0} flynedd", "ymhen {0} blynedd", "ymhen {0} blynedd", "ymhen {0} mlynedd"],
+    },
+];
+
+// wo
+static R112: Names = [
+    Patterns {
+        current: "leegi",
+        past: ["", "", "", "", "", "{0} saa ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} saa"],
+    },
+    Patterns {
+        current: "ci simili bii",
+        past: ["", "", "", "", "", "{0} simili ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} simili"],
+    },
+    Patterns {
+        current: "ci waxtu wii",
+        past: ["", "", "", "", "", "{0} waxtu ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} waxtu"],
+    },
+    Patterns {
+        current: "tay",
+        past: ["", "", "", "", "", "{0} fan ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} fan"],
+    },
+    Patterns {
+        current: "ayu-bis bii",
+        past: ["", "", "", "", "", "{0} ayi-bis ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} ayi-bis"],
+    },
+    Patterns {
+        current: "weer wii",
+        past: ["", "", "", "", "", "{0} weer ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} weer"],
+    },
+    Patterns {
+        current: "ren",
+        past: ["", "", "", "", "", "{0} at ci ginaaw"],
+        future: ["", "", "", "", "", "fileek {0} at"],
+    },
+];
+
+// fy
+static R113: Names = [
+    Patterns {
+        current: "nu",
+        past: ["", "{0} sekonde lyn", "", "", "", "{0} sekonden lyn"],
+        future: ["", "Oer {0} sekonde", "", "", "", "Oer {0} sekonden"],
+    },
+    Patterns {
+        current: "this minute",
+        past: ["", "{0} minút lyn", "", "", "", "{0} minuten lyn"],
+        future: ["", "Oer {0} minút", "", "", "", "Oer {0} minuten"],
+    },
+    Patterns {
+        current: "this hour",
+        past: ["", "", "", "", "", "{0} oere lyn"],
+        future: ["", "", "", "", "", "Oer {0} oere"],
+    },
+    Patterns {
+        current: "vandaag",
+        past: ["", "{0} dei lyn", "", "", "", "{0} deien lyn"],
+        future: ["", "Oer {0} dei", "", "", "", "Oer {0} deien"],
+    },
+    Patterns {
+        current: "dizze wike",
+        past: ["", "{0} wike lyn", "", "", "", "{0} wiken lyn"],
+        future: ["", "Oer {0} wike", "", "", "", "Oer {0} wiken"],
+    },
+    Patterns {
+        current: "dizze moanne",
+        past: ["", "{0} moanne lyn", "", "", "", "{0} moannen lyn"],
+        future: ["", "Oer {0} moanne", "", "", "", "Oer {0} moannen"],
+    },
+    Patterns {
+        current: "dit jier",
+        past: ["", "", "", "", "", "{0} jier lyn"],
+        future: ["", "", "", "", "", "Oer {0} jier"],
+    },
+];
+
+// xh
+static R114: Names = [
+    Patterns {
+        current: "ngoku",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "ngalo mzuzu",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "ngale yure",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "namhlanje",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "ku;e veki",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "kule nyanga",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "kulo nyaka",
+        past: ["", "", "", "", "", "-{0} y"],
+        future: ["", "", "", "", "", "+{0} y"],
+    },
+];
+
+// yo
+static R115: Names = [
+    Patterns {
+        current: "nísinsìyí",
+        past: ["", "", "", "", "", "-{0} s"],
+        future: ["", "", "", "", "", "+{0} s"],
+    },
+    Patterns {
+        current: "ìṣẹ́jú yìí",
+        past: ["", "", "", "", "", "-{0} min"],
+        future: ["", "", "", "", "", "+{0} min"],
+    },
+    Patterns {
+        current: "wákàtí yìí",
+        past: ["", "", "", "", "", "-{0} h"],
+        future: ["", "", "", "", "", "+{0} h"],
+    },
+    Patterns {
+        current: "Òní",
+        past: ["", "", "", "", "", "-{0} d"],
+        future: ["", "", "", "", "", "+{0} d"],
+    },
+    Patterns {
+        current: "ọ̀sẹ̀ yìí",
+        past: ["", "", "", "", "", "-{0} w"],
+        future: ["", "", "", "", "", "+{0} w"],
+    },
+    Patterns {
+        current: "oṣù yìí",
+        past: ["", "", "", "", "", "-{0} m"],
+        future: ["", "", "", "", "", "+{0} m"],
+    },
+    Patterns {
+        current: "Ọdún yìí",
+        past: ["", "", "", "", "", "{0} Ọdún sẹ́yìn"],
+        future: ["", "", "", "", "", "ní {0} Ọdún"],
+    },
+];
+
+// zu
+static R116: Names = [
+    Patterns {
+        current: "manje",
+        past: ["", "{0} isekhondi eledlule", "", "", "", "{0} amasekhondi edlule"],
+        future: ["", "kusekhondi elingu-{0} elizayo", "", "", "", "kumasekhondi angu-{0} ezayo"],
+    },
+    Patterns {
+        current: "leli minithi",
+        past: ["", "{0} iminithi eledlule", "", "", "", "{0} amaminithi edlule"],
+        future: ["", "kuminithi elingu-{0} elizayo", "", "", "", "kumaminithi angu-{0} ezayo"],
+    },
+    Patterns {
+        current: "leli hora",
+        past: ["", "{0} ihora eledlule", "", "", "", "emahoreni angu-{0} edlule"],
+        future: ["", "ehoreni elingu-{0} elizayo", "", "", "", "emahoreni angu-{0} ezayo"],
+    },
+    Patterns {
+        current: "namhlanje",
+        past: ["", "osukwini olungu-{0} olwedlule", "", "", "", "ezinsukwini ezingu-{0} ezedlule."],
+        future: ["", "osukwini olungu-{0} oluzayo", "", "", "", "ezinsukwini ezingu-{0} ezizayo"],
+    },
+    Patterns {
+        current: "leli viki",
+        past: ["", "evikini elingu-{0} eledlule", "", "", "", "amaviki angu-{0} edlule"],
+        future: ["", "evikini elingu-{0}", "", "", "", "emavikini angu-{0}"],
+    },
+    Patterns {
+        current: "le nyanga",
+        past: ["", "{0} inyanga edlule", "", "", "", "{0} izinyanga ezedlule"],
+        future: ["", "enyangeni engu-{0}", "", "", "", "ezinyangeni ezingu-{0} ezizayo"],
+    },
+    Patterns {
+        current: "kulo nyaka",
+        past: ["", "{0} unyaka odlule", "", "", "", "{0} iminyaka edlule"],
+        future: ["", "onyakeni ongu-{0} ozayo", "", "", "", "eminyakeni engu-{0} ezayo"],
+    },
+];
+
+/// The patterns of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [Option<&Names>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&R0),
+    /* ak */ Some(&R1),
+    /* sq */ Some(&R2),
+    /* am */ Some(&R3),
+    /* ar */ Some(&R4),
+    /* an */ None,
+    /* hy */ Some(&R5),
+    /* as */ Some(&R6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&R7),
+    /* bm */ None,
+    /* ba */ Some(&R8),
+    /* eu */ Some(&R9),
+    /* be */ Some(&R10),
+    /* bn */ Some(&R11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&R12),
+    /* br */ Some(&R13),
+    /* bg */ Some(&R14),
+    /* my */ Some(&R15),
+    /* ca */ Some(&R16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&R17),
+    /* cv */ Some(&R18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&R19),
+    /* cs */ Some(&R20),
+    /* da */ Some(&R21),
+    /* dv */ None,
+    /* nl */ Some(&R22),
+    /* dz */ None,
+    /* en */ Some(&R23),
+    /* eo */ Some(&R24),
+    /* et */ Some(&R25),
+    /* ee */ Some(&R26),
+    /* fo */ Some(&R27),
+    /* fj */ None,
+    /* fi */ Some(&R28),
+    /* fr */ Some(&R29),
+    /* ff */ None,
+    /* gl */ Some(&R30),
+    /* ka */ Some(&R31),
+    /* de */ Some(&R32),
+    /* el */ Some(&R33),
+    /* gn */ None,
+    /* gu */ Some(&R34),
+    /* ht */ Some(&R29),
+    /* ha */ Some(&R35),
+    /* he */ Some(&R36),
+    /* hz */ None,
+    /* hi */ Some(&R37),
+    /* ho */ None,
+    /* hu */ Some(&R38),
+    /* ia */ Some(&R39),
+    /* id */ Some(&R40),
+    /* ie */ Some(&R41),
+    /* ga */ Some(&R42),
+    /* ig */ Some(&R43),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&R44),
+    /* it */ Some(&R45),
+    /* iu */ None,
+    /* ja */ Some(&R46),
+    /* jv */ Some(&R47),
+    /* kl */ None,
+    /* kn */ Some(&R48),
+    /* kr */ None,
+    /* ks */ Some(&R49),
+    /* kk */ Some(&R50),
+    /* km */ Some(&R51),
+    /* ki */ None,
+    /* rw */ Some(&R52),
+    /* ky */ Some(&R53),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&R54),
+    /* ku */ Some(&R55),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&R56),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&R57),
+    /* lt */ Some(&R58),
+    /* lu */ None,
+    /* lv */ Some(&R59),
+    /* gv */ None,
+    /* mk */ Some(&R60),
+    /* mg */ None,
+    /* ms */ Some(&R61),
+    /* ml */ Some(&R62),
+    /* mt */ Some(&R63),
+    /* mi */ Some(&R64),
+    /* mr */ Some(&R65),
+    /* mh */ None,
+    /* mn */ Some(&R66),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&R67),
+    /* ng */ None,
+    /* nb */ Some(&R68),
+    /* nn */ Some(&R69),
+    /* no */ Some(&R68),
+    /* ii */ Some(&R70),
+    /* nr */ None,
+    /* oc */ None,
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ Some(&R71),
+    /* or */ Some(&R72),
+    /* os */ None,
+    /* pa */ Some(&R73),
+    /* pi */ None,
+    /* fa */ Some(&R74),
+    /* pl */ Some(&R75),
+    /* ps */ Some(&R76),
+    /* pt */ Some(&R77),
+    /* qu */ Some(&R78),
+    /* rm */ Some(&R79),
+    /* rn */ None,
+    /* ro */ Some(&R80),
+    /* ru */ Some(&R81),
+    /* sa */ Some(&R82),
+    /* sc */ Some(&R83),
+    /* sd */ Some(&R84),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&R85),
+    /* gd */ Some(&R86),
+    /* sn */ None,
+    /* si */ Some(&R87),
+    /* sk */ Some(&R88),
+    /* sl */ Some(&R89),
+    /* so */ Some(&R90),
+    /* st */ None,
+    /* es */ Some(&R91),
+    /* su */ Some(&R92),
+    /* sw */ Some(&R93),
+    /* ss */ None,
+    /* sv */ Some(&R94),
+    /* ta */ Some(&R95),
+    /* te */ Some(&R96),
+    /* tg */ Some(&R97),
+    /* th */ Some(&R98),
+    /* ti */ Some(&R99),
+    /* bo */ None,
+    /* tk */ Some(&R100),
+    /* tl */ Some(&R101),
+    /* tn */ Some(&R102),
+    /* to */ Some(&R103),
+    /* tr */ Some(&R104),
+    /* ts */ None,
+    /* tt */ Some(&R105),
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&R106),
+    /* uk */ Some(&R107),
+    /* ur */ Some(&R108),
+    /* uz */ Some(&R109),
+    /* ve */ None,
+    /* vi */ Some(&R110),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&R111),
+    /* wo */ Some(&R112),
+    /* fy */ Some(&R113),
+    /* xh */ Some(&R114),
+    /* yi */ None,
+    /* yo */ Some(&R115),
+    /* za */ None,
+    /* zu */ Some(&R116),
+];
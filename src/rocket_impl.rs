@@ -0,0 +1,311 @@
+//! `rocket` support: the `Config` fairing, the `LangCode` request guard,
+//! and custom resolvers with access to the `rocket::Request`.
+use crate::{source::LangSource, *};
+use rocket::{
+    async_trait,
+    fairing::{Fairing, Info, Kind},
+    futures::FutureExt,
+    request::{FromRequest, Outcome},
+    response::{Responder, Response},
+    Build, Data, Request, Rocket,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+};
+
+// type aliases for reduced verbosity
+pub(crate) type Fun = fn(&Request) -> Result<LangCode, Error>;
+pub(crate) type AsyncFn = Arc<
+    dyn Fn(
+            &Request,
+        )
+            -> Pin<Box<dyn Future<Output = Result<LangCode, Error>> + Send + Sync + 'static>>
+        + Send
+        + Sync
+        + 'static,
+>;
+
+impl Config {
+    /// Used to specify a custom language resolution method.
+    pub fn custom(self, f: Fun) -> Self {
+        Self {
+            custom: Some(Ok(f)),
+            ..self
+        }
+    }
+    /// Used to specify a custom language resolution method with async block.
+    pub fn custom_async<F>(self, f: fn(&Request) -> F) -> Self
+    where
+        F: Future<Output = Result<LangCode, Error>> + Send + Sync + 'static,
+    {
+        Self {
+            custom: Some(Err(Arc::new(move |req| Box::pin(f(req))))),
+            ..self
+        }
+    }
+    /// Takes the Config structure by value and returns a new one that resolves
+    /// the language of every request as soon as it arrives.
+    /// By default the language is only resolved the first time
+    /// it is requested, so requests that never use it skip the negotiation.
+    pub fn eager(mut self) -> Self {
+        self.eager = true;
+        self
+    }
+
+    /// Returns the configuration that applies to a request, which is the scope of
+    /// the route it was routed to, then the scope matching its path, or `self`.
+    pub fn for_request(&self, req: &Request<'_>) -> &Config {
+        req.route()
+            .and_then(|route| route.name.as_deref())
+            .and_then(|name| {
+                self.route_scopes
+                    .iter()
+                    .find(|(scope, _)| scope == name)
+            })
+            .map_or_else(
+                || self.for_path(req.uri().path().as_str()),
+                |(_, config)| config,
+            )
+    }
+
+    /// Returns the wildcard if it has a quality of 0.0, while other languages are supported.
+    fn unsupported_wildcard(&self) -> Option<LangCode> {
+        let lang = self.wildcard?;
+        let supported = self.supported_languages();
+        (!supported.is_empty() && !supported.contains(&lang)).then_some(lang)
+    }
+
+    /// Returns true if no resolution method can ever produce a language.
+    fn never_succeeds(&self) -> bool {
+        self.custom.is_none()
+            && self.resolver.is_none()
+            && self.url.is_none()
+            && self.cookie.is_none()
+            && self.wildcard.is_none()
+            && self
+                .supported_languages()
+                .is_empty()
+    }
+
+    pub(crate) async fn choose(&self, req: &Request<'_>) -> Result<LangCode, Error> {
+        let custom = self.with_custom(req).await;
+        self.choose_after(req, custom)
+    }
+
+    /// Resolves the language without waiting. Returns `None` if the
+    /// async custom resolver can't complete right away.
+    pub(crate) fn choose_now(&self, req: &Request<'_>) -> Option<Result<LangCode, Error>> {
+        let custom = self
+            .with_custom(req)
+            .now_or_never()?;
+        Some(self.choose_after(req, custom))
+    }
+    async fn with_custom(&self, req: &Request<'_>) -> Result<LangCode, Option<Error>> {
+        match self.custom.as_ref() {
+            Some(Ok(custom)) => custom(req).map_err(Some),
+            Some(Err(custom)) => custom(req)
+                .await
+                .map_err(Some),
+            None => Err(None),
+        }
+    }
+}
+
+pub(crate) struct PrivConfig(pub(crate) Config);
+
+/// Returns the amount of segments of the longest mounted route,
+/// or `None` if there are no routes, or a route matches any amount of segments.
+fn longest_route(rocket: &Rocket<Build>) -> Option<usize> {
+    let mut longest = None;
+    for route in rocket.routes() {
+        let segments: Vec<_> = route
+            .uri
+            .path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        if segments
+            .last()
+            .is_some_and(|segment| segment.ends_with("..>"))
+        {
+            return None;
+        }
+        longest = longest.max(Some(segments.len()));
+    }
+    longest
+}
+
+/// The language of a request, once resolved.
+#[derive(Default)]
+struct Resolved(OnceLock<Result<LangCode, Error>>);
+
+/// Returns the cell holding the language of the request once it is resolved.
+fn resolved<'r>(req: &'r Request<'_>) -> &'r OnceLock<Result<LangCode, Error>> {
+    &req.local_cache(Resolved::default)
+        .0
+}
+
+/// Resolves the language of a request on first use, and caches it.
+pub(crate) async fn resolve(req: &Request<'_>) -> Result<LangCode, Error> {
+    if let Some(lang) = resolved(req).get() {
+        return lang.clone();
+    }
+    let lang = match req
+        .rocket()
+        .state::<PrivConfig>()
+    {
+        Some(config) => {
+            config
+                .0
+                .for_request(req)
+                .choose(req)
+                .await
+        }
+        None => crate::fallback::resolve(req),
+    };
+    resolved(req)
+        .get_or_init(|| lang)
+        .clone()
+}
+
+/// Same as `resolve`, for synchronous contexts. If the async custom resolver
+/// can't complete right away, it fails with `Error::Pending`, and nothing is
+/// cached, so the language can still be resolved with `resolve`.
+pub(crate) fn resolve_now(req: &Request<'_>) -> Result<LangCode, Error> {
+    if let Some(lang) = resolved(req).get() {
+        return lang.clone();
+    }
+    let lang = match req
+        .rocket()
+        .state::<PrivConfig>()
+    {
+        Some(config) => config
+            .0
+            .for_request(req)
+            .choose_now(req)
+            .ok_or(Error::Pending)?,
+        None => crate::fallback::resolve(req),
+    };
+    resolved(req)
+        .get_or_init(|| lang)
+        .clone()
+}
+
+#[async_trait]
+impl Fairing for Config {
+    fn info(&self) -> Info {
+        Info {
+            name: "Language configuration",
+            kind: Kind::Ignite | Kind::Request | Kind::Response,
+        }
+    }
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> rocket::fairing::Result {
+        let problems = self.problems(longest_route(&rocket));
+        if !problems.is_empty() {
+            rocket::error!("Invalid language configuration:");
+            for problem in problems {
+                rocket::error_!("{}", problem);
+            }
+            return Err(rocket);
+        }
+        for (name, _) in &self.route_scopes {
+            if !rocket
+                .routes()
+                .any(|route| route.name.as_deref() == Some(name))
+            {
+                rocket::warn!(
+                    "The language configuration is scoped to `{}`, but no route has that name.",
+                    name
+                );
+            }
+        }
+        let configs = std::iter::once(("/", self)).chain(
+            self.scopes
+                .iter()
+                .chain(&self.route_scopes)
+                .map(|(base, config)| (base.as_str(), config)),
+        );
+        for (base, config) in configs {
+            if config.never_succeeds() {
+                rocket::warn!(
+                    "The language configuration for `{}` can never resolve a language: \
+                     every quality is 0.0, and there is no wildcard, url, cookie or custom resolver.",
+                    base
+                );
+            }
+            if let Some(lang) = config.unsupported_wildcard() {
+                rocket::warn!(
+                    "The wildcard `{}` of the language configuration for `{}` has a quality of 0.0.",
+                    lang,
+                    base
+                );
+            }
+        }
+        Ok(rocket.manage(PrivConfig(self.clone())))
+    }
+    // in eager mode this gets executed before routing the request,
+    // otherwise the language is resolved by the first `LangCode` guard.
+    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
+        if self.eager {
+            let _ = resolve(req).await;
+        }
+    }
+    // responses get the language as their `Content-Language`, if it was resolved.
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Some(Ok(lang)) = resolved(req).get() {
+            if !res
+                .headers()
+                .contains("Content-Language")
+            {
+                res.set_raw_header("Content-Language", lang.as_str());
+            }
+        }
+    }
+}
+
+impl LangSource for Request<'_> {
+    fn path(&self) -> &str {
+        self.uri().path().as_str()
+    }
+    fn accept_language(&self) -> Option<&str> {
+        self.headers()
+            .get_one("Accept-Language")
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        self.cookies()
+            .get(name)
+            .map(|cookie| cookie.value())
+    }
+}
+
+impl TryFrom<&Request<'_>> for LangCode {
+    type Error = Error;
+    fn try_from(req: &Request) -> Result<LangCode, Error> {
+        resolve_now(req)
+    }
+}
+
+/// The language code value gets cached on construction,
+/// so it is ok to construct it multiple times.
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for LangCode {
+    type Error = Error;
+    /// The language is resolved with the attached config the first time
+    /// it is requested, unless the config is eager.
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match resolve(request).await {
+            Ok(lang) => Outcome::Success(lang),
+            Err(err) => Outcome::Failure((err.status(), err)),
+        }
+    }
+}
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for Error {
+    fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
+        Response::build()
+            .status(self.status())
+            .ok()
+    }
+}
@@ -0,0 +1,246 @@
+//! Localized sitemap generation.
+//!
+//! Given a list of paths without a language segment, the sitemap lists the
+//! localized url of every page for each language with a non-zero quality.
+//! The language segment is inserted at the position set with `Config::url`,
+//! and every entry links to its translations with `xhtml:link` alternates.
+//! ```rust
+//! # use rocket_lang::{*, sitemap::Sitemap};
+//! let mut config = Config::new().url(0);
+//! config[En] = 1.0;
+//! config[Es] = 1.0;
+//!
+//! let sitemap = Sitemap::new("https://example.com", ["/", "/about"]);
+//! # #[cfg(feature = "rocket")]
+//! let rocket = rocket::build()
+//!     .mount("/", sitemap.routes())
+//!     .attach(config);
+//! ```
+//! The routes serve `/sitemap.xml`. When there are more entries than
+//! `Sitemap::max_urls`, `/sitemap.xml` becomes a sitemap index, and the
+//! entries are split across `/sitemap/1.xml`, `/sitemap/2.xml`, and so on.
+#[cfg(feature = "rocket")]
+use crate::rocket_impl::PrivConfig;
+use crate::*;
+#[cfg(feature = "rocket")]
+use rocket::{
+    http::{ContentType, Method},
+    route::{self, Handler},
+    Data, Request, Route,
+};
+use std::fmt::Write;
+
+/// The maximum amount of urls in a single sitemap file allowed by the protocol.
+pub const MAX_URLS: usize = 50_000;
+
+/// A sitemap generator for a set of language independent paths.
+#[derive(Debug, Clone)]
+pub struct Sitemap {
+    base_url: String,
+    paths: Vec<String>,
+    max_urls: usize,
+}
+
+impl Sitemap {
+    /// Constructs a sitemap for the given paths. The base url is
+    /// prepended to every path, as sitemaps require absolute urls.
+    pub fn new<I, S>(base_url: impl Into<String>, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let base_url: String = base_url.into();
+        Self {
+            base_url: base_url
+                .trim_end_matches('/')
+                .into(),
+            paths: paths
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_urls: MAX_URLS,
+        }
+    }
+
+    /// Sets the maximum amount of urls per sitemap file.
+    /// Larger sitemaps are split and served through a sitemap index.
+    pub fn max_urls(mut self, max_urls: usize) -> Self {
+        self.max_urls = max_urls.clamp(1, MAX_URLS);
+        self
+    }
+
+    /// Returns the mountable routes serving the sitemap.
+    #[cfg(feature = "rocket")]
+    pub fn routes(self) -> Vec<Route> {
+        vec![
+            Route::new(Method::Get, "/sitemap.xml", Serve(self.clone(), false)),
+            Route::new(Method::Get, "/sitemap/<page>", Serve(self, true)),
+        ]
+    }
+
+    /// Returns the amount of sitemap files needed for the configuration.
+    pub fn pages(&self, config: &Config) -> usize {
+        let urls = self.entries(config).count();
+        urls.div_ceil(self.max_urls)
+            .max(1)
+    }
+
+    /// Renders the sitemap file with the given page number, starting from 1.
+    /// Returns `None` if there is no such page.
+    pub fn urlset(&self, config: &Config, page: usize) -> Option<String> {
+        if page == 0 || page > self.pages(config) {
+            return None;
+        }
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+            "\n",
+        ));
+        let entries = self
+            .entries(config)
+            .skip((page - 1) * self.max_urls)
+            .take(self.max_urls);
+        for (path, config, lang) in entries {
+            self.write_url(&mut xml, config, path, lang);
+        }
+        xml.push_str("</urlset>\n");
+        Some(xml)
+    }
+
+    /// Renders a sitemap index pointing to every sitemap file.
+    /// `prefix` is the path the routes were mounted on.
+    pub fn index(&self, config: &Config, prefix: &str) -> String {
+        let prefix = prefix.trim_end_matches('/');
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+            "\n",
+        ));
+        for page in 1..=self.pages(config) {
+            let loc = format!("{}{prefix}/sitemap/{page}.xml", self.base_url);
+            let _ = writeln!(xml, "  <sitemap><loc>{}</loc></sitemap>", escape(&loc));
+        }
+        xml.push_str("</sitemapindex>\n");
+        xml
+    }
+
+    /// Every path with the configuration of its scope, and each of its languages.
+    fn entries<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> impl Iterator<Item = (&'a str, &'a Config, Option<LangCode>)> + 'a {
+        self.paths
+            .iter()
+            .flat_map(move |path| {
+                let config = config.for_path(path);
+                languages(config)
+                    .into_iter()
+                    .map(move |lang| (path.as_str(), config, lang))
+            })
+    }
+
+    fn localize(&self, config: &Config, path: &str, lang: Option<LangCode>) -> Option<String> {
+        let path = match (config.url, lang) {
+            (Some(pos), Some(lang)) => url::insert(path, pos, lang)?,
+            _ => path.to_string(),
+        };
+        Some(format!("{}{path}", self.base_url))
+    }
+
+    fn write_url(&self, xml: &mut String, config: &Config, path: &str, lang: Option<LangCode>) {
+        let loc = match self.localize(config, path, lang) {
+            Some(loc) => loc,
+            None => return,
+        };
+        let _ = writeln!(xml, "  <url>\n    <loc>{}</loc>", escape(&loc));
+        if lang.is_some() {
+            let alternates = config
+                .supported_languages()
+                .into_iter()
+                .map(|lang| (lang.as_str(), lang))
+                .chain(
+                    config
+                        .wildcard
+                        .map(|lang| ("x-default", lang)),
+                );
+            for (hreflang, lang) in alternates {
+                if let Some(href) = self.localize(config, path, Some(lang)) {
+                    let _ = writeln!(
+                        xml,
+                        r#"    <xhtml:link rel="alternate" hreflang="{hreflang}" href="{}"/>"#,
+                        escape(&href)
+                    );
+                }
+            }
+        }
+        xml.push_str("  </url>\n");
+    }
+}
+
+/// The languages of a path. `None` stands for
+/// the path itself when the url position isn't set.
+fn languages(config: &Config) -> Vec<Option<LangCode>> {
+    if config.url.is_none() {
+        return vec![None];
+    }
+    config
+        .supported_languages()
+        .into_iter()
+        .map(Some)
+        .collect()
+}
+
+/// Escapes text for xml and html, in content and attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The route handler. The flag is set for the route serving the split files.
+#[cfg(feature = "rocket")]
+#[derive(Clone)]
+struct Serve(Sitemap, bool);
+
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl Handler for Serve {
+    async fn handle<'r>(&self, req: &'r Request<'_>, data: Data<'r>) -> route::Outcome<'r> {
+        let default = Config::new();
+        let config = req
+            .rocket()
+            .state::<PrivConfig>()
+            .map_or(&default, |config| &config.0);
+
+        let prefix = req
+            .route()
+            .map(|route| route.uri.base())
+            .unwrap_or("/");
+        let sitemap = &self.0;
+        let page = if self.1 { req.param::<&str>(1) } else { None };
+        let xml = match page {
+            None if sitemap.pages(config) == 1 => sitemap.urlset(config, 1),
+            None => Some(sitemap.index(config, prefix)),
+            Some(Ok(file)) => file
+                .strip_suffix(".xml")
+                .and_then(|page| page.parse().ok())
+                .and_then(|page| sitemap.urlset(config, page)),
+            Some(Err(_)) => None,
+        };
+        match xml {
+            Some(xml) => route::Outcome::from(req, (ContentType::XML, xml)),
+            None => route::Outcome::forward(data),
+        }
+    }
+}
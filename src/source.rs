@@ -0,0 +1,160 @@
+//! The parts of a request the language is resolved from.
+//!
+//! The negotiation doesn't depend on any web framework. `Config::negotiate` resolves the
+//! language of anything implementing `LangSource`, such as an `http::Request`, or `Parts`
+//! built from plain strings:
+//! ```rust
+//! # use rocket_lang::{*, source::Parts};
+//! let config = Config::new()
+//!     .cookie("lang")
+//!     .supported(&[En, Es]);
+//! let parts = Parts::new("/home")
+//!     .accept_language("en-US, es;q=0.5")
+//!     .cookies("theme=dark; lang=es");
+//! assert_eq!(config.negotiate(&parts).unwrap(), Es);
+//! ```
+#[cfg(any(feature = "tower", feature = "actix"))]
+use crate::*;
+use http::header::{ACCEPT_LANGUAGE, COOKIE};
+#[cfg(any(feature = "tower", feature = "actix"))]
+use std::sync::{Arc, OnceLock};
+
+/// A request the language can be resolved from.
+pub trait LangSource {
+    /// The path of the request, without the query.
+    fn path(&self) -> &str;
+    /// The value of the `Accept-Language` header.
+    fn accept_language(&self) -> Option<&str>;
+    /// The value of the cookie with the given name.
+    fn cookie(&self, name: &str) -> Option<&str>;
+}
+
+/// The parts of a request, as plain strings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Parts<'a> {
+    path: &'a str,
+    accept_language: Option<&'a str>,
+    cookies: Option<&'a str>,
+}
+
+impl<'a> Parts<'a> {
+    /// Constructs the parts of a request to the given path.
+    pub fn new(path: &'a str) -> Self {
+        Self {
+            path,
+            ..Self::default()
+        }
+    }
+    /// Sets the value of the `Accept-Language` header.
+    pub fn accept_language(mut self, header: &'a str) -> Self {
+        self.accept_language = Some(header);
+        self
+    }
+    /// Sets the value of the `Cookie` header, such as `lang=es; theme=dark`.
+    pub fn cookies(mut self, header: &'a str) -> Self {
+        self.cookies = Some(header);
+        self
+    }
+}
+
+impl LangSource for Parts<'_> {
+    fn path(&self) -> &str {
+        self.path
+    }
+    fn accept_language(&self) -> Option<&str> {
+        self.accept_language
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        find_cookie(self.cookies?, name)
+    }
+}
+
+impl<B> LangSource for http::Request<B> {
+    fn path(&self) -> &str {
+        self.uri().path()
+    }
+    fn accept_language(&self) -> Option<&str> {
+        self.headers()
+            .get(ACCEPT_LANGUAGE)?
+            .to_str()
+            .ok()
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        self.headers()
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .find_map(|header| find_cookie(header, name))
+    }
+}
+
+impl LangSource for http::request::Parts {
+    fn path(&self) -> &str {
+        self.uri.path()
+    }
+    fn accept_language(&self) -> Option<&str> {
+        self.headers
+            .get(ACCEPT_LANGUAGE)?
+            .to_str()
+            .ok()
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .find_map(|header| find_cookie(header, name))
+    }
+}
+
+impl<T: LangSource + ?Sized> LangSource for &T {
+    fn path(&self) -> &str {
+        (**self).path()
+    }
+    fn accept_language(&self) -> Option<&str> {
+        (**self).accept_language()
+    }
+    fn cookie(&self, name: &str) -> Option<&str> {
+        (**self).cookie(name)
+    }
+}
+
+/// Stored in the request extensions by the middlewares, so the language
+/// can be resolved once, and read again for the response.
+#[cfg(any(feature = "tower", feature = "actix"))]
+#[derive(Clone)]
+pub(crate) struct Negotiation {
+    config: Arc<Config>,
+    lang: Arc<OnceLock<Result<LangCode, Error>>>,
+}
+
+#[cfg(any(feature = "tower", feature = "actix"))]
+impl Negotiation {
+    pub(crate) fn new(config: Arc<Config>) -> Self {
+        Self {
+            config,
+            lang: Arc::default(),
+        }
+    }
+
+    /// Resolves the language on first use.
+    pub(crate) fn resolve(&self, source: &(impl LangSource + ?Sized)) -> Result<LangCode, Error> {
+        self.lang
+            .get_or_init(|| self.config.negotiate(source))
+            .clone()
+    }
+
+    /// Returns the language, if it was resolved successfully.
+    pub(crate) fn resolved(&self) -> Option<LangCode> {
+        self.lang.get()?.clone().ok()
+    }
+}
+
+/// Returns the value of a cookie in a `Cookie` header.
+pub(crate) fn find_cookie<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+    header
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim_matches('"'))
+}
@@ -0,0 +1,113 @@
+//! `sqlx` support. `LangCode` is stored as its lower case
+//! string representation in any TEXT compatible column.
+use crate::*;
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+impl<DB: Database> Type<DB> for LangCode
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for LangCode
+where
+    &'q str: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.as_str()
+            .encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for LangCode
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(<&str as Decode<DB>>::decode(value)?.parse()?)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+pub use postgres::LangCodeEnum;
+
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use crate::*;
+    use sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef},
+        Decode, Encode, Postgres, Type,
+    };
+
+    /// A wrapper around `LangCode` mapped to a Postgres enum
+    /// named `lang_code`, instead of a TEXT column.
+    /// The type can be created with the statement returned by
+    /// [`LangCodeEnum::create_type`].
+    /// ```rust
+    /// # use rocket_lang::*;
+    /// let lang = LangCodeEnum::from(Es);
+    /// assert_eq!(LangCode::from(lang), Es);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+    pub struct LangCodeEnum(pub LangCode);
+
+    impl LangCodeEnum {
+        /// The name of the Postgres type.
+        pub const TYPE_NAME: &'static str = "lang_code";
+
+        /// Returns a `CREATE TYPE` statement declaring
+        /// every language code as a variant of the enum.
+        pub fn create_type() -> String {
+            let variants: Vec<_> = LangCode::ALL_CODES
+                .iter()
+                .map(|lang| format!("'{}'", lang.as_str()))
+                .collect();
+            format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                Self::TYPE_NAME,
+                variants.join(", ")
+            )
+        }
+    }
+
+    impl From<LangCode> for LangCodeEnum {
+        fn from(lang: LangCode) -> Self {
+            Self(lang)
+        }
+    }
+
+    impl From<LangCodeEnum> for LangCode {
+        fn from(lang: LangCodeEnum) -> Self {
+            lang.0
+        }
+    }
+
+    impl Type<Postgres> for LangCodeEnum {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name(Self::TYPE_NAME)
+        }
+    }
+
+    impl Encode<'_, Postgres> for LangCodeEnum {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <&str as Encode<Postgres>>::encode(self.0.as_str(), buf)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for LangCodeEnum {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(Self(<&str as Decode<Postgres>>::decode(value)?.parse()?))
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/// Declares an enum restricted to the languages an application actually supports.
+///
+/// The generated enum mirrors the corresponding `LangCode` variants, so matching on it
+/// is exhaustive without a `_` arm, and the compiler will point out a missing translation.
+/// It implements `FromRequest`, `TryFrom<&Request>`, `FromStr`, `Display`, and conversions
+/// to and from `LangCode`. A `config()` preset is also generated, where every declared
+/// language has a quality of 1.0 and every other language a quality of 0.0.
+///
+/// ```rust
+/// use rocket_lang::{supported_languages, LangCode};
+///
+/// supported_languages! {
+///     pub enum AppLang { En, Es, Fr }
+/// }
+///
+/// fn greeting(lang: AppLang) -> &'static str {
+///     match lang {
+///         AppLang::En => "Hello",
+///         AppLang::Es => "Hola",
+///         AppLang::Fr => "Bonjour",
+///     }
+/// }
+///
+/// assert_eq!(greeting(AppLang::Es), "Hola");
+/// assert_eq!(LangCode::from(AppLang::Fr), LangCode::Fr);
+/// assert!(AppLang::try_from(LangCode::De).is_err());
+///
+/// # #[cfg(feature = "rocket")]
+/// let rocket = rocket::build().attach(AppLang::config());
+/// ```
+///
+/// When a request resolves to a language outside of the declared set,
+/// the guard fails with `Error::NotAcceptable`.
+#[macro_export]
+macro_rules! supported_languages {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($upper:ident),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+        $vis enum $name {$(
+            #[doc = concat!("See `LangCode::", stringify!($upper), "`.")]
+            $upper
+        ),+}
+
+        impl $name {
+            /// A collection with all the supported values to
+            /// iterate through them easily.
+            pub const ALL_CODES: &'static [Self] = &[$(Self::$upper,)*];
+
+            /// transforms the enum value to its lower case string representation.
+            pub fn as_str(self) -> &'static str {
+                $crate::LangCode::from(self).as_str()
+            }
+            /// Returns the name of the language in
+            /// english
+            pub fn english_name(self) -> &'static str {
+                $crate::LangCode::from(self).english_name()
+            }
+            /// Returns the name of the language in
+            /// its native name.
+            pub fn native_name(self) -> &'static str {
+                $crate::LangCode::from(self).native_name()
+            }
+            /// Returns the name of the language in
+            /// another language.
+            pub fn display_name_in(self, lang: $crate::LangCode) -> &'static str {
+                $crate::LangCode::from(self).display_name_in(lang)
+            }
+            /// Constructs a configuration where every supported language
+            /// has a quality of 1.0, and all others are left at 0.0.
+            pub fn config() -> $crate::Config {
+                let mut config = $crate::Config::new();
+                for lang in Self::ALL_CODES {
+                    config[$crate::LangCode::from(*lang)] = 1.0;
+                }
+                config
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::LangCode {
+            fn from(lang: $name) -> $crate::LangCode {
+                match lang {
+                    $($name::$upper => $crate::LangCode::$upper,)*
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::LangCode> for $name {
+            type Error = $crate::Error;
+            fn try_from(lang: $crate::LangCode) -> ::core::result::Result<$name, $crate::Error> {
+                match lang {
+                    $($crate::LangCode::$upper => Ok($name::$upper),)*
+                    _ => Err($crate::Error::NotAcceptable),
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::Error;
+            fn from_str(input: &str) -> ::core::result::Result<$name, $crate::Error> {
+                input
+                    .parse::<$crate::LangCode>()
+                    .and_then(::core::convert::TryFrom::try_from)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        $crate::__supported_languages_rocket!($name);
+    };
+}
+
+/// The `rocket` implementations of `supported_languages!`. The expansion depends on
+/// the features of this crate, so it can't be a `cfg` in the user's crate.
+#[cfg(feature = "rocket")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __supported_languages_rocket {
+    ($name:ident) => {
+        impl ::core::convert::TryFrom<&$crate::__private::rocket::Request<'_>> for $name {
+            type Error = $crate::Error;
+            fn try_from(
+                req: &$crate::__private::rocket::Request<'_>,
+            ) -> ::core::result::Result<$name, $crate::Error> {
+                $crate::LangCode::try_from(req).and_then(::core::convert::TryFrom::try_from)
+            }
+        }
+
+        impl $crate::__private::rocket::Sentinel for $name {
+            fn abort(
+                rocket: &$crate::__private::rocket::Rocket<$crate::__private::rocket::Ignite>,
+            ) -> bool {
+                <$crate::LangCode as $crate::__private::rocket::Sentinel>::abort(rocket)
+            }
+        }
+
+        #[$crate::__private::rocket::async_trait]
+        impl<'r> $crate::__private::rocket::request::FromRequest<'r> for $name {
+            type Error = $crate::Error;
+            async fn from_request(
+                request: &'r $crate::__private::rocket::Request<'_>,
+            ) -> $crate::__private::rocket::request::Outcome<Self, Self::Error> {
+                use $crate::__private::rocket::{outcome::Outcome, request::FromRequest};
+                let lang = match $crate::LangCode::from_request(request).await {
+                    Outcome::Success(lang) => lang,
+                    Outcome::Failure(failure) => return Outcome::Failure(failure),
+                    Outcome::Forward(forward) => return Outcome::Forward(forward),
+                };
+                match <$name as ::core::convert::TryFrom<_>>::try_from(lang) {
+                    Ok(lang) => Outcome::Success(lang),
+                    Err(err) => Outcome::Failure((err.status(), err)),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rocket"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __supported_languages_rocket {
+    ($name:ident) => {};
+}
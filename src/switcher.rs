@@ -0,0 +1,234 @@
+//! Routes for persisting an explicit language choice.
+//!
+//! The routes validate the requested language code, store it in the
+//! preference cookie, and redirect the client back to the page it came from.
+//! They can be mounted on any base path:
+//! ```rust
+//! # use rocket_lang::*;
+//! let config = Config::new().cookie("lang");
+//! let rocket = rocket::build()
+//!     .mount("/", switcher::routes())
+//!     .attach(config);
+//! ```
+//! Two routes are provided:
+//! * `GET /lang/<code>?redirect=/some/path`
+//! * `POST /lang`, with a form containing the fields `lang` and optionally `redirect`.
+//!
+//! The language must be supported by the configuration that applies to the redirect target,
+//! and it is stored in the cookie of that configuration.
+//!
+//! Only local paths are accepted as redirect targets. If the target is missing or
+//! points to another site, the `Referer` header is used when it belongs to the same host,
+//! and `/` otherwise.
+use crate::{rocket_impl::PrivConfig, *};
+use rocket::{
+    form::Form,
+    get,
+    http::{
+        uri::{Absolute, Origin},
+        Cookie, CookieJar, SameSite,
+    },
+    post,
+    response::Redirect,
+    routes, Route,
+};
+
+/// The cookie name used when `Config::cookie` was not set.
+pub const DEFAULT_COOKIE: &str = "lang";
+
+/// Returns the language switcher routes.
+pub fn routes() -> Vec<Route> {
+    routes![switch_get, switch_post]
+}
+
+/// Writes the language preference cookie.
+/// It uses the cookie name set in the `Config` scope of the request,
+/// then in the attached `Config`, or `DEFAULT_COOKIE` if there is none.
+/// ```rust
+/// # use rocket::post;
+/// # use rocket_lang::{LangCode, switcher::LangCookie};
+/// #[post("/profile/spanish")]
+/// fn prefer_spanish(cookie: LangCookie<'_>) {
+///     cookie.set(LangCode::Es);
+/// }
+/// ```
+pub struct LangCookie<'r> {
+    jar: &'r CookieJar<'r>,
+    config: Option<&'r Config>,
+    name: &'r str,
+}
+
+impl<'r> LangCookie<'r> {
+    /// Stores the language code in the preference cookie.
+    pub fn set(&self, lang: LangCode) {
+        let cookie = Cookie::build(self.name.to_string(), lang.as_str())
+            .path("/")
+            .same_site(SameSite::Lax)
+            .permanent()
+            .finish();
+        self.jar.add(cookie);
+    }
+    /// Removes the preference cookie.
+    pub fn remove(&self) {
+        self.jar.remove(
+            Cookie::build(self.name.to_string(), "")
+                .path("/")
+                .finish(),
+        );
+    }
+    /// Returns the language currently stored in the cookie, if any.
+    pub fn get(&self) -> Option<LangCode> {
+        self.jar
+            .get(self.name)?
+            .value()
+            .parse()
+            .ok()
+    }
+    /// Returns the cookie of the configuration that applies to `path`.
+    fn for_path(self, path: &str) -> Self {
+        LangCookie {
+            name: self
+                .config
+                .map_or(DEFAULT_COOKIE, |config| {
+                    cookie_name(config, config.for_path(path))
+                }),
+            ..self
+        }
+    }
+}
+
+/// Returns the cookie name of a scoped configuration, or else of the global one.
+fn cookie_name<'c>(config: &'c Config, scoped: &'c Config) -> &'c str {
+    scoped
+        .cookie
+        .as_deref()
+        .or(config.cookie.as_deref())
+        .unwrap_or(DEFAULT_COOKIE)
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for LangCookie<'r> {
+    type Error = std::convert::Infallible;
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let config = request
+            .rocket()
+            .state::<PrivConfig>()
+            .map(|config| &config.0);
+        Outcome::Success(LangCookie {
+            jar: request.cookies(),
+            config,
+            name: config.map_or(DEFAULT_COOKIE, |config| {
+                cookie_name(config, config.for_request(request))
+            }),
+        })
+    }
+}
+
+/// The page the client should be sent back to when no
+/// valid redirect target was specified.
+struct Back(String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Back {
+    type Error = std::convert::Infallible;
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let back = request
+            .headers()
+            .get_one("Referer")
+            .and_then(|referer| same_host(request, referer))
+            .unwrap_or_else(|| "/".into());
+        Outcome::Success(Back(back))
+    }
+}
+
+/// Returns the path and query of the referer if it points to the same host.
+fn same_host(request: &Request, referer: &str) -> Option<String> {
+    let referer = Absolute::parse(referer).ok()?;
+    let host = request
+        .headers()
+        .get_one("Host")?;
+    if !host.eq_ignore_ascii_case(
+        &referer
+            .authority()?
+            .to_string(),
+    ) {
+        return None;
+    }
+    let path = referer.path().as_str();
+    let path = if path.is_empty() { "/" } else { path };
+    let target = match referer.query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path.to_string(),
+    };
+    is_local(&target).then_some(target)
+}
+
+/// Checks that the target is a path on this site, so it cannot be
+/// used to send users to a different origin.
+pub(crate) fn is_local(target: &str) -> bool {
+    target.starts_with('/')
+        && !target.starts_with("//")
+        && !target.starts_with("/\\")
+        && Origin::parse(target).is_ok()
+}
+
+fn switch(
+    lang: &str,
+    redirect: Option<&str>,
+    cookie: LangCookie<'_>,
+    back: Back,
+) -> Result<Redirect, Error> {
+    let lang: LangCode = lang.parse()?;
+    let target = match redirect {
+        Some(target) if is_local(target) => target.to_string(),
+        _ => back.0,
+    };
+    let path = target
+        .split('?')
+        .next()
+        .unwrap_or("/");
+    if let Some(config) = cookie.config {
+        if !config
+            .for_path(path)
+            .allows(lang)
+        {
+            return Err(Error::NotAcceptable);
+        }
+    }
+    cookie
+        .for_path(path)
+        .set(lang);
+    Ok(Redirect::to(target))
+}
+
+#[get("/lang/<lang>?<redirect>")]
+fn switch_get(
+    lang: &str,
+    redirect: Option<&str>,
+    cookie: LangCookie<'_>,
+    back: Back,
+) -> Result<Redirect, Error> {
+    switch(lang, redirect, cookie, back)
+}
+
+mod form {
+    // rocket's form derive references a lint that newer compilers removed.
+    #![allow(renamed_and_removed_lints)]
+    use rocket::form::FromForm;
+
+    #[derive(FromForm)]
+    pub(super) struct Switch<'r> {
+        pub(super) lang: &'r str,
+        pub(super) redirect: Option<&'r str>,
+    }
+}
+use form::Switch;
+
+#[post("/lang", data = "<form>")]
+fn switch_post(
+    form: Form<Switch<'_>>,
+    cookie: LangCookie<'_>,
+    back: Back,
+) -> Result<Redirect, Error> {
+    switch(form.lang, form.redirect, cookie, back)
+}
@@ -0,0 +1,520 @@
+//! Template helpers for `rocket_dyn_templates`.
+//!
+//! The helpers are registered for both Tera and Handlebars, and they read the
+//! language negotiated for the request being rendered. For this, templates have
+//! to be returned wrapped in a `LangTemplate`.
+//!
+//! Helper | Tera | Handlebars
+//! ----|----|----
+//! The negotiated language code | `{{ lang() }}` | `{{lang}}`
+//! Its writing direction | `{{ lang_dir() }}` | `{{lang_dir}}`
+//! A translation | `{{ t(key="greeting") }}` | `{{t "greeting"}}`
+//! The native name of a language | `{{ language_name(code="es") }}` | `{{language_name "es"}}`
+//! The alternate url for a language | `{{ alternate_url(code="es") }}` | `{{alternate_url "es"}}`
+//! A formatted number | `{{ format_number(value=1234.5) }}` | `{{format_number 1234.5}}`
+//! A formatted percentage | `{{ format_percent(value=0.25) }}` | `{{format_percent 0.25}}`
+//!
+//! `lang_dir` optionally takes a language code, and `t`, `format_number` and `format_percent`
+//! optionally take a `lang` argument to override the negotiated language. Missing translations render the key itself.
+//! If no language can be negotiated, `LangTemplate` responds with the status of the error,
+//! such as `406 Not Acceptable`, and the helpers that need the negotiated language fail
+//! to render in templates that are not returned in a `LangTemplate` or `LocalizedTemplate`.
+//! ```rust
+//! # use rocket::get;
+//! # use rocket_dyn_templates::{context, Template};
+//! use rocket_lang::{templates::{self, LangTemplate, Translations}, *};
+//!
+//! #[get("/<_>/home")]
+//! fn home() -> LangTemplate {
+//!     Template::render("home", context! {}).into()
+//! }
+//!
+//! let translations = Translations::new()
+//!     .with(En, "greeting", "Hello")
+//!     .with(Es, "greeting", "Hola");
+//!
+//! let rocket = rocket::build()
+//!     .attach(Config::new().url(0))
+//!     .attach(templates::fairing(translations));
+//! ```
+//!
+//! ## Localized templates
+//! Alternatively, a separate template can be written for each language,
+//! and `LocalizedTemplate` will pick the best one available. For a template named `home`,
+//! the variants are looked up in the following order:
+//!   1. `home.es-MX`, when the client asked for a region of the negotiated language.
+//!   2. `home.es`, for the negotiated language.
+//!   3. `home.en`, for the wildcard language of the `Config`.
+//!   4. `home`
+//!
+//! Note that the files need to keep the data type extension,
+//! as in `home.es.html.tera`, or the language will be mistaken for it.
+//! The language of the rendered variant is reported in the `Content-Language` header,
+//! and it is also the language seen by the helpers. When no language can be negotiated,
+//! the wildcard and default variants are still looked up, and a warning is logged.
+//!
+//! Responders can't wait for an async custom resolver, so with `Config::custom_async`,
+//! handlers returning a `LangTemplate` or `LocalizedTemplate` have to take a `LangCode`
+//! guard, unless the `Config` is eager. Otherwise they fail with a `500 Internal Server Error`.
+//! ```rust
+//! # use rocket::get;
+//! # use rocket_dyn_templates::context;
+//! use rocket_lang::templates::LocalizedTemplate;
+//!
+//! #[get("/home")]
+//! fn home() -> LocalizedTemplate {
+//!     LocalizedTemplate::render("home", context! { user: "Ana" })
+//! }
+//! ```
+use crate::{alternates::Alternates, rocket_impl::PrivConfig, *};
+use rocket::{
+    fairing::Fairing,
+    futures::FutureExt,
+    http::{Header, Status},
+    response::{self, Responder},
+    serde::Serialize,
+};
+use rocket_dyn_templates::{
+    handlebars::{
+        Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderError,
+        ScopedJson,
+    },
+    tera::{self, Value},
+    Engines, Metadata, Template,
+};
+use std::{cell::RefCell, collections::HashMap, sync::Arc};
+
+/// A collection of translated strings, indexed by language and key.
+#[derive(Debug, Clone, Default)]
+pub struct Translations(HashMap<LangCode, HashMap<String, String>>);
+
+impl Translations {
+    /// Constructs an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Takes the collection by value and returns it with a translation added.
+    pub fn with(
+        mut self,
+        lang: LangCode,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.insert(lang, key, value);
+        self
+    }
+    /// Adds a translation.
+    pub fn insert(&mut self, lang: LangCode, key: impl Into<String>, value: impl Into<String>) {
+        self.0
+            .entry(lang)
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+    /// Adds every translation for a language.
+    pub fn extend<K, V>(&mut self, lang: LangCode, entries: impl IntoIterator<Item = (K, V)>)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let table = self
+            .0
+            .entry(lang)
+            .or_default();
+        for (key, value) in entries {
+            table.insert(key.into(), value.into());
+        }
+    }
+    /// Returns the translation for a key.
+    pub fn get(&self, lang: LangCode, key: &str) -> Option<&str> {
+        self.0
+            .get(&lang)?
+            .get(key)
+            .map(String::as_str)
+    }
+}
+
+/// Returns a template fairing with the helpers registered.
+/// It can be attached instead of `Template::fairing()`.
+pub fn fairing(translations: Translations) -> impl Fairing {
+    let helpers = Helpers(Arc::new(translations));
+    Template::custom(move |engines| helpers.register(engines))
+}
+
+/// Registers the helpers in the template engines. This can be used
+/// from within `Template::custom`, when other customizations are needed.
+pub fn register(engines: &mut Engines, translations: Translations) {
+    Helpers(Arc::new(translations)).register(engines)
+}
+
+/// A template responder that makes the negotiated language
+/// of the request available to the helpers while it is rendered.
+pub struct LangTemplate(pub Template);
+
+impl From<Template> for LangTemplate {
+    fn from(template: Template) -> Self {
+        Self(template)
+    }
+}
+
+impl<'r> Responder<'r, 'static> for LangTemplate {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let lang = negotiated(req).map_err(|err| err.status())?;
+        let _current = Current::set(lang, Alternates::for_request(req));
+        self.0.respond_to(req)
+    }
+}
+
+/// Returns the language negotiated for a request, from a responder. Responders can't
+/// wait for an async custom resolver, so unless the `Config` is eager, the handler
+/// has to take a `LangCode` guard for the language to be resolved before.
+fn negotiated(req: &Request) -> Result<LangCode, Error> {
+    let lang = LangCode::try_from(req);
+    if let Err(Error::Pending) = lang {
+        rocket::error_!(
+            "The language of '{}' depends on an async resolver that hasn't completed. \
+             Its handler has to take a `LangCode` guard, or the `Config` has to be eager.",
+            req.uri()
+        );
+    }
+    lang
+}
+
+/// A template responder that renders the best
+/// variant available for the negotiated language.
+pub struct LocalizedTemplate {
+    name: String,
+    context: Result<Value, String>,
+}
+
+impl LocalizedTemplate {
+    /// Constructs a localized template from the name
+    /// of the template without language, and its context.
+    /// If the context can't be serialized, responding fails
+    /// with a `500 Internal Server Error`.
+    pub fn render(name: impl Into<String>, context: impl Serialize) -> Self {
+        Self {
+            name: name.into(),
+            context: tera::to_value(context).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Returns the template variants that will be looked up
+    /// for the request, and their language, in order of preference.
+    /// If no language can be negotiated, only the wildcard and default variants
+    /// are looked up. It fails with `Error::Pending` if the language depends on
+    /// an async resolver, and the handler didn't take a `LangCode` guard.
+    pub fn variants(&self, req: &Request) -> Result<Vec<(String, Option<String>)>, Error> {
+        let lang = match LangCode::try_from(req) {
+            Ok(lang) => Some(lang),
+            Err(Error::Pending) => return Err(Error::Pending),
+            Err(_) => None,
+        };
+        let config = req
+            .rocket()
+            .state::<PrivConfig>()
+            .map(|config| config.0.for_request(req));
+        let wildcard = config.and_then(|config| config.wildcard);
+        let mut tags = vec![];
+        if let Some(lang) = lang {
+            if let Some(region) = accept_language::locale(req, config, lang).region() {
+                tags.push(format!("{lang}-{region}"));
+            }
+            tags.push(lang.to_string());
+        }
+        if let Some(wildcard) = wildcard.filter(|&w| Some(w) != lang) {
+            tags.push(wildcard.to_string());
+        }
+        let mut variants: Vec<_> = tags
+            .into_iter()
+            .map(|tag| (format!("{}.{tag}", self.name), Some(tag)))
+            .collect();
+        variants.push((self.name.clone(), None));
+        Ok(variants)
+    }
+
+    /// Returns the name of the variant that will be rendered
+    /// for the request, and its language.
+    pub fn resolve(&self, req: &Request) -> Result<Option<(String, Option<String>)>, Error> {
+        let variants = self.variants(req)?;
+        let Some(metadata) = req
+            .guard::<Metadata>()
+            .now_or_never()
+            .and_then(|outcome| outcome.succeeded())
+        else {
+            return Ok(None);
+        };
+        Ok(variants
+            .into_iter()
+            .find(|(name, _)| metadata.contains_template(name)))
+    }
+}
+
+impl<'r> Responder<'r, 'static> for LocalizedTemplate {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let lang = match negotiated(req) {
+            Ok(lang) => Some(lang),
+            Err(Error::Pending) => return Err(Error::Pending.status()),
+            Err(err) => {
+                rocket::warn_!(
+                    "No language was negotiated for '{}': {} \
+                     Template '{}' falls back to its default variants.",
+                    req.uri(),
+                    err,
+                    self.name
+                );
+                None
+            }
+        };
+        // if no variant exists, rendering the plain name reports the missing template.
+        let (name, tag) = self
+            .resolve(req)
+            .map_err(|err| err.status())?
+            .unwrap_or_else(|| (self.name.clone(), None));
+        let context = self.context.map_err(|e| {
+            rocket::error_!(
+                "The context of template '{}' can't be serialized: {}",
+                name,
+                e
+            );
+            Status::InternalServerError
+        })?;
+        // the helpers see the language of the variant, or else the negotiated one.
+        let lang = tag
+            .as_deref()
+            .and_then(|tag| tag.split('-').next())
+            .and_then(|code| code.parse().ok())
+            .or(lang);
+        let mut response = {
+            let _current = lang.map(|lang| Current::set(lang, Alternates::for_request(req)));
+            Template::render(name, context).respond_to(req)?
+        };
+        if let Some(tag) = tag {
+            response.set_header(Header::new("Content-Language", tag));
+        }
+        Ok(response)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<(LangCode, Alternates)>> = const { RefCell::new(None) };
+}
+
+/// Holds the language of the template being rendered
+/// in this thread, until it is dropped.
+pub(crate) struct Current;
+
+impl Current {
+    pub(crate) fn set(lang: LangCode, alternates: Alternates) -> Current {
+        CURRENT.with(|current| *current.borrow_mut() = Some((lang, alternates)));
+        Current
+    }
+    /// Returns the language of the template, or an error if it is not
+    /// rendered by a `LangTemplate` or `LocalizedTemplate`.
+    fn lang() -> Result<LangCode, String> {
+        CURRENT.with(|current| {
+            current
+                .borrow()
+                .as_ref()
+                .map(|(lang, _)| *lang)
+                .ok_or_else(Current::missing)
+        })
+    }
+    fn alternate_url(lang: &str) -> Result<Option<String>, String> {
+        CURRENT.with(|current| {
+            let current = current.borrow();
+            let (_, alternates) = current
+                .as_ref()
+                .ok_or_else(Current::missing)?;
+            let url = alternates
+                .iter()
+                .find(|alt| alt.hreflang == lang)
+                .map(|alt| alt.href.clone());
+            Ok(url)
+        })
+    }
+    fn missing() -> String {
+        "no language was negotiated for the template, it has to be returned \
+         in a `LangTemplate` or `LocalizedTemplate`."
+            .to_string()
+    }
+}
+
+impl Drop for Current {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = None);
+    }
+}
+
+#[derive(Clone)]
+struct Helpers(Arc<Translations>);
+
+impl Helpers {
+    fn register(&self, engines: &mut Engines) {
+        for (name, helper) in self.all() {
+            // translations are escaped, everything else is known to be safe.
+            let safe = name != "t";
+            engines
+                .tera
+                .register_function(name, TeraHelper(helper.clone(), safe));
+            engines
+                .handlebars
+                .register_helper(name, Box::new(HandlebarsHelper(helper, safe)));
+        }
+    }
+
+    fn all(&self) -> Vec<(&'static str, Arc<HelperFn>)> {
+        let translations = self.0.clone();
+        vec![
+            (
+                "lang",
+                Arc::new(|_: &Args| Ok(Current::lang()?.to_string())),
+            ),
+            (
+                "lang_dir",
+                Arc::new(|args: &Args| {
+                    let lang = args.lang("code")?;
+                    Ok(lang.dir().to_string())
+                }),
+            ),
+            (
+                "t",
+                Arc::new(move |args: &Args| {
+                    let key = args.required("key")?;
+                    let lang = args.lang("lang")?;
+                    let text = translations
+                        .get(lang, key)
+                        .unwrap_or(key);
+                    Ok(text.to_string())
+                }),
+            ),
+            (
+                "language_name",
+                Arc::new(|args: &Args| {
+                    let lang = args.lang("code")?;
+                    Ok(lang.native_name().to_string())
+                }),
+            ),
+            (
+                "format_number",
+                Arc::new(|args: &Args| {
+                    let value = args.number("value")?;
+                    let lang = args.lang("lang")?;
+                    Ok(numbers::format_number(lang, value))
+                }),
+            ),
+            (
+                "format_percent",
+                Arc::new(|args: &Args| {
+                    let value = args.number("value")?;
+                    let lang = args.lang("lang")?;
+                    Ok(numbers::format_percent(lang, value))
+                }),
+            ),
+            (
+                "alternate_url",
+                Arc::new(|args: &Args| {
+                    let lang = args.required("code")?;
+                    let url = Current::alternate_url(lang)?.unwrap_or_default();
+                    Ok(sitemap::escape(&url))
+                }),
+            ),
+        ]
+    }
+}
+
+type HelperFn = dyn Fn(&Args) -> Result<String, String> + Send + Sync;
+
+/// The arguments of a helper. Tera arguments are always named, while
+/// Handlebars takes the first named argument as a positional parameter.
+enum Args<'a, 'reg, 'rc> {
+    Tera(&'a HashMap<String, Value>),
+    Handlebars(&'a Helper<'reg, 'rc>),
+}
+
+impl Args<'_, '_, '_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        match self {
+            Args::Tera(args) => args.get(name)?.as_str(),
+            Args::Handlebars(helper) => match name {
+                "lang" => helper
+                    .hash_get(name)?
+                    .value()
+                    .as_str(),
+                _ => helper
+                    .param(0)?
+                    .value()
+                    .as_str(),
+            },
+        }
+    }
+    fn number(&self, name: &str) -> Result<f64, String> {
+        let value = match self {
+            Args::Tera(args) => args.get(name),
+            Args::Handlebars(helper) => helper
+                .param(0)
+                .map(|param| param.value()),
+        };
+        value
+            .and_then(Value::as_f64)
+            .ok_or_else(|| format!("missing `{name}` number argument."))
+    }
+    fn required(&self, name: &str) -> Result<&str, String> {
+        self.get(name)
+            .ok_or_else(|| format!("missing `{name}` string argument."))
+    }
+    /// Parses the language code argument,
+    /// defaulting to the language of the request.
+    fn lang(&self, name: &str) -> Result<LangCode, String> {
+        match self.get(name) {
+            Some(code) => code
+                .parse()
+                .map_err(|_| format!("`{code}` is not a language code.")),
+            None => Current::lang(),
+        }
+    }
+}
+
+struct TeraHelper(Arc<HelperFn>, bool);
+
+impl tera::Function for TeraHelper {
+    fn call(&self, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        (self.0)(&Args::Tera(args))
+            .map(Value::String)
+            .map_err(tera::Error::msg)
+    }
+    fn is_safe(&self) -> bool {
+        self.1
+    }
+}
+
+struct HandlebarsHelper(Arc<HelperFn>, bool);
+
+impl HelperDef for HandlebarsHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        helper: &Helper<'reg, 'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let text = (self.0)(&Args::Handlebars(helper)).map_err(RenderError::new)?;
+        Ok(ScopedJson::Derived(Value::String(text)))
+    }
+
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        helper: &Helper<'reg, 'rc>,
+        registry: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let text = (self.0)(&Args::Handlebars(helper)).map_err(RenderError::new)?;
+        if self.1 {
+            out.write(&text)?;
+        } else {
+            out.write(&registry.get_escape_fn()(&text))?;
+        }
+        Ok(())
+    }
+}
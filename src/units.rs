@@ -0,0 +1,61 @@
+//! Locale-aware formatting of measurement units, with data from the Unicode CLDR.
+//!
+//! A value is formatted with the number format of a language, and the name or symbol
+//! of the unit in one of three widths. Long names agree with the value:
+//! ```rust
+//! # use rocket_lang::{*, units::{format_unit, Unit, UnitWidth}};
+//! assert_eq!(format_unit(En, 1.0, Unit::Kilometer, UnitWidth::Long), "1 kilometer");
+//! assert_eq!(format_unit(En, 2.5, Unit::Kilometer, UnitWidth::Long), "2.5 kilometers");
+//! assert_eq!(format_unit(Ru, 5.0, Unit::Kilometer, UnitWidth::Long), "5 километров");
+//! assert_eq!(format_unit(De, 1234.5, Unit::Kilogram, UnitWidth::Short), "1.234,5 kg");
+//! assert_eq!(format_unit(En, 20.0, Unit::Kilogram, UnitWidth::Narrow), "20kg");
+//! ```
+//! The data is generated from the Unicode CLDR by `tools/cldr`, and covers the
+//! length, mass and volume units that ICU4X has names for. Languages without names
+//! of their own use the symbols of the root locale, as in `2 g`.
+use crate::{plurals::Forms, *};
+
+#[rustfmt::skip]
+mod data;
+
+/// The long, short and narrow forms of every unit, in the order of the `Unit` variants.
+type Names = [[Forms; 3]; 14];
+
+/// A measurement unit.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Millimeter,
+    Centimeter,
+    Meter,
+    Kilometer,
+    Inch,
+    Foot,
+    Mile,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    Milliliter,
+    Liter,
+    /// The US gallon.
+    Gallon,
+}
+
+/// The width of a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitWidth {
+    /// `5 kilometers`
+    Long,
+    /// `5 km`
+    Short,
+    /// `5km`
+    Narrow,
+}
+
+/// Formats a value of a unit for a language.
+pub fn format_unit(lang: LangCode, value: f64, unit: Unit, width: UnitWidth) -> String {
+    let names = data::LANGUAGES[lang as usize].unwrap_or(&data::ROOT);
+    let forms = &names[unit as usize][width as usize];
+    plurals::select(forms, lang, value).replace("{0}", &numbers::format_number(lang, value))
+}
@@ -0,0 +1,2080 @@
+// @generated by tools/cldr from CLDR 48.2.1. Do not edit.
+use super::Names;
+
+/// The names of the root locale, used by the languages without names of their own.
+pub(super) static ROOT: Names = [
+    [["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// af
+static N0: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} sentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} duim"], ["", "", "", "", "", "{0} duim"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} voet"], ["", "", "", "", "", "{0} vt."], ["", "", "", "", "", "{0} vt."]],
+    [["", "", "", "", "", "{0} myl"], ["", "", "", "", "", "{0} myl"], ["", "", "", "", "", "{0} myl"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} ons", "", "", "", "{0} onse"], ["", "", "", "", "", "{0} ons."], ["", "", "", "", "", "{0}ons."]],
+    [["", "", "", "", "", "{0} pond"], ["", "", "", "", "", "{0} lb."], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gelling"], ["", "", "", "", "", "{0} gell."], ["", "", "", "", "", "{0}gell."]],
+];
+
+// ak
+static N1: Names = [
+    [["", "milimita {0}", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "sɛntimita {0}"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "mita {0}"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "kilomita {0}"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "inkyisi {0}"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "ananmɔn {0}", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"], ["", "{0}′", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "kwansini {0}"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "grame {0}", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "kilograme {0}"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "awnse {0}"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "pɔns {0}"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} l", "", "", "", "{0} lita"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// sq
+static N2: Names = [
+    [["", "{0} milimetër", "", "", "", "{0} milimetra"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetër", "", "", "", "{0} centimetra"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metër", "", "", "", "{0} metra"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometër", "", "", "", "{0} kilometra"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inç"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} këmbë"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} milje"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "", "", "{0} gramë"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "", "", "{0} kilogramë"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} ons", "", "", "", "{0} onsë"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} paund"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitër", "", "", "", "{0} mililitra"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} litër", "", "", "", "{0} litra"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "{0} gallon", "", "", "", "{0} gallonë"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// am
+static N3: Names = [
+    [["", "", "", "", "", "{0} ሚሊሜትር"], ["", "", "", "", "", "{0} ሚሜ"], ["", "", "", "", "", "{0} ሚሜ"]],
+    [["", "", "", "", "", "{0} ሴንቲሜትር"], ["", "", "", "", "", "{0} ሴሜ"], ["", "", "", "", "", "{0} ሴሜ"]],
+    [["", "", "", "", "", "{0} ሜትር"], ["", "", "", "", "", "{0} ሜ"], ["", "", "", "", "", "{0} ሜ"]],
+    [["", "", "", "", "", "{0} ኪሎሜትር"], ["", "", "", "", "", "{0} ኪሜ"], ["", "", "", "", "", "{0} ኪሜ"]],
+    [["", "", "", "", "", "{0} ኢንች"], ["", "", "", "", "", "{0} ኢንች"], ["", "", "", "", "", "{0} ኢንች"]],
+    [["", "", "", "", "", "{0} ጫማ"], ["", "", "", "", "", "{0} ጫማ"], ["", "", "", "", "", "{0} ጫማ"]],
+    [["", "", "", "", "", "{0} ማይል"], ["", "", "", "", "", "{0} ማይል"], ["", "", "", "", "", "{0} ማይል"]],
+    [["", "", "", "", "", "{0} ግራም"], ["", "", "", "", "", "{0} ግ"], ["", "", "", "", "", "{0} ግ"]],
+    [["", "", "", "", "", "{0} ኪሎግራም"], ["", "", "", "", "", "{0} ኪግ"], ["", "", "", "", "", "{0} ኪግ"]],
+    [["", "", "", "", "", "{0} አውንስ"], ["", "", "", "", "", "{0} አውንስ"], ["", "", "", "", "", "{0} አውንስ"]],
+    [["", "", "", "", "", "{0} ፓውንድ"], ["", "", "", "", "", "{0} ፓውንድ"], ["", "", "", "", "", "{0} ፓውንድ"]],
+    [["", "", "", "", "", "{0} ሚሊ ሊትር"], ["", "", "", "", "", "{0} ሚሊ ሊትር"], ["", "", "", "", "", "{0} ሚሊ"]],
+    [["", "", "", "", "", "{0} ሊትር"], ["", "", "", "", "", "{0} ሊ"], ["", "", "", "", "", "{0} ሊ"]],
+    [["", "", "", "", "", "{0}/ጋሎን"], ["", "{0}/ጋሎን", "", "", "", "{0} ጋሎን"], ["", "", "", "", "", "{0} ጋሎን"]],
+];
+
+// ar
+static N4: Names = [
+    [["", "", "", "", "", "{0} مليمتر"], ["", "", "", "", "", "{0} مم"], ["", "", "", "", "", "{0} مم"]],
+    [["", "", "", "", "", "{0} سنتيمتر"], ["", "", "", "", "", "{0} سم"], ["", "", "", "", "", "{0} سم"]],
+    [["", "متر", "", "{0} أمتار", "{0} مترًا", "{0} متر"], ["", "متر", "متران", "{0} أمتار", "{0} مترًا", "{0} متر"], ["", "", "", "", "", "{0} م"]],
+    [["", "", "", "{0} كيلومترات", "{0} كيلومترًا", "{0} كيلومتر"], ["", "", "", "", "", "{0} كم"], ["", "", "", "", "", "{0} كم"]],
+    [["", "", "", "", "", "{0} بوصة"], ["", "", "", "", "", "{0} بوصة"], ["", "", "", "", "", "{0} بوصة"]],
+    [["", "قدم", "", "", "", "{0} قدم"], ["", "قدم", "", "", "", "{0} قدم"], ["", "قدم", "", "", "{0} قدمًا", "{0} قدم"]],
+    [["", "ميل", "ميلان", "{0} أميال", "{0} ميلاً", "{0} ميل"], ["", "ميل", "", "", "", "{0} ميل"], ["", "", "", "{0} أميال", "{0} ميلاً", "{0} ميل"]],
+    [["", "غرام", "غرامان", "{0} غرامات", "{0} غرامًا", "{0} غرام"], ["", "غرام", "", "", "", "{0} غرام"], ["", "", "", "", "", "{0} غ"]],
+    [["", "", "", "", "", "{0} كيلوغرام"], ["", "", "", "", "", "{0} كغم"], ["", "", "", "", "", "{0} كغ"]],
+    [["", "", "", "", "", "{0} أونصة"], ["", "أونصة", "", "", "", "{0} أونصة"], ["", "أونصة", "", "", "", "{0} أونصة"]],
+    [["", "", "رطلان", "", "{0} رطلًا", "{0} رطل"], ["", "", "", "", "", "{0} رطل"], ["", "", "", "", "", "{0} رطل"]],
+    [["", "", "", "", "", "{0} مليلتر"], ["", "", "", "", "", "{0} ملتر"], ["", "", "", "", "", "{0} ملتر"]],
+    [["", "لتر", "", "", "", "{0} لتر"], ["", "لتر", "", "", "", "{0} لتر"], ["", "", "", "", "", "{0} ل"]],
+    [["", "غالون", "", "", "", "{0} غالون"], ["", "غالون", "", "", "", "{0} غالون"], ["", "غالون", "", "", "", "{0} غالون"]],
+];
+
+// hy
+static N5: Names = [
+    [["", "", "", "", "", "{0} միլիմետր"], ["", "", "", "", "", "{0} մմ"], ["", "", "", "", "", "{0} մմ"]],
+    [["", "", "", "", "", "{0} սանտիմետր"], ["", "", "", "", "", "{0} սմ"], ["", "", "", "", "", "{0} սմ"]],
+    [["", "", "", "", "", "{0} մետր"], ["", "", "", "", "", "{0} մ"], ["", "", "", "", "", "{0} մ"]],
+    [["", "", "", "", "", "{0} կիլոմետր"], ["", "", "", "", "", "{0} կմ"], ["", "", "", "", "", "{0} կմ"]],
+    [["", "", "", "", "", "{0} մատնաչափ"], ["", "", "", "", "", "{0} մատ"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} ֆուտ"], ["", "", "", "", "", "{0} ֆտ"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} մղոն"], ["", "", "", "", "", "{0} մղ"], ["", "", "", "", "", "{0} մղ"]],
+    [["", "", "", "", "", "{0} գրամ"], ["", "", "", "", "", "{0} գ"], ["", "", "", "", "", "{0} գ"]],
+    [["", "", "", "", "", "{0} կիլոգրամ"], ["", "", "", "", "", "{0} կգ"], ["", "", "", "", "", "{0} կգ"]],
+    [["", "", "", "", "", "{0} ունկի"], ["", "", "", "", "", "{0} ու"], ["", "", "", "", "", "{0}ու"]],
+    [["", "", "", "", "", "{0} ֆունտ"], ["", "", "", "", "", "{0} ֆունտ"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} միլիլիտր"], ["", "", "", "", "", "{0} մլ"], ["", "", "", "", "", "{0} մլ"]],
+    [["", "", "", "", "", "{0} լիտր"], ["", "", "", "", "", "{0} լ"], ["", "", "", "", "", "{0} լ"]],
+    [["", "", "", "", "", "{0} գալոն"], ["", "", "", "", "", "{0} գալ"], ["", "", "", "", "", "{0}գալ"]],
+];
+
+// as
+static N6: Names = [
+    [["", "", "", "", "", "{0} মিলিমিটাৰ"], ["", "", "", "", "", "{0} মিঃ মিঃ"], ["", "", "", "", "", "{0} মিঃ মিঃ"]],
+    [["", "", "", "", "", "{0} ছেণ্টিমিটাৰ"], ["", "", "", "", "", "{0} ছেঃ মিঃ"], ["", "", "", "", "", "{0} ছেঃ মিঃ"]],
+    [["", "", "", "", "", "{0} মিটাৰ"], ["", "", "", "", "", "{0} মিঃ"], ["", "", "", "", "", "{0} মিঃ"]],
+    [["", "", "", "", "", "{0} কিলোমিটাৰ"], ["", "", "", "", "", "{0} কিঃ মিঃ"], ["", "", "", "", "", "{0} কিঃ মিঃ"]],
+    [["", "", "", "", "", "{0} ইঞ্চি"], ["", "", "", "", "", "{0} ইঃ"], ["", "", "", "", "", "{0} ইঃ"]],
+    [["", "", "", "", "", "{0} ফুট"], ["", "", "", "", "", "{0} ফুঃ"], ["", "", "", "", "", "{0} ফুঃ"]],
+    [["", "", "", "", "", "{0} মাইল"], ["", "", "", "", "", "{0} মাঃ"], ["", "", "", "", "", "{0} মাঃ"]],
+    [["", "", "", "", "", "{0} গ্ৰাম"], ["", "", "", "", "", "{0} গ্ৰাঃ"], ["", "", "", "", "", "{0} গ্ৰাঃ"]],
+    [["", "", "", "", "", "{0} কিলোগ্ৰাম"], ["", "", "", "", "", "{0} কিঃ গ্ৰাঃ"], ["", "", "", "", "", "{0} কিঃ গ্ৰাঃ"]],
+    [["", "", "", "", "", "{0} আউন্স"], ["", "", "", "", "", "{0} আউন্স"], ["", "", "", "", "", "{0} আউন্স"]],
+    [["", "", "", "", "", "{0} পাউণ্ড"], ["", "", "", "", "", "{0} পাউণ্ড"], ["", "", "", "", "", "{0} পাউণ্ড"]],
+    [["", "", "", "", "", "{0} মিলিলিটাৰ"], ["", "", "", "", "", "{0} মিঃ লিঃ"], ["", "", "", "", "", "{0} মিঃ লিঃ"]],
+    [["", "", "", "", "", "{0} লিটাৰ"], ["", "", "", "", "", "{0} লিঃ"], ["", "", "", "", "", "{0} লিঃ"]],
+    [["", "", "", "", "", "{0} গেলন"], ["", "", "", "", "", "{0} গেলন"], ["", "", "", "", "", "{0} গেলন"]],
+];
+
+// az
+static N7: Names = [
+    [["", "", "", "", "", "{0} millimetr"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} santimetr"], ["", "", "", "", "", "{0} sm"], ["", "", "", "", "", "{0} sm"]],
+    [["", "", "", "", "", "{0} metr"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometr"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} düym"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} fut"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"]],
+    [["", "", "", "", "", "{0} qram"], ["", "", "", "", "", "{0} q"], ["", "", "", "", "", "{0} q"]],
+    [["", "", "", "", "", "{0} kiloqram"], ["", "", "", "", "", "{0} kq"], ["", "", "", "", "", "{0} kq"]],
+    [["", "", "", "", "", "{0} unsiya"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} funt"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} millilitr"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} litr"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} qallon"], ["", "", "", "", "", "{0} qal"], ["", "", "", "", "", "{0} qal"]],
+];
+
+// ba
+static N8: Names = [
+    [["", "", "", "", "", "{0} миллиметр"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "", "", "", "", "{0} сантиметр"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "", "", "", "", "{0} метр"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["", "", "", "", "", "{0} километр"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"]],
+    [["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"]],
+    [["", "", "", "", "", "{0} миль"], ["", "", "", "", "", "{0} миль"], ["", "", "", "", "", "{0} миля"]],
+    [["", "", "", "", "", "{0} грамм"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0} г"]],
+    [["", "", "", "", "", "{0} килограмм"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "", "", "", "", "{0} унция"], ["", "", "", "", "", "{0} унция"], ["", "", "", "", "", "{0} унция"]],
+    [["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фнт"], ["", "", "", "", "", "{0} фнт"]],
+    [["", "", "", "", "", "{0} миллилитр"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "", "", "", "", "{0} литр"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "", "", "", "", "{0} галлон"], ["", "", "", "", "", "{0} галлон"], ["", "", "", "", "", "{0} галлон"]],
+];
+
+// eu
+static N9: Names = [
+    [["", "", "", "", "", "{0} milimetro"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} zentimetro"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} metro"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometro"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} hazbete"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} oin"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} milia"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gramo"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogramo"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} ontza"], ["", "", "", "", "", "{0} ontza"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} libra"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mililitro"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} litro"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} galoi"], ["", "", "", "", "", "{0} galoi"], ["", "", "", "", "", "{0} galoi"]],
+];
+
+// be
+static N10: Names = [
+    [["", "{0} міліметр", "", "{0} міліметры", "{0} міліметраў", "{0} міліметра"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "{0} сантыметр", "", "{0} сантыметры", "{0} сантыметраў", "{0} сантыметра"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "{0} метр", "", "{0} метры", "{0} метраў", "{0} метра"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["", "{0} кіламетр", "", "{0} кіламетры", "{0} кіламетраў", "{0} кіламетра"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "{0} цаля", "", "", "{0} цаляў", "{0} цалі"], ["", "", "", "", "", "{0} цал"], ["", "", "", "", "", "{0} цал"]],
+    [["", "{0} фут", "", "{0} футы", "{0} футаў", "{0} фута"], ["", "", "", "", "", "{0} фт"], ["", "", "", "", "", "{0} фт"]],
+    [["", "{0} міля", "", "", "{0} міль", "{0} мілі"], ["", "{0} міля", "", "", "{0} міль", "{0} мілі"], ["", "{0} міля", "", "", "{0} міль", "{0} мілі"]],
+    [["", "{0} грам", "", "{0} грамы", "{0} грамаў", "{0} грама"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0} г"]],
+    [["", "{0} кілаграм", "", "{0} кілаграмы", "{0} кілаграмаў", "{0} кілаграма"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "{0} унцыя", "", "", "{0} унцый", "{0} унцыі"], ["", "", "", "", "", "{0} унц."], ["", "", "", "", "", "{0} унц."]],
+    [["", "{0} фунт", "", "{0} фунты", "{0} фунтаў", "{0} фунта"], ["", "{0} фунт", "", "{0} фунты", "{0} фунтаў", "{0} фунта"], ["", "{0} фунт", "", "{0} фунты", "{0} фунтаў", "{0} фунта"]],
+    [["", "{0} мілілітр", "", "{0} мілілітры", "{0} мілілітраў", "{0} мілілітра"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "{0} літр", "", "{0} літры", "{0} літраў", "{0} літра"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "{0} галон", "", "{0} галоны", "{0} галонаў", "{0} галона"], ["", "", "", "", "", "{0} гал"], ["", "", "", "", "", "{0} гал"]],
+];
+
+// bn
+static N11: Names = [
+    [["", "", "", "", "", "{0} মিলিমিটার"], ["", "", "", "", "", "{0} মিমি"], ["", "", "", "", "", "{0} মিমি"]],
+    [["", "", "", "", "", "{0} সেন্টিমিটার"], ["", "", "", "", "", "{0} সেমি"], ["", "", "", "", "", "{0} সেমি"]],
+    [["", "", "", "", "", "{0} মিটার"], ["", "", "", "", "", "{0} মি"], ["", "", "", "", "", "{0} মি"]],
+    [["", "", "", "", "", "{0} কিলোমিটার"], ["", "", "", "", "", "{0} কিমি"], ["", "", "", "", "", "{0} কিমি"]],
+    [["", "", "", "", "", "{0} ইঞ্চি"], ["", "", "", "", "", "{0} ইঞ্চি"], ["", "", "", "", "", "{0} ইঞ্চি"]],
+    [["", "", "", "", "", "{0} ফুট"], ["", "", "", "", "", "{0} ফুট"], ["", "", "", "", "", "{0} ফুট"]],
+    [["", "", "", "", "", "{0} মাইল"], ["", "", "", "", "", "{0} মাইল"], ["", "", "", "", "", "{0} মাইল"]],
+    [["", "", "", "", "", "{0} গ্রাম"], ["", "{0}গ্রাম", "", "", "", "{0} গ্রাম"], ["", "", "", "", "", "{0} গ্রাম"]],
+    [["", "", "", "", "", "{0} কিলোগ্রাম"], ["", "", "", "", "", "{0} কেজি"], ["", "", "", "", "", "{0} কেজি"]],
+    [["", "", "", "", "", "{0} আউন্স"], ["", "", "", "", "", "{0} আউন্স"], ["", "", "", "", "", "{0} আউন্স"]],
+    [["", "", "", "", "", "{0} পাউন্ড"], ["", "{0} পাউন্ড", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} পাউন্ড"]],
+    [["", "", "", "", "", "{0} মিলিলিটার"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} লিটার"], ["", "", "", "", "", "{0} লিটার"], ["", "", "", "", "", "{0} লিটার"]],
+    [["", "", "", "", "", "{0} গ্যালন"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// bs
+static N12: Names = [
+    [["", "{0} milimetar", "", "{0} milimetra", "", "{0} milimetara"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetar", "", "{0} centimetra", "", "{0} centimetara"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metar", "", "{0} metra", "", "{0} metara"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometar", "", "{0} kilometra", "", "{0} kilometara"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} inč", "", "", "", "{0} inča"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "{0} stope", "", "{0} stopa"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "{0} milje", "", "{0} milja"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "", "", "{0} grama"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "", "", "{0} kilograma"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unca", "", "{0} unce", "", "{0} unci"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} funta", "", "{0} funte", "", "{0} funti"], ["", "{0} lb", "", "{0} lb", "", "{0} lbs"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitar", "", "{0} mililitra", "", "{0} mililitara"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litar", "", "{0} litra", "", "{0} litara"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} galon", "", "", "", "{0} galona"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// br
+static N13: Names = [
+    [["", "", "{0} vilimetr", "", "{0} a vilimetroù", "{0} milimetr"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "{0} a santimetroù", "{0} santimetr"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "{0} vetr", "", "{0} a vetroù", "{0} metr"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} c'hilometr", "{0} gilometr", "{0} c'hilometr", "{0} a gilometroù", "{0} kilometr"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "{0} veutad", "", "{0} a veutadoù", "{0} meutad"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "{0} droatad", "{0} zroatad", "{0} a droatadoù", "{0} troatad"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "{0} viltir", "", "{0} a viltirioù", "{0} miltir"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "{0} c'hramm", "", "{0} a c'hrammoù", "{0} gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} c'hilogramm", "{0} gilogramm", "{0} c'hilogramm", "{0} a gilogrammoù", "{0} kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "{0} a oñsoù", "{0} oñs"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "{0} a lurioù", "{0} lur"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "{0} vililitr", "", "{0} a vililitroù", "{0} mililitr"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "", "", "", "{0} a litroù", "{0} litr"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "{0} c'hallon", "", "{0} a c'hallonoù", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// bg
+static N14: Names = [
+    [["", "{0} милиметър", "", "", "", "{0} милиметра"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} сантиметър", "", "", "", "{0} сантиметра"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} метър", "", "", "", "{0} метра"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} километър", "", "", "", "{0} километра"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} инч", "", "", "", "{0} инча"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}\""]],
+    [["", "{0} фут", "", "", "", "{0} фута"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} миля", "", "", "", "{0} мили"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} грам", "", "", "", "{0} грама"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} килограм", "", "", "", "{0} килограма"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} унция", "", "", "", "{0} унции"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} фунт", "", "", "", "{0} фунта"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} милилитър", "", "", "", "{0} милилитра"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} литър", "", "", "", "{0} литра"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} галон", "", "", "", "{0} галона"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// my
+static N15: Names = [
+    [["", "", "", "", "", "{0} မီလီမီတာ"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} စင်တီမီတာ"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} မီတာ"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} ကီလိုမီတာ"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} လက်မ"], ["", "", "", "", "", "{0} လက်မ"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} ပေ"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} မိုင်"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} ဂရမ်"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} ကီလိုဂရမ်"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} အောင်စ"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} ပေါင်"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} မီလီလီတာ"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} လီတာ"], ["", "", "", "", "", "{0} လီတာ"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} ဂါလံ"], ["", "", "", "", "", "{0} ဂါလံ"], ["", "", "", "", "", "{0} ဂါလံ"]],
+];
+
+// ca
+static N16: Names = [
+    [["", "{0} mil·límetre", "", "", "", "{0} mil·límetres"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centímetre", "", "", "", "{0} centímetres"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metre", "", "", "", "{0} metres"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} quilòmetre", "", "", "", "{0} quilòmetres"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} polzada", "", "", "", "{0} polzades"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} peu", "", "", "", "{0} peus"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} milla", "", "", "", "{0} milles"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "", "", "{0} grams"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} quilogram", "", "", "", "{0} quilograms"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unça", "", "", "", "{0} unces"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} lliura", "", "", "", "{0} lliures"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mil·lilitre", "", "", "", "{0} mil·lilitres"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litre", "", "", "", "{0} litres"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galó", "", "", "", "{0} galons"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// zh
+static N17: Names = [
+    [["", "", "", "", "", "{0}毫米"], ["", "", "", "", "", "{0}毫米"], ["", "", "", "", "", "{0}毫米"]],
+    [["", "", "", "", "", "{0}厘米"], ["", "", "", "", "", "{0}厘米"], ["", "", "", "", "", "{0}厘米"]],
+    [["", "", "", "", "", "{0}米"], ["", "", "", "", "", "{0}米"], ["", "", "", "", "", "{0}米"]],
+    [["", "", "", "", "", "{0}公里"], ["", "", "", "", "", "{0}公里"], ["", "", "", "", "", "{0}公里"]],
+    [["", "", "", "", "", "{0}英寸"], ["", "", "", "", "", "{0}英寸"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0}英尺"], ["", "", "", "", "", "{0}英尺"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0}英里"], ["", "", "", "", "", "{0}英里"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0}克"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0}千克"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0}盎司"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0}磅"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0}毫升"], ["", "", "", "", "", "{0}毫升"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "", "", "{0}升"], ["", "", "", "", "", "{0}升"], ["", "", "", "", "", "{0}升"]],
+    [["", "", "", "", "", "{0}加仑"], ["", "", "", "", "", "{0}加仑"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// cv
+static N18: Names = [
+    [["{0} мм", "{0} мм", "", "", "", "{0} миллиметр"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["{0} см", "{0} см", "", "", "", "{0} сантиметр"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["{0} м", "{0} м", "", "", "", "{0} метр"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["{0} км", "{0} км", "", "", "", "{0} километр"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"]],
+    [["{0} фт", "{0} фт", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фт"], ["", "", "", "", "", "{0} фт"]],
+    [["{0} ми", "{0} ми", "", "", "", "{0} миля"], ["", "", "", "", "", "{0} ми"], ["", "", "", "", "", "{0} ми"]],
+    [["{0} г", "{0} г", "", "", "", "{0} грамм"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0} г"]],
+    [["{0} кг", "{0} кг", "", "", "", "{0} килограмм"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "", "", "", "", "{0} унци"], ["", "", "", "", "", "{0} унци"], ["", "", "", "", "", "{0} унци"]],
+    [["{0} фнт", "{0} фнт", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фнт"], ["", "", "", "", "", "{0} фнт"]],
+    [["{0} мл", "{0} мл", "", "", "", "{0} миллилитр"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["{0} л", "{0} л", "", "", "", "{0} литр"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["{0} ам. гал.", "{0} ам. гал.", "", "", "", "{0} америкӑлла галлон"], ["", "", "", "", "", "{0} ам. гал."], ["", "", "", "", "", "{0} ам. гал."]],
+];
+
+// hr
+static N19: Names = [
+    [["", "{0} milimetar", "", "{0} milimetra", "", "{0} milimetara"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetar", "", "{0} centimetra", "", "{0} centimetara"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metar", "", "{0} metra", "", "{0} metara"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometar", "", "{0} kilometra", "", "{0} kilometara"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} inč", "", "", "", "{0} inča"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "{0} stope", "", "{0} stopa"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "{0} milje", "", "{0} milja"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "", "", "{0} grama"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "", "", "{0} kilograma"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unca", "", "{0} unce", "", "{0} unci"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} funta", "", "{0} funte", "", "{0} funti"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitar", "", "{0} mililitra", "", "{0} mililitara"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litra", "", "{0} litre", "", "{0} litara"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galon", "", "", "", "{0} galona"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// cs
+static N20: Names = [
+    [["", "{0} milimetr", "", "{0} milimetry", "{0} milimetru", "{0} milimetrů"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetr", "", "{0} centimetry", "{0} centimetru", "{0} centimetrů"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metr", "", "{0} metry", "{0} metru", "{0} metrů"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometr", "", "{0} kilometry", "{0} kilometru", "{0} kilometrů"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} palec", "", "{0} palce", "{0} palce", "{0} palců"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} stopa", "", "{0} stopy", "{0} stopy", "{0} stop"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} míle", "", "{0} míle", "{0} míle", "{0} mil"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "{0} gramy", "{0} gramu", "{0} gramů"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "{0} kilogramy", "{0} kilogramu", "{0} kilogramů"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unce", "", "{0} unce", "{0} unce", "{0} uncí"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} libra", "", "{0} libry", "{0} libry", "{0} liber"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitr", "", "{0} mililitry", "{0} mililitru", "{0} mililitrů"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litr", "", "{0} litry", "{0} litru", "{0} litrů"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galon", "", "{0} galony", "{0} galonu", "{0} galonů"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// da
+static N21: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} centimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} tomme", "", "", "", "{0} tommer"], ["", "{0} tomme", "", "", "", "{0} tommer"], ["", "", "", "", "", "{0}\""]],
+    [["", "", "", "", "", "{0} fod"], ["", "", "", "", "", "{0} fod"], ["", "", "", "", "", "{0} fod"]],
+    [["", "{0} mile", "", "", "", "{0} miles"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} ounce", "", "", "", "{0} ounces"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} pund"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallons"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// nl
+static N22: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} centimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} inch", "", "", "", "{0} inches"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} voet"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mijl"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} ounce"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} pound"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// en
+static N23: Names = [
+    [["", "{0} millimeter", "", "", "", "{0} millimeters"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} centimeter", "", "", "", "{0} centimeters"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} meter", "", "", "", "{0} meters"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometer", "", "", "", "{0} kilometers"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} inch", "", "", "", "{0} inches"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} foot", "", "", "", "{0} feet"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} mile", "", "", "", "{0} miles"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} gram", "", "", "", "{0} grams"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} kilogram", "", "", "", "{0} kilograms"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} ounce", "", "", "", "{0} ounces"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} pound", "", "", "", "{0} pounds"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "{0} milliliter", "", "", "", "{0} milliliters"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0}mL"]],
+    [["", "{0} liter", "", "", "", "{0} liters"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "{0} gallon", "", "", "", "{0} gallons"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// eo
+static N24: Names = [
+    [["", "{0} milimetro", "", "", "", "{0} milimetroj"], ["", "", "", "", "", "{0}\u{202F}mm"], ["", "", "", "", "", "{0}\u{202F}mm"]],
+    [["", "{0} centimetro", "", "", "", "{0} centimetroj"], ["", "", "", "", "", "{0}\u{202F}cm"], ["", "", "", "", "", "{0}\u{202F}cm"]],
+    [["", "{0} metro", "", "", "", "{0} metroj"], ["", "", "", "", "", "{0}\u{202F}m"], ["", "", "", "", "", "{0}\u{202F}m"]],
+    [["", "{0} kilometro", "", "", "", "{0} kilometroj"], ["", "", "", "", "", "{0}\u{202F}km"], ["", "", "", "", "", "{0}\u{202F}km"]],
+    [["", "{0} colo", "", "", "", "{0} coloj"], ["", "", "", "", "", "{0}\u{202F}in"], ["", "", "", "", "", "{0}\u{202F}in"]],
+    [["", "{0} futo", "", "", "", "{0} futoj"], ["", "", "", "", "", "{0}\u{202F}ft"], ["", "", "", "", "", "{0}\u{202F}ft"]],
+    [["", "{0} mejlo", "", "", "", "{0} mejloj"], ["", "", "", "", "", "{0}\u{202F}mi"], ["", "", "", "", "", "{0}\u{202F}mi"]],
+    [["", "{0} gramo", "", "", "", "{0} gramoj"], ["", "", "", "", "", "{0}\u{202F}g"], ["", "", "", "", "", "{0}\u{202F}g"]],
+    [["", "{0} kilogramo", "", "", "", "{0} kilogramoj"], ["", "", "", "", "", "{0}\u{202F}kg"], ["", "", "", "", "", "{0}\u{202F}kg"]],
+    [["", "{0} unco", "", "", "", "{0} uncoj"], ["", "", "", "", "", "{0}\u{202F}oz"], ["", "", "", "", "", "{0}\u{202F}oz"]],
+    [["", "{0} funto", "", "", "", "{0} funtoj"], ["", "", "", "", "", "{0}\u{202F}lb"], ["", "", "", "", "", "{0}\u{202F}lb"]],
+    [["", "{0} mililitro", "", "", "", "{0} mililitroj"], ["", "", "", "", "", "{0}\u{202F}ml"], ["", "", "", "", "", "{0}\u{202F}ml"]],
+    [["", "{0} litro", "", "", "", "{0} litroj"], ["", "", "", "", "", "{0}\u{202F}l"], ["", "", "", "", "", "{0}\u{202F}l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// et
+static N25: Names = [
+    [["", "{0} millimeeter", "", "", "", "{0} millimeetrit"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} sentimeeter", "", "", "", "{0} sentimeetrit"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} meeter", "", "", "", "{0} meetrit"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilomeeter", "", "", "", "{0} kilomeetrit"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} toll", "", "", "", "{0} tolli"], ["", "", "", "", "", "{0} in"], ["", "{0} toll", "", "", "", "{0} tolli"]],
+    [["", "{0} jalg", "", "", "", "{0} jalga"], ["", "", "", "", "", "{0} ft"], ["", "{0} jalg", "", "", "", "{0} jalga"]],
+    [["", "{0} miil", "", "", "", "{0} miili"], ["", "", "", "", "", "{0} mi"], ["", "{0} miil", "", "", "", "{0} miili"]],
+    [["", "{0} gramm", "", "", "", "{0} grammi"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogramm", "", "", "", "{0} kilogrammi"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unts", "", "", "", "{0} untsi"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} nael", "", "", "", "{0} naela"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} milliliiter", "", "", "", "{0} milliliitrit"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} liiter", "", "", "", "{0} liitrit"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallonit"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// fo
+static N26: Names = [
+    [["", "{0} millimetur", "", "", "", "{0} millimetrar"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} sentimetur", "", "", "", "{0} sentimetrar"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metur", "", "", "", "{0} metrar"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometur", "", "", "", "{0} kilometrar"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} tummi", "", "", "", "{0} tummar"], ["", "", "", "", "", "{0} tum."], ["", "", "", "", "", "{0} tum."]],
+    [["", "{0} fótur", "", "", "", "{0} føtur"], ["", "{0} fótur", "", "", "", "{0} føtur"], ["", "{0} fótur", "", "", "", "{0} føtur"]],
+    [["", "", "", "", "", "{0} míl"], ["", "", "", "", "", "{0} míl"], ["", "", "", "", "", "{0} míl"]],
+    [["", "", "", "", "", "{0} gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} unsa", "", "", "", "{0} unsur"], ["", "{0} unsa", "", "", "", "{0} unsur"], ["", "{0}unsa", "", "", "", "{0}unsur"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0}#"]],
+    [["", "{0} millilitur", "", "", "", "{0} millilitrar"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0} litur", "", "", "", "{0} litrar"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallons"], ["", "{0} gallon", "", "", "", "{0} gallons"], ["", "{0} gallon", "", "", "", "{0} gallons"]],
+];
+
+// fi
+static N27: Names = [
+    [["", "{0} millimetri", "", "", "", "{0} millimetriä"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} senttimetri", "", "", "", "{0} senttimetriä"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metri", "", "", "", "{0} metriä"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometri", "", "", "", "{0} kilometriä"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} tuuma", "", "", "", "{0} tuumaa"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} jalka", "", "", "", "{0} jalkaa"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} maili", "", "", "", "{0} mailia"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} gramma", "", "", "", "{0} grammaa"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} kilogramma", "", "", "", "{0} kilogrammaa"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} unssi", "", "", "", "{0} unssia"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} pauna", "", "", "", "{0} paunaa"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} millilitra", "", "", "", "{0} millilitraa"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0} litra", "", "", "", "{0} litraa"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} am. gallona", "", "", "", "{0} am. gallonaa"], ["", "", "", "", "", "{0} am. gal"], ["", "", "", "", "", "{0}am.gal"]],
+];
+
+// fr
+static N28: Names = [
+    [["", "{0}\u{A0}millimètre", "", "", "", "{0}\u{A0}millimètres"], ["", "", "", "", "", "{0}\u{202F}mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0}\u{A0}centimètre", "", "", "", "{0}\u{A0}centimètres"], ["", "", "", "", "", "{0}\u{202F}cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0}\u{A0}mètre", "", "", "", "{0}\u{A0}mètres"], ["", "", "", "", "", "{0}\u{202F}m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0}\u{A0}kilomètre", "", "", "", "{0}\u{A0}kilomètres"], ["", "", "", "", "", "{0}\u{202F}km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0}\u{A0}pouce", "", "", "", "{0}\u{A0}pouces"], ["", "", "", "", "", "{0}\u{202F}po"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0}\u{A0}pied", "", "", "", "{0} pieds"], ["", "", "", "", "", "{0}\u{202F}pi"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0}\u{A0}mile", "", "", "", "{0}\u{A0}miles"], ["", "", "", "", "", "{0}\u{202F}mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0}\u{A0}gramme", "", "", "", "{0}\u{A0}grammes"], ["", "", "", "", "", "{0}\u{202F}g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0}\u{A0}kilogramme", "", "", "", "{0}\u{A0}kilogrammes"], ["", "", "", "", "", "{0}\u{202F}kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0}\u{A0}once", "", "", "", "{0}\u{A0}onces"], ["", "", "", "", "", "{0}\u{202F}oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0}\u{A0}livre", "", "", "", "{0}\u{A0}livres"], ["", "", "", "", "", "{0}\u{202F}lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} millilitre", "", "", "", "{0} millilitres"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0}\u{A0}litre", "", "", "", "{0}\u{A0}litres"], ["", "", "", "", "", "{0}\u{202F}l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallons"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// gl
+static N29: Names = [
+    [["", "{0} milímetro", "", "", "", "{0} milímetros"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centímetro", "", "", "", "{0} centímetros"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metro", "", "", "", "{0} metros"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} quilómetro", "", "", "", "{0} quilómetros"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} polgada", "", "", "", "{0} polgadas"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} pé", "", "", "", "{0} pés"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} milla", "", "", "", "{0} millas"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gramo", "", "", "", "{0} gramos"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} quilogramo", "", "", "", "{0} quilogramos"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} onza", "", "", "", "{0} onzas"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} libra", "", "", "", "{0} libras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitro", "", "", "", "{0} mililitros"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litro", "", "", "", "{0} litros"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galón estadounidense", "", "", "", "{0} galóns estadounidenses"], ["", "", "", "", "", "{0} gal EUA"], ["", "", "", "", "", "{0} gal EUA"]],
+];
+
+// ka
+static N30: Names = [
+    [["", "", "", "", "", "{0} მილიმეტრი"], ["", "", "", "", "", "{0} მმ"], ["", "", "", "", "", "{0} მმ"]],
+    [["", "", "", "", "", "{0} სანტიმეტრი"], ["", "", "", "", "", "{0} სმ"], ["", "", "", "", "", "{0} სმ"]],
+    [["", "", "", "", "", "{0} მეტრი"], ["", "", "", "", "", "{0} მ"], ["", "", "", "", "", "{0}მ"]],
+    [["", "", "", "", "", "{0} კილომეტრი"], ["", "", "", "", "", "{0} კმ"], ["", "", "", "", "", "{0} კმ"]],
+    [["", "", "", "", "", "{0} დუიმი"], ["", "", "", "", "", "{0} დუიმი"], ["", "", "", "", "", "{0} დმ"]],
+    [["", "", "", "", "", "{0} ფუტი"], ["", "", "", "", "", "{0} ფტ"], ["", "", "", "", "", "{0} ფტ"]],
+    [["", "", "", "", "", "{0} მილი"], ["", "", "", "", "", "{0} მილი"], ["", "", "", "", "", "{0} მილი"]],
+    [["", "", "", "", "", "{0} გრამი"], ["", "", "", "", "", "{0} გ"], ["", "", "", "", "", "{0}გ"]],
+    [["", "", "", "", "", "{0} კილოგრამი"], ["", "", "", "", "", "{0} კგ"], ["", "", "", "", "", "{0}კგ"]],
+    [["", "", "", "", "", "{0} უნცია"], ["", "", "", "", "", "{0} უნც"], ["", "", "", "", "", "{0} უნც"]],
+    [["", "", "", "", "", "{0} ფუნტი"], ["", "", "", "", "", "{0} ფნტ"], ["", "", "", "", "", "{0} ფნტ"]],
+    [["", "", "", "", "", "{0} მილილიტრი"], ["", "", "", "", "", "{0} მილილ."], ["", "", "", "", "", "{0} მილილ."]],
+    [["", "", "", "", "", "{0} ლიტრი"], ["", "", "", "", "", "{0} ლ"], ["", "", "", "", "", "{0}ლ"]],
+    [["", "", "", "", "", "{0} გალონი"], ["", "", "", "", "", "{0} გალონი"], ["", "", "", "", "", "{0} გალონი"]],
+];
+
+// de
+static N31: Names = [
+    [["", "", "", "", "", "{0} Millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} Zentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} Meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} Kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} Zoll"], ["", "{0}\u{A0}in", "", "", "", "{0} in"], ["", "{0}\u{A0}in", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} Fuß"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} Meile", "", "", "", "{0} Meilen"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} Gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} Kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} Unze", "", "", "", "{0} Unzen"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} Pfund"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0}\u{A0}Milliliter"], ["", "", "", "", "", "{0}\u{A0}ml"], ["", "", "", "", "", "{0}\u{A0}ml"]],
+    [["", "", "", "", "", "{0} Liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0}\u{A0}Gallone", "", "", "", "{0}\u{A0}Gallonen"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// el
+static N32: Names = [
+    [["", "{0} χιλιοστόμετρο", "", "", "", "{0} χιλιοστόμετρα"], ["", "", "", "", "", "{0} χλστ."], ["", "", "", "", "", "{0} χλστ."]],
+    [["", "{0} εκατοστό", "", "", "", "{0} εκατοστά"], ["", "", "", "", "", "{0} εκ."], ["", "", "", "", "", "{0} εκ."]],
+    [["", "{0} μέτρο", "", "", "", "{0} μέτρα"], ["", "", "", "", "", "{0} μ."], ["", "", "", "", "", "{0} μ."]],
+    [["", "{0} χιλιόμετρο", "", "", "", "{0} χιλιόμετρα"], ["", "", "", "", "", "{0} χλμ."], ["", "", "", "", "", "{0} χλμ."]],
+    [["", "{0} ίντσα", "", "", "", "{0} ίντσες"], ["", "", "", "", "", "{0} ίν."], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} πόδι", "", "", "", "{0} πόδια"], ["", "", "", "", "", "{0} πδ"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} μίλι", "", "", "", "{0} μίλια"], ["", "", "", "", "", "{0} μίλ."], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} γραμμάριο", "", "", "", "{0} γραμμάρια"], ["", "", "", "", "", "{0} γρ."], ["", "", "", "", "", "{0} γρ."]],
+    [["", "{0} χιλιόγραμμο", "", "", "", "{0} χιλιόγραμμα"], ["", "{0} κιλό", "", "", "", "{0} κιλά"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} ουγγιά", "", "", "", "{0} ουγγιές"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} λίβρα", "", "", "", "{0} λίβρες"], ["", "", "", "", "", "{0} λβ"], ["", "", "", "", "", "{0} λβ"]],
+    [["", "{0} χιλιοστόλιτρο", "", "", "", "{0} χιλιοστόλιτρα"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} λίτρο", "", "", "", "{0} λίτρα"], ["", "", "", "", "", "{0} λίτ."], ["", "", "", "", "", "{0} λ."]],
+    [["", "{0} γαλόνι", "", "", "", "{0} γαλόνια"], ["", "", "", "", "", "{0} γαλ."], ["", "", "", "", "", "{0} γαλ."]],
+];
+
+// gu
+static N33: Names = [
+    [["", "", "", "", "", "{0} મિલિમીટર"], ["", "", "", "", "", "{0} મિમી"], ["", "", "", "", "", "{0} મિમી"]],
+    [["", "", "", "", "", "{0} સેન્ટિમીટર"], ["", "", "", "", "", "{0} સેમી"], ["", "", "", "", "", "{0} સેમી"]],
+    [["", "", "", "", "", "{0} મીટર"], ["", "", "", "", "", "{0} મી"], ["", "", "", "", "", "{0} મી"]],
+    [["", "", "", "", "", "{0} કિલોમીટર"], ["", "", "", "", "", "{0} કિમી"], ["", "", "", "", "", "{0} કિમી"]],
+    [["", "", "", "", "", "{0} ઇંચ"], ["", "", "", "", "", "{0} ઇંચ"], ["", "", "", "", "", "{0} \""]],
+    [["", "", "", "", "", "{0} ફૂટ"], ["", "", "", "", "", "{0} ફૂટ"], ["", "", "", "", "", "{0} '"]],
+    [["", "", "", "", "", "{0} માઇલ"], ["", "", "", "", "", "{0} માઇલ"], ["", "", "", "", "", "{0} માઇલ"]],
+    [["", "", "", "", "", "{0} ગ્રામ"], ["", "", "", "", "", "{0} ગ્રા"], ["", "", "", "", "", "{0} ગ્રા"]],
+    [["", "", "", "", "", "{0} કિલોગ્રામ"], ["", "", "", "", "", "{0} કિગ્રા"], ["", "", "", "", "", "{0} કિગ્રા"]],
+    [["", "", "", "", "", "{0} ઔંસ"], ["", "", "", "", "", "{0} ઔંસ"], ["", "", "", "", "", "{0} ઔંસ"]],
+    [["", "", "", "", "", "{0} પાઉન્ડ"], ["", "", "", "", "", "{0} પાઉન્ડ"], ["", "", "", "", "", "{0} પાઉન્ડ"]],
+    [["", "", "", "", "", "{0} મિલિલીટર"], ["", "", "", "", "", "{0} મિલિલીટર"], ["", "", "", "", "", "{0} મિલિલીટર"]],
+    [["", "", "", "", "", "{0} લિટર"], ["", "", "", "", "", "{0} લિ"], ["", "", "", "", "", "{0} લિ"]],
+    [["", "", "", "", "", "{0} ગૅલન"], ["", "", "", "", "", "{0} ગૅલન"], ["", "", "", "", "", "{0} ગૅલન"]],
+];
+
+// ht
+static N34: Names = [
+    [["", "{0}\u{A0}millimètre", "", "", "", "{0}\u{A0}millimètres"], ["", "", "", "", "", "{0}\u{202F}mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0}\u{A0}centimètre", "", "", "", "{0}\u{A0}centimètres"], ["", "", "", "", "", "{0}\u{202F}cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0}\u{A0}mètre", "", "", "", "{0}\u{A0}mètres"], ["", "", "", "", "", "{0}\u{202F}m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0}\u{A0}kilomètre", "", "", "", "{0}\u{A0}kilomètres"], ["", "", "", "", "", "{0}\u{202F}km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0}\u{A0}pouce", "", "", "", "{0}\u{A0}pouces"], ["", "", "", "", "", "{0}\u{202F}po"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0}\u{A0}pied", "", "", "", "{0} pieds"], ["", "", "", "", "", "{0}\u{202F}pi"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0}\u{A0}mile", "", "", "", "{0}\u{A0}miles"], ["", "", "", "", "", "{0}\u{202F}mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0}\u{A0}gramme", "", "", "", "{0}\u{A0}grammes"], ["", "", "", "", "", "{0}gr"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0}\u{A0}kilogramme", "", "", "", "{0}\u{A0}kilogrammes"], ["", "", "", "", "", "{0}\u{202F}kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0}\u{A0}once", "", "", "", "{0}\u{A0}onces"], ["", "", "", "", "", "{0}\u{202F}oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0}\u{A0}livre", "", "", "", "{0}\u{A0}livres"], ["", "", "", "", "", "{0}\u{202F}lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} millilitre", "", "", "", "{0} millilitres"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0}\u{A0}litre", "", "", "", "{0}\u{A0}litres"], ["", "", "", "", "", "{0}\u{202F}l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallons"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// ha
+static N35: Names = [
+    [["", "milimita {0}", "", "", "", "milimitoci {0}"], ["", "", "", "", "", "mm {0}"], ["", "", "", "", "", "mm{0}"]],
+    [["", "sentimita {0}", "", "", "", "sentimitoci {0}"], ["", "", "", "", "", "sm {0}"], ["", "", "", "", "", "sm{0}"]],
+    [["", "mita {0}", "", "", "", "mitoci {0}"], ["", "", "", "", "", "m {0}"], ["", "", "", "", "", "m{0}"]],
+    [["", "kilomita {0}", "", "", "", "kilomitoci {0}"], ["", "", "", "", "", "km {0}"], ["", "", "", "", "", "km{0}"]],
+    [["", "inci {0}", "", "", "", "incina {0}"], ["", "", "", "", "", "in {0}"], ["", "", "", "", "", "{0}″"]],
+    [["", "ƙafa {0}", "", "", "", "ƙafafu {0}"], ["", "ƙf {0}", "", "", "", "ƙff {0}"], ["", "", "", "", "", "{0}'"]],
+    [["", "mil {0}", "", "", "", "mil-mil {0}"], ["", "", "", "", "", "mi {0}"], ["", "mi{0}", "", "", "", "mil-mil{0}"]],
+    [["", "giram {0}", "", "", "", "giram-giram {0}"], ["", "", "", "", "", "g {0}"], ["", "", "", "", "", "g{0}"]],
+    [["", "kilogiram {0}", "", "", "", "kilogiramgiram {0}"], ["", "", "", "", "", "kg {0}"], ["", "", "", "", "", "kg{0}"]],
+    [["", "oza {0}", "", "", "", "oza-oza {0}"], ["", "", "", "", "", "oz {0}"], ["", "", "", "", "", "oz{0}"]],
+    [["", "Laba {0}", "", "", "", "laba-laba {0}"], ["", "", "", "", "", "lb {0}"], ["", "", "", "", "", "{0}#"]],
+    [["", "millimita {0}", "", "", "", "millimitoci {0}"], ["", "", "", "", "", "mL {0}"], ["", "", "", "", "", "mL{0}"]],
+    [["", "lita {0}", "", "", "", "litoci {0}"], ["", "", "", "", "", "L {0}"], ["", "", "", "", "", "L{0}"]],
+    [["", "galan {0}", "", "", "", "galan-galan {0}"], ["", "", "", "", "", "gal {0}"], ["", "", "", "", "", "gal{0}"]],
+];
+
+// he
+static N36: Names = [
+    [["", "{0} מילימטר", "", "", "", "{0} מילימטרים"], ["", "{0} מ″מ", "", "", "", "{0} מ״מ"], ["", "{0} מ″מ", "", "", "", "{0} מ״מ"]],
+    [["", "{0} סנטימטר", "", "", "", "{0} סנטימטרים"], ["", "", "", "", "", "{0} ס״מ"], ["", "", "", "", "", "{0} ס״מ"]],
+    [["", "{0} מטר", "", "", "", "{0} מטרים"], ["", "", "", "", "", "{0} מ׳"], ["", "", "", "", "", "{0} מ׳"]],
+    [["", "{0} קילומטר", "", "", "", "{0} קילומטרים"], ["", "", "", "", "", "{0} ק״מ"], ["", "", "", "", "", "{0} ק״מ"]],
+    [["", "", "", "", "", "{0} אינץ׳"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} רגל"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} מייל"], ["", "", "", "", "", "{0} מייל"], ["", "", "", "", "", "{0} מייל"]],
+    [["", "", "", "", "", "{0} גרם"], ["", "", "", "", "", "{0} גר׳"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} קילוגרם"], ["", "", "", "", "", "{0} ק״ג"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} אונקיה", "", "", "", "{0} אונקיות"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} פאונד"], ["", "{0} lb", "", "", "", "{0} lbs"], ["", "{0} lb", "", "", "", "{0} lbs"]],
+    [["", "", "", "", "", "{0} מיליליטר"], ["", "", "", "", "", "{0} מ״ל"], ["", "", "", "", "", "{0} מ״ל"]],
+    [["", "", "", "", "", "{0} ליטר"], ["", "", "", "", "", "{0} ל׳"], ["", "", "", "", "", "{0} ל׳"]],
+    [["", "{0} גלון", "", "", "", "{0} גלונים"], ["", "", "", "", "", "{0} גל׳"], ["", "", "", "", "", "{0} גל׳"]],
+];
+
+// hi
+static N37: Names = [
+    [["", "", "", "", "", "{0} मिलीमीटर"], ["", "", "", "", "", "{0} मि॰मी॰"], ["", "", "", "", "", "{0} मिमी"]],
+    [["", "", "", "", "", "{0} सेंटीमीटर"], ["", "", "", "", "", "{0} सें॰मी॰"], ["", "", "", "", "", "{0} सें॰मी॰"]],
+    [["", "", "", "", "", "{0} मीटर"], ["", "", "", "", "", "{0} मी"], ["", "", "", "", "", "{0} मी"]],
+    [["", "", "", "", "", "{0} किलोमीटर"], ["", "", "", "", "", "{0} कि॰मी॰"], ["", "", "", "", "", "{0} किमी"]],
+    [["", "", "", "", "", "{0} इंच"], ["", "", "", "", "", "{0} इंच"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} फ़ुट", "", "", "", "{0} फ़ीट"], ["", "", "", "", "", "{0} फ़ीट"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} मील"], ["", "", "", "", "", "{0} मील"], ["", "", "", "", "", "{0} मील"]],
+    [["", "", "", "", "", "{0} ग्राम"], ["", "", "", "", "", "{0} ग्रा॰"], ["", "", "", "", "", "{0} ग्रा"]],
+    [["", "", "", "", "", "{0} किलोग्राम"], ["", "", "", "", "", "{0} कि॰ग्रा॰"], ["", "", "", "", "", "{0} किग्रा"]],
+    [["", "", "", "", "", "{0} औंस"], ["", "", "", "", "", "{0} औंस"], ["", "", "", "", "", "{0} औं॰"]],
+    [["", "", "", "", "", "{0} पौंड"], ["", "", "", "", "", "{0} पौंड"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} मिलीलीटर"], ["", "", "", "", "", "{0} मि॰ ली॰"], ["", "", "", "", "", "{0} मिली"]],
+    [["", "", "", "", "", "{0} लीटर"], ["", "", "", "", "", "{0} ली॰"], ["", "", "", "", "", "{0} ली"]],
+    [["", "", "", "", "", "{0} गैलन"], ["", "", "", "", "", "{0} गैलन"], ["", "", "", "", "", "{0} गै"]],
+];
+
+// hu
+static N38: Names = [
+    [["", "", "", "", "", "{0} milliméter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} centiméter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} méter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilométer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} hüvelyk"], ["", "", "", "", "", "{0} hüvelyk"], ["", "", "", "", "", "{0} hüvelyk"]],
+    [["", "", "", "", "", "{0} láb"], ["", "", "", "", "", "{0} láb"], ["", "", "", "", "", "{0} láb"]],
+    [["", "", "", "", "", "{0} mérföld"], ["", "", "", "", "", "{0} mf"], ["", "", "", "", "", "{0} mf"]],
+    [["", "", "", "", "", "{0} gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} uncia"], ["", "", "", "", "", "{0} oz"], ["", "{0} oz", "", "", "", "{0} uncia"]],
+    [["", "", "", "", "", "{0} font"], ["", "", "", "", "", "{0} lb"], ["", "{0} lb", "", "", "", "{0} font"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ia
+static N39: Names = [
+    [["", "{0} millimetro", "", "", "", "{0} millimetros"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} centimetro", "", "", "", "{0} centimetros"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metro", "", "", "", "{0} metros"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometro", "", "", "", "{0} kilometros"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} pollice", "", "", "", "{0} pollices"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} pede", "", "", "", "{0} pedes"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} millia", "", "", "", "{0} millias"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gramma", "", "", "", "{0} grammas"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogramma", "", "", "", "{0} kilogrammas"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} uncia", "", "", "", "{0} uncias"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} libra", "", "", "", "{0} libras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} millilitro", "", "", "", "{0} millilitros"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0} litro", "", "", "", "{0} litros"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} gallon", "", "", "", "{0} gallones"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// id
+static N40: Names = [
+    [["", "", "", "", "", "{0} milimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} sentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inci"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} kaki"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} ounce"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} pound"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} mililiter"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "", "", "", "", "{0} galon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ga
+static N41: Names = [
+    [["", "{0} mhilliméadar", "{0} mhilliméadar", "{0} mhilliméadar", "", "{0} milliméadar"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} cheintiméadar", "{0} cheintiméadar", "{0} cheintiméadar", "{0} gceintiméadar", "{0} ceintiméadar"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} mhéadar", "{0} mhéadar", "{0} mhéadar", "", "{0} méadar"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} chiliméadar", "{0} chiliméadar", "{0} chiliméadar", "{0} gciliméadar", "{0} ciliméadar"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "{0} or.", "{0} orlach"], ["", "", "", "", "", "{0} or."], ["", "", "", "", "{0} n-or.", "{0} or."]],
+    [["", "{0} troigh", "{0} thr.", "{0} thr.", "{0} dtr.", "{0} tr."], ["", "", "", "", "", "{0} tr."], ["", "", "", "", "", "{0} tr."]],
+    [["", "{0} mhíle", "{0} mhíle", "{0} mhíle", "", "{0} míle"], ["", "{0} mhíle", "{0} mhíle", "{0} mhíle", "{0} míle", "{0} mi"], ["", "{0} mhíle", "{0} mhíle", "{0} mhíle", "", "{0} míle"]],
+    [["", "{0} ghram", "{0} ghram", "{0} ghram", "{0} ngram", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} chileagram", "{0} chileagram", "{0} chileagram", "{0} gcileagram", "{0} cileagram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "{0} n-unsa", "{0} unsa"], ["", "", "", "", "{0} n-unsa", "{0} unsa"], ["", "", "", "", "{0} n-unsa", "{0} unsa"]],
+    [["", "{0} phunt", "{0} phunt", "{0} phunt", "{0} bpunt", "{0} punt"], ["", "{0} phunt", "{0} phunt", "{0} phunt", "{0} bpunt", "{0} punt"], ["", "{0} phunt", "{0} phunt", "{0} phunt", "{0} bpunt", "{0} punt"]],
+    [["", "{0} mhillilítear", "{0} mhillilítear", "{0} mhillilítear", "", "{0} millilítear"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} lítear"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} ghalún", "{0} ghalún", "{0} ghalún", "{0} ngalún", "{0} galún"], ["", "{0} ghal.", "{0} ghal.", "{0} ghal.", "{0} ngal.", "{0} gal."], ["", "{0} ghal.", "{0} ghal.", "{0} ghal.", "{0} ngal.", "{0} gal."]],
+];
+
+// ig
+static N42: Names = [
+    [["", "", "", "", "", "{0} millimita"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} centimita"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} mita"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilomita"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inches"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} feet"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} mile"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilograms"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} ounce"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} pound"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// is
+static N43: Names = [
+    [["", "{0} millimetri", "", "", "", "{0} millimetrar"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} sentimetri", "", "", "", "{0} sentimetrar"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metri", "", "", "", "{0} metrar"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kílómetri", "", "", "", "{0} kílómetrar"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} tomma", "", "", "", "{0} tommur"], ["", "", "", "", "", "{0} t."], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fet"], ["", "", "", "", "", "{0} fet"], ["", "", "", "", "", "{0} fet"]],
+    [["", "{0} míla", "", "", "", "{0} mílur"], ["", "", "", "", "", "{0} mí"], ["", "", "", "", "", "{0} mí"]],
+    [["", "{0} gramm", "", "", "", "{0} grömm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kílógramm", "", "", "", "{0} kílógrömm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} únsa", "", "", "", "{0} únsur"], ["", "", "", "", "", "{0} oz"], ["", "{0} únsa", "", "", "", "{0} únsur"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} p."]],
+    [["", "{0} millilítri", "", "", "", "{0} millilítrar"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} lítri", "", "", "", "{0} lítrar"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// it
+static N44: Names = [
+    [["", "{0} millimetro", "", "", "", "{0} millimetri"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} centimetro", "", "", "", "{0} centimetri"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metro", "", "", "", "{0} metri"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} chilometro", "", "", "", "{0} chilometri"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} pollice", "", "", "", "{0} pollici"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} piede", "", "", "", "{0} piedi"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} miglio", "", "", "", "{0} miglia"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} grammo", "", "", "", "{0} grammi"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} chilogrammo", "", "", "", "{0} chilogrammi"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} oncia", "", "", "", "{0} once"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} libbra", "", "", "", "{0} libbre"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} millilitro", "", "", "", "{0} millilitri"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0} litro", "", "", "", "{0} litri"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallone", "", "", "", "{0} galloni"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// ja
+static N45: Names = [
+    [["", "", "", "", "", "{0} ミリメートル"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} センチメートル"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} メートル"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} キロメートル"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} インチ"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} フィート"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} マイル"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} グラム"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} キログラム"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} オンス"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} ポンド"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "", "", "", "{0} ミリリットル"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "", "", "", "", "{0} リットル"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} ガロン"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// jv
+static N46: Names = [
+    [["", "", "", "", "", "{0} milimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} sentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inci"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} kaki"], ["", "", "", "", "", "{0} kaki"], ["", "", "", "", "", "{0} kaki"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} ons"], ["", "", "", "", "", "{0} ons"], ["", "", "", "", "", "{0} ons"]],
+    [["", "", "", "", "", "{0} pon"], ["", "", "", "", "", "{0} pon"], ["", "", "", "", "", "{0} pon"]],
+    [["", "", "", "", "", "{0} mililiter"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "", "", "", "", "{0} galon"], ["", "", "", "", "", "{0} galon"], ["", "", "", "", "", "{0} galon"]],
+];
+
+// kn
+static N47: Names = [
+    [["", "{0} ಮಿಲಿಮೀಟರ್", "", "", "", "{0} ಮಿಲಿಮೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಮಿಮೀ"], ["", "", "", "", "", "{0}ಮಿಮೀ"]],
+    [["", "{0} ಸೆಂಟಿಮೀಟರ್", "", "", "", "{0} ಸೆಂಟಿಮೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಸೆಮೀ"], ["", "", "", "", "", "{0} ಸೆಮೀ"]],
+    [["", "{0} ಮೀಟರ್", "", "", "", "{0} ಮೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಮೀ"], ["", "", "", "", "", "{0}ಮೀ"]],
+    [["", "{0} ಕಿಲೋಮೀಟರ್", "", "", "", "{0} ಕಿಲೋಮೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಕಿಮೀ"], ["", "", "", "", "", "{0}ಕಿಮೀ"]],
+    [["", "{0} ಅಂಗುಲ", "", "", "", "{0} ಅಂಗುಲಗಳು"], ["", "", "", "", "", "{0} ಅಂ"], ["", "", "", "", "", "{0} ″"]],
+    [["", "", "", "", "", "{0} ಅಡಿ"], ["", "", "", "", "", "{0} ಅ"], ["", "", "", "", "", "{0} ′"]],
+    [["", "{0} ಮೈಲು", "", "", "", "{0} ಮೈಲುಗಳು"], ["", "", "", "", "", "{0} ಮೈ."], ["", "", "", "", "", "{0}ಮೈ."]],
+    [["", "{0} ಗ್ರಾಂ", "", "", "", "{0} ಗ್ರಾಂಗಳು"], ["", "", "", "", "", "{0} ಗ್ರಾ"], ["", "", "", "", "", "{0}ಗ್ರಾ"]],
+    [["", "{0} ಕಿಲೋಗ್ರಾಂ", "", "", "", "{0} ಕಿಲೋಗ್ರಾಂಗಳು"], ["", "", "", "", "", "{0} ಕೆಜಿ"], ["", "", "", "", "", "{0}ಕೆಜಿ"]],
+    [["", "{0} ಔನ್ಸ್", "", "", "", "{0} ಔನ್ಸ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಔ"], ["", "", "", "", "", "{0}ಔ"]],
+    [["", "{0} ಪೌಂಡ್", "", "", "", "{0} ಪೌಂಡ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಪೌಂ"], ["", "", "", "", "", "{0}#"]],
+    [["", "{0} ಮಿಲಿಲೀಟರ್", "", "", "", "{0} ಮಿಲಿಲೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಮಿ.ಲೀ"], ["", "", "", "", "", "{0}ಮಿ.ಲೀ"]],
+    [["", "{0} ಲೀಟರ್", "", "", "", "{0} ಲೀಟರ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಲೀ"], ["", "", "", "", "", "{0}ಲೀ"]],
+    [["", "{0} ಗ್ಯಾಲನ್\u{200C}", "", "", "", "{0} ಗ್ಯಾಲನ್\u{200C}ಗಳು"], ["", "", "", "", "", "{0} ಗ್ಯಾ"], ["", "", "", "", "", "{0} ಗ್ಯಾ"]],
+];
+
+// kk
+static N48: Names = [
+    [["", "", "", "", "", "{0} миллиметр"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "", "", "", "", "{0} сантиметр"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "", "", "", "", "{0} метр"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["", "", "", "", "", "{0} километр"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"]],
+    [["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"]],
+    [["", "", "", "", "", "{0} миль"], ["", "", "", "", "", "{0} миль"], ["", "", "", "", "", "{0} миль"]],
+    [["", "", "", "", "", "{0} грамм"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0} г"]],
+    [["", "", "", "", "", "{0} килограмм"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "", "", "", "", "{0} унция"], ["", "", "", "", "", "{0} унц."], ["", "", "", "", "", "{0} унция"]],
+    [["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фунт"]],
+    [["", "", "", "", "", "{0} миллилитр"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "", "", "", "", "{0} литр"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "", "", "", "", "{0} галлон"], ["", "", "", "", "", "{0} гал."], ["", "", "", "", "", "{0} гал."]],
+];
+
+// km
+static N49: Names = [
+    [["", "", "", "", "", "{0} មិល្លីម៉ែត្រ"], ["", "", "", "", "", "{0} មិល្លីម៉ែត្រ"], ["", "", "", "", "", "{0} មម"]],
+    [["", "", "", "", "", "{0} សង់ទីម៉ែត្រ"], ["", "", "", "", "", "{0} សម"], ["", "", "", "", "", "{0} សម"]],
+    [["", "", "", "", "", "{0} ម៉ែត្រ"], ["", "", "", "", "", "{0} ម"], ["", "", "", "", "", "{0} ម"]],
+    [["", "", "", "", "", "{0} គីឡូម៉ែត្រ"], ["", "", "", "", "", "{0} គម"], ["", "", "", "", "", "{0} គម"]],
+    [["", "", "", "", "", "{0} អ៊ីញ"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} ហ្វីត"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} ម៉ាយ"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} ម៉."]],
+    [["", "", "", "", "", "{0} ក្រាម"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} គីឡូក្រាម"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} អោន"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} អ."]],
+    [["", "", "", "", "", "{0} ផោន"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} មិល្លីលីត្រ"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} លីត្រ"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0}\u{A0}ហ្គាឡុង"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ky
+static N50: Names = [
+    [["", "", "", "", "", "{0} миллиметр"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "", "", "", "", "{0} сантиметр"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "", "", "", "", "{0} метр"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0}м"]],
+    [["", "", "", "", "", "{0} километр"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"], ["", "", "", "", "", "{0} дюйм"]],
+    [["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"]],
+    [["", "", "", "", "", "{0} миля"], ["", "", "", "", "", "{0} миля"], ["", "", "", "", "", "{0} миля"]],
+    [["", "", "", "", "", "{0} грамм"], ["", "", "", "", "", "{0} гр"], ["", "", "", "", "", "{0} гр"]],
+    [["", "", "", "", "", "{0} килограмм"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "", "", "", "", "{0} унция"], ["", "", "", "", "", "{0} унц."], ["", "", "", "", "", "{0} унц."]],
+    [["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} миллилитр"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "", "", "", "", "{0} литр"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "", "", "", "", "{0} галлон"], ["", "", "", "", "", "{0} гал"], ["", "", "", "", "", "{0} гал"]],
+];
+
+// ko
+static N51: Names = [
+    [["", "", "", "", "", "{0}밀리미터"], ["", "", "", "", "", "{0}mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0}센티미터"], ["", "", "", "", "", "{0}cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0}미터"], ["", "", "", "", "", "{0}m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0}킬로미터"], ["", "", "", "", "", "{0}km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0}인치"], ["", "", "", "", "", "{0}in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0}피트"], ["", "", "", "", "", "{0}ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0}마일"], ["", "", "", "", "", "{0}mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0}그램"], ["", "", "", "", "", "{0}g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0}킬로그램"], ["", "", "", "", "", "{0}kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0}온스"], ["", "", "", "", "", "{0}oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0}파운드"], ["", "", "", "", "", "{0}lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "", "", "", "{0}밀리리터"], ["", "", "", "", "", "{0}mL"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "", "", "{0}리터"], ["", "", "", "", "", "{0}L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0}갤런"], ["", "", "", "", "", "{0}gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// ku
+static N52: Names = [
+    [["", "", "", "", "", "{0} mîlîmetre"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} metre"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kîlometre"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} înç"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fît"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} mîl"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// lb
+static N53: Names = [
+    [["", "", "", "", "", "{0} Millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} Zentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} Meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} Kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} Zoll"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} Fouss"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} Meil", "", "", "", "{0} Meilen"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} Gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} Kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} Onz", "", "", "", "{0} Onzen"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} Pond"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} Milliliter"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} Liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} Galloun", "", "", "", "{0} Gallounen"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// lo
+static N54: Names = [
+    [["", "", "", "", "", "{0} ມິນລິແມັດ"], ["", "", "", "", "", "{0} ມມ"], ["", "", "", "", "", "{0} ມມ"]],
+    [["", "", "", "", "", "{0} ຊັງຕີແມັດ"], ["", "", "", "", "", "{0} ຊມ"], ["", "", "", "", "", "{0} ຊມ"]],
+    [["", "", "", "", "", "{0} ແມັດ"], ["", "", "", "", "", "{0} ມ"], ["", "", "", "", "", "{0} ມ"]],
+    [["", "", "", "", "", "{0} ກິໂລແມັດ"], ["", "", "", "", "", "{0} ກມ"], ["", "", "", "", "", "{0} ກມ"]],
+    [["", "", "", "", "", "{0} ນິ້ວ"], ["", "", "", "", "", "{0} ນິ"], ["", "", "", "", "", "{0} ນິ"]],
+    [["", "", "", "", "", "{0} ຟຸດ"], ["", "", "", "", "", "{0} ຟ"], ["", "", "", "", "", "{0} ຟ"]],
+    [["", "", "", "", "", "{0} ໄມລ໌"], ["", "", "", "", "", "{0} ໄມ"], ["", "", "", "", "", "{0} ໄມ"]],
+    [["", "", "", "", "", "{0} ກຣາມ"], ["", "", "", "", "", "{0} ກຼ"], ["", "", "", "", "", "{0} ກຼ"]],
+    [["", "", "", "", "", "{0} ກິໂລກຣາມ"], ["", "", "", "", "", "{0} ກິໂລ"], ["", "", "", "", "", "{0} ກລ"]],
+    [["", "", "", "", "", "{0} ອອນສ"], ["", "", "", "", "", "{0} ອ"], ["", "", "", "", "", "{0} ອ"]],
+    [["", "", "", "", "", "{0} ປອນ"], ["", "", "", "", "", "{0} ປ"], ["", "", "", "", "", "{0} ປ"]],
+    [["", "", "", "", "", "{0} ມິນລີລິດ"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} ລິດ"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} ແກລລອນ"], ["", "", "", "", "", "{0} ແກລລອນ"], ["", "", "", "", "", "{0} ແກລລອນ"]],
+];
+
+// lt
+static N55: Names = [
+    [["", "{0} milimetras", "", "{0} milimetrai", "{0} milimetro", "{0} milimetrų"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetras", "", "{0} centimetrai", "{0} centimetro", "{0} centimetrų"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metras", "", "{0} metrai", "{0} metro", "{0} metrų"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometras", "", "{0} kilometrai", "{0} kilometro", "{0} kilometrų"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} colis", "", "{0} coliai", "{0} colio", "{0} colių"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} pėda", "", "{0} pėdos", "{0} pėdos", "{0} pėdų"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} mylia", "", "{0} mylios", "{0} mylios", "{0} mylių"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gramas", "", "{0} gramai", "{0} gramo", "{0} gramų"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogramas", "", "{0} kilogramai", "{0} kilogramo", "{0} kilogramų"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} uncija", "", "{0} uncijos", "{0} uncijos", "{0} uncijų"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} svaras", "", "{0} svarai", "{0} svaro", "{0} svarų"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitras", "", "{0} mililitrai", "{0} mililitro", "{0} mililitrų"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litras", "", "{0} litrai", "{0} litro", "{0} litrų"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galonas", "", "{0} galonai", "{0} galono", "{0} galonų"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// lv
+static N56: Names = [
+    [["{0} milimetru", "{0} milimetrs", "", "", "", "{0} milimetri"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["{0} centimetru", "{0} centimetrs", "", "", "", "{0} centimetri"], ["", "", "", "", "", "{0} cm"], ["", "{0}cm", "", "", "", "{0} cm"]],
+    [["{0} metru", "{0} metrs", "", "", "", "{0} metri"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["{0} kilometru", "{0} kilometrs", "", "", "", "{0} kilometri"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["{0} collu", "{0} colla", "", "", "", "{0} collas"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}in"]],
+    [["{0} pēdu", "{0} pēda", "", "", "", "{0} pēdas"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}ft"]],
+    [["{0} jūdžu", "{0} jūdze", "", "", "", "{0} jūdzes"], ["", "{0} jūdze", "", "", "", "{0} jūdzes"], ["", "", "", "", "", "{0}mi"]],
+    [["{0} gramu", "{0} grams", "", "", "", "{0} grami"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["{0} kilogramu", "{0} kilograms", "", "", "", "{0} kilogrami"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["{0} unču", "{0} unce", "", "", "", "{0} unces"], ["{0}\u{A0}unču", "{0} unce", "", "", "", "{0} unces"], ["", "", "", "", "", "{0}oz"]],
+    [["{0} mārciņu", "{0} mārciņa", "", "", "", "{0} mārciņas"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["{0} mililitru", "{0} mililitrs", "", "", "", "{0} mililitri"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["{0} litru", "{0} litrs", "", "", "", "{0} litri"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["{0} galonu", "{0} galons", "", "", "", "{0} galoni"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// mk
+static N57: Names = [
+    [["", "{0} милиметар", "", "", "", "{0} милиметри"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} сантиметар", "", "", "", "{0} сантиметри"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} метар", "", "", "", "{0} метри"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} километар", "", "", "", "{0} километри"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} инч", "", "", "", "{0} инчи"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} стапка", "", "", "", "{0} стапки"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} милја", "", "", "", "{0} милји"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} грам", "", "", "", "{0} грама"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} килограм", "", "", "", "{0} килограми"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} унца", "", "", "", "{0} унци"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} фунта", "", "", "", "{0} фунти"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} милилитар", "", "", "", "{0} милилитри"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} литар", "", "", "", "{0} литри"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "{0} галон", "", "", "", "{0} галони"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ms
+static N58: Names = [
+    [["", "", "", "", "", "{0} milimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} sentimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inci"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}\""]],
+    [["", "", "", "", "", "{0} kaki"], ["", "", "", "", "", "{0} ka"], ["", "", "", "", "", "{0}'"]],
+    [["", "", "", "", "", "{0} batu"], ["", "", "", "", "", "{0} bt"], ["", "", "", "", "", "{0} bt"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} auns"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} auns"]],
+    [["", "", "", "", "", "{0} paun"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} paun"]],
+    [["", "", "", "", "", "{0} mililiter"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gelen"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ml
+static N59: Names = [
+    [["", "", "", "", "", "{0} മില്ലീമീറ്റർ"], ["", "", "", "", "", "{0} മി.മീ."], ["", "", "", "", "", "{0} മി.മീ."]],
+    [["", "", "", "", "", "{0} സെന്റീമീറ്റർ"], ["", "", "", "", "", "{0} സെ.മീ."], ["", "", "", "", "", "{0} സെ.മീ."]],
+    [["", "", "", "", "", "{0} മീറ്റർ"], ["", "", "", "", "", "{0} മീ."], ["", "", "", "", "", "{0} മീ."]],
+    [["", "", "", "", "", "{0} കിലോമീറ്റർ"], ["", "", "", "", "", "{0} കി.മീ."], ["", "", "", "", "", "{0} കി.മീ."]],
+    [["", "", "", "", "", "{0} ഇഞ്ച്"], ["", "", "", "", "", "{0} ഇഞ്ച്"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} അടി"], ["", "", "", "", "", "{0} അടി"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} മൈൽ"], ["", "", "", "", "", "{0} മൈൽ"], ["", "", "", "", "", "{0} മൈ"]],
+    [["", "", "", "", "", "{0} ഗ്രാം"], ["", "", "", "", "", "{0} ഗ്രാം"], ["", "", "", "", "", "{0} ഗ്രാം"]],
+    [["", "", "", "", "", "{0} കിലോഗ്രാം"], ["", "", "", "", "", "{0} കി.ഗ്രാം"], ["", "", "", "", "", "{0}കി.ഗ്രാം"]],
+    [["", "", "", "", "", "{0} ഔൺസ്"], ["", "", "", "", "", "{0} ഔ."], ["", "", "", "", "", "{0} ഔ"]],
+    [["", "", "", "", "", "{0} പൗണ്ട്"], ["", "", "", "", "", "{0} പൗ"], ["", "", "", "", "", "{0} പൗ"]],
+    [["", "", "", "", "", "{0} മില്ലിലിറ്റർ"], ["", "", "", "", "", "{0} മി.ലി."], ["", "", "", "", "", "{0} മി.ലി."]],
+    [["", "", "", "", "", "{0} ലിറ്റർ"], ["", "", "", "", "", "{0} ലി."], ["", "", "", "", "", "{0} ലി."]],
+    [["", "", "", "", "", "{0} ഗാലൺ"], ["", "", "", "", "", "{0} ഗാ."], ["", "", "", "", "", "{0} ഗാ."]],
+];
+
+// mi
+static N60: Names = [
+    [["", "", "", "", "", "{0} mitamano"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} mitarau"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} mita"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} manomita"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} īnihi"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} pūtu"], ["", "", "", "", "", "{0} pūtu"], ["", "", "", "", "", "{0} pūtu"]],
+    [["", "", "", "", "", "{0} māero"], ["", "", "", "", "", "{0} māero"], ["", "", "", "", "", "{0} māero"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} ritamano"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} rita"], ["", "", "", "", "", "{0} rita"], ["", "", "", "", "", "{0} rita"]],
+    [["", "", "", "", "", "{0} kārani"], ["", "", "", "", "", "{0} kārani"], ["", "", "", "", "", "{0} kārani"]],
+];
+
+// mr
+static N61: Names = [
+    [["", "", "", "", "", "{0} मिलिमीटर"], ["", "", "", "", "", "{0} मिमी"], ["", "", "", "", "", "{0}मिमी"]],
+    [["", "", "", "", "", "{0} सेंटीमीटर"], ["", "", "", "", "", "{0} सेंमी"], ["", "", "", "", "", "{0}सेंमी"]],
+    [["", "", "", "", "", "{0} मीटर"], ["", "", "", "", "", "{0} मी"], ["", "", "", "", "", "{0}मी"]],
+    [["", "", "", "", "", "{0} किलोमीटर"], ["", "", "", "", "", "{0} किमी"], ["", "", "", "", "", "{0}किमी"]],
+    [["", "", "", "", "", "{0} इंच"], ["", "", "", "", "", "{0} इंच"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} फूट"], ["", "", "", "", "", "{0} फूट"], ["", "", "", "", "", "{0}'"]],
+    [["", "", "", "", "", "{0} मैल"], ["", "", "", "", "", "{0} मैल"], ["", "", "", "", "", "{0} मैल"]],
+    [["", "", "", "", "", "{0} ग्रॅम"], ["", "", "", "", "", "{0} ग्रॅ"], ["", "", "", "", "", "{0}ग्रॅ"]],
+    [["", "", "", "", "", "{0} किलोग्रॅम"], ["", "", "", "", "", "{0} किग्रॅ"], ["", "", "", "", "", "{0}किग्रॅ"]],
+    [["", "", "", "", "", "{0} औंस"], ["", "", "", "", "", "{0} औंस"], ["", "", "", "", "", "{0}औंस"]],
+    [["", "", "", "", "", "{0} पाउंड"], ["", "", "", "", "", "{0} पाउंड"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} मिलीलीटर"], ["", "", "", "", "", "{0} मिली"], ["", "", "", "", "", "{0} मिली"]],
+    [["", "", "", "", "", "{0} लीटर"], ["", "", "", "", "", "{0} ली"], ["", "", "", "", "", "{0}ली"]],
+    [["", "", "", "", "", "{0} गॅलन"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// mn
+static N62: Names = [
+    [["", "", "", "", "", "{0} миллиметр"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "{0} сантиметр", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "", "", "", "", "{0} метр"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["", "", "", "", "", "{0} километр"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "", "", "", "", "{0} инч"], ["", "", "", "", "", "{0} инч"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0} фут"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} миль"], ["", "", "", "", "", "{0} ми"], ["", "", "", "", "", "{0} миль"]],
+    [["", "", "", "", "", "{0} грамм"], ["", "", "", "", "", "{0} гр"], ["", "", "", "", "", "{0} гр"]],
+    [["", "", "", "", "", "{0} килограмм"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "", "", "", "", "{0} унц"], ["", "", "", "", "", "{0} унц"], ["", "", "", "", "", "{0} унц"]],
+    [["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0} фунт"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} миллилитр"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "", "", "", "", "{0} литр"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "", "", "", "", "{0} галлон"], ["", "", "", "", "", "{0} гал"], ["", "", "", "", "", "{0} гал"]],
+];
+
+// ne
+static N63: Names = [
+    [["", "", "", "", "", "{0} मिलिमिटर"], ["", "", "", "", "", "{0}मि.मि."], ["", "", "", "", "", "{0}मि.मि."]],
+    [["", "", "", "", "", "{0} सेन्टिमिटर"], ["", "", "", "", "", "{0}से.मि."], ["", "", "", "", "", "{0}से.मि."]],
+    [["", "", "", "", "", "{0} मिटर"], ["", "", "", "", "", "{0} मि."], ["", "", "", "", "", "{0} मि."]],
+    [["", "{0} किलोमिटर", "", "", "", "{0}किलोमिटर"], ["", "", "", "", "", "{0}कि.मि."], ["", "", "", "", "", "{0}कि.मि."]],
+    [["", "", "", "", "", "{0}इन्च"], ["", "इन्च", "", "", "", "{0}इन्च"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} फुट", "", "", "", "{0}फिट"], ["", "", "", "", "", "{0}फिट"], ["", "{0}फिट", "", "", "", "{0}′"]],
+    [["", "माइल", "", "", "", "{0} माइल"], ["", "", "", "", "", "{0} माइल"], ["", "", "", "", "", "{0} माइल"]],
+    [["", "", "", "", "", "{0} ग्राम"], ["", "", "", "", "", "{0} ग्राम"], ["", "", "", "", "", "{0} ग्राम"]],
+    [["", "", "", "", "", "{0} किलोग्राम"], ["", "", "", "", "", "{0} किलो"], ["", "", "", "", "", "{0} किलो"]],
+    [["", "", "", "", "", "{0} आउन्स"], ["", "", "", "", "", "{0} आउन्स"], ["", "", "", "", "", "{0} आउन्स"]],
+    [["", "", "", "", "", "{0}पाउन्ड"], ["", "", "", "", "", "{0}पाउन्ड"], ["", "", "", "", "", "{0} पाउण्ड"]],
+    [["", "", "", "", "", "{0}मिलिलिटर"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} लिटर"], ["", "", "", "", "", "{0} लि."], ["", "", "", "", "", "{0} लि."]],
+    [["", "", "", "", "", "{0}ग्यालोन"], ["", "", "", "", "", "{0}ग्यालोन"], ["", "", "", "", "", "{0}ग्यालोन"]],
+];
+
+// nb, no
+static N64: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} centimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} tomme", "", "", "", "{0} tommer"], ["", "{0} tomme", "", "", "", "{0} tommer"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} mile", "", "", "", "{0} miles"], ["", "{0} mile", "", "", "", "{0} miles"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} unse", "", "", "", "{0} unser"], ["", "{0} unse", "", "", "", "{0} unser"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// nn
+static N65: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} centimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} tomme", "", "", "", "{0} tommar"], ["", "{0} tomme", "", "", "", "{0} tommar"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} engelsk mil", "", "", "", "{0} engelske mil"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} unse", "", "", "", "{0} unser"], ["", "{0} unse", "", "", "", "{0} unser"], ["", "{0} unse", "", "", "", "{0} unser"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "{0} ml", "", "", "", "{0}ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// or
+static N66: Names = [
+    [["", "", "", "", "", "{0} ମିଲିମିଟର୍"], ["", "", "", "", "", "{0} ମିମି"], ["", "", "", "", "", "{0}ମିମି"]],
+    [["", "", "", "", "", "{0} ସେଣ୍ଟିମିଟର୍"], ["", "", "", "", "", "{0} ସେମି"], ["", "{0} ସେମି", "", "", "", "{0}ସେମି"]],
+    [["", "", "", "", "", "{0} ମିଟର୍"], ["", "", "", "", "", "{0} ମି"], ["", "", "", "", "", "{0} ମି"]],
+    [["", "", "", "", "", "{0} କିଲୋମିଟର୍"], ["", "", "", "", "", "{0} କିମି"], ["", "", "", "", "", "{0} କିମି"]],
+    [["", "", "", "", "", "{0} ଇଞ୍ଚ୍"], ["", "", "", "", "", "{0} ଇଞ୍ଚ୍"], ["", "", "", "", "", "{0} ଇଞ୍ଚ୍"]],
+    [["", "{0} ଫୁଟ୍", "", "", "", "{0} ଫିଟ୍"], ["", "", "", "", "", "{0} ଫିଟ୍"], ["", "", "", "", "", "{0} ଫିଟ୍"]],
+    [["", "", "", "", "", "{0} ମାଇଲ୍"], ["", "", "", "", "", "{0} ମା"], ["", "", "", "", "", "{0}ମାଇଲ"]],
+    [["", "", "", "", "", "{0} ଗ୍ରାମ୍"], ["", "", "", "", "", "{0} ଗ୍ରା"], ["", "", "", "", "", "{0}ଗ୍ରା"]],
+    [["", "", "", "", "", "{0} କିଲୋଗ୍ରାମ୍"], ["", "", "", "", "", "{0} କିଗ୍ରା"], ["", "", "", "", "", "{0}କିଗ୍ରା"]],
+    [["", "", "", "", "", "{0} ଆଉନ୍ସ"], ["", "", "", "", "", "{0} ଓଜ୍"], ["", "", "", "", "", "{0}ଅ."]],
+    [["", "", "", "", "", "{0} ପାଉଣ୍ଡ୍"], ["", "", "", "", "", "{0} ପାଉଣ୍ଡ୍"], ["", "", "", "", "", "{0} ପାଉଣ୍ଡ୍"]],
+    [["", "", "", "", "", "{0} ମିଲିଲିଟର୍"], ["", "", "", "", "", "{0} ମିଲି"], ["", "", "", "", "", "{0} ମି. ଲି."]],
+    [["", "", "", "", "", "{0} ଲିଟର୍"], ["", "", "", "", "", "{0} ଲି"], ["", "", "", "", "", "{0}ଲି"]],
+    [["", "", "", "", "", "{0} ଗ୍ୟାଲନ୍"], ["", "", "", "", "", "{0} ଗ୍ୟାଲ୍"], ["", "", "", "", "", "{0} ଗେ"]],
+];
+
+// pa
+static N67: Names = [
+    [["", "", "", "", "", "{0} ਮਿਲੀਮੀਟਰ"], ["", "", "", "", "", "{0} ਮਿ.ਮੀ."], ["", "", "", "", "", "{0} ਮਿ.ਮੀ."]],
+    [["", "", "", "", "", "{0} ਸੈਂਟੀਮੀਟਰ"], ["", "", "", "", "", "{0} ਸੈਂ.ਮੀ."], ["", "", "", "", "", "{0}ਸੈਂ.ਮੀ."]],
+    [["", "", "", "", "", "{0} ਮੀਟਰ"], ["", "", "", "", "", "{0} ਮੀ."], ["", "", "", "", "", "{0} ਮੀ."]],
+    [["", "", "", "", "", "{0} ਕਿਲੋਮੀਟਰ"], ["", "", "", "", "", "{0} ਕਿ.ਮੀ."], ["", "", "", "", "", "{0} ਕਿ.ਮੀ."]],
+    [["", "", "", "", "", "{0} ਇੰਚ"], ["", "", "", "", "", "{0} ਇੰਚ"], ["", "", "", "", "", "{0}\""]],
+    [["", "", "", "", "", "{0} ਫੁੱਟ"], ["", "", "", "", "", "{0} ਫੁੱਟ"], ["", "", "", "", "", "{0}'"]],
+    [["", "", "", "", "", "{0} ਮੀਲ"], ["", "", "", "", "", "{0} ਮੀਲ"], ["", "", "", "", "", "{0} ਮੀਲ"]],
+    [["", "", "", "", "", "{0} ਗ੍ਰਾਮ"], ["", "", "", "", "", "{0} ਗ੍ਰਾ."], ["", "", "", "", "", "{0} ਗ੍ਰਾ."]],
+    [["", "", "", "", "", "{0} ਕਿਲੋਗ੍ਰਾਮ"], ["", "", "", "", "", "{0} ਕਿ.ਗ੍ਰਾ."], ["", "", "", "", "", "{0} ਕਿ.ਗ੍ਰਾ."]],
+    [["", "", "", "", "", "{0} ਔਂਸ"], ["", "", "", "", "", "{0} ਔਂਸ"], ["", "", "", "", "", "{0} ਔਂਸ"]],
+    [["", "", "", "", "", "{0} ਪੌਂਡ"], ["", "", "", "", "", "{0} ਪੌਂਡ"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} ਮਿਲੀਲਿਟਰ"], ["", "", "", "", "", "{0} ਮਿ.ਲਿ."], ["", "", "", "", "", "{0} ਮਿ.ਲਿ."]],
+    [["", "", "", "", "", "{0} ਲਿਟਰ"], ["", "", "", "", "", "{0} ਲਿ."], ["", "", "", "", "", "{0} ਲਿ."]],
+    [["", "", "", "", "", "{0} ਗੈਲਨ"], ["", "", "", "", "", "{0} ਗੈਲਨ"], ["", "", "", "", "", "{0}ਗੈਲਨ"]],
+];
+
+// fa
+static N68: Names = [
+    [["", "", "", "", "", "{0} میلی\u{200C}متر"], ["", "", "", "", "", "{0} میلی\u{200C}متر"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} سانتی\u{200C}متر"], ["", "", "", "", "", "{0} سانتی\u{200C}متر"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} متر"], ["", "", "", "", "", "{0}متر"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} کیلومتر"], ["", "", "", "", "", "{0} کیلومتر"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} اینچ"], ["", "", "", "", "", "{0} اینچ"], ["", "", "", "", "", "{0}in"]],
+    [["", "", "", "", "", "{0} فوت"], ["", "", "", "", "", "{0} فوت"], ["", "", "", "", "", "{0}ft"]],
+    [["", "", "", "", "", "{0} مایل"], ["", "", "", "", "", "{0} مایل"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} گرم"], ["", "", "", "", "", "{0} گرم"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} کیلوگرم"], ["", "", "", "", "", "{0} کیلوگرم"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} اونس"], ["", "", "", "", "", "{0} اونس"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} پوند"], ["", "", "", "", "", "{0} پوند"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "", "", "", "{0} میلی\u{200C}لیتر"], ["", "", "", "", "", "{0} میلی\u{200C}لیتر"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "", "", "{0} لیتر"], ["", "", "", "", "", "{0}L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} گالن"], ["", "", "", "", "", "{0} گالن"], ["", "", "", "", "", "{0} گالن"]],
+];
+
+// pl
+static N69: Names = [
+    [["", "{0} milimetr", "", "{0} milimetry", "{0} milimetrów", "{0} milimetra"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centymetr", "", "{0} centymetry", "{0} centymetrów", "{0} centymetra"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metr", "", "{0} metry", "{0} metrów", "{0} metra"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometr", "", "{0} kilometry", "{0} kilometrów", "{0} kilometra"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} cal", "", "{0} cale", "{0} cali", "{0} cala"], ["", "{0} cal", "", "{0} cale", "{0} cali", "{0} cala"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} stopa", "", "", "{0} stóp", "{0} stopy"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} mila", "", "{0} mile", "{0} mil", "{0} mili"], ["", "{0} mila", "", "{0} mile", "{0} mil", "{0} mili"], ["", "{0} mila", "", "{0} mile", "{0} mil", "{0} mili"]],
+    [["", "{0} gram", "", "{0} gramy", "{0} gramów", "{0} grama"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "{0} kilogramy", "{0} kilogramów", "{0} kilograma"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} uncja", "", "{0} uncje", "", "{0} uncji"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} funt", "", "{0} funty", "{0} funtów", "{0} funta"], ["", "{0} funt", "", "{0} funty", "{0} funtów", "{0} funta"], ["", "{0} funt", "", "{0} funty", "{0} funtów", "{0} funta"]],
+    [["", "{0} mililitr", "", "{0} mililitry", "{0} mililitrów", "{0} mililitra"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litr", "", "{0} litry", "{0} litrów", "{0} litra"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galon amerykański", "", "{0} galony amerykańskie", "{0} galonów amerykańskich", "{0} galona amerykańskiego"], ["", "", "", "", "", "{0} gal am."], ["", "", "", "", "", "{0} gal am."]],
+];
+
+// ps
+static N70: Names = [
+    [["", "{0} ملي متر", "", "", "", "{0} ملي متره"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} سنتي متر", "", "", "", "{0} سنتي متره"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} متر", "", "", "", "{0} متره"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} کيلومتر", "", "", "", "{0} کيلومتره"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} انچ", "", "", "", "{0} انچې"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} فټ", "", "", "", "{0} فټه"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} ميل", "", "", "", "{0} ميلونه"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} ګرام", "", "", "", "{0} ګرامز"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} کيلو ګرام", "", "", "", "{0} کيلو ګرامز"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} اونس", "", "", "", "{0} اونسز"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} پاونډ", "", "", "", "{0} پاونډز"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} ملي ليټر", "", "", "", "{0} ملي ليټرز"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} ليټر", "", "", "", "{0} ليټرز"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "{0} ګيلن", "", "", "", "{0} ګيلنز"], ["", "", "", "", "", "{0} ګيلن"], ["", "", "", "", "", "{0}ګيلن"]],
+];
+
+// pt
+static N71: Names = [
+    [["", "{0} milímetro", "", "", "", "{0} milímetros"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centímetro", "", "", "", "{0} centímetros"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metro", "", "", "", "{0} metros"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} quilômetro", "", "", "", "{0} quilômetros"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} polegada", "", "", "", "{0} polegadas"], ["", "", "", "", "", "{0} pol."], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} pé", "", "", "", "{0} pés"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} milha", "", "", "", "{0} milhas"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} grama", "", "", "", "{0} gramas"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} quilograma", "", "", "", "{0} quilogramas"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} onça", "", "", "", "{0} onças"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} libra", "", "", "", "{0} libras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitro", "", "", "", "{0} mililitros"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litro", "", "", "", "{0} litros"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} galão", "", "", "", "{0} galões"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// qu
+static N72: Names = [
+    [["", "", "", "", "", "{0} milímetro"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} centímetro"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} metro"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilómetro"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} pulgada"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} pie"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} milla"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mililitro"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} litro"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} litro"]],
+    [["", "", "", "", "", "{0} US galón"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// rm
+static N73: Names = [
+    [["", "{0} millimeter", "", "", "", "{0} millimeters"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} centimeter", "", "", "", "{0} centimeters"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} meter", "", "", "", "{0} meters"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometer", "", "", "", "{0} kilometers"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} polesch", "", "", "", "{0} poleschs"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}in"]],
+    [["", "{0} pe", "", "", "", "{0} pes"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} miglia", "", "", "", "{0} miglias"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} gram", "", "", "", "{0} grams"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} kilogram", "", "", "", "{0} kilograms"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} unza", "", "", "", "{0} unzas"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} glivra", "", "", "", "{0} glivras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} milliliter", "", "", "", "{0} milliliters"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0}\u{A0}liter", "", "", "", "{0}\u{A0}liters"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallun", "", "", "", "{0} galluns"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// ro
+static N74: Names = [
+    [["", "{0} milimetru", "", "{0} milimetri", "", "{0} de milimetri"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimetru", "", "{0} centimetri", "", "{0} de centimetri"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} metru", "", "{0} metri", "", "{0} de metri"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometru", "", "{0} kilometri", "", "{0} de kilometri"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} inch", "", "{0} inchi", "", "{0} de inchi"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} picior", "", "{0} picioare", "", "{0} de picioare"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} milă", "", "{0} mile", "", "{0} de mile"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "{0} grame", "", "{0} de grame"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "{0} kilograme", "", "{0} de kilograme"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} uncie", "", "{0} uncii", "", "{0} de uncii"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} livră", "", "{0} livre", "", "{0} de livre"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililitru", "", "{0} mililitri", "", "{0} de mililitri"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litru", "", "{0} litri", "", "{0} de litri"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galon", "", "{0} galoane", "", "{0} de galoane"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// ru
+static N75: Names = [
+    [["", "{0} миллиметр", "", "", "{0} миллиметров", "{0} миллиметра"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0} мм"]],
+    [["", "{0} сантиметр", "", "", "{0} сантиметров", "{0} сантиметра"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0} см"]],
+    [["", "{0} метр", "", "", "{0} метров", "{0} метра"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0} м"]],
+    [["", "{0} километр", "", "", "{0} километров", "{0} километра"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0} км"]],
+    [["", "{0} дюйм", "", "", "{0} дюймов", "{0} дюйма"], ["", "{0} дюйм", "", "", "", "{0} дюйм."], ["", "", "", "", "", "{0} дюйм."]],
+    [["", "{0} фут", "", "", "{0} футов", "{0} фута"], ["", "", "", "", "", "{0} фт"], ["", "", "", "", "", "{0} фт"]],
+    [["", "{0} миля", "", "", "{0} миль", "{0} мили"], ["", "", "", "", "", "{0} ми"], ["", "", "", "", "", "{0} ми"]],
+    [["", "{0} грамм", "", "", "{0} грамм", "{0} грамма"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0} г"]],
+    [["", "{0} килограмм", "", "", "{0} килограмм", "{0} килограмма"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0} кг"]],
+    [["", "{0} унция", "", "", "{0} унций", "{0} унции"], ["", "", "", "", "", "{0} унц."], ["", "", "", "", "", "{0} унц."]],
+    [["", "{0} фунт", "", "", "{0} фунтов", "{0} фунта"], ["", "", "", "", "", "{0} фнт"], ["", "", "", "", "", "{0} фнт"]],
+    [["", "{0} миллилитр", "", "", "{0} миллилитров", "{0} миллилитра"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0} мл"]],
+    [["", "{0} литр", "", "", "{0} литров", "{0} литра"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0} л"]],
+    [["", "{0} амер. галлон", "", "", "{0} амер. галлонов", "{0} амер. галлона"], ["", "", "", "", "", "{0} ам. гал."], ["", "", "", "", "", "{0} ам. гал."]],
+];
+
+// sc
+static N76: Names = [
+    [["", "{0} millìmetru", "", "", "", "{0} millìmetros"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} tzentìmetru", "", "", "", "{0} tzentìmetros"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metru", "", "", "", "{0} metros"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} chilòmetru", "", "", "", "{0} chilòmetros"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} pòddighe", "", "", "", "{0} pòddighes"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}in"]],
+    [["", "{0} pede", "", "", "", "{0} pedes"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} mìlliu", "", "", "", "{0} mìllias"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} grammu", "", "", "", "{0} grammos"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} chilogrammu", "", "", "", "{0} chilogrammos"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} untza", "", "", "", "{0} untzas"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} libbra", "", "", "", "{0} libbras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} millìlitru", "", "", "", "{0} millìlitros"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "{0} litru", "", "", "", "{0} litros"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} gallone", "", "", "", "{0} gallones"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// sd
+static N77: Names = [
+    [["", "", "", "", "", "{0} ملي ميٽر"], ["", "", "", "", "", "{0} ملي ميٽر"], ["", "", "", "", "", "{0} ملي ميٽر"]],
+    [["", "", "", "", "", "{0} سينٽي ميٽر"], ["", "", "", "", "", "{0} سينٽي ميٽر"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} ميٽر"], ["", "", "", "", "", "{0} ميٽر"], ["", "", "", "", "", "{0} ميٽر"]],
+    [["", "", "", "", "", "{0} ڪلوميٽر"], ["", "", "", "", "", "{0} ڪلوميٽر"], ["", "", "", "", "", "{0} ڪلوميٽر"]],
+    [["", "", "", "", "", "{0} انچ"], ["", "", "", "", "", "{0} انچ"], ["", "", "", "", "", "{0} انچ"]],
+    [["", "", "", "", "", "{0} فوٽ"], ["", "", "", "", "", "{0} فوٽ"], ["", "", "", "", "", "{0} فوٽ"]],
+    [["", "", "", "", "", "{0} ميل"], ["", "", "", "", "", "{0} ميل"], ["", "", "", "", "", "{0} ميل"]],
+    [["", "{0} گرام", "", "", "", "{0} گرامَ"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} ڪلوگرام", "", "", "", "{0}ڪلوگرامَ"], ["", "{0} ڪلو گرام", "", "", "", "{0} kg"], ["", "{0}kg", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} اونس"], ["", "", "", "", "", "{0} اونس"], ["", "", "", "", "", "{0} اونس"]],
+    [["", "", "", "", "", "{0} پائونڊ"], ["", "", "", "", "", "{0} پائونڊ"], ["", "", "", "", "", "{0} پائونڊ"]],
+    [["", "", "", "", "", "{0} ملي ليٽر"], ["", "", "", "", "", "{0} ملي ليٽر"], ["", "", "", "", "", "{0} ملي ليٽر"]],
+    [["", "", "", "", "", "{0} ليٽر"], ["", "", "", "", "", "{0} ليٽر"], ["", "", "", "", "", "{0} ليٽر"]],
+    [["", "", "", "", "", "{0} گيلن"], ["", "", "", "", "", "{0} گيلن"], ["", "", "", "", "", "{0} گيلن"]],
+];
+
+// sr
+static N78: Names = [
+    [["", "{0} милиметар", "", "{0} милиметра", "", "{0} милиметара"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} центиметар", "", "{0} центиметра", "", "{0} центиметара"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} метар", "", "{0} метра", "", "{0} метара"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} километар", "", "{0} километра", "", "{0} километара"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} инч", "", "", "", "{0} инча"], ["", "{0} инч", "", "", "", "{0} инча"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "{0} стопе", "", "{0} стопа"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "{0} миље", "", "{0} миља"], ["", "", "", "", "", "{0} mi"], ["", "", "", "{0} миље", "", "{0} миља"]],
+    [["", "{0} грам", "", "", "", "{0} грама"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} килограм", "", "", "", "{0} килограма"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} унца", "", "{0} унце", "", "{0} унци"], ["", "", "", "", "", "{0} oz"], ["", "{0} унца", "", "{0} унце", "", "{0} унци"]],
+    [["", "{0} фунта", "", "{0} фунте", "", "{0} фунти"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} милилитар", "", "{0} милилитра", "", "{0} милилитара"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} литар", "", "{0} литра", "", "{0} литара"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} галон", "", "", "", "{0} галона"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// gd
+static N79: Names = [
+    [["", "{0} mhili-meatair", "{0} mhili-meatair", "{0} mili-meatairean", "", "{0} mili-meatair"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} cheudameatair", "{0} cheudameatair", "{0} ceudameatairean", "", "{0} ceudameatair"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} mheatair", "{0} mheatair", "{0} meatairean", "", "{0} meatair"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} chilemeatair", "{0} chilemeatair", "{0} cilemeatairean", "", "{0} cilemeatair"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "{0} òirlich", "", "{0} òirleach"], ["", "", "", "", "", "{0} òirl"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "{0} throigh", "{0} troighean", "", "{0} troigh"], ["", "", "{0} throigh", "", "", "{0} troigh"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} mhìle", "{0} mhìle", "{0} mìltean", "", "{0} mìle"], ["", "", "", "", "", "{0} mì"], ["", "", "", "", "", "{0}mì"]],
+    [["", "{0} ghram", "{0} ghram", "{0} gramaichean", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} chileagram", "{0} chileagram", "{0} cileagramaichean", "", "{0} cileagram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "{0} unnsachan", "", "{0} unnsa"], ["", "", "", "", "", "{0} unnsa"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} phunnd", "{0} phunnd", "{0} puinnd", "", "{0} punnd"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} mhililiotair", "{0} mhililiotair", "{0} mililiotairean", "", "{0} mililiotair"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "{0} liotairean", "", "{0} liotair"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "{0} ghalan", "{0} ghalan", "{0} galanan", "", "{0} galan"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// si
+static N80: Names = [
+    [["", "", "", "", "", "මිලිමීටර් {0}"], ["", "", "", "", "", "මිමී {0}"], ["", "", "", "", "", "මිමී {0}"]],
+    [["", "", "", "", "", "සෙන්ටිමීටර් {0}"], ["", "", "", "", "", "සෙ.මී {0}"], ["", "", "", "", "", "සෙ.මී {0}"]],
+    [["", "", "", "", "", "මීටර් {0}"], ["", "", "", "", "", "මී {0}"], ["", "", "", "", "", "මී {0}"]],
+    [["", "", "", "", "", "කිලෝමීටර් {0}"], ["", "", "", "", "", "කි.මී {0}"], ["", "", "", "", "", "කි.මී {0}"]],
+    [["", "", "", "", "", "අඟල් {0}"], ["", "", "", "", "", "අඟල් {0}"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "අඩි {0}"], ["", "", "", "", "", "අඩි {0}"], ["", "", "", "", "", "{0}'"]],
+    [["", "", "", "", "", "සැතපුම් {0}"], ["", "", "", "", "", "සැත {0}"], ["", "", "", "", "", "සැත {0}"]],
+    [["", "", "", "", "", "ග්\u{200D}රෑම් {0}"], ["", "", "", "", "", "ග්\u{200D}රෑ. {0}"], ["", "", "", "", "", "ග්\u{200D}රෑ. {0}"]],
+    [["", "", "", "", "", "කිලෝග්\u{200D}රෑම් {0}"], ["", "", "", "", "", "කි.ග්\u{200D}රෑ. {0}"], ["", "", "", "", "", "කිග්\u{200D}රෑ{0}"]],
+    [["", "", "", "", "", "අවුන්ස {0}"], ["", "", "", "", "", "අවුස {0}"], ["", "", "", "", "", "අවුස{0}"]],
+    [["", "", "", "", "", "රාත්තල් {0}"], ["", "", "", "", "", "රා {0}"], ["", "", "", "", "", "රා{0}"]],
+    [["", "", "", "", "", "මිලිලීටර් {0}"], ["", "", "", "", "", "මිලී {0}"], ["", "", "", "", "", "මිලී {0}"]],
+    [["", "", "", "", "", "ලීටර් {0}"], ["", "", "", "", "", "ලී {0}"], ["", "", "", "", "", "ලී {0}"]],
+    [["", "", "", "", "", "ගැලුම් {0}"], ["", "", "", "", "", "ගැලු {0}"], ["", "", "", "", "", "ගැලු {0}"]],
+];
+
+// sk
+static N81: Names = [
+    [["", "{0} milimeter", "", "{0} milimetre", "{0} milimetra", "{0} milimetrov"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimeter", "", "{0} centimetre", "{0} centimetra", "{0} centimetrov"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} meter", "", "{0} metre", "{0} metra", "{0} metrov"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometer", "", "{0} kilometre", "{0} kilometra", "{0} kilometrov"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} palec", "", "{0} palce", "{0} palca", "{0} palcov"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} stopa", "", "{0} stopy", "{0} stopy", "{0} stôp"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} míľa", "", "{0} míle", "{0} míle", "{0} míľ"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "", "{0} gramy", "{0} gramu", "{0} gramov"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "", "{0} kilogramy", "{0} kilogramu", "{0} kilogramov"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unca", "", "{0} unce", "{0} unce", "{0} uncí"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} libra", "", "{0} libry", "{0} libry", "{0} libier"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililiter", "", "{0} mililitre", "{0} mililitra", "{0} mililitrov"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} liter", "", "{0} litre", "{0} litra", "{0} litrov"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galón", "", "{0} galóny", "{0} galónu", "{0} galónov"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// sl
+static N82: Names = [
+    [["", "{0} milimeter", "{0} milimetra", "{0} milimetri", "", "{0} milimetrov"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "{0} centimeter", "{0} centimetra", "{0} centimetri", "", "{0} centimetrov"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "{0} meter", "{0} metra", "{0} metri", "", "{0} metrov"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "{0} kilometer", "{0} kilometra", "{0} kilometri", "", "{0} kilometrov"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} palec", "{0} palca", "{0} palci", "", "{0} palcev"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "{0} čevelj", "{0} čevlja", "{0} čevlji", "", "{0} čevljev"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "{0} milja", "{0} milji", "{0} milje", "", "{0} milj"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} gram", "{0} grama", "{0} grami", "", "{0} gramov"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "{0} kilogram", "{0} kilograma", "{0} kilogrami", "", "{0} kilogramov"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "{0} unča", "{0} unči", "{0} unče", "", "{0} unč"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} funt", "{0} funta", "{0} funti", "", "{0} funtov"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "{0} mililiter", "{0} mililitra", "{0} mililitri", "", "{0} militrov"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} liter", "{0} litra", "{0} litri", "", "{0} litrov"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "{0} galona", "{0} galoni", "{0} galone", "", "{0} galon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// so
+static N83: Names = [
+    [["", "", "", "", "", "{0} milimitir"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} sentimitir"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} mitir"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kiilo mitir"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "Injis", "", "", "", "{0} injis"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "Fuudh", "", "", "", "{0} fiit"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} meyl"], ["", "", "", "", "", "{0} my"], ["", "", "", "", "", "{0} my"]],
+    [["", "", "", "", "", "{0} garaam"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kiilogaraam"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} ownis"], ["", "", "", "", "", "{0} ow"], ["", "", "", "", "", "{0} ow"]],
+    [["", "", "", "", "", "{0} bownd"], ["", "", "", "", "", "{0} bw"], ["", "", "", "", "", "{0} bw"]],
+    [["", "", "", "", "", "{0} mililitar"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} litar"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} galaan"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// es
+static N84: Names = [
+    [["", "{0} milímetro", "", "", "", "{0} milímetros"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "{0} centímetro", "", "", "", "{0} centímetros"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metro", "", "", "", "{0} metros"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilómetro", "", "", "", "{0} kilómetros"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} pulgada", "", "", "", "{0} pulgadas"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}in"]],
+    [["", "{0} pie", "", "", "", "{0} pies"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}ft"]],
+    [["", "{0} milla", "", "", "", "{0} millas"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} gramo", "", "", "", "{0} gramos"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "{0} kilogramo", "", "", "", "{0} kilogramos"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} onza", "", "", "", "{0} onzas"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} libra", "", "", "", "{0} libras"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "{0} mililitro", "", "", "", "{0} mililitros"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "{0} litro", "", "", "", "{0} litros"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "{0} galón", "", "", "", "{0} galones"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// sw
+static N85: Names = [
+    [["", "", "", "", "", "milimita {0}"], ["", "", "", "", "", "milimita {0}"], ["", "", "", "", "", "mm {0}"]],
+    [["", "", "", "", "", "sentimita {0}"], ["", "", "", "", "", "sentimita {0}"], ["", "", "", "", "", "cm {0}"]],
+    [["", "", "", "", "", "mita {0}"], ["", "", "", "", "", "mita {0}"], ["", "", "", "", "", "mita {0}"]],
+    [["", "", "", "", "", "kilomita {0}"], ["", "", "", "", "", "km {0}"], ["", "", "", "", "", "km {0}"]],
+    [["", "", "", "", "", "inchi {0}"], ["", "", "", "", "", "inchi {0}"], ["", "", "", "", "", "inchi {0}"]],
+    [["", "", "", "", "", "futi {0}"], ["", "", "", "", "", "futi {0}"], ["", "", "", "", "", "futi {0}"]],
+    [["", "", "", "", "", "maili {0}"], ["", "", "", "", "", "maili {0}"], ["", "", "", "", "", "maili {0}"]],
+    [["", "", "", "", "", "gramu {0}"], ["", "", "", "", "", "gramu {0}"], ["", "", "", "", "", "gramu {0}"]],
+    [["", "", "", "", "", "kilogramu {0}"], ["", "", "", "", "", "kg {0}"], ["", "", "", "", "", "kg {0}"]],
+    [["", "", "", "", "", "aunsi {0}"], ["", "", "", "", "", "aunsi {0}"], ["", "", "", "", "", "Aunsi {0}"]],
+    [["", "", "", "", "", "ratili {0}"], ["", "", "", "", "", "ratili {0}"], ["", "", "", "", "", "Ratili {0}"]],
+    [["", "", "", "", "", "mililita {0}"], ["", "", "", "", "", "mililita {0}"], ["", "", "", "", "", "mililita {0}"]],
+    [["", "", "", "", "", "lita {0}"], ["", "", "", "", "", "lita {0}"], ["", "", "", "", "", "lita {0}"]],
+    [["", "", "", "", "", "galoni {0}"], ["", "", "", "", "", "galoni {0}"], ["", "", "", "", "", "galoni {0}"]],
+];
+
+// sv
+static N86: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} centimeter"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} tum"], ["", "", "", "", "", "{0} tum"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0} fot"], ["", "", "", "", "", "{0}fot"]],
+    [["", "", "", "", "", "{0} mile"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} uns"], ["", "", "", "", "", "{0} uns"], ["", "", "", "", "", "{0}uns"]],
+    [["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0} pund"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "", "", "", "{0} milliliter"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0}ml"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// ta
+static N87: Names = [
+    [["", "{0} மில்லிமீட்டர்", "", "", "", "{0} மில்லிமீட்டர்கள்"], ["", "", "", "", "", "{0} மி.மீ."], ["", "", "", "", "", "{0}மி.மீ."]],
+    [["", "{0} சென்டிமீட்டர்", "", "", "", "{0} சென்டிமீட்டர்கள்"], ["", "", "", "", "", "{0} செ.மீ."], ["", "", "", "", "", "{0}செ.மீ."]],
+    [["", "{0} மீட்டர்", "", "", "", "{0} மீட்டர்கள்"], ["", "", "", "", "", "{0} மீ."], ["", "", "", "", "", "{0}மீ."]],
+    [["", "{0} கிலோமீட்டர்", "", "", "", "{0} கிலோமீட்டர்கள்"], ["", "", "", "", "", "{0} கி.மீ."], ["", "", "", "", "", "{0}கி.மீ."]],
+    [["", "{0} அங்குலம்", "", "", "", "{0} அங்குலங்கள்"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} அடி"], ["", "", "", "", "", "{0} அடி"], ["", "", "", "", "", "{0} அடி"]],
+    [["", "{0} மைல்", "", "", "", "{0} மைல்கள்"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "{0} கிராம்", "", "", "", "{0} கிராம்கள்"], ["", "", "", "", "", "{0} கி."], ["", "", "", "", "", "{0} கி."]],
+    [["", "{0} கிலோகிராம்", "", "", "", "{0} கிலோகிராம்கள்"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}கி.கி."]],
+    [["", "{0} அவுன்ஸ்", "", "", "", "{0} அவுன்ஸ்கள்"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "{0} பவுண்டு", "", "", "", "{0} பவுண்டுகள்"], ["", "{0} lb", "", "", "", "{0} பவு."], ["", "{0} lb", "", "", "", "{0} பவு."]],
+    [["", "{0} மில்லிலிட்டர்", "", "", "", "{0} மில்லிலிட்டர்கள்"], ["", "", "", "", "", "{0} மிலி"], ["", "", "", "", "", "{0}மிலி"]],
+    [["", "{0} லிட்டர்", "", "", "", "{0} லிட்டர்கள்"], ["", "", "", "", "", "{0} லி."], ["", "", "", "", "", "{0}லி."]],
+    [["", "{0} கேலன்", "", "", "", "{0} கேலன்கள்"], ["", "", "", "", "", "{0} கேல."], ["", "", "", "", "", "{0}கேல."]],
+];
+
+// te
+static N88: Names = [
+    [["", "{0} మిల్లీమీటరు", "", "", "", "{0} మిల్లీమీటర్లు"], ["", "", "", "", "", "{0} మి.మీ."], ["", "", "", "", "", "{0}మి.మీ"]],
+    [["", "{0} సెంటీమీటరు", "", "", "", "{0} సెంటీమీటర్లు"], ["", "", "", "", "", "{0} సెం.మీ."], ["", "", "", "", "", "{0}సెం.మీ"]],
+    [["", "{0} మీటరు", "", "", "", "{0} మీటర్లు"], ["", "", "", "", "", "{0} మీ."], ["", "", "", "", "", "{0}మీ"]],
+    [["", "{0} కిలోమీటరు", "", "", "", "{0} కిలోమీటర్లు"], ["", "", "", "", "", "{0} కి.మీ."], ["", "", "", "", "", "{0}కి.మీ"]],
+    [["", "{0} అంగుళం", "", "", "", "{0} అంగుళాలు"], ["", "", "", "", "", "{0} అం."], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} అడుగు", "", "", "", "{0} అడుగులు"], ["", "", "", "", "", "{0} అ."], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} మైలు", "", "", "", "{0} మైళ్లు"], ["", "", "", "", "", "{0} మై."], ["", "", "", "", "", "{0} మై"]],
+    [["", "{0} గ్రాము", "", "", "", "{0} గ్రాములు"], ["", "", "", "", "", "{0} గ్రా."], ["", "", "", "", "", "{0}గ్రా"]],
+    [["", "{0} కిలోగ్రాము", "", "", "", "{0} కిలోగ్రాములు"], ["", "", "", "", "", "{0} కి.గ్రా."], ["", "", "", "", "", "{0}కి.గ్రా"]],
+    [["", "{0} ఔన్సు", "", "", "", "{0} ఔన్సులు"], ["", "", "", "", "", "{0} ఔ."], ["", "", "", "", "", "{0} ఔ."]],
+    [["", "{0} పౌండు", "", "", "", "{0} పౌండ్లు"], ["", "", "", "", "", "{0} పౌ."], ["", "", "", "", "", "{0} పౌ."]],
+    [["", "{0} మిల్లీలీటరు", "", "", "", "{0} మిల్లీలీటర్లు"], ["", "", "", "", "", "{0} మి.లీ."], ["", "", "", "", "", "{0} మి.లీ."]],
+    [["", "{0} లీటరు", "", "", "", "{0} లీటర్లు"], ["", "", "", "", "", "{0} లీ."], ["", "", "", "", "", "{0}లీ"]],
+    [["", "{0} గ్యాలన్", "", "", "", "{0} గ్యాలన్లు"], ["", "", "", "", "", "{0} గ్యా."], ["", "", "", "", "", "{0} గ్యా."]],
+];
+
+// th
+static N89: Names = [
+    [["", "", "", "", "", "{0} มิลลิเมตร"], ["", "", "", "", "", "{0} มม."], ["", "", "", "", "", "{0}มม."]],
+    [["", "", "", "", "", "{0} เซนติเมตร"], ["", "", "", "", "", "{0} ซม."], ["", "", "", "", "", "{0}ซม."]],
+    [["", "", "", "", "", "{0} เมตร"], ["", "", "", "", "", "{0} ม."], ["", "", "", "", "", "{0}ม."]],
+    [["", "", "", "", "", "{0} กิโลเมตร"], ["", "", "", "", "", "{0} กม."], ["", "", "", "", "", "{0}กม."]],
+    [["", "", "", "", "", "{0} นิ้ว"], ["", "", "", "", "", "{0} นิ้ว"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} ฟุต"], ["", "", "", "", "", "{0} ฟุต"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} ไมล์"], ["", "", "", "", "", "{0} ไมล์"], ["", "", "", "", "", "{0}ไมล์"]],
+    [["", "", "", "", "", "{0} กรัม"], ["", "", "", "", "", "{0} ก."], ["", "", "", "", "", "{0}ก."]],
+    [["", "", "", "", "", "{0} กิโลกรัม"], ["", "", "", "", "", "{0} กก."], ["", "", "", "", "", "{0}กก."]],
+    [["", "", "", "", "", "{0} ออนซ์"], ["", "", "", "", "", "{0} ออนซ์"], ["", "", "", "", "", "{0}ออนซ์"]],
+    [["", "", "", "", "", "{0} ปอนด์"], ["", "", "", "", "", "{0} ปอนด์"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} มิลลิลิตร"], ["", "", "", "", "", "{0} มล."], ["", "", "", "", "", "{0}มล."]],
+    [["", "", "", "", "", "{0} ลิตร"], ["", "", "", "", "", "{0} ล."], ["", "", "", "", "", "{0}ล."]],
+    [["", "", "", "", "", "{0} แกลลอน"], ["", "", "", "", "", "{0} แกลลอน"], ["", "", "", "", "", "{0}แกลลอน"]],
+];
+
+// ti
+static N90: Names = [
+    [["", "", "", "", "", "{0} ሚሊሜተር"], ["", "", "", "", "", "{0} ሚሜ"], ["", "", "", "", "", "{0}ሚሜ"]],
+    [["", "", "", "", "", "{0} ሴንቲሜተር"], ["", "", "", "", "", "{0} ሴሜ"], ["", "", "", "", "", "{0}ሴሜ"]],
+    [["", "", "", "", "", "{0}/ሜትር"], ["", "", "", "", "", "{0} ሜ"], ["", "", "", "", "", "{0}ሜ"]],
+    [["", "", "", "", "", "{0} ኪሎ ሜትር"], ["", "", "", "", "", "{0} ኪሜ"], ["", "", "", "", "", "{0} ኪሜ"]],
+    [["", "{0} ኢንች", "", "", "", "{0} ኢንችስ"], ["", "", "", "", "", "{0} ኢን"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} ፉት", "", "", "", "{0} ፊት"], ["", "", "", "", "", "{0} ፊት"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} ማይል", "", "", "", "{0} ማይላት"], ["", "", "", "", "", "{0} ማ"], ["", "", "", "", "", "{0}ማ"]],
+    [["", "", "", "", "", "{0} ግራም"], ["", "", "", "", "", "{0} ግ"], ["", "", "", "", "", "{0}ግ"]],
+    [["", "", "", "", "", "{0} ኪሎግራም"], ["", "", "", "", "", "{0} ኪግ"], ["", "", "", "", "", "{0}ኪግ"]],
+    [["", "{0} ኣውንስ", "", "", "", "{0} oኣውንስ"], ["", "", "", "", "", "{0} ኣውንስ"], ["", "", "", "", "", "{0}ኣውንስ"]],
+    [["", "", "", "", "", "{0} ፓውንድ"], ["", "", "", "", "", "{0} ፓውንድ"], ["", "", "", "", "", "{0} ፓውንድ"]],
+    [["", "", "", "", "", "{0} ሚሊ ሊትሮ"], ["", "", "", "", "", "{0} ሚሊ"], ["", "", "", "", "", "{0}ሚሊ"]],
+    [["", "", "", "", "", "{0} ሊትሮ"], ["", "", "", "", "", "{0} ሊ"], ["", "", "", "", "", "{0}ሊ"]],
+    [["", "", "", "", "", "{0} ጋሎን"], ["", "", "", "", "", "{0} ጋሎን"], ["", "", "", "", "", "{0}ጋሎን"]],
+];
+
+// tk
+static N91: Names = [
+    [["", "", "", "", "", "{0} millimetr"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} santimetr"], ["", "", "", "", "", "{0} sm"], ["", "", "", "", "", "{0}sm"]],
+    [["", "", "", "", "", "{0} metr"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kilometr"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} dýuým"], ["", "", "", "", "", "{0} dý"], ["", "", "", "", "", "{0} dý"]],
+    [["", "", "", "", "", "{0} fut"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} unsiýa"], ["", "", "", "", "", "{0} uns."], ["", "", "", "", "", "{0} uns."]],
+    [["", "", "", "", "", "{0} funt"], ["", "", "", "", "", "{0} funt"], ["", "", "", "", "", "{0} funt"]],
+    [["", "", "", "", "", "{0} millilitr"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} litr"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal."], ["", "", "", "", "", "{0} gal."]],
+];
+
+// tl
+static N92: Names = [
+    [["", "{0} milimetro", "", "", "", "{0} na milimetro"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} sentimetro"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "{0} metro", "", "", "", "{0} na metro"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "{0} kilometro", "", "", "", "{0} na kilometro"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} pulgada", "", "", "", "{0} na pulgada"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} talampakan", "", "", "", "{0} na talampakan"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}′"]],
+    [["", "{0} milya", "", "", "", "{0} na milya"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "{0} gramo", "", "", "", "{0} na gramo"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} kilo"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "{0} onsa", "", "", "", "{0} na onsa"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "{0} libra", "", "", "", "{0} na libra"], ["", "{0} lb", "", "", "", "{0} lbs"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} mililitro"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "{0} litro", "", "", "", "{0} na litro"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "{0} galon", "", "", "", "{0} na galon"], ["", "{0} gal", "", "", "", "{0} na gal"], ["", "{0} gal", "", "", "", "{0} na gal"]],
+];
+
+// to
+static N93: Names = [
+    [["", "", "", "", "", "milimita ʻe {0}"], ["", "", "", "", "", "mm ʻe {0}"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "senitimita ʻe {0}"], ["", "", "", "", "", "sm ʻe {0}"], ["", "", "", "", "", "{0} sm"]],
+    [["", "", "", "", "", "mita ʻe {0}"], ["", "", "", "", "", "m ʻe {0}"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "kilomita ʻe {0}"], ["", "", "", "", "", "km ʻe {0}"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "ʻinisi ʻe {0}"], ["", "", "", "", "", "in ʻe {0}"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "fute ʻe {0}"], ["", "", "", "", "", "ft ʻe {0}"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "maile ʻe {0}"], ["", "", "", "", "", "mi ʻe {0}"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "kalami ʻe {0}"], ["", "", "", "", "", "k ʻe {0}"], ["", "", "", "", "", "{0} k"]],
+    [["", "", "", "", "", "kilokalami ʻe {0}"], ["", "", "", "", "", "kk ʻe {0}"], ["", "", "", "", "", "{0} kk"]],
+    [["", "", "", "", "", "ʻaunisi ʻe {0}"], ["", "", "", "", "", "ʻau ʻe {0}"], ["", "", "", "", "", "{0} ʻau"]],
+    [["", "", "", "", "", "pāuni ʻe {0}"], ["", "", "", "", "", "pāu ʻe {0}"], ["", "", "", "", "", "{0} pāu"]],
+    [["", "", "", "", "", "mililita ʻe {0}"], ["", "", "", "", "", "ml ʻe {0}"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "lita ʻe {0}"], ["", "", "", "", "", "l ʻe {0}"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "kālani ʻe {0}"], ["", "", "", "", "", "kā ʻe {0}"], ["", "", "", "", "", "{0} kā"]],
+];
+
+// tr
+static N94: Names = [
+    [["", "", "", "", "", "{0} milimetre"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} santimetre"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} metre"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometre"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} inç"], ["", "", "", "", "", "{0} inç"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} fit"], ["", "", "", "", "", "{0} fit"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} ons"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} libre"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mililitre"], ["", "", "", "", "", "{0} ml"], ["", "", "", "", "", "{0} ml"]],
+    [["", "", "", "", "", "{0} litre"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} galon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} galon"]],
+];
+
+// ug
+static N95: Names = [
+    [["", "", "", "", "", "{0} مىللىمېتىر"], ["", "", "", "", "", "{0} مىللىمېتىر"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} سانتىمېتىر"], ["", "", "", "", "", "{0} سانتىمېتىر"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} مېتىر"], ["", "", "", "", "", "{0} مېتىر"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} كىلومېتىر"], ["", "", "", "", "", "{0} كىلومېتىر"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} ديۇيم"], ["", "", "", "", "", "{0} ئىنگلىز چىسى"], ["", "", "", "", "", "{0}″"]],
+    [["", "", "", "", "", "{0} فۇت"], ["", "", "", "", "", "{0} پۇت"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} مىل"], ["", "", "", "", "", "{0} مىل"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} گرام"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["", "", "", "", "", "{0} كىلوگرام"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} ئۇنسىيە"], ["", "", "", "", "", "{0} ئۇنسىيە"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} قاداق"], ["", "", "", "", "", "{0} قاداق"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} لىتېر"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0}l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// uk
+static N96: Names = [
+    [["", "{0} міліметр", "", "{0} міліметри", "{0} міліметрів", "{0} міліметра"], ["", "", "", "", "", "{0} мм"], ["", "", "", "", "", "{0}мм"]],
+    [["", "{0} сантиметр", "", "{0} сантиметри", "{0} сантиметрів", "{0} сантиметра"], ["", "", "", "", "", "{0} см"], ["", "", "", "", "", "{0}см"]],
+    [["", "{0} метр", "", "{0} метри", "{0} метрів", "{0} метра"], ["", "", "", "", "", "{0} м"], ["", "", "", "", "", "{0}м"]],
+    [["", "{0} кілометр", "", "{0} кілометри", "{0} кілометрів", "{0} кілометра"], ["", "", "", "", "", "{0} км"], ["", "", "", "", "", "{0}км"]],
+    [["", "{0} дюйм", "", "{0} дюйми", "{0} дюймів", "{0} дюйма"], ["", "{0} дюйм", "", "{0} дюйми", "{0} дюймів", "{0} дюйма"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} фут", "", "{0} фути", "{0} футів", "{0} фута"], ["", "", "", "", "", "{0} фт"], ["", "", "", "", "", "{0}фт"]],
+    [["", "{0} миля", "", "", "{0} миль", "{0} милі"], ["", "{0} миля", "", "", "{0} миль", "{0} милі"], ["", "", "", "", "", "{0}мл"]],
+    [["", "{0} грам", "", "{0} грами", "{0} грамів", "{0} грама"], ["", "", "", "", "", "{0} г"], ["", "", "", "", "", "{0}г"]],
+    [["", "{0} кілограм", "", "{0} кілограми", "{0} кілограмів", "{0} кілограма"], ["", "", "", "", "", "{0} кг"], ["", "", "", "", "", "{0}кг"]],
+    [["", "{0} унція", "", "", "{0} унцій", "{0} унції"], ["", "", "", "", "", "{0} унц."], ["", "", "", "", "", "{0}ун."]],
+    [["", "{0} фунт", "", "{0} фунти", "{0} фунтів", "{0} фунта"], ["", "", "", "", "", "{0} фнт"], ["", "", "", "", "", "{0}фнт"]],
+    [["", "{0} мілілітр", "", "{0} мілілітри", "{0} мілілітрів", "{0} мілілітра"], ["", "", "", "", "", "{0} мл"], ["", "", "", "", "", "{0}мл"]],
+    [["", "{0} літр", "", "{0} літри", "{0} літрів", "{0} літра"], ["", "", "", "", "", "{0} л"], ["", "", "", "", "", "{0}л"]],
+    [["", "{0} галон", "", "{0} галони", "{0} галонів", "{0} галона"], ["", "", "", "", "", "{0} гал."], ["", "", "", "", "", "{0}гал."]],
+];
+
+// ur
+static N97: Names = [
+    [["", "{0} ملی میٹر", "", "", "", "{0} ملیمیٹر"], ["", "{0} ملی میٹر", "", "", "", "{0} ملیمیٹر"], ["", "{0} ملی میٹر", "", "", "", "{0}ملی میٹر"]],
+    [["", "", "", "", "", "{0} سینٹی میٹر"], ["", "", "", "", "", "{0} سینٹی میٹر"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} میٹر"], ["", "", "", "", "", "{0} میٹر"], ["", "", "", "", "", "{0} میٹر"]],
+    [["", "", "", "", "", "{0} کلو میٹر"], ["", "", "", "", "", "{0} کلو میٹر"], ["", "", "", "", "", "{0} کلو میٹر"]],
+    [["", "", "", "", "", "{0} انچ"], ["", "{0} انچ", "", "", "", "{0} in"], ["", "", "", "", "", "{0} انچ"]],
+    [["", "", "", "", "", "{0} فیٹ"], ["", "", "", "", "", "{0} فیٹ"], ["", "", "", "", "", "{0}′"]],
+    [["", "", "", "", "", "{0} میل"], ["", "", "", "", "", "{0} میل"], ["", "", "", "", "", "{0} میل"]],
+    [["", "", "", "", "", "{0} گرام"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} گرام"]],
+    [["", "", "", "", "", "{0} کلو گرام"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0}kg"]],
+    [["", "", "", "", "", "{0} اونس"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} ونس"]],
+    [["", "", "", "", "", "{0} پونڈ"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} پونڈ"]],
+    [["", "", "", "", "", "{0} ملی لیٹر"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} لیٹر"], ["", "", "", "", "", "{0} لیٹر"], ["", "", "", "", "", "{0} لیٹر"]],
+    [["", "", "", "", "", "{0} گیلن"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// uz
+static N98: Names = [
+    [["", "", "", "", "", "{0} millimetr"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} santimetr"], ["", "", "", "", "", "{0} sm"], ["", "", "", "", "", "{0} sm"]],
+    [["", "", "", "", "", "{0} metr"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometr"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} duym"], ["", "", "", "", "", "{0} dy"], ["", "", "", "", "", "{0} duym"]],
+    [["", "", "", "", "", "{0} fut"], ["", "", "", "", "", "{0} fut"], ["", "", "", "", "", "{0} fut"]],
+    [["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"], ["", "", "", "", "", "{0} mil"]],
+    [["", "", "", "", "", "{0} gramm"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogramm"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} unsiya"], ["", "", "", "", "", "{0} unsiya"], ["", "", "", "", "", "{0} unsiya"]],
+    [["", "", "", "", "", "{0} funt"], ["", "", "", "", "", "{0} funt"], ["", "", "", "", "", "{0} funt"]],
+    [["", "", "", "", "", "{0} millilitr"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} litr"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// vi
+static N99: Names = [
+    [["", "", "", "", "", "{0} milimét"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["", "", "", "", "", "{0} xentimét"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["", "", "", "", "", "{0} mét"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["", "", "", "", "", "{0} kilômét"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "", "", "", "", "{0} inch"], ["", "", "", "", "", "{0} inch"], ["", "", "", "", "", "{0}\""]],
+    [["", "", "", "", "", "{0} feet"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0}'"]],
+    [["", "", "", "", "", "{0} dặm"], ["", "", "", "", "", "{0} dặm"], ["", "", "", "", "", "{0} dặm"]],
+    [["", "", "", "", "", "{0} gam"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilôgam"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} aoxơ"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} pao"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}lb"]],
+    [["", "", "", "", "", "{0} mililít"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} lít"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0}L"]],
+    [["", "", "", "", "", "{0} gallon"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"]],
+];
+
+// cy
+static N100: Names = [
+    [["{0} mm", "", "{0} filimetr", "{0} mm", "{0} mm", "{0} milimetr"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0}mm"]],
+    [["{0} cm", "", "{0} cm", "{0} cm", "{0} cm", "{0} centimetr"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0}cm"]],
+    [["{0} m", "", "{0} m", "{0} m", "{0} m", "{0} metr"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0}m"]],
+    [["{0} km", "", "{0} km", "{0} km", "{0} km", "{0} cilometr"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0}km"]],
+    [["", "{0} fodfedd", "{0} fodfedd", "", "", "{0} modfedd"], ["", "{0} fodfedd", "{0} fodfedd", "", "", "{0} modfedd"], ["", "", "", "", "", "{0}″"]],
+    [["", "{0} droedfedd", "{0} droedfedd", "", "{0} throedfedd", "{0} troedfedd"], ["", "", "", "", "", "{0}′"], ["", "", "", "", "", "{0}′"]],
+    [["{0} mi", "{0} filltir", "{0} filltir", "", "", "{0} milltir"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["{0} g", "", "{0} g", "{0} g", "{0} g", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0}g"]],
+    [["{0} kg", "", "{0} kg", "{0} kg", "{0} kg", "{0} cilogram"], ["", "", "", "", "", "{0} kg"], ["{0} kg", "{0} kg", "{0} kg", "{0} kg", "{0} kg", "{0}kg"]],
+    [["", "", "", "", "", "{0} owns"], ["", "", "", "", "", "{0} owns"], ["", "", "", "", "", "{0}owns"]],
+    [["", "", "{0} bwys", "{0} phwys", "{0} phwys", "{0} pwys"], ["", "", "{0} bwys", "{0} phwys", "{0} phwys", "{0} pwys"], ["", "", "{0}bwys", "{0}phwys", "{0}phwys", "{0}pwys"]],
+    [["{0} mL", "", "{0} mL", "{0} mL", "{0} mL", "{0} mililitr"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "{0} L", "{0} L", "{0} litr"], ["", "", "", "", "", "{0} L"], ["", "", "", "", "", "{0} L"]],
+    [["", "", "{0} alwyn", "", "", "{0} galwyn"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// fy
+static N101: Names = [
+    [["", "", "", "", "", "{0} millimeter"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} sentimeter"], ["", "", "", "", "", "{0} sm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} meter"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} kilometer"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "{0} tomme", "", "", "", "{0} tommen"], ["", "", "", "", "", "{0} tm"], ["", "", "", "", "", "{0}\""]],
+    [["", "", "", "", "", "{0} foet"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} myl"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} gram"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kilogram"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"]],
+    [["", "", "", "", "", "{0} liter"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"]],
+];
+
+// yo
+static N102: Names = [
+    [["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0}mL"]],
+    [["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0} gal US"], ["", "", "", "", "", "{0}gal"]],
+];
+
+// zu
+static N103: Names = [
+    [["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"], ["", "", "", "", "", "{0} mm"]],
+    [["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"], ["", "", "", "", "", "{0} cm"]],
+    [["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} m"]],
+    [["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"], ["", "", "", "", "", "{0} km"]],
+    [["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"], ["", "", "", "", "", "{0} in"]],
+    [["", "", "", "", "", "{0} ft"], ["", "", "", "", "", "{0} ft"], ["", "{0}′", "", "", "", "{0} ft"]],
+    [["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0} mi"], ["", "", "", "", "", "{0}mi"]],
+    [["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"], ["", "", "", "", "", "{0} g"]],
+    [["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"], ["", "", "", "", "", "{0} kg"]],
+    [["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0} oz"], ["", "", "", "", "", "{0}oz"]],
+    [["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0} lb"], ["", "", "", "", "", "{0}#"]],
+    [["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0} mL"], ["", "", "", "", "", "{0}cL"]],
+    [["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"], ["", "", "", "", "", "{0} l"]],
+    [["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0} gal"], ["", "", "", "", "", "{0}gal"]],
+];
+
+/// The names of every language, indexed by the `LangCode` discriminant.
+pub(super) static LANGUAGES: [Option<&Names>; 184] = [
+    /* aa */ None,
+    /* ab */ None,
+    /* af */ Some(&N0),
+    /* ak */ Some(&N1),
+    /* sq */ Some(&N2),
+    /* am */ Some(&N3),
+    /* ar */ Some(&N4),
+    /* an */ None,
+    /* hy */ Some(&N5),
+    /* as */ Some(&N6),
+    /* av */ None,
+    /* ae */ None,
+    /* ay */ None,
+    /* az */ Some(&N7),
+    /* bm */ None,
+    /* ba */ Some(&N8),
+    /* eu */ Some(&N9),
+    /* be */ Some(&N10),
+    /* bn */ Some(&N11),
+    /* bh */ None,
+    /* bi */ None,
+    /* bs */ Some(&N12),
+    /* br */ Some(&N13),
+    /* bg */ Some(&N14),
+    /* my */ Some(&N15),
+    /* ca */ Some(&N16),
+    /* ch */ None,
+    /* ce */ None,
+    /* ny */ None,
+    /* zh */ Some(&N17),
+    /* cv */ Some(&N18),
+    /* kw */ None,
+    /* co */ None,
+    /* cr */ None,
+    /* hr */ Some(&N19),
+    /* cs */ Some(&N20),
+    /* da */ Some(&N21),
+    /* dv */ None,
+    /* nl */ Some(&N22),
+    /* dz */ None,
+    /* en */ Some(&N23),
+    /* eo */ Some(&N24),
+    /* et */ Some(&N25),
+    /* ee */ None,
+    /* fo */ Some(&N26),
+    /* fj */ None,
+    /* fi */ Some(&N27),
+    /* fr */ Some(&N28),
+    /* ff */ None,
+    /* gl */ Some(&N29),
+    /* ka */ Some(&N30),
+    /* de */ Some(&N31),
+    /* el */ Some(&N32),
+    /* gn */ None,
+    /* gu */ Some(&N33),
+    /* ht */ Some(&N34),
+    /* ha */ Some(&N35),
+    /* he */ Some(&N36),
+    /* hz */ None,
+    /* hi */ Some(&N37),
+    /* ho */ None,
+    /* hu */ Some(&N38),
+    /* ia */ Some(&N39),
+    /* id */ Some(&N40),
+    /* ie */ None,
+    /* ga */ Some(&N41),
+    /* ig */ Some(&N42),
+    /* ik */ None,
+    /* io */ None,
+    /* is */ Some(&N43),
+    /* it */ Some(&N44),
+    /* iu */ None,
+    /* ja */ Some(&N45),
+    /* jv */ Some(&N46),
+    /* kl */ None,
+    /* kn */ Some(&N47),
+    /* kr */ None,
+    /* ks */ None,
+    /* kk */ Some(&N48),
+    /* km */ Some(&N49),
+    /* ki */ None,
+    /* rw */ None,
+    /* ky */ Some(&N50),
+    /* kv */ None,
+    /* kg */ None,
+    /* ko */ Some(&N51),
+    /* ku */ Some(&N52),
+    /* kj */ None,
+    /* la */ None,
+    /* lb */ Some(&N53),
+    /* lg */ None,
+    /* li */ None,
+    /* ln */ None,
+    /* lo */ Some(&N54),
+    /* lt */ Some(&N55),
+    /* lu */ None,
+    /* lv */ Some(&N56),
+    /* gv */ None,
+    /* mk */ Some(&N57),
+    /* mg */ None,
+    /* ms */ Some(&N58),
+    /* ml */ Some(&N59),
+    /* mt */ None,
+    /* mi */ Some(&N60),
+    /* mr */ Some(&N61),
+    /* mh */ None,
+    /* mn */ Some(&N62),
+    /* na */ None,
+    /* nv */ None,
+    /* nd */ None,
+    /* ne */ Some(&N63),
+    /* ng */ None,
+    /* nb */ Some(&N64),
+    /* nn */ Some(&N65),
+    /* no */ Some(&N64),
+    /* ii */ None,
+    /* nr */ None,
+    /* oc */ None,
+    /* oj */ None,
+    /* cu */ None,
+    /* om */ None,
+    /* or */ Some(&N66),
+    /* os */ None,
+    /* pa */ Some(&N67),
+    /* pi */ None,
+    /* fa */ Some(&N68),
+    /* pl */ Some(&N69),
+    /* ps */ Some(&N70),
+    /* pt */ Some(&N71),
+    /* qu */ Some(&N72),
+    /* rm */ Some(&N73),
+    /* rn */ None,
+    /* ro */ Some(&N74),
+    /* ru */ Some(&N75),
+    /* sa */ None,
+    /* sc */ Some(&N76),
+    /* sd */ Some(&N77),
+    /* se */ None,
+    /* sm */ None,
+    /* sg */ None,
+    /* sr */ Some(&N78),
+    /* gd */ Some(&N79),
+    /* sn */ None,
+    /* si */ Some(&N80),
+    /* sk */ Some(&N81),
+    /* sl */ Some(&N82),
+    /* so */ Some(&N83),
+    /* st */ None,
+    /* es */ Some(&N84),
+    /* su */ None,
+    /* sw */ Some(&N85),
+    /* ss */ None,
+    /* sv */ Some(&N86),
+    /* ta */ Some(&N87),
+    /* te */ Some(&N88),
+    /* tg */ None,
+    /* th */ Some(&N89),
+    /* ti */ Some(&N90),
+    /* bo */ None,
+    /* tk */ Some(&N91),
+    /* tl */ Some(&N92),
+    /* tn */ None,
+    /* to */ Some(&N93),
+    /* tr */ Some(&N94),
+    /* ts */ None,
+    /* tt */ None,
+    /* tw */ None,
+    /* ty */ None,
+    /* ug */ Some(&N95),
+    /* uk */ Some(&N96),
+    /* ur */ Some(&N97),
+    /* uz */ Some(&N98),
+    /* ve */ None,
+    /* vi */ Some(&N99),
+    /* vo */ None,
+    /* wa */ None,
+    /* cy */ Some(&N100),
+    /* wo */ None,
+    /* fy */ Some(&N101),
+    /* xh */ None,
+    /* yi */ None,
+    /* yo */ Some(&N102),
+    /* za */ None,
+    /* zu */ Some(&N103),
+];
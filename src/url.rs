@@ -1,55 +1,61 @@
 #![warn(dead_code)]
 
 use crate::error::Error;
-use rocket::Request;
 
 use crate::LangCode;
 
-fn minus_one_optimization(req: &Request) -> Option<LangCode> {
-    return req
-        .uri()
-        .path()
-        .segments()
-        .last()?
-        .parse()
-        .ok();
+/// Returns the language code at `pos` in the path.
+pub(crate) fn get(path: &str, pos: i32) -> Result<LangCode, Error> {
+    let segments: Vec<_> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    index(segments.len(), pos)
+        .and_then(|index| segments[index].parse().ok())
+        .ok_or(Error::NotFound)
 }
 
-fn parse_negative(req: &Request, pos: i32) -> Option<LangCode> {
-    if pos == -1 {
-        return minus_one_optimization(req);
-    }
-    let total = req
-        .uri()
-        .path()
-        .segments()
-        .count();
-
-    req.uri()
-        .path()
-        .segments()
-        .nth(total.checked_sub(pos.abs() as usize)?)
-        .unwrap() // we can unwrap this because the checked subtraction
-        .parse()
-        .ok()
-}
-fn parse_positive(req: &Request, pos: i32) -> Option<LangCode> {
-    req.uri()
-        .path()
-        .segments()
-        .nth(pos as usize)?
-        .parse()
-        .ok()
+/// Returns the index of the segment at `pos` in a path with `total` segments.
+fn index(total: usize, pos: i32) -> Option<usize> {
+    let index = if pos.is_negative() {
+        total.checked_sub(pos.unsigned_abs() as usize)?
+    } else {
+        pos as usize
+    };
+    (index < total).then_some(index)
 }
 
-fn parse(req: &Request, pos: i32) -> Option<LangCode> {
-    if pos.is_negative() {
-        parse_negative(req, pos)
+/// Replaces the language segment at `pos` in `path`,
+/// keeping the rest of the path untouched.
+/// Returns `None` if the segment at `pos` is not a language code.
+pub(crate) fn replace(path: &str, pos: i32, lang: LangCode) -> Option<String> {
+    let mut segments: Vec<_> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let index = index(segments.len(), pos)?;
+    segments[index]
+        .parse::<LangCode>()
+        .ok()?;
+    segments[index] = lang.as_str();
+    let trailing = if path.ends_with('/') && !segments.is_empty() {
+        "/"
     } else {
-        parse_positive(req, pos)
-    }
+        ""
+    };
+    Some(format!("/{}{trailing}", segments.join("/")))
 }
 
-pub(crate) fn get(req: &Request<'_>, pos: i32) -> Result<LangCode, Error> {
-    parse(req, pos).ok_or(Error::NotFound)
+/// Inserts a language segment into a path without one, so that
+/// it ends up at `pos` once inserted.
+/// Returns `None` if the path is too short for that position.
+pub(crate) fn insert(path: &str, pos: i32, lang: LangCode) -> Option<String> {
+    let mut segments: Vec<_> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let index = index(segments.len() + 1, pos)?;
+    segments.insert(index, lang.as_str());
+    let trailing = if path.ends_with('/') { "/" } else { "" };
+    Some(format!("/{}{trailing}", segments.join("/")))
 }
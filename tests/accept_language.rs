@@ -1,3 +1,4 @@
+#![cfg(feature = "rocket")]
 use rocket::{
     http::{Header, Status},
     local::asynchronous::{Client, LocalResponse},
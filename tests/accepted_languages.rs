@@ -0,0 +1,73 @@
+#![cfg(feature = "rocket")]
+use rocket::{get, http::Header, local::asynchronous::Client, routes};
+use rocket_lang::*;
+
+#[get("/")]
+fn ranked(accepted: AcceptedLanguages) -> String {
+    accepted
+        .languages()
+        .map(|lang| lang.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[test]
+fn sorted_by_quality() {
+    let accepted = AcceptedLanguages::new("fr;q=0.5, de, es;q=0.8", None);
+    let langs: Vec<_> = accepted.languages().collect();
+    assert_eq!(langs, [De, Es, Fr]);
+    assert_eq!(accepted.quality(Es), Some(0.8));
+    assert_eq!(accepted.quality(En), None);
+}
+
+#[test]
+fn ties_keep_header_order() {
+    let accepted = AcceptedLanguages::new("it;q=0.5, fr;q=0.5, de, es;q=0.5", None);
+    let langs: Vec<_> = accepted.languages().collect();
+    assert_eq!(langs, [De, It, Fr, Es]);
+}
+
+#[test]
+fn duplicates_and_rejected() {
+    let accepted = AcceptedLanguages::new("en-US;q=0.4, en-GB, de;q=0, es;q=0.6", None);
+    assert_eq!(
+        accepted
+            .iter()
+            .collect::<Vec<_>>(),
+        [(En, 1.0), (Es, 0.6)]
+    );
+}
+
+#[test]
+fn intersected_with_config() {
+    let mut config = Config::new();
+    config[Es] = 1.0;
+    config[Fr] = 0.2;
+    let accepted = AcceptedLanguages::new("de, fr;q=0.9, pt, es;q=0.5", Some(&config));
+    assert_eq!(accepted.len(), 2);
+    assert_eq!(accepted.get(0), Some(Fr));
+    assert_eq!(accepted.get(1), Some(Es));
+    assert_eq!(accepted.get(2), None);
+    assert!(AcceptedLanguages::new("de", Some(&config)).is_empty());
+}
+
+#[tokio::test]
+async fn request_guard() {
+    let mut config = Config::new();
+    config[En] = 1.0;
+    config[Es] = 1.0;
+    let rocket = rocket::build()
+        .mount("/", routes![ranked])
+        .attach(config);
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    let body = client
+        .get("/")
+        .header(Header::new("accept-language", "de, es;q=0.7, en;q=0.7"))
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.unwrap(), "es,en");
+}
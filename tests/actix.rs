@@ -0,0 +1,128 @@
+#![cfg(feature = "actix")]
+use actix_web::{
+    dev::{Service, ServiceResponse},
+    http::{header::CONTENT_LANGUAGE, StatusCode},
+    test, web, App, HttpRequest,
+};
+use rocket_lang::{middleware::LangMiddleware, source::LangSource, *};
+
+fn country(source: &dyn LangSource) -> Result<LangCode, Error> {
+    match source.cookie("country") {
+        Some("mx") => Ok(Es),
+        _ => Err(Error::NotAcceptable),
+    }
+}
+
+fn config() -> Config {
+    Config::new()
+        .resolver(country)
+        .cookie("lang")
+        .supported(&[En, Es])
+        .scope("/api", Config::new().wildcard(En))
+}
+
+async fn index(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+async fn call(req: test::TestRequest) -> ServiceResponse {
+    let app = test::init_service(
+        App::new()
+            .wrap(LangMiddleware::new(config()))
+            .route("/", web::get().to(index))
+            .route("/api/users", web::get().to(index))
+            .route("/asset", web::get().to(|| async { "asset" }))
+            .route(
+                "/resolve",
+                web::get().to(|req: HttpRequest| async move {
+                    middleware::resolve(&req)
+                        .unwrap()
+                        .to_string()
+                }),
+            ),
+    )
+    .await;
+    app.call(req.to_request())
+        .await
+        .unwrap()
+}
+
+fn request(path: &str, header: &str) -> test::TestRequest {
+    test::TestRequest::get()
+        .uri(path)
+        .insert_header(("accept-language", header))
+}
+
+#[actix_web::test]
+async fn extractor() {
+    let response = call(request("/", "de, es;q=0.5")).await;
+    assert_eq!(
+        response
+            .headers()
+            .get(CONTENT_LANGUAGE)
+            .unwrap(),
+        "es"
+    );
+    assert_eq!(test::read_body(response).await, "es");
+
+    let response = call(request("/api/users", "es")).await;
+    assert_eq!(test::read_body(response).await, "en");
+}
+
+#[actix_web::test]
+async fn cookie() {
+    let request = test::TestRequest::get()
+        .uri("/")
+        .insert_header(("cookie", "lang=es"));
+    let response = call(request).await;
+    assert_eq!(test::read_body(response).await, "es");
+}
+
+#[actix_web::test]
+async fn not_acceptable() {
+    let response = call(request("/", "de")).await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    assert!(response
+        .headers()
+        .get(CONTENT_LANGUAGE)
+        .is_none());
+}
+
+#[actix_web::test]
+async fn unused_language_is_not_resolved() {
+    let response = call(request("/asset", "es")).await;
+    assert!(response
+        .headers()
+        .get(CONTENT_LANGUAGE)
+        .is_none());
+}
+
+#[actix_web::test]
+async fn resolve() {
+    let response = call(request("/resolve", "es")).await;
+    assert_eq!(
+        response
+            .headers()
+            .get(CONTENT_LANGUAGE)
+            .unwrap(),
+        "es"
+    );
+    assert_eq!(test::read_body(response).await, "es");
+}
+
+#[actix_web::test]
+async fn missing_middleware() {
+    let app = test::init_service(App::new().route("/", web::get().to(index))).await;
+    let response = app
+        .call(request("/", "es").to_request())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn resolver() {
+    let request = request("/", "en").insert_header(("cookie", "country=mx"));
+    let response = call(request).await;
+    assert_eq!(test::read_body(response).await, "es");
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "rocket")]
+mod common;
+use common::*;
+use rocket::local::asynchronous::Client;
+use rocket_lang::alternates::{AlternateLinks, Alternates};
+
+fn config() -> Config {
+    let mut config = Config::new()
+        .base_url("https://example.com/")
+        .url(0)
+        .wildcard(En);
+    config[En] = 1.0;
+    config[Es] = 0.8;
+    config[Fr] = 0.1;
+    config
+}
+
+#[test]
+fn alternates_for_path() {
+    let alternates = Alternates::new(&config(), "/es/some/page/");
+    let links: Vec<_> = alternates
+        .iter()
+        .map(|alt| (alt.hreflang.as_str(), alt.href.as_str()))
+        .collect();
+    assert_eq!(
+        links,
+        [
+            ("en", "https://example.com/en/some/page/"),
+            ("fr", "https://example.com/fr/some/page/"),
+            ("es", "https://example.com/es/some/page/"),
+            ("x-default", "https://example.com/en/some/page/"),
+        ]
+    );
+    assert!(alternates.html().starts_with(
+        "<link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/some/page/\">\n"
+    ));
+}
+
+#[test]
+fn alternates_negative_position() {
+    let mut config = Config::new()
+        .base_url("http://localhost:8000")
+        .url(-1);
+    config[De] = 1.0;
+    let alternates = Alternates::new(&config, "/docs/intro/fr");
+    assert_eq!(
+        alternates
+            .iter()
+            .map(|alt| alt.href.as_str())
+            .collect::<Vec<_>>(),
+        ["http://localhost:8000/docs/intro/de"]
+    );
+}
+
+#[test]
+fn alternates_without_language_segment() {
+    assert!(Alternates::new(&config(), "/static/style.css").is_empty());
+    assert!(Alternates::new(&config(), "/").is_empty());
+    assert!(Alternates::new(&Config::new(), "/es/page").is_empty());
+    let no_base = Config::new()
+        .url(0)
+        .supported(&[En]);
+    assert!(Alternates::new(&no_base, "/es/page").is_empty());
+}
+
+#[test]
+fn alternates_escaping() {
+    let alternates = Alternates::new(&config(), r#"/es/a"b>c,d"#);
+    assert!(alternates
+        .html()
+        .starts_with(
+            "<link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/a&quot;b&gt;c,d\">\n"
+        ));
+    assert!(alternates
+        .link_value()
+        .unwrap()
+        .starts_with(
+            "<https://example.com/en/a%22b%3Ec%2Cd>; rel=\"alternate\"; hreflang=\"en\", "
+        ));
+}
+
+#[tokio::test]
+async fn alternates_fairing() {
+    let rocket = rocket::build()
+        .mount("/", rocket::routes![])
+        .attach(config())
+        .attach(AlternateLinks);
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    let res = client
+        .get("/fr/about")
+        .dispatch()
+        .await;
+    assert_eq!(
+        res.headers().get_one("Link"),
+        Some(
+            "<https://example.com/en/about>; rel=\"alternate\"; hreflang=\"en\", \
+             <https://example.com/fr/about>; rel=\"alternate\"; hreflang=\"fr\", \
+             <https://example.com/es/about>; rel=\"alternate\"; hreflang=\"es\", \
+             <https://example.com/en/about>; rel=\"alternate\"; hreflang=\"x-default\""
+        )
+    );
+    let res = client
+        .get("/favicon.ico")
+        .dispatch()
+        .await;
+    assert_eq!(res.headers().get_one("Link"), None);
+}
@@ -0,0 +1,33 @@
+use rocket_lang::*;
+
+#[test]
+fn languages() {
+    let config = Config::new().languages([(En, 1.0), (Es, 0.8), (Pt, 0.2)]);
+    assert_eq!(config[En], 1.0);
+    assert_eq!(config[Es], 0.8);
+    assert_eq!(config[Pt], 0.2);
+    assert_eq!(config[De], 0.0);
+    assert_eq!(config.supported_languages().len(), 3);
+}
+
+#[test]
+fn supported() {
+    let config = Config::new()
+        .supported(&[En, Es, Fr])
+        .languages([(Es, 0.5)]);
+    assert_eq!(config[En], 1.0);
+    assert_eq!(config[Es], 0.5);
+    assert_eq!(config[Fr], 1.0);
+    assert_eq!(config.supported_languages(), [En, Fr, Es]);
+}
+
+#[test]
+fn every_code_has_its_own_quality() {
+    let mut config = Config::new();
+    for (i, &lang) in LangCode::ALL_CODES.iter().enumerate() {
+        config[lang] = i as f32;
+    }
+    for (i, &lang) in LangCode::ALL_CODES.iter().enumerate() {
+        assert_eq!(config[lang], i as f32);
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "collation")]
+use rocket_lang::{
+    collation::{compare, sort, Collator},
+    *,
+};
+use std::cmp::Ordering::*;
+
+#[test]
+fn root() {
+    let mut words = [
+        "résumé", "Resume", "resume", "rèsumé", "résume", "re-sume", "10", "2",
+    ];
+    sort(En, &mut words);
+    assert_eq!(
+        words,
+        ["10", "2", "re-sume", "resume", "Resume", "résume", "résumé", "rèsumé"]
+    );
+    assert_eq!(compare(De, "Straße", "Strasse"), Greater);
+    assert_eq!(compare(De, "Straße", "Strassen"), Less);
+    assert_eq!(compare(En, "Æsir", "Aesir"), Greater);
+    assert_eq!(compare(En, "Æsir", "Azure"), Less);
+    assert_eq!(compare(En, "Æsir", "Bee"), Less);
+    assert_eq!(compare(En, "zoo", "Ωμέγα"), Less);
+    assert_eq!(compare(Ru, "ёлка", "ель"), Less);
+    assert_eq!(compare(Ru, "ёж", "еж"), Greater);
+    assert_eq!(compare(Ru, "ёлка", "есть"), Less);
+    // katakana and hiragana only differ at the quaternary level.
+    assert_eq!(compare(Ja, "カ", "か"), Equal);
+    assert_eq!(compare(Ja, "が", "き"), Less);
+}
+
+#[test]
+fn tailorings() {
+    let spanish = Collator::new(Es);
+    assert_eq!(spanish.compare("cañón", "canto"), Greater);
+    assert_eq!(spanish.compare("Ñu", "nube"), Greater);
+
+    let mut words = ["Ærø", "Aabenraa", "Odense", "Øster", "Zealand"];
+    sort(Da, &mut words);
+    assert_eq!(words, ["Odense", "Zealand", "Ærø", "Øster", "Aabenraa"]);
+
+    assert_eq!(compare(Sv, "Müller", "Myra"), Less);
+    assert_eq!(compare(Sv, "Mæ", "Må"), Greater);
+    assert_eq!(compare(Cs, "chata", "hrad"), Greater);
+    assert_eq!(compare(Cs, "chata", "ivo"), Less);
+    assert_eq!(compare(Pl, "łódź", "lwy"), Greater);
+    assert_eq!(compare(Tr, "ılık", "ilik"), Less);
+    assert_eq!(compare(Hr, "ljubav", "lutka"), Greater);
+    assert_eq!(compare(En, "ljubav", "lutka"), Less);
+}
+
+#[test]
+fn languages_by_name() {
+    let mut config = Config::new();
+    config[Sv] = 1.0;
+    config[De] = 0.5;
+    config["fr-CA"] = 1.0;
+    assert_eq!(config.languages_by_name(Sv), [Fr, Sv, De]);
+    assert_eq!(config.languages_by_name(De), [De, Fr, Sv]);
+    assert_eq!(config.languages_by_name(Ja), [Sv, De, Fr]);
+}
+
+#[test]
+fn generated_tailorings() {
+    assert_eq!(compare(Et, "zoo", "tee"), Less);
+    assert_eq!(compare(En, "zoo", "tee"), Greater);
+    assert_eq!(compare(Lt, "yra", "jis"), Less);
+    assert_eq!(compare(Tl, "ngipin", "nuno"), Greater);
+}
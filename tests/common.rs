@@ -1,3 +1,6 @@
+#![cfg(feature = "rocket")]
+// rocket's route attributes emit macro re-exports that are unused in some tests
+#![allow(unused_imports)]
 use rocket::local::asynchronous::Client;
 use rocket::{get, routes};
 pub use rocket_lang::Config;
@@ -18,6 +21,7 @@ fn nothing(lang: LangCode) -> &'static str {
     lang.as_str()
 }
 
+#[allow(dead_code)]
 pub async fn configured(config: Config) -> Client {
     let rocket = rocket::build()
         .mount("/namespaced", routes![])
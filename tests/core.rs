@@ -0,0 +1,122 @@
+use rocket_lang::{
+    source::{LangSource, Parts},
+    *,
+};
+
+fn config() -> Config {
+    Config::new()
+        .url(0)
+        .cookie("lang")
+        .supported(&[En, Es])
+        .scope("/api", Config::new().supported(&[En, Fr]))
+}
+
+#[test]
+fn plain_parts() {
+    let config = config();
+    assert_eq!(
+        config
+            .negotiate(&Parts::new("/es/home"))
+            .unwrap(),
+        Es
+    );
+    let parts = Parts::new("/home/page")
+        .cookies("theme=dark; lang=es")
+        .accept_language("en");
+    assert_eq!(
+        config
+            .negotiate(&parts)
+            .unwrap(),
+        Es
+    );
+    // unsupported languages in the cookie are ignored
+    let parts = Parts::new("/home/page")
+        .cookies("theme=dark; lang=fr")
+        .accept_language("en");
+    assert_eq!(
+        config
+            .negotiate(&parts)
+            .unwrap(),
+        En
+    );
+    let parts = Parts::new("/home/page").accept_language("de, es;q=0.5");
+    assert_eq!(
+        config
+            .negotiate(&parts)
+            .unwrap(),
+        Es
+    );
+    let error = config
+        .negotiate(&Parts::new("/home/page").accept_language("de"))
+        .unwrap_err();
+    // the url error takes precedence
+    assert_eq!(error.status_code(), 404);
+}
+
+fn country(source: &dyn LangSource) -> Result<LangCode, Error> {
+    match source.cookie("country") {
+        Some("mx") => Ok(Es),
+        _ => Err(Error::NotAcceptable),
+    }
+}
+
+#[test]
+fn resolver() {
+    let config = config().resolver(country);
+    let parts = Parts::new("/fr/home").cookies("country=mx");
+    assert_eq!(
+        config
+            .negotiate(&parts)
+            .unwrap(),
+        Es
+    );
+
+    // if the resolver fails, the url is used
+    let parts = Parts::new("/fr/home").cookies("country=us");
+    assert_eq!(
+        config
+            .negotiate(&parts)
+            .unwrap(),
+        Fr
+    );
+}
+
+#[test]
+fn scopes() {
+    let parts = Parts::new("/api/users").accept_language("fr, es;q=0.5");
+    assert_eq!(
+        config()
+            .negotiate(&parts)
+            .unwrap(),
+        Fr
+    );
+}
+
+#[test]
+fn http_request() {
+    let request = http::Request::builder()
+        .uri("https://example.com/home?page=1")
+        .header("accept-language", "de, es;q=0.8")
+        .header("cookie", "theme=dark")
+        .body(())
+        .unwrap();
+    assert_eq!(
+        config()
+            .negotiate(&request)
+            .unwrap(),
+        Es
+    );
+
+    let request = http::Request::builder()
+        .uri("/home")
+        .header("cookie", "theme=dark")
+        .header("cookie", "lang=\"en\"")
+        .body(())
+        .unwrap();
+    assert_eq!(
+        config()
+            .negotiate(&request)
+            .unwrap(),
+        En
+    );
+}
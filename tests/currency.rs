@@ -0,0 +1,90 @@
+#![cfg(feature = "currency")]
+use rocket_lang::{
+    currency::{format_currency, CurrencyDisplay, CurrencyFormat},
+    *,
+};
+
+#[test]
+fn symbol_placement() {
+    assert_eq!(format_currency(En, 1234.56, "USD"), "$1,234.56");
+    assert_eq!(
+        format_currency(Fr, 1234.56, "EUR"),
+        "1\u{202F}234,56\u{A0}€"
+    );
+    assert_eq!(format_currency(Pt, 1234.56, "BRL"), "R$\u{A0}1.234,56");
+    assert_eq!(format_currency(Nl, -1234.56, "EUR"), "€\u{A0}-1.234,56");
+    assert_eq!(format_currency(Hi, 1234567.0, "INR"), "₹12,34,567.00");
+}
+
+#[test]
+fn local_symbols() {
+    assert_eq!(format_currency(Es, 10.0, "USD"), "10,00\u{A0}US$");
+    assert_eq!(format_currency(Fr, 10.0, "USD"), "10,00\u{A0}$US");
+    assert_eq!(format_currency(Ru, 10.0, "RUB"), "10,00\u{A0}₽");
+    assert_eq!(format_currency(Zh, 10.0, "CNY"), "¥10.00");
+    assert_eq!(format_currency(En, 10.0, "CNY"), "CN¥10.00");
+    assert_eq!(
+        CurrencyFormat::with_region(En, "CA", "CAD").format(10.0),
+        "$10.00"
+    );
+    assert_eq!(
+        CurrencyFormat::with_region(En, "CA", "USD").format(10.0),
+        "US$10.00"
+    );
+}
+
+#[test]
+fn displays() {
+    let format = CurrencyFormat::new(En, "SEK");
+    assert_eq!(format.symbol(), "SEK");
+    assert_eq!(format.format(5.0), "SEK\u{A0}5.00");
+    let narrow = format
+        .clone()
+        .display(CurrencyDisplay::Narrow);
+    assert_eq!(narrow.format(5.0), "kr\u{A0}5.00");
+    let code = CurrencyFormat::new(De, "eur").display(CurrencyDisplay::Code);
+    assert_eq!(code.format(5.0), "5,00\u{A0}EUR");
+}
+
+#[test]
+fn fraction_digits() {
+    assert_eq!(format_currency(En, 1234.6, "KRW"), "₩1,235");
+    assert_eq!(format_currency(En, 1.2346, "KWD"), "KWD\u{A0}1.235");
+    assert_eq!(format_currency(En, 1.0, "XYZ"), "XYZ\u{A0}1.00");
+}
+
+#[test]
+fn accounting() {
+    let format = CurrencyFormat::new(En, "USD").accounting();
+    assert_eq!(format.format(5.0), "$5.00");
+    assert_eq!(format.format(-5.0), "($5.00)");
+    let format = CurrencyFormat::new(De, "EUR").accounting();
+    assert_eq!(format.format(-5.0), "-5,00\u{A0}€");
+    let format = CurrencyFormat::new(Fr, "EUR").accounting();
+    assert_eq!(format.format(-5.0), "(5,00\u{A0}€)");
+    let format = CurrencyFormat::new(Ja, "JPY").accounting();
+    assert_eq!(format.format(-5.0), "(￥5)");
+    let swiss = CurrencyFormat::with_region(De, "CH", "CHF");
+    assert_eq!(swiss.format(-1234.5), "CHF-1'234.50");
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(format_currency(Sw, 5.0, "KES"), "Ksh\u{A0}5.00");
+    assert_eq!(format_currency(Uk, 5.0, "UAH"), "5,00\u{A0}₴");
+    assert_eq!(format_currency(Nb, -5.0, "NOK"), "−5,00\u{A0}kr");
+    assert_eq!(
+        format_currency(He, -5.0, "ILS"),
+        "\u{200F}\u{200E}-5.00\u{A0}\u{200F}₪"
+    );
+    assert_eq!(
+        CurrencyFormat::with_region(Fr, "CA", "CAD").format(1234.5),
+        "1\u{A0}234,50\u{A0}$"
+    );
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(format_currency(Aa, 5.0, "EUR"), "€\u{A0}5.00");
+    assert_eq!(format_currency(Aa, 5.0, "XYZ"), "XYZ\u{A0}5.00");
+}
@@ -0,0 +1,161 @@
+use rocket_lang::{
+    dates::{
+        date_pattern, fields_pattern, first_day_of_week, format_date, format_fields, format_time,
+        month_name, weekday_name, Fields, Length, Weekday, Width,
+    },
+    *,
+};
+
+const DATE: (i32, u8, u8) = (2024, 3, 9);
+
+#[test]
+fn lengths() {
+    let formats = [
+        (Length::Full, "Saturday, March 9, 2024"),
+        (Length::Long, "March 9, 2024"),
+        (Length::Medium, "Mar 9, 2024"),
+        (Length::Short, "3/9/24"),
+    ];
+    for (length, expected) in formats {
+        assert_eq!(format_date(En, &DATE, length), expected);
+    }
+    assert_eq!(date_pattern(Fr, Length::Short), "dd/MM/y");
+}
+
+#[test]
+fn languages() {
+    assert_eq!(format_date(Fr, &DATE, Length::Full), "samedi 9 mars 2024");
+    assert_eq!(format_date(Pt, &DATE, Length::Medium), "9 de mar. de 2024");
+    assert_eq!(
+        format_date(Ru, &DATE, Length::Long),
+        "9 марта 2024\u{202F}г."
+    );
+    assert_eq!(format_date(Zh, &DATE, Length::Full), "2024年3月9日星期六");
+    assert_eq!(format_date(Ko, &DATE, Length::Short), "24. 3. 9.");
+    assert_eq!(format_date(Ar, &DATE, Length::Long), "9 مارس 2024");
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(format_date(Ay, &DATE, Length::Short), "2024-03-09");
+    assert_eq!(format_date(Ay, &DATE, Length::Medium), "2024 M03 9");
+    assert_eq!(month_name(Ay, 3, Width::Wide), None);
+    assert_eq!(weekday_name(Ay, Weekday::Monday, Width::Wide), None);
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(format_date(Sw, &DATE, Length::Long), "9 Machi 2024");
+    assert_eq!(
+        format_date(Uk, &DATE, Length::Long),
+        "9 березня 2024\u{202F}р."
+    );
+    assert_eq!(format_date(Tl, &DATE, Length::Long), "Marso 9, 2024");
+    assert_eq!(month_name(Is, 3, Width::Wide), Some("mars"));
+}
+
+#[test]
+fn fields() {
+    let formats = [
+        (Fields::Year, "2024"),
+        (Fields::YearMonth, "March 2024"),
+        (Fields::MonthDay, "March 9"),
+        (Fields::MonthDayWeekday, "Saturday, March 9"),
+        (Fields::Weekday, "Saturday"),
+    ];
+    for (fields, expected) in formats {
+        assert_eq!(format_fields(En, &DATE, fields, Length::Long), expected);
+    }
+    assert_eq!(
+        format_fields(En, &DATE, Fields::YearMonth, Length::Short),
+        "3/24"
+    );
+    assert_eq!(
+        fields_pattern(Fr, Fields::MonthDay, Length::Medium),
+        "d MMM"
+    );
+    // standalone names, which differ from the names inside dates
+    assert_eq!(
+        format_fields(Ru, &DATE, Fields::Month, Length::Long),
+        "март"
+    );
+    assert_eq!(
+        format_fields(Ru, &DATE, Fields::MonthDay, Length::Long),
+        "9 марта"
+    );
+}
+
+#[test]
+fn times() {
+    assert_eq!(
+        format_time(En, &(0, 5, 9), Length::Short),
+        "12:05\u{202F}AM"
+    );
+    assert_eq!(
+        format_time(En, &(13, 5, 9), Length::Medium),
+        "1:05:09\u{202F}PM"
+    );
+    assert_eq!(format_time(Es, &(9, 5, 0), Length::Short), "09:05");
+    assert_eq!(format_time(Ja, &(9, 5, 0), Length::Full), "9:05:00");
+    assert_eq!(format_time(Ko, &(15, 5, 0), Length::Short), "오후 3:05");
+}
+
+#[test]
+fn names() {
+    assert_eq!(month_name(De, 3, Width::Wide), Some("März"));
+    assert_eq!(month_name(It, 6, Width::Narrow), Some("G"));
+    assert_eq!(
+        weekday_name(Nl, Weekday::Sunday, Width::Abbreviated),
+        Some("zo")
+    );
+    assert_eq!(Weekday::of(&(1970, 1, 1)), Weekday::Thursday);
+    assert_eq!(Weekday::of(&(2024, 2, 29)), Weekday::Thursday);
+}
+
+#[test]
+fn invalid_month() {
+    assert_eq!(month_name(En, 0, Width::Wide), None);
+    assert_eq!(month_name(En, 13, Width::Wide), None);
+}
+
+#[test]
+fn first_day() {
+    assert_eq!(first_day_of_week(En, None), Weekday::Sunday);
+    assert_eq!(first_day_of_week(En, Some("gb")), Weekday::Monday);
+    assert_eq!(first_day_of_week(En, Some("US")), Weekday::Sunday);
+    assert_eq!(first_day_of_week(Fr, Some("CA")), Weekday::Sunday);
+    assert_eq!(first_day_of_week(De, None), Weekday::Monday);
+    assert_eq!(first_day_of_week(Ar, None), Weekday::Saturday);
+    assert_eq!(first_day_of_week(Ar, Some("MA")), Weekday::Monday);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_values() {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use rocket_lang::dates::format_date_time;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+    assert_eq!(format_date(It, &date, Length::Long), "9 marzo 2024");
+    let zoned = FixedOffset::east_opt(3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 3, 9, 23, 30, 0)
+        .unwrap();
+    assert_eq!(
+        format_date_time(En, &zoned, Length::Medium),
+        "Mar 9, 2024, 11:30:00\u{202F}PM"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_values() {
+    use rocket_lang::dates::format_date_time;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+    let date = Date::from_calendar_date(2024, Month::March, 9).unwrap();
+    let value = PrimitiveDateTime::new(date, Time::from_hms(8, 0, 0).unwrap());
+    assert_eq!(
+        format_date_time(Fr, &value, Length::Full),
+        "samedi 9 mars 2024 à 08:00:00"
+    );
+    assert_eq!(format_date(Nl, &date, Length::Short), "09-03-2024");
+}
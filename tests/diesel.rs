@@ -0,0 +1,67 @@
+#![cfg(feature = "diesel")]
+use diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+use rocket_lang::LangCode::{self, *};
+
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        language -> Text,
+    }
+}
+
+#[derive(Queryable, Insertable, Debug, PartialEq)]
+#[diesel(table_name = users)]
+struct User {
+    id: i32,
+    language: LangCode,
+}
+
+fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY NOT NULL, language TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn diesel_round_trip() {
+    let conn = &mut connection();
+    let new_users = [User { id: 1, language: Fr }, User { id: 2, language: Ja }];
+    diesel::insert_into(users::table)
+        .values(&new_users[..])
+        .execute(conn)
+        .unwrap();
+
+    let loaded: Vec<User> = users::table
+        .order(users::id)
+        .load(conn)
+        .unwrap();
+    assert_eq!(loaded, new_users);
+
+    let stored: String = users::table
+        .select(users::language)
+        .filter(users::id.eq(2))
+        .first::<LangCode>(conn)
+        .unwrap()
+        .to_string();
+    assert_eq!(stored, "ja");
+
+    let french: i64 = users::table
+        .filter(users::language.eq(Fr))
+        .count()
+        .get_result(conn)
+        .unwrap();
+    assert_eq!(french, 1);
+}
+
+#[test]
+fn diesel_invalid_code() {
+    let conn = &mut connection();
+    sql_query("INSERT INTO users (id, language) VALUES (1, 'xx')")
+        .execute(conn)
+        .unwrap();
+    assert!(users::table
+        .load::<User>(conn)
+        .is_err());
+}
@@ -0,0 +1,50 @@
+use rocket_lang::{
+    display_names::{region_name, tag_name},
+    *,
+};
+
+#[test]
+fn languages() {
+    assert_eq!(De.display_name_in(En), "German");
+    assert_eq!(De.display_name_in(Nl), "Duits");
+    assert_eq!(De.display_name_in(Ru), "немецкий");
+    assert_eq!(De.display_name_in(Ja), "ドイツ語");
+    assert_eq!(De.display_name_in(Ko), "독일어");
+    assert_eq!(De.display_name_in(Ar), "الألمانية");
+    assert_eq!(Es.display_name_in(Es), "español");
+    assert_eq!(Es.display_name_in(Pl), "hiszpański");
+    assert_eq!(Es.display_name_in(Aa), "Spanish");
+    assert_eq!(Bh.display_name_in(Es), "Bihari");
+    for &lang in LangCode::ALL_CODES {
+        assert!(!lang
+            .display_name_in(It)
+            .is_empty());
+    }
+}
+
+#[test]
+fn regions() {
+    assert_eq!(region_name("mx", Es), Some("México"));
+    assert_eq!(region_name("419", De), Some("Lateinamerika"));
+    assert_eq!(region_name("US", Sv), Some("USA"));
+    assert_eq!(region_name("US", Aa), Some("United States"));
+    assert_eq!(region_name("EU", Es), Some("Unión Europea"));
+    assert_eq!(region_name("ZZ", En), None);
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(De.display_name_in(Sw), "Kijerumani");
+    assert_eq!(Fr.display_name_in(Uk), "французька");
+    assert_eq!(region_name("DE", Tr), Some("Almanya"));
+    assert_eq!(tag_name("pt-BR", Pl).unwrap(), "portugalski (Brazylia)");
+}
+
+#[test]
+fn tags() {
+    assert_eq!(tag_name("pt", En).unwrap(), "Portuguese");
+    assert_eq!(tag_name("pt_BR", De).unwrap(), "Portugiesisch (Brasilien)");
+    assert_eq!(tag_name("pt-BR", Zh).unwrap(), "葡萄牙语（巴西）");
+    assert_eq!(tag_name("en-ZZ", Es).unwrap(), "inglés (ZZ)");
+    assert_eq!(tag_name("xx-BR", Es), None);
+}
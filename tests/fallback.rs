@@ -0,0 +1,88 @@
+#![cfg(feature = "rocket")]
+use rocket::{get, http::Status, local::asynchronous::Client, routes};
+use rocket_lang::*;
+
+supported_languages! {
+    enum AppLang { En, Es }
+}
+
+#[get("/")]
+fn index(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+#[get("/app")]
+fn app(lang: AppLang) -> &'static str {
+    lang.as_str()
+}
+
+async fn client(fallback: &str) -> Client {
+    let figment = rocket::Config::figment().merge(("lang_fallback", fallback));
+    let rocket = rocket::custom(figment).mount("/", routes![index, app]);
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+async fn get(client: &Client, path: &'static str) -> (Status, String) {
+    let res = client
+        .get(path)
+        .dispatch()
+        .await;
+    let status = res.status();
+    (
+        status,
+        res.into_string()
+            .await
+            .unwrap_or_default(),
+    )
+}
+
+#[tokio::test]
+async fn fallback_language() {
+    let client = &client("es").await;
+    assert_eq!(get(client, "/").await.1, "es");
+    assert_eq!(get(client, "/app").await.1, "es");
+}
+
+#[tokio::test]
+async fn fallback_warn() {
+    let client = &client("warn").await;
+    assert_eq!(get(client, "/").await.1, "en");
+}
+
+#[tokio::test]
+async fn fallback_invalid() {
+    let client = &client("klingon").await;
+    assert_eq!(get(client, "/").await.1, "en");
+    assert_eq!(get(client, "/app").await.1, "en");
+}
+
+#[tokio::test]
+async fn fallback_error() {
+    let client = &client("error").await;
+    assert_eq!(get(client, "/").await.0, Status::InternalServerError);
+    assert_eq!(get(client, "/app").await.0, Status::InternalServerError);
+}
+
+#[tokio::test]
+async fn attached_config_ignores_fallback() {
+    let figment = rocket::Config::figment().merge(("lang_fallback", "error"));
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![index])
+        .attach(Config::new().wildcard(De));
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    assert_eq!(get(client, "/").await.1, "de");
+}
+
+#[tokio::test]
+async fn missing_config_launches() {
+    let rocket = rocket::build().mount("/", routes![index, app]);
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    assert_eq!(get(client, "/").await.1, "en");
+    assert_eq!(get(client, "/app").await.1, "en");
+}
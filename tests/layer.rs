@@ -0,0 +1,148 @@
+#![cfg(feature = "axum")]
+use axum::{body::Body, routing::get, Router};
+use http::{header::CONTENT_LANGUAGE, Request, Response, StatusCode};
+use rocket_lang::{layer::LangLayer, source::LangSource, *};
+use std::convert::Infallible;
+use tower::{service_fn, Layer, ServiceExt};
+
+fn country(source: &dyn LangSource) -> Result<LangCode, Error> {
+    match source.cookie("country") {
+        Some("mx") => Ok(Es),
+        _ => Err(Error::NotAcceptable),
+    }
+}
+
+fn config() -> Config {
+    Config::new()
+        .resolver(country)
+        .cookie("lang")
+        .supported(&[En, Es])
+        .scope("/api", Config::new().wildcard(En))
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/", get(|lang: LangCode| async move { lang.as_str() }))
+        .route(
+            "/api/users",
+            get(|lang: LangCode| async move { lang.as_str() }),
+        )
+        .route("/asset", get(|| async { "asset" }))
+        .layer(LangLayer::new(config()))
+}
+
+fn request(path: &str, header: &str) -> Request<Body> {
+    Request::builder()
+        .uri(path)
+        .header("accept-language", header)
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn body(response: Response<Body>) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn extractor() {
+    let response = app()
+        .oneshot(request("/", "de, es;q=0.5"))
+        .await
+        .unwrap();
+    assert_eq!(response.headers()[CONTENT_LANGUAGE], "es");
+    assert_eq!(body(response).await, "es");
+
+    let response = app()
+        .oneshot(request("/api/users", "es"))
+        .await
+        .unwrap();
+    assert_eq!(body(response).await, "en");
+}
+
+#[tokio::test]
+async fn cookie() {
+    let request = Request::builder()
+        .uri("/")
+        .header("cookie", "lang=es")
+        .body(Body::empty())
+        .unwrap();
+    let response = app()
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(body(response).await, "es");
+}
+
+#[tokio::test]
+async fn not_acceptable() {
+    let response = app()
+        .oneshot(request("/", "de"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    assert!(response
+        .headers()
+        .get(CONTENT_LANGUAGE)
+        .is_none());
+}
+
+#[tokio::test]
+async fn unused_language_is_not_resolved() {
+    let response = app()
+        .oneshot(request("/asset", "es"))
+        .await
+        .unwrap();
+    assert!(response
+        .headers()
+        .get(CONTENT_LANGUAGE)
+        .is_none());
+}
+
+#[tokio::test]
+async fn missing_layer() {
+    let app = Router::new().route("/", get(|lang: LangCode| async move { lang.as_str() }));
+    let response = app
+        .oneshot(request("/", "es"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn plain_tower_service() {
+    let service = LangLayer::new(config()).layer(service_fn(|req: Request<()>| async move {
+        let (parts, _) = req.into_parts();
+        let lang = rocket_lang::layer::resolve(&parts).unwrap();
+        Ok::<_, Infallible>(Response::new(lang.to_string()))
+    }));
+    let request = Request::builder()
+        .uri("/")
+        .header("accept-language", "es")
+        .body(())
+        .unwrap();
+    let response = service
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.headers()[CONTENT_LANGUAGE], "es");
+    assert_eq!(response.into_body(), "es");
+}
+
+#[tokio::test]
+async fn resolver() {
+    let request = Request::builder()
+        .uri("/")
+        .header("accept-language", "en")
+        .header("cookie", "country=mx")
+        .body(Body::empty())
+        .unwrap();
+    let response = app()
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.headers()[CONTENT_LANGUAGE], "es");
+    assert_eq!(body(response).await, "es");
+}
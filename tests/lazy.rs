@@ -0,0 +1,185 @@
+#![cfg(feature = "rocket")]
+use rocket::{
+    get,
+    local::asynchronous::Client,
+    request::{FromRequest, Outcome},
+    response::{self, Responder},
+    routes, Request,
+};
+use rocket_lang::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[get("/asset")]
+fn asset() -> &'static str {
+    "asset"
+}
+
+#[get("/page")]
+fn page(lang: LangCode, again: LangCode) -> String {
+    format!("{lang} {again}")
+}
+
+/// Reads the language from the request when responding, like templates do.
+struct Sync;
+
+impl<'r> Responder<'r, 'static> for Sync {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        LangCode::try_from(req)
+            .unwrap()
+            .as_str()
+            .respond_to(req)
+    }
+}
+
+#[get("/sync")]
+fn sync() -> Sync {
+    Sync
+}
+
+/// Reads the language synchronously, before the `LangCode` guard runs.
+struct Early(Result<LangCode, Error>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Early {
+    type Error = ();
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+        Outcome::Success(Early(LangCode::try_from(req)))
+    }
+}
+
+#[get("/early")]
+fn early(early: Early, lang: LangCode) -> String {
+    match early.0 {
+        Ok(early) => format!("{early} {lang}"),
+        Err(e) => format!("{} {lang}", e.status_code()),
+    }
+}
+
+static LAZY: AtomicUsize = AtomicUsize::new(0);
+static EAGER: AtomicUsize = AtomicUsize::new(0);
+
+async fn client(config: Config) -> Client {
+    let rocket = rocket::build()
+        .mount("/", routes![asset, page])
+        .attach(config);
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn lazy_skips_unused() {
+    let config = Config::new().custom_async(|_: &Request| {
+        LAZY.fetch_add(1, Ordering::SeqCst);
+        async { Ok(Es) }
+    });
+    let client = client(config).await;
+    client
+        .get("/asset")
+        .dispatch()
+        .await;
+    assert_eq!(LAZY.load(Ordering::SeqCst), 0);
+
+    let body = client
+        .get("/page")
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.unwrap(), "es es");
+    assert_eq!(LAZY.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn eager_resolves_every_request() {
+    let config = Config::new()
+        .eager()
+        .custom_async(|_: &Request| {
+            EAGER.fetch_add(1, Ordering::SeqCst);
+            async { Ok(Es) }
+        });
+    let client = client(config).await;
+    client
+        .get("/asset")
+        .dispatch()
+        .await;
+    assert_eq!(EAGER.load(Ordering::SeqCst), 1);
+
+    client
+        .get("/page")
+        .dispatch()
+        .await;
+    assert_eq!(EAGER.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn content_language() {
+    let config = Config::new().custom(|_| Ok(Es));
+    let client = client(config).await;
+    let response = client
+        .get("/page")
+        .dispatch()
+        .await;
+    assert_eq!(
+        response
+            .headers()
+            .get_one("Content-Language"),
+        Some("es")
+    );
+
+    // the language is not resolved, so there is no header
+    let response = client
+        .get("/asset")
+        .dispatch()
+        .await;
+    assert_eq!(
+        response
+            .headers()
+            .get_one("Content-Language"),
+        None
+    );
+}
+
+#[tokio::test]
+async fn lazy_try_from() {
+    let mut config = Config::new();
+    config[De] = 1.0;
+    let rocket = rocket::build()
+        .mount("/", routes![sync])
+        .attach(config);
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    let body = client
+        .get("/sync")
+        .header(rocket::http::Header::new("accept-language", "de"))
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.unwrap(), "de");
+}
+
+#[tokio::test]
+async fn try_from_pending() {
+    let config = Config::new()
+        .wildcard(En)
+        .custom_async(|_: &Request| async {
+            tokio::task::yield_now().await;
+            Ok(Es)
+        });
+    let rocket = rocket::build()
+        .mount("/", routes![early])
+        .attach(config);
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    // the pending result isn't cached, so the guard still awaits the resolver
+    let body = client
+        .get("/early")
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.unwrap(), "500 es");
+}
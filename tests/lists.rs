@@ -0,0 +1,83 @@
+use rocket_lang::{
+    lists::{format_list, format_list_width, ListType::*, ListWidth},
+    *,
+};
+
+#[test]
+fn lengths() {
+    let empty: [&str; 0] = [];
+    assert_eq!(format_list(En, empty, Conjunction), "");
+    assert_eq!(format_list(En, ["A"], Conjunction), "A");
+    assert_eq!(format_list(En, ["A", "B"], Conjunction), "A and B");
+    assert_eq!(
+        format_list(En, ["A", "B", "C", "D"], Disjunction),
+        "A, B, C, or D"
+    );
+    assert_eq!(format_list(En, vec![String::from("A")], Disjunction), "A");
+}
+
+#[test]
+fn languages() {
+    let items = ["A", "B", "C"];
+    assert_eq!(format_list(Fr, items, Conjunction), "A, B et C");
+    assert_eq!(format_list(It, items, Disjunction), "A, B o C");
+    assert_eq!(format_list(Nl, items, Conjunction), "A, B en C");
+    assert_eq!(format_list(Ru, items, Conjunction), "A, B и C");
+    assert_eq!(format_list(Ja, items, Conjunction), "A、B、C");
+    assert_eq!(format_list(Ja, ["A", "B"], Disjunction), "AまたはB");
+    assert_eq!(format_list(Ko, items, Disjunction), "A, B 또는 C");
+    assert_eq!(format_list(Ar, items, Conjunction), "A وB وC");
+}
+
+#[test]
+fn spanish_conjunctions() {
+    assert_eq!(
+        format_list(Es, ["padre", "hijo"], Conjunction),
+        "padre e hijo"
+    );
+    assert_eq!(
+        format_list(Es, ["agua", "hielo"], Conjunction),
+        "agua y hielo"
+    );
+    assert_eq!(format_list(Es, ["uno", "otro"], Disjunction), "uno u otro");
+    assert_eq!(format_list(Es, ["7", "8"], Disjunction), "7 u 8");
+    assert_eq!(format_list(Es, ["A", "B"], Disjunction), "A o B");
+}
+
+#[test]
+fn generated_languages() {
+    let items = ["A", "B", "C"];
+    assert_eq!(format_list(Sv, items, Conjunction), "A, B och C");
+    assert_eq!(format_list(Da, items, Disjunction), "A, B eller C");
+    assert_eq!(format_list(Fi, items, Conjunction), "A, B ja C");
+    assert_eq!(format_list(Pl, items, Disjunction), "A, B lub C");
+    assert_eq!(format_list(Tr, items, Conjunction), "A, B ve C");
+    assert_eq!(format_list(El, items, Conjunction), "A, B και C");
+    assert_eq!(format_list(Hi, items, Disjunction), "A, B या C");
+    assert_eq!(format_list(No, items, Conjunction), "A, B og C");
+    // the pattern of a language can have text after the last item.
+    assert_eq!(format_list(Mi, items, Disjunction), "A, B, C rānei");
+}
+
+#[test]
+fn hebrew_conjunction() {
+    assert_eq!(format_list(He, ["א", "ב"], Conjunction), "א וב");
+    assert_eq!(format_list(He, ["א", "B"], Conjunction), "א ו\u{2011}B");
+    assert_eq!(format_list(He, ["א", "ב"], Disjunction), "א או ב");
+}
+
+#[test]
+fn widths() {
+    let items = ["A", "B", "C"];
+    let list = |lang, kind, width| format_list_width(lang, items, kind, width);
+    assert_eq!(list(En, Conjunction, ListWidth::Wide), "A, B, and C");
+    assert_eq!(list(En, Conjunction, ListWidth::Short), "A, B, & C");
+    assert_eq!(list(En, Conjunction, ListWidth::Narrow), "A, B, C");
+    assert_eq!(list(Sv, Conjunction, ListWidth::Narrow), "A, B, C");
+    assert_eq!(list(Es, Conjunction, ListWidth::Narrow), "A, B y C");
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(format_list(Aa, ["A", "B", "C"], Conjunction), "A, B, C");
+}
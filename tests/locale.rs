@@ -0,0 +1,81 @@
+use rocket_lang::{locale::Locale, *};
+
+fn tag(text: &str) -> Locale {
+    text.parse().unwrap()
+}
+
+#[test]
+fn parse() {
+    let locale = tag("ZH_hant_tw");
+    assert_eq!(locale.lang(), Zh);
+    assert_eq!(locale.script(), Some("Hant"));
+    assert_eq!(locale.region(), Some("TW"));
+    assert_eq!(locale.to_string(), "zh-Hant-TW");
+    assert_eq!(tag("es-419").region(), Some("419"));
+    assert_eq!(tag("en"), Locale::from(En));
+    assert!("xx-US"
+        .parse::<Locale>()
+        .is_err());
+    assert!("en-US-Latn"
+        .parse::<Locale>()
+        .is_err());
+    assert!("en-USA"
+        .parse::<Locale>()
+        .is_err());
+}
+
+#[test]
+fn maximize() {
+    let tags = [
+        ("en", "en-Latn-US"),
+        ("zh", "zh-Hans-CN"),
+        ("zh-TW", "zh-Hant-TW"),
+        ("zh-Hant", "zh-Hant-TW"),
+        ("zh-Hant-HK", "zh-Hant-HK"),
+        ("sr", "sr-Cyrl-RS"),
+        ("sr-Latn", "sr-Latn-RS"),
+        ("pt-AO", "pt-Latn-AO"),
+        ("pa-PK", "pa-Arab-PK"),
+        ("ja", "ja-Jpan-JP"),
+    ];
+    for (tag, max) in tags {
+        assert_eq!(
+            self::tag(tag)
+                .maximize()
+                .to_string(),
+            max
+        );
+    }
+}
+
+#[test]
+fn minimize() {
+    let tags = [
+        ("en-Latn-US", "en"),
+        ("en-GB", "en-GB"),
+        ("zh-Hant-TW", "zh-TW"),
+        ("zh-Hant", "zh-TW"),
+        ("zh-Hans-CN", "zh"),
+        ("sr-Latn-RS", "sr-Latn"),
+        ("sr-Latn-ME", "sr-ME"),
+        ("zh-Hant-CN", "zh-Hant-CN"),
+    ];
+    for (tag, min) in tags {
+        assert_eq!(
+            self::tag(tag)
+                .minimize()
+                .to_string(),
+            min
+        );
+    }
+}
+
+#[test]
+fn likely_subtags() {
+    for &lang in LangCode::ALL_CODES {
+        assert_eq!(lang.likely_script().len(), 4, "{lang}");
+        let max = Locale::from(lang).maximize();
+        assert_eq!(max.region(), Some(lang.likely_region()));
+        assert_eq!(max.minimize(), Locale::from(lang));
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg(feature = "rocket")]
 mod common;
 use common::*;
 use rocket::http::{Header, Status};
@@ -96,3 +97,25 @@ async fn mixed_custom_url() {
     assert_mixed(config.clone(), "/some/bad/path", "es", "la").await;
     assert_mixed(config.clone(), "/some-good/path/pt", "es", "la").await;
 }
+
+#[tokio::test]
+async fn mixed_resolver_url() {
+    let config = Config::new()
+        .url(-1)
+        .resolver(|source| {
+            if source
+                .path()
+                .starts_with("/some-good/")
+            {
+                return Ok(Fr);
+            }
+            Err(Error::NotAcceptable)
+        });
+
+    assert_mixed(config.clone(), "/some-good/path/pt", "es", "fr").await;
+    assert_mixed(config.clone(), "/some/bad/pt", "es", "pt").await;
+
+    // the custom closure comes first
+    let config = config.custom(|_| Ok(La));
+    assert_mixed(config, "/some-good/path/pt", "es", "la").await;
+}
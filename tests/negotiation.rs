@@ -0,0 +1,188 @@
+use proptest::prelude::*;
+#[cfg(feature = "rocket")]
+use rocket::{get, http::Header, local::asynchronous::Client, routes};
+use rocket_lang::{negotiation::*, *};
+
+fn candidates(list: &[(LangCode, f32, f32)]) -> Vec<Candidate> {
+    list.iter()
+        .map(|&(lang, client, server)| Candidate {
+            lang,
+            client,
+            server,
+        })
+        .collect()
+}
+
+fn strategies() -> [(&'static str, Box<dyn NegotiationStrategy>); 4] {
+    [
+        ("product", Box::new(Product)),
+        ("client first", Box::new(ClientFirst)),
+        ("server first", Box::new(ServerFirst)),
+        ("legacy", Box::new(Legacy)),
+    ]
+}
+
+#[test]
+fn built_in_strategies() {
+    // client prefers Spanish, the server prefers English.
+    let list = candidates(&[(Es, 1.0, 0.3), (En, 0.5, 1.0), (Fr, 0.8, 0.8)]);
+    assert_eq!(Product.negotiate(&list), Some(Fr));
+    assert_eq!(ClientFirst.negotiate(&list), Some(Es));
+    assert_eq!(ServerFirst.negotiate(&list), Some(En));
+    assert_eq!(Legacy.negotiate(&list), Some(Fr));
+
+    // complete ties go to the language that comes first in `ALL_CODES`.
+    let ties = candidates(&[(De, 0.5, 1.0), (Es, 0.5, 0.4), (Fr, 0.5, 1.0)]);
+    assert_eq!(ClientFirst.negotiate(&ties), Some(Fr));
+    assert_eq!(ServerFirst.negotiate(&ties), Some(Fr));
+
+    let rejected = candidates(&[(Es, 0.0, 1.0)]);
+    assert_eq!(Product.negotiate(&rejected), None);
+    assert_eq!(ClientFirst.negotiate(&[]), None);
+}
+
+#[cfg(feature = "rocket")]
+#[get("/")]
+fn index(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+#[cfg(feature = "rocket")]
+#[tokio::test]
+async fn selectable_on_config() {
+    let mut config = Config::new().strategy(ServerFirst);
+    config[En] = 1.0;
+    config[Es] = 0.5;
+    let rocket = rocket::build()
+        .mount("/", routes![index])
+        .attach(config);
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    let body = client
+        .get("/")
+        .header(Header::new("accept-language", "es, en;q=0.1"))
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.unwrap(), "en");
+}
+
+fn lang() -> impl Strategy<Value = LangCode> {
+    proptest::sample::select(LangCode::ALL_CODES)
+}
+
+fn candidate_list() -> impl Strategy<Value = Vec<Candidate>> {
+    let quality = prop_oneof![Just(0.0), Just(0.5), Just(1.0), 0.0f32..=1.0];
+    let server = prop_oneof![Just(0.5), Just(1.0), 0.01f32..=1.0];
+    proptest::collection::vec((lang(), quality, server), 0..8).prop_map(|list| candidates(&list))
+}
+
+/// The best candidate according to `key`, among those the client didn't reject.
+fn max_key(list: &[Candidate], key: impl Fn(&Candidate) -> f32) -> Option<f32> {
+    list.iter()
+        .filter(|c| c.client > 0.0)
+        .map(key)
+        .max_by(f32::total_cmp)
+}
+
+/// The qualities of a few languages on the server and in the `Accept-Language` header,
+/// a wildcard, and a strategy.
+type Setup = (
+    Vec<(LangCode, f32)>,
+    Vec<(LangCode, f32)>,
+    Option<LangCode>,
+    usize,
+);
+
+fn setup() -> impl Strategy<Value = Setup> {
+    let few = proptest::sample::select(&[En, Es, Fr, De, Pt][..]);
+    let quality = prop_oneof![Just(0.0), Just(1.0), 0.0f32..=1.0];
+    (
+        proptest::collection::vec((few.clone(), quality.clone()), 0..4),
+        proptest::collection::vec((few, quality), 0..5),
+        proptest::option::of(lang()),
+        0..4usize,
+    )
+}
+
+fn config((server, _, wildcard, strategy): &Setup) -> Config {
+    let mut config = Config::new().languages(server.iter().copied());
+    if let Some(wildcard) = *wildcard {
+        config = config.wildcard(wildcard);
+    }
+    match strategy {
+        0 => config.strategy(Product),
+        1 => config.strategy(ClientFirst),
+        2 => config.strategy(ServerFirst),
+        _ => config.strategy(Legacy),
+    }
+}
+
+proptest! {
+    #[test]
+    fn quality_ordering(list in candidate_list()) {
+        // the key each strategy maximizes, in the order of `strategies`.
+        let keys: [fn(&Candidate) -> f32; 3] = [|c| c.client * c.server, |c| c.client, |c| c.server];
+        for ((name, strategy), key) in strategies().into_iter().zip(keys) {
+            let best = max_key(&list, key);
+            match strategy.negotiate(&list) {
+                Some(lang) => {
+                    // the chosen language was accepted by the client, and nothing ranks higher.
+                    let chosen = list
+                        .iter()
+                        .filter(|c| c.lang == lang && c.client > 0.0)
+                        .map(key)
+                        .max_by(f32::total_cmp);
+                    prop_assert!(chosen.is_some(), "{}", name);
+                    prop_assert_eq!(chosen, best, "{}", name);
+                }
+                None => prop_assert_eq!(best, None, "{}", name),
+            }
+        }
+        if let Some(lang) = Legacy.negotiate(&list) {
+            prop_assert!(list.iter().any(|c| c.lang == lang));
+        }
+    }
+
+    #[test]
+    fn result_is_supported(setup in setup()) {
+        let config = config(&setup);
+        let header = setup
+            .1
+            .iter()
+            .map(|(lang, q)| format!("{lang};q={q:.3}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let parts = source::Parts::new("/").accept_language(&header);
+        let supported = config.supported_languages();
+        let matches = setup
+            .1
+            .iter()
+            .any(|(lang, q)| format!("{q:.3}") != "0.000" && supported.contains(lang));
+        let legacy = setup.3 == 3;
+        match config.negotiate(&parts) {
+            Ok(lang) if matches || legacy => {
+                prop_assert!(supported.contains(&lang) || config.wildcard == Some(lang));
+            }
+            // when nothing matches, the wildcard is the fallback
+            Ok(lang) => prop_assert_eq!(Some(lang), config.wildcard),
+            Err(error) => {
+                prop_assert!(!matches);
+                prop_assert_eq!(config.wildcard, None);
+                prop_assert_eq!(error.status_code(), 406);
+            }
+        }
+    }
+
+    #[test]
+    fn independent_of_header_order(
+        (list, shuffled) in candidate_list()
+            .prop_flat_map(|list| (Just(list.clone()), Just(list).prop_shuffle()))
+    ) {
+        for (name, strategy) in strategies().into_iter().take(3) {
+            prop_assert_eq!(strategy.negotiate(&list), strategy.negotiate(&shuffled), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use rocket_lang::{
+    numbers::{format_number, format_percent, Digits, NumberFormat},
+    *,
+};
+
+#[test]
+fn separators() {
+    assert_eq!(format_number(En, 1234567.891), "1,234,567.891");
+    assert_eq!(format_number(De, 1234567.891), "1.234.567,891");
+    assert_eq!(
+        format_number(Fr, 1234567.891),
+        "1\u{202F}234\u{202F}567,891"
+    );
+    assert_eq!(format_number(Ru, 1234567.891), "1\u{A0}234\u{A0}567,891");
+    assert_eq!(format_number(Ja, 1234567.891), "1,234,567.891");
+}
+
+#[test]
+fn indian_grouping() {
+    assert_eq!(format_number(Hi, 123.0), "123");
+    assert_eq!(format_number(Hi, 1234.0), "1,234");
+    assert_eq!(format_number(Hi, 123456789.0), "12,34,56,789");
+    assert_eq!(
+        NumberFormat::with_region(En, "IN").format(1234567.0),
+        "12,34,567"
+    );
+    assert_eq!(NumberFormat::with_region(En, "in").grouping(), (3, 2));
+}
+
+#[test]
+fn minimum_grouping_digits() {
+    assert_eq!(format_number(Es, 1234.0), "1234");
+    assert_eq!(format_number(Es, 12345.0), "12.345");
+    assert_eq!(format_number(Pl, 1234.0), "1234");
+    assert_eq!(NumberFormat::with_region(Es, "MX").format(1234.0), "1,234");
+    assert_eq!(NumberFormat::with_region(Es, "US").format(1234.0), "1,234");
+}
+
+#[test]
+fn regions() {
+    assert_eq!(
+        NumberFormat::with_region(Fr, "CA").format(1234.5),
+        "1\u{A0}234,5"
+    );
+    assert_eq!(
+        NumberFormat::with_region(En, "ZA").format(1234.5),
+        "1\u{A0}234,5"
+    );
+    assert_eq!(
+        NumberFormat::with_region(En, "US").format(1234.5),
+        "1,234.5"
+    );
+}
+
+#[test]
+fn native_digits() {
+    assert_eq!(format_number(Ar, -1234.5), "\u{200E}-1,234.5");
+    assert_eq!(
+        NumberFormat::new(Ar)
+            .native()
+            .format(-1234.5),
+        "\u{61C}-١٬٢٣٤٫٥"
+    );
+    assert_eq!(
+        NumberFormat::with_region(Ar, "EG").format(-1234.5),
+        "\u{61C}-١٬٢٣٤٫٥"
+    );
+    assert_eq!(format_number(Fa, 1234.5), "۱٬۲۳۴٫۵");
+    assert_eq!(format_number(Bn, 1234567.0), "১২,৩৪,৫৬৭");
+    assert_eq!(
+        NumberFormat::with_region(Ar, "MA").format(1234.5),
+        "1.234,5"
+    );
+    assert_eq!(NumberFormat::new(Hi).digits, Digits::Latin);
+    assert_eq!(
+        NumberFormat::new(Hi)
+            .native()
+            .digits,
+        Digits::Devanagari
+    );
+    assert_eq!(NumberFormat::new(En).native(), NumberFormat::new(En));
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(format_number(Bs, 1234567.5), "1.234.567,5");
+    assert_eq!(format_number(Fo, -1234.5), "\u{2212}1.234,5");
+    assert_eq!(format_number(Eo, 1234567.5), "1\u{202F}234\u{202F}567,5");
+    assert_eq!(format_number(Ee, 1234.0), "1234");
+    assert_eq!(format_number(Ee, 12345.0), "12345");
+    assert_eq!(format_number(Ee, 123456.0), "123,456");
+    assert_eq!(format_percent(Br, 0.25), "25\u{A0}%");
+}
+
+#[test]
+fn rounding_and_signs() {
+    assert_eq!(format_number(En, 0.12345), "0.123");
+    assert_eq!(format_number(En, 2.0), "2");
+    assert_eq!(format_number(En, 999999.9999), "1,000,000");
+    assert_eq!(format_number(En, -0.0001), "0");
+    assert_eq!(format_number(Sv, -1234.5), "\u{2212}1\u{A0}234,5");
+    assert_eq!(format_number(En, f64::NAN), "NaN");
+    assert_eq!(format_number(En, f64::NEG_INFINITY), "-∞");
+}
+
+#[test]
+fn percents() {
+    assert_eq!(format_percent(En, 0.256), "26%");
+    assert_eq!(format_percent(Fr, 0.25), "25\u{A0}%");
+    assert_eq!(format_percent(Tr, 0.25), "%25");
+    assert_eq!(format_percent(Hi, 1234.56), "1,23,456%");
+    assert_eq!(format_percent(En, -0.5), "-50%");
+}
@@ -0,0 +1,82 @@
+use rocket_lang::{
+    plurals::{plural_category, PluralCategory::*},
+    *,
+};
+
+#[test]
+fn categories() {
+    assert_eq!(plural_category(En, 0.0), Other);
+    assert_eq!(plural_category(En, -1.0), One);
+    assert_eq!(plural_category(Fr, 0.0), One);
+    assert_eq!(plural_category(Es, 1000000.0), Many);
+    assert_eq!(plural_category(Pl, 22.0), Few);
+    assert_eq!(plural_category(Pl, 25.0), Many);
+    assert_eq!(plural_category(Pl, 1.5), Other);
+    assert_eq!(plural_category(Cs, 1.5), Many);
+    assert_eq!(plural_category(Ja, 1.0), Other);
+    assert_eq!(plural_category(He, 2.0), Two);
+    assert_eq!(plural_category(Hi, 0.5), One);
+}
+
+#[test]
+fn arabic() {
+    let categories = [
+        (0.0, Zero),
+        (1.0, One),
+        (2.0, Two),
+        (3.0, Few),
+        (110.0, Few),
+        (11.0, Many),
+        (100.0, Other),
+        (1.5, Other),
+    ];
+    for (value, category) in categories {
+        assert_eq!(plural_category(Ar, value), category, "{value}");
+    }
+}
+
+#[test]
+fn generated_rules() {
+    let categories = [
+        (Lt, 1.0, One),
+        (Lt, 2.0, Few),
+        (Lt, 9.0, Few),
+        (Lt, 11.0, Other),
+        (Lt, 21.0, One),
+        (Lt, 0.5, Many),
+        (Hr, 3.0, Few),
+        (Hr, 5.0, Other),
+        (Hr, 12.0, Other),
+        (Hr, 22.0, Few),
+        (Hr, 0.1, One),
+        (Sr, 21.0, One),
+        (Sr, 24.0, Few),
+        (Sr, 25.0, Other),
+        (Sl, 1.0, One),
+        (Sl, 2.0, Two),
+        (Sl, 102.0, Two),
+        (Sl, 3.0, Few),
+        (Sl, 5.0, Other),
+        (Ro, 0.0, Few),
+        (Ro, 2.0, Few),
+        (Ro, 19.0, Few),
+        (Ro, 20.0, Other),
+        (Ro, 1.5, Few),
+        (Cy, 0.0, Zero),
+        (Cy, 2.0, Two),
+        (Cy, 3.0, Few),
+        (Cy, 6.0, Many),
+        (Cy, 5.0, Other),
+        (Ga, 2.0, Two),
+        (Ga, 5.0, Few),
+        (Ga, 7.0, Many),
+        (Ga, 11.0, Other),
+        (Lv, 0.0, Zero),
+        (Lv, 11.0, Zero),
+        (Lv, 21.0, One),
+        (Lv, 22.0, Other),
+    ];
+    for (lang, value, category) in categories {
+        assert_eq!(plural_category(lang, value), category, "{lang:?} {value}");
+    }
+}
@@ -0,0 +1,228 @@
+#![cfg(feature = "rocket")]
+use rocket::{get, http::Header, local::asynchronous::Client, routes};
+use rocket_lang::{locale::Locale, *};
+
+#[get("/")]
+fn index(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+#[get("/locale")]
+fn locale(locale: Locale) -> String {
+    locale.to_string()
+}
+
+fn portuguese() -> Config {
+    let mut config = Config::new();
+    config["pt-BR"] = 1.0;
+    config["pt-PT"] = 0.4;
+    config[Es] = 0.8;
+    config
+}
+
+async fn get(client: &Client, header: &'static str) -> String {
+    client
+        .get("/")
+        .header(Header::new("accept-language", header))
+        .dispatch()
+        .await
+        .into_string()
+        .await
+        .unwrap_or_default()
+}
+
+#[test]
+fn inheritance() {
+    let mut config = Config::new();
+    config[Pt] = 0.5;
+    config["pt-br"] = 1.0;
+    assert_eq!(config["pt-BR"], 1.0);
+    assert_eq!(config["pt_BR"], 1.0);
+    assert_eq!(config["pt-PT"], 0.5);
+    assert_eq!(config["pt"], 0.5);
+    assert_eq!(config[Pt], 0.5);
+    assert_eq!(config.supported_languages(), [Pt]);
+    assert_eq!(portuguese().supported_languages(), [Pt, Es]);
+}
+
+#[test]
+#[should_panic]
+fn invalid_tag() {
+    let mut config = Config::new();
+    config["xx-BR"] = 1.0;
+}
+
+#[tokio::test]
+async fn negotiation_with_regions() {
+    let rocket = rocket::build()
+        .mount("/", routes![index])
+        .attach(portuguese());
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    assert_eq!(get(client, "pt-BR, es;q=0.9").await, "pt");
+    // european portuguese is only partially translated.
+    assert_eq!(get(client, "pt-PT, es;q=0.9").await, "es");
+    // a tag without a region matches the best supported region.
+    assert_eq!(get(client, "pt, es;q=0.9").await, "pt");
+    assert_eq!(get(client, "pt-AO, es;q=0.9").await, "es");
+}
+
+#[tokio::test]
+async fn negotiated_locale() {
+    let rocket = rocket::build()
+        .mount("/", routes![locale])
+        .attach(portuguese());
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    let locale = |header: &'static str| async move {
+        client
+            .get("/locale")
+            .header(Header::new("accept-language", header))
+            .dispatch()
+            .await
+            .into_string()
+            .await
+            .unwrap_or_default()
+    };
+    assert_eq!(locale("pt-BR, es;q=0.9").await, "pt-BR");
+    // the tag with the best quality in the config wins over the client's preference.
+    assert_eq!(locale("pt-PT, pt-BR;q=0.9").await, "pt-BR");
+    assert_eq!(locale("pt-PT;q=0.9, es;q=0.1").await, "pt-PT");
+    assert_eq!(locale("pt, es;q=0.9").await, "pt");
+    assert_eq!(locale("es-MX").await, "es-MX");
+}
+
+#[test]
+fn negotiate_locale() {
+    let config = portuguese();
+    let parts = source::Parts::new("/").accept_language("es-AR, pt-PT;q=0.5");
+    let locale = config
+        .negotiate_locale(&parts)
+        .unwrap();
+    assert_eq!(locale.to_string(), "es-AR");
+    assert_eq!(locale.region(), Some("AR"));
+}
+
+#[test]
+fn accepted_languages() {
+    let accepted = AcceptedLanguages::new("pt-AO, pt-PT;q=0.5", Some(&portuguese()));
+    assert_eq!(
+        accepted
+            .iter()
+            .collect::<Vec<_>>(),
+        [(Pt, 0.5)]
+    );
+}
+
+#[test]
+fn scripts() {
+    let mut config = Config::new();
+    config["zh-Hant"] = 1.0;
+    config["sr-Latn"] = 1.0;
+    config[En] = 0.5;
+    assert_eq!(config["zh-hant"], 1.0);
+    assert_eq!(config.supported_languages(), [Zh, En, Sr]);
+    let accepted = |header| {
+        AcceptedLanguages::new(header, Some(&config))
+            .languages()
+            .collect::<Vec<_>>()
+    };
+    // traditional chinese is likely in taiwan and hong kong.
+    assert_eq!(accepted("zh-TW, en;q=0.5"), [Zh, En]);
+    assert_eq!(accepted("zh-HK"), [Zh]);
+    assert_eq!(accepted("zh-Hant-TW"), [Zh]);
+    assert_eq!(accepted("zh-CN, en;q=0.5"), [En]);
+    assert_eq!(accepted("zh-Hans"), []);
+    assert_eq!(accepted("zh"), [Zh]);
+    // serbian is likely written in cyrillic in serbia, but in latin in montenegro.
+    assert_eq!(accepted("sr-RS"), []);
+    assert_eq!(accepted("sr-ME"), [Sr]);
+}
+
+#[test]
+fn negotiation_without_scripts() {
+    // languages written in a single script negotiate as they did before the likely
+    // scripts were generated.
+    let negotiate = |config: &Config, header| {
+        let parts = source::Parts::new("/").accept_language(header);
+        config
+            .negotiate(&parts)
+            .map_err(|e| e.status_code())
+    };
+    let accepted = |config: &Config, header| {
+        AcceptedLanguages::new(header, Some(config))
+            .iter()
+            .collect::<Vec<_>>()
+    };
+    let config = portuguese();
+    assert_eq!(negotiate(&config, "pt-AO"), Err(406));
+    assert_eq!(negotiate(&config, "PT-br"), Err(406));
+    assert_eq!(negotiate(&config, "pt-AO, es;q=0.9"), Ok(Es));
+    assert_eq!(accepted(&config, "pt-AO, es;q=0.9"), [(Es, 0.9)]);
+    assert_eq!(negotiate(&config, "pt-br;q=0.5, es;q=0.6"), Ok(Pt));
+    assert_eq!(
+        accepted(&config, "pt-br;q=0.5, es;q=0.6"),
+        [(Es, 0.6), (Pt, 0.5)]
+    );
+    assert_eq!(negotiate(&config, "xx-YY, es-CL;q=0.2"), Ok(Es));
+
+    let mut config = Config::new();
+    config[Pt] = 0.5;
+    config["pt-BR"] = 1.0;
+    config[En] = 0.6;
+    config["en-GB"] = 0.9;
+    config[De] = 1.0;
+    assert_eq!(negotiate(&config, "pt-AO"), Ok(Pt));
+    assert_eq!(negotiate(&config, "en-GB, de;q=0.95"), Ok(De));
+    assert_eq!(
+        accepted(&config, "en-GB, de;q=0.95"),
+        [(En, 1.0), (De, 0.95)]
+    );
+    assert_eq!(negotiate(&config, "en-US, de;q=0.5"), Ok(En));
+    assert_eq!(negotiate(&config, "de-CH, en-GB;q=0.5"), Ok(De));
+    assert_eq!(negotiate(&config, "de-DE-1996"), Ok(De));
+    assert_eq!(negotiate(&config, "en-US-x-twain"), Ok(En));
+    assert_eq!(negotiate(&config, "es, en;q=0.5"), Ok(En));
+    assert_eq!(accepted(&config, "es, en;q=0.5"), [(En, 0.5)]);
+    assert_eq!(negotiate(&config, "*"), Err(406));
+    assert_eq!(negotiate(&config, "en-US, *;q=0.5"), Ok(En));
+
+    let mut config = Config::new();
+    config[En] = 1.0;
+    config[Es] = 0.7;
+    config["es-MX"] = 1.0;
+    config["es-419"] = 0.9;
+    config[Fr] = 0.3;
+    assert_eq!(negotiate(&config, "es-ES;q=0.9, en;q=0.8"), Ok(En));
+    assert_eq!(
+        accepted(&config, "es-ES;q=0.9, en;q=0.8"),
+        [(Es, 0.9), (En, 0.8)]
+    );
+    assert_eq!(negotiate(&config, "fr-CA, es-MX;q=0.1"), Ok(Fr));
+    assert_eq!(negotiate(&config, "es-419"), Ok(Es));
+    assert_eq!(negotiate(&config, "de-DE-1996"), Err(406));
+    assert_eq!(negotiate(&config, "pt-BR, pt;q=0.9, en;q=0.1"), Ok(En));
+    // the quality of a tag with an extension is not ignored.
+    assert_eq!(
+        accepted(&config, "es-MX-u-ca-gregory;q=0.5, en;q=0.4"),
+        [(Es, 0.5), (En, 0.4)]
+    );
+}
+
+#[test]
+fn locale_with_extensions() {
+    let mut config = Config::new();
+    config[En] = 1.0;
+    let parts = source::Parts::new("/").accept_language("en-US-x-twain");
+    let locale = config
+        .negotiate_locale(&parts)
+        .unwrap();
+    assert_eq!(locale.region(), Some("US"));
+    let parts = source::Parts::new("/").accept_language("en-Latn-GB-oxendict");
+    let locale = config
+        .negotiate_locale(&parts)
+        .unwrap();
+    assert_eq!(locale.to_string(), "en-Latn-GB");
+}
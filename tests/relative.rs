@@ -0,0 +1,104 @@
+use rocket_lang::{
+    relative::{format_relative, format_relative_unit, RelativeUnit},
+    *,
+};
+use std::time::Duration;
+
+const HOUR: i64 = 60 * 60;
+const DAY: i64 = 24 * HOUR;
+
+#[test]
+fn largest_unit() {
+    assert_eq!(format_relative(En, 0), "now");
+    assert_eq!(format_relative(En, -1), "1 second ago");
+    assert_eq!(format_relative(En, 119), "in 1 minute");
+    assert_eq!(format_relative(En, -13 * DAY), "1 week ago");
+    assert_eq!(format_relative(En, 45 * DAY), "in 1 month");
+    assert_eq!(format_relative(En, -800 * DAY), "2 years ago");
+    assert_eq!(
+        format_relative(En, Duration::from_secs(3 * 60)),
+        "in 3 minutes"
+    );
+}
+
+#[test]
+fn languages() {
+    assert_eq!(format_relative(Es, -DAY), "hace 1 día");
+    assert_eq!(format_relative(Fr, -2 * HOUR), "il y a 2 heures");
+    assert_eq!(format_relative(De, -3 * DAY), "vor 3 Tagen");
+    assert_eq!(format_relative(It, -3 * DAY), "3 giorni fa");
+    assert_eq!(format_relative(Pt, -3 * DAY), "há 3 dias");
+    assert_eq!(format_relative(Nl, 2 * HOUR), "over 2 uur");
+    assert_eq!(format_relative(Zh, -3 * DAY), "3天前");
+    assert_eq!(format_relative(Ko, 3 * DAY), "3일 후");
+    assert_eq!(format_relative(Ja, -3 * DAY), "3 日前");
+}
+
+#[test]
+fn plurals() {
+    let russian = [
+        (1, "через 1 минуту"),
+        (2, "через 2 минуты"),
+        (5, "через 5 минут"),
+        (21, "через 21 минуту"),
+        (11, "через 11 минут"),
+    ];
+    for (value, text) in russian {
+        assert_eq!(format_relative_unit(Ru, value, RelativeUnit::Minute), text);
+    }
+    assert_eq!(
+        format_relative_unit(Ar, -3, RelativeUnit::Day),
+        format!("قبل {} أيام", numbers::format_number(Ar, 3.0))
+    );
+    let arabic = [
+        (-1, "قبل يوم واحد"),
+        (-2, "قبل يومين"),
+        (-11, "قبل 11 يومًا"),
+        (-100, "قبل 100 يوم"),
+    ];
+    for (value, text) in arabic {
+        assert_eq!(format_relative_unit(Ar, value, RelativeUnit::Day), text);
+    }
+    assert_eq!(
+        format_relative_unit(He, 2, RelativeUnit::Year),
+        "בעוד שנתיים"
+    );
+    // the languages with generated plural rules.
+    assert_eq!(format_relative(Lt, -2 * DAY), "prieš 2 dienas");
+    assert_eq!(format_relative(Ro, -2 * DAY), "acum 2 zile");
+    assert_eq!(format_relative(Sl, -2 * DAY), "pred 2 dnevoma");
+    assert_eq!(format_relative(Sl, -3 * DAY), "pred 3 dnevi");
+}
+
+#[test]
+fn current_unit() {
+    assert_eq!(format_relative(Ar, 0), "الآن");
+    assert_eq!(format_relative_unit(De, 0, RelativeUnit::Day), "heute");
+    assert_eq!(format_relative_unit(En, 0, RelativeUnit::Week), "this week");
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(format_relative(Sw, -3 * DAY), "siku 3 zilizopita");
+    assert_eq!(format_relative(Uk, -2 * HOUR), "2 години тому");
+    assert_eq!(format_relative(Pl, 5 * 60), "za 5 minut");
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(format_relative(Aa, -3 * DAY), "-3 d");
+    assert_eq!(format_relative(Aa, 0), "now");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn chrono() {
+    let delta = chrono::TimeDelta::try_hours(-5).unwrap();
+    assert_eq!(format_relative(En, delta), "5 hours ago");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn time() {
+    assert_eq!(format_relative(Fr, time::Duration::days(2)), "dans 2 jours");
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "rocket")]
+use rocket::{
+    get,
+    http::{Header, Status},
+    local::asynchronous::Client,
+    routes,
+};
+use rocket_lang::*;
+
+#[get("/<_..>")]
+fn any(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+#[get("/status")]
+fn status(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+fn config() -> Config {
+    let mut api = Config::new().wildcard(En);
+    api[Es] = 1.0;
+    api[Fr] = 0.5;
+    Config::new()
+        .url(0)
+        .scope("/api", api)
+        .scope("/admin/", Config::new().wildcard(En))
+        .scope("/api/legacy", Config::new().wildcard(De))
+}
+
+async fn get(client: &Client, path: &'static str, header: &'static str) -> String {
+    client
+        .get(path)
+        .header(Header::new("accept-language", header))
+        .dispatch()
+        .await
+        .into_string()
+        .await
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn scoped_configs() {
+    let rocket = rocket::build()
+        .mount("/", routes![any])
+        .attach(config());
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    assert_eq!(get(client, "/fr/home", "es").await, "fr");
+    assert_eq!(get(client, "/api/users", "es").await, "es");
+    assert_eq!(get(client, "/api/users", "fr, es;q=0.1").await, "fr");
+    assert_eq!(get(client, "/api/users", "pt").await, "en");
+    assert_eq!(get(client, "/api", "es").await, "es");
+    assert_eq!(get(client, "/api/legacy/users", "es").await, "de");
+    assert_eq!(get(client, "/admin/es", "es").await, "en");
+    // not in the scope, so the language is taken from the url
+    let status = client
+        .get("/apis")
+        .header(Header::new("accept-language", "es"))
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::NotFound);
+}
+
+#[test]
+fn scope_for_path() {
+    let config = config();
+    assert_eq!(
+        config
+            .for_path("/api/x")
+            .wildcard,
+        Some(En)
+    );
+    assert_eq!(
+        config
+            .for_path("/api/legacy")
+            .wildcard,
+        Some(De)
+    );
+    assert_eq!(
+        config
+            .for_path("/admin")
+            .wildcard,
+        Some(En)
+    );
+    assert_eq!(config.for_path("/").wildcard, None);
+}
+
+#[tokio::test]
+async fn route_scope() {
+    let config = config()
+        .route_scope("status", Config::new().wildcard(Ja))
+        .scope("/status", Config::new().wildcard(Ko));
+    let rocket = rocket::build()
+        .mount("/", routes![status, any])
+        .mount("/v2", routes![status])
+        .attach(config);
+    let client = &Client::tracked(rocket)
+        .await
+        .unwrap();
+    // the route scope takes precedence over the path scope
+    assert_eq!(get(client, "/status", "es").await, "ja");
+    assert_eq!(get(client, "/v2/status", "es").await, "ja");
+    assert_eq!(get(client, "/es/status", "fr").await, "es");
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "rocket")]
+use rocket::{
+    http::{ContentType, Status},
+    local::asynchronous::Client,
+};
+use rocket_lang::{sitemap::Sitemap, *};
+
+fn config() -> Config {
+    let mut config = Config::new()
+        .url(0)
+        .wildcard(En);
+    config[En] = 1.0;
+    config[Es] = 1.0;
+    config
+}
+
+#[test]
+fn sitemap_urlset() {
+    let sitemap = Sitemap::new("https://example.com/", ["/", "/about?a=1&b=2"]);
+    assert_eq!(sitemap.pages(&config()), 1);
+    let xml = sitemap
+        .urlset(&config(), 1)
+        .unwrap();
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset"));
+    assert_eq!(xml.matches("<url>").count(), 4);
+    assert!(xml.contains("<loc>https://example.com/en/</loc>"));
+    assert!(xml.contains("<loc>https://example.com/es/about?a=1&amp;b=2</loc>"));
+    assert!(xml.contains(
+        r#"<xhtml:link rel="alternate" hreflang="es" href="https://example.com/es/"/>"#
+    ));
+    assert!(xml.contains(
+        r#"<xhtml:link rel="alternate" hreflang="x-default" href="https://example.com/en/"/>"#
+    ));
+    assert!(sitemap
+        .urlset(&config(), 2)
+        .is_none());
+}
+
+#[test]
+fn sitemap_negative_position() {
+    let mut config = Config::new().url(-1);
+    config[Fr] = 1.0;
+    let xml = Sitemap::new("https://example.com", ["/docs/intro"])
+        .urlset(&config, 1)
+        .unwrap();
+    assert!(xml.contains("<loc>https://example.com/docs/intro/fr</loc>"));
+}
+
+#[test]
+fn sitemap_without_url_position() {
+    let xml = Sitemap::new("https://example.com", ["/a", "/b"])
+        .urlset(&Config::new(), 1)
+        .unwrap();
+    assert_eq!(xml.matches("<url>").count(), 2);
+    assert!(!xml.contains("xhtml:link rel"));
+}
+
+#[test]
+fn sitemap_index() {
+    let sitemap = Sitemap::new("https://example.com", ["/a", "/b", "/c"]).max_urls(4);
+    assert_eq!(sitemap.pages(&config()), 2);
+    let index = sitemap.index(&config(), "/");
+    assert!(index.contains("<loc>https://example.com/sitemap/1.xml</loc>"));
+    assert!(index.contains("<loc>https://example.com/sitemap/2.xml</loc>"));
+    let first = sitemap
+        .urlset(&config(), 1)
+        .unwrap();
+    let second = sitemap
+        .urlset(&config(), 2)
+        .unwrap();
+    assert_eq!(first.matches("<url>").count(), 4);
+    assert_eq!(second.matches("<url>").count(), 2);
+}
+
+#[tokio::test]
+async fn sitemap_routes() {
+    let sitemap = Sitemap::new("https://example.com", ["/a", "/b", "/c"]).max_urls(4);
+    let rocket = rocket::build()
+        .mount("/seo", sitemap.routes())
+        .attach(config());
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+
+    let res = client
+        .get("/seo/sitemap.xml")
+        .dispatch()
+        .await;
+    assert_eq!(res.content_type(), Some(ContentType::XML));
+    let body = res
+        .into_string()
+        .await
+        .unwrap();
+    assert!(body.contains("<sitemapindex"));
+    assert!(body.contains("<loc>https://example.com/seo/sitemap/2.xml</loc>"));
+
+    let body = client
+        .get("/seo/sitemap/2.xml")
+        .dispatch()
+        .await
+        .into_string()
+        .await
+        .unwrap();
+    assert!(body.contains("<loc>https://example.com/en/c</loc>"));
+
+    let res = client
+        .get("/seo/sitemap/3.xml")
+        .dispatch()
+        .await;
+    assert_eq!(res.status(), Status::NotFound);
+}
+
+#[test]
+fn sitemap_scopes() {
+    let config = config().scope("/admin", Config::new());
+    let xml = Sitemap::new("https://example.com", ["/about", "/admin/login"])
+        .urlset(&config, 1)
+        .unwrap();
+    assert_eq!(xml.matches("<url>").count(), 3);
+    assert!(xml.contains("<loc>https://example.com/es/about</loc>"));
+    assert!(xml.contains("<loc>https://example.com/admin/login</loc>"));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "sqlx")]
+use rocket_lang::LangCode::{self, *};
+use sqlx::{sqlite::SqlitePool, Row};
+
+async fn pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, language TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+#[tokio::test]
+async fn sqlx_round_trip() {
+    let pool = pool().await;
+    for (id, lang) in [(1, Es), (2, Zh), (3, En)] {
+        sqlx::query("INSERT INTO users (id, language) VALUES (?, ?)")
+            .bind(id)
+            .bind(lang)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    let stored: String = sqlx::query_scalar("SELECT language FROM users WHERE id = 2")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, "zh");
+
+    let langs: Vec<LangCode> = sqlx::query_scalar("SELECT language FROM users ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(langs, [Es, Zh, En]);
+}
+
+#[tokio::test]
+async fn sqlx_invalid_code() {
+    let pool = pool().await;
+    sqlx::query("INSERT INTO users (id, language) VALUES (1, 'xx')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let row = sqlx::query("SELECT language FROM users")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(row
+        .try_get::<LangCode, _>("language")
+        .is_err());
+}
+
+#[cfg(feature = "sqlx-postgres")]
+#[test]
+fn sqlx_postgres_enum_definition() {
+    use rocket_lang::LangCodeEnum;
+    let ddl = LangCodeEnum::create_type();
+    assert!(ddl.starts_with("CREATE TYPE lang_code AS ENUM ('aa', 'ab', "));
+    assert!(ddl.ends_with("'zu')"));
+    assert_eq!(ddl.matches('\'').count(), LangCode::ALL_CODES.len() * 2);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "rocket")]
+use rocket::{
+    get,
+    http::{Header, Status},
+    local::asynchronous::Client,
+    routes, Request,
+};
+use rocket_lang::{supported_languages, Config, LangCode};
+
+supported_languages! {
+    /// The languages this application is translated to.
+    pub enum AppLang { En, Es, Fr }
+}
+
+#[get("/")]
+fn greeting(lang: AppLang) -> &'static str {
+    match lang {
+        AppLang::En => "Hello",
+        AppLang::Es => "Hola",
+        AppLang::Fr => "Bonjour",
+    }
+}
+
+async fn client(config: Config) -> Client {
+    let rocket = rocket::build()
+        .mount("/", routes![greeting])
+        .attach(config);
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+async fn get_with(client: &Client, header: &'static str) -> (Status, String) {
+    let mut req = client.get("/");
+    req.add_header(Header::new("accept-language", header));
+    let res = req.dispatch().await;
+    let status = res.status();
+    (
+        status,
+        res.into_string()
+            .await
+            .unwrap_or_default(),
+    )
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(AppLang::ALL_CODES.len(), 3);
+    for &lang in AppLang::ALL_CODES {
+        let code = LangCode::from(lang);
+        assert_eq!(AppLang::try_from(code).unwrap(), lang);
+        assert_eq!(lang.as_str(), code.as_str());
+        assert_eq!(lang.to_string().parse::<AppLang>().unwrap(), lang);
+    }
+    assert!(AppLang::try_from(LangCode::De).is_err());
+    assert!("de".parse::<AppLang>().is_err());
+    assert_eq!(AppLang::Es.english_name(), "Spanish");
+    assert_eq!(AppLang::Fr.native_name(), "français");
+}
+
+#[test]
+fn config_preset() {
+    let config = AppLang::config();
+    for &code in LangCode::ALL_CODES {
+        let expected = if AppLang::try_from(code).is_ok() { 1.0 } else { 0.0 };
+        assert_eq!(config[code], expected);
+    }
+}
+
+#[tokio::test]
+async fn request_guard() {
+    let client = &client(AppLang::config()).await;
+    assert_eq!(get_with(client, "es").await.1, "Hola");
+    assert_eq!(get_with(client, "de, fr;q=0.5").await.1, "Bonjour");
+    assert_eq!(get_with(client, "de").await.0, Status::NotAcceptable);
+}
+
+#[tokio::test]
+async fn request_guard_outside_of_subset() {
+    let client = &client(Config::new().wildcard(LangCode::De)).await;
+    assert_eq!(get_with(client, "es").await.0, Status::NotAcceptable);
+}
+
+#[tokio::test]
+async fn request_guard_async_resolver() {
+    let config = AppLang::config().custom_async(|_: &Request| async {
+        // resolvers that don't complete right away must still be awaited
+        tokio::task::yield_now().await;
+        Ok(LangCode::Fr)
+    });
+    let client = &client(config).await;
+    assert_eq!(get_with(client, "es").await.1, "Bonjour");
+}
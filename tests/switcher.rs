@@ -0,0 +1,238 @@
+#![cfg(feature = "rocket")]
+mod common;
+use common::*;
+use rocket::{
+    http::{Cookie, Header, Status},
+    local::asynchronous::{Client, LocalResponse},
+};
+use rocket_lang::switcher;
+
+async fn client(config: Config) -> Client {
+    let rocket = rocket::build()
+        .mount("/", switcher::routes())
+        .mount("/", rocket::routes![current])
+        .attach(config);
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+#[rocket::get("/current")]
+fn current(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+fn location<'a>(res: &'a LocalResponse<'_>) -> Option<&'a str> {
+    res.headers().get_one("Location")
+}
+
+#[tokio::test]
+async fn switcher_get() {
+    let client = client(Config::new().cookie("lang")).await;
+    let res = client
+        .get("/lang/es?redirect=/some/page")
+        .dispatch()
+        .await;
+    assert_eq!(res.status(), Status::SeeOther);
+    assert_eq!(location(&res), Some("/some/page"));
+    assert_eq!(
+        res.cookies()
+            .get("lang")
+            .map(Cookie::value),
+        Some("es")
+    );
+
+    let body = client
+        .get("/current")
+        .dispatch()
+        .await
+        .into_string()
+        .await;
+    assert_eq!(body.as_deref(), Some("es"));
+}
+
+#[tokio::test]
+async fn switcher_post() {
+    let client = client(Config::new().cookie("language")).await;
+    let res = client
+        .post("/lang")
+        .header(rocket::http::ContentType::Form)
+        .body("lang=fr&redirect=/home")
+        .dispatch()
+        .await;
+    assert_eq!(location(&res), Some("/home"));
+    assert_eq!(
+        res.cookies()
+            .get("language")
+            .map(Cookie::value),
+        Some("fr")
+    );
+}
+
+#[tokio::test]
+async fn switcher_invalid_code() {
+    let client = client(Config::new().cookie("lang")).await;
+    let res = client
+        .get("/lang/xx?redirect=/")
+        .dispatch()
+        .await;
+    assert_eq!(res.status(), Status::NotAcceptable);
+    assert!(res
+        .cookies()
+        .get("lang")
+        .is_none());
+}
+
+#[tokio::test]
+async fn switcher_open_redirect() {
+    let client = client(Config::new()).await;
+    for target in [
+        "https://evil.com",
+        "//evil.com/path",
+        "/\\evil.com",
+        "evil.com",
+    ] {
+        let res = client
+            .get(format!(
+                "/lang/de?redirect={}",
+                rocket::http::RawStr::new(target).percent_encode()
+            ))
+            .dispatch()
+            .await;
+        assert_eq!(location(&res), Some("/"), "{target}");
+        assert_eq!(
+            res.cookies()
+                .get(switcher::DEFAULT_COOKIE)
+                .map(Cookie::value),
+            Some("de")
+        );
+    }
+}
+
+#[tokio::test]
+async fn switcher_referer() {
+    let client = client(Config::new()).await;
+    let res = client
+        .get("/lang/de")
+        .header(Header::new("Host", "example.com"))
+        .header(Header::new("Referer", "http://example.com/back?page=2"))
+        .dispatch()
+        .await;
+    assert_eq!(location(&res), Some("/back?page=2"));
+
+    let res = client
+        .get("/lang/de")
+        .header(Header::new("Host", "example.com"))
+        .header(Header::new("Referer", "http://evil.com/back"))
+        .dispatch()
+        .await;
+    assert_eq!(location(&res), Some("/"));
+}
+
+#[tokio::test]
+async fn cookie_precedence() {
+    let mut config = Config::new()
+        .cookie("lang")
+        .url(0);
+    config[En] = 1.0;
+    config[Pt] = 0.5;
+    let client = configured(config).await;
+    let res = client
+        .get("/")
+        .cookie(Cookie::new("lang", "pt"))
+        .header(Header::new("accept-language", "en"))
+        .dispatch()
+        .await;
+    assert_eq!(res.into_string().await.as_deref(), Some("pt"));
+
+    let res = client
+        .get("/es/path/segment")
+        .cookie(Cookie::new("lang", "pt"))
+        .dispatch()
+        .await;
+    assert_eq!(res.into_string().await.as_deref(), Some("es"));
+
+    let res = client
+        .get("/")
+        .cookie(Cookie::new("lang", "invalid"))
+        .header(Header::new("accept-language", "en"))
+        .dispatch()
+        .await;
+    assert_eq!(res.into_string().await.as_deref(), Some("en"));
+}
+
+#[tokio::test]
+async fn cookie_unsupported() {
+    let config = Config::new()
+        .cookie("lang")
+        .supported(&[En, Es]);
+    let client = configured(config).await;
+    let res = client
+        .get("/")
+        .cookie(Cookie::new("lang", "de"))
+        .header(Header::new("accept-language", "es"))
+        .dispatch()
+        .await;
+    assert_eq!(res.into_string().await.as_deref(), Some("es"));
+}
+
+#[tokio::test]
+async fn switcher_unsupported() {
+    let client = client(
+        Config::new()
+            .cookie("lang")
+            .supported(&[En, Es]),
+    )
+    .await;
+    let res = client
+        .get("/lang/de?redirect=/")
+        .dispatch()
+        .await;
+    assert_eq!(res.status(), Status::NotAcceptable);
+    assert!(res
+        .cookies()
+        .get("lang")
+        .is_none());
+}
+
+#[tokio::test]
+async fn switcher_scoped_cookie() {
+    let admin = Config::new()
+        .cookie("admin_lang")
+        .supported(&[En]);
+    let config = Config::new()
+        .cookie("lang")
+        .scope("/admin", admin);
+    let client = client(config).await;
+    let res = client
+        .get("/lang/en?redirect=/admin/users")
+        .dispatch()
+        .await;
+    assert_eq!(
+        res.cookies()
+            .get("admin_lang")
+            .map(Cookie::value),
+        Some("en")
+    );
+    assert!(res
+        .cookies()
+        .get("lang")
+        .is_none());
+
+    let res = client
+        .get("/lang/es?redirect=/admin/users")
+        .dispatch()
+        .await;
+    assert_eq!(res.status(), Status::NotAcceptable);
+
+    let res = client
+        .get("/lang/es?redirect=/home")
+        .dispatch()
+        .await;
+    assert_eq!(
+        res.cookies()
+            .get("lang")
+            .map(Cookie::value),
+        Some("es")
+    );
+}
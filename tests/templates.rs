@@ -0,0 +1,238 @@
+#![cfg(feature = "templates")]
+use rocket::{
+    figment::Figment,
+    get,
+    http::{Header, Status},
+    local::asynchronous::Client,
+    routes, Request,
+};
+use rocket_dyn_templates::{context, Template};
+use rocket_lang::{
+    templates::{self, LangTemplate, LocalizedTemplate, Translations},
+    *,
+};
+use std::collections::HashMap;
+
+#[get("/<_>/tera")]
+fn tera_page() -> LangTemplate {
+    Template::render("tera_page", context! { name: "Ana" }).into()
+}
+
+#[get("/<_>/hbs")]
+fn hbs_page() -> LangTemplate {
+    Template::render("hbs_page", context! { name: "Ana" }).into()
+}
+
+#[get("/<_>/plain")]
+fn plain_page() -> Template {
+    Template::render("tera_page", context! { name: "Ana" })
+}
+
+#[get("/home")]
+fn home() -> LocalizedTemplate {
+    LocalizedTemplate::render("home", context! { user: "Ana" })
+}
+
+#[get("/guarded")]
+fn guarded_home(_lang: LangCode) -> LocalizedTemplate {
+    LocalizedTemplate::render("home", context! { user: "Ana" })
+}
+
+#[get("/missing")]
+fn missing() -> LocalizedTemplate {
+    LocalizedTemplate::render("missing", context! {})
+}
+
+#[get("/invalid")]
+fn invalid() -> LocalizedTemplate {
+    // maps with non-string keys can't be template contexts
+    let context: HashMap<(i32, i32), &str> = [((1, 2), "a")].into();
+    LocalizedTemplate::render("home", context)
+}
+
+async fn client() -> Client {
+    let mut config = Config::new()
+        .base_url("https://example.com")
+        .url(0);
+    config[En] = 1.0;
+    config[Es] = 1.0;
+    config[Ar] = 1.0;
+    let translations = Translations::new()
+        .with(En, "greeting", "Hello")
+        .with(Es, "greeting", "Hola")
+        .with(Fr, "greeting", "Bonjour")
+        .with(Ar, "greeting", "مرحبا");
+    let figment = Figment::from(rocket::Config::default()).merge(("template_dir", "tests/templates"));
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![tera_page, hbs_page])
+        .attach(config)
+        .attach(templates::fairing(translations));
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+async fn localized_client(config: Config) -> Client {
+    let figment = Figment::from(rocket::Config::default()).merge(("template_dir", "tests/templates"));
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![home, guarded_home, missing, invalid])
+        .attach(config)
+        .attach(templates::fairing(Translations::new()));
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
+async fn localized(client: &Client, header: &'static str) -> (String, Option<String>) {
+    let res = client
+        .get("/home")
+        .header(Header::new("accept-language", header))
+        .dispatch()
+        .await;
+    let lang = res
+        .headers()
+        .get_one("Content-Language")
+        .map(String::from);
+    (res.into_string().await.unwrap(), lang)
+}
+
+#[tokio::test]
+async fn localized_template_fallback() {
+    let mut config = Config::new().wildcard(De);
+    config[Es] = 1.0;
+    config[Fr] = 1.0;
+    let client = &localized_client(config).await;
+
+    let (body, lang) = localized(client, "es-MX, es;q=0.9").await;
+    assert_eq!(body, "es-MX Ana es\n");
+    assert_eq!(lang.as_deref(), Some("es-MX"));
+
+    let (body, lang) = localized(client, "es-AR").await;
+    assert_eq!(body, "es Ana es\n");
+    assert_eq!(lang.as_deref(), Some("es"));
+
+    let (body, lang) = localized(client, "fr").await;
+    assert_eq!(body, "de Ana de\n");
+    assert_eq!(lang.as_deref(), Some("de"));
+
+    let mut config = Config::new();
+    config[Fr] = 1.0;
+    let client = &localized_client(config).await;
+    let (body, lang) = localized(client, "fr").await;
+    assert_eq!(body, "default Ana fr\n");
+    // the default template has no tag, so the fairing reports the negotiated language
+    assert_eq!(lang.as_deref(), Some("fr"));
+}
+
+#[tokio::test]
+async fn localized_template_async_resolver() {
+    let config = Config::new().custom_async(|_: &Request| async {
+        rocket::tokio::task::yield_now().await;
+        Ok(Es)
+    });
+    let client = localized_client(config).await;
+    // the responder can't wait for the resolver, so the handler has to take the guard.
+    let status = client
+        .get("/home")
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::InternalServerError);
+
+    let res = client
+        .get("/guarded")
+        .dispatch()
+        .await;
+    assert_eq!(res.headers().get_one("Content-Language"), Some("es"));
+    assert_eq!(res.into_string().await.unwrap(), "es Ana es\n");
+}
+
+#[tokio::test]
+async fn localized_template_missing() {
+    let client = localized_client(Config::new()).await;
+    let status = client
+        .get("/missing")
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::InternalServerError);
+}
+
+#[tokio::test]
+async fn localized_template_invalid_context() {
+    let client = localized_client(Config::new()).await;
+    let status = client
+        .get("/invalid")
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::InternalServerError);
+}
+
+async fn render(client: &Client, path: &str) -> String {
+    client
+        .get(path)
+        .dispatch()
+        .await
+        .into_string()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn template_helpers() {
+    let client = &client().await;
+    for engine in ["tera", "hbs"] {
+        let html = render(client, &format!("/es/{engine}")).await;
+        assert!(html.contains(r#"<html lang="es" dir="ltr">"#), "{engine}: {html}");
+        assert!(html.contains("<h1>Hola, Ana</h1>"), "{engine}: {html}");
+        assert!(html.contains("<p>Bonjour missing rtl</p>"), "{engine}: {html}");
+        assert!(html.contains(r#"<a href="https://example.com/es/"#), "{engine}: {html}");
+        assert!(html.contains(">Español</a>"), "{engine}: {html}");
+        assert!(html.contains("<p>12.345,5 25\u{A0}%</p>"), "{engine}: {html}");
+
+        let html = render(client, &format!("/ar/{engine}")).await;
+        assert!(html.contains(r#"<html lang="ar" dir="rtl">"#), "{engine}: {html}");
+        assert!(html.contains("<h1>مرحبا, Ana</h1>"), "{engine}: {html}");
+        assert!(html.contains("<p>12,345.5 25\u{200E}%\u{200E}</p>"), "{engine}: {html}");
+    }
+}
+
+#[tokio::test]
+async fn unnegotiated_language() {
+    let mut config = Config::new();
+    config[Es] = 1.0;
+    let figment = Figment::from(rocket::Config::default()).merge(("template_dir", "tests/templates"));
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![tera_page, plain_page])
+        .attach(config)
+        .attach(templates::fairing(Translations::new()));
+    let client = Client::tracked(rocket)
+        .await
+        .unwrap();
+    // the error of the negotiation is the status, rather than english content.
+    let status = client
+        .get("/es/tera")
+        .header(Header::new("accept-language", "de"))
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::NotAcceptable);
+    // outside of a `LangTemplate`, the helpers don't know the language.
+    let status = client
+        .get("/es/plain")
+        .header(Header::new("accept-language", "es"))
+        .dispatch()
+        .await
+        .status();
+    assert_eq!(status, Status::InternalServerError);
+}
+
+#[test]
+fn translations() {
+    let mut translations = Translations::new().with(En, "yes", "Yes");
+    translations.extend(Es, [("yes", "Sí"), ("no", "No")]);
+    assert_eq!(translations.get(En, "yes"), Some("Yes"));
+    assert_eq!(translations.get(Es, "yes"), Some("Sí"));
+    assert_eq!(translations.get(En, "no"), None);
+}
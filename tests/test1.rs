@@ -1,3 +1,4 @@
+#![cfg(feature = "rocket")]
 use std::collections::{BTreeSet, HashSet};
 
 use rocket::http::{Header, Status};
@@ -0,0 +1,130 @@
+#![cfg(feature = "units")]
+use rocket_lang::{
+    units::{format_unit, Unit, UnitWidth},
+    *,
+};
+
+#[test]
+fn long_names() {
+    assert_eq!(format_unit(Es, 1.0, Unit::Mile, UnitWidth::Long), "1 milla");
+    assert_eq!(
+        format_unit(Es, 3.0, Unit::Mile, UnitWidth::Long),
+        "3 millas"
+    );
+    assert_eq!(
+        format_unit(Fr, 1.5, Unit::Liter, UnitWidth::Long),
+        "1,5\u{A0}litre"
+    );
+    assert_eq!(
+        format_unit(De, 2.0, Unit::Mile, UnitWidth::Long),
+        "2 Meilen"
+    );
+    assert_eq!(
+        format_unit(Ja, 30.0, Unit::Kilometer, UnitWidth::Long),
+        "30 キロメートル"
+    );
+}
+
+#[test]
+fn slavic_forms() {
+    let forms = [
+        (1.0, "1 метр"),
+        (3.0, "3 метра"),
+        (5.0, "5 метров"),
+        (11.0, "11 метров"),
+        (21.0, "21 метр"),
+        (1.5, "1,5 метра"),
+    ];
+    for (value, expected) in forms {
+        assert_eq!(
+            format_unit(Ru, value, Unit::Meter, UnitWidth::Long),
+            expected
+        );
+    }
+}
+
+#[test]
+fn few_forms() {
+    let forms = [
+        (Hr, 3.0, "3 kilometra"),
+        (Hr, 5.0, "5 kilometara"),
+        (Hr, 21.0, "21 kilometar"),
+        (Lt, 1.5, "1,5 kilometro"),
+        (Lt, 21.0, "21 kilometras"),
+        (Ro, 3.0, "3 kilometri"),
+        (Ro, 21.0, "21 de kilometri"),
+        (Sl, 2.0, "2 kilometra"),
+        (Sl, 3.0, "3 kilometri"),
+        (Sl, 5.0, "5 kilometrov"),
+    ];
+    for (lang, value, expected) in forms {
+        assert_eq!(
+            format_unit(lang, value, Unit::Kilometer, UnitWidth::Long),
+            expected
+        );
+    }
+}
+
+#[test]
+fn arabic_forms() {
+    // the singular and dual forms are written without the number.
+    let forms = [
+        (1.0, "غرام"),
+        (2.0, "غرامان"),
+        (5.0, "5 غرامات"),
+        (15.0, "15 غرامًا"),
+        (100.0, "100 غرام"),
+    ];
+    for (value, expected) in forms {
+        assert_eq!(
+            format_unit(Ar, value, Unit::Gram, UnitWidth::Long),
+            expected
+        );
+    }
+}
+
+#[test]
+fn symbols() {
+    assert_eq!(format_unit(En, 5.0, Unit::Liter, UnitWidth::Short), "5 L");
+    assert_eq!(
+        format_unit(Fr, 5.0, Unit::Kilometer, UnitWidth::Short),
+        "5\u{202F}km"
+    );
+    assert_eq!(
+        format_unit(Fr, 5.0, Unit::Kilometer, UnitWidth::Narrow),
+        "5km"
+    );
+    assert_eq!(
+        format_unit(Ru, 5.0, Unit::Kilometer, UnitWidth::Narrow),
+        "5 км"
+    );
+    assert_eq!(
+        format_unit(Zh, 5.0, Unit::Kilometer, UnitWidth::Short),
+        "5公里"
+    );
+    assert_eq!(format_unit(En, -3.0, Unit::Foot, UnitWidth::Short), "-3 ft");
+    assert_eq!(format_unit(En, 3.0, Unit::Inch, UnitWidth::Narrow), "3″");
+}
+
+#[test]
+fn generated_languages() {
+    assert_eq!(format_unit(Sw, 2.0, Unit::Gram, UnitWidth::Long), "gramu 2");
+    assert_eq!(format_unit(Pl, 2.0, Unit::Gram, UnitWidth::Long), "2 gramy");
+    assert_eq!(
+        format_unit(Pl, 5.0, Unit::Gram, UnitWidth::Long),
+        "5 gramów"
+    );
+    assert_eq!(
+        format_unit(En, 1.0, Unit::Gallon, UnitWidth::Long),
+        "1 gallon"
+    );
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(format_unit(Aa, 2.0, Unit::Gram, UnitWidth::Long), "2 g");
+    assert_eq!(
+        format_unit(Aa, 2.0, Unit::Kilometer, UnitWidth::Narrow),
+        "2 km"
+    );
+}
@@ -1,7 +1,26 @@
+#![cfg(feature = "rocket")]
 mod common;
 use common::*;
 use rocket::http::Status;
+use rocket::local::asynchronous::Client;
+use rocket::{get, routes};
 use rocket_lang::LangCode;
+#[get("/<_..>")]
+fn any(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+/// Matches paths of any length, so url positions past the end of
+/// a short path are not rejected when the config is validated.
+async fn any_path(config: Config) -> Client {
+    let rocket = rocket::build()
+        .mount("/", routes![any])
+        .attach(config);
+    Client::tracked(rocket)
+        .await
+        .unwrap()
+}
+
 async fn test_config(url: &str, lang: &str, config: Config) {
     let body = configured(config)
         .await
@@ -53,7 +72,7 @@ async fn url_not_a_lang_code() {
 
 #[tokio::test]
 async fn url_not_long_enough_positive() {
-    let status = configured(Config::new().url(3))
+    let status = any_path(Config::new().url(3))
         .await
         .get("/fail")
         .dispatch()
@@ -73,7 +92,7 @@ async fn url_not_long_enough_minus_one() {
 }
 #[tokio::test]
 async fn url_not_long_enough_negative() {
-    let status = configured(Config::new().url(-4))
+    let status = any_path(Config::new().url(-4))
         .await
         .get("/")
         .dispatch()
@@ -0,0 +1,92 @@
+#![cfg(feature = "rocket")]
+use rocket::{error::ErrorKind, get, local::asynchronous::Client, routes};
+use rocket_lang::*;
+
+#[get("/<_>/home")]
+fn home(lang: LangCode) -> &'static str {
+    lang.as_str()
+}
+
+#[get("/static/<_..>", rank = 2)]
+fn assets() -> &'static str {
+    "asset"
+}
+
+#[test]
+fn invalid_qualities() {
+    let mut config = Config::new();
+    config[Es] = 7.0;
+    config[En] = -0.5;
+    config["pt-BR"] = f32::NAN;
+    config[Fr] = 1.0;
+    let problems = config.validate().unwrap_err();
+    assert_eq!(problems.len(), 3);
+    assert!(problems.contains(&ConfigError::InvalidQuality {
+        tag: "es".into(),
+        quality: 7.0
+    }));
+    assert_eq!(
+        problems[0].to_string(),
+        "the quality of `en` is -0.5, but it must be between 0.0 and 1.0."
+    );
+
+    let mut valid = Config::new().wildcard(De);
+    valid[Es] = 1.0;
+    valid[En] = 0.0;
+    assert_eq!(valid.validate(), Ok(()));
+}
+
+#[test]
+fn invalid_scope() {
+    let mut api = Config::new();
+    api[Es] = 2.0;
+    let problems = Config::new()
+        .scope("/api", api)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        problems[0].to_string(),
+        "in the scope `/api`: the quality of `es` is 2, but it must be between 0.0 and 1.0."
+    );
+}
+
+async fn launch(config: Config, routes: Vec<rocket::Route>) -> Result<Client, rocket::Error> {
+    let rocket = rocket::build()
+        .mount("/", routes)
+        .attach(config);
+    Client::tracked(rocket).await
+}
+
+fn failed_fairing(result: Result<Client, rocket::Error>) -> bool {
+    match result {
+        Ok(_) => false,
+        Err(error) => matches!(error.kind(), ErrorKind::FailedFairings(_)),
+    }
+}
+
+#[tokio::test]
+async fn aborts_launch() {
+    let mut config = Config::new();
+    config[Es] = f32::NAN;
+    assert!(failed_fairing(launch(config, routes![home]).await));
+}
+
+#[tokio::test]
+async fn url_position_out_of_range() {
+    assert!(launch(Config::new().url(1), routes![home])
+        .await
+        .is_ok());
+    assert!(launch(Config::new().url(-2), routes![home])
+        .await
+        .is_ok());
+    assert!(failed_fairing(
+        launch(Config::new().url(2), routes![home]).await
+    ));
+    assert!(failed_fairing(
+        launch(Config::new().url(-3), routes![home]).await
+    ));
+    // routes with trailing segments can be of any length
+    assert!(launch(Config::new().url(5), routes![home, assets])
+        .await
+        .is_ok());
+}
@@ -0,0 +1,238 @@
+//! Currency symbols, patterns and fraction digits, for `src/currency/data.rs`.
+use crate::{cldr_code, ids, load, numbers, quote, regions};
+use icu_experimental::{
+    dimension::provider::currency::{
+        essentials::CurrencyEssentialsV1,
+        fractions::{CurrencyFractionsV1, FractionInfo},
+        symbols::CurrencySymbolsV1,
+    },
+    provider::Baked,
+};
+use rocket_lang::LangCode;
+use std::{collections::BTreeSet, fmt::Write};
+use writeable::Writeable;
+use zerovec::ule::AsULE;
+
+/// Returns the symbol and narrow symbol of a currency in a locale. The currencies
+/// without symbol are written with their code, and without narrow symbol with their symbol.
+fn symbols(locale: &str, code: &str) -> (String, String) {
+    let symbol = |attributes: &str| {
+        load::<CurrencySymbolsV1>(&Baked, locale, attributes).map(|(data, _)| {
+            data.get()
+                .as_str()
+                .to_string()
+        })
+    };
+    let short = symbol(&format!("s/{code}")).unwrap_or_else(|| code.to_string());
+    let narrow = symbol(&format!("n/{code}")).unwrap_or_else(|| short.clone());
+    (short, narrow)
+}
+
+/// Returns the standard, standard alpha, accounting and accounting alpha patterns
+/// of a locale as a Rust expression, with the number pattern of the locale.
+fn patterns(lang: &str, locale: &str) -> String {
+    let (essentials, _) = load::<CurrencyEssentialsV1>(&Baked, locale, "").unwrap();
+    let essentials = essentials.get();
+    let format = numbers::format(lang, locale);
+    let (integer, _) = format
+        .pattern
+        .split_once('.')
+        .unwrap();
+    let number = format!("{integer}.00");
+    // the negative patterns have the minus sign of the locale, written `-` in CLDR.
+    let pattern = |index: u8| {
+        essentials
+            .patterns
+            .get(index as usize)
+            .unwrap()
+            .interpolate([number.as_str(), "¤"])
+            .write_to_string()
+            .replace(&format.minus_sign, "-")
+    };
+    let subpatterns = |positive: u8, negative: Option<u8>| match negative {
+        Some(negative) => quote(&format!("{};{}", pattern(positive), pattern(negative))),
+        None => quote(&pattern(positive)),
+    };
+    let indices = essentials.indices;
+    format!(
+        "Patterns {{ standard: {}, standard_alpha: {}, accounting: {}, accounting_alpha: {} }}",
+        subpatterns(indices.standard, indices.standard_negative),
+        subpatterns(
+            indices.standard_alpha_next_to_number,
+            indices.standard_alpha_next_to_number_negative
+        ),
+        subpatterns(indices.accounting_positive, indices.accounting_negative),
+        subpatterns(
+            indices.accounting_alpha_next_to_number_positive,
+            indices.accounting_alpha_next_to_number_negative
+        ),
+    )
+}
+
+/// Returns the index of the patterns in `all`, adding them if they are new.
+fn index(all: &mut Vec<String>, patterns: String) -> usize {
+    match all
+        .iter()
+        .position(|p| *p == patterns)
+    {
+        Some(index) => index,
+        None => {
+            all.push(patterns);
+            all.len() - 1
+        }
+    }
+}
+
+pub fn generate() -> String {
+    let regions = regions();
+    let ids = ids::<CurrencySymbolsV1>();
+    let codes: BTreeSet<&str> = ids
+        .iter()
+        .map(|(_, attributes)| &attributes[2..])
+        .collect();
+    let mut out = String::from("use super::Patterns;\nuse crate::LangCode::{self, *};\n\n");
+
+    // the patterns, shared by the languages and regions that use the same ones.
+    let mut all = vec![];
+    let mut languages = vec![];
+    let mut overrides = vec![];
+    for lang in LangCode::ALL_CODES {
+        let code = cldr_code(*lang);
+        let patterns = patterns(code, code);
+        for region in &regions {
+            let regional = self::patterns(code, &format!("{code}-{region}"));
+            if regional != patterns {
+                overrides.push((lang, region, index(&mut all, regional)));
+            }
+        }
+        languages.push((lang, index(&mut all, patterns)));
+    }
+    for (index, patterns) in all.iter().enumerate() {
+        writeln!(out, "static P{index}: Patterns = {patterns};").unwrap();
+    }
+    writeln!(
+        out,
+        "\n/// The patterns of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [&Patterns; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in languages {
+        writeln!(out, "    /* {} */ &P{index},", lang.as_str()).unwrap();
+    }
+    out.push_str(
+        "];\n\n\
+         /// The patterns of the regions that differ from the patterns of their language.\n\
+         pub(super) static REGIONS: &[(LangCode, &str, &Patterns)] = &[\n",
+    );
+    for (lang, region, index) in overrides {
+        writeln!(out, "    ({lang:?}, {}, &P{index}),", quote(region)).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    // the symbols of the root locale, then the ones that differ in languages and regions.
+    out.push_str(
+        "/// The symbol and narrow symbol of the currencies, sorted by code.\n\
+         pub(super) static SYMBOLS: &[(&str, &str, &str)] = &[\n",
+    );
+    for code in &codes {
+        let (symbol, narrow) = symbols("und", code);
+        if symbol != *code || narrow != *code {
+            writeln!(
+                out,
+                "    ({}, {}, {}),",
+                quote(code),
+                quote(&symbol),
+                quote(&narrow)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str(
+        "];\n\n\
+         /// The symbols of the languages that differ from the root ones, sorted by\n\
+         /// language and code.\n\
+         pub(super) static LANGUAGE_SYMBOLS: &[(LangCode, &str, &str, &str)] = &[\n",
+    );
+    let mut regional = vec![];
+    for lang in LangCode::ALL_CODES {
+        let cldr = cldr_code(*lang);
+        for code in &codes {
+            let symbols = symbols(cldr, code);
+            if symbols != self::symbols("und", code) {
+                writeln!(
+                    out,
+                    "    ({lang:?}, {}, {}, {}),",
+                    quote(code),
+                    quote(&symbols.0),
+                    quote(&symbols.1)
+                )
+                .unwrap();
+            }
+        }
+        // only the currencies with data in a locale of the language can differ in its regions.
+        let prefix = format!("{cldr}-");
+        let candidates: BTreeSet<&str> = ids
+            .iter()
+            .filter(|(locale, _)| locale.starts_with(&prefix))
+            .map(|(_, attributes)| &attributes[2..])
+            .collect();
+        for region in &regions {
+            for &code in &candidates {
+                let symbols = self::symbols(&format!("{cldr}-{region}"), code);
+                if symbols != self::symbols(cldr, code) {
+                    regional.push((lang, region, code, symbols));
+                }
+            }
+        }
+    }
+    out.push_str(
+        "];\n\n\
+         /// The symbols of the regions that differ from the ones of their language.\n\
+         pub(super) static REGION_SYMBOLS: &[(LangCode, &str, &str, &str, &str)] = &[\n",
+    );
+    for (lang, region, code, (symbol, narrow)) in regional {
+        writeln!(
+            out,
+            "    ({lang:?}, {}, {}, {}, {}),",
+            quote(region),
+            quote(code),
+            quote(&symbol),
+            quote(&narrow)
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+
+    let (fractions, _) = load::<CurrencyFractionsV1>(&Baked, "und", "").unwrap();
+    let fractions = fractions.get();
+    writeln!(
+        out,
+        "/// The fraction digits of the currencies without fraction digits of their own.\n\
+         pub(super) const DEFAULT_FRACTION_DIGITS: usize = {};\n\n\
+         /// The fraction digits of the other currencies, sorted by code.\n\
+         pub(super) static FRACTION_DIGITS: &[(&str, usize)] = &[",
+        fractions.default.digits
+    )
+    .unwrap();
+    for (code, info) in fractions.fractions.iter() {
+        let info: FractionInfo = AsULE::from_unaligned(*info);
+        // the currencies that only differ in cash payments.
+        if info.digits == fractions.default.digits {
+            continue;
+        }
+        writeln!(
+            out,
+            "    ({}, {}),",
+            quote(
+                &code
+                    .try_into_tinystr()
+                    .unwrap()
+            ),
+            info.digits
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,278 @@
+//! Month and weekday names, date patterns and week data, for `src/dates/data.rs`.
+use crate::{cldr_code, quote, regions};
+use icu::{
+    calendar::{week::WeekInformation, Date, Gregorian},
+    datetime::{
+        fieldsets::{
+            self,
+            enums::{CompositeDateTimeFieldSet, CompositeFieldSet},
+        },
+        options::TimePrecision,
+        pattern::{DateTimePattern, FixedCalendarDateTimeNames},
+        scaffold::GetField,
+        DateTimeFormatter,
+    },
+    locale::Locale,
+    time::{DateTime, Time},
+};
+use rocket_lang::LangCode;
+use std::{collections::BTreeSet, fmt::Write};
+use writeable::TryWriteable;
+
+/// The field sets of `Fields`, in the order of its variants, at the long,
+/// medium and short lengths.
+fn fields() -> Vec<[CompositeDateTimeFieldSet; 3]> {
+    use fieldsets::{D, DE, E, M, MD, MDE, Y, YM, YMD, YMDE};
+    vec![
+        [set(Y::long()), set(Y::medium()), set(Y::short())],
+        [set(YM::long()), set(YM::medium()), set(YM::short())],
+        [set(YMD::long()), set(YMD::medium()), set(YMD::short())],
+        [set(YMDE::long()), set(YMDE::medium()), set(YMDE::short())],
+        [set(M::long()), set(M::medium()), set(M::short())],
+        [set(MD::long()), set(MD::medium()), set(MD::short())],
+        [set(MDE::long()), set(MDE::medium()), set(MDE::short())],
+        [set(D::long()), set(D::medium()), set(D::short())],
+        [set(DE::long()), set(DE::medium()), set(DE::short())],
+        [set(E::long()), set(E::medium()), set(E::short())],
+    ]
+}
+
+/// Returns a field set without time zone.
+fn set(field_set: impl GetField<CompositeFieldSet>) -> CompositeDateTimeFieldSet {
+    CompositeDateTimeFieldSet::try_from_composite_field_set(field_set.get_field()).unwrap()
+}
+
+fn locale(lang: &str) -> Locale {
+    format!("{lang}-u-ca-gregory")
+        .parse()
+        .unwrap()
+}
+
+fn date_time(month: u8, day: u8, hour: u8) -> DateTime<Gregorian> {
+    DateTime {
+        date: Date::try_new_gregorian(2024, month, day).unwrap(),
+        time: Time::try_new(hour, 5, 9, 0).unwrap(),
+    }
+}
+
+/// Returns the pattern a field set is formatted with in a language.
+fn pattern(lang: &str, field_set: CompositeDateTimeFieldSet) -> String {
+    let formatter = DateTimeFormatter::try_new(locale(lang).into(), field_set).unwrap();
+    formatter
+        .format(&date_time(3, 9, 16))
+        .pattern()
+        .to_string()
+}
+
+/// Formats values with a pattern, such as `MMMM` for the names of months.
+fn names(lang: &str, pattern: &str, values: Vec<DateTime<Gregorian>>) -> Vec<String> {
+    let mut names: FixedCalendarDateTimeNames<Gregorian> =
+        FixedCalendarDateTimeNames::try_new(locale(lang).into()).unwrap();
+    let pattern: DateTimePattern = pattern.parse().unwrap();
+    let formatter = names
+        .include_for_pattern(&pattern)
+        .unwrap();
+    values
+        .iter()
+        .map(|value| {
+            formatter
+                .format(value)
+                .try_write_to_string()
+                .unwrap()
+                .into_owned()
+        })
+        .collect()
+}
+
+/// The names in the wide, abbreviated and narrow widths, as a Rust expression.
+fn widths(lang: &str, letter: char, values: impl Fn() -> Vec<DateTime<Gregorian>>) -> String {
+    let widths = [4, 3, 5].map(|count| {
+        let pattern = letter
+            .to_string()
+            .repeat(count);
+        let names: Vec<String> = names(lang, &pattern, values())
+            .iter()
+            .map(|name| quote(name))
+            .collect();
+        format!("[{}]", names.join(", "))
+    });
+    format!("[{}]", widths.join(", "))
+}
+
+fn weekday(weekday: icu::calendar::types::Weekday) -> &'static str {
+    use icu::calendar::types::Weekday::*;
+    match weekday {
+        Monday => "Weekday::Monday",
+        Tuesday => "Weekday::Tuesday",
+        Wednesday => "Weekday::Wednesday",
+        Thursday => "Weekday::Thursday",
+        Friday => "Weekday::Friday",
+        Saturday => "Weekday::Saturday",
+        Sunday => "Weekday::Sunday",
+    }
+}
+
+/// Returns the `Symbols` of a language as a Rust expression, and adds the letters
+/// of its patterns to `letters`.
+fn symbols(lang: &str, letters: &mut BTreeSet<char>) -> String {
+    use fieldsets::{T, YMD, YMDE};
+    let months = || {
+        (1..=12)
+            .map(|month| date_time(month, 1, 0))
+            .collect()
+    };
+    // the first of January 2024 is a Monday.
+    let weekdays = || {
+        (1..=7)
+            .map(|day| date_time(1, day, 0))
+            .collect()
+    };
+    let am_pm = names(lang, "a", vec![date_time(1, 1, 9), date_time(1, 1, 21)]);
+    let before = DateTime {
+        date: Date::try_new_gregorian(-100, 1, 1).unwrap(),
+        ..date_time(1, 1, 0)
+    };
+    let eras = names(lang, "G", vec![before, date_time(1, 1, 0)]);
+    let mut patterns = |sets: &[CompositeDateTimeFieldSet]| {
+        let patterns: Vec<String> = sets
+            .iter()
+            .map(|&set| {
+                let pattern = pattern(lang, set);
+                letters.extend(
+                    pattern
+                        .split('\'')
+                        .step_by(2)
+                        .flat_map(str::chars)
+                        .filter(char::is_ascii_alphabetic),
+                );
+                quote(&pattern)
+            })
+            .collect();
+        format!("[{}]", patterns.join(", "))
+    };
+    let dates = patterns(&[
+        set(YMDE::long()),
+        set(YMD::long()),
+        set(YMD::medium()),
+        set(YMD::short()),
+    ]);
+    let times = patterns(&[
+        set(T::long().with_time_precision(TimePrecision::Second)),
+        set(T::long().with_time_precision(TimePrecision::Second)),
+        set(T::medium().with_time_precision(TimePrecision::Second)),
+        set(T::short().with_time_precision(TimePrecision::Minute)),
+    ]);
+    let date_times = patterns(&[
+        set(YMDE::long().with_time_hms()),
+        set(YMD::long().with_time_hms()),
+        set(YMD::medium().with_time_hms()),
+        set(YMD::short().with_time_hm()),
+    ]);
+    let fields: Vec<String> = fields()
+        .iter()
+        .map(|sets| patterns(sets))
+        .collect();
+    let first_day = WeekInformation::try_new((&locale(lang)).into())
+        .unwrap()
+        .first_weekday;
+    format!(
+        "Symbols {{\n    \
+         months: {},\n    \
+         standalone_months: {},\n    \
+         weekdays: {},\n    \
+         standalone_weekdays: {},\n    \
+         am_pm: [{}, {}],\n    \
+         eras: [{}, {}],\n    \
+         dates: {dates},\n    \
+         times: {times},\n    \
+         date_times: {date_times},\n    \
+         fields: [{}],\n    \
+         first_day: {},\n\
+         }}",
+        widths(lang, 'M', months),
+        widths(lang, 'L', months),
+        widths(lang, 'E', weekdays),
+        widths(lang, 'c', weekdays),
+        quote(&am_pm[0]),
+        quote(&am_pm[1]),
+        quote(&eras[0]),
+        quote(&eras[1]),
+        fields.join(", "),
+        weekday(first_day),
+    )
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use super::{Symbols, Weekday};\n\n");
+    let mut letters = BTreeSet::new();
+    let root = symbols("und", &mut letters);
+    writeln!(
+        out,
+        "/// The data of the root locale, used by the languages without data of their own.\n\
+         pub(super) static ROOT: Symbols = {root};\n"
+    )
+    .unwrap();
+    // languages with the same data share a static.
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for lang in LangCode::ALL_CODES {
+        let symbols = symbols(cldr_code(*lang), &mut letters);
+        let index = if symbols == root {
+            None
+        } else if let Some(index) = all
+            .iter()
+            .position(|s| *s == symbols)
+        {
+            Some(index)
+        } else {
+            all.push(symbols);
+            Some(all.len() - 1)
+        };
+        languages.push((lang.as_str(), index));
+    }
+    eprintln!("pattern letters: {letters:?}");
+    for (index, symbols) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        writeln!(
+            out,
+            "// {}\nstatic S{index}: Symbols = {symbols};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The data of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [Option<&Symbols>; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&S{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n\n");
+    out.push_str(
+        "/// The regions whose weeks don't start on Monday.\n\
+         pub(super) static FIRST_DAYS: &[(&str, Weekday)] = &[\n",
+    );
+    for region in regions() {
+        let locale: Locale = format!("und-{region}")
+            .parse()
+            .unwrap();
+        let first = WeekInformation::try_new((&locale).into())
+            .unwrap()
+            .first_weekday;
+        if first != icu::calendar::types::Weekday::Monday {
+            writeln!(out, "    ({}, {}),", quote(&region), weekday(first)).unwrap();
+        }
+    }
+    out.push_str("];\n");
+    out
+}
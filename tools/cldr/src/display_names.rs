@@ -0,0 +1,169 @@
+//! Names of languages and regions in every language, for `src/display_names/data.rs`.
+use crate::{cldr_code, load};
+use icu_experimental::{
+    displaynames::provider::{LanguageDisplayNamesV1, RegionDisplayNamesV1},
+    provider::Baked,
+};
+use rocket_lang::LangCode;
+use std::{collections::BTreeMap, fmt::Write};
+
+/// Returns the names of a locale by code, or `None` if it has no names of its own.
+fn language_names(locale: &str) -> Option<BTreeMap<String, String>> {
+    let (data, found) = load::<LanguageDisplayNamesV1>(&Baked, locale, "")?;
+    if found.as_deref() == Some("und") {
+        return None;
+    }
+    Some(
+        data.get()
+            .names
+            .iter()
+            .filter_map(|(code, name)| {
+                let code = code.try_into_tinystr().ok()?;
+                Some((code.to_string(), name.to_string()))
+            })
+            .collect(),
+    )
+}
+
+/// Returns the region names of a locale by code, or `None` if it has no names of its own.
+fn region_names(locale: &str) -> Option<BTreeMap<String, String>> {
+    let (data, found) = load::<RegionDisplayNamesV1>(&Baked, locale, "")?;
+    if found.as_deref() == Some("und") {
+        return None;
+    }
+    Some(
+        data.get()
+            .names
+            .iter()
+            .filter_map(|(code, name)| {
+                let code = code.try_into_tinystr().ok()?;
+                Some((code.to_string(), name.to_string()))
+            })
+            .collect(),
+    )
+}
+
+/// Writes the names as an array, with `""` for the codes without a name.
+fn array<'a>(
+    names: &BTreeMap<String, String>,
+    codes: impl Iterator<Item = &'a [&'a str]>,
+) -> String {
+    let names: Vec<String> = codes
+        .map(|codes| {
+            let name = codes
+                .iter()
+                .find_map(|code| names.get(*code))
+                .map_or("", String::as_str);
+            crate::quote(name)
+        })
+        .collect();
+    format!("[{}]", names.join(", "))
+}
+
+/// Writes the statics of the tables of every language, sharing the same ones,
+/// and the array of the languages pointing to them.
+fn tables(out: &mut String, name: &str, prefix: &str, len: usize, tables: Vec<Option<String>>) {
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for (lang, table) in LangCode::ALL_CODES
+        .iter()
+        .zip(tables)
+    {
+        let index = table.map(|table| {
+            all.iter()
+                .position(|t| *t == table)
+                .unwrap_or_else(|| {
+                    all.push(table);
+                    all.len() - 1
+                })
+        });
+        languages.push((lang.as_str(), index));
+    }
+    for (index, table) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        writeln!(
+            out,
+            "// {}\nstatic {prefix}{index}: [&str; {len}] = {table};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The {name} names of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static {}S: [Option<&[&str; {len}]>; {}] = [",
+        name.to_uppercase(),
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&{prefix}{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n\n");
+}
+
+pub fn generate() -> String {
+    let mut out = String::new();
+    // languages are named by their ISO 639-1 code, or else by their CLDR code.
+    let codes: Vec<[&str; 2]> = LangCode::ALL_CODES
+        .iter()
+        .map(|lang| [lang.as_str(), cldr_code(*lang)])
+        .collect();
+    let languages = LangCode::ALL_CODES
+        .iter()
+        .map(|lang| {
+            let names = language_names(cldr_code(*lang))?;
+            Some(array(
+                &names,
+                codes
+                    .iter()
+                    .map(|codes| &codes[..]),
+            ))
+        })
+        .collect();
+    tables(&mut out, "language", "L", codes.len(), languages);
+    // the regions are the ones named in English, without the unknown region.
+    let regions: Vec<String> = region_names("en")
+        .unwrap()
+        .into_keys()
+        .filter(|region| region != "ZZ")
+        .collect();
+    let quoted: Vec<String> = regions
+        .iter()
+        .map(|region| crate::quote(region))
+        .collect();
+    writeln!(
+        out,
+        "/// The codes of the regions, sorted, in the order of the region names.\n\
+         pub(super) static REGION_CODES: [&str; {}] = [{}];\n",
+        regions.len(),
+        quoted.join(", ")
+    )
+    .unwrap();
+    let region_codes: Vec<[&str; 1]> = regions
+        .iter()
+        .map(|region| [region.as_str()])
+        .collect();
+    let tables_of_regions = LangCode::ALL_CODES
+        .iter()
+        .map(|lang| {
+            let names = region_names(cldr_code(*lang))?;
+            Some(array(
+                &names,
+                region_codes
+                    .iter()
+                    .map(|codes| &codes[..]),
+            ))
+        })
+        .collect();
+    tables(&mut out, "region", "R", regions.len(), tables_of_regions);
+    out.pop();
+    out
+}
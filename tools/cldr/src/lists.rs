@@ -0,0 +1,128 @@
+//! List patterns by type and width, for `src/lists/data.rs`.
+use crate::{cldr_code, quote};
+use icu::list::{
+    options::{ListFormatterOptions, ListLength},
+    ListFormatter,
+};
+use rocket_lang::LangCode;
+use std::fmt::Write;
+use writeable::Writeable;
+
+/// The items of the lists formatted to find the patterns, which are in no pattern.
+/// They start with a Hebrew letter and no Spanish vowel, to get the default patterns
+/// rather than the ones CLDR uses before some words, which `src/lists.rs` chooses.
+const ITEMS: [&str; 4] = [
+    "\u{5D0}\u{E000}",
+    "\u{5D0}\u{E001}",
+    "\u{5D0}\u{E002}",
+    "\u{5D0}\u{E003}",
+];
+
+/// Returns the text of a formatted list before an item, and after it.
+fn split(list: &str, item: usize) -> (&str, &str) {
+    let (before, after) = list
+        .split_once(ITEMS[item])
+        .unwrap();
+    (before, after)
+}
+
+/// The patterns of a list type and width in a locale, as a Rust expression. As in
+/// CLDR, the start pattern can have text before the first item, and the end pattern
+/// after the last one.
+fn patterns(locale: &str, and: bool, length: ListLength) -> String {
+    let locale: icu::locale::Locale = locale.parse().unwrap();
+    let options = ListFormatterOptions::default().with_length(length);
+    let formatter = match and {
+        true => ListFormatter::try_new_and((&locale).into(), options),
+        false => ListFormatter::try_new_or((&locale).into(), options),
+    }
+    .unwrap();
+    let format = |len: usize| {
+        formatter
+            .format(ITEMS[..len].iter())
+            .write_to_string()
+            .into_owned()
+    };
+    let (two, three, four) = (format(2), format(3), format(4));
+    let (start, end) = split(&three, 1);
+    let (_, middle) = split(&four, 1);
+    let (middle, _) = split(middle, 2);
+    format!(
+        "Patterns {{\n            start: {},\n            middle: {},\n            end: {},\n            two: {},\n        }}",
+        quote(&format!("{}{{1}}", start.replace(ITEMS[0], "{0}"))),
+        quote(&format!("{{0}}{middle}{{1}}")),
+        quote(&format!("{{0}}{}", end.replace(ITEMS[2], "{1}"))),
+        quote(
+            &two.replace(ITEMS[0], "{0}")
+                .replace(ITEMS[1], "{1}")
+        )
+    )
+}
+
+/// The patterns of both list types in every width, in the order of the `ListType`
+/// and `ListWidth` variants.
+fn names(locale: &str) -> String {
+    let types = [true, false].map(|and| {
+        let widths = [ListLength::Wide, ListLength::Short, ListLength::Narrow]
+            .map(|length| patterns(locale, and, length));
+        format!("[\n        {},\n    ]", widths.join(",\n        "))
+    });
+    format!("[\n    {},\n]", types.join(",\n    "))
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use super::{Names, Patterns};\n\n");
+    let root = names("und");
+    writeln!(
+        out,
+        "/// The patterns of the root locale, used by the languages without patterns of their own.\n\
+         pub(super) static ROOT: Names = {root};\n"
+    )
+    .unwrap();
+    // languages with the same patterns share a static.
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for lang in LangCode::ALL_CODES {
+        let names = names(cldr_code(*lang));
+        let index = if names == root {
+            None
+        } else if let Some(index) = all
+            .iter()
+            .position(|n| *n == names)
+        {
+            Some(index)
+        } else {
+            all.push(names);
+            Some(all.len() - 1)
+        };
+        languages.push((lang.as_str(), index));
+    }
+    for (index, names) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        writeln!(
+            out,
+            "// {}\nstatic L{index}: Names = {names};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The patterns of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [Option<&Names>; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&L{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,132 @@
+//! Likely scripts and regions of the languages, for `src/locale/data.rs`.
+use crate::{load, quote, regions};
+use icu::locale::{
+    provider::{Baked, LocaleLikelySubtagsExtendedV1, LocaleLikelySubtagsScriptRegionV1},
+    subtags::Script,
+    LanguageIdentifier, LocaleExpander,
+};
+use icu_locale_fallback::provider::LocaleLikelySubtagsLanguageV1;
+use rocket_lang::LangCode;
+use std::fmt::Write;
+
+/// Every script with likely subtags.
+fn scripts() -> Vec<String> {
+    let (script_region, _) = load::<LocaleLikelySubtagsScriptRegionV1>(&Baked, "und", "").unwrap();
+    let (extended, _) = load::<LocaleLikelySubtagsExtendedV1>(&Baked, "und", "").unwrap();
+    let mut scripts: Vec<String> = [&script_region.get().script, &extended.get().script]
+        .into_iter()
+        .flat_map(|script| {
+            script
+                .iter_keys()
+                .filter_map(|script| {
+                    Script::try_from_str(
+                        script
+                            .try_into_tinystr()
+                            .ok()?
+                            .as_str(),
+                    )
+                    .ok()
+                })
+        })
+        .map(|script| script.to_string())
+        .collect();
+    scripts.sort();
+    scripts.dedup();
+    scripts
+}
+
+/// Returns the likely script and region of a tag. The tags of languages without
+/// likely subtags take the ones of `und`, as in the CLDR.
+fn maximize(tag: &str) -> (String, String) {
+    let (language, _) =
+        load::<LocaleLikelySubtagsLanguageV1>(&icu_locale_fallback::provider::Baked, "und", "")
+            .unwrap();
+    let (_, script, region) = language.get().und;
+    let mut id: LanguageIdentifier = tag.parse().unwrap();
+    LocaleExpander::new_extended().maximize(&mut id);
+    (
+        id.script
+            .unwrap_or(script)
+            .to_string(),
+        id.region
+            .unwrap_or(region)
+            .to_string(),
+    )
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use crate::LangCode::{self, *};\n\n");
+    let likely: Vec<(String, String)> = LangCode::ALL_CODES
+        .iter()
+        .map(|lang| maximize(lang.as_str()))
+        .collect();
+    writeln!(
+        out,
+        "/// The likely script and region of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LIKELY_SUBTAGS: [(&str, &str); {}] = [",
+        likely.len()
+    )
+    .unwrap();
+    for (lang, (script, region)) in LangCode::ALL_CODES
+        .iter()
+        .zip(&likely)
+    {
+        writeln!(
+            out,
+            "    /* {lang} */ ({}, {}),",
+            quote(script),
+            quote(region)
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "];\n\n\
+         /// The likely script of a language in a region, when it is not the script of\n\
+         /// the language, sorted by language and region.\n\
+         pub(super) static REGION_SCRIPTS: &[(LangCode, &str, &str)] = &[\n",
+    );
+    let regions = regions();
+    for (lang, (script, _)) in LangCode::ALL_CODES
+        .iter()
+        .zip(&likely)
+    {
+        for region in &regions {
+            let (likely, _) = maximize(&format!("{lang}-{region}"));
+            if likely != *script {
+                writeln!(
+                    out,
+                    "    ({lang:?}, {}, {}),",
+                    quote(region),
+                    quote(&likely)
+                )
+                .unwrap();
+            }
+        }
+    }
+    out.push_str(
+        "];\n\n\
+         /// The likely region of a language in a script, when it is not the region of\n\
+         /// the language, sorted by language and script.\n\
+         pub(super) static SCRIPT_REGIONS: &[(LangCode, &str, &str)] = &[\n",
+    );
+    let scripts = scripts();
+    for (lang, (_, region)) in LangCode::ALL_CODES
+        .iter()
+        .zip(&likely)
+    {
+        for script in &scripts {
+            let (_, likely) = maximize(&format!("{lang}-{script}"));
+            if likely != *region {
+                writeln!(
+                    out,
+                    "    ({lang:?}, {}, {}),",
+                    quote(script),
+                    quote(&likely)
+                )
+                .unwrap();
+            }
+        }
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,219 @@
+//! Generates the CLDR tables of rocket_lang, from the data compiled into ICU4X.
+//!
+//! ```sh
+//! cargo run --manifest-path tools/cldr/Cargo.toml
+//! ```
+//! Every table is written to a `data.rs` file next to the module that uses it.
+use icu_provider::prelude::*;
+use std::{fmt::Write, path::Path};
+
+mod currency;
+mod dates;
+mod display_names;
+mod lists;
+mod locale;
+mod numbers;
+mod plurals;
+mod relative;
+mod units;
+
+/// The CLDR version of the ICU4X data.
+const CLDR_VERSION: &str = "48.2.1";
+
+/// Returns the contents of a `data.rs` file.
+type Generator = fn() -> String;
+
+/// The modules with generated data, and the functions generating it.
+const TABLES: &[(&str, Generator)] = &[
+    ("currency", currency::generate),
+    ("dates", dates::generate),
+    ("display_names", display_names::generate),
+    ("lists", lists::generate),
+    ("locale", locale::generate),
+    ("numbers", numbers::generate),
+    ("plurals", plurals::generate),
+    ("relative", relative::generate),
+    ("units", units::generate),
+];
+
+fn main() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../src");
+    for (module, generate) in TABLES {
+        let mut file =
+            format!("// @generated by tools/cldr from CLDR {CLDR_VERSION}. Do not edit.\n");
+        file.push_str(&generate());
+        std::fs::write(
+            src.join(module)
+                .join("data.rs"),
+            file,
+        )
+        .unwrap();
+    }
+}
+
+/// Loads the data of a locale, and returns it with the locale it was found for,
+/// which is `None` if it is the requested one.
+pub fn load<M: DataMarker>(
+    provider: &impl DataProvider<M>,
+    locale: &str,
+    attributes: &str,
+) -> Option<(DataPayload<M>, Option<String>)> {
+    let locale: icu::locale::Locale = locale.parse().ok()?;
+    let locale = DataLocale::from(&locale);
+    let response = provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                DataMarkerAttributes::from_str_or_panic(attributes),
+                &locale,
+            ),
+            ..Default::default()
+        })
+        .ok()?;
+    Some((
+        response.payload,
+        response
+            .metadata
+            .locale
+            .map(|locale| locale.to_string()),
+    ))
+}
+
+/// Returns the CLDR code of a language, which differs from ISO 639-1 for Tagalog.
+pub fn cldr_code(lang: rocket_lang::LangCode) -> &'static str {
+    match lang.as_str() {
+        "tl" => "fil",
+        code => code,
+    }
+}
+
+/// The compiled data of ICU4X, which can list the locales and attributes it has data for.
+pub struct Iterable;
+
+#[allow(unused_imports)]
+const _: () = {
+    use icu_experimental_data::*;
+    pub mod icu {
+        pub use ::icu::*;
+    }
+    make_provider!(Iterable);
+    impl_currency_essentials_v1!(Iterable, ITER);
+    impl_currency_symbols_v1!(Iterable, ITER);
+    impl_units_names_length_core_v1!(Iterable, ITER);
+    impl_units_names_length_extended_v1!(Iterable, ITER);
+    impl_units_names_length_outlier_v1!(Iterable, ITER);
+    impl_units_names_mass_core_v1!(Iterable, ITER);
+    impl_units_names_mass_extended_v1!(Iterable, ITER);
+    impl_units_names_mass_outlier_v1!(Iterable, ITER);
+    impl_units_names_volume_core_v1!(Iterable, ITER);
+    impl_units_names_volume_extended_v1!(Iterable, ITER);
+    impl_units_names_volume_outlier_v1!(Iterable, ITER);
+};
+
+/// Returns the locales and attributes with data of their own, the data of the other
+/// ones being the same as the data they fall back to.
+pub fn ids<M: DataMarker>() -> Vec<(String, String)>
+where
+    Iterable: IterableDataProvider<M>,
+{
+    let mut ids: Vec<(String, String)> = Iterable
+        .iter_ids()
+        .unwrap()
+        .into_iter()
+        .map(|id| {
+            (
+                id.locale.to_string(),
+                id.marker_attributes
+                    .to_string(),
+            )
+        })
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Every region with likely subtags, and the macro regions used by locales.
+pub fn regions() -> Vec<String> {
+    use icu::locale::{
+        provider::{Baked, LocaleLikelySubtagsExtendedV1, LocaleLikelySubtagsScriptRegionV1},
+        subtags::Region,
+    };
+    use icu_locale_fallback::provider::LocaleLikelySubtagsLanguageV1;
+    let mut regions: Vec<Region> = vec![];
+    let (language, _) =
+        load::<LocaleLikelySubtagsLanguageV1>(&icu_locale_fallback::provider::Baked, "und", "")
+            .unwrap();
+    let language = language.get();
+    regions.push(language.und.2);
+    regions.extend(
+        language
+            .language
+            .iter_copied_values()
+            .map(|(_, (_, region))| region),
+    );
+    regions.extend(
+        language
+            .language_script
+            .iter_copied_values()
+            .map(|(_, region)| region),
+    );
+    let (script_region, _) = load::<LocaleLikelySubtagsScriptRegionV1>(&Baked, "und", "").unwrap();
+    let (extended, _) = load::<LocaleLikelySubtagsExtendedV1>(&Baked, "und", "").unwrap();
+    let script_region = script_region.get();
+    let extended = extended.get();
+    for (script, region) in [
+        (&script_region.script, &script_region.region),
+        (&extended.script, &extended.region),
+    ] {
+        regions.extend(
+            script
+                .iter_copied_values()
+                .map(|(_, (_, region))| region),
+        );
+        regions.extend(
+            region
+                .iter_keys()
+                .filter_map(|region| {
+                    Region::try_from_str(
+                        region
+                            .try_into_tinystr()
+                            .ok()?
+                            .as_str(),
+                    )
+                    .ok()
+                }),
+        );
+    }
+    let mut regions: Vec<String> = regions
+        .iter()
+        .map(|region| region.to_string())
+        .collect();
+    regions.extend(["001", "150", "419"].map(String::from));
+    regions.sort();
+    regions.dedup();
+    regions
+}
+
+/// Writes a string literal, escaping the spaces and the invisible formatting characters.
+pub fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => write!(out, "\\{c}").unwrap(),
+            ' ' => out.push(c),
+            c if c.is_whitespace() || c.is_control() || invisible(c) => {
+                write!(out, "\\u{{{:X}}}", c as u32).unwrap()
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Returns true for the formatting characters without glyph, such as bidi marks.
+fn invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{61C}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{206F}' | '\u{FEFF}'
+    )
+}
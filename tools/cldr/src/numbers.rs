@@ -0,0 +1,226 @@
+//! Number symbols and patterns, for `src/numbers/data.rs`.
+use crate::{cldr_code, load, quote, regions};
+use icu::decimal::provider::{Baked, DecimalSymbolsV1};
+use icu_experimental::dimension::provider::percent::PercentEssentialsV1;
+use rocket_lang::LangCode;
+use std::fmt::Write;
+use writeable::Writeable;
+
+/// The fields of a `NumberFormat`, as Rust expressions.
+#[derive(PartialEq)]
+pub struct Format {
+    decimal: String,
+    group: String,
+    percent_sign: String,
+    pub minus_sign: String,
+    pub pattern: String,
+    percent_pattern: String,
+    min_grouping: u8,
+    digits: &'static str,
+    native_digits: &'static str,
+}
+
+/// The `Digits` variant of a CLDR numbering system.
+fn digits(numbering_system: &str) -> &'static str {
+    match numbering_system {
+        "latn" => "Latin",
+        "arab" => "ArabicIndic",
+        "arabext" => "ExtendedArabicIndic",
+        "beng" => "Bengali",
+        "deva" => "Devanagari",
+        "gujr" => "Gujarati",
+        "guru" => "Gurmukhi",
+        "knda" => "Kannada",
+        "khmr" => "Khmer",
+        "laoo" => "Lao",
+        "mlym" => "Malayalam",
+        "mymr" => "Myanmar",
+        "orya" => "Odia",
+        "tamldec" => "Tamil",
+        "telu" => "Telugu",
+        "thai" => "Thai",
+        "tibt" => "Tibetan",
+        other => panic!("unsupported numbering system {other}"),
+    }
+}
+
+/// The native numbering system of the languages that default to latin digits.
+/// ICU4X drops the `native` numbering system of CLDR, so it is listed here.
+fn native(lang: &str) -> &'static str {
+    match lang {
+        "ar" | "sd" => "arab",
+        "fa" | "ps" | "ur" => "arabext",
+        "bn" | "as" => "beng",
+        "hi" | "mr" | "ne" | "sa" => "deva",
+        "gu" => "gujr",
+        "pa" => "guru",
+        "kn" => "knda",
+        "km" => "khmr",
+        "lo" => "laoo",
+        "ml" => "mlym",
+        "my" => "mymr",
+        "or" => "orya",
+        "ta" => "tamldec",
+        "te" => "telu",
+        "th" => "thai",
+        "bo" | "dz" => "tibt",
+        _ => "latn",
+    }
+}
+
+pub fn format(lang: &str, locale: &str) -> Format {
+    format_with(lang, locale, "").unwrap()
+}
+
+fn format_with(lang: &str, locale: &str, system: &str) -> Option<Format> {
+    let (symbols, _) = load::<DecimalSymbolsV1>(&Baked, locale, system)?;
+    let symbols = symbols.get();
+    let (percent, _) =
+        load::<PercentEssentialsV1>(&icu_experimental::provider::Baked, locale, "").unwrap();
+    let sizes = symbols.grouping_sizes;
+    let integer = match (sizes.primary, sizes.secondary) {
+        (0, _) => "0".to_string(),
+        (primary, secondary) if primary == secondary => {
+            format!("#,{}0", "#".repeat(primary as usize - 1))
+        }
+        (primary, secondary) => format!(
+            "#,{},{}0",
+            "#".repeat(secondary as usize),
+            "#".repeat(primary as usize - 1)
+        ),
+    };
+    // the percent pattern is a placeholder for the number, and a literal with the sign.
+    let percent = percent
+        .get()
+        .unsigned_pattern
+        .interpolate(["\0"])
+        .write_to_string()
+        .into_owned();
+    let (prefix, suffix) = percent
+        .split_once('\0')
+        .unwrap();
+    let literal = if prefix.is_empty() { suffix } else { prefix };
+    let sign = literal.trim_matches(|c: char| c.is_whitespace());
+    let default = symbols.numsys();
+    let native = if default == "latn" {
+        native(lang)
+    } else {
+        default
+    };
+    Some(Format {
+        decimal: symbols
+            .decimal_separator()
+            .to_string(),
+        group: symbols
+            .grouping_separator()
+            .to_string(),
+        percent_sign: sign.to_string(),
+        minus_sign: symbols
+            .minus_sign_prefix()
+            .to_string(),
+        pattern: format!("{integer}.###"),
+        percent_pattern: format!(
+            "{}{integer}{}",
+            prefix.replace(sign, "%"),
+            suffix.replace(sign, "%")
+        ),
+        min_grouping: sizes.min_grouping.max(1),
+        digits: digits(default),
+        native_digits: digits(native),
+    })
+}
+
+impl Format {
+    fn rust(&self) -> String {
+        format!(
+            "NumberFormat {{ decimal: {}, group: {}, percent_sign: {}, minus_sign: {}, pattern: {}, \
+             percent_pattern: {}, min_grouping: {}, digits: Digits::{}, native_digits: Digits::{} }}",
+            quote(&self.decimal),
+            quote(&self.group),
+            quote(&self.percent_sign),
+            quote(&self.minus_sign),
+            quote(&self.pattern),
+            quote(&self.percent_pattern),
+            self.min_grouping,
+            self.digits,
+            self.native_digits,
+        )
+    }
+}
+
+pub fn generate() -> String {
+    let regions = regions();
+    let mut out =
+        String::from("use super::{Digits, NumberFormat};\nuse crate::LangCode::{self, *};\n\n");
+    writeln!(
+        out,
+        "/// The format of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [NumberFormat; {}] = [",
+        LangCode::ALL_CODES.len()
+    )
+    .unwrap();
+    let mut overrides = vec![];
+    for lang in LangCode::ALL_CODES {
+        let code = cldr_code(*lang);
+        let format = format(code, code);
+        writeln!(out, "    /* {} */ {},", lang.as_str(), format.rust()).unwrap();
+        for region in &regions {
+            let regional = self::format(code, &format!("{code}-{region}"));
+            if regional != format {
+                overrides.push((lang, region, regional));
+            }
+        }
+    }
+    out.push_str("];\n\n");
+    out.push_str(
+        "/// The formats of the regions that differ from the format of their language.\n\
+         pub(super) static REGIONS: &[(LangCode, &str, NumberFormat)] = &[\n",
+    );
+    for (lang, region, format) in overrides {
+        writeln!(out, "    ({lang:?}, {}, {}),", quote(region), format.rust()).unwrap();
+    }
+    out.push_str("];\n\n");
+    out.push_str(
+        "/// The decimal separator, group separator and minus sign of the numbering systems,\n\
+         /// when every language with CLDR data for them uses the same ones.\n\
+         pub(super) static SYMBOLS: &[(Digits, &str, &str, &str)] = &[\n",
+    );
+    let mut systems: Vec<(&str, Vec<[String; 3]>)> = vec![];
+    for lang in LangCode::ALL_CODES {
+        let code = cldr_code(*lang);
+        for system in [native(code), "arab", "arabext"] {
+            let Some(format) = format_with(code, code, system) else {
+                continue;
+            };
+            let symbols = [format.decimal, format.group, format.minus_sign];
+            match systems
+                .iter_mut()
+                .find(|(s, _)| *s == system)
+            {
+                Some((_, all)) if !all.contains(&symbols) => all.push(symbols),
+                Some(_) => {}
+                None => systems.push((system, vec![symbols])),
+            }
+        }
+    }
+    systems.sort();
+    for (system, symbols) in systems {
+        if system == "latn" {
+            continue;
+        }
+        match &symbols[..] {
+            [[decimal, group, minus]] => writeln!(
+                out,
+                "    (Digits::{}, {}, {}, {}),",
+                digits(system),
+                quote(decimal),
+                quote(group),
+                quote(minus)
+            )
+            .unwrap(),
+            _ => eprintln!("{system} has different symbols: {symbols:?}"),
+        }
+    }
+    out.push_str("];\n");
+    out
+}
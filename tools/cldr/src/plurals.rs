@@ -0,0 +1,147 @@
+//! Cardinal plural rules, for `src/plurals/data.rs`.
+use crate::{cldr_code, load};
+use icu::plurals::provider::{
+    rules::{
+        reference::ast::{Operand, Operator, RangeListItem, Relation, Rule},
+        runtime,
+    },
+    Baked, PluralsCardinalV1,
+};
+use rocket_lang::LangCode;
+use std::fmt::Write;
+
+/// Writes a relation as a Rust expression. Numbers are written without trailing
+/// zeros, so the operands without them are the same as the ones with them.
+fn relation(relation: &Relation) -> String {
+    let operand = match relation.expression.operand {
+        Operand::N => "N",
+        Operand::I => "I",
+        Operand::V | Operand::W => "V",
+        Operand::F | Operand::T => "F",
+        Operand::C | Operand::E => "E",
+    };
+    let ranges: Vec<String> = relation
+        .range_list
+        .0
+        .iter()
+        .map(|item| match item {
+            RangeListItem::Range(range) => format!("({}, {})", range.start().0, range.end().0),
+            RangeListItem::Value(value) => format!("({0}, {0})", value.0),
+        })
+        .collect();
+    format!(
+        "Relation {{ operand: {operand}, modulus: {}, equal: {}, ranges: &[{}] }}",
+        relation
+            .expression
+            .modulus
+            .as_ref()
+            .map_or(0, |modulus| modulus.0),
+        relation.operator == Operator::Eq,
+        ranges.join(", ")
+    )
+}
+
+/// Writes a rule as a Rust expression, `&[]` if the category is unused.
+fn rule(rule: Option<&runtime::ast::Rule>) -> String {
+    let Some(rule) = rule else {
+        return String::from("&[]");
+    };
+    let conditions: Vec<String> = Rule::from(rule)
+        .condition
+        .0
+        .iter()
+        .filter(|and| !and.0.is_empty())
+        .map(|and| {
+            let relations: Vec<String> = and
+                .0
+                .iter()
+                .map(relation)
+                .collect();
+            format!("&[{}]", relations.join(", "))
+        })
+        .collect();
+    format!("&[\n        {},\n    ]", conditions.join(",\n        "))
+}
+
+/// The rules of a locale, as a Rust expression with the CLDR rules as comments,
+/// or `None` if every number is `other`.
+fn rules(locale: &str) -> Option<String> {
+    let (data, _) = load::<PluralsCardinalV1>(&Baked, locale, "")
+        .unwrap_or_else(|| panic!("no data for {locale}"));
+    let data = data.get();
+    let categories = [
+        ("zero", data.zero.as_ref()),
+        ("one", data.one.as_ref()),
+        ("two", data.two.as_ref()),
+        ("few", data.few.as_ref()),
+        ("many", data.many.as_ref()),
+    ];
+    if categories
+        .iter()
+        .all(|(_, rule)| rule.is_none())
+    {
+        return None;
+    }
+    let mut out = String::new();
+    for (name, rule) in &categories {
+        if let Some(rule) = rule {
+            writeln!(out, "// {name}: {rule}").unwrap();
+        }
+    }
+    let rules: Vec<String> = categories
+        .iter()
+        .map(|(_, r)| rule(*r))
+        .collect();
+    write!(out, "[\n    {},\n]", rules.join(",\n    ")).unwrap();
+    Some(out)
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use super::{Operand::*, Relation, Rules};\n\n");
+    // languages with the same rules share a static.
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for lang in LangCode::ALL_CODES {
+        let index = rules(cldr_code(*lang)).map(|rules| {
+            all.iter()
+                .position(|r| *r == rules)
+                .unwrap_or_else(|| {
+                    all.push(rules);
+                    all.len() - 1
+                })
+        });
+        languages.push((lang.as_str(), index));
+    }
+    for (index, rules) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        let (comments, rules) = rules
+            .rsplit_once("\n[")
+            .unwrap();
+        writeln!(
+            out,
+            "// {}\n{comments}\nstatic P{index}: Rules = [{rules};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The rules of every language, indexed by the `LangCode` discriminant,\n\
+         /// `None` for the languages where every number is `other`.\n\
+         pub(super) static LANGUAGES: [Option<&Rules>; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&P{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,138 @@
+//! Relative time patterns by unit and plural category, for `src/relative/data.rs`.
+use crate::{cldr_code, load, quote};
+use icu_experimental::{
+    provider::Baked,
+    relativetime::provider::{
+        LongDayRelativeV1, LongHourRelativeV1, LongMinuteRelativeV1, LongMonthRelativeV1,
+        LongSecondRelativeV1, LongWeekRelativeV1, LongYearRelativeV1, RelativeTimePatternData,
+    },
+};
+use icu_provider::prelude::*;
+use rocket_lang::LangCode;
+use std::fmt::Write;
+use writeable::Writeable;
+
+/// Writes forms as `[zero, one, two, few, many, other]`, leaving empty the ones
+/// that are the same as `other`.
+fn forms(
+    elements: &icu::plurals::provider::PluralElementsPackedCow<
+        '_,
+        icu::pattern::SinglePlaceholderPattern,
+    >,
+) -> String {
+    let elements = elements.elements.decode();
+    let pattern = |(_, pattern): &(_, &icu::pattern::SinglePlaceholderPattern)| {
+        pattern
+            .interpolate(["{0}"])
+            .write_to_string()
+            .into_owned()
+    };
+    let forms = [
+        pattern(elements.zero()),
+        pattern(elements.one()),
+        pattern(elements.two()),
+        pattern(elements.few()),
+        pattern(elements.many()),
+        pattern(elements.other()),
+    ];
+    let forms: Vec<String> = forms
+        .iter()
+        .enumerate()
+        .map(|(i, form)| match i < 5 && *form == forms[5] {
+            true => quote(""),
+            false => quote(form),
+        })
+        .collect();
+    format!("[{}]", forms.join(", "))
+}
+
+/// The patterns of a unit in a locale, as a Rust expression.
+fn unit<M>(locale: &str) -> String
+where
+    M: DataMarker<DataStruct = RelativeTimePatternData<'static>>,
+    Baked: DataProvider<M>,
+{
+    let (data, _) = load::<M>(&Baked, locale, "").unwrap_or_else(|| panic!("no data for {locale}"));
+    let data = data.get();
+    let current = data
+        .relatives
+        .get(&0)
+        .map_or(String::new(), String::from);
+    format!(
+        "Patterns {{\n        current: {},\n        past: {},\n        future: {},\n    }}",
+        quote(&current),
+        forms(&data.past),
+        forms(&data.future)
+    )
+}
+
+/// The patterns of every unit in a locale, in the order of the `RelativeUnit` variants.
+fn names(locale: &str) -> String {
+    let units = [
+        unit::<LongSecondRelativeV1>(locale),
+        unit::<LongMinuteRelativeV1>(locale),
+        unit::<LongHourRelativeV1>(locale),
+        unit::<LongDayRelativeV1>(locale),
+        unit::<LongWeekRelativeV1>(locale),
+        unit::<LongMonthRelativeV1>(locale),
+        unit::<LongYearRelativeV1>(locale),
+    ];
+    format!("[\n    {},\n]", units.join(",\n    "))
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use super::{Names, Patterns};\n\n");
+    let root = names("und");
+    writeln!(
+        out,
+        "/// The patterns of the root locale, used by the languages without patterns of their own.\n\
+         pub(super) static ROOT: Names = {root};\n"
+    )
+    .unwrap();
+    // languages with the same patterns share a static.
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for lang in LangCode::ALL_CODES {
+        let names = names(cldr_code(*lang));
+        let index = if names == root {
+            None
+        } else if let Some(index) = all
+            .iter()
+            .position(|n| *n == names)
+        {
+            Some(index)
+        } else {
+            all.push(names);
+            Some(all.len() - 1)
+        };
+        languages.push((lang.as_str(), index));
+    }
+    for (index, names) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        writeln!(
+            out,
+            "// {}\nstatic R{index}: Names = {names};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The patterns of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [Option<&Names>; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&R{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,166 @@
+//! Unit names by width and plural category, for `src/units/data.rs`.
+use crate::{cldr_code, load, quote};
+use icu_experimental::{
+    dimension::provider::units::{categorized_display_names::*, display_names::UnitsDisplayNames},
+    provider::Baked,
+};
+use icu_provider::prelude::*;
+use rocket_lang::LangCode;
+use std::fmt::Write;
+use writeable::Writeable;
+
+/// The CLDR names of the units, in the order of the `Unit` variants.
+const UNITS: &[&str] = &[
+    "millimeter",
+    "centimeter",
+    "meter",
+    "kilometer",
+    "inch",
+    "foot",
+    "mile",
+    "gram",
+    "kilogram",
+    "ounce",
+    "pound",
+    "milliliter",
+    "liter",
+    "gallon",
+];
+
+/// Loads the patterns of a unit, as `[zero, one, two, few, many, other]`, from the
+/// most specific locale among the core, extended and outlier data of its category.
+fn patterns<C, E, O>(locale: &str, attributes: &str) -> [String; 6]
+where
+    C: DataMarker<DataStruct = UnitsDisplayNames<'static>>,
+    E: DataMarker<DataStruct = UnitsDisplayNames<'static>>,
+    O: DataMarker<DataStruct = UnitsDisplayNames<'static>>,
+    Baked: DataProvider<C> + DataProvider<E> + DataProvider<O>,
+{
+    let rank = |found: Option<String>| match found.as_deref() {
+        None => 0,
+        Some("und") => 2,
+        Some(_) => 1,
+    };
+    let candidates = [
+        load::<C>(&Baked, locale, attributes).map(|(data, found)| (rank(found), data.cast())),
+        load::<E>(&Baked, locale, attributes).map(|(data, found)| (rank(found), data.cast())),
+        load::<O>(&Baked, locale, attributes).map(|(data, found)| (rank(found), data.cast())),
+    ];
+    let data: DataPayload<C> = candidates
+        .into_iter()
+        .flatten()
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, data)| data)
+        .unwrap_or_else(|| panic!("no data for {locale} {attributes}"));
+    let elements = data.get().elements.decode();
+    let pattern = |(_, pattern): &(_, &icu::pattern::SinglePlaceholderPattern)| {
+        pattern
+            .interpolate(["{0}"])
+            .write_to_string()
+            .into_owned()
+    };
+    [
+        pattern(elements.zero()),
+        pattern(elements.one()),
+        pattern(elements.two()),
+        pattern(elements.few()),
+        pattern(elements.many()),
+        pattern(elements.other()),
+    ]
+}
+
+/// The names of every unit in a language, as a Rust expression.
+fn names(locale: &str) -> String {
+    let mut units = vec![];
+    for unit in UNITS {
+        let widths = ["long", "short", "narrow"].map(|width| {
+            let attributes = format!("{width}-{unit}");
+            let forms = match *unit {
+                "gram" | "kilogram" | "ounce" | "pound" => patterns::<
+                    UnitsNamesMassCoreV1,
+                    UnitsNamesMassExtendedV1,
+                    UnitsNamesMassOutlierV1,
+                >(locale, &attributes),
+                "milliliter" | "liter" | "gallon" => patterns::<
+                    UnitsNamesVolumeCoreV1,
+                    UnitsNamesVolumeExtendedV1,
+                    UnitsNamesVolumeOutlierV1,
+                >(locale, &attributes),
+                _ => patterns::<
+                    UnitsNamesLengthCoreV1,
+                    UnitsNamesLengthExtendedV1,
+                    UnitsNamesLengthOutlierV1,
+                >(locale, &attributes),
+            };
+            // the forms that are the same as `other` are left empty.
+            let forms: Vec<String> = forms
+                .iter()
+                .enumerate()
+                .map(|(i, form)| match i < 5 && *form == forms[5] {
+                    true => quote(""),
+                    false => quote(form),
+                })
+                .collect();
+            format!("[{}]", forms.join(", "))
+        });
+        units.push(format!("[{}]", widths.join(", ")));
+    }
+    format!("[\n    {},\n]", units.join(",\n    "))
+}
+
+pub fn generate() -> String {
+    let mut out = String::from("use super::Names;\n\n");
+    let root = names("und");
+    writeln!(
+        out,
+        "/// The names of the root locale, used by the languages without names of their own.\n\
+         pub(super) static ROOT: Names = {root};\n"
+    )
+    .unwrap();
+    // languages with the same names share a static.
+    let mut all: Vec<String> = vec![];
+    let mut languages = vec![];
+    for lang in LangCode::ALL_CODES {
+        let names = names(cldr_code(*lang));
+        let index = if names == root {
+            None
+        } else if let Some(index) = all
+            .iter()
+            .position(|n| *n == names)
+        {
+            Some(index)
+        } else {
+            all.push(names);
+            Some(all.len() - 1)
+        };
+        languages.push((lang.as_str(), index));
+    }
+    for (index, names) in all.iter().enumerate() {
+        let langs: Vec<&str> = languages
+            .iter()
+            .filter(|(_, i)| *i == Some(index))
+            .map(|(lang, _)| *lang)
+            .collect();
+        writeln!(
+            out,
+            "// {}\nstatic N{index}: Names = {names};\n",
+            langs.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// The names of every language, indexed by the `LangCode` discriminant.\n\
+         pub(super) static LANGUAGES: [Option<&Names>; {}] = [",
+        languages.len()
+    )
+    .unwrap();
+    for (lang, index) in &languages {
+        match index {
+            Some(index) => writeln!(out, "    /* {lang} */ Some(&N{index}),").unwrap(),
+            None => writeln!(out, "    /* {lang} */ None,").unwrap(),
+        }
+    }
+    out.push_str("];\n");
+    out
+}